@@ -111,6 +111,16 @@ impl BenchmarkGroupMetrics {
 impl BenchmarkParams {
     pub fn format_params(&self) -> String {
         let actors_info = self.format_actors_info();
+        if self.benchmark_kind == BenchmarkKind::ConnectionStorm {
+            let tls = if self.tls { "TLS" } else { "no TLS" };
+            let connections = self.connections as u64;
+            return format!(
+                "{actors_info}  •  {} connections/connector  •  {} connections  •  {tls}",
+                connections.human_count_bare(),
+                (connections * self.producers as u64).human_count_bare(),
+            );
+        }
+
         let message_batches = self.message_batches as u64;
         let messages_per_batch = self.messages_per_batch as u64;
         let message_size = self.message_size as u64;
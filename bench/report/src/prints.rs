@@ -23,11 +23,17 @@ use tracing::info;
 use crate::{
     benchmark_kind::BenchmarkKind, group_metrics::BenchmarkGroupMetrics,
     group_metrics_kind::GroupMetricsKind, report::BenchmarkReport,
+    sweep_report::BenchmarkSweepReport,
 };
 
 impl BenchmarkReport {
     pub fn print_summary(&self) {
         let kind = self.params.benchmark_kind;
+        if kind == BenchmarkKind::ConnectionStorm {
+            self.print_connection_storm_summary();
+            return;
+        }
+
         let total_messages_sent: u64 = self.params.messages_per_batch as u64
             * self.params.message_batches as u64
             * self.params.producers as u64;
@@ -76,20 +82,112 @@ impl BenchmarkReport {
         let params_print = format!("Benchmark: {kind}, {producers}{consumers}{streams}{topics}{partitions}{consumer_groups}{total_messages}{messages_per_batch}{message_batches}{message_size}{total_size}\n",).blue();
 
         info!("{}", params_print);
+        info!(
+            "{}",
+            format!(
+                "Seed: {}, reproduce with: {}\n",
+                self.manifest.seed, self.params.bench_command
+            )
+            .blue()
+        );
 
         self.group_metrics
             .iter()
             .for_each(|s| info!("{}\n", s.formatted_string()));
     }
+
+    fn print_connection_storm_summary(&self) {
+        let connections = self.params.connections as u64 * self.params.producers as u64;
+        let tls = if self.params.tls {
+            "with TLS"
+        } else {
+            "without TLS"
+        };
+        println!();
+        let params_print = format!(
+            "Benchmark: {}, {} connectors, {} connections per connector, {} connections {tls}\n",
+            self.params.benchmark_kind,
+            self.params.producers,
+            self.params.connections.human_count_bare(),
+            connections.human_count_bare()
+        )
+        .blue();
+
+        info!("{}", params_print);
+        info!(
+            "{}",
+            format!(
+                "Seed: {}, reproduce with: {}\n",
+                self.manifest.seed, self.params.bench_command
+            )
+            .blue()
+        );
+
+        self.group_metrics
+            .iter()
+            .for_each(|s| info!("{}\n", s.formatted_connections_string()));
+    }
+}
+
+impl BenchmarkSweepReport {
+    pub fn print_summary(&self) {
+        println!();
+        info!(
+            "{}",
+            format!("Sweep finished, {} runs:\n", self.runs.len()).blue()
+        );
+        for run in &self.runs {
+            let params = format!(
+                "{}, {} per message, {} messages per batch",
+                run.params.format_actors_info(),
+                run.params.message_size.human_count_bytes(),
+                run.params.messages_per_batch
+            );
+            for metrics in &run.group_metrics {
+                info!(
+                    "{}: {}: total throughput: {:.2} MB/s, {:.0} messages/s, p50 latency: {:.2} ms, p99 latency: {:.2} ms",
+                    params,
+                    metrics.summary.kind,
+                    metrics.summary.total_throughput_megabytes_per_second,
+                    metrics.summary.total_throughput_messages_per_second,
+                    metrics.summary.average_p50_latency_ms,
+                    metrics.summary.average_p99_latency_ms
+                );
+            }
+        }
+    }
 }
 
 impl BenchmarkGroupMetrics {
+    /// Formats the results of the connection storm, where each message is a completed connection
+    /// and the latency is the time it took to connect and log in.
+    pub fn formatted_connections_string(&self) -> ColoredString {
+        format!(
+            "Connectors Results: Total rate: {:.0} connections/s, average rate per connector: {:.0} connections/s, \
+            p50 handshake latency: {:.2} ms, p90 handshake latency: {:.2} ms, p95 handshake latency: {:.2} ms, \
+            p99 handshake latency: {:.2} ms, p999 handshake latency: {:.2} ms, p9999 handshake latency: {:.2} ms, \
+            average handshake latency: {:.2} ms, median handshake latency: {:.2} ms",
+            self.summary.total_throughput_messages_per_second,
+            self.summary.average_throughput_messages_per_second,
+            self.summary.average_p50_latency_ms,
+            self.summary.average_p90_latency_ms,
+            self.summary.average_p95_latency_ms,
+            self.summary.average_p99_latency_ms,
+            self.summary.average_p999_latency_ms,
+            self.summary.average_p9999_latency_ms,
+            self.summary.average_latency_ms,
+            self.summary.average_median_latency_ms,
+        )
+        .color(Color::Green)
+    }
+
     pub fn formatted_string(&self) -> ColoredString {
         let (prefix, color) = match self.summary.kind {
             GroupMetricsKind::Producers => ("Producers Results", Color::Green),
             GroupMetricsKind::Consumers => ("Consumers Results", Color::Green),
             GroupMetricsKind::ProducersAndConsumers => ("Aggregate Results", Color::Red),
             GroupMetricsKind::ProducingConsumers => ("Producing Consumer Results", Color::Red),
+            GroupMetricsKind::Connectors => ("Connectors Results", Color::Green),
         };
 
         let actor = self.summary.kind.actor();
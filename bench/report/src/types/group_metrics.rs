@@ -16,7 +16,10 @@
  * under the License.
  */
 
-use super::{group_metrics_summary::BenchmarkGroupMetricsSummary, time_series::TimeSeries};
+use super::{
+    group_metrics_summary::BenchmarkGroupMetricsSummary,
+    time_series::{DownsampledMetricsTimeSeries, TimeSeries},
+};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Clone, PartialEq, Deserialize)]
@@ -25,4 +28,6 @@ pub struct BenchmarkGroupMetrics {
     pub avg_throughput_mb_ts: TimeSeries,
     pub avg_throughput_msg_ts: TimeSeries,
     pub avg_latency_ts: TimeSeries,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub downsampled_ts: Option<DownsampledMetricsTimeSeries>,
 }
@@ -17,7 +17,8 @@
  */
 
 use super::{
-    individual_metrics_summary::BenchmarkIndividualMetricsSummary, time_series::TimeSeries,
+    individual_metrics_summary::BenchmarkIndividualMetricsSummary,
+    time_series::{DownsampledMetricsTimeSeries, TimeSeries},
 };
 use serde::{Deserialize, Serialize};
 
@@ -27,4 +28,6 @@ pub struct BenchmarkIndividualMetrics {
     pub throughput_mb_ts: TimeSeries,
     pub throughput_msg_ts: TimeSeries,
     pub latency_ts: TimeSeries,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub downsampled_ts: Option<DownsampledMetricsTimeSeries>,
 }
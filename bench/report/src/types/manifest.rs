@@ -0,0 +1,42 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use super::hardware::BenchmarkHardware;
+use serde::{Deserialize, Serialize};
+
+/// Everything needed to reproduce the benchmark run with the byte-identical workload.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct BenchmarkManifest {
+    /// Seed of all the randomness in the benchmark
+    pub seed: u64,
+
+    /// Command line arguments the benchmark was started with
+    pub args: Vec<String>,
+
+    /// Version of the iggy-bench binary
+    pub bench_version: String,
+
+    /// Version of the iggy SDK used by the benchmark
+    pub sdk_version: String,
+
+    /// Version of the benchmarked server
+    pub server_version: String,
+
+    /// Hardware the benchmark was run on
+    pub hardware: BenchmarkHardware,
+}
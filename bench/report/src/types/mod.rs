@@ -24,8 +24,10 @@ pub mod group_metrics_summary;
 pub mod hardware;
 pub mod individual_metrics;
 pub mod individual_metrics_summary;
+pub mod manifest;
 pub mod params;
 pub mod report;
 pub mod server_stats;
+pub mod sweep_report;
 pub mod time_series;
 pub mod transport;
@@ -40,6 +40,12 @@ pub struct BenchmarkParams {
     pub pretty_name: String,
     pub bench_command: String,
     pub params_identifier: String,
+    /// Number of connections opened by each connector, only used by the connection storm benchmark.
+    #[serde(default)]
+    pub connections: u32,
+    /// Whether the TCP connections were secured with TLS.
+    #[serde(default)]
+    pub tls: bool,
 }
 
 impl BenchmarkParams {
@@ -67,6 +73,7 @@ impl BenchmarkParams {
                     self.producers, self.consumer_groups
                 )
             }
+            BenchmarkKind::ConnectionStorm => format!("{} connectors", self.producers),
         }
     }
 }
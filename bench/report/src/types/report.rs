@@ -20,6 +20,7 @@ use super::server_stats::BenchmarkServerStats;
 use crate::group_metrics::BenchmarkGroupMetrics;
 use crate::individual_metrics::BenchmarkIndividualMetrics;
 use crate::types::hardware::BenchmarkHardware;
+use crate::types::manifest::BenchmarkManifest;
 use crate::types::params::BenchmarkParams;
 use serde::{Deserialize, Serialize};
 use std::path::Path;
@@ -42,6 +43,10 @@ pub struct BenchmarkReport {
     /// Benchmark parameters
     pub params: BenchmarkParams,
 
+    /// Benchmark run manifest, used to reproduce the same workload
+    #[serde(default)]
+    pub manifest: BenchmarkManifest,
+
     /// Benchmark metrics for all actors of same type (all producers, all consumers or all actors)
     pub group_metrics: Vec<BenchmarkGroupMetrics>,
 
@@ -0,0 +1,95 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::report::BenchmarkReport;
+use serde::{Deserialize, Serialize};
+use std::fmt::Write;
+use std::path::Path;
+use uuid::Uuid;
+
+const CSV_HEADER: &str = "message_size,messages_per_batch,producers,consumers,group,\
+total_throughput_megabytes_per_second,total_throughput_messages_per_second,\
+average_p50_latency_ms,average_p90_latency_ms,average_p99_latency_ms,\
+average_p999_latency_ms,average_latency_ms";
+
+/// Combined report of the benchmark runs executed for each combination of the swept parameters.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct BenchmarkSweepReport {
+    /// Sweep unique identifier
+    pub uuid: Uuid,
+
+    /// Timestamp when the sweep was finished
+    pub timestamp: String,
+
+    /// Reports of the sweep runs, in the order they were executed
+    pub runs: Vec<BenchmarkReport>,
+}
+
+impl BenchmarkSweepReport {
+    pub fn new(timestamp: String, runs: Vec<BenchmarkReport>) -> Self {
+        Self {
+            uuid: Uuid::new_v4(),
+            timestamp,
+            runs,
+        }
+    }
+
+    /// Returns the response surface of the sweep, a single row for the metrics
+    /// of each group of actors in each run.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from(CSV_HEADER);
+        csv.push('\n');
+        for run in &self.runs {
+            for metrics in &run.group_metrics {
+                let summary = &metrics.summary;
+                let _ = writeln!(
+                    csv,
+                    "{},{},{},{},{},{:.3},{:.3},{:.3},{:.3},{:.3},{:.3},{:.3}",
+                    run.params.message_size,
+                    run.params.messages_per_batch,
+                    run.params.producers,
+                    run.params.consumers,
+                    summary.kind,
+                    summary.total_throughput_megabytes_per_second,
+                    summary.total_throughput_messages_per_second,
+                    summary.average_p50_latency_ms,
+                    summary.average_p90_latency_ms,
+                    summary.average_p99_latency_ms,
+                    summary.average_p999_latency_ms,
+                    summary.average_latency_ms,
+                );
+            }
+        }
+        csv
+    }
+
+    pub fn dump_to_json(&self, output_dir: &str) {
+        std::fs::create_dir_all(output_dir).expect("Failed to create output directory");
+
+        let report_path = Path::new(output_dir).join("sweep_report.json");
+        let report_json = serde_json::to_string(self).unwrap();
+        std::fs::write(report_path, report_json).expect("Failed to write sweep report to file");
+    }
+
+    pub fn dump_to_csv(&self, output_dir: &str) {
+        std::fs::create_dir_all(output_dir).expect("Failed to create output directory");
+
+        let csv_path = Path::new(output_dir).join("sweep.csv");
+        std::fs::write(csv_path, self.to_csv()).expect("Failed to write sweep CSV to file");
+    }
+}
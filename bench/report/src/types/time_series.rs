@@ -56,3 +56,49 @@ impl TimeSeries {
             .collect()
     }
 }
+
+/// A bucket of downsampled time series, aggregating the points within its time range
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct TimeBucket {
+    /// Start of the bucket
+    pub time_s: f64,
+    pub mean: f64,
+    pub max: f64,
+    pub p99: f64,
+}
+
+/// Buckets of the same size covering a contiguous time range
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct TimeSeriesTier {
+    pub bucket_secs: f64,
+    pub buckets: Vec<TimeBucket>,
+}
+
+/// Time series stored with multiple resolutions, the recent data with the finest one
+/// and the older data rolled up into the coarser buckets, ordered from the finest tier
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct DownsampledTimeSeries {
+    pub tiers: Vec<TimeSeriesTier>,
+}
+
+impl DownsampledTimeSeries {
+    /// Flattens the tiers into the regular time series of the bucket means, ordered by time
+    pub fn to_time_series(&self, kind: TimeSeriesKind) -> TimeSeries {
+        let mut points = self
+            .tiers
+            .iter()
+            .flat_map(|tier| tier.buckets.iter())
+            .map(|bucket| TimePoint::new(bucket.time_s, bucket.mean))
+            .collect::<Vec<_>>();
+        points.sort_by(|a, b| a.time_s.total_cmp(&b.time_s));
+        TimeSeries { points, kind }
+    }
+}
+
+/// Downsampled time series of the individual or group metrics, present only for the long runs
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct DownsampledMetricsTimeSeries {
+    pub throughput_mb: DownsampledTimeSeries,
+    pub throughput_msg: DownsampledTimeSeries,
+    pub latency: DownsampledTimeSeries,
+}
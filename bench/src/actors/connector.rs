@@ -0,0 +1,134 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::analytics::metrics::individual::from_records;
+use crate::analytics::record::BenchmarkRecord;
+use crate::rate_limiter::RateLimiter;
+use human_repr::HumanCount;
+use iggy::client::{Client, UserClient};
+use iggy::clients::client::IggyClient;
+use iggy::error::IggyError;
+use iggy::users::defaults::{DEFAULT_ROOT_PASSWORD, DEFAULT_ROOT_USERNAME};
+use iggy::utils::duration::IggyDuration;
+use iggy_bench_report::actor_kind::ActorKind;
+use iggy_bench_report::benchmark_kind::BenchmarkKind;
+use iggy_bench_report::individual_metrics::BenchmarkIndividualMetrics;
+use integration::test_server::ClientFactory;
+use std::sync::Arc;
+use tokio::time::Instant;
+use tracing::info;
+
+/// Repeatedly connects, logs in and disconnects, the latency of each record being the time
+/// needed to establish the connection (including the TLS handshake) and log in.
+pub struct Connector {
+    client_factory: Arc<dyn ClientFactory>,
+    benchmark_kind: BenchmarkKind,
+    connector_id: u32,
+    connections: u32,
+    sampling_time: IggyDuration,
+    moving_average_window: u32,
+    rate_limiter: Option<RateLimiter>,
+}
+
+impl Connector {
+    pub fn new(
+        client_factory: Arc<dyn ClientFactory>,
+        benchmark_kind: BenchmarkKind,
+        connector_id: u32,
+        connections: u32,
+        sampling_time: IggyDuration,
+        moving_average_window: u32,
+        rate_limiter: Option<RateLimiter>,
+    ) -> Self {
+        Connector {
+            client_factory,
+            benchmark_kind,
+            connector_id,
+            connections,
+            sampling_time,
+            moving_average_window,
+            rate_limiter,
+        }
+    }
+
+    pub async fn run(&self) -> Result<BenchmarkIndividualMetrics, IggyError> {
+        let connections = self.connections;
+        info!(
+            "Connector #{} → opening {} connections...",
+            self.connector_id,
+            connections.human_count_bare()
+        );
+
+        let start_timestamp = Instant::now();
+        let mut records = Vec::with_capacity(connections as usize);
+        for i in 1..=connections {
+            if let Some(limiter) = &self.rate_limiter {
+                limiter.throttle(1).await;
+            }
+            let before_connect = Instant::now();
+            let client = self.client_factory.create_client().await;
+            let client = IggyClient::create(client, None, None);
+            client
+                .login_user(DEFAULT_ROOT_USERNAME, DEFAULT_ROOT_PASSWORD)
+                .await?;
+            let latency = before_connect.elapsed();
+            client.disconnect().await?;
+
+            records.push(BenchmarkRecord {
+                elapsed_time_us: start_timestamp.elapsed().as_micros() as u64,
+                latency_us: latency.as_micros() as u64,
+                messages: i as u64,
+                message_batches: i as u64,
+                user_data_bytes: 0,
+                total_bytes: 0,
+            });
+        }
+
+        let metrics = from_records(
+            records,
+            self.benchmark_kind,
+            ActorKind::Connector,
+            self.connector_id,
+            self.sampling_time,
+            self.moving_average_window,
+        );
+
+        Self::log_statistics(self.connector_id, connections, &metrics);
+
+        Ok(metrics)
+    }
+
+    fn log_statistics(connector_id: u32, connections: u32, metrics: &BenchmarkIndividualMetrics) {
+        info!(
+            "Connector #{} → opened {} connections in {:.2} s, average throughput: {:.2} connections/s, \
+    p50 latency: {:.2} ms, p90 latency: {:.2} ms, p95 latency: {:.2} ms, p99 latency: {:.2} ms, p999 latency: {:.2} ms, \
+    average latency: {:.2} ms, median latency: {:.2} ms",
+            connector_id,
+            connections,
+            metrics.summary.total_time_secs,
+            metrics.summary.throughput_messages_per_second,
+            metrics.summary.p50_latency_ms,
+            metrics.summary.p90_latency_ms,
+            metrics.summary.p95_latency_ms,
+            metrics.summary.p99_latency_ms,
+            metrics.summary.p999_latency_ms,
+            metrics.summary.avg_latency_ms,
+            metrics.summary.median_latency_ms
+        );
+    }
+}
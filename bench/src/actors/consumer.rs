@@ -21,11 +21,13 @@ use crate::analytics::metrics::individual::from_records;
 use crate::analytics::record::BenchmarkRecord;
 use crate::rate_limiter::RateLimiter;
 use human_repr::HumanCount;
-use iggy::client::{ConsumerGroupClient, MessageClient};
+use iggy::client::{ConsumerGroupClient, ConsumerOffsetClient, MessageClient};
 use iggy::clients::client::IggyClient;
 use iggy::consumer::Consumer as IggyConsumer;
 use iggy::error::IggyError;
+use iggy::identifier::Identifier;
 use iggy::messages::poll_messages::{PollingKind, PollingStrategy};
+use iggy::models::messages::PolledMessages;
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::duration::IggyDuration;
 use iggy::utils::sizeable::Sizeable;
@@ -53,6 +55,7 @@ pub struct Consumer {
     moving_average_window: u32,
     polling_kind: PollingKind,
     calculate_latency_from_message_payload: bool,
+    explicit_commit: bool,
     rate_limiter: Option<RateLimiter>,
 }
 
@@ -72,6 +75,7 @@ impl Consumer {
         moving_average_window: u32,
         polling_kind: PollingKind,
         calculate_latency_from_message_payload: bool,
+        explicit_commit: bool,
         rate_limiter: Option<RateLimiter>,
     ) -> Self {
         Self {
@@ -88,6 +92,7 @@ impl Consumer {
             moving_average_window,
             polling_kind,
             calculate_latency_from_message_payload,
+            explicit_commit,
             rate_limiter,
         }
     }
@@ -124,6 +129,11 @@ impl Consumer {
             None => IggyConsumer::new(self.consumer_id.try_into().unwrap()),
         };
         let mut latencies: Vec<Duration> = Vec::with_capacity(message_batches as usize);
+        let mut commit_latencies: Vec<Duration> = if self.explicit_commit {
+            Vec::with_capacity(message_batches as usize)
+        } else {
+            Vec::new()
+        };
         let mut total_user_data_bytes = IggyByteSize::default();
         let mut total_bytes = IggyByteSize::default();
         let mut topic_not_found_counter = 0;
@@ -150,14 +160,7 @@ impl Consumer {
             let warmup_end = Instant::now() + self.warmup_time.get_duration();
             while Instant::now() < warmup_end {
                 let offset = current_iteration * messages_per_batch as u64;
-                let (strategy, auto_commit) = match self.polling_kind {
-                    PollingKind::Offset => (PollingStrategy::offset(offset), false),
-                    PollingKind::Next => (PollingStrategy::next(), true),
-                    _ => panic!(
-                        "Unsupported polling kind for benchmark: {:?}",
-                        self.polling_kind
-                    ),
-                };
+                let (strategy, auto_commit) = self.polling_strategy(offset);
                 let polled_messages = client
                     .poll_messages(
                         &stream_id,
@@ -177,6 +180,10 @@ impl Consumer {
                     );
                     continue;
                 }
+                if self.explicit_commit {
+                    self.commit_offset(&client, &consumer, &stream_id, &topic_id, &polled_messages)
+                        .await?;
+                }
                 current_iteration += 1;
             }
         }
@@ -211,14 +218,7 @@ impl Consumer {
             }
             let offset = current_iteration * messages_per_batch as u64;
 
-            let (strategy, auto_commit) = match self.polling_kind {
-                PollingKind::Offset => (PollingStrategy::offset(offset), false),
-                PollingKind::Next => (PollingStrategy::next(), true),
-                _ => panic!(
-                    "Unsupported polling kind for benchmark: {:?}",
-                    self.polling_kind
-                ),
-            };
+            let (strategy, auto_commit) = self.polling_strategy(offset);
             let before_poll = Instant::now();
             let polled_messages = client
                 .poll_messages(
@@ -299,6 +299,13 @@ impl Consumer {
             initial_poll_timestamp = None; // Reset the timestamp after successful poll
             latencies.push(latency);
 
+            if self.explicit_commit {
+                let before_commit = Instant::now();
+                self.commit_offset(&client, &consumer, &stream_id, &topic_id, &polled_messages)
+                    .await?;
+                commit_latencies.push(before_commit.elapsed());
+            }
+
             self.batches_left_to_receive.fetch_sub(1, Ordering::AcqRel);
 
             received_messages += polled_messages.messages.len() as u64;
@@ -343,9 +350,84 @@ impl Consumer {
             &metrics,
         );
 
+        if self.explicit_commit {
+            Self::log_commit_statistics(self.consumer_id, &mut commit_latencies, &metrics);
+        }
+
         Ok(metrics)
     }
 
+    /// Returns the polling strategy for the given offset and whether the server should
+    /// auto-commit the offset. With explicit commits, the offset is stored by the consumer
+    /// after every batch instead, so that the commit overhead can be measured on its own.
+    fn polling_strategy(&self, offset: u64) -> (PollingStrategy, bool) {
+        match self.polling_kind {
+            PollingKind::Offset => (PollingStrategy::offset(offset), false),
+            PollingKind::Next => (PollingStrategy::next(), !self.explicit_commit),
+            _ => panic!(
+                "Unsupported polling kind for benchmark: {:?}",
+                self.polling_kind
+            ),
+        }
+    }
+
+    async fn commit_offset(
+        &self,
+        client: &IggyClient,
+        consumer: &IggyConsumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        polled_messages: &PolledMessages,
+    ) -> Result<(), IggyError> {
+        let Some(last_message) = polled_messages.messages.last() else {
+            return Ok(());
+        };
+        client
+            .store_consumer_offset(
+                consumer,
+                stream_id,
+                topic_id,
+                Some(polled_messages.partition_id),
+                last_message.offset,
+            )
+            .await
+    }
+
+    fn log_commit_statistics(
+        consumer_id: u32,
+        commit_latencies: &mut [Duration],
+        metrics: &BenchmarkIndividualMetrics,
+    ) {
+        if commit_latencies.is_empty() {
+            return;
+        }
+
+        commit_latencies.sort_unstable();
+        let percentile = |p: f64| {
+            let index = ((commit_latencies.len() - 1) as f64 * p).round() as usize;
+            commit_latencies[index].as_secs_f64() * 1000.0
+        };
+        let total_commit_time: Duration = commit_latencies.iter().sum();
+        let avg_commit_latency_ms =
+            total_commit_time.as_secs_f64() * 1000.0 / commit_latencies.len() as f64;
+        let commit_time_share = if metrics.summary.total_time_secs > 0.0 {
+            total_commit_time.as_secs_f64() / metrics.summary.total_time_secs * 100.0
+        } else {
+            0.0
+        };
+        info!(
+            "Consumer #{} → committed {} offsets in {:.2} s ({:.2}% of total time), \
+    p50 commit latency: {:.2} ms, p99 commit latency: {:.2} ms, average commit latency: {:.2} ms",
+            consumer_id,
+            commit_latencies.len().human_count_bare(),
+            total_commit_time.as_secs_f64(),
+            commit_time_share,
+            percentile(0.5),
+            percentile(0.99),
+            avg_commit_latency_ms
+        );
+    }
+
     pub fn log_statistics(
         consumer_id: u32,
         total_messages: u64,
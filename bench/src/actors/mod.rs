@@ -16,6 +16,7 @@
  * under the License.
  */
 
+pub mod connector;
 pub mod consumer;
 pub mod producer;
 pub mod producing_consumer;
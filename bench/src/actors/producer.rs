@@ -16,7 +16,7 @@
  * under the License.
  */
 
-use crate::actors::utils::put_timestamp_in_first_message;
+use crate::actors::utils::{create_payloads, put_timestamp_in_first_message};
 use crate::analytics::metrics::individual::from_records;
 use crate::analytics::record::BenchmarkRecord;
 use crate::rate_limiter::RateLimiter;
@@ -47,6 +47,7 @@ pub struct Producer {
     message_batches: u32,
     messages_per_batch: u32,
     message_size: u32,
+    seed: u64,
     warmup_time: IggyDuration,
     sampling_time: IggyDuration,
     moving_average_window: u32,
@@ -65,6 +66,7 @@ impl Producer {
         messages_per_batch: u32,
         message_batches: u32,
         message_size: u32,
+        seed: u64,
         warmup_time: IggyDuration,
         sampling_time: IggyDuration,
         moving_average_window: u32,
@@ -80,6 +82,7 @@ impl Producer {
             messages_per_batch,
             message_batches,
             message_size,
+            seed,
             warmup_time,
             sampling_time,
             moving_average_window,
@@ -104,11 +107,16 @@ impl Producer {
             "Producer #{} → preparing the test messages...",
             self.producer_id
         );
-        let payload = Self::create_payload(message_size);
+        let payloads = create_payloads(
+            self.seed,
+            self.producer_id,
+            messages_per_batch,
+            message_size,
+        );
         let mut batch_user_data_bytes = 0;
         let mut batch_total_bytes = 0;
         let mut messages = Vec::with_capacity(messages_per_batch as usize);
-        for _ in 0..messages_per_batch {
+        for payload in payloads {
             let message = Message::from_str(&payload).unwrap();
             batch_user_data_bytes += message.length as u64;
             batch_total_bytes += message.get_size_bytes().as_bytes_u64();
@@ -203,16 +211,6 @@ impl Producer {
         Ok(metrics)
     }
 
-    fn create_payload(size: u32) -> String {
-        let mut payload = String::with_capacity(size as usize);
-        for i in 0..size {
-            let char = (i % 26 + 97) as u8 as char;
-            payload.push(char);
-        }
-
-        payload
-    }
-
     fn log_statistics(
         producer_id: u32,
         total_messages: u64,
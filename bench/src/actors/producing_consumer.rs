@@ -16,7 +16,9 @@
  * under the License.
  */
 
-use crate::actors::utils::{calculate_latency_from_first_message, put_timestamp_in_first_message};
+use crate::actors::utils::{
+    calculate_latency_from_first_message, create_payloads, put_timestamp_in_first_message,
+};
 use crate::analytics::metrics::individual::from_records;
 use crate::analytics::record::BenchmarkRecord;
 use crate::rate_limiter::RateLimiter;
@@ -52,6 +54,7 @@ pub struct ProducingConsumer {
     messages_per_batch: u32,
     message_batches: u32,
     message_size: u32,
+    seed: u64,
     batches_left_to_receive: Arc<AtomicI64>,
     warmup_time: IggyDuration,
     sampling_time: IggyDuration,
@@ -73,6 +76,7 @@ impl ProducingConsumer {
         messages_per_batch: u32,
         message_batches: u32,
         message_size: u32,
+        seed: u64,
         batches_left_to_receive: Arc<AtomicI64>,
         warmup_time: IggyDuration,
         sampling_time: IggyDuration,
@@ -91,6 +95,7 @@ impl ProducingConsumer {
             messages_per_batch,
             message_batches,
             message_size,
+            seed,
             batches_left_to_receive,
             warmup_time,
             sampling_time,
@@ -117,11 +122,11 @@ impl ProducingConsumer {
             "ProducingConsumer #{} → preparing test messages...",
             self.actor_id
         );
-        let payload = self.create_payload(message_size);
+        let payloads = create_payloads(self.seed, self.actor_id, messages_per_batch, message_size);
         let mut batch_user_data_bytes = 0;
         let mut batch_total_bytes = 0;
         let mut messages = Vec::with_capacity(messages_per_batch as usize);
-        for _ in 0..messages_per_batch {
+        for payload in payloads {
             let message = Message::from_str(&payload).unwrap();
             batch_user_data_bytes += message.length as u64;
             batch_total_bytes += message.get_size_bytes().as_bytes_u64();
@@ -345,10 +350,6 @@ impl ProducingConsumer {
         Ok(metrics)
     }
 
-    fn create_payload(&self, size: u32) -> String {
-        "a".repeat(size as usize)
-    }
-
     fn log_statistics(
         actor_id: u32,
         total_messages: u64,
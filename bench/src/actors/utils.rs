@@ -20,6 +20,30 @@ use std::time::Duration;
 
 use bytes::Bytes;
 use iggy::{messages::send_messages::Message, models::messages::PolledMessage};
+use rand::distr::Alphanumeric;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// Creates the random number generator of the actor, derived only from the seed and the actor ID.
+/// ChaCha is used on purpose, as its output is the same on every platform, unlike the `StdRng`.
+pub fn create_rng(seed: u64, actor_id: u32) -> ChaCha8Rng {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    rng.set_stream(actor_id as u64);
+    rng
+}
+
+/// Creates the alphanumeric payloads of the messages in a batch, the same seed and actor ID
+/// always produce the byte-identical payloads.
+pub fn create_payloads(seed: u64, actor_id: u32, count: u32, size: u32) -> Vec<String> {
+    let mut rng = create_rng(seed, actor_id);
+    (0..count)
+        .map(|_| {
+            (0..size)
+                .map(|_| char::from(rng.sample(Alphanumeric)))
+                .collect()
+        })
+        .collect()
+}
 
 pub fn put_timestamp_in_first_message(message: &mut Message) {
     let now = std::time::SystemTime::now()
@@ -53,3 +77,23 @@ pub fn calculate_latency_from_first_message(message: &PolledMessage) -> Duration
         .as_micros() as u64;
     Duration::from_micros(now - send_timestamp)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn payloads_should_be_reproducible_for_the_same_seed_and_actor() {
+        let payloads = create_payloads(42, 1, 10, 100);
+        assert_eq!(payloads, create_payloads(42, 1, 10, 100));
+        assert_eq!(payloads.len(), 10);
+        assert!(payloads.iter().all(|payload| payload.len() == 100));
+    }
+
+    #[test]
+    fn payloads_should_differ_for_different_seeds_or_actors() {
+        let payloads = create_payloads(42, 1, 10, 100);
+        assert_ne!(payloads, create_payloads(43, 1, 10, 100));
+        assert_ne!(payloads, create_payloads(42, 2, 10, 100));
+    }
+}
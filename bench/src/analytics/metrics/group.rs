@@ -94,6 +94,7 @@ pub fn from_individual_metrics(
         ActorKind::Producer => GroupMetricsKind::Producers,
         ActorKind::Consumer => GroupMetricsKind::Consumers,
         ActorKind::ProducingConsumer => GroupMetricsKind::ProducingConsumers,
+        ActorKind::Connector => GroupMetricsKind::Connectors,
     };
 
     let calculator = TimeSeriesCalculator::new();
@@ -146,5 +147,6 @@ pub fn from_individual_metrics(
         avg_throughput_mb_ts,
         avg_throughput_msg_ts,
         avg_latency_ts,
+        downsampled_ts: None,
     })
 }
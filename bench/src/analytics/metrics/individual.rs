@@ -59,6 +59,7 @@ pub fn from_records(
             throughput_mb_ts: TimeSeries::default(),
             throughput_msg_ts: TimeSeries::default(),
             latency_ts: TimeSeries::default(),
+            downsampled_ts: None,
         };
     }
 
@@ -136,10 +137,11 @@ pub fn from_records(
         throughput_mb_ts,
         throughput_msg_ts,
         latency_ts,
+        downsampled_ts: None,
     }
 }
 
-fn calculate_percentile(sorted_data: &[f64], percentile: f64) -> f64 {
+pub(crate) fn calculate_percentile(sorted_data: &[f64], percentile: f64) -> f64 {
     if sorted_data.is_empty() {
         return 0.0;
     }
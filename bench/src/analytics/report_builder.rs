@@ -19,20 +19,24 @@
 use std::collections::HashMap;
 
 use super::metrics::group::{from_individual_metrics, from_producers_and_consumers_statistics};
+use super::time_series::processors::downsampling::DownsamplingProcessor;
 use crate::utils::get_server_stats;
 use chrono::{DateTime, Utc};
 use iggy::{
     models::stats::{CacheMetrics, CacheMetricsKey, Stats},
-    utils::timestamp::IggyTimestamp,
+    utils::{duration::IggyDuration, timestamp::IggyTimestamp},
+    SDK_VERSION,
 };
 use iggy_bench_report::{
     actor_kind::ActorKind,
     benchmark_kind::BenchmarkKind,
     hardware::BenchmarkHardware,
     individual_metrics::BenchmarkIndividualMetrics,
+    manifest::BenchmarkManifest,
     params::BenchmarkParams,
     report::BenchmarkReport,
     server_stats::{BenchmarkCacheMetrics, BenchmarkCacheMetricsKey, BenchmarkServerStats},
+    time_series::{DownsampledMetricsTimeSeries, TimeSeries},
 };
 
 pub struct BenchmarkReportBuilder;
@@ -43,6 +47,8 @@ impl BenchmarkReportBuilder {
         mut params: BenchmarkParams,
         mut individual_metrics: Vec<BenchmarkIndividualMetrics>,
         moving_average_window: u32,
+        downsampling_threshold: IggyDuration,
+        seed: u64,
     ) -> BenchmarkReport {
         let uuid = uuid::Uuid::new_v4();
 
@@ -58,6 +64,15 @@ impl BenchmarkReportBuilder {
             .await
             .expect("Failed to get server stats");
 
+        let manifest = BenchmarkManifest {
+            seed,
+            args: std::env::args().collect(),
+            bench_version: env!("CARGO_PKG_VERSION").to_string(),
+            sdk_version: SDK_VERSION.to_string(),
+            server_version: server_stats.iggy_server_version.clone(),
+            hardware: hardware.clone(),
+        };
+
         if params.gitref.is_none() {
             params.gitref = Some(server_stats.iggy_server_version.clone());
         };
@@ -87,6 +102,11 @@ impl BenchmarkReportBuilder {
             .filter(|m| m.summary.actor_kind == ActorKind::ProducingConsumer)
             .cloned()
             .collect();
+        let connector_metrics: Vec<BenchmarkIndividualMetrics> = individual_metrics
+            .iter()
+            .filter(|m| m.summary.actor_kind == ActorKind::Connector)
+            .cloned()
+            .collect();
 
         if !producer_metrics.is_empty() {
             if let Some(metrics) = from_individual_metrics(&producer_metrics, moving_average_window)
@@ -110,6 +130,14 @@ impl BenchmarkReportBuilder {
             }
         }
 
+        if !connector_metrics.is_empty() {
+            if let Some(metrics) =
+                from_individual_metrics(&connector_metrics, moving_average_window)
+            {
+                group_metrics.push(metrics);
+            }
+        }
+
         if matches!(
             params.benchmark_kind,
             BenchmarkKind::PinnedProducerAndConsumer
@@ -126,18 +154,65 @@ impl BenchmarkReportBuilder {
             }
         }
 
+        let total_time_secs = individual_metrics
+            .iter()
+            .map(|m| m.summary.total_time_secs)
+            .fold(0.0, f64::max);
+        if !downsampling_threshold.is_zero()
+            && total_time_secs > downsampling_threshold.as_secs_f64()
+        {
+            for metrics in individual_metrics.iter_mut() {
+                metrics.downsampled_ts = Some(downsample(
+                    &mut metrics.throughput_mb_ts,
+                    &mut metrics.throughput_msg_ts,
+                    &mut metrics.latency_ts,
+                ));
+            }
+            for metrics in group_metrics.iter_mut() {
+                metrics.downsampled_ts = Some(downsample(
+                    &mut metrics.avg_throughput_mb_ts,
+                    &mut metrics.avg_throughput_msg_ts,
+                    &mut metrics.avg_latency_ts,
+                ));
+            }
+        }
+
         BenchmarkReport {
             uuid,
             server_stats: stats_to_benchmark_server_stats(server_stats),
             timestamp,
             hardware,
             params,
+            manifest,
             group_metrics,
             individual_metrics,
         }
     }
 }
 
+/// Replaces the full resolution time series with the means of the downsampled buckets,
+/// so that the report of the long run stays manageable, and returns the downsampled ones.
+fn downsample(
+    throughput_mb_ts: &mut TimeSeries,
+    throughput_msg_ts: &mut TimeSeries,
+    latency_ts: &mut TimeSeries,
+) -> DownsampledMetricsTimeSeries {
+    let processor = DownsamplingProcessor;
+    let downsampled_ts = DownsampledMetricsTimeSeries {
+        throughput_mb: processor.downsample(throughput_mb_ts),
+        throughput_msg: processor.downsample(throughput_msg_ts),
+        latency: processor.downsample(latency_ts),
+    };
+    *throughput_mb_ts = downsampled_ts
+        .throughput_mb
+        .to_time_series(throughput_mb_ts.kind);
+    *throughput_msg_ts = downsampled_ts
+        .throughput_msg
+        .to_time_series(throughput_msg_ts.kind);
+    *latency_ts = downsampled_ts.latency.to_time_series(latency_ts.kind);
+    downsampled_ts
+}
+
 /// This function is a workaround.
 /// See server_stats.rs in `iggy_bench_report` crate for more details.
 fn stats_to_benchmark_server_stats(stats: Stats) -> BenchmarkServerStats {
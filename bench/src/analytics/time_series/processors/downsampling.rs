@@ -0,0 +1,185 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use super::TimeSeriesProcessor;
+use crate::analytics::metrics::individual::calculate_percentile;
+use iggy_bench_report::time_series::{
+    DownsampledTimeSeries, TimeBucket, TimeSeries, TimeSeriesTier,
+};
+
+/// Resolution of the time series data newer than `max_age_secs`, relative to the end of the run
+#[derive(Debug, Clone, Copy)]
+struct DownsamplingTier {
+    max_age_secs: f64,
+    bucket_secs: f64,
+}
+
+/// 1s buckets for the last 10 minutes, 10s buckets for the last hour and 1m buckets for the older data
+const TIERS: [DownsamplingTier; 3] = [
+    DownsamplingTier {
+        max_age_secs: 600.0,
+        bucket_secs: 1.0,
+    },
+    DownsamplingTier {
+        max_age_secs: 3600.0,
+        bucket_secs: 10.0,
+    },
+    DownsamplingTier {
+        max_age_secs: f64::INFINITY,
+        bucket_secs: 60.0,
+    },
+];
+
+/// Downsampling processor, rolling up the older data of the long runs into the coarser buckets
+pub struct DownsamplingProcessor;
+
+impl DownsamplingProcessor {
+    pub fn downsample(&self, data: &TimeSeries) -> DownsampledTimeSeries {
+        let Some(last_point) = data.points.last() else {
+            return DownsampledTimeSeries::default();
+        };
+
+        let end_s = last_point.time_s;
+        let mut tiers = Vec::with_capacity(TIERS.len());
+        let mut tier_end_s = f64::INFINITY;
+        for (index, tier) in TIERS.iter().enumerate() {
+            // The boundary is aligned to the buckets of the coarser tier, so that none of them is split.
+            let tier_start_s = match TIERS.get(index + 1) {
+                Some(coarser_tier) => {
+                    ((end_s - tier.max_age_secs) / coarser_tier.bucket_secs).floor()
+                        * coarser_tier.bucket_secs
+                }
+                None => f64::NEG_INFINITY,
+            };
+            let buckets = downsample_range(data, tier_start_s, tier_end_s, tier.bucket_secs);
+            if !buckets.is_empty() {
+                tiers.push(TimeSeriesTier {
+                    bucket_secs: tier.bucket_secs,
+                    buckets,
+                });
+            }
+            tier_end_s = tier_start_s;
+        }
+
+        DownsampledTimeSeries { tiers }
+    }
+}
+
+impl TimeSeriesProcessor for DownsamplingProcessor {
+    fn process(&self, data: &TimeSeries) -> TimeSeries {
+        self.downsample(data).to_time_series(data.kind)
+    }
+}
+
+/// Aggregates the points within `[start_s, end_s)` into the buckets of the given size
+fn downsample_range(
+    data: &TimeSeries,
+    start_s: f64,
+    end_s: f64,
+    bucket_secs: f64,
+) -> Vec<TimeBucket> {
+    let mut buckets = Vec::new();
+    let mut bucket_index = None;
+    let mut values = Vec::new();
+    for point in data
+        .points
+        .iter()
+        .filter(|point| point.time_s >= start_s && point.time_s < end_s)
+    {
+        let index = (point.time_s / bucket_secs).floor() as i64;
+        if bucket_index != Some(index) {
+            if let Some(bucket_index) = bucket_index {
+                buckets.push(to_bucket(bucket_index as f64 * bucket_secs, &mut values));
+            }
+            bucket_index = Some(index);
+        }
+        values.push(point.value);
+    }
+
+    if let Some(bucket_index) = bucket_index {
+        buckets.push(to_bucket(bucket_index as f64 * bucket_secs, &mut values));
+    }
+    buckets
+}
+
+fn to_bucket(time_s: f64, values: &mut Vec<f64>) -> TimeBucket {
+    values.sort_by(|a, b| a.total_cmp(b));
+    let mean = values.iter().sum::<f64>() / values.len() as f64;
+    let bucket = TimeBucket {
+        time_s,
+        mean: round(mean),
+        max: round(values[values.len() - 1]),
+        p99: round(calculate_percentile(values, 99.0)),
+    };
+    values.clear();
+    bucket
+}
+
+fn round(value: f64) -> f64 {
+    (value * 1000.0).round() / 1000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy_bench_report::time_series::{TimePoint, TimeSeriesKind};
+
+    #[test]
+    fn older_data_should_be_rolled_up_into_coarser_buckets() {
+        // Two hours sampled every 0.5s, with the value equal to the time.
+        let data = TimeSeries {
+            points: (0..14_400)
+                .map(|i| TimePoint::new(i as f64 * 0.5, i as f64 * 0.5))
+                .collect(),
+            kind: TimeSeriesKind::Latency,
+        };
+
+        let downsampled = DownsamplingProcessor.downsample(&data);
+        let bucket_sizes = downsampled
+            .tiers
+            .iter()
+            .map(|tier| tier.bucket_secs)
+            .collect::<Vec<_>>();
+        assert_eq!(bucket_sizes, vec![1.0, 10.0, 60.0]);
+
+        let recent = &downsampled.tiers[0];
+        assert_eq!(recent.buckets.first().unwrap().time_s, 6590.0);
+        assert_eq!(recent.buckets.len(), 610);
+        let rollup = &downsampled.tiers[1];
+        assert_eq!(rollup.buckets.first().unwrap().time_s, 3540.0);
+        assert_eq!(rollup.buckets.last().unwrap().time_s, 6580.0);
+        let oldest = &downsampled.tiers[2];
+        assert_eq!(oldest.buckets.len(), 59);
+        assert_eq!(
+            oldest.buckets[0],
+            TimeBucket {
+                time_s: 0.0,
+                mean: 29.75,
+                max: 59.5,
+                p99: 58.905,
+            }
+        );
+
+        let time_series = DownsamplingProcessor.process(&data);
+        assert_eq!(time_series.points.len(), 610 + 305 + 59);
+        assert!(time_series
+            .points
+            .windows(2)
+            .all(|points| points[0].time_s < points[1].time_s));
+    }
+}
@@ -18,6 +18,7 @@
 
 use iggy_bench_report::time_series::TimeSeries;
 
+pub mod downsampling;
 pub mod moving_average;
 
 /// Process time series data
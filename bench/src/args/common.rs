@@ -34,7 +34,7 @@ use std::path::Path;
 use std::str::FromStr;
 use tracing::info;
 
-#[derive(Parser, Debug)]
+#[derive(Parser, Debug, Clone)]
 #[command(author, version, about, long_about = None)]
 pub struct IggyBenchArgs {
     /// Benchmark kind
@@ -78,6 +78,12 @@ pub struct IggyBenchArgs {
     #[arg(long, short = 'W', default_value_t = DEFAULT_MOVING_AVERAGE_WINDOW)]
     pub moving_average_window: u32,
 
+    /// Time series of the runs longer than this are downsampled in the report, keeping 1s resolution
+    /// for the last 10 minutes, 10s for the last hour and 1m for the older data, with the mean, max and p99 of every bucket.
+    /// Use "0" to always keep the full resolution.
+    #[arg(long, default_value_t = IggyDuration::from_str(DEFAULT_DOWNSAMPLING_THRESHOLD).unwrap(), value_parser = IggyDuration::from_str, verbatim_doc_comment)]
+    pub downsampling_threshold: IggyDuration,
+
     /// Shutdown iggy-server and remove server local_data directory after the benchmark is finished.
     /// Only applicable to local benchmarks.
     #[arg(long, default_value_t = DEFAULT_PERFORM_CLEANUP, verbatim_doc_comment)]
@@ -92,6 +98,30 @@ pub struct IggyBenchArgs {
     /// Only applicable to local benchmarks.
     #[arg(long, short = 'k', default_value_t = DEFAULT_SKIP_SERVER_START, verbatim_doc_comment)]
     pub skip_server_start: bool,
+
+    /// Seed of all the randomness in the benchmark, e.g. the message payloads.
+    /// A random one is picked if not provided, it's always recorded in the run manifest,
+    /// so that the byte-identical workload can be reproduced on another machine.
+    #[arg(long, verbatim_doc_comment)]
+    pub seed: Option<u64>,
+
+    /// Message sizes in bytes to sweep over, comma separated, e.g. "100,1000,10000".
+    /// Providing any of the sweep options runs the benchmark sequentially for each combination
+    /// of the swept message sizes, messages per batch and clients, instead of the single values.
+    #[arg(long, value_delimiter = ',', verbatim_doc_comment)]
+    pub sweep_message_sizes: Vec<NonZeroU32>,
+
+    /// Numbers of messages per batch to sweep over, comma separated, e.g. "10,100,1000"
+    #[arg(long, value_delimiter = ',')]
+    pub sweep_messages_per_batch: Vec<NonZeroU32>,
+
+    /// Numbers of clients (producers and consumers) to sweep over, comma separated, e.g. "1,4,16"
+    #[arg(long, value_delimiter = ',')]
+    pub sweep_clients: Vec<NonZeroU32>,
+
+    /// Cooldown between the sweep runs in human readable format, e.g. "5s", "1m"
+    #[arg(long, default_value_t = IggyDuration::from_str(DEFAULT_SWEEP_COOLDOWN).unwrap())]
+    pub sweep_cooldown: IggyDuration,
 }
 
 fn validate_server_executable_path(v: &str) -> Result<String, String> {
@@ -115,6 +145,20 @@ impl IggyBenchArgs {
         self.benchmark_kind.transport_command().nodelay()
     }
 
+    pub fn tls_enabled(&self) -> bool {
+        match self.transport_command() {
+            BenchmarkTransportCommand::Tcp(args) => args.tls,
+            _ => false,
+        }
+    }
+
+    pub fn tls_ca_file(&self) -> &str {
+        match self.transport_command() {
+            BenchmarkTransportCommand::Tcp(args) => &args.tls_ca_file,
+            _ => "",
+        }
+    }
+
     pub fn server_address(&self) -> &str {
         self.benchmark_kind
             .inner()
@@ -126,6 +170,60 @@ impl IggyBenchArgs {
         self.start_stream_id.get()
     }
 
+    /// Picks the random seed if it wasn't provided, must be called before the benchmark is started.
+    pub fn resolve_seed(&mut self) {
+        if self.seed.is_none() {
+            self.seed = Some(rand::random());
+        }
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+            .expect("Seed must be resolved before the benchmark is started")
+    }
+
+    pub fn is_sweep(&self) -> bool {
+        !self.sweep_message_sizes.is_empty()
+            || !self.sweep_messages_per_batch.is_empty()
+            || !self.sweep_clients.is_empty()
+    }
+
+    pub fn sweep_cooldown(&self) -> IggyDuration {
+        self.sweep_cooldown
+    }
+
+    /// Returns the arguments of each sweep run, one for every combination of the swept parameters.
+    /// The parameters which aren't swept keep their single values.
+    pub fn sweep_runs(&self) -> Vec<IggyBenchArgs> {
+        let message_sizes = sweep_values(&self.sweep_message_sizes, self.message_size);
+        let messages_per_batch =
+            sweep_values(&self.sweep_messages_per_batch, self.messages_per_batch);
+        let clients = if self.sweep_clients.is_empty() {
+            vec![None]
+        } else {
+            self.sweep_clients.iter().copied().map(Some).collect()
+        };
+        let mut runs =
+            Vec::with_capacity(message_sizes.len() * messages_per_batch.len() * clients.len());
+        for message_size in &message_sizes {
+            for batch_size in &messages_per_batch {
+                for client in &clients {
+                    let mut args = self.clone();
+                    args.sweep_message_sizes.clear();
+                    args.sweep_messages_per_batch.clear();
+                    args.sweep_clients.clear();
+                    args.message_size = *message_size;
+                    args.messages_per_batch = *batch_size;
+                    if let Some(clients) = client {
+                        args.benchmark_kind.set_clients(*clients);
+                    }
+                    runs.push(args);
+                }
+            }
+        }
+        runs
+    }
+
     pub fn validate(&self) {
         let server_address = self.server_address().parse::<SocketAddr>().unwrap();
         if (self.cleanup || self.verbose) && !server_address.ip().is_loopback() {
@@ -155,6 +253,13 @@ impl IggyBenchArgs {
                 .exit();
         }
 
+        if self.is_sweep() {
+            for args in self.sweep_runs() {
+                args.validate();
+            }
+            return;
+        }
+
         self.benchmark_kind.inner().validate()
     }
 
@@ -194,6 +299,10 @@ impl IggyBenchArgs {
         self.benchmark_kind.inner().number_of_consumer_groups()
     }
 
+    pub fn use_consumer_group(&self) -> bool {
+        self.benchmark_kind.inner().use_consumer_group()
+    }
+
     pub fn warmup_time(&self) -> IggyDuration {
         self.warmup_time
     }
@@ -206,6 +315,10 @@ impl IggyBenchArgs {
         self.moving_average_window
     }
 
+    pub fn downsampling_threshold(&self) -> IggyDuration {
+        self.downsampling_threshold
+    }
+
     pub fn rate_limit(&self) -> Option<IggyByteSize> {
         self.rate_limit
     }
@@ -306,10 +419,12 @@ impl IggyBenchArgs {
             BenchmarkKindCommand::EndToEndProducingConsumerGroup(_) => {
                 "end_to_end_producing_consumer_group"
             }
+            BenchmarkKindCommand::ConnectionStorm(_) => "connection_storm",
             BenchmarkKindCommand::Examples => unreachable!(),
         };
 
         let transport = match self.transport_command() {
+            BenchmarkTransportCommand::Tcp(args) if args.tls => "tcp_tls",
             BenchmarkTransportCommand::Tcp(_) => "tcp",
             BenchmarkTransportCommand::Quic(_) => "quic",
             BenchmarkTransportCommand::Http(_) => "http",
@@ -328,17 +443,27 @@ impl IggyBenchArgs {
             }
             BenchmarkKindCommand::EndToEndProducingConsumer(_) => self.producers(),
             BenchmarkKindCommand::EndToEndProducingConsumerGroup(_) => self.producers(),
+            BenchmarkKindCommand::ConnectionStorm(_) => self.producers(),
             BenchmarkKindCommand::Examples => unreachable!(),
         };
 
-        let mut parts = vec![
-            benchmark_kind.to_string(),
-            actors.to_string(),
-            self.message_size().to_string(),
-            self.messages_per_batch().to_string(),
-            self.message_batches().to_string(),
-            transport.to_string(),
-        ];
+        let mut parts = if self.is_sweep() {
+            vec![
+                benchmark_kind.to_string(),
+                "sweep".to_string(),
+                self.message_batches().to_string(),
+                transport.to_string(),
+            ]
+        } else {
+            vec![
+                benchmark_kind.to_string(),
+                actors.to_string(),
+                self.message_size().to_string(),
+                self.messages_per_batch().to_string(),
+                self.message_batches().to_string(),
+                transport.to_string(),
+            ]
+        };
 
         if let Some(remark) = &self.remark() {
             parts.push(remark.to_string());
@@ -381,15 +506,27 @@ impl IggyBenchArgs {
                     self.consumers()
                 )
             }
+            BenchmarkKindCommand::ConnectionStorm(args) => {
+                format!(
+                    "{} connectors, {} connections each",
+                    args.connectors, args.connections
+                )
+            }
             BenchmarkKindCommand::Examples => unreachable!(),
         };
 
-        let mut name = format!(
-            "{}, {}B msgs, {} msgs/batch",
-            consumer_or_producer,
-            self.message_size(),
-            self.messages_per_batch(),
-        );
+        let mut name = match &self.benchmark_kind {
+            BenchmarkKindCommand::ConnectionStorm(_) => consumer_or_producer,
+            _ => format!(
+                "{}, {}B msgs, {} msgs/batch",
+                consumer_or_producer,
+                self.message_size(),
+                self.messages_per_batch(),
+            ),
+        };
+        if self.tls_enabled() {
+            name.push_str(", TLS");
+        }
 
         if let Some(remark) = &self.remark() {
             name.push_str(&format!(" ({})", remark));
@@ -399,6 +536,14 @@ impl IggyBenchArgs {
     }
 }
 
+fn sweep_values<T: Copy>(values: &[T], single: T) -> Vec<T> {
+    if values.is_empty() {
+        vec![single]
+    } else {
+        values.to_vec()
+    }
+}
+
 fn recreate_bench_command(args: &IggyBenchArgs) -> String {
     let mut parts = Vec::new();
 
@@ -447,6 +592,8 @@ fn recreate_bench_command(args: &IggyBenchArgs) -> String {
         parts.push(format!("--warmup-time \'{}\'", args.warmup_time()));
     }
 
+    parts.push(format!("--seed {}", args.seed()));
+
     let kind_str = match args.benchmark_kind.as_simple_kind() {
         BenchmarkKind::PinnedProducer => "pinned-producer",
         BenchmarkKind::PinnedConsumer => "pinned-consumer",
@@ -456,6 +603,7 @@ fn recreate_bench_command(args: &IggyBenchArgs) -> String {
         BenchmarkKind::BalancedProducerAndConsumerGroup => "balanced-producer-and-consumer-group",
         BenchmarkKind::EndToEndProducingConsumer => "end-to-end-producing-consumer",
         BenchmarkKind::EndToEndProducingConsumerGroup => "end-to-end-producing-consumer-group",
+        BenchmarkKind::ConnectionStorm => "connection-storm",
     };
     parts.push(kind_str.to_string());
 
@@ -497,6 +645,19 @@ fn recreate_bench_command(args: &IggyBenchArgs) -> String {
                 parts.push(format!("--consumer-groups {}", number_of_consumer_groups));
             }
         }
+        BenchmarkKind::ConnectionStorm => {
+            if let BenchmarkKindCommand::ConnectionStorm(storm_args) = &args.benchmark_kind {
+                if storm_args.connectors != DEFAULT_NUMBER_OF_CONNECTORS {
+                    parts.push(format!("--connectors {}", storm_args.connectors));
+                }
+                if storm_args.connections != DEFAULT_NUMBER_OF_CONNECTIONS {
+                    parts.push(format!("--connections {}", storm_args.connections));
+                }
+                if let Some(connection_rate) = storm_args.connection_rate {
+                    parts.push(format!("--connection-rate {}", connection_rate));
+                }
+            }
+        }
     }
 
     let streams = args.streams();
@@ -506,7 +667,9 @@ fn recreate_bench_command(args: &IggyBenchArgs) -> String {
         | BenchmarkKind::BalancedProducer => DEFAULT_BALANCED_NUMBER_OF_STREAMS.get(),
         _ => DEFAULT_PINNED_NUMBER_OF_STREAMS.get(),
     };
-    if streams != default_streams {
+    if streams != default_streams
+        && args.benchmark_kind.as_simple_kind() != BenchmarkKind::ConnectionStorm
+    {
         parts.push(format!("--streams {}", streams));
     }
 
@@ -517,7 +680,9 @@ fn recreate_bench_command(args: &IggyBenchArgs) -> String {
         | BenchmarkKind::BalancedProducer => DEFAULT_BALANCED_NUMBER_OF_PARTITIONS.get(),
         _ => DEFAULT_PINNED_NUMBER_OF_PARTITIONS.get(),
     };
-    if partitions != default_partitions {
+    if partitions != default_partitions
+        && args.benchmark_kind.as_simple_kind() != BenchmarkKind::ConnectionStorm
+    {
         parts.push(format!("--partitions {}", partitions));
     }
 
@@ -529,6 +694,10 @@ fn recreate_bench_command(args: &IggyBenchArgs) -> String {
         parts.push(format!("--consumer-groups {}", consumer_groups));
     }
 
+    if args.use_consumer_group() {
+        parts.push("--consumer-group".to_string());
+    }
+
     if let Some(max_topic_size) = args.max_topic_size() {
         parts.push(format!("--max-topic-size \'{}\'", max_topic_size));
     }
@@ -537,6 +706,13 @@ fn recreate_bench_command(args: &IggyBenchArgs) -> String {
     let transport = args.transport().to_string().to_lowercase();
     parts.push(transport.clone());
 
+    if args.tls_enabled() {
+        parts.push("--tls".to_string());
+        if args.tls_ca_file() != DEFAULT_TCP_TLS_CA_FILE {
+            parts.push(format!("--tls-ca-file {}", args.tls_ca_file()));
+        }
+    }
+
     let default_address = match transport.as_str() {
         "tcp" => DEFAULT_TCP_SERVER_ADDRESS,
         "quic" => DEFAULT_QUIC_SERVER_ADDRESS,
@@ -585,6 +761,11 @@ impl From<&IggyBenchArgs> for BenchmarkParams {
         let rate_limit = args.rate_limit().map(|limit| limit.to_string());
         let pretty_name = args.generate_pretty_name();
         let bench_command = recreate_bench_command(args);
+        let connections = match &args.benchmark_kind {
+            BenchmarkKindCommand::ConnectionStorm(storm_args) => storm_args.connections.get(),
+            _ => 0,
+        };
+        let tls = args.tls_enabled();
 
         let remark_for_identifier = remark
             .clone()
@@ -627,6 +808,8 @@ impl From<&IggyBenchArgs> for BenchmarkParams {
             pretty_name,
             bench_command,
             params_identifier,
+            connections,
+            tls,
         }
     }
 }
@@ -22,6 +22,7 @@ use std::num::NonZeroU32;
 pub const DEFAULT_HTTP_SERVER_ADDRESS: &str = "127.0.0.1:3000";
 
 pub const DEFAULT_TCP_SERVER_ADDRESS: &str = "127.0.0.1:8090";
+pub const DEFAULT_TCP_TLS_CA_FILE: &str = "certs/iggy_cert.pem";
 
 pub const DEFAULT_QUIC_CLIENT_ADDRESS: &str = "127.0.0.1:0";
 pub const DEFAULT_QUIC_SERVER_ADDRESS: &str = "127.0.0.1:8080";
@@ -42,6 +43,9 @@ pub const DEFAULT_BALANCED_NUMBER_OF_PARTITIONS: NonZeroU32 = u32!(24);
 pub const DEFAULT_NUMBER_OF_CONSUMERS: NonZeroU32 = u32!(8);
 pub const DEFAULT_NUMBER_OF_CONSUMER_GROUPS: NonZeroU32 = u32!(1);
 pub const DEFAULT_NUMBER_OF_PRODUCERS: NonZeroU32 = u32!(8);
+pub const DEFAULT_NUMBER_OF_CONNECTORS: NonZeroU32 = u32!(8);
+pub const DEFAULT_NUMBER_OF_CONNECTIONS: NonZeroU32 = u32!(1000);
+pub const DEFAULT_PINNED_CONSUMER_GROUP: bool = false;
 
 pub const DEFAULT_PERFORM_CLEANUP: bool = false;
 pub const DEFAULT_SERVER_STDOUT_VISIBILITY: bool = false;
@@ -51,3 +55,6 @@ pub const DEFAULT_SKIP_SERVER_START: bool = false;
 
 pub const DEFAULT_SAMPLING_TIME: &str = "10ms";
 pub const DEFAULT_MOVING_AVERAGE_WINDOW: u32 = 20;
+pub const DEFAULT_DOWNSAMPLING_THRESHOLD: &str = "30m";
+
+pub const DEFAULT_SWEEP_COOLDOWN: &str = "5s";
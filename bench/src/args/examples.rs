@@ -43,7 +43,15 @@ const EXAMPLES: &str = r#"EXAMPLES:
     $ cargo r -r --bin iggy-bench -- end-to-end-producing-consumer --producers 12 --streams 12 tcp
     $ cargo r -r --bin iggy-bench -- end-to-end-producing-consumer-group --partitions 24 --producers 6 tcp
 
-4) Advanced Configuration:
+4) Connection Storm Benchmarking:
+
+    Run benchmarks measuring the connect and login throughput and the handshake latency,
+    each connector repeatedly connecting, logging in and disconnecting:
+
+    $ cargo r -r --bin iggy-bench -- connection-storm --connectors 16 --connections 1000 tcp
+    $ cargo r -r --bin iggy-bench -- connection-storm --connectors 16 --connection-rate 100 tcp --tls
+
+5) Advanced Configuration:
 
     You can customize various parameters for any benchmark mode:
 
@@ -81,7 +89,7 @@ const EXAMPLES: &str = r#"EXAMPLES:
         --max-topic-size "1GiB" \
         tcp
 
-5) Remote Server Benchmarking:
+6) Remote Server Benchmarking:
 
     To benchmark a remote server, specify the server address in the transport subcommand:
 
@@ -89,7 +97,7 @@ const EXAMPLES: &str = r#"EXAMPLES:
         --streams 5 --producers 5 \
         tcp --server-address 192.168.1.100:8090
 
-6) Output Data and Results:
+7) Output Data and Results:
 
     The benchmark tool can store detailed results for analysis and comparison:
 
@@ -121,7 +129,29 @@ const EXAMPLES: &str = r#"EXAMPLES:
     --gitref-date      : Git reference date (merge/commit date)
     --open-charts      : Auto-open result charts in browser
 
-7) Help and Documentation:
+8) Parameter Sweeps:
+
+    Run the benchmark for each combination of the swept parameters, one after another,
+    to find the best settings without dozens of manual invocations:
+
+    $ cargo r -r --bin iggy-bench -- \
+        --sweep-message-sizes 100,1000,10000 \
+        --sweep-messages-per-batch 10,100,1000 \
+        --sweep-clients 1,4,8 \
+        --sweep-cooldown 10s \
+        balanced-producer --partitions 24 tcp \
+        output --output-dir performance_results
+
+    Each run is stored in its own directory, the combined sweep_report.json and
+    sweep.csv with the throughput and latency of each run are stored in the sweep directory.
+
+    Sweep options (before the benchmark command):
+    --sweep-message-sizes      : Message sizes in bytes, comma separated
+    --sweep-messages-per-batch : Messages per batch, comma separated
+    --sweep-clients            : Numbers of producers and consumers, comma separated
+    --sweep-cooldown           : Cooldown between the runs [default: 5s]
+
+9) Help and Documentation:
 
     For more details on available options:
 
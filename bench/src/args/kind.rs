@@ -24,14 +24,16 @@ use super::kinds::end_to_end::producing_consumer_group::EndToEndProducingConsume
 use super::props::BenchmarkKindProps;
 use super::transport::BenchmarkTransportCommand;
 use crate::args::kinds::balanced::consumer_group::BalancedConsumerGroupArgs;
+use crate::args::kinds::control_plane::connection_storm::ConnectionStormArgs;
 use crate::args::kinds::pinned::consumer::PinnedConsumerArgs;
 use crate::args::kinds::pinned::producer::PinnedProducerArgs;
 use crate::args::kinds::pinned::producer_and_consumer::PinnedProducerAndConsumerArgs;
 use clap::Subcommand;
 use iggy::utils::byte_size::IggyByteSize;
 use iggy_bench_report::benchmark_kind::BenchmarkKind;
+use std::num::NonZeroU32;
 
-#[derive(Subcommand, Debug)]
+#[derive(Subcommand, Debug, Clone)]
 pub enum BenchmarkKindCommand {
     #[command(
         about = "Pinned producer benchmark",
@@ -93,6 +95,14 @@ pub enum BenchmarkKindCommand {
     )]
     EndToEndProducingConsumerGroup(EndToEndProducingConsumerGroupArgs),
 
+    #[command(
+        about = "Connection storm benchmark",
+        long_about = "N connectors repeatedly connecting, logging in and disconnecting, measuring the handshake latency",
+        visible_alias = "cs",
+        verbatim_doc_comment
+    )]
+    ConnectionStorm(ConnectionStormArgs),
+
     #[command(about = "Print examples", visible_alias = "e", verbatim_doc_comment)]
     Examples,
 }
@@ -116,12 +126,43 @@ impl BenchmarkKindCommand {
             BenchmarkKindCommand::EndToEndProducingConsumerGroup(_) => {
                 BenchmarkKind::EndToEndProducingConsumerGroup
             }
+            BenchmarkKindCommand::ConnectionStorm(_) => BenchmarkKind::ConnectionStorm,
             BenchmarkKindCommand::Examples => {
                 print_examples();
                 std::process::exit(0);
             }
         }
     }
+
+    /// Sets the number of producers and consumers used by the benchmark,
+    /// the pinned benchmarks get one stream per each of them.
+    pub fn set_clients(&mut self, clients: NonZeroU32) {
+        match self {
+            BenchmarkKindCommand::PinnedProducer(args) => {
+                args.producers = clients;
+                args.streams = clients;
+            }
+            BenchmarkKindCommand::PinnedConsumer(args) => {
+                args.consumers = clients;
+                args.streams = clients;
+            }
+            BenchmarkKindCommand::PinnedProducerAndConsumer(args) => {
+                args.producers = clients;
+                args.consumers = clients;
+                args.streams = clients;
+            }
+            BenchmarkKindCommand::BalancedProducer(args) => args.producers = clients,
+            BenchmarkKindCommand::BalancedConsumerGroup(args) => args.consumers = clients,
+            BenchmarkKindCommand::BalancedProducerAndConsumerGroup(args) => {
+                args.producers = clients;
+                args.consumers = clients;
+            }
+            BenchmarkKindCommand::EndToEndProducingConsumer(args) => args.producers = clients,
+            BenchmarkKindCommand::EndToEndProducingConsumerGroup(args) => args.producers = clients,
+            BenchmarkKindCommand::ConnectionStorm(args) => args.connectors = clients,
+            BenchmarkKindCommand::Examples => {}
+        }
+    }
 }
 
 impl BenchmarkKindProps for BenchmarkKindCommand {
@@ -153,6 +194,10 @@ impl BenchmarkKindProps for BenchmarkKindCommand {
         self.inner().max_topic_size()
     }
 
+    fn use_consumer_group(&self) -> bool {
+        self.inner().use_consumer_group()
+    }
+
     fn inner(&self) -> &dyn BenchmarkKindProps {
         match self {
             BenchmarkKindCommand::PinnedProducer(args) => args,
@@ -163,6 +208,7 @@ impl BenchmarkKindProps for BenchmarkKindCommand {
             BenchmarkKindCommand::BalancedProducerAndConsumerGroup(args) => args,
             BenchmarkKindCommand::EndToEndProducingConsumer(args) => args,
             BenchmarkKindCommand::EndToEndProducingConsumerGroup(args) => args,
+            BenchmarkKindCommand::ConnectionStorm(args) => args,
             BenchmarkKindCommand::Examples => {
                 print_examples();
                 std::process::exit(0);
@@ -0,0 +1,74 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::args::{defaults::*, props::BenchmarkKindProps, transport::BenchmarkTransportCommand};
+use clap::Parser;
+use iggy::utils::byte_size::IggyByteSize;
+use std::num::NonZeroU32;
+
+/// N connectors repeatedly connecting, logging in and disconnecting, without sending or polling any messages.
+/// For the HTTP transport, only the login is measured, as the connections aren't kept.
+#[derive(Parser, Debug, Clone)]
+pub struct ConnectionStormArgs {
+    #[command(subcommand)]
+    pub transport: BenchmarkTransportCommand,
+
+    /// Number of connectors, each of them opens the connections one after another
+    #[arg(long, short = 'c', default_value_t = DEFAULT_NUMBER_OF_CONNECTORS)]
+    pub connectors: NonZeroU32,
+
+    /// Number of connections opened by each connector
+    #[arg(long, short = 'n', default_value_t = DEFAULT_NUMBER_OF_CONNECTIONS)]
+    pub connections: NonZeroU32,
+
+    /// Optional rate limit per individual connector in connections per second (not aggregate)
+    #[arg(long)]
+    pub connection_rate: Option<NonZeroU32>,
+}
+
+impl BenchmarkKindProps for ConnectionStormArgs {
+    fn streams(&self) -> u32 {
+        0
+    }
+
+    fn partitions(&self) -> u32 {
+        0
+    }
+
+    fn consumers(&self) -> u32 {
+        0
+    }
+
+    fn producers(&self) -> u32 {
+        self.connectors.get()
+    }
+
+    fn transport_command(&self) -> &BenchmarkTransportCommand {
+        &self.transport
+    }
+
+    fn number_of_consumer_groups(&self) -> u32 {
+        0
+    }
+
+    fn max_topic_size(&self) -> Option<IggyByteSize> {
+        None
+    }
+
+    fn validate(&self) {}
+}
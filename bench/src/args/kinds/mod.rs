@@ -17,5 +17,6 @@
  */
 
 pub mod balanced;
+pub mod control_plane;
 pub mod end_to_end;
 pub mod pinned;
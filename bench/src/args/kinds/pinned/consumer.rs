@@ -36,6 +36,11 @@ pub struct PinnedConsumerArgs {
     /// Number of consumers
     #[arg(long, short = 'c', default_value_t = DEFAULT_NUMBER_OF_PRODUCERS)]
     pub consumers: NonZeroU32,
+
+    /// Poll through a consumer group (one per stream) and commit the offset after every batch,
+    /// commit latency is measured and reported separately from the poll latency
+    #[arg(long, default_value_t = DEFAULT_PINNED_CONSUMER_GROUP)]
+    pub consumer_group: bool,
 }
 
 impl BenchmarkKindProps for PinnedConsumerArgs {
@@ -67,6 +72,10 @@ impl BenchmarkKindProps for PinnedConsumerArgs {
         None
     }
 
+    fn use_consumer_group(&self) -> bool {
+        self.consumer_group
+    }
+
     fn validate(&self) {
         let mut cmd = IggyBenchArgs::command();
         let streams = self.streams();
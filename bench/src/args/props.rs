@@ -28,6 +28,9 @@ pub trait BenchmarkKindProps {
     fn producers(&self) -> u32;
     fn transport_command(&self) -> &BenchmarkTransportCommand;
     fn max_topic_size(&self) -> Option<IggyByteSize>;
+    fn use_consumer_group(&self) -> bool {
+        false
+    }
     fn validate(&self);
     fn inner(&self) -> &dyn BenchmarkKindProps
     where
@@ -124,6 +124,14 @@ pub struct TcpArgs {
     #[arg(long, default_value_t = false)]
     pub nodelay: bool,
 
+    /// Secure the connections with TLS, the local iggy-server is started with TLS enabled as well
+    #[arg(long, default_value_t = false)]
+    pub tls: bool,
+
+    /// Path to the CA certificate used to validate the server certificate when TLS is enabled
+    #[arg(long, default_value_t = DEFAULT_TCP_TLS_CA_FILE.to_owned())]
+    pub tls_ca_file: String,
+
     /// Optional output command, used to output results (charts, raw json data) to a directory
     #[command(subcommand)]
     output: Option<BenchmarkOutputCommand>,
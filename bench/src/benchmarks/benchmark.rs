@@ -32,6 +32,7 @@ use integration::test_server::{login_root, ClientFactory};
 use std::{pin::Pin, sync::Arc};
 use tracing::info;
 
+use super::connection_storm_benchmark::ConnectionStormBenchmark;
 use super::consumer_benchmark::ConsumerBenchmark;
 use super::consumer_group_benchmark::ConsumerGroupBenchmark;
 use super::producer_and_consumer_benchmark::ProducerAndConsumerBenchmark;
@@ -74,6 +75,10 @@ impl From<IggyBenchArgs> for Box<dyn Benchmarkable> {
             BenchmarkKindCommand::EndToEndProducingConsumerGroup(_) => Box::new(
                 EndToEndProducingConsumerGroupBenchmark::new(Arc::new(args), client_factory),
             ),
+            BenchmarkKindCommand::ConnectionStorm(_) => Box::new(ConnectionStormBenchmark::new(
+                Arc::new(args),
+                client_factory,
+            )),
             _ => todo!(),
         }
     }
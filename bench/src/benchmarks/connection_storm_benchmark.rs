@@ -0,0 +1,104 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::actors::connector::Connector;
+use crate::args::common::IggyBenchArgs;
+use crate::args::kind::BenchmarkKindCommand;
+use crate::benchmarks::benchmark::{BenchmarkFutures, Benchmarkable};
+use crate::rate_limiter::RateLimiter;
+use async_trait::async_trait;
+use iggy_bench_report::benchmark_kind::BenchmarkKind;
+use integration::test_server::ClientFactory;
+use std::sync::Arc;
+use tracing::info;
+
+pub struct ConnectionStormBenchmark {
+    args: Arc<IggyBenchArgs>,
+    client_factory: Arc<dyn ClientFactory>,
+}
+
+impl ConnectionStormBenchmark {
+    pub fn new(args: Arc<IggyBenchArgs>, client_factory: Arc<dyn ClientFactory>) -> Self {
+        Self {
+            args,
+            client_factory,
+        }
+    }
+
+    fn connections(&self) -> u32 {
+        match &self.args.benchmark_kind {
+            BenchmarkKindCommand::ConnectionStorm(args) => args.connections.get(),
+            _ => unreachable!(),
+        }
+    }
+
+    fn connection_rate(&self) -> Option<u64> {
+        match &self.args.benchmark_kind {
+            BenchmarkKindCommand::ConnectionStorm(args) => {
+                args.connection_rate.map(|rate| rate.get() as u64)
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[async_trait]
+impl Benchmarkable for ConnectionStormBenchmark {
+    async fn run(&mut self) -> BenchmarkFutures {
+        let connectors_count = self.args.producers();
+        let connections = self.connections();
+        info!("Creating {} connector(s)...", connectors_count);
+
+        let mut futures: BenchmarkFutures = Ok(Vec::with_capacity(connectors_count as usize));
+        for connector_id in 1..=connectors_count {
+            let args = self.args.clone();
+            let client_factory = self.client_factory.clone();
+            info!("Executing the benchmark on connector #{}...", connector_id);
+
+            let connector = Connector::new(
+                client_factory,
+                args.kind(),
+                connector_id,
+                connections,
+                args.sampling_time(),
+                args.moving_average_window(),
+                self.connection_rate().map(RateLimiter::new),
+            );
+            let future = Box::pin(async move { connector.run().await });
+            futures.as_mut().unwrap().push(future);
+        }
+        info!("Created {} connector(s).", connectors_count);
+        futures
+    }
+
+    fn kind(&self) -> BenchmarkKind {
+        self.args.kind()
+    }
+
+    fn total_messages(&self) -> u64 {
+        self.args.producers() as u64 * self.connections() as u64
+    }
+
+    fn args(&self) -> &IggyBenchArgs {
+        &self.args
+    }
+
+    fn client_factory(&self) -> &Arc<dyn ClientFactory> {
+        &self.client_factory
+    }
+}
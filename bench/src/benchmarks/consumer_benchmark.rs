@@ -19,14 +19,18 @@
 use crate::actors::consumer::Consumer;
 use crate::args::common::IggyBenchArgs;
 use crate::benchmarks::benchmark::{BenchmarkFutures, Benchmarkable};
+use crate::benchmarks::{CONSUMER_GROUP_BASE_ID, CONSUMER_GROUP_NAME_PREFIX};
 use crate::rate_limiter::RateLimiter;
 use async_trait::async_trait;
+use iggy::client::ConsumerGroupClient;
+use iggy::clients::client::IggyClient;
+use iggy::error::IggyError;
 use iggy::messages::poll_messages::PollingKind;
 use iggy_bench_report::benchmark_kind::BenchmarkKind;
-use integration::test_server::ClientFactory;
+use integration::test_server::{login_root, ClientFactory};
 use std::sync::atomic::AtomicI64;
 use std::sync::Arc;
-use tracing::info;
+use tracing::{error, info};
 
 pub struct ConsumerBenchmark {
     args: Arc<IggyBenchArgs>,
@@ -40,12 +44,54 @@ impl ConsumerBenchmark {
             client_factory,
         }
     }
+
+    /// Creates a single consumer group on the topic of every stream, so that each pinned
+    /// consumer polls its stream through a group instead of directly from the partition.
+    pub async fn init_consumer_groups(&self) -> Result<(), IggyError> {
+        let start_stream_id = self.args.start_stream_id();
+        let topic_id: u32 = 1;
+        let consumer_group_id = CONSUMER_GROUP_BASE_ID + 1;
+        let consumer_group_name = format!("{}-{}", CONSUMER_GROUP_NAME_PREFIX, consumer_group_id);
+        let client = self.client_factory.create_client().await;
+        let client = IggyClient::create(client, None, None);
+        login_root(&client).await;
+        for i in 1..=self.args.streams() {
+            let stream_id = start_stream_id + i;
+            info!(
+                "Creating test consumer group with name: {}, id: {}, stream id: {}, topic id: {}",
+                consumer_group_name, consumer_group_id, stream_id, topic_id
+            );
+
+            let cg = client
+                .create_consumer_group(
+                    &stream_id.try_into().unwrap(),
+                    &topic_id.try_into().unwrap(),
+                    &consumer_group_name,
+                    Some(consumer_group_id),
+                )
+                .await;
+            if let Err(error) = cg {
+                match error {
+                    IggyError::ConsumerGroupIdAlreadyExists(_, _) => continue,
+                    _ => error!("Error when creating consumer group : {error}"),
+                }
+            }
+        }
+
+        Ok(())
+    }
 }
 
 #[async_trait]
 impl Benchmarkable for ConsumerBenchmark {
     async fn run(&mut self) -> BenchmarkFutures {
         self.check_streams().await?;
+        let use_consumer_group = self.args.use_consumer_group();
+        if use_consumer_group {
+            self.init_consumer_groups()
+                .await
+                .expect("Failed to init consumer groups");
+        }
         let consumers_count = self.args.consumers();
         info!("Creating {} consumer(s)...", consumers_count);
         let messages_per_batch = self.args.messages_per_batch();
@@ -73,14 +119,16 @@ impl Benchmarkable for ConsumerBenchmark {
             let polling_kind = match self.args.kind() {
                 BenchmarkKind::BalancedConsumerGroup
                 | BenchmarkKind::BalancedProducerAndConsumerGroup => PollingKind::Next,
+                _ if use_consumer_group => PollingKind::Next,
                 _ => PollingKind::Offset,
             };
+            let consumer_group_id = use_consumer_group.then_some(CONSUMER_GROUP_BASE_ID + 1);
 
             let consumer = Consumer::new(
                 client_factory,
                 self.args.kind(),
                 consumer_id,
-                None,
+                consumer_group_id,
                 stream_id,
                 messages_per_batch,
                 message_batches,
@@ -91,6 +139,7 @@ impl Benchmarkable for ConsumerBenchmark {
                 args.moving_average_window(),
                 polling_kind,
                 false, // TODO: Calculate latency from timestamp in first message, it should be an argument to iggy-bench
+                use_consumer_group,
                 args.rate_limit()
                     .map(|rl| RateLimiter::new(rl.as_bytes_u64())),
             );
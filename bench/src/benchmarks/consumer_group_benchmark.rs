@@ -124,6 +124,7 @@ impl Benchmarkable for ConsumerGroupBenchmark {
                 self.args.moving_average_window(),
                 polling_kind,
                 false, // TODO: Calculate latency from timestamp in first message, it should be an argument to iggy-bench
+                false,
                 self.args
                     .rate_limit()
                     .map(|rl| RateLimiter::new(rl.as_bytes_u64())),
@@ -17,6 +17,7 @@
  */
 
 pub mod benchmark;
+pub mod connection_storm_benchmark;
 pub mod consumer_benchmark;
 pub mod consumer_group_benchmark;
 pub mod producer_and_consumer_benchmark;
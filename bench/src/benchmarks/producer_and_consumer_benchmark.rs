@@ -69,6 +69,7 @@ impl Benchmarkable for ProducerAndConsumerBenchmark {
                 messages_per_batch,
                 message_batches,
                 message_size,
+                self.args.seed(),
                 warmup_time,
                 self.args.sampling_time(),
                 self.args.moving_average_window(),
@@ -99,6 +100,7 @@ impl Benchmarkable for ProducerAndConsumerBenchmark {
                 self.args.moving_average_window(),
                 polling_kind,
                 false, // TODO: Calculate latency from timestamp in first message, it should be an argument to iggy-bench
+                false,
                 self.args
                     .rate_limit()
                     .map(|rl| RateLimiter::new(rl.as_bytes_u64())),
@@ -69,6 +69,7 @@ impl Benchmarkable for ProducerBenchmark {
                 messages_per_batch,
                 message_batches,
                 message_size,
+                args.seed(),
                 warmup_time,
                 args.sampling_time(),
                 args.moving_average_window(),
@@ -74,6 +74,7 @@ impl Benchmarkable for EndToEndProducingConsumerBenchmark {
                 messages_per_batch,
                 message_batches,
                 message_size,
+                args.seed(),
                 Arc::new(AtomicI64::new(message_batches as i64)),
                 warmup_time,
                 args.sampling_time(),
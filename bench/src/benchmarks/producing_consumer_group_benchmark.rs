@@ -131,6 +131,7 @@ impl Benchmarkable for EndToEndProducingConsumerGroupBenchmark {
                 messages_per_batch,
                 message_batches,
                 message_size,
+                args.seed(),
                 total_message_batches.clone(),
                 warmup_time,
                 args.sampling_time(),
@@ -23,9 +23,10 @@ mod benchmarks;
 mod plot;
 mod rate_limiter;
 mod runner;
+mod sweep_runner;
 mod utils;
 
-use crate::{args::common::IggyBenchArgs, runner::BenchmarkRunner};
+use crate::{args::common::IggyBenchArgs, runner::BenchmarkRunner, sweep_runner::SweepRunner};
 use clap::Parser;
 use figlet_rs::FIGfont;
 use iggy::error::IggyError;
@@ -41,8 +42,9 @@ async fn main() -> Result<(), IggyError> {
     let figure = standard_font.convert("Iggy Bench");
     println!("{}", figure.unwrap());
 
-    let args = IggyBenchArgs::parse();
+    let mut args = IggyBenchArgs::parse();
     args.validate();
+    args.resolve_seed();
 
     // Store output_dir before moving args
     let output_dir = args.output_dir();
@@ -79,11 +81,16 @@ async fn main() -> Result<(), IggyError> {
             .init();
     }
 
-    let mut benchmark_runner = BenchmarkRunner::new(args);
-
-    info!("Starting the benchmarks...");
+    let seed = args.seed();
+    info!("Starting the benchmarks with seed: {seed}...");
     let ctrl_c = tokio::signal::ctrl_c();
-    let benchmark_future = benchmark_runner.run();
+    let benchmark_future = async move {
+        if args.is_sweep() {
+            SweepRunner::new(args).run().await
+        } else {
+            BenchmarkRunner::new(args).run().await.map(|_| ())
+        }
+    };
 
     tokio::select! {
         _ = ctrl_c => {
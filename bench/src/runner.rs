@@ -27,6 +27,7 @@ use futures::future::select_all;
 use iggy::error::IggyError;
 use iggy_bench_report::hardware::BenchmarkHardware;
 use iggy_bench_report::params::BenchmarkParams;
+use iggy_bench_report::report::BenchmarkReport;
 use integration::test_server::TestServer;
 use std::path::Path;
 use std::time::Duration;
@@ -46,7 +47,7 @@ impl BenchmarkRunner {
         }
     }
 
-    pub async fn run(&mut self) -> Result<(), IggyError> {
+    pub async fn run(&mut self) -> Result<BenchmarkReport, IggyError> {
         let mut args = self.args.take().unwrap();
         let should_open_charts = args.open_charts();
         self.test_server = start_server_if_needed(&mut args).await;
@@ -83,6 +84,8 @@ impl BenchmarkRunner {
             params,
             individual_metrics,
             benchmark.args().moving_average_window(),
+            benchmark.args().downsampling_threshold(),
+            benchmark.args().seed(),
         )
         .await;
 
@@ -136,6 +139,6 @@ impl BenchmarkRunner {
             })?;
         }
 
-        Ok(())
+        Ok(report)
     }
 }
@@ -0,0 +1,83 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::args::common::IggyBenchArgs;
+use crate::runner::BenchmarkRunner;
+use crate::utils::cpu_name::append_cpu_name_lowercase;
+use chrono::{DateTime, Utc};
+use iggy::error::IggyError;
+use iggy::utils::timestamp::IggyTimestamp;
+use iggy_bench_report::sweep_report::BenchmarkSweepReport;
+use std::path::Path;
+use tokio::time::sleep;
+use tracing::info;
+
+/// Runs the benchmark sequentially for each combination of the swept parameters,
+/// waiting for the cooldown between the runs, and combines their reports.
+pub struct SweepRunner {
+    args: IggyBenchArgs,
+}
+
+impl SweepRunner {
+    pub fn new(args: IggyBenchArgs) -> Self {
+        Self { args }
+    }
+
+    pub async fn run(&mut self) -> Result<(), IggyError> {
+        let runs = self.args.sweep_runs();
+        let runs_count = runs.len();
+        let cooldown = self.args.sweep_cooldown();
+        let mut reports = Vec::with_capacity(runs_count);
+        for (index, args) in runs.into_iter().enumerate() {
+            if index > 0 && !cooldown.is_zero() {
+                info!("Cooling down for {cooldown} before the next sweep run...");
+                sleep(cooldown.get_duration()).await;
+            }
+
+            info!(
+                "Starting sweep run {}/{runs_count}: {}",
+                index + 1,
+                args.generate_pretty_name()
+            );
+            // The runner is dropped after each run, so the local server is restarted for the next one.
+            let mut benchmark_runner = BenchmarkRunner::new(args);
+            reports.push(benchmark_runner.run().await?);
+        }
+
+        let timestamp =
+            DateTime::<Utc>::from_timestamp_micros(IggyTimestamp::now().as_micros() as i64)
+                .map(|dt| dt.to_rfc3339())
+                .unwrap_or_else(|| String::from("unknown"));
+        let sweep_report = BenchmarkSweepReport::new(timestamp, reports);
+        sweep_report.print_summary();
+
+        if let Some(output_dir) = self.args.output_dir() {
+            let mut dir_name = self.args.generate_dir_name();
+            append_cpu_name_lowercase(&mut dir_name);
+            let full_output_path = Path::new(&output_dir)
+                .join(dir_name)
+                .to_string_lossy()
+                .to_string();
+            sweep_report.dump_to_json(&full_output_path);
+            sweep_report.dump_to_csv(&full_output_path);
+            info!("Sweep report and CSV saved to: {full_output_path}");
+        }
+
+        Ok(())
+    }
+}
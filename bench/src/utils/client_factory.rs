@@ -31,6 +31,8 @@ pub fn create_client_factory(args: &IggyBenchArgs) -> Arc<dyn ClientFactory> {
         Transport::Tcp => Arc::new(TcpClientFactory {
             server_addr: args.server_address().to_owned(),
             nodelay: args.nodelay(),
+            tls_enabled: args.tls_enabled(),
+            tls_ca_file: args.tls_enabled().then(|| args.tls_ca_file().to_owned()),
         }),
         Transport::Quic => Arc::new(QuicClientFactory {
             server_addr: args.server_address().to_owned(),
@@ -73,6 +73,10 @@ pub async fn start_server_if_needed(args: &mut IggyBenchArgs) -> Option<TestServ
                 ),
                 ("IGGY_HTTP_ENABLED".to_owned(), "false".to_owned()),
                 ("IGGY_QUIC_ENABLED".to_owned(), "false".to_owned()),
+                (
+                    "IGGY_TCP_TLS_ENABLED".to_owned(),
+                    args.tls_enabled().to_string(),
+                ),
             ]);
             (
                 addresses_are_equivalent(&args_tcp_address, &config_tcp_address)
@@ -16,7 +16,7 @@
  * under the License.
  */
 
-use clap::ValueEnum;
+use clap::{Args, ValueEnum};
 use iggy::cli::client::get_clients::GetClientsOutput;
 use iggy::cli::consumer_group::get_consumer_groups::GetConsumerGroupsOutput;
 use iggy::cli::context::get_contexts::GetContextsOutput;
@@ -25,6 +25,8 @@ use iggy::cli::streams::get_streams::GetStreamsOutput;
 use iggy::cli::system::stats::GetStatsOutput;
 use iggy::cli::topics::get_topics::GetTopicsOutput;
 use iggy::cli::users::get_users::GetUsersOutput;
+use iggy::error::IggyError;
+use iggy::models::metadata::{MetadataFilter, ResourceMetadata};
 
 #[derive(Debug, Clone, Copy, ValueEnum)]
 pub(crate) enum ListMode {
@@ -113,3 +115,83 @@ impl From<ListModeExt> for GetStatsOutput {
         }
     }
 }
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct MetadataArgs {
+    /// Free-form description
+    #[clap(long)]
+    pub(crate) description: Option<String>,
+    /// Owner, e.g. the team name
+    #[clap(long)]
+    pub(crate) owner: Option<String>,
+    /// Label in key=value form, can be repeated
+    #[clap(long = "label", value_name = "LABEL", value_parser = parse_label)]
+    pub(crate) labels: Vec<(String, String)>,
+}
+
+impl From<MetadataArgs> for ResourceMetadata {
+    fn from(args: MetadataArgs) -> Self {
+        ResourceMetadata {
+            description: args.description,
+            owner: args.owner,
+            labels: args.labels.into_iter().collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct MetadataFilterArgs {
+    /// Show only the ones with the given owner
+    #[clap(long)]
+    pub(crate) owner: Option<String>,
+    /// Show only the ones with the label in key=value form, can be repeated
+    #[clap(long = "label", value_name = "LABEL", value_parser = parse_label)]
+    pub(crate) labels: Vec<(String, String)>,
+    /// Show only the ones with the description containing the given text
+    #[clap(long)]
+    pub(crate) description: Option<String>,
+}
+
+impl From<MetadataFilterArgs> for MetadataFilter {
+    fn from(args: MetadataFilterArgs) -> Self {
+        MetadataFilter {
+            owner: args.owner,
+            labels: args.labels.into_iter().collect(),
+            description: args.description,
+        }
+    }
+}
+
+/// Parse the label key and value separated by a '='
+fn parse_label(s: &str) -> Result<(String, String), IggyError> {
+    let (key, value) = s.split_once('=').ok_or(IggyError::InvalidFormat)?;
+    if key.is_empty() {
+        return Err(IggyError::InvalidFormat);
+    }
+
+    Ok((key.to_owned(), value.to_owned()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_label_should_parse_key_value_pair() {
+        let result = parse_label("team=payments");
+        assert_eq!(
+            result.unwrap(),
+            ("team".to_string(), "payments".to_string())
+        );
+    }
+
+    #[test]
+    fn parse_label_without_separator_should_return_err() {
+        assert!(parse_label("team").is_err());
+    }
+
+    #[test]
+    fn parse_label_without_key_should_return_err() {
+        assert!(parse_label("=payments").is_err());
+    }
+}
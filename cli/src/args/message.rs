@@ -36,6 +36,8 @@ pub(crate) enum MessageAction {
     ///  iggy message send stream 2 "long message"
     ///  iggy message send 1 topic message1 message2 message3
     ///  iggy message send stream topic "long message with spaces"
+    ///  iggy message send --file payload.json --repeat 10 --rate 2 stream topic
+    ///  cat events.txt | iggy message send --headers source=shell stream topic
     #[clap(verbatim_doc_comment, visible_alias = "s")]
     Send(SendMessagesArgs),
     /// Poll messages from given topic ID and given stream ID
@@ -50,6 +52,20 @@ pub(crate) enum MessageAction {
     ///  iggy message poll --offset 0 stream topic 1
     #[clap(verbatim_doc_comment, visible_alias = "p")]
     Poll(PollMessagesArgs),
+    /// Browse messages from given topic ID and given stream ID
+    ///
+    /// Messages are returned without committing the offset or affecting
+    /// the delivery state of the consumer (group), so that production
+    /// consumer groups can be inspected without perturbing them.
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    ///
+    /// Examples:
+    ///  iggy message browse --first 1 2 1
+    ///  iggy message browse --next --consumer-group --consumer orders stream topic 1
+    #[clap(verbatim_doc_comment, visible_alias = "b")]
+    Browse(BrowseMessagesArgs),
     /// Flush messages from given topic ID and given stream ID
     ///
     /// Command is used to force a flush of unsaved_buffer to disk
@@ -105,6 +121,7 @@ pub(crate) struct SendMessagesArgs {
     /// Headers are comma seperated key-value pairs that can be sent with the message.
     /// Kind can be one of the following: raw, string, bool, int8, int16, int32, int64,
     /// int128, uint8, uint16, uint32, uint64, uint128, float32, float64
+    /// Header in key=value form is sent as a string header.
     #[clap(verbatim_doc_comment)]
     #[clap(short = 'H', long, value_parser = parse_key_val, value_delimiter = ',')]
     pub(crate) headers: Vec<(HeaderKey, HeaderValue)>,
@@ -119,10 +136,41 @@ pub(crate) struct SendMessagesArgs {
     #[clap(verbatim_doc_comment)]
     #[clap(long, value_parser = NonEmptyStringValueParser::new(), group = "input_messages")]
     pub(crate) input_file: Option<String>,
+    /// File with the payload of a single message to be sent
+    ///
+    /// Whole content of the file is sent as a payload of one message,
+    /// e.g. a JSON document. Option cannot be used with the messages
+    /// option or the input file option.
+    #[clap(verbatim_doc_comment)]
+    #[clap(short, long, value_parser = NonEmptyStringValueParser::new(), group = "input_messages")]
+    pub(crate) file: Option<String>,
+    /// Number of times each message is sent
+    ///
+    /// Placeholders {{seq}}, {{timestamp}} and {{uuid}} in the message payload
+    /// are replaced with the sequence number of the sent message, current
+    /// timestamp in microseconds and a newly generated UUID respectively.
+    #[clap(verbatim_doc_comment)]
+    #[clap(short, long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+    pub(crate) repeat: u32,
+    /// Maximum number of messages sent per second
+    ///
+    /// If not specified, all the messages are sent in a single batch,
+    /// otherwise the messages are sent one by one with the given rate.
+    #[clap(verbatim_doc_comment)]
+    #[clap(long, value_parser = clap::value_parser!(u32).range(1..))]
+    pub(crate) rate: Option<u32>,
 }
 
 /// Parse Header Key, Kind and Value from the string separated by a ':'
+/// or Header Key and string Value separated by a '='
 fn parse_key_val(s: &str) -> Result<(HeaderKey, HeaderValue), IggyError> {
+    if !s.contains(':') {
+        let (key, value) = s.split_once('=').ok_or(InvalidFormat)?;
+        let key = HeaderKey::from_str(key)?;
+        let value = HeaderValue::from_str(value)?;
+        return Ok((key, value));
+    }
+
     let lower = s.to_lowercase();
     let parts = lower.split(':').collect::<Vec<_>>();
 
@@ -209,6 +257,63 @@ pub(crate) struct PollMessagesArgs {
     pub(crate) output_file: Option<String>,
 }
 
+#[derive(Debug, Clone, Args)]
+#[command(group = ArgGroup::new("polling_strategy").required(true))]
+pub(crate) struct BrowseMessagesArgs {
+    /// ID of the stream from which message will be browsed
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// ID of the topic from which message will be browsed
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+    /// Partition ID from which message will be browsed
+    #[arg(value_parser = clap::value_parser!(u32).range(1..))]
+    pub(crate) partition_id: u32,
+    /// Number of messages to browse
+    #[clap(verbatim_doc_comment)]
+    #[clap(short, long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+    pub(crate) message_count: u32,
+    /// Browsing strategy - offset to start browsing messages from
+    ///
+    /// Offset must be specified as a number
+    #[clap(verbatim_doc_comment)]
+    #[clap(short, long, group = "polling_strategy")]
+    pub(crate) offset: Option<u64>,
+    /// Browsing strategy - start browsing from the first message in the partition
+    #[clap(verbatim_doc_comment)]
+    #[clap(short, long, default_value_t = false, group = "polling_strategy")]
+    pub(crate) first: bool,
+    /// Browsing strategy - start browsing from the last message in the partition
+    #[clap(verbatim_doc_comment)]
+    #[clap(short, long, default_value_t = false, group = "polling_strategy")]
+    pub(crate) last: bool,
+    /// Browsing strategy - start browsing from the next message
+    ///
+    /// Start browsing after the stored offset of the consumer (group),
+    /// which is left unchanged
+    #[clap(verbatim_doc_comment)]
+    #[clap(short, long, default_value_t = false, group = "polling_strategy")]
+    pub(crate) next: bool,
+    /// Consumer whose messages will be browsed
+    ///
+    /// Consumer ID can be specified as a consumer name or ID
+    #[clap(verbatim_doc_comment)]
+    #[clap(short, long, default_value_t = Identifier::default(), value_parser = clap::value_parser!(Identifier))]
+    pub(crate) consumer: Identifier,
+    /// Treat the consumer as the consumer group
+    #[clap(verbatim_doc_comment)]
+    #[clap(short = 'g', long, default_value_t = false)]
+    pub(crate) consumer_group: bool,
+    /// Include the message headers in the output
+    #[clap(verbatim_doc_comment)]
+    #[clap(short, long, default_value_t = false)]
+    pub(crate) show_headers: bool,
+}
+
 #[derive(Debug, Clone, Args)]
 pub(crate) struct FlushMessagesArgs {
     /// ID of the stream for which messages will be flushed
@@ -278,6 +383,21 @@ mod tests {
         assert_eq!(value.as_bool().unwrap(), expected_value);
     }
 
+    #[test]
+    fn parse_key_val_should_parse_key_value_pair_as_string() {
+        let result = parse_key_val("source=Shell");
+        assert!(result.is_ok());
+        let (key, value) = result.unwrap();
+        assert_eq!(key, HeaderKey::from_str("source").unwrap());
+        assert_eq!(value.as_str().unwrap(), "Shell");
+    }
+
+    #[test]
+    fn parse_key_val_without_separator_should_return_err() {
+        let result = parse_key_val("source");
+        assert!(result.is_err());
+    }
+
     #[test]
     fn parse_key_val_to_less_params_should_return_err() {
         let result = parse_key_val("key:string");
@@ -156,6 +156,13 @@ pub(crate) enum Command {
     /// Server OS name, version, etc. are also collected.
     #[clap(verbatim_doc_comment)]
     Stats(StatsArgs),
+    /// diagnose connection and environment problems
+    ///
+    /// Command checks the connectivity on each transport, validity of the credentials,
+    /// TLS configuration, clock skew, server limits and version compatibility
+    /// and prints the suggested fix for every detected problem.
+    #[clap(verbatim_doc_comment)]
+    Doctor,
     /// collect iggy server troubleshooting data
     #[clap(verbatim_doc_comment)]
     Snapshot(SnapshotArgs),
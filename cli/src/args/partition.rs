@@ -47,6 +47,19 @@ pub(crate) enum PartitionAction {
     ///  iggy partition delete 1 sensor 16
     #[clap(verbatim_doc_comment, visible_alias = "d")]
     Delete(PartitionDeleteArgs),
+    /// Restore the archived segments of the specified partition ID,
+    /// topic ID and stream ID back into the local storage.
+    ///
+    /// All the archived segments overlapping the given offsets range,
+    /// which are no longer available locally, are restored.
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    ///
+    /// Examples
+    ///  iggy partition restore 1 1 1 0 999
+    ///  iggy partition restore prod sensor 2 5000 5999
+    #[clap(verbatim_doc_comment, visible_alias = "r")]
+    Restore(PartitionRestoreArgs),
 }
 
 #[derive(Debug, Clone, Args)]
@@ -82,3 +95,24 @@ pub(crate) struct PartitionDeleteArgs {
     #[arg(value_parser = clap::value_parser!(u32).range(1..100_001))]
     pub(crate) partitions_count: u32,
 }
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct PartitionRestoreArgs {
+    /// Stream ID to restore archived segments
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// Topic ID to restore archived segments
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+    /// Partition ID to restore archived segments
+    #[arg(value_parser = clap::value_parser!(u32).range(1..))]
+    pub(crate) partition_id: u32,
+    /// The first offset of the range to restore
+    pub(crate) start_offset: u64,
+    /// The last offset of the range to restore, inclusive
+    pub(crate) end_offset: u64,
+}
@@ -18,6 +18,8 @@
 
 use crate::args::common::ListMode;
 use clap::{Args, Subcommand};
+use iggy::models::personal_access_token::PersonalAccessTokenResource;
+use iggy::utils::ip_range::IpRange;
 use iggy::utils::personal_access_token_expiry::PersonalAccessTokenExpiry;
 
 #[derive(Debug, Clone, Subcommand)]
@@ -32,6 +34,8 @@ pub(crate) enum PersonalAccessTokenAction {
     ///  iggy pat create name
     ///  iggy pat create client 1day
     ///  iggy pat create sensor 3weeks
+    ///  iggy pat create reader --read-only --resource 1 --resource 2/3
+    ///  iggy pat create ci 30days --allowed-ip 10.0.0.0/8
     #[clap(verbatim_doc_comment, visible_alias = "c")]
     Create(PersonalAccessTokenCreateArgs),
     /// Delete personal access token
@@ -47,6 +51,18 @@ pub(crate) enum PersonalAccessTokenAction {
     ///  iggy pat list
     #[clap(verbatim_doc_comment, visible_alias = "l")]
     List(PersonalAccessTokenListArgs),
+    /// Rotate personal access token secret
+    ///
+    /// Issue a new secret for the existing personal access token, keeping its name
+    /// and scope. The previous secret stops working immediately. If the token is
+    /// stored in the platform-specific secure store, the stored token is replaced.
+    /// In quiet mode only the new personal access token is printed
+    ///
+    /// Examples
+    ///  iggy pat rotate name
+    ///  iggy pat rotate client 30days
+    #[clap(verbatim_doc_comment, visible_alias = "r")]
+    Rotate(PersonalAccessTokenRotateArgs),
 }
 
 #[derive(Debug, Clone, Args)]
@@ -68,6 +84,21 @@ pub(crate) struct PersonalAccessTokenCreateArgs {
     /// This option can only be used for creating tokens which does not have expiry time set.
     #[clap(short, long, default_value_t = false, group = "store")]
     pub(crate) store_token: bool,
+    /// Allow only the commands which do not modify the data
+    ///
+    /// Consuming the messages (storing the offsets, joining the consumer groups etc.) is still allowed.
+    #[clap(long, default_value_t = false)]
+    pub(crate) read_only: bool,
+    /// Limit the token to the stream or the topic, in the stream_id or stream_id/topic_id format
+    ///
+    /// Can be provided multiple times, all the streams are allowed if not provided.
+    #[arg(long = "resource", value_parser = clap::value_parser!(PersonalAccessTokenResource))]
+    pub(crate) resources: Vec<PersonalAccessTokenResource>,
+    /// Allow using the token only from the IP address range in the CIDR notation, e.g. 10.0.0.0/8
+    ///
+    /// Can be provided multiple times, any address is allowed if not provided.
+    #[arg(long = "allowed-ip", value_parser = clap::value_parser!(IpRange))]
+    pub(crate) allowed_ip_ranges: Vec<IpRange>,
 }
 
 #[derive(Debug, Clone, Args)]
@@ -76,6 +107,18 @@ pub(crate) struct PersonalAccessTokenDeleteArgs {
     pub(crate) name: String,
 }
 
+#[derive(Debug, Clone, Args)]
+pub(crate) struct PersonalAccessTokenRotateArgs {
+    /// Personal access token name to rotate
+    pub(crate) name: String,
+    /// Expiry time of the new secret in human-readable format
+    ///
+    /// Expiry time must be expressed in human-readable format like 15days 2min 2s
+    /// ("none" or skipping parameter disables personal access token expiry)
+    #[arg(value_parser = clap::value_parser!(PersonalAccessTokenExpiry))]
+    pub(crate) expiry: Option<Vec<PersonalAccessTokenExpiry>>,
+}
+
 #[derive(Debug, Clone, Args)]
 pub(crate) struct PersonalAccessTokenListArgs {
     /// List mode (table or list)
@@ -16,7 +16,7 @@
  * under the License.
  */
 
-use crate::args::common::ListMode;
+use crate::args::common::{ListMode, MetadataArgs, MetadataFilterArgs};
 use clap::{Args, Subcommand};
 use iggy::identifier::Identifier;
 
@@ -29,6 +29,7 @@ pub(crate) enum StreamAction {
     /// Examples:
     ///  iggy stream create prod
     ///  iggy stream create -s 1 test
+    ///  iggy stream create --owner payments --label env=prod prod
     #[clap(verbatim_doc_comment, visible_alias = "c")]
     Create(StreamCreateArgs),
     /// Delete stream with given ID
@@ -64,6 +65,7 @@ pub(crate) enum StreamAction {
     ///  iggy stream list
     ///  iggy stream list --list-mode table
     ///  iggy stream list -l table
+    ///  iggy stream list --owner payments --label env=prod
     #[clap(verbatim_doc_comment, visible_alias = "l")]
     List(StreamListArgs),
     /// Purge all topics in given stream ID
@@ -85,6 +87,8 @@ pub(crate) struct StreamCreateArgs {
     pub(crate) stream_id: Option<u32>,
     /// Name of the stream
     pub(crate) name: String,
+    #[clap(flatten)]
+    pub(crate) metadata: MetadataArgs,
 }
 
 #[derive(Debug, Clone, Args)]
@@ -118,6 +122,8 @@ pub(crate) struct StreamListArgs {
     /// List mode (table or list)
     #[clap(short, long, value_enum, default_value_t = ListMode::Table)]
     pub(crate) list_mode: ListMode,
+    #[clap(flatten)]
+    pub(crate) filter: MetadataFilterArgs,
 }
 
 #[derive(Debug, Clone, Args)]
@@ -16,10 +16,11 @@
  * under the License.
  */
 
-use crate::args::common::ListMode;
+use crate::args::common::{ListMode, MetadataArgs, MetadataFilterArgs};
 use clap::{Args, Subcommand};
 use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::identifier::Identifier;
+use iggy::utils::duration::IggyDuration;
 use iggy::utils::expiry::IggyExpiry;
 use iggy::utils::topic_size::MaxTopicSize;
 
@@ -35,6 +36,7 @@ pub(crate) enum TopicAction {
     ///  iggy topic create prod sensor2 2 none
     ///  iggy topic create test debugs 2 gzip 1day 1hour 1min 1sec
     ///  iggy topic create -t 3 1 sensor3 2 none unlimited
+    ///  iggy topic create --owner payments --label env=prod prod sensor4 2 none
     #[clap(verbatim_doc_comment, visible_alias = "c")]
     Create(TopicCreateArgs),
     /// Delete topic with given ID in given stream ID
@@ -81,11 +83,13 @@ pub(crate) enum TopicAction {
     /// Examples
     ///  iggy topic list 1
     ///  iggy topic list prod
+    ///  iggy topic list --owner payments --label env=prod prod
     #[clap(verbatim_doc_comment, visible_alias = "l")]
     List(TopicListArgs),
     /// Purge topic with given ID in given stream ID
     ///
     /// Command removes all messages from given topic
+    /// Consumer offsets are reset unless they are explicitly kept
     /// Stream ID can be specified as a stream name or ID
     /// Topic ID can be specified as a topic name or ID
     ///
@@ -94,6 +98,8 @@ pub(crate) enum TopicAction {
     ///  iggy topic purge prod 2
     ///  iggy topic purge test debugs
     ///  iggy topic purge 2 debugs
+    ///  iggy topic purge prod 2 --keep-consumer-offsets
+    ///  iggy topic purge prod 2 --older-than 7days
     #[clap(verbatim_doc_comment, visible_alias = "p")]
     Purge(TopicPurgeArgs),
 }
@@ -129,6 +135,8 @@ pub(crate) struct TopicCreateArgs {
     /// "server_default" or skipping parameter makes CLI to use server default (from current server config) expiry time
     #[arg(default_value = "server_default", value_parser = clap::value_parser!(IggyExpiry), verbatim_doc_comment)]
     pub(crate) message_expiry: Vec<IggyExpiry>,
+    #[clap(flatten)]
+    pub(crate) metadata: MetadataArgs,
 }
 
 #[derive(Debug, Clone, Args)]
@@ -171,6 +179,9 @@ pub(crate) struct TopicUpdateArgs {
     #[arg(short, long, default_value = "1")]
     /// New replication factor for the topic
     pub(crate) replication_factor: u8,
+    /// New max number of segments per partition, 0 for unlimited, skipping parameter keeps the current value
+    #[arg(short = 's', long)]
+    pub(crate) max_segments: Option<u32>,
     /// New message expiry time in human-readable format like "unlimited" or "15days 2min 2s"
     ///
     /// "server_default" or skipping parameter makes CLI to use server default (from current server config) expiry time
@@ -203,6 +214,8 @@ pub(crate) struct TopicListArgs {
     /// List mode (table or list)
     #[clap(short, long, value_enum, default_value_t = ListMode::Table)]
     pub(crate) list_mode: ListMode,
+    #[clap(flatten)]
+    pub(crate) filter: MetadataFilterArgs,
 }
 
 #[derive(Debug, Clone, Args)]
@@ -217,4 +230,14 @@ pub(crate) struct TopicPurgeArgs {
     /// Topic ID can be specified as a topic name or ID
     #[arg(value_parser = clap::value_parser!(Identifier))]
     pub(crate) topic_id: Identifier,
+    /// Keep the consumer offsets and continue the offsets sequence
+    ///
+    /// By default, the offsets are reset and consumers start from the beginning
+    #[arg(short, long, default_value_t = false)]
+    pub(crate) keep_consumer_offsets: bool,
+    /// Purge only the data older than given duration
+    ///
+    /// Only the closed segments with all the messages older than given duration are removed
+    #[arg(short, long)]
+    pub(crate) older_than: Option<IggyDuration>,
 }
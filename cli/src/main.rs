@@ -55,17 +55,21 @@ use iggy::cli::{
         flush_messages::FlushMessagesCmd, poll_messages::PollMessagesCmd,
         send_messages::SendMessagesCmd,
     },
-    partitions::{create_partitions::CreatePartitionsCmd, delete_partitions::DeletePartitionsCmd},
+    partitions::{
+        create_partitions::CreatePartitionsCmd, delete_partitions::DeletePartitionsCmd,
+        restore_archived_segments::RestoreArchivedSegmentsCmd,
+    },
     personal_access_tokens::{
         create_personal_access_token::CreatePersonalAccessTokenCmd,
         delete_personal_access_tokens::DeletePersonalAccessTokenCmd,
         get_personal_access_tokens::GetPersonalAccessTokensCmd,
+        rotate_personal_access_token::RotatePersonalAccessTokenCmd,
     },
     streams::{
         create_stream::CreateStreamCmd, delete_stream::DeleteStreamCmd, get_stream::GetStreamCmd,
         get_streams::GetStreamsCmd, purge_stream::PurgeStreamCmd, update_stream::UpdateStreamCmd,
     },
-    system::{me::GetMeCmd, ping::PingCmd, stats::GetStatsCmd},
+    system::{doctor::DoctorCmd, me::GetMeCmd, ping::PingCmd, stats::GetStatsCmd},
     topics::{
         create_topic::CreateTopicCmd, delete_topic::DeleteTopicCmd, get_topic::GetTopicCmd,
         get_topics::GetTopicsCmd, purge_topic::PurgeTopicCmd, update_topic::UpdateTopicCmd,
@@ -83,6 +87,7 @@ use iggy::cli::{
 use iggy::cli_command::{CliCommand, PRINT_TARGET};
 use iggy::client_provider::{self, ClientProviderConfig};
 use iggy::clients::client::IggyClient;
+use iggy::models::personal_access_token::PersonalAccessTokenScope;
 use iggy::utils::crypto::{Aes256GcmEncryptor, EncryptorKind};
 use iggy::utils::personal_access_token_expiry::PersonalAccessTokenExpiry;
 use std::sync::Arc;
@@ -105,16 +110,21 @@ fn get_command(
     #[warn(clippy::let_and_return)]
     match command {
         Command::Stream(command) => match command {
-            StreamAction::Create(args) => {
-                Box::new(CreateStreamCmd::new(args.stream_id, args.name.clone()))
-            }
+            StreamAction::Create(args) => Box::new(CreateStreamCmd::new(
+                args.stream_id,
+                args.name.clone(),
+                args.metadata.clone().into(),
+            )),
             StreamAction::Delete(args) => Box::new(DeleteStreamCmd::new(args.stream_id.clone())),
             StreamAction::Update(args) => Box::new(UpdateStreamCmd::new(
                 args.stream_id.clone(),
                 args.name.clone(),
             )),
             StreamAction::Get(args) => Box::new(GetStreamCmd::new(args.stream_id.clone())),
-            StreamAction::List(args) => Box::new(GetStreamsCmd::new(args.list_mode.into())),
+            StreamAction::List(args) => Box::new(GetStreamsCmd::new(
+                args.list_mode.into(),
+                args.filter.clone().into(),
+            )),
             StreamAction::Purge(args) => Box::new(PurgeStreamCmd::new(args.stream_id.clone())),
         },
         Command::Topic(command) => match command {
@@ -127,6 +137,7 @@ fn get_command(
                 args.message_expiry.clone().into(),
                 args.max_topic_size,
                 args.replication_factor,
+                args.metadata.clone().into(),
             )),
             TopicAction::Delete(args) => Box::new(DeleteTopicCmd::new(
                 args.stream_id.clone(),
@@ -140,6 +151,7 @@ fn get_command(
                 args.message_expiry.clone().into(),
                 args.max_topic_size,
                 args.replication_factor,
+                args.max_segments,
             )),
             TopicAction::Get(args) => Box::new(GetTopicCmd::new(
                 args.stream_id.clone(),
@@ -148,10 +160,13 @@ fn get_command(
             TopicAction::List(args) => Box::new(GetTopicsCmd::new(
                 args.stream_id.clone(),
                 args.list_mode.into(),
+                args.filter.clone().into(),
             )),
             TopicAction::Purge(args) => Box::new(PurgeTopicCmd::new(
                 args.stream_id.clone(),
                 args.topic_id.clone(),
+                args.keep_consumer_offsets,
+                args.older_than,
             )),
         },
         Command::Partition(command) => match command {
@@ -165,10 +180,27 @@ fn get_command(
                 args.topic_id.clone(),
                 args.partitions_count,
             )),
+            PartitionAction::Restore(args) => Box::new(RestoreArchivedSegmentsCmd::new(
+                args.stream_id.clone(),
+                args.topic_id.clone(),
+                args.partition_id,
+                args.start_offset,
+                args.end_offset,
+            )),
         },
         Command::Ping(args) => Box::new(PingCmd::new(args.count)),
         Command::Me => Box::new(GetMeCmd::new()),
         Command::Stats(args) => Box::new(GetStatsCmd::new(cli_options.quiet, args.output.into())),
+        Command::Doctor => {
+            let mut args = iggy_args.clone();
+            if let Some(username) = &cli_options.username {
+                args.username.clone_from(username);
+            }
+            if let Some(password) = &cli_options.password {
+                args.password.clone_from(password);
+            }
+            Box::new(DoctorCmd::new(args, cli_options.token.clone()))
+        }
         Command::Snapshot(args) => Box::new(GetSnapshotCmd::new(
             args.compression,
             args.snapshot_types,
@@ -179,6 +211,11 @@ fn get_command(
                 Box::new(CreatePersonalAccessTokenCmd::new(
                     pat_create_args.name.clone(),
                     PersonalAccessTokenExpiry::new(pat_create_args.expiry.clone()),
+                    PersonalAccessTokenScope {
+                        read_only: pat_create_args.read_only,
+                        resources: pat_create_args.resources.clone(),
+                        allowed_ip_ranges: pat_create_args.allowed_ip_ranges.clone(),
+                    },
                     cli_options.quiet,
                     pat_create_args.store_token,
                     iggy_args.get_server_address().unwrap(),
@@ -193,6 +230,14 @@ fn get_command(
             PersonalAccessTokenAction::List(pat_list_args) => Box::new(
                 GetPersonalAccessTokensCmd::new(pat_list_args.list_mode.into()),
             ),
+            PersonalAccessTokenAction::Rotate(pat_rotate_args) => {
+                Box::new(RotatePersonalAccessTokenCmd::new(
+                    pat_rotate_args.name.clone(),
+                    PersonalAccessTokenExpiry::new(pat_rotate_args.expiry.clone()),
+                    cli_options.quiet,
+                    iggy_args.get_server_address().unwrap(),
+                ))
+            }
         },
         Command::User(command) => match command {
             UserAction::Create(create_args) => Box::new(CreateUserCmd::new(
@@ -270,6 +315,9 @@ fn get_command(
                 send_args.messages.clone(),
                 send_args.headers.clone(),
                 send_args.input_file.clone(),
+                send_args.file.clone(),
+                send_args.repeat,
+                send_args.rate,
             )),
             MessageAction::Poll(poll_args) => Box::new(PollMessagesCmd::new(
                 poll_args.stream_id.clone(),
@@ -285,6 +333,23 @@ fn get_command(
                 poll_args.show_headers,
                 poll_args.output_file.clone(),
             )),
+            MessageAction::Browse(browse_args) => Box::new(
+                PollMessagesCmd::new(
+                    browse_args.stream_id.clone(),
+                    browse_args.topic_id.clone(),
+                    browse_args.partition_id,
+                    browse_args.message_count,
+                    false,
+                    browse_args.offset,
+                    browse_args.first,
+                    browse_args.last,
+                    browse_args.next,
+                    browse_args.consumer.clone(),
+                    browse_args.show_headers,
+                    None,
+                )
+                .browse(browse_args.consumer_group),
+            ),
             MessageAction::Flush(flush_args) => Box::new(FlushMessagesCmd::new(
                 flush_args.stream_id.clone(),
                 flush_args.topic_id.clone(),
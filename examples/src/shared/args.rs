@@ -105,6 +105,9 @@ pub struct Args {
     #[arg(long, default_value = "false")]
     pub tcp_nodelay: bool,
 
+    #[arg(long, default_value = "local_data/iggy.sock")]
+    pub uds_socket_path: String,
+
     #[arg(long, default_value = "127.0.0.1:0")]
     pub quic_client_address: String,
 
@@ -198,6 +201,7 @@ impl Default for Args {
             tcp_tls_enabled: false,
             tcp_tls_domain: "localhost".to_string(),
             tcp_nodelay: true,
+            uds_socket_path: "local_data/iggy.sock".to_string(),
             quic_client_address: "127.0.0.1:0".to_string(),
             quic_server_address: "127.0.0.1:8080".to_string(),
             quic_server_name: "localhost".to_string(),
@@ -234,10 +238,23 @@ impl Args {
             tcp_reconnection_interval: self.tcp_reconnection_interval.clone(),
             tcp_reconnection_reestablish_after: self.tcp_reconnection_reestablish_after.clone(),
             tcp_heartbeat_interval: self.tcp_heartbeat_interval.clone(),
+            tcp_heartbeat_timeout: "5s".to_string(),
             tcp_tls_enabled: self.tcp_tls_enabled,
             tcp_tls_domain: self.tcp_tls_domain.clone(),
             tcp_tls_ca_file: None,
+            tcp_tls_cert_file: None,
+            tcp_tls_key_file: None,
             tcp_nodelay: self.tcp_nodelay,
+            tcp_frame_checksums: false,
+            tcp_dns_ttl: "30s".to_string(),
+            tcp_health_check_interval: "0".to_string(),
+            tcp_health_check_timeout: "3s".to_string(),
+            uds_socket_path: self.uds_socket_path.clone(),
+            uds_reconnection_enabled: self.tcp_reconnection_enabled,
+            uds_reconnection_max_retries: self.tcp_reconnection_max_retries,
+            uds_reconnection_interval: self.tcp_reconnection_interval.clone(),
+            uds_reconnection_reestablish_after: self.tcp_reconnection_reestablish_after.clone(),
+            uds_heartbeat_interval: self.tcp_heartbeat_interval.clone(),
             quic_client_address: self.quic_client_address.clone(),
             quic_server_address: self.quic_server_address.clone(),
             quic_server_name: self.quic_server_name.clone(),
@@ -25,3 +25,6 @@ pub mod quic_client;
 pub mod tcp_client;
 #[allow(deprecated)]
 pub mod test_server;
+#[cfg(unix)]
+#[allow(deprecated)]
+pub mod uds_client;
@@ -27,6 +27,8 @@ use std::sync::Arc;
 pub struct TcpClientFactory {
     pub server_addr: String,
     pub nodelay: bool,
+    pub tls_enabled: bool,
+    pub tls_ca_file: Option<String>,
 }
 
 #[async_trait]
@@ -35,6 +37,8 @@ impl ClientFactory for TcpClientFactory {
         let config = TcpClientConfig {
             server_address: self.server_addr.clone(),
             nodelay: self.nodelay,
+            tls_enabled: self.tls_enabled,
+            tls_ca_file: self.tls_ca_file.clone(),
             ..TcpClientConfig::default()
         };
         let client = TcpClient::create(Arc::new(config)).unwrap_or_else(|e| {
@@ -83,6 +83,9 @@ enum ServerProtocolAddr {
 
     #[display("QUIC_UDP:{_0}")]
     QuicUdp(SocketAddr),
+
+    #[display("UDS:{_0}")]
+    Uds(String),
 }
 
 #[derive(Debug)]
@@ -301,6 +304,7 @@ impl TestServer {
                 ServerProtocolAddr::QuicUdp(addr) => {
                     ("IGGY_QUIC_ADDRESS".to_string(), addr.to_string())
                 }
+                ServerProtocolAddr::Uds(path) => ("IGGY_UDS_PATH".to_string(), path.clone()),
             };
 
             self.envs.entry(key.0).or_insert(key.1);
@@ -350,6 +354,11 @@ impl TestServer {
             self.server_addrs.push(ServerProtocolAddr::HttpTcp(
                 config.http.address.parse().unwrap(),
             ));
+
+            if config.uds.enabled {
+                self.server_addrs
+                    .push(ServerProtocolAddr::Uds(config.uds.path.clone()));
+            }
         } else {
             panic!(
                 "Failed to load config from file {} in {} s!",
@@ -413,6 +422,15 @@ impl TestServer {
         None
     }
 
+    pub fn get_uds_path(&self) -> Option<String> {
+        for server_protocol_addr in &self.server_addrs {
+            if let ServerProtocolAddr::Uds(path) = server_protocol_addr {
+                return Some(path.clone());
+            }
+        }
+        None
+    }
+
     pub fn get_server_ip_addr(&self) -> Option<String> {
         if let Some(server_address) = self
             .get_raw_tcp_addr()
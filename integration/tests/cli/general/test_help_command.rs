@@ -39,6 +39,7 @@ Commands:
   ping             ping iggy server
   me               get current client info
   stats            get iggy server statistics
+  doctor           diagnose connection and environment problems
   snapshot         collect iggy server troubleshooting data
   pat              personal access token operations
   user             user operations [aliases: u]
@@ -53,7 +54,7 @@ Commands:
 
 Options:
       --transport <TRANSPORT>
-          The transport to use. Valid values are `quic`, `http` and `tcp`
+          The transport to use. Valid values are `quic`, `http`, `tcp` and `uds`
 {CLAP_INDENT}
           [default: tcp]
 
@@ -105,6 +106,11 @@ Options:
 {CLAP_INDENT}
           [default: localhost]
 
+      --uds-socket-path <UDS_SOCKET_PATH>
+          The optional socket path for the UDS transport
+{CLAP_INDENT}
+          [default: local_data/iggy.sock]
+
       --quic-client-address <QUIC_CLIENT_ADDRESS>
           The optional client address for the QUIC transport
 {CLAP_INDENT}
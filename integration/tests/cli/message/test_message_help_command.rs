@@ -33,10 +33,11 @@ pub async fn should_help_match() {
 {USAGE_PREFIX} message <COMMAND>
 
 Commands:
-  send   Send messages to given topic ID and given stream ID [aliases: s]
-  poll   Poll messages from given topic ID and given stream ID [aliases: p]
-  flush  Flush messages from given topic ID and given stream ID [aliases: f]
-  help   Print this message or the help of the given subcommand(s)
+  send    Send messages to given topic ID and given stream ID [aliases: s]
+  poll    Poll messages from given topic ID and given stream ID [aliases: p]
+  browse  Browse messages from given topic ID and given stream ID [aliases: b]
+  flush   Flush messages from given topic ID and given stream ID [aliases: f]
+  help    Print this message or the help of the given subcommand(s)
 
 Options:
   -h, --help  Print help
@@ -400,6 +400,8 @@ Examples
  iggy message send stream 2 "long message"
  iggy message send 1 topic message1 message2 message3
  iggy message send stream topic "long message with spaces"
+ iggy message send --file payload.json --repeat 10 --rate 2 stream topic
+ cat events.txt | iggy message send --headers source=shell stream topic
 
 {USAGE_PREFIX} message send [OPTIONS] <STREAM_ID> <TOPIC_ID> [MESSAGES]...
 
@@ -438,6 +440,7 @@ Options:
           Headers are comma seperated key-value pairs that can be sent with the message.
           Kind can be one of the following: raw, string, bool, int8, int16, int32, int64,
           int128, uint8, uint16, uint32, uint64, uint128, float32, float64
+          Header in key=value form is sent as a string header.
 
       --input-file <INPUT_FILE>
           Input file with messages to be sent
@@ -449,6 +452,28 @@ Options:
           will be read from the file and sent as is. Option cannot be used
           with the messages option (messages given as command line arguments).
 
+  -f, --file <FILE>
+          File with the payload of a single message to be sent
+{CLAP_INDENT}
+          Whole content of the file is sent as a payload of one message,
+          e.g. a JSON document. Option cannot be used with the messages
+          option or the input file option.
+
+  -r, --repeat <REPEAT>
+          Number of times each message is sent
+{CLAP_INDENT}
+          Placeholders {{{{seq}}}}, {{{{timestamp}}}} and {{{{uuid}}}} in the message payload
+          are replaced with the sequence number of the sent message, current
+          timestamp in microseconds and a newly generated UUID respectively.
+{CLAP_INDENT}
+          [default: 1]
+
+      --rate <RATE>
+          Maximum number of messages sent per second
+{CLAP_INDENT}
+          If not specified, all the messages are sent in a single batch,
+          otherwise the messages are sent one by one with the given rate.
+
   -h, --help
           Print help (see a summary with '-h')
 "#,
@@ -480,6 +505,9 @@ Options:
   -m, --message-key <MESSAGE_KEY>    Messages key which will be used to partition the messages
   -H, --headers <HEADERS>            Comma separated list of key:kind:value, sent as header with the message
       --input-file <INPUT_FILE>      Input file with messages to be sent
+  -f, --file <FILE>                  File with the payload of a single message to be sent
+  -r, --repeat <REPEAT>              Number of times each message is sent [default: 1]
+      --rate <RATE>                  Maximum number of messages sent per second
   -h, --help                         Print help (see more with '--help')
 "#,
             ),
@@ -33,11 +33,13 @@ pub async fn should_help_match() {
 {USAGE_PREFIX} partition <COMMAND>
 
 Commands:
-  create  Create partitions for the specified topic ID
-          and stream ID based on the given count. [aliases: c]
-  delete  Delete partitions for the specified topic ID
-          and stream ID based on the given count. [aliases: d]
-  help    Print this message or the help of the given subcommand(s)
+  create   Create partitions for the specified topic ID
+           and stream ID based on the given count. [aliases: c]
+  delete   Delete partitions for the specified topic ID
+           and stream ID based on the given count. [aliases: d]
+  restore  Restore the archived segments of the specified partition ID,
+           topic ID and stream ID back into the local storage. [aliases: r]
+  help     Print this message or the help of the given subcommand(s)
 
 Options:
   -h, --help  Print help
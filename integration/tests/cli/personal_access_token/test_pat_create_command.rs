@@ -135,6 +135,8 @@ Examples
  iggy pat create name
  iggy pat create client 1day
  iggy pat create sensor 3weeks
+ iggy pat create reader --read-only --resource 1 --resource 2/3
+ iggy pat create ci 30days --allowed-ip 10.0.0.0/8
 
 {USAGE_PREFIX} pat create [OPTIONS] <NAME> [EXPIRY]...
 
@@ -153,6 +155,21 @@ Options:
 {CLAP_INDENT}
           Generated token is stored in a platform-specific secure storage without revealing its content to the user. It can be used to authenticate on iggy server using associated name and -n/--token-name command line option instead of -u/--username and -p/--password or -t/--token. In quiet mode only the token name is printed. This option can only be used for creating tokens which does not have expiry time set.
 
+      --read-only
+          Allow only the commands which do not modify the data
+{CLAP_INDENT}
+          Consuming the messages (storing the offsets, joining the consumer groups etc.) is still allowed.
+
+      --resource <RESOURCES>
+          Limit the token to the stream or the topic, in the stream_id or stream_id/topic_id format
+{CLAP_INDENT}
+          Can be provided multiple times, all the streams are allowed if not provided.
+
+      --allowed-ip <ALLOWED_IP_RANGES>
+          Allow using the token only from the IP address range in the CIDR notation, e.g. 10.0.0.0/8
+{CLAP_INDENT}
+          Can be provided multiple times, any address is allowed if not provided.
+
   -h, --help
           Print help (see a summary with '-h')
 "#,
@@ -179,8 +196,11 @@ Arguments:
   [EXPIRY]...  Personal access token expiry time in human-readable format
 
 Options:
-  -s, --store-token  Store token in an underlying platform-specific secure store
-  -h, --help         Print help (see more with '--help')
+  -s, --store-token                     Store token in an underlying platform-specific secure store
+      --read-only                       Allow only the commands which do not modify the data
+      --resource <RESOURCES>            Limit the token to the stream or the topic, in the stream_id or stream_id/topic_id format
+      --allowed-ip <ALLOWED_IP_RANGES>  Allow using the token only from the IP address range in the CIDR notation, e.g. 10.0.0.0/8
+  -h, --help                            Print help (see more with '--help')
 "#,
             ),
         ))
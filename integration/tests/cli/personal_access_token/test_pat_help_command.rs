@@ -36,6 +36,7 @@ Commands:
   create  Create personal access token [aliases: c]
   delete  Delete personal access token [aliases: d]
   list    List all personal access tokens [aliases: l]
+  rotate  Rotate personal access token secret [aliases: r]
   help    Print this message or the help of the given subcommand(s)
 
 Options:
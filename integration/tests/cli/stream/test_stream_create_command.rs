@@ -20,17 +20,28 @@ use crate::cli::common::{IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpC
 use assert_cmd::assert::Assert;
 use async_trait::async_trait;
 use iggy::client::Client;
+use iggy::models::metadata::ResourceMetadata;
 use predicates::str::diff;
 use serial_test::parallel;
 
 struct TestStreamCreateCmd {
     stream_id: Option<u32>,
     name: String,
+    metadata: ResourceMetadata,
 }
 
 impl TestStreamCreateCmd {
     fn new(stream_id: Option<u32>, name: String) -> Self {
-        Self { stream_id, name }
+        Self {
+            stream_id,
+            name,
+            metadata: ResourceMetadata::default(),
+        }
+    }
+
+    fn with_metadata(mut self, metadata: ResourceMetadata) -> Self {
+        self.metadata = metadata;
+        self
     }
 
     fn to_args(&self) -> Vec<String> {
@@ -41,6 +52,21 @@ impl TestStreamCreateCmd {
             args.push(format!("{}", stream_id));
         }
 
+        if let Some(description) = &self.metadata.description {
+            args.push("--description".to_string());
+            args.push(description.clone());
+        }
+
+        if let Some(owner) = &self.metadata.owner {
+            args.push("--owner".to_string());
+            args.push(owner.clone());
+        }
+
+        for (key, value) in &self.metadata.labels {
+            args.push("--label".to_string());
+            args.push(format!("{key}={value}"));
+        }
+
         args.push(self.name.clone());
 
         args
@@ -80,6 +106,7 @@ impl IggyCmdTestCase for TestStreamCreateCmd {
         assert!(stream.is_ok());
         let stream = stream.unwrap().expect("Stream not found");
         assert_eq!(stream.name, self.name);
+        assert_eq!(stream.metadata, self.metadata);
         if let Some(stream_id) = self.stream_id {
             assert_eq!(stream.id, stream_id);
         }
@@ -105,6 +132,19 @@ pub async fn should_be_successful() {
     iggy_cmd_test
         .execute_test(TestStreamCreateCmd::new(None, String::from("prod")))
         .await;
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(
+            TestStreamCreateCmd::new(None, String::from("billing")).with_metadata(
+                ResourceMetadata {
+                    description: Some(String::from("Billing events")),
+                    owner: Some(String::from("payments")),
+                    labels: [(String::from("env"), String::from("prod"))].into(),
+                },
+            ),
+        )
+        .await;
 }
 
 #[tokio::test]
@@ -123,6 +163,7 @@ If stream ID is not provided then the server will automatically assign it
 Examples:
  iggy stream create prod
  iggy stream create -s 1 test
+ iggy stream create --owner payments --label env=prod prod
 
 {USAGE_PREFIX} stream create [OPTIONS] <NAME>
 
@@ -134,6 +175,15 @@ Options:
   -s, --stream-id <STREAM_ID>
           Stream ID to create
 
+      --description <DESCRIPTION>
+          Free-form description
+
+      --owner <OWNER>
+          Owner, e.g. the team name
+
+      --label <LABEL>
+          Label in key=value form, can be repeated
+
   -h, --help
           Print help (see a summary with '-h')
 "#,
@@ -159,8 +209,11 @@ Arguments:
   <NAME>  Name of the stream
 
 Options:
-  -s, --stream-id <STREAM_ID>  Stream ID to create
-  -h, --help                   Print help (see more with '--help')
+  -s, --stream-id <STREAM_ID>      Stream ID to create
+      --description <DESCRIPTION>  Free-form description
+      --owner <OWNER>              Owner, e.g. the team name
+      --label <LABEL>              Label in key=value form, can be repeated
+  -h, --help                       Print help (see more with '--help')
 "#,
             ),
         ))
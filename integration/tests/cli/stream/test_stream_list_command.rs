@@ -23,6 +23,9 @@ use crate::cli::common::{
 use assert_cmd::assert::Assert;
 use async_trait::async_trait;
 use iggy::client::Client;
+use iggy::models::metadata::ResourceMetadata;
+use iggy::streams::create_stream::CreateStreamOptions;
+use predicates::prelude::PredicateBooleanExt;
 use predicates::str::{contains, starts_with};
 use serial_test::parallel;
 
@@ -74,6 +77,58 @@ impl IggyCmdTestCase for TestStreamListCmd {
     async fn verify_server_state(&self, _client: &dyn Client) {}
 }
 
+struct TestStreamListFilteredCmd {
+    owner: String,
+    owned_stream: String,
+    other_stream: String,
+}
+
+impl TestStreamListFilteredCmd {
+    fn new(owner: String, owned_stream: String, other_stream: String) -> Self {
+        Self {
+            owner,
+            owned_stream,
+            other_stream,
+        }
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestStreamListFilteredCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let options = CreateStreamOptions {
+            metadata: ResourceMetadata {
+                owner: Some(self.owner.clone()),
+                ..Default::default()
+            },
+        };
+        let stream = client
+            .create_stream_with_options(&self.owned_stream, None, &options)
+            .await;
+        assert!(stream.is_ok());
+        let stream = client.create_stream(&self.other_stream, None).await;
+        assert!(stream.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("stream")
+            .arg("list")
+            .arg("--owner")
+            .arg(self.owner.clone())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        command_state
+            .success()
+            .stdout(contains(self.owned_stream.clone()))
+            .stdout(contains(self.other_stream.clone()).not());
+    }
+
+    async fn verify_server_state(&self, _client: &dyn Client) {}
+}
+
 #[tokio::test]
 #[parallel]
 pub async fn should_be_successful() {
@@ -103,6 +158,21 @@ pub async fn should_be_successful() {
         .await;
 }
 
+#[tokio::test]
+#[parallel]
+pub async fn should_list_only_streams_matching_filter() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestStreamListFilteredCmd::new(
+            String::from("payments"),
+            String::from("billing"),
+            String::from("analytics"),
+        ))
+        .await;
+}
+
 #[tokio::test]
 #[parallel]
 pub async fn should_help_match() {
@@ -118,6 +188,7 @@ Examples:
  iggy stream list
  iggy stream list --list-mode table
  iggy stream list -l table
+ iggy stream list --owner payments --label env=prod
 
 {USAGE_PREFIX} stream list [OPTIONS]
 
@@ -128,6 +199,15 @@ Options:
           [default: table]
           [possible values: table, list]
 
+      --owner <OWNER>
+          Show only the ones with the given owner
+
+      --label <LABEL>
+          Show only the ones with the label in key=value form, can be repeated
+
+      --description <DESCRIPTION>
+          Show only the ones with the description containing the given text
+
   -h, --help
           Print help (see a summary with '-h')
 "#,
@@ -150,8 +230,11 @@ pub async fn should_short_help_match() {
 {USAGE_PREFIX} stream list [OPTIONS]
 
 Options:
-  -l, --list-mode <LIST_MODE>  List mode (table or list) [default: table] [possible values: table, list]
-  -h, --help                   Print help (see more with '--help')
+  -l, --list-mode <LIST_MODE>      List mode (table or list) [default: table] [possible values: table, list]
+      --owner <OWNER>              Show only the ones with the given owner
+      --label <LABEL>              Show only the ones with the label in key=value form, can be repeated
+      --description <DESCRIPTION>  Show only the ones with the description containing the given text
+  -h, --help                       Print help (see more with '--help')
 "#,
             ),
         ))
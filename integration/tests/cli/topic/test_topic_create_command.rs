@@ -280,6 +280,7 @@ Examples
  iggy topic create prod sensor2 2 none
  iggy topic create test debugs 2 gzip 1day 1hour 1min 1sec
  iggy topic create -t 3 1 sensor3 2 none unlimited
+ iggy topic create --owner payments --label env=prod prod sensor4 2 none
 
 {USAGE_PREFIX} topic create [OPTIONS] <STREAM_ID> <NAME> <PARTITIONS_COUNT> <COMPRESSION_ALGORITHM> [MESSAGE_EXPIRY]...
 
@@ -322,6 +323,15 @@ Options:
 {CLAP_INDENT}
           [default: 1]
 
+      --description <DESCRIPTION>
+          Free-form description
+
+      --owner <OWNER>
+          Owner, e.g. the team name
+
+      --label <LABEL>
+          Label in key=value form, can be repeated
+
   -h, --help
           Print help (see a summary with '-h')
 "#,
@@ -356,6 +366,12 @@ Options:
           Max topic size in human-readable format like "unlimited" or "15GB" [default: server_default]
   -r, --replication-factor <REPLICATION_FACTOR>
           Replication factor for the topic [default: 1]
+      --description <DESCRIPTION>
+          Free-form description
+      --owner <OWNER>
+          Owner, e.g. the team name
+      --label <LABEL>
+          Label in key=value form, can be repeated
   -h, --help
           Print help (see more with '--help')
 "#,
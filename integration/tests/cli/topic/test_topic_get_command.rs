@@ -129,6 +129,7 @@ impl IggyCmdTestCase for TestTopicGetCmd {
             .stdout(contains("Topic size          | 0"))
             .stdout(contains("Message expiry      | unlimited"))
             .stdout(contains("Max topic size      | unlimited"))
+            .stdout(contains("Max segments        | unlimited"))
             .stdout(contains("Topic message count | 0"))
             .stdout(contains("Partitions count    | 1"));
     }
@@ -184,6 +184,7 @@ Stream ID can be specified as a stream name or ID
 Examples
  iggy topic list 1
  iggy topic list prod
+ iggy topic list --owner payments --label env=prod prod
 
 {USAGE_PREFIX} topic list [OPTIONS] <STREAM_ID>
 
@@ -200,6 +201,15 @@ Options:
           [default: table]
           [possible values: table, list]
 
+      --owner <OWNER>
+          Show only the ones with the given owner
+
+      --label <LABEL>
+          Show only the ones with the label in key=value form, can be repeated
+
+      --description <DESCRIPTION>
+          Show only the ones with the description containing the given text
+
   -h, --help
           Print help (see a summary with '-h')
 "#,
@@ -225,8 +235,11 @@ Arguments:
   <STREAM_ID>  Stream ID to list topics
 
 Options:
-  -l, --list-mode <LIST_MODE>  List mode (table or list) [default: table] [possible values: table, list]
-  -h, --help                   Print help (see more with '--help')
+  -l, --list-mode <LIST_MODE>      List mode (table or list) [default: table] [possible values: table, list]
+      --owner <OWNER>              Show only the ones with the given owner
+      --label <LABEL>              Show only the ones with the label in key=value form, can be repeated
+      --description <DESCRIPTION>  Show only the ones with the description containing the given text
+  -h, --help                       Print help (see more with '--help')
 "#,
             ),
         ))
@@ -232,6 +232,7 @@ pub async fn should_help_match() {
                 r"Purge topic with given ID in given stream ID
 
 Command removes all messages from given topic
+Consumer offsets are reset unless they are explicitly kept
 Stream ID can be specified as a stream name or ID
 Topic ID can be specified as a topic name or ID
 
@@ -240,8 +241,10 @@ Examples
  iggy topic purge prod 2
  iggy topic purge test debugs
  iggy topic purge 2 debugs
+ iggy topic purge prod 2 --keep-consumer-offsets
+ iggy topic purge prod 2 --older-than 7days
 
-{USAGE_PREFIX} topic purge <STREAM_ID> <TOPIC_ID>
+{USAGE_PREFIX} topic purge [OPTIONS] <STREAM_ID> <TOPIC_ID>
 
 Arguments:
   <STREAM_ID>
@@ -255,6 +258,16 @@ Arguments:
           Topic ID can be specified as a topic name or ID
 
 Options:
+  -k, --keep-consumer-offsets
+          Keep the consumer offsets and continue the offsets sequence
+{CLAP_INDENT}
+          By default, the offsets are reset and consumers start from the beginning
+
+  -o, --older-than <OLDER_THAN>
+          Purge only the data older than given duration
+{CLAP_INDENT}
+          Only the closed segments with all the messages older than given duration are removed
+
   -h, --help
           Print help (see a summary with '-h')
 ",
@@ -274,14 +287,16 @@ pub async fn should_short_help_match() {
             format!(
                 r#"Purge topic with given ID in given stream ID
 
-{USAGE_PREFIX} topic purge <STREAM_ID> <TOPIC_ID>
+{USAGE_PREFIX} topic purge [OPTIONS] <STREAM_ID> <TOPIC_ID>
 
 Arguments:
   <STREAM_ID>  Stream ID to purge topic
   <TOPIC_ID>   Topic ID to purge
 
 Options:
-  -h, --help  Print help (see more with '--help')
+  -k, --keep-consumer-offsets    Keep the consumer offsets and continue the offsets sequence
+  -o, --older-than <OLDER_THAN>  Purge only the data older than given duration
+  -h, --help                     Print help (see more with '--help')
 "#,
             ),
         ))
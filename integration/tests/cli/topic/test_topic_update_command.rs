@@ -185,10 +185,10 @@ impl IggyCmdTestCase for TestTopicUpdateCmd {
 
         let expected_message = format!("Executing update topic with ID: {topic_id}, name: {new_topic_name}, \
                                 message expiry: {message_expiry}, compression algorithm: {compression_algorithm}, max topic size: {new_max_topic_size}, \
-                                replication factor: {replication_factor}, in stream with ID: {stream_id}\n\
+                                replication factor: {replication_factor}, max segments: unchanged, in stream with ID: {stream_id}\n\
                                 Topic with ID: {topic_id} updated name: {new_topic_name}, updated message expiry: {message_expiry}, \
                                 updated compression algorithm: {compression_algorithm}, updated max topic size: {new_max_topic_size}, \
-                                updated replication factor: {replication_factor} in stream with ID: {stream_id}\n");
+                                updated replication factor: {replication_factor}, updated max segments: unchanged in stream with ID: {stream_id}\n");
 
         command_state.success().stdout(diff(expected_message));
     }
@@ -426,6 +426,9 @@ Options:
 {CLAP_INDENT}
           [default: 1]
 
+  -s, --max-segments <MAX_SEGMENTS>
+          New max number of segments per partition, 0 for unlimited, skipping parameter keeps the current value
+
   -h, --help
           Print help (see a summary with '-h')
 "#,
@@ -459,6 +462,8 @@ Options:
           New max topic size in human-readable format like "unlimited" or "15GB" [default: server_default]
   -r, --replication-factor <REPLICATION_FACTOR>
           New replication factor for the topic [default: 1]
+  -s, --max-segments <MAX_SEGMENTS>
+          New max number of segments per partition, 0 for unlimited, skipping parameter keeps the current value
   -h, --help
           Print help (see more with '--help')
 "#,
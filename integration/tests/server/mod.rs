@@ -20,3 +20,5 @@ mod http_server;
 mod quic_server;
 mod scenarios;
 mod tcp_server;
+#[cfg(unix)]
+mod uds_server;
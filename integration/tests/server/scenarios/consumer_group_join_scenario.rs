@@ -26,7 +26,7 @@ use iggy::clients::client::IggyClient;
 use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::identifier::Identifier;
 use iggy::models::client_info::ClientInfoDetails;
-use iggy::models::consumer_group::ConsumerGroupDetails;
+use iggy::models::consumer_group::{ConsumerGroupDetails, RebalanceTrigger};
 use iggy::utils::expiry::IggyExpiry;
 use iggy::utils::topic_size::MaxTopicSize;
 use integration::test_server::{
@@ -132,6 +132,14 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert_ne!(member1.partitions[0], member3.partitions[0]);
     assert_ne!(member2.partitions[0], member3.partitions[0]);
 
+    // 13. Validate that each join has been recorded as the consumer group rebalance
+    assert_eq!(consumer_group.rebalances.len(), 3);
+    let rebalance = consumer_group.rebalances.last().unwrap();
+    assert_eq!(rebalance.trigger, RebalanceTrigger::MemberJoined);
+    assert_eq!(rebalance.members_before.len(), 2);
+    assert_eq!(rebalance.members_after.len(), 3);
+    assert!(rebalance.partitions_moved > 0);
+
     cleanup(&system_client, true).await;
     assert_clean_system(&system_client).await;
 }
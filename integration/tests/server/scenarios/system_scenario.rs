@@ -36,6 +36,7 @@ use iggy::identifier::Identifier;
 use iggy::messages::poll_messages::PollingStrategy;
 use iggy::messages::send_messages::{Message, Partitioning};
 use iggy::models::messages::PolledMessage;
+use iggy::topics::update_topic::UpdateTopicOptions;
 use iggy::users::defaults::{DEFAULT_ROOT_PASSWORD, DEFAULT_ROOT_USERNAME};
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::expiry::IggyExpiry;
@@ -263,6 +264,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         .await
         .unwrap();
     assert_eq!(polled_messages.messages.len() as u32, MESSAGES_COUNT);
+    assert_eq!(polled_messages.remaining_messages, 0);
     for i in 0..MESSAGES_COUNT {
         let offset = i as u64;
         let message = polled_messages.messages.get(i as usize).unwrap();
@@ -287,6 +289,10 @@ pub async fn run(client_factory: &dyn ClientFactory) {
             .await
             .unwrap();
         assert_eq!(polled_messages.messages.len() as u32, batch_size);
+        assert_eq!(
+            polled_messages.remaining_messages,
+            (MESSAGES_COUNT - (i + 1) * batch_size) as u64
+        );
         for i in 0..batch_size as u64 {
             let offset = start_offset + i;
             let message = polled_messages.messages.get(i as usize).unwrap();
@@ -307,7 +313,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert_eq!(topic.name, TOPIC_NAME);
     assert_eq!(topic.partitions_count, PARTITIONS_COUNT);
     assert_eq!(topic.partitions.len(), PARTITIONS_COUNT as usize);
-    assert_eq!(topic.size, 55914);
+    assert_eq!(topic.size, 55918);
     assert_eq!(topic.messages_count, MESSAGES_COUNT as u64);
     let topic_partition = topic.partitions.get((PARTITION_ID - 1) as usize).unwrap();
     assert_eq!(topic_partition.id, PARTITION_ID);
@@ -614,9 +620,10 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     let message_expiry_duration = updated_message_expiry.into();
     let updated_max_topic_size = MaxTopicSize::Custom(IggyByteSize::from_str("2 GB").unwrap());
     let updated_replication_factor = 5;
+    let updated_max_segments = 10;
 
     client
-        .update_topic(
+        .update_topic_with_options(
             &Identifier::numeric(STREAM_ID).unwrap(),
             &Identifier::numeric(TOPIC_ID).unwrap(),
             &updated_topic_name,
@@ -624,6 +631,9 @@ pub async fn run(client_factory: &dyn ClientFactory) {
             Some(updated_replication_factor),
             IggyExpiry::ExpireDuration(message_expiry_duration),
             updated_max_topic_size,
+            &UpdateTopicOptions {
+                max_segments: Some(updated_max_segments),
+            },
         )
         .await
         .unwrap();
@@ -648,6 +658,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     );
     assert_eq!(updated_topic.max_topic_size, updated_max_topic_size);
     assert_eq!(updated_topic.replication_factor, updated_replication_factor);
+    assert_eq!(updated_topic.max_segments, updated_max_segments);
 
     // 39. Purge the existing topic and ensure it has no messages
     client
@@ -0,0 +1,98 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::server::scenarios::{system_scenario, user_scenario};
+use iggy::client::{StreamClient, SystemClient, UserClient};
+use iggy::clients::client::IggyClient;
+use iggy::error::IggyError;
+use iggy::users::defaults::{DEFAULT_ROOT_PASSWORD, DEFAULT_ROOT_USERNAME, DEFAULT_ROOT_USER_ID};
+use integration::test_server::{ClientFactory, IpAddrKind, TestServer, SYSTEM_PATH_ENV_VAR};
+use integration::uds_client::UdsClientFactory;
+use serial_test::parallel;
+use std::collections::HashMap;
+
+fn start_uds_server(trusted_username: &str) -> TestServer {
+    let local_data_path = TestServer::get_random_path();
+    let envs = HashMap::from([
+        (SYSTEM_PATH_ENV_VAR.to_owned(), local_data_path.clone()),
+        ("IGGY_UDS_ENABLED".to_owned(), "true".to_owned()),
+        (
+            "IGGY_UDS_PATH".to_owned(),
+            format!("{local_data_path}/iggy.sock"),
+        ),
+        (
+            "IGGY_UDS_TRUSTED_USERNAME".to_owned(),
+            trusted_username.to_owned(),
+        ),
+    ]);
+    let mut test_server = TestServer::new(Some(envs), true, None, IpAddrKind::V4);
+    test_server.start();
+    test_server
+}
+
+fn uds_client_factory(test_server: &TestServer) -> UdsClientFactory {
+    UdsClientFactory {
+        socket_path: test_server
+            .get_uds_path()
+            .expect("UDS should be enabled for the test server"),
+    }
+}
+
+#[tokio::test]
+#[parallel]
+async fn system_scenario_should_be_valid() {
+    let test_server = start_uds_server("");
+    system_scenario::run(&uds_client_factory(&test_server)).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn user_scenario_should_be_valid() {
+    let test_server = start_uds_server("");
+    user_scenario::run(&uds_client_factory(&test_server)).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn trusted_user_should_be_logged_in_automatically() {
+    let test_server = start_uds_server(DEFAULT_ROOT_USERNAME);
+    let client = uds_client_factory(&test_server).create_client().await;
+    let client = IggyClient::create(client, None, None);
+
+    let me = client.get_me().await.unwrap();
+    assert_eq!(me.user_id, Some(DEFAULT_ROOT_USER_ID));
+    assert_eq!(me.transport, "UDS");
+    assert!(client.get_streams().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+#[parallel]
+async fn connection_without_trusted_user_should_require_login() {
+    let test_server = start_uds_server("");
+    let client = uds_client_factory(&test_server).create_client().await;
+    let client = IggyClient::create(client, None, None);
+
+    let error = client.get_streams().await.unwrap_err();
+    assert_eq!(error.as_code(), IggyError::Unauthenticated.as_code());
+
+    client
+        .login_user(DEFAULT_ROOT_USERNAME, DEFAULT_ROOT_PASSWORD)
+        .await
+        .unwrap();
+    assert!(client.get_streams().await.unwrap().is_empty());
+}
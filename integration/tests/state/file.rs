@@ -49,6 +49,7 @@ async fn should_apply_single_entry() {
             password: "secret".to_string(),
             status: Default::default(),
             permissions: None,
+            namespace_id: None,
         },
     });
     let command_bytes = command.to_bytes();
@@ -75,6 +76,7 @@ async fn should_apply_encrypted_entry() {
             password: "secret".to_string(),
             status: Default::default(),
             permissions: None,
+            namespace_id: None,
         },
     });
     let command_bytes = command.to_bytes();
@@ -106,6 +108,7 @@ async fn should_apply_multiple_entries() {
             password: "secret".to_string(),
             status: Default::default(),
             permissions: None,
+            namespace_id: None,
         },
     });
     let create_user_bytes = create_user.to_bytes();
@@ -122,6 +125,8 @@ async fn should_apply_multiple_entries() {
         command: CreateStream {
             stream_id: Some(stream_id),
             name: "test".to_string(),
+            metadata: Default::default(),
+            namespace_id: None,
         },
     });
     let create_stream_bytes = create_stream.to_bytes();
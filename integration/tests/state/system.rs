@@ -18,6 +18,7 @@
 
 use crate::state::StateSetup;
 use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
+use iggy::models::personal_access_token::PersonalAccessTokenScope;
 use iggy::partitions::create_partitions::CreatePartitions;
 use iggy::personal_access_tokens::create_personal_access_token::CreatePersonalAccessToken;
 use iggy::streams::create_stream::CreateStream;
@@ -45,23 +46,29 @@ async fn should_be_initialized_based_on_state_entries() {
         password: "secret".to_string(),
         status: Default::default(),
         permissions: None,
+        namespace_id: None,
     };
     let create_user_clone = CreateUser {
         username: "user".to_string(),
         password: "secret".to_string(),
         status: Default::default(),
         permissions: None,
+        namespace_id: None,
     };
 
     let stream1_id = 1;
     let create_stream1 = CreateStream {
         stream_id: Some(stream1_id),
         name: "stream1".to_string(),
+        metadata: Default::default(),
+        namespace_id: None,
     };
 
     let create_stream1_clone = CreateStream {
         stream_id: Some(stream1_id),
         name: "stream1".to_string(),
+        metadata: Default::default(),
+        namespace_id: None,
     };
 
     let topic1_id = 1;
@@ -74,6 +81,9 @@ async fn should_be_initialized_based_on_state_entries() {
         max_topic_size: Default::default(),
         name: "topic1".to_string(),
         replication_factor: None,
+        metadata: Default::default(),
+        message_id_scheme: Default::default(),
+        cleanup_policy: Default::default(),
     };
 
     let create_topic1_clone = CreateTopic {
@@ -85,12 +95,17 @@ async fn should_be_initialized_based_on_state_entries() {
         max_topic_size: Default::default(),
         name: "topic1".to_string(),
         replication_factor: None,
+        metadata: Default::default(),
+        message_id_scheme: Default::default(),
+        cleanup_policy: Default::default(),
     };
 
     let stream2_id = 2;
     let create_stream2 = CreateStream {
         stream_id: Some(stream2_id),
         name: "stream2".to_string(),
+        metadata: Default::default(),
+        namespace_id: None,
     };
 
     let topic2_id = 2;
@@ -103,6 +118,9 @@ async fn should_be_initialized_based_on_state_entries() {
         max_topic_size: Default::default(),
         name: "topic2".to_string(),
         replication_factor: None,
+        metadata: Default::default(),
+        message_id_scheme: Default::default(),
+        cleanup_policy: Default::default(),
     };
 
     let create_partitions = CreatePartitions {
@@ -119,6 +137,7 @@ async fn should_be_initialized_based_on_state_entries() {
         command: CreatePersonalAccessToken {
             name: "test".to_string(),
             expiry: IggyExpiry::NeverExpire,
+            scope: PersonalAccessTokenScope::default(),
         },
         hash: "hash".to_string(),
     };
@@ -127,6 +146,7 @@ async fn should_be_initialized_based_on_state_entries() {
         command: CreatePersonalAccessToken {
             name: "test".to_string(),
             expiry: IggyExpiry::NeverExpire,
+            scope: PersonalAccessTokenScope::default(),
         },
         hash: "hash".to_string(),
     };
@@ -137,6 +157,7 @@ async fn should_be_initialized_based_on_state_entries() {
         topic_id: topic1_id.try_into().unwrap(),
         group_id: Some(group_id),
         name: "test".to_string(),
+        offset_recovery: Default::default(),
     };
 
     let create_consumer_group_clone = CreateConsumerGroup {
@@ -144,6 +165,7 @@ async fn should_be_initialized_based_on_state_entries() {
         topic_id: topic1_id.try_into().unwrap(),
         group_id: Some(group_id),
         name: "test".to_string(),
+        offset_recovery: Default::default(),
     };
 
     state
@@ -20,21 +20,21 @@ use crate::streaming::common::test_setup::TestSetup;
 use iggy::consumer::ConsumerKind;
 use server::configs::system::SystemConfig;
 use server::streaming::partitions::partition::ConsumerOffset;
-use server::streaming::storage::PartitionStorageKind;
+use server::streaming::storage::ConsumerOffsetStorageKind;
 use std::sync::Arc;
 use tokio::fs;
 
 #[tokio::test]
 async fn should_persist_consumer_offsets_and_then_load_them_from_disk() {
     let setup = TestSetup::init().await;
-    let storage = setup.storage.partition.as_ref();
+    let storage = setup.storage.consumer_offsets.as_ref();
     assert_persisted_offsets(&setup.config, storage, ConsumerKind::Consumer).await;
     assert_persisted_offsets(&setup.config, storage, ConsumerKind::ConsumerGroup).await;
 }
 
 async fn assert_persisted_offsets(
     config: &Arc<SystemConfig>,
-    storage: &PartitionStorageKind,
+    storage: &ConsumerOffsetStorageKind,
     kind: ConsumerKind,
 ) {
     let consumer_ids_count = 3;
@@ -56,12 +56,12 @@ async fn assert_persisted_offsets(
 
 async fn assert_persisted_offset(
     path: &str,
-    storage: &PartitionStorageKind,
+    storage: &ConsumerOffsetStorageKind,
     consumer_offset: &ConsumerOffset,
     expected_offsets_count: u32,
 ) {
     storage
-        .save_consumer_offset(consumer_offset.offset, &consumer_offset.path)
+        .save_consumer_offset(path, consumer_offset.consumer_id, consumer_offset.offset)
         .await
         .unwrap();
     let consumer_offsets = storage
@@ -19,6 +19,7 @@
 use crate::streaming::common::test_setup::TestSetup;
 use bytes::BytesMut;
 use iggy::bytes_serializable::BytesSerializable;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::messages::send_messages::Message;
 use iggy::models::header::{HeaderKey, HeaderValue};
 use iggy::utils::byte_size::IggyByteSize;
@@ -81,7 +82,7 @@ async fn test_get_messages_by_timestamp(
     index_cache_enabled: bool,
 ) {
     println!(
-        "Running test with msg_cache_enabled: {}, messages_required_to_save: {}, segment_size: {}, message_size: {}, cache_indexes: {}",
+        "Running test with msg_cache_enabled: {}, messages_required_to_save: {}, segment_size: {}, message_size: {}, index_cache_enabled: {}",
         msg_cache_size.is_some(),
         messages_required_to_save,
         segment_size,
@@ -106,6 +107,7 @@ async fn test_get_messages_by_timestamp(
         cache: CacheConfig {
             enabled: msg_cache_enabled,
             size: msg_cache_size,
+            ..Default::default()
         },
         partition: PartitionConfig {
             messages_required_to_save,
@@ -113,7 +115,7 @@ async fn test_get_messages_by_timestamp(
             ..Default::default()
         },
         segment: SegmentConfig {
-            cache_indexes: index_cache_enabled,
+            index_cache_size: IggyByteSize::from(if index_cache_enabled { 1_000_000 } else { 0 }),
             size: segment_size,
             ..Default::default()
         },
@@ -127,6 +129,7 @@ async fn test_get_messages_by_timestamp(
         config.clone(),
         setup.storage.clone(),
         IggyExpiry::NeverExpire,
+        CompressionAlgorithm::None,
         Arc::new(AtomicU64::new(0)),
         Arc::new(AtomicU64::new(0)),
         Arc::new(AtomicU64::new(0)),
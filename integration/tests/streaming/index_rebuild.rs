@@ -0,0 +1,328 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::common::test_setup::TestSetup;
+use bytes::Bytes;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::identifier::Identifier;
+use iggy::messages::message_id_scheme::MessageIdScheme;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::models::metadata::ResourceMetadata;
+use iggy::streams::create_stream::CreateStream;
+use iggy::topics::cleanup_policy::CleanupPolicy;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::sizeable::Sizeable;
+use iggy::utils::timestamp::IggyTimestamp;
+use iggy::utils::topic_size::MaxTopicSize;
+use server::configs::server::{DataMaintenanceConfig, PersonalAccessTokenConfig};
+use server::configs::system::{CacheConfig, PartitionConfig, SegmentConfig, SystemConfig};
+use server::state::command::EntryCommand;
+use server::state::file::FileState;
+use server::state::models::{CreateStreamWithId, CreateTopicWithId};
+use server::state::system::PartitionState;
+use server::state::State;
+use server::streaming::batching::appendable_batch_info::AppendableBatchInfo;
+use server::streaming::partitions::partition::Partition;
+use server::streaming::persistence::persister::{FileWithSyncPersister, PersisterKind};
+use server::streaming::session::Session;
+use server::streaming::systems::health::HealthWarning;
+use server::streaming::systems::system::System;
+use server::versioning::SemanticVersion;
+use std::net::{Ipv4Addr, SocketAddr};
+use std::sync::atomic::{AtomicU32, AtomicU64};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::fs;
+use tokio::time::{sleep, timeout};
+
+const BATCHES_COUNT: u32 = 4;
+const MESSAGES_PER_BATCH: u32 = 5;
+
+#[tokio::test]
+async fn should_poll_messages_inside_segment_with_pending_index_rebuild() {
+    let setup = TestSetup::init_with_config(create_config()).await;
+    let partition = create_partition_with_segments(&setup).await;
+    let index_path = partition.get_segments()[0].index_path.clone();
+    fs::remove_file(&index_path).await.unwrap();
+
+    let loaded_partition = load_partition(&setup, &partition).await;
+
+    let segment = &loaded_partition.get_segments()[0];
+    assert!(segment.index_rebuild_pending);
+    assert_eq!(
+        segment.current_offset,
+        partition.get_segments()[0].current_offset
+    );
+    let messages = loaded_partition.get_messages_by_offset(2, 2).await.unwrap();
+    let offsets = messages
+        .iter()
+        .map(|message| message.offset)
+        .collect::<Vec<_>>();
+    assert_eq!(offsets, vec![2, 3]);
+    let messages_count = BATCHES_COUNT * MESSAGES_PER_BATCH;
+    let messages = loaded_partition
+        .get_messages_by_offset(0, messages_count)
+        .await
+        .unwrap();
+    let offsets = messages
+        .iter()
+        .map(|message| message.offset)
+        .collect::<Vec<_>>();
+    assert_eq!(offsets, (0..messages_count as u64).collect::<Vec<_>>());
+}
+
+#[tokio::test]
+async fn should_remove_leftover_of_interrupted_index_rebuild_when_loading_partition() {
+    let setup = TestSetup::init_with_config(create_config()).await;
+    let partition = create_partition_with_segments(&setup).await;
+    let index_path = partition.get_segments()[0].index_path.clone();
+    let rebuilt_index_path = format!("{index_path}.rebuilding");
+    fs::remove_file(&index_path).await.unwrap();
+    fs::write(&rebuilt_index_path, [1, 2, 3]).await.unwrap();
+
+    let loaded_partition = load_partition(&setup, &partition).await;
+
+    assert!(loaded_partition.get_segments()[0].index_rebuild_pending);
+    assert!(fs::metadata(&rebuilt_index_path).await.is_err());
+}
+
+#[tokio::test]
+async fn should_report_failed_index_rebuild_in_health() {
+    let setup = TestSetup::init_with_config(create_config()).await;
+    let session = Session::new(1, 1, SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234));
+    let stream_id = Identifier::numeric(1).unwrap();
+    let topic_id = Identifier::numeric(1).unwrap();
+    let mut system = create_system(&setup);
+    system.init().await.unwrap();
+    system
+        .create_stream(&session, Some(1), "test", Default::default(), None)
+        .await
+        .unwrap();
+    system
+        .create_topic(
+            &session,
+            &stream_id,
+            Some(1),
+            "test",
+            1,
+            IggyExpiry::NeverExpire,
+            CompressionAlgorithm::default(),
+            MaxTopicSize::ServerDefault,
+            None,
+            ResourceMetadata::default(),
+            MessageIdScheme::default(),
+            CleanupPolicy::default(),
+        )
+        .await
+        .unwrap();
+    for batch in 0..BATCHES_COUNT {
+        system
+            .append_messages(
+                &session,
+                stream_id.clone(),
+                topic_id.clone(),
+                Partitioning::partition_id(1),
+                create_messages(batch),
+                None,
+            )
+            .await
+            .unwrap();
+    }
+    system.shutdown().await.unwrap();
+    // The system doesn't record the created resources in the state on its own, the command handlers do.
+    apply_stream_and_topic_to_state(&setup).await;
+
+    // The rebuild fails, as the index cannot be written into the directory in place of the file.
+    let index_path = format!("{}.index", setup.config.get_segment_path(1, 1, 1, 0));
+    fs::remove_file(&index_path).await.unwrap();
+    fs::create_dir(format!("{index_path}.rebuilding"))
+        .await
+        .unwrap();
+    let mut system = create_system(&setup);
+    system.init().await.unwrap();
+
+    let health = timeout(Duration::from_secs(10), async {
+        loop {
+            let health = system.get_health(&session).unwrap();
+            if health.pending_index_rebuilds == 0 {
+                return health;
+            }
+            sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .unwrap();
+
+    assert!(!health.healthy);
+    assert!(matches!(
+        &health.warnings[..],
+        [HealthWarning::IndexRebuildFailed(failure)]
+            if failure.stream_id == 1
+                && failure.topic_id == 1
+                && failure.partition_id == 1
+                && failure.start_offset == 0
+    ));
+}
+
+fn create_config() -> SystemConfig {
+    SystemConfig {
+        cache: CacheConfig {
+            enabled: false,
+            ..Default::default()
+        },
+        partition: PartitionConfig {
+            messages_required_to_save: 1,
+            enforce_fsync: true,
+            ..Default::default()
+        },
+        segment: SegmentConfig {
+            index_cache_size: IggyByteSize::from(1_000_000),
+            size: IggyByteSize::from(500),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+fn create_system(setup: &TestSetup) -> System {
+    System::new(
+        setup.config.clone(),
+        DataMaintenanceConfig::default(),
+        PersonalAccessTokenConfig::default(),
+    )
+}
+
+async fn apply_stream_and_topic_to_state(setup: &TestSetup) {
+    let state = FileState::new(
+        &setup.config.get_state_log_path(),
+        &SemanticVersion::current().unwrap(),
+        Arc::new(PersisterKind::FileWithSync(FileWithSyncPersister)),
+        None,
+    );
+    state.init().await.unwrap();
+    state
+        .apply(
+            1,
+            EntryCommand::CreateStream(CreateStreamWithId {
+                stream_id: 1,
+                command: CreateStream {
+                    stream_id: Some(1),
+                    name: "test".to_string(),
+                    metadata: Default::default(),
+                    namespace_id: None,
+                },
+            }),
+        )
+        .await
+        .unwrap();
+    state
+        .apply(
+            1,
+            EntryCommand::CreateTopic(CreateTopicWithId {
+                topic_id: 1,
+                command: CreateTopic {
+                    stream_id: Identifier::numeric(1).unwrap(),
+                    topic_id: Some(1),
+                    partitions_count: 1,
+                    compression_algorithm: Default::default(),
+                    message_expiry: IggyExpiry::NeverExpire,
+                    max_topic_size: MaxTopicSize::ServerDefault,
+                    name: "test".to_string(),
+                    replication_factor: None,
+                    metadata: Default::default(),
+                    message_id_scheme: Default::default(),
+                    cleanup_policy: Default::default(),
+                },
+            }),
+        )
+        .await
+        .unwrap();
+}
+
+fn create_messages(batch: u32) -> Vec<Message> {
+    (0..MESSAGES_PER_BATCH)
+        .map(|index| {
+            let payload = Bytes::from(vec![b'a'; 100]);
+            Message {
+                id: (batch * MESSAGES_PER_BATCH + index + 1) as u128,
+                length: payload.len() as u32,
+                payload,
+                headers: None,
+            }
+        })
+        .collect()
+}
+
+/// Creates the partition with the messages appended in the batches exceeding the segment size,
+/// so that each batch is stored in the separate segment.
+async fn create_partition_with_segments(setup: &TestSetup) -> Partition {
+    setup.create_partitions_directory(1, 1).await;
+    let mut partition = create_partition(setup, true, IggyTimestamp::now()).await;
+    partition.persist().await.unwrap();
+    for batch in 0..BATCHES_COUNT {
+        let messages = create_messages(batch);
+        let appendable_batch_info = AppendableBatchInfo::new(
+            messages
+                .iter()
+                .map(|message| message.get_size_bytes())
+                .sum::<IggyByteSize>(),
+            partition.partition_id,
+        );
+        partition
+            .append_messages(appendable_batch_info, messages, None)
+            .await
+            .unwrap();
+    }
+    assert!(partition.get_segments().len() > 1);
+    partition
+}
+
+async fn load_partition(setup: &TestSetup, partition: &Partition) -> Partition {
+    let now = IggyTimestamp::now();
+    let mut loaded_partition = create_partition(setup, false, now).await;
+    loaded_partition
+        .load(PartitionState {
+            id: partition.partition_id,
+            created_at: now,
+        })
+        .await
+        .unwrap();
+    loaded_partition
+}
+
+async fn create_partition(setup: &TestSetup, with_segment: bool, now: IggyTimestamp) -> Partition {
+    Partition::create(
+        1,
+        1,
+        1,
+        with_segment,
+        setup.config.clone(),
+        setup.storage.clone(),
+        IggyExpiry::NeverExpire,
+        CompressionAlgorithm::None,
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU32::new(0)),
+        now,
+    )
+    .await
+}
@@ -23,6 +23,7 @@ mod common;
 mod consumer_offset;
 mod get_by_offset;
 mod get_by_timestamp;
+mod index_rebuild;
 mod messages;
 mod partition;
 mod segment;
@@ -18,6 +18,7 @@
 
 use crate::streaming::common::test_setup::TestSetup;
 use crate::streaming::create_messages;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::expiry::IggyExpiry;
 use iggy::utils::sizeable::Sizeable;
@@ -25,6 +26,7 @@ use iggy::utils::timestamp::IggyTimestamp;
 use server::state::system::PartitionState;
 use server::streaming::batching::appendable_batch_info::AppendableBatchInfo;
 use server::streaming::partitions::partition::Partition;
+use server::streaming::polling_consumer::PollingConsumer;
 use server::streaming::segments::*;
 use std::sync::atomic::{AtomicU32, AtomicU64};
 use std::sync::Arc;
@@ -47,6 +49,7 @@ async fn should_persist_partition_with_segment() {
             setup.config.clone(),
             setup.storage.clone(),
             IggyExpiry::NeverExpire,
+            CompressionAlgorithm::None,
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
@@ -79,6 +82,7 @@ async fn should_load_existing_partition_from_disk() {
             setup.config.clone(),
             setup.storage.clone(),
             IggyExpiry::NeverExpire,
+            CompressionAlgorithm::None,
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
@@ -99,6 +103,7 @@ async fn should_load_existing_partition_from_disk() {
             setup.config.clone(),
             setup.storage.clone(),
             IggyExpiry::NeverExpire,
+            CompressionAlgorithm::None,
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
@@ -154,6 +159,7 @@ async fn should_delete_existing_partition_from_disk() {
             setup.config.clone(),
             setup.storage.clone(),
             IggyExpiry::NeverExpire,
+            CompressionAlgorithm::None,
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
@@ -188,6 +194,7 @@ async fn should_purge_existing_partition_on_disk() {
             setup.config.clone(),
             setup.storage.clone(),
             IggyExpiry::NeverExpire,
+            CompressionAlgorithm::None,
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
@@ -213,7 +220,7 @@ async fn should_purge_existing_partition_on_disk() {
             .unwrap();
         let loaded_messages = partition.get_messages_by_offset(0, 100).await.unwrap();
         assert_eq!(loaded_messages.len(), messages_count);
-        partition.purge().await.unwrap();
+        partition.purge(false, None).await.unwrap();
         assert_eq!(partition.current_offset, 0);
         assert_eq!(partition.unsaved_messages_count, 0);
         assert!(!partition.should_increment_offset);
@@ -222,6 +229,65 @@ async fn should_purge_existing_partition_on_disk() {
     }
 }
 
+#[tokio::test]
+async fn should_purge_existing_partition_on_disk_keeping_consumer_offsets() {
+    let setup = TestSetup::init().await;
+    let with_segment = true;
+    let stream_id = 1;
+    let topic_id = 2;
+    setup.create_partitions_directory(stream_id, topic_id).await;
+    let partition_ids = get_partition_ids();
+    for partition_id in partition_ids {
+        let mut partition = Partition::create(
+            stream_id,
+            topic_id,
+            partition_id,
+            with_segment,
+            setup.config.clone(),
+            setup.storage.clone(),
+            IggyExpiry::NeverExpire,
+            CompressionAlgorithm::None,
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU32::new(0)),
+            IggyTimestamp::now(),
+        )
+        .await;
+        partition.persist().await.unwrap();
+        let messages = create_messages();
+        let messages_count = messages.len() as u64;
+        let appendable_batch_info = AppendableBatchInfo::new(
+            messages
+                .iter()
+                .map(|msg| msg.get_size_bytes())
+                .sum::<IggyByteSize>(),
+            partition.partition_id,
+        );
+        partition
+            .append_messages(appendable_batch_info, messages, None)
+            .await
+            .unwrap();
+        let consumer = PollingConsumer::Consumer(1, partition_id);
+        partition
+            .store_consumer_offset(consumer, messages_count - 1)
+            .await
+            .unwrap();
+
+        partition.purge(true, None).await.unwrap();
+
+        assert_eq!(partition.current_offset, messages_count - 1);
+        assert!(partition.should_increment_offset);
+        assert_eq!(partition.get_segments().len(), 1);
+        assert_eq!(partition.get_segments()[0].start_offset, messages_count);
+        let loaded_messages = partition.get_messages_by_offset(0, 100).await.unwrap();
+        assert!(loaded_messages.is_empty());
+        let consumer_offset = partition.get_consumer_offset(consumer).await.unwrap();
+        assert_eq!(consumer_offset, Some(messages_count - 1));
+    }
+}
+
 async fn assert_persisted_partition(partition_path: &str, with_segment: bool) {
     assert!(fs::metadata(&partition_path).await.is_ok());
 
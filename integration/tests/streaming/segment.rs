@@ -19,6 +19,7 @@
 use crate::streaming::common::test_setup::TestSetup;
 use bytes::Bytes;
 use iggy::bytes_serializable::BytesSerializable;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::confirmation::Confirmation;
 use iggy::models::messages::{MessageState, PolledMessage};
 use iggy::utils::byte_size::IggyByteSize;
@@ -49,6 +50,7 @@ async fn should_persist_segment() {
             start_offset,
             setup.config.clone(),
             IggyExpiry::NeverExpire,
+            CompressionAlgorithm::None,
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
@@ -86,6 +88,7 @@ async fn should_load_existing_segment_from_disk() {
             start_offset,
             setup.config.clone(),
             IggyExpiry::NeverExpire,
+            CompressionAlgorithm::None,
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
@@ -112,6 +115,7 @@ async fn should_load_existing_segment_from_disk() {
             start_offset,
             setup.config.clone(),
             IggyExpiry::NeverExpire,
+            CompressionAlgorithm::None,
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
@@ -148,6 +152,7 @@ async fn should_persist_and_load_segment_with_messages() {
         start_offset,
         setup.config.clone(),
         IggyExpiry::NeverExpire,
+        CompressionAlgorithm::None,
         Arc::new(AtomicU64::new(0)),
         Arc::new(AtomicU64::new(0)),
         Arc::new(AtomicU64::new(0)),
@@ -198,6 +203,7 @@ async fn should_persist_and_load_segment_with_messages() {
         start_offset,
         setup.config.clone(),
         IggyExpiry::NeverExpire,
+        CompressionAlgorithm::None,
         Arc::new(AtomicU64::new(0)),
         Arc::new(AtomicU64::new(0)),
         Arc::new(AtomicU64::new(0)),
@@ -234,6 +240,7 @@ async fn should_persist_and_load_segment_with_messages_with_nowait_confirmation(
         start_offset,
         setup.config.clone(),
         IggyExpiry::NeverExpire,
+        CompressionAlgorithm::None,
         Arc::new(AtomicU64::new(0)),
         Arc::new(AtomicU64::new(0)),
         Arc::new(AtomicU64::new(0)),
@@ -288,6 +295,7 @@ async fn should_persist_and_load_segment_with_messages_with_nowait_confirmation(
         start_offset,
         setup.config.clone(),
         IggyExpiry::NeverExpire,
+        CompressionAlgorithm::None,
         Arc::new(AtomicU64::new(0)),
         Arc::new(AtomicU64::new(0)),
         Arc::new(AtomicU64::new(0)),
@@ -319,6 +327,7 @@ async fn given_all_expired_messages_segment_should_be_expired() {
         start_offset,
         setup.config.clone(),
         message_expiry,
+        CompressionAlgorithm::None,
         Arc::new(AtomicU64::new(0)),
         Arc::new(AtomicU64::new(0)),
         Arc::new(AtomicU64::new(0)),
@@ -386,6 +395,7 @@ async fn given_at_least_one_not_expired_message_segment_should_not_be_expired()
         start_offset,
         setup.config.clone(),
         message_expiry,
+        CompressionAlgorithm::None,
         Arc::new(AtomicU64::new(0)),
         Arc::new(AtomicU64::new(0)),
         Arc::new(AtomicU64::new(0)),
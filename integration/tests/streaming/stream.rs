@@ -20,7 +20,7 @@ use crate::streaming::common::test_setup::TestSetup;
 use crate::streaming::create_messages;
 use ahash::AHashMap;
 use iggy::identifier::Identifier;
-use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::poll_messages::{IsolationLevel, PollingStrategy};
 use iggy::messages::send_messages::Partitioning;
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::expiry::IggyExpiry;
@@ -78,6 +78,9 @@ async fn should_load_existing_stream_from_disk() {
             id: stream_id,
             name: name.clone(),
             created_at: IggyTimestamp::now(),
+            metadata: Default::default(),
+            quota: Default::default(),
+            namespace_id: None,
             topics: AHashMap::new(),
         };
         loaded_stream.load(state).await.unwrap();
@@ -160,6 +163,8 @@ async fn should_purge_existing_stream_on_disk() {
                 1,
                 PollingStrategy::offset(0),
                 100,
+                IsolationLevel::ReadUncommitted,
+                None,
             )
             .await
             .unwrap();
@@ -173,6 +178,8 @@ async fn should_purge_existing_stream_on_disk() {
                 1,
                 PollingStrategy::offset(0),
                 100,
+                IsolationLevel::ReadUncommitted,
+                None,
             )
             .await
             .unwrap();
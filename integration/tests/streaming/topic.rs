@@ -20,7 +20,7 @@ use crate::streaming::common::test_setup::TestSetup;
 use crate::streaming::create_messages;
 use ahash::AHashMap;
 use iggy::compression::compression_algorithm::CompressionAlgorithm;
-use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::poll_messages::{IsolationLevel, PollingStrategy};
 use iggy::messages::send_messages::Partitioning;
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::expiry::IggyExpiry;
@@ -133,8 +133,16 @@ async fn should_load_existing_topic_from_disk() {
             compression_algorithm: Default::default(),
             message_expiry: IggyExpiry::NeverExpire,
             max_topic_size: MaxTopicSize::ServerDefault,
+            max_segments: 0,
+            config: Default::default(),
             replication_factor: Some(1),
             created_at: Default::default(),
+            metadata: Default::default(),
+            message_id_scheme: Default::default(),
+            cleanup_policy: Default::default(),
+            allowed_producers: Default::default(),
+            expiry_watchers: Default::default(),
+            delete_at: None,
         };
         loaded_topic.load(topic_state).await.unwrap();
 
@@ -240,18 +248,22 @@ async fn should_purge_existing_topic_on_disk() {
                 1,
                 PollingStrategy::offset(0),
                 100,
+                IsolationLevel::ReadUncommitted,
+                None,
             )
             .await
             .unwrap();
         assert_eq!(loaded_messages.messages.len(), messages_count);
 
-        topic.purge().await.unwrap();
+        topic.purge(false, None).await.unwrap();
         let loaded_messages = topic
             .get_messages(
                 PollingConsumer::Consumer(1, 1),
                 1,
                 PollingStrategy::offset(0),
                 100,
+                IsolationLevel::ReadUncommitted,
+                None,
             )
             .await
             .unwrap();
@@ -19,7 +19,7 @@
 use crate::streaming::common::test_setup::TestSetup;
 use bytes::Bytes;
 use iggy::locking::IggySharedMutFn;
-use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::poll_messages::{IsolationLevel, PollingStrategy};
 use iggy::messages::send_messages::{Message, Partitioning};
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::expiry::IggyExpiry;
@@ -53,6 +53,7 @@ async fn given_enabled_cache_with_enough_capacity_all_messages_should_be_polled(
         CacheConfig {
             enabled: true,
             size: MemoryResourceQuota::Bytes(IggyByteSize::from(100_000_000)),
+            ..Default::default()
         },
         true,
     )
@@ -65,6 +66,7 @@ async fn given_enabled_cache_without_enough_capacity_all_messages_should_be_poll
         CacheConfig {
             enabled: true,
             size: MemoryResourceQuota::Bytes(IggyByteSize::from(100_000)),
+            ..Default::default()
         },
         true,
     )
@@ -113,6 +115,8 @@ async fn assert_polling_messages(cache: CacheConfig, expect_enabled_cache: bool)
             partition_id,
             PollingStrategy::offset(0),
             messages_count,
+            IsolationLevel::ReadUncommitted,
+            None,
         )
         .await
         .unwrap();
@@ -236,7 +240,14 @@ fn get_payload(id: u32) -> String {
 async fn assert_messages(topic: &Topic, partition_id: u32, expected_messages: u32) {
     let consumer = PollingConsumer::Consumer(0, partition_id);
     let polled_messages = topic
-        .get_messages(consumer, partition_id, PollingStrategy::offset(0), 1000)
+        .get_messages(
+            consumer,
+            partition_id,
+            PollingStrategy::offset(0),
+            1000,
+            IsolationLevel::ReadUncommitted,
+            None,
+        )
         .await
         .unwrap();
     assert_eq!(polled_messages.messages.len() as u32, expected_messages);
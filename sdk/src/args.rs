@@ -28,7 +28,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Parser, Debug, Clone, Deserialize, Serialize, Default)]
 #[command(author, version, about, long_about = None)]
 pub struct ArgsOptional {
-    /// The transport to use. Valid values are `quic`, `http` and `tcp`
+    /// The transport to use. Valid values are `quic`, `http`, `tcp` and `uds`
     ///
     /// [default: tcp]
     #[arg(long)]
@@ -103,6 +103,13 @@ pub struct ArgsOptional {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tcp_tls_domain: Option<String>,
 
+    /// The optional socket path for the UDS transport
+    ///
+    /// [default: local_data/iggy.sock]
+    #[arg(long)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uds_socket_path: Option<String>,
+
     /// The optional client address for the QUIC transport
     ///
     /// [default: 127.0.0.1:0]
@@ -203,7 +210,7 @@ pub struct ArgsOptional {
 /// The arguments used by the `ClientProviderConfig` to create a client.
 #[derive(Debug, Clone)]
 pub struct Args {
-    /// The transport to use. Valid values are `quic`, `http` and `tcp`
+    /// The transport to use. Valid values are `quic`, `http`, `tcp` and `uds`
     pub transport: String,
 
     /// Optional encryption key for the message payload used by the client
@@ -239,6 +246,9 @@ pub struct Args {
     /// The optional heartbeat interval for the TCP transport
     pub tcp_heartbeat_interval: String,
 
+    /// The optional timeout of the heartbeat responses for the TCP transport
+    pub tcp_heartbeat_timeout: String,
+
     /// Flag to enable TLS for the TCP transport
     pub tcp_tls_enabled: bool,
 
@@ -248,9 +258,45 @@ pub struct Args {
     /// The optional CA file for the TCP transport
     pub tcp_tls_ca_file: Option<String>,
 
+    /// The optional client certificate file for the mutual TLS of the TCP transport
+    pub tcp_tls_cert_file: Option<String>,
+
+    /// The optional private key file of the client certificate for the TCP transport
+    pub tcp_tls_key_file: Option<String>,
+
     /// Disable nodelay for the TCP transport
     pub tcp_nodelay: bool,
 
+    /// Negotiate the CRC32C checksums of the frames for the TCP transport
+    pub tcp_frame_checksums: bool,
+
+    /// The optional time the resolved server endpoints are reused for the TCP transport
+    pub tcp_dns_ttl: String,
+
+    /// The optional interval of the server endpoints health checks for the TCP transport
+    pub tcp_health_check_interval: String,
+
+    /// The optional timeout of the server endpoints health checks for the TCP transport
+    pub tcp_health_check_timeout: String,
+
+    /// The optional socket path for the UDS transport
+    pub uds_socket_path: String,
+
+    /// Flag to enable reconnection for the UDS transport
+    pub uds_reconnection_enabled: bool,
+
+    /// The optional number of maximum reconnect retries for the UDS transport
+    pub uds_reconnection_max_retries: Option<u32>,
+
+    /// The optional reconnect interval for the UDS transport
+    pub uds_reconnection_interval: String,
+
+    /// The optional re-establish after last connection interval for UDS
+    pub uds_reconnection_reestablish_after: String,
+
+    /// The optional heartbeat interval for the UDS transport
+    pub uds_heartbeat_interval: String,
+
     /// The optional client address for the QUIC transport
     pub quic_client_address: String,
 
@@ -306,6 +352,7 @@ pub struct Args {
 const QUIC_TRANSPORT: &str = "quic";
 const HTTP_TRANSPORT: &str = "http";
 const TCP_TRANSPORT: &str = "tcp";
+const UDS_TRANSPORT: &str = "uds";
 
 impl Args {
     pub fn get_server_address(&self) -> Option<String> {
@@ -318,6 +365,7 @@ impl Args {
                     .replace("localhost", "127.0.0.1"),
             ),
             TCP_TRANSPORT => Some(self.tcp_server_address.replace("localhost", "127.0.0.1")),
+            UDS_TRANSPORT => Some(self.uds_socket_path.clone()),
             _ => None,
         }
     }
@@ -338,10 +386,23 @@ impl Default for Args {
             tcp_reconnection_interval: "1s".to_string(),
             tcp_reconnection_reestablish_after: "5s".to_string(),
             tcp_heartbeat_interval: "5s".to_string(),
+            tcp_heartbeat_timeout: "5s".to_string(),
             tcp_tls_enabled: false,
             tcp_tls_domain: "localhost".to_string(),
             tcp_tls_ca_file: None,
+            tcp_tls_cert_file: None,
+            tcp_tls_key_file: None,
             tcp_nodelay: false,
+            tcp_frame_checksums: false,
+            tcp_dns_ttl: "30s".to_string(),
+            tcp_health_check_interval: "0".to_string(),
+            tcp_health_check_timeout: "3s".to_string(),
+            uds_socket_path: "local_data/iggy.sock".to_string(),
+            uds_reconnection_enabled: true,
+            uds_reconnection_max_retries: None,
+            uds_reconnection_interval: "1s".to_string(),
+            uds_reconnection_reestablish_after: "5s".to_string(),
+            uds_heartbeat_interval: "5s".to_string(),
             quic_client_address: "127.0.0.1:0".to_string(),
             quic_server_address: "127.0.0.1:8080".to_string(),
             quic_server_name: "localhost".to_string(),
@@ -401,6 +462,9 @@ impl From<Vec<ArgsOptional>> for Args {
             if let Some(tcp_tls_domain) = optional_args.tcp_tls_domain {
                 args.tcp_tls_domain = tcp_tls_domain;
             }
+            if let Some(uds_socket_path) = optional_args.uds_socket_path {
+                args.uds_socket_path = uds_socket_path;
+            }
             if let Some(quic_client_address) = optional_args.quic_client_address {
                 args.quic_client_address = quic_client_address;
             }
@@ -21,13 +21,21 @@ use crate::binary::{fail_if_not_authenticated, mapper};
 use crate::client::ConsumerGroupClient;
 use crate::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use crate::consumer_groups::delete_consumer_group::DeleteConsumerGroup;
+use crate::consumer_groups::delete_partition_state::DeletePartitionState;
 use crate::consumer_groups::get_consumer_group::GetConsumerGroup;
 use crate::consumer_groups::get_consumer_groups::GetConsumerGroups;
+use crate::consumer_groups::get_partition_state::GetPartitionState;
 use crate::consumer_groups::join_consumer_group::JoinConsumerGroup;
 use crate::consumer_groups::leave_consumer_group::LeaveConsumerGroup;
+use crate::consumer_groups::offset_recovery_policy::OffsetRecoveryPolicy;
+use crate::consumer_groups::store_partition_state::StorePartitionState;
+use crate::consumer_groups::update_consumer_group_dead_letter::UpdateConsumerGroupDeadLetter;
+use crate::consumer_groups::update_consumer_group_visibility_timeout::UpdateConsumerGroupVisibilityTimeout;
 use crate::error::IggyError;
 use crate::identifier::Identifier;
 use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails};
+use crate::utils::duration::IggyDuration;
+use bytes::Bytes;
 
 #[async_trait::async_trait]
 impl<B: BinaryClient> ConsumerGroupClient for B {
@@ -49,7 +57,7 @@ impl<B: BinaryClient> ConsumerGroupClient for B {
             return Ok(None);
         }
 
-        mapper::map_consumer_group(response).map(Some)
+        mapper::map_consumer_group(response, self.get_protocol_features()).map(Some)
     }
 
     async fn get_consumer_groups(
@@ -73,6 +81,24 @@ impl<B: BinaryClient> ConsumerGroupClient for B {
         topic_id: &Identifier,
         name: &str,
         group_id: Option<u32>,
+    ) -> Result<ConsumerGroupDetails, IggyError> {
+        self.create_consumer_group_with_offset_recovery(
+            stream_id,
+            topic_id,
+            name,
+            group_id,
+            OffsetRecoveryPolicy::ServerDefault,
+        )
+        .await
+    }
+
+    async fn create_consumer_group_with_offset_recovery(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        name: &str,
+        group_id: Option<u32>,
+        offset_recovery: OffsetRecoveryPolicy,
     ) -> Result<ConsumerGroupDetails, IggyError> {
         fail_if_not_authenticated(self).await?;
         let response = self
@@ -81,9 +107,50 @@ impl<B: BinaryClient> ConsumerGroupClient for B {
                 topic_id: topic_id.clone(),
                 name: name.to_string(),
                 group_id,
+                offset_recovery,
             })
             .await?;
-        mapper::map_consumer_group(response)
+        mapper::map_consumer_group(response, self.get_protocol_features())
+    }
+
+    async fn update_consumer_group_dead_letter(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+        dead_letter_stream_id: Option<&Identifier>,
+        dead_letter_topic_id: Option<&Identifier>,
+        max_delivery_count: u32,
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&UpdateConsumerGroupDeadLetter {
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            group_id: group_id.clone(),
+            dead_letter_stream_id: dead_letter_stream_id.cloned(),
+            dead_letter_topic_id: dead_letter_topic_id.cloned(),
+            max_delivery_count,
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn update_consumer_group_visibility_timeout(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+        visibility_timeout: IggyDuration,
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&UpdateConsumerGroupVisibilityTimeout {
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            group_id: group_id.clone(),
+            visibility_timeout,
+        })
+        .await?;
+        Ok(())
     }
 
     async fn delete_consumer_group(
@@ -133,4 +200,72 @@ impl<B: BinaryClient> ConsumerGroupClient for B {
         .await?;
         Ok(())
     }
+
+    async fn get_partition_state(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+        partition_id: u32,
+        key: &str,
+    ) -> Result<Option<Bytes>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&GetPartitionState {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                group_id: group_id.clone(),
+                partition_id,
+                key: key.to_string(),
+            })
+            .await?;
+        // The stored values are never empty, so the empty response means that the key does not exist.
+        if response.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(response))
+    }
+
+    async fn store_partition_state(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+        partition_id: u32,
+        key: &str,
+        value: Bytes,
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&StorePartitionState {
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            group_id: group_id.clone(),
+            partition_id,
+            key: key.to_string(),
+            value,
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn delete_partition_state(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+        partition_id: u32,
+        key: &str,
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&DeletePartitionState {
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            group_id: group_id.clone(),
+            partition_id,
+            key: key.to_string(),
+        })
+        .await?;
+        Ok(())
+    }
 }
@@ -22,10 +22,15 @@ use crate::client::ConsumerOffsetClient;
 use crate::consumer::Consumer;
 use crate::consumer_offsets::delete_consumer_offset::DeleteConsumerOffset;
 use crate::consumer_offsets::get_consumer_offset::GetConsumerOffset;
+use crate::consumer_offsets::get_offsets_for_timestamps::{
+    GetOffsetsForTimestamps, PartitionTimestamp,
+};
 use crate::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
+use crate::consumer_offsets::store_consumer_offsets::{PartitionOffset, StoreConsumerOffsets};
 use crate::error::IggyError;
 use crate::identifier::Identifier;
 use crate::models::consumer_offset_info::ConsumerOffsetInfo;
+use crate::models::partition_timestamp_offset::PartitionTimestampOffset;
 
 #[async_trait::async_trait]
 impl<B: BinaryClient> ConsumerOffsetClient for B {
@@ -49,6 +54,24 @@ impl<B: BinaryClient> ConsumerOffsetClient for B {
         Ok(())
     }
 
+    async fn store_consumer_offsets(
+        &self,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        offsets: &[PartitionOffset],
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&StoreConsumerOffsets {
+            consumer: consumer.clone(),
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            offsets: offsets.to_vec(),
+        })
+        .await?;
+        Ok(())
+    }
+
     async fn get_consumer_offset(
         &self,
         consumer: &Consumer,
@@ -89,4 +112,21 @@ impl<B: BinaryClient> ConsumerOffsetClient for B {
         .await?;
         Ok(())
     }
+
+    async fn get_offsets_for_timestamps(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        timestamps: &[PartitionTimestamp],
+    ) -> Result<Vec<PartitionTimestampOffset>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&GetOffsetsForTimestamps {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                timestamps: timestamps.to_vec(),
+            })
+            .await?;
+        mapper::map_partition_timestamp_offsets(response)
+    }
 }
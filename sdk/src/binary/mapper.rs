@@ -18,21 +18,54 @@
 
 use crate::bytes_serializable::BytesSerializable;
 use crate::compression::compression_algorithm::CompressionAlgorithm;
+use crate::consumer_groups::offset_recovery_policy::OffsetRecoveryPolicy;
 use crate::error::IggyError;
+use crate::messages::message_id_scheme::MessageIdScheme;
+use crate::messages::replay_messages::{ReplayRange, ReplayRangeKind};
+use crate::models::audit_entry::AuditEntry;
+use crate::models::backup::Backup;
 use crate::models::client_info::{ClientInfo, ClientInfoDetails, ConsumerGroupInfo};
-use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails, ConsumerGroupMember};
+use crate::models::consumer_group::{
+    ConsumerGroup, ConsumerGroupDeadLetter, ConsumerGroupDetails, ConsumerGroupMember,
+    ConsumerGroupRebalance, PartitionAssignmentStrategy, RebalanceTrigger,
+};
 use crate::models::consumer_offset_info::ConsumerOffsetInfo;
+use crate::models::error_catalog::ErrorCatalogEntry;
 use crate::models::identity_info::IdentityInfo;
-use crate::models::messages::{MessageState, PolledMessage, PolledMessages};
-use crate::models::partition::Partition;
+use crate::models::maintenance_mode::MaintenanceMode;
+use crate::models::message_size_distribution::MessageSizeDistribution;
+use crate::models::messages::{
+    MessageState, MessagesGap, MessagesGapReason, PolledMessage, PolledMessages,
+};
+use crate::models::messages_aggregate::MessagesBucket;
+use crate::models::metadata::ResourceMetadata;
+use crate::models::namespace::{Namespace, NamespaceDetails, NamespaceQuotas};
+use crate::models::partition::{Partition, PartitionRecoveryProgress};
+use crate::models::partition_timestamp_offset::PartitionTimestampOffset;
 use crate::models::permissions::Permissions;
-use crate::models::personal_access_token::{PersonalAccessTokenInfo, RawPersonalAccessToken};
-use crate::models::stats::{CacheMetrics, CacheMetricsKey, Stats};
-use crate::models::stream::{Stream, StreamDetails};
+use crate::models::personal_access_token::{
+    PersonalAccessTokenInfo, PersonalAccessTokenScope, RawPersonalAccessToken,
+};
+use crate::models::producer_epoch::ProducerEpoch;
+use crate::models::producer_session::ProducerSession;
+use crate::models::push_subscription::{PushSubscription, PushSubscriptionStatus};
+use crate::models::replay_job::{ReplayJob, ReplayJobStatus};
+use crate::models::restored_segments::RestoredSegments;
+use crate::models::segment_range::SegmentRange;
+use crate::models::server_info::{ServerInfo, ServerLimits};
+use crate::models::stats::{CacheMetrics, CacheMetricsKey, Stats, TransportUtilization};
+use crate::models::stream::{Stream, StreamDetails, StreamQuota};
 use crate::models::topic::{Topic, TopicDetails};
+use crate::models::transaction::Transaction;
+use crate::models::unsaved_state::PartitionUnsavedState;
 use crate::models::user_info::{UserInfo, UserInfoDetails};
 use crate::models::user_status::UserStatus;
+use crate::system::handshake::Handshake;
+use crate::topics::cleanup_policy::CleanupPolicy;
+use crate::topics::topic_config::TopicConfig;
+use crate::users::user_quotas::UserQuotas;
 use crate::utils::byte_size::IggyByteSize;
+use crate::utils::duration::IggyDuration;
 use crate::utils::expiry::IggyExpiry;
 use crate::utils::topic_size::MaxTopicSize;
 use bytes::Bytes;
@@ -42,10 +75,13 @@ use std::str::from_utf8;
 const EMPTY_MESSAGES: Vec<PolledMessage> = vec![];
 const EMPTY_TOPICS: Vec<Topic> = vec![];
 const EMPTY_STREAMS: Vec<Stream> = vec![];
+const EMPTY_NAMESPACES: Vec<Namespace> = vec![];
 const EMPTY_CLIENTS: Vec<ClientInfo> = vec![];
 const EMPTY_USERS: Vec<UserInfo> = vec![];
 const EMPTY_PERSONAL_ACCESS_TOKENS: Vec<PersonalAccessTokenInfo> = vec![];
 const EMPTY_CONSUMER_GROUPS: Vec<ConsumerGroup> = vec![];
+const EMPTY_REPLAY_JOBS: Vec<ReplayJob> = vec![];
+const EMPTY_PUSH_SUBSCRIPTIONS: Vec<PushSubscription> = vec![];
 
 pub fn map_stats(payload: Bytes) -> Result<Stats, IggyError> {
     let process_id = u32::from_le_bytes(
@@ -352,6 +388,24 @@ pub fn map_stats(payload: Bytes) -> Result<Stats, IggyError> {
         }
     }
 
+    // Read transport utilization (if it exists)
+    let mut transport_utilization = Vec::new();
+    if current_position + 4 <= payload.len() {
+        let transports_count = u32::from_le_bytes(
+            payload[current_position..current_position + 4]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        ) as usize;
+        current_position += 4;
+
+        for _ in 0..transports_count {
+            let (utilization, read_bytes) =
+                map_to_transport_utilization(payload.clone(), current_position)?;
+            current_position += read_bytes;
+            transport_utilization.push(utilization);
+        }
+    }
+
     Ok(Stats {
         process_id,
         cpu_usage,
@@ -378,9 +432,70 @@ pub fn map_stats(payload: Bytes) -> Result<Stats, IggyError> {
         iggy_server_version,
         iggy_server_semver,
         cache_metrics,
+        transport_utilization,
     })
 }
 
+fn map_to_transport_utilization(
+    payload: Bytes,
+    position: usize,
+) -> Result<(TransportUtilization, usize), IggyError> {
+    let transport_length = *payload
+        .get(position)
+        .ok_or(IggyError::InvalidNumberEncoding)? as usize;
+    let mut current_position = position + 1;
+    if current_position + transport_length + 32 > payload.len() {
+        return Err(IggyError::InvalidNumberEncoding);
+    }
+    let transport = from_utf8(&payload[current_position..current_position + transport_length])
+        .map_err(|_| IggyError::InvalidUtf8)?
+        .to_string();
+    current_position += transport_length;
+    let connections = u32::from_le_bytes(
+        payload[current_position..current_position + 4]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let max_connections = u32::from_le_bytes(
+        payload[current_position + 4..current_position + 8]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let in_flight_requests = u32::from_le_bytes(
+        payload[current_position + 8..current_position + 12]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let max_in_flight_requests = u32::from_le_bytes(
+        payload[current_position + 12..current_position + 16]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let rejected_connections = u64::from_le_bytes(
+        payload[current_position + 16..current_position + 24]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let rejected_requests = u64::from_le_bytes(
+        payload[current_position + 24..current_position + 32]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    current_position += 32;
+    Ok((
+        TransportUtilization {
+            transport,
+            connections,
+            max_connections,
+            in_flight_requests,
+            max_in_flight_requests,
+            rejected_connections,
+            rejected_requests,
+        },
+        current_position - position,
+    ))
+}
+
 pub fn map_consumer_offset(payload: Bytes) -> Result<ConsumerOffsetInfo, IggyError> {
     let partition_id = u32::from_le_bytes(
         payload[..4]
@@ -404,19 +519,87 @@ pub fn map_consumer_offset(payload: Bytes) -> Result<ConsumerOffsetInfo, IggyErr
     })
 }
 
+pub fn map_messages_buckets(payload: Bytes) -> Result<Vec<MessagesBucket>, IggyError> {
+    const BUCKET_SIZE: usize = 40;
+    if payload.len() % BUCKET_SIZE != 0 {
+        return Err(IggyError::InvalidCommand);
+    }
+
+    let mut buckets = Vec::with_capacity(payload.len() / BUCKET_SIZE);
+    for chunk in payload.chunks_exact(BUCKET_SIZE) {
+        let read_u64 = |position: usize| {
+            chunk[position..position + 8]
+                .try_into()
+                .map(u64::from_le_bytes)
+                .map_err(|_| IggyError::InvalidNumberEncoding)
+        };
+        buckets.push(MessagesBucket {
+            start_timestamp: read_u64(0)?.into(),
+            messages_count: read_u64(8)?,
+            size_bytes: read_u64(16)?.into(),
+            min_timestamp: read_u64(24)?.into(),
+            max_timestamp: read_u64(32)?.into(),
+        });
+    }
+    Ok(buckets)
+}
+
+pub fn map_partition_timestamp_offsets(
+    payload: Bytes,
+) -> Result<Vec<PartitionTimestampOffset>, IggyError> {
+    const OFFSET_SIZE: usize = 21;
+    if payload.len() % OFFSET_SIZE != 0 {
+        return Err(IggyError::InvalidCommand);
+    }
+
+    let mut offsets = Vec::with_capacity(payload.len() / OFFSET_SIZE);
+    for chunk in payload.chunks_exact(OFFSET_SIZE) {
+        let partition_id = u32::from_le_bytes(
+            chunk[0..4]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let timestamp = u64::from_le_bytes(
+            chunk[4..12]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let offset = u64::from_le_bytes(
+            chunk[13..21]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        offsets.push(PartitionTimestampOffset {
+            partition_id,
+            timestamp: timestamp.into(),
+            offset: if chunk[12] == 1 { Some(offset) } else { None },
+        });
+    }
+    Ok(offsets)
+}
+
 pub fn map_user(payload: Bytes) -> Result<UserInfoDetails, IggyError> {
     let (user, position) = map_to_user_info(payload.clone(), 0)?;
     let has_permissions = payload[position];
-    let permissions = if has_permissions == 1 {
+    let (permissions, position) = if has_permissions == 1 {
         let permissions_length = u32::from_le_bytes(
             payload[position + 1..position + 5]
                 .try_into()
                 .map_err(|_| IggyError::InvalidNumberEncoding)?,
         ) as usize;
         let permissions = payload.slice(position + 5..position + 5 + permissions_length);
-        Some(Permissions::from_bytes(permissions)?)
+        (
+            Some(Permissions::from_bytes(permissions)?),
+            position + 5 + permissions_length,
+        )
     } else {
-        None
+        (None, position + 1)
+    };
+    // The quotas are missing in the responses of the older servers.
+    let quotas = if payload.len() > position {
+        UserQuotas::from_bytes_at(&payload, position)?.0
+    } else {
+        UserQuotas::default()
     };
 
     let user = UserInfoDetails {
@@ -425,6 +608,7 @@ pub fn map_user(payload: Bytes) -> Result<UserInfoDetails, IggyError> {
         status: user.status,
         username: user.username,
         permissions,
+        quotas,
     };
     Ok(user)
 }
@@ -448,6 +632,7 @@ pub fn map_users(payload: Bytes) -> Result<Vec<UserInfo>, IggyError> {
 
 pub fn map_personal_access_tokens(
     payload: Bytes,
+    features: Handshake,
 ) -> Result<Vec<PersonalAccessTokenInfo>, IggyError> {
     if payload.is_empty() {
         return Ok(EMPTY_PERSONAL_ACCESS_TOKENS);
@@ -457,7 +642,8 @@ pub fn map_personal_access_tokens(
     let length = payload.len();
     let mut position = 0;
     while position < length {
-        let (personal_access_token, read_bytes) = map_to_pat_info(payload.clone(), position)?;
+        let (personal_access_token, read_bytes) =
+            map_to_pat_info(payload.clone(), position, features)?;
         personal_access_tokens.push(personal_access_token);
         position += read_bytes;
     }
@@ -545,12 +731,26 @@ pub fn map_clients(payload: Bytes) -> Result<Vec<ClientInfo>, IggyError> {
     Ok(clients)
 }
 
-pub fn map_polled_messages(payload: Bytes) -> Result<PolledMessages, IggyError> {
+/// The `features` are the ones negotiated during the handshake, as only then the response contains the optional fields,
+/// while the `chunked` flag tells whether the messages were polled with the preferred chunk size,
+/// as only then the response contains the chunk sizes.
+pub fn map_polled_messages(
+    payload: Bytes,
+    features: Handshake,
+    chunked: bool,
+    with_partition_epoch: bool,
+) -> Result<PolledMessages, IggyError> {
     if payload.is_empty() {
         return Ok(PolledMessages {
             messages: EMPTY_MESSAGES,
             partition_id: 0,
             current_offset: 0,
+            remaining_messages: 0,
+            gaps: Vec::new(),
+            chunk_sizes: Vec::new(),
+            partition_epoch: 0,
+            throttle_time_ms: 0,
+            topic_delete_at: 0,
         });
     }
 
@@ -565,13 +765,126 @@ pub fn map_polled_messages(payload: Bytes) -> Result<PolledMessages, IggyError>
             .try_into()
             .map_err(|_| IggyError::InvalidNumberEncoding)?,
     );
+    let mut position = 12;
+    let mut remaining_messages = 0;
+    if features.remaining_messages {
+        remaining_messages = u64::from_le_bytes(
+            payload
+                .get(position..position + 8)
+                .ok_or(IggyError::InvalidCommand)?
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        position += 8;
+    }
     // Currently ignored
     let _messages_count = u32::from_le_bytes(
-        payload[12..16]
+        payload
+            .get(position..position + 4)
+            .ok_or(IggyError::InvalidCommand)?
             .try_into()
             .map_err(|_| IggyError::InvalidNumberEncoding)?,
     );
-    let mut position = 16;
+    position += 4;
+    let mut gaps_count = 0;
+    if features.message_gaps {
+        gaps_count = u32::from_le_bytes(
+            payload
+                .get(position..position + 4)
+                .ok_or(IggyError::InvalidCommand)?
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        position += 4;
+    }
+    let mut gaps = Vec::with_capacity(gaps_count as usize);
+    for _ in 0..gaps_count {
+        if position + 17 > length {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let start_offset = u64::from_le_bytes(
+            payload[position..position + 8]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let end_offset = u64::from_le_bytes(
+            payload[position + 8..position + 16]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let reason = MessagesGapReason::from_code(payload[position + 16])?;
+        gaps.push(MessagesGap {
+            start_offset,
+            end_offset,
+            reason,
+        });
+        position += 17;
+    }
+
+    // The partition epoch is present only if it was requested, while the throttle time and the topic deletion time
+    // are present only if these were negotiated, so that the older clients can still read the response.
+    let mut partition_epoch = 0;
+    let mut throttle_time_ms = 0;
+    let mut topic_delete_at = 0;
+    if with_partition_epoch {
+        partition_epoch = u64::from_le_bytes(
+            payload
+                .get(position..position + 8)
+                .ok_or(IggyError::InvalidCommand)?
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        position += 8;
+    }
+    if features.throttle_time {
+        throttle_time_ms = u32::from_le_bytes(
+            payload
+                .get(position..position + 4)
+                .ok_or(IggyError::InvalidCommand)?
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        position += 4;
+    }
+    if features.topic_deletion_time {
+        topic_delete_at = u64::from_le_bytes(
+            payload
+                .get(position..position + 8)
+                .ok_or(IggyError::InvalidCommand)?
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        position += 8;
+    }
+
+    let mut chunk_sizes = Vec::new();
+    if chunked && position < length {
+        if position + 4 > length {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let chunks_count = u32::from_le_bytes(
+            payload[position..position + 4]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        position += 4;
+        if position + 4 * chunks_count as usize > length {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        chunk_sizes.reserve(chunks_count as usize);
+        for _ in 0..chunks_count {
+            chunk_sizes.push(u32::from_le_bytes(
+                payload[position..position + 4]
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            ));
+            position += 4;
+        }
+    }
+
     let mut messages = Vec::new();
     while position < length {
         let offset = u64::from_le_bytes(
@@ -641,207 +954,502 @@ pub fn map_polled_messages(payload: Bytes) -> Result<PolledMessages, IggyError>
     Ok(PolledMessages {
         partition_id,
         current_offset,
+        remaining_messages,
+        gaps,
+        chunk_sizes,
+        partition_epoch,
+        throttle_time_ms,
+        topic_delete_at,
         messages,
     })
 }
 
-pub fn map_streams(payload: Bytes) -> Result<Vec<Stream>, IggyError> {
-    if payload.is_empty() {
-        return Ok(EMPTY_STREAMS);
+pub fn map_server_info(payload: Bytes) -> Result<ServerInfo, IggyError> {
+    if payload.len() < 17 {
+        return Err(IggyError::InvalidCommand);
     }
 
-    let mut streams = Vec::new();
-    let length = payload.len();
-    let mut position = 0;
-    while position < length {
-        let (stream, read_bytes) = map_to_stream(payload.clone(), position)?;
-        streams.push(stream);
-        position += read_bytes;
-    }
-    streams.sort_by(|x, y| x.id.cmp(&y.id));
-    Ok(streams)
+    let read_u32 = |position: usize| -> Result<u32, IggyError> {
+        Ok(u32::from_le_bytes(
+            payload[position..position + 4]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        ))
+    };
+    let limits = ServerLimits {
+        max_streams: read_u32(0)?,
+        max_topics_per_stream: read_u32(4)?,
+        max_partitions_per_topic: read_u32(8)?,
+        max_total_partitions: read_u32(12)?,
+    };
+    let version_length = payload[16] as usize;
+    let version = payload
+        .get(17..17 + version_length)
+        .ok_or(IggyError::InvalidCommand)?;
+    let version = from_utf8(version)
+        .map_err(|_| IggyError::InvalidUtf8)?
+        .to_string();
+    Ok(ServerInfo { version, limits })
 }
 
-pub fn map_stream(payload: Bytes) -> Result<StreamDetails, IggyError> {
-    let (stream, mut position) = map_to_stream(payload.clone(), 0)?;
-    let mut topics = Vec::new();
-    let length = payload.len();
-    while position < length {
-        let (topic, read_bytes) = map_to_topic(payload.clone(), position)?;
-        topics.push(topic);
-        position += read_bytes;
+pub fn map_maintenance_mode(payload: Bytes) -> Result<MaintenanceMode, IggyError> {
+    if payload.len() < 14 {
+        return Err(IggyError::InvalidCommand);
     }
 
-    topics.sort_by(|x, y| x.id.cmp(&y.id));
-    let stream = StreamDetails {
-        id: stream.id,
-        created_at: stream.created_at,
-        topics_count: stream.topics_count,
-        size: stream.size,
-        messages_count: stream.messages_count,
-        name: stream.name,
-        topics,
+    let enabled_at = u64::from_le_bytes(
+        payload[2..10]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let message_length = u32::from_le_bytes(
+        payload[10..14]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    ) as usize;
+    let message = payload
+        .get(14..14 + message_length)
+        .ok_or(IggyError::InvalidCommand)?;
+    let message = match message_length {
+        0 => None,
+        _ => Some(
+            from_utf8(message)
+                .map_err(|_| IggyError::InvalidUtf8)?
+                .to_string(),
+        ),
     };
-    Ok(stream)
+    Ok(MaintenanceMode {
+        enabled: payload[0] == 1,
+        reject_reads: payload[1] == 1,
+        message,
+        enabled_at: match enabled_at {
+            0 => None,
+            value => Some(value.into()),
+        },
+    })
 }
 
-fn map_to_stream(payload: Bytes, position: usize) -> Result<(Stream, usize), IggyError> {
-    let id = u32::from_le_bytes(
-        payload[position..position + 4]
+pub fn map_backup(payload: Bytes) -> Result<Backup, IggyError> {
+    if payload.len() < 29 {
+        return Err(IggyError::InvalidCommand);
+    }
+
+    let created_at = u64::from_le_bytes(
+        payload[0..8]
             .try_into()
             .map_err(|_| IggyError::InvalidNumberEncoding)?,
     );
-    let created_at = u64::from_le_bytes(
-        payload[position + 4..position + 12]
+    let files_count = u32::from_le_bytes(
+        payload[8..12]
             .try_into()
             .map_err(|_| IggyError::InvalidNumberEncoding)?,
-    )
-    .into();
-    let topics_count = u32::from_le_bytes(
-        payload[position + 12..position + 16]
+    );
+    let linked_files_count = u32::from_le_bytes(
+        payload[12..16]
             .try_into()
             .map_err(|_| IggyError::InvalidNumberEncoding)?,
     );
-    let size_bytes = u64::from_le_bytes(
-        payload[position + 16..position + 24]
+    let size = u64::from_le_bytes(
+        payload[16..24]
             .try_into()
             .map_err(|_| IggyError::InvalidNumberEncoding)?,
-    )
-    .into();
-    let messages_count = u64::from_le_bytes(
-        payload[position + 24..position + 32]
+    );
+    let name_length = payload[24] as usize;
+    let name = payload
+        .get(25..25 + name_length)
+        .ok_or(IggyError::InvalidCommand)?;
+    let name = from_utf8(name)
+        .map_err(|_| IggyError::InvalidUtf8)?
+        .to_string();
+    let position = 25 + name_length;
+    let path_length = u32::from_le_bytes(
+        payload
+            .get(position..position + 4)
+            .ok_or(IggyError::InvalidCommand)?
             .try_into()
             .map_err(|_| IggyError::InvalidNumberEncoding)?,
-    );
-    let name_length = payload[position + 32];
-    let name = from_utf8(&payload[position + 33..position + 33 + name_length as usize])
+    ) as usize;
+    let path = payload
+        .get(position + 4..position + 4 + path_length)
+        .ok_or(IggyError::InvalidCommand)?;
+    let path = from_utf8(path)
         .map_err(|_| IggyError::InvalidUtf8)?
         .to_string();
-    let read_bytes = 4 + 8 + 4 + 8 + 8 + 1 + name_length as usize;
-    Ok((
-        Stream {
-            id,
-            created_at,
-            name,
-            size: size_bytes,
-            messages_count,
-            topics_count,
-        },
-        read_bytes,
-    ))
-}
-
-pub fn map_topics(payload: Bytes) -> Result<Vec<Topic>, IggyError> {
-    if payload.is_empty() {
-        return Ok(EMPTY_TOPICS);
-    }
-
-    let mut topics = Vec::new();
-    let length = payload.len();
-    let mut position = 0;
-    while position < length {
-        let (topic, read_bytes) = map_to_topic(payload.clone(), position)?;
-        topics.push(topic);
-        position += read_bytes;
-    }
-    topics.sort_by(|x, y| x.id.cmp(&y.id));
-    Ok(topics)
+    Ok(Backup {
+        name,
+        path,
+        created_at: created_at.into(),
+        files_count,
+        linked_files_count,
+        size: size.into(),
+    })
 }
 
-pub fn map_topic(payload: Bytes) -> Result<TopicDetails, IggyError> {
-    let (topic, mut position) = map_to_topic(payload.clone(), 0)?;
-    let mut partitions = Vec::new();
-    let length = payload.len();
-    while position < length {
-        let (partition, read_bytes) = map_to_partition(payload.clone(), position)?;
-        partitions.push(partition);
-        position += read_bytes;
+pub fn map_restored_segments(payload: Bytes) -> Result<RestoredSegments, IggyError> {
+    if payload.len() != 20 {
+        return Err(IggyError::InvalidCommand);
     }
 
-    partitions.sort_by(|x, y| x.id.cmp(&y.id));
-    let topic = TopicDetails {
-        id: topic.id,
-        created_at: topic.created_at,
-        name: topic.name,
-        size: topic.size,
-        messages_count: topic.messages_count,
-        message_expiry: topic.message_expiry,
-        compression_algorithm: topic.compression_algorithm,
-        max_topic_size: topic.max_topic_size,
-        replication_factor: topic.replication_factor,
-        #[allow(clippy::cast_possible_truncation)]
-        partitions_count: partitions.len() as u32,
-        partitions,
-    };
-    Ok(topic)
-}
-
-fn map_to_topic(payload: Bytes, position: usize) -> Result<(Topic, usize), IggyError> {
-    let id = u32::from_le_bytes(
-        payload[position..position + 4]
+    let segments_count = u32::from_le_bytes(
+        payload[0..4]
             .try_into()
             .map_err(|_| IggyError::InvalidNumberEncoding)?,
     );
-    let created_at = u64::from_le_bytes(
-        payload[position + 4..position + 12]
+    let messages_count = u64::from_le_bytes(
+        payload[4..12]
             .try_into()
             .map_err(|_| IggyError::InvalidNumberEncoding)?,
     );
-    let created_at = created_at.into();
-    let partitions_count = u32::from_le_bytes(
-        payload[position + 12..position + 16]
+    let size = u64::from_le_bytes(
+        payload[12..20]
             .try_into()
             .map_err(|_| IggyError::InvalidNumberEncoding)?,
     );
-    let message_expiry = match u64::from_le_bytes(
-        payload[position + 16..position + 24]
+    Ok(RestoredSegments {
+        segments_count,
+        messages_count,
+        size: size.into(),
+    })
+}
+
+pub fn map_segment_range(payload: Bytes) -> Result<SegmentRange, IggyError> {
+    const HEADER_SIZE: usize = 41;
+    if payload.len() < HEADER_SIZE {
+        return Err(IggyError::InvalidCommand);
+    }
+
+    let segment_start_offset = u64::from_le_bytes(
+        payload[0..8]
             .try_into()
             .map_err(|_| IggyError::InvalidNumberEncoding)?,
-    ) {
-        0 => IggyExpiry::NeverExpire,
-        message_expiry => message_expiry.into(),
-    };
-    let compression_algorithm = CompressionAlgorithm::from_code(payload[position + 24])?;
-    let max_topic_size = u64::from_le_bytes(
-        payload[position + 25..position + 33]
+    );
+    let segment_end_offset = u64::from_le_bytes(
+        payload[8..16]
             .try_into()
             .map_err(|_| IggyError::InvalidNumberEncoding)?,
     );
-    let max_topic_size: MaxTopicSize = max_topic_size.into();
-    let replication_factor = payload[position + 33];
-    let size_bytes = IggyByteSize::from(u64::from_le_bytes(
-        payload[position + 34..position + 42]
+    let segment_size = u64::from_le_bytes(
+        payload[16..24]
             .try_into()
             .map_err(|_| IggyError::InvalidNumberEncoding)?,
-    ));
-    let messages_count = u64::from_le_bytes(
-        payload[position + 42..position + 50]
+    );
+    let segment_closed = payload[24] == 1;
+    let start_position = u64::from_le_bytes(
+        payload[25..33]
             .try_into()
             .map_err(|_| IggyError::InvalidNumberEncoding)?,
     );
-    let name_length = payload[position + 50];
-    let name = from_utf8(&payload[position + 51..position + 51 + name_length as usize])
-        .map_err(|_| IggyError::InvalidUtf8)?
-        .to_string();
-    let read_bytes = 4 + 8 + 4 + 8 + 8 + 8 + 8 + 1 + 1 + 1 + name_length as usize;
-    Ok((
-        Topic {
-            id,
-            created_at,
-            name,
-            partitions_count,
-            size: size_bytes,
+    let next_position = u64::from_le_bytes(
+        payload[33..41]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    Ok(SegmentRange {
+        segment_start_offset,
+        segment_end_offset,
+        segment_size,
+        segment_closed,
+        start_position,
+        next_position,
+        bytes: payload.slice(HEADER_SIZE..),
+    })
+}
+
+pub fn map_unsaved_state(payload: Bytes) -> Result<Vec<PartitionUnsavedState>, IggyError> {
+    const STATE_SIZE: usize = 28;
+    if payload.len() % STATE_SIZE != 0 {
+        return Err(IggyError::InvalidCommand);
+    }
+
+    let mut unsaved_state = Vec::with_capacity(payload.len() / STATE_SIZE);
+    for chunk in payload.chunks_exact(STATE_SIZE) {
+        let stream_id = u32::from_le_bytes(
+            chunk[0..4]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let topic_id = u32::from_le_bytes(
+            chunk[4..8]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let partition_id = u32::from_le_bytes(
+            chunk[8..12]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let messages_count = u64::from_le_bytes(
+            chunk[12..20]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let size = u64::from_le_bytes(
+            chunk[20..28]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        unsaved_state.push(PartitionUnsavedState {
+            stream_id,
+            topic_id,
+            partition_id,
             messages_count,
-            message_expiry,
-            compression_algorithm,
-            max_topic_size,
-            replication_factor,
-        },
-        read_bytes,
-    ))
+            size: size.into(),
+        });
+    }
+    Ok(unsaved_state)
+}
+
+pub fn map_audit_entries(payload: Bytes) -> Result<Vec<AuditEntry>, IggyError> {
+    let mut entries = Vec::new();
+    let mut position = 0;
+    while position < payload.len() {
+        let (entry, read_bytes) = AuditEntry::from_bytes_at(&payload, position)?;
+        entries.push(entry);
+        position += read_bytes;
+    }
+    Ok(entries)
+}
+
+pub fn map_error_catalog(payload: Bytes) -> Result<Vec<ErrorCatalogEntry>, IggyError> {
+    let mut entries = Vec::new();
+    let mut position = 0;
+    while position < payload.len() {
+        let (entry, read_bytes) = ErrorCatalogEntry::from_bytes_at(&payload, position)?;
+        entries.push(entry);
+        position += read_bytes;
+    }
+    Ok(entries)
+}
+
+pub fn map_push_subscription(payload: Bytes) -> Result<PushSubscription, IggyError> {
+    let (push_subscription, _) = map_to_push_subscription(payload, 0)?;
+    Ok(push_subscription)
+}
+
+pub fn map_push_subscriptions(payload: Bytes) -> Result<Vec<PushSubscription>, IggyError> {
+    if payload.is_empty() {
+        return Ok(EMPTY_PUSH_SUBSCRIPTIONS);
+    }
+
+    let mut push_subscriptions = Vec::new();
+    let length = payload.len();
+    let mut position = 0;
+    while position < length {
+        let (push_subscription, read_bytes) = map_to_push_subscription(payload.clone(), position)?;
+        push_subscriptions.push(push_subscription);
+        position += read_bytes;
+    }
+    push_subscriptions.sort_by(|x, y| x.id.cmp(&y.id));
+    Ok(push_subscriptions)
+}
+
+pub fn map_producer_epoch(payload: Bytes) -> Result<ProducerEpoch, IggyError> {
+    if payload.len() < 5 {
+        return Err(IggyError::InvalidCommand);
+    }
+
+    let epoch = u32::from_le_bytes(
+        payload[0..4]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let name_length = payload[4] as usize;
+    let name = from_utf8(
+        payload
+            .get(5..5 + name_length)
+            .ok_or(IggyError::InvalidCommand)?,
+    )
+    .map_err(|_| IggyError::InvalidUtf8)?
+    .to_string();
+    Ok(ProducerEpoch { name, epoch })
+}
+
+pub fn map_producer_session(payload: Bytes) -> Result<ProducerSession, IggyError> {
+    if payload.len() != 8 {
+        return Err(IggyError::InvalidCommand);
+    }
+
+    let producer_id = u64::from_le_bytes(
+        payload[0..8]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    Ok(ProducerSession { producer_id })
+}
+
+pub fn map_transaction(payload: Bytes) -> Result<Transaction, IggyError> {
+    if payload.len() != 8 {
+        return Err(IggyError::InvalidCommand);
+    }
+
+    let transaction_id = u64::from_le_bytes(
+        payload[0..8]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    Ok(Transaction { transaction_id })
+}
+
+pub fn map_replay_job(payload: Bytes) -> Result<ReplayJob, IggyError> {
+    let (replay_job, _) = map_to_replay_job(payload, 0)?;
+    Ok(replay_job)
+}
+
+pub fn map_replay_jobs(payload: Bytes) -> Result<Vec<ReplayJob>, IggyError> {
+    if payload.is_empty() {
+        return Ok(EMPTY_REPLAY_JOBS);
+    }
+
+    let mut replay_jobs = Vec::new();
+    let length = payload.len();
+    let mut position = 0;
+    while position < length {
+        let (replay_job, read_bytes) = map_to_replay_job(payload.clone(), position)?;
+        replay_jobs.push(replay_job);
+        position += read_bytes;
+    }
+    replay_jobs.sort_by(|x, y| x.id.cmp(&y.id));
+    Ok(replay_jobs)
+}
+
+pub fn map_streams(payload: Bytes, features: Handshake) -> Result<Vec<Stream>, IggyError> {
+    if payload.is_empty() {
+        return Ok(EMPTY_STREAMS);
+    }
+
+    let mut streams = Vec::new();
+    let length = payload.len();
+    let mut position = 0;
+    while position < length {
+        let (stream, read_bytes) = map_to_stream(payload.clone(), position, features)?;
+        streams.push(stream);
+        position += read_bytes;
+    }
+    streams.sort_by(|x, y| x.id.cmp(&y.id));
+    Ok(streams)
+}
+
+pub fn map_stream(payload: Bytes, features: Handshake) -> Result<StreamDetails, IggyError> {
+    let (stream, mut position) = map_to_stream(payload.clone(), 0, features)?;
+    let mut topics = Vec::new();
+    let length = payload.len();
+    while position < length {
+        let (topic, read_bytes) = map_to_topic(payload.clone(), position, features)?;
+        topics.push(topic);
+        position += read_bytes;
+    }
+
+    topics.sort_by(|x, y| x.id.cmp(&y.id));
+    let stream = StreamDetails {
+        id: stream.id,
+        created_at: stream.created_at,
+        topics_count: stream.topics_count,
+        size: stream.size,
+        messages_count: stream.messages_count,
+        name: stream.name,
+        metadata: stream.metadata,
+        quota: stream.quota,
+        topics,
+    };
+    Ok(stream)
+}
+
+// The fields added after the initial version of the protocol are present only if their features were negotiated.
+fn map_to_stream(
+    payload: Bytes,
+    position: usize,
+    features: Handshake,
+) -> Result<(Stream, usize), IggyError> {
+    let id = read_u32_at(&payload, position)?;
+    let created_at = read_u64_at(&payload, position + 4)?.into();
+    let topics_count = read_u32_at(&payload, position + 12)?;
+    let size_bytes = read_u64_at(&payload, position + 16)?.into();
+    let messages_count = read_u64_at(&payload, position + 24)?;
+    let (name, name_length) = read_name_at(&payload, position + 32)?;
+    let mut read_bytes = 4 + 8 + 4 + 8 + 8 + 1 + name_length;
+    let mut stream = Stream {
+        id,
+        created_at,
+        name,
+        size: size_bytes,
+        messages_count,
+        topics_count,
+        metadata: ResourceMetadata::default(),
+        quota: StreamQuota::default(),
+    };
+    if features.resource_metadata {
+        let (metadata, metadata_bytes) =
+            ResourceMetadata::from_bytes_at(&payload, position + read_bytes)?;
+        stream.metadata = metadata;
+        read_bytes += metadata_bytes;
+    }
+    if features.stream_quota {
+        stream.quota = StreamQuota {
+            soft_limit: read_u64_at(&payload, position + read_bytes)?.into(),
+            hard_limit: read_u64_at(&payload, position + read_bytes + 8)?.into(),
+        };
+        read_bytes += 8 + 8;
+    }
+    Ok((stream, read_bytes))
+}
+
+pub fn map_namespaces(payload: Bytes) -> Result<Vec<Namespace>, IggyError> {
+    if payload.is_empty() {
+        return Ok(EMPTY_NAMESPACES);
+    }
+
+    let mut namespaces = Vec::new();
+    let length = payload.len();
+    let mut position = 0;
+    while position < length {
+        let (namespace, read_bytes) = map_to_namespace(&payload, position)?;
+        namespaces.push(namespace);
+        position += read_bytes;
+    }
+    namespaces.sort_by(|x, y| x.id.cmp(&y.id));
+    Ok(namespaces)
+}
+
+pub fn map_namespace(payload: Bytes) -> Result<NamespaceDetails, IggyError> {
+    let (namespace, position) = map_to_namespace(&payload, 0)?;
+    let read_ids = |position: usize, count: u32| -> Result<Vec<u32>, IggyError> {
+        (0..count as usize)
+            .map(|index| {
+                let id_position = position + index * 4;
+                Ok(u32::from_le_bytes(
+                    payload
+                        .get(id_position..id_position + 4)
+                        .ok_or(IggyError::InvalidNumberEncoding)?
+                        .try_into()
+                        .map_err(|_| IggyError::InvalidNumberEncoding)?,
+                ))
+            })
+            .collect()
+    };
+    let stream_ids = read_ids(position, namespace.streams_count)?;
+    let user_ids = read_ids(
+        position + 4 * namespace.streams_count as usize,
+        namespace.users_count,
+    )?;
+    Ok(NamespaceDetails {
+        id: namespace.id,
+        created_at: namespace.created_at,
+        name: namespace.name,
+        quotas: namespace.quotas,
+        streams_count: namespace.streams_count,
+        users_count: namespace.users_count,
+        size: namespace.size,
+        messages_count: namespace.messages_count,
+        stream_ids,
+        user_ids,
+    })
 }
 
-fn map_to_partition(payload: Bytes, position: usize) -> Result<(Partition, usize), IggyError> {
+fn map_to_namespace(payload: &Bytes, position: usize) -> Result<(Namespace, usize), IggyError> {
+    if payload.len() < position + 53 {
+        return Err(IggyError::InvalidNumberEncoding);
+    }
+
     let id = u32::from_le_bytes(
         payload[position..position + 4]
             .try_into()
@@ -852,29 +1460,218 @@ fn map_to_partition(payload: Bytes, position: usize) -> Result<(Partition, usize
             .try_into()
             .map_err(|_| IggyError::InvalidNumberEncoding)?,
     );
-    let created_at = created_at.into();
-    let segments_count = u32::from_le_bytes(
+    let streams_count = u32::from_le_bytes(
         payload[position + 12..position + 16]
             .try_into()
             .map_err(|_| IggyError::InvalidNumberEncoding)?,
     );
-    let current_offset = u64::from_le_bytes(
-        payload[position + 16..position + 24]
+    let users_count = u32::from_le_bytes(
+        payload[position + 16..position + 20]
             .try_into()
             .map_err(|_| IggyError::InvalidNumberEncoding)?,
     );
-    let size_bytes = u64::from_le_bytes(
-        payload[position + 24..position + 32]
+    let size = u64::from_le_bytes(
+        payload[position + 20..position + 28]
             .try_into()
             .map_err(|_| IggyError::InvalidNumberEncoding)?,
-    )
-    .into();
+    );
     let messages_count = u64::from_le_bytes(
-        payload[position + 32..position + 40]
+        payload[position + 28..position + 36]
             .try_into()
             .map_err(|_| IggyError::InvalidNumberEncoding)?,
     );
-    let read_bytes = 4 + 8 + 4 + 8 + 8 + 8;
+    let (quotas, quotas_bytes) = NamespaceQuotas::from_bytes_at(payload, position + 36)?;
+    let name_position = position + 36 + quotas_bytes;
+    let name_length = payload[name_position] as usize;
+    let name = from_utf8(
+        payload
+            .get(name_position + 1..name_position + 1 + name_length)
+            .ok_or(IggyError::InvalidNumberEncoding)?,
+    )
+    .map_err(|_| IggyError::InvalidUtf8)?
+    .to_string();
+    let read_bytes = 4 + 8 + 4 + 4 + 8 + 8 + quotas_bytes + 1 + name_length;
+    Ok((
+        Namespace {
+            id,
+            created_at,
+            name,
+            quotas,
+            streams_count,
+            users_count,
+            size: size.into(),
+            messages_count,
+        },
+        read_bytes,
+    ))
+}
+
+pub fn map_topics(payload: Bytes, features: Handshake) -> Result<Vec<Topic>, IggyError> {
+    if payload.is_empty() {
+        return Ok(EMPTY_TOPICS);
+    }
+
+    let mut topics = Vec::new();
+    let length = payload.len();
+    let mut position = 0;
+    while position < length {
+        let (topic, read_bytes) = map_to_topic(payload.clone(), position, features)?;
+        topics.push(topic);
+        position += read_bytes;
+    }
+    topics.sort_by(|x, y| x.id.cmp(&y.id));
+    Ok(topics)
+}
+
+pub fn map_topic(payload: Bytes, features: Handshake) -> Result<TopicDetails, IggyError> {
+    let (topic, mut position) = map_to_topic(payload.clone(), 0, features)?;
+    let mut message_sizes = MessageSizeDistribution::default();
+    if features.message_sizes {
+        let (distribution, read_bytes) =
+            MessageSizeDistribution::from_bytes_at(&payload, position)?;
+        message_sizes = distribution;
+        position += read_bytes;
+    }
+    let mut partitions = Vec::new();
+    let length = payload.len();
+    while position < length {
+        let (partition, read_bytes) = map_to_partition(payload.clone(), position, features)?;
+        partitions.push(partition);
+        position += read_bytes;
+    }
+
+    partitions.sort_by(|x, y| x.id.cmp(&y.id));
+    let topic = TopicDetails {
+        id: topic.id,
+        created_at: topic.created_at,
+        name: topic.name,
+        size: topic.size,
+        messages_count: topic.messages_count,
+        message_expiry: topic.message_expiry,
+        compression_algorithm: topic.compression_algorithm,
+        max_topic_size: topic.max_topic_size,
+        max_segments: topic.max_segments,
+        replication_factor: topic.replication_factor,
+        #[allow(clippy::cast_possible_truncation)]
+        partitions_count: partitions.len() as u32,
+        metadata: topic.metadata,
+        message_id_scheme: topic.message_id_scheme,
+        cleanup_policy: topic.cleanup_policy,
+        allowed_producers: topic.allowed_producers,
+        delete_at: topic.delete_at,
+        config: topic.config,
+        message_sizes,
+        partitions,
+    };
+    Ok(topic)
+}
+
+// The fields added after the initial version of the protocol are present only if their features were negotiated.
+fn map_to_topic(
+    payload: Bytes,
+    position: usize,
+    features: Handshake,
+) -> Result<(Topic, usize), IggyError> {
+    let id = read_u32_at(&payload, position)?;
+    let created_at = read_u64_at(&payload, position + 4)?.into();
+    let partitions_count = read_u32_at(&payload, position + 12)?;
+    let message_expiry = match read_u64_at(&payload, position + 16)? {
+        0 => IggyExpiry::NeverExpire,
+        message_expiry => message_expiry.into(),
+    };
+    let compression_algorithm =
+        CompressionAlgorithm::from_code(read_u8_at(&payload, position + 24)?)?;
+    let max_topic_size: MaxTopicSize = read_u64_at(&payload, position + 25)?.into();
+    let replication_factor = read_u8_at(&payload, position + 33)?;
+    let size_bytes = IggyByteSize::from(read_u64_at(&payload, position + 34)?);
+    let messages_count = read_u64_at(&payload, position + 42)?;
+    let (name, name_length) = read_name_at(&payload, position + 50)?;
+    let mut read_bytes = 4 + 8 + 4 + 8 + 1 + 8 + 1 + 8 + 8 + 1 + name_length;
+    let mut topic = Topic {
+        id,
+        created_at,
+        name,
+        partitions_count,
+        size: size_bytes,
+        messages_count,
+        message_expiry,
+        compression_algorithm,
+        max_topic_size,
+        max_segments: 0,
+        replication_factor,
+        metadata: ResourceMetadata::default(),
+        message_id_scheme: MessageIdScheme::default(),
+        cleanup_policy: CleanupPolicy::default(),
+        allowed_producers: Vec::new(),
+        delete_at: None,
+        config: TopicConfig::default(),
+    };
+    if features.resource_metadata {
+        let (metadata, metadata_bytes) =
+            ResourceMetadata::from_bytes_at(&payload, position + read_bytes)?;
+        topic.metadata = metadata;
+        read_bytes += metadata_bytes;
+    }
+    if features.message_id_scheme {
+        topic.message_id_scheme =
+            MessageIdScheme::from_code(read_u8_at(&payload, position + read_bytes)?)?;
+        read_bytes += 1;
+    }
+    if features.cleanup_policy {
+        topic.cleanup_policy =
+            CleanupPolicy::from_code(read_u8_at(&payload, position + read_bytes)?)?;
+        read_bytes += 1;
+    }
+    if features.allowed_producers {
+        let allowed_producers_count = read_u32_at(&payload, position + read_bytes)? as usize;
+        read_bytes += 4;
+        topic.allowed_producers = (0..allowed_producers_count)
+            .map(|index| read_u32_at(&payload, position + read_bytes + index * 4))
+            .collect::<Result<_, _>>()?;
+        read_bytes += allowed_producers_count * 4;
+    }
+    if features.topic_deletion_time {
+        topic.delete_at = match read_u64_at(&payload, position + read_bytes)? {
+            0 => None,
+            delete_at => Some(delete_at.into()),
+        };
+        read_bytes += 8;
+    }
+    if features.max_segments {
+        topic.max_segments = read_u32_at(&payload, position + read_bytes)?;
+        read_bytes += 4;
+    }
+    if features.topic_config {
+        let (config, config_bytes) = TopicConfig::from_bytes_at(&payload, position + read_bytes)?;
+        topic.config = config;
+        read_bytes += config_bytes;
+    }
+    Ok((topic, read_bytes))
+}
+
+fn map_to_partition(
+    payload: Bytes,
+    position: usize,
+    features: Handshake,
+) -> Result<(Partition, usize), IggyError> {
+    let id = read_u32_at(&payload, position)?;
+    let created_at = read_u64_at(&payload, position + 4)?.into();
+    let segments_count = read_u32_at(&payload, position + 12)?;
+    let current_offset = read_u64_at(&payload, position + 16)?;
+    let size_bytes = read_u64_at(&payload, position + 24)?.into();
+    let messages_count = read_u64_at(&payload, position + 32)?;
+    let mut read_bytes = 4 + 8 + 4 + 8 + 8 + 8;
+    let mut recovery = None;
+    if features.partition_recovery {
+        let has_recovery = read_u8_at(&payload, position + read_bytes)?;
+        read_bytes += 1;
+        if has_recovery == 1 {
+            let (progress, recovery_read_bytes) =
+                map_to_partition_recovery(&payload, position + read_bytes)?;
+            recovery = Some(progress);
+            read_bytes += recovery_read_bytes;
+        }
+    }
     Ok((
         Partition {
             id,
@@ -883,11 +1680,84 @@ fn map_to_partition(payload: Bytes, position: usize) -> Result<(Partition, usize
             current_offset,
             size: size_bytes,
             messages_count,
+            recovery,
         },
         read_bytes,
     ))
 }
 
+fn map_to_partition_recovery(
+    payload: &[u8],
+    position: usize,
+) -> Result<(PartitionRecoveryProgress, usize), IggyError> {
+    let started_at = read_u64_at(payload, position)?.into();
+    let total_segments = read_u32_at(payload, position + 8)?;
+    let recovered_segments = read_u32_at(payload, position + 12)?;
+    let total_size = read_u64_at(payload, position + 16)?.into();
+    let recovered_size = read_u64_at(payload, position + 24)?.into();
+    let has_eta = read_u8_at(payload, position + 32)?;
+    let mut read_bytes = 8 + 4 + 4 + 8 + 8 + 1;
+    let mut eta = None;
+    if has_eta == 1 {
+        eta = Some(IggyDuration::from(read_u64_at(
+            payload,
+            position + read_bytes,
+        )?));
+        read_bytes += 8;
+    }
+    Ok((
+        PartitionRecoveryProgress {
+            started_at,
+            total_segments,
+            recovered_segments,
+            total_size,
+            recovered_size,
+            eta,
+        },
+        read_bytes,
+    ))
+}
+
+fn read_u8_at(payload: &[u8], position: usize) -> Result<u8, IggyError> {
+    payload
+        .get(position)
+        .copied()
+        .ok_or(IggyError::InvalidCommand)
+}
+
+fn read_u32_at(payload: &[u8], position: usize) -> Result<u32, IggyError> {
+    Ok(u32::from_le_bytes(
+        payload
+            .get(position..position + 4)
+            .ok_or(IggyError::InvalidCommand)?
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    ))
+}
+
+fn read_u64_at(payload: &[u8], position: usize) -> Result<u64, IggyError> {
+    Ok(u64::from_le_bytes(
+        payload
+            .get(position..position + 8)
+            .ok_or(IggyError::InvalidCommand)?
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    ))
+}
+
+/// Reads the name prefixed with its single byte length, returning the name and its length.
+fn read_name_at(payload: &[u8], position: usize) -> Result<(String, usize), IggyError> {
+    let name_length = read_u8_at(payload, position)? as usize;
+    let name = from_utf8(
+        payload
+            .get(position + 1..position + 1 + name_length)
+            .ok_or(IggyError::InvalidCommand)?,
+    )
+    .map_err(|_| IggyError::InvalidUtf8)?
+    .to_string();
+    Ok((name, name_length))
+}
+
 pub fn map_consumer_groups(payload: Bytes) -> Result<Vec<ConsumerGroup>, IggyError> {
     if payload.is_empty() {
         return Ok(EMPTY_CONSUMER_GROUPS);
@@ -905,26 +1775,140 @@ pub fn map_consumer_groups(payload: Bytes) -> Result<Vec<ConsumerGroup>, IggyErr
     Ok(consumer_groups)
 }
 
-pub fn map_consumer_group(payload: Bytes) -> Result<ConsumerGroupDetails, IggyError> {
+pub fn map_consumer_group(
+    payload: Bytes,
+    features: Handshake,
+) -> Result<ConsumerGroupDetails, IggyError> {
     let (consumer_group, mut position) = map_to_consumer_group(payload.clone(), 0)?;
     let mut members = Vec::new();
     let length = payload.len();
-    while position < length {
+    for _ in 0..consumer_group.members_count {
+        if position >= length {
+            break;
+        }
         let (member, read_bytes) = map_to_consumer_group_member(payload.clone(), position)?;
         members.push(member);
         position += read_bytes;
     }
     members.sort_by(|x, y| x.id.cmp(&y.id));
+    let mut rebalances = Vec::new();
+    if features.consumer_group_rebalances {
+        let rebalances_count = read_u32_at(&payload, position)?;
+        position += 4;
+        for _ in 0..rebalances_count {
+            let (rebalance, read_bytes) = map_to_consumer_group_rebalance(&payload, position)?;
+            rebalances.push(rebalance);
+            position += read_bytes;
+        }
+    }
+    let mut generation = 0;
+    let mut assignment_strategy = PartitionAssignmentStrategy::default();
+    if features.consumer_group_assignment {
+        generation = read_u32_at(&payload, position)?;
+        assignment_strategy =
+            PartitionAssignmentStrategy::from_code(read_u8_at(&payload, position + 4)?)?;
+        position += 5;
+    }
+    let mut offset_recovery = OffsetRecoveryPolicy::default();
+    if features.offset_recovery {
+        offset_recovery = OffsetRecoveryPolicy::from_code(read_u8_at(&payload, position)?)?;
+        position += 1;
+    }
+    let mut dead_letter = None;
+    if features.dead_letter {
+        let has_dead_letter = read_u8_at(&payload, position)?;
+        position += 1;
+        if has_dead_letter == 1 {
+            dead_letter = Some(ConsumerGroupDeadLetter {
+                stream_id: read_u32_at(&payload, position)?,
+                topic_id: read_u32_at(&payload, position + 4)?,
+                max_delivery_count: read_u32_at(&payload, position + 8)?,
+            });
+            position += 12;
+        }
+    }
+    let mut visibility_timeout = None;
+    if features.visibility_timeout {
+        let micros = read_u64_at(&payload, position)?;
+        if micros > 0 {
+            visibility_timeout = Some(IggyDuration::from(micros));
+        }
+    }
     let consumer_group_details = ConsumerGroupDetails {
         id: consumer_group.id,
         name: consumer_group.name,
         partitions_count: consumer_group.partitions_count,
         members_count: consumer_group.members_count,
         members,
+        rebalances,
+        generation,
+        assignment_strategy,
+        offset_recovery,
+        dead_letter,
+        visibility_timeout,
     };
     Ok(consumer_group_details)
 }
 
+fn map_to_consumer_group_rebalance(
+    payload: &[u8],
+    position: usize,
+) -> Result<(ConsumerGroupRebalance, usize), IggyError> {
+    let timestamp = u64::from_le_bytes(
+        payload[position..position + 8]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    )
+    .into();
+    let trigger = RebalanceTrigger::from_code(payload[position + 8])?;
+    let duration = u64::from_le_bytes(
+        payload[position + 9..position + 17]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    )
+    .into();
+    let partitions_moved = u32::from_le_bytes(
+        payload[position + 17..position + 21]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let mut read_bytes = 21;
+    let (members_before, members_bytes) = map_to_member_ids(payload, position + read_bytes)?;
+    read_bytes += members_bytes;
+    let (members_after, members_bytes) = map_to_member_ids(payload, position + read_bytes)?;
+    read_bytes += members_bytes;
+    Ok((
+        ConsumerGroupRebalance {
+            timestamp,
+            trigger,
+            members_before,
+            members_after,
+            partitions_moved,
+            duration,
+        },
+        read_bytes,
+    ))
+}
+
+fn map_to_member_ids(payload: &[u8], position: usize) -> Result<(Vec<u32>, usize), IggyError> {
+    let count = u32::from_le_bytes(
+        payload[position..position + 4]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let mut members = Vec::with_capacity(count as usize);
+    let mut current = position + 4;
+    for _ in 0..count {
+        members.push(u32::from_le_bytes(
+            payload[current..current + 4]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        ));
+        current += 4;
+    }
+    Ok((members, current - position))
+}
+
 fn map_to_consumer_group(
     payload: Bytes,
     position: usize,
@@ -1019,6 +2003,8 @@ fn map_to_client_info(
     let transport = match transport {
         1 => "TCP",
         2 => "QUIC",
+        3 => "UDS",
+        4 => "WebSocket",
         _ => "Unknown",
     }
     .to_string();
@@ -1085,6 +2071,7 @@ fn map_to_user_info(payload: Bytes, position: usize) -> Result<(UserInfo, usize)
 fn map_to_pat_info(
     payload: Bytes,
     position: usize,
+    features: Handshake,
 ) -> Result<(PersonalAccessTokenInfo, usize), IggyError> {
     let name_length = payload[position];
     let name = from_utf8(&payload[position + 1..position + 1 + name_length as usize])
@@ -1100,6 +2087,788 @@ fn map_to_pat_info(
         0 => None,
         value => Some(value.into()),
     };
-    let read_bytes = 1 + name_length as usize + 8;
-    Ok((PersonalAccessTokenInfo { name, expiry_at }, read_bytes))
+    let (scope, scope_bytes) = if features.token_scope {
+        PersonalAccessTokenScope::from_bytes_at(&payload, position + 8)?
+    } else {
+        (PersonalAccessTokenScope::default(), 0)
+    };
+    let position = position + 8 + scope_bytes;
+    let mut last_used_at = None;
+    let mut last_used_bytes = 0;
+    if features.token_last_used {
+        last_used_at = match read_u64_at(&payload, position)? {
+            0 => None,
+            value => Some(value.into()),
+        };
+        last_used_bytes = 8;
+    }
+    let read_bytes = 1 + name_length as usize + 8 + scope_bytes + last_used_bytes;
+    Ok((
+        PersonalAccessTokenInfo {
+            name,
+            expiry_at,
+            scope,
+            last_used_at,
+        },
+        read_bytes,
+    ))
+}
+
+fn map_to_replay_job(payload: Bytes, position: usize) -> Result<(ReplayJob, usize), IggyError> {
+    let payload = payload
+        .get(position..position + 82)
+        .ok_or(IggyError::InvalidCommand)?;
+    let read_u32 = |offset: usize| -> Result<u32, IggyError> {
+        payload[offset..offset + 4]
+            .try_into()
+            .map(u32::from_le_bytes)
+            .map_err(|_| IggyError::InvalidNumberEncoding)
+    };
+    let read_u64 = |offset: usize| -> Result<u64, IggyError> {
+        payload[offset..offset + 8]
+            .try_into()
+            .map(u64::from_le_bytes)
+            .map_err(|_| IggyError::InvalidNumberEncoding)
+    };
+    let finished_at = match read_u64(74)? {
+        0 => None,
+        value => Some(value.into()),
+    };
+    let replay_job = ReplayJob {
+        id: read_u32(0)?,
+        user_id: read_u32(4)?,
+        status: ReplayJobStatus::from_code(payload[8])?,
+        source_stream_id: read_u32(9)?,
+        source_topic_id: read_u32(13)?,
+        source_partition_id: read_u32(17)?,
+        destination_stream_id: read_u32(21)?,
+        destination_topic_id: read_u32(25)?,
+        range: ReplayRange {
+            kind: ReplayRangeKind::from_code(payload[29])?,
+            start: read_u64(30)?,
+            end: read_u64(38)?,
+        },
+        current_offset: read_u64(46)?,
+        replayed_messages: read_u64(54)?,
+        messages_per_second: read_u32(62)?,
+        created_at: read_u64(66)?.into(),
+        finished_at,
+    };
+    Ok((replay_job, 82))
+}
+
+fn map_to_push_subscription(
+    payload: Bytes,
+    position: usize,
+) -> Result<(PushSubscription, usize), IggyError> {
+    let payload = payload.get(position..).ok_or(IggyError::InvalidCommand)?;
+    let read_u32 = |offset: usize| -> Result<u32, IggyError> {
+        payload
+            .get(offset..offset + 4)
+            .ok_or(IggyError::InvalidCommand)?
+            .try_into()
+            .map(u32::from_le_bytes)
+            .map_err(|_| IggyError::InvalidNumberEncoding)
+    };
+    let read_u64 = |offset: usize| -> Result<u64, IggyError> {
+        payload
+            .get(offset..offset + 8)
+            .ok_or(IggyError::InvalidCommand)?
+            .try_into()
+            .map(u64::from_le_bytes)
+            .map_err(|_| IggyError::InvalidNumberEncoding)
+    };
+    let read_string = |offset: usize, length: usize| -> Result<String, IggyError> {
+        let value = payload
+            .get(offset..offset + length)
+            .ok_or(IggyError::InvalidCommand)?;
+        Ok(from_utf8(value)
+            .map_err(|_| IggyError::InvalidUtf8)?
+            .to_string())
+    };
+
+    let status =
+        PushSubscriptionStatus::from_code(*payload.get(8).ok_or(IggyError::InvalidCommand)?)?;
+    let last_pushed_at = match read_u64(53)? {
+        0 => None,
+        value => Some(value.into()),
+    };
+    let endpoint_length = read_u32(61)? as usize;
+    let endpoint = read_string(65, endpoint_length)?;
+    let mut read_bytes = 65 + endpoint_length;
+    let last_error_length = read_u32(read_bytes)? as usize;
+    read_bytes += 4;
+    let last_error = match last_error_length {
+        0 => None,
+        _ => Some(read_string(read_bytes, last_error_length)?),
+    };
+    read_bytes += last_error_length;
+    let push_subscription = PushSubscription {
+        id: read_u32(0)?,
+        user_id: read_u32(4)?,
+        status,
+        stream_id: read_u32(9)?,
+        topic_id: read_u32(13)?,
+        partition_id: read_u32(17)?,
+        batch_size: read_u32(21)?,
+        next_offset: read_u64(25)?,
+        pushed_messages: read_u64(33)?,
+        failed_attempts: read_u32(41)?,
+        created_at: read_u64(45)?.into(),
+        last_pushed_at,
+        endpoint,
+        last_error,
+    };
+    Ok((push_subscription, read_bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::message_size_distribution::MESSAGE_SIZE_BUCKETS_COUNT;
+    use crate::models::personal_access_token::PersonalAccessTokenResource;
+    use crate::utils::timestamp::IggyTimestamp;
+    use bytes::{BufMut, BytesMut};
+
+    fn stream_bytes(id: u32, name: &str, bytes: &mut BytesMut) {
+        bytes.put_u32_le(id);
+        bytes.put_u64_le(1);
+        bytes.put_u32_le(0);
+        bytes.put_u64_le(100);
+        bytes.put_u64_le(10);
+        bytes.put_u8(name.len() as u8);
+        bytes.put_slice(name.as_bytes());
+    }
+
+    #[test]
+    fn streams_without_optional_features_should_be_mapped() {
+        let mut bytes = BytesMut::new();
+        stream_bytes(1, "prod", &mut bytes);
+        stream_bytes(2, "test", &mut bytes);
+
+        let streams = map_streams(bytes.freeze(), Handshake::default()).unwrap();
+
+        assert_eq!(streams.len(), 2);
+        assert_eq!(streams[0].name, "prod");
+        assert_eq!(streams[1].name, "test");
+        assert_eq!(streams[1].messages_count, 10);
+        assert_eq!(streams[1].metadata, ResourceMetadata::default());
+    }
+
+    #[test]
+    fn streams_with_resource_metadata_should_be_mapped() {
+        let metadata = ResourceMetadata {
+            description: Some("Production".to_string()),
+            owner: Some("payments".to_string()),
+            labels: [("env".to_string(), "prod".to_string())].into(),
+        };
+        let mut bytes = BytesMut::new();
+        stream_bytes(1, "prod", &mut bytes);
+        metadata.write_to_buffer(&mut bytes);
+
+        let features = Handshake {
+            resource_metadata: true,
+            ..Default::default()
+        };
+
+        let streams = map_streams(bytes.freeze(), features).unwrap();
+
+        assert_eq!(streams.len(), 1);
+        assert_eq!(streams[0].metadata, metadata);
+        assert_eq!(streams[0].quota, StreamQuota::default());
+    }
+
+    #[test]
+    fn streams_with_quota_should_be_mapped() {
+        let mut bytes = BytesMut::new();
+        stream_bytes(1, "prod", &mut bytes);
+        bytes.put_u64_le(1000);
+        bytes.put_u64_le(2000);
+        stream_bytes(2, "test", &mut bytes);
+        bytes.put_u64_le(0);
+        bytes.put_u64_le(0);
+
+        let features = Handshake {
+            stream_quota: true,
+            ..Default::default()
+        };
+
+        let streams = map_streams(bytes.freeze(), features).unwrap();
+
+        assert_eq!(streams.len(), 2);
+        assert_eq!(streams[0].metadata, ResourceMetadata::default());
+        assert_eq!(streams[0].quota.soft_limit.as_bytes_u64(), 1000);
+        assert_eq!(streams[0].quota.hard_limit.as_bytes_u64(), 2000);
+        assert_eq!(streams[1].name, "test");
+    }
+
+    #[test]
+    fn truncated_stream_should_not_be_mapped() {
+        let mut bytes = BytesMut::new();
+        stream_bytes(1, "prod", &mut bytes);
+        let bytes = bytes.freeze();
+
+        for length in [3, 20, 33, bytes.len() - 1] {
+            let result = map_streams(bytes.slice(..length), Handshake::default());
+            assert!(matches!(result, Err(IggyError::InvalidCommand)));
+        }
+    }
+
+    fn topic_bytes(id: u32, name: &str, bytes: &mut BytesMut) {
+        bytes.put_u32_le(id);
+        bytes.put_u64_le(1);
+        bytes.put_u32_le(1);
+        bytes.put_u64_le(0);
+        bytes.put_u8(CompressionAlgorithm::None.as_code());
+        bytes.put_u64_le(0);
+        bytes.put_u8(1);
+        bytes.put_u64_le(100);
+        bytes.put_u64_le(10);
+        bytes.put_u8(name.len() as u8);
+        bytes.put_slice(name.as_bytes());
+    }
+
+    fn partition_bytes(id: u32, bytes: &mut BytesMut) {
+        bytes.put_u32_le(id);
+        bytes.put_u64_le(1);
+        bytes.put_u32_le(1);
+        bytes.put_u64_le(9);
+        bytes.put_u64_le(100);
+        bytes.put_u64_le(10);
+    }
+
+    #[test]
+    fn topic_without_optional_features_should_be_mapped() {
+        let mut bytes = BytesMut::new();
+        topic_bytes(1, "orders", &mut bytes);
+        partition_bytes(1, &mut bytes);
+
+        let topic = map_topic(bytes.freeze(), Handshake::default()).unwrap();
+
+        assert_eq!(topic.name, "orders");
+        assert_eq!(topic.messages_count, 10);
+        assert_eq!(topic.metadata, ResourceMetadata::default());
+        assert_eq!(topic.partitions.len(), 1);
+        assert_eq!(topic.partitions[0].current_offset, 9);
+    }
+
+    #[test]
+    fn topic_with_message_id_scheme_should_be_mapped() {
+        let mut bytes = BytesMut::new();
+        topic_bytes(1, "orders", &mut bytes);
+        bytes.put_u8(MessageIdScheme::Ulid.as_code());
+        partition_bytes(1, &mut bytes);
+
+        let features = Handshake {
+            message_id_scheme: true,
+            ..Default::default()
+        };
+
+        let topic = map_topic(bytes.freeze(), features).unwrap();
+
+        assert_eq!(topic.message_id_scheme, MessageIdScheme::Ulid);
+        assert_eq!(topic.metadata, ResourceMetadata::default());
+        assert_eq!(topic.partitions.len(), 1);
+        assert_eq!(topic.partitions[0].current_offset, 9);
+    }
+
+    #[test]
+    fn topic_with_cleanup_policy_should_be_mapped() {
+        let mut bytes = BytesMut::new();
+        topic_bytes(1, "orders", &mut bytes);
+        bytes.put_u8(CleanupPolicy::Compact.as_code());
+        partition_bytes(1, &mut bytes);
+
+        let features = Handshake {
+            cleanup_policy: true,
+            ..Default::default()
+        };
+
+        let topic = map_topic(bytes.freeze(), features).unwrap();
+
+        assert_eq!(topic.cleanup_policy, CleanupPolicy::Compact);
+        assert_eq!(topic.message_id_scheme, MessageIdScheme::default());
+        assert_eq!(topic.partitions.len(), 1);
+    }
+
+    #[test]
+    fn topic_with_allowed_producers_should_be_mapped() {
+        let mut bytes = BytesMut::new();
+        topic_bytes(1, "orders", &mut bytes);
+        bytes.put_u32_le(2);
+        bytes.put_u32_le(3);
+        bytes.put_u32_le(5);
+        partition_bytes(1, &mut bytes);
+
+        let features = Handshake {
+            allowed_producers: true,
+            ..Default::default()
+        };
+
+        let topic = map_topic(bytes.freeze(), features).unwrap();
+
+        assert_eq!(topic.allowed_producers, vec![3, 5]);
+        assert_eq!(topic.partitions.len(), 1);
+        assert_eq!(topic.partitions[0].current_offset, 9);
+    }
+
+    #[test]
+    fn topic_with_deletion_time_should_be_mapped() {
+        let mut bytes = BytesMut::new();
+        topic_bytes(1, "orders", &mut bytes);
+        bytes.put_u64_le(5000);
+        partition_bytes(1, &mut bytes);
+
+        let features = Handshake {
+            topic_deletion_time: true,
+            ..Default::default()
+        };
+
+        let topic = map_topic(bytes.freeze(), features).unwrap();
+
+        assert_eq!(topic.delete_at, Some(5000.into()));
+        assert_eq!(topic.partitions.len(), 1);
+    }
+
+    #[test]
+    fn topic_with_max_segments_should_be_mapped() {
+        let mut bytes = BytesMut::new();
+        topic_bytes(1, "orders", &mut bytes);
+        bytes.put_u32_le(10);
+        partition_bytes(1, &mut bytes);
+
+        let features = Handshake {
+            max_segments: true,
+            ..Default::default()
+        };
+
+        let topic = map_topic(bytes.freeze(), features).unwrap();
+
+        assert_eq!(topic.max_segments, 10);
+        assert_eq!(topic.partitions.len(), 1);
+    }
+
+    #[test]
+    fn topic_with_partition_recovery_should_be_mapped() {
+        let mut bytes = BytesMut::new();
+        topic_bytes(1, "orders", &mut bytes);
+        partition_bytes(1, &mut bytes);
+        bytes.put_u8(1);
+        bytes.put_u64_le(1000);
+        bytes.put_u32_le(4);
+        bytes.put_u32_le(1);
+        bytes.put_u64_le(400);
+        bytes.put_u64_le(100);
+        bytes.put_u8(1);
+        bytes.put_u64_le(3000);
+        partition_bytes(2, &mut bytes);
+        bytes.put_u8(0);
+
+        let features = Handshake {
+            partition_recovery: true,
+            ..Default::default()
+        };
+
+        let topic = map_topic(bytes.freeze(), features).unwrap();
+
+        assert_eq!(topic.partitions.len(), 2);
+        let recovery = topic.partitions[0].recovery.as_ref().unwrap();
+        assert_eq!(recovery.total_segments, 4);
+        assert_eq!(recovery.recovered_segments, 1);
+        assert_eq!(recovery.eta, Some(IggyDuration::from(3000)));
+        assert!(topic.partitions[1].recovery.is_none());
+    }
+
+    #[test]
+    fn topic_with_config_should_be_mapped() {
+        let config = TopicConfig {
+            segment_size: Some(IggyByteSize::from(1000)),
+            cache_enabled: Some(false),
+            ..Default::default()
+        };
+        let mut bytes = BytesMut::new();
+        topic_bytes(1, "orders", &mut bytes);
+        config.write_to_buffer(&mut bytes);
+        partition_bytes(1, &mut bytes);
+
+        let features = Handshake {
+            topic_config: true,
+            ..Default::default()
+        };
+
+        let topic = map_topic(bytes.freeze(), features).unwrap();
+
+        assert_eq!(topic.config, config);
+        assert_eq!(topic.partitions.len(), 1);
+    }
+
+    #[test]
+    fn topic_with_message_sizes_should_be_mapped() {
+        let mut buckets_counts = [0; MESSAGE_SIZE_BUCKETS_COUNT];
+        buckets_counts[0] = 3;
+        buckets_counts[2] = 1;
+        let message_sizes = MessageSizeDistribution::new(buckets_counts, 400, 10, 200);
+        let mut bytes = BytesMut::new();
+        topic_bytes(1, "orders", &mut bytes);
+        message_sizes.write_to_buffer(&mut bytes);
+        partition_bytes(1, &mut bytes);
+
+        let features = Handshake {
+            message_sizes: true,
+            ..Default::default()
+        };
+
+        let topic = map_topic(bytes.freeze(), features).unwrap();
+
+        assert_eq!(topic.message_sizes, message_sizes);
+        assert_eq!(topic.partitions.len(), 1);
+    }
+
+    fn consumer_group_bytes(id: u32, name: &str, bytes: &mut BytesMut) {
+        bytes.put_u32_le(id);
+        bytes.put_u32_le(2);
+        bytes.put_u32_le(1);
+        bytes.put_u8(name.len() as u8);
+        bytes.put_slice(name.as_bytes());
+        bytes.put_u32_le(1);
+        bytes.put_u32_le(2);
+        bytes.put_u32_le(1);
+        bytes.put_u32_le(2);
+    }
+
+    #[test]
+    fn consumer_group_without_optional_features_should_be_mapped() {
+        let mut bytes = BytesMut::new();
+        consumer_group_bytes(1, "workers", &mut bytes);
+
+        let consumer_group = map_consumer_group(bytes.freeze(), Handshake::default()).unwrap();
+
+        assert_eq!(consumer_group.id, 1);
+        assert_eq!(consumer_group.name, "workers");
+        assert_eq!(consumer_group.members.len(), 1);
+        assert_eq!(consumer_group.members[0].partitions, vec![1, 2]);
+        assert!(consumer_group.rebalances.is_empty());
+    }
+
+    #[test]
+    fn consumer_group_with_rebalances_should_be_mapped() {
+        let mut bytes = BytesMut::new();
+        consumer_group_bytes(1, "workers", &mut bytes);
+        bytes.put_u32_le(1);
+        bytes.put_u64_le(1000);
+        bytes.put_u8(RebalanceTrigger::MemberJoined.as_code());
+        bytes.put_u64_le(50);
+        bytes.put_u32_le(2);
+        bytes.put_u32_le(0);
+        bytes.put_u32_le(1);
+        bytes.put_u32_le(1);
+
+        let features = Handshake {
+            consumer_group_rebalances: true,
+            ..Default::default()
+        };
+
+        let consumer_group = map_consumer_group(bytes.freeze(), features).unwrap();
+
+        assert_eq!(consumer_group.members.len(), 1);
+        assert_eq!(consumer_group.rebalances.len(), 1);
+        let rebalance = &consumer_group.rebalances[0];
+        assert_eq!(rebalance.trigger, RebalanceTrigger::MemberJoined);
+        assert_eq!(rebalance.partitions_moved, 2);
+        assert!(rebalance.members_before.is_empty());
+        assert_eq!(rebalance.members_after, vec![1]);
+    }
+
+    #[test]
+    fn consumer_group_with_assignment_should_be_mapped() {
+        let mut bytes = BytesMut::new();
+        consumer_group_bytes(1, "workers", &mut bytes);
+        bytes.put_u32_le(3);
+        bytes.put_u8(PartitionAssignmentStrategy::Sticky.as_code());
+
+        let features = Handshake {
+            consumer_group_assignment: true,
+            ..Default::default()
+        };
+
+        let consumer_group = map_consumer_group(bytes.freeze(), features).unwrap();
+
+        assert_eq!(consumer_group.members.len(), 1);
+        assert_eq!(consumer_group.generation, 3);
+        assert_eq!(
+            consumer_group.assignment_strategy,
+            PartitionAssignmentStrategy::Sticky
+        );
+    }
+
+    #[test]
+    fn consumer_group_with_offset_recovery_should_be_mapped() {
+        let mut bytes = BytesMut::new();
+        consumer_group_bytes(1, "workers", &mut bytes);
+        bytes.put_u8(OffsetRecoveryPolicy::Earliest.as_code());
+
+        let features = Handshake {
+            offset_recovery: true,
+            ..Default::default()
+        };
+
+        let consumer_group = map_consumer_group(bytes.freeze(), features).unwrap();
+
+        assert_eq!(consumer_group.members.len(), 1);
+        assert_eq!(
+            consumer_group.offset_recovery,
+            OffsetRecoveryPolicy::Earliest
+        );
+    }
+
+    #[test]
+    fn consumer_group_with_dead_letter_should_be_mapped() {
+        let mut bytes = BytesMut::new();
+        consumer_group_bytes(1, "workers", &mut bytes);
+        bytes.put_u8(1);
+        bytes.put_u32_le(2);
+        bytes.put_u32_le(3);
+        bytes.put_u32_le(5);
+
+        let features = Handshake {
+            dead_letter: true,
+            ..Default::default()
+        };
+
+        let consumer_group = map_consumer_group(bytes.freeze(), features).unwrap();
+
+        assert_eq!(consumer_group.members.len(), 1);
+        let dead_letter = consumer_group.dead_letter.unwrap();
+        assert_eq!(dead_letter.stream_id, 2);
+        assert_eq!(dead_letter.topic_id, 3);
+        assert_eq!(dead_letter.max_delivery_count, 5);
+    }
+
+    #[test]
+    fn consumer_group_with_visibility_timeout_should_be_mapped() {
+        let mut bytes = BytesMut::new();
+        consumer_group_bytes(1, "workers", &mut bytes);
+        bytes.put_u64_le(30_000_000);
+
+        let features = Handshake {
+            visibility_timeout: true,
+            ..Default::default()
+        };
+
+        let consumer_group = map_consumer_group(bytes.freeze(), features).unwrap();
+
+        assert_eq!(consumer_group.members.len(), 1);
+        assert_eq!(
+            consumer_group.visibility_timeout,
+            Some(IggyDuration::from(30_000_000))
+        );
+    }
+
+    fn pat_bytes(name: &str, expiry_at: u64, bytes: &mut BytesMut) {
+        bytes.put_u8(name.len() as u8);
+        bytes.put_slice(name.as_bytes());
+        bytes.put_u64_le(expiry_at);
+    }
+
+    #[test]
+    fn personal_access_tokens_without_optional_features_should_be_mapped() {
+        let mut bytes = BytesMut::new();
+        pat_bytes("deploy", 0, &mut bytes);
+        pat_bytes("backup", 1000, &mut bytes);
+
+        let tokens = map_personal_access_tokens(bytes.freeze(), Handshake::default()).unwrap();
+
+        assert_eq!(tokens.len(), 2);
+        assert_eq!(tokens[0].name, "backup");
+        assert_eq!(tokens[0].expiry_at, Some(IggyTimestamp::from(1000)));
+        assert_eq!(tokens[1].name, "deploy");
+        assert!(tokens[1].expiry_at.is_none());
+        assert_eq!(tokens[1].scope, PersonalAccessTokenScope::default());
+    }
+
+    #[test]
+    fn personal_access_tokens_with_scope_should_be_mapped() {
+        let scope = PersonalAccessTokenScope {
+            read_only: true,
+            resources: vec![PersonalAccessTokenResource {
+                stream_id: 1,
+                topic_id: Some(2),
+            }],
+            allowed_ip_ranges: Vec::new(),
+        };
+        let mut bytes = BytesMut::new();
+        pat_bytes("deploy", 0, &mut bytes);
+        scope.write_to_buffer(&mut bytes);
+        pat_bytes("backup", 0, &mut bytes);
+        PersonalAccessTokenScope::default().write_to_buffer(&mut bytes);
+
+        let features = Handshake {
+            token_scope: true,
+            ..Default::default()
+        };
+
+        let tokens = map_personal_access_tokens(bytes.freeze(), features).unwrap();
+
+        assert_eq!(tokens.len(), 2);
+        assert_eq!(tokens[0].scope, PersonalAccessTokenScope::default());
+        assert_eq!(tokens[1].scope, scope);
+    }
+
+    #[test]
+    fn personal_access_tokens_with_last_used_time_should_be_mapped() {
+        let mut bytes = BytesMut::new();
+        pat_bytes("deploy", 0, &mut bytes);
+        bytes.put_u64_le(2000);
+        pat_bytes("backup", 0, &mut bytes);
+        bytes.put_u64_le(0);
+
+        let features = Handshake {
+            token_last_used: true,
+            ..Default::default()
+        };
+
+        let tokens = map_personal_access_tokens(bytes.freeze(), features).unwrap();
+
+        assert_eq!(tokens.len(), 2);
+        assert!(tokens[0].last_used_at.is_none());
+        assert_eq!(tokens[1].last_used_at, Some(IggyTimestamp::from(2000)));
+    }
+
+    #[test]
+    fn truncated_topic_should_not_be_mapped() {
+        let mut bytes = BytesMut::new();
+        topic_bytes(1, "orders", &mut bytes);
+        partition_bytes(1, &mut bytes);
+        let bytes = bytes.freeze();
+
+        for length in [3, 24, 50, 53, bytes.len() - 1] {
+            let result = map_topic(bytes.slice(..length), Handshake::default());
+            assert!(matches!(result, Err(IggyError::InvalidCommand)));
+        }
+    }
+
+    fn polled_messages_bytes(features: Handshake, offsets: &[u64]) -> Bytes {
+        let mut bytes = BytesMut::new();
+        bytes.put_u32_le(1);
+        bytes.put_u64_le(offsets.last().copied().unwrap_or_default());
+        if features.remaining_messages {
+            bytes.put_u64_le(7);
+        }
+        bytes.put_u32_le(offsets.len() as u32);
+        if features.message_gaps {
+            bytes.put_u32_le(1);
+            bytes.put_u64_le(1);
+            bytes.put_u64_le(2);
+            bytes.put_u8(MessagesGapReason::Compacted.as_code());
+        }
+        if features.throttle_time {
+            bytes.put_u32_le(250);
+        }
+        if features.topic_deletion_time {
+            bytes.put_u64_le(5000);
+        }
+        for offset in offsets {
+            let payload = format!("message-{offset}");
+            bytes.put_u64_le(*offset);
+            bytes.put_u8(MessageState::Available.as_code());
+            bytes.put_u64_le(1000 + offset);
+            bytes.put_u128_le(*offset as u128);
+            bytes.put_u32_le(0);
+            bytes.put_u32_le(0);
+            bytes.put_u32_le(payload.len() as u32);
+            bytes.put_slice(payload.as_bytes());
+        }
+        bytes.freeze()
+    }
+
+    #[test]
+    fn polled_messages_without_optional_features_should_be_mapped() {
+        let features = Handshake::default();
+        let bytes = polled_messages_bytes(features, &[3, 4]);
+
+        let polled_messages = map_polled_messages(bytes, features, false, false).unwrap();
+
+        assert_eq!(polled_messages.partition_id, 1);
+        assert_eq!(polled_messages.current_offset, 4);
+        assert_eq!(polled_messages.remaining_messages, 0);
+        assert!(polled_messages.gaps.is_empty());
+        assert_eq!(polled_messages.messages.len(), 2);
+        assert_eq!(polled_messages.messages[0].offset, 3);
+        assert_eq!(polled_messages.messages[1].payload, "message-4".as_bytes());
+    }
+
+    #[test]
+    fn polled_messages_with_remaining_messages_should_be_mapped() {
+        let features = Handshake {
+            remaining_messages: true,
+            ..Default::default()
+        };
+        let bytes = polled_messages_bytes(features, &[3, 4]);
+
+        let polled_messages = map_polled_messages(bytes, features, false, false).unwrap();
+
+        assert_eq!(polled_messages.current_offset, 4);
+        assert_eq!(polled_messages.remaining_messages, 7);
+        assert_eq!(polled_messages.messages.len(), 2);
+        assert_eq!(polled_messages.messages[0].offset, 3);
+        assert_eq!(polled_messages.messages[1].payload, "message-4".as_bytes());
+    }
+
+    #[test]
+    fn polled_messages_with_gaps_should_be_mapped() {
+        let features = Handshake {
+            remaining_messages: true,
+            message_gaps: true,
+            ..Default::default()
+        };
+        let bytes = polled_messages_bytes(features, &[3, 4]);
+
+        let polled_messages = map_polled_messages(bytes, features, false, false).unwrap();
+
+        assert_eq!(polled_messages.remaining_messages, 7);
+        assert_eq!(
+            polled_messages.gaps,
+            vec![MessagesGap {
+                start_offset: 1,
+                end_offset: 2,
+                reason: MessagesGapReason::Compacted,
+            }]
+        );
+        assert_eq!(polled_messages.messages.len(), 2);
+        assert_eq!(polled_messages.messages[0].offset, 3);
+    }
+
+    #[test]
+    fn polled_messages_with_throttle_time_should_be_mapped_without_partition_epoch() {
+        let features = Handshake {
+            throttle_time: true,
+            ..Default::default()
+        };
+        let bytes = polled_messages_bytes(features, &[3, 4]);
+
+        let polled_messages = map_polled_messages(bytes, features, false, false).unwrap();
+
+        assert_eq!(polled_messages.throttle_time_ms, 250);
+        assert_eq!(polled_messages.partition_epoch, 0);
+        assert_eq!(polled_messages.messages.len(), 2);
+        assert_eq!(polled_messages.messages[1].payload, "message-4".as_bytes());
+    }
+
+    #[test]
+    fn polled_messages_with_topic_deletion_time_should_be_mapped_without_partition_epoch() {
+        let features = Handshake {
+            throttle_time: true,
+            topic_deletion_time: true,
+            ..Default::default()
+        };
+        let bytes = polled_messages_bytes(features, &[3, 4]);
+
+        let polled_messages = map_polled_messages(bytes, features, false, false).unwrap();
+
+        assert_eq!(polled_messages.throttle_time_ms, 250);
+        assert_eq!(polled_messages.topic_delete_at, 5000);
+        assert_eq!(polled_messages.partition_epoch, 0);
+        assert_eq!(polled_messages.messages.len(), 2);
+        assert_eq!(polled_messages.messages[1].payload, "message-4".as_bytes());
+    }
 }
@@ -19,15 +19,39 @@
 use crate::binary::binary_client::BinaryClient;
 use crate::binary::{fail_if_not_authenticated, mapper};
 use crate::client::MessageClient;
-use crate::command::{POLL_MESSAGES_CODE, SEND_MESSAGES_CODE};
+use crate::command::{POLL_MESSAGES_CODE, SEND_MESSAGES_BATCH_CODE, SEND_MESSAGES_CODE};
 use crate::consumer::Consumer;
 use crate::error::IggyError;
 use crate::identifier::Identifier;
+use crate::messages::abort_transaction::AbortTransaction;
+use crate::messages::ack_messages::AckMessages;
+use crate::messages::aggregate_messages::AggregateMessages;
+use crate::messages::begin_transaction::BeginTransaction;
+use crate::messages::cancel_replay_job::CancelReplayJob;
+use crate::messages::commit_transaction::CommitTransaction;
+use crate::messages::create_push_subscription::CreatePushSubscription;
+use crate::messages::delete_push_subscription::DeletePushSubscription;
 use crate::messages::flush_unsaved_buffer::FlushUnsavedBuffer;
-use crate::messages::poll_messages::PollingStrategy;
+use crate::messages::get_push_subscriptions::GetPushSubscriptions;
+use crate::messages::get_replay_jobs::GetReplayJobs;
+use crate::messages::init_producer_id::InitProducerId;
+use crate::messages::message_filter::MessageFilter;
+use crate::messages::nack_messages::NackMessages;
+use crate::messages::poll_messages::{IsolationLevel, PollMessagesOptions, PollingStrategy};
+use crate::messages::register_producer::RegisterProducer;
+use crate::messages::replay_messages::{HeadersTransform, ReplayMessages, ReplayRange};
 use crate::messages::send_messages::{Message, Partitioning};
-use crate::messages::{poll_messages, send_messages};
+use crate::messages::send_messages_batch::TopicMessages;
+use crate::messages::{poll_messages, send_messages, send_messages_batch};
 use crate::models::messages::PolledMessages;
+use crate::models::messages_aggregate::MessagesBucket;
+use crate::models::producer_epoch::ProducerEpoch;
+use crate::models::producer_session::ProducerSession;
+use crate::models::push_subscription::PushSubscription;
+use crate::models::replay_job::ReplayJob;
+use crate::models::transaction::Transaction;
+use crate::utils::duration::IggyDuration;
+use crate::utils::timestamp::IggyTimestamp;
 
 #[async_trait::async_trait]
 impl<B: BinaryClient> MessageClient for B {
@@ -40,6 +64,30 @@ impl<B: BinaryClient> MessageClient for B {
         strategy: &PollingStrategy,
         count: u32,
         auto_commit: bool,
+    ) -> Result<PolledMessages, IggyError> {
+        self.poll_messages_with_options(
+            stream_id,
+            topic_id,
+            partition_id,
+            consumer,
+            strategy,
+            count,
+            auto_commit,
+            &PollMessagesOptions::default(),
+        )
+        .await
+    }
+
+    async fn poll_messages_with_options(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        auto_commit: bool,
+        options: &PollMessagesOptions,
     ) -> Result<PolledMessages, IggyError> {
         fail_if_not_authenticated(self).await?;
         let response = self
@@ -53,10 +101,78 @@ impl<B: BinaryClient> MessageClient for B {
                     strategy,
                     count,
                     auto_commit,
+                    options.isolation_level,
+                    options.chunk_size,
+                    options.filter.as_ref(),
+                    options.partition_epoch,
+                    false,
+                ),
+            )
+            .await?;
+        mapper::map_polled_messages(
+            response,
+            self.get_protocol_features(),
+            options.chunk_size > 0,
+            options.partition_epoch.is_some(),
+        )
+    }
+
+    async fn peek_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        isolation_level: IsolationLevel,
+        filter: Option<&MessageFilter>,
+    ) -> Result<PolledMessages, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_raw_with_response(
+                POLL_MESSAGES_CODE,
+                poll_messages::as_bytes(
+                    stream_id,
+                    topic_id,
+                    partition_id,
+                    consumer,
+                    strategy,
+                    count,
+                    false,
+                    isolation_level,
+                    0,
+                    filter,
+                    None,
+                    true,
                 ),
             )
             .await?;
-        mapper::map_polled_messages(response)
+        // The partition epoch always precedes the peek flag, so it's returned along with the messages.
+        mapper::map_polled_messages(response, self.get_protocol_features(), false, true)
+    }
+
+    async fn aggregate_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        from: IggyTimestamp,
+        to: IggyTimestamp,
+        bucket: IggyDuration,
+    ) -> Result<Vec<MessagesBucket>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&AggregateMessages {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                partition_id,
+                from,
+                to,
+                bucket,
+            })
+            .await?;
+        mapper::map_messages_buckets(response)
     }
 
     async fn send_messages(
@@ -75,6 +191,17 @@ impl<B: BinaryClient> MessageClient for B {
         Ok(())
     }
 
+    async fn send_messages_batch(&self, batches: &mut [TopicMessages]) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        send_messages_batch::validate_batches(batches)?;
+        self.send_raw_with_response(
+            SEND_MESSAGES_BATCH_CODE,
+            send_messages_batch::as_bytes(batches),
+        )
+        .await?;
+        Ok(())
+    }
+
     async fn flush_unsaved_buffer(
         &self,
         stream_id: &Identifier,
@@ -92,4 +219,176 @@ impl<B: BinaryClient> MessageClient for B {
         .await?;
         Ok(())
     }
+
+    async fn nack_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        offsets: &[u64],
+        reason: &str,
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&NackMessages {
+            consumer: consumer.clone(),
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            partition_id,
+            offsets: offsets.to_vec(),
+            reason: reason.to_string(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn ack_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        offsets: &[u64],
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&AckMessages {
+            consumer: consumer.clone(),
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            partition_id,
+            offsets: offsets.to_vec(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn replay_messages(
+        &self,
+        source_stream_id: &Identifier,
+        source_topic_id: &Identifier,
+        source_partition_id: u32,
+        range: &ReplayRange,
+        destination_stream_id: &Identifier,
+        destination_topic_id: &Identifier,
+        partitioning: &Partitioning,
+        headers: &HeadersTransform,
+        messages_per_second: u32,
+    ) -> Result<ReplayJob, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&ReplayMessages {
+                source_stream_id: source_stream_id.clone(),
+                source_topic_id: source_topic_id.clone(),
+                source_partition_id,
+                range: *range,
+                destination_stream_id: destination_stream_id.clone(),
+                destination_topic_id: destination_topic_id.clone(),
+                partitioning: partitioning.clone(),
+                headers: headers.clone(),
+                messages_per_second,
+                ..Default::default()
+            })
+            .await?;
+        mapper::map_replay_job(response)
+    }
+
+    async fn get_replay_jobs(&self) -> Result<Vec<ReplayJob>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self.send_with_response(&GetReplayJobs {}).await?;
+        mapper::map_replay_jobs(response)
+    }
+
+    async fn cancel_replay_job(&self, job_id: u32) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&CancelReplayJob { job_id }).await?;
+        Ok(())
+    }
+
+    async fn create_push_subscription(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        endpoint: &str,
+        start_offset: u64,
+        batch_size: u32,
+    ) -> Result<PushSubscription, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&CreatePushSubscription {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                partition_id,
+                endpoint: endpoint.to_string(),
+                start_offset,
+                batch_size,
+            })
+            .await?;
+        mapper::map_push_subscription(response)
+    }
+
+    async fn get_push_subscriptions(&self) -> Result<Vec<PushSubscription>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self.send_with_response(&GetPushSubscriptions {}).await?;
+        mapper::map_push_subscriptions(response)
+    }
+
+    async fn delete_push_subscription(&self, subscription_id: u32) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&DeletePushSubscription { subscription_id })
+            .await?;
+        Ok(())
+    }
+
+    async fn register_producer(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        name: &str,
+    ) -> Result<ProducerEpoch, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&RegisterProducer {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                name: name.to_string(),
+            })
+            .await?;
+        mapper::map_producer_epoch(response)
+    }
+
+    async fn init_producer_id(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<ProducerSession, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&InitProducerId {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+            })
+            .await?;
+        mapper::map_producer_session(response)
+    }
+
+    async fn begin_transaction(&self) -> Result<Transaction, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self.send_with_response(&BeginTransaction {}).await?;
+        mapper::map_transaction(response)
+    }
+
+    async fn commit_transaction(&self, transaction_id: u64) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&CommitTransaction { transaction_id })
+            .await?;
+        Ok(())
+    }
+
+    async fn abort_transaction(&self, transaction_id: u64) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&AbortTransaction { transaction_id })
+            .await?;
+        Ok(())
+    }
 }
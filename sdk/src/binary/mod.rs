@@ -19,6 +19,7 @@
 use crate::command::Command;
 use crate::diagnostic::DiagnosticEvent;
 use crate::error::IggyError;
+use crate::system::handshake::Handshake;
 use crate::utils::duration::IggyDuration;
 use async_trait::async_trait;
 use bytes::Bytes;
@@ -30,13 +31,16 @@ pub mod binary_client;
 pub mod consumer_groups;
 #[allow(deprecated)]
 pub mod consumer_offsets;
-mod mapper;
+pub(crate) mod mapper;
 #[allow(deprecated)]
 pub mod messages;
 #[allow(deprecated)]
+pub mod namespaces;
+#[allow(deprecated)]
 pub mod partitions;
 #[allow(deprecated)]
 pub mod personal_access_tokens;
+pub mod service;
 #[allow(deprecated)]
 pub mod streams;
 #[allow(deprecated)]
@@ -80,6 +84,10 @@ pub trait BinaryTransport {
     async fn send_with_response<T: Command>(&self, command: &T) -> Result<Bytes, IggyError>;
     async fn send_raw_with_response(&self, code: u32, payload: Bytes) -> Result<Bytes, IggyError>;
     fn get_heartbeat_interval(&self) -> IggyDuration;
+    /// Gets the optional features of the binary protocol negotiated with the server.
+    fn get_protocol_features(&self) -> Handshake {
+        Handshake::default()
+    }
 }
 
 async fn fail_if_not_authenticated<T: BinaryTransport>(transport: &T) -> Result<(), IggyError> {
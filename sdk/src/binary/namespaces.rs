@@ -0,0 +1,139 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::binary_client::BinaryClient;
+use crate::binary::{fail_if_not_authenticated, mapper};
+use crate::client::NamespaceClient;
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::models::metadata::ResourceMetadata;
+use crate::models::namespace::{Namespace, NamespaceDetails, NamespaceQuotas};
+use crate::models::permissions::Permissions;
+use crate::models::stream::StreamDetails;
+use crate::models::user_info::UserInfoDetails;
+use crate::models::user_status::UserStatus;
+use crate::namespaces::create_namespace::CreateNamespace;
+use crate::namespaces::delete_namespace::DeleteNamespace;
+use crate::namespaces::get_namespace::GetNamespace;
+use crate::namespaces::get_namespaces::GetNamespaces;
+use crate::namespaces::update_namespace::UpdateNamespace;
+use crate::streams::create_stream::CreateStream;
+use crate::users::create_user::CreateUser;
+
+#[async_trait::async_trait]
+impl<B: BinaryClient> NamespaceClient for B {
+    async fn get_namespace(
+        &self,
+        namespace_id: &Identifier,
+    ) -> Result<Option<NamespaceDetails>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&GetNamespace {
+                namespace_id: namespace_id.clone(),
+            })
+            .await?;
+        if response.is_empty() {
+            return Ok(None);
+        }
+
+        mapper::map_namespace(response).map(Some)
+    }
+
+    async fn get_namespaces(&self) -> Result<Vec<Namespace>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self.send_with_response(&GetNamespaces {}).await?;
+        mapper::map_namespaces(response)
+    }
+
+    async fn create_namespace(
+        &self,
+        name: &str,
+        quotas: NamespaceQuotas,
+    ) -> Result<NamespaceDetails, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&CreateNamespace {
+                name: name.to_string(),
+                quotas,
+            })
+            .await?;
+        mapper::map_namespace(response)
+    }
+
+    async fn update_namespace(
+        &self,
+        namespace_id: &Identifier,
+        quotas: NamespaceQuotas,
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&UpdateNamespace {
+            namespace_id: namespace_id.clone(),
+            quotas,
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn delete_namespace(&self, namespace_id: &Identifier) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&DeleteNamespace {
+            namespace_id: namespace_id.clone(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn create_namespace_stream(
+        &self,
+        namespace_id: u32,
+        name: &str,
+        stream_id: Option<u32>,
+    ) -> Result<StreamDetails, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&CreateStream {
+                name: name.to_string(),
+                stream_id,
+                metadata: ResourceMetadata::default(),
+                namespace_id: Some(namespace_id),
+            })
+            .await?;
+        mapper::map_stream(response, self.get_protocol_features())
+    }
+
+    async fn create_namespace_user(
+        &self,
+        namespace_id: u32,
+        username: &str,
+        password: &str,
+        status: UserStatus,
+        permissions: Option<Permissions>,
+    ) -> Result<UserInfoDetails, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&CreateUser {
+                username: username.to_string(),
+                password: password.to_string(),
+                status,
+                permissions,
+                namespace_id: Some(namespace_id),
+            })
+            .await?;
+        mapper::map_user(response)
+    }
+}
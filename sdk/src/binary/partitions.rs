@@ -18,12 +18,18 @@
 
 #[allow(deprecated)]
 use crate::binary::binary_client::BinaryClient;
-use crate::binary::fail_if_not_authenticated;
+use crate::binary::{fail_if_not_authenticated, mapper};
 use crate::client::PartitionClient;
 use crate::error::IggyError;
 use crate::identifier::Identifier;
+use crate::models::restored_segments::RestoredSegments;
+use crate::models::segment_range::SegmentRange;
+use crate::models::unsaved_state::PartitionUnsavedState;
 use crate::partitions::create_partitions::CreatePartitions;
 use crate::partitions::delete_partitions::DeletePartitions;
+use crate::partitions::flush_partition::FlushPartition;
+use crate::partitions::read_segment_range::ReadSegmentRange;
+use crate::partitions::restore_archived_segments::RestoreArchivedSegments;
 
 #[async_trait::async_trait]
 impl<B: BinaryClient> PartitionClient for B {
@@ -58,4 +64,67 @@ impl<B: BinaryClient> PartitionClient for B {
         .await?;
         Ok(())
     }
+
+    async fn restore_archived_segments(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        start_offset: u64,
+        end_offset: u64,
+    ) -> Result<RestoredSegments, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&RestoreArchivedSegments {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                partition_id,
+                start_offset,
+                end_offset,
+            })
+            .await?;
+        mapper::map_restored_segments(response)
+    }
+
+    async fn flush_partition(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+    ) -> Result<Vec<PartitionUnsavedState>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&FlushPartition {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                partition_id,
+            })
+            .await?;
+        mapper::map_unsaved_state(response)
+    }
+
+    async fn read_segment_range(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        offset: u64,
+        start_position: u64,
+        end_position: Option<u64>,
+        max_bytes: u32,
+    ) -> Result<SegmentRange, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&ReadSegmentRange {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                partition_id,
+                offset,
+                start_position,
+                end_position,
+                max_bytes,
+            })
+            .await?;
+        mapper::map_segment_range(response)
+    }
 }
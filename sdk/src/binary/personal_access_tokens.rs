@@ -21,11 +21,14 @@ use crate::binary::{fail_if_not_authenticated, mapper, ClientState};
 use crate::client::PersonalAccessTokenClient;
 use crate::error::IggyError;
 use crate::models::identity_info::IdentityInfo;
-use crate::models::personal_access_token::{PersonalAccessTokenInfo, RawPersonalAccessToken};
+use crate::models::personal_access_token::{
+    PersonalAccessTokenInfo, PersonalAccessTokenScope, RawPersonalAccessToken,
+};
 use crate::personal_access_tokens::create_personal_access_token::CreatePersonalAccessToken;
 use crate::personal_access_tokens::delete_personal_access_token::DeletePersonalAccessToken;
 use crate::personal_access_tokens::get_personal_access_tokens::GetPersonalAccessTokens;
 use crate::personal_access_tokens::login_with_personal_access_token::LoginWithPersonalAccessToken;
+use crate::personal_access_tokens::rotate_personal_access_token::RotatePersonalAccessToken;
 use crate::utils::personal_access_token_expiry::PersonalAccessTokenExpiry;
 
 #[async_trait::async_trait]
@@ -33,19 +36,30 @@ impl<B: BinaryClient> PersonalAccessTokenClient for B {
     async fn get_personal_access_tokens(&self) -> Result<Vec<PersonalAccessTokenInfo>, IggyError> {
         fail_if_not_authenticated(self).await?;
         let response = self.send_with_response(&GetPersonalAccessTokens {}).await?;
-        mapper::map_personal_access_tokens(response)
+        mapper::map_personal_access_tokens(response, self.get_protocol_features())
     }
 
     async fn create_personal_access_token(
         &self,
         name: &str,
         expiry: PersonalAccessTokenExpiry,
+    ) -> Result<RawPersonalAccessToken, IggyError> {
+        self.create_scoped_personal_access_token(name, expiry, &PersonalAccessTokenScope::default())
+            .await
+    }
+
+    async fn create_scoped_personal_access_token(
+        &self,
+        name: &str,
+        expiry: PersonalAccessTokenExpiry,
+        scope: &PersonalAccessTokenScope,
     ) -> Result<RawPersonalAccessToken, IggyError> {
         fail_if_not_authenticated(self).await?;
         let response = self
             .send_with_response(&CreatePersonalAccessToken {
                 name: name.to_string(),
                 expiry,
+                scope: scope.clone(),
             })
             .await?;
         mapper::map_raw_pat(response)
@@ -60,6 +74,21 @@ impl<B: BinaryClient> PersonalAccessTokenClient for B {
         Ok(())
     }
 
+    async fn rotate_personal_access_token(
+        &self,
+        name: &str,
+        expiry: PersonalAccessTokenExpiry,
+    ) -> Result<RawPersonalAccessToken, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&RotatePersonalAccessToken {
+                name: name.to_string(),
+                expiry,
+            })
+            .await?;
+        mapper::map_raw_pat(response)
+    }
+
     async fn login_with_personal_access_token(
         &self,
         token: &str,
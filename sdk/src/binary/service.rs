@@ -0,0 +1,414 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::binary_client::BinaryClient;
+use crate::binary::{BinaryTransport, ClientState};
+use crate::client::Client;
+use crate::command::Command;
+use crate::diagnostic::DiagnosticEvent;
+use crate::error::IggyError;
+use crate::system::handshake::Handshake;
+use crate::utils::duration::IggyDuration;
+use async_broadcast::{broadcast, Receiver, Sender};
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures::future::poll_fn;
+use std::error::Error;
+use std::fmt::Debug;
+use std::future::Future;
+use std::pin::Pin;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use tokio::sync::Mutex;
+use tower_layer::Layer;
+use tower_service::Service;
+use tracing::error;
+
+/// The raw binary request (command code and payload) handled by the transport services.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BinaryRequest {
+    pub code: u32,
+    pub payload: Bytes,
+}
+
+impl BinaryRequest {
+    /// Creates a new binary request for the provided command code and payload.
+    pub fn new(code: u32, payload: Bytes) -> Self {
+        Self { code, payload }
+    }
+
+    /// Creates a new binary request from the provided command, after validating it.
+    pub fn from_command<T: Command>(command: &T) -> Result<Self, IggyError> {
+        command.validate()?;
+        Ok(Self::new(command.code(), command.to_bytes()))
+    }
+}
+
+/// The connection lifecycle (connecting, state, diagnostic events) used by the `ServiceClient`,
+/// independent of the service which sends the requests.
+#[async_trait]
+pub trait Connection: Send + Sync + Debug {
+    /// Connects to the server.
+    async fn connect(&self) -> Result<(), IggyError>;
+    /// Disconnects from the server.
+    async fn disconnect(&self) -> Result<(), IggyError>;
+    /// Shuts down the connection and releases all the resources.
+    async fn shutdown(&self) -> Result<(), IggyError>;
+    /// Gets the state of the connection.
+    async fn get_state(&self) -> ClientState;
+    /// Sets the state of the connection.
+    async fn set_state(&self, state: ClientState);
+    /// Publishes the diagnostic event.
+    async fn publish_event(&self, event: DiagnosticEvent);
+    /// Subscribes to the diagnostic events.
+    async fn subscribe_events(&self) -> Receiver<DiagnosticEvent>;
+    /// Gets the heartbeat interval.
+    fn get_heartbeat_interval(&self) -> IggyDuration;
+    /// Gets the optional features of the binary protocol negotiated with the server.
+    fn get_protocol_features(&self) -> Handshake {
+        Handshake::default()
+    }
+}
+
+/// The connection without any underlying network transport, e.g. for the in-memory services used in tests.
+#[derive(Debug)]
+pub struct LocalConnection {
+    state: Mutex<ClientState>,
+    events: (Sender<DiagnosticEvent>, Receiver<DiagnosticEvent>),
+    heartbeat_interval: IggyDuration,
+}
+
+impl Default for LocalConnection {
+    fn default() -> Self {
+        Self::new(IggyDuration::from_str("5s").unwrap())
+    }
+}
+
+impl LocalConnection {
+    /// Creates a new local connection with the provided heartbeat interval.
+    pub fn new(heartbeat_interval: IggyDuration) -> Self {
+        Self {
+            state: Mutex::new(ClientState::Disconnected),
+            events: broadcast(1000),
+            heartbeat_interval,
+        }
+    }
+}
+
+#[async_trait]
+impl Connection for LocalConnection {
+    async fn connect(&self) -> Result<(), IggyError> {
+        let mut state = self.state.lock().await;
+        if *state == ClientState::Shutdown {
+            return Err(IggyError::ClientShutdown);
+        }
+
+        if *state == ClientState::Disconnected {
+            *state = ClientState::Connected;
+            drop(state);
+            self.publish_event(DiagnosticEvent::Connected).await;
+        }
+        Ok(())
+    }
+
+    async fn disconnect(&self) -> Result<(), IggyError> {
+        let mut state = self.state.lock().await;
+        if *state == ClientState::Disconnected || *state == ClientState::Shutdown {
+            return Ok(());
+        }
+
+        *state = ClientState::Disconnected;
+        drop(state);
+        self.publish_event(DiagnosticEvent::Disconnected).await;
+        Ok(())
+    }
+
+    async fn shutdown(&self) -> Result<(), IggyError> {
+        *self.state.lock().await = ClientState::Shutdown;
+        self.publish_event(DiagnosticEvent::Shutdown).await;
+        Ok(())
+    }
+
+    async fn get_state(&self) -> ClientState {
+        *self.state.lock().await
+    }
+
+    async fn set_state(&self, state: ClientState) {
+        *self.state.lock().await = state;
+    }
+
+    async fn publish_event(&self, event: DiagnosticEvent) {
+        if let Err(error) = self.events.0.broadcast(event).await {
+            error!("Failed to send a local diagnostic event: {error}");
+        }
+    }
+
+    async fn subscribe_events(&self) -> Receiver<DiagnosticEvent> {
+        self.events.1.clone()
+    }
+
+    fn get_heartbeat_interval(&self) -> IggyDuration {
+        self.heartbeat_interval
+    }
+}
+
+/// The service sending the binary requests through the existing transport (TCP, QUIC etc.),
+/// which can be wrapped with any tower middleware (timeouts, retries, load shedding etc.).
+#[derive(Debug)]
+pub struct TransportService<T> {
+    transport: Arc<T>,
+}
+
+impl<T> TransportService<T> {
+    /// Creates a new service for the provided transport.
+    pub fn new(transport: Arc<T>) -> Self {
+        Self { transport }
+    }
+}
+
+impl<T> Clone for TransportService<T> {
+    fn clone(&self) -> Self {
+        Self {
+            transport: self.transport.clone(),
+        }
+    }
+}
+
+impl<T: BinaryTransport + Send + Sync + 'static> Service<BinaryRequest> for TransportService<T> {
+    type Response = Bytes;
+    type Error = IggyError;
+    type Future = Pin<Box<dyn Future<Output = Result<Bytes, IggyError>> + Send>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: BinaryRequest) -> Self::Future {
+        let transport = self.transport.clone();
+        Box::pin(async move {
+            transport
+                .send_raw_with_response(request.code, request.payload)
+                .await
+        })
+    }
+}
+
+/// The client sending all the commands through the provided tower service,
+/// while the connection lifecycle is handled by the provided connection.
+///
+/// As it implements `BinaryClient`, it can be used as any other client, e.g. wrapped by `IggyClient`.
+#[derive(Debug)]
+pub struct ServiceClient<S, C = LocalConnection> {
+    service: S,
+    connection: Arc<C>,
+}
+
+impl<S, C> ServiceClient<S, C> {
+    /// Creates a new client for the provided service and connection.
+    pub fn new(service: S, connection: Arc<C>) -> Self {
+        Self {
+            service,
+            connection,
+        }
+    }
+
+    /// Gets the underlying connection.
+    pub fn connection(&self) -> &Arc<C> {
+        &self.connection
+    }
+}
+
+impl<S> ServiceClient<S, LocalConnection> {
+    /// Creates a new client for the provided service, without any underlying network transport.
+    pub fn local(service: S) -> Self {
+        Self::new(service, Arc::new(LocalConnection::default()))
+    }
+}
+
+impl<S, T> ServiceClient<S, T>
+where
+    T: BinaryTransport + Connection + 'static,
+{
+    /// Creates a new client sending the commands through the provided transport wrapped with the provided layer.
+    pub fn layered<L>(transport: Arc<T>, layer: L) -> Self
+    where
+        L: Layer<TransportService<T>, Service = S>,
+    {
+        let service = layer.layer(TransportService::new(transport.clone()));
+        Self::new(service, transport)
+    }
+}
+
+#[async_trait]
+impl<S, C> Client for ServiceClient<S, C>
+where
+    S: Service<BinaryRequest, Response = Bytes> + Clone + Debug + Send + Sync + 'static,
+    S::Error: Into<Box<dyn Error + Send + Sync>>,
+    S::Future: Send,
+    C: Connection + 'static,
+{
+    async fn connect(&self) -> Result<(), IggyError> {
+        self.connection.connect().await
+    }
+
+    async fn disconnect(&self) -> Result<(), IggyError> {
+        self.connection.disconnect().await
+    }
+
+    async fn shutdown(&self) -> Result<(), IggyError> {
+        self.connection.shutdown().await
+    }
+
+    async fn subscribe_events(&self) -> Receiver<DiagnosticEvent> {
+        self.connection.subscribe_events().await
+    }
+}
+
+#[async_trait]
+impl<S, C> BinaryTransport for ServiceClient<S, C>
+where
+    S: Service<BinaryRequest, Response = Bytes> + Clone + Debug + Send + Sync + 'static,
+    S::Error: Into<Box<dyn Error + Send + Sync>>,
+    S::Future: Send,
+    C: Connection + 'static,
+{
+    async fn get_state(&self) -> ClientState {
+        self.connection.get_state().await
+    }
+
+    async fn set_state(&self, state: ClientState) {
+        self.connection.set_state(state).await
+    }
+
+    async fn publish_event(&self, event: DiagnosticEvent) {
+        self.connection.publish_event(event).await
+    }
+
+    async fn send_with_response<T: Command>(&self, command: &T) -> Result<Bytes, IggyError> {
+        let request = BinaryRequest::from_command(command)?;
+        self.send_raw_with_response(request.code, request.payload)
+            .await
+    }
+
+    async fn send_raw_with_response(&self, code: u32, payload: Bytes) -> Result<Bytes, IggyError> {
+        let mut service = self.service.clone();
+        poll_fn(|cx| service.poll_ready(cx))
+            .await
+            .map_err(map_service_error)?;
+        service
+            .call(BinaryRequest::new(code, payload))
+            .await
+            .map_err(map_service_error)
+    }
+
+    fn get_heartbeat_interval(&self) -> IggyDuration {
+        self.connection.get_heartbeat_interval()
+    }
+
+    fn get_protocol_features(&self) -> Handshake {
+        self.connection.get_protocol_features()
+    }
+}
+
+impl<S, C> BinaryClient for ServiceClient<S, C>
+where
+    S: Service<BinaryRequest, Response = Bytes> + Clone + Debug + Send + Sync + 'static,
+    S::Error: Into<Box<dyn Error + Send + Sync>>,
+    S::Future: Send,
+    C: Connection + 'static,
+{
+}
+
+/// Maps the error returned by the service (or any of its middleware) to the `IggyError`.
+fn map_service_error<E: Into<Box<dyn Error + Send + Sync>>>(error: E) -> IggyError {
+    match error.into().downcast::<IggyError>() {
+        Ok(error) => *error,
+        Err(error) => {
+            error!("Service failed to handle the request: {error}");
+            IggyError::Error
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fmt::{Display, Formatter};
+
+    #[derive(Debug, Clone)]
+    struct EchoService;
+
+    impl Service<BinaryRequest> for EchoService {
+        type Response = Bytes;
+        type Error = IggyError;
+        type Future = Pin<Box<dyn Future<Output = Result<Bytes, IggyError>> + Send>>;
+
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, request: BinaryRequest) -> Self::Future {
+            Box::pin(async move {
+                if request.payload.is_empty() {
+                    return Err(IggyError::InvalidCommand);
+                }
+                Ok(request.payload)
+            })
+        }
+    }
+
+    #[derive(Debug)]
+    struct MiddlewareError;
+
+    impl Display for MiddlewareError {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            write!(f, "middleware error")
+        }
+    }
+
+    impl Error for MiddlewareError {}
+
+    #[tokio::test]
+    async fn local_client_should_send_requests_through_service() {
+        let client = ServiceClient::local(EchoService);
+        client.connect().await.unwrap();
+        assert_eq!(client.get_state().await, ClientState::Connected);
+
+        let response = client
+            .send_raw_with_response(1, Bytes::from_static(b"test"))
+            .await
+            .unwrap();
+        assert_eq!(response, Bytes::from_static(b"test"));
+
+        let error = client
+            .send_raw_with_response(1, Bytes::new())
+            .await
+            .unwrap_err();
+        assert_eq!(error.as_code(), IggyError::InvalidCommand.as_code());
+    }
+
+    #[test]
+    fn service_errors_should_be_mapped_to_iggy_errors() {
+        let error = map_service_error(IggyError::Unauthenticated);
+        assert_eq!(error.as_code(), IggyError::Unauthenticated.as_code());
+
+        let error = map_service_error(MiddlewareError);
+        assert_eq!(error.as_code(), IggyError::Error.as_code());
+    }
+}
@@ -21,13 +21,16 @@ use crate::binary::{fail_if_not_authenticated, mapper};
 use crate::client::StreamClient;
 use crate::error::IggyError;
 use crate::identifier::Identifier;
-use crate::models::stream::{Stream, StreamDetails};
-use crate::streams::create_stream::CreateStream;
+use crate::models::metadata::{MetadataFilter, ResourceMetadata};
+use crate::models::stream::{Stream, StreamDetails, StreamQuota};
+use crate::streams::create_stream::{CreateStream, CreateStreamOptions};
 use crate::streams::delete_stream::DeleteStream;
 use crate::streams::get_stream::GetStream;
 use crate::streams::get_streams::GetStreams;
 use crate::streams::purge_stream::PurgeStream;
 use crate::streams::update_stream::UpdateStream;
+use crate::streams::update_stream_metadata::UpdateStreamMetadata;
+use crate::streams::update_stream_quota::UpdateStreamQuota;
 
 #[async_trait::async_trait]
 impl<B: BinaryClient> StreamClient for B {
@@ -42,28 +45,53 @@ impl<B: BinaryClient> StreamClient for B {
             return Ok(None);
         }
 
-        mapper::map_stream(response).map(Some)
+        mapper::map_stream(response, self.get_protocol_features()).map(Some)
     }
 
     async fn get_streams(&self) -> Result<Vec<Stream>, IggyError> {
         fail_if_not_authenticated(self).await?;
-        let response = self.send_with_response(&GetStreams {}).await?;
-        mapper::map_streams(response)
+        let response = self.send_with_response(&GetStreams::default()).await?;
+        mapper::map_streams(response, self.get_protocol_features())
+    }
+
+    async fn get_streams_by_metadata(
+        &self,
+        filter: &MetadataFilter,
+    ) -> Result<Vec<Stream>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&GetStreams {
+                filter: filter.clone(),
+            })
+            .await?;
+        mapper::map_streams(response, self.get_protocol_features())
     }
 
     async fn create_stream(
         &self,
         name: &str,
         stream_id: Option<u32>,
+    ) -> Result<StreamDetails, IggyError> {
+        self.create_stream_with_options(name, stream_id, &CreateStreamOptions::default())
+            .await
+    }
+
+    async fn create_stream_with_options(
+        &self,
+        name: &str,
+        stream_id: Option<u32>,
+        options: &CreateStreamOptions,
     ) -> Result<StreamDetails, IggyError> {
         fail_if_not_authenticated(self).await?;
         let response = self
             .send_with_response(&CreateStream {
                 name: name.to_string(),
                 stream_id,
+                metadata: options.metadata.clone(),
+                namespace_id: None,
             })
             .await?;
-        mapper::map_stream(response)
+        mapper::map_stream(response, self.get_protocol_features())
     }
 
     async fn update_stream(&self, stream_id: &Identifier, name: &str) -> Result<(), IggyError> {
@@ -76,6 +104,34 @@ impl<B: BinaryClient> StreamClient for B {
         Ok(())
     }
 
+    async fn update_stream_metadata(
+        &self,
+        stream_id: &Identifier,
+        metadata: ResourceMetadata,
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&UpdateStreamMetadata {
+            stream_id: stream_id.clone(),
+            metadata,
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn update_stream_quota(
+        &self,
+        stream_id: &Identifier,
+        quota: StreamQuota,
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&UpdateStreamQuota {
+            stream_id: stream_id.clone(),
+            quota,
+        })
+        .await?;
+        Ok(())
+    }
+
     async fn delete_stream(&self, stream_id: &Identifier) -> Result<(), IggyError> {
         fail_if_not_authenticated(self).await?;
         self.send_with_response(&DeleteStream {
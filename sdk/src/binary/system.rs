@@ -20,16 +20,29 @@ use crate::binary::binary_client::BinaryClient;
 use crate::binary::{fail_if_not_authenticated, mapper};
 use crate::client::SystemClient;
 use crate::error::IggyError;
+use crate::models::audit_entry::{AuditAction, AuditEntry};
+use crate::models::backup::Backup;
 use crate::models::client_info::{ClientInfo, ClientInfoDetails};
+use crate::models::error_catalog::ErrorCatalogEntry;
+use crate::models::maintenance_mode::MaintenanceMode;
+use crate::models::server_info::ServerInfo;
 use crate::models::snapshot::Snapshot;
 use crate::models::stats::Stats;
+use crate::models::unsaved_state::PartitionUnsavedState;
 use crate::snapshot::{SnapshotCompression, SystemSnapshotType};
+use crate::system::create_backup::CreateBackup;
+use crate::system::get_audit_log::GetAuditLog;
 use crate::system::get_client::GetClient;
 use crate::system::get_clients::GetClients;
+use crate::system::get_error_catalog::GetErrorCatalog;
+use crate::system::get_maintenance_mode::GetMaintenanceMode;
 use crate::system::get_me::GetMe;
+use crate::system::get_server_info::GetServerInfo;
 use crate::system::get_snapshot::GetSnapshot;
 use crate::system::get_stats::GetStats;
+use crate::system::get_unsaved_state::GetUnsavedState;
 use crate::system::ping::Ping;
+use crate::system::set_maintenance_mode::SetMaintenanceMode;
 use crate::utils::duration::IggyDuration;
 
 #[async_trait::async_trait]
@@ -85,4 +98,70 @@ impl<B: BinaryClient> SystemClient for B {
         let snapshot = Snapshot::new(response.to_vec());
         Ok(snapshot)
     }
+
+    async fn get_maintenance_mode(&self) -> Result<MaintenanceMode, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self.send_with_response(&GetMaintenanceMode {}).await?;
+        mapper::map_maintenance_mode(response)
+    }
+
+    async fn set_maintenance_mode(
+        &self,
+        enabled: bool,
+        reject_reads: bool,
+        message: Option<&str>,
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&SetMaintenanceMode {
+            enabled,
+            reject_reads,
+            message: message.map(|message| message.to_string()),
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn get_server_info(&self) -> Result<ServerInfo, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self.send_with_response(&GetServerInfo {}).await?;
+        mapper::map_server_info(response)
+    }
+
+    async fn create_backup(&self, name: &str) -> Result<Backup, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&CreateBackup {
+                name: name.to_string(),
+            })
+            .await?;
+        mapper::map_backup(response)
+    }
+
+    async fn get_unsaved_state(&self) -> Result<Vec<PartitionUnsavedState>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self.send_with_response(&GetUnsavedState {}).await?;
+        mapper::map_unsaved_state(response)
+    }
+
+    async fn get_audit_log(
+        &self,
+        count: u32,
+        user_id: Option<u32>,
+        action: Option<AuditAction>,
+    ) -> Result<Vec<AuditEntry>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&GetAuditLog {
+                count,
+                user_id,
+                action,
+            })
+            .await?;
+        mapper::map_audit_entries(response)
+    }
+
+    async fn get_error_catalog(&self) -> Result<Vec<ErrorCatalogEntry>, IggyError> {
+        let response = self.send_with_response(&GetErrorCatalog {}).await?;
+        mapper::map_error_catalog(response)
+    }
 }
@@ -20,15 +20,26 @@ use crate::binary::binary_client::BinaryClient;
 use crate::binary::{fail_if_not_authenticated, mapper};
 use crate::client::TopicClient;
 use crate::compression::compression_algorithm::CompressionAlgorithm;
+use crate::consumer::Consumer;
 use crate::error::IggyError;
 use crate::identifier::Identifier;
+use crate::messages::message_id_scheme::MessageIdScheme;
+use crate::models::metadata::{MetadataFilter, ResourceMetadata};
 use crate::models::topic::{Topic, TopicDetails};
-use crate::topics::create_topic::CreateTopic;
+use crate::topics::cleanup_policy::CleanupPolicy;
+use crate::topics::create_topic::{CreateTopic, CreateTopicOptions};
 use crate::topics::delete_topic::DeleteTopic;
 use crate::topics::get_topic::GetTopic;
 use crate::topics::get_topics::GetTopics;
-use crate::topics::purge_topic::PurgeTopic;
-use crate::topics::update_topic::UpdateTopic;
+use crate::topics::mark_topic_for_deletion::MarkTopicForDeletion;
+use crate::topics::purge_topic::{PurgeTopic, PurgeTopicOptions};
+use crate::topics::topic_config::TopicConfig;
+use crate::topics::update_topic::{UpdateTopic, UpdateTopicOptions};
+use crate::topics::update_topic_config::UpdateTopicConfig;
+use crate::topics::update_topic_expiry_watcher::UpdateTopicExpiryWatcher;
+use crate::topics::update_topic_metadata::UpdateTopicMetadata;
+use crate::topics::update_topic_producers::UpdateTopicProducers;
+use crate::utils::duration::IggyDuration;
 use crate::utils::expiry::IggyExpiry;
 use crate::utils::topic_size::MaxTopicSize;
 
@@ -50,7 +61,7 @@ impl<B: BinaryClient> TopicClient for B {
             return Ok(None);
         }
 
-        mapper::map_topic(response).map(Some)
+        mapper::map_topic(response, self.get_protocol_features()).map(Some)
     }
 
     async fn get_topics(&self, stream_id: &Identifier) -> Result<Vec<Topic>, IggyError> {
@@ -58,9 +69,25 @@ impl<B: BinaryClient> TopicClient for B {
         let response = self
             .send_with_response(&GetTopics {
                 stream_id: stream_id.clone(),
+                filter: MetadataFilter::default(),
             })
             .await?;
-        mapper::map_topics(response)
+        mapper::map_topics(response, self.get_protocol_features())
+    }
+
+    async fn get_topics_by_metadata(
+        &self,
+        stream_id: &Identifier,
+        filter: &MetadataFilter,
+    ) -> Result<Vec<Topic>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&GetTopics {
+                stream_id: stream_id.clone(),
+                filter: filter.clone(),
+            })
+            .await?;
+        mapper::map_topics(response, self.get_protocol_features())
     }
 
     async fn create_topic(
@@ -73,6 +100,32 @@ impl<B: BinaryClient> TopicClient for B {
         topic_id: Option<u32>,
         message_expiry: IggyExpiry,
         max_topic_size: MaxTopicSize,
+    ) -> Result<TopicDetails, IggyError> {
+        self.create_topic_with_options(
+            stream_id,
+            name,
+            partitions_count,
+            compression_algorithm,
+            replication_factor,
+            topic_id,
+            message_expiry,
+            max_topic_size,
+            &CreateTopicOptions::default(),
+        )
+        .await
+    }
+
+    async fn create_topic_with_options(
+        &self,
+        stream_id: &Identifier,
+        name: &str,
+        partitions_count: u32,
+        compression_algorithm: CompressionAlgorithm,
+        replication_factor: Option<u8>,
+        topic_id: Option<u32>,
+        message_expiry: IggyExpiry,
+        max_topic_size: MaxTopicSize,
+        options: &CreateTopicOptions,
     ) -> Result<TopicDetails, IggyError> {
         fail_if_not_authenticated(self).await?;
         let response = self
@@ -85,9 +138,12 @@ impl<B: BinaryClient> TopicClient for B {
                 topic_id,
                 message_expiry,
                 max_topic_size,
+                metadata: options.metadata.clone(),
+                message_id_scheme: MessageIdScheme::ServerDefault,
+                cleanup_policy: CleanupPolicy::ServerDefault,
             })
             .await?;
-        mapper::map_topic(response)
+        mapper::map_topic(response, self.get_protocol_features())
     }
 
     async fn update_topic(
@@ -99,6 +155,30 @@ impl<B: BinaryClient> TopicClient for B {
         replication_factor: Option<u8>,
         message_expiry: IggyExpiry,
         max_topic_size: MaxTopicSize,
+    ) -> Result<(), IggyError> {
+        self.update_topic_with_options(
+            stream_id,
+            topic_id,
+            name,
+            compression_algorithm,
+            replication_factor,
+            message_expiry,
+            max_topic_size,
+            &UpdateTopicOptions::default(),
+        )
+        .await
+    }
+
+    async fn update_topic_with_options(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        name: &str,
+        compression_algorithm: CompressionAlgorithm,
+        replication_factor: Option<u8>,
+        message_expiry: IggyExpiry,
+        max_topic_size: MaxTopicSize,
+        options: &UpdateTopicOptions,
     ) -> Result<(), IggyError> {
         fail_if_not_authenticated(self).await?;
         self.send_with_response(&UpdateTopic {
@@ -109,6 +189,90 @@ impl<B: BinaryClient> TopicClient for B {
             replication_factor,
             message_expiry,
             max_topic_size,
+            max_segments: options.max_segments,
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn update_topic_metadata(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        metadata: ResourceMetadata,
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&UpdateTopicMetadata {
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            metadata,
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn update_topic_producers(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        allowed_producers: &[u32],
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&UpdateTopicProducers {
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            allowed_producers: allowed_producers.to_vec(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn update_topic_config(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        config: TopicConfig,
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&UpdateTopicConfig {
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            config,
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn update_topic_expiry_watcher(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        consumer: &Consumer,
+        enabled: bool,
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&UpdateTopicExpiryWatcher {
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            consumer_kind: consumer.kind,
+            consumer_id: consumer.id.clone(),
+            enabled,
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn mark_topic_for_deletion(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        drain_period: IggyDuration,
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&MarkTopicForDeletion {
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            drain_period,
         })
         .await?;
         Ok(())
@@ -132,11 +296,23 @@ impl<B: BinaryClient> TopicClient for B {
         &self,
         stream_id: &Identifier,
         topic_id: &Identifier,
+    ) -> Result<(), IggyError> {
+        self.purge_topic_with_options(stream_id, topic_id, &PurgeTopicOptions::default())
+            .await
+    }
+
+    async fn purge_topic_with_options(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        options: &PurgeTopicOptions,
     ) -> Result<(), IggyError> {
         fail_if_not_authenticated(self).await?;
         self.send_with_response(&PurgeTopic {
             stream_id: stream_id.clone(),
             topic_id: topic_id.clone(),
+            keep_consumer_offsets: options.keep_consumer_offsets,
+            older_than: options.older_than,
         })
         .await?;
         Ok(())
@@ -31,10 +31,13 @@ use crate::users::create_user::CreateUser;
 use crate::users::delete_user::DeleteUser;
 use crate::users::get_user::GetUser;
 use crate::users::get_users::GetUsers;
+use crate::users::login_as::LoginAs;
 use crate::users::login_user::LoginUser;
 use crate::users::logout_user::LogoutUser;
 use crate::users::update_permissions::UpdatePermissions;
 use crate::users::update_user::UpdateUser;
+use crate::users::update_user_quotas::UpdateUserQuotas;
+use crate::users::user_quotas::UserQuotas;
 
 #[async_trait::async_trait]
 impl<B: BinaryClient> UserClient for B {
@@ -72,6 +75,7 @@ impl<B: BinaryClient> UserClient for B {
                 password: password.to_string(),
                 status,
                 permissions,
+                namespace_id: None,
             })
             .await?;
         mapper::map_user(response)
@@ -116,6 +120,20 @@ impl<B: BinaryClient> UserClient for B {
         Ok(())
     }
 
+    async fn update_user_quotas(
+        &self,
+        user_id: &Identifier,
+        quotas: UserQuotas,
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&UpdateUserQuotas {
+            user_id: user_id.clone(),
+            quotas,
+        })
+        .await?;
+        Ok(())
+    }
+
     async fn change_password(
         &self,
         user_id: &Identifier,
@@ -153,4 +171,14 @@ impl<B: BinaryClient> UserClient for B {
         self.publish_event(DiagnosticEvent::SignedOut).await;
         Ok(())
     }
+
+    async fn login_as(&self, user_id: &Identifier) -> Result<IdentityInfo, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&LoginAs {
+                user_id: user_id.clone(),
+            })
+            .await?;
+        mapper::map_identity_info(response)
+    }
 }
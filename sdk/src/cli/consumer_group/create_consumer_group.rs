@@ -19,6 +19,7 @@
 use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::consumer_groups::create_consumer_group::CreateConsumerGroup;
+use crate::consumer_groups::offset_recovery_policy::OffsetRecoveryPolicy;
 use crate::identifier::Identifier;
 use anyhow::Context;
 use async_trait::async_trait;
@@ -41,6 +42,7 @@ impl CreateConsumerGroupCmd {
                 topic_id,
                 name,
                 group_id,
+                offset_recovery: OffsetRecoveryPolicy::ServerDefault,
             },
         }
     }
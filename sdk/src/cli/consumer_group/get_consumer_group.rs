@@ -85,6 +85,37 @@ impl CliCommand for GetConsumerGroupCmd {
             "Members count",
             format!("{}", consumer_group.members_count).as_str(),
         ]);
+        table.add_row(vec![
+            "Generation",
+            format!("{}", consumer_group.generation).as_str(),
+        ]);
+        table.add_row(vec![
+            "Assignment strategy",
+            format!("{}", consumer_group.assignment_strategy).as_str(),
+        ]);
+        table.add_row(vec![
+            "Offset recovery",
+            format!("{}", consumer_group.offset_recovery).as_str(),
+        ]);
+        table.add_row(vec![
+            "Dead letter",
+            match consumer_group.dead_letter {
+                Some(dead_letter) => format!(
+                    "stream: {}, topic: {}, max delivery count: {}",
+                    dead_letter.stream_id, dead_letter.topic_id, dead_letter.max_delivery_count
+                ),
+                None => String::from("disabled"),
+            }
+            .as_str(),
+        ]);
+        table.add_row(vec![
+            "Visibility timeout",
+            match consumer_group.visibility_timeout {
+                Some(visibility_timeout) => visibility_timeout.as_human_time_string(),
+                None => String::from("disabled"),
+            }
+            .as_str(),
+        ]);
 
         if consumer_group.members_count > 0 {
             let mut members_table = Table::new();
@@ -106,6 +137,40 @@ impl CliCommand for GetConsumerGroupCmd {
             table.add_row(vec!["Members", members_table.to_string().as_str()]);
         }
 
+        if !consumer_group.rebalances.is_empty() {
+            let mut rebalances_table = Table::new();
+            rebalances_table.load_preset(ASCII_NO_BORDERS);
+            rebalances_table.set_header(vec![
+                "Timestamp",
+                "Trigger",
+                "Members before",
+                "Members after",
+                "Partitions moved",
+                "Duration",
+            ]);
+            let format_members = |members: &[u32]| {
+                members
+                    .iter()
+                    .map(|i| format!("{}", i))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            };
+            for rebalance in consumer_group.rebalances.iter().rev() {
+                rebalances_table.add_row(vec![
+                    rebalance
+                        .timestamp
+                        .to_local_string("%Y-%m-%d %H:%M:%S")
+                        .as_str(),
+                    format!("{}", rebalance.trigger).as_str(),
+                    format_members(&rebalance.members_before).as_str(),
+                    format_members(&rebalance.members_after).as_str(),
+                    format!("{}", rebalance.partitions_moved).as_str(),
+                    format!("{}", rebalance.duration).as_str(),
+                ]);
+            }
+            table.add_row(vec!["Rebalances", rebalances_table.to_string().as_str()]);
+        }
+
         event!(target: PRINT_TARGET, Level::INFO,"{table}");
 
         Ok(())
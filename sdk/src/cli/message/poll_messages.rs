@@ -21,7 +21,7 @@ use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::consumer::Consumer;
 use crate::identifier::Identifier;
-use crate::messages::poll_messages::{PollMessages, PollingStrategy};
+use crate::messages::poll_messages::{IsolationLevel, PollMessages, PollingStrategy};
 use crate::messages::send_messages::Message;
 use crate::models::header::{HeaderKey, HeaderKind};
 use crate::models::messages::PolledMessages;
@@ -74,12 +74,27 @@ impl PollMessagesCmd {
                 strategy,
                 count: message_count,
                 auto_commit,
+                isolation_level: IsolationLevel::default(),
+                chunk_size: 0,
+                filter: None,
+                partition_epoch: None,
+                peek: false,
             },
             show_headers,
             output_file,
         }
     }
 
+    /// Browses the messages instead, without committing the offset or affecting the delivery state of the consumer (group).
+    pub fn browse(mut self, consumer_group: bool) -> Self {
+        self.poll_messages.peek = true;
+        self.poll_messages.auto_commit = false;
+        if consumer_group {
+            self.poll_messages.consumer = Consumer::group(self.poll_messages.consumer.id.clone());
+        }
+        self
+    }
+
     fn create_message_header_keys(
         &self,
         polled_messages: &PolledMessages,
@@ -161,30 +176,54 @@ impl PollMessagesCmd {
 impl CliCommand for PollMessagesCmd {
     fn explain(&self) -> String {
         format!(
-            "poll messages from topic ID: {} and stream with ID: {}",
-            self.poll_messages.topic_id, self.poll_messages.stream_id
+            "{} messages from topic ID: {} and stream with ID: {}",
+            if self.poll_messages.peek {
+                "browse"
+            } else {
+                "poll"
+            },
+            self.poll_messages.topic_id,
+            self.poll_messages.stream_id
         )
     }
 
     async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
         let start = std::time::Instant::now();
-        let messages = client
-            .poll_messages(
-                &self.poll_messages.stream_id,
-                &self.poll_messages.topic_id,
-                self.poll_messages.partition_id,
-                &self.poll_messages.consumer,
-                &self.poll_messages.strategy,
-                self.poll_messages.count,
-                self.poll_messages.auto_commit,
+        let messages = match self.poll_messages.peek {
+            true => {
+                client
+                    .peek_messages(
+                        &self.poll_messages.stream_id,
+                        &self.poll_messages.topic_id,
+                        self.poll_messages.partition_id,
+                        &self.poll_messages.consumer,
+                        &self.poll_messages.strategy,
+                        self.poll_messages.count,
+                        self.poll_messages.isolation_level,
+                        None,
+                    )
+                    .await
+            }
+            false => {
+                client
+                    .poll_messages(
+                        &self.poll_messages.stream_id,
+                        &self.poll_messages.topic_id,
+                        self.poll_messages.partition_id,
+                        &self.poll_messages.consumer,
+                        &self.poll_messages.strategy,
+                        self.poll_messages.count,
+                        self.poll_messages.auto_commit,
+                    )
+                    .await
+            }
+        }
+        .with_context(|| {
+            format!(
+                "Problem polling messages to topic with ID: {} and stream with ID: {}",
+                self.poll_messages.topic_id, self.poll_messages.stream_id
             )
-            .await
-            .with_context(|| {
-                format!(
-                    "Problem polling messages to topic with ID: {} and stream with ID: {}",
-                    self.poll_messages.topic_id, self.poll_messages.stream_id
-                )
-            })?;
+        })?;
         let elapsed = IggyDuration::new(start.elapsed());
 
         event!(target: PRINT_TARGET, Level::INFO,
@@ -23,14 +23,20 @@ use crate::identifier::Identifier;
 use crate::messages::send_messages::{Message, Partitioning};
 use crate::models::header::{HeaderKey, HeaderValue};
 use crate::utils::sizeable::Sizeable;
+use crate::utils::timestamp::IggyTimestamp;
 use anyhow::Context;
 use async_trait::async_trait;
 use bytes::Bytes;
 use std::collections::HashMap;
 use std::io::{self, Read};
+use std::time::Duration;
 use tokio::io::AsyncReadExt;
 use tracing::{event, Level};
 
+const SEQUENCE_PLACEHOLDER: &str = "{{seq}}";
+const TIMESTAMP_PLACEHOLDER: &str = "{{timestamp}}";
+const UUID_PLACEHOLDER: &str = "{{uuid}}";
+
 pub struct SendMessagesCmd {
     stream_id: Identifier,
     topic_id: Identifier,
@@ -38,9 +44,13 @@ pub struct SendMessagesCmd {
     messages: Option<Vec<String>>,
     headers: Vec<(HeaderKey, HeaderValue)>,
     input_file: Option<String>,
+    file: Option<String>,
+    repeat: u32,
+    rate: Option<u32>,
 }
 
 impl SendMessagesCmd {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         stream_id: Identifier,
         topic_id: Identifier,
@@ -49,6 +59,9 @@ impl SendMessagesCmd {
         messages: Option<Vec<String>>,
         headers: Vec<(HeaderKey, HeaderValue)>,
         input_file: Option<String>,
+        file: Option<String>,
+        repeat: u32,
+        rate: Option<u32>,
     ) -> Self {
         let partitioning = match (partition_id, message_key) {
             (Some(_), Some(_)) => unreachable!(),
@@ -69,6 +82,9 @@ impl SendMessagesCmd {
             messages,
             headers,
             input_file,
+            file,
+            repeat: repeat.max(1),
+            rate,
         }
     }
 
@@ -86,82 +102,110 @@ impl SendMessagesCmd {
             _ => Some(self.headers.iter().cloned().collect()),
         }
     }
-}
 
-#[async_trait]
-impl CliCommand for SendMessagesCmd {
-    fn explain(&self) -> String {
-        format!(
-            "send messages to topic with ID: {} and stream with ID: {}",
-            self.topic_id, self.stream_id
-        )
+    async fn read_binary_messages(&self, input_file: &str) -> anyhow::Result<Vec<Message>> {
+        let mut file = tokio::fs::OpenOptions::new()
+            .read(true)
+            .open(input_file)
+            .await
+            .with_context(|| format!("Problem opening file for reading: {input_file}"))?;
+        let mut buffer = Vec::new();
+        file.read_to_end(&mut buffer)
+            .await
+            .with_context(|| format!("Problem reading file: {input_file}"))?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Read {} bytes from {} file", buffer.len(), input_file,
+        );
+
+        let mut messages: Vec<Message> = Vec::new();
+        let mut bytes_read = 0usize;
+        let all_messages_bytes: Bytes = buffer.into();
+
+        while bytes_read < all_messages_bytes.len() {
+            let message_bytes = all_messages_bytes.slice(bytes_read..);
+            let message = Message::from_bytes(message_bytes);
+            match message {
+                Ok(message) => {
+                    let message_size = message.get_size_bytes().as_bytes_usize();
+                    messages.push(message);
+                    bytes_read += message_size;
+                }
+                Err(e) => {
+                    event!(target: PRINT_TARGET, Level::ERROR,
+                        "Failed to parse message from bytes: {e} at offset {bytes_read}",
+                    );
+                    break;
+                }
+            }
+        }
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Created {} messages using {bytes_read} bytes", messages.len(),
+        );
+
+        Ok(messages)
     }
 
-    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
-        let mut messages = if let Some(input_file) = &self.input_file {
-            let mut file = tokio::fs::OpenOptions::new()
-                .read(true)
-                .open(input_file)
-                .await
-                .with_context(|| format!("Problem opening file for reading: {input_file}"))?;
-            let mut buffer = Vec::new();
-            file.read_to_end(&mut buffer)
+    async fn read_payloads(&self) -> anyhow::Result<Vec<Bytes>> {
+        if let Some(file) = &self.file {
+            let payload = tokio::fs::read(file)
                 .await
-                .with_context(|| format!("Problem reading file: {input_file}"))?;
-
+                .with_context(|| format!("Problem reading payload file: {file}"))?;
             event!(target: PRINT_TARGET, Level::INFO,
-                "Read {} bytes from {} file", buffer.len(), input_file,
+                "Read {} bytes of payload from {} file", payload.len(), file,
             );
+            return Ok(vec![payload.into()]);
+        }
 
-            let mut messages: Vec<Message> = Vec::new();
-            let mut bytes_read = 0usize;
-            let all_messages_bytes: Bytes = buffer.into();
-
-            while bytes_read < all_messages_bytes.len() {
-                let message_bytes = all_messages_bytes.slice(bytes_read..);
-                let message = Message::from_bytes(message_bytes);
-                match message {
-                    Ok(message) => {
-                        let message_size = message.get_size_bytes().as_bytes_usize();
-                        messages.push(message);
-                        bytes_read += message_size;
-                    }
-                    Err(e) => {
-                        event!(target: PRINT_TARGET, Level::ERROR,
-                            "Failed to parse message from bytes: {e} at offset {bytes_read}",
-                        );
-                        break;
+        let payloads = match &self.messages {
+            Some(messages) => messages.iter().map(|m| m.clone().into()).collect(),
+            None => self
+                .read_message_from_stdin()?
+                .lines()
+                .map(|m| String::from(m).into())
+                .collect(),
+        };
+        Ok(payloads)
+    }
+
+    /// Builds the messages to be sent, each payload is repeated the configured number of times
+    /// and the placeholders ({{seq}}, {{timestamp}} and {{uuid}}) are replaced in UTF-8 payloads.
+    async fn build_messages(&self) -> anyhow::Result<Vec<Message>> {
+        if let Some(input_file) = &self.input_file {
+            let messages = self.read_binary_messages(input_file).await?;
+            let mut repeated = Vec::with_capacity(messages.len() * self.repeat as usize);
+            for round in 0..self.repeat {
+                repeated.extend(messages.iter().cloned().map(|mut message| {
+                    // The repeated messages get new IDs generated by the server.
+                    if round > 0 {
+                        message.id = 0;
                     }
-                }
+                    message
+                }));
             }
-            event!(target: PRINT_TARGET, Level::INFO,
-                "Created {} messages using {bytes_read} bytes", messages.len(),
-            );
+            return Ok(repeated);
+        }
 
-            messages
-        } else {
-            match &self.messages {
-                Some(messages) => messages
-                    .iter()
-                    .map(|s| Message::new(None, s.clone().into(), self.get_headers()))
-                    .collect::<Vec<_>>(),
-                None => {
-                    let input = self.read_message_from_stdin()?;
-
-                    input
-                        .lines()
-                        .map(|m| Message::new(None, String::from(m).into(), self.get_headers()))
-                        .collect()
-                }
+        let payloads = self.read_payloads().await?;
+        let mut messages = Vec::with_capacity(payloads.len() * self.repeat as usize);
+        let mut sequence = 0u64;
+        for _ in 0..self.repeat {
+            for payload in &payloads {
+                let payload = render_template(payload, sequence);
+                messages.push(Message::new(None, payload, self.get_headers()));
+                sequence += 1;
             }
-        };
+        }
+        Ok(messages)
+    }
 
+    async fn send(&self, client: &dyn Client, messages: &mut [Message]) -> anyhow::Result<()> {
         client
             .send_messages(
                 &self.stream_id,
                 &self.topic_id,
                 &self.partitioning,
-                &mut messages,
+                messages,
             )
             .await
             .with_context(|| {
@@ -170,6 +214,51 @@ impl CliCommand for SendMessagesCmd {
                     self.topic_id, self.stream_id
                 )
             })?;
+        Ok(())
+    }
+}
+
+fn render_template(payload: &Bytes, sequence: u64) -> Bytes {
+    let Ok(text) = std::str::from_utf8(payload) else {
+        return payload.clone();
+    };
+
+    if !text.contains("{{") {
+        return payload.clone();
+    }
+
+    text.replace(SEQUENCE_PLACEHOLDER, &sequence.to_string())
+        .replace(
+            TIMESTAMP_PLACEHOLDER,
+            &IggyTimestamp::now().as_micros().to_string(),
+        )
+        .replace(UUID_PLACEHOLDER, &uuid::Uuid::now_v7().to_string())
+        .into()
+}
+
+#[async_trait]
+impl CliCommand for SendMessagesCmd {
+    fn explain(&self) -> String {
+        format!(
+            "send messages to topic with ID: {} and stream with ID: {}",
+            self.topic_id, self.stream_id
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let mut messages = self.build_messages().await?;
+
+        match self.rate {
+            Some(rate) => {
+                let mut interval =
+                    tokio::time::interval(Duration::from_secs_f64(1.0 / rate.max(1) as f64));
+                for message in messages.chunks_mut(1) {
+                    interval.tick().await;
+                    self.send(client, message).await?;
+                }
+            }
+            None => self.send(client, &mut messages).await?,
+        }
 
         event!(target: PRINT_TARGET, Level::INFO,
             "Sent messages to topic with ID: {} and stream with ID: {}",
@@ -180,3 +269,30 @@ impl CliCommand for SendMessagesCmd {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_template_should_replace_placeholders() {
+        let payload = Bytes::from(r#"{"seq": {{seq}}, "ts": {{timestamp}}, "id": "{{uuid}}"}"#);
+        let rendered = render_template(&payload, 7);
+        let rendered = std::str::from_utf8(&rendered).unwrap();
+
+        assert!(rendered.starts_with(r#"{"seq": 7, "ts": "#));
+        assert!(!rendered.contains("{{"));
+    }
+
+    #[test]
+    fn render_template_should_keep_payload_without_placeholders() {
+        let payload = Bytes::from("plain message");
+        assert_eq!(render_template(&payload, 1), payload);
+    }
+
+    #[test]
+    fn render_template_should_keep_binary_payload() {
+        let payload = Bytes::from(vec![0xff, 0xfe, b'{', b'{']);
+        assert_eq!(render_template(&payload, 1), payload);
+    }
+}
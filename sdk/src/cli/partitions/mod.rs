@@ -18,3 +18,4 @@
 
 pub mod create_partitions;
 pub mod delete_partitions;
+pub mod restore_archived_segments;
@@ -0,0 +1,97 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use crate::partitions::restore_archived_segments::RestoreArchivedSegments;
+use anyhow::Context;
+use async_trait::async_trait;
+use tracing::{event, Level};
+
+pub struct RestoreArchivedSegmentsCmd {
+    restore_archived_segments: RestoreArchivedSegments,
+}
+
+impl RestoreArchivedSegmentsCmd {
+    pub fn new(
+        stream_id: Identifier,
+        topic_id: Identifier,
+        partition_id: u32,
+        start_offset: u64,
+        end_offset: u64,
+    ) -> Self {
+        Self {
+            restore_archived_segments: RestoreArchivedSegments {
+                stream_id,
+                topic_id,
+                partition_id,
+                start_offset,
+                end_offset,
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for RestoreArchivedSegmentsCmd {
+    fn explain(&self) -> String {
+        format!(
+            "restore archived segments for offsets from: {} to: {} for partition with ID: {}, topic with ID: {} and stream with ID: {}",
+            self.restore_archived_segments.start_offset,
+            self.restore_archived_segments.end_offset,
+            self.restore_archived_segments.partition_id,
+            self.restore_archived_segments.topic_id,
+            self.restore_archived_segments.stream_id
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let restored_segments = client
+            .restore_archived_segments(
+                &self.restore_archived_segments.stream_id,
+                &self.restore_archived_segments.topic_id,
+                self.restore_archived_segments.partition_id,
+                self.restore_archived_segments.start_offset,
+                self.restore_archived_segments.end_offset,
+            )
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem restoring archived segments for offsets from: {} to: {} for partition with ID: {}, topic with ID: {} and stream with ID: {}",
+                    self.restore_archived_segments.start_offset,
+                    self.restore_archived_segments.end_offset,
+                    self.restore_archived_segments.partition_id,
+                    self.restore_archived_segments.topic_id,
+                    self.restore_archived_segments.stream_id
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Restored {} segment(s) with {} message(s) ({}) for partition with ID: {}, topic with ID: {} and stream with ID: {}",
+            restored_segments.segments_count,
+            restored_segments.messages_count,
+            restored_segments.size,
+            self.restore_archived_segments.partition_id,
+            self.restore_archived_segments.topic_id,
+            self.restore_archived_segments.stream_id,
+        );
+
+        Ok(())
+    }
+}
@@ -18,6 +18,7 @@
 
 use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
+use crate::models::personal_access_token::PersonalAccessTokenScope;
 use crate::personal_access_tokens::create_personal_access_token::CreatePersonalAccessToken;
 use crate::utils::personal_access_token_expiry::PersonalAccessTokenExpiry;
 use anyhow::Context;
@@ -37,6 +38,7 @@ impl CreatePersonalAccessTokenCmd {
     pub fn new(
         name: String,
         pat_expiry: Option<PersonalAccessTokenExpiry>,
+        scope: PersonalAccessTokenScope,
         quiet_mode: bool,
         store_token: bool,
         server_address: String,
@@ -48,6 +50,7 @@ impl CreatePersonalAccessTokenCmd {
                     None => PersonalAccessTokenExpiry::NeverExpire,
                     Some(value) => *value,
                 },
+                scope,
             },
             token_expiry: pat_expiry,
             quiet_mode,
@@ -64,15 +67,24 @@ impl CliCommand for CreatePersonalAccessTokenCmd {
             Some(value) => format!("token expire time: {}", value),
             None => String::from("without token expire time"),
         };
+        let scope_text = if self.create_token.scope == PersonalAccessTokenScope::default() {
+            String::new()
+        } else {
+            format!(" with scope: {}", self.create_token.scope)
+        };
         format!(
-            "create personal access token with name: {} and {}",
-            self.create_token.name, expiry_text
+            "create personal access token with name: {} and {}{}",
+            self.create_token.name, expiry_text, scope_text
         )
     }
 
     async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
         let token = client
-            .create_personal_access_token(&self.create_token.name, self.create_token.expiry)
+            .create_scoped_personal_access_token(
+                &self.create_token.name,
+                self.create_token.expiry,
+                &self.create_token.scope,
+            )
             .await
             .with_context(|| {
                 format!(
@@ -63,7 +63,7 @@ impl CliCommand for GetPersonalAccessTokensCmd {
             GetPersonalAccessTokensOutput::Table => {
                 let mut table = Table::new();
 
-                table.set_header(vec!["Name", "Token Expiry Time"]);
+                table.set_header(vec!["Name", "Token Expiry Time", "Last Used"]);
 
                 tokens.iter().for_each(|token| {
                     table.add_row(vec![
@@ -72,6 +72,10 @@ impl CliCommand for GetPersonalAccessTokensCmd {
                             None => String::from("unlimited"),
                             Some(value) => value.to_local_string("%Y-%m-%d %H:%M:%S"),
                         },
+                        match token.last_used_at {
+                            None => String::from("never"),
+                            Some(value) => value.to_local_string("%Y-%m-%d %H:%M:%S"),
+                        },
                     ]);
                 });
 
@@ -80,12 +84,16 @@ impl CliCommand for GetPersonalAccessTokensCmd {
             GetPersonalAccessTokensOutput::List => {
                 tokens.iter().for_each(|token| {
                     event!(target: PRINT_TARGET, Level::INFO,
-                        "{}|{}",
+                        "{}|{}|{}",
                         token.name,
                         match token.expiry_at {
                             None => String::from("unlimited"),
                             Some(value) => value.to_local_string("%Y-%m-%d %H:%M:%S"),
                         },
+                        match token.last_used_at {
+                            None => String::from("never"),
+                            Some(value) => value.to_local_string("%Y-%m-%d %H:%M:%S"),
+                        },
                     );
                 });
             }
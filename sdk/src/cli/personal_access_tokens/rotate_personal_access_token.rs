@@ -0,0 +1,113 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::personal_access_tokens::rotate_personal_access_token::RotatePersonalAccessToken;
+use crate::utils::personal_access_token_expiry::PersonalAccessTokenExpiry;
+use anyhow::Context;
+use async_trait::async_trait;
+use keyring::Entry;
+use tracing::{event, Level};
+
+pub struct RotatePersonalAccessTokenCmd {
+    rotate_token: RotatePersonalAccessToken,
+    token_expiry: Option<PersonalAccessTokenExpiry>,
+    quiet_mode: bool,
+    server_address: String,
+}
+
+impl RotatePersonalAccessTokenCmd {
+    pub fn new(
+        name: String,
+        pat_expiry: Option<PersonalAccessTokenExpiry>,
+        quiet_mode: bool,
+        server_address: String,
+    ) -> Self {
+        Self {
+            rotate_token: RotatePersonalAccessToken {
+                name,
+                expiry: match &pat_expiry {
+                    None => PersonalAccessTokenExpiry::NeverExpire,
+                    Some(value) => *value,
+                },
+            },
+            token_expiry: pat_expiry,
+            quiet_mode,
+            server_address,
+        }
+    }
+
+    fn expiry_text(&self) -> String {
+        match &self.token_expiry {
+            Some(value) => format!("token expire time: {}", value),
+            None => String::from("without token expire time"),
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for RotatePersonalAccessTokenCmd {
+    fn explain(&self) -> String {
+        format!(
+            "rotate personal access token with name: {} and {}",
+            self.rotate_token.name,
+            self.expiry_text()
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let token = client
+            .rotate_personal_access_token(&self.rotate_token.name, self.rotate_token.expiry)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem rotating personal access token with name: {}",
+                    self.rotate_token.name
+                )
+            })?;
+
+        let server_address = format!("iggy:{}", self.server_address);
+        let stored_entry = Entry::new(&server_address, &self.rotate_token.name)
+            .ok()
+            .filter(|entry| entry.get_password().is_ok());
+
+        if let Some(entry) = stored_entry {
+            entry.set_password(&token.token)?;
+            event!(target: PRINT_TARGET, Level::DEBUG,"Updated stored token under service: {} and name: {}", server_address,
+                    self.rotate_token.name);
+            event!(target: PRINT_TARGET, Level::INFO,
+                "Personal access token with name: {} and {} rotated",
+                self.rotate_token.name,
+                self.expiry_text(),
+            );
+        } else if self.quiet_mode {
+            println!("{}", token.token);
+        } else {
+            event!(target: PRINT_TARGET, Level::INFO,
+                "Personal access token with name: {} and {} rotated",
+                self.rotate_token.name,
+                self.expiry_text(),
+            );
+            event!(target: PRINT_TARGET, Level::INFO,"Token: {}",
+                            token.token);
+        }
+
+        Ok(())
+    }
+}
@@ -18,7 +18,8 @@
 
 use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
-use crate::streams::create_stream::CreateStream;
+use crate::models::metadata::ResourceMetadata;
+use crate::streams::create_stream::{CreateStream, CreateStreamOptions};
 use anyhow::Context;
 use async_trait::async_trait;
 use tracing::{event, Level};
@@ -28,9 +29,14 @@ pub struct CreateStreamCmd {
 }
 
 impl CreateStreamCmd {
-    pub fn new(stream_id: Option<u32>, name: String) -> Self {
+    pub fn new(stream_id: Option<u32>, name: String, metadata: ResourceMetadata) -> Self {
         Self {
-            create_stream: CreateStream { stream_id, name },
+            create_stream: CreateStream {
+                stream_id,
+                name,
+                metadata,
+                ..Default::default()
+            },
         }
     }
 
@@ -53,8 +59,15 @@ impl CliCommand for CreateStreamCmd {
     }
 
     async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let options = CreateStreamOptions {
+            metadata: self.create_stream.metadata.clone(),
+        };
         client
-            .create_stream(&self.create_stream.name, self.create_stream.stream_id)
+            .create_stream_with_options(
+                &self.create_stream.name,
+                self.create_stream.stream_id,
+                &options,
+            )
             .await
             .with_context(|| {
                 format!(
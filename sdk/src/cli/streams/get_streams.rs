@@ -18,6 +18,7 @@
 
 use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
+use crate::models::metadata::MetadataFilter;
 use crate::streams::get_streams::GetStreams;
 use anyhow::Context;
 use async_trait::async_trait;
@@ -30,14 +31,14 @@ pub enum GetStreamsOutput {
 }
 
 pub struct GetStreamsCmd {
-    _get_streams: GetStreams,
+    get_streams: GetStreams,
     output: GetStreamsOutput,
 }
 
 impl GetStreamsCmd {
-    pub fn new(output: GetStreamsOutput) -> Self {
+    pub fn new(output: GetStreamsOutput, filter: MetadataFilter) -> Self {
         GetStreamsCmd {
-            _get_streams: GetStreams {},
+            get_streams: GetStreams { filter },
             output,
         }
     }
@@ -46,7 +47,7 @@ impl GetStreamsCmd {
 impl Default for GetStreamsCmd {
     fn default() -> Self {
         GetStreamsCmd {
-            _get_streams: GetStreams {},
+            get_streams: GetStreams::default(),
             output: GetStreamsOutput::Table,
         }
     }
@@ -64,7 +65,7 @@ impl CliCommand for GetStreamsCmd {
 
     async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
         let streams = client
-            .get_streams()
+            .get_streams_by_metadata(&self.get_streams.filter)
             .await
             .with_context(|| String::from("Problem getting list of streams"))?;
 
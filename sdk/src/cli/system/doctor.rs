@@ -0,0 +1,559 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::args::Args;
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::client_error::ClientError;
+use crate::client_provider::{self, ClientProviderConfig};
+use crate::error::IggyError;
+use crate::models::stats::Stats;
+use crate::utils::timestamp::IggyTimestamp;
+use crate::SDK_VERSION;
+use anyhow::bail;
+use async_trait::async_trait;
+use comfy_table::Table;
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::{timeout, Instant};
+use tracing::{event, Level};
+
+#[cfg(unix)]
+const TRANSPORTS: &[&str] = &["tcp", "quic", "http", "uds"];
+#[cfg(not(unix))]
+const TRANSPORTS: &[&str] = &["tcp", "quic", "http"];
+const CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+/// The server reports its start and run time with the resolution of seconds, so the smaller skew can't be detected.
+const MAX_CLOCK_SKEW_SECS: i64 = 5;
+const UTILIZATION_WARNING_PERCENT: u64 = 80;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CheckStatus {
+    Passed,
+    Warning,
+    Failed,
+    Skipped,
+}
+
+impl Display for CheckStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CheckStatus::Passed => write!(f, "passed"),
+            CheckStatus::Warning => write!(f, "warning"),
+            CheckStatus::Failed => write!(f, "failed"),
+            CheckStatus::Skipped => write!(f, "skipped"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct CheckResult {
+    name: String,
+    status: CheckStatus,
+    details: String,
+    fix: Option<String>,
+}
+
+impl CheckResult {
+    fn new(name: &str, status: CheckStatus, details: String, fix: Option<String>) -> Self {
+        Self {
+            name: name.to_owned(),
+            status,
+            details,
+            fix,
+        }
+    }
+}
+
+/// Diagnoses the most common problems with the client environment and the server,
+/// like the unreachable transports, invalid credentials, clock skew or incompatible versions,
+/// and prints the fix for each of them.
+pub struct DoctorCmd {
+    args: Args,
+    token: Option<String>,
+}
+
+impl DoctorCmd {
+    pub fn new(args: Args, token: Option<String>) -> Self {
+        Self { args, token }
+    }
+
+    /// Connects with the given transport without the reconnection and the auto login,
+    /// so that the unreachable server is reported right away.
+    async fn connect(&self, transport: &str) -> Result<Box<dyn Client>, IggyError> {
+        let mut args = self.args.clone();
+        args.transport = transport.to_owned();
+        args.tcp_reconnection_enabled = false;
+        args.quic_reconnection_enabled = false;
+        args.uds_reconnection_enabled = false;
+        args.http_retries = 0;
+        let config = ClientProviderConfig::from_args_set_autologin(args, false)
+            .map_err(|_| IggyError::InvalidConfiguration)?;
+        let client = timeout(
+            CHECK_TIMEOUT,
+            client_provider::get_raw_client(Arc::new(config), true),
+        )
+        .await
+        .map_err(|_| IggyError::CannotEstablishConnection)?
+        .map_err(|error| match error {
+            ClientError::SdkError(error) => error,
+            _ => IggyError::CannotEstablishConnection,
+        })?;
+        timeout(CHECK_TIMEOUT, client.ping())
+            .await
+            .map_err(|_| IggyError::CannotEstablishConnection)??;
+        Ok(client)
+    }
+
+    fn get_transport_address(&self, transport: &str) -> String {
+        match transport {
+            "tcp" => self.args.tcp_server_address.clone(),
+            "quic" => self.args.quic_server_address.clone(),
+            "http" => self.args.http_api_url.clone(),
+            _ => self.args.uds_socket_path.clone(),
+        }
+    }
+
+    async fn check_transports(&self, results: &mut Vec<CheckResult>) -> Option<Box<dyn Client>> {
+        let mut selected_client = None;
+        for transport in TRANSPORTS {
+            let name = format!("{} connection", transport.to_uppercase());
+            let address = self.get_transport_address(transport);
+            let is_selected = *transport == self.args.transport;
+            let started_at = Instant::now();
+            match self.connect(transport).await {
+                Ok(client) => {
+                    let elapsed = started_at.elapsed();
+                    results.push(CheckResult::new(
+                        &name,
+                        CheckStatus::Passed,
+                        format!("connected to {address} in {} ms", elapsed.as_millis()),
+                        None,
+                    ));
+                    if is_selected {
+                        selected_client = Some(client);
+                    }
+                }
+                Err(error) => {
+                    let status = if is_selected {
+                        CheckStatus::Failed
+                    } else {
+                        CheckStatus::Warning
+                    };
+                    results.push(CheckResult::new(
+                        &name,
+                        status,
+                        format!("cannot connect to {address}: {error}"),
+                        Some(get_connection_fix(transport, &address, &error, is_selected)),
+                    ));
+                }
+            }
+        }
+        selected_client
+    }
+
+    fn check_tls(&self, results: &[CheckResult]) -> CheckResult {
+        let name = "TLS";
+        match self.args.transport.as_str() {
+            "tcp" if self.args.tcp_tls_enabled => {
+                let connected = results
+                    .iter()
+                    .any(|result| result.name == "TCP connection" && result.status == CheckStatus::Passed);
+                if connected {
+                    CheckResult::new(
+                        name,
+                        CheckStatus::Passed,
+                        format!("certificate chain is valid for domain: {}", self.args.tcp_tls_domain),
+                        None,
+                    )
+                } else {
+                    CheckResult::new(
+                        name,
+                        CheckStatus::Failed,
+                        format!("cannot establish TLS connection for domain: {}", self.args.tcp_tls_domain),
+                        Some("Make sure the `--tcp-tls-domain` matches the server certificate and the certificate authority is trusted, or that the server has TLS enabled in the `[tcp.tls]` section.".to_owned()),
+                    )
+                }
+            }
+            "quic" if !self.args.quic_validate_certificate => CheckResult::new(
+                name,
+                CheckStatus::Warning,
+                "server certificate is not validated".to_owned(),
+                Some("Pass `--quic-validate-certificate true` to reject the untrusted certificates.".to_owned()),
+            ),
+            "http" if self.args.http_api_url.starts_with("http://") => CheckResult::new(
+                name,
+                CheckStatus::Warning,
+                "HTTP API is used without TLS".to_owned(),
+                Some("Enable `[http.tls]` on the server and use the `https://` URL, unless the server runs locally.".to_owned()),
+            ),
+            "tcp" => CheckResult::new(
+                name,
+                CheckStatus::Warning,
+                "TCP transport is used without TLS".to_owned(),
+                Some("Enable `[tcp.tls]` on the server and pass `--tcp-tls-enabled true`, unless the server runs locally.".to_owned()),
+            ),
+            _ => CheckResult::new(
+                name,
+                CheckStatus::Passed,
+                "connection is encrypted or local".to_owned(),
+                None,
+            ),
+        }
+    }
+
+    async fn check_credentials(&self, client: &dyn Client) -> CheckResult {
+        let name = "Credentials";
+        let (method, result) = match &self.token {
+            Some(token) => (
+                "personal access token".to_owned(),
+                client.login_with_personal_access_token(token).await,
+            ),
+            None => (
+                format!("user: {}", self.args.username),
+                client
+                    .login_user(&self.args.username, &self.args.password)
+                    .await,
+            ),
+        };
+        match result {
+            Ok(identity) => CheckResult::new(
+                name,
+                CheckStatus::Passed,
+                format!("logged in with {method}, user ID: {}", identity.user_id),
+                None,
+            ),
+            Err(error) => CheckResult::new(
+                name,
+                CheckStatus::Failed,
+                format!("cannot log in with {method}: {error}"),
+                Some(match self.token {
+                    Some(_) => "Create a new personal access token with `iggy pat create`, the current one might have expired or been deleted.".to_owned(),
+                    None => "Pass the valid `--username` and `--password` or set the `IGGY_USERNAME` and `IGGY_PASSWORD` environment variables.".to_owned(),
+                }),
+            ),
+        }
+    }
+
+    async fn get_stats(&self, client: &dyn Client) -> Result<(Stats, IggyTimestamp), IggyError> {
+        let sent_at = IggyTimestamp::now().as_micros();
+        let stats = client.get_stats().await?;
+        let received_at = IggyTimestamp::now().as_micros();
+        let local_time = IggyTimestamp::from(sent_at + (received_at - sent_at) / 2);
+        Ok((stats, local_time))
+    }
+
+    fn check_limits(&self, stats: &Stats) -> CheckResult {
+        let name = "Server limits";
+        let transport = self.args.transport.to_uppercase();
+        let Some(utilization) = stats
+            .transport_utilization
+            .iter()
+            .find(|utilization| utilization.transport == transport)
+        else {
+            return CheckResult::new(
+                name,
+                CheckStatus::Skipped,
+                format!("server doesn't report the limits of {transport} transport"),
+                None,
+            );
+        };
+
+        let mut problems = Vec::new();
+        if is_close_to_limit(utilization.connections, utilization.max_connections) {
+            problems.push(format!(
+                "{}/{} connections",
+                utilization.connections, utilization.max_connections
+            ));
+        }
+        if is_close_to_limit(
+            utilization.in_flight_requests,
+            utilization.max_in_flight_requests,
+        ) {
+            problems.push(format!(
+                "{}/{} in-flight requests",
+                utilization.in_flight_requests, utilization.max_in_flight_requests
+            ));
+        }
+        if utilization.rejected_connections > 0 || utilization.rejected_requests > 0 {
+            problems.push(format!(
+                "{} rejected connections, {} rejected requests",
+                utilization.rejected_connections, utilization.rejected_requests
+            ));
+        }
+
+        if problems.is_empty() {
+            return CheckResult::new(
+                name,
+                CheckStatus::Passed,
+                format!(
+                    "{} connections, {} in-flight requests (0 means unlimited)",
+                    format_args!(
+                        "{}/{}",
+                        utilization.connections, utilization.max_connections
+                    ),
+                    format_args!(
+                        "{}/{}",
+                        utilization.in_flight_requests, utilization.max_in_flight_requests
+                    )
+                ),
+                None,
+            );
+        }
+
+        CheckResult::new(
+            name,
+            CheckStatus::Warning,
+            problems.join(", "),
+            Some(format!("Raise the limits in the `[{}.limits]` section of the server configuration, reduce the number of the clients or send smaller batches of messages.", self.args.transport)),
+        )
+    }
+}
+
+#[async_trait]
+impl CliCommand for DoctorCmd {
+    fn explain(&self) -> String {
+        "doctor command".to_owned()
+    }
+
+    fn login_required(&self) -> bool {
+        false
+    }
+
+    fn connection_required(&self) -> bool {
+        false
+    }
+
+    async fn execute_cmd(&mut self, _client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let mut results = Vec::new();
+        let selected_client = self.check_transports(&mut results).await;
+        results.push(self.check_tls(&results));
+
+        let names = ["Credentials", "Clock skew", "Server limits", "Version"];
+        match selected_client {
+            None => {
+                for name in names {
+                    results.push(CheckResult::new(
+                        name,
+                        CheckStatus::Skipped,
+                        format!("cannot connect with {} transport", self.args.transport),
+                        None,
+                    ));
+                }
+            }
+            Some(client) => {
+                let credentials = self.check_credentials(client.as_ref()).await;
+                let is_authenticated = credentials.status == CheckStatus::Passed;
+                results.push(credentials);
+                match is_authenticated {
+                    false => {
+                        for name in &names[1..] {
+                            results.push(CheckResult::new(
+                                name,
+                                CheckStatus::Skipped,
+                                "cannot log in".to_owned(),
+                                None,
+                            ));
+                        }
+                    }
+                    true => match self.get_stats(client.as_ref()).await {
+                        Ok((stats, local_time)) => {
+                            results.push(check_clock_skew(&stats, local_time));
+                            results.push(self.check_limits(&stats));
+                            results.push(check_version(&stats.iggy_server_version, SDK_VERSION));
+                        }
+                        Err(error) => {
+                            for name in &names[1..] {
+                                results.push(CheckResult::new(
+                                    name,
+                                    CheckStatus::Skipped,
+                                    format!("cannot get server stats: {error}"),
+                                    None,
+                                ));
+                            }
+                        }
+                    },
+                }
+                let _ = client.disconnect().await;
+            }
+        }
+
+        let mut table = Table::new();
+        table.set_header(vec!["Check", "Status", "Details"]);
+        for result in &results {
+            table.add_row(vec![
+                result.name.clone(),
+                result.status.to_string(),
+                result.details.clone(),
+            ]);
+        }
+        event!(target: PRINT_TARGET, Level::INFO, "{table}");
+
+        let fixes = results
+            .iter()
+            .filter_map(|result| result.fix.as_ref().map(|fix| (&result.name, fix)))
+            .collect::<Vec<_>>();
+        if !fixes.is_empty() {
+            event!(target: PRINT_TARGET, Level::INFO, "");
+            event!(target: PRINT_TARGET, Level::INFO, "Suggested fixes:");
+            for (name, fix) in fixes {
+                event!(target: PRINT_TARGET, Level::INFO, "- {name}: {fix}");
+            }
+        }
+
+        let failed = results
+            .iter()
+            .filter(|result| result.status == CheckStatus::Failed)
+            .count();
+        if failed > 0 {
+            bail!("{failed} check(s) failed");
+        }
+
+        Ok(())
+    }
+}
+
+fn get_connection_fix(
+    transport: &str,
+    address: &str,
+    error: &IggyError,
+    is_selected: bool,
+) -> String {
+    let fix = match error {
+        IggyError::InvalidTlsCertificate | IggyError::InvalidTlsCertificatePath => {
+            "Check the CA certificate of the server and whether it's trusted by this machine.".to_owned()
+        }
+        IggyError::InvalidTlsDomain => {
+            "Pass the `--tcp-tls-domain` matching the server certificate.".to_owned()
+        }
+        _ if transport == "uds" => format!(
+            "Make sure the server runs on this machine with `[uds]` enabled and the socket at {address} is accessible for the current user."
+        ),
+        _ => format!(
+            "Make sure the server is running, `[{transport}]` is enabled in its configuration and {address} is reachable from this machine (firewall, port, address)."
+        ),
+    };
+    match is_selected {
+        true => fix,
+        false => format!("{fix} Ignore it, if {transport} transport is not used."),
+    }
+}
+
+fn is_close_to_limit(value: u32, limit: u32) -> bool {
+    limit > 0 && value as u64 * 100 >= limit as u64 * UTILIZATION_WARNING_PERCENT
+}
+
+fn check_clock_skew(stats: &Stats, local_time: IggyTimestamp) -> CheckResult {
+    let name = "Clock skew";
+    let server_time = stats.start_time.as_micros() + stats.run_time.as_micros();
+    let skew_secs = (server_time as i64 - local_time.as_micros() as i64) / 1_000_000;
+    if skew_secs.abs() <= MAX_CLOCK_SKEW_SECS {
+        return CheckResult::new(
+            name,
+            CheckStatus::Passed,
+            format!("{skew_secs} s between the server and this machine"),
+            None,
+        );
+    }
+
+    CheckResult::new(
+        name,
+        CheckStatus::Warning,
+        format!("server clock is {skew_secs} s off this machine"),
+        Some("Synchronize the clocks with NTP, the skew affects the expiry of the tokens and the messages, and polling by timestamp.".to_owned()),
+    )
+}
+
+fn check_version(server_version: &str, sdk_version: &str) -> CheckResult {
+    let name = "Version";
+    let (Some(server), Some(sdk)) = (parse_version(server_version), parse_version(sdk_version))
+    else {
+        return CheckResult::new(
+            name,
+            CheckStatus::Warning,
+            format!("cannot compare server version: {server_version} with client version: {sdk_version}"),
+            Some("Upgrade the server, the older ones don't report their version.".to_owned()),
+        );
+    };
+
+    // Before 1.0 every minor release might change the protocol, so only the patch releases are compatible.
+    let is_compatible = match server.0 {
+        0 => server.0 == sdk.0 && server.1 == sdk.1,
+        _ => server.0 == sdk.0,
+    };
+    if is_compatible {
+        return CheckResult::new(
+            name,
+            CheckStatus::Passed,
+            format!("server: {server_version}, client: {sdk_version}"),
+            None,
+        );
+    }
+
+    CheckResult::new(
+        name,
+        CheckStatus::Warning,
+        format!("server: {server_version} might be incompatible with client: {sdk_version}"),
+        Some(format!(
+            "Use the CLI and the SDK matching the server version {}.{}.x.",
+            server.0, server.1
+        )),
+    )
+}
+
+fn parse_version(version: &str) -> Option<(u32, u32, u32)> {
+    let version = version.split(['-', '+']).next()?;
+    let mut parts = version.split('.').map(|part| part.parse::<u32>().ok());
+    Some((parts.next()??, parts.next()??, parts.next()??))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn versions_should_be_compatible_only_within_the_same_release_line() {
+        assert_eq!(check_version("0.4.2", "0.4.0").status, CheckStatus::Passed);
+        assert_eq!(check_version("0.5.0", "0.4.0").status, CheckStatus::Warning);
+        assert_eq!(check_version("1.2.0", "1.0.3").status, CheckStatus::Passed);
+        assert_eq!(check_version("2.0.0", "1.0.3").status, CheckStatus::Warning);
+        assert_eq!(check_version("", "1.0.3").status, CheckStatus::Warning);
+    }
+
+    #[test]
+    fn clock_skew_should_be_reported_above_threshold() {
+        let stats = Stats {
+            start_time: IggyTimestamp::from(1_000_000_000),
+            run_time: Duration::from_secs(100).into(),
+            ..Default::default()
+        };
+
+        let in_sync = IggyTimestamp::from(1_000_000_000 + 101_000_000);
+        let skewed = IggyTimestamp::from(1_000_000_000 + 160_000_000);
+
+        assert_eq!(
+            check_clock_skew(&stats, in_sync).status,
+            CheckStatus::Passed
+        );
+        assert_eq!(
+            check_clock_skew(&stats, skewed).status,
+            CheckStatus::Warning
+        );
+    }
+}
@@ -16,6 +16,7 @@
  * under the License.
  */
 
+pub mod doctor;
 pub mod login;
 pub mod logout;
 pub mod me;
@@ -20,7 +20,8 @@ use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::compression::compression_algorithm::CompressionAlgorithm;
 use crate::identifier::Identifier;
-use crate::topics::create_topic::CreateTopic;
+use crate::models::metadata::ResourceMetadata;
+use crate::topics::create_topic::{CreateTopic, CreateTopicOptions};
 use crate::utils::expiry::IggyExpiry;
 use crate::utils::topic_size::MaxTopicSize;
 use anyhow::Context;
@@ -46,6 +47,7 @@ impl CreateTopicCmd {
         message_expiry: IggyExpiry,
         max_topic_size: MaxTopicSize,
         replication_factor: u8,
+        metadata: ResourceMetadata,
     ) -> Self {
         Self {
             create_topic: CreateTopic {
@@ -57,6 +59,8 @@ impl CreateTopicCmd {
                 message_expiry,
                 max_topic_size,
                 replication_factor: Some(replication_factor),
+                metadata,
+                ..Default::default()
             },
             message_expiry,
             max_topic_size,
@@ -79,8 +83,11 @@ impl CliCommand for CreateTopicCmd {
     }
 
     async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let options = CreateTopicOptions {
+            metadata: self.create_topic.metadata.clone(),
+        };
         client
-            .create_topic(&self.create_topic.stream_id, &self.create_topic.name, self.create_topic.partitions_count, self.create_topic.compression_algorithm, self.create_topic.replication_factor, self.create_topic.topic_id, self.create_topic.message_expiry, self.create_topic.max_topic_size)
+            .create_topic_with_options(&self.create_topic.stream_id, &self.create_topic.name, self.create_topic.partitions_count, self.create_topic.compression_algorithm, self.create_topic.replication_factor, self.create_topic.topic_id, self.create_topic.message_expiry, self.create_topic.max_topic_size, &options)
             .await
             .with_context(|| {
                 format!(
@@ -81,6 +81,14 @@ impl CliCommand for GetTopicCmd {
             "Compression",
             topic.compression_algorithm.to_string().as_str(),
         ]);
+        table.add_row(vec![
+            "Message ID scheme",
+            topic.message_id_scheme.to_string().as_str(),
+        ]);
+        table.add_row(vec![
+            "Cleanup policy",
+            topic.cleanup_policy.to_string().as_str(),
+        ]);
         table.add_row(vec![
             "Message expiry",
             match topic.message_expiry {
@@ -94,6 +102,14 @@ impl CliCommand for GetTopicCmd {
             "Max topic size",
             format!("{}", topic.max_topic_size).as_str(),
         ]);
+        table.add_row(vec![
+            "Max segments",
+            match topic.max_segments {
+                0 => String::from("unlimited"),
+                max_segments => format!("{max_segments} per partition"),
+            }
+            .as_str(),
+        ]);
         table.add_row(vec![
             "Topic message count",
             format!("{}", topic.messages_count).as_str(),
@@ -103,6 +119,49 @@ impl CliCommand for GetTopicCmd {
             format!("{}", topic.partitions_count).as_str(),
         ]);
 
+        let message_sizes = &topic.message_sizes;
+        if message_sizes.messages_count > 0 {
+            table.add_row(vec![
+                "Message sizes",
+                format!(
+                    "min: {}, avg: {}, max: {}\np50: ~{}, p90: ~{}, p99: ~{}",
+                    message_sizes.min_size,
+                    message_sizes.average_size(),
+                    message_sizes.max_size,
+                    message_sizes.percentile(50.0),
+                    message_sizes.percentile(90.0),
+                    message_sizes.percentile(99.0)
+                )
+                .as_str(),
+            ]);
+        }
+
+        let recovering_partitions = topic
+            .partitions
+            .iter()
+            .filter_map(|partition| {
+                let recovery = partition.recovery.as_ref()?;
+                Some(format!(
+                    "partition {}: {}/{} segments ({}/{}), ETA: {}",
+                    partition.id,
+                    recovery.recovered_segments,
+                    recovery.total_segments,
+                    recovery.recovered_size,
+                    recovery.total_size,
+                    match recovery.eta {
+                        Some(eta) => eta.as_human_time_string(),
+                        None => String::from("unknown"),
+                    }
+                ))
+            })
+            .collect::<Vec<_>>();
+        if !recovering_partitions.is_empty() {
+            table.add_row(vec![
+                "Recovering partitions",
+                recovering_partitions.join("\n").as_str(),
+            ]);
+        }
+
         event!(target: PRINT_TARGET, Level::INFO,"{table}");
 
         Ok(())
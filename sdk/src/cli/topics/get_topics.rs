@@ -19,6 +19,7 @@
 use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::identifier::Identifier;
+use crate::models::metadata::MetadataFilter;
 use crate::topics::get_topics::GetTopics;
 use crate::utils::expiry::IggyExpiry;
 use anyhow::Context;
@@ -49,9 +50,9 @@ pub struct GetTopicsCmd {
 }
 
 impl GetTopicsCmd {
-    pub fn new(stream_id: Identifier, output: GetTopicsOutput) -> Self {
+    pub fn new(stream_id: Identifier, output: GetTopicsOutput, filter: MetadataFilter) -> Self {
         Self {
-            get_topics: GetTopics { stream_id },
+            get_topics: GetTopics { stream_id, filter },
             output,
         }
     }
@@ -68,7 +69,7 @@ impl CliCommand for GetTopicsCmd {
 
     async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
         let topics = client
-            .get_topics(&self.get_topics.stream_id)
+            .get_topics_by_metadata(&self.get_topics.stream_id, &self.get_topics.filter)
             .await
             .with_context(|| {
                 format!(
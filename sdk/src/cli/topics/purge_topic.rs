@@ -19,7 +19,9 @@
 use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::identifier::Identifier;
-use crate::topics::purge_topic::PurgeTopic;
+use crate::topics::purge_topic::{PurgeTopic, PurgeTopicOptions};
+use crate::utils::duration::IggyDuration;
+use crate::utils::timestamp::IggyTimestamp;
 use anyhow::Context;
 use async_trait::async_trait;
 use tracing::{event, Level};
@@ -29,11 +31,25 @@ pub struct PurgeTopicCmd {
 }
 
 impl PurgeTopicCmd {
-    pub fn new(stream_id: Identifier, topic_id: Identifier) -> Self {
+    pub fn new(
+        stream_id: Identifier,
+        topic_id: Identifier,
+        keep_consumer_offsets: bool,
+        older_than: Option<IggyDuration>,
+    ) -> Self {
+        let older_than = older_than.map(|duration| {
+            IggyTimestamp::from(
+                IggyTimestamp::now()
+                    .as_micros()
+                    .saturating_sub(duration.as_micros()),
+            )
+        });
         Self {
             purge_topic: PurgeTopic {
                 stream_id,
                 topic_id,
+                keep_consumer_offsets,
+                older_than,
             },
         }
     }
@@ -50,7 +66,14 @@ impl CliCommand for PurgeTopicCmd {
 
     async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
         client
-            .purge_topic(&self.purge_topic.stream_id, &self.purge_topic.topic_id)
+            .purge_topic_with_options(
+                &self.purge_topic.stream_id,
+                &self.purge_topic.topic_id,
+                &PurgeTopicOptions {
+                    keep_consumer_offsets: self.purge_topic.keep_consumer_offsets,
+                    older_than: self.purge_topic.older_than,
+                },
+            )
             .await
             .with_context(|| {
                 format!(
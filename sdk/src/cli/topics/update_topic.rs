@@ -20,7 +20,7 @@ use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::compression::compression_algorithm::CompressionAlgorithm;
 use crate::identifier::Identifier;
-use crate::topics::update_topic::UpdateTopic;
+use crate::topics::update_topic::{UpdateTopic, UpdateTopicOptions};
 use crate::utils::expiry::IggyExpiry;
 use crate::utils::topic_size::MaxTopicSize;
 use anyhow::Context;
@@ -44,6 +44,7 @@ impl UpdateTopicCmd {
         message_expiry: IggyExpiry,
         max_topic_size: MaxTopicSize,
         replication_factor: u8,
+        max_segments: Option<u32>,
     ) -> Self {
         Self {
             update_topic: UpdateTopic {
@@ -54,12 +55,20 @@ impl UpdateTopicCmd {
                 message_expiry,
                 max_topic_size,
                 replication_factor: Some(replication_factor),
+                max_segments,
             },
             message_expiry,
             max_topic_size,
             replication_factor,
         }
     }
+
+    fn max_segments(&self) -> String {
+        match self.update_topic.max_segments {
+            Some(max_segments) => max_segments.to_string(),
+            None => "unchanged".to_string(),
+        }
+    }
 }
 
 #[async_trait]
@@ -70,7 +79,7 @@ impl CliCommand for UpdateTopicCmd {
 
     async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
         client
-            .update_topic(&self.update_topic.stream_id, &self.update_topic.topic_id, &self.update_topic.name, self.update_topic.compression_algorithm, self.replication_factor.into(), self.message_expiry, self.max_topic_size)
+            .update_topic_with_options(&self.update_topic.stream_id, &self.update_topic.topic_id, &self.update_topic.name, self.update_topic.compression_algorithm, self.replication_factor.into(), self.message_expiry, self.max_topic_size, &UpdateTopicOptions { max_segments: self.update_topic.max_segments })
             .await
             .with_context(|| {
                 format!(
@@ -83,13 +92,14 @@ impl CliCommand for UpdateTopicCmd {
             })?;
 
         event!(target: PRINT_TARGET, Level::INFO,
-            "Topic with ID: {} updated name: {}, updated message expiry: {}, updated compression algorithm: {}, updated max topic size: {}, updated replication factor: {} in stream with ID: {}",
+            "Topic with ID: {} updated name: {}, updated message expiry: {}, updated compression algorithm: {}, updated max topic size: {}, updated replication factor: {}, updated max segments: {} in stream with ID: {}",
             self.update_topic.topic_id,
             self.update_topic.name,
             self.message_expiry,
             self.update_topic.compression_algorithm,
             self.max_topic_size,
             self.replication_factor,
+            self.max_segments(),
             self.update_topic.stream_id,
         );
 
@@ -105,13 +115,14 @@ impl fmt::Display for UpdateTopicCmd {
         let message_expiry = &self.message_expiry;
         let max_topic_size = &self.max_topic_size;
         let replication_factor = self.replication_factor;
+        let max_segments = self.max_segments();
         let stream_id = &self.update_topic.stream_id;
 
         write!(
             f,
             "update topic with ID: {topic_id}, name: {topic_name}, message expiry: \
             {message_expiry}, compression algorithm: {compression_algorithm}, max topic size: {max_topic_size}, replication \
-            factor: {replication_factor}, in stream with ID: {stream_id}",
+            factor: {replication_factor}, max segments: {max_segments}, in stream with ID: {stream_id}",
         )
     }
 }
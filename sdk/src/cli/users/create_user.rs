@@ -42,6 +42,7 @@ impl CreateUserCmd {
                 password,
                 status,
                 permissions,
+                namespace_id: None,
             },
         }
     }
@@ -18,32 +18,65 @@
 
 use crate::compression::compression_algorithm::CompressionAlgorithm;
 use crate::consumer::Consumer;
+use crate::consumer_groups::offset_recovery_policy::OffsetRecoveryPolicy;
+use crate::consumer_offsets::get_offsets_for_timestamps::PartitionTimestamp;
+use crate::consumer_offsets::store_consumer_offsets::PartitionOffset;
 use crate::diagnostic::DiagnosticEvent;
 use crate::error::IggyError;
 use crate::identifier::Identifier;
-use crate::messages::poll_messages::PollingStrategy;
+use crate::messages::message_filter::MessageFilter;
+use crate::messages::poll_messages::{IsolationLevel, PollMessagesOptions, PollingStrategy};
+use crate::messages::replay_messages::{HeadersTransform, ReplayRange};
 use crate::messages::send_messages::{Message, Partitioning};
+use crate::messages::send_messages_batch::TopicMessages;
+use crate::models::audit_entry::{AuditAction, AuditEntry};
+use crate::models::backup::Backup;
 use crate::models::client_info::{ClientInfo, ClientInfoDetails};
 use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails};
 use crate::models::consumer_offset_info::ConsumerOffsetInfo;
+use crate::models::error_catalog::ErrorCatalogEntry;
 use crate::models::identity_info::IdentityInfo;
+use crate::models::maintenance_mode::MaintenanceMode;
 use crate::models::messages::PolledMessages;
+use crate::models::messages_aggregate::MessagesBucket;
+use crate::models::metadata::{MetadataFilter, ResourceMetadata};
+use crate::models::namespace::{Namespace, NamespaceDetails, NamespaceQuotas};
+use crate::models::partition_timestamp_offset::PartitionTimestampOffset;
 use crate::models::permissions::Permissions;
-use crate::models::personal_access_token::{PersonalAccessTokenInfo, RawPersonalAccessToken};
+use crate::models::personal_access_token::{
+    PersonalAccessTokenInfo, PersonalAccessTokenScope, RawPersonalAccessToken,
+};
+use crate::models::producer_epoch::ProducerEpoch;
+use crate::models::producer_session::ProducerSession;
+use crate::models::push_subscription::PushSubscription;
+use crate::models::replay_job::ReplayJob;
+use crate::models::restored_segments::RestoredSegments;
+use crate::models::segment_range::SegmentRange;
+use crate::models::server_info::ServerInfo;
 use crate::models::snapshot::Snapshot;
 use crate::models::stats::Stats;
-use crate::models::stream::{Stream, StreamDetails};
+use crate::models::stream::{Stream, StreamDetails, StreamQuota};
 use crate::models::topic::{Topic, TopicDetails};
+use crate::models::transaction::Transaction;
+use crate::models::unsaved_state::PartitionUnsavedState;
 use crate::models::user_info::{UserInfo, UserInfoDetails};
 use crate::models::user_status::UserStatus;
 use crate::snapshot::{SnapshotCompression, SystemSnapshotType};
-use crate::tcp::config::{TcpClientConfig, TcpClientReconnectionConfig};
+use crate::streams::create_stream::CreateStreamOptions;
+use crate::tcp::config::{TcpClientConfig, TcpClientEndpointsConfig, TcpClientReconnectionConfig};
+use crate::topics::create_topic::CreateTopicOptions;
+use crate::topics::purge_topic::PurgeTopicOptions;
+use crate::topics::topic_config::TopicConfig;
+use crate::topics::update_topic::UpdateTopicOptions;
+use crate::users::user_quotas::UserQuotas;
 use crate::utils::duration::IggyDuration;
 use crate::utils::expiry::IggyExpiry;
 use crate::utils::personal_access_token_expiry::PersonalAccessTokenExpiry;
+use crate::utils::timestamp::IggyTimestamp;
 use crate::utils::topic_size::MaxTopicSize;
 use async_broadcast::Receiver;
 use async_trait::async_trait;
+use bytes::Bytes;
 use std::fmt::Debug;
 use std::str::FromStr;
 
@@ -76,6 +109,7 @@ pub trait Client:
     SystemClient
     + UserClient
     + PersonalAccessTokenClient
+    + NamespaceClient
     + StreamClient
     + TopicClient
     + PartitionClient
@@ -130,6 +164,50 @@ pub trait SystemClient {
         compression: SnapshotCompression,
         snapshot_types: Vec<SystemSnapshotType>,
     ) -> Result<Snapshot, IggyError>;
+    /// Get the current maintenance mode of the server, including the optional message explaining the maintenance.
+    ///
+    /// Authentication is required.
+    async fn get_maintenance_mode(&self) -> Result<MaintenanceMode, IggyError>;
+    /// Enable or disable the server-wide maintenance mode. While it's enabled, the writes (and optionally the reads)
+    /// are rejected with the retryable `ServerInMaintenance` error, and the buffered messages are saved on disk.
+    ///
+    /// Authentication is required, and the permission to manage the servers.
+    async fn set_maintenance_mode(
+        &self,
+        enabled: bool,
+        reject_reads: bool,
+        message: Option<&str>,
+    ) -> Result<(), IggyError>;
+    /// Get the server version and the server-wide limits of the streams, topics and partitions, enforced when creating them.
+    ///
+    /// Authentication is required.
+    async fn get_server_info(&self) -> Result<ServerInfo, IggyError>;
+    /// Take a consistent backup of the server data while it keeps running, in the backup directory of the server.
+    /// The writes are paused only for the time of the cut, and the backup can be restored
+    /// by replacing the data directory of the stopped server with the backup directory.
+    ///
+    /// Authentication is required, and the permission to manage the servers.
+    async fn create_backup(&self, name: &str) -> Result<Backup, IggyError>;
+    /// Get the messages of all the partitions which are accumulated in memory and not yet saved on disk.
+    /// Only the partitions having any unsaved messages are returned, so an empty list means that all the messages are saved.
+    ///
+    /// Authentication is required, and the permission to read the server info.
+    async fn get_unsaved_state(&self) -> Result<Vec<PartitionUnsavedState>, IggyError>;
+    /// Get the most recent entries of the audit log, in chronological order.
+    /// The entries can be optionally filtered by the user who performed the action and the kind of the action,
+    /// only the entries still kept in memory by the server are returned.
+    ///
+    /// Authentication is required, and the permission to read the server info.
+    async fn get_audit_log(
+        &self,
+        count: u32,
+        user_id: Option<u32>,
+        action: Option<AuditAction>,
+    ) -> Result<Vec<AuditEntry>, IggyError>;
+    /// Get the stable numeric codes, names and categories of all the errors known to the server,
+    /// so that the failures can be handled by the code or the category instead of the error message.
+    /// The code of the error is returned as the status of the binary response and the `id` of the HTTP error response.
+    async fn get_error_catalog(&self) -> Result<Vec<ErrorCatalogEntry>, IggyError>;
 }
 
 /// This trait defines the methods to interact with the user module.
@@ -174,6 +252,15 @@ pub trait UserClient {
         user_id: &Identifier,
         permissions: Option<Permissions>,
     ) -> Result<(), IggyError>;
+    /// Override the server default quotas (produce and consume byte rates, request rate) for a user by unique ID or username.
+    /// The given quotas replace the previous ones, the quotas left as `None` use the server defaults.
+    ///
+    /// Authentication is required, and the permission to manage the users.
+    async fn update_user_quotas(
+        &self,
+        user_id: &Identifier,
+        quotas: UserQuotas,
+    ) -> Result<(), IggyError>;
     /// Change the password of a user by unique ID or username.
     ///
     /// Authentication is required, and the permission to manage the users, unless the provided user ID is the same as the authenticated user.
@@ -187,6 +274,10 @@ pub trait UserClient {
     async fn login_user(&self, username: &str, password: &str) -> Result<IdentityInfo, IggyError>;
     /// Logout the currently authenticated user.
     async fn logout_user(&self) -> Result<(), IggyError>;
+    /// Impersonate the user by unique ID (numeric or name) for the rest of the session, until logout.
+    ///
+    /// Authentication is required, and the permission to manage the users.
+    async fn login_as(&self, user_id: &Identifier) -> Result<IdentityInfo, IggyError>;
 }
 
 /// This trait defines the methods to interact with the personal access token module.
@@ -200,8 +291,25 @@ pub trait PersonalAccessTokenClient {
         name: &str,
         expiry: PersonalAccessTokenExpiry,
     ) -> Result<RawPersonalAccessToken, IggyError>;
+    /// Create a new personal access token for the currently authenticated user,
+    /// restricted to the provided scope (read-only, specific streams and topics, allowed source IP ranges).
+    ///
+    /// The tokens restricted to the read-only commands or to the specific streams can be used only with the binary protocol (TCP, QUIC, UDS and WebSocket).
+    async fn create_scoped_personal_access_token(
+        &self,
+        name: &str,
+        expiry: PersonalAccessTokenExpiry,
+        scope: &PersonalAccessTokenScope,
+    ) -> Result<RawPersonalAccessToken, IggyError>;
     /// Delete a personal access token of the currently authenticated user by unique token name.
     async fn delete_personal_access_token(&self, name: &str) -> Result<(), IggyError>;
+    /// Replace the secret of a personal access token of the currently authenticated user with a new one,
+    /// keeping its name and scope, and returning the new secret. The previous secret stops working immediately.
+    async fn rotate_personal_access_token(
+        &self,
+        name: &str,
+        expiry: PersonalAccessTokenExpiry,
+    ) -> Result<RawPersonalAccessToken, IggyError>;
     /// Login the user with the provided personal access token.
     async fn login_with_personal_access_token(
         &self,
@@ -209,6 +317,66 @@ pub trait PersonalAccessTokenClient {
     ) -> Result<IdentityInfo, IggyError>;
 }
 
+/// This trait defines the methods to interact with the namespace module.
+/// The namespace isolates its own streams, users and personal access tokens from the other namespaces,
+/// the namespaced users can only access the streams and the users of their namespace.
+#[async_trait]
+pub trait NamespaceClient {
+    /// Get the info about a specific namespace by unique ID or name, including its streams, users and usage.
+    ///
+    /// Authentication is required, and either the permission to read the servers or the membership of the namespace.
+    async fn get_namespace(
+        &self,
+        namespace_id: &Identifier,
+    ) -> Result<Option<NamespaceDetails>, IggyError>;
+    /// Get the info about all the namespaces.
+    ///
+    /// Authentication is required, and the permission to read the servers outside any namespace.
+    async fn get_namespaces(&self) -> Result<Vec<Namespace>, IggyError>;
+    /// Create a new namespace with the limits of its streams, users and storage (`0` means unlimited).
+    ///
+    /// Authentication is required, and the permission to manage the servers outside any namespace.
+    async fn create_namespace(
+        &self,
+        name: &str,
+        quotas: NamespaceQuotas,
+    ) -> Result<NamespaceDetails, IggyError>;
+    /// Replace the limits of the streams, users and storage of a namespace by unique ID or name.
+    ///
+    /// Authentication is required, and the permission to manage the servers outside any namespace.
+    async fn update_namespace(
+        &self,
+        namespace_id: &Identifier,
+        quotas: NamespaceQuotas,
+    ) -> Result<(), IggyError>;
+    /// Delete a namespace by unique ID or name, it must not contain any streams or users.
+    ///
+    /// Authentication is required, and the permission to manage the servers outside any namespace.
+    async fn delete_namespace(&self, namespace_id: &Identifier) -> Result<(), IggyError>;
+    /// Create a new stream in the given namespace.
+    /// The namespaced users can only create the streams in their own namespace, as with the `create_stream` method.
+    ///
+    /// Authentication is required, and the permission to manage the streams.
+    async fn create_namespace_stream(
+        &self,
+        namespace_id: u32,
+        name: &str,
+        stream_id: Option<u32>,
+    ) -> Result<StreamDetails, IggyError>;
+    /// Create a new user in the given namespace.
+    /// The namespaced users can only create the users in their own namespace, as with the `create_user` method.
+    ///
+    /// Authentication is required, and the permission to manage the users.
+    async fn create_namespace_user(
+        &self,
+        namespace_id: u32,
+        username: &str,
+        password: &str,
+        status: UserStatus,
+        permissions: Option<Permissions>,
+    ) -> Result<UserInfoDetails, IggyError>;
+}
+
 /// This trait defines the methods to interact with the stream module.
 #[async_trait]
 pub trait StreamClient {
@@ -220,6 +388,13 @@ pub trait StreamClient {
     ///
     /// Authentication is required, and the permission to read the streams.
     async fn get_streams(&self) -> Result<Vec<Stream>, IggyError>;
+    /// Get the info about the streams matching the provided metadata filter (owner, labels, description).
+    ///
+    /// Authentication is required, and the permission to read the streams.
+    async fn get_streams_by_metadata(
+        &self,
+        filter: &MetadataFilter,
+    ) -> Result<Vec<Stream>, IggyError>;
     /// Create a new stream.
     ///
     /// Authentication is required, and the permission to manage the streams.
@@ -228,10 +403,35 @@ pub trait StreamClient {
         name: &str,
         stream_id: Option<u32>,
     ) -> Result<StreamDetails, IggyError>;
+    /// Create a new stream with the optional settings, such as the metadata (description, owner and labels).
+    ///
+    /// Authentication is required, and the permission to manage the streams.
+    async fn create_stream_with_options(
+        &self,
+        name: &str,
+        stream_id: Option<u32>,
+        options: &CreateStreamOptions,
+    ) -> Result<StreamDetails, IggyError>;
     /// Update a stream by unique ID or name.
     ///
     /// Authentication is required, and the permission to manage the streams.
     async fn update_stream(&self, stream_id: &Identifier, name: &str) -> Result<(), IggyError>;
+    /// Replace the metadata (description, owner and labels) of a stream by unique ID or name.
+    ///
+    /// Authentication is required, and the permission to manage the streams.
+    async fn update_stream_metadata(
+        &self,
+        stream_id: &Identifier,
+        metadata: ResourceMetadata,
+    ) -> Result<(), IggyError>;
+    /// Replace the storage quota (soft and hard limits) of a stream by unique ID or name.
+    ///
+    /// Authentication is required, and the permission to manage the streams.
+    async fn update_stream_quota(
+        &self,
+        stream_id: &Identifier,
+        quota: StreamQuota,
+    ) -> Result<(), IggyError>;
     /// Delete a stream by unique ID or name.
     ///
     /// Authentication is required, and the permission to manage the streams.
@@ -258,6 +458,14 @@ pub trait TopicClient {
     ///
     /// Authentication is required, and the permission to read the topics.
     async fn get_topics(&self, stream_id: &Identifier) -> Result<Vec<Topic>, IggyError>;
+    /// Get the info about the topics matching the provided metadata filter (owner, labels, description).
+    ///
+    /// Authentication is required, and the permission to read the topics.
+    async fn get_topics_by_metadata(
+        &self,
+        stream_id: &Identifier,
+        filter: &MetadataFilter,
+    ) -> Result<Vec<Topic>, IggyError>;
     /// Create a new topic.
     ///
     /// Authentication is required, and the permission to manage the topics.
@@ -273,6 +481,22 @@ pub trait TopicClient {
         message_expiry: IggyExpiry,
         max_topic_size: MaxTopicSize,
     ) -> Result<TopicDetails, IggyError>;
+    /// Create a new topic with the optional settings, such as the metadata (description, owner and labels).
+    ///
+    /// Authentication is required, and the permission to manage the topics.
+    #[allow(clippy::too_many_arguments)]
+    async fn create_topic_with_options(
+        &self,
+        stream_id: &Identifier,
+        name: &str,
+        partitions_count: u32,
+        compression_algorithm: CompressionAlgorithm,
+        replication_factor: Option<u8>,
+        topic_id: Option<u32>,
+        message_expiry: IggyExpiry,
+        max_topic_size: MaxTopicSize,
+        options: &CreateTopicOptions,
+    ) -> Result<TopicDetails, IggyError>;
     /// Update a topic by unique ID or name.
     ///
     /// Authentication is required, and the permission to manage the topics.
@@ -286,6 +510,74 @@ pub trait TopicClient {
         message_expiry: IggyExpiry,
         max_topic_size: MaxTopicSize,
     ) -> Result<(), IggyError>;
+    /// Update a topic by unique ID or name with the optional settings, such as the max number of segments per partition.
+    /// The settings which are not set are kept as they are.
+    ///
+    /// Authentication is required, and the permission to manage the topics.
+    #[allow(clippy::too_many_arguments)]
+    async fn update_topic_with_options(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        name: &str,
+        compression_algorithm: CompressionAlgorithm,
+        replication_factor: Option<u8>,
+        message_expiry: IggyExpiry,
+        max_topic_size: MaxTopicSize,
+        options: &UpdateTopicOptions,
+    ) -> Result<(), IggyError>;
+    /// Replace the metadata (description, owner and labels) of a topic by unique ID or name.
+    ///
+    /// Authentication is required, and the permission to manage the topics.
+    async fn update_topic_metadata(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        metadata: ResourceMetadata,
+    ) -> Result<(), IggyError>;
+    /// Restrict the producers of a topic by unique ID or name to the users with the given IDs,
+    /// even if the other users have the permission to send the messages. Empty list removes the restriction.
+    ///
+    /// Authentication is required, and the permission to manage the topics.
+    async fn update_topic_producers(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        allowed_producers: &[u32],
+    ) -> Result<(), IggyError>;
+    /// Override the server defaults (segment size, message expiry, cache and compression) for a topic by unique ID or name.
+    /// The given config replaces the previous one, the settings left as `None` use the server defaults.
+    ///
+    /// Authentication is required, and the permission to manage the topics.
+    async fn update_topic_config(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        config: TopicConfig,
+    ) -> Result<(), IggyError>;
+    /// Register (or remove) the interest of the consumer or the consumer group in the expiry of the messages
+    /// of a topic by unique ID or name. The registered consumers are listed in the notifications published
+    /// to the internal `__expiry_notifications` topic, which precede the deletion of the expired messages.
+    ///
+    /// Authentication is required, and the permission to poll the messages.
+    async fn update_topic_expiry_watcher(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        consumer: &Consumer,
+        enabled: bool,
+    ) -> Result<(), IggyError>;
+    /// Mark a topic by unique ID or name for deletion. The topic stops accepting the messages immediately,
+    /// but it can still be polled until the drain period elapses (`0` for the server default one), and then it's deleted.
+    /// The polled messages carry the time of the deletion, so that the consumers can tell the topic is going away.
+    ///
+    /// Authentication is required, and the permission to manage the topics.
+    async fn mark_topic_for_deletion(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        drain_period: IggyDuration,
+    ) -> Result<(), IggyError>;
     /// Delete a topic by unique ID or name.
     ///
     /// Authentication is required, and the permission to manage the topics.
@@ -296,12 +588,26 @@ pub trait TopicClient {
     ) -> Result<(), IggyError>;
     /// Purge a topic by unique ID or name.
     ///
+    /// All the messages are removed and the offsets are reset, including the stored consumer offsets.
+    ///
     /// Authentication is required, and the permission to manage the topics.
     async fn purge_topic(
         &self,
         stream_id: &Identifier,
         topic_id: &Identifier,
     ) -> Result<(), IggyError>;
+    /// Purge a topic by unique ID or name with the optional settings.
+    ///
+    /// When `keep_consumer_offsets` is set, the offsets sequence continues and the consumer offsets are kept.
+    /// When `older_than` is set, only the closed segments with all the messages older than it are removed.
+    ///
+    /// Authentication is required, and the permission to manage the topics.
+    async fn purge_topic_with_options(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        options: &PurgeTopicOptions,
+    ) -> Result<(), IggyError>;
 }
 
 /// This trait defines the methods to interact with the partition module.
@@ -329,6 +635,47 @@ pub trait PartitionClient {
         topic_id: &Identifier,
         partitions_count: u32,
     ) -> Result<(), IggyError>;
+    /// Restore the archived segments of the partition overlapping the given offsets range back into the local storage.
+    ///
+    /// The segments still available locally are skipped, and the restored ones are no longer deleted once expired.
+    ///
+    /// Authentication is required, and the permission to manage the servers.
+    async fn restore_archived_segments(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        start_offset: u64,
+        end_offset: u64,
+    ) -> Result<RestoredSegments, IggyError>;
+    /// Save the unsaved messages of the partition (or all the partitions of the topic, if not specified) on disk and fsync them,
+    /// regardless of the message saver interval, returning the state of the flushed partitions.
+    ///
+    /// Authentication is required, and the permission to manage the servers.
+    async fn flush_partition(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+    ) -> Result<Vec<PartitionUnsavedState>, IggyError>;
+    /// Read the raw bytes of the persisted message batches from the segment containing the given offset,
+    /// starting at the first batch located at or after the start position, and including the batches starting before the end position
+    /// (or the end of the segment, if not specified) as long as they fit within the maximum number of bytes (at least one batch is always returned).
+    /// The returned `next_position` can be used to read the next range, which allows the external processors to read the history in parallel
+    /// by splitting the segments into position ranges, without going through the regular polling of the messages.
+    ///
+    /// Authentication is required, and the permission to read the servers and poll the messages from the topic.
+    #[allow(clippy::too_many_arguments)]
+    async fn read_segment_range(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        offset: u64,
+        start_position: u64,
+        end_position: Option<u64>,
+        max_bytes: u32,
+    ) -> Result<SegmentRange, IggyError>;
 }
 
 /// This trait defines the methods to interact with the messaging module.
@@ -348,6 +695,57 @@ pub trait MessageClient {
         count: u32,
         auto_commit: bool,
     ) -> Result<PolledMessages, IggyError>;
+    /// Poll given amount of messages like `poll_messages` with the optional settings,
+    /// such as the isolation level, the chunk size, the filter and the expected epoch of the partition.
+    ///
+    /// Authentication is required, and the permission to poll the messages.
+    #[allow(clippy::too_many_arguments)]
+    async fn poll_messages_with_options(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        auto_commit: bool,
+        options: &PollMessagesOptions,
+    ) -> Result<PolledMessages, IggyError>;
+    /// Browse given amount of messages like `poll_messages_with_options`, without perturbing the delivery state,
+    /// e.g. by the monitoring or debugging tools running against the production consumer groups.
+    /// The offset is never committed, and for the consumer group, the partition of the member isn't moved to the next one,
+    /// nor are the messages tracked as in-flight or dead-lettered. The `partition_id` is required for the consumer group
+    /// if the client isn't its member.
+    ///
+    /// Authentication is required, and the permission to poll the messages.
+    #[allow(clippy::too_many_arguments)]
+    async fn peek_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        isolation_level: IsolationLevel,
+        filter: Option<&MessageFilter>,
+    ) -> Result<PolledMessages, IggyError>;
+    /// Aggregate the messages of the given partition, or all the partitions if `None`, for the specified stream and topic by unique IDs or names,
+    /// computing the messages count, their size and the min/max timestamps per time bucket of the given width within the `[from, to)` range.
+    /// Only the segment indexes are read, so the messages are attributed to the buckets per batch, using the timestamp of its latest message.
+    /// The empty buckets are returned as well, e.g. to detect the gaps in the traffic.
+    ///
+    /// Authentication is required, and the permission to poll the messages.
+    #[allow(clippy::too_many_arguments)]
+    async fn aggregate_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        from: IggyTimestamp,
+        to: IggyTimestamp,
+        bucket: IggyDuration,
+    ) -> Result<Vec<MessagesBucket>, IggyError>;
     /// Send messages using specified partitioning strategy to the given stream and topic by unique IDs or names.
     ///
     /// Authentication is required, and the permission to send the messages.
@@ -358,6 +756,12 @@ pub trait MessageClient {
         partitioning: &Partitioning,
         messages: &mut [Message],
     ) -> Result<(), IggyError>;
+    /// Send the messages to many topics and partitions in a single request, either all of them are appended, or none of them.
+    /// Unless the messages are sent within the transaction, they're appended within the implicit one,
+    /// so that the read-committed consumers see all or none of them.
+    ///
+    /// Authentication is required, and the permission to send the messages to all the topics.
+    async fn send_messages_batch(&self, batches: &mut [TopicMessages]) -> Result<(), IggyError>;
     /// Force flush of the `unsaved_messages` buffer to disk, optionally fsyncing the data.
     #[allow(clippy::too_many_arguments)]
     async fn flush_unsaved_buffer(
@@ -367,6 +771,115 @@ pub trait MessageClient {
         partition_id: u32,
         fsync: bool,
     ) -> Result<(), IggyError>;
+    /// Negatively acknowledge the messages polled by the consumer group member, so that the server republishes them
+    /// to the dead-letter topic of the consumer group, with the failure reason in their headers.
+    /// The dead-lettered messages are skipped by the subsequent polls of the consumer group.
+    /// If the consumer group has the visibility timeout configured, but no dead-letter topic, the messages are redelivered immediately instead.
+    ///
+    /// Authentication is required, and the permission to poll the messages.
+    async fn nack_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        offsets: &[u64],
+        reason: &str,
+    ) -> Result<(), IggyError>;
+    /// Acknowledge the messages polled by the consumer group member having the visibility timeout configured.
+    /// The offset of the consumer group is committed up to the first unacknowledged message,
+    /// while the messages not acknowledged within the visibility timeout are redelivered.
+    ///
+    /// Authentication is required, and the permission to poll the messages.
+    async fn ack_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        offsets: &[u64],
+    ) -> Result<(), IggyError>;
+    /// Replay the messages from the given range of the source partition into the destination topic, optionally transforming their headers.
+    /// The replay is executed by the server in the background at the specified rate (`0` means unlimited), use `get_replay_jobs` to track its progress.
+    ///
+    /// Authentication is required, and the permission to poll the messages from the source topic and to send the messages to the destination topic.
+    #[allow(clippy::too_many_arguments)]
+    async fn replay_messages(
+        &self,
+        source_stream_id: &Identifier,
+        source_topic_id: &Identifier,
+        source_partition_id: u32,
+        range: &ReplayRange,
+        destination_stream_id: &Identifier,
+        destination_topic_id: &Identifier,
+        partitioning: &Partitioning,
+        headers: &HeadersTransform,
+        messages_per_second: u32,
+    ) -> Result<ReplayJob, IggyError>;
+    /// Get the progress of the replay jobs started by the current user, or all of them for the user with the `read_servers` or `manage_servers` permission.
+    ///
+    /// Authentication is required.
+    async fn get_replay_jobs(&self) -> Result<Vec<ReplayJob>, IggyError>;
+    /// Cancel the running replay job by its unique ID. The already replayed messages are kept in the destination topic.
+    ///
+    /// Authentication is required, and the job must be started by the current user or the user must have the `manage_servers` permission.
+    async fn cancel_replay_job(&self, job_id: u32) -> Result<(), IggyError>;
+    /// Register the HTTP(S) endpoint to which the server pushes the messages from the given partition, starting with the specified offset.
+    /// The server polls the partition on behalf of the current user and sends the batches of messages with the POST request,
+    /// the failed deliveries are retried with the exponential backoff and the acknowledged offset is tracked per subscription.
+    ///
+    /// Authentication is required, and the permission to poll the messages from the topic.
+    async fn create_push_subscription(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        endpoint: &str,
+        start_offset: u64,
+        batch_size: u32,
+    ) -> Result<PushSubscription, IggyError>;
+    /// Get the state of the push subscriptions created by the current user, or all of them for the user with the `read_servers` or `manage_servers` permission.
+    ///
+    /// Authentication is required.
+    async fn get_push_subscriptions(&self) -> Result<Vec<PushSubscription>, IggyError>;
+    /// Delete the push subscription by its unique ID, no more messages are pushed to its endpoint.
+    ///
+    /// Authentication is required, and the subscription must be created by the current user or the user must have the `manage_servers` permission.
+    async fn delete_push_subscription(&self, subscription_id: u32) -> Result<(), IggyError>;
+    /// Register the new instance of the named producer for the given stream and topic by unique IDs or names, and bump its epoch.
+    /// The messages sent with the producer headers carrying any older epoch of the same producer are rejected with the `ProducerFenced` error.
+    ///
+    /// Authentication is required, and the permission to send the messages.
+    async fn register_producer(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        name: &str,
+    ) -> Result<ProducerEpoch, IggyError>;
+    /// Start the new idempotent producer session for the given stream and topic by unique IDs or names, and return its unique producer ID.
+    /// The messages sent with the producer ID and increasing sequence numbers in their headers are appended only once by each partition,
+    /// the retried ones with the already appended sequence numbers are ignored as duplicates.
+    ///
+    /// Authentication is required, and the permission to send the messages.
+    async fn init_producer_id(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<ProducerSession, IggyError>;
+    /// Begin the new transaction and return its unique transaction ID.
+    /// The messages sent with the transaction ID in their headers to any streams, topics and partitions are committed or aborted atomically,
+    /// the transaction is aborted automatically if it's not completed within the timeout configured on the server.
+    ///
+    /// Authentication is required.
+    async fn begin_transaction(&self) -> Result<Transaction, IggyError>;
+    /// Commit the open transaction by its unique ID, making all the messages sent within it visible to the read-committed consumers at once.
+    ///
+    /// Authentication is required, and the transaction must be started by the current user.
+    async fn commit_transaction(&self, transaction_id: u64) -> Result<(), IggyError>;
+    /// Abort the open transaction by its unique ID, so that the messages sent within it are skipped by the read-committed consumers.
+    ///
+    /// Authentication is required, and the transaction must be started by the current user.
+    async fn abort_transaction(&self, transaction_id: u64) -> Result<(), IggyError>;
 }
 
 /// This trait defines the methods to interact with the consumer offset module.
@@ -383,6 +896,16 @@ pub trait ConsumerOffsetClient {
         partition_id: Option<u32>,
         offset: u64,
     ) -> Result<(), IggyError>;
+    /// Store the consumer offsets for many partitions at once for a specific consumer or consumer group for the given stream and topic by unique IDs or names.
+    ///
+    /// Authentication is required, and the permission to poll the messages.
+    async fn store_consumer_offsets(
+        &self,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        offsets: &[PartitionOffset],
+    ) -> Result<(), IggyError>;
     /// Get the consumer offset for a specific consumer or consumer group for the given stream and topic by unique IDs or names.
     ///
     /// Authentication is required, and the permission to poll the messages.
@@ -403,6 +926,17 @@ pub trait ConsumerOffsetClient {
         topic_id: &Identifier,
         partition_id: Option<u32>,
     ) -> Result<(), IggyError>;
+    /// Get the offsets of the earliest messages with the timestamp greater than or equal to the given one,
+    /// for many partitions at once for the given stream and topic by unique IDs or names.
+    /// The offset is `None` for the partitions in which all the messages are older.
+    ///
+    /// Authentication is required, and the permission to poll the messages.
+    async fn get_offsets_for_timestamps(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        timestamps: &[PartitionTimestamp],
+    ) -> Result<Vec<PartitionTimestampOffset>, IggyError>;
 }
 
 /// This trait defines the methods to interact with the consumer group module.
@@ -435,6 +969,46 @@ pub trait ConsumerGroupClient {
         name: &str,
         group_id: Option<u32>,
     ) -> Result<ConsumerGroupDetails, IggyError>;
+    /// Create a new consumer group for the given stream and topic by unique IDs or names,
+    /// with the policy used to recover its offsets which are out of the range of the available messages,
+    /// e.g. after the topic has been purged.
+    ///
+    /// Authentication is required, and the permission to manage the streams or topics.
+    async fn create_consumer_group_with_offset_recovery(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        name: &str,
+        group_id: Option<u32>,
+        offset_recovery: OffsetRecoveryPolicy,
+    ) -> Result<ConsumerGroupDetails, IggyError>;
+    /// Configure the dead-letter topic of a consumer group by unique ID or name for the given stream and topic by unique IDs or names.
+    /// The messages negatively acknowledged by the members, or delivered more than `max_delivery_count` times (if greater than 0)
+    /// without being committed, are republished to the dead-letter topic. Passing `None` disables the dead-lettering.
+    ///
+    /// Authentication is required, and the permission to manage the streams or topics.
+    #[allow(clippy::too_many_arguments)]
+    async fn update_consumer_group_dead_letter(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+        dead_letter_stream_id: Option<&Identifier>,
+        dead_letter_topic_id: Option<&Identifier>,
+        max_delivery_count: u32,
+    ) -> Result<(), IggyError>;
+    /// Configure the visibility timeout of a consumer group by unique ID or name for the given stream and topic by unique IDs or names.
+    /// When configured, the polled messages must be acknowledged by the members using `ack_messages`,
+    /// otherwise they're redelivered once the visibility timeout expires. Passing zero disables the acknowledgement mode.
+    ///
+    /// Authentication is required, and the permission to manage the streams or topics.
+    async fn update_consumer_group_visibility_timeout(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+        visibility_timeout: IggyDuration,
+    ) -> Result<(), IggyError>;
     /// Delete a consumer group by unique ID or name for the given stream and topic by unique IDs or names.
     ///
     /// Authentication is required, and the permission to manage the streams or topics.
@@ -462,6 +1036,45 @@ pub trait ConsumerGroupClient {
         topic_id: &Identifier,
         group_id: &Identifier,
     ) -> Result<(), IggyError>;
+    /// Get the value stored under the key in the state store of the partition assigned to the client
+    /// as the member of the consumer group by unique ID or name for the given stream and topic by unique IDs or names.
+    /// Returns `None` if the key does not exist.
+    ///
+    /// Authentication is required, and the permission to poll the messages.
+    async fn get_partition_state(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+        partition_id: u32,
+        key: &str,
+    ) -> Result<Option<Bytes>, IggyError>;
+    /// Store the value under the key in the state store of the partition assigned to the client
+    /// as the member of the consumer group by unique ID or name for the given stream and topic by unique IDs or names.
+    /// The store follows the partition when the consumer group is rebalanced.
+    ///
+    /// Authentication is required, and the permission to poll the messages.
+    async fn store_partition_state(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+        partition_id: u32,
+        key: &str,
+        value: Bytes,
+    ) -> Result<(), IggyError>;
+    /// Delete the value stored under the key in the state store of the partition assigned to the client
+    /// as the member of the consumer group by unique ID or name for the given stream and topic by unique IDs or names.
+    ///
+    /// Authentication is required, and the permission to poll the messages.
+    async fn delete_partition_state(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+        partition_id: u32,
+        key: &str,
+    ) -> Result<(), IggyError>;
 }
 
 impl FromStr for ConnectionString {
@@ -544,11 +1157,18 @@ impl ConnectionString {
         let mut tls_enabled = false;
         let mut tls_domain = "localhost".to_string();
         let mut tls_ca_file = None;
+        let mut tls_cert_file = None;
+        let mut tls_key_file = None;
         let mut reconnection_retries = "unlimited".to_owned();
         let mut reconnection_interval = "1s".to_owned();
         let mut reestablish_after = "5s".to_owned();
         let mut heartbeat_interval = "5s".to_owned();
+        let mut heartbeat_timeout = "5s".to_owned();
         let mut nodelay = false;
+        let mut frame_checksums = false;
+        let mut dns_ttl = "30s".to_owned();
+        let mut health_check_interval = "0".to_owned();
+        let mut health_check_timeout = "3s".to_owned();
 
         for option in options {
             let option_parts = option.split('=').collect::<Vec<&str>>();
@@ -565,6 +1185,12 @@ impl ConnectionString {
                 "tls_ca_file" => {
                     tls_ca_file = Some(option_parts[1].to_string());
                 }
+                "tls_cert_file" => {
+                    tls_cert_file = Some(option_parts[1].to_string());
+                }
+                "tls_key_file" => {
+                    tls_key_file = Some(option_parts[1].to_string());
+                }
                 "reconnection_retries" => {
                     reconnection_retries = option_parts[1].to_string();
                 }
@@ -577,9 +1203,24 @@ impl ConnectionString {
                 "heartbeat_interval" => {
                     heartbeat_interval = option_parts[1].to_string();
                 }
+                "heartbeat_timeout" => {
+                    heartbeat_timeout = option_parts[1].to_string();
+                }
                 "nodelay" => {
                     nodelay = option_parts[1] == "true";
                 }
+                "frame_checksums" => {
+                    frame_checksums = option_parts[1] == "true";
+                }
+                "dns_ttl" => {
+                    dns_ttl = option_parts[1].to_string();
+                }
+                "health_check_interval" => {
+                    health_check_interval = option_parts[1].to_string();
+                }
+                "health_check_timeout" => {
+                    health_check_timeout = option_parts[1].to_string();
+                }
                 _ => {
                     return Err(IggyError::InvalidConnectionString);
                 }
@@ -589,8 +1230,12 @@ impl ConnectionString {
             tls_enabled,
             tls_domain,
             tls_ca_file,
+            tls_cert_file,
+            tls_key_file,
             heartbeat_interval: IggyDuration::from_str(heartbeat_interval.as_str())
                 .map_err(|_| IggyError::InvalidConnectionString)?,
+            heartbeat_timeout: IggyDuration::from_str(heartbeat_timeout.as_str())
+                .map_err(|_| IggyError::InvalidConnectionString)?,
             reconnection: TcpClientReconnectionConfig {
                 enabled: true,
                 max_retries: match reconnection_retries.as_str() {
@@ -606,7 +1251,16 @@ impl ConnectionString {
                 reestablish_after: IggyDuration::from_str(reestablish_after.as_str())
                     .map_err(|_| IggyError::InvalidConnectionString)?,
             },
+            endpoints: TcpClientEndpointsConfig {
+                dns_ttl: IggyDuration::from_str(dns_ttl.as_str())
+                    .map_err(|_| IggyError::InvalidConnectionString)?,
+                health_check_interval: IggyDuration::from_str(health_check_interval.as_str())
+                    .map_err(|_| IggyError::InvalidConnectionString)?,
+                health_check_timeout: IggyDuration::from_str(health_check_timeout.as_str())
+                    .map_err(|_| IggyError::InvalidConnectionString)?,
+            },
             nodelay,
+            frame_checksums,
         })
     }
 }
@@ -616,9 +1270,14 @@ struct ConnectionStringOptions {
     tls_enabled: bool,
     tls_domain: String,
     tls_ca_file: Option<String>,
+    tls_cert_file: Option<String>,
+    tls_key_file: Option<String>,
     reconnection: TcpClientReconnectionConfig,
+    endpoints: TcpClientEndpointsConfig,
     heartbeat_interval: IggyDuration,
+    heartbeat_timeout: IggyDuration,
     nodelay: bool,
+    frame_checksums: bool,
 }
 
 impl Default for ConnectionStringOptions {
@@ -627,9 +1286,14 @@ impl Default for ConnectionStringOptions {
             tls_enabled: false,
             tls_domain: "".to_string(),
             tls_ca_file: None,
+            tls_cert_file: None,
+            tls_key_file: None,
             reconnection: Default::default(),
+            endpoints: Default::default(),
             heartbeat_interval: IggyDuration::from_str("5s").unwrap(),
+            heartbeat_timeout: IggyDuration::from_str("5s").unwrap(),
             nodelay: false,
+            frame_checksums: false,
         }
     }
 }
@@ -642,9 +1306,14 @@ impl From<ConnectionString> for TcpClientConfig {
             tls_enabled: connection_string.options.tls_enabled,
             tls_domain: connection_string.options.tls_domain,
             tls_ca_file: connection_string.options.tls_ca_file,
+            tls_cert_file: connection_string.options.tls_cert_file,
+            tls_key_file: connection_string.options.tls_key_file,
             reconnection: connection_string.options.reconnection,
+            endpoints: connection_string.options.endpoints,
             heartbeat_interval: connection_string.options.heartbeat_interval,
+            heartbeat_timeout: connection_string.options.heartbeat_timeout,
             nodelay: connection_string.options.nodelay,
+            frame_checksums: connection_string.options.frame_checksums,
         }
     }
 }
@@ -710,6 +1379,16 @@ mod tests {
             IggyDuration::from_str("1s").unwrap()
         );
         assert!(!connection_string.options.nodelay);
+        assert!(!connection_string.options.frame_checksums);
+        assert_eq!(
+            connection_string.options.endpoints.dns_ttl,
+            IggyDuration::from_str("30s").unwrap()
+        );
+        assert!(connection_string
+            .options
+            .endpoints
+            .health_check_interval
+            .is_zero());
     }
 
     #[test]
@@ -720,12 +1399,19 @@ mod tests {
         let tls = true;
         let tls_domain = "test.com";
         let tls_ca_file = "ca.pem";
+        let tls_cert_file = "client_cert.pem";
+        let tls_key_file = "client_key.pem";
         let reconnection_retries = 5;
         let reconnection_interval = "5s";
         let reestablish_after = "10s";
         let heartbeat_interval = "3s";
+        let heartbeat_timeout = "2s";
         let nodelay = true;
-        let value = format!("{CONNECTION_STRING_PREFIX}{username}:{password}@{server_address}?tls={tls}&tls_domain={tls_domain}&tls_ca_file={tls_ca_file}&reconnection_retries={reconnection_retries}&reconnection_interval={reconnection_interval}&reestablish_after={reestablish_after}&heartbeat_interval={heartbeat_interval}&nodelay={nodelay}");
+        let frame_checksums = true;
+        let dns_ttl = "10s";
+        let health_check_interval = "15s";
+        let health_check_timeout = "2s";
+        let value = format!("{CONNECTION_STRING_PREFIX}{username}:{password}@{server_address}?tls={tls}&tls_domain={tls_domain}&tls_ca_file={tls_ca_file}&tls_cert_file={tls_cert_file}&tls_key_file={tls_key_file}&reconnection_retries={reconnection_retries}&reconnection_interval={reconnection_interval}&reestablish_after={reestablish_after}&heartbeat_interval={heartbeat_interval}&heartbeat_timeout={heartbeat_timeout}&nodelay={nodelay}&frame_checksums={frame_checksums}&dns_ttl={dns_ttl}&health_check_interval={health_check_interval}&health_check_timeout={health_check_timeout}");
         let connection_string = ConnectionString::new(&value);
         assert!(connection_string.is_ok());
         let connection_string = connection_string.unwrap();
@@ -744,6 +1430,14 @@ mod tests {
             connection_string.options.tls_ca_file,
             Some(tls_ca_file.to_owned())
         );
+        assert_eq!(
+            connection_string.options.tls_cert_file,
+            Some(tls_cert_file.to_owned())
+        );
+        assert_eq!(
+            connection_string.options.tls_key_file,
+            Some(tls_key_file.to_owned())
+        );
         assert!(connection_string.options.reconnection.enabled);
         assert_eq!(
             connection_string.options.reconnection.max_retries,
@@ -761,6 +1455,23 @@ mod tests {
             connection_string.options.heartbeat_interval,
             IggyDuration::from_str(heartbeat_interval).unwrap()
         );
+        assert_eq!(
+            connection_string.options.heartbeat_timeout,
+            IggyDuration::from_str(heartbeat_timeout).unwrap()
+        );
         assert_eq!(connection_string.options.nodelay, nodelay);
+        assert_eq!(connection_string.options.frame_checksums, frame_checksums);
+        assert_eq!(
+            connection_string.options.endpoints.dns_ttl,
+            IggyDuration::from_str(dns_ttl).unwrap()
+        );
+        assert_eq!(
+            connection_string.options.endpoints.health_check_interval,
+            IggyDuration::from_str(health_check_interval).unwrap()
+        );
+        assert_eq!(
+            connection_string.options.endpoints.health_check_timeout,
+            IggyDuration::from_str(health_check_timeout).unwrap()
+        );
     }
 }
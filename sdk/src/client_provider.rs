@@ -20,45 +20,67 @@ use crate::client::{AutoLogin, Client, Credentials};
 use crate::client_error::ClientError;
 #[allow(deprecated)]
 use crate::clients::client::IggyClient;
+#[cfg(feature = "http")]
 use crate::http::client::HttpClient;
+#[cfg(feature = "http")]
 use crate::http::config::HttpClientConfig;
+#[cfg(feature = "quic")]
 use crate::quic::client::QuicClient;
+#[cfg(feature = "quic")]
 use crate::quic::config::{QuicClientConfig, QuicClientReconnectionConfig};
 use crate::tcp::client::TcpClient;
-use crate::tcp::config::{TcpClientConfig, TcpClientReconnectionConfig};
+use crate::tcp::config::{TcpClientConfig, TcpClientEndpointsConfig, TcpClientReconnectionConfig};
+#[cfg(unix)]
+use crate::uds::client::UdsClient;
+#[cfg(unix)]
+use crate::uds::config::{UdsClientConfig, UdsClientReconnectionConfig};
 use crate::utils::duration::IggyDuration;
 use std::str::FromStr;
 use std::sync::Arc;
 
+#[cfg(feature = "quic")]
 const QUIC_TRANSPORT: &str = "quic";
+#[cfg(feature = "http")]
 const HTTP_TRANSPORT: &str = "http";
 const TCP_TRANSPORT: &str = "tcp";
+#[cfg(unix)]
+const UDS_TRANSPORT: &str = "uds";
 
 /// Configuration for the `ClientProvider`.
 /// It consists of the following fields:
-/// - `transport`: the transport to use. Valid values are `quic`, `http` and `tcp`.
-/// - `http`: the optional configuration for the HTTP transport.
-/// - `quic`: the optional configuration for the QUIC transport.
+/// - `transport`: the transport to use. Valid values are `quic`, `http`, `tcp` and `uds`.
+/// - `http`: the optional configuration for the HTTP transport (requires the `http` feature).
+/// - `quic`: the optional configuration for the QUIC transport (requires the `quic` feature).
 /// - `tcp`: the optional configuration for the TCP transport.
+/// - `uds`: the optional configuration for the UDS transport (Unix only).
 #[derive(Debug)]
 pub struct ClientProviderConfig {
-    /// The transport to use. Valid values are `quic`, `http` and `tcp`.
+    /// The transport to use. Valid values are `quic`, `http`, `tcp` and `uds`.
     pub transport: String,
     /// The optional configuration for the HTTP transport.
+    #[cfg(feature = "http")]
     pub http: Option<Arc<HttpClientConfig>>,
     /// The optional configuration for the QUIC transport.
+    #[cfg(feature = "quic")]
     pub quic: Option<Arc<QuicClientConfig>>,
     /// The optional configuration for the TCP transport.
     pub tcp: Option<Arc<TcpClientConfig>>,
+    /// The optional configuration for the UDS transport.
+    #[cfg(unix)]
+    pub uds: Option<Arc<UdsClientConfig>>,
 }
 
 impl Default for ClientProviderConfig {
     fn default() -> ClientProviderConfig {
         ClientProviderConfig {
             transport: TCP_TRANSPORT.to_string(),
+            #[cfg(feature = "http")]
             http: Some(Arc::new(HttpClientConfig::default())),
+            #[cfg(feature = "quic")]
             quic: Some(Arc::new(QuicClientConfig::default())),
             tcp: Some(Arc::new(TcpClientConfig::default())),
+            #[cfg(unix)]
+            uds: Some(Arc::new(UdsClientConfig::default())),
         }
     }
 }
@@ -78,11 +100,16 @@ impl ClientProviderConfig {
         let transport = args.transport;
         let mut config = Self {
             transport,
+            #[cfg(feature = "http")]
             http: None,
+            #[cfg(feature = "quic")]
             quic: None,
             tcp: None,
+            #[cfg(unix)]
+            uds: None,
         };
         match config.transport.as_str() {
+            #[cfg(feature = "quic")]
             QUIC_TRANSPORT => {
                 config.quic = Some(Arc::new(QuicClientConfig {
                     client_address: args.quic_client_address,
@@ -118,6 +145,7 @@ impl ClientProviderConfig {
                     validate_certificate: args.quic_validate_certificate,
                 }));
             }
+            #[cfg(feature = "http")]
             HTTP_TRANSPORT => {
                 config.http = Some(Arc::new(HttpClientConfig {
                     api_url: args.http_api_url,
@@ -130,9 +158,13 @@ impl ClientProviderConfig {
                     tls_enabled: args.tcp_tls_enabled,
                     tls_domain: args.tcp_tls_domain,
                     tls_ca_file: args.tcp_tls_ca_file,
+                    tls_cert_file: args.tcp_tls_cert_file,
+                    tls_key_file: args.tcp_tls_key_file,
                     nodelay: args.tcp_nodelay,
+                    frame_checksums: args.tcp_frame_checksums,
                     heartbeat_interval: IggyDuration::from_str(&args.tcp_heartbeat_interval)
                         .unwrap(),
+                    heartbeat_timeout: IggyDuration::from_str(&args.tcp_heartbeat_timeout).unwrap(),
                     reconnection: TcpClientReconnectionConfig {
                         enabled: args.tcp_reconnection_enabled,
                         max_retries: args.tcp_reconnection_max_retries,
@@ -142,6 +174,42 @@ impl ClientProviderConfig {
                         )
                         .unwrap(),
                     },
+                    endpoints: TcpClientEndpointsConfig {
+                        dns_ttl: IggyDuration::from_str(&args.tcp_dns_ttl).unwrap(),
+                        health_check_interval: IggyDuration::from_str(
+                            &args.tcp_health_check_interval,
+                        )
+                        .unwrap(),
+                        health_check_timeout: IggyDuration::from_str(
+                            &args.tcp_health_check_timeout,
+                        )
+                        .unwrap(),
+                    },
+                    auto_login: if auto_login {
+                        AutoLogin::Enabled(Credentials::UsernamePassword(
+                            args.username,
+                            args.password,
+                        ))
+                    } else {
+                        AutoLogin::Disabled
+                    },
+                }));
+            }
+            #[cfg(unix)]
+            UDS_TRANSPORT => {
+                config.uds = Some(Arc::new(UdsClientConfig {
+                    socket_path: args.uds_socket_path,
+                    heartbeat_interval: IggyDuration::from_str(&args.uds_heartbeat_interval)
+                        .unwrap(),
+                    reconnection: UdsClientReconnectionConfig {
+                        enabled: args.uds_reconnection_enabled,
+                        max_retries: args.uds_reconnection_max_retries,
+                        interval: IggyDuration::from_str(&args.uds_reconnection_interval).unwrap(),
+                        reestablish_after: IggyDuration::from_str(
+                            &args.uds_reconnection_reestablish_after,
+                        )
+                        .unwrap(),
+                    },
                     auto_login: if auto_login {
                         AutoLogin::Enabled(Credentials::UsernamePassword(
                             args.username,
@@ -184,6 +252,7 @@ pub async fn get_raw_client(
 ) -> Result<Box<dyn Client>, ClientError> {
     let transport = config.transport.clone();
     match transport.as_str() {
+        #[cfg(feature = "quic")]
         QUIC_TRANSPORT => {
             let quic_config = config.quic.as_ref().unwrap();
             let client = QuicClient::create(quic_config.clone())?;
@@ -192,6 +261,7 @@ pub async fn get_raw_client(
             };
             Ok(Box::new(client))
         }
+        #[cfg(feature = "http")]
         HTTP_TRANSPORT => {
             let http_config = config.http.as_ref().unwrap();
             let client = HttpClient::create(http_config.clone())?;
@@ -205,6 +275,15 @@ pub async fn get_raw_client(
             };
             Ok(Box::new(client))
         }
+        #[cfg(unix)]
+        UDS_TRANSPORT => {
+            let uds_config = config.uds.as_ref().unwrap();
+            let client = UdsClient::create(uds_config.clone())?;
+            if establish_connection {
+                Client::connect(&client).await?
+            };
+            Ok(Box::new(client))
+        }
         _ => Err(ClientError::InvalidTransport(transport)),
     }
 }
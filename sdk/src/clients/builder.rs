@@ -19,13 +19,21 @@
 use crate::client::{AutoLogin, Client};
 use crate::clients::client::IggyClient;
 use crate::error::IggyError;
+#[cfg(feature = "http")]
 use crate::http::client::HttpClient;
+#[cfg(feature = "http")]
 use crate::http::config::HttpClientConfigBuilder;
 use crate::partitioner::Partitioner;
+#[cfg(feature = "quic")]
 use crate::quic::client::QuicClient;
+#[cfg(feature = "quic")]
 use crate::quic::config::QuicClientConfigBuilder;
 use crate::tcp::client::TcpClient;
 use crate::tcp::config::TcpClientConfigBuilder;
+#[cfg(unix)]
+use crate::uds::client::UdsClient;
+#[cfg(unix)]
+use crate::uds::config::UdsClientConfigBuilder;
 use crate::utils::crypto::EncryptorKind;
 use crate::utils::duration::IggyDuration;
 use std::sync::Arc;
@@ -85,6 +93,7 @@ impl IggyClientBuilder {
     /// This method provides fluent API for the QUIC client configuration.
     /// It returns the `QuicClientBuilder` instance, which allows to configure the QUIC client with custom settings or using defaults.
     /// This should be called after the non-protocol specific methods, such as `with_partitioner`, `with_encryptor` or `with_message_handler`.
+    #[cfg(feature = "quic")]
     pub fn with_quic(self) -> QuicClientBuilder {
         QuicClientBuilder {
             config: QuicClientConfigBuilder::default(),
@@ -92,9 +101,21 @@ impl IggyClientBuilder {
         }
     }
 
+    /// This method provides fluent API for the UDS client configuration.
+    /// It returns the `UdsClientBuilder` instance, which allows to configure the UDS client with custom settings or using defaults.
+    /// This should be called after the non-protocol specific methods, such as `with_partitioner`, `with_encryptor` or `with_message_handler`.
+    #[cfg(unix)]
+    pub fn with_uds(self) -> UdsClientBuilder {
+        UdsClientBuilder {
+            config: UdsClientConfigBuilder::default(),
+            parent_builder: self,
+        }
+    }
+
     /// This method provides fluent API for the HTTP client configuration.
     /// It returns the `HttpClientBuilder` instance, which allows to configure the HTTP client with custom settings or using defaults.
     /// This should be called after the non-protocol specific methods, such as `with_partitioner`, `with_encryptor` or `with_message_handler`.
+    #[cfg(feature = "http")]
     pub fn with_http(self) -> HttpClientBuilder {
         HttpClientBuilder {
             config: HttpClientConfigBuilder::default(),
@@ -105,7 +126,7 @@ impl IggyClientBuilder {
     /// Build the `IggyClient` instance.
     /// This method returns an error if the client is not provided.
     /// If the client is provided, it creates the `IggyClient` instance with the provided configuration.
-    /// To provide the client configuration, use the `with_tcp`, `with_quic` or `with_http` methods.
+    /// To provide the client configuration, use the `with_tcp`, `with_quic`, `with_uds` or `with_http` methods.
     pub fn build(self) -> Result<IggyClient, IggyError> {
         let Some(client) = self.client else {
             error!("Client is not provided");
@@ -169,12 +190,36 @@ impl TcpClientBuilder {
         self
     }
 
+    /// Sets the client certificate and its private key (PEM) for the mutual TLS.
+    pub fn with_tls_client_certificate(mut self, cert_file: String, key_file: String) -> Self {
+        self.config = self.config.with_tls_client_certificate(cert_file, key_file);
+        self
+    }
+
     /// Sets the nodelay option for the TCP socket.
     pub fn with_no_delay(mut self) -> Self {
         self.config = self.config.with_no_delay();
         self
     }
 
+    /// Enables the CRC32C checksums of the protocol frames, if supported by the server.
+    pub fn with_frame_checksums(mut self) -> Self {
+        self.config = self.config.with_frame_checksums();
+        self
+    }
+
+    /// Sets how long the resolved endpoints are reused before the server address is resolved again.
+    pub fn with_dns_ttl(mut self, dns_ttl: IggyDuration) -> Self {
+        self.config = self.config.with_dns_ttl(dns_ttl);
+        self
+    }
+
+    /// Enables the health checks of the resolved endpoints, performed at the given interval.
+    pub fn with_health_checks(mut self, interval: IggyDuration, timeout: IggyDuration) -> Self {
+        self.config = self.config.with_health_checks(interval, timeout);
+        self
+    }
+
     /// Builds the parent `IggyClient` with TCP configuration.
     pub fn build(self) -> Result<IggyClient, IggyError> {
         let client = TcpClient::create(Arc::new(self.config.build()))?;
@@ -183,12 +228,59 @@ impl TcpClientBuilder {
     }
 }
 
+#[cfg(unix)]
+#[derive(Debug, Default)]
+pub struct UdsClientBuilder {
+    config: UdsClientConfigBuilder,
+    parent_builder: IggyClientBuilder,
+}
+
+#[cfg(unix)]
+impl UdsClientBuilder {
+    /// Sets the path to the socket file of the server.
+    pub fn with_socket_path(mut self, socket_path: String) -> Self {
+        self.config = self.config.with_socket_path(socket_path);
+        self
+    }
+
+    /// Sets the auto sign in during connection.
+    pub fn with_auto_sign_in(mut self, auto_sign_in: AutoLogin) -> Self {
+        self.config = self.config.with_auto_sign_in(auto_sign_in);
+        self
+    }
+
+    /// Sets the number of max retries when connecting to the server.
+    pub fn with_reconnection_max_retries(mut self, reconnection_retries: Option<u32>) -> Self {
+        self.config = self
+            .config
+            .with_reconnection_max_retries(reconnection_retries);
+        self
+    }
+
+    /// Sets the interval between retries when connecting to the server.
+    pub fn with_reconnection_interval(mut self, reconnection_interval: IggyDuration) -> Self {
+        self.config = self
+            .config
+            .with_reconnection_interval(reconnection_interval);
+        self
+    }
+
+    /// Builds the parent `IggyClient` with UDS configuration.
+    pub fn build(self) -> Result<IggyClient, IggyError> {
+        let client = UdsClient::create(Arc::new(self.config.build()))?;
+        let client = self.parent_builder.with_client(Box::new(client)).build()?;
+        Ok(client)
+    }
+}
+
+#[cfg(feature = "quic")]
 #[derive(Debug, Default)]
 pub struct QuicClientBuilder {
     config: QuicClientConfigBuilder,
     parent_builder: IggyClientBuilder,
 }
 
+#[cfg(feature = "quic")]
 impl QuicClientBuilder {
     /// Sets the server address for the QUIC client.
     pub fn with_server_address(mut self, server_address: String) -> Self {
@@ -232,12 +324,14 @@ impl QuicClientBuilder {
     }
 }
 
+#[cfg(feature = "http")]
 #[derive(Debug, Default)]
 pub struct HttpClientBuilder {
     config: HttpClientConfigBuilder,
     parent_builder: IggyClientBuilder,
 }
 
+#[cfg(feature = "http")]
 impl HttpClientBuilder {
     /// Sets the server address for the HTTP client.
     pub fn with_api_url(mut self, api_url: String) -> Self {
@@ -0,0 +1,516 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::client::ConsumerOffsetClient;
+use crate::clients::consumer::ReceivedMessage;
+use crate::consumer::Consumer;
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use async_trait::async_trait;
+use std::fmt::Display;
+use std::sync::Arc;
+use tracing::{error, info, warn};
+
+/// The batch of consumed messages handled by the sink as a single transaction,
+/// identified by the partition and the inclusive range of the messages offsets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CheckpointBatch {
+    /// The partition the messages were consumed from.
+    pub partition_id: u32,
+    /// The offset of the first message in the batch.
+    pub first_offset: u64,
+    /// The offset of the last message in the batch.
+    pub last_offset: u64,
+}
+
+impl CheckpointBatch {
+    /// Creates the batch for the messages consumed from a single partition.
+    pub fn from_messages(messages: &[ReceivedMessage]) -> Result<Self, IggyError> {
+        let (Some(first), Some(last)) = (messages.first(), messages.last()) else {
+            return Err(IggyError::InvalidCheckpointBatch);
+        };
+
+        if messages
+            .iter()
+            .any(|message| message.partition_id != first.partition_id)
+        {
+            return Err(IggyError::InvalidCheckpointBatch);
+        }
+
+        if first.message.offset > last.message.offset {
+            return Err(IggyError::InvalidCheckpointBatch);
+        }
+
+        Ok(CheckpointBatch {
+            partition_id: first.partition_id,
+            first_offset: first.message.offset,
+            last_offset: last.message.offset,
+        })
+    }
+}
+
+impl Display for CheckpointBatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}:{}..={}",
+            self.partition_id, self.first_offset, self.last_offset
+        )
+    }
+}
+
+/// The sink writing the consumed messages to an external transactional system (e.g. a database)
+/// using the two-phase commit, so that the writes can be coordinated with the consumer offsets.
+///
+/// The sink is the source of truth for what has been written: it must durably keep the prepared
+/// batches and the last committed offset per partition, typically in the same transaction as the data.
+#[async_trait]
+pub trait TwoPhaseSink: Send + Sync {
+    /// Writes the messages in a durable transaction which is neither visible nor committed yet,
+    /// and can be later found by `in_doubt_batches` until it's committed or aborted.
+    async fn prepare(
+        &self,
+        batch: &CheckpointBatch,
+        messages: &[ReceivedMessage],
+    ) -> Result<(), IggyError>;
+    /// Commits the prepared transaction, together with the last offset of the batch for its partition.
+    async fn commit(&self, batch: &CheckpointBatch) -> Result<(), IggyError>;
+    /// Rolls back the prepared transaction.
+    async fn abort(&self, batch: &CheckpointBatch) -> Result<(), IggyError>;
+    /// Returns the batches for the partition which were prepared, but neither committed nor aborted.
+    async fn in_doubt_batches(&self, partition_id: u32) -> Result<Vec<CheckpointBatch>, IggyError>;
+    /// Returns the offset of the last message committed for the partition, if any.
+    async fn last_committed_offset(&self, partition_id: u32) -> Result<Option<u64>, IggyError>;
+}
+
+/// The outcome of the partition recovery.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CheckpointRecovery {
+    /// The recovered partition.
+    pub partition_id: u32,
+    /// The offset of the last message committed by the sink, the consumption should resume right after it.
+    pub committed_offset: Option<u64>,
+    /// The number of in-doubt batches which have been committed.
+    pub committed_batches: u32,
+    /// The number of in-doubt batches which have been aborted.
+    pub aborted_batches: u32,
+}
+
+/// `CheckpointBarrier` coordinates the writes to a `TwoPhaseSink` with the consumer offsets stored on the server,
+/// providing the effectively-once delivery of the consumed messages to the external system.
+///
+/// Each batch is prepared and committed in the sink first, and only then the consumer offset is stored,
+/// hence the consumer should be created with the auto-commit disabled.
+/// On startup, `recover` must be called for each assigned partition before consuming,
+/// to resolve the batches left in-doubt by a crash and to align the stored consumer offset with the sink.
+pub struct CheckpointBarrier<C: ConsumerOffsetClient + ?Sized, S: TwoPhaseSink> {
+    client: Arc<C>,
+    consumer: Consumer,
+    stream_id: Identifier,
+    topic_id: Identifier,
+    sink: S,
+}
+
+impl<C: ConsumerOffsetClient + ?Sized, S: TwoPhaseSink> CheckpointBarrier<C, S> {
+    /// Creates a new checkpoint barrier for the consumer of the given stream and topic.
+    pub fn new(
+        client: Arc<C>,
+        consumer: Consumer,
+        stream_id: Identifier,
+        topic_id: Identifier,
+        sink: S,
+    ) -> Self {
+        Self {
+            client,
+            consumer,
+            stream_id,
+            topic_id,
+            sink,
+        }
+    }
+
+    /// Returns the sink.
+    pub fn sink(&self) -> &S {
+        &self.sink
+    }
+
+    /// Writes the messages consumed from a single partition to the sink and stores the consumer offset afterwards.
+    ///
+    /// If the sink fails to prepare the batch, it's aborted. If the process stops after the batch has been
+    /// prepared or committed, but before the offset is stored, the batch is resolved by `recover`.
+    pub async fn checkpoint(&self, messages: &[ReceivedMessage]) -> Result<(), IggyError> {
+        if messages.is_empty() {
+            return Ok(());
+        }
+
+        let batch = CheckpointBatch::from_messages(messages)?;
+        if let Err(error) = self.sink.prepare(&batch, messages).await {
+            error!("Failed to prepare checkpoint batch: {batch}. {error}");
+            if let Err(abort_error) = self.sink.abort(&batch).await {
+                error!("Failed to abort checkpoint batch: {batch}. {abort_error}");
+            }
+            return Err(error);
+        }
+
+        self.sink.commit(&batch).await?;
+        self.store_offset(batch.partition_id, batch.last_offset)
+            .await
+    }
+
+    /// Resolves the in-doubt batches of the partition and stores the consumer offset committed by the sink.
+    ///
+    /// The in-doubt batches following the last committed offset contain exactly the consumed messages,
+    /// so they're committed, while the ones overlapping the already committed messages are aborted.
+    pub async fn recover(&self, partition_id: u32) -> Result<CheckpointRecovery, IggyError> {
+        let mut committed_offset = self.sink.last_committed_offset(partition_id).await?;
+        let mut in_doubt_batches = self.sink.in_doubt_batches(partition_id).await?;
+        in_doubt_batches.sort_by_key(|batch| batch.first_offset);
+        let mut recovery = CheckpointRecovery {
+            partition_id,
+            committed_offset,
+            committed_batches: 0,
+            aborted_batches: 0,
+        };
+
+        for batch in in_doubt_batches {
+            let follows_committed = match committed_offset {
+                Some(offset) => batch.first_offset > offset,
+                None => true,
+            };
+            if follows_committed {
+                info!("Committing in-doubt checkpoint batch: {batch}");
+                self.sink.commit(&batch).await?;
+                committed_offset = Some(batch.last_offset);
+                recovery.committed_batches += 1;
+            } else {
+                info!("Aborting in-doubt checkpoint batch: {batch}");
+                self.sink.abort(&batch).await?;
+                recovery.aborted_batches += 1;
+            }
+        }
+
+        recovery.committed_offset = committed_offset;
+        let Some(committed_offset) = committed_offset else {
+            return Ok(recovery);
+        };
+
+        let stored_offset = self
+            .client
+            .get_consumer_offset(
+                &self.consumer,
+                &self.stream_id,
+                &self.topic_id,
+                Some(partition_id),
+            )
+            .await?
+            .map(|info| info.stored_offset);
+        match stored_offset {
+            Some(stored_offset) if stored_offset == committed_offset => {}
+            Some(stored_offset) if stored_offset > committed_offset => {
+                warn!("Stored offset: {stored_offset} for partition: {partition_id} is ahead of the offset: {committed_offset} committed by the sink, the messages in between were not written to the sink.");
+            }
+            _ => {
+                self.store_offset(partition_id, committed_offset).await?;
+            }
+        }
+        Ok(recovery)
+    }
+
+    async fn store_offset(&self, partition_id: u32, offset: u64) -> Result<(), IggyError> {
+        self.client
+            .store_consumer_offset(
+                &self.consumer,
+                &self.stream_id,
+                &self.topic_id,
+                Some(partition_id),
+                offset,
+            )
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::consumer_offsets::get_offsets_for_timestamps::PartitionTimestamp;
+    use crate::consumer_offsets::store_consumer_offsets::PartitionOffset;
+    use crate::models::consumer_offset_info::ConsumerOffsetInfo;
+    use crate::models::messages::{MessageState, PolledMessage};
+    use crate::models::partition_timestamp_offset::PartitionTimestampOffset;
+    use crate::utils::byte_size::IggyByteSize;
+    use bytes::Bytes;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct TestClient {
+        offsets: Mutex<HashMap<u32, u64>>,
+    }
+
+    #[async_trait]
+    impl ConsumerOffsetClient for TestClient {
+        async fn store_consumer_offset(
+            &self,
+            _consumer: &Consumer,
+            _stream_id: &Identifier,
+            _topic_id: &Identifier,
+            partition_id: Option<u32>,
+            offset: u64,
+        ) -> Result<(), IggyError> {
+            self.offsets
+                .lock()
+                .unwrap()
+                .insert(partition_id.unwrap(), offset);
+            Ok(())
+        }
+
+        async fn store_consumer_offsets(
+            &self,
+            _consumer: &Consumer,
+            _stream_id: &Identifier,
+            _topic_id: &Identifier,
+            offsets: &[PartitionOffset],
+        ) -> Result<(), IggyError> {
+            let mut stored_offsets = self.offsets.lock().unwrap();
+            for offset in offsets {
+                stored_offsets.insert(offset.partition_id, offset.offset);
+            }
+            Ok(())
+        }
+
+        async fn get_consumer_offset(
+            &self,
+            _consumer: &Consumer,
+            _stream_id: &Identifier,
+            _topic_id: &Identifier,
+            partition_id: Option<u32>,
+        ) -> Result<Option<ConsumerOffsetInfo>, IggyError> {
+            let partition_id = partition_id.unwrap();
+            Ok(self
+                .offsets
+                .lock()
+                .unwrap()
+                .get(&partition_id)
+                .map(|offset| ConsumerOffsetInfo {
+                    partition_id,
+                    current_offset: *offset,
+                    stored_offset: *offset,
+                }))
+        }
+
+        async fn delete_consumer_offset(
+            &self,
+            _consumer: &Consumer,
+            _stream_id: &Identifier,
+            _topic_id: &Identifier,
+            partition_id: Option<u32>,
+        ) -> Result<(), IggyError> {
+            self.offsets.lock().unwrap().remove(&partition_id.unwrap());
+            Ok(())
+        }
+
+        async fn get_offsets_for_timestamps(
+            &self,
+            _stream_id: &Identifier,
+            _topic_id: &Identifier,
+            _timestamps: &[PartitionTimestamp],
+        ) -> Result<Vec<PartitionTimestampOffset>, IggyError> {
+            Ok(Vec::new())
+        }
+    }
+
+    #[derive(Default)]
+    struct TestSink {
+        prepared: Mutex<Vec<CheckpointBatch>>,
+        committed: Mutex<HashMap<u32, u64>>,
+        aborted: Mutex<Vec<CheckpointBatch>>,
+        fail_commit: bool,
+    }
+
+    #[async_trait]
+    impl TwoPhaseSink for TestSink {
+        async fn prepare(
+            &self,
+            batch: &CheckpointBatch,
+            _messages: &[ReceivedMessage],
+        ) -> Result<(), IggyError> {
+            self.prepared.lock().unwrap().push(*batch);
+            Ok(())
+        }
+
+        async fn commit(&self, batch: &CheckpointBatch) -> Result<(), IggyError> {
+            if self.fail_commit {
+                return Err(IggyError::Error);
+            }
+            self.prepared.lock().unwrap().retain(|b| b != batch);
+            self.committed
+                .lock()
+                .unwrap()
+                .insert(batch.partition_id, batch.last_offset);
+            Ok(())
+        }
+
+        async fn abort(&self, batch: &CheckpointBatch) -> Result<(), IggyError> {
+            self.prepared.lock().unwrap().retain(|b| b != batch);
+            self.aborted.lock().unwrap().push(*batch);
+            Ok(())
+        }
+
+        async fn in_doubt_batches(
+            &self,
+            partition_id: u32,
+        ) -> Result<Vec<CheckpointBatch>, IggyError> {
+            Ok(self
+                .prepared
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|batch| batch.partition_id == partition_id)
+                .copied()
+                .collect())
+        }
+
+        async fn last_committed_offset(&self, partition_id: u32) -> Result<Option<u64>, IggyError> {
+            Ok(self.committed.lock().unwrap().get(&partition_id).copied())
+        }
+    }
+
+    fn barrier(client: Arc<TestClient>, sink: TestSink) -> CheckpointBarrier<TestClient, TestSink> {
+        CheckpointBarrier::new(
+            client,
+            Consumer::default(),
+            Identifier::numeric(1).unwrap(),
+            Identifier::numeric(1).unwrap(),
+            sink,
+        )
+    }
+
+    fn messages(partition_id: u32, offsets: std::ops::RangeInclusive<u64>) -> Vec<ReceivedMessage> {
+        offsets
+            .map(|offset| {
+                ReceivedMessage::new(
+                    PolledMessage {
+                        offset,
+                        state: MessageState::Available,
+                        timestamp: 0,
+                        id: 0,
+                        checksum: 0,
+                        headers: None,
+                        length: IggyByteSize::from(0),
+                        payload: Bytes::new(),
+                    },
+                    offset,
+                    partition_id,
+                )
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn checkpoint_should_commit_batch_and_store_offset() {
+        let client = Arc::new(TestClient::default());
+        let barrier = barrier(client.clone(), TestSink::default());
+
+        barrier.checkpoint(&messages(1, 0..=9)).await.unwrap();
+
+        assert_eq!(
+            barrier.sink().last_committed_offset(1).await.unwrap(),
+            Some(9)
+        );
+        assert_eq!(client.offsets.lock().unwrap().get(&1), Some(&9));
+    }
+
+    #[tokio::test]
+    async fn checkpoint_should_not_store_offset_when_commit_fails() {
+        let client = Arc::new(TestClient::default());
+        let sink = TestSink {
+            fail_commit: true,
+            ..Default::default()
+        };
+        let barrier = barrier(client.clone(), sink);
+
+        assert!(barrier.checkpoint(&messages(1, 0..=9)).await.is_err());
+        assert!(client.offsets.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn checkpoint_should_fail_for_messages_from_multiple_partitions() {
+        let barrier = barrier(Arc::new(TestClient::default()), TestSink::default());
+        let mut batch = messages(1, 0..=4);
+        batch.extend(messages(2, 5..=9));
+
+        let result = barrier.checkpoint(&batch).await;
+
+        assert!(matches!(result, Err(IggyError::InvalidCheckpointBatch)));
+    }
+
+    #[tokio::test]
+    async fn recover_should_commit_in_doubt_batch_following_committed_offset() {
+        let client = Arc::new(TestClient::default());
+        let sink = TestSink::default();
+        sink.committed.lock().unwrap().insert(1, 9);
+        sink.prepared.lock().unwrap().push(CheckpointBatch {
+            partition_id: 1,
+            first_offset: 10,
+            last_offset: 19,
+        });
+        let barrier = barrier(client.clone(), sink);
+
+        let recovery = barrier.recover(1).await.unwrap();
+
+        assert_eq!(recovery.committed_offset, Some(19));
+        assert_eq!(recovery.committed_batches, 1);
+        assert_eq!(recovery.aborted_batches, 0);
+        assert_eq!(client.offsets.lock().unwrap().get(&1), Some(&19));
+    }
+
+    #[tokio::test]
+    async fn recover_should_abort_in_doubt_batch_overlapping_committed_offset() {
+        let client = Arc::new(TestClient::default());
+        client.offsets.lock().unwrap().insert(1, 9);
+        let sink = TestSink::default();
+        sink.committed.lock().unwrap().insert(1, 9);
+        sink.prepared.lock().unwrap().push(CheckpointBatch {
+            partition_id: 1,
+            first_offset: 5,
+            last_offset: 14,
+        });
+        let barrier = barrier(client.clone(), sink);
+
+        let recovery = barrier.recover(1).await.unwrap();
+
+        assert_eq!(recovery.committed_offset, Some(9));
+        assert_eq!(recovery.committed_batches, 0);
+        assert_eq!(recovery.aborted_batches, 1);
+        assert_eq!(client.offsets.lock().unwrap().get(&1), Some(&9));
+    }
+
+    #[tokio::test]
+    async fn recover_should_store_offset_committed_by_sink() {
+        let client = Arc::new(TestClient::default());
+        client.offsets.lock().unwrap().insert(1, 4);
+        let sink = TestSink::default();
+        sink.committed.lock().unwrap().insert(1, 9);
+        let barrier = barrier(client.clone(), sink);
+
+        let recovery = barrier.recover(1).await.unwrap();
+
+        assert_eq!(recovery.committed_offset, Some(9));
+        assert_eq!(client.offsets.lock().unwrap().get(&1), Some(&9));
+    }
+}
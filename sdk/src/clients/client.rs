@@ -17,42 +17,76 @@
  */
 
 use crate::client::{
-    Client, ConsumerGroupClient, ConsumerOffsetClient, MessageClient, PartitionClient,
-    PersonalAccessTokenClient, StreamClient, SystemClient, TopicClient, UserClient,
+    Client, ConsumerGroupClient, ConsumerOffsetClient, MessageClient, NamespaceClient,
+    PartitionClient, PersonalAccessTokenClient, StreamClient, SystemClient, TopicClient,
+    UserClient,
 };
 use crate::clients::builder::IggyClientBuilder;
 use crate::clients::consumer::IggyConsumerBuilder;
+use crate::clients::pattern_consumer::{IggyPatternConsumerBuilder, TopicPattern};
 use crate::clients::producer::IggyProducerBuilder;
 use crate::compression::compression_algorithm::CompressionAlgorithm;
 use crate::consumer::Consumer;
+use crate::consumer_groups::offset_recovery_policy::OffsetRecoveryPolicy;
+use crate::consumer_offsets::get_offsets_for_timestamps::PartitionTimestamp;
+use crate::consumer_offsets::store_consumer_offsets::PartitionOffset;
 use crate::diagnostic::DiagnosticEvent;
 use crate::error::IggyError;
 use crate::identifier::Identifier;
 use crate::locking::IggySharedMut;
 use crate::locking::IggySharedMutFn;
-use crate::messages::poll_messages::PollingStrategy;
+use crate::messages::message_filter::MessageFilter;
+use crate::messages::poll_messages::{IsolationLevel, PollMessagesOptions, PollingStrategy};
+use crate::messages::replay_messages::{HeadersTransform, ReplayRange};
 use crate::messages::send_messages::{Message, Partitioning};
+use crate::messages::send_messages_batch::TopicMessages;
+use crate::models::audit_entry::{AuditAction, AuditEntry};
+use crate::models::backup::Backup;
 use crate::models::client_info::{ClientInfo, ClientInfoDetails};
 use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails};
 use crate::models::consumer_offset_info::ConsumerOffsetInfo;
+use crate::models::error_catalog::ErrorCatalogEntry;
 use crate::models::identity_info::IdentityInfo;
+use crate::models::maintenance_mode::MaintenanceMode;
 use crate::models::messages::PolledMessages;
+use crate::models::messages_aggregate::MessagesBucket;
+use crate::models::metadata::{MetadataFilter, ResourceMetadata};
+use crate::models::namespace::{Namespace, NamespaceDetails, NamespaceQuotas};
+use crate::models::partition_timestamp_offset::PartitionTimestampOffset;
 use crate::models::permissions::Permissions;
-use crate::models::personal_access_token::{PersonalAccessTokenInfo, RawPersonalAccessToken};
+use crate::models::personal_access_token::{
+    PersonalAccessTokenInfo, PersonalAccessTokenScope, RawPersonalAccessToken,
+};
+use crate::models::producer_epoch::ProducerEpoch;
+use crate::models::producer_session::ProducerSession;
+use crate::models::push_subscription::PushSubscription;
+use crate::models::replay_job::ReplayJob;
+use crate::models::restored_segments::RestoredSegments;
+use crate::models::segment_range::SegmentRange;
+use crate::models::server_info::ServerInfo;
 use crate::models::snapshot::Snapshot;
 use crate::models::stats::Stats;
-use crate::models::stream::{Stream, StreamDetails};
+use crate::models::stream::{Stream, StreamDetails, StreamQuota};
 use crate::models::topic::{Topic, TopicDetails};
+use crate::models::transaction::Transaction;
+use crate::models::unsaved_state::PartitionUnsavedState;
 use crate::models::user_info::{UserInfo, UserInfoDetails};
 use crate::models::user_status::UserStatus;
 use crate::partitioner::Partitioner;
 use crate::snapshot::{SnapshotCompression, SystemSnapshotType};
+use crate::streams::create_stream::CreateStreamOptions;
 use crate::tcp::client::TcpClient;
+use crate::topics::create_topic::CreateTopicOptions;
+use crate::topics::purge_topic::PurgeTopicOptions;
+use crate::topics::topic_config::TopicConfig;
+use crate::topics::update_topic::UpdateTopicOptions;
+use crate::users::user_quotas::UserQuotas;
 use crate::utils::byte_size::IggyByteSize;
 use crate::utils::crypto::EncryptorKind;
 use crate::utils::duration::IggyDuration;
 use crate::utils::expiry::IggyExpiry;
 use crate::utils::personal_access_token_expiry::PersonalAccessTokenExpiry;
+use crate::utils::timestamp::IggyTimestamp;
 use crate::utils::topic_size::MaxTopicSize;
 use async_broadcast::Receiver;
 use async_dropper::AsyncDrop;
@@ -62,8 +96,7 @@ use std::fmt::Debug;
 use std::sync::Arc;
 use tokio::spawn;
 use tokio::time::sleep;
-use tracing::log::warn;
-use tracing::{debug, error, info};
+use tracing::{debug, error, info, warn};
 
 /// The main client struct which implements all the `Client` traits and wraps the underlying low-level client for the specific transport.
 ///
@@ -175,6 +208,61 @@ impl IggyClient {
         ))
     }
 
+    /// Returns the builder for the consumer of all the topics in the stream matching the pattern,
+    /// each of them consumed from the provided partition.
+    pub fn pattern_consumer(
+        &self,
+        name: &str,
+        stream: &str,
+        pattern: TopicPattern,
+        partition: u32,
+    ) -> Result<IggyPatternConsumerBuilder, IggyError> {
+        let stream: Identifier = stream.try_into()?;
+        // The topic is set separately for each of the consumers created for the matching topics.
+        let consumer = IggyConsumerBuilder::new(
+            self.client.clone(),
+            name.to_owned(),
+            Consumer::new(name.try_into()?),
+            stream.clone(),
+            Identifier::default(),
+            Some(partition),
+            self.encryptor.clone(),
+            None,
+        );
+        Ok(IggyPatternConsumerBuilder::new(
+            self.client.clone(),
+            stream,
+            pattern,
+            consumer,
+        ))
+    }
+
+    /// Returns the builder for the consumer group of all the topics in the stream matching the pattern.
+    pub fn pattern_consumer_group(
+        &self,
+        name: &str,
+        stream: &str,
+        pattern: TopicPattern,
+    ) -> Result<IggyPatternConsumerBuilder, IggyError> {
+        let stream: Identifier = stream.try_into()?;
+        let consumer = IggyConsumerBuilder::new(
+            self.client.clone(),
+            name.to_owned(),
+            Consumer::group(name.try_into()?),
+            stream.clone(),
+            Identifier::default(),
+            None,
+            self.encryptor.clone(),
+            None,
+        );
+        Ok(IggyPatternConsumerBuilder::new(
+            self.client.clone(),
+            stream,
+            pattern,
+            consumer,
+        ))
+    }
+
     /// Returns the builder for the producer.
     pub fn producer(&self, stream: &str, topic: &str) -> Result<IggyProducerBuilder, IggyError> {
         Ok(IggyProducerBuilder::new(
@@ -284,6 +372,18 @@ impl UserClient for IggyClient {
             .await
     }
 
+    async fn update_user_quotas(
+        &self,
+        user_id: &Identifier,
+        quotas: UserQuotas,
+    ) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .update_user_quotas(user_id, quotas)
+            .await
+    }
+
     async fn change_password(
         &self,
         user_id: &Identifier,
@@ -308,6 +408,10 @@ impl UserClient for IggyClient {
     async fn logout_user(&self) -> Result<(), IggyError> {
         self.client.read().await.logout_user().await
     }
+
+    async fn login_as(&self, user_id: &Identifier) -> Result<IdentityInfo, IggyError> {
+        self.client.read().await.login_as(user_id).await
+    }
 }
 
 #[async_trait]
@@ -328,6 +432,19 @@ impl PersonalAccessTokenClient for IggyClient {
             .await
     }
 
+    async fn create_scoped_personal_access_token(
+        &self,
+        name: &str,
+        expiry: PersonalAccessTokenExpiry,
+        scope: &PersonalAccessTokenScope,
+    ) -> Result<RawPersonalAccessToken, IggyError> {
+        self.client
+            .read()
+            .await
+            .create_scoped_personal_access_token(name, expiry, scope)
+            .await
+    }
+
     async fn delete_personal_access_token(&self, name: &str) -> Result<(), IggyError> {
         self.client
             .read()
@@ -336,6 +453,18 @@ impl PersonalAccessTokenClient for IggyClient {
             .await
     }
 
+    async fn rotate_personal_access_token(
+        &self,
+        name: &str,
+        expiry: PersonalAccessTokenExpiry,
+    ) -> Result<RawPersonalAccessToken, IggyError> {
+        self.client
+            .read()
+            .await
+            .rotate_personal_access_token(name, expiry)
+            .await
+    }
+
     async fn login_with_personal_access_token(
         &self,
         token: &str,
@@ -385,6 +514,126 @@ impl SystemClient for IggyClient {
             .snapshot(compression, snapshot_types)
             .await
     }
+
+    async fn get_maintenance_mode(&self) -> Result<MaintenanceMode, IggyError> {
+        self.client.read().await.get_maintenance_mode().await
+    }
+
+    async fn set_maintenance_mode(
+        &self,
+        enabled: bool,
+        reject_reads: bool,
+        message: Option<&str>,
+    ) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .set_maintenance_mode(enabled, reject_reads, message)
+            .await
+    }
+
+    async fn get_server_info(&self) -> Result<ServerInfo, IggyError> {
+        self.client.read().await.get_server_info().await
+    }
+
+    async fn create_backup(&self, name: &str) -> Result<Backup, IggyError> {
+        self.client.read().await.create_backup(name).await
+    }
+
+    async fn get_unsaved_state(&self) -> Result<Vec<PartitionUnsavedState>, IggyError> {
+        self.client.read().await.get_unsaved_state().await
+    }
+
+    async fn get_audit_log(
+        &self,
+        count: u32,
+        user_id: Option<u32>,
+        action: Option<AuditAction>,
+    ) -> Result<Vec<AuditEntry>, IggyError> {
+        self.client
+            .read()
+            .await
+            .get_audit_log(count, user_id, action)
+            .await
+    }
+
+    async fn get_error_catalog(&self) -> Result<Vec<ErrorCatalogEntry>, IggyError> {
+        self.client.read().await.get_error_catalog().await
+    }
+}
+
+#[async_trait]
+impl NamespaceClient for IggyClient {
+    async fn get_namespace(
+        &self,
+        namespace_id: &Identifier,
+    ) -> Result<Option<NamespaceDetails>, IggyError> {
+        self.client.read().await.get_namespace(namespace_id).await
+    }
+
+    async fn get_namespaces(&self) -> Result<Vec<Namespace>, IggyError> {
+        self.client.read().await.get_namespaces().await
+    }
+
+    async fn create_namespace(
+        &self,
+        name: &str,
+        quotas: NamespaceQuotas,
+    ) -> Result<NamespaceDetails, IggyError> {
+        self.client
+            .read()
+            .await
+            .create_namespace(name, quotas)
+            .await
+    }
+
+    async fn update_namespace(
+        &self,
+        namespace_id: &Identifier,
+        quotas: NamespaceQuotas,
+    ) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .update_namespace(namespace_id, quotas)
+            .await
+    }
+
+    async fn delete_namespace(&self, namespace_id: &Identifier) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .delete_namespace(namespace_id)
+            .await
+    }
+
+    async fn create_namespace_stream(
+        &self,
+        namespace_id: u32,
+        name: &str,
+        stream_id: Option<u32>,
+    ) -> Result<StreamDetails, IggyError> {
+        self.client
+            .read()
+            .await
+            .create_namespace_stream(namespace_id, name, stream_id)
+            .await
+    }
+
+    async fn create_namespace_user(
+        &self,
+        namespace_id: u32,
+        username: &str,
+        password: &str,
+        status: UserStatus,
+        permissions: Option<Permissions>,
+    ) -> Result<UserInfoDetails, IggyError> {
+        self.client
+            .read()
+            .await
+            .create_namespace_user(namespace_id, username, password, status, permissions)
+            .await
+    }
 }
 
 #[async_trait]
@@ -397,6 +646,17 @@ impl StreamClient for IggyClient {
         self.client.read().await.get_streams().await
     }
 
+    async fn get_streams_by_metadata(
+        &self,
+        filter: &MetadataFilter,
+    ) -> Result<Vec<Stream>, IggyError> {
+        self.client
+            .read()
+            .await
+            .get_streams_by_metadata(filter)
+            .await
+    }
+
     async fn create_stream(
         &self,
         name: &str,
@@ -409,6 +669,19 @@ impl StreamClient for IggyClient {
             .await
     }
 
+    async fn create_stream_with_options(
+        &self,
+        name: &str,
+        stream_id: Option<u32>,
+        options: &CreateStreamOptions,
+    ) -> Result<StreamDetails, IggyError> {
+        self.client
+            .read()
+            .await
+            .create_stream_with_options(name, stream_id, options)
+            .await
+    }
+
     async fn update_stream(&self, stream_id: &Identifier, name: &str) -> Result<(), IggyError> {
         self.client
             .read()
@@ -417,6 +690,30 @@ impl StreamClient for IggyClient {
             .await
     }
 
+    async fn update_stream_metadata(
+        &self,
+        stream_id: &Identifier,
+        metadata: ResourceMetadata,
+    ) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .update_stream_metadata(stream_id, metadata)
+            .await
+    }
+
+    async fn update_stream_quota(
+        &self,
+        stream_id: &Identifier,
+        quota: StreamQuota,
+    ) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .update_stream_quota(stream_id, quota)
+            .await
+    }
+
     async fn delete_stream(&self, stream_id: &Identifier) -> Result<(), IggyError> {
         self.client.read().await.delete_stream(stream_id).await
     }
@@ -444,6 +741,18 @@ impl TopicClient for IggyClient {
         self.client.read().await.get_topics(stream_id).await
     }
 
+    async fn get_topics_by_metadata(
+        &self,
+        stream_id: &Identifier,
+        filter: &MetadataFilter,
+    ) -> Result<Vec<Topic>, IggyError> {
+        self.client
+            .read()
+            .await
+            .get_topics_by_metadata(stream_id, filter)
+            .await
+    }
+
     async fn create_topic(
         &self,
         stream_id: &Identifier,
@@ -471,6 +780,35 @@ impl TopicClient for IggyClient {
             .await
     }
 
+    async fn create_topic_with_options(
+        &self,
+        stream_id: &Identifier,
+        name: &str,
+        partitions_count: u32,
+        compression_algorithm: CompressionAlgorithm,
+        replication_factor: Option<u8>,
+        topic_id: Option<u32>,
+        message_expiry: IggyExpiry,
+        max_topic_size: MaxTopicSize,
+        options: &CreateTopicOptions,
+    ) -> Result<TopicDetails, IggyError> {
+        self.client
+            .read()
+            .await
+            .create_topic_with_options(
+                stream_id,
+                name,
+                partitions_count,
+                compression_algorithm,
+                replication_factor,
+                topic_id,
+                message_expiry,
+                max_topic_size,
+                options,
+            )
+            .await
+    }
+
     async fn update_topic(
         &self,
         stream_id: &Identifier,
@@ -496,88 +834,272 @@ impl TopicClient for IggyClient {
             .await
     }
 
-    async fn delete_topic(
+    async fn update_topic_with_options(
         &self,
         stream_id: &Identifier,
         topic_id: &Identifier,
+        name: &str,
+        compression_algorithm: CompressionAlgorithm,
+        replication_factor: Option<u8>,
+        message_expiry: IggyExpiry,
+        max_topic_size: MaxTopicSize,
+        options: &UpdateTopicOptions,
     ) -> Result<(), IggyError> {
         self.client
             .read()
             .await
-            .delete_topic(stream_id, topic_id)
+            .update_topic_with_options(
+                stream_id,
+                topic_id,
+                name,
+                compression_algorithm,
+                replication_factor,
+                message_expiry,
+                max_topic_size,
+                options,
+            )
             .await
     }
 
-    async fn purge_topic(
+    async fn update_topic_metadata(
         &self,
         stream_id: &Identifier,
         topic_id: &Identifier,
+        metadata: ResourceMetadata,
     ) -> Result<(), IggyError> {
         self.client
             .read()
             .await
-            .purge_topic(stream_id, topic_id)
+            .update_topic_metadata(stream_id, topic_id, metadata)
             .await
     }
-}
 
-#[async_trait]
-impl PartitionClient for IggyClient {
-    async fn create_partitions(
+    async fn update_topic_producers(
         &self,
         stream_id: &Identifier,
         topic_id: &Identifier,
-        partitions_count: u32,
+        allowed_producers: &[u32],
     ) -> Result<(), IggyError> {
         self.client
             .read()
             .await
-            .create_partitions(stream_id, topic_id, partitions_count)
+            .update_topic_producers(stream_id, topic_id, allowed_producers)
             .await
     }
 
-    async fn delete_partitions(
+    async fn update_topic_config(
         &self,
         stream_id: &Identifier,
         topic_id: &Identifier,
-        partitions_count: u32,
+        config: TopicConfig,
     ) -> Result<(), IggyError> {
         self.client
             .read()
             .await
-            .delete_partitions(stream_id, topic_id, partitions_count)
+            .update_topic_config(stream_id, topic_id, config)
             .await
     }
-}
 
-#[async_trait]
-impl MessageClient for IggyClient {
-    async fn poll_messages(
+    async fn update_topic_expiry_watcher(
         &self,
         stream_id: &Identifier,
         topic_id: &Identifier,
-        partition_id: Option<u32>,
         consumer: &Consumer,
-        strategy: &PollingStrategy,
-        count: u32,
-        auto_commit: bool,
-    ) -> Result<PolledMessages, IggyError> {
-        if count == 0 {
-            return Err(IggyError::InvalidMessagesCount);
-        }
+        enabled: bool,
+    ) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .update_topic_expiry_watcher(stream_id, topic_id, consumer, enabled)
+            .await
+    }
 
-        let mut polled_messages = self
-            .client
+    async fn mark_topic_for_deletion(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        drain_period: IggyDuration,
+    ) -> Result<(), IggyError> {
+        self.client
             .read()
             .await
-            .poll_messages(
-                stream_id,
+            .mark_topic_for_deletion(stream_id, topic_id, drain_period)
+            .await
+    }
+
+    async fn delete_topic(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .delete_topic(stream_id, topic_id)
+            .await
+    }
+
+    async fn purge_topic(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .purge_topic(stream_id, topic_id)
+            .await
+    }
+
+    async fn purge_topic_with_options(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        options: &PurgeTopicOptions,
+    ) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .purge_topic_with_options(stream_id, topic_id, options)
+            .await
+    }
+}
+
+#[async_trait]
+impl PartitionClient for IggyClient {
+    async fn create_partitions(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitions_count: u32,
+    ) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .create_partitions(stream_id, topic_id, partitions_count)
+            .await
+    }
+
+    async fn delete_partitions(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitions_count: u32,
+    ) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .delete_partitions(stream_id, topic_id, partitions_count)
+            .await
+    }
+
+    async fn restore_archived_segments(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        start_offset: u64,
+        end_offset: u64,
+    ) -> Result<RestoredSegments, IggyError> {
+        self.client
+            .read()
+            .await
+            .restore_archived_segments(stream_id, topic_id, partition_id, start_offset, end_offset)
+            .await
+    }
+
+    async fn flush_partition(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+    ) -> Result<Vec<PartitionUnsavedState>, IggyError> {
+        self.client
+            .read()
+            .await
+            .flush_partition(stream_id, topic_id, partition_id)
+            .await
+    }
+
+    async fn read_segment_range(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        offset: u64,
+        start_position: u64,
+        end_position: Option<u64>,
+        max_bytes: u32,
+    ) -> Result<SegmentRange, IggyError> {
+        self.client
+            .read()
+            .await
+            .read_segment_range(
+                stream_id,
+                topic_id,
+                partition_id,
+                offset,
+                start_position,
+                end_position,
+                max_bytes,
+            )
+            .await
+    }
+}
+
+#[async_trait]
+impl MessageClient for IggyClient {
+    async fn poll_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        auto_commit: bool,
+    ) -> Result<PolledMessages, IggyError> {
+        self.poll_messages_with_options(
+            stream_id,
+            topic_id,
+            partition_id,
+            consumer,
+            strategy,
+            count,
+            auto_commit,
+            &PollMessagesOptions::default(),
+        )
+        .await
+    }
+
+    async fn poll_messages_with_options(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        auto_commit: bool,
+        options: &PollMessagesOptions,
+    ) -> Result<PolledMessages, IggyError> {
+        if count == 0 {
+            return Err(IggyError::InvalidMessagesCount);
+        }
+
+        let mut polled_messages = self
+            .client
+            .read()
+            .await
+            .poll_messages_with_options(
+                stream_id,
                 topic_id,
                 partition_id,
                 consumer,
                 strategy,
                 count,
                 auto_commit,
+                options,
             )
             .await?;
 
@@ -592,6 +1114,64 @@ impl MessageClient for IggyClient {
         Ok(polled_messages)
     }
 
+    async fn peek_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        isolation_level: IsolationLevel,
+        filter: Option<&MessageFilter>,
+    ) -> Result<PolledMessages, IggyError> {
+        if count == 0 {
+            return Err(IggyError::InvalidMessagesCount);
+        }
+
+        let mut polled_messages = self
+            .client
+            .read()
+            .await
+            .peek_messages(
+                stream_id,
+                topic_id,
+                partition_id,
+                consumer,
+                strategy,
+                count,
+                isolation_level,
+                filter,
+            )
+            .await?;
+
+        if let Some(ref encryptor) = self.encryptor {
+            for message in &mut polled_messages.messages {
+                let payload = encryptor.decrypt(&message.payload)?;
+                message.payload = Bytes::from(payload);
+                message.length = IggyByteSize::from(message.payload.len() as u64);
+            }
+        }
+
+        Ok(polled_messages)
+    }
+
+    async fn aggregate_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        from: IggyTimestamp,
+        to: IggyTimestamp,
+        bucket: IggyDuration,
+    ) -> Result<Vec<MessagesBucket>, IggyError> {
+        self.client
+            .read()
+            .await
+            .aggregate_messages(stream_id, topic_id, partition_id, from, to, bucket)
+            .await
+    }
+
     async fn send_messages(
         &self,
         stream_id: &Identifier,
@@ -617,6 +1197,24 @@ impl MessageClient for IggyClient {
             .await
     }
 
+    async fn send_messages_batch(&self, batches: &mut [TopicMessages]) -> Result<(), IggyError> {
+        if batches.iter().any(|batch| batch.messages.is_empty()) {
+            return Err(IggyError::InvalidMessagesCount);
+        }
+
+        if let Some(encryptor) = &self.encryptor {
+            for message in batches
+                .iter_mut()
+                .flat_map(|batch| batch.messages.iter_mut())
+            {
+                message.payload = Bytes::from(encryptor.encrypt(&message.payload)?);
+                message.length = message.payload.len() as u32;
+            }
+        }
+
+        self.client.read().await.send_messages_batch(batches).await
+    }
+
     async fn flush_unsaved_buffer(
         &self,
         stream_id: &Identifier,
@@ -630,6 +1228,154 @@ impl MessageClient for IggyClient {
             .flush_unsaved_buffer(stream_id, topic_id, partition_id, fsync)
             .await
     }
+
+    async fn nack_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        offsets: &[u64],
+        reason: &str,
+    ) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .nack_messages(stream_id, topic_id, partition_id, consumer, offsets, reason)
+            .await
+    }
+
+    async fn ack_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        offsets: &[u64],
+    ) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .ack_messages(stream_id, topic_id, partition_id, consumer, offsets)
+            .await
+    }
+
+    async fn replay_messages(
+        &self,
+        source_stream_id: &Identifier,
+        source_topic_id: &Identifier,
+        source_partition_id: u32,
+        range: &ReplayRange,
+        destination_stream_id: &Identifier,
+        destination_topic_id: &Identifier,
+        partitioning: &Partitioning,
+        headers: &HeadersTransform,
+        messages_per_second: u32,
+    ) -> Result<ReplayJob, IggyError> {
+        self.client
+            .read()
+            .await
+            .replay_messages(
+                source_stream_id,
+                source_topic_id,
+                source_partition_id,
+                range,
+                destination_stream_id,
+                destination_topic_id,
+                partitioning,
+                headers,
+                messages_per_second,
+            )
+            .await
+    }
+
+    async fn get_replay_jobs(&self) -> Result<Vec<ReplayJob>, IggyError> {
+        self.client.read().await.get_replay_jobs().await
+    }
+
+    async fn cancel_replay_job(&self, job_id: u32) -> Result<(), IggyError> {
+        self.client.read().await.cancel_replay_job(job_id).await
+    }
+
+    async fn create_push_subscription(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        endpoint: &str,
+        start_offset: u64,
+        batch_size: u32,
+    ) -> Result<PushSubscription, IggyError> {
+        self.client
+            .read()
+            .await
+            .create_push_subscription(
+                stream_id,
+                topic_id,
+                partition_id,
+                endpoint,
+                start_offset,
+                batch_size,
+            )
+            .await
+    }
+
+    async fn get_push_subscriptions(&self) -> Result<Vec<PushSubscription>, IggyError> {
+        self.client.read().await.get_push_subscriptions().await
+    }
+
+    async fn delete_push_subscription(&self, subscription_id: u32) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .delete_push_subscription(subscription_id)
+            .await
+    }
+
+    async fn register_producer(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        name: &str,
+    ) -> Result<ProducerEpoch, IggyError> {
+        self.client
+            .read()
+            .await
+            .register_producer(stream_id, topic_id, name)
+            .await
+    }
+
+    async fn init_producer_id(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<ProducerSession, IggyError> {
+        self.client
+            .read()
+            .await
+            .init_producer_id(stream_id, topic_id)
+            .await
+    }
+
+    async fn begin_transaction(&self) -> Result<Transaction, IggyError> {
+        self.client.read().await.begin_transaction().await
+    }
+
+    async fn commit_transaction(&self, transaction_id: u64) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .commit_transaction(transaction_id)
+            .await
+    }
+
+    async fn abort_transaction(&self, transaction_id: u64) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .abort_transaction(transaction_id)
+            .await
+    }
 }
 
 #[async_trait]
@@ -649,6 +1395,20 @@ impl ConsumerOffsetClient for IggyClient {
             .await
     }
 
+    async fn store_consumer_offsets(
+        &self,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        offsets: &[PartitionOffset],
+    ) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .store_consumer_offsets(consumer, stream_id, topic_id, offsets)
+            .await
+    }
+
     async fn get_consumer_offset(
         &self,
         consumer: &Consumer,
@@ -676,6 +1436,19 @@ impl ConsumerOffsetClient for IggyClient {
             .delete_consumer_offset(consumer, stream_id, topic_id, partition_id)
             .await
     }
+
+    async fn get_offsets_for_timestamps(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        timestamps: &[PartitionTimestamp],
+    ) -> Result<Vec<PartitionTimestampOffset>, IggyError> {
+        self.client
+            .read()
+            .await
+            .get_offsets_for_timestamps(stream_id, topic_id, timestamps)
+            .await
+    }
 }
 
 #[async_trait]
@@ -719,6 +1492,69 @@ impl ConsumerGroupClient for IggyClient {
             .await
     }
 
+    async fn create_consumer_group_with_offset_recovery(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        name: &str,
+        group_id: Option<u32>,
+        offset_recovery: OffsetRecoveryPolicy,
+    ) -> Result<ConsumerGroupDetails, IggyError> {
+        self.client
+            .read()
+            .await
+            .create_consumer_group_with_offset_recovery(
+                stream_id,
+                topic_id,
+                name,
+                group_id,
+                offset_recovery,
+            )
+            .await
+    }
+
+    async fn update_consumer_group_dead_letter(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+        dead_letter_stream_id: Option<&Identifier>,
+        dead_letter_topic_id: Option<&Identifier>,
+        max_delivery_count: u32,
+    ) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .update_consumer_group_dead_letter(
+                stream_id,
+                topic_id,
+                group_id,
+                dead_letter_stream_id,
+                dead_letter_topic_id,
+                max_delivery_count,
+            )
+            .await
+    }
+
+    async fn update_consumer_group_visibility_timeout(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+        visibility_timeout: IggyDuration,
+    ) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .update_consumer_group_visibility_timeout(
+                stream_id,
+                topic_id,
+                group_id,
+                visibility_timeout,
+            )
+            .await
+    }
+
     async fn delete_consumer_group(
         &self,
         stream_id: &Identifier,
@@ -757,6 +1593,52 @@ impl ConsumerGroupClient for IggyClient {
             .leave_consumer_group(stream_id, topic_id, group_id)
             .await
     }
+
+    async fn get_partition_state(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+        partition_id: u32,
+        key: &str,
+    ) -> Result<Option<Bytes>, IggyError> {
+        self.client
+            .read()
+            .await
+            .get_partition_state(stream_id, topic_id, group_id, partition_id, key)
+            .await
+    }
+
+    async fn store_partition_state(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+        partition_id: u32,
+        key: &str,
+        value: Bytes,
+    ) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .store_partition_state(stream_id, topic_id, group_id, partition_id, key, value)
+            .await
+    }
+
+    async fn delete_partition_state(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+        partition_id: u32,
+        key: &str,
+    ) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .delete_partition_state(stream_id, topic_id, group_id, partition_id, key)
+            .await
+    }
 }
 
 #[async_trait]
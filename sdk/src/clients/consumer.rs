@@ -17,12 +17,17 @@
  */
 
 use crate::client::Client;
+use crate::compression::compression_algorithm::{CompressionAlgorithm, COMPRESSION_HEADER};
 use crate::consumer::{Consumer, ConsumerKind};
+use crate::consumer_offsets::store_consumer_offsets::PartitionOffset;
 use crate::diagnostic::DiagnosticEvent;
 use crate::error::IggyError;
 use crate::identifier::{IdKind, Identifier};
 use crate::locking::{IggySharedMut, IggySharedMutFn};
-use crate::messages::poll_messages::{PollingKind, PollingStrategy};
+use crate::messages::poll_messages::{
+    IsolationLevel, PollMessagesOptions, PollingKind, PollingStrategy,
+};
+use crate::models::header::HeaderKey;
 use crate::models::messages::{PolledMessage, PolledMessages};
 use crate::utils::byte_size::IggyByteSize;
 use crate::utils::crypto::EncryptorKind;
@@ -32,7 +37,7 @@ use bytes::Bytes;
 use dashmap::DashMap;
 use futures::Stream;
 use futures_util::{FutureExt, StreamExt};
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 use std::future::Future;
 use std::pin::Pin;
 use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64};
@@ -93,6 +98,37 @@ pub enum AutoCommitAfter {
     ConsumingEachMessage,
     /// The offset is stored on the server after consuming every Nth message.
     ConsumingEveryNthMessage(u32),
+    /// The offset is stored on the server after each message is successfully processed by the message consumer.
+    /// The consumption stops with the error of the first message which failed to be processed, without storing its offset,
+    /// so that the message is delivered again once the consumption is resumed.
+    ProcessingEachMessage,
+}
+
+/// The delivery guarantee of the consumer, determining whether the offset is stored on the server
+/// before or after the messages are handed to the application.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum DeliveryMode {
+    /// The offset is stored on the server when polling the messages, before they are handed to the application.
+    /// Each message is processed at most once, the messages being processed when the consumer fails are lost.
+    AtMostOnce,
+    /// The offset is stored on the server only after each message has been successfully processed.
+    /// No message is lost, the messages being processed when the consumer fails are delivered again.
+    ///
+    /// **This will only work with the `IggyConsumerMessageExt` trait when using `consume_messages()`,
+    /// otherwise the offset must be stored with `store_offset()` after processing the messages.**
+    AtLeastOnce,
+    /// The offset is never stored automatically, it must be stored with `store_offset()`.
+    Manual,
+}
+
+impl From<DeliveryMode> for AutoCommit {
+    fn from(delivery_mode: DeliveryMode) -> Self {
+        match delivery_mode {
+            DeliveryMode::AtMostOnce => AutoCommit::When(AutoCommitWhen::PollingMessages),
+            DeliveryMode::AtLeastOnce => AutoCommit::After(AutoCommitAfter::ProcessingEachMessage),
+            DeliveryMode::Manual => AutoCommit::Disabled,
+        }
+    }
 }
 
 unsafe impl Send for IggyConsumer {}
@@ -119,6 +155,7 @@ pub struct IggyConsumer {
     last_stored_offsets: Arc<DashMap<u32, AtomicU64>>,
     last_consumed_offsets: Arc<DashMap<u32, AtomicU64>>,
     current_offsets: Arc<DashMap<u32, AtomicU64>>,
+    partition_epochs: Arc<DashMap<u32, u64>>,
     poll_future: Option<PollMessagesFuture>,
     buffered_messages: VecDeque<PolledMessage>,
     encryptor: Option<Arc<EncryptorKind>>,
@@ -127,11 +164,13 @@ pub struct IggyConsumer {
     store_offset_after_all_messages: bool,
     store_after_every_nth_message: u64,
     last_polled_at: Arc<AtomicU64>,
+    throttled_until: Arc<AtomicU64>,
     current_partition_id: Arc<AtomicU32>,
     reconnection_retry_interval: IggyDuration,
     init_retries: Option<u32>,
     init_retry_interval: IggyDuration,
     allow_replay: bool,
+    isolation_level: IsolationLevel,
 }
 
 impl IggyConsumer {
@@ -154,6 +193,7 @@ impl IggyConsumer {
         init_retries: Option<u32>,
         init_retry_interval: IggyDuration,
         allow_replay: bool,
+        isolation_level: IsolationLevel,
     ) -> Self {
         let (store_offset_sender, _) = flume::unbounded();
         Self {
@@ -172,6 +212,7 @@ impl IggyConsumer {
             last_stored_offsets: Arc::new(DashMap::new()),
             last_consumed_offsets: Arc::new(DashMap::new()),
             current_offsets: Arc::new(DashMap::new()),
+            partition_epochs: Arc::new(DashMap::new()),
             poll_future: None,
             batch_size,
             auto_commit,
@@ -203,11 +244,13 @@ impl IggyConsumer {
                 _ => 0,
             },
             last_polled_at: Arc::new(AtomicU64::new(0)),
+            throttled_until: Arc::new(AtomicU64::new(0)),
             current_partition_id: Arc::new(AtomicU32::new(0)),
             reconnection_retry_interval,
             init_retries,
             init_retry_interval,
             allow_replay,
+            isolation_level,
         }
     }
 
@@ -367,15 +410,20 @@ impl IggyConsumer {
         tokio::spawn(async move {
             while let Ok((partition_id, offset)) = store_offset_receiver.recv_async().await {
                 trace!("Received offset to store: {offset}, partition ID: {partition_id}, stream: {stream_id}, topic: {topic_id}");
-                _ = Self::store_consumer_offset(
+                // Coalesce the offsets queued in the meantime, so that only the highest offset
+                // for each partition is committed, and all of them are sent in a single request.
+                let mut offsets = HashMap::from([(partition_id, offset)]);
+                for (partition_id, offset) in store_offset_receiver.drain() {
+                    let entry = offsets.entry(partition_id).or_insert(offset);
+                    *entry = (*entry).max(offset);
+                }
+                _ = Self::store_consumer_offsets(
                     &client,
                     &consumer,
                     &stream_id,
                     &topic_id,
-                    partition_id,
-                    offset,
+                    offsets.into_iter().collect(),
                     &last_stored_offsets,
-                    false,
                 )
                 .await
             }
@@ -431,6 +479,67 @@ impl IggyConsumer {
         Ok(())
     }
 
+    async fn store_consumer_offsets(
+        client: &IggySharedMut<Box<dyn Client>>,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        offsets: Vec<(u32, u64)>,
+        last_stored_offsets: &DashMap<u32, AtomicU64>,
+    ) -> Result<(), IggyError> {
+        let mut offsets = offsets
+            .into_iter()
+            .filter(|(partition_id, offset)| {
+                let stored_offset = last_stored_offsets
+                    .get(partition_id)
+                    .map(|entry| entry.load(ORDERING))
+                    .unwrap_or_default();
+                !(*offset <= stored_offset && *offset >= 1)
+            })
+            .map(|(partition_id, offset)| PartitionOffset::new(partition_id, offset))
+            .collect::<Vec<_>>();
+        match offsets.len() {
+            0 => return Ok(()),
+            1 => {
+                let offset = offsets.remove(0);
+                return Self::store_consumer_offset(
+                    client,
+                    consumer,
+                    stream_id,
+                    topic_id,
+                    offset.partition_id,
+                    offset.offset,
+                    last_stored_offsets,
+                    false,
+                )
+                .await;
+            }
+            _ => {}
+        }
+
+        trace!("Storing {} offsets for consumer: {consumer}, topic: {topic_id}, stream: {stream_id}...", offsets.len());
+        let client = client.read().await;
+        if let Err(error) = client
+            .store_consumer_offsets(consumer, stream_id, topic_id, &offsets)
+            .await
+        {
+            error!("Failed to store {} offsets for consumer: {consumer}, topic: {topic_id}, stream: {stream_id}. {error}", offsets.len());
+            return Err(error);
+        }
+        trace!(
+            "Stored {} offsets for consumer: {consumer}, topic: {topic_id}, stream: {stream_id}.",
+            offsets.len()
+        );
+        for offset in offsets {
+            if let Some(last_offset_entry) = last_stored_offsets.get(&offset.partition_id) {
+                last_offset_entry.store(offset.offset, ORDERING);
+            } else {
+                last_stored_offsets.insert(offset.partition_id, AtomicU64::new(offset.offset));
+            }
+        }
+        Ok(())
+    }
+
     fn store_offsets_in_background(&self, interval: IggyDuration) {
         let client = self.client.clone();
         let consumer = self.consumer.clone();
@@ -441,21 +550,19 @@ impl IggyConsumer {
         tokio::spawn(async move {
             loop {
                 sleep(interval.get_duration()).await;
-                for entry in last_consumed_offsets.iter() {
-                    let partition_id = *entry.key();
-                    let consumed_offset = entry.load(ORDERING);
-                    _ = Self::store_consumer_offset(
-                        &client,
-                        &consumer,
-                        &stream_id,
-                        &topic_id,
-                        partition_id,
-                        consumed_offset,
-                        &last_stored_offsets,
-                        false,
-                    )
-                    .await;
-                }
+                let offsets = last_consumed_offsets
+                    .iter()
+                    .map(|entry| (*entry.key(), entry.load(ORDERING)))
+                    .collect::<Vec<_>>();
+                _ = Self::store_consumer_offsets(
+                    &client,
+                    &consumer,
+                    &stream_id,
+                    &topic_id,
+                    offsets,
+                    &last_stored_offsets,
+                )
+                .await;
             }
         });
     }
@@ -579,11 +686,30 @@ impl IggyConsumer {
                         joined_consumer_group.store(false, ORDERING);
                         can_poll.store(false, ORDERING);
                     }
+                    DiagnosticEvent::EndpointChanged(endpoint) => {
+                        info!("Connected to the different server endpoint: {endpoint}");
+                    }
                 }
             }
         });
     }
 
+    fn decompress_message(message: &mut PolledMessage) -> Result<(), IggyError> {
+        let Some(compression) = CompressionAlgorithm::from_headers(&message.headers)? else {
+            return Ok(());
+        };
+
+        message.payload = Bytes::from(compression.decompress(&message.payload)?);
+        message.length = IggyByteSize::from(message.payload.len() as u64);
+        if let Some(headers) = message.headers.as_mut() {
+            headers.remove(&HeaderKey::new(COMPRESSION_HEADER)?);
+            if headers.is_empty() {
+                message.headers = None;
+            }
+        }
+        Ok(())
+    }
+
     fn create_poll_messages_future(
         &self,
     ) -> impl Future<Output = Result<PolledMessages, IggyError>> {
@@ -598,17 +724,22 @@ impl IggyConsumer {
         let auto_commit_enabled = self.auto_commit != AutoCommit::Disabled;
         let interval = self.poll_interval_micros;
         let last_polled_at = self.last_polled_at.clone();
+        let throttled_until = self.throttled_until.clone();
         let can_poll = self.can_poll.clone();
         let retry_interval = self.reconnection_retry_interval;
         let last_stored_offset = self.last_stored_offsets.clone();
         let last_consumed_offset = self.last_consumed_offsets.clone();
+        let partition_epochs = self.partition_epochs.clone();
         let allow_replay = self.allow_replay;
+        let isolation_level = self.isolation_level;
 
         async move {
             if interval > 0 {
                 Self::wait_before_polling(interval, last_polled_at.load(ORDERING)).await;
             }
 
+            Self::wait_until_not_throttled(throttled_until.load(ORDERING)).await;
+
             if !can_poll.load(ORDERING) {
                 trace!("Trying to poll messages in {retry_interval}...");
                 sleep(retry_interval.get_duration()).await;
@@ -616,10 +747,13 @@ impl IggyConsumer {
 
             trace!("Sending poll messages request");
             last_polled_at.store(IggyTimestamp::now().into(), ORDERING);
+            // The partition assigned by the consumer group is not known upfront, so only its epoch is requested.
+            let expected_epoch = partition_id
+                .and_then(|partition_id| partition_epochs.get(&partition_id).map(|epoch| *epoch));
             let polled_messages = client
                 .read()
                 .await
-                .poll_messages(
+                .poll_messages_with_options(
                     &stream_id,
                     &topic_id,
                     partition_id,
@@ -627,15 +761,39 @@ impl IggyConsumer {
                     &polling_strategy,
                     count,
                     auto_commit_after_polling,
+                    &PollMessagesOptions {
+                        isolation_level,
+                        partition_epoch: Some(expected_epoch.unwrap_or_default()),
+                        ..Default::default()
+                    },
                 )
                 .await;
 
             if let Ok(mut polled_messages) = polled_messages {
+                if polled_messages.throttle_time_ms > 0 {
+                    let now: u64 = IggyTimestamp::now().into();
+                    throttled_until.store(
+                        now + polled_messages.throttle_time_ms as u64 * 1000,
+                        ORDERING,
+                    );
+                }
+
+                let partition_id = polled_messages.partition_id;
+                if partition_id > 0 {
+                    let previous_epoch =
+                        partition_epochs.insert(partition_id, polled_messages.partition_epoch);
+                    if previous_epoch.is_some_and(|epoch| epoch != polled_messages.partition_epoch)
+                    {
+                        warn!("Partition ID: {partition_id}, topic: {topic_id}, stream: {stream_id} has been purged, resetting the offsets of consumer: {consumer}");
+                        last_consumed_offset.remove(&partition_id);
+                        last_stored_offset.remove(&partition_id);
+                    }
+                }
+
                 if polled_messages.messages.is_empty() {
                     return Ok(polled_messages);
                 }
 
-                let partition_id = polled_messages.partition_id;
                 let consumed_offset;
                 let has_consumed_offset;
                 if let Some(offset_entry) = last_consumed_offset.get(&partition_id) {
@@ -655,6 +813,12 @@ impl IggyConsumer {
                         return Ok(PolledMessages {
                             messages: EMPTY_MESSAGES,
                             current_offset: polled_messages.current_offset,
+                            remaining_messages: 0,
+                            gaps: Vec::new(),
+                            chunk_sizes: Vec::new(),
+                            partition_epoch: polled_messages.partition_epoch,
+                            throttle_time_ms: polled_messages.throttle_time_ms,
+                            topic_delete_at: polled_messages.topic_delete_at,
                             partition_id,
                         });
                     }
@@ -710,6 +874,12 @@ impl IggyConsumer {
                     return Ok(PolledMessages {
                         messages: EMPTY_MESSAGES,
                         current_offset: polled_messages.current_offset,
+                        remaining_messages: 0,
+                        gaps: Vec::new(),
+                        chunk_sizes: Vec::new(),
+                        partition_epoch: polled_messages.partition_epoch,
+                        throttle_time_ms: polled_messages.throttle_time_ms,
+                        topic_delete_at: polled_messages.topic_delete_at,
                         partition_id,
                     });
                 }
@@ -719,6 +889,12 @@ impl IggyConsumer {
 
             let error = polled_messages.unwrap_err();
             error!("Failed to poll messages: {error}");
+            // The next poll receives the new epoch, starting over from the offsets of the purged partition.
+            if let IggyError::PartitionEpochChanged(partition_id, _, _) = error {
+                partition_epochs.remove(&partition_id);
+                last_consumed_offset.remove(&partition_id);
+                last_stored_offset.remove(&partition_id);
+            }
             if matches!(
                 error,
                 IggyError::Disconnected | IggyError::Unauthenticated | IggyError::StaleClient
@@ -747,6 +923,18 @@ impl IggyConsumer {
         sleep(Duration::from_micros(remaining)).await;
     }
 
+    /// Backs off from polling until the throttle time reported by the server for exceeding the fetch quota has passed.
+    async fn wait_until_not_throttled(throttled_until: u64) {
+        let now: u64 = IggyTimestamp::now().into();
+        if throttled_until <= now {
+            return;
+        }
+
+        let remaining = throttled_until - now;
+        trace!("Consumer is throttled by the server, waiting for {remaining} microseconds before polling messages...");
+        sleep(Duration::from_micros(remaining)).await;
+    }
+
     async fn initialize_consumer_group(
         client: IggySharedMut<Box<dyn Client>>,
         create_consumer_group_if_not_exists: bool,
@@ -880,6 +1068,15 @@ impl Stream for IggyConsumer {
                     let partition_id = polled_messages.partition_id;
                     self.current_partition_id.store(partition_id, ORDERING);
                     if polled_messages.messages.is_empty() {
+                        // The polled range might consist only of the skipped messages of the aborted transactions.
+                        if self.polling_strategy.kind == PollingKind::Offset {
+                            if let Some(gap) = polled_messages.gaps.last() {
+                                if gap.end_offset >= self.polling_strategy.value {
+                                    self.polling_strategy =
+                                        PollingStrategy::offset(gap.end_offset + 1);
+                                }
+                            }
+                        }
                         self.poll_future = Some(Box::pin(self.create_poll_messages_future()));
                     } else {
                         if let Some(ref encryptor) = self.encryptor {
@@ -898,6 +1095,14 @@ impl Stream for IggyConsumer {
                             }
                         }
 
+                        for message in &mut polled_messages.messages {
+                            if let Err(error) = Self::decompress_message(message) {
+                                self.poll_future = None;
+                                error!("Failed to decompress the message payload at offset: {}, partition ID: {}", message.offset, partition_id);
+                                return Poll::Ready(Some(Err(error)));
+                            }
+                        }
+
                         if let Some(current_offset_entry) = self.current_offsets.get(&partition_id)
                         {
                             current_offset_entry.store(polled_messages.current_offset, ORDERING);
@@ -953,7 +1158,7 @@ impl Stream for IggyConsumer {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct IggyConsumerBuilder {
     client: IggySharedMut<Box<dyn Client>>,
     consumer_name: String,
@@ -972,6 +1177,7 @@ pub struct IggyConsumerBuilder {
     init_retries: Option<u32>,
     init_retry_interval: IggyDuration,
     allow_replay: bool,
+    isolation_level: IsolationLevel,
 }
 
 impl IggyConsumerBuilder {
@@ -1007,6 +1213,7 @@ impl IggyConsumerBuilder {
             init_retries: None,
             init_retry_interval: IggyDuration::ONE_SECOND,
             allow_replay: false,
+            isolation_level: IsolationLevel::ReadUncommitted,
         }
     }
 
@@ -1046,6 +1253,31 @@ impl IggyConsumerBuilder {
         }
     }
 
+    /// Sets the auto-commit configuration matching the delivery guarantee, replacing the one set with `auto_commit()`.
+    pub fn delivery_mode(self, delivery_mode: DeliveryMode) -> Self {
+        Self {
+            auto_commit: delivery_mode.into(),
+            ..self
+        }
+    }
+
+    /// Stores the offset on the server when polling the messages, before they are handed to the application.
+    /// See `DeliveryMode::AtMostOnce` for details.
+    pub fn at_most_once(self) -> Self {
+        self.delivery_mode(DeliveryMode::AtMostOnce)
+    }
+
+    /// Stores the offset on the server only after each message has been successfully processed.
+    /// See `DeliveryMode::AtLeastOnce` for details.
+    pub fn at_least_once(self) -> Self {
+        self.delivery_mode(DeliveryMode::AtLeastOnce)
+    }
+
+    /// Never stores the offset automatically, it must be stored with `store_offset()`.
+    pub fn manual_commit(self) -> Self {
+        self.delivery_mode(DeliveryMode::Manual)
+    }
+
     pub fn commit_failed_messages(self) -> Self {
         Self {
             auto_commit: AutoCommit::Disabled,
@@ -1144,6 +1376,15 @@ impl IggyConsumerBuilder {
         }
     }
 
+    /// Sets the isolation level, `IsolationLevel::ReadUncommitted` by default.
+    /// With `IsolationLevel::ReadCommitted`, only the messages of the committed transactions and the ones sent outside of any transaction are consumed.
+    pub fn isolation_level(self, isolation_level: IsolationLevel) -> Self {
+        Self {
+            isolation_level,
+            ..self
+        }
+    }
+
     /// Builds the consumer.
     ///
     /// Note: After building the consumer, `init()` must be invoked before producing messages.
@@ -1166,6 +1407,49 @@ impl IggyConsumerBuilder {
             self.init_retries,
             self.init_retry_interval,
             self.allow_replay,
+            self.isolation_level,
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::client::MockClient;
+
+    #[test]
+    fn delivery_modes_should_be_mapped_to_auto_commit() {
+        assert_eq!(
+            AutoCommit::from(DeliveryMode::AtMostOnce),
+            AutoCommit::When(AutoCommitWhen::PollingMessages)
+        );
+        assert_eq!(
+            AutoCommit::from(DeliveryMode::AtLeastOnce),
+            AutoCommit::After(AutoCommitAfter::ProcessingEachMessage)
+        );
+        assert_eq!(AutoCommit::from(DeliveryMode::Manual), AutoCommit::Disabled);
+    }
+
+    #[test]
+    fn delivery_mode_should_replace_auto_commit() {
+        let client: Box<dyn Client> = Box::new(MockClient::new());
+        let consumer = IggyConsumerBuilder::new(
+            IggySharedMut::new(client),
+            "consumer".to_owned(),
+            Consumer::default(),
+            Identifier::numeric(1).unwrap(),
+            Identifier::numeric(1).unwrap(),
+            Some(1),
+            None,
+            None,
+        )
+        .auto_commit(AutoCommit::Interval(IggyDuration::ONE_SECOND))
+        .at_least_once()
+        .build();
+
+        assert_eq!(
+            consumer.auto_commit(),
+            AutoCommit::After(AutoCommitAfter::ProcessingEachMessage)
+        );
+    }
+}
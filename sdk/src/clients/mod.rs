@@ -17,6 +17,9 @@
  */
 
 pub mod builder;
+pub mod checkpoint;
 pub mod client;
 pub mod consumer;
+pub mod pattern_consumer;
 pub mod producer;
+pub mod snapshot_bootstrap;
@@ -0,0 +1,379 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::client::Client;
+use crate::clients::consumer::{IggyConsumer, IggyConsumerBuilder, ReceivedMessage};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::locking::{IggySharedMut, IggySharedMutFn};
+use crate::utils::duration::IggyDuration;
+use futures::stream::SelectAll;
+use futures::Stream;
+use futures_util::{FutureExt, StreamExt};
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use tokio::sync::oneshot;
+use tokio::time::{Interval, MissedTickBehavior};
+use tracing::{error, info};
+
+type TopicMessagesStream = Pin<Box<dyn Stream<Item = Result<TopicReceivedMessage, IggyError>>>>;
+type DiscoverTopicsFuture = Pin<Box<dyn Future<Output = Result<DiscoveredTopics, IggyError>>>>;
+
+/// The pattern evaluated against the topic names to decide which topics should be consumed.
+#[derive(Debug, Clone)]
+pub struct TopicPattern {
+    pattern: String,
+    regex: Regex,
+}
+
+impl TopicPattern {
+    /// Creates the pattern from the glob expression, where `*` matches any sequence of characters
+    /// and `?` matches a single character, e.g. `logs.*`.
+    pub fn glob(pattern: &str) -> Result<Self, IggyError> {
+        let mut expression = String::with_capacity(pattern.len() + 2);
+        expression.push('^');
+        for character in pattern.chars() {
+            match character {
+                '*' => expression.push_str(".*"),
+                '?' => expression.push('.'),
+                _ => expression.push_str(&regex::escape(&character.to_string())),
+            }
+        }
+        expression.push('$');
+        Self::new(pattern, &expression)
+    }
+
+    /// Creates the pattern from the regular expression, which must match the whole topic name.
+    pub fn regex(pattern: &str) -> Result<Self, IggyError> {
+        Self::new(pattern, &format!("^(?:{pattern})$"))
+    }
+
+    fn new(pattern: &str, expression: &str) -> Result<Self, IggyError> {
+        let regex = Regex::new(expression)
+            .map_err(|_| IggyError::InvalidTopicPattern(pattern.to_owned()))?;
+        Ok(Self {
+            pattern: pattern.to_owned(),
+            regex,
+        })
+    }
+
+    /// Returns `true` if the topic name matches the pattern.
+    pub fn matches(&self, topic_name: &str) -> bool {
+        self.regex.is_match(topic_name)
+    }
+}
+
+impl Display for TopicPattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.pattern)
+    }
+}
+
+/// The message received by the pattern consumer along with the topic it was consumed from.
+pub struct TopicReceivedMessage {
+    pub topic_id: u32,
+    pub topic_name: String,
+    pub message: ReceivedMessage,
+}
+
+struct DiscoveredTopics {
+    matching_topic_ids: HashSet<u32>,
+    consumers: Vec<(u32, String, IggyConsumer)>,
+}
+
+unsafe impl Send for IggyPatternConsumer {}
+unsafe impl Sync for IggyPatternConsumer {}
+
+/// The consumer subscribed to all the topics in the stream matching the pattern.
+/// The metadata is refreshed periodically, so the newly created matching topics are consumed automatically,
+/// while the consumers of the deleted or renamed topics are stopped.
+pub struct IggyPatternConsumer {
+    initialized: bool,
+    client: IggySharedMut<Box<dyn Client>>,
+    stream_id: Identifier,
+    pattern: TopicPattern,
+    consumer: IggyConsumerBuilder,
+    metadata_refresh_interval: IggyDuration,
+    refresh_timer: Option<Interval>,
+    discover_future: Option<DiscoverTopicsFuture>,
+    subscriptions: HashMap<u32, oneshot::Sender<()>>,
+    streams: SelectAll<TopicMessagesStream>,
+}
+
+impl IggyPatternConsumer {
+    pub(crate) fn new(
+        client: IggySharedMut<Box<dyn Client>>,
+        stream_id: Identifier,
+        pattern: TopicPattern,
+        consumer: IggyConsumerBuilder,
+        metadata_refresh_interval: IggyDuration,
+    ) -> Self {
+        Self {
+            initialized: false,
+            client,
+            stream_id,
+            pattern,
+            consumer,
+            metadata_refresh_interval,
+            refresh_timer: None,
+            discover_future: None,
+            subscriptions: HashMap::new(),
+            streams: SelectAll::new(),
+        }
+    }
+
+    /// Returns the stream ID of the consumer.
+    pub fn stream(&self) -> &Identifier {
+        &self.stream_id
+    }
+
+    /// Returns the pattern evaluated against the topic names.
+    pub fn pattern(&self) -> &TopicPattern {
+        &self.pattern
+    }
+
+    /// Returns the IDs of the topics currently consumed.
+    pub fn topics(&self) -> Vec<u32> {
+        let mut topic_ids = self.subscriptions.keys().copied().collect::<Vec<_>>();
+        topic_ids.sort_unstable();
+        topic_ids
+    }
+
+    /// Initializes the consumers for all the topics matching the pattern and starts the periodic metadata refresh.
+    ///
+    /// Note: This method must be called before polling messages.
+    pub async fn init(&mut self) -> Result<(), IggyError> {
+        if self.initialized {
+            return Ok(());
+        }
+
+        info!(
+            "Initializing pattern consumer for stream: {}, topics: {}...",
+            self.stream_id, self.pattern
+        );
+        let discovered = self.discover_topics().await?;
+        self.subscribe(discovered);
+
+        let interval = self.metadata_refresh_interval.get_duration();
+        let mut refresh_timer =
+            tokio::time::interval_at(tokio::time::Instant::now() + interval, interval);
+        refresh_timer.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        self.refresh_timer = Some(refresh_timer);
+        self.initialized = true;
+        info!(
+            "Pattern consumer for stream: {}, topics: {} has been initialized with {} matching topic(s).",
+            self.stream_id,
+            self.pattern,
+            self.subscriptions.len()
+        );
+        Ok(())
+    }
+
+    fn discover_topics(&self) -> DiscoverTopicsFuture {
+        let client = self.client.clone();
+        let stream_id = self.stream_id.clone();
+        let pattern = self.pattern.clone();
+        let consumer = self.consumer.clone();
+        let subscribed_topic_ids = self.subscriptions.keys().copied().collect::<HashSet<_>>();
+        Box::pin(async move {
+            let topics = client.read().await.get_topics(&stream_id).await?;
+            let mut discovered = DiscoveredTopics {
+                matching_topic_ids: HashSet::new(),
+                consumers: Vec::new(),
+            };
+            for topic in topics {
+                if !pattern.matches(&topic.name) {
+                    continue;
+                }
+
+                discovered.matching_topic_ids.insert(topic.id);
+                if subscribed_topic_ids.contains(&topic.id) {
+                    continue;
+                }
+
+                let mut topic_consumer = consumer
+                    .clone()
+                    .stream(stream_id.clone())
+                    .topic(Identifier::numeric(topic.id)?)
+                    .build();
+                topic_consumer.init().await?;
+                discovered
+                    .consumers
+                    .push((topic.id, topic.name, topic_consumer));
+            }
+            Ok(discovered)
+        })
+    }
+
+    fn subscribe(&mut self, discovered: DiscoveredTopics) {
+        let stream_id = &self.stream_id;
+        self.subscriptions.retain(|topic_id, _| {
+            let matching = discovered.matching_topic_ids.contains(topic_id);
+            if !matching {
+                info!("Topic with ID: {topic_id} in stream: {stream_id} no longer matches the pattern, stopping its consumer.");
+            }
+            matching
+        });
+
+        for (topic_id, topic_name, consumer) in discovered.consumers {
+            info!("Subscribing to topic: {topic_name} with ID: {topic_id} in stream: {stream_id} matching the pattern: {}.", self.pattern);
+            // Dropping the sender on unsubscribe completes the receiver and ends the topic stream.
+            let (sender, receiver) = oneshot::channel::<()>();
+            let stream = consumer.take_until(receiver).map(move |message| {
+                message.map(|message| TopicReceivedMessage {
+                    topic_id,
+                    topic_name: topic_name.clone(),
+                    message,
+                })
+            });
+            self.subscriptions.insert(topic_id, sender);
+            self.streams.push(Box::pin(stream));
+        }
+    }
+}
+
+impl Stream for IggyPatternConsumer {
+    type Item = Result<TopicReceivedMessage, IggyError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if self.discover_future.is_none() {
+            if let Some(refresh_timer) = self.refresh_timer.as_mut() {
+                if refresh_timer.poll_tick(cx).is_ready() {
+                    self.discover_future = Some(self.discover_topics());
+                }
+            }
+        }
+
+        if let Some(future) = self.discover_future.as_mut() {
+            if let Poll::Ready(result) = future.poll_unpin(cx) {
+                self.discover_future = None;
+                match result {
+                    Ok(discovered) => self.subscribe(discovered),
+                    Err(error) => {
+                        error!(
+                            "Failed to refresh the topics matching the pattern: {} in stream: {}. {error}",
+                            self.pattern, self.stream_id
+                        );
+                        return Poll::Ready(Some(Err(error)));
+                    }
+                }
+            }
+        }
+
+        match self.streams.poll_next_unpin(cx) {
+            Poll::Ready(Some(message)) => Poll::Ready(Some(message)),
+            // No matching topics yet, the refresh timer will wake up the task.
+            Poll::Ready(None) | Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct IggyPatternConsumerBuilder {
+    client: IggySharedMut<Box<dyn Client>>,
+    stream: Identifier,
+    pattern: TopicPattern,
+    consumer: IggyConsumerBuilder,
+    metadata_refresh_interval: IggyDuration,
+}
+
+impl IggyPatternConsumerBuilder {
+    pub(crate) fn new(
+        client: IggySharedMut<Box<dyn Client>>,
+        stream: Identifier,
+        pattern: TopicPattern,
+        consumer: IggyConsumerBuilder,
+    ) -> Self {
+        Self {
+            client,
+            stream,
+            pattern,
+            consumer,
+            metadata_refresh_interval: IggyDuration::new_from_secs(30),
+        }
+    }
+
+    /// Sets the interval of fetching the topics to discover the newly created ones matching the pattern, 30 seconds by default.
+    pub fn metadata_refresh_interval(self, interval: IggyDuration) -> Self {
+        Self {
+            metadata_refresh_interval: interval,
+            ..self
+        }
+    }
+
+    /// Configures the consumer created for each of the matching topics, e.g. the batch size or the auto-commit.
+    pub fn consumer(
+        self,
+        configure: impl FnOnce(IggyConsumerBuilder) -> IggyConsumerBuilder,
+    ) -> Self {
+        Self {
+            consumer: configure(self.consumer),
+            ..self
+        }
+    }
+
+    /// Builds the pattern consumer.
+    ///
+    /// Note: After building the consumer, `init()` must be invoked before consuming messages.
+    pub fn build(self) -> IggyPatternConsumer {
+        IggyPatternConsumer::new(
+            self.client,
+            self.stream,
+            self.pattern,
+            self.consumer,
+            self.metadata_refresh_interval,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn glob_pattern_should_match_topic_names() {
+        let pattern = TopicPattern::glob("logs.*").unwrap();
+        assert!(pattern.matches("logs.api"));
+        assert!(pattern.matches("logs."));
+        assert!(!pattern.matches("logs"));
+        assert!(!pattern.matches("applogs.api"));
+
+        let pattern = TopicPattern::glob("logs-?").unwrap();
+        assert!(pattern.matches("logs-1"));
+        assert!(!pattern.matches("logs-10"));
+    }
+
+    #[test]
+    fn regex_pattern_should_match_whole_topic_names() {
+        let pattern = TopicPattern::regex(r"orders-\d+").unwrap();
+        assert!(pattern.matches("orders-1"));
+        assert!(pattern.matches("orders-2024"));
+        assert!(!pattern.matches("orders-eu"));
+        assert!(!pattern.matches("old-orders-1"));
+    }
+
+    #[test]
+    fn invalid_regex_pattern_should_be_rejected() {
+        let pattern = TopicPattern::regex("orders-(");
+        assert!(matches!(pattern, Err(IggyError::InvalidTopicPattern(_))));
+    }
+}
@@ -23,6 +23,9 @@ use crate::error::IggyError;
 use crate::identifier::{IdKind, Identifier};
 use crate::locking::{IggySharedMut, IggySharedMutFn};
 use crate::messages::send_messages::{Message, Partitioning};
+use crate::models::producer_epoch::ProducerEpoch;
+use crate::models::producer_session::{ProducerSequence, ProducerSession};
+use crate::models::transaction::Transaction;
 use crate::partitioner::Partitioner;
 use crate::utils::crypto::EncryptorKind;
 use crate::utils::duration::IggyDuration;
@@ -54,6 +57,7 @@ pub struct IggyProducer {
     batch_size: Option<usize>,
     partitioning: Option<Arc<Partitioning>>,
     encryptor: Option<Arc<EncryptorKind>>,
+    compression: CompressionAlgorithm,
     partitioner: Option<Arc<dyn Partitioner>>,
     send_interval_micros: u64,
     create_stream_if_not_exists: bool,
@@ -67,6 +71,12 @@ pub struct IggyProducer {
     last_sent_at: Arc<AtomicU64>,
     send_retries_count: Option<u32>,
     send_retries_interval: Option<IggyDuration>,
+    producer_name: Option<String>,
+    producer_epoch: Option<ProducerEpoch>,
+    idempotent: bool,
+    producer_session: Option<ProducerSession>,
+    next_sequence: Arc<AtomicU64>,
+    transaction_id: Arc<AtomicU64>,
 }
 
 impl IggyProducer {
@@ -80,6 +90,7 @@ impl IggyProducer {
         batch_size: Option<usize>,
         partitioning: Option<Partitioning>,
         encryptor: Option<Arc<EncryptorKind>>,
+        compression: CompressionAlgorithm,
         partitioner: Option<Arc<dyn Partitioner>>,
         interval: Option<IggyDuration>,
         create_stream_if_not_exists: bool,
@@ -90,6 +101,8 @@ impl IggyProducer {
         topic_max_size: MaxTopicSize,
         send_retries_count: Option<u32>,
         send_retries_interval: Option<IggyDuration>,
+        producer_name: Option<String>,
+        idempotent: bool,
     ) -> Self {
         Self {
             initialized: false,
@@ -102,6 +115,7 @@ impl IggyProducer {
             batch_size,
             partitioning: partitioning.map(Arc::new),
             encryptor,
+            compression,
             partitioner,
             send_interval_micros: interval.map_or(0, |i| i.as_micros()),
             create_stream_if_not_exists,
@@ -115,6 +129,12 @@ impl IggyProducer {
             last_sent_at: Arc::new(AtomicU64::new(0)),
             send_retries_count,
             send_retries_interval,
+            producer_name,
+            producer_epoch: None,
+            idempotent,
+            producer_session: None,
+            next_sequence: Arc::new(AtomicU64::new(1)),
+            transaction_id: Arc::new(AtomicU64::new(0)),
         }
     }
 
@@ -188,11 +208,79 @@ impl IggyProducer {
                 .await?;
         }
 
+        if let Some(producer_name) = &self.producer_name {
+            let producer_epoch = client
+                .register_producer(&stream_id, &topic_id, producer_name)
+                .await?;
+            info!(
+                "Registered producer: {producer_name} with epoch: {} for stream: {stream_id} and topic: {topic_id}.",
+                producer_epoch.epoch
+            );
+            self.producer_epoch = Some(producer_epoch);
+        }
+
+        if self.idempotent {
+            let producer_session = client.init_producer_id(&stream_id, &topic_id).await?;
+            info!(
+                "Initialized idempotent producer with ID: {} for stream: {stream_id} and topic: {topic_id}.",
+                producer_session.producer_id
+            );
+            self.producer_session = Some(producer_session);
+        }
+
         self.initialized = true;
         info!("Producer has been initialized for stream: {stream_id} and topic: {topic_id}.");
         Ok(())
     }
 
+    /// Begins the new transaction, all the messages sent until it's committed or aborted are sent within it,
+    /// regardless of the stream, topic and partition they are sent to.
+    ///
+    /// Note: The transaction is aborted by the server if it's not completed within the configured timeout.
+    pub async fn begin_transaction(&self) -> Result<(), IggyError> {
+        let transaction = self.client.read().await.begin_transaction().await?;
+        let previous_transaction_id = self
+            .transaction_id
+            .swap(transaction.transaction_id, ORDERING);
+        if previous_transaction_id != 0 {
+            warn!("Transaction with ID: {previous_transaction_id} has not been completed and will be aborted by the server after the timeout.");
+        }
+        trace!("Began transaction with ID: {}.", transaction.transaction_id);
+        Ok(())
+    }
+
+    /// Commits the current transaction, making all the messages sent within it visible to the read-committed consumers at once.
+    pub async fn commit_transaction(&self) -> Result<(), IggyError> {
+        let transaction_id = self.transaction_id.swap(0, ORDERING);
+        if transaction_id == 0 {
+            return Err(IggyError::TransactionNotFound(transaction_id));
+        }
+
+        self.client
+            .read()
+            .await
+            .commit_transaction(transaction_id)
+            .await?;
+        trace!("Committed transaction with ID: {transaction_id}.");
+        Ok(())
+    }
+
+    /// Aborts the current transaction, so that the messages sent within it are skipped by the read-committed consumers.
+    pub async fn abort_transaction(&self) -> Result<(), IggyError> {
+        let transaction_id = self.transaction_id.swap(0, ORDERING);
+        if transaction_id == 0 {
+            return Err(IggyError::TransactionNotFound(transaction_id));
+        }
+
+        self.client
+            .read()
+            .await
+            .abort_transaction(transaction_id)
+            .await?;
+        trace!("Aborted transaction with ID: {transaction_id}.");
+        Ok(())
+    }
+
     async fn subscribe_events(&self) {
         trace!("Subscribing to diagnostic events");
         let mut receiver;
@@ -225,6 +313,9 @@ impl IggyProducer {
                     DiagnosticEvent::SignedOut => {
                         can_send.store(false, ORDERING);
                     }
+                    DiagnosticEvent::EndpointChanged(endpoint) => {
+                        info!("Connected to the different server endpoint: {endpoint}");
+                    }
                 }
             }
         });
@@ -309,7 +400,9 @@ impl IggyProducer {
         mut messages: Vec<Message>,
         partitioning: Option<Arc<Partitioning>>,
     ) -> Result<(), IggyError> {
+        self.compress_messages(&mut messages)?;
         self.encrypt_messages(&mut messages)?;
+        self.set_producer_headers(&mut messages)?;
         let partitioning = self.get_partitioning(&stream, &topic, &messages, partitioning)?;
         let batch_size = self.batch_size.unwrap_or(MAX_BATCH_SIZE);
         let batches = messages.chunks_mut(batch_size);
@@ -346,7 +439,9 @@ impl IggyProducer {
         partitioning: Option<Arc<Partitioning>>,
     ) -> Result<(), IggyError> {
         trace!("No batch size specified, sending messages immediately.");
+        self.compress_messages(&mut messages)?;
         self.encrypt_messages(&mut messages)?;
+        self.set_producer_headers(&mut messages)?;
         let partitioning = self.get_partitioning(stream, topic, &messages, partitioning)?;
         let batch_size = self.batch_size.unwrap_or(MAX_BATCH_SIZE);
         if messages.len() <= batch_size {
@@ -383,6 +478,22 @@ impl IggyProducer {
         sleep(Duration::from_micros(remaining)).await;
     }
 
+    fn compress_messages(&self, messages: &mut [Message]) -> Result<(), IggyError> {
+        if self.compression == CompressionAlgorithm::None {
+            return Ok(());
+        }
+
+        for message in messages {
+            if CompressionAlgorithm::from_headers(&message.headers)?.is_some() {
+                continue;
+            }
+            message.payload = Bytes::from(self.compression.compress(&message.payload)?);
+            message.length = message.payload.len() as u32;
+            self.compression.set_header(&mut message.headers)?;
+        }
+        Ok(())
+    }
+
     fn encrypt_messages(&self, messages: &mut [Message]) -> Result<(), IggyError> {
         if let Some(encryptor) = &self.encryptor {
             for message in messages {
@@ -393,6 +504,32 @@ impl IggyProducer {
         Ok(())
     }
 
+    fn set_producer_headers(&self, messages: &mut [Message]) -> Result<(), IggyError> {
+        if let Some(producer_epoch) = &self.producer_epoch {
+            for message in messages.iter_mut() {
+                producer_epoch.set_headers(&mut message.headers)?;
+            }
+        }
+        // The sequence numbers are assigned once, before batching, so the retried batches are sent with the same ones.
+        if let Some(producer_session) = &self.producer_session {
+            for message in messages.iter_mut() {
+                ProducerSequence {
+                    producer_id: producer_session.producer_id,
+                    sequence: self.next_sequence.fetch_add(1, ORDERING),
+                }
+                .set_headers(&mut message.headers)?;
+            }
+        }
+        let transaction_id = self.transaction_id.load(ORDERING);
+        if transaction_id != 0 {
+            let transaction = Transaction { transaction_id };
+            for message in messages {
+                transaction.set_headers(&mut message.headers)?;
+            }
+        }
+        Ok(())
+    }
+
     async fn try_send_messages(
         &self,
         stream: &Identifier,
@@ -485,6 +622,13 @@ impl IggyProducer {
                 .await
             {
                 Ok(_) => return Ok(()),
+                Err(error @ IggyError::ProducerFenced(..)) => {
+                    error!(
+                        "Failed to send messages to topic: {topic}, stream: {stream}, \
+                         the producer has been fenced by its newer instance. {error}."
+                    );
+                    return Err(error);
+                }
                 Err(error) => {
                     retries += 1;
                     if retries > max_retries {
@@ -544,6 +688,7 @@ pub struct IggyProducerBuilder {
     batch_size: Option<usize>,
     partitioning: Option<Partitioning>,
     encryptor: Option<Arc<EncryptorKind>>,
+    compression: CompressionAlgorithm,
     partitioner: Option<Arc<dyn Partitioner>>,
     send_interval: Option<IggyDuration>,
     create_stream_if_not_exists: bool,
@@ -554,6 +699,8 @@ pub struct IggyProducerBuilder {
     send_retries_interval: Option<IggyDuration>,
     topic_message_expiry: IggyExpiry,
     topic_max_size: MaxTopicSize,
+    producer_name: Option<String>,
+    idempotent: bool,
 }
 
 impl IggyProducerBuilder {
@@ -576,6 +723,7 @@ impl IggyProducerBuilder {
             batch_size: Some(1000),
             partitioning: None,
             encryptor,
+            compression: CompressionAlgorithm::None,
             partitioner,
             send_interval: Some(IggyDuration::from(1000)),
             create_stream_if_not_exists: true,
@@ -586,6 +734,8 @@ impl IggyProducerBuilder {
             topic_max_size: MaxTopicSize::ServerDefault,
             send_retries_count: Some(3),
             send_retries_interval: Some(IggyDuration::ONE_SECOND),
+            producer_name: None,
+            idempotent: false,
         }
     }
 
@@ -651,6 +801,15 @@ impl IggyProducerBuilder {
         }
     }
 
+    /// Sets the algorithm for compressing the messages' payloads before sending them.
+    /// The messages are marked as compressed, so the server doesn't compress them again.
+    pub fn compression(self, compression: CompressionAlgorithm) -> Self {
+        Self {
+            compression,
+            ..self
+        }
+    }
+
     /// Sets the partitioning strategy for messages.
     pub fn partitioning(self, partitioning: Partitioning) -> Self {
         Self {
@@ -736,6 +895,43 @@ impl IggyProducerBuilder {
         }
     }
 
+    /// Registers the producer under the given name when initialized, which fences all the previous instances of the same producer.
+    /// The messages are sent with the producer name and epoch headers, and once the newer instance registers,
+    /// the messages sent by this one are rejected with the `ProducerFenced` error instead of being appended as duplicates.
+    pub fn fencing(self, name: &str) -> Self {
+        Self {
+            producer_name: Some(name.to_string()),
+            ..self
+        }
+    }
+
+    /// Disables the producer registration and fencing.
+    pub fn without_fencing(self) -> Self {
+        Self {
+            producer_name: None,
+            ..self
+        }
+    }
+
+    /// Starts the idempotent producer session when initialized, and sends each message with the producer ID
+    /// and the increasing sequence number headers, so that the messages sent again on retry are appended only once by the partition.
+    /// The retries are deduplicated deterministically only when they are sent to the same partition (using the partition ID or messages key),
+    /// and the messages must not be sent concurrently to the same partition, as the partition ignores the ones with lower sequence numbers.
+    pub fn idempotence(self) -> Self {
+        Self {
+            idempotent: true,
+            ..self
+        }
+    }
+
+    /// Disables the idempotent producer session.
+    pub fn without_idempotence(self) -> Self {
+        Self {
+            idempotent: false,
+            ..self
+        }
+    }
+
     /// Builds the producer.
     ///
     /// Note: After building the producer, `init()` must be invoked before producing messages.
@@ -749,6 +945,7 @@ impl IggyProducerBuilder {
             self.batch_size,
             self.partitioning,
             self.encryptor,
+            self.compression,
             self.partitioner,
             self.send_interval,
             self.create_stream_if_not_exists,
@@ -759,6 +956,8 @@ impl IggyProducerBuilder {
             self.topic_max_size,
             self.send_retries_count,
             self.send_retries_interval,
+            self.producer_name,
+            self.idempotent,
         )
     }
 }
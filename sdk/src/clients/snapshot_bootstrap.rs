@@ -0,0 +1,376 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::mapper;
+use crate::client::ConsumerOffsetClient;
+use crate::clients::consumer::ReceivedMessage;
+use crate::consumer::Consumer;
+use crate::consumer_offsets::store_consumer_offsets::PartitionOffset;
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::models::messages::PolledMessage;
+use crate::system::handshake::Handshake;
+use bytes::Bytes;
+use std::path::Path;
+use std::sync::Arc;
+use tracing::info;
+
+/// The extension of the partition snapshot files written by the server.
+pub const TOPIC_SNAPSHOT_FILE_EXTENSION: &str = "snapshot";
+
+/// The length of the header preceding the messages in the partition snapshot.
+const PARTITION_SNAPSHOT_HEADER_LEN: usize = 16;
+
+/// The snapshot of a single partition, containing its most recent messages at the time it was taken.
+#[derive(Debug)]
+pub struct PartitionSnapshot {
+    /// The partition the messages belong to.
+    pub partition_id: u32,
+    /// The current offset of the partition when the snapshot was taken.
+    pub current_offset: u64,
+    /// The messages sorted by their offsets.
+    pub messages: Vec<PolledMessage>,
+}
+
+impl PartitionSnapshot {
+    /// Parses the snapshot stored by the server, which uses the same binary format as the poll response
+    /// without any optional features negotiated.
+    pub fn from_bytes(bytes: Bytes) -> Result<Self, IggyError> {
+        if bytes.len() < PARTITION_SNAPSHOT_HEADER_LEN {
+            return Err(IggyError::InvalidTopicSnapshot(format!(
+                "partition snapshot has only {} bytes",
+                bytes.len()
+            )));
+        }
+
+        let polled_messages =
+            mapper::map_polled_messages(bytes, Handshake::default(), false, false)?;
+        Ok(PartitionSnapshot {
+            partition_id: polled_messages.partition_id,
+            current_offset: polled_messages.current_offset,
+            messages: polled_messages.messages,
+        })
+    }
+
+    /// Returns the offset of the last message in the snapshot, if any.
+    pub fn last_offset(&self) -> Option<u64> {
+        self.messages.last().map(|message| message.offset)
+    }
+}
+
+/// The snapshot of a topic stored periodically by the server in the cold storage (archiver),
+/// which allows the new consumers to start with the recent state instead of replaying the whole topic.
+#[derive(Debug, Default)]
+pub struct TopicSnapshot {
+    /// The snapshots of the topic partitions, sorted by the partition ID.
+    pub partitions: Vec<PartitionSnapshot>,
+}
+
+impl TopicSnapshot {
+    /// Loads the snapshot from the directory containing the `{partition_id}.snapshot` files,
+    /// e.g. the archived `topic_snapshots/{stream_id}/{topic_id}` directory downloaded from the cold storage.
+    pub async fn load(directory: impl AsRef<Path>) -> Result<Self, IggyError> {
+        let directory = directory.as_ref();
+        let mut entries = tokio::fs::read_dir(directory).await.map_err(|error| {
+            IggyError::InvalidTopicSnapshot(format!(
+                "cannot read directory: {}. {error}",
+                directory.display()
+            ))
+        })?;
+
+        let mut partitions = Vec::new();
+        while let Some(entry) = entries.next_entry().await.map_err(|error| {
+            IggyError::InvalidTopicSnapshot(format!(
+                "cannot read directory: {}. {error}",
+                directory.display()
+            ))
+        })? {
+            let path = entry.path();
+            if path.extension().and_then(|extension| extension.to_str())
+                != Some(TOPIC_SNAPSHOT_FILE_EXTENSION)
+            {
+                continue;
+            }
+
+            let bytes = tokio::fs::read(&path).await.map_err(|error| {
+                IggyError::InvalidTopicSnapshot(format!(
+                    "cannot read file: {}. {error}",
+                    path.display()
+                ))
+            })?;
+            partitions.push(PartitionSnapshot::from_bytes(Bytes::from(bytes))?);
+        }
+
+        partitions.sort_by_key(|partition| partition.partition_id);
+        Ok(TopicSnapshot { partitions })
+    }
+
+    /// Returns the total number of the messages in the snapshot.
+    pub fn messages_count(&self) -> usize {
+        self.partitions
+            .iter()
+            .map(|partition| partition.messages.len())
+            .sum()
+    }
+}
+
+/// `SnapshotBootstrap` hands over the messages from a topic snapshot to the consumer
+/// and then aligns its stored offsets, so that the consumer polling with `PollingStrategy::next()`
+/// switches to the live messages appended right after the snapshot.
+pub struct SnapshotBootstrap<C: ConsumerOffsetClient + ?Sized> {
+    client: Arc<C>,
+    consumer: Consumer,
+    stream_id: Identifier,
+    topic_id: Identifier,
+}
+
+impl<C: ConsumerOffsetClient + ?Sized> SnapshotBootstrap<C> {
+    /// Creates a new snapshot bootstrap for the consumer of the given stream and topic.
+    pub fn new(
+        client: Arc<C>,
+        consumer: Consumer,
+        stream_id: Identifier,
+        topic_id: Identifier,
+    ) -> Self {
+        Self {
+            client,
+            consumer,
+            stream_id,
+            topic_id,
+        }
+    }
+
+    /// Passes all the snapshot messages to the handler, partition by partition, and stores the offset
+    /// of the last handled message of each partition, returning the number of the handled messages.
+    ///
+    /// The offsets are stored only once all the messages have been handled, so if the handler fails,
+    /// the bootstrap can be safely repeated.
+    pub async fn bootstrap<F>(
+        &self,
+        snapshot: TopicSnapshot,
+        mut handler: F,
+    ) -> Result<u64, IggyError>
+    where
+        F: FnMut(ReceivedMessage) -> Result<(), IggyError>,
+    {
+        let mut offsets = Vec::with_capacity(snapshot.partitions.len());
+        let mut handled_messages = 0;
+        for partition in snapshot.partitions {
+            let Some(last_offset) = partition.last_offset() else {
+                continue;
+            };
+
+            for message in partition.messages {
+                handler(ReceivedMessage::new(
+                    message,
+                    partition.current_offset,
+                    partition.partition_id,
+                ))?;
+                handled_messages += 1;
+            }
+            offsets.push(PartitionOffset {
+                partition_id: partition.partition_id,
+                offset: last_offset,
+            });
+        }
+
+        if !offsets.is_empty() {
+            self.client
+                .store_consumer_offsets(&self.consumer, &self.stream_id, &self.topic_id, &offsets)
+                .await?;
+        }
+        info!(
+            "Bootstrapped consumer: {} from snapshot of topic: {} in stream: {} with {handled_messages} messages.",
+            self.consumer.id, self.topic_id, self.stream_id
+        );
+        Ok(handled_messages)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::consumer_offsets::get_offsets_for_timestamps::PartitionTimestamp;
+    use crate::models::consumer_offset_info::ConsumerOffsetInfo;
+    use crate::models::partition_timestamp_offset::PartitionTimestampOffset;
+    use async_trait::async_trait;
+    use bytes::{BufMut, BytesMut};
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct TestClient {
+        offsets: Mutex<HashMap<u32, u64>>,
+    }
+
+    #[async_trait]
+    impl ConsumerOffsetClient for TestClient {
+        async fn store_consumer_offset(
+            &self,
+            _consumer: &Consumer,
+            _stream_id: &Identifier,
+            _topic_id: &Identifier,
+            partition_id: Option<u32>,
+            offset: u64,
+        ) -> Result<(), IggyError> {
+            self.offsets
+                .lock()
+                .unwrap()
+                .insert(partition_id.unwrap(), offset);
+            Ok(())
+        }
+
+        async fn store_consumer_offsets(
+            &self,
+            _consumer: &Consumer,
+            _stream_id: &Identifier,
+            _topic_id: &Identifier,
+            offsets: &[PartitionOffset],
+        ) -> Result<(), IggyError> {
+            let mut stored_offsets = self.offsets.lock().unwrap();
+            for offset in offsets {
+                stored_offsets.insert(offset.partition_id, offset.offset);
+            }
+            Ok(())
+        }
+
+        async fn get_consumer_offset(
+            &self,
+            _consumer: &Consumer,
+            _stream_id: &Identifier,
+            _topic_id: &Identifier,
+            _partition_id: Option<u32>,
+        ) -> Result<Option<ConsumerOffsetInfo>, IggyError> {
+            Ok(None)
+        }
+
+        async fn delete_consumer_offset(
+            &self,
+            _consumer: &Consumer,
+            _stream_id: &Identifier,
+            _topic_id: &Identifier,
+            partition_id: Option<u32>,
+        ) -> Result<(), IggyError> {
+            self.offsets.lock().unwrap().remove(&partition_id.unwrap());
+            Ok(())
+        }
+
+        async fn get_offsets_for_timestamps(
+            &self,
+            _stream_id: &Identifier,
+            _topic_id: &Identifier,
+            _timestamps: &[PartitionTimestamp],
+        ) -> Result<Vec<PartitionTimestampOffset>, IggyError> {
+            Ok(Vec::new())
+        }
+    }
+
+    fn partition_snapshot_bytes(partition_id: u32, offsets: &[u64]) -> Bytes {
+        let mut bytes = BytesMut::new();
+        bytes.put_u32_le(partition_id);
+        bytes.put_u64_le(offsets.last().copied().unwrap_or_default());
+        bytes.put_u32_le(offsets.len() as u32);
+        for offset in offsets {
+            let payload = format!("message-{offset}");
+            bytes.put_u64_le(*offset);
+            bytes.put_u8(1);
+            bytes.put_u64_le(1000 + offset);
+            bytes.put_u128_le(*offset as u128);
+            bytes.put_u32_le(0);
+            bytes.put_u32_le(0);
+            bytes.put_u32_le(payload.len() as u32);
+            bytes.put_slice(payload.as_bytes());
+        }
+        bytes.freeze()
+    }
+
+    #[test]
+    fn partition_snapshot_should_be_parsed() {
+        let snapshot =
+            PartitionSnapshot::from_bytes(partition_snapshot_bytes(2, &[5, 6, 7])).unwrap();
+        assert_eq!(snapshot.partition_id, 2);
+        assert_eq!(snapshot.current_offset, 7);
+        assert_eq!(snapshot.last_offset(), Some(7));
+        assert_eq!(snapshot.messages.len(), 3);
+        assert_eq!(snapshot.messages[0].payload, Bytes::from("message-5"));
+    }
+
+    #[test]
+    fn truncated_partition_snapshot_should_be_rejected() {
+        let result = PartitionSnapshot::from_bytes(Bytes::from_static(&[1, 2, 3]));
+        assert!(matches!(result, Err(IggyError::InvalidTopicSnapshot(_))));
+    }
+
+    #[tokio::test]
+    async fn bootstrap_should_handle_all_messages_and_store_last_offsets() {
+        let client = Arc::new(TestClient::default());
+        let bootstrap = SnapshotBootstrap::new(
+            client.clone(),
+            Consumer::new(Identifier::numeric(1).unwrap()),
+            Identifier::numeric(1).unwrap(),
+            Identifier::numeric(1).unwrap(),
+        );
+        let snapshot = TopicSnapshot {
+            partitions: vec![
+                PartitionSnapshot::from_bytes(partition_snapshot_bytes(1, &[3, 4])).unwrap(),
+                PartitionSnapshot::from_bytes(partition_snapshot_bytes(2, &[])).unwrap(),
+                PartitionSnapshot::from_bytes(partition_snapshot_bytes(3, &[10])).unwrap(),
+            ],
+        };
+        assert_eq!(snapshot.messages_count(), 3);
+
+        let mut received = Vec::new();
+        let handled_messages = bootstrap
+            .bootstrap(snapshot, |message| {
+                received.push((message.partition_id, message.message.offset));
+                Ok(())
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(handled_messages, 3);
+        assert_eq!(received, vec![(1, 3), (1, 4), (3, 10)]);
+        let offsets = client.offsets.lock().unwrap();
+        assert_eq!(offsets.len(), 2);
+        assert_eq!(offsets[&1], 4);
+        assert_eq!(offsets[&3], 10);
+    }
+
+    #[tokio::test]
+    async fn offsets_should_not_be_stored_when_handler_fails() {
+        let client = Arc::new(TestClient::default());
+        let bootstrap = SnapshotBootstrap::new(
+            client.clone(),
+            Consumer::new(Identifier::numeric(1).unwrap()),
+            Identifier::numeric(1).unwrap(),
+            Identifier::numeric(1).unwrap(),
+        );
+        let snapshot = TopicSnapshot {
+            partitions: vec![
+                PartitionSnapshot::from_bytes(partition_snapshot_bytes(1, &[3, 4])).unwrap(),
+            ],
+        };
+
+        let result = bootstrap
+            .bootstrap(snapshot, |_| Err(IggyError::InvalidCommand))
+            .await;
+
+        assert!(result.is_err());
+        assert!(client.offsets.lock().unwrap().is_empty());
+    }
+}
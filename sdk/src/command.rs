@@ -27,16 +27,34 @@ pub trait Command: BytesSerializable + Validatable<IggyError> + Send + Sync + Di
 
 pub const PING: &str = "ping";
 pub const PING_CODE: u32 = 1;
+pub const HANDSHAKE: &str = "handshake";
+pub const HANDSHAKE_CODE: u32 = 2;
 pub const GET_STATS: &str = "stats";
 pub const GET_STATS_CODE: u32 = 10;
 pub const GET_SNAPSHOT_FILE: &str = "snapshot";
 pub const GET_SNAPSHOT_FILE_CODE: u32 = 11;
+pub const GET_MAINTENANCE_MODE: &str = "maintenance.get";
+pub const GET_MAINTENANCE_MODE_CODE: u32 = 12;
+pub const SET_MAINTENANCE_MODE: &str = "maintenance.set";
+pub const SET_MAINTENANCE_MODE_CODE: u32 = 13;
+pub const GET_SERVER_INFO: &str = "server.info";
+pub const GET_SERVER_INFO_CODE: u32 = 14;
+pub const CREATE_BACKUP: &str = "backup.create";
+pub const CREATE_BACKUP_CODE: u32 = 15;
+pub const GET_UNSAVED_STATE: &str = "unsaved_state";
+pub const GET_UNSAVED_STATE_CODE: u32 = 16;
+pub const GET_AUDIT_LOG: &str = "audit_log";
+pub const GET_AUDIT_LOG_CODE: u32 = 17;
+pub const GET_ERROR_CATALOG: &str = "error_catalog";
+pub const GET_ERROR_CATALOG_CODE: u32 = 18;
 pub const GET_ME: &str = "me";
 pub const GET_ME_CODE: u32 = 20;
 pub const GET_CLIENT: &str = "client.get";
 pub const GET_CLIENT_CODE: u32 = 21;
 pub const GET_CLIENTS: &str = "client.list";
 pub const GET_CLIENTS_CODE: u32 = 22;
+pub const UPDATE_USER_QUOTAS: &str = "user.quotas";
+pub const UPDATE_USER_QUOTAS_CODE: u32 = 30;
 pub const GET_USER: &str = "user.get";
 pub const GET_USER_CODE: u32 = 31;
 pub const GET_USERS: &str = "user.list";
@@ -55,6 +73,8 @@ pub const LOGIN_USER: &str = "user.login";
 pub const LOGIN_USER_CODE: u32 = 38;
 pub const LOGOUT_USER: &str = "user.logout";
 pub const LOGOUT_USER_CODE: u32 = 39;
+pub const LOGIN_AS: &str = "user.login_as";
+pub const LOGIN_AS_CODE: u32 = 40;
 pub const GET_PERSONAL_ACCESS_TOKENS: &str = "personal_access_token.list";
 pub const GET_PERSONAL_ACCESS_TOKENS_CODE: u32 = 41;
 pub const CREATE_PERSONAL_ACCESS_TOKEN: &str = "personal_access_token.create";
@@ -63,18 +83,54 @@ pub const DELETE_PERSONAL_ACCESS_TOKEN: &str = "personal_access_token.delete";
 pub const DELETE_PERSONAL_ACCESS_TOKEN_CODE: u32 = 43;
 pub const LOGIN_WITH_PERSONAL_ACCESS_TOKEN: &str = "personal_access_token.login";
 pub const LOGIN_WITH_PERSONAL_ACCESS_TOKEN_CODE: u32 = 44;
+pub const ROTATE_PERSONAL_ACCESS_TOKEN: &str = "personal_access_token.rotate";
+pub const ROTATE_PERSONAL_ACCESS_TOKEN_CODE: u32 = 45;
 pub const POLL_MESSAGES: &str = "message.poll";
 pub const POLL_MESSAGES_CODE: u32 = 100;
 pub const SEND_MESSAGES: &str = "message.send";
 pub const SEND_MESSAGES_CODE: u32 = 101;
 pub const FLUSH_UNSAVED_BUFFER: &str = "message.flush_unsaved_buffer";
 pub const FLUSH_UNSAVED_BUFFER_CODE: u32 = 102;
+pub const BEGIN_TRANSACTION: &str = "message.transaction.begin";
+pub const BEGIN_TRANSACTION_CODE: u32 = 103;
+pub const COMMIT_TRANSACTION: &str = "message.transaction.commit";
+pub const COMMIT_TRANSACTION_CODE: u32 = 104;
+pub const ABORT_TRANSACTION: &str = "message.transaction.abort";
+pub const ABORT_TRANSACTION_CODE: u32 = 105;
+pub const AGGREGATE_MESSAGES: &str = "message.aggregate";
+pub const AGGREGATE_MESSAGES_CODE: u32 = 106;
+pub const SEND_MESSAGES_BATCH: &str = "message.send_batch";
+pub const SEND_MESSAGES_BATCH_CODE: u32 = 107;
+pub const REPLAY_MESSAGES: &str = "message.replay";
+pub const REPLAY_MESSAGES_CODE: u32 = 110;
+pub const GET_REPLAY_JOBS: &str = "message.replay_job.list";
+pub const GET_REPLAY_JOBS_CODE: u32 = 111;
+pub const CANCEL_REPLAY_JOB: &str = "message.replay_job.cancel";
+pub const CANCEL_REPLAY_JOB_CODE: u32 = 112;
+pub const CREATE_PUSH_SUBSCRIPTION: &str = "message.push_subscription.create";
+pub const CREATE_PUSH_SUBSCRIPTION_CODE: u32 = 113;
+pub const GET_PUSH_SUBSCRIPTIONS: &str = "message.push_subscription.list";
+pub const GET_PUSH_SUBSCRIPTIONS_CODE: u32 = 114;
+pub const DELETE_PUSH_SUBSCRIPTION: &str = "message.push_subscription.delete";
+pub const DELETE_PUSH_SUBSCRIPTION_CODE: u32 = 115;
+pub const REGISTER_PRODUCER: &str = "message.producer.register";
+pub const REGISTER_PRODUCER_CODE: u32 = 116;
+pub const INIT_PRODUCER_ID: &str = "message.producer.init_id";
+pub const INIT_PRODUCER_ID_CODE: u32 = 117;
+pub const NACK_MESSAGES: &str = "message.nack";
+pub const NACK_MESSAGES_CODE: u32 = 118;
+pub const ACK_MESSAGES: &str = "message.ack";
+pub const ACK_MESSAGES_CODE: u32 = 119;
 pub const GET_CONSUMER_OFFSET: &str = "consumer_offset.get";
 pub const GET_CONSUMER_OFFSET_CODE: u32 = 120;
 pub const STORE_CONSUMER_OFFSET: &str = "consumer_offset.store";
 pub const STORE_CONSUMER_OFFSET_CODE: u32 = 121;
 pub const DELETE_CONSUMER_OFFSET: &str = "consumer_offset.delete";
 pub const DELETE_CONSUMER_OFFSET_CODE: u32 = 122;
+pub const STORE_CONSUMER_OFFSETS: &str = "consumer_offset.store_batch";
+pub const STORE_CONSUMER_OFFSETS_CODE: u32 = 123;
+pub const GET_OFFSETS_FOR_TIMESTAMPS: &str = "consumer_offset.for_timestamps";
+pub const GET_OFFSETS_FOR_TIMESTAMPS_CODE: u32 = 124;
 pub const GET_STREAM: &str = "stream.get";
 pub const GET_STREAM_CODE: u32 = 200;
 pub const GET_STREAMS: &str = "stream.list";
@@ -87,6 +143,10 @@ pub const UPDATE_STREAM: &str = "stream.update";
 pub const UPDATE_STREAM_CODE: u32 = 204;
 pub const PURGE_STREAM: &str = "stream.purge";
 pub const PURGE_STREAM_CODE: u32 = 205;
+pub const UPDATE_STREAM_METADATA: &str = "stream.update_metadata";
+pub const UPDATE_STREAM_METADATA_CODE: u32 = 206;
+pub const UPDATE_STREAM_QUOTA: &str = "stream.update_quota";
+pub const UPDATE_STREAM_QUOTA_CODE: u32 = 207;
 pub const GET_TOPIC: &str = "topic.get";
 pub const GET_TOPIC_CODE: u32 = 300;
 pub const GET_TOPICS: &str = "topic.list";
@@ -99,10 +159,26 @@ pub const UPDATE_TOPIC: &str = "topic.update";
 pub const UPDATE_TOPIC_CODE: u32 = 304;
 pub const PURGE_TOPIC: &str = "topic.purge";
 pub const PURGE_TOPIC_CODE: u32 = 305;
+pub const UPDATE_TOPIC_METADATA: &str = "topic.update_metadata";
+pub const UPDATE_TOPIC_METADATA_CODE: u32 = 306;
+pub const UPDATE_TOPIC_PRODUCERS: &str = "topic.update_producers";
+pub const UPDATE_TOPIC_PRODUCERS_CODE: u32 = 307;
+pub const MARK_TOPIC_FOR_DELETION: &str = "topic.mark_for_deletion";
+pub const MARK_TOPIC_FOR_DELETION_CODE: u32 = 308;
+pub const UPDATE_TOPIC_CONFIG: &str = "topic.update_config";
+pub const UPDATE_TOPIC_CONFIG_CODE: u32 = 309;
+pub const UPDATE_TOPIC_EXPIRY_WATCHER: &str = "topic.update_expiry_watcher";
+pub const UPDATE_TOPIC_EXPIRY_WATCHER_CODE: u32 = 310;
 pub const CREATE_PARTITIONS: &str = "partition.create";
 pub const CREATE_PARTITIONS_CODE: u32 = 402;
 pub const DELETE_PARTITIONS: &str = "partition.delete";
 pub const DELETE_PARTITIONS_CODE: u32 = 403;
+pub const RESTORE_ARCHIVED_SEGMENTS: &str = "partition.restore_archived_segments";
+pub const RESTORE_ARCHIVED_SEGMENTS_CODE: u32 = 404;
+pub const FLUSH_PARTITION: &str = "partition.flush";
+pub const FLUSH_PARTITION_CODE: u32 = 405;
+pub const READ_SEGMENT_RANGE: &str = "partition.read_segment_range";
+pub const READ_SEGMENT_RANGE_CODE: u32 = 406;
 pub const GET_CONSUMER_GROUP: &str = "consumer_group.get";
 pub const GET_CONSUMER_GROUP_CODE: u32 = 600;
 pub const GET_CONSUMER_GROUPS: &str = "consumer_group.list";
@@ -115,11 +191,38 @@ pub const JOIN_CONSUMER_GROUP: &str = "consumer_group.join";
 pub const JOIN_CONSUMER_GROUP_CODE: u32 = 604;
 pub const LEAVE_CONSUMER_GROUP: &str = "consumer_group.leave";
 pub const LEAVE_CONSUMER_GROUP_CODE: u32 = 605;
+pub const UPDATE_CONSUMER_GROUP_DEAD_LETTER: &str = "consumer_group.update_dead_letter";
+pub const UPDATE_CONSUMER_GROUP_DEAD_LETTER_CODE: u32 = 606;
+pub const UPDATE_CONSUMER_GROUP_VISIBILITY_TIMEOUT: &str =
+    "consumer_group.update_visibility_timeout";
+pub const UPDATE_CONSUMER_GROUP_VISIBILITY_TIMEOUT_CODE: u32 = 607;
+pub const GET_PARTITION_STATE: &str = "consumer_group.get_partition_state";
+pub const GET_PARTITION_STATE_CODE: u32 = 608;
+pub const STORE_PARTITION_STATE: &str = "consumer_group.store_partition_state";
+pub const STORE_PARTITION_STATE_CODE: u32 = 609;
+pub const DELETE_PARTITION_STATE: &str = "consumer_group.delete_partition_state";
+pub const DELETE_PARTITION_STATE_CODE: u32 = 610;
+pub const GET_NAMESPACE: &str = "namespace.get";
+pub const GET_NAMESPACE_CODE: u32 = 700;
+pub const GET_NAMESPACES: &str = "namespace.list";
+pub const GET_NAMESPACES_CODE: u32 = 701;
+pub const CREATE_NAMESPACE: &str = "namespace.create";
+pub const CREATE_NAMESPACE_CODE: u32 = 702;
+pub const DELETE_NAMESPACE: &str = "namespace.delete";
+pub const DELETE_NAMESPACE_CODE: u32 = 703;
+pub const UPDATE_NAMESPACE: &str = "namespace.update";
+pub const UPDATE_NAMESPACE_CODE: u32 = 704;
 
 pub fn get_name_from_code(code: u32) -> Result<&'static str, IggyError> {
     match code {
         PING_CODE => Ok(PING),
+        HANDSHAKE_CODE => Ok(HANDSHAKE),
         GET_STATS_CODE => Ok(GET_STATS),
+        GET_SERVER_INFO_CODE => Ok(GET_SERVER_INFO),
+        CREATE_BACKUP_CODE => Ok(CREATE_BACKUP),
+        GET_UNSAVED_STATE_CODE => Ok(GET_UNSAVED_STATE),
+        GET_AUDIT_LOG_CODE => Ok(GET_AUDIT_LOG),
+        GET_ERROR_CATALOG_CODE => Ok(GET_ERROR_CATALOG),
         GET_ME_CODE => Ok(GET_ME),
         GET_CLIENT_CODE => Ok(GET_CLIENT),
         GET_CLIENTS_CODE => Ok(GET_CLIENTS),
@@ -129,38 +232,80 @@ pub fn get_name_from_code(code: u32) -> Result<&'static str, IggyError> {
         DELETE_USER_CODE => Ok(DELETE_USER),
         UPDATE_USER_CODE => Ok(UPDATE_USER),
         UPDATE_PERMISSIONS_CODE => Ok(UPDATE_PERMISSIONS),
+        UPDATE_USER_QUOTAS_CODE => Ok(UPDATE_USER_QUOTAS),
         CHANGE_PASSWORD_CODE => Ok(CHANGE_PASSWORD),
         LOGIN_USER_CODE => Ok(LOGIN_USER),
         LOGOUT_USER_CODE => Ok(LOGOUT_USER),
+        LOGIN_AS_CODE => Ok(LOGIN_AS),
         GET_PERSONAL_ACCESS_TOKENS_CODE => Ok(GET_PERSONAL_ACCESS_TOKENS),
         CREATE_PERSONAL_ACCESS_TOKEN_CODE => Ok(CREATE_PERSONAL_ACCESS_TOKEN),
         DELETE_PERSONAL_ACCESS_TOKEN_CODE => Ok(DELETE_PERSONAL_ACCESS_TOKEN),
         LOGIN_WITH_PERSONAL_ACCESS_TOKEN_CODE => Ok(LOGIN_WITH_PERSONAL_ACCESS_TOKEN),
+        ROTATE_PERSONAL_ACCESS_TOKEN_CODE => Ok(ROTATE_PERSONAL_ACCESS_TOKEN),
         SEND_MESSAGES_CODE => Ok(SEND_MESSAGES),
         POLL_MESSAGES_CODE => Ok(POLL_MESSAGES),
         FLUSH_UNSAVED_BUFFER_CODE => Ok(FLUSH_UNSAVED_BUFFER),
+        BEGIN_TRANSACTION_CODE => Ok(BEGIN_TRANSACTION),
+        COMMIT_TRANSACTION_CODE => Ok(COMMIT_TRANSACTION),
+        ABORT_TRANSACTION_CODE => Ok(ABORT_TRANSACTION),
+        AGGREGATE_MESSAGES_CODE => Ok(AGGREGATE_MESSAGES),
+        SEND_MESSAGES_BATCH_CODE => Ok(SEND_MESSAGES_BATCH),
+        REPLAY_MESSAGES_CODE => Ok(REPLAY_MESSAGES),
+        GET_REPLAY_JOBS_CODE => Ok(GET_REPLAY_JOBS),
+        CANCEL_REPLAY_JOB_CODE => Ok(CANCEL_REPLAY_JOB),
+        CREATE_PUSH_SUBSCRIPTION_CODE => Ok(CREATE_PUSH_SUBSCRIPTION),
+        GET_PUSH_SUBSCRIPTIONS_CODE => Ok(GET_PUSH_SUBSCRIPTIONS),
+        DELETE_PUSH_SUBSCRIPTION_CODE => Ok(DELETE_PUSH_SUBSCRIPTION),
+        REGISTER_PRODUCER_CODE => Ok(REGISTER_PRODUCER),
+        INIT_PRODUCER_ID_CODE => Ok(INIT_PRODUCER_ID),
+        NACK_MESSAGES_CODE => Ok(NACK_MESSAGES),
+        ACK_MESSAGES_CODE => Ok(ACK_MESSAGES),
         STORE_CONSUMER_OFFSET_CODE => Ok(STORE_CONSUMER_OFFSET),
         GET_CONSUMER_OFFSET_CODE => Ok(GET_CONSUMER_OFFSET),
+        STORE_CONSUMER_OFFSETS_CODE => Ok(STORE_CONSUMER_OFFSETS),
+        GET_OFFSETS_FOR_TIMESTAMPS_CODE => Ok(GET_OFFSETS_FOR_TIMESTAMPS),
         GET_STREAM_CODE => Ok(GET_STREAM),
         GET_STREAMS_CODE => Ok(GET_STREAMS),
         CREATE_STREAM_CODE => Ok(CREATE_STREAM),
         DELETE_STREAM_CODE => Ok(DELETE_STREAM),
         UPDATE_STREAM_CODE => Ok(UPDATE_STREAM),
         PURGE_STREAM_CODE => Ok(PURGE_STREAM),
+        UPDATE_STREAM_METADATA_CODE => Ok(UPDATE_STREAM_METADATA),
+        UPDATE_STREAM_QUOTA_CODE => Ok(UPDATE_STREAM_QUOTA),
         GET_TOPIC_CODE => Ok(GET_TOPIC),
         GET_TOPICS_CODE => Ok(GET_TOPICS),
         CREATE_TOPIC_CODE => Ok(CREATE_TOPIC),
         DELETE_TOPIC_CODE => Ok(DELETE_TOPIC),
         UPDATE_TOPIC_CODE => Ok(UPDATE_TOPIC),
         PURGE_TOPIC_CODE => Ok(PURGE_TOPIC),
+        UPDATE_TOPIC_METADATA_CODE => Ok(UPDATE_TOPIC_METADATA),
+        UPDATE_TOPIC_PRODUCERS_CODE => Ok(UPDATE_TOPIC_PRODUCERS),
+        MARK_TOPIC_FOR_DELETION_CODE => Ok(MARK_TOPIC_FOR_DELETION),
+        UPDATE_TOPIC_CONFIG_CODE => Ok(UPDATE_TOPIC_CONFIG),
+        UPDATE_TOPIC_EXPIRY_WATCHER_CODE => Ok(UPDATE_TOPIC_EXPIRY_WATCHER),
         CREATE_PARTITIONS_CODE => Ok(CREATE_PARTITIONS),
         DELETE_PARTITIONS_CODE => Ok(DELETE_PARTITIONS),
+        RESTORE_ARCHIVED_SEGMENTS_CODE => Ok(RESTORE_ARCHIVED_SEGMENTS),
+        FLUSH_PARTITION_CODE => Ok(FLUSH_PARTITION),
+        READ_SEGMENT_RANGE_CODE => Ok(READ_SEGMENT_RANGE),
         GET_CONSUMER_GROUP_CODE => Ok(GET_CONSUMER_GROUP),
         GET_CONSUMER_GROUPS_CODE => Ok(GET_CONSUMER_GROUPS),
         CREATE_CONSUMER_GROUP_CODE => Ok(CREATE_CONSUMER_GROUP),
         DELETE_CONSUMER_GROUP_CODE => Ok(DELETE_CONSUMER_GROUP),
         JOIN_CONSUMER_GROUP_CODE => Ok(JOIN_CONSUMER_GROUP),
         LEAVE_CONSUMER_GROUP_CODE => Ok(LEAVE_CONSUMER_GROUP),
+        UPDATE_CONSUMER_GROUP_DEAD_LETTER_CODE => Ok(UPDATE_CONSUMER_GROUP_DEAD_LETTER),
+        UPDATE_CONSUMER_GROUP_VISIBILITY_TIMEOUT_CODE => {
+            Ok(UPDATE_CONSUMER_GROUP_VISIBILITY_TIMEOUT)
+        }
+        GET_PARTITION_STATE_CODE => Ok(GET_PARTITION_STATE),
+        STORE_PARTITION_STATE_CODE => Ok(STORE_PARTITION_STATE),
+        DELETE_PARTITION_STATE_CODE => Ok(DELETE_PARTITION_STATE),
+        GET_NAMESPACE_CODE => Ok(GET_NAMESPACE),
+        GET_NAMESPACES_CODE => Ok(GET_NAMESPACES),
+        CREATE_NAMESPACE_CODE => Ok(CREATE_NAMESPACE),
+        DELETE_NAMESPACE_CODE => Ok(DELETE_NAMESPACE),
+        UPDATE_NAMESPACE_CODE => Ok(UPDATE_NAMESPACE),
         GET_SNAPSHOT_FILE_CODE => Ok(GET_SNAPSHOT_FILE),
         _ => Err(IggyError::InvalidCommand),
     }
@@ -21,14 +21,21 @@ use serde::{
     Deserialize, Serialize, Serializer,
 };
 use std::{
+    collections::HashMap,
     fmt::{Display, Formatter},
+    io::{Read, Write},
     str::FromStr,
 };
 
 use crate::error::IggyError;
+use crate::models::header::{HeaderKey, HeaderValue};
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
 
-// for now only those, in the future will add snappy, lz4, zstd (same as in confluent kafka) in addition to that
-// we should consider brotli as well.
+/// The header marking the message payload as already compressed with the given algorithm (stored as its code).
+/// Producers can set it to let the server skip the compression configured for the topic.
+pub const COMPRESSION_HEADER: &str = "iggy-compression";
+
+// in the future we might add snappy (same as in confluent kafka) and consider brotli as well.
 /// Supported compression algorithms
 #[derive(Debug, Default, PartialEq, Clone, Copy)]
 pub enum CompressionAlgorithm {
@@ -37,6 +44,10 @@ pub enum CompressionAlgorithm {
     None,
     // Gzip compression algorithm
     Gzip,
+    // LZ4 compression algorithm, the fastest one with the lowest compression ratio
+    Lz4,
+    // Zstandard compression algorithm, balancing the speed and the compression ratio
+    Zstd,
 }
 
 impl FromStr for CompressionAlgorithm {
@@ -45,6 +56,8 @@ impl FromStr for CompressionAlgorithm {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().as_str() {
             "gzip" => Ok(CompressionAlgorithm::Gzip),
+            "lz4" => Ok(CompressionAlgorithm::Lz4),
+            "zstd" => Ok(CompressionAlgorithm::Zstd),
             "none" => Ok(CompressionAlgorithm::None),
             _ => Err(format!("Unknown compression type: {}", s)),
         }
@@ -56,6 +69,8 @@ impl CompressionAlgorithm {
         match self {
             CompressionAlgorithm::None => 1,
             CompressionAlgorithm::Gzip => 2,
+            CompressionAlgorithm::Lz4 => 3,
+            CompressionAlgorithm::Zstd => 4,
         }
     }
 
@@ -63,9 +78,77 @@ impl CompressionAlgorithm {
         match code {
             1 => Ok(CompressionAlgorithm::None),
             2 => Ok(CompressionAlgorithm::Gzip),
+            3 => Ok(CompressionAlgorithm::Lz4),
+            4 => Ok(CompressionAlgorithm::Zstd),
             _ => Err(IggyError::InvalidCommand),
         }
     }
+
+    /// Compresses the data using the algorithm, `None` returns the data as is.
+    pub fn compress(&self, data: &[u8]) -> Result<Vec<u8>, IggyError> {
+        match self {
+            CompressionAlgorithm::None => Ok(data.to_vec()),
+            CompressionAlgorithm::Gzip => {
+                let mut encoder =
+                    GzEncoder::new(Vec::with_capacity(data.len()), Compression::default());
+                encoder
+                    .write_all(data)
+                    .map_err(|_| IggyError::CannotCompressData)?;
+                encoder.finish().map_err(|_| IggyError::CannotCompressData)
+            }
+            CompressionAlgorithm::Lz4 => Ok(lz4_flex::compress_prepend_size(data)),
+            CompressionAlgorithm::Zstd => {
+                zstd::bulk::compress(data, 0).map_err(|_| IggyError::CannotCompressData)
+            }
+        }
+    }
+
+    /// Decompresses the data using the algorithm, `None` returns the data as is.
+    pub fn decompress(&self, data: &[u8]) -> Result<Vec<u8>, IggyError> {
+        match self {
+            CompressionAlgorithm::None => Ok(data.to_vec()),
+            CompressionAlgorithm::Gzip => {
+                let mut decompressed = Vec::with_capacity(data.len() * 2);
+                GzDecoder::new(data)
+                    .read_to_end(&mut decompressed)
+                    .map_err(|_| IggyError::CannotDecompressData)?;
+                Ok(decompressed)
+            }
+            CompressionAlgorithm::Lz4 => lz4_flex::decompress_size_prepended(data)
+                .map_err(|_| IggyError::CannotDecompressData),
+            CompressionAlgorithm::Zstd => {
+                let mut decompressed = Vec::with_capacity(data.len() * 2);
+                zstd::stream::copy_decode(data, &mut decompressed)
+                    .map_err(|_| IggyError::CannotDecompressData)?;
+                Ok(decompressed)
+            }
+        }
+    }
+
+    /// Returns the algorithm the payload has been compressed with, based on the compression header.
+    pub fn from_headers(
+        headers: &Option<HashMap<HeaderKey, HeaderValue>>,
+    ) -> Result<Option<Self>, IggyError> {
+        let Some(headers) = headers else {
+            return Ok(None);
+        };
+        let Some(value) = headers.get(&HeaderKey::new(COMPRESSION_HEADER)?) else {
+            return Ok(None);
+        };
+        Self::from_code(value.as_uint8()?).map(Some)
+    }
+
+    /// Marks the payload as compressed with the algorithm by setting the compression header.
+    pub fn set_header(
+        &self,
+        headers: &mut Option<HashMap<HeaderKey, HeaderValue>>,
+    ) -> Result<(), IggyError> {
+        headers.get_or_insert_with(HashMap::new).insert(
+            HeaderKey::new(COMPRESSION_HEADER)?,
+            HeaderValue::from_uint8(self.as_code())?,
+        );
+        Ok(())
+    }
 }
 
 impl Display for CompressionAlgorithm {
@@ -73,6 +156,8 @@ impl Display for CompressionAlgorithm {
         match self {
             CompressionAlgorithm::None => write!(f, "none"),
             CompressionAlgorithm::Gzip => write!(f, "gzip"),
+            CompressionAlgorithm::Lz4 => write!(f, "lz4"),
+            CompressionAlgorithm::Zstd => write!(f, "zstd"),
         }
     }
 }
@@ -85,6 +170,8 @@ impl Serialize for CompressionAlgorithm {
         match self {
             CompressionAlgorithm::None => serializer.serialize_str("none"),
             CompressionAlgorithm::Gzip => serializer.serialize_str("gzip"),
+            CompressionAlgorithm::Lz4 => serializer.serialize_str("lz4"),
+            CompressionAlgorithm::Zstd => serializer.serialize_str("zstd"),
         }
     }
 }
@@ -94,6 +181,8 @@ impl From<CompressionAlgorithm> for String {
         match value {
             CompressionAlgorithm::None => "none".to_string(),
             CompressionAlgorithm::Gzip => "gzip".to_string(),
+            CompressionAlgorithm::Lz4 => "lz4".to_string(),
+            CompressionAlgorithm::Zstd => "zstd".to_string(),
         }
     }
 }
@@ -127,6 +216,45 @@ impl<'de> Deserialize<'de> for CompressionAlgorithm {
 mod tests {
     use super::*;
 
+    #[test]
+    fn gzip_compressed_data_should_be_decompressed() {
+        let data = "test".repeat(100).into_bytes();
+        let compressed = CompressionAlgorithm::Gzip.compress(&data).unwrap();
+        assert!(compressed.len() < data.len());
+        let decompressed = CompressionAlgorithm::Gzip.decompress(&compressed).unwrap();
+        assert_eq!(decompressed, data);
+    }
+
+    #[test]
+    fn lz4_and_zstd_compressed_data_should_be_decompressed() {
+        let data = "test".repeat(100).into_bytes();
+        for algorithm in [CompressionAlgorithm::Lz4, CompressionAlgorithm::Zstd] {
+            let compressed = algorithm.compress(&data).unwrap();
+            assert!(compressed.len() < data.len());
+            let decompressed = algorithm.decompress(&compressed).unwrap();
+            assert_eq!(decompressed, data);
+            assert_eq!(
+                CompressionAlgorithm::from_code(algorithm.as_code()).unwrap(),
+                algorithm
+            );
+            assert_eq!(
+                CompressionAlgorithm::from_str(&algorithm.to_string()).unwrap(),
+                algorithm
+            );
+        }
+    }
+
+    #[test]
+    fn compression_header_should_be_set_and_read() {
+        let mut headers = None;
+        assert_eq!(CompressionAlgorithm::from_headers(&headers).unwrap(), None);
+        CompressionAlgorithm::Gzip.set_header(&mut headers).unwrap();
+        assert_eq!(
+            CompressionAlgorithm::from_headers(&headers).unwrap(),
+            Some(CompressionAlgorithm::Gzip)
+        );
+    }
+
     #[test]
     fn test_from() {
         let none_alg = CompressionAlgorithm::from_str("none");
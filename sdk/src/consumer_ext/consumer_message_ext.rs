@@ -60,6 +60,12 @@ impl IggyConsumerMessageExt for IggyConsumer {
                 | AutoCommit::IntervalOrAfter(_, AutoCommitAfter::ConsumingEachMessage)
         );
 
+        let store_offset_after_processing = matches!(
+            auto_commit,
+            AutoCommit::After(AutoCommitAfter::ProcessingEachMessage)
+                | AutoCommit::IntervalOrAfter(_, AutoCommitAfter::ProcessingEachMessage)
+        );
+
         let store_offset_after_all_messages = matches!(
             auto_commit,
             AutoCommit::After(AutoCommitAfter::ConsumingAllMessages)
@@ -92,12 +98,17 @@ impl IggyConsumerMessageExt for IggyConsumer {
                             if let Err(err) = message_consumer.consume(received_message).await {
                                 error!("Error while handling message at offset: {message_offset}/{current_offset}, partition: {partition_id} for consumer: {name} on topic: {topic} and stream: {stream} due to error: {err}",
                                     name = self.name(), topic = self.topic(), stream = self.stream());
+                                if store_offset_after_processing {
+                                    error!("Stopping message consumption without storing offset: {message_offset}, partition: {partition_id} for consumer: {name} on topic: {topic} and stream: {stream}, the message will be delivered again.",
+                                        name = self.name(), topic = self.topic(), stream = self.stream());
+                                    return Err(err);
+                                }
                             } else {
                                 trace!("Message at offset: {message_offset}/{current_offset}, partition: {partition_id} has been handled by consumer: {name} on topic: {topic} and stream: {stream}",
                                     name = self.name(), topic = self.topic(), stream = self.stream());
                             }
 
-                            if store_offset_after_each_message {
+                            if store_offset_after_each_message || store_offset_after_processing {
                                 trace!("Storing offset: {message_offset}/{current_offset}, partition: {partition_id}, after each message for consumer: {name} on topic: {topic} and stream: {stream}",
                                     name = self.name(), topic = self.topic(), stream = self.stream());
                                 self.send_store_offset(partition_id, message_offset);
@@ -141,3 +152,129 @@ impl IggyConsumerMessageExt for IggyConsumer {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::{Client, MessageClient, StreamClient, TopicClient};
+    use crate::clients::consumer::{IggyConsumerBuilder, ReceivedMessage};
+    use crate::compression::compression_algorithm::CompressionAlgorithm;
+    use crate::consumer::Consumer;
+    use crate::identifier::Identifier;
+    use crate::locking::{IggySharedMut, IggySharedMutFn};
+    use crate::messages::send_messages::{Message, Partitioning};
+    use crate::mock::client::MockClient;
+    use crate::utils::expiry::IggyExpiry;
+    use crate::utils::topic_size::MaxTopicSize;
+    use std::str::FromStr;
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    struct FailingConsumer {
+        failing_offset: u64,
+        processed_offsets: Mutex<Vec<u64>>,
+    }
+
+    impl MessageConsumer for FailingConsumer {
+        async fn consume(&self, message: ReceivedMessage) -> Result<(), IggyError> {
+            self.processed_offsets
+                .lock()
+                .unwrap()
+                .push(message.message.offset);
+            if message.message.offset == self.failing_offset {
+                return Err(IggyError::InvalidCommand);
+            }
+            Ok(())
+        }
+    }
+
+    async fn init_client() -> MockClient {
+        let client = MockClient::new();
+        client.create_stream("stream", None).await.unwrap();
+        client
+            .create_topic(
+                &Identifier::named("stream").unwrap(),
+                "topic",
+                1,
+                CompressionAlgorithm::None,
+                None,
+                None,
+                IggyExpiry::NeverExpire,
+                MaxTopicSize::ServerDefault,
+            )
+            .await
+            .unwrap();
+        let mut messages = ["a", "b", "c"]
+            .iter()
+            .map(|payload| Message::from_str(payload).unwrap())
+            .collect::<Vec<_>>();
+        client
+            .send_messages(
+                &Identifier::numeric(1).unwrap(),
+                &Identifier::numeric(1).unwrap(),
+                &Partitioning::partition_id(1),
+                &mut messages,
+            )
+            .await
+            .unwrap();
+        client
+    }
+
+    #[tokio::test]
+    async fn at_least_once_should_stop_without_storing_offset_of_failed_message() {
+        let client: Box<dyn Client> = Box::new(init_client().await);
+        let client = IggySharedMut::new(client);
+        let mut consumer = IggyConsumerBuilder::new(
+            client.clone(),
+            "consumer".to_owned(),
+            Consumer::default(),
+            Identifier::numeric(1).unwrap(),
+            Identifier::numeric(1).unwrap(),
+            Some(1),
+            None,
+            None,
+        )
+        .at_least_once()
+        .build();
+        consumer.init().await.unwrap();
+        let message_consumer: &'static FailingConsumer = Box::leak(Box::new(FailingConsumer {
+            failing_offset: 1,
+            processed_offsets: Mutex::new(Vec::new()),
+        }));
+        let (_shutdown_tx, shutdown_rx) = oneshot::channel();
+
+        let result = consumer
+            .consume_messages(message_consumer, shutdown_rx)
+            .await;
+
+        assert_eq!(
+            result.unwrap_err().as_code(),
+            IggyError::InvalidCommand.as_code()
+        );
+        assert_eq!(
+            *message_consumer.processed_offsets.lock().unwrap(),
+            vec![0, 1]
+        );
+        // The offsets are stored in the background, the one of the failed message is never sent to be stored.
+        let mut stored_offset = None;
+        for _ in 0..100 {
+            stored_offset = client
+                .read()
+                .await
+                .get_consumer_offset(
+                    &Consumer::default(),
+                    &Identifier::numeric(1).unwrap(),
+                    &Identifier::numeric(1).unwrap(),
+                    Some(1),
+                )
+                .await
+                .unwrap()
+                .map(|offset| offset.stored_offset);
+            if stored_offset.is_some() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert_eq!(stored_offset, Some(0));
+    }
+}
@@ -26,7 +26,8 @@ pub trait IggyConsumerMessageExt {
     /// This function starts an event loop that consumes messages from the stream and
     /// applies the provided consumer. The loop will exit when the shutdown receiver is triggered.
     ///
-    /// This can be combined with `AutoCommitAfter` to automatically commit offsets after consuming.
+    /// This can be combined with `AutoCommitAfter` to automatically commit offsets after consuming,
+    /// or with `DeliveryMode::AtLeastOnce` to commit them only after the messages are successfully processed.
     ///
     /// # Arguments
     ///
@@ -18,6 +18,7 @@
 
 use crate::bytes_serializable::BytesSerializable;
 use crate::command::{Command, CREATE_CONSUMER_GROUP_CODE};
+use crate::consumer_groups::offset_recovery_policy::OffsetRecoveryPolicy;
 use crate::consumer_groups::MAX_NAME_LENGTH;
 use crate::error::IggyError;
 use crate::identifier::Identifier;
@@ -34,6 +35,7 @@ use std::str::from_utf8;
 /// - `topic_id` - unique topic ID (numeric or name).
 /// - `group_id` - unique consumer group ID.
 /// - `name` - unique consumer group name, max length is 255 characters.
+/// - `offset_recovery` - policy used to recover the stored offsets which are out of the range of the available messages.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct CreateConsumerGroup {
     /// Unique stream ID (numeric or name).
@@ -46,6 +48,9 @@ pub struct CreateConsumerGroup {
     pub group_id: Option<u32>,
     /// Unique consumer group name, max length is 255 characters.
     pub name: String,
+    /// Policy used to recover the stored offsets which are out of the range of the available messages.
+    #[serde(default)]
+    pub offset_recovery: OffsetRecoveryPolicy,
 }
 
 impl Command for CreateConsumerGroup {
@@ -61,6 +66,7 @@ impl Default for CreateConsumerGroup {
             topic_id: Identifier::default(),
             group_id: None,
             name: "consumer_group_1".to_string(),
+            offset_recovery: OffsetRecoveryPolicy::ServerDefault,
         }
     }
 }
@@ -86,7 +92,7 @@ impl BytesSerializable for CreateConsumerGroup {
         let stream_id_bytes = self.stream_id.to_bytes();
         let topic_id_bytes = self.topic_id.to_bytes();
         let mut bytes = BytesMut::with_capacity(
-            4 + stream_id_bytes.len() + topic_id_bytes.len() + self.name.len(),
+            4 + stream_id_bytes.len() + topic_id_bytes.len() + 1 + self.name.len() + 1,
         );
         bytes.put_slice(&stream_id_bytes);
         bytes.put_slice(&topic_id_bytes);
@@ -94,6 +100,7 @@ impl BytesSerializable for CreateConsumerGroup {
         #[allow(clippy::cast_possible_truncation)]
         bytes.put_u8(self.name.len() as u8);
         bytes.put_slice(self.name.as_bytes());
+        bytes.put_u8(self.offset_recovery.as_code());
         bytes.freeze()
     }
 
@@ -117,11 +124,17 @@ impl BytesSerializable for CreateConsumerGroup {
         let name = from_utf8(&bytes[position + 5..position + 5 + name_length as usize])
             .map_err(|_| IggyError::InvalidUtf8)?
             .to_string();
+        // The policy is optional for the clients which are not aware of it.
+        let offset_recovery = match bytes.get(position + 5 + name_length as usize) {
+            Some(code) => OffsetRecoveryPolicy::from_code(*code)?,
+            None => OffsetRecoveryPolicy::ServerDefault,
+        };
         let command = CreateConsumerGroup {
             stream_id,
             topic_id,
             group_id,
             name,
+            offset_recovery,
         };
         Ok(command)
     }
@@ -131,11 +144,12 @@ impl Display for CreateConsumerGroup {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{}|{}|{}|{}",
+            "{}|{}|{}|{}|{}",
             self.stream_id,
             self.topic_id,
             self.group_id.unwrap_or(0),
-            self.name
+            self.name,
+            self.offset_recovery
         )
     }
 }
@@ -151,6 +165,7 @@ mod tests {
             topic_id: Identifier::numeric(2).unwrap(),
             group_id: Some(3),
             name: "test".to_string(),
+            offset_recovery: OffsetRecoveryPolicy::Earliest,
         };
 
         let bytes = command.to_bytes();
@@ -163,11 +178,14 @@ mod tests {
 
         let name_length = bytes[position + 4];
         let name = from_utf8(&bytes[position + 5..position + 5 + name_length as usize]).unwrap();
+        let offset_recovery =
+            OffsetRecoveryPolicy::from_code(bytes[position + 5 + name_length as usize]).unwrap();
         assert!(!bytes.is_empty());
         assert_eq!(stream_id, command.stream_id);
         assert_eq!(topic_id, command.topic_id);
         assert_eq!(group_id, command.group_id.unwrap());
         assert_eq!(name, command.name);
+        assert_eq!(offset_recovery, command.offset_recovery);
     }
 
     #[test]
@@ -194,5 +212,6 @@ mod tests {
         assert_eq!(command.topic_id, topic_id);
         assert_eq!(command.group_id.unwrap(), group_id);
         assert_eq!(command.name, name);
+        assert_eq!(command.offset_recovery, OffsetRecoveryPolicy::ServerDefault);
     }
 }
@@ -0,0 +1,169 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, DELETE_PARTITION_STATE_CODE};
+use crate::consumer_groups::validate_partition_state_key;
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::utils::sizeable::Sizeable;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `DeletePartitionState` command removes the value stored under the key in the state store of the partition,
+/// which is accessible only to the member of the consumer group the partition is currently assigned to.
+/// It has additional payload:
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `group_id` - unique consumer group ID (numeric or name).
+/// - `partition_id` - partition ID assigned to the member.
+/// - `key` - key of the value to remove, up to 255 bytes.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct DeletePartitionState {
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    /// Unique consumer group ID (numeric or name).
+    #[serde(skip)]
+    pub group_id: Identifier,
+    /// Partition ID assigned to the member.
+    pub partition_id: u32,
+    /// Key of the value, up to 255 bytes.
+    pub key: String,
+}
+
+impl Command for DeletePartitionState {
+    fn code(&self) -> u32 {
+        DELETE_PARTITION_STATE_CODE
+    }
+}
+
+impl Validatable<IggyError> for DeletePartitionState {
+    fn validate(&self) -> Result<(), IggyError> {
+        validate_partition_state_key(&self.key)
+    }
+}
+
+impl BytesSerializable for DeletePartitionState {
+    fn to_bytes(&self) -> Bytes {
+        let stream_id_bytes = self.stream_id.to_bytes();
+        let topic_id_bytes = self.topic_id.to_bytes();
+        let group_id_bytes = self.group_id.to_bytes();
+        let mut bytes = BytesMut::with_capacity(
+            stream_id_bytes.len()
+                + topic_id_bytes.len()
+                + group_id_bytes.len()
+                + 5
+                + self.key.len(),
+        );
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        bytes.put_slice(&group_id_bytes);
+        bytes.put_u32_le(self.partition_id);
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(self.key.len() as u8);
+        bytes.put_slice(self.key.as_bytes());
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<DeletePartitionState, IggyError> {
+        if bytes.len() < 15 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone())?;
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        let group_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += group_id.get_size_bytes().as_bytes_usize();
+        let partition_id = u32::from_le_bytes(
+            bytes
+                .get(position..position + 4)
+                .ok_or(IggyError::InvalidCommand)?
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        position += 4;
+        let key_length = *bytes.get(position).ok_or(IggyError::InvalidCommand)? as usize;
+        position += 1;
+        if bytes.len() != position + key_length {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let key = String::from_utf8(bytes[position..].to_vec())
+            .map_err(|_| IggyError::InvalidPartitionStateKey)?;
+        let command = DeletePartitionState {
+            stream_id,
+            topic_id,
+            group_id,
+            partition_id,
+            key,
+        };
+        Ok(command)
+    }
+}
+
+impl Display for DeletePartitionState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}|{}",
+            self.stream_id, self.topic_id, self.group_id, self.partition_id, self.key
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_and_deserialized_from_bytes() {
+        let command = DeletePartitionState {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            group_id: Identifier::named("counters").unwrap(),
+            partition_id: 3,
+            key: "window:2024-01-01".to_string(),
+        };
+
+        let bytes = command.to_bytes();
+        let deserialized = DeletePartitionState::from_bytes(bytes).unwrap();
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_not_be_valid_given_empty_key() {
+        let command = DeletePartitionState {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            group_id: Identifier::numeric(3).unwrap(),
+            partition_id: 1,
+            key: String::new(),
+        };
+
+        assert!(command.validate().is_err());
+    }
+}
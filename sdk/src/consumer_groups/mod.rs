@@ -16,11 +16,31 @@
  * under the License.
  */
 
+use crate::error::IggyError;
+
 pub mod create_consumer_group;
 pub mod delete_consumer_group;
+pub mod delete_partition_state;
 pub mod get_consumer_group;
 pub mod get_consumer_groups;
+pub mod get_partition_state;
 pub mod join_consumer_group;
 pub mod leave_consumer_group;
+pub mod offset_recovery_policy;
+pub mod store_partition_state;
+pub mod update_consumer_group_dead_letter;
+pub mod update_consumer_group_visibility_timeout;
 
 const MAX_NAME_LENGTH: usize = 255;
+/// The maximum length (in bytes) of the key in the partition state store.
+pub const MAX_PARTITION_STATE_KEY_LENGTH: usize = 255;
+/// The maximum size (in bytes) of the value in the partition state store.
+pub const MAX_PARTITION_STATE_VALUE_SIZE: u32 = 1_000_000;
+
+pub(crate) fn validate_partition_state_key(key: &str) -> Result<(), IggyError> {
+    if key.is_empty() || key.len() > MAX_PARTITION_STATE_KEY_LENGTH {
+        return Err(IggyError::InvalidPartitionStateKey);
+    }
+
+    Ok(())
+}
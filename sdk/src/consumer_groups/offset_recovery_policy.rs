@@ -0,0 +1,121 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::error::IggyError;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// The policy used by the server to recover the stored consumer offsets which are out of the range
+/// of the available messages, e.g. after the partition has been purged or its old segments removed:
+/// - `ServerDefault`: use the policy configured on the server (only valid when creating the consumer group).
+/// - `Disabled`: the offsets are left as they are.
+/// - `Earliest`: the offsets are moved before the earliest available message.
+/// - `Latest`: the offsets are moved to the latest message.
+/// - `Archived`: the offsets are moved before the nearest message which is still available locally or in the archive,
+///   or to the latest message if they are ahead of the partition.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OffsetRecoveryPolicy {
+    #[default]
+    ServerDefault,
+    Disabled,
+    Earliest,
+    Latest,
+    Archived,
+}
+
+impl OffsetRecoveryPolicy {
+    pub fn as_code(&self) -> u8 {
+        match self {
+            OffsetRecoveryPolicy::ServerDefault => 0,
+            OffsetRecoveryPolicy::Disabled => 1,
+            OffsetRecoveryPolicy::Earliest => 2,
+            OffsetRecoveryPolicy::Latest => 3,
+            OffsetRecoveryPolicy::Archived => 4,
+        }
+    }
+
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            0 => Ok(OffsetRecoveryPolicy::ServerDefault),
+            1 => Ok(OffsetRecoveryPolicy::Disabled),
+            2 => Ok(OffsetRecoveryPolicy::Earliest),
+            3 => Ok(OffsetRecoveryPolicy::Latest),
+            4 => Ok(OffsetRecoveryPolicy::Archived),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+impl FromStr for OffsetRecoveryPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "server_default" | "default" => Ok(OffsetRecoveryPolicy::ServerDefault),
+            "disabled" | "none" => Ok(OffsetRecoveryPolicy::Disabled),
+            "earliest" => Ok(OffsetRecoveryPolicy::Earliest),
+            "latest" => Ok(OffsetRecoveryPolicy::Latest),
+            "archived" => Ok(OffsetRecoveryPolicy::Archived),
+            _ => Err(format!("Unknown offset recovery policy: {s}")),
+        }
+    }
+}
+
+impl Display for OffsetRecoveryPolicy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OffsetRecoveryPolicy::ServerDefault => write!(f, "server_default"),
+            OffsetRecoveryPolicy::Disabled => write!(f, "disabled"),
+            OffsetRecoveryPolicy::Earliest => write!(f, "earliest"),
+            OffsetRecoveryPolicy::Latest => write!(f, "latest"),
+            OffsetRecoveryPolicy::Archived => write!(f, "archived"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn policy_should_be_mapped_from_code() {
+        for policy in [
+            OffsetRecoveryPolicy::ServerDefault,
+            OffsetRecoveryPolicy::Disabled,
+            OffsetRecoveryPolicy::Earliest,
+            OffsetRecoveryPolicy::Latest,
+            OffsetRecoveryPolicy::Archived,
+        ] {
+            assert_eq!(
+                OffsetRecoveryPolicy::from_code(policy.as_code()).unwrap(),
+                policy
+            );
+            assert_eq!(
+                OffsetRecoveryPolicy::from_str(&policy.to_string()).unwrap(),
+                policy
+            );
+        }
+    }
+
+    #[test]
+    fn unknown_code_should_fail() {
+        assert!(OffsetRecoveryPolicy::from_code(5).is_err());
+    }
+}
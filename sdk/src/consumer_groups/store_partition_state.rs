@@ -0,0 +1,225 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, STORE_PARTITION_STATE_CODE};
+use crate::consumer_groups::{validate_partition_state_key, MAX_PARTITION_STATE_VALUE_SIZE};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::utils::sizeable::Sizeable;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use serde_with::base64::Base64;
+use serde_with::serde_as;
+use std::fmt::Display;
+
+/// `StorePartitionState` command stores the value under the key in the state store of the partition,
+/// which is accessible only to the member of the consumer group the partition is currently assigned to.
+/// The store is kept along with the partition, so it follows the partition when the consumer group is rebalanced.
+/// It has additional payload:
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `group_id` - unique consumer group ID (numeric or name).
+/// - `partition_id` - partition ID assigned to the member.
+/// - `key` - key of the value, up to 255 bytes.
+/// - `value` - value to store, up to 1 MB, replacing the existing one.
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct StorePartitionState {
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    /// Unique consumer group ID (numeric or name).
+    #[serde(skip)]
+    pub group_id: Identifier,
+    /// Partition ID assigned to the member.
+    pub partition_id: u32,
+    /// Key of the value, up to 255 bytes.
+    pub key: String,
+    /// Value to store, up to 1 MB, replacing the existing one.
+    #[serde_as(as = "Base64")]
+    pub value: Bytes,
+}
+
+impl Command for StorePartitionState {
+    fn code(&self) -> u32 {
+        STORE_PARTITION_STATE_CODE
+    }
+}
+
+impl Validatable<IggyError> for StorePartitionState {
+    fn validate(&self) -> Result<(), IggyError> {
+        validate_partition_state_key(&self.key)?;
+        if self.value.is_empty() || self.value.len() > MAX_PARTITION_STATE_VALUE_SIZE as usize {
+            return Err(IggyError::InvalidPartitionStateValue(
+                MAX_PARTITION_STATE_VALUE_SIZE,
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for StorePartitionState {
+    fn to_bytes(&self) -> Bytes {
+        let stream_id_bytes = self.stream_id.to_bytes();
+        let topic_id_bytes = self.topic_id.to_bytes();
+        let group_id_bytes = self.group_id.to_bytes();
+        let mut bytes = BytesMut::with_capacity(
+            stream_id_bytes.len()
+                + topic_id_bytes.len()
+                + group_id_bytes.len()
+                + 9
+                + self.key.len()
+                + self.value.len(),
+        );
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        bytes.put_slice(&group_id_bytes);
+        bytes.put_u32_le(self.partition_id);
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(self.key.len() as u8);
+        bytes.put_slice(self.key.as_bytes());
+        bytes.put_u32_le(self.value.len() as u32);
+        bytes.put_slice(&self.value);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<StorePartitionState, IggyError> {
+        if bytes.len() < 19 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone())?;
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        let group_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += group_id.get_size_bytes().as_bytes_usize();
+        let partition_id = u32::from_le_bytes(
+            bytes
+                .get(position..position + 4)
+                .ok_or(IggyError::InvalidCommand)?
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        position += 4;
+        let key_length = *bytes.get(position).ok_or(IggyError::InvalidCommand)? as usize;
+        position += 1;
+        let key = String::from_utf8(
+            bytes
+                .get(position..position + key_length)
+                .ok_or(IggyError::InvalidCommand)?
+                .to_vec(),
+        )
+        .map_err(|_| IggyError::InvalidPartitionStateKey)?;
+        position += key_length;
+        let value_length = u32::from_le_bytes(
+            bytes
+                .get(position..position + 4)
+                .ok_or(IggyError::InvalidCommand)?
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        ) as usize;
+        position += 4;
+        if bytes.len() != position + value_length {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let command = StorePartitionState {
+            stream_id,
+            topic_id,
+            group_id,
+            partition_id,
+            key,
+            value: bytes.slice(position..),
+        };
+        Ok(command)
+    }
+}
+
+impl Display for StorePartitionState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}|{}|{}",
+            self.stream_id,
+            self.topic_id,
+            self.group_id,
+            self.partition_id,
+            self.key,
+            self.value.len()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_and_deserialized_from_bytes() {
+        let command = StorePartitionState {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::named("orders").unwrap(),
+            group_id: Identifier::numeric(3).unwrap(),
+            partition_id: 2,
+            key: "count".to_string(),
+            value: Bytes::copy_from_slice(&42u64.to_le_bytes()),
+        };
+
+        let bytes = command.to_bytes();
+        let deserialized = StorePartitionState::from_bytes(bytes).unwrap();
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_truncated_bytes() {
+        let command = StorePartitionState {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            group_id: Identifier::numeric(3).unwrap(),
+            partition_id: 2,
+            key: "count".to_string(),
+            value: Bytes::from_static(b"value"),
+        };
+
+        let bytes = command.to_bytes();
+        let command = StorePartitionState::from_bytes(bytes.slice(..bytes.len() - 1));
+        assert!(command.is_err());
+    }
+
+    #[test]
+    fn should_not_be_valid_given_empty_value() {
+        let command = StorePartitionState {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            group_id: Identifier::numeric(3).unwrap(),
+            partition_id: 2,
+            key: "count".to_string(),
+            value: Bytes::new(),
+        };
+
+        assert!(command.validate().is_err());
+    }
+}
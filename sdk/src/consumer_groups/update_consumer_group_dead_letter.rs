@@ -0,0 +1,205 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, UPDATE_CONSUMER_GROUP_DEAD_LETTER_CODE};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::utils::sizeable::Sizeable;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `UpdateConsumerGroupDeadLetter` command configures the dead-letter topic of an existing consumer group.
+/// It has additional payload:
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `group_id` - unique consumer group ID (numeric or name).
+/// - `dead_letter_stream_id` - unique ID (numeric or name) of the stream of the dead-letter topic, `None` to disable the dead-lettering.
+/// - `dead_letter_topic_id` - unique ID (numeric or name) of the dead-letter topic, `None` to disable the dead-lettering.
+/// - `max_delivery_count` - number of deliveries after which the message is dead-lettered, 0 to dead-letter only the negatively acknowledged messages.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct UpdateConsumerGroupDeadLetter {
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    /// Unique consumer group ID (numeric or name).
+    #[serde(skip)]
+    pub group_id: Identifier,
+    /// Unique ID (numeric or name) of the stream of the dead-letter topic, `None` to disable the dead-lettering.
+    #[serde(default)]
+    pub dead_letter_stream_id: Option<Identifier>,
+    /// Unique ID (numeric or name) of the dead-letter topic, `None` to disable the dead-lettering.
+    #[serde(default)]
+    pub dead_letter_topic_id: Option<Identifier>,
+    /// Number of deliveries after which the message is dead-lettered, 0 to dead-letter only the negatively acknowledged messages.
+    #[serde(default)]
+    pub max_delivery_count: u32,
+}
+
+impl Command for UpdateConsumerGroupDeadLetter {
+    fn code(&self) -> u32 {
+        UPDATE_CONSUMER_GROUP_DEAD_LETTER_CODE
+    }
+}
+
+impl Validatable<IggyError> for UpdateConsumerGroupDeadLetter {
+    fn validate(&self) -> Result<(), IggyError> {
+        match (&self.dead_letter_stream_id, &self.dead_letter_topic_id) {
+            (Some(_), Some(_)) => Ok(()),
+            (None, None) if self.max_delivery_count == 0 => Ok(()),
+            _ => Err(IggyError::InvalidDeadLetterConfiguration),
+        }
+    }
+}
+
+impl BytesSerializable for UpdateConsumerGroupDeadLetter {
+    fn to_bytes(&self) -> Bytes {
+        let stream_id_bytes = self.stream_id.to_bytes();
+        let topic_id_bytes = self.topic_id.to_bytes();
+        let group_id_bytes = self.group_id.to_bytes();
+        let mut bytes = BytesMut::with_capacity(
+            stream_id_bytes.len() + topic_id_bytes.len() + group_id_bytes.len() + 1 + 4,
+        );
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        bytes.put_slice(&group_id_bytes);
+        match (&self.dead_letter_stream_id, &self.dead_letter_topic_id) {
+            (Some(dead_letter_stream_id), Some(dead_letter_topic_id)) => {
+                bytes.put_u8(1);
+                bytes.put_slice(&dead_letter_stream_id.to_bytes());
+                bytes.put_slice(&dead_letter_topic_id.to_bytes());
+            }
+            _ => bytes.put_u8(0),
+        }
+        bytes.put_u32_le(self.max_delivery_count);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<UpdateConsumerGroupDeadLetter, IggyError> {
+        if bytes.len() < 14 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone())?;
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        let group_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += group_id.get_size_bytes().as_bytes_usize();
+        let enabled = *bytes.get(position).ok_or(IggyError::InvalidCommand)? == 1;
+        position += 1;
+        let (dead_letter_stream_id, dead_letter_topic_id) = if enabled {
+            let dead_letter_stream_id = Identifier::from_bytes(bytes.slice(position..))?;
+            position += dead_letter_stream_id.get_size_bytes().as_bytes_usize();
+            let dead_letter_topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+            position += dead_letter_topic_id.get_size_bytes().as_bytes_usize();
+            (Some(dead_letter_stream_id), Some(dead_letter_topic_id))
+        } else {
+            (None, None)
+        };
+        let max_delivery_count = u32::from_le_bytes(
+            bytes
+                .get(position..position + 4)
+                .ok_or(IggyError::InvalidCommand)?
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let command = UpdateConsumerGroupDeadLetter {
+            stream_id,
+            topic_id,
+            group_id,
+            dead_letter_stream_id,
+            dead_letter_topic_id,
+            max_delivery_count,
+        };
+        Ok(command)
+    }
+}
+
+impl Display for UpdateConsumerGroupDeadLetter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (&self.dead_letter_stream_id, &self.dead_letter_topic_id) {
+            (Some(dead_letter_stream_id), Some(dead_letter_topic_id)) => write!(
+                f,
+                "{}|{}|{}|{}|{}|{}",
+                self.stream_id,
+                self.topic_id,
+                self.group_id,
+                dead_letter_stream_id,
+                dead_letter_topic_id,
+                self.max_delivery_count
+            ),
+            _ => write!(
+                f,
+                "{}|{}|{}|disabled",
+                self.stream_id, self.topic_id, self.group_id
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_and_deserialized_from_bytes() {
+        let command = UpdateConsumerGroupDeadLetter {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            group_id: Identifier::named("payments").unwrap(),
+            dead_letter_stream_id: Some(Identifier::numeric(1).unwrap()),
+            dead_letter_topic_id: Some(Identifier::named("payments-dlq").unwrap()),
+            max_delivery_count: 5,
+        };
+
+        let bytes = command.to_bytes();
+        let deserialized = UpdateConsumerGroupDeadLetter::from_bytes(bytes).unwrap();
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_be_serialized_and_deserialized_from_bytes_when_disabled() {
+        let command = UpdateConsumerGroupDeadLetter {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            group_id: Identifier::numeric(3).unwrap(),
+            ..Default::default()
+        };
+
+        let bytes = command.to_bytes();
+        let deserialized = UpdateConsumerGroupDeadLetter::from_bytes(bytes).unwrap();
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_not_be_valid_given_max_delivery_count_without_dead_letter_topic() {
+        let command = UpdateConsumerGroupDeadLetter {
+            max_delivery_count: 5,
+            ..Default::default()
+        };
+
+        assert!(command.validate().is_err());
+    }
+}
@@ -0,0 +1,154 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, UPDATE_CONSUMER_GROUP_VISIBILITY_TIMEOUT_CODE};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::utils::duration::IggyDuration;
+use crate::utils::sizeable::Sizeable;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `UpdateConsumerGroupVisibilityTimeout` command configures the acknowledgement mode of an existing consumer group.
+/// When enabled, the polled messages are committed only once acknowledged by the members,
+/// and the messages which haven't been acknowledged within the visibility timeout are redelivered.
+/// It has additional payload:
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `group_id` - unique consumer group ID (numeric or name).
+/// - `visibility_timeout` - time after which the unacknowledged message is redelivered, 0 to disable the acknowledgement mode.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct UpdateConsumerGroupVisibilityTimeout {
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    /// Unique consumer group ID (numeric or name).
+    #[serde(skip)]
+    pub group_id: Identifier,
+    /// Time after which the unacknowledged message is redelivered, 0 to disable the acknowledgement mode.
+    #[serde(default)]
+    pub visibility_timeout: IggyDuration,
+}
+
+impl Command for UpdateConsumerGroupVisibilityTimeout {
+    fn code(&self) -> u32 {
+        UPDATE_CONSUMER_GROUP_VISIBILITY_TIMEOUT_CODE
+    }
+}
+
+impl Validatable<IggyError> for UpdateConsumerGroupVisibilityTimeout {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for UpdateConsumerGroupVisibilityTimeout {
+    fn to_bytes(&self) -> Bytes {
+        let stream_id_bytes = self.stream_id.to_bytes();
+        let topic_id_bytes = self.topic_id.to_bytes();
+        let group_id_bytes = self.group_id.to_bytes();
+        let mut bytes = BytesMut::with_capacity(
+            stream_id_bytes.len() + topic_id_bytes.len() + group_id_bytes.len() + 8,
+        );
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        bytes.put_slice(&group_id_bytes);
+        bytes.put_u64_le(self.visibility_timeout.as_micros());
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<UpdateConsumerGroupVisibilityTimeout, IggyError> {
+        if bytes.len() < 17 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone())?;
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        let group_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += group_id.get_size_bytes().as_bytes_usize();
+        if bytes.len() != position + 8 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let visibility_timeout = u64::from_le_bytes(
+            bytes[position..position + 8]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let command = UpdateConsumerGroupVisibilityTimeout {
+            stream_id,
+            topic_id,
+            group_id,
+            visibility_timeout: visibility_timeout.into(),
+        };
+        Ok(command)
+    }
+}
+
+impl Display for UpdateConsumerGroupVisibilityTimeout {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}",
+            self.stream_id, self.topic_id, self.group_id, self.visibility_timeout
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_and_deserialized_from_bytes() {
+        let command = UpdateConsumerGroupVisibilityTimeout {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            group_id: Identifier::named("payments").unwrap(),
+            visibility_timeout: IggyDuration::from(30_000_000),
+        };
+
+        let bytes = command.to_bytes();
+        let deserialized = UpdateConsumerGroupVisibilityTimeout::from_bytes(bytes).unwrap();
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_truncated_bytes() {
+        let command = UpdateConsumerGroupVisibilityTimeout {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            group_id: Identifier::numeric(3).unwrap(),
+            visibility_timeout: IggyDuration::ONE_SECOND,
+        };
+
+        let bytes = command.to_bytes();
+        let command =
+            UpdateConsumerGroupVisibilityTimeout::from_bytes(bytes.slice(..bytes.len() - 1));
+        assert!(command.is_err());
+    }
+}
@@ -0,0 +1,240 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, GET_OFFSETS_FOR_TIMESTAMPS_CODE};
+use crate::consumer_offsets::store_consumer_offsets::MAX_CONSUMER_OFFSETS;
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::utils::sizeable::Sizeable;
+use crate::utils::timestamp::IggyTimestamp;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fmt::Display;
+
+/// `GetOffsetsForTimestamps` command finds the offsets of the earliest messages with the timestamp
+/// greater than or equal to the given one, for many partitions of the topic in a single request.
+/// It has additional payload:
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `timestamps` - the list of partition IDs and timestamps to look up, each partition can occur only once.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct GetOffsetsForTimestamps {
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    /// The list of partition IDs and timestamps to look up.
+    pub timestamps: Vec<PartitionTimestamp>,
+}
+
+/// `PartitionTimestamp` represents the timestamp to look up in a single partition.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Copy)]
+pub struct PartitionTimestamp {
+    /// Partition ID in which the offset is looked up.
+    pub partition_id: u32,
+    /// Timestamp (in microseconds) of the earliest message to find.
+    pub timestamp: IggyTimestamp,
+}
+
+impl PartitionTimestamp {
+    /// Creates a new partition timestamp.
+    pub fn new(partition_id: u32, timestamp: IggyTimestamp) -> Self {
+        PartitionTimestamp {
+            partition_id,
+            timestamp,
+        }
+    }
+}
+
+impl Default for GetOffsetsForTimestamps {
+    fn default() -> Self {
+        GetOffsetsForTimestamps {
+            stream_id: Identifier::default(),
+            topic_id: Identifier::default(),
+            timestamps: vec![PartitionTimestamp::new(1, IggyTimestamp::zero())],
+        }
+    }
+}
+
+impl Command for GetOffsetsForTimestamps {
+    fn code(&self) -> u32 {
+        GET_OFFSETS_FOR_TIMESTAMPS_CODE
+    }
+}
+
+impl Validatable<IggyError> for GetOffsetsForTimestamps {
+    fn validate(&self) -> Result<(), IggyError> {
+        let count = self.timestamps.len() as u32;
+        if count == 0 || count > MAX_CONSUMER_OFFSETS {
+            return Err(IggyError::InvalidConsumerOffsetsCount(count));
+        }
+
+        let mut partitions = HashSet::with_capacity(self.timestamps.len());
+        for timestamp in &self.timestamps {
+            if timestamp.partition_id == 0 || !partitions.insert(timestamp.partition_id) {
+                return Err(IggyError::InvalidConsumerOffsetPartition(
+                    timestamp.partition_id,
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for GetOffsetsForTimestamps {
+    fn to_bytes(&self) -> Bytes {
+        let stream_id_bytes = self.stream_id.to_bytes();
+        let topic_id_bytes = self.topic_id.to_bytes();
+        let mut bytes = BytesMut::with_capacity(
+            4 + 12 * self.timestamps.len() + stream_id_bytes.len() + topic_id_bytes.len(),
+        );
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        bytes.put_u32_le(self.timestamps.len() as u32);
+        for timestamp in &self.timestamps {
+            bytes.put_u32_le(timestamp.partition_id);
+            bytes.put_u64_le(timestamp.timestamp.as_micros());
+        }
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<GetOffsetsForTimestamps, IggyError> {
+        if bytes.len() < 10 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone())?;
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        if bytes.len() < position + 4 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let count = u32::from_le_bytes(
+            bytes[position..position + 4]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        position += 4;
+        if count > MAX_CONSUMER_OFFSETS || bytes.len() != position + 12 * count as usize {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut timestamps = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            let partition_id = u32::from_le_bytes(
+                bytes[position..position + 4]
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            );
+            let timestamp = u64::from_le_bytes(
+                bytes[position + 4..position + 12]
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            );
+            timestamps.push(PartitionTimestamp::new(partition_id, timestamp.into()));
+            position += 12;
+        }
+
+        let command = GetOffsetsForTimestamps {
+            stream_id,
+            topic_id,
+            timestamps,
+        };
+        Ok(command)
+    }
+}
+
+impl Display for GetOffsetsForTimestamps {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let timestamps = self
+            .timestamps
+            .iter()
+            .map(|timestamp| {
+                format!(
+                    "{}:{}",
+                    timestamp.partition_id,
+                    timestamp.timestamp.as_micros()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        write!(f, "{}|{}|{timestamps}", self.stream_id, self.topic_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes_and_deserialized_back() {
+        let command = GetOffsetsForTimestamps {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::named("topic").unwrap(),
+            timestamps: vec![
+                PartitionTimestamp::new(1, 1_000_000.into()),
+                PartitionTimestamp::new(3, 3_000_000.into()),
+            ],
+        };
+
+        let bytes = command.to_bytes();
+        let deserialized = GetOffsetsForTimestamps::from_bytes(bytes).unwrap();
+
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_given_truncated_timestamps() {
+        let command = GetOffsetsForTimestamps {
+            timestamps: vec![
+                PartitionTimestamp::new(1, 1_000_000.into()),
+                PartitionTimestamp::new(2, 2_000_000.into()),
+            ],
+            ..GetOffsetsForTimestamps::default()
+        };
+
+        let bytes = command.to_bytes();
+        let result = GetOffsetsForTimestamps::from_bytes(bytes.slice(..bytes.len() - 1));
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_not_be_valid_given_duplicated_partition() {
+        let command = GetOffsetsForTimestamps {
+            timestamps: vec![
+                PartitionTimestamp::new(2, 1_000_000.into()),
+                PartitionTimestamp::new(2, 2_000_000.into()),
+            ],
+            ..GetOffsetsForTimestamps::default()
+        };
+
+        assert!(matches!(
+            command.validate(),
+            Err(IggyError::InvalidConsumerOffsetPartition(2))
+        ));
+    }
+}
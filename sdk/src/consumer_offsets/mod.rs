@@ -18,4 +18,6 @@
 
 pub mod delete_consumer_offset;
 pub mod get_consumer_offset;
+pub mod get_offsets_for_timestamps;
 pub mod store_consumer_offset;
+pub mod store_consumer_offsets;
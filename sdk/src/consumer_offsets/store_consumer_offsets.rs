@@ -0,0 +1,263 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, STORE_CONSUMER_OFFSETS_CODE};
+use crate::consumer::{Consumer, ConsumerKind};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::utils::sizeable::Sizeable;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fmt::Display;
+
+/// The maximum number of partition offsets that can be stored in a single request.
+pub const MAX_CONSUMER_OFFSETS: u32 = 10_000;
+
+/// `StoreConsumerOffsets` command stores the offsets of a consumer for many partitions of the topic in a single request.
+/// It has additional payload:
+/// - `consumer` - the consumer that is storing the offsets, either the regular consumer or the consumer group.
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `offsets` - the list of partition IDs and offsets to store, each partition can occur only once.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct StoreConsumerOffsets {
+    /// The consumer that is storing the offsets, either the regular consumer or the consumer group.
+    #[serde(flatten)]
+    pub consumer: Consumer,
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    /// The list of partition IDs and offsets to store.
+    pub offsets: Vec<PartitionOffset>,
+}
+
+/// `PartitionOffset` represents the offset to store for a single partition.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Copy)]
+pub struct PartitionOffset {
+    /// Partition ID on which the offset is stored.
+    pub partition_id: u32,
+    /// Offset to store.
+    pub offset: u64,
+}
+
+impl PartitionOffset {
+    /// Creates a new partition offset.
+    pub fn new(partition_id: u32, offset: u64) -> Self {
+        PartitionOffset {
+            partition_id,
+            offset,
+        }
+    }
+}
+
+impl Default for StoreConsumerOffsets {
+    fn default() -> Self {
+        StoreConsumerOffsets {
+            consumer: Consumer::default(),
+            stream_id: Identifier::default(),
+            topic_id: Identifier::default(),
+            offsets: vec![PartitionOffset::new(1, 0)],
+        }
+    }
+}
+
+impl Command for StoreConsumerOffsets {
+    fn code(&self) -> u32 {
+        STORE_CONSUMER_OFFSETS_CODE
+    }
+}
+
+impl Validatable<IggyError> for StoreConsumerOffsets {
+    fn validate(&self) -> Result<(), IggyError> {
+        let count = self.offsets.len() as u32;
+        if count == 0 || count > MAX_CONSUMER_OFFSETS {
+            return Err(IggyError::InvalidConsumerOffsetsCount(count));
+        }
+
+        let mut partitions = HashSet::with_capacity(self.offsets.len());
+        for offset in &self.offsets {
+            if offset.partition_id == 0 || !partitions.insert(offset.partition_id) {
+                return Err(IggyError::InvalidConsumerOffsetPartition(
+                    offset.partition_id,
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for StoreConsumerOffsets {
+    fn to_bytes(&self) -> Bytes {
+        let consumer_bytes = self.consumer.to_bytes();
+        let stream_id_bytes = self.stream_id.to_bytes();
+        let topic_id_bytes = self.topic_id.to_bytes();
+        let mut bytes = BytesMut::with_capacity(
+            4 + 12 * self.offsets.len()
+                + consumer_bytes.len()
+                + stream_id_bytes.len()
+                + topic_id_bytes.len(),
+        );
+        bytes.put_slice(&consumer_bytes);
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        bytes.put_u32_le(self.offsets.len() as u32);
+        for offset in &self.offsets {
+            bytes.put_u32_le(offset.partition_id);
+            bytes.put_u64_le(offset.offset);
+        }
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<StoreConsumerOffsets, IggyError> {
+        if bytes.len() < 15 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let consumer_kind = ConsumerKind::from_code(bytes[0])?;
+        let consumer_id = Identifier::from_bytes(bytes.slice(1..))?;
+        position += 1 + consumer_id.get_size_bytes().as_bytes_usize();
+        let consumer = Consumer {
+            kind: consumer_kind,
+            id: consumer_id,
+        };
+        let stream_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        if bytes.len() < position + 4 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let count = u32::from_le_bytes(
+            bytes[position..position + 4]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        position += 4;
+        if count > MAX_CONSUMER_OFFSETS || bytes.len() != position + 12 * count as usize {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut offsets = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            let partition_id = u32::from_le_bytes(
+                bytes[position..position + 4]
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            );
+            let offset = u64::from_le_bytes(
+                bytes[position + 4..position + 12]
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            );
+            offsets.push(PartitionOffset::new(partition_id, offset));
+            position += 12;
+        }
+
+        let command = StoreConsumerOffsets {
+            consumer,
+            stream_id,
+            topic_id,
+            offsets,
+        };
+        Ok(command)
+    }
+}
+
+impl Display for StoreConsumerOffsets {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let offsets = self
+            .offsets
+            .iter()
+            .map(|offset| format!("{}:{}", offset.partition_id, offset.offset))
+            .collect::<Vec<_>>()
+            .join(",");
+        write!(
+            f,
+            "{}|{}|{}|{offsets}",
+            self.consumer, self.stream_id, self.topic_id
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes_and_deserialized_back() {
+        let command = StoreConsumerOffsets {
+            consumer: Consumer::group(Identifier::numeric(1).unwrap()),
+            stream_id: Identifier::numeric(2).unwrap(),
+            topic_id: Identifier::named("topic").unwrap(),
+            offsets: vec![PartitionOffset::new(1, 10), PartitionOffset::new(3, 30)],
+        };
+
+        let bytes = command.to_bytes();
+        let deserialized = StoreConsumerOffsets::from_bytes(bytes).unwrap();
+
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_given_truncated_offsets() {
+        let command = StoreConsumerOffsets {
+            offsets: vec![PartitionOffset::new(1, 10), PartitionOffset::new(2, 20)],
+            ..StoreConsumerOffsets::default()
+        };
+
+        let bytes = command.to_bytes();
+        let result = StoreConsumerOffsets::from_bytes(bytes.slice(..bytes.len() - 1));
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_not_be_valid_given_no_offsets() {
+        let command = StoreConsumerOffsets {
+            offsets: vec![],
+            ..StoreConsumerOffsets::default()
+        };
+
+        assert!(matches!(
+            command.validate(),
+            Err(IggyError::InvalidConsumerOffsetsCount(0))
+        ));
+    }
+
+    #[test]
+    fn should_not_be_valid_given_duplicated_partition() {
+        let command = StoreConsumerOffsets {
+            offsets: vec![PartitionOffset::new(2, 10), PartitionOffset::new(2, 20)],
+            ..StoreConsumerOffsets::default()
+        };
+
+        assert!(matches!(
+            command.validate(),
+            Err(IggyError::InvalidConsumerOffsetPartition(2))
+        ));
+    }
+}
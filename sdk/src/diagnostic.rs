@@ -18,6 +18,7 @@
 
 use derive_more::Display;
 use serde::{Deserialize, Serialize};
+use std::net::SocketAddr;
 
 #[derive(Debug, Serialize, Deserialize, PartialEq, Display, Copy, Clone)]
 #[serde(rename_all = "snake_case")]
@@ -32,4 +33,7 @@ pub enum DiagnosticEvent {
     SignedIn,
     #[display("signed_out")]
     SignedOut,
+    /// The client has connected to the different endpoint of the server than before.
+    #[display("endpoint_changed: {_0}")]
+    EndpointChanged(SocketAddr),
 }
@@ -16,9 +16,10 @@
  * under the License.
  */
 
+use crate::models::error_catalog::{ErrorCatalogEntry, ErrorCategory};
 use crate::utils::byte_size::IggyByteSize;
 use crate::utils::topic_size::MaxTopicSize;
-use strum::{EnumDiscriminants, FromRepr, IntoStaticStr};
+use strum::{EnumDiscriminants, EnumIter, FromRepr, IntoEnumIterator, IntoStaticStr};
 use thiserror::Error;
 
 #[derive(Debug, Error, EnumDiscriminants, IntoStaticStr, FromRepr)]
@@ -26,7 +27,7 @@ use thiserror::Error;
 #[strum(serialize_all = "snake_case")]
 #[strum_discriminants(
     vis(pub(crate)),
-    derive(FromRepr, IntoStaticStr),
+    derive(FromRepr, IntoStaticStr, EnumIter),
     strum(serialize_all = "snake_case")
 )]
 pub enum IggyError {
@@ -62,10 +63,28 @@ pub enum IggyError {
     StateFileCorrupted = 15,
     #[error("Invalid state entry checksum: {0}, expected: {1}, for index: {2}")]
     InvalidStateEntryChecksum(u32, u32, u64) = 16,
+    #[error("Server is running in read-only recovery mode")]
+    ReadOnlyMode = 17,
+    #[error("Server is in maintenance mode, retry later")]
+    ServerInMaintenance = 18,
     #[error("Cannot open database, Path: {0}")]
     CannotOpenDatabase(String) = 19,
     #[error("Resource with key: {0} was not found.")]
     ResourceNotFound(String) = 20,
+    #[error("Invalid resource metadata")]
+    InvalidResourceMetadata = 21,
+    #[error("Server is not the cluster leader, leader node ID: {0}")]
+    NotClusterLeader(u32) = 22,
+    #[error("Cannot replicate state entry with index: {0}")]
+    CannotReplicateStateEntry(u64) = 23,
+    #[error("Server is not the leader of partition with ID: {0}, leader node ID: {1}")]
+    NotPartitionLeader(u32, u32) = 24,
+    #[error("Invalid backup name")]
+    InvalidBackupName = 25,
+    #[error("Backup with name: {0} already exists.")]
+    BackupAlreadyExists(String) = 26,
+    #[error("Cannot create backup, Path: {0}")]
+    CannotCreateBackup(String) = 27,
     #[error("Stale client")]
     StaleClient = 30,
     #[error("TCP error")]
@@ -76,6 +95,16 @@ pub enum IggyError {
     InvalidServerAddress = 33,
     #[error("Invalid client address")]
     InvalidClientAddress = 34,
+    #[error("UDS error")]
+    UdsError = 35,
+    #[error("Too many {0} connections, limit: {1}")]
+    TooManyConnections(String, u32) = 36,
+    #[error("Too many {0} in-flight requests, limit: {1}")]
+    TooManyInFlightRequests(String, u32) = 37,
+    #[error("{0} frame size: {1} bytes exceeds the limit: {2} bytes")]
+    FrameTooLarge(String, u64, u64) = 38,
+    #[error("Invalid frame checksum: {0}, expected: {1}")]
+    InvalidFrameChecksum(u32, u32) = 39,
     #[error("Unauthenticated")]
     Unauthenticated = 40,
     #[error("Unauthorized")]
@@ -108,8 +137,22 @@ pub enum IggyError {
     PersonalAccessTokenExpired(String, u32) = 54,
     #[error("Users limit reached.")]
     UsersLimitReached = 55,
+    #[error("Too many failed login attempts with personal access token, retry in {0} seconds.")]
+    PersonalAccessTokenLoginThrottled(u64) = 56,
+    #[error("Invalid personal access token scope")]
+    InvalidPersonalAccessTokenScope = 57,
+    #[error(
+        "Personal access token: {0} for user with ID: {1} cannot be used from IP address: {2}"
+    )]
+    PersonalAccessTokenIpAddressNotAllowed(String, u32, String) = 58,
+    #[error("Command is outside of the personal access token scope")]
+    PersonalAccessTokenScopeExceeded = 59,
+    #[error("Personal access token: {0} for user with ID: {1} has a scope that cannot be enforced by this transport")]
+    PersonalAccessTokenScopeNotSupported(String, u32) = 60,
     #[error("Not connected")]
     NotConnected = 61,
+    #[error("Invalid IP range: {0}")]
+    InvalidIpRange(String) = 62,
     #[error("Client shutdown")]
     ClientShutdown = 63,
     #[error("Invalid TLS domain")]
@@ -120,6 +163,10 @@ pub enum IggyError {
     InvalidTlsCertificate = 66,
     #[error("Failed to add certificate")]
     FailedToAddCertificate = 67,
+    #[error("Cannot compress data")]
+    CannotCompressData = 68,
+    #[error("Cannot decompress data")]
+    CannotDecompressData = 69,
     #[error("Invalid encryption key")]
     InvalidEncryptionKey = 70,
     #[error("Cannot encrypt data")]
@@ -210,6 +257,30 @@ pub enum IggyError {
     MissingPartitions(u32, u32) = 1018,
     #[error("Max topic size cannot be lower than segment size. Max topic size: {0} < segment size: {1}.")]
     InvalidTopicSize(MaxTopicSize, IggyByteSize) = 1019,
+    #[error("Invalid stream quota, soft limit: {0} cannot be greater than hard limit: {1}.")]
+    InvalidStreamQuota(IggyByteSize, IggyByteSize) = 1020,
+    #[error("Stream with ID: {0} has exceeded its storage quota. Size: {1}, hard limit: {2}.")]
+    StreamQuotaExceeded(u32, IggyByteSize, IggyByteSize) = 1021,
+    #[error("Cannot create stream, the limit of {0} streams has been reached.")]
+    StreamsLimitReached(u32) = 1022,
+    #[error("Invalid namespace name")]
+    InvalidNamespaceName = 1100,
+    #[error("Invalid namespace ID")]
+    InvalidNamespaceId = 1101,
+    #[error("Namespace with ID: {0} was not found.")]
+    NamespaceIdNotFound(u32) = 1102,
+    #[error("Namespace with name: {0} was not found.")]
+    NamespaceNameNotFound(String) = 1103,
+    #[error("Namespace with name: {0} already exists.")]
+    NamespaceNameAlreadyExists(String) = 1104,
+    #[error("Namespace with ID: {0} still has streams or users.")]
+    NamespaceNotEmpty(u32) = 1105,
+    #[error("Cannot create stream, namespace with ID: {0} has reached its limit of {1} streams.")]
+    NamespaceStreamsLimitReached(u32, u32) = 1106,
+    #[error("Cannot create user, namespace with ID: {0} has reached its limit of {1} users.")]
+    NamespaceUsersLimitReached(u32, u32) = 1107,
+    #[error("Namespace with ID: {0} has exceeded its storage quota. Size: {1}, limit: {2}.")]
+    NamespaceStorageQuotaExceeded(u32, IggyByteSize, IggyByteSize) = 1108,
     #[error("Cannot create topics directory for stream with ID: {0}, Path: {1}")]
     CannotCreateTopicsDirectory(u32, String) = 2000,
     #[error(
@@ -250,6 +321,28 @@ pub enum IggyError {
     CannotReadTopics(u32) = 2017,
     #[error("Invalid replication factor")]
     InvalidReplicationFactor = 2018,
+    #[error("Invalid topic pattern: {0}")]
+    InvalidTopicPattern(String) = 2019,
+    #[error("Failed to save snapshot of topic with ID: {0} for stream with ID: {1}.")]
+    CannotSaveTopicSnapshot(u32, u32) = 2020,
+    #[error("Invalid topic snapshot: {0}")]
+    InvalidTopicSnapshot(String) = 2021,
+    #[error("Cannot create topic in stream with ID: {0}, the limit of {1} topics per stream has been reached.")]
+    TopicsLimitReached(u32, u32) = 2022,
+    #[error(
+        "Invalid topic producers, the user IDs must be unique and at most: {0} can be allowed."
+    )]
+    InvalidTopicProducers(u32) = 2023,
+    #[error(
+        "User with ID: {0} is not allowed to produce to topic with ID: {2} in stream with ID: {1}."
+    )]
+    ProducerNotAllowed(u32, u32, u32) = 2024,
+    #[error("Topic with ID: {1} in stream with ID: {0} is marked for deletion.")]
+    TopicMarkedForDeletion(u32, u32) = 2025,
+    #[error("Invalid topic config, the segment size must be between 1 B and 4 GB and the message expiry can't be the server default.")]
+    InvalidTopicConfig = 2026,
+    #[error("Cannot enable the cache for topic with ID: {1} in stream with ID: {0}, the cache is disabled on the server.")]
+    TopicCacheDisabled(u32, u32) = 2027,
     #[error("Cannot create partition with ID: {0} for stream with ID: {1} and topic with ID: {2}")]
     CannotCreatePartition(u32, u32, u32) = 3000,
     #[error(
@@ -282,10 +375,28 @@ pub enum IggyError {
     CannotDeleteConsumerOffsetFile(String) = 3011,
     #[error("Failed to create consumer offsets directory for path: {0}")]
     CannotCreateConsumerOffsetsDirectory(String) = 3012,
+    #[error("Failed to save consumer offset for path: {0}")]
+    CannotSaveConsumerOffset(String) = 3013,
     #[error("Failed to read consumers offsets from path: {0}")]
     CannotReadConsumerOffsets(String) = 3020,
     #[error("Consumer offset for consumer with ID: {0} was not found.")]
     ConsumerOffsetNotFound(u32) = 3021,
+    #[error("Invalid consumer offsets count: {0}")]
+    InvalidConsumerOffsetsCount(u32) = 3022,
+    #[error("Invalid or duplicated consumer offset partition ID: {0}")]
+    InvalidConsumerOffsetPartition(u32) = 3023,
+    #[error(
+        "Cannot create {0} partitions, the limit of {1} partitions per topic has been reached."
+    )]
+    PartitionsLimitReached(u32, u32) = 3024,
+    #[error(
+        "Cannot create {0} partitions, the limit of {1} partitions in total has been reached."
+    )]
+    TotalPartitionsLimitReached(u32, u32) = 3025,
+    #[error("Partition with ID: {0} has changed its epoch from: {1} to: {2}, the consumer offsets have to be reset")]
+    PartitionEpochChanged(u32, u64, u64) = 3026,
+    #[error("Partition with ID: {0} for topic with ID: {1} for stream with ID: {2} is recovering and has exceeded its write rate, retry later.")]
+    PartitionRecovering(u32, u32, u32) = 3027,
     #[error("Segment not found")]
     SegmentNotFound = 4000,
     #[error("Segment with start offset: {0} and partition with ID: {1} is closed")]
@@ -346,10 +457,66 @@ pub enum IggyError {
     InvalidKeyValueLength = 4028,
     #[error("Command length error: {0}")]
     CommandLengthError(String) = 4029,
+    #[error("Cannot fetch archived segment with start offset: {0} for partition with ID: {1}")]
+    CannotFetchArchivedSegment(u64, u32) = 4030,
+    #[error("Cannot compact segment with start offset: {0} for partition with ID: {1}")]
+    CannotCompactSegment(u64, u32) = 4031,
+    #[error("Invalid message filter: {0}")]
+    InvalidMessageFilter(String) = 4032,
+    #[error("Invalid batch checksum: {0}, expected: {1}, for batch with base offset: {2}")]
+    InvalidBatchChecksum(u32, u32, u64) = 4033,
+    #[error("Invalid messages batches count: {0}")]
+    InvalidMessagesBatchesCount(u32) = 4034,
+    #[error("Invalid segment range from position: {0} to: {1}")]
+    InvalidSegmentRange(u64, u64) = 4035,
+    #[error("Invalid segment position: {0}, segment size: {1}")]
+    InvalidSegmentPosition(u64, u64) = 4036,
+    #[error("Invalid segment range size: {0}")]
+    InvalidSegmentRangeSize(u32) = 4037,
     #[error("Cannot sed messages due to client disconnection")]
     CannotSendMessagesDueToClientDisconnection = 4050,
     #[error("Invalid offset: {0}")]
     InvalidOffset(u64) = 4100,
+    #[error("Invalid checkpoint batch")]
+    InvalidCheckpointBatch = 4101,
+    #[error("Invalid replay range")]
+    InvalidReplayRange = 4200,
+    #[error("Replay job with ID: {0} was not found.")]
+    ReplayJobNotFound(u32) = 4201,
+    #[error("Too many replay jobs, limit: {0}")]
+    TooManyReplayJobs(u32) = 4202,
+    #[error("Invalid push subscription endpoint")]
+    InvalidPushSubscriptionEndpoint = 4300,
+    #[error("Push subscription with ID: {0} was not found.")]
+    PushSubscriptionNotFound(u32) = 4301,
+    #[error("Too many push subscriptions, limit: {0}")]
+    TooManyPushSubscriptions(u32) = 4302,
+    #[error("Push subscriptions are disabled")]
+    PushSubscriptionsDisabled = 4303,
+    #[error("Producer: {0} with epoch: {1} has been fenced by the newer epoch: {2}")]
+    ProducerFenced(String, u32, u32) = 4400,
+    #[error("Invalid producer name")]
+    InvalidProducerName = 4401,
+    #[error("Invalid producer epoch header")]
+    InvalidProducerEpochHeader = 4402,
+    #[error("Invalid producer sequence header")]
+    InvalidProducerSequenceHeader = 4403,
+    #[error("Transaction with ID: {0} was not found.")]
+    TransactionNotFound(u64) = 4500,
+    #[error("Transaction with ID: {0} is not open.")]
+    TransactionNotOpen(u64) = 4501,
+    #[error("Invalid transaction header")]
+    InvalidTransactionHeader = 4502,
+    #[error("Transactions are not supported in the cluster mode")]
+    TransactionsUnsupportedInClusterMode = 4503,
+    #[error("Archiver is not enabled")]
+    ArchiverNotEnabled = 4600,
+    #[error("Invalid restore range, the start offset must not be greater than the end offset")]
+    InvalidRestoreRange = 4601,
+    #[error("Cannot restore archived segment with start offset: {0} for partition with ID: {1}")]
+    CannotRestoreArchivedSegment(u64, u32) = 4602,
+    #[error("Invalid aggregate range, the start must be before the end and the buckets count must not exceed: {0}")]
+    InvalidAggregateRange(u32) = 4700,
     #[error("Consumer group with ID: {0} for topic with ID: {1} was not found.")]
     ConsumerGroupIdNotFound(u32, u32) = 5000,
     #[error("Consumer group with ID: {0} for topic with ID: {1} already exists.")]
@@ -368,6 +535,24 @@ pub enum IggyError {
     CannotCreateConsumerGroupInfo(u32, u32, u32) = 5007,
     #[error("Failed to delete consumer group info file for ID: {0} for topic with ID: {1} for stream with ID: {2}.")]
     CannotDeleteConsumerGroupInfo(u32, u32, u32) = 5008,
+    #[error("Invalid dead-letter configuration, both the stream and the topic are required")]
+    InvalidDeadLetterConfiguration = 5009,
+    #[error("Dead-letter topic is not configured for consumer group with ID: {0} for topic with ID: {1}.")]
+    DeadLetterNotConfigured(u32, u32) = 5010,
+    #[error("Invalid negative acknowledgement reason")]
+    InvalidNackReason = 5011,
+    #[error(
+        "Acknowledgements are not enabled for consumer group with ID: {0} for topic with ID: {1}."
+    )]
+    AckModeNotEnabled(u32, u32) = 5012,
+    #[error("Partition with ID: {0} is not assigned to member with ID: {1} of consumer group with ID: {2}.")]
+    PartitionNotAssignedToMember(u32, u32, u32) = 5013,
+    #[error("Invalid partition state key")]
+    InvalidPartitionStateKey = 5014,
+    #[error("Invalid partition state value, it must not be empty or larger than: {0} bytes")]
+    InvalidPartitionStateValue(u32) = 5015,
+    #[error("Partition state store would exceed its maximum size of: {0} bytes")]
+    PartitionStateStoreFull(u64) = 5016,
     #[error("Base offset is missing")]
     MissingBaseOffsetRetainedMessageBatch = 6000,
     #[error("Last offset delta is missing")]
@@ -418,6 +603,8 @@ pub enum IggyError {
     CannotReadIndexPosition = 10011,
     #[error("Cannot read index timestamp")]
     CannotReadIndexTimestamp = 10012,
+    #[error("Cannot initialize io_uring")]
+    CannotInitializeIoUring = 10013,
 }
 
 impl IggyError {
@@ -440,6 +627,216 @@ impl IggyError {
             .map(|discriminant| discriminant.into())
             .unwrap_or("unknown error code")
     }
+
+    pub fn category(&self) -> ErrorCategory {
+        IggyErrorDiscriminants::from(self).category()
+    }
+
+    pub fn category_from_code(code: u32) -> ErrorCategory {
+        IggyErrorDiscriminants::from_repr(code)
+            .map(|discriminant| discriminant.category())
+            .unwrap_or(ErrorCategory::Internal)
+    }
+
+    /// Returns the codes, names and categories of all the errors, ordered by the code.
+    pub fn catalog() -> Vec<ErrorCatalogEntry> {
+        let mut catalog = IggyErrorDiscriminants::iter()
+            .map(|discriminant| ErrorCatalogEntry {
+                code: discriminant as u32,
+                name: <&'static str>::from(discriminant).to_owned(),
+                category: discriminant.category(),
+            })
+            .collect::<Vec<_>>();
+        catalog.sort_by_key(|entry| entry.code);
+        catalog
+    }
+}
+
+impl IggyErrorDiscriminants {
+    fn category(&self) -> ErrorCategory {
+        use IggyErrorDiscriminants::*;
+        match self {
+            InvalidCommand
+            | InvalidFormat
+            | InvalidIdentifier
+            | InvalidVersion
+            | InvalidResourceMetadata
+            | InvalidBackupName
+            | InvalidUsername
+            | InvalidPassword
+            | InvalidUserStatus
+            | InvalidPersonalAccessTokenName
+            | InvalidPersonalAccessTokenScope
+            | InvalidIpRange
+            | InvalidSizeBytes
+            | InvalidUtf8
+            | InvalidNumberEncoding
+            | InvalidBooleanValue
+            | InvalidNumberValue
+            | InvalidClientId
+            | CannotParseHeaderKind
+            | InvalidHttpRequest
+            | InvalidStreamName
+            | InvalidStreamId
+            | InvalidNamespaceName
+            | InvalidNamespaceId
+            | InvalidTopicSize
+            | InvalidStreamQuota
+            | InvalidTopicName
+            | InvalidTopicId
+            | InvalidReplicationFactor
+            | InvalidTopicPattern
+            | InvalidTopicSnapshot
+            | InvalidTopicProducers
+            | InvalidTopicConfig
+            | NoPartitions
+            | InvalidConsumerOffsetsCount
+            | InvalidConsumerOffsetPartition
+            | InvalidSegmentSize
+            | InvalidMessagesCount
+            | InvalidHeaderKey
+            | InvalidHeaderValue
+            | EmptyMessagePayload
+            | InvalidMessagePayloadLength
+            | InvalidMessageChecksum
+            | InvalidKeyValueLength
+            | CommandLengthError
+            | InvalidMessageFilter
+            | InvalidBatchChecksum
+            | InvalidMessagesBatchesCount
+            | InvalidSegmentRange
+            | InvalidSegmentPosition
+            | InvalidSegmentRangeSize
+            | InvalidOffset
+            | InvalidCheckpointBatch
+            | InvalidReplayRange
+            | InvalidPushSubscriptionEndpoint
+            | InvalidProducerName
+            | InvalidProducerEpochHeader
+            | InvalidProducerSequenceHeader
+            | InvalidTransactionHeader
+            | InvalidRestoreRange
+            | InvalidAggregateRange
+            | InvalidConsumerGroupId
+            | InvalidConsumerGroupName
+            | InvalidDeadLetterConfiguration
+            | InvalidNackReason
+            | InvalidPartitionStateKey
+            | InvalidPartitionStateValue => ErrorCategory::InvalidRequest,
+            InvalidConfiguration
+            | InvalidServerAddress
+            | InvalidClientAddress
+            | InvalidTlsDomain
+            | InvalidTlsCertificatePath
+            | InvalidTlsCertificate
+            | FailedToAddCertificate
+            | InvalidEncryptionKey
+            | InvalidJwtAlgorithm
+            | InvalidJwtSecret
+            | InvalidConnectionString => ErrorCategory::Configuration,
+            ResourceNotFound
+            | ClientNotFound
+            | StreamIdNotFound
+            | StreamNameNotFound
+            | NamespaceIdNotFound
+            | NamespaceNameNotFound
+            | TopicIdNotFound
+            | TopicNameNotFound
+            | PartitionNotFound
+            | SegmentNotFound
+            | ConsumerOffsetNotFound
+            | ReplayJobNotFound
+            | PushSubscriptionNotFound
+            | TransactionNotFound
+            | ConsumerGroupIdNotFound
+            | ConsumerGroupNameNotFound
+            | ConsumerGroupMemberNotFound => ErrorCategory::NotFound,
+            BackupAlreadyExists
+            | UserAlreadyExists
+            | PersonalAccessTokenAlreadyExists
+            | StreamIdAlreadyExists
+            | StreamNameAlreadyExists
+            | NamespaceNameAlreadyExists
+            | TopicIdAlreadyExists
+            | TopicNameAlreadyExists
+            | ConsumerGroupIdAlreadyExists
+            | ConsumerGroupNameAlreadyExists => ErrorCategory::AlreadyExists,
+            Unauthenticated
+            | InvalidCredentials
+            | InvalidPersonalAccessToken
+            | PersonalAccessTokenExpired
+            | JwtMissing
+            | AccessTokenMissing
+            | InvalidAccessToken => ErrorCategory::Unauthenticated,
+            Unauthorized
+            | UserInactive
+            | CannotDeleteUser
+            | CannotChangePermissions
+            | PersonalAccessTokenIpAddressNotAllowed
+            | PersonalAccessTokenScopeExceeded
+            | PersonalAccessTokenScopeNotSupported
+            | ProducerNotAllowed => ErrorCategory::Unauthorized,
+            PersonalAccessTokensLimitReached
+            | UsersLimitReached
+            | FrameTooLarge
+            | StreamQuotaExceeded
+            | StreamsLimitReached
+            | NamespaceStreamsLimitReached
+            | NamespaceUsersLimitReached
+            | NamespaceStorageQuotaExceeded
+            | TopicsLimitReached
+            | TooManyPartitions
+            | PartitionsLimitReached
+            | TotalPartitionsLimitReached
+            | TopicFull
+            | TooBigHeadersPayload
+            | TooBigMessagePayload
+            | TooManyMessages
+            | TooManyReplayJobs
+            | TooManyPushSubscriptions
+            | PartitionStateStoreFull => ErrorCategory::LimitExceeded,
+            ReadOnlyMode
+            | ServerInMaintenance
+            | NotClusterLeader
+            | NotPartitionLeader
+            | PartitionRecovering
+            | TooManyConnections
+            | TooManyInFlightRequests
+            | PersonalAccessTokenLoginThrottled => ErrorCategory::Unavailable,
+            ProducerFenced
+            | PartitionEpochChanged
+            | TopicMarkedForDeletion
+            | NamespaceNotEmpty
+            | SegmentClosed
+            | TransactionNotOpen
+            | PartitionNotAssignedToMember => ErrorCategory::Conflict,
+            FeatureUnavailable
+            | TransactionsUnsupportedInClusterMode
+            | PushSubscriptionsDisabled
+            | ArchiverNotEnabled
+            | TopicCacheDisabled
+            | AckModeNotEnabled
+            | DeadLetterNotConfigured => ErrorCategory::Unsupported,
+            Disconnected
+            | CannotEstablishConnection
+            | StaleClient
+            | TcpError
+            | QuicError
+            | UdsError
+            | InvalidFrameChecksum
+            | NotConnected
+            | ClientShutdown
+            | ConnectionClosed
+            | HttpResponseError
+            | InvalidJsonResponse
+            | InvalidBytesResponse
+            | EmptyResponse
+            | CannotCreateEndpoint
+            | CannotParseUrl
+            | CannotSendMessagesDueToClientDisconnection => ErrorCategory::Connection,
+            _ => ErrorCategory::Internal,
+        }
+    }
 }
 
 impl PartialEq for IggyError {
@@ -474,6 +871,31 @@ mod tests {
         )
     }
 
+    #[test]
+    fn gets_category_from_error_and_code() {
+        assert_eq!(
+            IggyError::StreamIdNotFound(1).category(),
+            ErrorCategory::NotFound
+        );
+        assert_eq!(
+            IggyError::category_from_code(GROUP_NAME_ERROR_CODE),
+            ErrorCategory::InvalidRequest
+        );
+        assert_eq!(IggyError::category_from_code(0), ErrorCategory::Internal);
+    }
+
+    #[test]
+    fn catalog_contains_all_errors_with_unique_codes() {
+        let catalog = IggyError::catalog();
+        assert!(catalog.windows(2).all(|pair| pair[0].code < pair[1].code));
+        let entry = catalog
+            .iter()
+            .find(|entry| entry.code == GROUP_NAME_ERROR_CODE)
+            .unwrap();
+        assert_eq!(entry.name, "invalid_consumer_group_name");
+        assert_eq!(entry.category, ErrorCategory::InvalidRequest);
+    }
+
     #[test]
     fn gets_string_from_code() {
         assert_eq!(
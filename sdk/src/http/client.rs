@@ -39,6 +39,7 @@ const PUBLIC_PATHS: &[&str] = &[
     "/metrics",
     "/ping",
     "/stats",
+    "/error-catalog",
     "/users/login",
     "/users/refresh-token",
     "/personal-access-tokens/login",
@@ -18,12 +18,17 @@
 
 use crate::client::ConsumerGroupClient;
 use crate::consumer_groups::create_consumer_group::CreateConsumerGroup;
+use crate::consumer_groups::offset_recovery_policy::OffsetRecoveryPolicy;
+use crate::consumer_groups::update_consumer_group_dead_letter::UpdateConsumerGroupDeadLetter;
+use crate::consumer_groups::update_consumer_group_visibility_timeout::UpdateConsumerGroupVisibilityTimeout;
 use crate::error::IggyError;
 use crate::http::client::HttpClient;
 use crate::http::HttpTransport;
 use crate::identifier::Identifier;
 use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails};
+use crate::utils::duration::IggyDuration;
 use async_trait::async_trait;
+use bytes::Bytes;
 
 #[async_trait]
 impl ConsumerGroupClient for HttpClient {
@@ -76,6 +81,24 @@ impl ConsumerGroupClient for HttpClient {
         topic_id: &Identifier,
         name: &str,
         group_id: Option<u32>,
+    ) -> Result<ConsumerGroupDetails, IggyError> {
+        self.create_consumer_group_with_offset_recovery(
+            stream_id,
+            topic_id,
+            name,
+            group_id,
+            OffsetRecoveryPolicy::ServerDefault,
+        )
+        .await
+    }
+
+    async fn create_consumer_group_with_offset_recovery(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        name: &str,
+        group_id: Option<u32>,
+        offset_recovery: OffsetRecoveryPolicy,
     ) -> Result<ConsumerGroupDetails, IggyError> {
         let response = self
             .post(
@@ -85,6 +108,7 @@ impl ConsumerGroupClient for HttpClient {
                     topic_id: topic_id.clone(),
                     name: name.to_string(),
                     group_id,
+                    offset_recovery,
                 },
             )
             .await?;
@@ -95,6 +119,58 @@ impl ConsumerGroupClient for HttpClient {
         Ok(consumer_group)
     }
 
+    async fn update_consumer_group_dead_letter(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+        dead_letter_stream_id: Option<&Identifier>,
+        dead_letter_topic_id: Option<&Identifier>,
+        max_delivery_count: u32,
+    ) -> Result<(), IggyError> {
+        self.put(
+            &format!(
+                "{}/{}/dead-letter",
+                get_path(&stream_id.as_cow_str(), &topic_id.as_cow_str()),
+                &group_id.as_cow_str()
+            ),
+            &UpdateConsumerGroupDeadLetter {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                group_id: group_id.clone(),
+                dead_letter_stream_id: dead_letter_stream_id.cloned(),
+                dead_letter_topic_id: dead_letter_topic_id.cloned(),
+                max_delivery_count,
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn update_consumer_group_visibility_timeout(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+        visibility_timeout: IggyDuration,
+    ) -> Result<(), IggyError> {
+        self.put(
+            &format!(
+                "{}/{}/visibility-timeout",
+                get_path(&stream_id.as_cow_str(), &topic_id.as_cow_str()),
+                &group_id.as_cow_str()
+            ),
+            &UpdateConsumerGroupVisibilityTimeout {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                group_id: group_id.clone(),
+                visibility_timeout,
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
     async fn delete_consumer_group(
         &self,
         stream_id: &Identifier,
@@ -127,6 +203,40 @@ impl ConsumerGroupClient for HttpClient {
     ) -> Result<(), IggyError> {
         Err(IggyError::FeatureUnavailable)
     }
+
+    async fn get_partition_state(
+        &self,
+        _: &Identifier,
+        _: &Identifier,
+        _: &Identifier,
+        _: u32,
+        _: &str,
+    ) -> Result<Option<Bytes>, IggyError> {
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn store_partition_state(
+        &self,
+        _: &Identifier,
+        _: &Identifier,
+        _: &Identifier,
+        _: u32,
+        _: &str,
+        _: Bytes,
+    ) -> Result<(), IggyError> {
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn delete_partition_state(
+        &self,
+        _: &Identifier,
+        _: &Identifier,
+        _: &Identifier,
+        _: u32,
+        _: &str,
+    ) -> Result<(), IggyError> {
+        Err(IggyError::FeatureUnavailable)
+    }
 }
 
 fn get_path(stream_id: &str, topic_id: &str) -> String {
@@ -19,12 +19,17 @@
 use crate::client::ConsumerOffsetClient;
 use crate::consumer::Consumer;
 use crate::consumer_offsets::get_consumer_offset::GetConsumerOffset;
+use crate::consumer_offsets::get_offsets_for_timestamps::{
+    GetOffsetsForTimestamps, PartitionTimestamp,
+};
 use crate::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
+use crate::consumer_offsets::store_consumer_offsets::{PartitionOffset, StoreConsumerOffsets};
 use crate::error::IggyError;
 use crate::http::client::HttpClient;
 use crate::http::HttpTransport;
 use crate::identifier::Identifier;
 use crate::models::consumer_offset_info::ConsumerOffsetInfo;
+use crate::models::partition_timestamp_offset::PartitionTimestampOffset;
 use async_trait::async_trait;
 
 #[async_trait]
@@ -51,6 +56,29 @@ impl ConsumerOffsetClient for HttpClient {
         Ok(())
     }
 
+    async fn store_consumer_offsets(
+        &self,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        offsets: &[PartitionOffset],
+    ) -> Result<(), IggyError> {
+        self.put(
+            &format!(
+                "{}/batch",
+                get_path(&stream_id.as_cow_str(), &topic_id.as_cow_str())
+            ),
+            &StoreConsumerOffsets {
+                consumer: consumer.clone(),
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                offsets: offsets.to_vec(),
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
     async fn get_consumer_offset(
         &self,
         consumer: &Consumer,
@@ -102,6 +130,32 @@ impl ConsumerOffsetClient for HttpClient {
         self.delete(&path).await?;
         Ok(())
     }
+
+    async fn get_offsets_for_timestamps(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        timestamps: &[PartitionTimestamp],
+    ) -> Result<Vec<PartitionTimestampOffset>, IggyError> {
+        let response = self
+            .post(
+                &format!(
+                    "{}/timestamps",
+                    get_path(&stream_id.as_cow_str(), &topic_id.as_cow_str())
+                ),
+                &GetOffsetsForTimestamps {
+                    stream_id: stream_id.clone(),
+                    topic_id: topic_id.clone(),
+                    timestamps: timestamps.to_vec(),
+                },
+            )
+            .await?;
+        let offsets = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(offsets)
+    }
 }
 
 fn get_path(stream_id: &str, topic_id: &str) -> String {
@@ -22,12 +22,39 @@ use crate::error::IggyError;
 use crate::http::client::HttpClient;
 use crate::http::HttpTransport;
 use crate::identifier::Identifier;
+use crate::messages::abort_transaction::AbortTransaction;
+use crate::messages::ack_messages::AckMessages;
+use crate::messages::aggregate_messages::AggregateMessages;
+use crate::messages::begin_transaction::BeginTransaction;
+use crate::messages::commit_transaction::CommitTransaction;
+use crate::messages::create_push_subscription::CreatePushSubscription;
 use crate::messages::flush_unsaved_buffer::FlushUnsavedBuffer;
-use crate::messages::poll_messages::{PollMessages, PollingStrategy};
+use crate::messages::init_producer_id::InitProducerId;
+use crate::messages::message_filter::MessageFilter;
+use crate::messages::nack_messages::NackMessages;
+use crate::messages::poll_messages::{
+    IsolationLevel, PollMessages, PollMessagesOptions, PollingStrategy,
+};
+use crate::messages::register_producer::RegisterProducer;
+use crate::messages::replay_messages::{HeadersTransform, ReplayMessages, ReplayRange};
 use crate::messages::send_messages::{Message, Partitioning, SendMessages};
+use crate::messages::send_messages_batch::{SendMessagesBatch, TopicMessages};
 use crate::models::messages::PolledMessages;
+use crate::models::messages_aggregate::MessagesBucket;
+use crate::models::producer_epoch::ProducerEpoch;
+use crate::models::producer_session::ProducerSession;
+use crate::models::push_subscription::PushSubscription;
+use crate::models::replay_job::ReplayJob;
+use crate::models::transaction::Transaction;
+use crate::utils::duration::IggyDuration;
+use crate::utils::timestamp::IggyTimestamp;
 use async_trait::async_trait;
 
+const REPLAY_JOBS_PATH: &str = "/replay-jobs";
+const PUSH_SUBSCRIPTIONS_PATH: &str = "/push-subscriptions";
+const TRANSACTIONS_PATH: &str = "/transactions";
+const MESSAGES_BATCH_PATH: &str = "/messages/batch";
+
 #[async_trait]
 impl MessageClient for HttpClient {
     async fn poll_messages(
@@ -39,6 +66,30 @@ impl MessageClient for HttpClient {
         strategy: &PollingStrategy,
         count: u32,
         auto_commit: bool,
+    ) -> Result<PolledMessages, IggyError> {
+        self.poll_messages_with_options(
+            stream_id,
+            topic_id,
+            partition_id,
+            consumer,
+            strategy,
+            count,
+            auto_commit,
+            &PollMessagesOptions::default(),
+        )
+        .await
+    }
+
+    async fn poll_messages_with_options(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        auto_commit: bool,
+        options: &PollMessagesOptions,
     ) -> Result<PolledMessages, IggyError> {
         let response = self
             .get_with_query(
@@ -51,6 +102,48 @@ impl MessageClient for HttpClient {
                     strategy: *strategy,
                     count,
                     auto_commit,
+                    isolation_level: options.isolation_level,
+                    chunk_size: options.chunk_size,
+                    filter: options.filter.clone(),
+                    partition_epoch: options.partition_epoch,
+                    peek: false,
+                },
+            )
+            .await?;
+        let messages = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(messages)
+    }
+
+    async fn peek_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        isolation_level: IsolationLevel,
+        filter: Option<&MessageFilter>,
+    ) -> Result<PolledMessages, IggyError> {
+        let response = self
+            .get_with_query(
+                &get_path(&stream_id.as_cow_str(), &topic_id.as_cow_str()),
+                &PollMessages {
+                    stream_id: stream_id.clone(),
+                    topic_id: topic_id.clone(),
+                    partition_id,
+                    consumer: consumer.clone(),
+                    strategy: *strategy,
+                    count,
+                    auto_commit: false,
+                    isolation_level,
+                    chunk_size: 0,
+                    filter: filter.cloned(),
+                    partition_epoch: None,
+                    peek: true,
                 },
             )
             .await?;
@@ -61,6 +154,38 @@ impl MessageClient for HttpClient {
         Ok(messages)
     }
 
+    async fn aggregate_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        from: IggyTimestamp,
+        to: IggyTimestamp,
+        bucket: IggyDuration,
+    ) -> Result<Vec<MessagesBucket>, IggyError> {
+        let response = self
+            .get_with_query(
+                &format!(
+                    "{}/aggregate",
+                    get_path(&stream_id.as_cow_str(), &topic_id.as_cow_str())
+                ),
+                &AggregateMessages {
+                    stream_id: stream_id.clone(),
+                    topic_id: topic_id.clone(),
+                    partition_id,
+                    from,
+                    to,
+                    bucket,
+                },
+            )
+            .await?;
+        let buckets = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(buckets)
+    }
+
     async fn send_messages(
         &self,
         stream_id: &Identifier,
@@ -81,6 +206,17 @@ impl MessageClient for HttpClient {
         Ok(())
     }
 
+    async fn send_messages_batch(&self, batches: &mut [TopicMessages]) -> Result<(), IggyError> {
+        self.post(
+            MESSAGES_BATCH_PATH,
+            &SendMessagesBatch {
+                batches: batches.to_vec(),
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
     async fn flush_unsaved_buffer(
         &self,
         stream_id: &Identifier,
@@ -106,12 +242,237 @@ impl MessageClient for HttpClient {
             .await?;
         Ok(())
     }
+
+    async fn nack_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        offsets: &[u64],
+        reason: &str,
+    ) -> Result<(), IggyError> {
+        self.post(
+            &format!(
+                "{}/nack",
+                get_path(&stream_id.as_cow_str(), &topic_id.as_cow_str())
+            ),
+            &NackMessages {
+                consumer: consumer.clone(),
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                partition_id,
+                offsets: offsets.to_vec(),
+                reason: reason.to_string(),
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn ack_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        offsets: &[u64],
+    ) -> Result<(), IggyError> {
+        self.post(
+            &format!(
+                "{}/ack",
+                get_path(&stream_id.as_cow_str(), &topic_id.as_cow_str())
+            ),
+            &AckMessages {
+                consumer: consumer.clone(),
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                partition_id,
+                offsets: offsets.to_vec(),
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn replay_messages(
+        &self,
+        source_stream_id: &Identifier,
+        source_topic_id: &Identifier,
+        source_partition_id: u32,
+        range: &ReplayRange,
+        destination_stream_id: &Identifier,
+        destination_topic_id: &Identifier,
+        partitioning: &Partitioning,
+        headers: &HeadersTransform,
+        messages_per_second: u32,
+    ) -> Result<ReplayJob, IggyError> {
+        let response = self
+            .post(
+                REPLAY_JOBS_PATH,
+                &ReplayMessages {
+                    source_stream_id: source_stream_id.clone(),
+                    source_topic_id: source_topic_id.clone(),
+                    source_partition_id,
+                    range: *range,
+                    destination_stream_id: destination_stream_id.clone(),
+                    destination_topic_id: destination_topic_id.clone(),
+                    partitioning: partitioning.clone(),
+                    headers: headers.clone(),
+                    messages_per_second,
+                    ..Default::default()
+                },
+            )
+            .await?;
+        let replay_job = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(replay_job)
+    }
+
+    async fn get_replay_jobs(&self) -> Result<Vec<ReplayJob>, IggyError> {
+        let response = self.get(REPLAY_JOBS_PATH).await?;
+        let replay_jobs = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(replay_jobs)
+    }
+
+    async fn cancel_replay_job(&self, job_id: u32) -> Result<(), IggyError> {
+        self.delete(&format!("{REPLAY_JOBS_PATH}/{job_id}")).await?;
+        Ok(())
+    }
+
+    async fn create_push_subscription(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        endpoint: &str,
+        start_offset: u64,
+        batch_size: u32,
+    ) -> Result<PushSubscription, IggyError> {
+        let response = self
+            .post(
+                PUSH_SUBSCRIPTIONS_PATH,
+                &CreatePushSubscription {
+                    stream_id: stream_id.clone(),
+                    topic_id: topic_id.clone(),
+                    partition_id,
+                    endpoint: endpoint.to_string(),
+                    start_offset,
+                    batch_size,
+                },
+            )
+            .await?;
+        let push_subscription = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(push_subscription)
+    }
+
+    async fn get_push_subscriptions(&self) -> Result<Vec<PushSubscription>, IggyError> {
+        let response = self.get(PUSH_SUBSCRIPTIONS_PATH).await?;
+        let push_subscriptions = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(push_subscriptions)
+    }
+
+    async fn delete_push_subscription(&self, subscription_id: u32) -> Result<(), IggyError> {
+        self.delete(&format!("{PUSH_SUBSCRIPTIONS_PATH}/{subscription_id}"))
+            .await?;
+        Ok(())
+    }
+
+    async fn register_producer(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        name: &str,
+    ) -> Result<ProducerEpoch, IggyError> {
+        let response = self
+            .post(
+                &get_path_producers(&stream_id.as_cow_str(), &topic_id.as_cow_str()),
+                &RegisterProducer {
+                    stream_id: stream_id.clone(),
+                    topic_id: topic_id.clone(),
+                    name: name.to_string(),
+                },
+            )
+            .await?;
+        let producer_epoch = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(producer_epoch)
+    }
+
+    async fn init_producer_id(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<ProducerSession, IggyError> {
+        let response = self
+            .post(
+                &get_path_producer_sessions(&stream_id.as_cow_str(), &topic_id.as_cow_str()),
+                &InitProducerId {
+                    stream_id: stream_id.clone(),
+                    topic_id: topic_id.clone(),
+                },
+            )
+            .await?;
+        let producer_session = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(producer_session)
+    }
+
+    async fn begin_transaction(&self) -> Result<Transaction, IggyError> {
+        let response = self.post(TRANSACTIONS_PATH, &BeginTransaction {}).await?;
+        let transaction = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(transaction)
+    }
+
+    async fn commit_transaction(&self, transaction_id: u64) -> Result<(), IggyError> {
+        self.post(
+            &format!("{TRANSACTIONS_PATH}/{transaction_id}/commit"),
+            &CommitTransaction { transaction_id },
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn abort_transaction(&self, transaction_id: u64) -> Result<(), IggyError> {
+        self.post(
+            &format!("{TRANSACTIONS_PATH}/{transaction_id}/abort"),
+            &AbortTransaction { transaction_id },
+        )
+        .await?;
+        Ok(())
+    }
 }
 
 fn get_path(stream_id: &str, topic_id: &str) -> String {
     format!("streams/{stream_id}/topics/{topic_id}/messages")
 }
 
+fn get_path_producers(stream_id: &str, topic_id: &str) -> String {
+    format!("streams/{stream_id}/topics/{topic_id}/producers")
+}
+
+fn get_path_producer_sessions(stream_id: &str, topic_id: &str) -> String {
+    format!("streams/{stream_id}/topics/{topic_id}/producer-sessions")
+}
+
 fn get_path_flush_unsaved_buffer(
     stream_id: &str,
     topic_id: &str,
@@ -28,6 +28,7 @@ pub mod config;
 pub mod consumer_groups;
 pub mod consumer_offsets;
 pub mod messages;
+pub mod namespaces;
 pub mod partitions;
 pub mod personal_access_tokens;
 pub mod streams;
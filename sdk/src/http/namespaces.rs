@@ -0,0 +1,170 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::client::NamespaceClient;
+use crate::error::IggyError;
+use crate::http::client::HttpClient;
+use crate::http::HttpTransport;
+use crate::identifier::Identifier;
+use crate::models::metadata::ResourceMetadata;
+use crate::models::namespace::{Namespace, NamespaceDetails, NamespaceQuotas};
+use crate::models::permissions::Permissions;
+use crate::models::stream::StreamDetails;
+use crate::models::user_info::UserInfoDetails;
+use crate::models::user_status::UserStatus;
+use crate::namespaces::create_namespace::CreateNamespace;
+use crate::namespaces::update_namespace::UpdateNamespace;
+use crate::streams::create_stream::CreateStream;
+use crate::users::create_user::CreateUser;
+use async_trait::async_trait;
+
+const PATH: &str = "/namespaces";
+const STREAMS_PATH: &str = "/streams";
+const USERS_PATH: &str = "/users";
+
+#[async_trait]
+impl NamespaceClient for HttpClient {
+    async fn get_namespace(
+        &self,
+        namespace_id: &Identifier,
+    ) -> Result<Option<NamespaceDetails>, IggyError> {
+        let response = self
+            .get(&get_details_path(&namespace_id.as_cow_str()))
+            .await;
+        if let Err(error) = response {
+            if matches!(error, IggyError::ResourceNotFound(_)) {
+                return Ok(None);
+            }
+
+            return Err(error);
+        }
+
+        let namespace = response?
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(Some(namespace))
+    }
+
+    async fn get_namespaces(&self) -> Result<Vec<Namespace>, IggyError> {
+        let response = self.get(PATH).await?;
+        let namespaces = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(namespaces)
+    }
+
+    async fn create_namespace(
+        &self,
+        name: &str,
+        quotas: NamespaceQuotas,
+    ) -> Result<NamespaceDetails, IggyError> {
+        let response = self
+            .post(
+                PATH,
+                &CreateNamespace {
+                    name: name.to_string(),
+                    quotas,
+                },
+            )
+            .await?;
+        let namespace = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(namespace)
+    }
+
+    async fn update_namespace(
+        &self,
+        namespace_id: &Identifier,
+        quotas: NamespaceQuotas,
+    ) -> Result<(), IggyError> {
+        self.put(
+            &get_details_path(&namespace_id.as_cow_str()),
+            &UpdateNamespace {
+                namespace_id: namespace_id.clone(),
+                quotas,
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn delete_namespace(&self, namespace_id: &Identifier) -> Result<(), IggyError> {
+        self.delete(&get_details_path(&namespace_id.as_cow_str()))
+            .await?;
+        Ok(())
+    }
+
+    async fn create_namespace_stream(
+        &self,
+        namespace_id: u32,
+        name: &str,
+        stream_id: Option<u32>,
+    ) -> Result<StreamDetails, IggyError> {
+        let response = self
+            .post(
+                STREAMS_PATH,
+                &CreateStream {
+                    name: name.to_string(),
+                    stream_id,
+                    metadata: ResourceMetadata::default(),
+                    namespace_id: Some(namespace_id),
+                },
+            )
+            .await?;
+        let stream = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(stream)
+    }
+
+    async fn create_namespace_user(
+        &self,
+        namespace_id: u32,
+        username: &str,
+        password: &str,
+        status: UserStatus,
+        permissions: Option<Permissions>,
+    ) -> Result<UserInfoDetails, IggyError> {
+        let response = self
+            .post(
+                USERS_PATH,
+                &CreateUser {
+                    username: username.to_string(),
+                    password: password.to_string(),
+                    status,
+                    permissions,
+                    namespace_id: Some(namespace_id),
+                },
+            )
+            .await?;
+        let user = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(user)
+    }
+}
+
+fn get_details_path(namespace_id: &str) -> String {
+    format!("{PATH}/{namespace_id}")
+}
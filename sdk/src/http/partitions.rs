@@ -21,9 +21,19 @@ use crate::error::IggyError;
 use crate::http::client::HttpClient;
 use crate::http::HttpTransport;
 use crate::identifier::Identifier;
+use crate::models::restored_segments::RestoredSegments;
+use crate::models::segment_range::{
+    SegmentRange, SEGMENT_CLOSED_HEADER, SEGMENT_END_OFFSET_HEADER, SEGMENT_NEXT_POSITION_HEADER,
+    SEGMENT_SIZE_HEADER, SEGMENT_START_OFFSET_HEADER, SEGMENT_START_POSITION_HEADER,
+};
+use crate::models::unsaved_state::PartitionUnsavedState;
 use crate::partitions::create_partitions::CreatePartitions;
 use crate::partitions::delete_partitions::DeletePartitions;
+use crate::partitions::flush_partition::FlushPartition;
+use crate::partitions::read_segment_range::ReadSegmentRange;
+use crate::partitions::restore_archived_segments::RestoreArchivedSegments;
 use async_trait::async_trait;
+use reqwest::Response;
 
 #[async_trait]
 impl PartitionClient for HttpClient {
@@ -62,8 +72,121 @@ impl PartitionClient for HttpClient {
         .await?;
         Ok(())
     }
+
+    async fn restore_archived_segments(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        start_offset: u64,
+        end_offset: u64,
+    ) -> Result<RestoredSegments, IggyError> {
+        let response = self
+            .post(
+                &format!(
+                    "{}/{partition_id}/restore",
+                    get_path(&stream_id.as_cow_str(), &topic_id.as_cow_str())
+                ),
+                &RestoreArchivedSegments {
+                    stream_id: stream_id.clone(),
+                    topic_id: topic_id.clone(),
+                    partition_id,
+                    start_offset,
+                    end_offset,
+                },
+            )
+            .await?;
+        let restored_segments = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(restored_segments)
+    }
+
+    async fn flush_partition(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+    ) -> Result<Vec<PartitionUnsavedState>, IggyError> {
+        let response = self
+            .post(
+                &format!(
+                    "{}/flush",
+                    get_path(&stream_id.as_cow_str(), &topic_id.as_cow_str())
+                ),
+                &FlushPartition {
+                    stream_id: stream_id.clone(),
+                    topic_id: topic_id.clone(),
+                    partition_id,
+                },
+            )
+            .await?;
+        let flushed_partitions = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(flushed_partitions)
+    }
+
+    async fn read_segment_range(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        offset: u64,
+        start_position: u64,
+        end_position: Option<u64>,
+        max_bytes: u32,
+    ) -> Result<SegmentRange, IggyError> {
+        let response = self
+            .get_with_query(
+                &format!(
+                    "{}/{partition_id}/segments/range",
+                    get_path(&stream_id.as_cow_str(), &topic_id.as_cow_str())
+                ),
+                &ReadSegmentRange {
+                    stream_id: stream_id.clone(),
+                    topic_id: topic_id.clone(),
+                    partition_id,
+                    offset,
+                    start_position,
+                    end_position,
+                    max_bytes,
+                },
+            )
+            .await?;
+        let segment_start_offset = get_header_value(&response, SEGMENT_START_OFFSET_HEADER)?;
+        let segment_end_offset = get_header_value(&response, SEGMENT_END_OFFSET_HEADER)?;
+        let segment_size = get_header_value(&response, SEGMENT_SIZE_HEADER)?;
+        let segment_closed = get_header_value(&response, SEGMENT_CLOSED_HEADER)?;
+        let start_position = get_header_value(&response, SEGMENT_START_POSITION_HEADER)?;
+        let next_position = get_header_value(&response, SEGMENT_NEXT_POSITION_HEADER)?;
+        let bytes = response
+            .bytes()
+            .await
+            .map_err(|_| IggyError::InvalidBytesResponse)?;
+        Ok(SegmentRange {
+            segment_start_offset,
+            segment_end_offset,
+            segment_size,
+            segment_closed,
+            start_position,
+            next_position,
+            bytes,
+        })
+    }
 }
 
 fn get_path(stream_id: &str, topic_id: &str) -> String {
     format!("streams/{stream_id}/topics/{topic_id}/partitions")
 }
+
+fn get_header_value<T: std::str::FromStr>(response: &Response, name: &str) -> Result<T, IggyError> {
+    response
+        .headers()
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse().ok())
+        .ok_or(IggyError::InvalidBytesResponse)
+}
@@ -21,9 +21,12 @@ use crate::error::IggyError;
 use crate::http::client::HttpClient;
 use crate::http::HttpTransport;
 use crate::models::identity_info::IdentityInfo;
-use crate::models::personal_access_token::{PersonalAccessTokenInfo, RawPersonalAccessToken};
+use crate::models::personal_access_token::{
+    PersonalAccessTokenInfo, PersonalAccessTokenScope, RawPersonalAccessToken,
+};
 use crate::personal_access_tokens::create_personal_access_token::CreatePersonalAccessToken;
 use crate::personal_access_tokens::login_with_personal_access_token::LoginWithPersonalAccessToken;
+use crate::personal_access_tokens::rotate_personal_access_token::RotatePersonalAccessToken;
 use crate::utils::personal_access_token_expiry::PersonalAccessTokenExpiry;
 use async_trait::async_trait;
 
@@ -44,6 +47,16 @@ impl PersonalAccessTokenClient for HttpClient {
         &self,
         name: &str,
         expiry: PersonalAccessTokenExpiry,
+    ) -> Result<RawPersonalAccessToken, IggyError> {
+        self.create_scoped_personal_access_token(name, expiry, &PersonalAccessTokenScope::default())
+            .await
+    }
+
+    async fn create_scoped_personal_access_token(
+        &self,
+        name: &str,
+        expiry: PersonalAccessTokenExpiry,
+        scope: &PersonalAccessTokenScope,
     ) -> Result<RawPersonalAccessToken, IggyError> {
         let response = self
             .post(
@@ -51,6 +64,7 @@ impl PersonalAccessTokenClient for HttpClient {
                 &CreatePersonalAccessToken {
                     name: name.to_string(),
                     expiry,
+                    scope: scope.clone(),
                 },
             )
             .await?;
@@ -66,6 +80,27 @@ impl PersonalAccessTokenClient for HttpClient {
         Ok(())
     }
 
+    async fn rotate_personal_access_token(
+        &self,
+        name: &str,
+        expiry: PersonalAccessTokenExpiry,
+    ) -> Result<RawPersonalAccessToken, IggyError> {
+        let response = self
+            .post(
+                &format!("{PATH}/{name}/rotate"),
+                &RotatePersonalAccessToken {
+                    name: name.to_string(),
+                    expiry,
+                },
+            )
+            .await?;
+        let personal_access_token = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(personal_access_token)
+    }
+
     async fn login_with_personal_access_token(
         &self,
         token: &str,
@@ -21,9 +21,12 @@ use crate::error::IggyError;
 use crate::http::client::HttpClient;
 use crate::http::HttpTransport;
 use crate::identifier::Identifier;
-use crate::models::stream::{Stream, StreamDetails};
-use crate::streams::create_stream::CreateStream;
+use crate::models::metadata::{MetadataFilter, MetadataFilterQuery, ResourceMetadata};
+use crate::models::stream::{Stream, StreamDetails, StreamQuota};
+use crate::streams::create_stream::{CreateStream, CreateStreamOptions};
 use crate::streams::update_stream::UpdateStream;
+use crate::streams::update_stream_metadata::UpdateStreamMetadata;
+use crate::streams::update_stream_quota::UpdateStreamQuota;
 use async_trait::async_trait;
 
 const PATH: &str = "/streams";
@@ -56,10 +59,34 @@ impl StreamClient for HttpClient {
         Ok(streams)
     }
 
+    async fn get_streams_by_metadata(
+        &self,
+        filter: &MetadataFilter,
+    ) -> Result<Vec<Stream>, IggyError> {
+        let response = self
+            .get_with_query(PATH, &MetadataFilterQuery::from(filter))
+            .await?;
+        let streams = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(streams)
+    }
+
     async fn create_stream(
         &self,
         name: &str,
         stream_id: Option<u32>,
+    ) -> Result<StreamDetails, IggyError> {
+        self.create_stream_with_options(name, stream_id, &CreateStreamOptions::default())
+            .await
+    }
+
+    async fn create_stream_with_options(
+        &self,
+        name: &str,
+        stream_id: Option<u32>,
+        options: &CreateStreamOptions,
     ) -> Result<StreamDetails, IggyError> {
         let response = self
             .post(
@@ -67,6 +94,8 @@ impl StreamClient for HttpClient {
                 &CreateStream {
                     name: name.to_string(),
                     stream_id,
+                    metadata: options.metadata.clone(),
+                    namespace_id: None,
                 },
             )
             .await?;
@@ -89,6 +118,38 @@ impl StreamClient for HttpClient {
         Ok(())
     }
 
+    async fn update_stream_metadata(
+        &self,
+        stream_id: &Identifier,
+        metadata: ResourceMetadata,
+    ) -> Result<(), IggyError> {
+        self.put(
+            &format!("{}/metadata", get_details_path(&stream_id.as_cow_str())),
+            &UpdateStreamMetadata {
+                stream_id: stream_id.clone(),
+                metadata,
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn update_stream_quota(
+        &self,
+        stream_id: &Identifier,
+        quota: StreamQuota,
+    ) -> Result<(), IggyError> {
+        self.put(
+            &format!("{}/quota", get_details_path(&stream_id.as_cow_str())),
+            &UpdateStreamQuota {
+                stream_id: stream_id.clone(),
+                quota,
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
     async fn delete_stream(&self, stream_id: &Identifier) -> Result<(), IggyError> {
         self.delete(&get_details_path(&stream_id.as_cow_str()))
             .await?;
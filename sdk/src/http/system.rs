@@ -20,11 +20,20 @@ use crate::client::SystemClient;
 use crate::error::IggyError;
 use crate::http::client::HttpClient;
 use crate::http::HttpTransport;
+use crate::models::audit_entry::{AuditAction, AuditEntry};
+use crate::models::backup::Backup;
 use crate::models::client_info::{ClientInfo, ClientInfoDetails};
+use crate::models::error_catalog::ErrorCatalogEntry;
+use crate::models::maintenance_mode::MaintenanceMode;
+use crate::models::server_info::ServerInfo;
 use crate::models::snapshot::Snapshot;
 use crate::models::stats::Stats;
+use crate::models::unsaved_state::PartitionUnsavedState;
 use crate::snapshot::{SnapshotCompression, SystemSnapshotType};
+use crate::system::create_backup::CreateBackup;
+use crate::system::get_audit_log::GetAuditLog;
 use crate::system::get_snapshot::GetSnapshot;
+use crate::system::set_maintenance_mode::SetMaintenanceMode;
 use crate::utils::duration::IggyDuration;
 use async_trait::async_trait;
 
@@ -32,6 +41,12 @@ const PING: &str = "/ping";
 const CLIENTS: &str = "/clients";
 const STATS: &str = "/stats";
 const SNAPSHOT: &str = "/snapshot";
+const MAINTENANCE: &str = "/maintenance";
+const INFO: &str = "/info";
+const BACKUPS: &str = "/backups";
+const UNSAVED_STATE: &str = "/unsaved-state";
+const AUDIT_LOG: &str = "/audit-log";
+const ERROR_CATALOG: &str = "/error-catalog";
 
 #[async_trait]
 impl SystemClient for HttpClient {
@@ -104,4 +119,97 @@ impl SystemClient for HttpClient {
         let snapshot = Snapshot::new(file.to_vec());
         Ok(snapshot)
     }
+
+    async fn get_maintenance_mode(&self) -> Result<MaintenanceMode, IggyError> {
+        let response = self.get(MAINTENANCE).await?;
+        let maintenance_mode = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(maintenance_mode)
+    }
+
+    async fn set_maintenance_mode(
+        &self,
+        enabled: bool,
+        reject_reads: bool,
+        message: Option<&str>,
+    ) -> Result<(), IggyError> {
+        self.put(
+            MAINTENANCE,
+            &SetMaintenanceMode {
+                enabled,
+                reject_reads,
+                message: message.map(|message| message.to_string()),
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn get_server_info(&self) -> Result<ServerInfo, IggyError> {
+        let response = self.get(INFO).await?;
+        let server_info = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(server_info)
+    }
+
+    async fn create_backup(&self, name: &str) -> Result<Backup, IggyError> {
+        let response = self
+            .post(
+                BACKUPS,
+                &CreateBackup {
+                    name: name.to_string(),
+                },
+            )
+            .await?;
+        let backup = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(backup)
+    }
+
+    async fn get_unsaved_state(&self) -> Result<Vec<PartitionUnsavedState>, IggyError> {
+        let response = self.get(UNSAVED_STATE).await?;
+        let unsaved_state = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(unsaved_state)
+    }
+
+    async fn get_audit_log(
+        &self,
+        count: u32,
+        user_id: Option<u32>,
+        action: Option<AuditAction>,
+    ) -> Result<Vec<AuditEntry>, IggyError> {
+        let response = self
+            .get_with_query(
+                AUDIT_LOG,
+                &GetAuditLog {
+                    count,
+                    user_id,
+                    action,
+                },
+            )
+            .await?;
+        let entries = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(entries)
+    }
+
+    async fn get_error_catalog(&self) -> Result<Vec<ErrorCatalogEntry>, IggyError> {
+        let response = self.get(ERROR_CATALOG).await?;
+        let entries = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(entries)
+    }
 }
@@ -18,13 +18,25 @@
 
 use crate::client::TopicClient;
 use crate::compression::compression_algorithm::CompressionAlgorithm;
+use crate::consumer::Consumer;
 use crate::error::IggyError;
 use crate::http::client::HttpClient;
 use crate::http::HttpTransport;
 use crate::identifier::Identifier;
+use crate::messages::message_id_scheme::MessageIdScheme;
+use crate::models::metadata::{MetadataFilter, MetadataFilterQuery, ResourceMetadata};
 use crate::models::topic::{Topic, TopicDetails};
-use crate::topics::create_topic::CreateTopic;
-use crate::topics::update_topic::UpdateTopic;
+use crate::topics::cleanup_policy::CleanupPolicy;
+use crate::topics::create_topic::{CreateTopic, CreateTopicOptions};
+use crate::topics::mark_topic_for_deletion::MarkTopicForDeletion;
+use crate::topics::purge_topic::{PurgeTopic, PurgeTopicOptions};
+use crate::topics::topic_config::TopicConfig;
+use crate::topics::update_topic::{UpdateTopic, UpdateTopicOptions};
+use crate::topics::update_topic_config::UpdateTopicConfig;
+use crate::topics::update_topic_expiry_watcher::UpdateTopicExpiryWatcher;
+use crate::topics::update_topic_metadata::UpdateTopicMetadata;
+use crate::topics::update_topic_producers::UpdateTopicProducers;
+use crate::utils::duration::IggyDuration;
 use crate::utils::expiry::IggyExpiry;
 use crate::utils::topic_size::MaxTopicSize;
 use async_trait::async_trait;
@@ -66,6 +78,24 @@ impl TopicClient for HttpClient {
         Ok(topics)
     }
 
+    async fn get_topics_by_metadata(
+        &self,
+        stream_id: &Identifier,
+        filter: &MetadataFilter,
+    ) -> Result<Vec<Topic>, IggyError> {
+        let response = self
+            .get_with_query(
+                &get_path(&stream_id.as_cow_str()),
+                &MetadataFilterQuery::from(filter),
+            )
+            .await?;
+        let topics = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(topics)
+    }
+
     async fn create_topic(
         &self,
         stream_id: &Identifier,
@@ -76,6 +106,32 @@ impl TopicClient for HttpClient {
         topic_id: Option<u32>,
         message_expiry: IggyExpiry,
         max_topic_size: MaxTopicSize,
+    ) -> Result<TopicDetails, IggyError> {
+        self.create_topic_with_options(
+            stream_id,
+            name,
+            partitions_count,
+            compression_algorithm,
+            replication_factor,
+            topic_id,
+            message_expiry,
+            max_topic_size,
+            &CreateTopicOptions::default(),
+        )
+        .await
+    }
+
+    async fn create_topic_with_options(
+        &self,
+        stream_id: &Identifier,
+        name: &str,
+        partitions_count: u32,
+        compression_algorithm: CompressionAlgorithm,
+        replication_factor: Option<u8>,
+        topic_id: Option<u32>,
+        message_expiry: IggyExpiry,
+        max_topic_size: MaxTopicSize,
+        options: &CreateTopicOptions,
     ) -> Result<TopicDetails, IggyError> {
         let response = self
             .post(
@@ -89,6 +145,9 @@ impl TopicClient for HttpClient {
                     topic_id,
                     message_expiry,
                     max_topic_size,
+                    metadata: options.metadata.clone(),
+                    message_id_scheme: MessageIdScheme::ServerDefault,
+                    cleanup_policy: CleanupPolicy::ServerDefault,
                 },
             )
             .await?;
@@ -108,6 +167,30 @@ impl TopicClient for HttpClient {
         replication_factor: Option<u8>,
         message_expiry: IggyExpiry,
         max_topic_size: MaxTopicSize,
+    ) -> Result<(), IggyError> {
+        self.update_topic_with_options(
+            stream_id,
+            topic_id,
+            name,
+            compression_algorithm,
+            replication_factor,
+            message_expiry,
+            max_topic_size,
+            &UpdateTopicOptions::default(),
+        )
+        .await
+    }
+
+    async fn update_topic_with_options(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        name: &str,
+        compression_algorithm: CompressionAlgorithm,
+        replication_factor: Option<u8>,
+        message_expiry: IggyExpiry,
+        max_topic_size: MaxTopicSize,
+        options: &UpdateTopicOptions,
     ) -> Result<(), IggyError> {
         self.put(
             &get_details_path(&stream_id.as_cow_str(), &topic_id.as_cow_str()),
@@ -119,6 +202,115 @@ impl TopicClient for HttpClient {
                 replication_factor,
                 message_expiry,
                 max_topic_size,
+                max_segments: options.max_segments,
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn update_topic_metadata(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        metadata: ResourceMetadata,
+    ) -> Result<(), IggyError> {
+        self.put(
+            &format!(
+                "{}/metadata",
+                get_details_path(&stream_id.as_cow_str(), &topic_id.as_cow_str())
+            ),
+            &UpdateTopicMetadata {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                metadata,
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn update_topic_producers(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        allowed_producers: &[u32],
+    ) -> Result<(), IggyError> {
+        self.put(
+            &format!(
+                "{}/producers",
+                get_details_path(&stream_id.as_cow_str(), &topic_id.as_cow_str())
+            ),
+            &UpdateTopicProducers {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                allowed_producers: allowed_producers.to_vec(),
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn update_topic_config(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        config: TopicConfig,
+    ) -> Result<(), IggyError> {
+        self.put(
+            &format!(
+                "{}/config",
+                get_details_path(&stream_id.as_cow_str(), &topic_id.as_cow_str())
+            ),
+            &UpdateTopicConfig {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                config,
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn update_topic_expiry_watcher(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        consumer: &Consumer,
+        enabled: bool,
+    ) -> Result<(), IggyError> {
+        self.put(
+            &format!(
+                "{}/expiry-watchers",
+                get_details_path(&stream_id.as_cow_str(), &topic_id.as_cow_str())
+            ),
+            &UpdateTopicExpiryWatcher {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                consumer_kind: consumer.kind,
+                consumer_id: consumer.id.clone(),
+                enabled,
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn mark_topic_for_deletion(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        drain_period: IggyDuration,
+    ) -> Result<(), IggyError> {
+        self.put(
+            &format!(
+                "{}/deletion",
+                get_details_path(&stream_id.as_cow_str(), &topic_id.as_cow_str())
+            ),
+            &MarkTopicForDeletion {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                drain_period,
             },
         )
         .await?;
@@ -143,10 +335,28 @@ impl TopicClient for HttpClient {
         stream_id: &Identifier,
         topic_id: &Identifier,
     ) -> Result<(), IggyError> {
-        self.delete(&format!(
-            "{}/purge",
-            &get_details_path(&stream_id.as_cow_str(), &topic_id.as_cow_str(),)
-        ))
+        self.purge_topic_with_options(stream_id, topic_id, &PurgeTopicOptions::default())
+            .await
+    }
+
+    async fn purge_topic_with_options(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        options: &PurgeTopicOptions,
+    ) -> Result<(), IggyError> {
+        self.delete_with_query(
+            &format!(
+                "{}/purge",
+                &get_details_path(&stream_id.as_cow_str(), &topic_id.as_cow_str(),)
+            ),
+            &PurgeTopic {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                keep_consumer_offsets: options.keep_consumer_offsets,
+                older_than: options.older_than,
+            },
+        )
         .await?;
         Ok(())
     }
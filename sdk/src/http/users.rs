@@ -30,6 +30,8 @@ use crate::users::create_user::CreateUser;
 use crate::users::login_user::LoginUser;
 use crate::users::update_permissions::UpdatePermissions;
 use crate::users::update_user::UpdateUser;
+use crate::users::update_user_quotas::UpdateUserQuotas;
+use crate::users::user_quotas::UserQuotas;
 use async_trait::async_trait;
 
 const PATH: &str = "/users";
@@ -77,6 +79,7 @@ impl UserClient for HttpClient {
                     password: password.to_string(),
                     status,
                     permissions,
+                    namespace_id: None,
                 },
             )
             .await?;
@@ -127,6 +130,22 @@ impl UserClient for HttpClient {
         Ok(())
     }
 
+    async fn update_user_quotas(
+        &self,
+        user_id: &Identifier,
+        quotas: UserQuotas,
+    ) -> Result<(), IggyError> {
+        self.put(
+            &format!("{PATH}/{}/quotas", &user_id.as_cow_str()),
+            &UpdateUserQuotas {
+                user_id: user_id.clone(),
+                quotas,
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
     async fn change_password(
         &self,
         user_id: &Identifier,
@@ -170,4 +189,8 @@ impl UserClient for HttpClient {
         self.set_access_token(None).await;
         Ok(())
     }
+
+    async fn login_as(&self, _: &Identifier) -> Result<IdentityInfo, IggyError> {
+        Err(IggyError::FeatureUnavailable)
+    }
 }
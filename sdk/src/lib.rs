@@ -16,6 +16,9 @@
  * under the License.
  */
 
+/// The version of the SDK.
+pub const SDK_VERSION: &str = env!("CARGO_PKG_VERSION");
+
 pub mod args;
 pub mod binary;
 pub mod bytes_serializable;
@@ -38,14 +41,19 @@ pub mod consumer_groups;
 pub mod consumer_offsets;
 pub mod diagnostic;
 pub mod error;
+#[cfg(feature = "http")]
 pub mod http;
 pub mod identifier;
 pub mod locking;
 pub mod messages;
+#[cfg(any(test, feature = "mock"))]
+pub mod mock;
 pub mod models;
+pub mod namespaces;
 pub mod partitioner;
 pub mod partitions;
 pub mod personal_access_tokens;
+#[cfg(feature = "quic")]
 pub mod quic;
 pub mod snapshot;
 pub mod stream_builder;
@@ -53,6 +61,8 @@ pub mod streams;
 pub mod system;
 pub mod tcp;
 pub mod topics;
+#[cfg(unix)]
+pub mod uds;
 pub mod users;
 pub mod utils;
 pub mod validatable;
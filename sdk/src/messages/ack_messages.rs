@@ -0,0 +1,225 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, ACK_MESSAGES_CODE};
+use crate::consumer::{Consumer, ConsumerKind};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::utils::sizeable::Sizeable;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `AckMessages` command acknowledges the messages polled by the consumer group member,
+/// when the consumer group has the visibility timeout configured (acknowledgement mode).
+/// The unacknowledged messages are redelivered once their visibility timeout expires,
+/// while the offset of the consumer group is committed up to the first unacknowledged message.
+/// It has additional payload:
+/// - `consumer` - the consumer group which processed the messages.
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `partition_id` - partition ID from which the messages were polled, if `None` the currently assigned partition of the member is used.
+/// - `offsets` - offsets of the processed messages.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct AckMessages {
+    /// The consumer group which processed the messages.
+    #[serde(flatten)]
+    pub consumer: Consumer,
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    /// Partition ID from which the messages were polled, if `None` the currently assigned partition of the member is used.
+    pub partition_id: Option<u32>,
+    /// Offsets of the processed messages.
+    pub offsets: Vec<u64>,
+}
+
+impl Default for AckMessages {
+    fn default() -> Self {
+        AckMessages {
+            consumer: Consumer::group(Identifier::default()),
+            stream_id: Identifier::default(),
+            topic_id: Identifier::default(),
+            partition_id: None,
+            offsets: vec![0],
+        }
+    }
+}
+
+impl Command for AckMessages {
+    fn code(&self) -> u32 {
+        ACK_MESSAGES_CODE
+    }
+}
+
+impl Validatable<IggyError> for AckMessages {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.consumer.kind != ConsumerKind::ConsumerGroup {
+            return Err(IggyError::InvalidConsumerGroupId);
+        }
+
+        if self.offsets.is_empty() {
+            return Err(IggyError::InvalidMessagesCount);
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for AckMessages {
+    fn to_bytes(&self) -> Bytes {
+        let consumer_bytes = self.consumer.to_bytes();
+        let stream_id_bytes = self.stream_id.to_bytes();
+        let topic_id_bytes = self.topic_id.to_bytes();
+        let mut bytes = BytesMut::with_capacity(
+            consumer_bytes.len()
+                + stream_id_bytes.len()
+                + topic_id_bytes.len()
+                + 4
+                + 4
+                + 8 * self.offsets.len(),
+        );
+        bytes.put_slice(&consumer_bytes);
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        bytes.put_u32_le(self.partition_id.unwrap_or(0));
+        bytes.put_u32_le(self.offsets.len() as u32);
+        for offset in &self.offsets {
+            bytes.put_u64_le(*offset);
+        }
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<AckMessages, IggyError> {
+        if bytes.len() < 18 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let consumer_kind = ConsumerKind::from_code(bytes[0])?;
+        let consumer_id = Identifier::from_bytes(bytes.slice(1..))?;
+        let mut position = 1 + consumer_id.get_size_bytes().as_bytes_usize();
+        let consumer = Consumer {
+            kind: consumer_kind,
+            id: consumer_id,
+        };
+        let stream_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        let read_u32 = |position: usize| {
+            bytes
+                .get(position..position + 4)
+                .ok_or(IggyError::InvalidCommand)?
+                .try_into()
+                .map(u32::from_le_bytes)
+                .map_err(|_| IggyError::InvalidNumberEncoding)
+        };
+        let partition_id = match read_u32(position)? {
+            0 => None,
+            partition_id => Some(partition_id),
+        };
+        let count = read_u32(position + 4)? as usize;
+        position += 8;
+        if bytes.len() != position + 8 * count {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let offsets = bytes[position..]
+            .chunks_exact(8)
+            .map(|chunk| u64::from_le_bytes(chunk.try_into().unwrap()))
+            .collect();
+        let command = AckMessages {
+            consumer,
+            stream_id,
+            topic_id,
+            partition_id,
+            offsets,
+        };
+        Ok(command)
+    }
+}
+
+impl Display for AckMessages {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let offsets = self
+            .offsets
+            .iter()
+            .map(|offset| offset.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        write!(
+            f,
+            "{}|{}|{}|{}|{}",
+            self.consumer,
+            self.stream_id,
+            self.topic_id,
+            self.partition_id.unwrap_or(0),
+            offsets
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_and_deserialized_from_bytes() {
+        let command = AckMessages {
+            consumer: Consumer::group(Identifier::named("payments").unwrap()),
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            partition_id: Some(3),
+            offsets: vec![10, 12],
+        };
+
+        let bytes = command.to_bytes();
+        let deserialized = AckMessages::from_bytes(bytes).unwrap();
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_truncated_bytes() {
+        let command = AckMessages {
+            consumer: Consumer::group(Identifier::numeric(1).unwrap()),
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            partition_id: None,
+            offsets: vec![10],
+        };
+
+        let bytes = command.to_bytes();
+        let command = AckMessages::from_bytes(bytes.slice(..bytes.len() - 1));
+        assert!(command.is_err());
+    }
+
+    #[test]
+    fn should_not_be_valid_given_regular_consumer() {
+        let command = AckMessages {
+            consumer: Consumer::new(Identifier::numeric(1).unwrap()),
+            ..Default::default()
+        };
+
+        assert!(command.validate().is_err());
+    }
+}
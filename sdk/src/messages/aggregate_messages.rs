@@ -0,0 +1,220 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, AGGREGATE_MESSAGES_CODE};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::utils::duration::IggyDuration;
+use crate::utils::sizeable::Sizeable;
+use crate::utils::timestamp::IggyTimestamp;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// The maximum number of the time buckets returned by the single `AggregateMessages` command.
+pub const MAX_AGGREGATE_BUCKETS: u32 = 10_000;
+
+/// `AggregateMessages` command computes the number of messages, their size and the min/max timestamps
+/// per time bucket for the given range, using only the segment indexes, without reading the payloads.
+/// It has additional payload:
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `partition_id` - partition ID to aggregate, `None` aggregates all the partitions of the topic.
+/// - `from` - inclusive start of the time range (timestamp in microseconds).
+/// - `to` - exclusive end of the time range (timestamp in microseconds).
+/// - `bucket` - width of the single time bucket.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct AggregateMessages {
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    /// Partition ID to aggregate, `None` aggregates all the partitions of the topic.
+    #[serde(default)]
+    pub partition_id: Option<u32>,
+    /// Inclusive start of the time range (timestamp in microseconds).
+    pub from: IggyTimestamp,
+    /// Exclusive end of the time range (timestamp in microseconds).
+    pub to: IggyTimestamp,
+    /// Width of the single time bucket.
+    pub bucket: IggyDuration,
+}
+
+impl AggregateMessages {
+    /// Returns the number of the time buckets covering the range, the last one can be shorter than the others.
+    pub fn buckets_count(&self) -> u64 {
+        let range = self.to.as_micros().saturating_sub(self.from.as_micros());
+        let bucket = self.bucket.as_micros();
+        if bucket == 0 {
+            return 0;
+        }
+
+        range.div_ceil(bucket)
+    }
+}
+
+impl Default for AggregateMessages {
+    fn default() -> Self {
+        AggregateMessages {
+            stream_id: Identifier::default(),
+            topic_id: Identifier::default(),
+            partition_id: None,
+            from: IggyTimestamp::zero(),
+            to: 60_000_000.into(),
+            bucket: IggyDuration::ONE_SECOND,
+        }
+    }
+}
+
+impl Command for AggregateMessages {
+    fn code(&self) -> u32 {
+        AGGREGATE_MESSAGES_CODE
+    }
+}
+
+impl Validatable<IggyError> for AggregateMessages {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.partition_id == Some(0)
+            || self.from.as_micros() >= self.to.as_micros()
+            || self.bucket.is_zero()
+            || self.buckets_count() > MAX_AGGREGATE_BUCKETS as u64
+        {
+            return Err(IggyError::InvalidAggregateRange(MAX_AGGREGATE_BUCKETS));
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for AggregateMessages {
+    fn to_bytes(&self) -> Bytes {
+        let stream_id_bytes = self.stream_id.to_bytes();
+        let topic_id_bytes = self.topic_id.to_bytes();
+        let mut bytes = BytesMut::with_capacity(stream_id_bytes.len() + topic_id_bytes.len() + 28);
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        bytes.put_u32_le(self.partition_id.unwrap_or_default());
+        bytes.put_u64_le(self.from.as_micros());
+        bytes.put_u64_le(self.to.as_micros());
+        bytes.put_u64_le(self.bucket.as_micros());
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<AggregateMessages, IggyError> {
+        if bytes.len() < 34 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone())?;
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        if bytes.len() != position + 28 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let partition_id = u32::from_le_bytes(
+            bytes[position..position + 4]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let from = u64::from_le_bytes(
+            bytes[position + 4..position + 12]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let to = u64::from_le_bytes(
+            bytes[position + 12..position + 20]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let bucket = u64::from_le_bytes(
+            bytes[position + 20..position + 28]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let command = AggregateMessages {
+            stream_id,
+            topic_id,
+            partition_id: match partition_id {
+                0 => None,
+                partition_id => Some(partition_id),
+            },
+            from: from.into(),
+            to: to.into(),
+            bucket: bucket.into(),
+        };
+        Ok(command)
+    }
+}
+
+impl Display for AggregateMessages {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}|{}|{}",
+            self.stream_id,
+            self.topic_id,
+            self.partition_id.unwrap_or_default(),
+            self.from.as_micros(),
+            self.to.as_micros(),
+            self.bucket.as_micros()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes_and_deserialized_back() {
+        let command = AggregateMessages {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::named("topic").unwrap(),
+            partition_id: Some(2),
+            from: 1_000_000.into(),
+            to: 5_000_000.into(),
+            bucket: IggyDuration::ONE_SECOND,
+        };
+
+        let bytes = command.to_bytes();
+        let deserialized = AggregateMessages::from_bytes(bytes).unwrap();
+
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_not_be_valid_given_too_many_buckets() {
+        let command = AggregateMessages {
+            from: IggyTimestamp::zero(),
+            to: (MAX_AGGREGATE_BUCKETS as u64 * 1_000_000 + 1).into(),
+            ..AggregateMessages::default()
+        };
+
+        assert_eq!(command.buckets_count(), MAX_AGGREGATE_BUCKETS as u64 + 1);
+        assert!(matches!(
+            command.validate(),
+            Err(IggyError::InvalidAggregateRange(MAX_AGGREGATE_BUCKETS))
+        ));
+    }
+}
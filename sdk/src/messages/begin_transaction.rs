@@ -0,0 +1,82 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, BEGIN_TRANSACTION_CODE};
+use crate::error::IggyError;
+use crate::validatable::Validatable;
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `BeginTransaction` command starts the new transaction and returns its unique transaction ID.
+/// The messages sent with the transaction ID header to any topics and partitions are appended as uncommitted,
+/// and become visible to the read-committed consumers only once the transaction is committed.
+/// It has no additional payload.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct BeginTransaction {}
+
+impl Command for BeginTransaction {
+    fn code(&self) -> u32 {
+        BEGIN_TRANSACTION_CODE
+    }
+}
+
+impl Validatable<IggyError> for BeginTransaction {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for BeginTransaction {
+    fn to_bytes(&self) -> Bytes {
+        Bytes::new()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<BeginTransaction, IggyError> {
+        if !bytes.is_empty() {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        Ok(BeginTransaction {})
+    }
+}
+
+impl Display for BeginTransaction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_empty_bytes() {
+        let command = BeginTransaction {};
+        let bytes = command.to_bytes();
+        assert!(bytes.is_empty());
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_non_empty_bytes() {
+        let command = BeginTransaction::from_bytes(Bytes::from_static(&[0]));
+        assert!(command.is_err());
+    }
+}
@@ -0,0 +1,98 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, COMMIT_TRANSACTION_CODE};
+use crate::error::IggyError;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `CommitTransaction` command commits the open transaction, making all the messages sent within it visible to the read-committed consumers at once.
+/// It has additional payload:
+/// - `transaction_id` - unique transaction ID returned by the `BeginTransaction` command.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct CommitTransaction {
+    /// Unique transaction ID returned by the `BeginTransaction` command.
+    #[serde(skip)]
+    pub transaction_id: u64,
+}
+
+impl Command for CommitTransaction {
+    fn code(&self) -> u32 {
+        COMMIT_TRANSACTION_CODE
+    }
+}
+
+impl Validatable<IggyError> for CommitTransaction {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for CommitTransaction {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(8);
+        bytes.put_u64_le(self.transaction_id);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<CommitTransaction, IggyError> {
+        if bytes.len() != 8 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let transaction_id = u64::from_le_bytes(
+            bytes[..8]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        Ok(CommitTransaction { transaction_id })
+    }
+}
+
+impl Display for CommitTransaction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.transaction_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_and_deserialized() {
+        let command = CommitTransaction {
+            transaction_id: 1 << 32,
+        };
+
+        let bytes = command.to_bytes();
+        let deserialized = CommitTransaction::from_bytes(bytes).unwrap();
+
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_given_invalid_length() {
+        let result = CommitTransaction::from_bytes(Bytes::from_static(&[1, 2, 3]));
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,230 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, CREATE_PUSH_SUBSCRIPTION_CODE};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::utils::sizeable::Sizeable;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+const DEFAULT_BATCH_SIZE: u32 = 100;
+const MAX_BATCH_SIZE: u32 = 10_000;
+const MAX_ENDPOINT_LENGTH: usize = 2048;
+
+/// `CreatePushSubscription` command is used to register the external HTTP endpoint to which the server pushes the messages from the partition.
+/// The server polls the partition on behalf of the user and sends the batches of messages with the POST request, retrying the failed deliveries with the backoff.
+/// It has additional payload:
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `partition_id` - partition ID from which the messages will be pushed.
+/// - `endpoint` - HTTP(S) URL to which the batches of messages will be sent.
+/// - `start_offset` - offset of the first message to be pushed.
+/// - `batch_size` - maximum number of messages sent in a single request.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct CreatePushSubscription {
+    /// Unique stream ID (numeric or name).
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    pub topic_id: Identifier,
+    /// Partition ID from which the messages will be pushed.
+    pub partition_id: u32,
+    /// HTTP(S) URL to which the batches of messages will be sent.
+    pub endpoint: String,
+    /// Offset of the first message to be pushed.
+    #[serde(default)]
+    pub start_offset: u64,
+    /// Maximum number of messages sent in a single request.
+    #[serde(default = "default_batch_size")]
+    pub batch_size: u32,
+}
+
+impl Default for CreatePushSubscription {
+    fn default() -> Self {
+        Self {
+            stream_id: Identifier::default(),
+            topic_id: Identifier::default(),
+            partition_id: 1,
+            endpoint: "https://localhost/messages".to_string(),
+            start_offset: 0,
+            batch_size: default_batch_size(),
+        }
+    }
+}
+
+fn default_batch_size() -> u32 {
+    DEFAULT_BATCH_SIZE
+}
+
+impl Command for CreatePushSubscription {
+    fn code(&self) -> u32 {
+        CREATE_PUSH_SUBSCRIPTION_CODE
+    }
+}
+
+impl Validatable<IggyError> for CreatePushSubscription {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.endpoint.is_empty() || self.endpoint.len() > MAX_ENDPOINT_LENGTH {
+            return Err(IggyError::InvalidPushSubscriptionEndpoint);
+        }
+
+        if !self.endpoint.starts_with("https://") && !self.endpoint.starts_with("http://") {
+            return Err(IggyError::InvalidPushSubscriptionEndpoint);
+        }
+
+        if self.batch_size == 0 || self.batch_size > MAX_BATCH_SIZE {
+            return Err(IggyError::InvalidMessagesCount);
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for CreatePushSubscription {
+    fn to_bytes(&self) -> Bytes {
+        let stream_id_bytes = self.stream_id.to_bytes();
+        let topic_id_bytes = self.topic_id.to_bytes();
+        let mut bytes = BytesMut::with_capacity(
+            stream_id_bytes.len() + topic_id_bytes.len() + 4 + 8 + 4 + 4 + self.endpoint.len(),
+        );
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        bytes.put_u32_le(self.partition_id);
+        bytes.put_u64_le(self.start_offset);
+        bytes.put_u32_le(self.batch_size);
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u32_le(self.endpoint.len() as u32);
+        bytes.put_slice(self.endpoint.as_bytes());
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<CreatePushSubscription, IggyError> {
+        if bytes.len() < 26 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone())?;
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        let partition_id = read_u32(&bytes, position)?;
+        position += 4;
+        let start_offset = bytes
+            .get(position..position + 8)
+            .ok_or(IggyError::InvalidCommand)?
+            .try_into()
+            .map(u64::from_le_bytes)
+            .map_err(|_| IggyError::InvalidNumberEncoding)?;
+        position += 8;
+        let batch_size = read_u32(&bytes, position)?;
+        position += 4;
+        let endpoint_length = read_u32(&bytes, position)? as usize;
+        position += 4;
+        if bytes.len() != position + endpoint_length {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let endpoint = std::str::from_utf8(&bytes[position..])
+            .map_err(|_| IggyError::InvalidPushSubscriptionEndpoint)?
+            .to_string();
+        Ok(CreatePushSubscription {
+            stream_id,
+            topic_id,
+            partition_id,
+            endpoint,
+            start_offset,
+            batch_size,
+        })
+    }
+}
+
+fn read_u32(bytes: &Bytes, position: usize) -> Result<u32, IggyError> {
+    bytes
+        .get(position..position + 4)
+        .ok_or(IggyError::InvalidCommand)?
+        .try_into()
+        .map(u32::from_le_bytes)
+        .map_err(|_| IggyError::InvalidNumberEncoding)
+}
+
+impl Display for CreatePushSubscription {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}|{}|{}",
+            self.stream_id,
+            self.topic_id,
+            self.partition_id,
+            self.endpoint,
+            self.start_offset,
+            self.batch_size
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn command() -> CreatePushSubscription {
+        CreatePushSubscription {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::named("orders").unwrap(),
+            partition_id: 2,
+            endpoint: "https://example.com/webhooks/orders".to_string(),
+            start_offset: 100,
+            batch_size: 50,
+        }
+    }
+
+    #[test]
+    fn should_be_serialized_and_deserialized() {
+        let command = command();
+        let deserialized = CreatePushSubscription::from_bytes(command.to_bytes()).unwrap();
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_truncated_bytes() {
+        let bytes = command().to_bytes();
+        let command = CreatePushSubscription::from_bytes(bytes.slice(..bytes.len() - 3));
+        assert!(command.is_err());
+    }
+
+    #[test]
+    fn should_not_be_valid_for_endpoint_without_http_scheme() {
+        let command = CreatePushSubscription {
+            endpoint: "ftp://example.com/orders".to_string(),
+            ..command()
+        };
+        assert!(command.validate().is_err());
+    }
+
+    #[test]
+    fn should_not_be_valid_for_empty_batch() {
+        let command = CreatePushSubscription {
+            batch_size: 0,
+            ..command()
+        };
+        assert!(command.validate().is_err());
+    }
+}
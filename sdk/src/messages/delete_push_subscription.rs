@@ -0,0 +1,96 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, DELETE_PUSH_SUBSCRIPTION_CODE};
+use crate::error::IggyError;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `DeletePushSubscription` command is used to stop pushing the messages to the endpoint and remove the push subscription.
+/// The batch which is being delivered at the moment may still reach the endpoint.
+/// It has additional payload:
+/// - `subscription_id` - unique push subscription ID.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct DeletePushSubscription {
+    /// Unique push subscription ID.
+    #[serde(skip)]
+    pub subscription_id: u32,
+}
+
+impl Command for DeletePushSubscription {
+    fn code(&self) -> u32 {
+        DELETE_PUSH_SUBSCRIPTION_CODE
+    }
+}
+
+impl Validatable<IggyError> for DeletePushSubscription {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for DeletePushSubscription {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(4);
+        bytes.put_u32_le(self.subscription_id);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<DeletePushSubscription, IggyError> {
+        if bytes.len() != 4 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let subscription_id = u32::from_le_bytes(
+            bytes[..4]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        Ok(DeletePushSubscription { subscription_id })
+    }
+}
+
+impl Display for DeletePushSubscription {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.subscription_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_and_deserialized() {
+        let command = DeletePushSubscription { subscription_id: 7 };
+        let bytes = command.to_bytes();
+        assert_eq!(bytes.len(), 4);
+
+        let deserialized = DeletePushSubscription::from_bytes(bytes).unwrap();
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_invalid_bytes() {
+        let command = DeletePushSubscription::from_bytes(Bytes::from_static(&[1, 2]));
+        assert!(command.is_err());
+    }
+}
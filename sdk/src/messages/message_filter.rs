@@ -0,0 +1,334 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::error::IggyError;
+use crate::models::header::HeaderKey;
+use crate::models::messages::PolledMessage;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// The filter expression evaluated by the server when polling the messages, so that only the matching ones are returned.
+/// The expression consists of the predicates joined with `and`, all of which must match:
+/// - `header.<key> == <value>` - the header value is equal to the given one.
+/// - `header.<key> contains <value>` - the header value contains the given one.
+/// - `header.<key> exists` - the header is present.
+/// - `id in <from>..<to>` - the message ID is within the inclusive range.
+///
+/// The header values are compared by their string representation.
+/// The values containing whitespace can be enclosed in double quotes, with `\` escaping the next character.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MessageFilter {
+    predicates: Vec<MessagePredicate>,
+}
+
+/// The single predicate of the `MessageFilter`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MessagePredicate {
+    /// The header value is equal to the given one.
+    HeaderEquals(HeaderKey, String),
+    /// The header value contains the given one.
+    HeaderContains(HeaderKey, String),
+    /// The header is present.
+    HeaderExists(HeaderKey),
+    /// The message ID is within the inclusive range.
+    IdRange(u128, u128),
+}
+
+impl MessageFilter {
+    pub fn new(predicates: Vec<MessagePredicate>) -> Result<Self, IggyError> {
+        if predicates.is_empty() {
+            return Err(IggyError::InvalidMessageFilter(
+                "at least one predicate is required".to_owned(),
+            ));
+        }
+
+        Ok(Self { predicates })
+    }
+
+    pub fn predicates(&self) -> &[MessagePredicate] {
+        &self.predicates
+    }
+
+    /// Returns `true` if the message matches all the predicates.
+    pub fn matches(&self, message: &PolledMessage) -> bool {
+        self.predicates
+            .iter()
+            .all(|predicate| predicate.matches(message))
+    }
+}
+
+impl MessagePredicate {
+    /// Returns `true` if the message matches the predicate.
+    pub fn matches(&self, message: &PolledMessage) -> bool {
+        match self {
+            MessagePredicate::HeaderEquals(key, value) => {
+                header_value(message, key).is_some_and(|header| header == *value)
+            }
+            MessagePredicate::HeaderContains(key, value) => {
+                header_value(message, key).is_some_and(|header| header.contains(value.as_str()))
+            }
+            MessagePredicate::HeaderExists(key) => message
+                .headers
+                .as_ref()
+                .is_some_and(|headers| headers.contains_key(key)),
+            MessagePredicate::IdRange(from, to) => (*from..=*to).contains(&message.id),
+        }
+    }
+}
+
+fn header_value(message: &PolledMessage, key: &HeaderKey) -> Option<String> {
+    message
+        .headers
+        .as_ref()?
+        .get(key)
+        .map(|value| value.value_only_to_string())
+}
+
+impl FromStr for MessageFilter {
+    type Err = IggyError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let tokens = tokenize(input)?;
+        let mut tokens = tokens.iter().map(String::as_str);
+        let mut predicates = Vec::new();
+        loop {
+            predicates.push(parse_predicate(&mut tokens)?);
+            match tokens.next() {
+                None => break,
+                Some(token) if token.eq_ignore_ascii_case("and") => continue,
+                Some(token) => {
+                    return Err(IggyError::InvalidMessageFilter(format!(
+                        "expected 'and', found '{token}'"
+                    )))
+                }
+            }
+        }
+        Self::new(predicates)
+    }
+}
+
+fn parse_predicate<'a>(
+    tokens: &mut impl Iterator<Item = &'a str>,
+) -> Result<MessagePredicate, IggyError> {
+    let subject = next_token(tokens, "subject")?;
+    if subject.eq_ignore_ascii_case("id") {
+        let operator = next_token(tokens, "operator")?;
+        if !operator.eq_ignore_ascii_case("in") {
+            return Err(IggyError::InvalidMessageFilter(format!(
+                "unknown operator: '{operator}' for the message ID"
+            )));
+        }
+
+        let range = next_token(tokens, "ID range")?;
+        let invalid_range =
+            || IggyError::InvalidMessageFilter(format!("invalid ID range: '{range}'"));
+        let (from, to) = range.split_once("..").ok_or_else(invalid_range)?;
+        let from = from.parse::<u128>().map_err(|_| invalid_range())?;
+        let to = to.parse::<u128>().map_err(|_| invalid_range())?;
+        if from > to {
+            return Err(invalid_range());
+        }
+
+        return Ok(MessagePredicate::IdRange(from, to));
+    }
+
+    let Some(key) = subject.strip_prefix("header.") else {
+        return Err(IggyError::InvalidMessageFilter(format!(
+            "unknown subject: '{subject}'"
+        )));
+    };
+    let key = HeaderKey::new(key)
+        .map_err(|_| IggyError::InvalidMessageFilter(format!("invalid header key: '{key}'")))?;
+    let operator = next_token(tokens, "operator")?;
+    match operator.to_ascii_lowercase().as_str() {
+        "==" => Ok(MessagePredicate::HeaderEquals(
+            key,
+            next_token(tokens, "value")?.to_owned(),
+        )),
+        "contains" => Ok(MessagePredicate::HeaderContains(
+            key,
+            next_token(tokens, "value")?.to_owned(),
+        )),
+        "exists" => Ok(MessagePredicate::HeaderExists(key)),
+        _ => Err(IggyError::InvalidMessageFilter(format!(
+            "unknown operator: '{operator}' for the header"
+        ))),
+    }
+}
+
+fn next_token<'a>(
+    tokens: &mut impl Iterator<Item = &'a str>,
+    expected: &str,
+) -> Result<&'a str, IggyError> {
+    tokens
+        .next()
+        .ok_or_else(|| IggyError::InvalidMessageFilter(format!("missing {expected}")))
+}
+
+fn tokenize(input: &str) -> Result<Vec<String>, IggyError> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+    while let Some(char) = chars.next() {
+        if char.is_whitespace() {
+            continue;
+        }
+
+        let mut token = String::new();
+        if char == '"' {
+            loop {
+                match chars.next() {
+                    Some('"') => break,
+                    Some('\\') => match chars.next() {
+                        Some(escaped) => token.push(escaped),
+                        None => break,
+                    },
+                    Some(char) => token.push(char),
+                    None => {
+                        return Err(IggyError::InvalidMessageFilter(
+                            "unterminated quoted value".to_owned(),
+                        ))
+                    }
+                }
+            }
+        } else {
+            token.push(char);
+            while let Some(char) = chars.next_if(|char| !char.is_whitespace()) {
+                token.push(char);
+            }
+        }
+        tokens.push(token);
+    }
+    Ok(tokens)
+}
+
+impl Display for MessageFilter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (index, predicate) in self.predicates.iter().enumerate() {
+            if index > 0 {
+                write!(f, " and ")?;
+            }
+            write!(f, "{predicate}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for MessagePredicate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MessagePredicate::HeaderEquals(key, value) => {
+                write!(f, "header.{key} == {}", quote(value))
+            }
+            MessagePredicate::HeaderContains(key, value) => {
+                write!(f, "header.{key} contains {}", quote(value))
+            }
+            MessagePredicate::HeaderExists(key) => write!(f, "header.{key} exists"),
+            MessagePredicate::IdRange(from, to) => write!(f, "id in {from}..{to}"),
+        }
+    }
+}
+
+fn quote(value: &str) -> String {
+    if !value.is_empty()
+        && !value
+            .chars()
+            .any(|char| char.is_whitespace() || char == '"' || char == '\\')
+    {
+        return value.to_owned();
+    }
+
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::header::HeaderValue;
+    use crate::models::messages::MessageState;
+    use bytes::Bytes;
+    use std::collections::HashMap;
+
+    fn message(id: u128, headers: &[(&str, &str)]) -> PolledMessage {
+        let headers = headers
+            .iter()
+            .map(|(key, value)| {
+                (
+                    HeaderKey::new(key).unwrap(),
+                    HeaderValue::from_str(value).unwrap(),
+                )
+            })
+            .collect::<HashMap<_, _>>();
+        PolledMessage::create(
+            0,
+            MessageState::Available,
+            0.into(),
+            id,
+            Bytes::new(),
+            0,
+            Some(headers),
+        )
+    }
+
+    #[test]
+    fn should_parse_and_display_filter_expression() {
+        let filter = MessageFilter::from_str(
+            r#"header.region == "eu west" and header.tag exists and id in 1..10"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            filter.predicates(),
+            &[
+                MessagePredicate::HeaderEquals(
+                    HeaderKey::new("region").unwrap(),
+                    "eu west".to_owned()
+                ),
+                MessagePredicate::HeaderExists(HeaderKey::new("tag").unwrap()),
+                MessagePredicate::IdRange(1, 10),
+            ]
+        );
+        assert_eq!(
+            MessageFilter::from_str(&filter.to_string()).unwrap(),
+            filter
+        );
+    }
+
+    #[test]
+    fn should_not_parse_invalid_filter_expression() {
+        for input in [
+            "",
+            "header.region ==",
+            "header.region = eu",
+            "id in 10..1",
+            "payload contains x",
+            "header.region exists or header.tag exists",
+            "header.region == \"eu",
+        ] {
+            assert!(MessageFilter::from_str(input).is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn should_match_messages() {
+        let filter = MessageFilter::from_str("header.region contains eu and id in 1..10").unwrap();
+
+        assert!(filter.matches(&message(5, &[("region", "eu-west")])));
+        assert!(!filter.matches(&message(11, &[("region", "eu-west")])));
+        assert!(!filter.matches(&message(5, &[("region", "us-east")])));
+        assert!(!filter.matches(&message(5, &[])));
+    }
+}
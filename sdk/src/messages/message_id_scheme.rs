@@ -0,0 +1,118 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::error::IggyError;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// The scheme used by the server to assign the IDs to the messages sent with `id = 0`.
+/// The scheme is chosen when the topic is created and recorded in the topic metadata:
+/// - `ServerDefault`: use the scheme configured on the server (only valid when creating the topic).
+/// - `Uuid`: random UUID v7.
+/// - `Snowflake`: 64-bit time-sortable ID composed of the milliseconds timestamp, node ID and sequence.
+/// - `Ulid`: 128-bit time-sortable ULID.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MessageIdScheme {
+    #[default]
+    ServerDefault,
+    Uuid,
+    Snowflake,
+    Ulid,
+}
+
+impl MessageIdScheme {
+    pub fn as_code(&self) -> u8 {
+        match self {
+            MessageIdScheme::ServerDefault => 0,
+            MessageIdScheme::Uuid => 1,
+            MessageIdScheme::Snowflake => 2,
+            MessageIdScheme::Ulid => 3,
+        }
+    }
+
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            0 => Ok(MessageIdScheme::ServerDefault),
+            1 => Ok(MessageIdScheme::Uuid),
+            2 => Ok(MessageIdScheme::Snowflake),
+            3 => Ok(MessageIdScheme::Ulid),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+
+    /// Returns `true` if the IDs assigned with the scheme are sortable by the creation time.
+    pub fn is_time_sortable(&self) -> bool {
+        matches!(self, MessageIdScheme::Snowflake | MessageIdScheme::Ulid)
+    }
+}
+
+impl FromStr for MessageIdScheme {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "server_default" | "default" => Ok(MessageIdScheme::ServerDefault),
+            "uuid" => Ok(MessageIdScheme::Uuid),
+            "snowflake" => Ok(MessageIdScheme::Snowflake),
+            "ulid" => Ok(MessageIdScheme::Ulid),
+            _ => Err(format!("Unknown message ID scheme: {s}")),
+        }
+    }
+}
+
+impl Display for MessageIdScheme {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MessageIdScheme::ServerDefault => write!(f, "server_default"),
+            MessageIdScheme::Uuid => write!(f, "uuid"),
+            MessageIdScheme::Snowflake => write!(f, "snowflake"),
+            MessageIdScheme::Ulid => write!(f, "ulid"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scheme_should_be_mapped_from_code() {
+        for scheme in [
+            MessageIdScheme::ServerDefault,
+            MessageIdScheme::Uuid,
+            MessageIdScheme::Snowflake,
+            MessageIdScheme::Ulid,
+        ] {
+            assert_eq!(
+                MessageIdScheme::from_code(scheme.as_code()).unwrap(),
+                scheme
+            );
+            assert_eq!(
+                MessageIdScheme::from_str(&scheme.to_string()).unwrap(),
+                scheme
+            );
+        }
+    }
+
+    #[test]
+    fn unknown_code_should_fail() {
+        assert!(MessageIdScheme::from_code(4).is_err());
+    }
+}
@@ -16,9 +16,26 @@
  * under the License.
  */
 
+pub mod abort_transaction;
+pub mod ack_messages;
+pub mod aggregate_messages;
+pub mod begin_transaction;
+pub mod cancel_replay_job;
+pub mod commit_transaction;
+pub mod create_push_subscription;
+pub mod delete_push_subscription;
 pub mod flush_unsaved_buffer;
+pub mod get_push_subscriptions;
+pub mod get_replay_jobs;
+pub mod init_producer_id;
+pub mod message_filter;
+pub mod message_id_scheme;
+pub mod nack_messages;
 pub mod poll_messages;
+pub mod register_producer;
+pub mod replay_messages;
 pub mod send_messages;
+pub mod send_messages_batch;
 
 const MAX_HEADERS_SIZE: u32 = 100 * 1000;
 pub const MAX_PAYLOAD_SIZE: u32 = 10 * 1000 * 1000;
@@ -21,6 +21,7 @@ use crate::command::{Command, POLL_MESSAGES_CODE};
 use crate::consumer::{Consumer, ConsumerKind};
 use crate::error::IggyError;
 use crate::identifier::Identifier;
+use crate::messages::message_filter::MessageFilter;
 use crate::utils::sizeable::Sizeable;
 use crate::utils::timestamp::IggyTimestamp;
 use crate::validatable::Validatable;
@@ -39,6 +40,12 @@ use std::str::FromStr;
 /// - `strategy` - polling strategy which specifies from where to start polling messages.
 /// - `count` - number of messages to poll.
 /// - `auto_commit` - whether to commit offset on the server automatically after polling the messages.
+/// - `isolation_level` - whether to return the messages sent within the transactions which have not been committed yet.
+/// - `chunk_size` - preferred number of messages in each chunk of the response, `0` to disable the chunking.
+/// - `filter` - optional filter expression, only the matching messages are returned.
+/// - `partition_epoch` - optional epoch of the partition expected by the consumer, `0` to only receive the current one.
+/// - `peek` - whether to only browse the messages, without committing the offset or affecting the delivery state of the consumer group.
+#[serde_as]
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct PollMessages {
     /// Consumer which will poll messages. Either regular consumer or consumer group.
@@ -62,6 +69,48 @@ pub struct PollMessages {
     #[serde(default)]
     /// Whether to commit offset on the server automatically after polling the messages.
     pub auto_commit: bool,
+    #[serde(default)]
+    /// Whether to return the messages sent within the transactions which have not been committed yet.
+    pub isolation_level: IsolationLevel,
+    #[serde(default)]
+    /// Preferred number of messages in each chunk of the response, `0` to disable the chunking.
+    /// The count is rounded up to the multiple of it, and the server splits or coalesces the stored batches,
+    /// so that every chunk has this size, except the last one when there are not enough messages in the partition.
+    pub chunk_size: u32,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    /// Optional filter expression evaluated by the server, only the matching messages are returned.
+    /// The skipped messages are reported as the filtered gaps, and they're committed as well with `auto_commit`.
+    pub filter: Option<MessageFilter>,
+    #[serde(default)]
+    /// Epoch of the partition expected by the consumer, which is changed when the partition is purged and its offsets start over.
+    /// If it doesn't match, the `PartitionEpochChanged` error is returned instead of the messages with the recycled offsets.
+    /// The epochs start from `1`, so `Some(0)` only requests the current epoch to be returned along with the messages.
+    pub partition_epoch: Option<u64>,
+    #[serde(default)]
+    /// Whether to only browse the messages, e.g. by the monitoring or debugging tools, without perturbing the delivery state.
+    /// The offset is never committed, and for the consumer group, the partition of the member isn't moved to the next one
+    /// (the `partition_id` is required if the client isn't a member), nor are the messages tracked as in-flight or dead-lettered.
+    pub peek: bool,
+}
+
+/// The optional settings of the messages polled with `MessageClient::poll_messages_with_options`.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct PollMessagesOptions {
+    /// Whether to return the messages sent within the transactions which have not been committed yet.
+    /// With `IsolationLevel::ReadCommitted`, the polling stops before the first message of the oldest open transaction in the partition.
+    pub isolation_level: IsolationLevel,
+    /// Preferred number of messages in each chunk of the response, `0` to disable the chunking.
+    /// The count is rounded up to the multiple of it, and every chunk has this size,
+    /// except the last one when there are not enough messages in the partition.
+    pub chunk_size: u32,
+    /// Optional filter expression evaluated by the server, only the matching messages are returned.
+    /// The skipped messages are reported as the filtered gaps, and they're committed as well with `auto_commit`.
+    pub filter: Option<MessageFilter>,
+    /// Epoch of the partition expected by the consumer, which is changed when the partition is purged and its offsets start over.
+    /// If it doesn't match, the `PartitionEpochChanged` error is returned, and the consumer has to reset its offsets.
+    /// `Some(0)` only requests the current epoch to be returned along with the messages, e.g. when the partition is assigned by the consumer group.
+    pub partition_epoch: Option<u64>,
 }
 
 /// `PollingStrategy` specifies from where to start polling messages.
@@ -101,6 +150,18 @@ pub enum PollingKind {
     Next,
 }
 
+/// `IsolationLevel` specifies which of the messages sent within the transactions are returned when polling.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Copy, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum IsolationLevel {
+    #[default]
+    /// Return all the appended messages, including the ones sent within the open or aborted transactions.
+    ReadUncommitted,
+    /// Return only the messages sent outside of the transactions or within the committed ones.
+    /// The polling stops before the first message of the oldest open transaction, and the messages of the aborted transactions are skipped.
+    ReadCommitted,
+}
+
 impl Default for PollMessages {
     fn default() -> Self {
         Self {
@@ -111,6 +172,11 @@ impl Default for PollMessages {
             strategy: default_strategy(),
             count: default_count(),
             auto_commit: false,
+            isolation_level: IsolationLevel::default(),
+            chunk_size: 0,
+            filter: None,
+            partition_epoch: None,
+            peek: false,
         }
     }
 }
@@ -152,6 +218,10 @@ fn default_count() -> u32 {
 
 impl Validatable<IggyError> for PollMessages {
     fn validate(&self) -> Result<(), IggyError> {
+        if self.peek && self.auto_commit {
+            return Err(IggyError::InvalidCommand);
+        }
+
         Ok(())
     }
 }
@@ -256,6 +326,45 @@ impl Display for PollingKind {
     }
 }
 
+impl IsolationLevel {
+    /// Returns code of the isolation level.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            IsolationLevel::ReadUncommitted => 1,
+            IsolationLevel::ReadCommitted => 2,
+        }
+    }
+
+    /// Returns isolation level from the specified code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(IsolationLevel::ReadUncommitted),
+            2 => Ok(IsolationLevel::ReadCommitted),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+impl FromStr for IsolationLevel {
+    type Err = IggyError;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "u" | "read_uncommitted" => Ok(IsolationLevel::ReadUncommitted),
+            "c" | "read_committed" => Ok(IsolationLevel::ReadCommitted),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+impl Display for IsolationLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IsolationLevel::ReadUncommitted => write!(f, "read_uncommitted"),
+            IsolationLevel::ReadCommitted => write!(f, "read_committed"),
+        }
+    }
+}
+
 impl BytesSerializable for PollMessages {
     fn to_bytes(&self) -> Bytes {
         as_bytes(
@@ -266,6 +375,11 @@ impl BytesSerializable for PollMessages {
             &self.strategy,
             self.count,
             self.auto_commit,
+            self.isolation_level,
+            self.chunk_size,
+            self.filter.as_ref(),
+            self.partition_epoch,
+            self.peek,
         )
     }
 
@@ -313,6 +427,47 @@ impl BytesSerializable for PollMessages {
         );
         let auto_commit = bytes[position + 12];
         let auto_commit = matches!(auto_commit, 1);
+        // The isolation level is optional, so that the commands sent by the older clients can still be read.
+        let isolation_level = match bytes.get(position + 13) {
+            Some(code) => IsolationLevel::from_code(*code)?,
+            None => IsolationLevel::default(),
+        };
+        let chunk_size = match bytes.get(position + 14..position + 18) {
+            Some(chunk_size) => u32::from_le_bytes(
+                chunk_size
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            ),
+            None => 0,
+        };
+        let filter_length = match bytes.get(position + 18..position + 22) {
+            Some(filter_length) => u32::from_le_bytes(
+                filter_length
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            ) as usize,
+            None => 0,
+        };
+        let filter = match filter_length {
+            0 => None,
+            filter_length => {
+                let filter = bytes
+                    .get(position + 22..position + 22 + filter_length)
+                    .ok_or(IggyError::InvalidCommand)?;
+                let filter = std::str::from_utf8(filter).map_err(|_| IggyError::InvalidUtf8)?;
+                Some(MessageFilter::from_str(filter)?)
+            }
+        };
+        position += 22 + filter_length;
+        let partition_epoch = match bytes.get(position..position + 8) {
+            Some(partition_epoch) => Some(u64::from_le_bytes(
+                partition_epoch
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            )),
+            None => None,
+        };
+        let peek = matches!(bytes.get(position + 8), Some(1));
         let command = PollMessages {
             consumer,
             stream_id,
@@ -321,12 +476,18 @@ impl BytesSerializable for PollMessages {
             strategy,
             count,
             auto_commit,
+            isolation_level,
+            chunk_size,
+            filter,
+            partition_epoch,
+            peek,
         };
         Ok(command)
     }
 }
 
 // This method is used by the new version of `IggyClient` to serialize `PollMessages` without cloning the args.
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn as_bytes(
     stream_id: &Identifier,
     topic_id: &Identifier,
@@ -335,13 +496,20 @@ pub(crate) fn as_bytes(
     strategy: &PollingStrategy,
     count: u32,
     auto_commit: bool,
+    isolation_level: IsolationLevel,
+    chunk_size: u32,
+    filter: Option<&MessageFilter>,
+    partition_epoch: Option<u64>,
+    peek: bool,
 ) -> Bytes {
     let consumer_bytes = consumer.to_bytes();
     let stream_id_bytes = stream_id.to_bytes();
     let topic_id_bytes = topic_id.to_bytes();
     let strategy_bytes = strategy.to_bytes();
+    let filter = filter.map(|filter| filter.to_string()).unwrap_or_default();
     let mut bytes = BytesMut::with_capacity(
-        9 + consumer_bytes.len()
+        26 + filter.len()
+            + consumer_bytes.len()
             + stream_id_bytes.len()
             + topic_id_bytes.len()
             + strategy_bytes.len(),
@@ -361,6 +529,18 @@ pub(crate) fn as_bytes(
     } else {
         bytes.put_u8(0);
     }
+    bytes.put_u8(isolation_level.as_code());
+    bytes.put_u32_le(chunk_size);
+    bytes.put_u32_le(filter.len() as u32);
+    bytes.put_slice(filter.as_bytes());
+    // The partition epoch is optional, so that the older servers can still read the command.
+    // It precedes the peek flag, so it's always present in the peek mode, `0` to only receive the current one.
+    if partition_epoch.is_some() || peek {
+        bytes.put_u64_le(partition_epoch.unwrap_or_default());
+    }
+    if peek {
+        bytes.put_u8(1);
+    }
 
     bytes.freeze()
 }
@@ -369,14 +549,24 @@ impl Display for PollMessages {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{}|{}|{}|{}|{}|{}|{}",
+            "{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}",
             self.consumer,
             self.stream_id,
             self.topic_id,
             self.partition_id.unwrap_or(0),
             self.strategy,
             self.count,
-            auto_commit_to_string(self.auto_commit)
+            auto_commit_to_string(self.auto_commit),
+            self.isolation_level,
+            self.chunk_size,
+            self.filter
+                .as_ref()
+                .map(|filter| filter.to_string())
+                .unwrap_or_default(),
+            self.partition_epoch
+                .map(|partition_epoch| partition_epoch.to_string())
+                .unwrap_or_default(),
+            self.peek
         )
     }
 }
@@ -433,6 +623,11 @@ mod tests {
             strategy: PollingStrategy::offset(2),
             count: 3,
             auto_commit: true,
+            isolation_level: IsolationLevel::ReadCommitted,
+            chunk_size: 5,
+            filter: None,
+            partition_epoch: None,
+            peek: false,
         };
 
         let bytes = command.to_bytes();
@@ -459,6 +654,9 @@ mod tests {
         let count = u32::from_le_bytes(bytes[position + 8..position + 12].try_into().unwrap());
         let auto_commit = bytes[position + 12];
         let auto_commit = matches!(auto_commit, 1);
+        let isolation_level = IsolationLevel::from_code(bytes[position + 13]).unwrap();
+        let chunk_size =
+            u32::from_le_bytes(bytes[position + 14..position + 18].try_into().unwrap());
 
         assert!(!bytes.is_empty());
         assert_eq!(consumer, command.consumer);
@@ -468,6 +666,8 @@ mod tests {
         assert_eq!(strategy, command.strategy);
         assert_eq!(count, command.count);
         assert_eq!(auto_commit, command.auto_commit);
+        assert_eq!(isolation_level, command.isolation_level);
+        assert_eq!(chunk_size, command.chunk_size);
     }
 
     #[test]
@@ -511,5 +711,85 @@ mod tests {
         assert_eq!(command.strategy, strategy);
         assert_eq!(command.count, count);
         assert_eq!(command.auto_commit, auto_commit);
+        assert_eq!(command.isolation_level, IsolationLevel::ReadUncommitted);
+        assert_eq!(command.chunk_size, 0);
+    }
+
+    #[test]
+    fn should_be_deserialized_with_isolation_level() {
+        let command = PollMessages {
+            isolation_level: IsolationLevel::ReadCommitted,
+            ..PollMessages::default()
+        };
+
+        let deserialized = PollMessages::from_bytes(command.to_bytes()).unwrap();
+
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_be_deserialized_with_filter() {
+        let command = PollMessages {
+            chunk_size: 10,
+            filter: Some(MessageFilter::from_str("header.region == eu and id in 1..100").unwrap()),
+            ..PollMessages::default()
+        };
+
+        let deserialized = PollMessages::from_bytes(command.to_bytes()).unwrap();
+
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_be_deserialized_with_partition_epoch() {
+        let command = PollMessages {
+            filter: Some(MessageFilter::from_str("id in 1..100").unwrap()),
+            partition_epoch: Some(3),
+            ..PollMessages::default()
+        };
+
+        let deserialized = PollMessages::from_bytes(command.to_bytes()).unwrap();
+
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_be_deserialized_with_peek() {
+        let command = PollMessages {
+            partition_id: Some(2),
+            strategy: PollingStrategy::next(),
+            peek: true,
+            ..PollMessages::default()
+        };
+
+        let deserialized = PollMessages::from_bytes(command.to_bytes()).unwrap();
+
+        assert!(deserialized.peek);
+        assert_eq!(deserialized.partition_epoch, Some(0));
+        assert_eq!(deserialized.strategy, command.strategy);
+    }
+
+    #[test]
+    fn should_not_be_valid_given_peek_with_auto_commit() {
+        let command = PollMessages {
+            auto_commit: true,
+            peek: true,
+            ..PollMessages::default()
+        };
+
+        assert!(command.validate().is_err());
+    }
+
+    #[test]
+    fn should_be_deserialized_with_chunk_size() {
+        let command = PollMessages {
+            isolation_level: IsolationLevel::ReadCommitted,
+            chunk_size: 100,
+            ..PollMessages::default()
+        };
+
+        let deserialized = PollMessages::from_bytes(command.to_bytes()).unwrap();
+
+        assert_eq!(deserialized, command);
     }
 }
@@ -0,0 +1,162 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, REGISTER_PRODUCER_CODE};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::utils::sizeable::Sizeable;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::from_utf8;
+
+const MAX_NAME_LENGTH: usize = 255;
+
+/// `RegisterProducer` command registers the new instance of the named producer for the topic.
+/// Each registration bumps the producer epoch, and the messages sent with any older epoch of the same producer are rejected,
+/// so that the zombie instances (e.g. left behind by the rolling update) cannot append the duplicated messages anymore.
+/// It has additional payload:
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `name` - unique name of the producer within the topic, max length is 255 characters.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct RegisterProducer {
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    /// Unique name of the producer within the topic, max length is 255 characters.
+    pub name: String,
+}
+
+impl Default for RegisterProducer {
+    fn default() -> Self {
+        RegisterProducer {
+            stream_id: Identifier::default(),
+            topic_id: Identifier::default(),
+            name: "producer".to_string(),
+        }
+    }
+}
+
+impl Command for RegisterProducer {
+    fn code(&self) -> u32 {
+        REGISTER_PRODUCER_CODE
+    }
+}
+
+impl Validatable<IggyError> for RegisterProducer {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.name.is_empty() || self.name.len() > MAX_NAME_LENGTH {
+            return Err(IggyError::InvalidProducerName);
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for RegisterProducer {
+    fn to_bytes(&self) -> Bytes {
+        let stream_id_bytes = self.stream_id.to_bytes();
+        let topic_id_bytes = self.topic_id.to_bytes();
+        let mut bytes = BytesMut::with_capacity(
+            1 + stream_id_bytes.len() + topic_id_bytes.len() + self.name.len(),
+        );
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(self.name.len() as u8);
+        bytes.put_slice(self.name.as_bytes());
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<RegisterProducer, IggyError> {
+        if bytes.len() < 8 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone())?;
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        let name_length = *bytes.get(position).ok_or(IggyError::InvalidCommand)? as usize;
+        position += 1;
+        if bytes.len() != position + name_length {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let name = from_utf8(&bytes[position..])
+            .map_err(|_| IggyError::InvalidUtf8)?
+            .to_string();
+        Ok(RegisterProducer {
+            stream_id,
+            topic_id,
+            name,
+        })
+    }
+}
+
+impl Display for RegisterProducer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}|{}|{}", self.stream_id, self.topic_id, self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_and_deserialized() {
+        let command = RegisterProducer {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::named("orders").unwrap(),
+            name: "orders-writer".to_string(),
+        };
+
+        let bytes = command.to_bytes();
+        let deserialized = RegisterProducer::from_bytes(bytes).unwrap();
+
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_given_invalid_name_length() {
+        let command = RegisterProducer::default();
+        let bytes = command.to_bytes();
+
+        let result = RegisterProducer::from_bytes(bytes.slice(..bytes.len() - 1));
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_not_be_valid_given_empty_name() {
+        let command = RegisterProducer {
+            name: "".to_string(),
+            ..RegisterProducer::default()
+        };
+
+        assert!(command.validate().is_err());
+    }
+}
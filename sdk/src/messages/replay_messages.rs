@@ -0,0 +1,520 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, REPLAY_MESSAGES_CODE};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::messages::send_messages::Partitioning;
+use crate::models::header::{HeaderKey, HeaderValue};
+use crate::utils::sizeable::Sizeable;
+use crate::utils::timestamp::IggyTimestamp;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::str::FromStr;
+
+const DEFAULT_BATCH_SIZE: u32 = 1000;
+const MAX_BATCH_SIZE: u32 = 100_000;
+
+/// `ReplayMessages` command is used to re-publish a range of messages from the source partition into the destination topic.
+/// The replay is executed by the server in the background as a job, which progress can be tracked with `GetReplayJobs` command.
+/// It has additional payload:
+/// - `source_stream_id` - unique source stream ID (numeric or name).
+/// - `source_topic_id` - unique source topic ID (numeric or name).
+/// - `source_partition_id` - partition ID from which the messages will be replayed.
+/// - `range` - offset or timestamp range of the messages to replay.
+/// - `destination_stream_id` - unique destination stream ID (numeric or name).
+/// - `destination_topic_id` - unique destination topic ID (numeric or name).
+/// - `partitioning` - to which partition of the destination topic the messages should be sent.
+/// - `headers` - transformation applied to the headers of each replayed message.
+/// - `messages_per_second` - maximum replay rate, `0` means unlimited.
+/// - `batch_size` - number of messages read from the source and appended to the destination at once.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct ReplayMessages {
+    /// Unique source stream ID (numeric or name).
+    pub source_stream_id: Identifier,
+    /// Unique source topic ID (numeric or name).
+    pub source_topic_id: Identifier,
+    /// Partition ID from which the messages will be replayed.
+    pub source_partition_id: u32,
+    /// Offset or timestamp range of the messages to replay.
+    pub range: ReplayRange,
+    /// Unique destination stream ID (numeric or name).
+    pub destination_stream_id: Identifier,
+    /// Unique destination topic ID (numeric or name).
+    pub destination_topic_id: Identifier,
+    /// To which partition of the destination topic the messages should be sent.
+    #[serde(default)]
+    pub partitioning: Partitioning,
+    /// Transformation applied to the headers of each replayed message.
+    #[serde(default)]
+    pub headers: HeadersTransform,
+    /// Maximum replay rate, `0` means unlimited.
+    #[serde(default)]
+    pub messages_per_second: u32,
+    /// Number of messages read from the source and appended to the destination at once.
+    #[serde(default = "default_batch_size")]
+    pub batch_size: u32,
+}
+
+/// `ReplayRange` specifies which messages of the source partition should be replayed.
+/// Both `start` and `end` are inclusive.
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Copy, Clone)]
+pub struct ReplayRange {
+    /// Kind of the replay range.
+    #[serde(default)]
+    pub kind: ReplayRangeKind,
+    /// Start of the range (offset or timestamp in microseconds).
+    #[serde_as(as = "DisplayFromStr")]
+    pub start: u64,
+    /// End of the range (offset or timestamp in microseconds).
+    #[serde_as(as = "DisplayFromStr")]
+    pub end: u64,
+}
+
+/// `ReplayRangeKind` specifies how the `ReplayRange` boundaries are interpreted.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Copy, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum ReplayRangeKind {
+    /// The range boundaries are message offsets.
+    #[default]
+    Offset,
+    /// The range boundaries are message timestamps.
+    Timestamp,
+}
+
+/// `HeadersTransform` describes the changes applied to the headers of each replayed message.
+/// The headers listed in `remove` are removed first, then the headers from `set` are inserted or overwritten.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Clone)]
+pub struct HeadersTransform {
+    /// Headers to insert or overwrite.
+    #[serde(default)]
+    pub set: HashMap<HeaderKey, HeaderValue>,
+    /// Header keys to remove.
+    #[serde(default)]
+    pub remove: Vec<HeaderKey>,
+}
+
+impl Default for ReplayMessages {
+    fn default() -> Self {
+        Self {
+            source_stream_id: Identifier::default(),
+            source_topic_id: Identifier::default(),
+            source_partition_id: 1,
+            range: ReplayRange::offset(0, 0),
+            destination_stream_id: Identifier::default(),
+            destination_topic_id: Identifier::default(),
+            partitioning: Partitioning::default(),
+            headers: HeadersTransform::default(),
+            messages_per_second: 0,
+            batch_size: default_batch_size(),
+        }
+    }
+}
+
+fn default_batch_size() -> u32 {
+    DEFAULT_BATCH_SIZE
+}
+
+impl ReplayRange {
+    /// Create the range of messages between the specified offsets (inclusive).
+    pub fn offset(start: u64, end: u64) -> Self {
+        Self {
+            kind: ReplayRangeKind::Offset,
+            start,
+            end,
+        }
+    }
+
+    /// Create the range of messages between the specified timestamps (inclusive).
+    pub fn timestamp(start: IggyTimestamp, end: IggyTimestamp) -> Self {
+        Self {
+            kind: ReplayRangeKind::Timestamp,
+            start: start.as_micros(),
+            end: end.as_micros(),
+        }
+    }
+}
+
+impl Display for ReplayRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}..={}", self.kind, self.start, self.end)
+    }
+}
+
+impl ReplayRangeKind {
+    /// Returns the code of the replay range kind.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            ReplayRangeKind::Offset => 1,
+            ReplayRangeKind::Timestamp => 2,
+        }
+    }
+
+    /// Returns the replay range kind from the code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(ReplayRangeKind::Offset),
+            2 => Ok(ReplayRangeKind::Timestamp),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+impl FromStr for ReplayRangeKind {
+    type Err = IggyError;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "o" | "offset" => Ok(ReplayRangeKind::Offset),
+            "t" | "timestamp" => Ok(ReplayRangeKind::Timestamp),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+impl Display for ReplayRangeKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReplayRangeKind::Offset => write!(f, "offset"),
+            ReplayRangeKind::Timestamp => write!(f, "timestamp"),
+        }
+    }
+}
+
+impl HeadersTransform {
+    /// Returns `true` if the transformation doesn't change the headers.
+    pub fn is_empty(&self) -> bool {
+        self.set.is_empty() && self.remove.is_empty()
+    }
+
+    /// Applies the transformation to the given headers, returns `None` if no headers are left.
+    pub fn apply(
+        &self,
+        headers: Option<HashMap<HeaderKey, HeaderValue>>,
+    ) -> Option<HashMap<HeaderKey, HeaderValue>> {
+        if self.is_empty() {
+            return headers;
+        }
+
+        let mut headers = headers.unwrap_or_default();
+        for key in &self.remove {
+            headers.remove(key);
+        }
+        for (key, value) in &self.set {
+            headers.insert(key.clone(), value.clone());
+        }
+
+        if headers.is_empty() {
+            None
+        } else {
+            Some(headers)
+        }
+    }
+}
+
+impl Display for HeadersTransform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut set = self
+            .set
+            .iter()
+            .map(|(key, value)| format!("{key}={value}"))
+            .collect::<Vec<_>>();
+        set.sort();
+        let remove = self
+            .remove
+            .iter()
+            .map(|key| key.as_str())
+            .collect::<Vec<_>>();
+        write!(f, "{}|{}", set.join(","), remove.join(","))
+    }
+}
+
+impl Command for ReplayMessages {
+    fn code(&self) -> u32 {
+        REPLAY_MESSAGES_CODE
+    }
+}
+
+impl Validatable<IggyError> for ReplayMessages {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.range.start > self.range.end {
+            return Err(IggyError::InvalidReplayRange);
+        }
+
+        if self.batch_size == 0 || self.batch_size > MAX_BATCH_SIZE {
+            return Err(IggyError::InvalidMessagesCount);
+        }
+
+        if self.headers.remove.len() > u8::MAX as usize {
+            return Err(IggyError::InvalidHeaderKey);
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for ReplayMessages {
+    fn to_bytes(&self) -> Bytes {
+        let source_stream_id_bytes = self.source_stream_id.to_bytes();
+        let source_topic_id_bytes = self.source_topic_id.to_bytes();
+        let destination_stream_id_bytes = self.destination_stream_id.to_bytes();
+        let destination_topic_id_bytes = self.destination_topic_id.to_bytes();
+        let set_headers_bytes = self.headers.set.to_bytes();
+        let mut bytes = BytesMut::with_capacity(
+            source_stream_id_bytes.len()
+                + source_topic_id_bytes.len()
+                + 4
+                + 17
+                + destination_stream_id_bytes.len()
+                + destination_topic_id_bytes.len()
+                + self.partitioning.get_size_bytes().as_bytes_usize()
+                + 8
+                + 4
+                + set_headers_bytes.len()
+                + 1
+                + self
+                    .headers
+                    .remove
+                    .iter()
+                    .map(|key| 1 + key.as_str().len())
+                    .sum::<usize>(),
+        );
+        bytes.put_slice(&source_stream_id_bytes);
+        bytes.put_slice(&source_topic_id_bytes);
+        bytes.put_u32_le(self.source_partition_id);
+        bytes.put_u8(self.range.kind.as_code());
+        bytes.put_u64_le(self.range.start);
+        bytes.put_u64_le(self.range.end);
+        bytes.put_slice(&destination_stream_id_bytes);
+        bytes.put_slice(&destination_topic_id_bytes);
+        bytes.put_slice(&self.partitioning.to_bytes());
+        bytes.put_u32_le(self.messages_per_second);
+        bytes.put_u32_le(self.batch_size);
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u32_le(set_headers_bytes.len() as u32);
+        bytes.put_slice(&set_headers_bytes);
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(self.headers.remove.len() as u8);
+        for key in &self.headers.remove {
+            #[allow(clippy::cast_possible_truncation)]
+            bytes.put_u8(key.as_str().len() as u8);
+            bytes.put_slice(key.as_str().as_bytes());
+        }
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<ReplayMessages, IggyError> {
+        if bytes.len() < 38 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let source_stream_id = Identifier::from_bytes(bytes.clone())?;
+        position += source_stream_id.get_size_bytes().as_bytes_usize();
+        let source_topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += source_topic_id.get_size_bytes().as_bytes_usize();
+        let source_partition_id = read_u32(&bytes, position)?;
+        position += 4;
+        let kind =
+            ReplayRangeKind::from_code(*bytes.get(position).ok_or(IggyError::InvalidCommand)?)?;
+        position += 1;
+        let start = read_u64(&bytes, position)?;
+        position += 8;
+        let end = read_u64(&bytes, position)?;
+        position += 8;
+        let destination_stream_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += destination_stream_id.get_size_bytes().as_bytes_usize();
+        let destination_topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += destination_topic_id.get_size_bytes().as_bytes_usize();
+        let partitioning = Partitioning::from_bytes(bytes.slice(position..))?;
+        position += partitioning.get_size_bytes().as_bytes_usize();
+        let messages_per_second = read_u32(&bytes, position)?;
+        position += 4;
+        let batch_size = read_u32(&bytes, position)?;
+        position += 4;
+        let set_headers_length = read_u32(&bytes, position)? as usize;
+        position += 4;
+        if bytes.len() < position + set_headers_length + 1 {
+            return Err(IggyError::InvalidCommand);
+        }
+        let set = HashMap::from_bytes(bytes.slice(position..position + set_headers_length))?;
+        position += set_headers_length;
+        let remove_count = bytes[position];
+        position += 1;
+        let mut remove = Vec::with_capacity(remove_count as usize);
+        for _ in 0..remove_count {
+            let key_length = *bytes.get(position).ok_or(IggyError::InvalidCommand)? as usize;
+            position += 1;
+            let key = bytes
+                .get(position..position + key_length)
+                .ok_or(IggyError::InvalidCommand)?;
+            let key = std::str::from_utf8(key).map_err(|_| IggyError::InvalidHeaderKey)?;
+            remove.push(HeaderKey::new(key)?);
+            position += key_length;
+        }
+
+        Ok(ReplayMessages {
+            source_stream_id,
+            source_topic_id,
+            source_partition_id,
+            range: ReplayRange { kind, start, end },
+            destination_stream_id,
+            destination_topic_id,
+            partitioning,
+            headers: HeadersTransform { set, remove },
+            messages_per_second,
+            batch_size,
+        })
+    }
+}
+
+fn read_u32(bytes: &Bytes, position: usize) -> Result<u32, IggyError> {
+    bytes
+        .get(position..position + 4)
+        .ok_or(IggyError::InvalidCommand)?
+        .try_into()
+        .map(u32::from_le_bytes)
+        .map_err(|_| IggyError::InvalidNumberEncoding)
+}
+
+fn read_u64(bytes: &Bytes, position: usize) -> Result<u64, IggyError> {
+    bytes
+        .get(position..position + 8)
+        .ok_or(IggyError::InvalidCommand)?
+        .try_into()
+        .map(u64::from_le_bytes)
+        .map_err(|_| IggyError::InvalidNumberEncoding)
+}
+
+impl Display for ReplayMessages {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}|{}|{}|{}|{}|{}|{}",
+            self.source_stream_id,
+            self.source_topic_id,
+            self.source_partition_id,
+            self.range,
+            self.destination_stream_id,
+            self.destination_topic_id,
+            self.partitioning,
+            self.headers,
+            self.messages_per_second,
+            self.batch_size
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn command() -> ReplayMessages {
+        ReplayMessages {
+            source_stream_id: Identifier::numeric(1).unwrap(),
+            source_topic_id: Identifier::named("orders").unwrap(),
+            source_partition_id: 2,
+            range: ReplayRange::offset(10, 20),
+            destination_stream_id: Identifier::named("backfill").unwrap(),
+            destination_topic_id: Identifier::numeric(3).unwrap(),
+            partitioning: Partitioning::partition_id(1),
+            headers: HeadersTransform {
+                set: HashMap::from([(
+                    HeaderKey::new("replayed").unwrap(),
+                    HeaderValue::from_bool(true).unwrap(),
+                )]),
+                remove: vec![HeaderKey::new("trace-id").unwrap()],
+            },
+            messages_per_second: 500,
+            batch_size: 100,
+        }
+    }
+
+    #[test]
+    fn should_be_serialized_and_deserialized() {
+        let command = command();
+        let deserialized = ReplayMessages::from_bytes(command.to_bytes()).unwrap();
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_be_serialized_and_deserialized_without_headers_transform() {
+        let command = ReplayMessages {
+            headers: HeadersTransform::default(),
+            range: ReplayRange::timestamp(IggyTimestamp::from(1000), IggyTimestamp::from(2000)),
+            ..command()
+        };
+        let deserialized = ReplayMessages::from_bytes(command.to_bytes()).unwrap();
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_truncated_bytes() {
+        let bytes = command().to_bytes();
+        let command = ReplayMessages::from_bytes(bytes.slice(..bytes.len() - 3));
+        assert!(command.is_err());
+    }
+
+    #[test]
+    fn should_not_be_valid_for_inverted_range() {
+        let command = ReplayMessages {
+            range: ReplayRange::offset(20, 10),
+            ..command()
+        };
+        assert!(command.validate().is_err());
+    }
+
+    #[test]
+    fn headers_transform_should_remove_and_set_headers() {
+        let transform = command().headers;
+        let headers = HashMap::from([
+            (
+                HeaderKey::new("trace-id").unwrap(),
+                HeaderValue::from_str("abc").unwrap(),
+            ),
+            (
+                HeaderKey::new("tenant").unwrap(),
+                HeaderValue::from_str("acme").unwrap(),
+            ),
+        ]);
+
+        let headers = transform.apply(Some(headers)).unwrap();
+        assert_eq!(headers.len(), 2);
+        assert!(headers.contains_key(&HeaderKey::new("tenant").unwrap()));
+        assert!(headers.contains_key(&HeaderKey::new("replayed").unwrap()));
+    }
+
+    #[test]
+    fn headers_transform_should_return_none_when_no_headers_are_left() {
+        let transform = HeadersTransform {
+            set: HashMap::new(),
+            remove: vec![HeaderKey::new("trace-id").unwrap()],
+        };
+        let headers = HashMap::from([(
+            HeaderKey::new("trace-id").unwrap(),
+            HeaderValue::from_str("abc").unwrap(),
+        )]);
+
+        assert!(transform.apply(Some(headers)).is_none());
+    }
+}
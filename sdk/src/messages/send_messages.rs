@@ -34,7 +34,6 @@ use std::collections::HashMap;
 use std::fmt::Display;
 use std::hash::{Hash, Hasher};
 use std::str::FromStr;
-use uuid::Uuid;
 
 const EMPTY_KEY_VALUE: Vec<u8> = vec![];
 
@@ -235,40 +234,48 @@ impl Command for SendMessages {
 
 impl Validatable<IggyError> for SendMessages {
     fn validate(&self) -> Result<(), IggyError> {
-        if self.messages.is_empty() {
-            return Err(IggyError::InvalidMessagesCount);
-        }
+        validate_messages(&self.partitioning, &self.messages)
+    }
+}
 
-        let key_value_length = self.partitioning.value.len();
-        if key_value_length > 255
-            || (self.partitioning.kind != PartitioningKind::Balanced && key_value_length == 0)
-        {
-            return Err(IggyError::InvalidKeyValueLength);
-        }
+/// Validates the partitioning and the messages sent to a single topic.
+pub(crate) fn validate_messages(
+    partitioning: &Partitioning,
+    messages: &[Message],
+) -> Result<(), IggyError> {
+    if messages.is_empty() {
+        return Err(IggyError::InvalidMessagesCount);
+    }
 
-        let mut headers_size = 0;
-        let mut payload_size = 0;
-        for message in &self.messages {
-            if let Some(headers) = &message.headers {
-                for value in headers.values() {
-                    headers_size += value.value.len() as u32;
-                    if headers_size > MAX_HEADERS_SIZE {
-                        return Err(IggyError::TooBigHeadersPayload);
-                    }
+    let key_value_length = partitioning.value.len();
+    if key_value_length > 255
+        || (partitioning.kind != PartitioningKind::Balanced && key_value_length == 0)
+    {
+        return Err(IggyError::InvalidKeyValueLength);
+    }
+
+    let mut headers_size = 0;
+    let mut payload_size = 0;
+    for message in messages {
+        if let Some(headers) = &message.headers {
+            for value in headers.values() {
+                headers_size += value.value.len() as u32;
+                if headers_size > MAX_HEADERS_SIZE {
+                    return Err(IggyError::TooBigHeadersPayload);
                 }
             }
-            payload_size += message.payload.len() as u32;
-            if payload_size > MAX_PAYLOAD_SIZE {
-                return Err(IggyError::TooBigMessagePayload);
-            }
         }
-
-        if payload_size == 0 {
-            return Err(IggyError::EmptyMessagePayload);
+        payload_size += message.payload.len() as u32;
+        if payload_size > MAX_PAYLOAD_SIZE {
+            return Err(IggyError::TooBigMessagePayload);
         }
+    }
 
-        Ok(())
+    if payload_size == 0 {
+        return Err(IggyError::EmptyMessagePayload);
     }
+
+    Ok(())
 }
 
 impl PartitioningKind {
@@ -399,14 +406,12 @@ impl BytesSerializable for Message {
             return Err(IggyError::InvalidCommand);
         }
 
-        let mut id = u128::from_le_bytes(
+        // The ID equal to 0 is assigned by the server, using the scheme configured for the topic.
+        let id = u128::from_le_bytes(
             bytes[..16]
                 .try_into()
                 .map_err(|_| IggyError::InvalidNumberEncoding)?,
         );
-        if id == 0 {
-            id = Uuid::now_v7().to_u128_le();
-        }
         let headers_length = u32::from_le_bytes(
             bytes[16..20]
                 .try_into()
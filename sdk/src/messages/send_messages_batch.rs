@@ -0,0 +1,278 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, SEND_MESSAGES_BATCH_CODE};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::messages::send_messages::{self, Message, Partitioning, SendMessages};
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// The maximum number of topics and partitions the messages can be sent to in a single request.
+pub const MAX_MESSAGES_BATCHES: u32 = 1_000;
+
+/// `SendMessagesBatch` command sends the messages to many topics and partitions in a single request,
+/// either all of them are appended, or none of them.
+/// Unless the messages are sent within the transaction, the server appends them within the implicit one,
+/// committed once all the batches have been appended, so that the read-committed consumers see all or none of them.
+/// It has additional payload:
+/// - `batches` - the messages to send, each batch to a single topic using its own partitioning.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct SendMessagesBatch {
+    /// The messages to send, each batch to a single topic using its own partitioning.
+    pub batches: Vec<TopicMessages>,
+}
+
+/// `TopicMessages` represents the messages sent to a single topic within the `SendMessagesBatch` command.
+/// It consists of the following fields:
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `partitioning` - to which partition the messages should be sent.
+/// - `messages` - collection of messages to be sent.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct TopicMessages {
+    /// Unique stream ID (numeric or name).
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    pub topic_id: Identifier,
+    /// To which partition the messages should be sent - either provided by the client or calculated by the server.
+    pub partitioning: Partitioning,
+    /// Collection of messages to be sent.
+    pub messages: Vec<Message>,
+}
+
+impl TopicMessages {
+    /// Creates the messages to be sent to the topic using the provided partitioning.
+    pub fn new(
+        stream_id: Identifier,
+        topic_id: Identifier,
+        partitioning: Partitioning,
+        messages: Vec<Message>,
+    ) -> Self {
+        TopicMessages {
+            stream_id,
+            topic_id,
+            partitioning,
+            messages,
+        }
+    }
+}
+
+impl From<SendMessages> for TopicMessages {
+    fn from(command: SendMessages) -> Self {
+        TopicMessages::new(
+            command.stream_id,
+            command.topic_id,
+            command.partitioning,
+            command.messages,
+        )
+    }
+}
+
+impl From<TopicMessages> for SendMessages {
+    fn from(batch: TopicMessages) -> Self {
+        SendMessages {
+            stream_id: batch.stream_id,
+            topic_id: batch.topic_id,
+            partitioning: batch.partitioning,
+            messages: batch.messages,
+        }
+    }
+}
+
+impl Default for SendMessagesBatch {
+    fn default() -> Self {
+        SendMessagesBatch {
+            batches: vec![SendMessages::default().into()],
+        }
+    }
+}
+
+impl Command for SendMessagesBatch {
+    fn code(&self) -> u32 {
+        SEND_MESSAGES_BATCH_CODE
+    }
+}
+
+impl Validatable<IggyError> for SendMessagesBatch {
+    fn validate(&self) -> Result<(), IggyError> {
+        validate_batches(&self.batches)
+    }
+}
+
+pub(crate) fn validate_batches(batches: &[TopicMessages]) -> Result<(), IggyError> {
+    let count = batches.len() as u32;
+    if count == 0 || count > MAX_MESSAGES_BATCHES {
+        return Err(IggyError::InvalidMessagesBatchesCount(count));
+    }
+
+    for batch in batches {
+        send_messages::validate_messages(&batch.partitioning, &batch.messages)?;
+    }
+
+    Ok(())
+}
+
+// This method is used by `IggyClient` to serialize `SendMessagesBatch` without copying the messages.
+pub(crate) fn as_bytes(batches: &[TopicMessages]) -> Bytes {
+    let batches_bytes = batches
+        .iter()
+        .map(|batch| {
+            send_messages::as_bytes(
+                &batch.stream_id,
+                &batch.topic_id,
+                &batch.partitioning,
+                &batch.messages,
+            )
+        })
+        .collect::<Vec<_>>();
+    let mut bytes = BytesMut::with_capacity(
+        4 + batches_bytes
+            .iter()
+            .map(|batch_bytes| 4 + batch_bytes.len())
+            .sum::<usize>(),
+    );
+    bytes.put_u32_le(batches_bytes.len() as u32);
+    for batch_bytes in batches_bytes {
+        bytes.put_u32_le(batch_bytes.len() as u32);
+        bytes.put_slice(&batch_bytes);
+    }
+    bytes.freeze()
+}
+
+impl BytesSerializable for SendMessagesBatch {
+    fn to_bytes(&self) -> Bytes {
+        as_bytes(&self.batches)
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<SendMessagesBatch, IggyError> {
+        if bytes.len() < 4 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let count = u32::from_le_bytes(
+            bytes[..4]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        if count > MAX_MESSAGES_BATCHES {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut position = 4;
+        let mut batches = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            if bytes.len() < position + 4 {
+                return Err(IggyError::InvalidCommand);
+            }
+
+            let length = u32::from_le_bytes(
+                bytes[position..position + 4]
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            ) as usize;
+            position += 4;
+            if bytes.len() < position + length {
+                return Err(IggyError::InvalidCommand);
+            }
+
+            let batch = SendMessages::from_bytes(bytes.slice(position..position + length))?;
+            batches.push(batch.into());
+            position += length;
+        }
+
+        if position != bytes.len() {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        Ok(SendMessagesBatch { batches })
+    }
+}
+
+impl Display for SendMessagesBatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let batches = self
+            .batches
+            .iter()
+            .map(|batch| {
+                format!(
+                    "{}|{}|{}|batch_len:{}",
+                    batch.stream_id,
+                    batch.topic_id,
+                    batch.partitioning,
+                    batch.messages.len()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        write!(f, "{batches}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn should_be_serialized_as_bytes_and_deserialized_from_bytes() {
+        let command = SendMessagesBatch {
+            batches: vec![
+                TopicMessages::new(
+                    Identifier::numeric(1).unwrap(),
+                    Identifier::numeric(2).unwrap(),
+                    Partitioning::partition_id(3),
+                    vec![
+                        Message::from_str("event 1").unwrap(),
+                        Message::from_str("event 2").unwrap(),
+                    ],
+                ),
+                TopicMessages::new(
+                    Identifier::numeric(1).unwrap(),
+                    Identifier::named("audit").unwrap(),
+                    Partitioning::messages_key_str("user-1").unwrap(),
+                    vec![Message::new(Some(4), "audit 1".into(), None)],
+                ),
+            ],
+        };
+
+        let bytes = command.to_bytes();
+        let deserialized_command = SendMessagesBatch::from_bytes(bytes).unwrap();
+
+        assert_eq!(deserialized_command, command);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_truncated_bytes() {
+        let bytes = SendMessagesBatch::default().to_bytes();
+
+        let command = SendMessagesBatch::from_bytes(bytes.slice(..bytes.len() - 1));
+
+        assert!(command.is_err());
+    }
+
+    #[test]
+    fn should_fail_validation_given_no_batches() {
+        let command = SendMessagesBatch { batches: vec![] };
+
+        assert!(command.validate().is_err());
+    }
+}
@@ -0,0 +1,207 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::client::Client;
+use crate::diagnostic::DiagnosticEvent;
+use crate::error::IggyError;
+use crate::mock::state::MockState;
+use crate::utils::duration::IggyDuration;
+use ahash::AHashMap;
+use async_broadcast::{broadcast, Receiver, Sender};
+use async_trait::async_trait;
+use std::fmt::{Debug, Formatter};
+use std::sync::{Mutex, MutexGuard};
+
+type ErrorFactory = Box<dyn Fn() -> IggyError + Send + Sync>;
+
+/// The error returned by the calls of the command, either for the given number of times or until the faults are cleared.
+struct Fault {
+    error: ErrorFactory,
+    remaining: Option<u32>,
+}
+
+/// In-memory client, which can be used instead of the real one in the unit tests.
+/// The faults are injected per command, using the command names defined in the `command` module (e.g. `SEND_MESSAGES`),
+/// and every call is counted, regardless of whether it has failed or not.
+pub struct MockClient {
+    state: Mutex<MockState>,
+    faults: Mutex<AHashMap<String, Fault>>,
+    calls: Mutex<AHashMap<String, u32>>,
+    events: (Sender<DiagnosticEvent>, Receiver<DiagnosticEvent>),
+}
+
+impl MockClient {
+    /// Create a new mock client without any streams.
+    pub fn new() -> Self {
+        Self {
+            state: Mutex::new(MockState::default()),
+            faults: Mutex::new(AHashMap::new()),
+            calls: Mutex::new(AHashMap::new()),
+            events: broadcast(1000),
+        }
+    }
+
+    /// Fail all the subsequent calls of the command with the error, until the faults are cleared.
+    pub fn fail<F>(&self, command: &str, error: F)
+    where
+        F: Fn() -> IggyError + Send + Sync + 'static,
+    {
+        self.add_fault(command, error, None);
+    }
+
+    /// Fail the given number of the subsequent calls of the command with the error.
+    pub fn fail_times<F>(&self, command: &str, times: u32, error: F)
+    where
+        F: Fn() -> IggyError + Send + Sync + 'static,
+    {
+        self.add_fault(command, error, Some(times));
+    }
+
+    /// Remove all the injected faults.
+    pub fn clear_faults(&self) {
+        self.faults.lock().unwrap().clear();
+    }
+
+    /// Returns the number of calls of the command, including the failed ones.
+    pub fn calls(&self, command: &str) -> u32 {
+        self.calls
+            .lock()
+            .unwrap()
+            .get(command)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Move the logical clock forward, e.g. to separate the timestamps of the consecutive messages.
+    pub fn advance_clock(&self, duration: IggyDuration) {
+        self.state().clock += duration.as_micros();
+    }
+
+    fn add_fault<F>(&self, command: &str, error: F, remaining: Option<u32>)
+    where
+        F: Fn() -> IggyError + Send + Sync + 'static,
+    {
+        if remaining == Some(0) {
+            return;
+        }
+
+        self.faults.lock().unwrap().insert(
+            command.to_owned(),
+            Fault {
+                error: Box::new(error),
+                remaining,
+            },
+        );
+    }
+
+    /// Counts the call of the command and returns the injected error, if any.
+    pub(crate) fn call(&self, command: &str) -> Result<(), IggyError> {
+        *self
+            .calls
+            .lock()
+            .unwrap()
+            .entry(command.to_owned())
+            .or_default() += 1;
+
+        let mut faults = self.faults.lock().unwrap();
+        let Some(fault) = faults.get_mut(command) else {
+            return Ok(());
+        };
+
+        let error = (fault.error)();
+        if let Some(remaining) = fault.remaining.as_mut() {
+            *remaining -= 1;
+            if *remaining == 0 {
+                faults.remove(command);
+            }
+        }
+        Err(error)
+    }
+
+    pub(crate) fn state(&self) -> MutexGuard<'_, MockState> {
+        self.state.lock().unwrap()
+    }
+}
+
+impl Default for MockClient {
+    fn default() -> Self {
+        MockClient::new()
+    }
+}
+
+impl Debug for MockClient {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MockClient")
+            .field("state", &self.state)
+            .finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl Client for MockClient {
+    async fn connect(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+
+    async fn disconnect(&self) -> Result<(), IggyError> {
+        self.state().user_id = None;
+        Ok(())
+    }
+
+    async fn shutdown(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+
+    async fn subscribe_events(&self) -> Receiver<DiagnosticEvent> {
+        self.events.1.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::StreamClient;
+    use crate::command::{CREATE_STREAM, GET_STREAMS};
+
+    #[tokio::test]
+    async fn calls_should_fail_given_number_of_times() {
+        let client = MockClient::new();
+        client.fail_times(CREATE_STREAM, 2, || IggyError::NotConnected);
+
+        for _ in 0..2 {
+            let result = client.create_stream("test", None).await;
+            assert!(matches!(result, Err(IggyError::NotConnected)));
+        }
+        let stream = client.create_stream("test", None).await.unwrap();
+        assert_eq!(stream.id, 1);
+        assert_eq!(client.calls(CREATE_STREAM), 3);
+        assert_eq!(client.calls(GET_STREAMS), 0);
+    }
+
+    #[tokio::test]
+    async fn calls_should_fail_until_faults_are_cleared() {
+        let client = MockClient::new();
+        client.fail(GET_STREAMS, || IggyError::Unauthenticated);
+
+        for _ in 0..3 {
+            assert!(client.get_streams().await.is_err());
+        }
+        client.clear_faults();
+        assert!(client.get_streams().await.unwrap().is_empty());
+    }
+}
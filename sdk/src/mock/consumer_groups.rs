@@ -0,0 +1,375 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::client::ConsumerGroupClient;
+use crate::command::{
+    CREATE_CONSUMER_GROUP, DELETE_CONSUMER_GROUP, DELETE_PARTITION_STATE, GET_CONSUMER_GROUP,
+    GET_CONSUMER_GROUPS, GET_PARTITION_STATE, JOIN_CONSUMER_GROUP, LEAVE_CONSUMER_GROUP,
+    STORE_PARTITION_STATE, UPDATE_CONSUMER_GROUP_DEAD_LETTER,
+    UPDATE_CONSUMER_GROUP_VISIBILITY_TIMEOUT,
+};
+use crate::consumer_groups::offset_recovery_policy::OffsetRecoveryPolicy;
+use crate::consumer_groups::{validate_partition_state_key, MAX_PARTITION_STATE_VALUE_SIZE};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::mock::client::MockClient;
+use crate::mock::state::{MockConsumerGroup, CLIENT_ID};
+use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails};
+use crate::utils::duration::IggyDuration;
+use async_trait::async_trait;
+use bytes::Bytes;
+use std::collections::BTreeMap;
+
+#[async_trait]
+impl ConsumerGroupClient for MockClient {
+    async fn get_consumer_group(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+    ) -> Result<Option<ConsumerGroupDetails>, IggyError> {
+        self.call(GET_CONSUMER_GROUP)?;
+        let state = self.state();
+        let topic = state.get_topic(stream_id, topic_id)?;
+        Ok(topic
+            .get_consumer_group(group_id)
+            .ok()
+            .map(|group| topic.to_consumer_group_details(group)))
+    }
+
+    async fn get_consumer_groups(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<Vec<ConsumerGroup>, IggyError> {
+        self.call(GET_CONSUMER_GROUPS)?;
+        let state = self.state();
+        let topic = state.get_topic(stream_id, topic_id)?;
+        Ok(topic
+            .consumer_groups
+            .values()
+            .map(|group| topic.to_consumer_group(group))
+            .collect())
+    }
+
+    async fn create_consumer_group(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        name: &str,
+        group_id: Option<u32>,
+    ) -> Result<ConsumerGroupDetails, IggyError> {
+        self.create_consumer_group_with_offset_recovery(
+            stream_id,
+            topic_id,
+            name,
+            group_id,
+            OffsetRecoveryPolicy::default(),
+        )
+        .await
+    }
+
+    async fn create_consumer_group_with_offset_recovery(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        name: &str,
+        group_id: Option<u32>,
+        offset_recovery: OffsetRecoveryPolicy,
+    ) -> Result<ConsumerGroupDetails, IggyError> {
+        self.call(CREATE_CONSUMER_GROUP)?;
+        let mut state = self.state();
+        let topic = state.get_topic_mut(stream_id, topic_id)?;
+        if topic
+            .consumer_groups
+            .values()
+            .any(|group| group.name == name)
+        {
+            return Err(IggyError::ConsumerGroupNameAlreadyExists(
+                name.to_owned(),
+                topic.id,
+            ));
+        }
+
+        let id = match group_id {
+            Some(id) if topic.consumer_groups.contains_key(&id) => {
+                return Err(IggyError::ConsumerGroupIdAlreadyExists(id, topic.id));
+            }
+            Some(id) => id,
+            None => {
+                topic
+                    .consumer_groups
+                    .keys()
+                    .last()
+                    .copied()
+                    .unwrap_or_default()
+                    + 1
+            }
+        };
+        topic.consumer_groups.insert(
+            id,
+            MockConsumerGroup {
+                id,
+                name: name.to_owned(),
+                offset_recovery,
+                dead_letter: None,
+                visibility_timeout: None,
+                joined: false,
+                generation: 0,
+                current_partition_id: 0,
+                state: BTreeMap::new(),
+            },
+        );
+        Ok(topic.to_consumer_group_details(&topic.consumer_groups[&id]))
+    }
+
+    async fn update_consumer_group_dead_letter(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+        dead_letter_stream_id: Option<&Identifier>,
+        dead_letter_topic_id: Option<&Identifier>,
+        max_delivery_count: u32,
+    ) -> Result<(), IggyError> {
+        self.call(UPDATE_CONSUMER_GROUP_DEAD_LETTER)?;
+        let mut state = self.state();
+        let dead_letter = match (dead_letter_stream_id, dead_letter_topic_id) {
+            (Some(dead_letter_stream_id), Some(dead_letter_topic_id)) => {
+                let dead_letter_topic =
+                    state.get_topic(dead_letter_stream_id, dead_letter_topic_id)?;
+                Some((
+                    dead_letter_topic.stream_id,
+                    dead_letter_topic.id,
+                    max_delivery_count,
+                ))
+            }
+            _ => None,
+        };
+        state
+            .get_topic_mut(stream_id, topic_id)?
+            .get_consumer_group_mut(group_id)?
+            .dead_letter = dead_letter;
+        Ok(())
+    }
+
+    async fn update_consumer_group_visibility_timeout(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+        visibility_timeout: IggyDuration,
+    ) -> Result<(), IggyError> {
+        self.call(UPDATE_CONSUMER_GROUP_VISIBILITY_TIMEOUT)?;
+        self.state()
+            .get_topic_mut(stream_id, topic_id)?
+            .get_consumer_group_mut(group_id)?
+            .visibility_timeout = (!visibility_timeout.is_zero()).then_some(visibility_timeout);
+        Ok(())
+    }
+
+    async fn delete_consumer_group(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+    ) -> Result<(), IggyError> {
+        self.call(DELETE_CONSUMER_GROUP)?;
+        let mut state = self.state();
+        let topic = state.get_topic_mut(stream_id, topic_id)?;
+        let id = topic.get_consumer_group(group_id)?.id;
+        topic.consumer_groups.remove(&id);
+        Ok(())
+    }
+
+    async fn join_consumer_group(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+    ) -> Result<(), IggyError> {
+        self.call(JOIN_CONSUMER_GROUP)?;
+        let mut state = self.state();
+        let group = state
+            .get_topic_mut(stream_id, topic_id)?
+            .get_consumer_group_mut(group_id)?;
+        if !group.joined {
+            group.joined = true;
+            group.generation += 1;
+        }
+        Ok(())
+    }
+
+    async fn leave_consumer_group(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+    ) -> Result<(), IggyError> {
+        self.call(LEAVE_CONSUMER_GROUP)?;
+        let mut state = self.state();
+        let topic = state.get_topic_mut(stream_id, topic_id)?;
+        let topic_id = topic.id;
+        let group = topic.get_consumer_group_mut(group_id)?;
+        if !group.joined {
+            return Err(IggyError::ConsumerGroupMemberNotFound(
+                CLIENT_ID, group.id, topic_id,
+            ));
+        }
+
+        group.joined = false;
+        group.generation += 1;
+        group.current_partition_id = 0;
+        Ok(())
+    }
+
+    async fn get_partition_state(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+        partition_id: u32,
+        key: &str,
+    ) -> Result<Option<Bytes>, IggyError> {
+        self.call(GET_PARTITION_STATE)?;
+        validate_partition_state_key(key)?;
+        Ok(self
+            .state()
+            .get_topic_mut(stream_id, topic_id)?
+            .get_partition_state_mut(group_id, partition_id)?
+            .get(key)
+            .cloned())
+    }
+
+    async fn store_partition_state(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+        partition_id: u32,
+        key: &str,
+        value: Bytes,
+    ) -> Result<(), IggyError> {
+        self.call(STORE_PARTITION_STATE)?;
+        validate_partition_state_key(key)?;
+        if value.is_empty() || value.len() > MAX_PARTITION_STATE_VALUE_SIZE as usize {
+            return Err(IggyError::InvalidPartitionStateValue(
+                MAX_PARTITION_STATE_VALUE_SIZE,
+            ));
+        }
+
+        self.state()
+            .get_topic_mut(stream_id, topic_id)?
+            .get_partition_state_mut(group_id, partition_id)?
+            .insert(key.to_owned(), value);
+        Ok(())
+    }
+
+    async fn delete_partition_state(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+        partition_id: u32,
+        key: &str,
+    ) -> Result<(), IggyError> {
+        self.call(DELETE_PARTITION_STATE)?;
+        validate_partition_state_key(key)?;
+        self.state()
+            .get_topic_mut(stream_id, topic_id)?
+            .get_partition_state_mut(group_id, partition_id)?
+            .remove(key);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::{MessageClient, StreamClient, TopicClient};
+    use crate::compression::compression_algorithm::CompressionAlgorithm;
+    use crate::consumer::Consumer;
+    use crate::messages::poll_messages::PollingStrategy;
+    use crate::messages::send_messages::{Message, Partitioning};
+    use crate::utils::expiry::IggyExpiry;
+    use crate::utils::topic_size::MaxTopicSize;
+    use std::str::FromStr;
+
+    #[tokio::test]
+    async fn member_should_poll_partitions_in_round_robin_fashion() {
+        let client = MockClient::new();
+        let stream_id = Identifier::numeric(1).unwrap();
+        let topic_id = Identifier::numeric(1).unwrap();
+        let group_id = Identifier::named("group").unwrap();
+        client.create_stream("stream", None).await.unwrap();
+        client
+            .create_topic(
+                &stream_id,
+                "topic",
+                2,
+                CompressionAlgorithm::None,
+                None,
+                None,
+                IggyExpiry::NeverExpire,
+                MaxTopicSize::ServerDefault,
+            )
+            .await
+            .unwrap();
+        for partition_id in 1..=2 {
+            let mut messages = vec![Message::from_str("message").unwrap()];
+            client
+                .send_messages(
+                    &stream_id,
+                    &topic_id,
+                    &Partitioning::partition_id(partition_id),
+                    &mut messages,
+                )
+                .await
+                .unwrap();
+        }
+        client
+            .create_consumer_group(&stream_id, &topic_id, "group", None)
+            .await
+            .unwrap();
+
+        let consumer = Consumer::group(group_id.clone());
+        let strategy = PollingStrategy::next();
+        let poll =
+            || client.poll_messages(&stream_id, &topic_id, None, &consumer, &strategy, 10, true);
+        assert!(poll().await.is_err());
+
+        client
+            .join_consumer_group(&stream_id, &topic_id, &group_id)
+            .await
+            .unwrap();
+        let mut polled_partitions = Vec::new();
+        for _ in 0..3 {
+            let polled_messages = poll().await.unwrap();
+            polled_partitions.push((polled_messages.partition_id, polled_messages.messages.len()));
+        }
+        assert_eq!(polled_partitions, vec![(1, 1), (2, 1), (1, 0)]);
+
+        let group = client
+            .get_consumer_group(&stream_id, &topic_id, &group_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(group.members_count, 1);
+        assert_eq!(group.members[0].partitions, vec![1, 2]);
+    }
+}
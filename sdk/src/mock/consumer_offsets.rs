@@ -0,0 +1,154 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::client::ConsumerOffsetClient;
+use crate::command::{
+    DELETE_CONSUMER_OFFSET, GET_CONSUMER_OFFSET, GET_OFFSETS_FOR_TIMESTAMPS, STORE_CONSUMER_OFFSET,
+    STORE_CONSUMER_OFFSETS,
+};
+use crate::consumer::Consumer;
+use crate::consumer_offsets::get_offsets_for_timestamps::PartitionTimestamp;
+use crate::consumer_offsets::store_consumer_offsets::PartitionOffset;
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::mock::client::MockClient;
+use crate::models::consumer_offset_info::ConsumerOffsetInfo;
+use crate::models::partition_timestamp_offset::PartitionTimestampOffset;
+use async_trait::async_trait;
+
+#[async_trait]
+impl ConsumerOffsetClient for MockClient {
+    async fn store_consumer_offset(
+        &self,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        offset: u64,
+    ) -> Result<(), IggyError> {
+        self.call(STORE_CONSUMER_OFFSET)?;
+        let mut state = self.state();
+        let topic = state.get_topic_mut(stream_id, topic_id)?;
+        let key = topic.get_consumer_key(consumer)?;
+        let partition_id = topic.get_offset_partition_id(&key, partition_id)?;
+        let partition = topic.get_partition_mut(partition_id)?;
+        if offset > partition.get_current_offset() {
+            return Err(IggyError::InvalidOffset(offset));
+        }
+
+        partition.offsets.insert(key, offset);
+        Ok(())
+    }
+
+    async fn store_consumer_offsets(
+        &self,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        offsets: &[PartitionOffset],
+    ) -> Result<(), IggyError> {
+        self.call(STORE_CONSUMER_OFFSETS)?;
+        let mut state = self.state();
+        let topic = state.get_topic_mut(stream_id, topic_id)?;
+        let key = topic.get_consumer_key(consumer)?;
+        for partition_offset in offsets {
+            let partition = topic.get_partition(partition_offset.partition_id)?;
+            if partition_offset.offset > partition.get_current_offset() {
+                return Err(IggyError::InvalidOffset(partition_offset.offset));
+            }
+        }
+
+        for partition_offset in offsets {
+            topic
+                .get_partition_mut(partition_offset.partition_id)?
+                .offsets
+                .insert(key.clone(), partition_offset.offset);
+        }
+        Ok(())
+    }
+
+    async fn get_consumer_offset(
+        &self,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+    ) -> Result<Option<ConsumerOffsetInfo>, IggyError> {
+        self.call(GET_CONSUMER_OFFSET)?;
+        let state = self.state();
+        let topic = state.get_topic(stream_id, topic_id)?;
+        let key = topic.get_consumer_key(consumer)?;
+        let partition_id = topic.get_offset_partition_id(&key, partition_id)?;
+        let partition = topic.get_partition(partition_id)?;
+        Ok(partition
+            .offsets
+            .get(&key)
+            .map(|offset| ConsumerOffsetInfo {
+                partition_id,
+                current_offset: partition.get_current_offset(),
+                stored_offset: *offset,
+            }))
+    }
+
+    async fn delete_consumer_offset(
+        &self,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+    ) -> Result<(), IggyError> {
+        self.call(DELETE_CONSUMER_OFFSET)?;
+        let mut state = self.state();
+        let topic = state.get_topic_mut(stream_id, topic_id)?;
+        let key = topic.get_consumer_key(consumer)?;
+        let partition_id = topic.get_offset_partition_id(&key, partition_id)?;
+        topic
+            .get_partition_mut(partition_id)?
+            .offsets
+            .remove(&key)
+            .ok_or(IggyError::ConsumerOffsetNotFound(key.get_id()))?;
+        Ok(())
+    }
+
+    async fn get_offsets_for_timestamps(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        timestamps: &[PartitionTimestamp],
+    ) -> Result<Vec<PartitionTimestampOffset>, IggyError> {
+        self.call(GET_OFFSETS_FOR_TIMESTAMPS)?;
+        let state = self.state();
+        let topic = state.get_topic(stream_id, topic_id)?;
+        timestamps
+            .iter()
+            .map(|partition_timestamp| {
+                let partition = topic.get_partition(partition_timestamp.partition_id)?;
+                let timestamp = partition_timestamp.timestamp.as_micros();
+                Ok(PartitionTimestampOffset {
+                    partition_id: partition.id,
+                    timestamp: partition_timestamp.timestamp,
+                    offset: partition
+                        .messages
+                        .iter()
+                        .find(|message| message.timestamp >= timestamp)
+                        .map(|message| message.offset),
+                })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,482 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::client::MessageClient;
+use crate::command::{
+    ABORT_TRANSACTION, ACK_MESSAGES, AGGREGATE_MESSAGES, BEGIN_TRANSACTION, CANCEL_REPLAY_JOB,
+    COMMIT_TRANSACTION, CREATE_PUSH_SUBSCRIPTION, DELETE_PUSH_SUBSCRIPTION, FLUSH_UNSAVED_BUFFER,
+    GET_PUSH_SUBSCRIPTIONS, GET_REPLAY_JOBS, INIT_PRODUCER_ID, NACK_MESSAGES, POLL_MESSAGES,
+    REGISTER_PRODUCER, REPLAY_MESSAGES, SEND_MESSAGES, SEND_MESSAGES_BATCH,
+};
+use crate::consumer::Consumer;
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::messages::aggregate_messages::AggregateMessages;
+use crate::messages::message_filter::MessageFilter;
+use crate::messages::poll_messages::{IsolationLevel, PollMessagesOptions, PollingStrategy};
+use crate::messages::replay_messages::{HeadersTransform, ReplayRange};
+use crate::messages::send_messages::{Message, Partitioning};
+use crate::messages::send_messages_batch::TopicMessages;
+use crate::mock::client::MockClient;
+use crate::mock::state::PollArgs;
+use crate::models::messages::PolledMessages;
+use crate::models::messages_aggregate::{MessagesAggregate, MessagesBucket};
+use crate::models::producer_epoch::ProducerEpoch;
+use crate::models::producer_session::ProducerSession;
+use crate::models::push_subscription::PushSubscription;
+use crate::models::replay_job::ReplayJob;
+use crate::models::transaction::Transaction;
+use crate::utils::byte_size::IggyByteSize;
+use crate::utils::duration::IggyDuration;
+use crate::utils::timestamp::IggyTimestamp;
+use crate::validatable::Validatable;
+use async_trait::async_trait;
+
+#[async_trait]
+impl MessageClient for MockClient {
+    async fn poll_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        auto_commit: bool,
+    ) -> Result<PolledMessages, IggyError> {
+        self.poll_messages_with_options(
+            stream_id,
+            topic_id,
+            partition_id,
+            consumer,
+            strategy,
+            count,
+            auto_commit,
+            &PollMessagesOptions::default(),
+        )
+        .await
+    }
+
+    // The transactions are not supported, so all the messages are visible regardless of the isolation level.
+    async fn poll_messages_with_options(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        auto_commit: bool,
+        options: &PollMessagesOptions,
+    ) -> Result<PolledMessages, IggyError> {
+        self.call(POLL_MESSAGES)?;
+        self.state().poll_messages(
+            stream_id,
+            topic_id,
+            PollArgs {
+                partition_id,
+                consumer,
+                strategy,
+                count,
+                auto_commit,
+                chunk_size: options.chunk_size,
+                filter: options.filter.as_ref(),
+                partition_epoch: options.partition_epoch,
+                peek: false,
+            },
+        )
+    }
+
+    async fn peek_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        _isolation_level: IsolationLevel,
+        filter: Option<&MessageFilter>,
+    ) -> Result<PolledMessages, IggyError> {
+        self.call(POLL_MESSAGES)?;
+        self.state().poll_messages(
+            stream_id,
+            topic_id,
+            PollArgs {
+                partition_id,
+                consumer,
+                strategy,
+                count,
+                auto_commit: false,
+                chunk_size: 0,
+                filter,
+                partition_epoch: Some(0),
+                peek: true,
+            },
+        )
+    }
+
+    async fn aggregate_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        from: IggyTimestamp,
+        to: IggyTimestamp,
+        bucket: IggyDuration,
+    ) -> Result<Vec<MessagesBucket>, IggyError> {
+        self.call(AGGREGATE_MESSAGES)?;
+        AggregateMessages {
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            partition_id,
+            from,
+            to,
+            bucket,
+        }
+        .validate()?;
+
+        let state = self.state();
+        let topic = state.get_topic(stream_id, topic_id)?;
+        let partitions = match partition_id {
+            Some(partition_id) => vec![topic.get_partition(partition_id)?],
+            None => topic.partitions.values().collect(),
+        };
+        let mut aggregate = MessagesAggregate::new(from, to, bucket);
+        for message in partitions.iter().flat_map(|partition| &partition.messages) {
+            aggregate.add(message.timestamp, 1, IggyByteSize::from(message.size));
+        }
+        Ok(aggregate.into_buckets())
+    }
+
+    async fn send_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitioning: &Partitioning,
+        messages: &mut [Message],
+    ) -> Result<(), IggyError> {
+        self.call(SEND_MESSAGES)?;
+        self.state()
+            .send_messages(stream_id, topic_id, partitioning, messages)
+    }
+
+    async fn send_messages_batch(&self, batches: &mut [TopicMessages]) -> Result<(), IggyError> {
+        self.call(SEND_MESSAGES_BATCH)?;
+        self.state().send_messages_batch(batches)
+    }
+
+    async fn flush_unsaved_buffer(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        _fsync: bool,
+    ) -> Result<(), IggyError> {
+        self.call(FLUSH_UNSAVED_BUFFER)?;
+        self.state()
+            .get_topic(stream_id, topic_id)?
+            .get_partition(partition_id)?;
+        Ok(())
+    }
+
+    async fn nack_messages(
+        &self,
+        _stream_id: &Identifier,
+        _topic_id: &Identifier,
+        _partition_id: Option<u32>,
+        _consumer: &Consumer,
+        _offsets: &[u64],
+        _reason: &str,
+    ) -> Result<(), IggyError> {
+        self.call(NACK_MESSAGES)?;
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn ack_messages(
+        &self,
+        _stream_id: &Identifier,
+        _topic_id: &Identifier,
+        _partition_id: Option<u32>,
+        _consumer: &Consumer,
+        _offsets: &[u64],
+    ) -> Result<(), IggyError> {
+        self.call(ACK_MESSAGES)?;
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn replay_messages(
+        &self,
+        _source_stream_id: &Identifier,
+        _source_topic_id: &Identifier,
+        _source_partition_id: u32,
+        _range: &ReplayRange,
+        _destination_stream_id: &Identifier,
+        _destination_topic_id: &Identifier,
+        _partitioning: &Partitioning,
+        _headers: &HeadersTransform,
+        _messages_per_second: u32,
+    ) -> Result<ReplayJob, IggyError> {
+        self.call(REPLAY_MESSAGES)?;
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn get_replay_jobs(&self) -> Result<Vec<ReplayJob>, IggyError> {
+        self.call(GET_REPLAY_JOBS)?;
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn cancel_replay_job(&self, _job_id: u32) -> Result<(), IggyError> {
+        self.call(CANCEL_REPLAY_JOB)?;
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn create_push_subscription(
+        &self,
+        _stream_id: &Identifier,
+        _topic_id: &Identifier,
+        _partition_id: u32,
+        _endpoint: &str,
+        _start_offset: u64,
+        _batch_size: u32,
+    ) -> Result<PushSubscription, IggyError> {
+        self.call(CREATE_PUSH_SUBSCRIPTION)?;
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn get_push_subscriptions(&self) -> Result<Vec<PushSubscription>, IggyError> {
+        self.call(GET_PUSH_SUBSCRIPTIONS)?;
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn delete_push_subscription(&self, _subscription_id: u32) -> Result<(), IggyError> {
+        self.call(DELETE_PUSH_SUBSCRIPTION)?;
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn register_producer(
+        &self,
+        _stream_id: &Identifier,
+        _topic_id: &Identifier,
+        _name: &str,
+    ) -> Result<ProducerEpoch, IggyError> {
+        self.call(REGISTER_PRODUCER)?;
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn init_producer_id(
+        &self,
+        _stream_id: &Identifier,
+        _topic_id: &Identifier,
+    ) -> Result<ProducerSession, IggyError> {
+        self.call(INIT_PRODUCER_ID)?;
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn begin_transaction(&self) -> Result<Transaction, IggyError> {
+        self.call(BEGIN_TRANSACTION)?;
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn commit_transaction(&self, _transaction_id: u64) -> Result<(), IggyError> {
+        self.call(COMMIT_TRANSACTION)?;
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn abort_transaction(&self, _transaction_id: u64) -> Result<(), IggyError> {
+        self.call(ABORT_TRANSACTION)?;
+        Err(IggyError::FeatureUnavailable)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::{StreamClient, TopicClient};
+    use crate::compression::compression_algorithm::CompressionAlgorithm;
+    use crate::utils::expiry::IggyExpiry;
+    use crate::utils::topic_size::MaxTopicSize;
+    use std::str::FromStr;
+
+    async fn init_client(partitions_count: u32) -> MockClient {
+        let client = MockClient::new();
+        client.create_stream("stream", None).await.unwrap();
+        client
+            .create_topic(
+                &Identifier::named("stream").unwrap(),
+                "topic",
+                partitions_count,
+                CompressionAlgorithm::None,
+                None,
+                None,
+                IggyExpiry::NeverExpire,
+                MaxTopicSize::ServerDefault,
+            )
+            .await
+            .unwrap();
+        client
+    }
+
+    async fn send(client: &MockClient, partitioning: &Partitioning, payloads: &[&str]) {
+        let mut messages = payloads
+            .iter()
+            .map(|payload| Message::from_str(payload).unwrap())
+            .collect::<Vec<_>>();
+        client
+            .send_messages(
+                &Identifier::numeric(1).unwrap(),
+                &Identifier::numeric(1).unwrap(),
+                partitioning,
+                &mut messages,
+            )
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn sent_messages_should_be_polled_with_sequential_offsets_and_ids() {
+        let client = init_client(1).await;
+        send(&client, &Partitioning::partition_id(1), &["a", "b", "c"]).await;
+
+        let polled_messages = client
+            .poll_messages(
+                &Identifier::numeric(1).unwrap(),
+                &Identifier::numeric(1).unwrap(),
+                Some(1),
+                &Consumer::default(),
+                &PollingStrategy::offset(1),
+                10,
+                false,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(polled_messages.current_offset, 2);
+        assert_eq!(polled_messages.remaining_messages, 0);
+        let messages = polled_messages
+            .messages
+            .iter()
+            .map(|message| (message.offset, message.id, &message.payload[..]))
+            .collect::<Vec<_>>();
+        assert_eq!(messages, vec![(1, 2, &b"b"[..]), (2, 3, &b"c"[..])]);
+        assert!(polled_messages.messages[0].timestamp < polled_messages.messages[1].timestamp);
+    }
+
+    #[tokio::test]
+    async fn changed_partition_epoch_should_be_reported_after_purge() {
+        let client = init_client(1).await;
+        send(&client, &Partitioning::partition_id(1), &["a", "b"]).await;
+        let stream_id = Identifier::numeric(1).unwrap();
+        let topic_id = Identifier::numeric(1).unwrap();
+        let consumer = Consumer::default();
+        let strategy = PollingStrategy::offset(0);
+        let (client, stream_id, topic_id) = (&client, &stream_id, &topic_id);
+        let (consumer, strategy) = (&consumer, &strategy);
+        let poll = |partition_epoch| async move {
+            let options = PollMessagesOptions {
+                partition_epoch: Some(partition_epoch),
+                ..Default::default()
+            };
+            client
+                .poll_messages_with_options(
+                    stream_id,
+                    topic_id,
+                    Some(1),
+                    consumer,
+                    strategy,
+                    10,
+                    false,
+                    &options,
+                )
+                .await
+        };
+
+        let polled_messages = poll(0).await.unwrap();
+        assert_eq!(polled_messages.partition_epoch, 1);
+        assert_eq!(polled_messages.messages.len(), 2);
+
+        client.purge_topic(stream_id, topic_id).await.unwrap();
+        let error = poll(1).await.unwrap_err();
+        assert_eq!(
+            error.as_code(),
+            IggyError::PartitionEpochChanged(1, 1, 2).as_code()
+        );
+
+        send(client, &Partitioning::partition_id(1), &["c"]).await;
+        let polled_messages = poll(2).await.unwrap();
+        assert_eq!(polled_messages.partition_epoch, 2);
+        assert_eq!(polled_messages.messages[0].offset, 0);
+    }
+
+    #[tokio::test]
+    async fn next_messages_should_be_polled_given_auto_commit() {
+        let client = init_client(1).await;
+        send(&client, &Partitioning::balanced(), &["a", "b", "c"]).await;
+
+        let stream_id = Identifier::numeric(1).unwrap();
+        let topic_id = Identifier::numeric(1).unwrap();
+        let consumer = Consumer::new(Identifier::named("consumer").unwrap());
+        let mut offsets = Vec::new();
+        for _ in 0..3 {
+            let polled_messages = client
+                .poll_messages(
+                    &stream_id,
+                    &topic_id,
+                    Some(1),
+                    &consumer,
+                    &PollingStrategy::next(),
+                    2,
+                    true,
+                )
+                .await
+                .unwrap();
+            offsets.extend(
+                polled_messages
+                    .messages
+                    .iter()
+                    .map(|message| message.offset),
+            );
+        }
+
+        assert_eq!(offsets, vec![0, 1, 2]);
+    }
+
+    #[tokio::test]
+    async fn balanced_messages_should_be_distributed_across_partitions() {
+        let client = init_client(3).await;
+        for payload in ["a", "b", "c", "d"] {
+            send(&client, &Partitioning::balanced(), &[payload]).await;
+        }
+
+        let topic = client
+            .get_topic(
+                &Identifier::numeric(1).unwrap(),
+                &Identifier::numeric(1).unwrap(),
+            )
+            .await
+            .unwrap()
+            .unwrap();
+        let counts = topic
+            .partitions
+            .iter()
+            .map(|partition| partition.messages_count)
+            .collect::<Vec<_>>();
+        assert_eq!(counts, vec![2, 1, 1]);
+        assert_eq!(topic.messages_count, 4);
+    }
+}
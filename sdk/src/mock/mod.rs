@@ -0,0 +1,41 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+//! In-memory implementation of the client traits, which can be used in the unit tests of the applications
+//! instead of the running server. It's available with the `mock` feature enabled.
+//!
+//! The `MockClient` keeps the streams, topics, partitions, messages, consumer offsets and consumer groups in memory
+//! and behaves deterministically: the offsets and message IDs are assigned sequentially and the timestamps come
+//! from the logical clock, which only moves forward when the messages are appended or when it's advanced explicitly.
+//! The calls of any command can be failed on demand with the given error (see `MockClient::fail`),
+//! e.g. to verify the retries or the error handling of the application.
+//! The features which cannot be simulated locally (users management, personal access tokens, transactions,
+//! replays, push subscriptions, snapshots and backups) return `IggyError::FeatureUnavailable`.
+
+pub mod client;
+pub mod consumer_groups;
+pub mod consumer_offsets;
+pub mod messages;
+pub mod namespaces;
+pub mod partitions;
+pub mod personal_access_tokens;
+mod state;
+pub mod streams;
+pub mod system;
+pub mod topics;
+pub mod users;
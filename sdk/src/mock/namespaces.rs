@@ -0,0 +1,93 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::client::NamespaceClient;
+use crate::command::{
+    CREATE_NAMESPACE, CREATE_STREAM, CREATE_USER, DELETE_NAMESPACE, GET_NAMESPACE, GET_NAMESPACES,
+    UPDATE_NAMESPACE,
+};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::mock::client::MockClient;
+use crate::models::namespace::{Namespace, NamespaceDetails, NamespaceQuotas};
+use crate::models::permissions::Permissions;
+use crate::models::stream::StreamDetails;
+use crate::models::user_info::UserInfoDetails;
+use crate::models::user_status::UserStatus;
+use async_trait::async_trait;
+
+#[async_trait]
+impl NamespaceClient for MockClient {
+    async fn get_namespace(
+        &self,
+        _namespace_id: &Identifier,
+    ) -> Result<Option<NamespaceDetails>, IggyError> {
+        self.call(GET_NAMESPACE)?;
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn get_namespaces(&self) -> Result<Vec<Namespace>, IggyError> {
+        self.call(GET_NAMESPACES)?;
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn create_namespace(
+        &self,
+        _name: &str,
+        _quotas: NamespaceQuotas,
+    ) -> Result<NamespaceDetails, IggyError> {
+        self.call(CREATE_NAMESPACE)?;
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn update_namespace(
+        &self,
+        _namespace_id: &Identifier,
+        _quotas: NamespaceQuotas,
+    ) -> Result<(), IggyError> {
+        self.call(UPDATE_NAMESPACE)?;
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn delete_namespace(&self, _namespace_id: &Identifier) -> Result<(), IggyError> {
+        self.call(DELETE_NAMESPACE)?;
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn create_namespace_stream(
+        &self,
+        _namespace_id: u32,
+        _name: &str,
+        _stream_id: Option<u32>,
+    ) -> Result<StreamDetails, IggyError> {
+        self.call(CREATE_STREAM)?;
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn create_namespace_user(
+        &self,
+        _namespace_id: u32,
+        _username: &str,
+        _password: &str,
+        _status: UserStatus,
+        _permissions: Option<Permissions>,
+    ) -> Result<UserInfoDetails, IggyError> {
+        self.call(CREATE_USER)?;
+        Err(IggyError::FeatureUnavailable)
+    }
+}
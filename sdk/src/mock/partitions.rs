@@ -0,0 +1,133 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::client::PartitionClient;
+use crate::command::{
+    CREATE_PARTITIONS, DELETE_PARTITIONS, FLUSH_PARTITION, READ_SEGMENT_RANGE,
+    RESTORE_ARCHIVED_SEGMENTS,
+};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::mock::client::MockClient;
+use crate::models::restored_segments::RestoredSegments;
+use crate::models::segment_range::SegmentRange;
+use crate::models::unsaved_state::PartitionUnsavedState;
+use crate::partitions::read_segment_range::ReadSegmentRange;
+use crate::validatable::Validatable;
+use async_trait::async_trait;
+
+#[async_trait]
+impl PartitionClient for MockClient {
+    async fn create_partitions(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitions_count: u32,
+    ) -> Result<(), IggyError> {
+        self.call(CREATE_PARTITIONS)?;
+        let mut state = self.state();
+        let created_at = state.clock;
+        state
+            .get_topic_mut(stream_id, topic_id)?
+            .add_partitions(partitions_count, created_at);
+        Ok(())
+    }
+
+    async fn delete_partitions(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitions_count: u32,
+    ) -> Result<(), IggyError> {
+        self.call(DELETE_PARTITIONS)?;
+        let mut state = self.state();
+        let topic = state.get_topic_mut(stream_id, topic_id)?;
+        for _ in 0..partitions_count {
+            topic.partitions.pop_last();
+        }
+        Ok(())
+    }
+
+    async fn restore_archived_segments(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        start_offset: u64,
+        end_offset: u64,
+    ) -> Result<RestoredSegments, IggyError> {
+        self.call(RESTORE_ARCHIVED_SEGMENTS)?;
+        if start_offset > end_offset {
+            return Err(IggyError::InvalidRestoreRange);
+        }
+
+        // The mock client never archives the segments.
+        self.state()
+            .get_topic(stream_id, topic_id)?
+            .get_partition(partition_id)?;
+        Err(IggyError::ArchiverNotEnabled)
+    }
+
+    async fn flush_partition(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+    ) -> Result<Vec<PartitionUnsavedState>, IggyError> {
+        self.call(FLUSH_PARTITION)?;
+        // The mock client never has any unsaved messages to flush.
+        let state = self.state();
+        let topic = state.get_topic(stream_id, topic_id)?;
+        if let Some(partition_id) = partition_id {
+            topic.get_partition(partition_id)?;
+        }
+        Ok(Vec::new())
+    }
+
+    async fn read_segment_range(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        offset: u64,
+        start_position: u64,
+        end_position: Option<u64>,
+        max_bytes: u32,
+    ) -> Result<SegmentRange, IggyError> {
+        self.call(READ_SEGMENT_RANGE)?;
+        ReadSegmentRange {
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            partition_id,
+            offset,
+            start_position,
+            end_position,
+            max_bytes,
+        }
+        .validate()?;
+
+        // The mock client doesn't store the messages in the segment log files, so there's nothing to read.
+        self.state()
+            .get_topic(stream_id, topic_id)?
+            .get_partition(partition_id)?;
+        Ok(SegmentRange {
+            segment_closed: true,
+            ..SegmentRange::default()
+        })
+    }
+}
@@ -0,0 +1,85 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::client::PersonalAccessTokenClient;
+use crate::command::{
+    CREATE_PERSONAL_ACCESS_TOKEN, DELETE_PERSONAL_ACCESS_TOKEN, GET_PERSONAL_ACCESS_TOKENS,
+    LOGIN_WITH_PERSONAL_ACCESS_TOKEN, ROTATE_PERSONAL_ACCESS_TOKEN,
+};
+use crate::error::IggyError;
+use crate::mock::client::MockClient;
+use crate::mock::users::USER_ID;
+use crate::models::identity_info::IdentityInfo;
+use crate::models::personal_access_token::{
+    PersonalAccessTokenInfo, PersonalAccessTokenScope, RawPersonalAccessToken,
+};
+use crate::utils::personal_access_token_expiry::PersonalAccessTokenExpiry;
+use async_trait::async_trait;
+
+#[async_trait]
+impl PersonalAccessTokenClient for MockClient {
+    async fn get_personal_access_tokens(&self) -> Result<Vec<PersonalAccessTokenInfo>, IggyError> {
+        self.call(GET_PERSONAL_ACCESS_TOKENS)?;
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn create_personal_access_token(
+        &self,
+        _name: &str,
+        _expiry: PersonalAccessTokenExpiry,
+    ) -> Result<RawPersonalAccessToken, IggyError> {
+        self.call(CREATE_PERSONAL_ACCESS_TOKEN)?;
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn create_scoped_personal_access_token(
+        &self,
+        _name: &str,
+        _expiry: PersonalAccessTokenExpiry,
+        _scope: &PersonalAccessTokenScope,
+    ) -> Result<RawPersonalAccessToken, IggyError> {
+        self.call(CREATE_PERSONAL_ACCESS_TOKEN)?;
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn delete_personal_access_token(&self, _name: &str) -> Result<(), IggyError> {
+        self.call(DELETE_PERSONAL_ACCESS_TOKEN)?;
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn rotate_personal_access_token(
+        &self,
+        _name: &str,
+        _expiry: PersonalAccessTokenExpiry,
+    ) -> Result<RawPersonalAccessToken, IggyError> {
+        self.call(ROTATE_PERSONAL_ACCESS_TOKEN)?;
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn login_with_personal_access_token(
+        &self,
+        _token: &str,
+    ) -> Result<IdentityInfo, IggyError> {
+        self.call(LOGIN_WITH_PERSONAL_ACCESS_TOKEN)?;
+        self.state().user_id = Some(USER_ID);
+        Ok(IdentityInfo {
+            user_id: USER_ID,
+            access_token: None,
+        })
+    }
+}
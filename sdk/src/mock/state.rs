@@ -0,0 +1,1244 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::compression::compression_algorithm::CompressionAlgorithm;
+use crate::consumer::{Consumer, ConsumerKind};
+use crate::consumer_groups::offset_recovery_policy::OffsetRecoveryPolicy;
+use crate::error::IggyError;
+use crate::identifier::{IdKind, Identifier};
+use crate::messages::message_filter::MessageFilter;
+use crate::messages::message_id_scheme::MessageIdScheme;
+use crate::messages::poll_messages::{PollingKind, PollingStrategy};
+use crate::messages::send_messages::{Message, Partitioning, PartitioningKind};
+use crate::messages::send_messages_batch::TopicMessages;
+use crate::models::consumer_group::{
+    ConsumerGroup, ConsumerGroupDeadLetter, ConsumerGroupDetails, ConsumerGroupMember,
+    PartitionAssignmentStrategy,
+};
+use crate::models::header::{HeaderKey, HeaderValue};
+use crate::models::maintenance_mode::MaintenanceMode;
+use crate::models::message_size_distribution::MessageSizeDistribution;
+use crate::models::messages::{MessageState, PolledMessage, PolledMessages};
+use crate::models::metadata::ResourceMetadata;
+use crate::models::partition::Partition;
+use crate::models::stream::{Stream, StreamDetails, StreamQuota};
+use crate::models::topic::{Topic, TopicDetails};
+use crate::topics::cleanup_policy::CleanupPolicy;
+use crate::topics::topic_config::TopicConfig;
+use crate::utils::checksum;
+use crate::utils::duration::IggyDuration;
+use crate::utils::expiry::IggyExpiry;
+use crate::utils::sizeable::Sizeable;
+use crate::utils::topic_size::MaxTopicSize;
+use ahash::AHashMap;
+use bytes::Bytes;
+use std::collections::{BTreeMap, HashMap};
+
+/// The ID of the (only) client connected to the mock, also used as the ID of the consumer group member.
+pub(crate) const CLIENT_ID: u32 = 1;
+
+/// The initial value (in microseconds) of the logical clock.
+const CLOCK_START: u64 = 1_000_000;
+
+#[derive(Debug)]
+pub(crate) struct MockState {
+    pub clock: u64,
+    pub next_message_id: u128,
+    pub user_id: Option<u32>,
+    pub maintenance_mode: MaintenanceMode,
+    pub streams: BTreeMap<u32, MockStream>,
+}
+
+#[derive(Debug)]
+pub(crate) struct MockStream {
+    pub id: u32,
+    pub name: String,
+    pub created_at: u64,
+    pub metadata: ResourceMetadata,
+    pub quota: StreamQuota,
+    pub topics: BTreeMap<u32, MockTopic>,
+}
+
+#[derive(Debug)]
+pub(crate) struct MockTopic {
+    pub stream_id: u32,
+    pub id: u32,
+    pub name: String,
+    pub created_at: u64,
+    pub compression_algorithm: CompressionAlgorithm,
+    pub replication_factor: u8,
+    pub message_expiry: IggyExpiry,
+    pub max_topic_size: MaxTopicSize,
+    pub max_segments: u32,
+    pub config: TopicConfig,
+    pub metadata: ResourceMetadata,
+    pub allowed_producers: Vec<u32>,
+    pub delete_at: Option<u64>,
+    pub last_partition_id: u32,
+    pub partitions: BTreeMap<u32, MockPartition>,
+    pub consumer_groups: BTreeMap<u32, MockConsumerGroup>,
+}
+
+#[derive(Debug)]
+pub(crate) struct MockPartition {
+    pub id: u32,
+    pub created_at: u64,
+    pub next_offset: u64,
+    /// Incremented whenever the partition is purged and its offsets start over, like on the server.
+    pub epoch: u64,
+    pub messages: Vec<MockMessage>,
+    pub offsets: AHashMap<ConsumerKey, u64>,
+}
+
+#[derive(Debug)]
+pub(crate) struct MockMessage {
+    pub offset: u64,
+    pub timestamp: u64,
+    pub id: u128,
+    pub checksum: u32,
+    pub headers: Option<HashMap<HeaderKey, HeaderValue>>,
+    pub payload: Bytes,
+    pub size: u64,
+}
+
+#[derive(Debug)]
+pub(crate) struct MockConsumerGroup {
+    pub id: u32,
+    pub name: String,
+    pub offset_recovery: OffsetRecoveryPolicy,
+    pub dead_letter: Option<(u32, u32, u32)>,
+    pub visibility_timeout: Option<IggyDuration>,
+    pub joined: bool,
+    pub generation: u32,
+    pub current_partition_id: u32,
+    /// The state stores of the partitions, all of them are assigned to the single mocked member.
+    pub state: BTreeMap<u32, BTreeMap<String, Bytes>>,
+}
+
+/// The owner of the stored offset, the regular consumers are identified by their (numeric or named) ID.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) enum ConsumerKey {
+    Consumer(String),
+    ConsumerGroup(u32),
+}
+
+/// The arguments of the poll, which can be passed to the mock by any of the polling methods.
+pub(crate) struct PollArgs<'a> {
+    pub partition_id: Option<u32>,
+    pub consumer: &'a Consumer,
+    pub strategy: &'a PollingStrategy,
+    pub count: u32,
+    pub auto_commit: bool,
+    pub chunk_size: u32,
+    pub filter: Option<&'a MessageFilter>,
+    pub partition_epoch: Option<u64>,
+    pub peek: bool,
+}
+
+impl ConsumerKey {
+    /// Returns the numeric ID of the consumer, or 0 if the regular consumer is identified by name.
+    pub fn get_id(&self) -> u32 {
+        match self {
+            ConsumerKey::Consumer(id) => id.parse().unwrap_or_default(),
+            ConsumerKey::ConsumerGroup(id) => *id,
+        }
+    }
+}
+
+impl Default for MockState {
+    fn default() -> Self {
+        Self {
+            clock: CLOCK_START,
+            next_message_id: 1,
+            user_id: None,
+            maintenance_mode: MaintenanceMode::default(),
+            streams: BTreeMap::new(),
+        }
+    }
+}
+
+impl MockState {
+    pub fn get_stream(&self, stream_id: &Identifier) -> Result<&MockStream, IggyError> {
+        match stream_id.kind {
+            IdKind::Numeric => {
+                let id = stream_id.get_u32_value()?;
+                self.streams.get(&id).ok_or(IggyError::StreamIdNotFound(id))
+            }
+            IdKind::String => {
+                let name = stream_id.get_cow_str_value()?;
+                self.streams
+                    .values()
+                    .find(|stream| stream.name == name)
+                    .ok_or_else(|| IggyError::StreamNameNotFound(name.to_string()))
+            }
+        }
+    }
+
+    pub fn get_stream_mut(&mut self, stream_id: &Identifier) -> Result<&mut MockStream, IggyError> {
+        let id = self.get_stream(stream_id)?.id;
+        Ok(self.streams.get_mut(&id).unwrap())
+    }
+
+    pub fn get_topic(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<&MockTopic, IggyError> {
+        self.get_stream(stream_id)?.get_topic(topic_id)
+    }
+
+    pub fn get_topic_mut(
+        &mut self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<&mut MockTopic, IggyError> {
+        self.get_stream_mut(stream_id)?.get_topic_mut(topic_id)
+    }
+
+    pub fn create_stream(
+        &mut self,
+        name: &str,
+        stream_id: Option<u32>,
+        metadata: ResourceMetadata,
+    ) -> Result<&MockStream, IggyError> {
+        if self.streams.values().any(|stream| stream.name == name) {
+            return Err(IggyError::StreamNameAlreadyExists(name.to_owned()));
+        }
+
+        let id = match stream_id {
+            Some(id) if self.streams.contains_key(&id) => {
+                return Err(IggyError::StreamIdAlreadyExists(id));
+            }
+            Some(id) => id,
+            None => self.streams.keys().last().copied().unwrap_or_default() + 1,
+        };
+        let stream = MockStream {
+            id,
+            name: name.to_owned(),
+            created_at: self.clock,
+            metadata,
+            quota: StreamQuota::default(),
+            topics: BTreeMap::new(),
+        };
+        Ok(self.streams.entry(id).or_insert(stream))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_topic(
+        &mut self,
+        stream_id: &Identifier,
+        name: &str,
+        partitions_count: u32,
+        compression_algorithm: CompressionAlgorithm,
+        replication_factor: Option<u8>,
+        topic_id: Option<u32>,
+        message_expiry: IggyExpiry,
+        max_topic_size: MaxTopicSize,
+        metadata: ResourceMetadata,
+    ) -> Result<&MockTopic, IggyError> {
+        let created_at = self.clock;
+        let stream = self.get_stream_mut(stream_id)?;
+        if stream.topics.values().any(|topic| topic.name == name) {
+            return Err(IggyError::TopicNameAlreadyExists(
+                name.to_owned(),
+                stream.id,
+            ));
+        }
+
+        let id = match topic_id {
+            Some(id) if stream.topics.contains_key(&id) => {
+                return Err(IggyError::TopicIdAlreadyExists(id, stream.id));
+            }
+            Some(id) => id,
+            None => stream.topics.keys().last().copied().unwrap_or_default() + 1,
+        };
+        let mut topic = MockTopic {
+            stream_id: stream.id,
+            id,
+            name: name.to_owned(),
+            created_at,
+            compression_algorithm,
+            replication_factor: replication_factor.unwrap_or(1),
+            message_expiry,
+            max_topic_size,
+            max_segments: 0,
+            config: TopicConfig::default()
+                .with_topic_settings(message_expiry, compression_algorithm),
+            metadata,
+            allowed_producers: Vec::new(),
+            delete_at: None,
+            last_partition_id: 0,
+            partitions: BTreeMap::new(),
+            consumer_groups: BTreeMap::new(),
+        };
+        topic.add_partitions(partitions_count, created_at);
+        Ok(stream.topics.entry(id).or_insert(topic))
+    }
+
+    pub fn send_messages(
+        &mut self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitioning: &Partitioning,
+        messages: &[Message],
+    ) -> Result<(), IggyError> {
+        if messages.is_empty() {
+            return Err(IggyError::InvalidMessagesCount);
+        }
+
+        let mut clock = self.clock;
+        let mut next_message_id = self.next_message_id;
+        let topic = self.get_topic_mut(stream_id, topic_id)?;
+        if topic.delete_at.is_some() {
+            return Err(IggyError::TopicMarkedForDeletion(topic.stream_id, topic.id));
+        }
+
+        let partition_id = topic.get_partition_id(partitioning)?;
+        let partition = topic.get_partition_mut(partition_id)?;
+        for message in messages {
+            clock += 1;
+            let id = if message.id == 0 {
+                next_message_id += 1;
+                next_message_id - 1
+            } else {
+                message.id
+            };
+            partition.messages.push(MockMessage {
+                offset: partition.next_offset,
+                timestamp: clock,
+                id,
+                checksum: checksum::calculate(&message.payload),
+                headers: message.headers.clone(),
+                payload: message.payload.clone(),
+                size: message.get_size_bytes().as_bytes_u64(),
+            });
+            partition.next_offset += 1;
+        }
+        self.clock = clock;
+        self.next_message_id = next_message_id;
+        Ok(())
+    }
+
+    /// Sends the messages to many topics, none of them is sent if any of the batches would be rejected.
+    pub fn send_messages_batch(&mut self, batches: &[TopicMessages]) -> Result<(), IggyError> {
+        for batch in batches {
+            if batch.messages.is_empty() {
+                return Err(IggyError::InvalidMessagesCount);
+            }
+
+            let topic = self.get_topic(&batch.stream_id, &batch.topic_id)?;
+            if topic.delete_at.is_some() {
+                return Err(IggyError::TopicMarkedForDeletion(topic.stream_id, topic.id));
+            }
+
+            if topic.partitions.is_empty() {
+                return Err(IggyError::NoPartitions(topic.id, topic.stream_id));
+            }
+
+            if batch.partitioning.kind == PartitioningKind::PartitionId {
+                let partition_id = u32::from_le_bytes(
+                    batch
+                        .partitioning
+                        .value
+                        .get(..4)
+                        .and_then(|value| value.try_into().ok())
+                        .ok_or(IggyError::InvalidNumberEncoding)?,
+                );
+                topic.get_partition(partition_id)?;
+            }
+        }
+
+        for batch in batches {
+            self.send_messages(
+                &batch.stream_id,
+                &batch.topic_id,
+                &batch.partitioning,
+                &batch.messages,
+            )?;
+        }
+        Ok(())
+    }
+
+    pub fn poll_messages(
+        &mut self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        args: PollArgs,
+    ) -> Result<PolledMessages, IggyError> {
+        let topic = self.get_topic_mut(stream_id, topic_id)?;
+        let key = topic.get_consumer_key(args.consumer)?;
+        let partition_id = match (&key, args.partition_id) {
+            (_, Some(partition_id)) => partition_id,
+            (ConsumerKey::Consumer(_), None) => 1,
+            (ConsumerKey::ConsumerGroup(group_id), None) => {
+                let partition_id = match args.peek {
+                    true => topic.get_current_group_partition_id(*group_id)?,
+                    false => topic.get_next_group_partition_id(*group_id)?,
+                };
+                match partition_id {
+                    Some(partition_id) => partition_id,
+                    None => {
+                        return Ok(PolledMessages {
+                            partition_id: 0,
+                            current_offset: 0,
+                            remaining_messages: 0,
+                            gaps: Vec::new(),
+                            chunk_sizes: Vec::new(),
+                            partition_epoch: 0,
+                            throttle_time_ms: 0,
+                            topic_delete_at: topic.delete_at.unwrap_or_default(),
+                            messages: Vec::new(),
+                        })
+                    }
+                }
+            }
+        };
+
+        let topic_delete_at = topic.delete_at.unwrap_or_default();
+        let partition = topic.get_partition_mut(partition_id)?;
+        if let Some(expected_epoch) = args.partition_epoch {
+            if expected_epoch > 0 && expected_epoch != partition.epoch {
+                return Err(IggyError::PartitionEpochChanged(
+                    partition_id,
+                    expected_epoch,
+                    partition.epoch,
+                ));
+            }
+        }
+
+        let start_offset = match args.strategy.kind {
+            PollingKind::Offset => args.strategy.value,
+            PollingKind::Timestamp => partition
+                .messages
+                .iter()
+                .find(|message| message.timestamp >= args.strategy.value)
+                .map(|message| message.offset)
+                .unwrap_or(partition.next_offset),
+            PollingKind::First => partition
+                .messages
+                .first()
+                .map(|message| message.offset)
+                .unwrap_or_default(),
+            PollingKind::Last => partition.next_offset.saturating_sub(args.count as u64),
+            PollingKind::Next => partition
+                .offsets
+                .get(&key)
+                .map(|offset| offset + 1)
+                .unwrap_or_default(),
+        };
+
+        let position = partition
+            .messages
+            .partition_point(|message| message.offset < start_offset);
+        let messages = partition.messages[position..]
+            .iter()
+            .map(MockMessage::to_polled_message)
+            .filter(|message| args.filter.is_none_or(|filter| filter.matches(message)))
+            .take(args.count as usize)
+            .collect::<Vec<_>>();
+
+        let current_offset = partition.get_current_offset();
+        let last_offset = messages.last().map(|message| message.offset);
+        if let (true, false, Some(offset)) = (args.auto_commit, args.peek, last_offset) {
+            partition.offsets.insert(key, offset);
+        }
+
+        let chunk_sizes = if args.chunk_size > 0 {
+            messages
+                .chunks(args.chunk_size as usize)
+                .map(|chunk| chunk.len() as u32)
+                .collect()
+        } else {
+            Vec::new()
+        };
+        Ok(PolledMessages {
+            partition_id,
+            current_offset,
+            remaining_messages: last_offset
+                .map(|offset| current_offset - offset)
+                .unwrap_or_default(),
+            gaps: Vec::new(),
+            chunk_sizes,
+            partition_epoch: partition.epoch,
+            throttle_time_ms: 0,
+            topic_delete_at,
+            messages,
+        })
+    }
+}
+
+impl MockStream {
+    pub fn get_topic(&self, topic_id: &Identifier) -> Result<&MockTopic, IggyError> {
+        match topic_id.kind {
+            IdKind::Numeric => {
+                let id = topic_id.get_u32_value()?;
+                self.topics
+                    .get(&id)
+                    .ok_or(IggyError::TopicIdNotFound(id, self.id))
+            }
+            IdKind::String => {
+                let name = topic_id.get_cow_str_value()?;
+                self.topics
+                    .values()
+                    .find(|topic| topic.name == name)
+                    .ok_or_else(|| {
+                        IggyError::TopicNameNotFound(name.to_string(), self.id.to_string())
+                    })
+            }
+        }
+    }
+
+    pub fn get_topic_mut(&mut self, topic_id: &Identifier) -> Result<&mut MockTopic, IggyError> {
+        let id = self.get_topic(topic_id)?.id;
+        Ok(self.topics.get_mut(&id).unwrap())
+    }
+
+    pub fn get_size_bytes(&self) -> u64 {
+        self.topics.values().map(MockTopic::get_size_bytes).sum()
+    }
+
+    pub fn get_messages_count(&self) -> u64 {
+        self.topics
+            .values()
+            .map(MockTopic::get_messages_count)
+            .sum()
+    }
+
+    pub fn to_stream(&self) -> Stream {
+        Stream {
+            id: self.id,
+            created_at: self.created_at.into(),
+            name: self.name.clone(),
+            size: self.get_size_bytes().into(),
+            messages_count: self.get_messages_count(),
+            topics_count: self.topics.len() as u32,
+            metadata: self.metadata.clone(),
+            quota: self.quota,
+        }
+    }
+
+    pub fn to_stream_details(&self) -> StreamDetails {
+        StreamDetails {
+            id: self.id,
+            created_at: self.created_at.into(),
+            name: self.name.clone(),
+            size: self.get_size_bytes().into(),
+            messages_count: self.get_messages_count(),
+            topics_count: self.topics.len() as u32,
+            metadata: self.metadata.clone(),
+            quota: self.quota,
+            topics: self.topics.values().map(MockTopic::to_topic).collect(),
+        }
+    }
+}
+
+impl MockTopic {
+    pub fn get_partition(&self, partition_id: u32) -> Result<&MockPartition, IggyError> {
+        self.partitions
+            .get(&partition_id)
+            .ok_or(IggyError::PartitionNotFound(
+                partition_id,
+                self.id,
+                self.stream_id,
+            ))
+    }
+
+    pub fn get_partition_mut(
+        &mut self,
+        partition_id: u32,
+    ) -> Result<&mut MockPartition, IggyError> {
+        self.partitions
+            .get_mut(&partition_id)
+            .ok_or(IggyError::PartitionNotFound(
+                partition_id,
+                self.id,
+                self.stream_id,
+            ))
+    }
+
+    pub fn get_consumer_group(
+        &self,
+        group_id: &Identifier,
+    ) -> Result<&MockConsumerGroup, IggyError> {
+        match group_id.kind {
+            IdKind::Numeric => {
+                let id = group_id.get_u32_value()?;
+                self.consumer_groups
+                    .get(&id)
+                    .ok_or(IggyError::ConsumerGroupIdNotFound(id, self.id))
+            }
+            IdKind::String => {
+                let name = group_id.get_cow_str_value()?;
+                self.consumer_groups
+                    .values()
+                    .find(|group| group.name == name)
+                    .ok_or_else(|| {
+                        IggyError::ConsumerGroupNameNotFound(name.to_string(), self.id.to_string())
+                    })
+            }
+        }
+    }
+
+    pub fn get_consumer_group_mut(
+        &mut self,
+        group_id: &Identifier,
+    ) -> Result<&mut MockConsumerGroup, IggyError> {
+        let id = self.get_consumer_group(group_id)?.id;
+        Ok(self.consumer_groups.get_mut(&id).unwrap())
+    }
+
+    pub fn get_partition_state_mut(
+        &mut self,
+        group_id: &Identifier,
+        partition_id: u32,
+    ) -> Result<&mut BTreeMap<String, Bytes>, IggyError> {
+        self.get_partition(partition_id)?;
+        let topic_id = self.id;
+        let group = self.get_consumer_group_mut(group_id)?;
+        if !group.joined {
+            return Err(IggyError::ConsumerGroupMemberNotFound(
+                CLIENT_ID, group.id, topic_id,
+            ));
+        }
+
+        Ok(group.state.entry(partition_id).or_default())
+    }
+
+    pub fn get_consumer_key(&self, consumer: &Consumer) -> Result<ConsumerKey, IggyError> {
+        match consumer.kind {
+            ConsumerKind::Consumer => Ok(ConsumerKey::Consumer(consumer.id.as_string())),
+            ConsumerKind::ConsumerGroup => Ok(ConsumerKey::ConsumerGroup(
+                self.get_consumer_group(&consumer.id)?.id,
+            )),
+        }
+    }
+
+    /// Returns the partition the stored offset of the consumer refers to, if it's not provided explicitly.
+    pub fn get_offset_partition_id(
+        &self,
+        key: &ConsumerKey,
+        partition_id: Option<u32>,
+    ) -> Result<u32, IggyError> {
+        match (key, partition_id) {
+            (_, Some(partition_id)) => Ok(partition_id),
+            (ConsumerKey::Consumer(_), None) => Ok(1),
+            (ConsumerKey::ConsumerGroup(group_id), None) => {
+                let group = &self.consumer_groups[group_id];
+                if !group.joined {
+                    return Err(IggyError::ConsumerGroupMemberNotFound(
+                        CLIENT_ID, group.id, self.id,
+                    ));
+                }
+                Ok(group.current_partition_id.max(1))
+            }
+        }
+    }
+
+    /// Returns the next partition polled by the (only) member of the consumer group, in the round-robin fashion.
+    fn get_next_group_partition_id(&mut self, group_id: u32) -> Result<Option<u32>, IggyError> {
+        let group = self.consumer_groups.get_mut(&group_id).unwrap();
+        if !group.joined {
+            return Err(IggyError::ConsumerGroupMemberNotFound(
+                CLIENT_ID, group.id, self.id,
+            ));
+        }
+
+        let partition_id = self
+            .partitions
+            .range(group.current_partition_id + 1..)
+            .chain(self.partitions.iter())
+            .map(|(id, _)| *id)
+            .next();
+        if let Some(partition_id) = partition_id {
+            group.current_partition_id = partition_id;
+        }
+        Ok(partition_id)
+    }
+
+    /// Returns the partition the group has polled last (or the first one), without moving to the next one.
+    fn get_current_group_partition_id(&self, group_id: u32) -> Result<Option<u32>, IggyError> {
+        let group = self.consumer_groups.get(&group_id).unwrap();
+        if !group.joined {
+            return Err(IggyError::ConsumerGroupMemberNotFound(
+                CLIENT_ID, group.id, self.id,
+            ));
+        }
+
+        if self.partitions.contains_key(&group.current_partition_id) {
+            return Ok(Some(group.current_partition_id));
+        }
+
+        Ok(self.partitions.keys().next().copied())
+    }
+
+    fn get_partition_id(&mut self, partitioning: &Partitioning) -> Result<u32, IggyError> {
+        let partitions_count = self.partitions.len() as u32;
+        if partitions_count == 0 {
+            return Err(IggyError::NoPartitions(self.id, self.stream_id));
+        }
+
+        let partition_id = match partitioning.kind {
+            PartitioningKind::Balanced => {
+                let partition_id = self
+                    .partitions
+                    .range(self.last_partition_id + 1..)
+                    .chain(self.partitions.iter())
+                    .map(|(id, _)| *id)
+                    .next()
+                    .unwrap();
+                self.last_partition_id = partition_id;
+                partition_id
+            }
+            PartitioningKind::PartitionId => u32::from_le_bytes(
+                partitioning
+                    .value
+                    .get(..4)
+                    .and_then(|value| value.try_into().ok())
+                    .ok_or(IggyError::InvalidNumberEncoding)?,
+            ),
+            // The checksum is used instead of the server's hash function, so the chosen partition is stable,
+            // but it may differ from the one the server would choose for the same key.
+            PartitioningKind::MessagesKey => {
+                match checksum::calculate(&partitioning.value) % partitions_count {
+                    0 => partitions_count,
+                    partition_id => partition_id,
+                }
+            }
+        };
+        Ok(partition_id)
+    }
+
+    pub fn add_partitions(&mut self, partitions_count: u32, created_at: u64) {
+        let first_id = self.partitions.keys().last().copied().unwrap_or_default() + 1;
+        for id in first_id..first_id + partitions_count {
+            self.partitions.insert(
+                id,
+                MockPartition {
+                    id,
+                    created_at,
+                    next_offset: 0,
+                    epoch: 1,
+                    messages: Vec::new(),
+                    offsets: AHashMap::new(),
+                },
+            );
+        }
+    }
+
+    pub fn purge(&mut self, keep_consumer_offsets: bool, older_than: Option<u64>) {
+        for partition in self.partitions.values_mut() {
+            match older_than {
+                Some(timestamp) => partition
+                    .messages
+                    .retain(|message| message.timestamp >= timestamp),
+                None => {
+                    partition.messages.clear();
+                    partition.next_offset = 0;
+                    partition.epoch += 1;
+                }
+            }
+            if !keep_consumer_offsets {
+                partition.offsets.clear();
+            }
+        }
+    }
+
+    pub fn get_size_bytes(&self) -> u64 {
+        self.partitions
+            .values()
+            .map(MockPartition::get_size_bytes)
+            .sum()
+    }
+
+    pub fn get_messages_count(&self) -> u64 {
+        self.partitions
+            .values()
+            .map(|partition| partition.messages.len() as u64)
+            .sum()
+    }
+
+    pub fn to_topic(&self) -> Topic {
+        Topic {
+            id: self.id,
+            created_at: self.created_at.into(),
+            name: self.name.clone(),
+            size: self.get_size_bytes().into(),
+            message_expiry: self.message_expiry,
+            compression_algorithm: self.compression_algorithm,
+            max_topic_size: self.max_topic_size,
+            max_segments: self.max_segments,
+            config: self.config,
+            replication_factor: self.replication_factor,
+            messages_count: self.get_messages_count(),
+            partitions_count: self.partitions.len() as u32,
+            metadata: self.metadata.clone(),
+            message_id_scheme: MessageIdScheme::default(),
+            cleanup_policy: CleanupPolicy::default(),
+            allowed_producers: self.allowed_producers.clone(),
+            delete_at: self.delete_at.map(Into::into),
+        }
+    }
+
+    pub fn to_topic_details(&self) -> TopicDetails {
+        TopicDetails {
+            id: self.id,
+            created_at: self.created_at.into(),
+            name: self.name.clone(),
+            size: self.get_size_bytes().into(),
+            message_expiry: self.message_expiry,
+            compression_algorithm: self.compression_algorithm,
+            max_topic_size: self.max_topic_size,
+            max_segments: self.max_segments,
+            config: self.config,
+            replication_factor: self.replication_factor,
+            messages_count: self.get_messages_count(),
+            partitions_count: self.partitions.len() as u32,
+            metadata: self.metadata.clone(),
+            message_id_scheme: MessageIdScheme::default(),
+            cleanup_policy: CleanupPolicy::default(),
+            allowed_producers: self.allowed_producers.clone(),
+            delete_at: self.delete_at.map(Into::into),
+            message_sizes: MessageSizeDistribution::from_sizes(
+                self.partitions
+                    .values()
+                    .flat_map(|partition| partition.messages.iter().map(|message| message.size)),
+            ),
+            partitions: self
+                .partitions
+                .values()
+                .map(MockPartition::to_partition)
+                .collect(),
+        }
+    }
+
+    pub fn to_consumer_group(&self, group: &MockConsumerGroup) -> ConsumerGroup {
+        ConsumerGroup {
+            id: group.id,
+            name: group.name.clone(),
+            partitions_count: self.partitions.len() as u32,
+            members_count: group.joined as u32,
+        }
+    }
+
+    pub fn to_consumer_group_details(&self, group: &MockConsumerGroup) -> ConsumerGroupDetails {
+        let partitions_count = self.partitions.len() as u32;
+        let members = if group.joined {
+            vec![ConsumerGroupMember {
+                id: CLIENT_ID,
+                partitions_count,
+                partitions: self.partitions.keys().copied().collect(),
+            }]
+        } else {
+            Vec::new()
+        };
+        ConsumerGroupDetails {
+            id: group.id,
+            name: group.name.clone(),
+            partitions_count,
+            members_count: members.len() as u32,
+            members,
+            rebalances: Vec::new(),
+            generation: group.generation,
+            assignment_strategy: PartitionAssignmentStrategy::default(),
+            offset_recovery: group.offset_recovery,
+            dead_letter: group
+                .dead_letter
+                .map(
+                    |(stream_id, topic_id, max_delivery_count)| ConsumerGroupDeadLetter {
+                        stream_id,
+                        topic_id,
+                        max_delivery_count,
+                    },
+                ),
+            visibility_timeout: group.visibility_timeout,
+        }
+    }
+}
+
+impl MockPartition {
+    /// Returns the offset of the last appended message, or 0 if the partition is empty.
+    pub fn get_current_offset(&self) -> u64 {
+        self.next_offset.saturating_sub(1)
+    }
+
+    pub fn get_size_bytes(&self) -> u64 {
+        self.messages.iter().map(|message| message.size).sum()
+    }
+
+    pub fn to_partition(&self) -> Partition {
+        Partition {
+            id: self.id,
+            created_at: self.created_at.into(),
+            segments_count: 1,
+            current_offset: self.get_current_offset(),
+            size: self.get_size_bytes().into(),
+            messages_count: self.messages.len() as u64,
+            recovery: None,
+        }
+    }
+}
+
+impl MockMessage {
+    fn to_polled_message(&self) -> PolledMessage {
+        PolledMessage {
+            offset: self.offset,
+            state: MessageState::Available,
+            timestamp: self.timestamp,
+            id: self.id,
+            checksum: self.checksum,
+            headers: self.headers.clone(),
+            length: (self.payload.len() as u64).into(),
+            payload: self.payload.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn init_state(partitions_count: u32) -> MockState {
+        let mut state = MockState::default();
+        state
+            .create_stream("stream", None, ResourceMetadata::default())
+            .unwrap();
+        state
+            .create_topic(
+                &Identifier::numeric(1).unwrap(),
+                "topic",
+                partitions_count,
+                CompressionAlgorithm::None,
+                None,
+                None,
+                IggyExpiry::NeverExpire,
+                MaxTopicSize::ServerDefault,
+                ResourceMetadata::default(),
+            )
+            .unwrap();
+        state
+    }
+
+    fn messages(payloads: &[&str]) -> Vec<Message> {
+        payloads
+            .iter()
+            .map(|payload| Message::from_str(payload).unwrap())
+            .collect()
+    }
+
+    fn send(
+        state: &mut MockState,
+        partitioning: &Partitioning,
+        payloads: &[&str],
+    ) -> Result<(), IggyError> {
+        state.send_messages(
+            &Identifier::numeric(1).unwrap(),
+            &Identifier::numeric(1).unwrap(),
+            partitioning,
+            &messages(payloads),
+        )
+    }
+
+    fn poll(
+        state: &mut MockState,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        auto_commit: bool,
+        peek: bool,
+        partition_epoch: Option<u64>,
+    ) -> Result<PolledMessages, IggyError> {
+        state.poll_messages(
+            &Identifier::numeric(1).unwrap(),
+            &Identifier::numeric(1).unwrap(),
+            PollArgs {
+                partition_id: Some(1),
+                consumer,
+                strategy,
+                count: 10,
+                auto_commit,
+                chunk_size: 0,
+                filter: None,
+                partition_epoch,
+                peek,
+            },
+        )
+    }
+
+    fn payloads(polled_messages: &PolledMessages) -> Vec<&[u8]> {
+        polled_messages
+            .messages
+            .iter()
+            .map(|message| &message.payload[..])
+            .collect()
+    }
+
+    #[test]
+    fn create_stream_should_assign_next_id_and_reject_duplicates() {
+        let mut state = init_state(1);
+
+        let stream = state
+            .create_stream("other", None, ResourceMetadata::default())
+            .unwrap();
+        assert_eq!(stream.id, 2);
+        assert!(matches!(
+            state.create_stream("other", None, ResourceMetadata::default()),
+            Err(IggyError::StreamNameAlreadyExists(_))
+        ));
+        assert!(matches!(
+            state.create_stream("another", Some(1), ResourceMetadata::default()),
+            Err(IggyError::StreamIdAlreadyExists(1))
+        ));
+    }
+
+    #[test]
+    fn balanced_partitioning_should_send_messages_to_partitions_in_round_robin() {
+        let mut state = init_state(3);
+
+        for payload in ["a", "b", "c", "d"] {
+            send(&mut state, &Partitioning::balanced(), &[payload]).unwrap();
+        }
+
+        let topic = &state.streams[&1].topics[&1];
+        let counts = topic
+            .partitions
+            .values()
+            .map(|partition| partition.messages.len())
+            .collect::<Vec<_>>();
+        assert_eq!(counts, vec![2, 1, 1]);
+    }
+
+    #[test]
+    fn messages_key_partitioning_should_always_choose_the_same_partition() {
+        let mut state = init_state(3);
+        let partitioning = Partitioning::messages_key_str("key").unwrap();
+
+        send(&mut state, &partitioning, &["a"]).unwrap();
+        send(&mut state, &partitioning, &["b"]).unwrap();
+
+        let topic = &state.streams[&1].topics[&1];
+        let partitions = topic
+            .partitions
+            .values()
+            .filter(|partition| !partition.messages.is_empty())
+            .map(|partition| (partition.id, partition.messages.len()))
+            .collect::<Vec<_>>();
+        assert_eq!(partitions.len(), 1);
+        assert_eq!(partitions[0].1, 2);
+    }
+
+    #[test]
+    fn send_messages_batch_should_not_send_any_messages_if_any_batch_is_rejected() {
+        let mut state = init_state(1);
+        let batches = vec![
+            TopicMessages {
+                stream_id: Identifier::numeric(1).unwrap(),
+                topic_id: Identifier::numeric(1).unwrap(),
+                partitioning: Partitioning::partition_id(1),
+                messages: messages(&["a"]),
+            },
+            TopicMessages {
+                stream_id: Identifier::numeric(1).unwrap(),
+                topic_id: Identifier::numeric(1).unwrap(),
+                partitioning: Partitioning::partition_id(2),
+                messages: messages(&["b"]),
+            },
+        ];
+
+        let result = state.send_messages_batch(&batches);
+
+        assert!(matches!(result, Err(IggyError::PartitionNotFound(2, 1, 1))));
+        assert_eq!(state.streams[&1].get_messages_count(), 0);
+    }
+
+    #[test]
+    fn sending_messages_to_topic_marked_for_deletion_should_fail() {
+        let mut state = init_state(1);
+        state
+            .streams
+            .get_mut(&1)
+            .unwrap()
+            .topics
+            .get_mut(&1)
+            .unwrap()
+            .delete_at = Some(1);
+
+        let result = send(&mut state, &Partitioning::partition_id(1), &["a"]);
+
+        assert!(matches!(
+            result,
+            Err(IggyError::TopicMarkedForDeletion(1, 1))
+        ));
+    }
+
+    #[test]
+    fn polling_next_messages_with_auto_commit_should_store_offset_unless_peeking() {
+        let mut state = init_state(1);
+        send(&mut state, &Partitioning::partition_id(1), &["a", "b", "c"]).unwrap();
+        let consumer = Consumer::default();
+
+        let peeked = poll(
+            &mut state,
+            &consumer,
+            &PollingStrategy::next(),
+            true,
+            true,
+            None,
+        )
+        .unwrap();
+        let polled = poll(
+            &mut state,
+            &consumer,
+            &PollingStrategy::next(),
+            true,
+            false,
+            None,
+        )
+        .unwrap();
+        let polled_again = poll(
+            &mut state,
+            &consumer,
+            &PollingStrategy::next(),
+            true,
+            false,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(payloads(&peeked), vec![b"a", b"b", b"c"]);
+        assert_eq!(payloads(&polled), vec![b"a", b"b", b"c"]);
+        assert!(polled_again.messages.is_empty());
+        assert_eq!(polled_again.current_offset, 2);
+    }
+
+    #[test]
+    fn polling_by_timestamp_should_start_at_first_message_not_older_than_it() {
+        let mut state = init_state(1);
+        send(&mut state, &Partitioning::partition_id(1), &["a", "b", "c"]).unwrap();
+        let timestamp = state.streams[&1].topics[&1].partitions[&1].messages[1].timestamp;
+
+        let polled = poll(
+            &mut state,
+            &Consumer::default(),
+            &PollingStrategy::timestamp(timestamp.into()),
+            false,
+            false,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(payloads(&polled), vec![b"b", b"c"]);
+    }
+
+    #[test]
+    fn purge_should_restart_offsets_and_fail_polls_of_previous_partition_epoch() {
+        let mut state = init_state(1);
+        send(&mut state, &Partitioning::partition_id(1), &["a", "b"]).unwrap();
+        let consumer = Consumer::default();
+        poll(
+            &mut state,
+            &consumer,
+            &PollingStrategy::next(),
+            true,
+            false,
+            None,
+        )
+        .unwrap();
+
+        state
+            .get_topic_mut(
+                &Identifier::numeric(1).unwrap(),
+                &Identifier::numeric(1).unwrap(),
+            )
+            .unwrap()
+            .purge(false, None);
+        send(&mut state, &Partitioning::partition_id(1), &["c"]).unwrap();
+
+        assert!(matches!(
+            poll(
+                &mut state,
+                &consumer,
+                &PollingStrategy::next(),
+                false,
+                false,
+                Some(1)
+            ),
+            Err(IggyError::PartitionEpochChanged(1, 1, 2))
+        ));
+        let polled = poll(
+            &mut state,
+            &consumer,
+            &PollingStrategy::next(),
+            false,
+            false,
+            Some(2),
+        )
+        .unwrap();
+        assert_eq!(polled.messages[0].offset, 0);
+        assert_eq!(payloads(&polled), vec![b"c"]);
+        assert_eq!(polled.partition_epoch, 2);
+    }
+
+    #[test]
+    fn polling_consumer_group_without_joining_it_should_fail() {
+        let mut state = init_state(2);
+        state
+            .streams
+            .get_mut(&1)
+            .unwrap()
+            .topics
+            .get_mut(&1)
+            .unwrap()
+            .consumer_groups
+            .insert(
+                1,
+                MockConsumerGroup {
+                    id: 1,
+                    name: "group".to_owned(),
+                    offset_recovery: OffsetRecoveryPolicy::default(),
+                    dead_letter: None,
+                    visibility_timeout: None,
+                    joined: false,
+                    generation: 0,
+                    current_partition_id: 0,
+                    state: BTreeMap::new(),
+                },
+            );
+        let consumer = Consumer::group(Identifier::numeric(1).unwrap());
+
+        let result = state.poll_messages(
+            &Identifier::numeric(1).unwrap(),
+            &Identifier::numeric(1).unwrap(),
+            PollArgs {
+                partition_id: None,
+                consumer: &consumer,
+                strategy: &PollingStrategy::next(),
+                count: 10,
+                auto_commit: false,
+                chunk_size: 0,
+                filter: None,
+                partition_epoch: None,
+                peek: false,
+            },
+        );
+
+        assert!(matches!(
+            result,
+            Err(IggyError::ConsumerGroupMemberNotFound(CLIENT_ID, 1, 1))
+        ));
+    }
+}
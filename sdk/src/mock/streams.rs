@@ -0,0 +1,139 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::client::StreamClient;
+use crate::command::{
+    CREATE_STREAM, DELETE_STREAM, GET_STREAM, GET_STREAMS, PURGE_STREAM, UPDATE_STREAM,
+    UPDATE_STREAM_METADATA, UPDATE_STREAM_QUOTA,
+};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::mock::client::MockClient;
+use crate::models::metadata::{MetadataFilter, ResourceMetadata};
+use crate::models::stream::{Stream, StreamDetails, StreamQuota};
+use crate::streams::create_stream::CreateStreamOptions;
+use async_trait::async_trait;
+
+#[async_trait]
+impl StreamClient for MockClient {
+    async fn get_stream(&self, stream_id: &Identifier) -> Result<Option<StreamDetails>, IggyError> {
+        self.call(GET_STREAM)?;
+        Ok(self
+            .state()
+            .get_stream(stream_id)
+            .ok()
+            .map(|stream| stream.to_stream_details()))
+    }
+
+    async fn get_streams(&self) -> Result<Vec<Stream>, IggyError> {
+        self.call(GET_STREAMS)?;
+        Ok(self
+            .state()
+            .streams
+            .values()
+            .map(|stream| stream.to_stream())
+            .collect())
+    }
+
+    async fn get_streams_by_metadata(
+        &self,
+        filter: &MetadataFilter,
+    ) -> Result<Vec<Stream>, IggyError> {
+        self.call(GET_STREAMS)?;
+        Ok(self
+            .state()
+            .streams
+            .values()
+            .filter(|stream| stream.metadata.matches(filter))
+            .map(|stream| stream.to_stream())
+            .collect())
+    }
+
+    async fn create_stream(
+        &self,
+        name: &str,
+        stream_id: Option<u32>,
+    ) -> Result<StreamDetails, IggyError> {
+        self.create_stream_with_options(name, stream_id, &CreateStreamOptions::default())
+            .await
+    }
+
+    async fn create_stream_with_options(
+        &self,
+        name: &str,
+        stream_id: Option<u32>,
+        options: &CreateStreamOptions,
+    ) -> Result<StreamDetails, IggyError> {
+        self.call(CREATE_STREAM)?;
+        let mut state = self.state();
+        let stream = state.create_stream(name, stream_id, options.metadata.clone())?;
+        Ok(stream.to_stream_details())
+    }
+
+    async fn update_stream(&self, stream_id: &Identifier, name: &str) -> Result<(), IggyError> {
+        self.call(UPDATE_STREAM)?;
+        let mut state = self.state();
+        let id = state.get_stream(stream_id)?.id;
+        if state
+            .streams
+            .values()
+            .any(|stream| stream.id != id && stream.name == name)
+        {
+            return Err(IggyError::StreamNameAlreadyExists(name.to_owned()));
+        }
+
+        state.get_stream_mut(stream_id)?.name = name.to_owned();
+        Ok(())
+    }
+
+    async fn update_stream_metadata(
+        &self,
+        stream_id: &Identifier,
+        metadata: ResourceMetadata,
+    ) -> Result<(), IggyError> {
+        self.call(UPDATE_STREAM_METADATA)?;
+        self.state().get_stream_mut(stream_id)?.metadata = metadata;
+        Ok(())
+    }
+
+    async fn update_stream_quota(
+        &self,
+        stream_id: &Identifier,
+        quota: StreamQuota,
+    ) -> Result<(), IggyError> {
+        self.call(UPDATE_STREAM_QUOTA)?;
+        self.state().get_stream_mut(stream_id)?.quota = quota;
+        Ok(())
+    }
+
+    async fn delete_stream(&self, stream_id: &Identifier) -> Result<(), IggyError> {
+        self.call(DELETE_STREAM)?;
+        let mut state = self.state();
+        let id = state.get_stream(stream_id)?.id;
+        state.streams.remove(&id);
+        Ok(())
+    }
+
+    async fn purge_stream(&self, stream_id: &Identifier) -> Result<(), IggyError> {
+        self.call(PURGE_STREAM)?;
+        for topic in self.state().get_stream_mut(stream_id)?.topics.values_mut() {
+            topic.purge(false, None);
+        }
+        Ok(())
+    }
+}
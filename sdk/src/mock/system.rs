@@ -0,0 +1,202 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::client::SystemClient;
+use crate::command::{
+    CREATE_BACKUP, GET_AUDIT_LOG, GET_CLIENT, GET_CLIENTS, GET_ERROR_CATALOG, GET_MAINTENANCE_MODE,
+    GET_ME, GET_SERVER_INFO, GET_SNAPSHOT_FILE, GET_STATS, GET_UNSAVED_STATE, PING,
+    SET_MAINTENANCE_MODE,
+};
+use crate::error::IggyError;
+use crate::mock::client::MockClient;
+use crate::mock::state::{MockState, CLIENT_ID};
+use crate::models::audit_entry::{AuditAction, AuditEntry};
+use crate::models::backup::Backup;
+use crate::models::client_info::{ClientInfo, ClientInfoDetails, ConsumerGroupInfo};
+use crate::models::error_catalog::ErrorCatalogEntry;
+use crate::models::maintenance_mode::MaintenanceMode;
+use crate::models::server_info::{ServerInfo, ServerLimits};
+use crate::models::snapshot::Snapshot;
+use crate::models::stats::Stats;
+use crate::models::unsaved_state::PartitionUnsavedState;
+use crate::snapshot::{SnapshotCompression, SystemSnapshotType};
+use crate::utils::duration::IggyDuration;
+use crate::SDK_VERSION;
+use async_trait::async_trait;
+
+const ADDRESS: &str = "127.0.0.1:0";
+const TRANSPORT: &str = "Mock";
+
+#[async_trait]
+impl SystemClient for MockClient {
+    async fn get_stats(&self) -> Result<Stats, IggyError> {
+        self.call(GET_STATS)?;
+        let state = self.state();
+        let topics = state
+            .streams
+            .values()
+            .flat_map(|stream| stream.topics.values());
+        let mut stats = Stats {
+            streams_count: state.streams.len() as u32,
+            clients_count: 1,
+            iggy_server_version: SDK_VERSION.to_owned(),
+            ..Stats::default()
+        };
+        for topic in topics {
+            let size_bytes = topic.get_size_bytes();
+            stats.topics_count += 1;
+            stats.partitions_count += topic.partitions.len() as u32;
+            stats.segments_count += topic.partitions.len() as u32;
+            stats.messages_count += topic.get_messages_count();
+            stats.messages_size_bytes =
+                (stats.messages_size_bytes.as_bytes_u64() + size_bytes).into();
+            stats.consumer_groups_count += topic.consumer_groups.len() as u32;
+        }
+        Ok(stats)
+    }
+
+    async fn get_me(&self) -> Result<ClientInfoDetails, IggyError> {
+        self.call(GET_ME)?;
+        Ok(get_client_info_details(&self.state()))
+    }
+
+    async fn get_client(&self, client_id: u32) -> Result<Option<ClientInfoDetails>, IggyError> {
+        self.call(GET_CLIENT)?;
+        if client_id != CLIENT_ID {
+            return Ok(None);
+        }
+
+        Ok(Some(get_client_info_details(&self.state())))
+    }
+
+    async fn get_clients(&self) -> Result<Vec<ClientInfo>, IggyError> {
+        self.call(GET_CLIENTS)?;
+        let client = get_client_info_details(&self.state());
+        Ok(vec![ClientInfo {
+            client_id: client.client_id,
+            user_id: client.user_id,
+            address: client.address,
+            transport: client.transport,
+            consumer_groups_count: client.consumer_groups_count,
+        }])
+    }
+
+    async fn ping(&self) -> Result<(), IggyError> {
+        self.call(PING)
+    }
+
+    async fn heartbeat_interval(&self) -> IggyDuration {
+        IggyDuration::new_from_secs(5)
+    }
+
+    async fn snapshot(
+        &self,
+        _compression: SnapshotCompression,
+        _snapshot_types: Vec<SystemSnapshotType>,
+    ) -> Result<Snapshot, IggyError> {
+        self.call(GET_SNAPSHOT_FILE)?;
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn get_maintenance_mode(&self) -> Result<MaintenanceMode, IggyError> {
+        self.call(GET_MAINTENANCE_MODE)?;
+        Ok(self.state().maintenance_mode.clone())
+    }
+
+    async fn set_maintenance_mode(
+        &self,
+        enabled: bool,
+        reject_reads: bool,
+        message: Option<&str>,
+    ) -> Result<(), IggyError> {
+        self.call(SET_MAINTENANCE_MODE)?;
+        let mut state = self.state();
+        let enabled_at = enabled.then_some(state.clock.into());
+        state.maintenance_mode = MaintenanceMode {
+            enabled,
+            reject_reads: enabled && reject_reads,
+            message: message
+                .filter(|_| enabled)
+                .map(|message| message.to_owned()),
+            enabled_at,
+        };
+        Ok(())
+    }
+
+    async fn get_server_info(&self) -> Result<ServerInfo, IggyError> {
+        self.call(GET_SERVER_INFO)?;
+        Ok(ServerInfo {
+            version: SDK_VERSION.to_owned(),
+            limits: ServerLimits::default(),
+        })
+    }
+
+    async fn create_backup(&self, _name: &str) -> Result<Backup, IggyError> {
+        self.call(CREATE_BACKUP)?;
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn get_unsaved_state(&self) -> Result<Vec<PartitionUnsavedState>, IggyError> {
+        self.call(GET_UNSAVED_STATE)?;
+        // The mock client keeps all the messages in memory, there's no disk to save them on.
+        Ok(Vec::new())
+    }
+
+    async fn get_audit_log(
+        &self,
+        _count: u32,
+        _user_id: Option<u32>,
+        _action: Option<AuditAction>,
+    ) -> Result<Vec<AuditEntry>, IggyError> {
+        self.call(GET_AUDIT_LOG)?;
+        // The mock client doesn't record any of the performed actions.
+        Ok(Vec::new())
+    }
+
+    async fn get_error_catalog(&self) -> Result<Vec<ErrorCatalogEntry>, IggyError> {
+        self.call(GET_ERROR_CATALOG)?;
+        Ok(IggyError::catalog())
+    }
+}
+
+fn get_client_info_details(state: &MockState) -> ClientInfoDetails {
+    let consumer_groups = state
+        .streams
+        .values()
+        .flat_map(|stream| stream.topics.values())
+        .flat_map(|topic| {
+            topic
+                .consumer_groups
+                .values()
+                .filter(|group| group.joined)
+                .map(|group| ConsumerGroupInfo {
+                    stream_id: topic.stream_id,
+                    topic_id: topic.id,
+                    group_id: group.id,
+                })
+        })
+        .collect::<Vec<_>>();
+    ClientInfoDetails {
+        client_id: CLIENT_ID,
+        user_id: state.user_id,
+        address: ADDRESS.to_owned(),
+        transport: TRANSPORT.to_owned(),
+        consumer_groups_count: consumer_groups.len() as u32,
+        consumer_groups,
+    }
+}
@@ -0,0 +1,303 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::client::TopicClient;
+use crate::command::{
+    CREATE_TOPIC, DELETE_TOPIC, GET_TOPIC, GET_TOPICS, MARK_TOPIC_FOR_DELETION, PURGE_TOPIC,
+    UPDATE_TOPIC, UPDATE_TOPIC_CONFIG, UPDATE_TOPIC_EXPIRY_WATCHER, UPDATE_TOPIC_METADATA,
+    UPDATE_TOPIC_PRODUCERS,
+};
+use crate::compression::compression_algorithm::CompressionAlgorithm;
+use crate::consumer::{Consumer, ConsumerKind};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::mock::client::MockClient;
+use crate::models::metadata::{MetadataFilter, ResourceMetadata};
+use crate::models::topic::{Topic, TopicDetails};
+use crate::topics::create_topic::CreateTopicOptions;
+use crate::topics::purge_topic::PurgeTopicOptions;
+use crate::topics::topic_config::TopicConfig;
+use crate::topics::update_topic::UpdateTopicOptions;
+use crate::utils::duration::IggyDuration;
+use crate::utils::expiry::IggyExpiry;
+use crate::utils::topic_size::MaxTopicSize;
+use async_trait::async_trait;
+
+#[async_trait]
+impl TopicClient for MockClient {
+    async fn get_topic(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<Option<TopicDetails>, IggyError> {
+        self.call(GET_TOPIC)?;
+        Ok(self
+            .state()
+            .get_topic(stream_id, topic_id)
+            .ok()
+            .map(|topic| topic.to_topic_details()))
+    }
+
+    async fn get_topics(&self, stream_id: &Identifier) -> Result<Vec<Topic>, IggyError> {
+        self.call(GET_TOPICS)?;
+        Ok(self
+            .state()
+            .get_stream(stream_id)?
+            .topics
+            .values()
+            .map(|topic| topic.to_topic())
+            .collect())
+    }
+
+    async fn get_topics_by_metadata(
+        &self,
+        stream_id: &Identifier,
+        filter: &MetadataFilter,
+    ) -> Result<Vec<Topic>, IggyError> {
+        self.call(GET_TOPICS)?;
+        Ok(self
+            .state()
+            .get_stream(stream_id)?
+            .topics
+            .values()
+            .filter(|topic| topic.metadata.matches(filter))
+            .map(|topic| topic.to_topic())
+            .collect())
+    }
+
+    async fn create_topic(
+        &self,
+        stream_id: &Identifier,
+        name: &str,
+        partitions_count: u32,
+        compression_algorithm: CompressionAlgorithm,
+        replication_factor: Option<u8>,
+        topic_id: Option<u32>,
+        message_expiry: IggyExpiry,
+        max_topic_size: MaxTopicSize,
+    ) -> Result<TopicDetails, IggyError> {
+        self.create_topic_with_options(
+            stream_id,
+            name,
+            partitions_count,
+            compression_algorithm,
+            replication_factor,
+            topic_id,
+            message_expiry,
+            max_topic_size,
+            &CreateTopicOptions::default(),
+        )
+        .await
+    }
+
+    async fn create_topic_with_options(
+        &self,
+        stream_id: &Identifier,
+        name: &str,
+        partitions_count: u32,
+        compression_algorithm: CompressionAlgorithm,
+        replication_factor: Option<u8>,
+        topic_id: Option<u32>,
+        message_expiry: IggyExpiry,
+        max_topic_size: MaxTopicSize,
+        options: &CreateTopicOptions,
+    ) -> Result<TopicDetails, IggyError> {
+        self.call(CREATE_TOPIC)?;
+        let mut state = self.state();
+        let topic = state.create_topic(
+            stream_id,
+            name,
+            partitions_count,
+            compression_algorithm,
+            replication_factor,
+            topic_id,
+            message_expiry,
+            max_topic_size,
+            options.metadata.clone(),
+        )?;
+        Ok(topic.to_topic_details())
+    }
+
+    async fn update_topic(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        name: &str,
+        compression_algorithm: CompressionAlgorithm,
+        replication_factor: Option<u8>,
+        message_expiry: IggyExpiry,
+        max_topic_size: MaxTopicSize,
+    ) -> Result<(), IggyError> {
+        self.update_topic_with_options(
+            stream_id,
+            topic_id,
+            name,
+            compression_algorithm,
+            replication_factor,
+            message_expiry,
+            max_topic_size,
+            &UpdateTopicOptions::default(),
+        )
+        .await
+    }
+
+    async fn update_topic_with_options(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        name: &str,
+        compression_algorithm: CompressionAlgorithm,
+        replication_factor: Option<u8>,
+        message_expiry: IggyExpiry,
+        max_topic_size: MaxTopicSize,
+        options: &UpdateTopicOptions,
+    ) -> Result<(), IggyError> {
+        self.call(UPDATE_TOPIC)?;
+        let mut state = self.state();
+        let stream = state.get_stream_mut(stream_id)?;
+        let id = stream.get_topic(topic_id)?.id;
+        if stream
+            .topics
+            .values()
+            .any(|topic| topic.id != id && topic.name == name)
+        {
+            return Err(IggyError::TopicNameAlreadyExists(
+                name.to_owned(),
+                stream.id,
+            ));
+        }
+
+        let topic = stream.get_topic_mut(topic_id)?;
+        topic.name = name.to_owned();
+        topic.compression_algorithm = compression_algorithm;
+        topic.replication_factor = replication_factor.unwrap_or(topic.replication_factor);
+        topic.message_expiry = message_expiry;
+        topic.max_topic_size = max_topic_size;
+        if let Some(max_segments) = options.max_segments {
+            topic.max_segments = max_segments;
+        }
+        topic.config = topic
+            .config
+            .with_topic_settings(message_expiry, compression_algorithm);
+        Ok(())
+    }
+
+    async fn update_topic_metadata(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        metadata: ResourceMetadata,
+    ) -> Result<(), IggyError> {
+        self.call(UPDATE_TOPIC_METADATA)?;
+        self.state().get_topic_mut(stream_id, topic_id)?.metadata = metadata;
+        Ok(())
+    }
+
+    async fn update_topic_producers(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        allowed_producers: &[u32],
+    ) -> Result<(), IggyError> {
+        self.call(UPDATE_TOPIC_PRODUCERS)?;
+        self.state()
+            .get_topic_mut(stream_id, topic_id)?
+            .allowed_producers = allowed_producers.to_vec();
+        Ok(())
+    }
+
+    async fn update_topic_config(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        config: TopicConfig,
+    ) -> Result<(), IggyError> {
+        self.call(UPDATE_TOPIC_CONFIG)?;
+        let mut state = self.state();
+        let topic = state.get_topic_mut(stream_id, topic_id)?;
+        topic.message_expiry = config.message_expiry.unwrap_or(IggyExpiry::ServerDefault);
+        topic.compression_algorithm = config.compression_algorithm.unwrap_or_default();
+        topic.config = config;
+        Ok(())
+    }
+
+    async fn update_topic_expiry_watcher(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        consumer: &Consumer,
+        _enabled: bool,
+    ) -> Result<(), IggyError> {
+        self.call(UPDATE_TOPIC_EXPIRY_WATCHER)?;
+        let state = self.state();
+        let topic = state.get_topic(stream_id, topic_id)?;
+        if consumer.kind == ConsumerKind::ConsumerGroup {
+            topic.get_consumer_group(&consumer.id)?;
+        }
+        Ok(())
+    }
+
+    async fn mark_topic_for_deletion(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        drain_period: IggyDuration,
+    ) -> Result<(), IggyError> {
+        self.call(MARK_TOPIC_FOR_DELETION)?;
+        let mut state = self.state();
+        let delete_at = state.clock + drain_period.as_micros();
+        state.get_topic_mut(stream_id, topic_id)?.delete_at = Some(delete_at);
+        Ok(())
+    }
+
+    async fn delete_topic(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<(), IggyError> {
+        self.call(DELETE_TOPIC)?;
+        let mut state = self.state();
+        let stream = state.get_stream_mut(stream_id)?;
+        let id = stream.get_topic(topic_id)?.id;
+        stream.topics.remove(&id);
+        Ok(())
+    }
+
+    async fn purge_topic(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<(), IggyError> {
+        self.purge_topic_with_options(stream_id, topic_id, &PurgeTopicOptions::default())
+            .await
+    }
+
+    async fn purge_topic_with_options(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        options: &PurgeTopicOptions,
+    ) -> Result<(), IggyError> {
+        self.call(PURGE_TOPIC)?;
+        self.state().get_topic_mut(stream_id, topic_id)?.purge(
+            options.keep_consumer_offsets,
+            options.older_than.map(|timestamp| timestamp.as_micros()),
+        );
+        Ok(())
+    }
+}
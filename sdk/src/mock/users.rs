@@ -0,0 +1,126 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::client::UserClient;
+use crate::command::{
+    CHANGE_PASSWORD, CREATE_USER, DELETE_USER, GET_USER, GET_USERS, LOGIN_AS, LOGIN_USER,
+    LOGOUT_USER, UPDATE_PERMISSIONS, UPDATE_USER, UPDATE_USER_QUOTAS,
+};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::mock::client::MockClient;
+use crate::models::identity_info::IdentityInfo;
+use crate::models::permissions::Permissions;
+use crate::models::user_info::{UserInfo, UserInfoDetails};
+use crate::models::user_status::UserStatus;
+use crate::users::user_quotas::UserQuotas;
+use async_trait::async_trait;
+
+/// The ID of the user the mock client is signed in as, regardless of the provided credentials.
+pub(crate) const USER_ID: u32 = 1;
+
+#[async_trait]
+impl UserClient for MockClient {
+    async fn get_user(&self, _user_id: &Identifier) -> Result<Option<UserInfoDetails>, IggyError> {
+        self.call(GET_USER)?;
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn get_users(&self) -> Result<Vec<UserInfo>, IggyError> {
+        self.call(GET_USERS)?;
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn create_user(
+        &self,
+        _username: &str,
+        _password: &str,
+        _status: UserStatus,
+        _permissions: Option<Permissions>,
+    ) -> Result<UserInfoDetails, IggyError> {
+        self.call(CREATE_USER)?;
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn delete_user(&self, _user_id: &Identifier) -> Result<(), IggyError> {
+        self.call(DELETE_USER)?;
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn update_user(
+        &self,
+        _user_id: &Identifier,
+        _username: Option<&str>,
+        _status: Option<UserStatus>,
+    ) -> Result<(), IggyError> {
+        self.call(UPDATE_USER)?;
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn update_permissions(
+        &self,
+        _user_id: &Identifier,
+        _permissions: Option<Permissions>,
+    ) -> Result<(), IggyError> {
+        self.call(UPDATE_PERMISSIONS)?;
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn update_user_quotas(
+        &self,
+        _user_id: &Identifier,
+        _quotas: UserQuotas,
+    ) -> Result<(), IggyError> {
+        self.call(UPDATE_USER_QUOTAS)?;
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn change_password(
+        &self,
+        _user_id: &Identifier,
+        _current_password: &str,
+        _new_password: &str,
+    ) -> Result<(), IggyError> {
+        self.call(CHANGE_PASSWORD)?;
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn login_user(
+        &self,
+        _username: &str,
+        _password: &str,
+    ) -> Result<IdentityInfo, IggyError> {
+        self.call(LOGIN_USER)?;
+        self.state().user_id = Some(USER_ID);
+        Ok(IdentityInfo {
+            user_id: USER_ID,
+            access_token: None,
+        })
+    }
+
+    async fn logout_user(&self) -> Result<(), IggyError> {
+        self.call(LOGOUT_USER)?;
+        self.state().user_id = None;
+        Ok(())
+    }
+
+    async fn login_as(&self, _user_id: &Identifier) -> Result<IdentityInfo, IggyError> {
+        self.call(LOGIN_AS)?;
+        Err(IggyError::FeatureUnavailable)
+    }
+}
@@ -0,0 +1,359 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::error::IggyError;
+use crate::utils::timestamp::IggyTimestamp;
+use bytes::{BufMut, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// `AuditAction` represents the kind of the administrative or data-plane action recorded in the audit log.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditAction {
+    /// The user logged in with the username and password.
+    LoginUser,
+    /// The user logged in with the personal access token.
+    LoginWithPersonalAccessToken,
+    /// The user logged out.
+    LogoutUser,
+    /// The user was created.
+    CreateUser,
+    /// The username or the status of the user was updated.
+    UpdateUser,
+    /// The user was deleted.
+    DeleteUser,
+    /// The permissions of the user were updated.
+    UpdatePermissions,
+    /// The password of the user was changed.
+    ChangePassword,
+    /// The personal access token was created.
+    CreatePersonalAccessToken,
+    /// The personal access token was deleted.
+    DeletePersonalAccessToken,
+    /// The secret of the personal access token was rotated.
+    RotatePersonalAccessToken,
+    /// The stream was created.
+    CreateStream,
+    /// The stream was updated.
+    UpdateStream,
+    /// The stream was deleted.
+    DeleteStream,
+    /// The messages of the stream were purged.
+    PurgeStream,
+    /// The topic was created.
+    CreateTopic,
+    /// The topic was updated.
+    UpdateTopic,
+    /// The topic was deleted.
+    DeleteTopic,
+    /// The messages of the topic were purged.
+    PurgeTopic,
+    /// The partitions were added to the topic.
+    CreatePartitions,
+    /// The partitions were removed from the topic.
+    DeletePartitions,
+    /// The stored offset of the consumer was deleted.
+    DeleteConsumerOffset,
+    /// The namespace was created.
+    CreateNamespace,
+    /// The quotas of the namespace were updated.
+    UpdateNamespace,
+    /// The namespace was deleted.
+    DeleteNamespace,
+}
+
+impl AuditAction {
+    /// Returns the code of the audit action.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            AuditAction::LoginUser => 1,
+            AuditAction::LoginWithPersonalAccessToken => 2,
+            AuditAction::LogoutUser => 3,
+            AuditAction::CreateUser => 10,
+            AuditAction::UpdateUser => 11,
+            AuditAction::DeleteUser => 12,
+            AuditAction::UpdatePermissions => 13,
+            AuditAction::ChangePassword => 14,
+            AuditAction::CreatePersonalAccessToken => 20,
+            AuditAction::DeletePersonalAccessToken => 21,
+            AuditAction::RotatePersonalAccessToken => 22,
+            AuditAction::CreateStream => 30,
+            AuditAction::UpdateStream => 31,
+            AuditAction::DeleteStream => 32,
+            AuditAction::PurgeStream => 33,
+            AuditAction::CreateTopic => 40,
+            AuditAction::UpdateTopic => 41,
+            AuditAction::DeleteTopic => 42,
+            AuditAction::PurgeTopic => 43,
+            AuditAction::CreatePartitions => 50,
+            AuditAction::DeletePartitions => 51,
+            AuditAction::DeleteConsumerOffset => 60,
+            AuditAction::CreateNamespace => 70,
+            AuditAction::UpdateNamespace => 71,
+            AuditAction::DeleteNamespace => 72,
+        }
+    }
+
+    /// Returns the audit action from the code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(AuditAction::LoginUser),
+            2 => Ok(AuditAction::LoginWithPersonalAccessToken),
+            3 => Ok(AuditAction::LogoutUser),
+            10 => Ok(AuditAction::CreateUser),
+            11 => Ok(AuditAction::UpdateUser),
+            12 => Ok(AuditAction::DeleteUser),
+            13 => Ok(AuditAction::UpdatePermissions),
+            14 => Ok(AuditAction::ChangePassword),
+            20 => Ok(AuditAction::CreatePersonalAccessToken),
+            21 => Ok(AuditAction::DeletePersonalAccessToken),
+            22 => Ok(AuditAction::RotatePersonalAccessToken),
+            30 => Ok(AuditAction::CreateStream),
+            31 => Ok(AuditAction::UpdateStream),
+            32 => Ok(AuditAction::DeleteStream),
+            33 => Ok(AuditAction::PurgeStream),
+            40 => Ok(AuditAction::CreateTopic),
+            41 => Ok(AuditAction::UpdateTopic),
+            42 => Ok(AuditAction::DeleteTopic),
+            43 => Ok(AuditAction::PurgeTopic),
+            50 => Ok(AuditAction::CreatePartitions),
+            51 => Ok(AuditAction::DeletePartitions),
+            60 => Ok(AuditAction::DeleteConsumerOffset),
+            70 => Ok(AuditAction::CreateNamespace),
+            71 => Ok(AuditAction::UpdateNamespace),
+            72 => Ok(AuditAction::DeleteNamespace),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+impl FromStr for AuditAction {
+    type Err = IggyError;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "login_user" => Ok(AuditAction::LoginUser),
+            "login_with_personal_access_token" => Ok(AuditAction::LoginWithPersonalAccessToken),
+            "logout_user" => Ok(AuditAction::LogoutUser),
+            "create_user" => Ok(AuditAction::CreateUser),
+            "update_user" => Ok(AuditAction::UpdateUser),
+            "delete_user" => Ok(AuditAction::DeleteUser),
+            "update_permissions" => Ok(AuditAction::UpdatePermissions),
+            "change_password" => Ok(AuditAction::ChangePassword),
+            "create_personal_access_token" => Ok(AuditAction::CreatePersonalAccessToken),
+            "delete_personal_access_token" => Ok(AuditAction::DeletePersonalAccessToken),
+            "rotate_personal_access_token" => Ok(AuditAction::RotatePersonalAccessToken),
+            "create_stream" => Ok(AuditAction::CreateStream),
+            "update_stream" => Ok(AuditAction::UpdateStream),
+            "delete_stream" => Ok(AuditAction::DeleteStream),
+            "purge_stream" => Ok(AuditAction::PurgeStream),
+            "create_topic" => Ok(AuditAction::CreateTopic),
+            "update_topic" => Ok(AuditAction::UpdateTopic),
+            "delete_topic" => Ok(AuditAction::DeleteTopic),
+            "purge_topic" => Ok(AuditAction::PurgeTopic),
+            "create_partitions" => Ok(AuditAction::CreatePartitions),
+            "delete_partitions" => Ok(AuditAction::DeletePartitions),
+            "delete_consumer_offset" => Ok(AuditAction::DeleteConsumerOffset),
+            "create_namespace" => Ok(AuditAction::CreateNamespace),
+            "update_namespace" => Ok(AuditAction::UpdateNamespace),
+            "delete_namespace" => Ok(AuditAction::DeleteNamespace),
+            _ => Err(IggyError::InvalidFormat),
+        }
+    }
+}
+
+impl Display for AuditAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AuditAction::LoginUser => write!(f, "login_user"),
+            AuditAction::LoginWithPersonalAccessToken => {
+                write!(f, "login_with_personal_access_token")
+            }
+            AuditAction::LogoutUser => write!(f, "logout_user"),
+            AuditAction::CreateUser => write!(f, "create_user"),
+            AuditAction::UpdateUser => write!(f, "update_user"),
+            AuditAction::DeleteUser => write!(f, "delete_user"),
+            AuditAction::UpdatePermissions => write!(f, "update_permissions"),
+            AuditAction::ChangePassword => write!(f, "change_password"),
+            AuditAction::CreatePersonalAccessToken => write!(f, "create_personal_access_token"),
+            AuditAction::DeletePersonalAccessToken => write!(f, "delete_personal_access_token"),
+            AuditAction::RotatePersonalAccessToken => write!(f, "rotate_personal_access_token"),
+            AuditAction::CreateStream => write!(f, "create_stream"),
+            AuditAction::UpdateStream => write!(f, "update_stream"),
+            AuditAction::DeleteStream => write!(f, "delete_stream"),
+            AuditAction::PurgeStream => write!(f, "purge_stream"),
+            AuditAction::CreateTopic => write!(f, "create_topic"),
+            AuditAction::UpdateTopic => write!(f, "update_topic"),
+            AuditAction::DeleteTopic => write!(f, "delete_topic"),
+            AuditAction::PurgeTopic => write!(f, "purge_topic"),
+            AuditAction::CreatePartitions => write!(f, "create_partitions"),
+            AuditAction::DeletePartitions => write!(f, "delete_partitions"),
+            AuditAction::DeleteConsumerOffset => write!(f, "delete_consumer_offset"),
+            AuditAction::CreateNamespace => write!(f, "create_namespace"),
+            AuditAction::UpdateNamespace => write!(f, "update_namespace"),
+            AuditAction::DeleteNamespace => write!(f, "delete_namespace"),
+        }
+    }
+}
+
+/// `AuditEntry` represents a single action recorded in the audit log of the server.
+/// It consists of the following fields:
+/// - `id`: the unique, monotonically increasing identifier of the entry.
+/// - `timestamp`: the timestamp when the action was performed.
+/// - `user_id`: the identifier of the user who performed the action, 0 if the user couldn't be authenticated.
+/// - `client_id`: the identifier of the client connection, 0 for the stateless transports such as HTTP.
+/// - `ip_address`: the address of the client, empty if it's not known.
+/// - `action`: the kind of the action.
+/// - `resource`: the path of the affected resource, e.g. `streams/1/topics/2`, or the username for the logins.
+/// - `succeeded`: whether the action succeeded, only the failed logins are recorded as unsuccessful.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct AuditEntry {
+    /// The unique identifier of the entry.
+    pub id: u64,
+    /// The timestamp when the action was performed.
+    pub timestamp: IggyTimestamp,
+    /// The identifier of the user who performed the action.
+    pub user_id: u32,
+    /// The identifier of the client connection.
+    pub client_id: u32,
+    /// The address of the client.
+    pub ip_address: String,
+    /// The kind of the action.
+    pub action: AuditAction,
+    /// The path of the affected resource.
+    pub resource: String,
+    /// Whether the action succeeded.
+    pub succeeded: bool,
+}
+
+impl AuditEntry {
+    /// Returns the size of the binary representation in bytes.
+    pub fn get_size_bytes(&self) -> usize {
+        8 + 8 + 4 + 4 + 1 + 1 + 1 + self.ip_address.len() + 4 + self.resource.len()
+    }
+
+    /// Appends the binary representation to the provided buffer.
+    pub fn write_to_buffer(&self, bytes: &mut BytesMut) {
+        bytes.put_u64_le(self.id);
+        bytes.put_u64_le(self.timestamp.as_micros());
+        bytes.put_u32_le(self.user_id);
+        bytes.put_u32_le(self.client_id);
+        bytes.put_u8(self.action.as_code());
+        bytes.put_u8(self.succeeded as u8);
+        bytes.put_u8(self.ip_address.len() as u8);
+        bytes.put_slice(self.ip_address.as_bytes());
+        bytes.put_u32_le(self.resource.len() as u32);
+        bytes.put_slice(self.resource.as_bytes());
+    }
+
+    /// Reads the entry from the provided bytes starting at the given position.
+    /// Returns the entry and the number of bytes read.
+    pub fn from_bytes_at(bytes: &[u8], position: usize) -> Result<(Self, usize), IggyError> {
+        let get = |from: usize, length: usize| -> Result<&[u8], IggyError> {
+            bytes
+                .get(from..from + length)
+                .ok_or(IggyError::InvalidCommand)
+        };
+        let id = u64::from_le_bytes(
+            get(position, 8)?
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let timestamp = u64::from_le_bytes(
+            get(position + 8, 8)?
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let user_id = u32::from_le_bytes(
+            get(position + 16, 4)?
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let client_id = u32::from_le_bytes(
+            get(position + 20, 4)?
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let action = AuditAction::from_code(get(position + 24, 1)?[0])?;
+        let succeeded = get(position + 25, 1)?[0] == 1;
+        let ip_address_length = get(position + 26, 1)?[0] as usize;
+        let ip_address = String::from_utf8(get(position + 27, ip_address_length)?.to_vec())
+            .map_err(|_| IggyError::InvalidUtf8)?;
+        let mut read_bytes = 27 + ip_address_length;
+        let resource_length = u32::from_le_bytes(
+            get(position + read_bytes, 4)?
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        ) as usize;
+        read_bytes += 4;
+        let resource = String::from_utf8(get(position + read_bytes, resource_length)?.to_vec())
+            .map_err(|_| IggyError::InvalidUtf8)?;
+        read_bytes += resource_length;
+        Ok((
+            AuditEntry {
+                id,
+                timestamp: timestamp.into(),
+                user_id,
+                client_id,
+                ip_address,
+                action,
+                resource,
+                succeeded,
+            },
+            read_bytes,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entry_should_be_serialized_and_deserialized_from_bytes() {
+        let entry = AuditEntry {
+            id: 7,
+            timestamp: IggyTimestamp::from(1000),
+            user_id: 2,
+            client_id: 3,
+            ip_address: "127.0.0.1:8090".to_owned(),
+            action: AuditAction::DeleteTopic,
+            resource: "streams/1/topics/2".to_owned(),
+            succeeded: true,
+        };
+
+        let mut bytes = BytesMut::new();
+        entry.write_to_buffer(&mut bytes);
+        assert_eq!(bytes.len(), entry.get_size_bytes());
+
+        let (deserialized, read_bytes) = AuditEntry::from_bytes_at(&bytes, 0).unwrap();
+        assert_eq!(read_bytes, entry.get_size_bytes());
+        assert_eq!(deserialized, entry);
+    }
+
+    #[test]
+    fn action_should_be_converted_from_code_and_string() {
+        for code in 0..=u8::MAX {
+            if let Ok(action) = AuditAction::from_code(code) {
+                assert_eq!(action.as_code(), code);
+                assert_eq!(AuditAction::from_str(&action.to_string()).unwrap(), action);
+            }
+        }
+    }
+}
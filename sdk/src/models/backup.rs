@@ -0,0 +1,45 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::utils::byte_size::IggyByteSize;
+use crate::utils::timestamp::IggyTimestamp;
+use serde::{Deserialize, Serialize};
+
+/// `Backup` represents the backup of the server data created by the `CreateBackup` command.
+/// It consists of the following fields:
+/// - `name`: the unique name of the backup.
+/// - `path`: the path of the backup directory on the server, which mirrors the layout of the data directory.
+/// - `created_at`: the timestamp of the consistent cut.
+/// - `files_count`: the number of the files in the backup.
+/// - `linked_files_count`: the number of the files hard-linked instead of copied.
+/// - `size`: the total size of the files in the backup.
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Backup {
+    /// The unique name of the backup.
+    pub name: String,
+    /// The path of the backup directory on the server.
+    pub path: String,
+    /// The timestamp of the consistent cut.
+    pub created_at: IggyTimestamp,
+    /// The number of the files in the backup.
+    pub files_count: u32,
+    /// The number of the files hard-linked instead of copied.
+    pub linked_files_count: u32,
+    /// The total size of the files in the backup.
+    pub size: IggyByteSize,
+}
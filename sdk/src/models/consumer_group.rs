@@ -16,7 +16,13 @@
  * under the License.
  */
 
+use crate::consumer_groups::offset_recovery_policy::OffsetRecoveryPolicy;
+use crate::error::IggyError;
+use crate::utils::duration::IggyDuration;
+use crate::utils::timestamp::IggyTimestamp;
 use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
 
 /// `ConsumerGroup` represents the information about a consumer group.
 /// It consists of the following fields:
@@ -42,6 +48,13 @@ pub struct ConsumerGroup {
 /// - `name`: the name of the consumer group.
 /// - `partitions_count`: the number of partitions the consumer group is consuming.
 /// - `members_count`: the number of members in the consumer group.
+/// - `members`: the collection of members in the consumer group.
+/// - `rebalances`: the most recent rebalances of the consumer group, from the oldest to the newest.
+/// - `generation`: the number incremented on each rebalance, used to detect the stale partition assignments.
+/// - `assignment_strategy`: the strategy used to assign the partitions to the members.
+/// - `offset_recovery`: the policy used to recover the offsets of the consumer group which are out of range.
+/// - `dead_letter`: the topic to which the failed messages are republished, if configured.
+/// - `visibility_timeout`: the time after which the unacknowledged messages are redelivered, if the acknowledgement mode is enabled.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ConsumerGroupDetails {
     /// The unique identifier (numeric) of the consumer group.
@@ -54,6 +67,24 @@ pub struct ConsumerGroupDetails {
     pub members_count: u32,
     /// The collection of members in the consumer group.
     pub members: Vec<ConsumerGroupMember>,
+    /// The most recent rebalances of the consumer group, from the oldest to the newest.
+    #[serde(default)]
+    pub rebalances: Vec<ConsumerGroupRebalance>,
+    /// The number incremented on each rebalance, used to detect the stale partition assignments.
+    #[serde(default)]
+    pub generation: u32,
+    /// The strategy used to assign the partitions to the members.
+    #[serde(default)]
+    pub assignment_strategy: PartitionAssignmentStrategy,
+    /// The policy used to recover the offsets of the consumer group which are out of range.
+    #[serde(default)]
+    pub offset_recovery: OffsetRecoveryPolicy,
+    /// The topic to which the failed messages are republished, if configured.
+    #[serde(default)]
+    pub dead_letter: Option<ConsumerGroupDeadLetter>,
+    /// The time after which the unacknowledged messages are redelivered, if the acknowledgement mode is enabled.
+    #[serde(default)]
+    pub visibility_timeout: Option<IggyDuration>,
 }
 
 /// `ConsumerGroupMember` represents the information about a consumer group member.
@@ -70,3 +101,157 @@ pub struct ConsumerGroupMember {
     /// The collection of partitions the consumer group member is consuming.
     pub partitions: Vec<u32>,
 }
+
+/// `ConsumerGroupRebalance` represents a single reassignment of the partitions between the consumer group members.
+/// It consists of the following fields:
+/// - `timestamp`: the timestamp when the rebalance happened.
+/// - `trigger`: the reason of the rebalance.
+/// - `members_before`: the identifiers of the members before the rebalance.
+/// - `members_after`: the identifiers of the members after the rebalance.
+/// - `partitions_moved`: the number of partitions assigned to a different member (or unassigned) by the rebalance.
+/// - `duration`: the time it took to reassign the partitions.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConsumerGroupRebalance {
+    /// The timestamp when the rebalance happened.
+    pub timestamp: IggyTimestamp,
+    /// The reason of the rebalance.
+    pub trigger: RebalanceTrigger,
+    /// The identifiers of the members before the rebalance.
+    pub members_before: Vec<u32>,
+    /// The identifiers of the members after the rebalance.
+    pub members_after: Vec<u32>,
+    /// The number of partitions assigned to a different member (or unassigned) by the rebalance.
+    pub partitions_moved: u32,
+    /// The time it took to reassign the partitions.
+    pub duration: IggyDuration,
+}
+
+/// `ConsumerGroupDeadLetter` represents the dead-letter topic of a consumer group.
+/// The messages negatively acknowledged by the members, or delivered more than `max_delivery_count` times
+/// without being committed, are republished to the dead-letter topic with the failure headers.
+/// It consists of the following fields:
+/// - `stream_id`: the unique identifier (numeric) of the stream of the dead-letter topic.
+/// - `topic_id`: the unique identifier (numeric) of the dead-letter topic.
+/// - `max_delivery_count`: the number of deliveries after which the message is dead-lettered, 0 if only the negatively acknowledged messages are.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConsumerGroupDeadLetter {
+    /// The unique identifier (numeric) of the stream of the dead-letter topic.
+    pub stream_id: u32,
+    /// The unique identifier (numeric) of the dead-letter topic.
+    pub topic_id: u32,
+    /// The number of deliveries after which the message is dead-lettered, 0 if only the negatively acknowledged messages are.
+    pub max_delivery_count: u32,
+}
+
+/// The header containing the ID of the stream from which the message was dead-lettered.
+pub const DEAD_LETTER_SOURCE_STREAM_HEADER: &str = "iggy-dlq-source-stream";
+/// The header containing the ID of the topic from which the message was dead-lettered.
+pub const DEAD_LETTER_SOURCE_TOPIC_HEADER: &str = "iggy-dlq-source-topic";
+/// The header containing the ID of the partition from which the message was dead-lettered.
+pub const DEAD_LETTER_SOURCE_PARTITION_HEADER: &str = "iggy-dlq-source-partition";
+/// The header containing the offset of the dead-lettered message in the source partition.
+pub const DEAD_LETTER_SOURCE_OFFSET_HEADER: &str = "iggy-dlq-source-offset";
+/// The header containing the ID of the consumer group which failed to process the message.
+pub const DEAD_LETTER_CONSUMER_GROUP_HEADER: &str = "iggy-dlq-consumer-group";
+/// The header containing the number of times the message was delivered to the consumer group.
+pub const DEAD_LETTER_DELIVERY_COUNT_HEADER: &str = "iggy-dlq-delivery-count";
+/// The header containing the reason why the message was dead-lettered.
+pub const DEAD_LETTER_REASON_HEADER: &str = "iggy-dlq-reason";
+/// The header containing the timestamp (in microseconds) when the message was dead-lettered.
+pub const DEAD_LETTER_TIMESTAMP_HEADER: &str = "iggy-dlq-timestamp";
+
+/// `PartitionAssignmentStrategy` represents the way the partitions are assigned to the consumer group members on rebalance:
+/// - `Range`: each member gets a contiguous range of the partitions, ordered by the member ID.
+/// - `RoundRobin`: the partitions are distributed one by one across the members, ordered by the member ID.
+/// - `Sticky`: the members keep as many of their previously assigned partitions as possible while staying balanced.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PartitionAssignmentStrategy {
+    Range,
+    #[default]
+    RoundRobin,
+    Sticky,
+}
+
+impl PartitionAssignmentStrategy {
+    pub fn as_code(&self) -> u8 {
+        match self {
+            PartitionAssignmentStrategy::Range => 1,
+            PartitionAssignmentStrategy::RoundRobin => 2,
+            PartitionAssignmentStrategy::Sticky => 3,
+        }
+    }
+
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(PartitionAssignmentStrategy::Range),
+            2 => Ok(PartitionAssignmentStrategy::RoundRobin),
+            3 => Ok(PartitionAssignmentStrategy::Sticky),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+impl FromStr for PartitionAssignmentStrategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "range" => Ok(PartitionAssignmentStrategy::Range),
+            "round_robin" => Ok(PartitionAssignmentStrategy::RoundRobin),
+            "sticky" => Ok(PartitionAssignmentStrategy::Sticky),
+            _ => Err(format!("Unknown partition assignment strategy: {s}")),
+        }
+    }
+}
+
+impl Display for PartitionAssignmentStrategy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PartitionAssignmentStrategy::Range => write!(f, "range"),
+            PartitionAssignmentStrategy::RoundRobin => write!(f, "round_robin"),
+            PartitionAssignmentStrategy::Sticky => write!(f, "sticky"),
+        }
+    }
+}
+
+/// `RebalanceTrigger` represents the reason of the consumer group rebalance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RebalanceTrigger {
+    /// The member joined the consumer group.
+    MemberJoined,
+    /// The member left the consumer group (or its client disconnected).
+    MemberLeft,
+    /// The number of partitions in the topic has changed.
+    PartitionsChanged,
+}
+
+impl RebalanceTrigger {
+    pub fn as_code(&self) -> u8 {
+        match self {
+            RebalanceTrigger::MemberJoined => 1,
+            RebalanceTrigger::MemberLeft => 2,
+            RebalanceTrigger::PartitionsChanged => 3,
+        }
+    }
+
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(RebalanceTrigger::MemberJoined),
+            2 => Ok(RebalanceTrigger::MemberLeft),
+            3 => Ok(RebalanceTrigger::PartitionsChanged),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+impl Display for RebalanceTrigger {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RebalanceTrigger::MemberJoined => write!(f, "member_joined"),
+            RebalanceTrigger::MemberLeft => write!(f, "member_left"),
+            RebalanceTrigger::PartitionsChanged => write!(f, "partitions_changed"),
+        }
+    }
+}
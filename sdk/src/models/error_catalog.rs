@@ -0,0 +1,225 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::error::IggyError;
+use bytes::{BufMut, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// `ErrorCategory` is the machine-readable class of the error, stable across the server versions,
+/// so that the clients can decide how to handle the failure (e.g. whether to retry) without knowing every error code.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorCategory {
+    /// The unexpected failure of the server or the client, e.g. an I/O error.
+    Internal,
+    /// The request is malformed or contains an invalid value.
+    InvalidRequest,
+    /// The invalid configuration of the server or the client.
+    Configuration,
+    /// The requested resource does not exist.
+    NotFound,
+    /// The resource to be created already exists.
+    AlreadyExists,
+    /// The client is not authenticated or the provided credentials are invalid.
+    Unauthenticated,
+    /// The authenticated user is not allowed to perform the action.
+    Unauthorized,
+    /// The limit or the quota of the resources has been reached.
+    LimitExceeded,
+    /// The server can't handle the request at the moment, it can be retried later or on another node.
+    Unavailable,
+    /// The request conflicts with the current state of the resource.
+    Conflict,
+    /// The feature is disabled or not supported by the server.
+    Unsupported,
+    /// The connection to the server failed or the response couldn't be read.
+    Connection,
+}
+
+impl ErrorCategory {
+    /// Returns the code of the error category.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            ErrorCategory::Internal => 1,
+            ErrorCategory::InvalidRequest => 2,
+            ErrorCategory::Configuration => 3,
+            ErrorCategory::NotFound => 4,
+            ErrorCategory::AlreadyExists => 5,
+            ErrorCategory::Unauthenticated => 6,
+            ErrorCategory::Unauthorized => 7,
+            ErrorCategory::LimitExceeded => 8,
+            ErrorCategory::Unavailable => 9,
+            ErrorCategory::Conflict => 10,
+            ErrorCategory::Unsupported => 11,
+            ErrorCategory::Connection => 12,
+        }
+    }
+
+    /// Returns the error category from the code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(ErrorCategory::Internal),
+            2 => Ok(ErrorCategory::InvalidRequest),
+            3 => Ok(ErrorCategory::Configuration),
+            4 => Ok(ErrorCategory::NotFound),
+            5 => Ok(ErrorCategory::AlreadyExists),
+            6 => Ok(ErrorCategory::Unauthenticated),
+            7 => Ok(ErrorCategory::Unauthorized),
+            8 => Ok(ErrorCategory::LimitExceeded),
+            9 => Ok(ErrorCategory::Unavailable),
+            10 => Ok(ErrorCategory::Conflict),
+            11 => Ok(ErrorCategory::Unsupported),
+            12 => Ok(ErrorCategory::Connection),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+impl FromStr for ErrorCategory {
+    type Err = IggyError;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "internal" => Ok(ErrorCategory::Internal),
+            "invalid_request" => Ok(ErrorCategory::InvalidRequest),
+            "configuration" => Ok(ErrorCategory::Configuration),
+            "not_found" => Ok(ErrorCategory::NotFound),
+            "already_exists" => Ok(ErrorCategory::AlreadyExists),
+            "unauthenticated" => Ok(ErrorCategory::Unauthenticated),
+            "unauthorized" => Ok(ErrorCategory::Unauthorized),
+            "limit_exceeded" => Ok(ErrorCategory::LimitExceeded),
+            "unavailable" => Ok(ErrorCategory::Unavailable),
+            "conflict" => Ok(ErrorCategory::Conflict),
+            "unsupported" => Ok(ErrorCategory::Unsupported),
+            "connection" => Ok(ErrorCategory::Connection),
+            _ => Err(IggyError::InvalidFormat),
+        }
+    }
+}
+
+impl Display for ErrorCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ErrorCategory::Internal => write!(f, "internal"),
+            ErrorCategory::InvalidRequest => write!(f, "invalid_request"),
+            ErrorCategory::Configuration => write!(f, "configuration"),
+            ErrorCategory::NotFound => write!(f, "not_found"),
+            ErrorCategory::AlreadyExists => write!(f, "already_exists"),
+            ErrorCategory::Unauthenticated => write!(f, "unauthenticated"),
+            ErrorCategory::Unauthorized => write!(f, "unauthorized"),
+            ErrorCategory::LimitExceeded => write!(f, "limit_exceeded"),
+            ErrorCategory::Unavailable => write!(f, "unavailable"),
+            ErrorCategory::Conflict => write!(f, "conflict"),
+            ErrorCategory::Unsupported => write!(f, "unsupported"),
+            ErrorCategory::Connection => write!(f, "connection"),
+        }
+    }
+}
+
+/// `ErrorCatalogEntry` describes a single error which can be returned by the server or the client.
+/// It consists of the following fields:
+/// - `code`: the stable numeric code of the error, sent as the status of the binary response and the `id` of the HTTP error.
+/// - `name`: the stable name of the error in the snake case, e.g. `stream_id_not_found`.
+/// - `category`: the machine-readable category of the error.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ErrorCatalogEntry {
+    /// The stable numeric code of the error.
+    pub code: u32,
+    /// The stable name of the error.
+    pub name: String,
+    /// The category of the error.
+    pub category: ErrorCategory,
+}
+
+impl ErrorCatalogEntry {
+    /// Returns the size of the binary representation in bytes.
+    pub fn get_size_bytes(&self) -> usize {
+        4 + 1 + 1 + self.name.len()
+    }
+
+    /// Appends the binary representation to the provided buffer.
+    pub fn write_to_buffer(&self, bytes: &mut BytesMut) {
+        bytes.put_u32_le(self.code);
+        bytes.put_u8(self.category.as_code());
+        bytes.put_u8(self.name.len() as u8);
+        bytes.put_slice(self.name.as_bytes());
+    }
+
+    /// Reads the entry from the provided bytes starting at the given position.
+    /// Returns the entry and the number of bytes read.
+    pub fn from_bytes_at(bytes: &[u8], position: usize) -> Result<(Self, usize), IggyError> {
+        let get = |from: usize, length: usize| -> Result<&[u8], IggyError> {
+            bytes
+                .get(from..from + length)
+                .ok_or(IggyError::InvalidCommand)
+        };
+        let code = u32::from_le_bytes(
+            get(position, 4)?
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let category = ErrorCategory::from_code(get(position + 4, 1)?[0])?;
+        let name_length = get(position + 5, 1)?[0] as usize;
+        let name = String::from_utf8(get(position + 6, name_length)?.to_vec())
+            .map_err(|_| IggyError::InvalidUtf8)?;
+        Ok((
+            ErrorCatalogEntry {
+                code,
+                name,
+                category,
+            },
+            6 + name_length,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entry_should_be_serialized_and_deserialized_from_bytes() {
+        let entry = ErrorCatalogEntry {
+            code: 1009,
+            name: "stream_id_not_found".to_owned(),
+            category: ErrorCategory::NotFound,
+        };
+
+        let mut bytes = BytesMut::new();
+        entry.write_to_buffer(&mut bytes);
+        assert_eq!(bytes.len(), entry.get_size_bytes());
+
+        let (deserialized, read_bytes) = ErrorCatalogEntry::from_bytes_at(&bytes, 0).unwrap();
+        assert_eq!(read_bytes, entry.get_size_bytes());
+        assert_eq!(deserialized, entry);
+    }
+
+    #[test]
+    fn category_should_be_converted_from_code_and_string() {
+        for code in 0..=u8::MAX {
+            if let Ok(category) = ErrorCategory::from_code(code) {
+                assert_eq!(category.as_code(), code);
+                assert_eq!(
+                    ErrorCategory::from_str(&category.to_string()).unwrap(),
+                    category
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,96 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::consumer::ConsumerKind;
+use crate::utils::byte_size::IggyByteSize;
+use crate::utils::timestamp::IggyTimestamp;
+use serde::{Deserialize, Serialize};
+
+/// The name of the internal topic to which the notifications about the expiring messages are published.
+pub const EXPIRY_NOTIFICATIONS_TOPIC: &str = "__expiry_notifications";
+
+/// `ExpiryWatcher` represents the consumer or the consumer group interested in the expiry of the messages of the topic.
+/// It consists of the following fields:
+/// - `kind`: the kind of the consumer.
+/// - `id`: the identifier of the consumer group, or the resolved identifier of the consumer.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub struct ExpiryWatcher {
+    /// The kind of the consumer.
+    pub kind: ConsumerKind,
+    /// The identifier of the consumer group, or the resolved identifier of the consumer.
+    pub id: u32,
+}
+
+/// `ExpiryNotification` represents the range of the messages which have expired and are about to be deleted,
+/// published as the JSON payload of the message appended to the `__expiry_notifications` topic.
+/// It consists of the following fields:
+/// - `stream_id`: the identifier of the stream holding the expired messages.
+/// - `topic_id`: the identifier of the topic holding the expired messages.
+/// - `partition_id`: the identifier of the partition holding the expired messages.
+/// - `start_offset`: the offset of the first expired message.
+/// - `end_offset`: the offset of the last expired message.
+/// - `size_bytes`: the size of the expired segment.
+/// - `delete_at`: the timestamp after which the messages are deleted.
+/// - `watchers`: the consumers and the consumer groups which registered the interest in the expiry.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ExpiryNotification {
+    /// The identifier of the stream holding the expired messages.
+    pub stream_id: u32,
+    /// The identifier of the topic holding the expired messages.
+    pub topic_id: u32,
+    /// The identifier of the partition holding the expired messages.
+    pub partition_id: u32,
+    /// The offset of the first expired message.
+    pub start_offset: u64,
+    /// The offset of the last expired message.
+    pub end_offset: u64,
+    /// The size of the expired segment.
+    pub size_bytes: IggyByteSize,
+    /// The timestamp after which the messages are deleted.
+    pub delete_at: IggyTimestamp,
+    /// The consumers and the consumer groups which registered the interest in the expiry.
+    pub watchers: Vec<ExpiryWatcher>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn notification_should_be_serialized_and_deserialized_from_json() {
+        let notification = ExpiryNotification {
+            stream_id: 1,
+            topic_id: 2,
+            partition_id: 3,
+            start_offset: 100,
+            end_offset: 199,
+            size_bytes: IggyByteSize::from(1024),
+            delete_at: IggyTimestamp::from(1000),
+            watchers: vec![ExpiryWatcher {
+                kind: ConsumerKind::ConsumerGroup,
+                id: 4,
+            }],
+        };
+
+        let json = serde_json::to_value(&notification).unwrap();
+        assert_eq!(json["end_offset"], 199);
+        assert_eq!(json["watchers"][0]["kind"], "consumer_group");
+
+        let deserialized: ExpiryNotification = serde_json::from_value(json).unwrap();
+        assert_eq!(deserialized, notification);
+    }
+}
@@ -0,0 +1,39 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::utils::timestamp::IggyTimestamp;
+use serde::{Deserialize, Serialize};
+
+/// `MaintenanceMode` represents the server-wide maintenance mode, during which the writes
+/// (and optionally the reads) are rejected with the retryable `ServerInMaintenance` error.
+/// It consists of the following fields:
+/// - `enabled`: whether the maintenance mode is enabled.
+/// - `reject_reads`: whether the reads are rejected as well.
+/// - `message`: the optional message explaining the maintenance, which clients can surface.
+/// - `enabled_at`: the timestamp when the maintenance mode was enabled, `None` if it's disabled.
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
+pub struct MaintenanceMode {
+    /// Whether the maintenance mode is enabled.
+    pub enabled: bool,
+    /// Whether the reads are rejected as well.
+    pub reject_reads: bool,
+    /// The optional message explaining the maintenance.
+    pub message: Option<String>,
+    /// The timestamp when the maintenance mode was enabled.
+    pub enabled_at: Option<IggyTimestamp>,
+}
@@ -0,0 +1,76 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::utils::timestamp::IggyTimestamp;
+use serde::{Deserialize, Serialize};
+
+/// The name of the internal topic to which the provenance of the sampled messages is published.
+pub const MESSAGE_AUDIT_TOPIC: &str = "__message_audit";
+
+/// `MessageAuditRecord` represents the provenance of a single sampled message,
+/// published as the JSON payload of the message appended to the `__message_audit` topic.
+/// It consists of the following fields:
+/// - `stream_id`: the identifier of the stream to which the message was sent.
+/// - `topic_id`: the identifier of the topic to which the message was sent.
+/// - `message_id`: the identifier of the message, assigned by the server if not provided by the producer.
+/// - `user_id`: the identifier of the user who produced the message.
+/// - `client_id`: the identifier of the client connection which sent the message.
+/// - `ip_address`: the address of the client which sent the message.
+/// - `received_at`: the timestamp when the message was received by the server.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct MessageAuditRecord {
+    /// The identifier of the stream to which the message was sent.
+    pub stream_id: u32,
+    /// The identifier of the topic to which the message was sent.
+    pub topic_id: u32,
+    /// The identifier of the message.
+    pub message_id: u128,
+    /// The identifier of the user who produced the message.
+    pub user_id: u32,
+    /// The identifier of the client connection which sent the message.
+    pub client_id: u32,
+    /// The address of the client which sent the message.
+    pub ip_address: String,
+    /// The timestamp when the message was received by the server.
+    pub received_at: IggyTimestamp,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record_should_be_serialized_and_deserialized_from_json() {
+        let record = MessageAuditRecord {
+            stream_id: 1,
+            topic_id: 2,
+            message_id: 3,
+            user_id: 4,
+            client_id: 5,
+            ip_address: "127.0.0.1:8090".to_owned(),
+            received_at: IggyTimestamp::from(1000),
+        };
+
+        let json = serde_json::to_value(&record).unwrap();
+        assert_eq!(json["message_id"], 3);
+        assert_eq!(json["ip_address"], "127.0.0.1:8090");
+
+        let deserialized: MessageAuditRecord = serde_json::from_value(json).unwrap();
+        assert_eq!(deserialized, record);
+    }
+}
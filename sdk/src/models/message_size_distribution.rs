@@ -0,0 +1,262 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::error::IggyError;
+use crate::utils::byte_size::IggyByteSize;
+use bytes::{BufMut, BytesMut};
+use serde::{Deserialize, Serialize};
+
+/// Inclusive upper bounds (in bytes) of the message size buckets, growing by the powers of two from 64 B to 1 MiB.
+/// The messages larger than the last bound are counted in the additional, unbounded bucket.
+pub const MESSAGE_SIZE_BUCKETS_BOUNDS: [u64; 15] = [
+    64, 128, 256, 512, 1024, 2048, 4096, 8192, 16384, 32768, 65536, 131072, 262144, 524288, 1048576,
+];
+
+/// The total number of the message size buckets, including the unbounded one.
+pub const MESSAGE_SIZE_BUCKETS_COUNT: usize = MESSAGE_SIZE_BUCKETS_BOUNDS.len() + 1;
+
+/// `MessageSizeBucket` represents the number of messages whose size falls within the bucket.
+/// It consists of the following fields:
+/// - `max_size`: the inclusive upper bound of the bucket, `None` for the last, unbounded one.
+/// - `messages_count`: the number of messages in the bucket.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Copy)]
+pub struct MessageSizeBucket {
+    /// The inclusive upper bound of the bucket, `None` for the last, unbounded one.
+    pub max_size: Option<IggyByteSize>,
+    /// The number of messages in the bucket.
+    pub messages_count: u64,
+}
+
+/// `MessageSizeDistribution` represents the approximate distribution of the sizes of the messages appended to the topic,
+/// counted in the fixed buckets at produce time since the server has started, e.g. to tune the retention, cache and segment size.
+/// It consists of the following fields:
+/// - `messages_count`: the number of messages counted.
+/// - `total_size`: the total size of the messages counted.
+/// - `min_size`: the size of the smallest message, zero if no messages have been counted.
+/// - `max_size`: the size of the largest message, zero if no messages have been counted.
+/// - `buckets`: the message size buckets, ordered by their upper bounds.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq, Clone)]
+pub struct MessageSizeDistribution {
+    /// The number of messages counted.
+    pub messages_count: u64,
+    /// The total size of the messages counted.
+    pub total_size: IggyByteSize,
+    /// The size of the smallest message, zero if no messages have been counted.
+    pub min_size: IggyByteSize,
+    /// The size of the largest message, zero if no messages have been counted.
+    pub max_size: IggyByteSize,
+    /// The message size buckets, ordered by their upper bounds.
+    pub buckets: Vec<MessageSizeBucket>,
+}
+
+impl MessageSizeDistribution {
+    /// Creates the distribution from the numbers of messages counted in every bucket and the sizes (in bytes) of the messages.
+    pub fn new(
+        buckets_counts: [u64; MESSAGE_SIZE_BUCKETS_COUNT],
+        total_size: u64,
+        min_size: u64,
+        max_size: u64,
+    ) -> Self {
+        Self {
+            messages_count: buckets_counts.iter().sum(),
+            total_size: total_size.into(),
+            min_size: min_size.into(),
+            max_size: max_size.into(),
+            buckets: buckets_counts
+                .iter()
+                .enumerate()
+                .map(|(index, messages_count)| MessageSizeBucket {
+                    max_size: MESSAGE_SIZE_BUCKETS_BOUNDS
+                        .get(index)
+                        .map(|bound| (*bound).into()),
+                    messages_count: *messages_count,
+                })
+                .collect(),
+        }
+    }
+
+    /// Creates the distribution of the given message sizes (in bytes).
+    pub fn from_sizes(sizes: impl IntoIterator<Item = u64>) -> Self {
+        let mut buckets_counts = [0; MESSAGE_SIZE_BUCKETS_COUNT];
+        let (mut total_size, mut min_size, mut max_size) = (0, u64::MAX, 0);
+        for size in sizes {
+            buckets_counts[Self::bucket_index(size)] += 1;
+            total_size += size;
+            min_size = min_size.min(size);
+            max_size = max_size.max(size);
+        }
+        // No sizes leave the minimum unset, which is then reported as zero.
+        Self::new(buckets_counts, total_size, min_size.min(max_size), max_size)
+    }
+
+    /// Returns the index of the bucket counting the message of the given size (in bytes).
+    pub fn bucket_index(size: u64) -> usize {
+        MESSAGE_SIZE_BUCKETS_BOUNDS.partition_point(|bound| *bound < size)
+    }
+
+    /// Returns the average size of the messages, zero if no messages have been counted.
+    pub fn average_size(&self) -> IggyByteSize {
+        match self.messages_count {
+            0 => IggyByteSize::default(),
+            messages_count => (self.total_size.as_bytes_u64() / messages_count).into(),
+        }
+    }
+
+    /// Returns the approximate size not exceeded by the given percentage (0-100) of the messages,
+    /// which is the upper bound of the bucket containing the percentile, capped by the size of the largest message.
+    pub fn percentile(&self, percentage: f64) -> IggyByteSize {
+        if self.messages_count == 0 {
+            return IggyByteSize::default();
+        }
+
+        let rank = ((percentage.clamp(0.0, 100.0) / 100.0 * self.messages_count as f64).ceil()
+            as u64)
+            .max(1);
+        let mut messages_count = 0;
+        for bucket in &self.buckets {
+            messages_count += bucket.messages_count;
+            if messages_count >= rank {
+                return match bucket.max_size {
+                    Some(max_size) if max_size.as_bytes_u64() < self.max_size.as_bytes_u64() => {
+                        max_size
+                    }
+                    _ => self.max_size,
+                };
+            }
+        }
+        self.max_size
+    }
+
+    /// Returns the size of the binary representation in bytes.
+    pub fn get_size_bytes(&self) -> usize {
+        8 + 8 + 8 + 8 + 4 + self.buckets.len() * 16
+    }
+
+    /// Appends the binary representation to the provided buffer.
+    pub fn write_to_buffer(&self, bytes: &mut BytesMut) {
+        bytes.put_u64_le(self.messages_count);
+        bytes.put_u64_le(self.total_size.as_bytes_u64());
+        bytes.put_u64_le(self.min_size.as_bytes_u64());
+        bytes.put_u64_le(self.max_size.as_bytes_u64());
+        bytes.put_u32_le(self.buckets.len() as u32);
+        for bucket in &self.buckets {
+            bytes.put_u64_le(
+                bucket
+                    .max_size
+                    .map(|max_size| max_size.as_bytes_u64())
+                    .unwrap_or_default(),
+            );
+            bytes.put_u64_le(bucket.messages_count);
+        }
+    }
+
+    /// Reads the distribution from the provided bytes starting at the given position.
+    /// Returns the distribution and the number of bytes read.
+    pub fn from_bytes_at(bytes: &[u8], position: usize) -> Result<(Self, usize), IggyError> {
+        let read_u64 = |position: usize| -> Result<u64, IggyError> {
+            Ok(u64::from_le_bytes(
+                bytes
+                    .get(position..position + 8)
+                    .ok_or(IggyError::InvalidCommand)?
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            ))
+        };
+        let messages_count = read_u64(position)?;
+        let total_size = read_u64(position + 8)?.into();
+        let min_size = read_u64(position + 16)?.into();
+        let max_size = read_u64(position + 24)?.into();
+        let buckets_count = u32::from_le_bytes(
+            bytes
+                .get(position + 32..position + 36)
+                .ok_or(IggyError::InvalidCommand)?
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        ) as usize;
+        let mut read_bytes = 36;
+        let mut buckets = Vec::with_capacity(buckets_count.min(MESSAGE_SIZE_BUCKETS_COUNT));
+        for _ in 0..buckets_count {
+            let max_size = match read_u64(position + read_bytes)? {
+                0 => None,
+                max_size => Some(max_size.into()),
+            };
+            let messages_count = read_u64(position + read_bytes + 8)?;
+            buckets.push(MessageSizeBucket {
+                max_size,
+                messages_count,
+            });
+            read_bytes += 16;
+        }
+
+        Ok((
+            Self {
+                messages_count,
+                total_size,
+                min_size,
+                max_size,
+                buckets,
+            },
+            read_bytes,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn size_should_be_counted_in_bucket_with_inclusive_upper_bound() {
+        assert_eq!(MessageSizeDistribution::bucket_index(1), 0);
+        assert_eq!(MessageSizeDistribution::bucket_index(64), 0);
+        assert_eq!(MessageSizeDistribution::bucket_index(65), 1);
+        assert_eq!(MessageSizeDistribution::bucket_index(1048576), 14);
+        assert_eq!(
+            MessageSizeDistribution::bucket_index(1048577),
+            MESSAGE_SIZE_BUCKETS_COUNT - 1
+        );
+    }
+
+    #[test]
+    fn percentiles_should_be_approximated_by_bucket_bounds() {
+        let mut sizes = vec![100; 90];
+        sizes.extend([3000; 9]);
+        sizes.push(2_000_000);
+        let distribution = MessageSizeDistribution::from_sizes(sizes);
+
+        assert_eq!(distribution.percentile(50.0).as_bytes_u64(), 128);
+        assert_eq!(distribution.percentile(95.0).as_bytes_u64(), 4096);
+        assert_eq!(distribution.percentile(100.0).as_bytes_u64(), 2_000_000);
+        assert_eq!(distribution.average_size().as_bytes_u64(), 20_360);
+        assert_eq!(
+            MessageSizeDistribution::default().percentile(99.0),
+            IggyByteSize::default()
+        );
+    }
+
+    #[test]
+    fn distribution_should_be_serialized_and_deserialized() {
+        let distribution = MessageSizeDistribution::from_sizes([10, 500, 70_000]);
+        let mut bytes = BytesMut::new();
+        distribution.write_to_buffer(&mut bytes);
+        assert_eq!(bytes.len(), distribution.get_size_bytes());
+
+        let (deserialized, read_bytes) = MessageSizeDistribution::from_bytes_at(&bytes, 0).unwrap();
+        assert_eq!(read_bytes, bytes.len());
+        assert_eq!(deserialized, distribution);
+    }
+}
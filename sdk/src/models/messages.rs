@@ -37,6 +37,12 @@ pub const POLLED_MESSAGE_METADATA: u32 = 8 + 1 + 8 + 4;
 /// It consists of the following fields:
 /// - `partition_id`: the identifier of the partition.
 /// - `current_offset`: the current offset of the partition.
+/// - `remaining_messages`: the number of messages in the partition after the last polled one.
+/// - `gaps`: the ranges of offsets skipped in the response, as their messages were deleted or compacted.
+/// - `chunk_sizes`: the numbers of messages in the consecutive chunks of the response, if the chunking was requested.
+/// - `partition_epoch`: the epoch of the partition, if it was returned by the server.
+/// - `throttle_time_ms`: the time the consumer should wait before polling again, as it exceeded its fetch quota.
+/// - `topic_delete_at`: the time at which the topic is going to be deleted, if it's marked for deletion.
 /// - `messages`: the collection of messages.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PolledMessages {
@@ -44,10 +50,57 @@ pub struct PolledMessages {
     pub partition_id: u32,
     /// The current offset of the partition.
     pub current_offset: u64,
+    /// The number of messages stored in the partition after the last polled message (consumer lag).
+    /// It's '0' when the consumer has caught up with the partition or no messages were polled.
+    #[serde(default)]
+    pub remaining_messages: u64,
+    /// The ranges of offsets skipped in the response, ordered by their start offsets.
+    /// It allows the consumers tracking the contiguous offsets to distinguish the data loss from the deletion or compaction.
+    #[serde(default)]
+    pub gaps: Vec<MessagesGap>,
+    /// The numbers of messages in the consecutive chunks of the response, when polled with the preferred chunk size.
+    /// Every chunk has the preferred size, except the last one if the partition has no more messages.
+    /// It's empty when the chunking was not requested.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub chunk_sizes: Vec<u32>,
+    /// The epoch of the partition, changed every time the partition is purged and its offsets start over.
+    /// It's '0' if it wasn't returned by the server.
+    #[serde(default)]
+    pub partition_epoch: u64,
+    /// The time in milliseconds the consumer should wait before polling again, as it exceeded its fetch bandwidth quota.
+    /// The response of the throttled consumer might be trimmed, so it's '0' unless the quota was exceeded.
+    #[serde(default)]
+    pub throttle_time_ms: u32,
+    /// The time (in microseconds) at which the topic marked for deletion is going to be deleted,
+    /// so that the consumers know how long they can still drain it. It's '0' unless the topic is marked for deletion.
+    #[serde(default)]
+    pub topic_delete_at: u64,
     /// The collection of messages.
     pub messages: Vec<PolledMessage>,
 }
 
+impl PolledMessages {
+    /// Returns the messages split into the chunks of the response,
+    /// or a single chunk with all the messages if the chunking was not requested.
+    pub fn chunks(&self) -> Vec<&[PolledMessage]> {
+        if self.chunk_sizes.is_empty() {
+            if self.messages.is_empty() {
+                return Vec::new();
+            }
+            return vec![self.messages.as_slice()];
+        }
+
+        let mut chunks = Vec::with_capacity(self.chunk_sizes.len());
+        let mut remaining = self.messages.as_slice();
+        for chunk_size in &self.chunk_sizes {
+            let (chunk, rest) = remaining.split_at((*chunk_size as usize).min(remaining.len()));
+            chunks.push(chunk);
+            remaining = rest;
+        }
+        chunks
+    }
+}
+
 /// The single message that is polled from the partition.
 /// It consists of the following fields:
 /// - `offset`: the offset of the message.
@@ -81,6 +134,70 @@ pub struct PolledMessage {
     pub payload: Bytes,
 }
 
+/// The range of offsets (inclusive) for which there are no messages in the poll response.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub struct MessagesGap {
+    /// The first skipped offset.
+    pub start_offset: u64,
+    /// The last skipped offset.
+    pub end_offset: u64,
+    /// The reason why the messages are missing.
+    pub reason: MessagesGapReason,
+}
+
+/// The reason why the range of offsets is missing in the poll response.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum MessagesGapReason {
+    /// The messages were deleted, e.g. the segments expired or were removed to free the space.
+    Deleted,
+    /// The messages were removed by the compaction, as the newer messages with the same IDs exist.
+    Compacted,
+    /// The messages were sent within the aborted transaction, and the poll used the read-committed isolation level.
+    Aborted,
+    /// The messages did not match the filter of the poll.
+    Filtered,
+    /// The messages were republished to the dead-letter topic of the consumer group.
+    DeadLettered,
+}
+
+impl MessagesGapReason {
+    /// Returns the code of the gap reason.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            MessagesGapReason::Deleted => 1,
+            MessagesGapReason::Compacted => 2,
+            MessagesGapReason::Aborted => 3,
+            MessagesGapReason::Filtered => 4,
+            MessagesGapReason::DeadLettered => 5,
+        }
+    }
+
+    /// Returns the gap reason from the code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(MessagesGapReason::Deleted),
+            2 => Ok(MessagesGapReason::Compacted),
+            3 => Ok(MessagesGapReason::Aborted),
+            4 => Ok(MessagesGapReason::Filtered),
+            5 => Ok(MessagesGapReason::DeadLettered),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+impl Display for MessagesGapReason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MessagesGapReason::Deleted => write!(f, "deleted"),
+            MessagesGapReason::Compacted => write!(f, "compacted"),
+            MessagesGapReason::Aborted => write!(f, "aborted"),
+            MessagesGapReason::Filtered => write!(f, "filtered"),
+            MessagesGapReason::DeadLettered => write!(f, "dead_lettered"),
+        }
+    }
+}
+
 /// The state of the message, currently only the `Available` state is used.
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
@@ -172,6 +289,12 @@ impl PolledMessage {
 
     /// Extends the provided bytes with the message.
     pub fn extend(&self, bytes: &mut BytesMut) {
+        self.extend_header(bytes);
+        bytes.put_slice(&self.payload);
+    }
+
+    /// Writes all the fields of the message except the payload, which can be then sent separately without copying.
+    pub fn extend_header(&self, bytes: &mut BytesMut) {
         bytes.put_u64_le(self.offset);
         bytes.put_u8(self.state.as_code());
         bytes.put_u64_le(self.timestamp);
@@ -186,7 +309,6 @@ impl PolledMessage {
             bytes.put_u32_le(0u32);
         }
         bytes.put_u32_le(self.length.as_bytes_u64() as u32);
-        bytes.put_slice(&self.payload);
     }
 }
 
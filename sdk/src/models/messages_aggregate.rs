@@ -0,0 +1,146 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::utils::byte_size::IggyByteSize;
+use crate::utils::duration::IggyDuration;
+use crate::utils::timestamp::IggyTimestamp;
+use serde::{Deserialize, Serialize};
+
+/// `MessagesBucket` represents the aggregate of the messages appended within a single time bucket.
+/// The messages are attributed to the buckets per batch, using the timestamp of the latest message in the batch,
+/// as stored in the segment indexes.
+/// It consists of the following fields:
+/// - `start_timestamp`: the inclusive start of the time bucket.
+/// - `messages_count`: the number of messages in the bucket.
+/// - `size_bytes`: the size of the messages in the bucket.
+/// - `min_timestamp`: the earliest batch timestamp in the bucket, zero if the bucket is empty.
+/// - `max_timestamp`: the latest batch timestamp in the bucket, zero if the bucket is empty.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Copy)]
+pub struct MessagesBucket {
+    /// The inclusive start of the time bucket.
+    pub start_timestamp: IggyTimestamp,
+    /// The number of messages in the bucket.
+    pub messages_count: u64,
+    /// The size of the messages in the bucket.
+    pub size_bytes: IggyByteSize,
+    /// The earliest batch timestamp in the bucket, zero if the bucket is empty.
+    pub min_timestamp: IggyTimestamp,
+    /// The latest batch timestamp in the bucket, zero if the bucket is empty.
+    pub max_timestamp: IggyTimestamp,
+}
+
+impl MessagesBucket {
+    /// Creates an empty bucket starting at the given timestamp.
+    pub fn empty(start_timestamp: IggyTimestamp) -> Self {
+        Self {
+            start_timestamp,
+            messages_count: 0,
+            size_bytes: IggyByteSize::default(),
+            min_timestamp: IggyTimestamp::zero(),
+            max_timestamp: IggyTimestamp::zero(),
+        }
+    }
+
+    /// Returns `true` if no messages have been appended within the bucket, e.g. to detect the gaps in the traffic.
+    pub fn is_empty(&self) -> bool {
+        self.messages_count == 0
+    }
+
+    /// Adds the batch of messages with the given timestamp to the bucket.
+    pub fn add(&mut self, timestamp: IggyTimestamp, messages_count: u64, size_bytes: IggyByteSize) {
+        if self.is_empty() || timestamp.as_micros() < self.min_timestamp.as_micros() {
+            self.min_timestamp = timestamp;
+        }
+        if self.is_empty() || timestamp.as_micros() > self.max_timestamp.as_micros() {
+            self.max_timestamp = timestamp;
+        }
+        self.messages_count += messages_count;
+        self.size_bytes += size_bytes;
+    }
+}
+
+/// `MessagesAggregate` accumulates the batches of messages into the time buckets of the given width covering the `[from, to)` range.
+#[derive(Debug)]
+pub struct MessagesAggregate {
+    from: u64,
+    to: u64,
+    bucket: u64,
+    buckets: Vec<MessagesBucket>,
+}
+
+impl MessagesAggregate {
+    /// Creates the aggregate with the empty buckets, the last one can be shorter than the others.
+    pub fn new(from: IggyTimestamp, to: IggyTimestamp, bucket: IggyDuration) -> Self {
+        let from = from.as_micros();
+        let to = to.as_micros();
+        let bucket = bucket.as_micros().max(1);
+        let buckets = (from..to)
+            .step_by(bucket as usize)
+            .map(|start_timestamp| MessagesBucket::empty(start_timestamp.into()))
+            .collect();
+        Self {
+            from,
+            to,
+            bucket,
+            buckets,
+        }
+    }
+
+    /// Adds the batch of messages to the bucket containing its timestamp, the batches out of the range are ignored.
+    pub fn add(&mut self, timestamp: u64, messages_count: u64, size_bytes: IggyByteSize) {
+        if timestamp < self.from || timestamp >= self.to {
+            return;
+        }
+
+        let index = ((timestamp - self.from) / self.bucket) as usize;
+        if let Some(bucket) = self.buckets.get_mut(index) {
+            bucket.add(timestamp.into(), messages_count, size_bytes);
+        }
+    }
+
+    pub fn into_buckets(self) -> Vec<MessagesBucket> {
+        self.buckets
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn batches_should_be_added_to_buckets_containing_their_timestamps() {
+        let mut aggregate =
+            MessagesAggregate::new(1_000.into(), 3_500.into(), IggyDuration::from(1_000));
+        aggregate.add(999, 10, IggyByteSize::from(100));
+        aggregate.add(1_200, 10, IggyByteSize::from(100));
+        aggregate.add(1_800, 5, IggyByteSize::from(50));
+        aggregate.add(3_499, 1, IggyByteSize::from(10));
+        aggregate.add(3_500, 10, IggyByteSize::from(100));
+
+        let buckets = aggregate.into_buckets();
+        assert_eq!(buckets.len(), 3);
+        assert_eq!(buckets[0].messages_count, 15);
+        assert_eq!(buckets[0].size_bytes, IggyByteSize::from(150));
+        assert_eq!(buckets[0].min_timestamp.as_micros(), 1_200);
+        assert_eq!(buckets[0].max_timestamp.as_micros(), 1_800);
+        assert!(buckets[1].is_empty());
+        assert_eq!(buckets[1].start_timestamp.as_micros(), 2_000);
+        assert_eq!(buckets[2].messages_count, 1);
+        assert_eq!(buckets[2].max_timestamp.as_micros(), 3_499);
+    }
+}
@@ -0,0 +1,470 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::error::IggyError;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::str::from_utf8;
+
+/// The maximum length of the description in bytes.
+pub const MAX_DESCRIPTION_LENGTH: usize = 1024;
+/// The maximum length of the owner, label key and label value in bytes.
+pub const MAX_METADATA_VALUE_LENGTH: usize = 255;
+/// The maximum number of labels attached to a single resource.
+pub const MAX_LABELS_COUNT: usize = 64;
+
+/// `ResourceMetadata` represents the descriptive information attached to a stream or a topic.
+/// It consists of the following fields:
+/// - `description`: the optional free-form description, max length is 1024 bytes.
+/// - `owner`: the optional owner (e.g. team name), max length is 255 bytes.
+/// - `labels`: the key/value labels, up to 64 entries, each key and value max length is 255 bytes.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResourceMetadata {
+    /// The optional free-form description.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The optional owner of the resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    /// The key/value labels.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub labels: BTreeMap<String, String>,
+}
+
+/// `MetadataFilter` is used to narrow down the list of streams or topics by their metadata.
+/// All the provided criteria must match:
+/// - `owner`: the exact owner.
+/// - `labels`: all the key/value pairs must be present.
+/// - `description`: the substring which must be contained in the description.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MetadataFilter {
+    /// The exact owner to match.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    /// The labels which all must be present.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub labels: BTreeMap<String, String>,
+    /// The substring to search for in the description.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl ResourceMetadata {
+    /// Returns true if there is no description, owner or labels.
+    pub fn is_empty(&self) -> bool {
+        self.description.is_none() && self.owner.is_none() && self.labels.is_empty()
+    }
+
+    /// Returns the size of the binary representation in bytes.
+    pub fn get_size_bytes(&self) -> usize {
+        2 + self.description.as_ref().map_or(0, |d| d.len())
+            + 1
+            + self.owner.as_ref().map_or(0, |o| o.len())
+            + 1
+            + self
+                .labels
+                .iter()
+                .map(|(key, value)| 2 + key.len() + value.len())
+                .sum::<usize>()
+    }
+
+    /// Appends the binary representation to the provided buffer.
+    pub fn write_to_buffer(&self, bytes: &mut BytesMut) {
+        let description = self.description.as_deref().unwrap_or_default();
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u16_le(description.len() as u16);
+        bytes.put_slice(description.as_bytes());
+        let owner = self.owner.as_deref().unwrap_or_default();
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(owner.len() as u8);
+        bytes.put_slice(owner.as_bytes());
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(self.labels.len() as u8);
+        for (key, value) in &self.labels {
+            #[allow(clippy::cast_possible_truncation)]
+            bytes.put_u8(key.len() as u8);
+            bytes.put_slice(key.as_bytes());
+            #[allow(clippy::cast_possible_truncation)]
+            bytes.put_u8(value.len() as u8);
+            bytes.put_slice(value.as_bytes());
+        }
+    }
+
+    /// Returns the binary representation.
+    pub fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(self.get_size_bytes());
+        self.write_to_buffer(&mut bytes);
+        bytes.freeze()
+    }
+
+    /// Reads the metadata from the provided bytes starting at the given position.
+    /// Returns the metadata and the number of bytes read.
+    pub fn from_bytes_at(bytes: &[u8], position: usize) -> Result<(Self, usize), IggyError> {
+        let mut current = position;
+        let description_length = u16::from_le_bytes(
+            read_slice(bytes, current, 2)?
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        ) as usize;
+        current += 2;
+        let description = read_string(bytes, current, description_length)?;
+        current += description_length;
+        let owner_length = read_slice(bytes, current, 1)?[0] as usize;
+        current += 1;
+        let owner = read_string(bytes, current, owner_length)?;
+        current += owner_length;
+        let labels_count = read_slice(bytes, current, 1)?[0];
+        current += 1;
+        let mut labels = BTreeMap::new();
+        for _ in 0..labels_count {
+            let key_length = read_slice(bytes, current, 1)?[0] as usize;
+            current += 1;
+            let key = read_string(bytes, current, key_length)?;
+            current += key_length;
+            let value_length = read_slice(bytes, current, 1)?[0] as usize;
+            current += 1;
+            let value = read_string(bytes, current, value_length)?;
+            current += value_length;
+            labels.insert(key, value);
+        }
+
+        let metadata = ResourceMetadata {
+            description: non_empty(description),
+            owner: non_empty(owner),
+            labels,
+        };
+        Ok((metadata, current - position))
+    }
+
+    /// Returns true if the metadata satisfies all the criteria of the filter.
+    pub fn matches(&self, filter: &MetadataFilter) -> bool {
+        if let Some(owner) = &filter.owner {
+            if self.owner.as_ref() != Some(owner) {
+                return false;
+            }
+        }
+
+        if let Some(description) = &filter.description {
+            match &self.description {
+                Some(value) if value.contains(description.as_str()) => {}
+                _ => return false,
+            }
+        }
+
+        filter
+            .labels
+            .iter()
+            .all(|(key, value)| self.labels.get(key) == Some(value))
+    }
+}
+
+impl Validatable<IggyError> for ResourceMetadata {
+    fn validate(&self) -> Result<(), IggyError> {
+        if let Some(description) = &self.description {
+            if description.is_empty() || description.len() > MAX_DESCRIPTION_LENGTH {
+                return Err(IggyError::InvalidResourceMetadata);
+            }
+        }
+
+        if let Some(owner) = &self.owner {
+            if owner.is_empty() || owner.len() > MAX_METADATA_VALUE_LENGTH {
+                return Err(IggyError::InvalidResourceMetadata);
+            }
+        }
+
+        if self.labels.len() > MAX_LABELS_COUNT {
+            return Err(IggyError::InvalidResourceMetadata);
+        }
+
+        for (key, value) in &self.labels {
+            if key.is_empty()
+                || key.len() > MAX_METADATA_VALUE_LENGTH
+                || value.len() > MAX_METADATA_VALUE_LENGTH
+            {
+                return Err(IggyError::InvalidResourceMetadata);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for ResourceMetadata {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let labels = self
+            .labels
+            .iter()
+            .map(|(key, value)| format!("{key}={value}"))
+            .collect::<Vec<_>>()
+            .join(",");
+        write!(
+            f,
+            "{}|{}|{}",
+            self.description.as_deref().unwrap_or_default(),
+            self.owner.as_deref().unwrap_or_default(),
+            labels
+        )
+    }
+}
+
+impl MetadataFilter {
+    /// Returns true if the filter has no criteria.
+    pub fn is_empty(&self) -> bool {
+        self.owner.is_none() && self.description.is_none() && self.labels.is_empty()
+    }
+
+    /// Returns the size of the binary representation in bytes.
+    pub fn get_size_bytes(&self) -> usize {
+        self.as_metadata().get_size_bytes()
+    }
+
+    /// Appends the binary representation to the provided buffer.
+    /// The layout is the same as for `ResourceMetadata`.
+    pub fn write_to_buffer(&self, bytes: &mut BytesMut) {
+        self.as_metadata().write_to_buffer(bytes);
+    }
+
+    /// Reads the filter from the provided bytes starting at the given position.
+    pub fn from_bytes_at(bytes: &[u8], position: usize) -> Result<(Self, usize), IggyError> {
+        let (metadata, read_bytes) = ResourceMetadata::from_bytes_at(bytes, position)?;
+        let filter = MetadataFilter {
+            owner: metadata.owner,
+            labels: metadata.labels,
+            description: metadata.description,
+        };
+        Ok((filter, read_bytes))
+    }
+
+    fn as_metadata(&self) -> ResourceMetadata {
+        ResourceMetadata {
+            description: self.description.clone(),
+            owner: self.owner.clone(),
+            labels: self.labels.clone(),
+        }
+    }
+}
+
+impl Validatable<IggyError> for MetadataFilter {
+    fn validate(&self) -> Result<(), IggyError> {
+        self.as_metadata().validate()
+    }
+}
+
+impl Display for MetadataFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_metadata())
+    }
+}
+
+/// `MetadataFilterQuery` is the flat representation of `MetadataFilter` used in the HTTP query string.
+/// The labels are provided as a comma-separated list of `key=value` pairs.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MetadataFilterQuery {
+    /// The exact owner to match.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    /// The comma-separated list of `key=value` labels which all must be present.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<String>,
+    /// The substring to search for in the description.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl From<&MetadataFilter> for MetadataFilterQuery {
+    fn from(filter: &MetadataFilter) -> Self {
+        let labels = if filter.labels.is_empty() {
+            None
+        } else {
+            Some(
+                filter
+                    .labels
+                    .iter()
+                    .map(|(key, value)| format!("{key}={value}"))
+                    .collect::<Vec<_>>()
+                    .join(","),
+            )
+        };
+        MetadataFilterQuery {
+            owner: filter.owner.clone(),
+            labels,
+            description: filter.description.clone(),
+        }
+    }
+}
+
+impl TryFrom<MetadataFilterQuery> for MetadataFilter {
+    type Error = IggyError;
+
+    fn try_from(query: MetadataFilterQuery) -> Result<Self, Self::Error> {
+        let mut labels = BTreeMap::new();
+        if let Some(value) = query.labels {
+            for label in value.split(',').filter(|label| !label.trim().is_empty()) {
+                let (key, value) = parse_label(label)?;
+                labels.insert(key, value);
+            }
+        }
+
+        Ok(MetadataFilter {
+            owner: query.owner,
+            labels,
+            description: query.description,
+        })
+    }
+}
+
+/// Parses the labels provided in the `key=value` format.
+pub fn parse_label(value: &str) -> Result<(String, String), IggyError> {
+    let Some((key, value)) = value.split_once('=') else {
+        return Err(IggyError::InvalidResourceMetadata);
+    };
+
+    let key = key.trim();
+    if key.is_empty() {
+        return Err(IggyError::InvalidResourceMetadata);
+    }
+
+    Ok((key.to_string(), value.trim().to_string()))
+}
+
+fn read_slice(bytes: &[u8], position: usize, length: usize) -> Result<&[u8], IggyError> {
+    bytes
+        .get(position..position + length)
+        .ok_or(IggyError::InvalidCommand)
+}
+
+fn read_string(bytes: &[u8], position: usize, length: usize) -> Result<String, IggyError> {
+    let value = read_slice(bytes, position, length)?;
+    Ok(from_utf8(value)
+        .map_err(|_| IggyError::InvalidUtf8)?
+        .to_string())
+}
+
+fn non_empty(value: String) -> Option<String> {
+    if value.is_empty() {
+        None
+    } else {
+        Some(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metadata() -> ResourceMetadata {
+        ResourceMetadata {
+            description: Some("Orders placed in the web shop".to_string()),
+            owner: Some("checkout-team".to_string()),
+            labels: BTreeMap::from([
+                ("env".to_string(), "prod".to_string()),
+                ("tier".to_string(), "1".to_string()),
+            ]),
+        }
+    }
+
+    #[test]
+    fn should_be_serialized_and_deserialized_from_bytes() {
+        let metadata = metadata();
+        let bytes = metadata.to_bytes();
+        assert_eq!(bytes.len(), metadata.get_size_bytes());
+
+        let (deserialized, read_bytes) = ResourceMetadata::from_bytes_at(&bytes, 0).unwrap();
+        assert_eq!(read_bytes, bytes.len());
+        assert_eq!(deserialized, metadata);
+    }
+
+    #[test]
+    fn empty_metadata_should_be_serialized_and_deserialized_from_bytes() {
+        let metadata = ResourceMetadata::default();
+        let bytes = metadata.to_bytes();
+        assert_eq!(bytes.len(), 4);
+
+        let (deserialized, _) = ResourceMetadata::from_bytes_at(&bytes, 0).unwrap();
+        assert!(deserialized.is_empty());
+    }
+
+    #[test]
+    fn truncated_bytes_should_fail() {
+        let bytes = metadata().to_bytes();
+        assert!(ResourceMetadata::from_bytes_at(&bytes[..bytes.len() - 1], 0).is_err());
+    }
+
+    #[test]
+    fn filter_should_match_all_criteria() {
+        let metadata = metadata();
+        assert!(metadata.matches(&MetadataFilter::default()));
+        assert!(metadata.matches(&MetadataFilter {
+            owner: Some("checkout-team".to_string()),
+            labels: BTreeMap::from([("env".to_string(), "prod".to_string())]),
+            description: Some("web shop".to_string()),
+        }));
+        assert!(!metadata.matches(&MetadataFilter {
+            owner: Some("billing-team".to_string()),
+            ..Default::default()
+        }));
+        assert!(!metadata.matches(&MetadataFilter {
+            labels: BTreeMap::from([("env".to_string(), "dev".to_string())]),
+            ..Default::default()
+        }));
+        assert!(!ResourceMetadata::default().matches(&MetadataFilter {
+            description: Some("web".to_string()),
+            ..Default::default()
+        }));
+    }
+
+    #[test]
+    fn should_not_be_valid_given_too_many_labels() {
+        let labels = (0..=MAX_LABELS_COUNT)
+            .map(|i| (format!("key-{i}"), "value".to_string()))
+            .collect();
+        let metadata = ResourceMetadata {
+            labels,
+            ..Default::default()
+        };
+        assert!(metadata.validate().is_err());
+    }
+
+    #[test]
+    fn filter_should_be_converted_to_query_and_back() {
+        let filter = MetadataFilter {
+            owner: Some("team".to_string()),
+            labels: BTreeMap::from([
+                ("env".to_string(), "prod".to_string()),
+                ("tier".to_string(), "1".to_string()),
+            ]),
+            description: None,
+        };
+        let query = MetadataFilterQuery::from(&filter);
+        assert_eq!(query.labels.as_deref(), Some("env=prod,tier=1"));
+        assert_eq!(MetadataFilter::try_from(query).unwrap(), filter);
+    }
+
+    #[test]
+    fn label_should_be_parsed() {
+        assert_eq!(
+            parse_label("env=prod").unwrap(),
+            ("env".to_string(), "prod".to_string())
+        );
+        assert!(parse_label("env").is_err());
+        assert!(parse_label("=prod").is_err());
+    }
+}
@@ -0,0 +1,223 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::compression::compression_algorithm::CompressionAlgorithm;
+use crate::consumer::ConsumerKind;
+use crate::models::metadata::ResourceMetadata;
+use crate::models::stream::StreamQuota;
+use crate::topics::topic_config::TopicConfig;
+use crate::utils::byte_size::IggyByteSize;
+use crate::utils::expiry::IggyExpiry;
+use crate::utils::timestamp::IggyTimestamp;
+use crate::utils::topic_size::MaxTopicSize;
+use serde::{Deserialize, Serialize};
+
+/// The name of the internal topic to which the changes of the streams, topics and partitions are published.
+pub const METADATA_CHANGES_TOPIC: &str = "__metadata_changes";
+
+/// `MetadataChangeEvent` represents a single change of the streams, topics or partitions,
+/// published as the JSON payload of the message appended to the `__metadata_changes` topic.
+/// It consists of the following fields:
+/// - `user_id`: the identifier of the user who made the change.
+/// - `timestamp`: the timestamp when the change was made.
+/// - `change`: the change itself, tagged with its `type`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct MetadataChangeEvent {
+    /// The identifier of the user who made the change.
+    pub user_id: u32,
+    /// The timestamp when the change was made.
+    pub timestamp: IggyTimestamp,
+    /// The change itself.
+    #[serde(flatten)]
+    pub change: MetadataChange,
+}
+
+/// `MetadataChange` represents the kind of the change along with the resulting configuration.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum MetadataChange {
+    /// The stream has been created.
+    StreamCreated {
+        stream_id: u32,
+        name: String,
+        metadata: ResourceMetadata,
+    },
+    /// The stream has been renamed.
+    StreamUpdated { stream_id: u32, name: String },
+    /// The metadata of the stream has been replaced.
+    StreamMetadataUpdated {
+        stream_id: u32,
+        metadata: ResourceMetadata,
+    },
+    /// The storage quota of the stream has been updated.
+    StreamQuotaUpdated { stream_id: u32, quota: StreamQuota },
+    /// The size of the stream has exceeded its soft storage quota.
+    StreamSoftQuotaExceeded {
+        stream_id: u32,
+        size: IggyByteSize,
+        soft_limit: IggyByteSize,
+    },
+    /// The stream has been deleted along with all its topics.
+    StreamDeleted { stream_id: u32, name: String },
+    /// The topic has been created.
+    TopicCreated {
+        stream_id: u32,
+        topic_id: u32,
+        name: String,
+        partitions_count: u32,
+        message_expiry: IggyExpiry,
+        compression_algorithm: CompressionAlgorithm,
+        max_topic_size: MaxTopicSize,
+        replication_factor: u8,
+        metadata: ResourceMetadata,
+    },
+    /// The configuration of the topic has been updated.
+    TopicUpdated {
+        stream_id: u32,
+        topic_id: u32,
+        name: String,
+        message_expiry: IggyExpiry,
+        compression_algorithm: CompressionAlgorithm,
+        max_topic_size: MaxTopicSize,
+        replication_factor: u8,
+        #[serde(default)]
+        max_segments: u32,
+    },
+    /// The metadata of the topic has been replaced.
+    TopicMetadataUpdated {
+        stream_id: u32,
+        topic_id: u32,
+        metadata: ResourceMetadata,
+    },
+    /// The producers allowed to send the messages to the topic have been replaced.
+    TopicProducersUpdated {
+        stream_id: u32,
+        topic_id: u32,
+        allowed_producers: Vec<u32>,
+    },
+    /// The settings overriding the server defaults for the topic have been replaced.
+    TopicConfigUpdated {
+        stream_id: u32,
+        topic_id: u32,
+        config: TopicConfig,
+    },
+    /// The topic has been marked for deletion, it's going to be deleted at the given time.
+    TopicMarkedForDeletion {
+        stream_id: u32,
+        topic_id: u32,
+        delete_at: IggyTimestamp,
+    },
+    /// The topic has been deleted.
+    TopicDeleted { stream_id: u32, topic_id: u32 },
+    /// The partitions have been added to the topic.
+    PartitionsCreated {
+        stream_id: u32,
+        topic_id: u32,
+        created_partitions_count: u32,
+        partitions_count: u32,
+    },
+    /// The partitions have been removed from the topic.
+    PartitionsDeleted {
+        stream_id: u32,
+        topic_id: u32,
+        deleted_partitions_count: u32,
+        partitions_count: u32,
+    },
+    /// The stored offset which was out of the range of the available messages has been recovered,
+    /// `offset` is `None` if it has been removed.
+    ConsumerOffsetRecovered {
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+        consumer_kind: ConsumerKind,
+        consumer_id: u32,
+        previous_offset: u64,
+        offset: Option<u64>,
+    },
+}
+
+impl MetadataChange {
+    /// Returns the identifier of the stream affected by the change.
+    pub fn stream_id(&self) -> u32 {
+        match self {
+            MetadataChange::StreamCreated { stream_id, .. }
+            | MetadataChange::StreamUpdated { stream_id, .. }
+            | MetadataChange::StreamMetadataUpdated { stream_id, .. }
+            | MetadataChange::StreamQuotaUpdated { stream_id, .. }
+            | MetadataChange::StreamSoftQuotaExceeded { stream_id, .. }
+            | MetadataChange::StreamDeleted { stream_id, .. }
+            | MetadataChange::TopicCreated { stream_id, .. }
+            | MetadataChange::TopicUpdated { stream_id, .. }
+            | MetadataChange::TopicMetadataUpdated { stream_id, .. }
+            | MetadataChange::TopicProducersUpdated { stream_id, .. }
+            | MetadataChange::TopicConfigUpdated { stream_id, .. }
+            | MetadataChange::TopicMarkedForDeletion { stream_id, .. }
+            | MetadataChange::TopicDeleted { stream_id, .. }
+            | MetadataChange::PartitionsCreated { stream_id, .. }
+            | MetadataChange::PartitionsDeleted { stream_id, .. }
+            | MetadataChange::ConsumerOffsetRecovered { stream_id, .. } => *stream_id,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn event_should_be_serialized_with_change_type() {
+        let event = MetadataChangeEvent {
+            user_id: 1,
+            timestamp: IggyTimestamp::from(1000),
+            change: MetadataChange::PartitionsCreated {
+                stream_id: 1,
+                topic_id: 2,
+                created_partitions_count: 3,
+                partitions_count: 5,
+            },
+        };
+
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["type"], "partitions_created");
+        assert_eq!(json["user_id"], 1);
+        assert_eq!(json["topic_id"], 2);
+        assert_eq!(json["partitions_count"], 5);
+    }
+
+    #[test]
+    fn event_should_be_deserialized_from_json() {
+        let event = MetadataChangeEvent {
+            user_id: 1,
+            timestamp: IggyTimestamp::from(1000),
+            change: MetadataChange::TopicUpdated {
+                stream_id: 1,
+                topic_id: 2,
+                name: "orders".to_string(),
+                message_expiry: IggyExpiry::NeverExpire,
+                compression_algorithm: CompressionAlgorithm::Gzip,
+                max_topic_size: MaxTopicSize::Unlimited,
+                replication_factor: 1,
+                max_segments: 10,
+            },
+        };
+
+        let json = serde_json::to_vec(&event).unwrap();
+        let deserialized: MetadataChangeEvent = serde_json::from_slice(&json).unwrap();
+        assert_eq!(deserialized, event);
+    }
+}
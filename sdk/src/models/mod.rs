@@ -16,18 +16,39 @@
  * under the License.
  */
 
+pub mod audit_entry;
+pub mod backup;
 pub mod client_info;
 pub mod consumer_group;
 pub mod consumer_offset_info;
+pub mod error_catalog;
+pub mod expiry_notification;
 pub mod header;
 pub mod identity_info;
+pub mod maintenance_mode;
+pub mod message_audit;
+pub mod message_size_distribution;
 pub mod messages;
+pub mod messages_aggregate;
+pub mod metadata;
+pub mod metadata_change;
+pub mod namespace;
 pub mod partition;
+pub mod partition_timestamp_offset;
 pub mod permissions;
 pub mod personal_access_token;
+pub mod producer_epoch;
+pub mod producer_session;
+pub mod push_subscription;
+pub mod replay_job;
+pub mod restored_segments;
+pub mod segment_range;
+pub mod server_info;
 pub mod snapshot;
 pub mod stats;
 pub mod stream;
 pub mod topic;
+pub mod transaction;
+pub mod unsaved_state;
 pub mod user_info;
 pub mod user_status;
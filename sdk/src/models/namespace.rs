@@ -0,0 +1,202 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::error::IggyError;
+use crate::utils::byte_size::IggyByteSize;
+use bytes::{BufMut, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `Namespace` represents the tenant grouping its own streams, users and personal access tokens, isolated from the other namespaces.
+/// It consists of the following fields:
+/// - `id`: the unique identifier (numeric) of the namespace.
+/// - `created_at`: the timestamp when the namespace was created.
+/// - `name`: the unique name of the namespace.
+/// - `quotas`: the limits of the streams, users and storage of the namespace.
+/// - `streams_count`: the number of streams in the namespace.
+/// - `users_count`: the number of users in the namespace.
+/// - `size`: the total size of the streams in the namespace.
+/// - `messages_count`: the total number of messages in the namespace.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Namespace {
+    /// The unique identifier (numeric) of the namespace.
+    pub id: u32,
+    /// The timestamp when the namespace was created.
+    pub created_at: u64,
+    /// The unique name of the namespace.
+    pub name: String,
+    /// The limits of the streams, users and storage of the namespace.
+    pub quotas: NamespaceQuotas,
+    /// The number of streams in the namespace.
+    pub streams_count: u32,
+    /// The number of users in the namespace.
+    pub users_count: u32,
+    /// The total size of the streams in the namespace.
+    pub size: IggyByteSize,
+    /// The total number of messages in the namespace.
+    pub messages_count: u64,
+}
+
+/// `NamespaceDetails` represents the detailed information about the namespace.
+/// It consists of the same fields as `Namespace` and additionally:
+/// - `stream_ids`: the identifiers of the streams in the namespace.
+/// - `user_ids`: the identifiers of the users in the namespace.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct NamespaceDetails {
+    /// The unique identifier (numeric) of the namespace.
+    pub id: u32,
+    /// The timestamp when the namespace was created.
+    pub created_at: u64,
+    /// The unique name of the namespace.
+    pub name: String,
+    /// The limits of the streams, users and storage of the namespace.
+    pub quotas: NamespaceQuotas,
+    /// The number of streams in the namespace.
+    pub streams_count: u32,
+    /// The number of users in the namespace.
+    pub users_count: u32,
+    /// The total size of the streams in the namespace.
+    pub size: IggyByteSize,
+    /// The total number of messages in the namespace.
+    pub messages_count: u64,
+    /// The identifiers of the streams in the namespace.
+    pub stream_ids: Vec<u32>,
+    /// The identifiers of the users in the namespace.
+    pub user_ids: Vec<u32>,
+}
+
+/// `NamespaceQuotas` represents the limits of a single namespace, `0` means unlimited:
+/// - `max_streams`: the maximum number of streams in the namespace.
+/// - `max_users`: the maximum number of users in the namespace.
+/// - `max_size`: the maximum total size of the streams in the namespace.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct NamespaceQuotas {
+    /// The maximum number of streams in the namespace.
+    #[serde(default)]
+    pub max_streams: u32,
+    /// The maximum number of users in the namespace.
+    #[serde(default)]
+    pub max_users: u32,
+    /// The maximum total size of the streams in the namespace.
+    #[serde(default)]
+    pub max_size: IggyByteSize,
+}
+
+impl NamespaceQuotas {
+    /// Returns true if another stream can't be created in the namespace having the given number of streams.
+    pub fn is_streams_limit_reached(&self, streams_count: u32) -> bool {
+        self.max_streams > 0 && streams_count >= self.max_streams
+    }
+
+    /// Returns true if another user can't be created in the namespace having the given number of users.
+    pub fn is_users_limit_reached(&self, users_count: u32) -> bool {
+        self.max_users > 0 && users_count >= self.max_users
+    }
+
+    /// Returns true if the given total size of the streams exceeds the storage quota.
+    pub fn is_size_exceeded(&self, size: u64) -> bool {
+        let max_size = self.max_size.as_bytes_u64();
+        max_size > 0 && size > max_size
+    }
+
+    /// Returns the size of the binary representation in bytes.
+    pub fn get_size_bytes(&self) -> usize {
+        4 + 4 + 8
+    }
+
+    /// Appends the binary representation to the provided buffer.
+    pub fn write_to_buffer(&self, bytes: &mut BytesMut) {
+        bytes.put_u32_le(self.max_streams);
+        bytes.put_u32_le(self.max_users);
+        bytes.put_u64_le(self.max_size.as_bytes_u64());
+    }
+
+    /// Reads the quotas from the provided bytes starting at the given position.
+    /// Returns the quotas and the number of bytes read.
+    pub fn from_bytes_at(bytes: &[u8], position: usize) -> Result<(Self, usize), IggyError> {
+        let read_u32 = |position: usize| -> Result<u32, IggyError> {
+            Ok(u32::from_le_bytes(
+                bytes
+                    .get(position..position + 4)
+                    .ok_or(IggyError::InvalidCommand)?
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            ))
+        };
+        let max_streams = read_u32(position)?;
+        let max_users = read_u32(position + 4)?;
+        let max_size = u64::from_le_bytes(
+            bytes
+                .get(position + 8..position + 16)
+                .ok_or(IggyError::InvalidCommand)?
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let quotas = NamespaceQuotas {
+            max_streams,
+            max_users,
+            max_size: IggyByteSize::from(max_size),
+        };
+        Ok((quotas, 16))
+    }
+}
+
+impl Display for NamespaceQuotas {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let format_limit = |limit: u32| match limit {
+            0 => "unlimited".to_string(),
+            limit => limit.to_string(),
+        };
+        write!(
+            f,
+            "{}|{}|{}",
+            format_limit(self.max_streams),
+            format_limit(self.max_users),
+            self.max_size.as_human_string_with_zero_as_unlimited()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quotas_should_be_serialized_and_deserialized() {
+        let quotas = NamespaceQuotas {
+            max_streams: 10,
+            max_users: 5,
+            max_size: IggyByteSize::from(1024),
+        };
+        let mut bytes = BytesMut::new();
+        quotas.write_to_buffer(&mut bytes);
+        assert_eq!(bytes.len(), quotas.get_size_bytes());
+
+        let (deserialized, read_bytes) = NamespaceQuotas::from_bytes_at(&bytes, 0).unwrap();
+        assert_eq!(read_bytes, quotas.get_size_bytes());
+        assert_eq!(deserialized, quotas);
+    }
+
+    #[test]
+    fn zero_quotas_should_be_unlimited() {
+        let quotas = NamespaceQuotas::default();
+        assert!(!quotas.is_streams_limit_reached(u32::MAX));
+        assert!(!quotas.is_users_limit_reached(u32::MAX));
+        assert!(!quotas.is_size_exceeded(u64::MAX));
+    }
+}
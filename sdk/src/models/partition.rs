@@ -17,6 +17,7 @@
  */
 
 use crate::utils::byte_size::IggyByteSize;
+use crate::utils::duration::IggyDuration;
 use crate::utils::timestamp::IggyTimestamp;
 use serde::{Deserialize, Serialize};
 
@@ -28,6 +29,7 @@ use serde::{Deserialize, Serialize};
 /// - `current_offset`: the current offset of the partition.
 /// - `size_bytes`: the size of the partition in bytes.
 /// - `messages_count`: the number of messages in the partition.
+/// - `recovery`: the progress of the background recovery of the partition, if it's still being recovered.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Partition {
     /// Unique identifier of the partition.
@@ -42,4 +44,32 @@ pub struct Partition {
     pub size: IggyByteSize,
     /// The number of messages in the partition.
     pub messages_count: u64,
+    /// The progress of the background recovery of the partition, if it's still being recovered.
+    #[serde(default)]
+    pub recovery: Option<PartitionRecoveryProgress>,
+}
+
+/// `PartitionRecoveryProgress` represents the progress of rebuilding the indexes of the partition segments in the background.
+/// Meanwhile, the partition accepts the messages only up to the configured write rate.
+/// It consists of the following fields:
+/// - `started_at`: the timestamp of the recovery start.
+/// - `total_segments`: the number of the segments to be recovered.
+/// - `recovered_segments`: the number of the segments recovered so far.
+/// - `total_size`: the size of the segments to be recovered.
+/// - `recovered_size`: the size of the segments recovered so far.
+/// - `eta`: the estimated time remaining until the recovery is completed, unknown until any segment is recovered.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PartitionRecoveryProgress {
+    /// The timestamp of the recovery start.
+    pub started_at: IggyTimestamp,
+    /// The number of the segments to be recovered.
+    pub total_segments: u32,
+    /// The number of the segments recovered so far.
+    pub recovered_segments: u32,
+    /// The size of the segments to be recovered.
+    pub total_size: IggyByteSize,
+    /// The size of the segments recovered so far.
+    pub recovered_size: IggyByteSize,
+    /// The estimated time remaining until the recovery is completed, unknown until any segment is recovered.
+    pub eta: Option<IggyDuration>,
 }
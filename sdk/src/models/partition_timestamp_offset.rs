@@ -0,0 +1,35 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::utils::timestamp::IggyTimestamp;
+use serde::{Deserialize, Serialize};
+
+/// `PartitionTimestampOffset` represents the offset found for the timestamp in a single partition.
+/// It consists of the following fields:
+/// - `partition_id`: the unique identifier of the partition.
+/// - `timestamp`: the timestamp which was looked up.
+/// - `offset`: the offset of the earliest message with the timestamp greater than or equal to the given one, if any.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Copy)]
+pub struct PartitionTimestampOffset {
+    /// The unique identifier of the partition.
+    pub partition_id: u32,
+    /// The timestamp which was looked up.
+    pub timestamp: IggyTimestamp,
+    /// The offset of the earliest message with the timestamp greater than or equal to the given one,
+    /// or `None` if all the messages in the partition are older.
+    pub offset: Option<u64>,
+}
@@ -16,8 +16,14 @@
  * under the License.
  */
 
+use crate::error::IggyError;
+use crate::utils::ip_range::IpRange;
 use crate::utils::timestamp::IggyTimestamp;
+use bytes::{BufMut, BytesMut};
 use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::net::IpAddr;
+use std::str::{from_utf8, FromStr};
 
 /// `RawPersonalAccessToken` represents the raw personal access token - the secured token which is returned only once during the creation.
 /// It consists of the following fields:
@@ -32,10 +38,281 @@ pub struct RawPersonalAccessToken {
 /// It consists of the following fields:
 /// - `name`: the unique name of the token.
 /// - `expiry`: the optional expiry of the token.
+/// - `scope`: the restrictions applied to the sessions created with the token.
+/// - `last_used_at`: the time of the last successful login with the token since the server start, if any.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PersonalAccessTokenInfo {
     /// The unique name of the token.
     pub name: String,
     /// The optional expiry of the token.
     pub expiry_at: Option<IggyTimestamp>,
+    /// The restrictions applied to the sessions created with the token.
+    #[serde(default)]
+    pub scope: PersonalAccessTokenScope,
+    /// The time of the last successful login with the token since the server start, if any.
+    #[serde(default)]
+    pub last_used_at: Option<IggyTimestamp>,
+}
+
+/// `PersonalAccessTokenScope` narrows down what can be done with the personal access token,
+/// on top of the permissions of the user owning it. The default scope has no restrictions.
+/// It consists of the following fields:
+/// - `read_only`: only the commands that do not modify the data are allowed, consuming the messages (storing the offsets, joining the consumer groups etc.) is still possible.
+/// - `resources`: the streams and topics the token is limited to, all of them if empty.
+/// - `allowed_ip_ranges`: the source IP address ranges the token can be used from, any address if empty.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PersonalAccessTokenScope {
+    /// Only the commands that do not modify the data are allowed.
+    #[serde(default)]
+    pub read_only: bool,
+    /// The streams and topics the token is limited to, all of them if empty.
+    #[serde(default)]
+    pub resources: Vec<PersonalAccessTokenResource>,
+    /// The source IP address ranges (CIDR) the token can be used from, any address if empty.
+    #[serde(default)]
+    pub allowed_ip_ranges: Vec<IpRange>,
+}
+
+/// `PersonalAccessTokenResource` is a stream, or a single topic of the stream, the personal access token is limited to.
+/// It consists of the following fields:
+/// - `stream_id`: the numeric ID of the stream.
+/// - `topic_id`: the optional numeric ID of the topic, the whole stream if not set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PersonalAccessTokenResource {
+    /// The numeric ID of the stream.
+    pub stream_id: u32,
+    /// The optional numeric ID of the topic, the whole stream if not set.
+    #[serde(default)]
+    pub topic_id: Option<u32>,
+}
+
+impl PersonalAccessTokenScope {
+    /// Returns `true` if the commands executed with the token must be checked against the scope.
+    pub fn restricts_commands(&self) -> bool {
+        self.read_only || !self.resources.is_empty()
+    }
+
+    /// Checks if the token can be used from the IP address.
+    pub fn is_ip_address_allowed(&self, ip_address: &IpAddr) -> bool {
+        self.allowed_ip_ranges.is_empty()
+            || self
+                .allowed_ip_ranges
+                .iter()
+                .any(|range| range.contains(ip_address))
+    }
+
+    /// Checks if the whole stream is within the scope.
+    pub fn allows_stream(&self, stream_id: u32) -> bool {
+        self.resources.is_empty()
+            || self
+                .resources
+                .iter()
+                .any(|resource| resource.stream_id == stream_id && resource.topic_id.is_none())
+    }
+
+    /// Checks if at least a part of the stream (e.g. a single topic) is within the scope.
+    pub fn allows_any_of_stream(&self, stream_id: u32) -> bool {
+        self.resources.is_empty()
+            || self
+                .resources
+                .iter()
+                .any(|resource| resource.stream_id == stream_id)
+    }
+
+    /// Checks if the topic is within the scope.
+    pub fn allows_topic(&self, stream_id: u32, topic_id: u32) -> bool {
+        self.resources.is_empty()
+            || self.resources.iter().any(|resource| {
+                resource.stream_id == stream_id && resource.topic_id.is_none_or(|id| id == topic_id)
+            })
+    }
+
+    /// Returns the size of the binary representation in bytes.
+    pub fn get_size_bytes(&self) -> usize {
+        1 + 4
+            + 8 * self.resources.len()
+            + 4
+            + self
+                .allowed_ip_ranges
+                .iter()
+                .map(|range| 1 + range.to_string().len())
+                .sum::<usize>()
+    }
+
+    /// Appends the binary representation to the provided buffer.
+    pub fn write_to_buffer(&self, bytes: &mut BytesMut) {
+        bytes.put_u8(self.read_only as u8);
+        bytes.put_u32_le(self.resources.len() as u32);
+        for resource in &self.resources {
+            bytes.put_u32_le(resource.stream_id);
+            bytes.put_u32_le(resource.topic_id.unwrap_or_default());
+        }
+        bytes.put_u32_le(self.allowed_ip_ranges.len() as u32);
+        for range in &self.allowed_ip_ranges {
+            let range = range.to_string();
+            bytes.put_u8(range.len() as u8);
+            bytes.put_slice(range.as_bytes());
+        }
+    }
+
+    /// Reads the scope from the provided bytes starting at the given position.
+    /// Returns the scope and the number of bytes read.
+    pub fn from_bytes_at(bytes: &[u8], position: usize) -> Result<(Self, usize), IggyError> {
+        let get = |from: usize, length: usize| -> Result<&[u8], IggyError> {
+            bytes
+                .get(from..from + length)
+                .ok_or(IggyError::InvalidCommand)
+        };
+        let read_u32 = |from: usize| -> Result<u32, IggyError> {
+            get(from, 4)?
+                .try_into()
+                .map(u32::from_le_bytes)
+                .map_err(|_| IggyError::InvalidNumberEncoding)
+        };
+        let read_only = get(position, 1)?[0] == 1;
+        let resources_count = read_u32(position + 1)? as usize;
+        let mut read_bytes = 5;
+        let mut resources = Vec::new();
+        for _ in 0..resources_count {
+            let stream_id = read_u32(position + read_bytes)?;
+            let topic_id = match read_u32(position + read_bytes + 4)? {
+                0 => None,
+                topic_id => Some(topic_id),
+            };
+            resources.push(PersonalAccessTokenResource {
+                stream_id,
+                topic_id,
+            });
+            read_bytes += 8;
+        }
+        let ranges_count = read_u32(position + read_bytes)? as usize;
+        read_bytes += 4;
+        let mut allowed_ip_ranges = Vec::new();
+        for _ in 0..ranges_count {
+            let range_length = get(position + read_bytes, 1)?[0] as usize;
+            let range = from_utf8(get(position + read_bytes + 1, range_length)?)
+                .map_err(|_| IggyError::InvalidUtf8)?;
+            allowed_ip_ranges.push(range.parse()?);
+            read_bytes += 1 + range_length;
+        }
+        Ok((
+            PersonalAccessTokenScope {
+                read_only,
+                resources,
+                allowed_ip_ranges,
+            },
+            read_bytes,
+        ))
+    }
+}
+
+impl Display for PersonalAccessTokenScope {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let resources = self
+            .resources
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(",");
+        let allowed_ip_ranges = self
+            .allowed_ip_ranges
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(",");
+        write!(f, "{}|{resources}|{allowed_ip_ranges}", self.read_only)
+    }
+}
+
+impl Display for PersonalAccessTokenResource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.topic_id {
+            Some(topic_id) => write!(f, "{}/{topic_id}", self.stream_id),
+            None => write!(f, "{}", self.stream_id),
+        }
+    }
+}
+
+impl FromStr for PersonalAccessTokenResource {
+    type Err = IggyError;
+
+    /// Parses the resource in the `stream_id` or `stream_id/topic_id` format.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (stream_id, topic_id) = match value.split_once('/') {
+            Some((stream_id, topic_id)) => (stream_id, Some(topic_id)),
+            None => (value, None),
+        };
+        let stream_id = stream_id
+            .parse::<u32>()
+            .map_err(|_| IggyError::InvalidPersonalAccessTokenScope)?;
+        let topic_id = topic_id
+            .map(|topic_id| topic_id.parse::<u32>())
+            .transpose()
+            .map_err(|_| IggyError::InvalidPersonalAccessTokenScope)?;
+        if stream_id == 0 || topic_id == Some(0) {
+            return Err(IggyError::InvalidPersonalAccessTokenScope);
+        }
+
+        Ok(PersonalAccessTokenResource {
+            stream_id,
+            topic_id,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scope_should_be_serialized_and_deserialized() {
+        let scope = PersonalAccessTokenScope {
+            read_only: true,
+            resources: vec![
+                PersonalAccessTokenResource {
+                    stream_id: 1,
+                    topic_id: None,
+                },
+                PersonalAccessTokenResource {
+                    stream_id: 2,
+                    topic_id: Some(3),
+                },
+            ],
+            allowed_ip_ranges: vec!["10.0.0.0/8".parse().unwrap()],
+        };
+        let mut bytes = BytesMut::new();
+        bytes.put_u8(0);
+        scope.write_to_buffer(&mut bytes);
+        assert_eq!(bytes.len(), 1 + scope.get_size_bytes());
+
+        let (deserialized, read_bytes) =
+            PersonalAccessTokenScope::from_bytes_at(&bytes, 1).unwrap();
+        assert_eq!(read_bytes, scope.get_size_bytes());
+        assert_eq!(deserialized, scope);
+    }
+
+    #[test]
+    fn scope_should_allow_only_listed_resources() {
+        let scope = PersonalAccessTokenScope {
+            resources: vec![
+                PersonalAccessTokenResource {
+                    stream_id: 1,
+                    topic_id: None,
+                },
+                PersonalAccessTokenResource {
+                    stream_id: 2,
+                    topic_id: Some(3),
+                },
+            ],
+            ..Default::default()
+        };
+        assert!(scope.allows_stream(1));
+        assert!(scope.allows_topic(1, 10));
+        assert!(!scope.allows_stream(2));
+        assert!(scope.allows_any_of_stream(2));
+        assert!(scope.allows_topic(2, 3));
+        assert!(!scope.allows_topic(2, 4));
+        assert!(!scope.allows_any_of_stream(3));
+        assert!(PersonalAccessTokenScope::default().allows_topic(3, 1));
+    }
 }
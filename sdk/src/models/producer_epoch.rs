@@ -0,0 +1,125 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::error::IggyError;
+use crate::models::header::{HeaderKey, HeaderValue};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::str::FromStr;
+
+/// The header containing the name of the registered producer that sent the message.
+pub const PRODUCER_NAME_HEADER: &str = "iggy-producer-name";
+/// The header containing the epoch of the registered producer that sent the message.
+pub const PRODUCER_EPOCH_HEADER: &str = "iggy-producer-epoch";
+
+/// `ProducerEpoch` represents the epoch assigned to the registered producer instance.
+/// It consists of the following fields:
+/// - `name`: the name of the producer, unique within the topic.
+/// - `epoch`: the epoch of the producer, bumped with each registration.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct ProducerEpoch {
+    /// The name of the producer, unique within the topic.
+    pub name: String,
+    /// The epoch of the producer, bumped with each registration.
+    pub epoch: u32,
+}
+
+impl ProducerEpoch {
+    /// Returns the producer epoch the message has been sent with, based on the producer headers.
+    pub fn from_headers(
+        headers: &Option<HashMap<HeaderKey, HeaderValue>>,
+    ) -> Result<Option<Self>, IggyError> {
+        let Some(headers) = headers else {
+            return Ok(None);
+        };
+        let Some(name) = headers.get(&HeaderKey::new(PRODUCER_NAME_HEADER)?) else {
+            return Ok(None);
+        };
+        let Some(epoch) = headers.get(&HeaderKey::new(PRODUCER_EPOCH_HEADER)?) else {
+            return Err(IggyError::InvalidProducerEpochHeader);
+        };
+        let name = name
+            .as_str()
+            .map_err(|_| IggyError::InvalidProducerEpochHeader)?;
+        let epoch = epoch
+            .as_uint32()
+            .map_err(|_| IggyError::InvalidProducerEpochHeader)?;
+        Ok(Some(ProducerEpoch {
+            name: name.to_string(),
+            epoch,
+        }))
+    }
+
+    /// Marks the message as sent by the producer with this epoch by setting the producer headers.
+    pub fn set_headers(
+        &self,
+        headers: &mut Option<HashMap<HeaderKey, HeaderValue>>,
+    ) -> Result<(), IggyError> {
+        let headers = headers.get_or_insert_with(HashMap::new);
+        headers.insert(
+            HeaderKey::new(PRODUCER_NAME_HEADER)?,
+            HeaderValue::from_str(&self.name)?,
+        );
+        headers.insert(
+            HeaderKey::new(PRODUCER_EPOCH_HEADER)?,
+            HeaderValue::from_uint32(self.epoch)?,
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_read_from_headers_set_by_the_producer() {
+        let producer_epoch = ProducerEpoch {
+            name: "orders-writer".to_string(),
+            epoch: 3,
+        };
+        let mut headers = None;
+
+        producer_epoch.set_headers(&mut headers).unwrap();
+
+        assert_eq!(
+            ProducerEpoch::from_headers(&headers).unwrap(),
+            Some(producer_epoch)
+        );
+    }
+
+    #[test]
+    fn should_be_none_given_no_producer_headers() {
+        let headers = Some(HashMap::from([(
+            HeaderKey::new("key").unwrap(),
+            HeaderValue::from_uint32(1).unwrap(),
+        )]));
+
+        assert_eq!(ProducerEpoch::from_headers(&headers).unwrap(), None);
+    }
+
+    #[test]
+    fn should_fail_given_producer_name_without_epoch() {
+        let headers = Some(HashMap::from([(
+            HeaderKey::new(PRODUCER_NAME_HEADER).unwrap(),
+            HeaderValue::from_str("orders-writer").unwrap(),
+        )]));
+
+        assert!(ProducerEpoch::from_headers(&headers).is_err());
+    }
+}
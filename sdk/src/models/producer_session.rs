@@ -0,0 +1,125 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::error::IggyError;
+use crate::models::header::{HeaderKey, HeaderValue};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// The header containing the ID of the idempotent producer session that sent the message.
+pub const PRODUCER_ID_HEADER: &str = "iggy-producer-id";
+/// The header containing the sequence number of the message within the idempotent producer session.
+pub const PRODUCER_SEQUENCE_HEADER: &str = "iggy-producer-sequence";
+
+/// `ProducerSession` represents the idempotent producer session started with the `InitProducerId` command.
+/// It consists of the following fields:
+/// - `producer_id`: the unique ID of the producer session.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Copy)]
+pub struct ProducerSession {
+    /// The unique ID of the producer session.
+    pub producer_id: u64,
+}
+
+/// `ProducerSequence` represents the sequence number the message has been sent with by the idempotent producer.
+/// The sequence numbers must be increasing within the session, each partition appends only the messages
+/// with the sequence number greater than the last one it has appended for the same producer, and ignores the rest as duplicates.
+/// It consists of the following fields:
+/// - `producer_id`: the unique ID of the producer session.
+/// - `sequence`: the sequence number of the message.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Copy)]
+pub struct ProducerSequence {
+    /// The unique ID of the producer session.
+    pub producer_id: u64,
+    /// The sequence number of the message.
+    pub sequence: u64,
+}
+
+impl ProducerSequence {
+    /// Returns the producer sequence the message has been sent with, based on the producer session headers.
+    pub fn from_headers(
+        headers: &Option<HashMap<HeaderKey, HeaderValue>>,
+    ) -> Result<Option<Self>, IggyError> {
+        let Some(headers) = headers else {
+            return Ok(None);
+        };
+        let Some(producer_id) = headers.get(&HeaderKey::new(PRODUCER_ID_HEADER)?) else {
+            return Ok(None);
+        };
+        let Some(sequence) = headers.get(&HeaderKey::new(PRODUCER_SEQUENCE_HEADER)?) else {
+            return Err(IggyError::InvalidProducerSequenceHeader);
+        };
+        let producer_id = producer_id
+            .as_uint64()
+            .map_err(|_| IggyError::InvalidProducerSequenceHeader)?;
+        let sequence = sequence
+            .as_uint64()
+            .map_err(|_| IggyError::InvalidProducerSequenceHeader)?;
+        Ok(Some(ProducerSequence {
+            producer_id,
+            sequence,
+        }))
+    }
+
+    /// Marks the message as sent by the producer session with this sequence number by setting the producer session headers.
+    pub fn set_headers(
+        &self,
+        headers: &mut Option<HashMap<HeaderKey, HeaderValue>>,
+    ) -> Result<(), IggyError> {
+        let headers = headers.get_or_insert_with(HashMap::new);
+        headers.insert(
+            HeaderKey::new(PRODUCER_ID_HEADER)?,
+            HeaderValue::from_uint64(self.producer_id)?,
+        );
+        headers.insert(
+            HeaderKey::new(PRODUCER_SEQUENCE_HEADER)?,
+            HeaderValue::from_uint64(self.sequence)?,
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_read_from_headers_set_by_the_producer() {
+        let producer_sequence = ProducerSequence {
+            producer_id: 1 << 32,
+            sequence: 7,
+        };
+        let mut headers = None;
+
+        producer_sequence.set_headers(&mut headers).unwrap();
+
+        assert_eq!(
+            ProducerSequence::from_headers(&headers).unwrap(),
+            Some(producer_sequence)
+        );
+    }
+
+    #[test]
+    fn should_fail_given_producer_id_without_sequence() {
+        let headers = Some(HashMap::from([(
+            HeaderKey::new(PRODUCER_ID_HEADER).unwrap(),
+            HeaderValue::from_uint64(1).unwrap(),
+        )]));
+
+        assert!(ProducerSequence::from_headers(&headers).is_err());
+    }
+}
@@ -0,0 +1,132 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::error::IggyError;
+use crate::models::messages::PolledMessage;
+use crate::utils::timestamp::IggyTimestamp;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `PushSubscription` represents the delivery of the messages from the partition to the external HTTP endpoint, managed by the server.
+/// It consists of the following fields:
+/// - `id`: the unique identifier of the push subscription.
+/// - `user_id`: the identifier of the user who created the subscription, the messages are polled on their behalf.
+/// - `status`: the status of the delivery.
+/// - `stream_id`: the identifier of the stream.
+/// - `topic_id`: the identifier of the topic.
+/// - `partition_id`: the identifier of the partition from which the messages are pushed.
+/// - `endpoint`: the URL to which the batches of messages are sent with the POST request.
+/// - `batch_size`: the maximum number of messages sent in a single request.
+/// - `next_offset`: the offset of the next message to be pushed, all the previous ones were acknowledged by the endpoint.
+/// - `pushed_messages`: the number of the messages acknowledged by the endpoint.
+/// - `failed_attempts`: the number of the consecutive failed deliveries of the current batch.
+/// - `last_error`: the error of the last failed delivery, `None` if the last delivery succeeded.
+/// - `created_at`: the timestamp when the subscription was created.
+/// - `last_pushed_at`: the timestamp of the last successful delivery, `None` if nothing was pushed yet.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct PushSubscription {
+    /// The unique identifier of the push subscription.
+    pub id: u32,
+    /// The identifier of the user who created the subscription.
+    pub user_id: u32,
+    /// The status of the delivery.
+    pub status: PushSubscriptionStatus,
+    /// The identifier of the stream.
+    pub stream_id: u32,
+    /// The identifier of the topic.
+    pub topic_id: u32,
+    /// The identifier of the partition from which the messages are pushed.
+    pub partition_id: u32,
+    /// The URL to which the batches of messages are sent with the POST request.
+    pub endpoint: String,
+    /// The maximum number of messages sent in a single request.
+    pub batch_size: u32,
+    /// The offset of the next message to be pushed.
+    pub next_offset: u64,
+    /// The number of the messages acknowledged by the endpoint.
+    pub pushed_messages: u64,
+    /// The number of the consecutive failed deliveries of the current batch.
+    pub failed_attempts: u32,
+    /// The error of the last failed delivery, `None` if the last delivery succeeded.
+    pub last_error: Option<String>,
+    /// The timestamp when the subscription was created.
+    pub created_at: IggyTimestamp,
+    /// The timestamp of the last successful delivery, `None` if nothing was pushed yet.
+    pub last_pushed_at: Option<IggyTimestamp>,
+}
+
+/// `PushSubscriptionStatus` represents the status of the delivery of the push subscription.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum PushSubscriptionStatus {
+    /// The messages are being pushed, or the subscription waits for the new ones.
+    #[default]
+    Active,
+    /// The last delivery failed, the batch will be sent again after the backoff.
+    Retrying,
+}
+
+impl PushSubscriptionStatus {
+    /// Returns the code of the push subscription status.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            PushSubscriptionStatus::Active => 1,
+            PushSubscriptionStatus::Retrying => 2,
+        }
+    }
+
+    /// Returns the push subscription status from the code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(PushSubscriptionStatus::Active),
+            2 => Ok(PushSubscriptionStatus::Retrying),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+impl Display for PushSubscriptionStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PushSubscriptionStatus::Active => write!(f, "active"),
+            PushSubscriptionStatus::Retrying => write!(f, "retrying"),
+        }
+    }
+}
+
+/// `PushedMessages` is the JSON body of the POST request sent to the endpoint of the push subscription.
+/// The endpoint acknowledges the batch by responding with any 2xx status code, otherwise the same batch is sent again.
+/// It consists of the following fields:
+/// - `subscription_id`: the unique identifier of the push subscription.
+/// - `stream_id`: the identifier of the stream.
+/// - `topic_id`: the identifier of the topic.
+/// - `partition_id`: the identifier of the partition.
+/// - `messages`: the pushed messages, ordered by their offsets.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PushedMessages {
+    /// The unique identifier of the push subscription.
+    pub subscription_id: u32,
+    /// The identifier of the stream.
+    pub stream_id: u32,
+    /// The identifier of the topic.
+    pub topic_id: u32,
+    /// The identifier of the partition.
+    pub partition_id: u32,
+    /// The pushed messages, ordered by their offsets.
+    pub messages: Vec<PolledMessage>,
+}
@@ -0,0 +1,125 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::error::IggyError;
+use crate::messages::replay_messages::ReplayRange;
+use crate::utils::timestamp::IggyTimestamp;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `ReplayJob` represents the progress of the replay of messages from the source partition into the destination topic.
+/// It consists of the following fields:
+/// - `id`: the unique identifier of the replay job.
+/// - `user_id`: the identifier of the user who started the replay.
+/// - `status`: the status of the replay job.
+/// - `source_stream_id`: the identifier of the source stream.
+/// - `source_topic_id`: the identifier of the source topic.
+/// - `source_partition_id`: the identifier of the source partition.
+/// - `destination_stream_id`: the identifier of the destination stream.
+/// - `destination_topic_id`: the identifier of the destination topic.
+/// - `range`: the offset or timestamp range of the replayed messages.
+/// - `current_offset`: the offset of the last replayed message.
+/// - `replayed_messages`: the number of already replayed messages.
+/// - `messages_per_second`: the maximum replay rate, `0` means unlimited.
+/// - `created_at`: the timestamp when the replay job was started.
+/// - `finished_at`: the timestamp when the replay job was finished, `None` if it's still running.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ReplayJob {
+    /// The unique identifier of the replay job.
+    pub id: u32,
+    /// The identifier of the user who started the replay.
+    pub user_id: u32,
+    /// The status of the replay job.
+    pub status: ReplayJobStatus,
+    /// The identifier of the source stream.
+    pub source_stream_id: u32,
+    /// The identifier of the source topic.
+    pub source_topic_id: u32,
+    /// The identifier of the source partition.
+    pub source_partition_id: u32,
+    /// The identifier of the destination stream.
+    pub destination_stream_id: u32,
+    /// The identifier of the destination topic.
+    pub destination_topic_id: u32,
+    /// The offset or timestamp range of the replayed messages.
+    pub range: ReplayRange,
+    /// The offset of the last replayed message.
+    pub current_offset: u64,
+    /// The number of already replayed messages.
+    pub replayed_messages: u64,
+    /// The maximum replay rate, `0` means unlimited.
+    pub messages_per_second: u32,
+    /// The timestamp when the replay job was started.
+    pub created_at: IggyTimestamp,
+    /// The timestamp when the replay job was finished, `None` if it's still running.
+    pub finished_at: Option<IggyTimestamp>,
+}
+
+/// `ReplayJobStatus` represents the status of the replay job.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum ReplayJobStatus {
+    /// The messages are being replayed.
+    #[default]
+    Running,
+    /// All the messages from the range were replayed.
+    Completed,
+    /// The replay was stopped due to an error.
+    Failed,
+    /// The replay was cancelled by the user.
+    Cancelled,
+}
+
+impl ReplayJobStatus {
+    /// Returns the code of the replay job status.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            ReplayJobStatus::Running => 1,
+            ReplayJobStatus::Completed => 2,
+            ReplayJobStatus::Failed => 3,
+            ReplayJobStatus::Cancelled => 4,
+        }
+    }
+
+    /// Returns the replay job status from the code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(ReplayJobStatus::Running),
+            2 => Ok(ReplayJobStatus::Completed),
+            3 => Ok(ReplayJobStatus::Failed),
+            4 => Ok(ReplayJobStatus::Cancelled),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+
+    /// Returns `true` if the replay job is no longer running.
+    pub fn is_finished(&self) -> bool {
+        *self != ReplayJobStatus::Running
+    }
+}
+
+impl Display for ReplayJobStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReplayJobStatus::Running => write!(f, "running"),
+            ReplayJobStatus::Completed => write!(f, "completed"),
+            ReplayJobStatus::Failed => write!(f, "failed"),
+            ReplayJobStatus::Cancelled => write!(f, "cancelled"),
+        }
+    }
+}
@@ -0,0 +1,35 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::utils::byte_size::IggyByteSize;
+use serde::{Deserialize, Serialize};
+
+/// `RestoredSegments` represents the result of the `RestoreArchivedSegments` command.
+/// It consists of the following fields:
+/// - `segments_count`: the number of the segments restored into the local storage.
+/// - `messages_count`: the number of the messages in the restored segments.
+/// - `size`: the total size of the restored segments.
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RestoredSegments {
+    /// The number of the segments restored into the local storage.
+    pub segments_count: u32,
+    /// The number of the messages in the restored segments.
+    pub messages_count: u64,
+    /// The total size of the restored segments.
+    pub size: IggyByteSize,
+}
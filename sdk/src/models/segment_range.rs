@@ -0,0 +1,62 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use bytes::Bytes;
+
+/// The HTTP response headers carrying the metadata of the segment range, while the body contains its raw bytes.
+pub const SEGMENT_START_OFFSET_HEADER: &str = "x-iggy-segment-start-offset";
+pub const SEGMENT_END_OFFSET_HEADER: &str = "x-iggy-segment-end-offset";
+pub const SEGMENT_SIZE_HEADER: &str = "x-iggy-segment-size";
+pub const SEGMENT_CLOSED_HEADER: &str = "x-iggy-segment-closed";
+pub const SEGMENT_START_POSITION_HEADER: &str = "x-iggy-segment-start-position";
+pub const SEGMENT_NEXT_POSITION_HEADER: &str = "x-iggy-segment-next-position";
+
+/// `SegmentRange` represents the raw bytes of the message batches read from a segment log file by the `ReadSegmentRange` command.
+/// It consists of the following fields:
+/// - `segment_start_offset`: the start offset of the segment.
+/// - `segment_end_offset`: the end offset of the segment, or its current offset if the segment is not closed yet.
+/// - `segment_size`: the size of the persisted part of the segment log file.
+/// - `segment_closed`: whether the segment is closed, so no more messages will be appended to it.
+/// - `start_position`: the position of the first returned batch in the segment log file.
+/// - `next_position`: the position right after the last returned batch, which can be used to read the next range.
+/// - `bytes`: the raw bytes of the batches, exactly as stored in the segment log file.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct SegmentRange {
+    /// The start offset of the segment.
+    pub segment_start_offset: u64,
+    /// The end offset of the segment, or its current offset if the segment is not closed yet.
+    pub segment_end_offset: u64,
+    /// The size of the persisted part of the segment log file.
+    pub segment_size: u64,
+    /// Whether the segment is closed, so no more messages will be appended to it.
+    pub segment_closed: bool,
+    /// The position of the first returned batch in the segment log file.
+    pub start_position: u64,
+    /// The position right after the last returned batch, which can be used to read the next range.
+    pub next_position: u64,
+    /// The raw bytes of the batches, exactly as stored in the segment log file.
+    pub bytes: Bytes,
+}
+
+impl SegmentRange {
+    /// Returns `true` if there's nothing more to read from the segment after this range,
+    /// meaning that the next segment should be read, starting from the offset right after the end offset.
+    pub fn is_segment_completed(&self) -> bool {
+        self.segment_closed && self.next_position >= self.segment_size
+    }
+}
@@ -0,0 +1,49 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use serde::{Deserialize, Serialize};
+
+/// `ServerInfo` represents the general information about the server.
+/// It consists of the following fields:
+/// - `version`: the version of the server.
+/// - `limits`: the server-wide resource limits enforced when creating the streams, topics and partitions.
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ServerInfo {
+    /// The version of the server.
+    pub version: String,
+    /// The server-wide resource limits.
+    pub limits: ServerLimits,
+}
+
+/// `ServerLimits` represents the server-wide resource limits, `0` means that the resource is unlimited.
+/// It consists of the following fields:
+/// - `max_streams`: the maximum number of the streams.
+/// - `max_topics_per_stream`: the maximum number of the topics in each stream.
+/// - `max_partitions_per_topic`: the maximum number of the partitions in each topic.
+/// - `max_total_partitions`: the maximum number of the partitions in all the topics.
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub struct ServerLimits {
+    /// The maximum number of the streams.
+    pub max_streams: u32,
+    /// The maximum number of the topics in each stream.
+    pub max_topics_per_stream: u32,
+    /// The maximum number of the partitions in each topic.
+    pub max_partitions_per_topic: u32,
+    /// The maximum number of the partitions in all the topics.
+    pub max_total_partitions: u32,
+}
@@ -74,6 +74,28 @@ pub struct Stats {
     /// Cache metrics per partition
     #[serde(with = "cache_metrics_serializer")]
     pub cache_metrics: HashMap<CacheMetricsKey, CacheMetrics>,
+    /// Current utilization of the load shedding limits per transport
+    #[serde(default)]
+    pub transport_utilization: Vec<TransportUtilization>,
+}
+
+/// Utilization of the load shedding limits for a specific transport
+#[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq)]
+pub struct TransportUtilization {
+    /// Name of the transport e.g. TCP, QUIC or HTTP
+    pub transport: String,
+    /// Number of currently open connections
+    pub connections: u32,
+    /// Maximum number of open connections, 0 means unlimited
+    pub max_connections: u32,
+    /// Number of requests being currently processed
+    pub in_flight_requests: u32,
+    /// Maximum number of requests processed at the same time, 0 means unlimited
+    pub max_in_flight_requests: u32,
+    /// Total number of connections rejected due to the limit
+    pub rejected_connections: u64,
+    /// Total number of requests rejected due to the limit or the frame size
+    pub rejected_requests: u64,
 }
 
 /// Key for identifying a specific partition's cache metrics
@@ -181,6 +203,7 @@ impl Default for Stats {
             iggy_server_version: "unknown_iggy_version".to_string(),
             iggy_server_semver: None,
             cache_metrics: HashMap::new(),
+            transport_utilization: Vec::new(),
         }
     }
 }
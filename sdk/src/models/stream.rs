@@ -16,6 +16,7 @@
  * under the License.
  */
 
+use crate::models::metadata::ResourceMetadata;
 use crate::utils::byte_size::IggyByteSize;
 use crate::{models::topic::Topic, utils::timestamp::IggyTimestamp};
 use serde::{Deserialize, Serialize};
@@ -28,6 +29,8 @@ use serde::{Deserialize, Serialize};
 /// - `size_bytes`: the total size of the stream in bytes.
 /// - `messages_count`: the total number of messages in the stream.
 /// - `topics_count`: the total number of topics in the stream.
+/// - `metadata`: the description, owner and labels of the stream.
+/// - `quota`: the soft and hard storage limits of the stream.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Stream {
     /// The unique identifier (numeric) of the stream.
@@ -42,6 +45,12 @@ pub struct Stream {
     pub messages_count: u64,
     /// The total number of topics in the stream.
     pub topics_count: u32,
+    /// The description, owner and labels of the stream.
+    #[serde(default)]
+    pub metadata: ResourceMetadata,
+    /// The soft and hard storage limits of the stream.
+    #[serde(default)]
+    pub quota: StreamQuota,
 }
 
 /// `StreamDetails` represents the detailed information about the stream.
@@ -52,6 +61,8 @@ pub struct Stream {
 /// - `size_bytes`: the total size of the stream in bytes.
 /// - `messages_count`: the total number of messages in the stream.
 /// - `topics_count`: the total number of topics in the stream.
+/// - `metadata`: the description, owner and labels of the stream.
+/// - `quota`: the soft and hard storage limits of the stream.
 /// - `topics`: the list of topics in the stream.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct StreamDetails {
@@ -67,6 +78,66 @@ pub struct StreamDetails {
     pub messages_count: u64,
     /// The total number of topics in the stream.
     pub topics_count: u32,
+    /// The description, owner and labels of the stream.
+    #[serde(default)]
+    pub metadata: ResourceMetadata,
+    /// The soft and hard storage limits of the stream.
+    #[serde(default)]
+    pub quota: StreamQuota,
     /// The collection of topics in the stream.
     pub topics: Vec<Topic>,
 }
+
+/// `StreamQuota` represents the storage limits of the stream enforced when appending the messages.
+/// It consists of the following fields:
+/// - `soft_limit`: the size above which the warnings are emitted, but the messages are still accepted.
+/// - `hard_limit`: the size above which the messages are rejected until the space is freed or the quota is raised.
+///
+/// The value of 0 disables the particular limit.
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct StreamQuota {
+    /// The size above which the warnings are emitted.
+    #[serde(default)]
+    pub soft_limit: IggyByteSize,
+    /// The size above which the messages are rejected.
+    #[serde(default)]
+    pub hard_limit: IggyByteSize,
+}
+
+impl StreamQuota {
+    /// Returns `true` if the soft limit is set and the given size exceeds it.
+    pub fn is_soft_limit_exceeded(&self, size: u64) -> bool {
+        let soft_limit = self.soft_limit.as_bytes_u64();
+        soft_limit > 0 && size > soft_limit
+    }
+
+    /// Returns `true` if the hard limit is set and the given size exceeds it.
+    pub fn is_hard_limit_exceeded(&self, size: u64) -> bool {
+        let hard_limit = self.hard_limit.as_bytes_u64();
+        hard_limit > 0 && size > hard_limit
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn disabled_quota_should_never_be_exceeded() {
+        let quota = StreamQuota::default();
+        assert!(!quota.is_soft_limit_exceeded(u64::MAX));
+        assert!(!quota.is_hard_limit_exceeded(u64::MAX));
+    }
+
+    #[test]
+    fn quota_should_be_exceeded_only_above_the_limits() {
+        let quota = StreamQuota {
+            soft_limit: IggyByteSize::from(100_u64),
+            hard_limit: IggyByteSize::from(200_u64),
+        };
+        assert!(!quota.is_soft_limit_exceeded(100));
+        assert!(quota.is_soft_limit_exceeded(101));
+        assert!(!quota.is_hard_limit_exceeded(200));
+        assert!(quota.is_hard_limit_exceeded(201));
+    }
+}
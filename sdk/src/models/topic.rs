@@ -17,7 +17,12 @@
  */
 
 use crate::compression::compression_algorithm::CompressionAlgorithm;
+use crate::messages::message_id_scheme::MessageIdScheme;
+use crate::models::message_size_distribution::MessageSizeDistribution;
+use crate::models::metadata::ResourceMetadata;
 use crate::models::partition::Partition;
+use crate::topics::cleanup_policy::CleanupPolicy;
+use crate::topics::topic_config::TopicConfig;
 use crate::utils::byte_size::IggyByteSize;
 use crate::utils::expiry::IggyExpiry;
 use crate::utils::timestamp::IggyTimestamp;
@@ -32,9 +37,16 @@ use serde::{Deserialize, Serialize};
 /// - `size`: the total size of the topic in bytes.
 /// - `message_expiry`: the expiry of the messages in the topic.
 /// - `max_topic_size`: the maximum size of the topic.
+/// - `max_segments`: the maximum number of segments per partition, 0 if unlimited.
 /// - `replication_factor`: replication factor for the topic.
 /// - `messages_count`: the total number of messages in the topic.
 /// - `partitions_count`: the total number of partitions in the topic.
+/// - `metadata`: the description, owner and labels of the topic.
+/// - `message_id_scheme`: the scheme used to assign the IDs to the messages sent without ID.
+/// - `cleanup_policy`: the policy used to clean up the old messages.
+/// - `allowed_producers`: the IDs of the users allowed to send the messages, empty if not restricted.
+/// - `delete_at`: the time at which the topic marked for deletion is going to be deleted.
+/// - `config`: the settings overriding the server defaults for the topic.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Topic {
     /// The unique identifier (numeric) of the topic.
@@ -52,12 +64,34 @@ pub struct Topic {
     /// The optional maximum size of the topic.
     /// Can't be lower than segment size in the config.
     pub max_topic_size: MaxTopicSize,
+    /// The maximum number of segments per partition, 0 if unlimited.
+    /// The oldest closed segments exceeding the limit are deleted by the maintenance.
+    #[serde(default)]
+    pub max_segments: u32,
     /// Replication factor for the topic.
     pub replication_factor: u8,
     /// The total number of messages in the topic.
     pub messages_count: u64,
     /// The total number of partitions in the topic.
     pub partitions_count: u32,
+    /// The description, owner and labels of the topic.
+    #[serde(default)]
+    pub metadata: ResourceMetadata,
+    /// The scheme used to assign the IDs to the messages sent without ID.
+    #[serde(default)]
+    pub message_id_scheme: MessageIdScheme,
+    /// The policy used to clean up the old messages.
+    #[serde(default)]
+    pub cleanup_policy: CleanupPolicy,
+    /// The IDs of the users allowed to send the messages, empty if not restricted.
+    #[serde(default)]
+    pub allowed_producers: Vec<u32>,
+    /// The time at which the topic marked for deletion is going to be deleted, `None` if it's not marked.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delete_at: Option<IggyTimestamp>,
+    /// The settings overriding the server defaults for the topic.
+    #[serde(default)]
+    pub config: TopicConfig,
 }
 
 /// `TopicDetails` represents the detailed information about the topic.
@@ -68,9 +102,17 @@ pub struct Topic {
 /// - `size`: the total size of the topic.
 /// - `message_expiry`: the expiry of the messages in the topic.
 /// - `max_topic_size`: the maximum size of the topic.
+/// - `max_segments`: the maximum number of segments per partition, 0 if unlimited.
 /// - `replication_factor`: replication factor for the topic.
 /// - `messages_count`: the total number of messages in the topic.
 /// - `partitions_count`: the total number of partitions in the topic.
+/// - `metadata`: the description, owner and labels of the topic.
+/// - `message_id_scheme`: the scheme used to assign the IDs to the messages sent without ID.
+/// - `cleanup_policy`: the policy used to clean up the old messages.
+/// - `allowed_producers`: the IDs of the users allowed to send the messages, empty if not restricted.
+/// - `delete_at`: the time at which the topic marked for deletion is going to be deleted.
+/// - `config`: the settings overriding the server defaults for the topic.
+/// - `message_sizes`: the approximate distribution of the sizes of the messages appended to the topic.
 /// - `partitions`: the collection of partitions in the topic.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TopicDetails {
@@ -89,12 +131,37 @@ pub struct TopicDetails {
     /// The optional maximum size of the topic.
     /// Can't be lower than segment size in the config.
     pub max_topic_size: MaxTopicSize,
+    /// The maximum number of segments per partition, 0 if unlimited.
+    /// The oldest closed segments exceeding the limit are deleted by the maintenance.
+    #[serde(default)]
+    pub max_segments: u32,
     /// Replication factor for the topic.
     pub replication_factor: u8,
     /// The total number of messages in the topic.
     pub messages_count: u64,
     /// The total number of partitions in the topic.
     pub partitions_count: u32,
+    /// The description, owner and labels of the topic.
+    #[serde(default)]
+    pub metadata: ResourceMetadata,
+    /// The scheme used to assign the IDs to the messages sent without ID.
+    #[serde(default)]
+    pub message_id_scheme: MessageIdScheme,
+    /// The policy used to clean up the old messages.
+    #[serde(default)]
+    pub cleanup_policy: CleanupPolicy,
+    /// The IDs of the users allowed to send the messages, empty if not restricted.
+    #[serde(default)]
+    pub allowed_producers: Vec<u32>,
+    /// The time at which the topic marked for deletion is going to be deleted, `None` if it's not marked.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delete_at: Option<IggyTimestamp>,
+    /// The settings overriding the server defaults for the topic.
+    #[serde(default)]
+    pub config: TopicConfig,
+    /// The approximate distribution of the sizes of the messages appended to the topic since the server has started.
+    #[serde(default)]
+    pub message_sizes: MessageSizeDistribution,
     /// The collection of partitions in the topic.
     pub partitions: Vec<Partition>,
 }
@@ -0,0 +1,111 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::error::IggyError;
+use crate::models::header::{HeaderKey, HeaderValue};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// The header containing the ID of the transaction the message has been sent within.
+pub const TRANSACTION_ID_HEADER: &str = "iggy-transaction-id";
+
+/// `Transaction` represents the transaction started with the `BeginTransaction` command.
+/// The messages sent with its ID in the transaction header to any topics and partitions are committed or aborted atomically.
+/// It consists of the following fields:
+/// - `transaction_id`: the unique ID of the transaction.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Copy)]
+pub struct Transaction {
+    /// The unique ID of the transaction.
+    pub transaction_id: u64,
+}
+
+impl Transaction {
+    /// Returns the transaction the message has been sent within, based on the transaction header.
+    pub fn from_headers(
+        headers: &Option<HashMap<HeaderKey, HeaderValue>>,
+    ) -> Result<Option<Self>, IggyError> {
+        let Some(headers) = headers else {
+            return Ok(None);
+        };
+        let Some(transaction_id) = headers.get(&HeaderKey::new(TRANSACTION_ID_HEADER)?) else {
+            return Ok(None);
+        };
+        let transaction_id = transaction_id
+            .as_uint64()
+            .map_err(|_| IggyError::InvalidTransactionHeader)?;
+        Ok(Some(Transaction { transaction_id }))
+    }
+
+    /// Marks the message as sent within this transaction by setting the transaction header.
+    pub fn set_headers(
+        &self,
+        headers: &mut Option<HashMap<HeaderKey, HeaderValue>>,
+    ) -> Result<(), IggyError> {
+        let headers = headers.get_or_insert_with(HashMap::new);
+        headers.insert(
+            HeaderKey::new(TRANSACTION_ID_HEADER)?,
+            HeaderValue::from_uint64(self.transaction_id)?,
+        );
+        Ok(())
+    }
+
+    /// Removes the transaction header, e.g. when the message is copied outside of the transaction it has been sent within.
+    pub fn remove_headers(
+        headers: &mut Option<HashMap<HeaderKey, HeaderValue>>,
+    ) -> Result<(), IggyError> {
+        let Some(existing_headers) = headers.as_mut() else {
+            return Ok(());
+        };
+        existing_headers.remove(&HeaderKey::new(TRANSACTION_ID_HEADER)?);
+        if existing_headers.is_empty() {
+            *headers = None;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn should_be_read_from_headers_set_by_the_producer() {
+        let transaction = Transaction {
+            transaction_id: 1 << 32,
+        };
+        let mut headers = None;
+
+        transaction.set_headers(&mut headers).unwrap();
+
+        assert_eq!(
+            Transaction::from_headers(&headers).unwrap(),
+            Some(transaction)
+        );
+    }
+
+    #[test]
+    fn should_fail_given_invalid_transaction_id() {
+        let headers = Some(HashMap::from([(
+            HeaderKey::new(TRANSACTION_ID_HEADER).unwrap(),
+            HeaderValue::from_str("abc").unwrap(),
+        )]));
+
+        assert!(Transaction::from_headers(&headers).is_err());
+    }
+}
@@ -0,0 +1,41 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::utils::byte_size::IggyByteSize;
+use serde::{Deserialize, Serialize};
+
+/// `PartitionUnsavedState` represents the messages of a partition which are accumulated in memory and not yet saved on disk.
+/// It consists of the following fields:
+/// - `stream_id`: the unique identifier (numeric) of the stream.
+/// - `topic_id`: the unique identifier (numeric) of the topic.
+/// - `partition_id`: the unique identifier (numeric) of the partition.
+/// - `messages_count`: the number of the unsaved messages.
+/// - `size`: the total size of the unsaved messages.
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
+pub struct PartitionUnsavedState {
+    /// The unique identifier (numeric) of the stream.
+    pub stream_id: u32,
+    /// The unique identifier (numeric) of the topic.
+    pub topic_id: u32,
+    /// The unique identifier (numeric) of the partition.
+    pub partition_id: u32,
+    /// The number of the unsaved messages.
+    pub messages_count: u64,
+    /// The total size of the unsaved messages.
+    pub size: IggyByteSize,
+}
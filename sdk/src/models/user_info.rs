@@ -18,6 +18,7 @@
 
 use crate::models::permissions::Permissions;
 use crate::models::user_status::UserStatus;
+use crate::users::user_quotas::UserQuotas;
 use crate::utils::timestamp::IggyTimestamp;
 use serde::{Deserialize, Serialize};
 use std::sync::atomic::AtomicU32;
@@ -53,6 +54,7 @@ pub struct UserInfo {
 /// - `status`: the status of the user.
 /// - `username`: the username of the user.
 /// - `permissions`: the optional permissions of the user.
+/// - `quotas`: the quotas of the user overriding the server defaults.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct UserInfoDetails {
     /// The unique identifier (numeric) of the user.
@@ -65,4 +67,7 @@ pub struct UserInfoDetails {
     pub username: String,
     /// The optional permissions of the user.
     pub permissions: Option<Permissions>,
+    /// The quotas of the user overriding the server defaults.
+    #[serde(default)]
+    pub quotas: UserQuotas,
 }
@@ -0,0 +1,138 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, CREATE_NAMESPACE_CODE};
+use crate::error::IggyError;
+use crate::models::namespace::NamespaceQuotas;
+use crate::namespaces::MAX_NAME_LENGTH;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::from_utf8;
+
+/// `CreateNamespace` command is used to create a new namespace isolating its streams, users and quotas.
+/// It has additional payload:
+/// - `name` - unique namespace name (string), max length is 255 characters.
+/// - `quotas` - the limits of the streams, users and storage of the namespace, `0` means unlimited.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct CreateNamespace {
+    /// Unique namespace name (string), max length is 255 characters.
+    pub name: String,
+    /// The limits of the streams, users and storage of the namespace.
+    #[serde(default)]
+    pub quotas: NamespaceQuotas,
+}
+
+impl Command for CreateNamespace {
+    fn code(&self) -> u32 {
+        CREATE_NAMESPACE_CODE
+    }
+}
+
+impl Default for CreateNamespace {
+    fn default() -> Self {
+        CreateNamespace {
+            name: "namespace".to_string(),
+            quotas: NamespaceQuotas::default(),
+        }
+    }
+}
+
+impl Validatable<IggyError> for CreateNamespace {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.name.is_empty() || self.name.len() > MAX_NAME_LENGTH {
+            return Err(IggyError::InvalidNamespaceName);
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for CreateNamespace {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(1 + self.name.len() + self.quotas.get_size_bytes());
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(self.name.len() as u8);
+        bytes.put_slice(self.name.as_bytes());
+        self.quotas.write_to_buffer(&mut bytes);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<CreateNamespace, IggyError> {
+        if bytes.len() < 18 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let name_length = bytes[0] as usize;
+        let name = from_utf8(
+            bytes
+                .get(1..1 + name_length)
+                .ok_or(IggyError::InvalidCommand)?,
+        )
+        .map_err(|_| IggyError::InvalidUtf8)?
+        .to_string();
+        if name.len() != name_length {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let position = 1 + name_length;
+        let (quotas, read_bytes) = NamespaceQuotas::from_bytes_at(&bytes, position)?;
+        if position + read_bytes != bytes.len() {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        Ok(CreateNamespace { name, quotas })
+    }
+}
+
+impl Display for CreateNamespace {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}|{}", self.name, self.quotas)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::byte_size::IggyByteSize;
+
+    #[test]
+    fn should_be_serialized_and_deserialized() {
+        let command = CreateNamespace {
+            name: "team".to_string(),
+            quotas: NamespaceQuotas {
+                max_streams: 10,
+                max_users: 5,
+                max_size: IggyByteSize::from(1024),
+            },
+        };
+
+        let deserialized = CreateNamespace::from_bytes(command.to_bytes()).unwrap();
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_without_quotas() {
+        let mut bytes = BytesMut::new();
+        bytes.put_u8(4);
+        bytes.put_slice(b"team");
+        assert!(CreateNamespace::from_bytes(bytes.freeze()).is_err());
+    }
+}
@@ -0,0 +1,99 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, DELETE_NAMESPACE_CODE};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::validatable::Validatable;
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `DeleteNamespace` command is used to delete an existing namespace, it must not contain any streams or users.
+/// It has additional payload:
+/// - `namespace_id` - unique namespace ID (numeric or name).
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct DeleteNamespace {
+    /// Unique namespace ID (numeric or name).
+    #[serde(skip)]
+    pub namespace_id: Identifier,
+}
+
+impl Command for DeleteNamespace {
+    fn code(&self) -> u32 {
+        DELETE_NAMESPACE_CODE
+    }
+}
+
+impl Validatable<IggyError> for DeleteNamespace {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for DeleteNamespace {
+    fn to_bytes(&self) -> Bytes {
+        self.namespace_id.to_bytes()
+    }
+
+    fn from_bytes(bytes: Bytes) -> std::result::Result<DeleteNamespace, IggyError> {
+        if bytes.len() < 3 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let namespace_id = Identifier::from_bytes(bytes)?;
+        let command = DeleteNamespace { namespace_id };
+        Ok(command)
+    }
+}
+
+impl Display for DeleteNamespace {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.namespace_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = DeleteNamespace {
+            namespace_id: Identifier::numeric(1).unwrap(),
+        };
+
+        let bytes = command.to_bytes();
+        let namespace_id = Identifier::from_bytes(bytes.clone()).unwrap();
+
+        assert!(!bytes.is_empty());
+        assert_eq!(namespace_id, command.namespace_id);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let namespace_id = Identifier::numeric(1).unwrap();
+        let bytes = namespace_id.to_bytes();
+        let command = DeleteNamespace::from_bytes(bytes);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.namespace_id, namespace_id);
+    }
+}
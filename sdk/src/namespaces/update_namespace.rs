@@ -0,0 +1,110 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, UPDATE_NAMESPACE_CODE};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::models::namespace::NamespaceQuotas;
+use crate::utils::sizeable::Sizeable;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `UpdateNamespace` command is used to update the quotas of an existing namespace.
+/// It has additional payload:
+/// - `namespace_id` - unique namespace ID (numeric or name).
+/// - `quotas` - the limits of the streams, users and storage of the namespace, `0` means unlimited.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct UpdateNamespace {
+    /// Unique namespace ID (numeric or name).
+    #[serde(skip)]
+    pub namespace_id: Identifier,
+    /// The limits of the streams, users and storage of the namespace.
+    #[serde(default)]
+    pub quotas: NamespaceQuotas,
+}
+
+impl Command for UpdateNamespace {
+    fn code(&self) -> u32 {
+        UPDATE_NAMESPACE_CODE
+    }
+}
+
+impl Validatable<IggyError> for UpdateNamespace {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for UpdateNamespace {
+    fn to_bytes(&self) -> Bytes {
+        let namespace_id_bytes = self.namespace_id.to_bytes();
+        let mut bytes =
+            BytesMut::with_capacity(namespace_id_bytes.len() + self.quotas.get_size_bytes());
+        bytes.put_slice(&namespace_id_bytes);
+        self.quotas.write_to_buffer(&mut bytes);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<UpdateNamespace, IggyError> {
+        if bytes.len() < 19 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let namespace_id = Identifier::from_bytes(bytes.clone())?;
+        let position = namespace_id.get_size_bytes().as_bytes_usize();
+        let (quotas, read_bytes) = NamespaceQuotas::from_bytes_at(&bytes, position)?;
+        if position + read_bytes != bytes.len() {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        Ok(UpdateNamespace {
+            namespace_id,
+            quotas,
+        })
+    }
+}
+
+impl Display for UpdateNamespace {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}|{}", self.namespace_id, self.quotas)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::byte_size::IggyByteSize;
+
+    #[test]
+    fn should_be_serialized_and_deserialized() {
+        let command = UpdateNamespace {
+            namespace_id: Identifier::numeric(1).unwrap(),
+            quotas: NamespaceQuotas {
+                max_streams: 3,
+                max_users: 0,
+                max_size: IggyByteSize::from(4096),
+            },
+        };
+
+        let deserialized = UpdateNamespace::from_bytes(command.to_bytes()).unwrap();
+        assert_eq!(deserialized, command);
+    }
+}
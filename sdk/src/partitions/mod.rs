@@ -18,5 +18,8 @@
 
 pub mod create_partitions;
 pub mod delete_partitions;
+pub mod flush_partition;
+pub mod read_segment_range;
+pub mod restore_archived_segments;
 
 const MAX_PARTITIONS_COUNT: u32 = 1000;
@@ -0,0 +1,236 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, READ_SEGMENT_RANGE_CODE};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::utils::sizeable::Sizeable;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// The default maximum number of bytes returned by the `ReadSegmentRange` command.
+pub const DEFAULT_SEGMENT_RANGE_MAX_BYTES: u32 = 4 * 1024 * 1024;
+/// The maximum number of bytes which can be requested by the `ReadSegmentRange` command.
+pub const MAX_SEGMENT_RANGE_MAX_BYTES: u32 = 64 * 1024 * 1024;
+
+/// `ReadSegmentRange` command is used to read the raw bytes of the persisted message batches from a segment log file,
+/// so that the history of the partition can be processed in bulk (and in parallel, by splitting the segment into position ranges)
+/// without going through the regular polling of the messages.
+/// The returned range is always aligned to the batch boundaries: it starts at the first batch located at or after the `start_position`
+/// and contains the batches starting before the `end_position`, as long as they fit within `max_bytes` (at least one batch is always returned).
+/// It has additional payload:
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `partition_id` - partition ID.
+/// - `offset` - any offset stored in the segment, which is used to find the segment to read from.
+/// - `start_position` - the position in the segment log file to start reading from.
+/// - `end_position` - the position in the segment log file to stop reading at, if not specified, the segment is read to its end.
+/// - `max_bytes` - the maximum number of bytes to return.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct ReadSegmentRange {
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    /// Partition ID.
+    #[serde(skip)]
+    pub partition_id: u32,
+    /// Any offset stored in the segment, which is used to find the segment to read from.
+    #[serde(default)]
+    pub offset: u64,
+    /// The position in the segment log file to start reading from.
+    #[serde(default)]
+    pub start_position: u64,
+    /// The position in the segment log file to stop reading at, if not specified, the segment is read to its end.
+    #[serde(default)]
+    pub end_position: Option<u64>,
+    /// The maximum number of bytes to return.
+    #[serde(default = "default_max_bytes")]
+    pub max_bytes: u32,
+}
+
+fn default_max_bytes() -> u32 {
+    DEFAULT_SEGMENT_RANGE_MAX_BYTES
+}
+
+impl Default for ReadSegmentRange {
+    fn default() -> Self {
+        ReadSegmentRange {
+            stream_id: Identifier::default(),
+            topic_id: Identifier::default(),
+            partition_id: 1,
+            offset: 0,
+            start_position: 0,
+            end_position: None,
+            max_bytes: DEFAULT_SEGMENT_RANGE_MAX_BYTES,
+        }
+    }
+}
+
+impl Command for ReadSegmentRange {
+    fn code(&self) -> u32 {
+        READ_SEGMENT_RANGE_CODE
+    }
+}
+
+impl Validatable<IggyError> for ReadSegmentRange {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.max_bytes == 0 || self.max_bytes > MAX_SEGMENT_RANGE_MAX_BYTES {
+            return Err(IggyError::InvalidSegmentRangeSize(self.max_bytes));
+        }
+
+        if let Some(end_position) = self.end_position {
+            if end_position <= self.start_position {
+                return Err(IggyError::InvalidSegmentRange(
+                    self.start_position,
+                    end_position,
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for ReadSegmentRange {
+    fn to_bytes(&self) -> Bytes {
+        let stream_id_bytes = self.stream_id.to_bytes();
+        let topic_id_bytes = self.topic_id.to_bytes();
+        let mut bytes = BytesMut::with_capacity(32 + stream_id_bytes.len() + topic_id_bytes.len());
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        bytes.put_u32_le(self.partition_id);
+        bytes.put_u64_le(self.offset);
+        bytes.put_u64_le(self.start_position);
+        bytes.put_u64_le(self.end_position.unwrap_or(0));
+        bytes.put_u32_le(self.max_bytes);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> std::result::Result<ReadSegmentRange, IggyError> {
+        if bytes.len() < 38 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone())?;
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        if bytes.len() != position + 32 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let partition_id = u32::from_le_bytes(
+            bytes[position..position + 4]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let offset = u64::from_le_bytes(
+            bytes[position + 4..position + 12]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let start_position = u64::from_le_bytes(
+            bytes[position + 12..position + 20]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let end_position = u64::from_le_bytes(
+            bytes[position + 20..position + 28]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let end_position = match end_position {
+            0 => None,
+            end_position => Some(end_position),
+        };
+        let max_bytes = u32::from_le_bytes(
+            bytes[position + 28..position + 32]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let command = ReadSegmentRange {
+            stream_id,
+            topic_id,
+            partition_id,
+            offset,
+            start_position,
+            end_position,
+            max_bytes,
+        };
+        Ok(command)
+    }
+}
+
+impl Display for ReadSegmentRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}|{}|{}|{}",
+            self.stream_id,
+            self.topic_id,
+            self.partition_id,
+            self.offset,
+            self.start_position,
+            self.end_position.unwrap_or(0),
+            self.max_bytes
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_and_deserialized_from_bytes() {
+        let command = ReadSegmentRange {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::named("orders").unwrap(),
+            partition_id: 3,
+            offset: 1000,
+            start_position: 4096,
+            end_position: Some(8192),
+            max_bytes: 1024,
+        };
+
+        let bytes = command.to_bytes();
+        let deserialized = ReadSegmentRange::from_bytes(bytes).unwrap();
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_not_be_valid_given_end_position_not_after_start_position() {
+        let command = ReadSegmentRange {
+            start_position: 4096,
+            end_position: Some(4096),
+            ..ReadSegmentRange::default()
+        };
+
+        assert!(matches!(
+            command.validate(),
+            Err(IggyError::InvalidSegmentRange(4096, 4096))
+        ));
+    }
+}
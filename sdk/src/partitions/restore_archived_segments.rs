@@ -0,0 +1,165 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, RESTORE_ARCHIVED_SEGMENTS_CODE};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::utils::sizeable::Sizeable;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `RestoreArchivedSegments` command is used to restore the archived segments of a partition back into the local storage.
+/// It has additional payload:
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `partition_id` - partition ID.
+/// - `start_offset` - the first offset of the range to restore.
+/// - `end_offset` - the last offset of the range to restore, inclusive.
+///
+/// All the archived segments overlapping the range, which are no longer available locally, are restored.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct RestoreArchivedSegments {
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    /// Partition ID.
+    #[serde(skip)]
+    pub partition_id: u32,
+    /// The first offset of the range to restore.
+    pub start_offset: u64,
+    /// The last offset of the range to restore, inclusive.
+    pub end_offset: u64,
+}
+
+impl Command for RestoreArchivedSegments {
+    fn code(&self) -> u32 {
+        RESTORE_ARCHIVED_SEGMENTS_CODE
+    }
+}
+
+impl Validatable<IggyError> for RestoreArchivedSegments {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.start_offset > self.end_offset {
+            return Err(IggyError::InvalidRestoreRange);
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for RestoreArchivedSegments {
+    fn to_bytes(&self) -> Bytes {
+        let stream_id_bytes = self.stream_id.to_bytes();
+        let topic_id_bytes = self.topic_id.to_bytes();
+        let mut bytes = BytesMut::with_capacity(20 + stream_id_bytes.len() + topic_id_bytes.len());
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        bytes.put_u32_le(self.partition_id);
+        bytes.put_u64_le(self.start_offset);
+        bytes.put_u64_le(self.end_offset);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> std::result::Result<RestoreArchivedSegments, IggyError> {
+        if bytes.len() < 26 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone())?;
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        if bytes.len() != position + 20 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let partition_id = u32::from_le_bytes(
+            bytes[position..position + 4]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let start_offset = u64::from_le_bytes(
+            bytes[position + 4..position + 12]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let end_offset = u64::from_le_bytes(
+            bytes[position + 12..position + 20]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let command = RestoreArchivedSegments {
+            stream_id,
+            topic_id,
+            partition_id,
+            start_offset,
+            end_offset,
+        };
+        Ok(command)
+    }
+}
+
+impl Display for RestoreArchivedSegments {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}|{}",
+            self.stream_id, self.topic_id, self.partition_id, self.start_offset, self.end_offset
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_and_deserialized_from_bytes() {
+        let command = RestoreArchivedSegments {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::named("orders").unwrap(),
+            partition_id: 3,
+            start_offset: 100,
+            end_offset: 250,
+        };
+
+        let bytes = command.to_bytes();
+        let deserialized = RestoreArchivedSegments::from_bytes(bytes).unwrap();
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_not_be_valid_given_end_offset_lower_than_start_offset() {
+        let command = RestoreArchivedSegments {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            partition_id: 1,
+            start_offset: 10,
+            end_offset: 9,
+        };
+
+        assert!(command.validate().is_err());
+    }
+}
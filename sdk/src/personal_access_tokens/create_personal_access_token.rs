@@ -19,6 +19,7 @@
 use crate::bytes_serializable::BytesSerializable;
 use crate::command::{Command, CREATE_PERSONAL_ACCESS_TOKEN_CODE};
 use crate::error::IggyError;
+use crate::models::personal_access_token::PersonalAccessTokenScope;
 use crate::users::defaults::*;
 use crate::utils::expiry::IggyExpiry;
 use crate::validatable::Validatable;
@@ -31,12 +32,16 @@ use std::str::from_utf8;
 /// It has additional payload:
 /// - `name` - unique name of the token, must be between 3 and 30 characters long.
 /// - `expiry` - expiry of the token.
+/// - `scope` - optional restrictions (read-only, streams and topics, allowed source IP ranges) of the token.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct CreatePersonalAccessToken {
     /// Unique name of the token, must be between 3 and 30 characters long.
     pub name: String,
     /// Expiry of the token.
     pub expiry: IggyExpiry,
+    /// Optional restrictions of the token, by default the token has the same permissions as its owner.
+    #[serde(default)]
+    pub scope: PersonalAccessTokenScope,
 }
 
 impl Command for CreatePersonalAccessToken {
@@ -50,6 +55,7 @@ impl Default for CreatePersonalAccessToken {
         CreatePersonalAccessToken {
             name: "token".to_string(),
             expiry: IggyExpiry::NeverExpire,
+            scope: PersonalAccessTokenScope::default(),
         }
     }
 }
@@ -63,17 +69,33 @@ impl Validatable<IggyError> for CreatePersonalAccessToken {
             return Err(IggyError::InvalidPersonalAccessTokenName);
         }
 
+        if self.scope.resources.len() > MAX_PERSONAL_ACCESS_TOKEN_SCOPE_RESOURCES
+            || self.scope.allowed_ip_ranges.len() > MAX_PERSONAL_ACCESS_TOKEN_ALLOWED_IP_RANGES
+        {
+            return Err(IggyError::InvalidPersonalAccessTokenScope);
+        }
+
+        if self
+            .scope
+            .resources
+            .iter()
+            .any(|resource| resource.stream_id == 0 || resource.topic_id == Some(0))
+        {
+            return Err(IggyError::InvalidPersonalAccessTokenScope);
+        }
+
         Ok(())
     }
 }
 
 impl BytesSerializable for CreatePersonalAccessToken {
     fn to_bytes(&self) -> Bytes {
-        let mut bytes = BytesMut::with_capacity(5 + self.name.len());
+        let mut bytes = BytesMut::with_capacity(9 + self.name.len() + self.scope.get_size_bytes());
         #[allow(clippy::cast_possible_truncation)]
         bytes.put_u8(self.name.len() as u8);
         bytes.put_slice(self.name.as_bytes());
         bytes.put_u64_le(self.expiry.into());
+        self.scope.write_to_buffer(&mut bytes);
         bytes.freeze()
     }
 
@@ -97,27 +119,40 @@ impl BytesSerializable for CreatePersonalAccessToken {
                 .map_err(|_| IggyError::InvalidNumberEncoding)?,
         );
         let expiry: IggyExpiry = expiry.into();
+        // The scope is optional, so that the commands stored before it was introduced can still be read.
+        let position = position + 8;
+        let scope = if bytes.len() > position {
+            PersonalAccessTokenScope::from_bytes_at(&bytes, position)?.0
+        } else {
+            PersonalAccessTokenScope::default()
+        };
 
-        let command = CreatePersonalAccessToken { name, expiry };
+        let command = CreatePersonalAccessToken {
+            name,
+            expiry,
+            scope,
+        };
         Ok(command)
     }
 }
 
 impl Display for CreatePersonalAccessToken {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}|{}", self.name, self.expiry)
+        write!(f, "{}|{}|{}", self.name, self.expiry, self.scope)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::personal_access_token::PersonalAccessTokenResource;
 
     #[test]
     fn should_be_serialized_as_bytes() {
         let command = CreatePersonalAccessToken {
             name: "test".to_string(),
             expiry: IggyExpiry::NeverExpire,
+            scope: PersonalAccessTokenScope::default(),
         };
 
         let bytes = command.to_bytes();
@@ -150,5 +185,25 @@ mod tests {
         let command = command.unwrap();
         assert_eq!(command.name, name);
         assert_eq!(command.expiry, expiry);
+        assert_eq!(command.scope, PersonalAccessTokenScope::default());
+    }
+
+    #[test]
+    fn should_be_serialized_and_deserialized_with_scope() {
+        let command = CreatePersonalAccessToken {
+            name: "test".to_string(),
+            expiry: IggyExpiry::NeverExpire,
+            scope: PersonalAccessTokenScope {
+                read_only: true,
+                resources: vec![PersonalAccessTokenResource {
+                    stream_id: 1,
+                    topic_id: Some(2),
+                }],
+                allowed_ip_ranges: vec!["192.168.0.0/16".parse().unwrap()],
+            },
+        };
+
+        let deserialized = CreatePersonalAccessToken::from_bytes(command.to_bytes()).unwrap();
+        assert_eq!(deserialized, command);
     }
 }
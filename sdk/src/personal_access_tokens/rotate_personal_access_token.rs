@@ -0,0 +1,140 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, ROTATE_PERSONAL_ACCESS_TOKEN_CODE};
+use crate::error::IggyError;
+use crate::users::defaults::*;
+use crate::utils::expiry::IggyExpiry;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::str::from_utf8;
+
+/// `RotatePersonalAccessToken` command is used to atomically replace the secret of the existing personal access token
+/// of the authenticated user with a new one, keeping its name and scope. The previous secret stops working immediately.
+/// It has additional payload:
+/// - `name` - unique name of the token, must be between 3 and 30 characters long.
+/// - `expiry` - expiry of the new secret.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct RotatePersonalAccessToken {
+    /// Unique name of the token, must be between 3 and 30 characters long.
+    #[serde(skip)]
+    pub name: String,
+    /// Expiry of the new secret.
+    pub expiry: IggyExpiry,
+}
+
+impl Command for RotatePersonalAccessToken {
+    fn code(&self) -> u32 {
+        ROTATE_PERSONAL_ACCESS_TOKEN_CODE
+    }
+}
+
+impl Default for RotatePersonalAccessToken {
+    fn default() -> Self {
+        RotatePersonalAccessToken {
+            name: "token".to_string(),
+            expiry: IggyExpiry::NeverExpire,
+        }
+    }
+}
+
+impl Validatable<IggyError> for RotatePersonalAccessToken {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.name.is_empty()
+            || self.name.len() > MAX_PERSONAL_ACCESS_TOKEN_NAME_LENGTH
+            || self.name.len() < MIN_PERSONAL_ACCESS_TOKEN_NAME_LENGTH
+        {
+            return Err(IggyError::InvalidPersonalAccessTokenName);
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for RotatePersonalAccessToken {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(9 + self.name.len());
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(self.name.len() as u8);
+        bytes.put_slice(self.name.as_bytes());
+        bytes.put_u64_le(self.expiry.into());
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<RotatePersonalAccessToken, IggyError> {
+        if bytes.len() < 12 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let name_length = bytes[0] as usize;
+        if bytes.len() != 1 + name_length + 8 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let name = from_utf8(&bytes[1..1 + name_length])
+            .map_err(|_| IggyError::InvalidUtf8)?
+            .to_string();
+        let expiry = u64::from_le_bytes(
+            bytes[1 + name_length..9 + name_length]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let command = RotatePersonalAccessToken {
+            name,
+            expiry: expiry.into(),
+        };
+        Ok(command)
+    }
+}
+
+impl Display for RotatePersonalAccessToken {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}|{}", self.name, self.expiry)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::duration::IggyDuration;
+
+    #[test]
+    fn should_be_serialized_and_deserialized_from_bytes() {
+        let command = RotatePersonalAccessToken {
+            name: "test".to_string(),
+            expiry: IggyExpiry::ExpireDuration(IggyDuration::from(3_600_000_000)),
+        };
+
+        let bytes = command.to_bytes();
+        let deserialized = RotatePersonalAccessToken::from_bytes(bytes).unwrap();
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_given_invalid_name_length() {
+        let mut bytes = BytesMut::new();
+        bytes.put_u8(10);
+        bytes.put_slice("test".as_bytes());
+        bytes.put_u64_le(0);
+
+        assert!(RotatePersonalAccessToken::from_bytes(bytes.freeze()).is_err());
+    }
+}
@@ -17,12 +17,14 @@
  */
 
 use crate::binary::binary_client::BinaryClient;
-use crate::binary::{BinaryTransport, ClientState};
+use crate::binary::{service, BinaryTransport, ClientState};
+use crate::bytes_serializable::BytesSerializable;
 use crate::client::{AutoLogin, Client, Credentials, PersonalAccessTokenClient, UserClient};
 use crate::command::Command;
 use crate::diagnostic::DiagnosticEvent;
 use crate::error::IggyError;
 use crate::quic::config::QuicClientConfig;
+use crate::system::handshake::Handshake;
 use crate::utils::duration::IggyDuration;
 use crate::utils::timestamp::IggyTimestamp;
 use async_broadcast::{broadcast, Receiver, Sender};
@@ -35,6 +37,7 @@ use rustls::crypto::CryptoProvider;
 use rustls::pki_types::{CertificateDer, ServerName, UnixTime};
 use rustls::{DigitallySignedStruct, Error, SignatureScheme};
 use std::net::SocketAddr;
+use std::sync::atomic::{AtomicU32, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::Mutex;
@@ -55,6 +58,7 @@ pub struct QuicClient {
     pub(crate) state: Mutex<ClientState>,
     events: (Sender<DiagnosticEvent>, Receiver<DiagnosticEvent>),
     connected_at: Mutex<Option<IggyTimestamp>>,
+    protocol_features: AtomicU32,
 }
 
 unsafe impl Send for QuicClient {}
@@ -137,10 +141,53 @@ impl BinaryTransport for QuicClient {
     fn get_heartbeat_interval(&self) -> IggyDuration {
         self.config.heartbeat_interval
     }
+
+    fn get_protocol_features(&self) -> Handshake {
+        Handshake::from_flags(self.protocol_features.load(Ordering::SeqCst))
+    }
 }
 
 impl BinaryClient for QuicClient {}
 
+#[async_trait]
+impl service::Connection for QuicClient {
+    async fn connect(&self) -> Result<(), IggyError> {
+        QuicClient::connect(self).await
+    }
+
+    async fn disconnect(&self) -> Result<(), IggyError> {
+        QuicClient::disconnect(self).await
+    }
+
+    async fn shutdown(&self) -> Result<(), IggyError> {
+        QuicClient::shutdown(self).await
+    }
+
+    async fn get_state(&self) -> ClientState {
+        BinaryTransport::get_state(self).await
+    }
+
+    async fn set_state(&self, state: ClientState) {
+        BinaryTransport::set_state(self, state).await
+    }
+
+    async fn publish_event(&self, event: DiagnosticEvent) {
+        BinaryTransport::publish_event(self, event).await
+    }
+
+    async fn subscribe_events(&self) -> Receiver<DiagnosticEvent> {
+        self.events.1.clone()
+    }
+
+    fn get_heartbeat_interval(&self) -> IggyDuration {
+        BinaryTransport::get_heartbeat_interval(self)
+    }
+
+    fn get_protocol_features(&self) -> Handshake {
+        BinaryTransport::get_protocol_features(self)
+    }
+}
+
 impl QuicClient {
     /// Creates a new QUIC client for the provided client and server addresses.
     pub fn new(
@@ -200,9 +247,52 @@ impl QuicClient {
             state: Mutex::new(ClientState::Disconnected),
             events: broadcast(1000),
             connected_at: Mutex::new(None),
+            protocol_features: AtomicU32::new(0),
         })
     }
 
+    /// Negotiates the optional features of the binary protocol with the server, falling back to the initial version
+    /// of the protocol if the server doesn't support them, so that the client can still connect to the older servers.
+    async fn negotiate_protocol_features(&self) {
+        self.protocol_features.store(0, Ordering::SeqCst);
+        let handshake = Handshake {
+            resource_metadata: true,
+            remaining_messages: true,
+            message_id_scheme: true,
+            consumer_group_rebalances: true,
+            stream_quota: true,
+            cleanup_policy: true,
+            message_gaps: true,
+            consumer_group_assignment: true,
+            allowed_producers: true,
+            offset_recovery: true,
+            dead_letter: true,
+            visibility_timeout: true,
+            throttle_time: true,
+            topic_deletion_time: true,
+            max_segments: true,
+            partition_recovery: true,
+            topic_config: true,
+            message_sizes: true,
+            token_scope: true,
+            token_last_used: true,
+            ..Default::default()
+        };
+        match self
+            .send_raw(handshake.code(), handshake.to_bytes())
+            .await
+            .and_then(Handshake::from_bytes)
+        {
+            Ok(accepted) => {
+                self.protocol_features
+                    .store(accepted.as_flags(), Ordering::SeqCst);
+            }
+            Err(error) => {
+                warn!("{NAME} client cannot negotiate the binary protocol features, the initial version of the protocol will be used. {error}");
+            }
+        }
+    }
+
     async fn handle_response(&self, recv: &mut RecvStream) -> Result<Bytes, IggyError> {
         let buffer = recv
             .read_to_end(self.config.response_buffer_size as usize)
@@ -341,6 +431,7 @@ impl QuicClient {
         self.connection.lock().await.replace(connection);
         self.connected_at.lock().await.replace(now);
         self.publish_event(DiagnosticEvent::Connected).await;
+        self.negotiate_protocol_features().await;
 
         match &self.config.auto_login {
             AutoLogin::Disabled => {
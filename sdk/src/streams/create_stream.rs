@@ -19,6 +19,7 @@
 use crate::bytes_serializable::BytesSerializable;
 use crate::command::{Command, CREATE_STREAM_CODE};
 use crate::error::IggyError;
+use crate::models::metadata::ResourceMetadata;
 use crate::streams::MAX_NAME_LENGTH;
 use crate::validatable::Validatable;
 use bytes::{BufMut, Bytes, BytesMut};
@@ -30,12 +31,20 @@ use std::str::from_utf8;
 /// It has additional payload:
 /// - `stream_id` - unique stream ID (numeric)
 /// - `name` - unique stream name (string), max length is 255 characters.
+/// - `metadata` - optional description, owner and labels of the stream.
+/// - `namespace_id` - optional namespace of the stream, the streams created by the namespaced users always belong to the same namespace.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct CreateStream {
     /// Unique stream ID (numeric), if None is provided then the server will automatically assign it.
     pub stream_id: Option<u32>,
     /// Unique stream name (string), max length is 255 characters.
     pub name: String,
+    /// Optional description, owner and labels of the stream.
+    #[serde(default)]
+    pub metadata: ResourceMetadata,
+    /// Optional namespace of the stream, if not provided the stream belongs to the namespace of its creator (if any).
+    #[serde(default)]
+    pub namespace_id: Option<u32>,
 }
 
 impl Command for CreateStream {
@@ -49,10 +58,19 @@ impl Default for CreateStream {
         CreateStream {
             stream_id: Some(1),
             name: "stream".to_string(),
+            metadata: ResourceMetadata::default(),
+            namespace_id: None,
         }
     }
 }
 
+/// The optional settings of the stream created with `StreamClient::create_stream_with_options`.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CreateStreamOptions {
+    /// Description, owner and labels of the stream.
+    pub metadata: ResourceMetadata,
+}
+
 impl Validatable<IggyError> for CreateStream {
     fn validate(&self) -> Result<(), IggyError> {
         if let Some(stream_id) = self.stream_id {
@@ -65,17 +83,28 @@ impl Validatable<IggyError> for CreateStream {
             return Err(IggyError::InvalidStreamName);
         }
 
-        Ok(())
+        if self.namespace_id == Some(0) {
+            return Err(IggyError::InvalidNamespaceId);
+        }
+
+        self.metadata.validate()
     }
 }
 
 impl BytesSerializable for CreateStream {
     fn to_bytes(&self) -> Bytes {
-        let mut bytes = BytesMut::with_capacity(5 + self.name.len());
+        let mut bytes =
+            BytesMut::with_capacity(9 + self.name.len() + self.metadata.get_size_bytes());
         bytes.put_u32_le(self.stream_id.unwrap_or(0));
         #[allow(clippy::cast_possible_truncation)]
         bytes.put_u8(self.name.len() as u8);
         bytes.put_slice(self.name.as_bytes());
+        if !self.metadata.is_empty() || self.namespace_id.is_some() {
+            self.metadata.write_to_buffer(&mut bytes);
+        }
+        if let Some(namespace_id) = self.namespace_id {
+            bytes.put_u32_le(namespace_id);
+        }
         bytes.freeze()
     }
 
@@ -102,14 +131,49 @@ impl BytesSerializable for CreateStream {
             return Err(IggyError::InvalidCommand);
         }
 
-        let command = CreateStream { stream_id, name };
+        let mut position = 5 + name_length as usize;
+        let metadata = if bytes.len() > position {
+            let (metadata, read_bytes) = ResourceMetadata::from_bytes_at(&bytes, position)?;
+            position += read_bytes;
+            metadata
+        } else {
+            ResourceMetadata::default()
+        };
+        let namespace_id = if bytes.len() > position {
+            let namespace_id = u32::from_le_bytes(
+                bytes
+                    .get(position..position + 4)
+                    .ok_or(IggyError::InvalidCommand)?
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            );
+            Some(namespace_id).filter(|namespace_id| *namespace_id > 0)
+        } else {
+            None
+        };
+        let command = CreateStream {
+            stream_id,
+            name,
+            metadata,
+            namespace_id,
+        };
         Ok(command)
     }
 }
 
 impl Display for CreateStream {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}|{}", self.stream_id.unwrap_or(0), self.name)
+        write!(
+            f,
+            "{}|{}|{}",
+            self.stream_id.unwrap_or(0),
+            self.name,
+            self.metadata
+        )?;
+        if let Some(namespace_id) = self.namespace_id {
+            write!(f, "|{namespace_id}")?;
+        }
+        Ok(())
     }
 }
 
@@ -122,6 +186,8 @@ mod tests {
         let command = CreateStream {
             stream_id: Some(1),
             name: "test".to_string(),
+            metadata: ResourceMetadata::default(),
+            namespace_id: None,
         };
 
         let bytes = command.to_bytes();
@@ -149,5 +215,34 @@ mod tests {
         let command = command.unwrap();
         assert_eq!(command.stream_id.unwrap(), stream_id);
         assert_eq!(command.name, name);
+        assert!(command.metadata.is_empty());
+    }
+
+    #[test]
+    fn should_be_serialized_and_deserialized_with_metadata() {
+        let command = CreateStream {
+            stream_id: Some(1),
+            name: "test".to_string(),
+            metadata: ResourceMetadata {
+                description: Some("description".to_string()),
+                owner: Some("team".to_string()),
+                labels: [("env".to_string(), "prod".to_string())].into(),
+            },
+            namespace_id: None,
+        };
+
+        let deserialized = CreateStream::from_bytes(command.to_bytes()).unwrap();
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_be_serialized_and_deserialized_with_namespace() {
+        let command = CreateStream {
+            namespace_id: Some(2),
+            ..CreateStream::default()
+        };
+
+        let deserialized = CreateStream::from_bytes(command.to_bytes()).unwrap();
+        assert_eq!(deserialized, command);
     }
 }
@@ -19,15 +19,21 @@
 use crate::bytes_serializable::BytesSerializable;
 use crate::command::{Command, GET_STREAMS_CODE};
 use crate::error::IggyError;
+use crate::models::metadata::MetadataFilter;
 use crate::validatable::Validatable;
-use bytes::Bytes;
+use bytes::{Bytes, BytesMut};
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 
 /// `GetStreams` command is used to retrieve the information about all streams.
-/// It has no additional payload.
+/// It has additional, optional payload:
+/// - `filter` - the metadata filter (owner, labels, description), empty filter matches all the streams.
 #[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
-pub struct GetStreams {}
+pub struct GetStreams {
+    /// The metadata filter, empty filter matches all the streams.
+    #[serde(default)]
+    pub filter: MetadataFilter,
+}
 
 impl Command for GetStreams {
     fn code(&self) -> u32 {
@@ -37,27 +43,38 @@ impl Command for GetStreams {
 
 impl Validatable<IggyError> for GetStreams {
     fn validate(&self) -> Result<(), IggyError> {
-        Ok(())
+        self.filter.validate()
     }
 }
 
 impl BytesSerializable for GetStreams {
     fn to_bytes(&self) -> Bytes {
-        Bytes::new()
+        if self.filter.is_empty() {
+            return Bytes::new();
+        }
+
+        let mut bytes = BytesMut::with_capacity(self.filter.get_size_bytes());
+        self.filter.write_to_buffer(&mut bytes);
+        bytes.freeze()
     }
 
     fn from_bytes(bytes: Bytes) -> std::result::Result<GetStreams, IggyError> {
-        if !bytes.is_empty() {
+        if bytes.is_empty() {
+            return Ok(GetStreams::default());
+        }
+
+        let (filter, read_bytes) = MetadataFilter::from_bytes_at(&bytes, 0)?;
+        if read_bytes != bytes.len() {
             return Err(IggyError::InvalidCommand);
         }
 
-        Ok(GetStreams {})
+        Ok(GetStreams { filter })
     }
 }
 
 impl Display for GetStreams {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "")
+        write!(f, "{}", self.filter)
     }
 }
 
@@ -67,7 +84,7 @@ mod tests {
 
     #[test]
     fn should_be_serialized_as_empty_bytes() {
-        let command = GetStreams {};
+        let command = GetStreams::default();
         let bytes = command.to_bytes();
         assert!(bytes.is_empty());
     }
@@ -79,8 +96,22 @@ mod tests {
     }
 
     #[test]
-    fn should_not_be_deserialized_from_empty_bytes() {
+    fn should_not_be_deserialized_from_invalid_bytes() {
         let command = GetStreams::from_bytes(Bytes::from_static(&[0]));
         assert!(command.is_err());
     }
+
+    #[test]
+    fn should_be_serialized_and_deserialized_with_filter() {
+        let command = GetStreams {
+            filter: MetadataFilter {
+                owner: Some("team".to_string()),
+                labels: [("env".to_string(), "prod".to_string())].into(),
+                description: None,
+            },
+        };
+
+        let deserialized = GetStreams::from_bytes(command.to_bytes()).unwrap();
+        assert_eq!(deserialized, command);
+    }
 }
@@ -22,5 +22,7 @@ pub mod get_stream;
 pub mod get_streams;
 pub mod purge_stream;
 pub mod update_stream;
+pub mod update_stream_metadata;
+pub mod update_stream_quota;
 
 const MAX_NAME_LENGTH: usize = 255;
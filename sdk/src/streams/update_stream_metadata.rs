@@ -0,0 +1,126 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, UPDATE_STREAM_METADATA_CODE};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::models::metadata::ResourceMetadata;
+use crate::utils::sizeable::Sizeable;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `UpdateStreamMetadata` command is used to replace the metadata of an existing stream.
+/// It has additional payload:
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `metadata` - description, owner and labels of the stream, empty metadata clears all the fields.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct UpdateStreamMetadata {
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Description, owner and labels of the stream.
+    #[serde(flatten)]
+    pub metadata: ResourceMetadata,
+}
+
+impl Command for UpdateStreamMetadata {
+    fn code(&self) -> u32 {
+        UPDATE_STREAM_METADATA_CODE
+    }
+}
+
+impl Validatable<IggyError> for UpdateStreamMetadata {
+    fn validate(&self) -> Result<(), IggyError> {
+        self.metadata.validate()
+    }
+}
+
+impl BytesSerializable for UpdateStreamMetadata {
+    fn to_bytes(&self) -> Bytes {
+        let stream_id_bytes = self.stream_id.to_bytes();
+        let mut bytes =
+            BytesMut::with_capacity(stream_id_bytes.len() + self.metadata.get_size_bytes());
+        bytes.put_slice(&stream_id_bytes);
+        self.metadata.write_to_buffer(&mut bytes);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> std::result::Result<UpdateStreamMetadata, IggyError> {
+        if bytes.len() < 7 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let stream_id = Identifier::from_bytes(bytes.clone())?;
+        let position = stream_id.get_size_bytes().as_bytes_usize();
+        let (metadata, read_bytes) = ResourceMetadata::from_bytes_at(&bytes, position)?;
+        if position + read_bytes != bytes.len() {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let command = UpdateStreamMetadata {
+            stream_id,
+            metadata,
+        };
+        Ok(command)
+    }
+}
+
+impl Display for UpdateStreamMetadata {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}|{}", self.stream_id, self.metadata)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_and_deserialized_from_bytes() {
+        let command = UpdateStreamMetadata {
+            stream_id: Identifier::numeric(1).unwrap(),
+            metadata: ResourceMetadata {
+                description: Some("description".to_string()),
+                owner: Some("team".to_string()),
+                labels: [("env".to_string(), "prod".to_string())].into(),
+            },
+        };
+
+        let bytes = command.to_bytes();
+        let deserialized = UpdateStreamMetadata::from_bytes(bytes).unwrap();
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_truncated_bytes() {
+        let command = UpdateStreamMetadata {
+            stream_id: Identifier::numeric(1).unwrap(),
+            metadata: ResourceMetadata {
+                owner: Some("team".to_string()),
+                ..Default::default()
+            },
+        };
+
+        let bytes = command.to_bytes();
+        let command = UpdateStreamMetadata::from_bytes(bytes.slice(..bytes.len() - 1));
+        assert!(command.is_err());
+    }
+}
@@ -0,0 +1,169 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, UPDATE_STREAM_QUOTA_CODE};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::models::stream::StreamQuota;
+use crate::utils::byte_size::IggyByteSize;
+use crate::utils::sizeable::Sizeable;
+use crate::validatable::Validatable;
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `UpdateStreamQuota` command is used to replace the storage quota of an existing stream.
+/// It has additional payload:
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `quota` - soft and hard storage limits of the stream, 0 disables the particular limit.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct UpdateStreamQuota {
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Soft and hard storage limits of the stream.
+    #[serde(flatten)]
+    pub quota: StreamQuota,
+}
+
+impl Command for UpdateStreamQuota {
+    fn code(&self) -> u32 {
+        UPDATE_STREAM_QUOTA_CODE
+    }
+}
+
+impl Validatable<IggyError> for UpdateStreamQuota {
+    fn validate(&self) -> Result<(), IggyError> {
+        let soft_limit = self.quota.soft_limit.as_bytes_u64();
+        let hard_limit = self.quota.hard_limit.as_bytes_u64();
+        if soft_limit > 0 && hard_limit > 0 && soft_limit > hard_limit {
+            return Err(IggyError::InvalidStreamQuota(
+                self.quota.soft_limit,
+                self.quota.hard_limit,
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for UpdateStreamQuota {
+    fn to_bytes(&self) -> Bytes {
+        let stream_id_bytes = self.stream_id.to_bytes();
+        let mut bytes = BytesMut::with_capacity(stream_id_bytes.len() + 16);
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_u64_le(self.quota.soft_limit.as_bytes_u64());
+        bytes.put_u64_le(self.quota.hard_limit.as_bytes_u64());
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> std::result::Result<UpdateStreamQuota, IggyError> {
+        if bytes.len() < 19 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let stream_id = Identifier::from_bytes(bytes.clone())?;
+        let position = stream_id.get_size_bytes().as_bytes_usize();
+        if position + 16 != bytes.len() {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let soft_limit = bytes.slice(position..position + 8).get_u64_le();
+        let hard_limit = bytes.slice(position + 8..position + 16).get_u64_le();
+        let command = UpdateStreamQuota {
+            stream_id,
+            quota: StreamQuota {
+                soft_limit: IggyByteSize::from(soft_limit),
+                hard_limit: IggyByteSize::from(hard_limit),
+            },
+        };
+        Ok(command)
+    }
+}
+
+impl Display for UpdateStreamQuota {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}",
+            self.stream_id, self.quota.soft_limit, self.quota.hard_limit
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_and_deserialized_from_bytes() {
+        let command = UpdateStreamQuota {
+            stream_id: Identifier::numeric(1).unwrap(),
+            quota: StreamQuota {
+                soft_limit: IggyByteSize::from(1_000_u64),
+                hard_limit: IggyByteSize::from(2_000_u64),
+            },
+        };
+
+        let bytes = command.to_bytes();
+        let deserialized = UpdateStreamQuota::from_bytes(bytes).unwrap();
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_truncated_bytes() {
+        let command = UpdateStreamQuota {
+            stream_id: Identifier::numeric(1).unwrap(),
+            ..Default::default()
+        };
+
+        let bytes = command.to_bytes();
+        let command = UpdateStreamQuota::from_bytes(bytes.slice(..bytes.len() - 1));
+        assert!(command.is_err());
+    }
+
+    #[test]
+    fn should_not_be_valid_given_soft_limit_greater_than_hard_limit() {
+        let command = UpdateStreamQuota {
+            stream_id: Identifier::numeric(1).unwrap(),
+            quota: StreamQuota {
+                soft_limit: IggyByteSize::from(2_000_u64),
+                hard_limit: IggyByteSize::from(1_000_u64),
+            },
+        };
+
+        assert!(matches!(
+            command.validate(),
+            Err(IggyError::InvalidStreamQuota(_, _))
+        ));
+    }
+
+    #[test]
+    fn should_be_valid_given_only_soft_limit() {
+        let command = UpdateStreamQuota {
+            stream_id: Identifier::numeric(1).unwrap(),
+            quota: StreamQuota {
+                soft_limit: IggyByteSize::from(2_000_u64),
+                ..Default::default()
+            },
+        };
+
+        assert!(command.validate().is_ok());
+    }
+}
@@ -0,0 +1,132 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, CREATE_BACKUP_CODE};
+use crate::error::IggyError;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::from_utf8;
+
+/// The maximum length of the backup name in bytes.
+pub const MAX_BACKUP_NAME_LENGTH: usize = 255;
+
+/// `CreateBackup` command is used to take a consistent backup of the server data while it keeps running.
+/// The buffered messages are saved on disk and the writes are paused only for the time of the cut,
+/// during which the closed segments are hard-linked and the state and offsets are copied,
+/// then the active segments are copied up to the cut, in the backup directory of the server.
+/// It has additional payload:
+/// - `name` - unique name of the backup, used as its directory name, max length is 255 characters.
+///   Only the alphanumeric characters, `-`, `_` and `.` are allowed.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct CreateBackup {
+    /// Unique name of the backup, used as its directory name.
+    pub name: String,
+}
+
+impl Command for CreateBackup {
+    fn code(&self) -> u32 {
+        CREATE_BACKUP_CODE
+    }
+}
+
+impl Default for CreateBackup {
+    fn default() -> Self {
+        CreateBackup {
+            name: "backup".to_string(),
+        }
+    }
+}
+
+impl Validatable<IggyError> for CreateBackup {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.name.is_empty()
+            || self.name.len() > MAX_BACKUP_NAME_LENGTH
+            || self.name.starts_with('.')
+            || !self
+                .name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.')
+        {
+            return Err(IggyError::InvalidBackupName);
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for CreateBackup {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(1 + self.name.len());
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(self.name.len() as u8);
+        bytes.put_slice(self.name.as_bytes());
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<CreateBackup, IggyError> {
+        if bytes.len() < 2 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let name_length = bytes[0] as usize;
+        if bytes.len() != 1 + name_length {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let name = from_utf8(&bytes[1..])
+            .map_err(|_| IggyError::InvalidUtf8)?
+            .to_string();
+        let command = CreateBackup { name };
+        Ok(command)
+    }
+}
+
+impl Display for CreateBackup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_and_deserialized() {
+        let command = CreateBackup {
+            name: "backup-2024.01.01".to_string(),
+        };
+
+        let bytes = command.to_bytes();
+        let deserialized = CreateBackup::from_bytes(bytes).unwrap();
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_not_be_valid_given_path_in_name() {
+        for name in ["", "..", "../data", "nested/backup", ".hidden"] {
+            let command = CreateBackup {
+                name: name.to_string(),
+            };
+            assert!(command.validate().is_err());
+        }
+    }
+}
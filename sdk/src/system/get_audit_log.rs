@@ -0,0 +1,174 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, GET_AUDIT_LOG_CODE};
+use crate::error::IggyError;
+use crate::models::audit_entry::AuditAction;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+const DEFAULT_COUNT: u32 = 100;
+
+/// `GetAuditLog` command is used to get the most recent entries of the server audit log, in chronological order.
+/// It has additional payload:
+/// - `count` - the maximum number of the entries to return.
+/// - `user_id` - optional ID of the user who performed the actions, all the users if not provided.
+/// - `action` - optional kind of the actions, all the actions if not provided.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct GetAuditLog {
+    /// The maximum number of the entries to return.
+    #[serde(default = "default_count")]
+    pub count: u32,
+    /// Optional ID of the user who performed the actions.
+    #[serde(default)]
+    pub user_id: Option<u32>,
+    /// Optional kind of the actions.
+    #[serde(default)]
+    pub action: Option<AuditAction>,
+}
+
+impl Command for GetAuditLog {
+    fn code(&self) -> u32 {
+        GET_AUDIT_LOG_CODE
+    }
+}
+
+impl Default for GetAuditLog {
+    fn default() -> Self {
+        GetAuditLog {
+            count: default_count(),
+            user_id: None,
+            action: None,
+        }
+    }
+}
+
+fn default_count() -> u32 {
+    DEFAULT_COUNT
+}
+
+impl Validatable<IggyError> for GetAuditLog {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.count == 0 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        if self.user_id == Some(0) {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for GetAuditLog {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(9);
+        bytes.put_u32_le(self.count);
+        bytes.put_u32_le(self.user_id.unwrap_or_default());
+        bytes.put_u8(
+            self.action
+                .map(|action| action.as_code())
+                .unwrap_or_default(),
+        );
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<GetAuditLog, IggyError> {
+        if bytes.len() != 9 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let count = u32::from_le_bytes(
+            bytes[0..4]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let user_id = u32::from_le_bytes(
+            bytes[4..8]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let user_id = if user_id > 0 { Some(user_id) } else { None };
+        let action = match bytes[8] {
+            0 => None,
+            code => Some(AuditAction::from_code(code)?),
+        };
+        let command = GetAuditLog {
+            count,
+            user_id,
+            action,
+        };
+        Ok(command)
+    }
+}
+
+impl Display for GetAuditLog {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}",
+            self.count,
+            self.user_id.unwrap_or_default(),
+            self.action
+                .map(|action| action.to_string())
+                .unwrap_or_default()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = GetAuditLog {
+            count: 10,
+            user_id: Some(2),
+            action: Some(AuditAction::CreateStream),
+        };
+
+        let bytes = command.to_bytes();
+        let count = u32::from_le_bytes(bytes[0..4].try_into().unwrap());
+        let user_id = u32::from_le_bytes(bytes[4..8].try_into().unwrap());
+        let action = bytes[8];
+
+        assert_eq!(count, command.count);
+        assert_eq!(user_id, 2);
+        assert_eq!(action, AuditAction::CreateStream.as_code());
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let mut bytes = BytesMut::with_capacity(9);
+        bytes.put_u32_le(10);
+        bytes.put_u32_le(0);
+        bytes.put_u8(0);
+        let command = GetAuditLog::from_bytes(bytes.freeze());
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.count, 10);
+        assert!(command.user_id.is_none());
+        assert!(command.action.is_none());
+    }
+}
@@ -0,0 +1,382 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, HANDSHAKE_CODE};
+use crate::error::IggyError;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+const RESOURCE_METADATA_FLAG: u32 = 1;
+const REMAINING_MESSAGES_FLAG: u32 = 2;
+const MESSAGE_ID_SCHEME_FLAG: u32 = 4;
+const CONSUMER_GROUP_REBALANCES_FLAG: u32 = 8;
+const STREAM_QUOTA_FLAG: u32 = 16;
+const CLEANUP_POLICY_FLAG: u32 = 32;
+const MESSAGE_GAPS_FLAG: u32 = 64;
+const CONSUMER_GROUP_ASSIGNMENT_FLAG: u32 = 128;
+const ALLOWED_PRODUCERS_FLAG: u32 = 256;
+const OFFSET_RECOVERY_FLAG: u32 = 512;
+const DEAD_LETTER_FLAG: u32 = 1024;
+const FRAME_CHECKSUMS_FLAG: u32 = 2048;
+const VISIBILITY_TIMEOUT_FLAG: u32 = 4096;
+const THROTTLE_TIME_FLAG: u32 = 8192;
+const TOPIC_DELETION_TIME_FLAG: u32 = 16384;
+const MAX_SEGMENTS_FLAG: u32 = 32768;
+const PARTITION_RECOVERY_FLAG: u32 = 65536;
+const TOPIC_CONFIG_FLAG: u32 = 131072;
+const MESSAGE_SIZES_FLAG: u32 = 262144;
+const TOKEN_SCOPE_FLAG: u32 = 524288;
+const TOKEN_LAST_USED_FLAG: u32 = 1048576;
+const ERROR_CATEGORIES_FLAG: u32 = 2097152;
+
+/// `Handshake` command is used to negotiate the optional features of the binary protocol right after connecting.
+/// The server responds with the same structure containing only the features it has accepted,
+/// and the accepted features apply to all the frames following the response.
+/// It has additional payload:
+/// - `resource_metadata` - whether the streams and topics should contain their metadata (description, owner and labels).
+/// - `remaining_messages` - whether the polled messages should contain the number of messages remaining in the partition.
+/// - `message_id_scheme` - whether the topics should contain their message ID scheme.
+/// - `consumer_group_rebalances` - whether the consumer groups should contain the history of their rebalances.
+/// - `stream_quota` - whether the streams should contain their soft and hard size quota.
+/// - `cleanup_policy` - whether the topics should contain their cleanup policy.
+/// - `message_gaps` - whether the polled messages should contain the gaps in the offsets, e.g. left by the compaction.
+/// - `consumer_group_assignment` - whether the consumer groups should contain their generation and partition assignment strategy.
+/// - `allowed_producers` - whether the topics should contain the IDs of the users allowed to produce to them.
+/// - `offset_recovery` - whether the consumer groups should contain their offset recovery policy.
+/// - `dead_letter` - whether the consumer groups should contain their dead letter topic.
+/// - `frame_checksums` - whether every request and response frame should be followed by its CRC32C checksum.
+/// - `visibility_timeout` - whether the consumer groups should contain the visibility timeout of their in-flight messages.
+/// - `throttle_time` - whether the polled messages should contain the time for which the client is throttled by the fetch quotas.
+/// - `topic_deletion_time` - whether the polled messages and the topics should contain the time at which the topic marked for deletion is going to be deleted.
+/// - `max_segments` - whether the topics should contain their maximum number of segments per partition.
+/// - `partition_recovery` - whether the partitions should contain the progress of their recovery.
+/// - `topic_config` - whether the topics should contain their configuration overrides.
+/// - `message_sizes` - whether the topic details should contain the distribution of the message sizes.
+/// - `token_scope` - whether the personal access tokens should contain their scope.
+/// - `token_last_used` - whether the personal access tokens should contain the time they were last used at.
+/// - `error_categories` - whether every error response should contain the category code of the error as its single byte payload.
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct Handshake {
+    /// Whether the streams and topics should contain their metadata (description, owner and labels).
+    pub resource_metadata: bool,
+    /// Whether the polled messages should contain the number of messages in the partition after the last polled one.
+    #[serde(default)]
+    pub remaining_messages: bool,
+    /// Whether the topics should contain the scheme used to assign the IDs of their messages.
+    #[serde(default)]
+    pub message_id_scheme: bool,
+    /// Whether the consumer groups should contain the history of their recent rebalances.
+    #[serde(default)]
+    pub consumer_group_rebalances: bool,
+    /// Whether the streams should contain their soft and hard limits of the size.
+    #[serde(default)]
+    pub stream_quota: bool,
+    /// Whether the topics should contain the policy used to clean up their old messages, i.e. the deletion or the compaction.
+    #[serde(default)]
+    pub cleanup_policy: bool,
+    /// Whether the polled messages should contain the ranges of the offsets missing due to the compaction, the expiry or the deletion.
+    #[serde(default)]
+    pub message_gaps: bool,
+    /// Whether the consumer groups should contain their generation and the strategy used to assign their partitions.
+    #[serde(default)]
+    pub consumer_group_assignment: bool,
+    /// Whether the topics should contain the IDs of the users allowed to send the messages to them, empty if not restricted.
+    #[serde(default)]
+    pub allowed_producers: bool,
+    /// Whether the consumer groups should contain the policy used to recover their offsets which are out of range.
+    #[serde(default)]
+    pub offset_recovery: bool,
+    /// Whether the consumer groups should contain the topic to which their undeliverable messages are moved.
+    #[serde(default)]
+    pub dead_letter: bool,
+    /// Whether every request and response frame should be followed by its CRC32C checksum.
+    #[serde(default)]
+    pub frame_checksums: bool,
+    /// Whether the consumer groups should contain the time after which their unacknowledged in-flight messages are redelivered.
+    #[serde(default)]
+    pub visibility_timeout: bool,
+    /// Whether the polled messages should contain the time in milliseconds for which the client should back off
+    /// due to exceeding the fetch quotas.
+    #[serde(default)]
+    pub throttle_time: bool,
+    /// Whether the polled messages and the topics should contain the timestamp in microseconds at which the topic
+    /// marked for deletion is going to be deleted, or zero if it isn't marked.
+    #[serde(default)]
+    pub topic_deletion_time: bool,
+    /// Whether the topics should contain the maximum number of the closed segments kept per partition, zero if not limited.
+    #[serde(default)]
+    pub max_segments: bool,
+    /// Whether the partitions should contain the progress of their recovery, if they are still being loaded.
+    #[serde(default)]
+    pub partition_recovery: bool,
+    /// Whether the topics should contain the overrides of the server configuration set for them.
+    #[serde(default)]
+    pub topic_config: bool,
+    /// Whether the topic details should contain the distribution of the sizes of the messages appended to the topic.
+    #[serde(default)]
+    pub message_sizes: bool,
+    /// Whether the personal access tokens should contain their scope (read-only access, resources and allowed IP ranges).
+    #[serde(default)]
+    pub token_scope: bool,
+    /// Whether the personal access tokens should contain the time at which they were last used to sign in.
+    #[serde(default)]
+    pub token_last_used: bool,
+    /// Whether every error response should contain the category code of the error as its payload.
+    #[serde(default)]
+    pub error_categories: bool,
+}
+
+impl Handshake {
+    /// Returns the bitmask of the features.
+    pub fn as_flags(&self) -> u32 {
+        let mut flags = 0;
+        if self.resource_metadata {
+            flags |= RESOURCE_METADATA_FLAG;
+        }
+        if self.remaining_messages {
+            flags |= REMAINING_MESSAGES_FLAG;
+        }
+        if self.message_id_scheme {
+            flags |= MESSAGE_ID_SCHEME_FLAG;
+        }
+        if self.consumer_group_rebalances {
+            flags |= CONSUMER_GROUP_REBALANCES_FLAG;
+        }
+        if self.stream_quota {
+            flags |= STREAM_QUOTA_FLAG;
+        }
+        if self.cleanup_policy {
+            flags |= CLEANUP_POLICY_FLAG;
+        }
+        if self.message_gaps {
+            flags |= MESSAGE_GAPS_FLAG;
+        }
+        if self.consumer_group_assignment {
+            flags |= CONSUMER_GROUP_ASSIGNMENT_FLAG;
+        }
+        if self.allowed_producers {
+            flags |= ALLOWED_PRODUCERS_FLAG;
+        }
+        if self.offset_recovery {
+            flags |= OFFSET_RECOVERY_FLAG;
+        }
+        if self.dead_letter {
+            flags |= DEAD_LETTER_FLAG;
+        }
+        if self.frame_checksums {
+            flags |= FRAME_CHECKSUMS_FLAG;
+        }
+        if self.visibility_timeout {
+            flags |= VISIBILITY_TIMEOUT_FLAG;
+        }
+        if self.throttle_time {
+            flags |= THROTTLE_TIME_FLAG;
+        }
+        if self.topic_deletion_time {
+            flags |= TOPIC_DELETION_TIME_FLAG;
+        }
+        if self.max_segments {
+            flags |= MAX_SEGMENTS_FLAG;
+        }
+        if self.partition_recovery {
+            flags |= PARTITION_RECOVERY_FLAG;
+        }
+        if self.topic_config {
+            flags |= TOPIC_CONFIG_FLAG;
+        }
+        if self.message_sizes {
+            flags |= MESSAGE_SIZES_FLAG;
+        }
+        if self.token_scope {
+            flags |= TOKEN_SCOPE_FLAG;
+        }
+        if self.token_last_used {
+            flags |= TOKEN_LAST_USED_FLAG;
+        }
+        if self.error_categories {
+            flags |= ERROR_CATEGORIES_FLAG;
+        }
+        flags
+    }
+
+    /// Creates the features from the bitmask, ignoring the unknown flags.
+    pub fn from_flags(flags: u32) -> Self {
+        Handshake {
+            resource_metadata: flags & RESOURCE_METADATA_FLAG != 0,
+            remaining_messages: flags & REMAINING_MESSAGES_FLAG != 0,
+            message_id_scheme: flags & MESSAGE_ID_SCHEME_FLAG != 0,
+            consumer_group_rebalances: flags & CONSUMER_GROUP_REBALANCES_FLAG != 0,
+            stream_quota: flags & STREAM_QUOTA_FLAG != 0,
+            cleanup_policy: flags & CLEANUP_POLICY_FLAG != 0,
+            message_gaps: flags & MESSAGE_GAPS_FLAG != 0,
+            consumer_group_assignment: flags & CONSUMER_GROUP_ASSIGNMENT_FLAG != 0,
+            allowed_producers: flags & ALLOWED_PRODUCERS_FLAG != 0,
+            offset_recovery: flags & OFFSET_RECOVERY_FLAG != 0,
+            dead_letter: flags & DEAD_LETTER_FLAG != 0,
+            frame_checksums: flags & FRAME_CHECKSUMS_FLAG != 0,
+            visibility_timeout: flags & VISIBILITY_TIMEOUT_FLAG != 0,
+            throttle_time: flags & THROTTLE_TIME_FLAG != 0,
+            topic_deletion_time: flags & TOPIC_DELETION_TIME_FLAG != 0,
+            max_segments: flags & MAX_SEGMENTS_FLAG != 0,
+            partition_recovery: flags & PARTITION_RECOVERY_FLAG != 0,
+            topic_config: flags & TOPIC_CONFIG_FLAG != 0,
+            message_sizes: flags & MESSAGE_SIZES_FLAG != 0,
+            token_scope: flags & TOKEN_SCOPE_FLAG != 0,
+            token_last_used: flags & TOKEN_LAST_USED_FLAG != 0,
+            error_categories: flags & ERROR_CATEGORIES_FLAG != 0,
+        }
+    }
+}
+
+impl Command for Handshake {
+    fn code(&self) -> u32 {
+        HANDSHAKE_CODE
+    }
+}
+
+impl Validatable<IggyError> for Handshake {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for Handshake {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(4);
+        bytes.put_u32_le(self.as_flags());
+        bytes.freeze()
+    }
+
+    /// The unknown flags are ignored, so that the newer clients can still negotiate with the older servers.
+    fn from_bytes(bytes: Bytes) -> Result<Handshake, IggyError> {
+        if bytes.len() != 4 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let flags = u32::from_le_bytes(
+            bytes[..4]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        Ok(Handshake::from_flags(flags))
+    }
+}
+
+impl Display for Handshake {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "resource_metadata: {}, remaining_messages: {}, message_id_scheme: {}, consumer_group_rebalances: {}, stream_quota: {}, cleanup_policy: {}, message_gaps: {}, consumer_group_assignment: {}, allowed_producers: {}, offset_recovery: {}, dead_letter: {}, frame_checksums: {}, visibility_timeout: {}, throttle_time: {}, topic_deletion_time: {}, max_segments: {}, partition_recovery: {}, topic_config: {}, message_sizes: {}, token_scope: {}, token_last_used: {}, error_categories: {}",
+            self.resource_metadata,
+            self.remaining_messages,
+            self.message_id_scheme,
+            self.consumer_group_rebalances,
+            self.stream_quota,
+            self.cleanup_policy,
+            self.message_gaps,
+            self.consumer_group_assignment,
+            self.allowed_producers,
+            self.offset_recovery,
+            self.dead_letter,
+            self.frame_checksums,
+            self.visibility_timeout,
+            self.throttle_time,
+            self.topic_deletion_time,
+            self.max_segments,
+            self.partition_recovery,
+            self.topic_config,
+            self.message_sizes,
+            self.token_scope,
+            self.token_last_used,
+            self.error_categories
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_and_deserialized_from_bytes() {
+        let command = Handshake {
+            resource_metadata: true,
+            remaining_messages: true,
+            message_id_scheme: true,
+            consumer_group_rebalances: true,
+            stream_quota: true,
+            cleanup_policy: true,
+            message_gaps: true,
+            consumer_group_assignment: true,
+            allowed_producers: true,
+            offset_recovery: true,
+            dead_letter: true,
+            frame_checksums: true,
+            visibility_timeout: true,
+            throttle_time: true,
+            topic_deletion_time: true,
+            max_segments: true,
+            partition_recovery: true,
+            topic_config: true,
+            message_sizes: true,
+            token_scope: true,
+            token_last_used: true,
+            error_categories: true,
+        };
+
+        let bytes = command.to_bytes();
+        assert_eq!(bytes.as_ref(), &[255, 255, 63, 0]);
+        let deserialized = Handshake::from_bytes(bytes).unwrap();
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn unknown_flags_should_be_ignored() {
+        let command = Handshake::from_bytes(Bytes::from_static(&[0, 0, 0, 128])).unwrap();
+        assert!(!command.resource_metadata);
+        assert!(!command.remaining_messages);
+        assert!(!command.message_id_scheme);
+        assert!(!command.consumer_group_rebalances);
+        assert!(!command.stream_quota);
+        assert!(!command.cleanup_policy);
+        assert!(!command.message_gaps);
+        assert!(!command.consumer_group_assignment);
+        assert!(!command.allowed_producers);
+        assert!(!command.offset_recovery);
+        assert!(!command.dead_letter);
+        assert!(!command.frame_checksums);
+        assert!(!command.visibility_timeout);
+        assert!(!command.throttle_time);
+        assert!(!command.topic_deletion_time);
+        assert!(!command.max_segments);
+        assert!(!command.partition_recovery);
+        assert!(!command.topic_config);
+        assert!(!command.message_sizes);
+        assert!(!command.token_scope);
+        assert!(!command.token_last_used);
+        assert!(!command.error_categories);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_invalid_bytes() {
+        let command = Handshake::from_bytes(Bytes::from_static(&[1, 0]));
+        assert!(command.is_err());
+    }
+}
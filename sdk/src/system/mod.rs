@@ -16,9 +16,17 @@
  * under the License.
  */
 
+pub mod create_backup;
+pub mod get_audit_log;
 pub mod get_client;
 pub mod get_clients;
+pub mod get_error_catalog;
+pub mod get_maintenance_mode;
 pub mod get_me;
+pub mod get_server_info;
 pub mod get_snapshot;
 pub mod get_stats;
+pub mod get_unsaved_state;
+pub mod handshake;
 pub mod ping;
+pub mod set_maintenance_mode;
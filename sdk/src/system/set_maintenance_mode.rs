@@ -0,0 +1,159 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, SET_MAINTENANCE_MODE_CODE};
+use crate::error::IggyError;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::from_utf8;
+
+/// The maximum length of the maintenance message in bytes.
+pub const MAX_MAINTENANCE_MESSAGE_LENGTH: usize = 1024;
+
+/// `SetMaintenanceMode` command is used to enable or disable the server-wide maintenance mode.
+/// While it's enabled, the writes are rejected with the retryable `ServerInMaintenance` error,
+/// and the buffered messages are saved on disk, so that the upgrades and filesystem operations
+/// can proceed without racing the producers.
+/// It has additional payload:
+/// - `enabled` - whether the maintenance mode should be enabled.
+/// - `reject_reads` - whether the reads should be rejected as well.
+/// - `message` - optional message explaining the maintenance, up to 1024 bytes.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct SetMaintenanceMode {
+    /// Whether the maintenance mode should be enabled.
+    pub enabled: bool,
+    /// Whether the reads should be rejected as well.
+    #[serde(default)]
+    pub reject_reads: bool,
+    /// Optional message explaining the maintenance.
+    #[serde(default)]
+    pub message: Option<String>,
+}
+
+impl Command for SetMaintenanceMode {
+    fn code(&self) -> u32 {
+        SET_MAINTENANCE_MODE_CODE
+    }
+}
+
+impl Validatable<IggyError> for SetMaintenanceMode {
+    fn validate(&self) -> Result<(), IggyError> {
+        if let Some(message) = &self.message {
+            if message.is_empty() || message.len() > MAX_MAINTENANCE_MESSAGE_LENGTH {
+                return Err(IggyError::InvalidCommand);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for SetMaintenanceMode {
+    fn to_bytes(&self) -> Bytes {
+        let message = self.message.as_deref().unwrap_or_default();
+        let mut bytes = BytesMut::with_capacity(6 + message.len());
+        bytes.put_u8(if self.enabled { 1 } else { 0 });
+        bytes.put_u8(if self.reject_reads { 1 } else { 0 });
+        bytes.put_u32_le(message.len() as u32);
+        bytes.put_slice(message.as_bytes());
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<SetMaintenanceMode, IggyError> {
+        if bytes.len() < 6 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let enabled = bytes[0] == 1;
+        let reject_reads = bytes[1] == 1;
+        let message_length = u32::from_le_bytes(
+            bytes[2..6]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        ) as usize;
+        if bytes.len() != 6 + message_length {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let message = match message_length {
+            0 => None,
+            _ => Some(
+                from_utf8(&bytes[6..])
+                    .map_err(|_| IggyError::InvalidUtf8)?
+                    .to_string(),
+            ),
+        };
+        let command = SetMaintenanceMode {
+            enabled,
+            reject_reads,
+            message,
+        };
+        Ok(command)
+    }
+}
+
+impl Display for SetMaintenanceMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}",
+            self.enabled,
+            self.reject_reads,
+            self.message.as_deref().unwrap_or_default()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_and_deserialized() {
+        let command = SetMaintenanceMode {
+            enabled: true,
+            reject_reads: false,
+            message: Some("upgrading to the new version".to_string()),
+        };
+
+        let bytes = command.to_bytes();
+        let deserialized = SetMaintenanceMode::from_bytes(bytes).unwrap();
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_be_deserialized_without_message() {
+        let command = SetMaintenanceMode {
+            enabled: false,
+            reject_reads: false,
+            message: None,
+        };
+
+        let deserialized = SetMaintenanceMode::from_bytes(command.to_bytes()).unwrap();
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_invalid_bytes() {
+        let command = SetMaintenanceMode::from_bytes(Bytes::from_static(&[1, 0, 5, 0, 0, 0]));
+        assert!(command.is_err());
+    }
+}
@@ -17,34 +17,39 @@
  */
 
 use crate::binary::binary_client::BinaryClient;
-use crate::binary::{BinaryTransport, ClientState};
+use crate::binary::{service, BinaryTransport, ClientState};
+use crate::bytes_serializable::BytesSerializable;
 use crate::client::{
     AutoLogin, Client, ConnectionString, Credentials, PersonalAccessTokenClient, UserClient,
 };
-use crate::command::Command;
+use crate::command::{Command, PING_CODE};
 use crate::diagnostic::DiagnosticEvent;
 use crate::error::{IggyError, IggyErrorDiscriminants};
+use crate::system::handshake::Handshake;
 use crate::tcp::config::TcpClientConfig;
+use crate::tcp::endpoints::EndpointResolver;
+use crate::tcp::tls::{self, TlsClientStream};
+use crate::utils::checksum;
 use crate::utils::duration::IggyDuration;
 use crate::utils::timestamp::IggyTimestamp;
 use async_broadcast::{broadcast, Receiver, Sender};
 use async_trait::async_trait;
 use bytes::{BufMut, Bytes, BytesMut};
-use rustls::pki_types::{pem::PemObject, CertificateDer, ServerName};
 use std::fmt::Debug;
 use std::net::SocketAddr;
 use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 use std::sync::Arc;
 use tokio::io::{AsyncReadExt, AsyncWriteExt, BufReader, BufWriter};
 use tokio::net::tcp::{OwnedReadHalf, OwnedWriteHalf};
 use tokio::net::TcpStream;
 use tokio::sync::Mutex;
-use tokio::time::sleep;
-use tokio_rustls::{TlsConnector, TlsStream};
+use tokio::time::{sleep, timeout};
 use tracing::{error, info, trace, warn};
 
 const REQUEST_INITIAL_BYTES_LENGTH: usize = 4;
 const RESPONSE_INITIAL_BYTES_LENGTH: usize = 8;
+const CHECKSUM_BYTES_LENGTH: usize = 4;
 const NAME: &str = "Iggy";
 
 /// TCP client for interacting with the Iggy API.
@@ -57,6 +62,10 @@ pub struct TcpClient {
     client_address: Mutex<Option<SocketAddr>>,
     events: (Sender<DiagnosticEvent>, Receiver<DiagnosticEvent>),
     connected_at: Mutex<Option<IggyTimestamp>>,
+    frame_checksums: AtomicBool,
+    protocol_features: AtomicU32,
+    endpoints: Arc<EndpointResolver>,
+    health_checks_started: AtomicBool,
 }
 
 #[async_trait]
@@ -124,11 +133,11 @@ impl TcpConnectionStream {
 #[derive(Debug)]
 pub(crate) struct TcpTlsConnectionStream {
     client_address: SocketAddr,
-    stream: TlsStream<TcpStream>,
+    stream: TlsClientStream,
 }
 
 impl TcpTlsConnectionStream {
-    pub fn new(client_address: SocketAddr, stream: TlsStream<TcpStream>) -> Self {
+    pub fn new(client_address: SocketAddr, stream: TlsClientStream) -> Self {
         Self {
             client_address,
             stream,
@@ -270,6 +279,16 @@ impl BinaryTransport for TcpClient {
         }
 
         let error = result.unwrap_err();
+        if let IggyError::InvalidFrameChecksum(_, _) = error {
+            // The request might have been handled already, so it's not retried,
+            // but the connection can't be trusted anymore and has to be reestablished.
+            self.disconnect().await?;
+            if self.config.reconnection.enabled {
+                self.connect().await?;
+            }
+            return Err(error);
+        }
+
         if !matches!(
             error,
             IggyError::Disconnected
@@ -307,10 +326,53 @@ impl BinaryTransport for TcpClient {
     fn get_heartbeat_interval(&self) -> IggyDuration {
         self.config.heartbeat_interval
     }
+
+    fn get_protocol_features(&self) -> Handshake {
+        Handshake::from_flags(self.protocol_features.load(Ordering::SeqCst))
+    }
 }
 
 impl BinaryClient for TcpClient {}
 
+#[async_trait]
+impl service::Connection for TcpClient {
+    async fn connect(&self) -> Result<(), IggyError> {
+        TcpClient::connect(self).await
+    }
+
+    async fn disconnect(&self) -> Result<(), IggyError> {
+        TcpClient::disconnect(self).await
+    }
+
+    async fn shutdown(&self) -> Result<(), IggyError> {
+        TcpClient::shutdown(self).await
+    }
+
+    async fn get_state(&self) -> ClientState {
+        BinaryTransport::get_state(self).await
+    }
+
+    async fn set_state(&self, state: ClientState) {
+        BinaryTransport::set_state(self, state).await
+    }
+
+    async fn publish_event(&self, event: DiagnosticEvent) {
+        BinaryTransport::publish_event(self, event).await
+    }
+
+    async fn subscribe_events(&self) -> Receiver<DiagnosticEvent> {
+        self.events.1.clone()
+    }
+
+    fn get_heartbeat_interval(&self) -> IggyDuration {
+        BinaryTransport::get_heartbeat_interval(self)
+    }
+
+    fn get_protocol_features(&self) -> Handshake {
+        BinaryTransport::get_protocol_features(self)
+    }
+}
+
 impl TcpClient {
     /// Create a new TCP client for the provided server address.
     pub fn new(
@@ -351,6 +413,10 @@ impl TcpClient {
 
     /// Create a new TCP client based on the provided configuration.
     pub fn create(config: Arc<TcpClientConfig>) -> Result<Self, IggyError> {
+        let endpoints = Arc::new(EndpointResolver::new(
+            &config.server_address,
+            &config.endpoints,
+        ));
         Ok(Self {
             config,
             client_address: Mutex::new(None),
@@ -358,15 +424,88 @@ impl TcpClient {
             state: Mutex::new(ClientState::Disconnected),
             events: broadcast(1000),
             connected_at: Mutex::new(None),
+            frame_checksums: AtomicBool::new(false),
+            protocol_features: AtomicU32::new(0),
+            endpoints,
+            health_checks_started: AtomicBool::new(false),
         })
     }
 
+    /// Returns the resolved endpoint of the server the client is (or has been lately) connected to.
+    pub async fn get_active_endpoint(&self) -> Option<SocketAddr> {
+        self.endpoints.get_active_endpoint().await
+    }
+
+    /// Connects to the first of the resolved endpoints accepting the connection,
+    /// the unreachable ones are marked as unhealthy, so that they're tried last by the next reconnect.
+    async fn connect_to_endpoints(&self) -> Result<(TcpStream, SocketAddr), IggyError> {
+        let endpoints = self.endpoints.get_endpoints().await?;
+        for endpoint in endpoints {
+            match TcpStream::connect(endpoint).await {
+                Ok(stream) => return Ok((stream, endpoint)),
+                Err(error) => {
+                    warn!("Failed to connect to server endpoint: {endpoint}. {error}");
+                    self.endpoints.mark_unhealthy(endpoint).await;
+                }
+            }
+        }
+
+        // None of the endpoints is reachable, they might have been replaced e.g. by the restarted server.
+        self.endpoints.expire().await;
+        Err(IggyError::CannotEstablishConnection)
+    }
+
+    /// Starts the health checks of the resolved endpoints in the background, if enabled.
+    /// The checks are stopped once the client is shut down or dropped.
+    fn start_health_checks(&self) {
+        let interval = self.config.endpoints.health_check_interval;
+        if interval.is_zero() || self.health_checks_started.swap(true, Ordering::SeqCst) {
+            return;
+        }
+
+        info!(
+            "Starting the health checks of server: {} endpoints every: {interval}.",
+            self.config.server_address
+        );
+        let endpoints = Arc::downgrade(&self.endpoints);
+        tokio::spawn(async move {
+            loop {
+                sleep(interval.get_duration()).await;
+                let Some(endpoints) = endpoints.upgrade() else {
+                    break;
+                };
+                if endpoints.is_stopped() {
+                    break;
+                }
+                endpoints.check_health().await;
+            }
+            trace!("Stopped the health checks of server endpoints.");
+        });
+    }
+
     async fn handle_response(
         &self,
-        status: u32,
-        length: u32,
+        header: &[u8],
+        frame_checksums: bool,
         stream: &mut ConnectionStreamKind,
     ) -> Result<Bytes, IggyError> {
+        let status = u32::from_le_bytes(
+            header[..4]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let length = u32::from_le_bytes(
+            header[4..]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        // The whole frame has to be read to verify its checksum, regardless of the status.
+        let verified_payload = if frame_checksums {
+            Some(Self::read_verified_payload(header, length, stream).await?)
+        } else {
+            None
+        };
+
         if status != 0 {
             // TEMP: See https://github.com/apache/iggy/pull/604 for context.
             if status == IggyErrorDiscriminants::TopicIdAlreadyExists as u32
@@ -399,12 +538,87 @@ impl TcpClient {
             return Ok(Bytes::new());
         }
 
+        if let Some(payload) = verified_payload {
+            return Ok(payload);
+        }
+
         let mut response_buffer = BytesMut::with_capacity(length as usize);
         response_buffer.put_bytes(0, length as usize);
         stream.read(&mut response_buffer).await?;
         Ok(response_buffer.freeze())
     }
 
+    async fn read_verified_payload(
+        header: &[u8],
+        length: u32,
+        stream: &mut ConnectionStreamKind,
+    ) -> Result<Bytes, IggyError> {
+        let mut response_buffer = BytesMut::with_capacity(length as usize + CHECKSUM_BYTES_LENGTH);
+        response_buffer.put_bytes(0, length as usize + CHECKSUM_BYTES_LENGTH);
+        stream.read(&mut response_buffer).await?;
+        let checksum_bytes = response_buffer.split_off(length as usize);
+        let checksum = u32::from_le_bytes(
+            checksum_bytes[..]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let expected_checksum = checksum::calculate_frame(&[header, &response_buffer]);
+        if checksum != expected_checksum {
+            error!("Received a response with invalid frame checksum: {checksum}, expected: {expected_checksum}.");
+            return Err(IggyError::InvalidFrameChecksum(checksum, expected_checksum));
+        }
+
+        Ok(response_buffer.freeze())
+    }
+
+    /// Negotiates the optional features of the binary protocol with the server, falling back to the initial version
+    /// of the protocol if the server doesn't support them, so that the client can still connect to the older servers.
+    async fn negotiate_protocol_features(&self, client_address: SocketAddr) {
+        let handshake = Handshake {
+            resource_metadata: true,
+            remaining_messages: true,
+            message_id_scheme: true,
+            consumer_group_rebalances: true,
+            stream_quota: true,
+            cleanup_policy: true,
+            message_gaps: true,
+            consumer_group_assignment: true,
+            allowed_producers: true,
+            offset_recovery: true,
+            dead_letter: true,
+            frame_checksums: self.config.frame_checksums,
+            visibility_timeout: true,
+            throttle_time: true,
+            topic_deletion_time: true,
+            max_segments: true,
+            partition_recovery: true,
+            topic_config: true,
+            message_sizes: true,
+            token_scope: true,
+            token_last_used: true,
+            ..Default::default()
+        };
+        match self
+            .send_raw(handshake.code(), handshake.to_bytes())
+            .await
+            .and_then(Handshake::from_bytes)
+        {
+            Ok(accepted) => {
+                self.protocol_features
+                    .store(accepted.as_flags(), Ordering::SeqCst);
+                if accepted.frame_checksums {
+                    self.frame_checksums.store(true, Ordering::SeqCst);
+                    info!("{NAME} client: {client_address} has enabled the frame checksums.");
+                } else if handshake.frame_checksums {
+                    warn!("{NAME} client: {client_address} cannot enable the frame checksums, as they are not supported by the server.");
+                }
+            }
+            Err(error) => {
+                warn!("{NAME} client: {client_address} cannot negotiate the binary protocol features, the initial version of the protocol will be used. {error}");
+            }
+        }
+    }
+
     async fn connect(&self) -> Result<(), IggyError> {
         match self.get_state().await {
             ClientState::Shutdown => {
@@ -450,7 +664,7 @@ impl TcpClient {
                 self.config.server_address
             );
 
-            let connection = TcpStream::connect(&self.config.server_address).await;
+            let connection = self.connect_to_endpoints().await;
             if connection.is_err() {
                 error!(
                     "Failed to connect to server: {}",
@@ -486,18 +700,12 @@ impl TcpClient {
                 return Err(IggyError::CannotEstablishConnection);
             }
 
-            let stream = connection.map_err(|error| {
-                error!("Failed to establish TCP connection to the server: {error}",);
-                IggyError::CannotEstablishConnection
-            })?;
+            let (stream, endpoint) = connection?;
             client_address = stream.local_addr().map_err(|error| {
                 error!("Failed to get the local address of the client: {error}",);
                 IggyError::CannotEstablishConnection
             })?;
-            remote_address = stream.peer_addr().map_err(|error| {
-                error!("Failed to get the remote address of the server: {error}",);
-                IggyError::CannotEstablishConnection
-            })?;
+            remote_address = endpoint;
             self.client_address.lock().await.replace(client_address);
 
             if let Err(e) = stream.set_nodelay(self.config.nodelay) {
@@ -510,50 +718,9 @@ impl TcpClient {
                 break;
             }
 
-            let mut root_cert_store = rustls::RootCertStore::empty();
-            if let Some(certificate_path) = &self.config.tls_ca_file {
-                for cert in CertificateDer::pem_file_iter(certificate_path).map_err(|error| {
-                    error!("Failed to read the CA file: {certificate_path}. {error}",);
-                    IggyError::InvalidTlsCertificatePath
-                })? {
-                    let certificate = cert.map_err(|error| {
-                        error!(
-                            "Failed to read a certificate from the CA file: {certificate_path}. {error}",
-                        );
-                        IggyError::InvalidTlsCertificate
-                    })?;
-                    root_cert_store.add(certificate).map_err(|error| {
-                        error!(
-                            "Failed to add a certificate to the root certificate store. {error}",
-                        );
-                        IggyError::InvalidTlsCertificate
-                    })?;
-                }
-            } else {
-                root_cert_store.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
-            }
-
-            let config = rustls::ClientConfig::builder()
-                .with_root_certificates(root_cert_store)
-                .with_no_client_auth();
-            let connector = TlsConnector::from(Arc::new(config));
-            let stream = TcpStream::connect(client_address).await.map_err(|error| {
-                error!("Failed to establish TCP connection to the server: {error}",);
-                IggyError::CannotEstablishConnection
-            })?;
-            let tls_domain = self.config.tls_domain.to_owned();
-            let domain = ServerName::try_from(tls_domain).map_err(|error| {
-                error!("Failed to create a server name from the domain. {error}",);
-                IggyError::InvalidTlsDomain
-            })?;
-            let stream = connector.connect(domain, stream).await.map_err(|error| {
-                error!("Failed to establish a TLS connection to the server: {error}",);
-                IggyError::CannotEstablishConnection
-            })?;
-            connection_stream = ConnectionStreamKind::TcpTls(TcpTlsConnectionStream::new(
-                client_address,
-                TlsStream::Client(stream),
-            ));
+            let stream = tls::connect(&self.config, stream).await?;
+            connection_stream =
+                ConnectionStreamKind::TcpTls(TcpTlsConnectionStream::new(client_address, stream));
             break;
         }
 
@@ -561,10 +728,18 @@ impl TcpClient {
         info!(
             "{NAME} client: {client_address} has connected to server: {remote_address} at: {now}",
         );
+        self.frame_checksums.store(false, Ordering::SeqCst);
+        self.protocol_features.store(0, Ordering::SeqCst);
         self.stream.lock().await.replace(connection_stream);
         self.set_state(ClientState::Connected).await;
         self.connected_at.lock().await.replace(now);
         self.publish_event(DiagnosticEvent::Connected).await;
+        if self.endpoints.set_active_endpoint(remote_address).await {
+            self.publish_event(DiagnosticEvent::EndpointChanged(remote_address))
+                .await;
+        }
+        self.start_health_checks();
+        self.negotiate_protocol_features(client_address).await;
         match &self.config.auto_login {
             AutoLogin::Disabled => {
                 info!("Automatic sign-in is disabled.");
@@ -615,6 +790,7 @@ impl TcpClient {
         if let Some(mut stream) = stream {
             stream.shutdown().await?;
         }
+        self.endpoints.stop();
         self.set_state(ClientState::Shutdown).await;
         self.publish_event(DiagnosticEvent::Shutdown).await;
         info!("{NAME} TCP client: {client_address} has been shutdown.");
@@ -640,40 +816,57 @@ impl TcpClient {
 
         let mut stream = self.stream.lock().await;
         if let Some(stream) = stream.as_mut() {
-            let payload_length = payload.len() + REQUEST_INITIAL_BYTES_LENGTH;
-            trace!("Sending a TCP request with code: {code}");
-            stream.write(&(payload_length as u32).to_le_bytes()).await?;
-            stream.write(&code.to_le_bytes()).await?;
-            stream.write(&payload).await?;
-            stream.flush().await?;
-            trace!("Sent a TCP request with code: {code}, waiting for a response...");
-
+            let frame_checksums = self.frame_checksums.load(Ordering::SeqCst);
             let mut response_buffer = [0u8; RESPONSE_INITIAL_BYTES_LENGTH];
-            let read_bytes = stream.read(&mut response_buffer).await.map_err(|error| {
-                error!(
-                    "Failed to read response for TCP request with code: {code}: {error}",
-                    code = code,
-                    error = error
-                );
-                IggyError::Disconnected
-            })?;
+            let exchange = async {
+                let payload_length = payload.len() + REQUEST_INITIAL_BYTES_LENGTH;
+                let length_bytes = (payload_length as u32).to_le_bytes();
+                let code_bytes = code.to_le_bytes();
+                trace!("Sending a TCP request with code: {code}");
+                stream.write(&length_bytes).await?;
+                stream.write(&code_bytes).await?;
+                stream.write(&payload).await?;
+                if frame_checksums {
+                    let checksum =
+                        checksum::calculate_frame(&[&length_bytes, &code_bytes, &payload]);
+                    stream.write(&checksum.to_le_bytes()).await?;
+                }
+                stream.flush().await?;
+                trace!("Sent a TCP request with code: {code}, waiting for a response...");
+
+                stream.read(&mut response_buffer).await.map_err(|error| {
+                    error!(
+                        "Failed to read response for TCP request with code: {code}: {error}",
+                        code = code,
+                        error = error
+                    );
+                    IggyError::Disconnected
+                })
+            };
+
+            // Only the heartbeats are bounded, the other requests might take long to be handled,
+            // while the heartbeat which is not answered in time means that the connection is half-open.
+            let heartbeat_timeout = self.config.heartbeat_timeout;
+            let read_bytes = if code == PING_CODE && !heartbeat_timeout.is_zero() {
+                match timeout(heartbeat_timeout.get_duration(), exchange).await {
+                    Ok(read_bytes) => read_bytes?,
+                    Err(_) => {
+                        warn!("No response to the heartbeat has been received within: {heartbeat_timeout}, the server is considered dead.");
+                        return Err(IggyError::Disconnected);
+                    }
+                }
+            } else {
+                exchange.await?
+            };
 
             if read_bytes != RESPONSE_INITIAL_BYTES_LENGTH {
                 error!("Received an invalid or empty response.");
                 return Err(IggyError::EmptyResponse);
             }
 
-            let status = u32::from_le_bytes(
-                response_buffer[..4]
-                    .try_into()
-                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
-            );
-            let length = u32::from_le_bytes(
-                response_buffer[4..]
-                    .try_into()
-                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
-            );
-            return self.handle_response(status, length, stream).await;
+            return self
+                .handle_response(&response_buffer, frame_checksums, stream)
+                .await;
         }
 
         error!("Cannot send data. Client is not connected.");
@@ -31,14 +31,26 @@ pub struct TcpClientConfig {
     pub tls_domain: String,
     /// The path to the CA file for TLS.
     pub tls_ca_file: Option<String>,
+    /// The path to the client certificate file (PEM) presented to the server requiring mutual TLS.
+    pub tls_cert_file: Option<String>,
+    /// The path to the private key file (PEM) of the client certificate.
+    pub tls_key_file: Option<String>,
     /// Whether to automatically login user after establishing connection.
     pub auto_login: AutoLogin,
     /// Whether to automatically reconnect when disconnected.
     pub reconnection: TcpClientReconnectionConfig,
+    /// How the server address is resolved to the endpoints and how their health is checked.
+    pub endpoints: TcpClientEndpointsConfig,
     /// Interval of heartbeats sent by the client
     pub heartbeat_interval: IggyDuration,
+    /// Maximum time to wait for the response to a heartbeat, after which the server is considered dead
+    /// and the connection is reestablished (if the reconnection is enabled). Zero disables it.
+    pub heartbeat_timeout: IggyDuration,
     /// Disable Nagle algorithm for the TCP socket.
     pub nodelay: bool,
+    /// Whether to negotiate the CRC32C checksums of the request and response frames after connecting.
+    /// The checksums are not used if the server doesn't support them.
+    pub frame_checksums: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -49,6 +61,18 @@ pub struct TcpClientReconnectionConfig {
     pub reestablish_after: IggyDuration,
 }
 
+#[derive(Debug, Clone)]
+pub struct TcpClientEndpointsConfig {
+    /// How long the resolved endpoints are reused before the server address is resolved again on reconnect.
+    /// The system resolver doesn't expose the TTLs of the DNS records, so it should be set to at most the TTL of the server records.
+    /// Zero resolves the server address on every reconnect.
+    pub dns_ttl: IggyDuration,
+    /// Interval of the health checks of the resolved endpoints, zero disables them.
+    pub health_check_interval: IggyDuration,
+    /// Timeout of the connection made to the endpoint by the health check.
+    pub health_check_timeout: IggyDuration,
+}
+
 impl Default for TcpClientConfig {
     fn default() -> TcpClientConfig {
         TcpClientConfig {
@@ -56,10 +80,15 @@ impl Default for TcpClientConfig {
             tls_enabled: false,
             tls_domain: "localhost".to_string(),
             tls_ca_file: None,
+            tls_cert_file: None,
+            tls_key_file: None,
             heartbeat_interval: IggyDuration::from_str("5s").unwrap(),
+            heartbeat_timeout: IggyDuration::from_str("5s").unwrap(),
             auto_login: AutoLogin::Disabled,
             reconnection: TcpClientReconnectionConfig::default(),
+            endpoints: TcpClientEndpointsConfig::default(),
             nodelay: false,
+            frame_checksums: false,
         }
     }
 }
@@ -75,6 +104,16 @@ impl Default for TcpClientReconnectionConfig {
     }
 }
 
+impl Default for TcpClientEndpointsConfig {
+    fn default() -> TcpClientEndpointsConfig {
+        TcpClientEndpointsConfig {
+            dns_ttl: IggyDuration::from_str("30s").unwrap(),
+            health_check_interval: IggyDuration::from_str("0").unwrap(),
+            health_check_timeout: IggyDuration::from_str("3s").unwrap(),
+        }
+    }
+}
+
 /// Builder for the TCP client configuration.
 /// Allows configuring the TCP client with custom settings or using defaults:
 /// - `server_address`: Default is "127.0.0.1:8090"
@@ -83,6 +122,10 @@ impl Default for TcpClientReconnectionConfig {
 /// - `tls_enabled`: Default is false.
 /// - `tls_domain`: Default is "localhost".
 /// - `tls_ca_file`: Default is None.
+/// - `tls_cert_file` and `tls_key_file`: Default is None.
+/// - `frame_checksums`: Default is false.
+/// - `heartbeat`: Default is 5 seconds interval and 5 seconds timeout.
+/// - `endpoints`: Default is 30 seconds DNS TTL and disabled health checks.
 #[derive(Debug, Default)]
 pub struct TcpClientConfigBuilder {
     config: TcpClientConfig,
@@ -140,12 +183,46 @@ impl TcpClientConfigBuilder {
         self
     }
 
+    /// Sets the client certificate and its private key (PEM) for the mutual TLS,
+    /// the server can map the certificate to the user and log in the client right after the handshake.
+    pub fn with_tls_client_certificate(mut self, cert_file: String, key_file: String) -> Self {
+        self.config.tls_cert_file = Some(cert_file);
+        self.config.tls_key_file = Some(key_file);
+        self
+    }
+
     /// Sets the nodelay option for the TCP socket.
     pub fn with_no_delay(mut self) -> Self {
         self.config.nodelay = true;
         self
     }
 
+    /// Enables the CRC32C checksums of the protocol frames, if supported by the server.
+    pub fn with_frame_checksums(mut self) -> Self {
+        self.config.frame_checksums = true;
+        self
+    }
+
+    /// Sets the interval of the heartbeats and the timeout of their responses, zero timeout disables the dead server detection.
+    pub fn with_heartbeat(mut self, interval: IggyDuration, timeout: IggyDuration) -> Self {
+        self.config.heartbeat_interval = interval;
+        self.config.heartbeat_timeout = timeout;
+        self
+    }
+
+    /// Sets how long the resolved endpoints are reused before the server address is resolved again.
+    pub fn with_dns_ttl(mut self, dns_ttl: IggyDuration) -> Self {
+        self.config.endpoints.dns_ttl = dns_ttl;
+        self
+    }
+
+    /// Enables the health checks of the resolved endpoints, performed at the given interval.
+    pub fn with_health_checks(mut self, interval: IggyDuration, timeout: IggyDuration) -> Self {
+        self.config.endpoints.health_check_interval = interval;
+        self.config.endpoints.health_check_timeout = timeout;
+        self
+    }
+
     /// Builds the TCP client configuration.
     pub fn build(self) -> TcpClientConfig {
         self.config
@@ -0,0 +1,302 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::error::IggyError;
+use crate::tcp::config::TcpClientEndpointsConfig;
+use futures::future::join_all;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+use tokio::net::{lookup_host, TcpStream};
+use tokio::sync::Mutex;
+use tokio::time::timeout;
+use tracing::{debug, error, info, warn};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Endpoint {
+    address: SocketAddr,
+    healthy: bool,
+}
+
+#[derive(Debug, Default)]
+struct ResolvedEndpoints {
+    endpoints: Vec<Endpoint>,
+    resolved_at: Option<Instant>,
+}
+
+/// Resolves the server address to the endpoints the client can connect to and keeps track of their health.
+/// The resolved endpoints are reused until the DNS TTL elapses, then the address is resolved again on reconnect.
+#[derive(Debug)]
+pub(crate) struct EndpointResolver {
+    server_address: String,
+    dns_ttl: Duration,
+    health_check_timeout: Duration,
+    resolved: Mutex<ResolvedEndpoints>,
+    active_endpoint: Mutex<Option<SocketAddr>>,
+    stopped: AtomicBool,
+}
+
+impl EndpointResolver {
+    pub fn new(server_address: &str, config: &TcpClientEndpointsConfig) -> Self {
+        Self {
+            server_address: server_address.to_owned(),
+            dns_ttl: config.dns_ttl.get_duration(),
+            health_check_timeout: config.health_check_timeout.get_duration(),
+            resolved: Mutex::new(ResolvedEndpoints::default()),
+            active_endpoint: Mutex::new(None),
+            stopped: AtomicBool::new(false),
+        }
+    }
+
+    /// Returns the endpoints to connect to, the healthy ones first, starting with the active one.
+    pub async fn get_endpoints(&self) -> Result<Vec<SocketAddr>, IggyError> {
+        let mut resolved = self.resolved.lock().await;
+        self.resolve_if_expired(&mut resolved).await?;
+        let active_endpoint = *self.active_endpoint.lock().await;
+        Ok(order_endpoints(&resolved.endpoints, active_endpoint))
+    }
+
+    /// Forces the server address to be resolved again before the next connection attempt.
+    pub async fn expire(&self) {
+        self.resolved.lock().await.resolved_at = None;
+    }
+
+    pub async fn mark_unhealthy(&self, address: SocketAddr) {
+        let mut resolved = self.resolved.lock().await;
+        if let Some(endpoint) = resolved
+            .endpoints
+            .iter_mut()
+            .find(|endpoint| endpoint.address == address)
+        {
+            endpoint.healthy = false;
+        }
+    }
+
+    /// Sets the endpoint the client has connected to, returns `true` if it has replaced the different one.
+    pub async fn set_active_endpoint(&self, address: SocketAddr) -> bool {
+        let previous_endpoint = self.active_endpoint.lock().await.replace(address);
+        self.mark_healthy(address).await;
+        match previous_endpoint {
+            Some(previous_endpoint) if previous_endpoint != address => {
+                info!(
+                    "Active server endpoint has changed from: {previous_endpoint} to: {address}."
+                );
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub async fn get_active_endpoint(&self) -> Option<SocketAddr> {
+        *self.active_endpoint.lock().await
+    }
+
+    pub fn stop(&self) {
+        self.stopped.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_stopped(&self) -> bool {
+        self.stopped.load(Ordering::SeqCst)
+    }
+
+    /// Resolves the server address if the DNS TTL has elapsed and checks whether the resolved endpoints
+    /// accept the TCP connections, so that the unreachable ones are skipped by the next reconnect.
+    pub async fn check_health(&self) {
+        let addresses = {
+            let mut resolved = self.resolved.lock().await;
+            if let Err(error) = self.resolve_if_expired(&mut resolved).await {
+                warn!(
+                    "Cannot check the health of server: {} endpoints. {error}",
+                    self.server_address
+                );
+                return;
+            }
+            resolved
+                .endpoints
+                .iter()
+                .map(|endpoint| endpoint.address)
+                .collect::<Vec<_>>()
+        };
+
+        let results = join_all(addresses.into_iter().map(|address| async move {
+            let healthy = matches!(
+                timeout(self.health_check_timeout, TcpStream::connect(address)).await,
+                Ok(Ok(_))
+            );
+            (address, healthy)
+        }))
+        .await;
+
+        let mut resolved = self.resolved.lock().await;
+        for (address, healthy) in results {
+            let Some(endpoint) = resolved
+                .endpoints
+                .iter_mut()
+                .find(|endpoint| endpoint.address == address)
+            else {
+                continue;
+            };
+
+            if endpoint.healthy == healthy {
+                continue;
+            }
+
+            endpoint.healthy = healthy;
+            if healthy {
+                info!("Server endpoint: {address} is healthy again.");
+            } else {
+                warn!("Server endpoint: {address} has failed the health check.");
+            }
+        }
+    }
+
+    async fn mark_healthy(&self, address: SocketAddr) {
+        let mut resolved = self.resolved.lock().await;
+        if let Some(endpoint) = resolved
+            .endpoints
+            .iter_mut()
+            .find(|endpoint| endpoint.address == address)
+        {
+            endpoint.healthy = true;
+        }
+    }
+
+    async fn resolve_if_expired(&self, resolved: &mut ResolvedEndpoints) -> Result<(), IggyError> {
+        if !resolved.endpoints.is_empty()
+            && resolved
+                .resolved_at
+                .is_some_and(|resolved_at| resolved_at.elapsed() < self.dns_ttl)
+        {
+            return Ok(());
+        }
+
+        let addresses = lookup_host(&self.server_address)
+            .await
+            .map_err(|error| {
+                error!(
+                    "Failed to resolve the server address: {}. {error}",
+                    self.server_address
+                );
+                IggyError::CannotEstablishConnection
+            })?
+            .collect::<Vec<_>>();
+        if addresses.is_empty() {
+            error!(
+                "Server address: {} has not been resolved to any endpoint.",
+                self.server_address
+            );
+            return Err(IggyError::CannotEstablishConnection);
+        }
+
+        let mut endpoints = Vec::with_capacity(addresses.len());
+        for address in addresses {
+            if endpoints
+                .iter()
+                .any(|endpoint: &Endpoint| endpoint.address == address)
+            {
+                continue;
+            }
+
+            // The health of the already known endpoints is kept, the new ones are assumed to be healthy.
+            let healthy = resolved
+                .endpoints
+                .iter()
+                .find(|endpoint| endpoint.address == address)
+                .is_none_or(|endpoint| endpoint.healthy);
+            endpoints.push(Endpoint { address, healthy });
+        }
+
+        if endpoints.len() != resolved.endpoints.len()
+            || endpoints.iter().any(|endpoint| {
+                !resolved
+                    .endpoints
+                    .iter()
+                    .any(|e| e.address == endpoint.address)
+            })
+        {
+            info!(
+                "Server address: {} has been resolved to: {}.",
+                self.server_address,
+                endpoints
+                    .iter()
+                    .map(|endpoint| endpoint.address.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+        } else {
+            debug!(
+                "Server address: {} has been resolved to the same endpoints.",
+                self.server_address
+            );
+        }
+
+        resolved.endpoints = endpoints;
+        resolved.resolved_at = Some(Instant::now());
+        Ok(())
+    }
+}
+
+/// Orders the endpoints by their health, preferring the active one, so that the client sticks to it
+/// while it's healthy. The relative order of the resolved endpoints is kept otherwise.
+fn order_endpoints(endpoints: &[Endpoint], active_endpoint: Option<SocketAddr>) -> Vec<SocketAddr> {
+    let mut endpoints = endpoints.to_vec();
+    endpoints
+        .sort_by_key(|endpoint| (!endpoint.healthy, Some(endpoint.address) != active_endpoint));
+    endpoints
+        .into_iter()
+        .map(|endpoint| endpoint.address)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn healthy_endpoints_should_be_ordered_first_starting_with_active_one() {
+        let addresses = ["10.0.0.1:8090", "10.0.0.2:8090", "10.0.0.3:8090"]
+            .map(|address| address.parse::<SocketAddr>().unwrap());
+        let endpoints = [
+            Endpoint {
+                address: addresses[0],
+                healthy: false,
+            },
+            Endpoint {
+                address: addresses[1],
+                healthy: true,
+            },
+            Endpoint {
+                address: addresses[2],
+                healthy: true,
+            },
+        ];
+
+        assert_eq!(
+            order_endpoints(&endpoints, None),
+            vec![addresses[1], addresses[2], addresses[0]]
+        );
+        assert_eq!(
+            order_endpoints(&endpoints, Some(addresses[2])),
+            vec![addresses[2], addresses[1], addresses[0]]
+        );
+        assert_eq!(
+            order_endpoints(&endpoints, Some(addresses[0])),
+            vec![addresses[1], addresses[2], addresses[0]]
+        );
+    }
+}
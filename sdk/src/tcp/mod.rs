@@ -18,3 +18,5 @@
 
 pub mod client;
 pub mod config;
+pub(crate) mod endpoints;
+pub(crate) mod tls;
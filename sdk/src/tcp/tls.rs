@@ -0,0 +1,160 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+//! The TLS handshake of the TCP client, performed with `rustls` or `native-tls`, depending on the enabled feature.
+//! The `rustls` one is used if both features are enabled. Without any of them, the TLS connections are rejected.
+
+use crate::error::IggyError;
+use crate::tcp::config::TcpClientConfig;
+use tokio::net::TcpStream;
+use tracing::error;
+
+#[cfg(feature = "rustls")]
+pub(crate) type TlsClientStream = tokio_rustls::client::TlsStream<TcpStream>;
+
+#[cfg(all(feature = "native-tls", not(feature = "rustls")))]
+pub(crate) type TlsClientStream = tokio_native_tls::TlsStream<TcpStream>;
+
+#[cfg(not(any(feature = "rustls", feature = "native-tls")))]
+pub(crate) type TlsClientStream = TcpStream;
+
+#[cfg(feature = "rustls")]
+pub(crate) async fn connect(
+    config: &TcpClientConfig,
+    stream: TcpStream,
+) -> Result<TlsClientStream, IggyError> {
+    use rustls::pki_types::{pem::PemObject, CertificateDer, PrivateKeyDer, ServerName};
+    use std::sync::Arc;
+    use tokio_rustls::TlsConnector;
+
+    let mut root_cert_store = rustls::RootCertStore::empty();
+    if let Some(certificate_path) = &config.tls_ca_file {
+        for cert in CertificateDer::pem_file_iter(certificate_path).map_err(|error| {
+            error!("Failed to read the CA file: {certificate_path}. {error}",);
+            IggyError::InvalidTlsCertificatePath
+        })? {
+            let certificate = cert.map_err(|error| {
+                error!(
+                    "Failed to read a certificate from the CA file: {certificate_path}. {error}",
+                );
+                IggyError::InvalidTlsCertificate
+            })?;
+            root_cert_store.add(certificate).map_err(|error| {
+                error!("Failed to add a certificate to the root certificate store. {error}",);
+                IggyError::InvalidTlsCertificate
+            })?;
+        }
+    } else {
+        root_cert_store.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
+    }
+
+    let tls_config = rustls::ClientConfig::builder().with_root_certificates(root_cert_store);
+    let tls_config = match (&config.tls_cert_file, &config.tls_key_file) {
+        (Some(cert_file), Some(key_file)) => {
+            let certificates = CertificateDer::pem_file_iter(cert_file)
+                .map_err(|error| {
+                    error!("Failed to read the client certificate file: {cert_file}. {error}",);
+                    IggyError::InvalidTlsCertificatePath
+                })?
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|error| {
+                    error!("Failed to read the client certificate: {cert_file}. {error}",);
+                    IggyError::InvalidTlsCertificate
+                })?;
+            let key = PrivateKeyDer::from_pem_file(key_file).map_err(|error| {
+                error!("Failed to read the client key file: {key_file}. {error}",);
+                IggyError::InvalidTlsCertificate
+            })?;
+            tls_config
+                .with_client_auth_cert(certificates, key)
+                .map_err(|error| {
+                    error!("Failed to use the client certificate. {error}",);
+                    IggyError::InvalidTlsCertificate
+                })?
+        }
+        _ => tls_config.with_no_client_auth(),
+    };
+    let connector = TlsConnector::from(Arc::new(tls_config));
+    let tls_domain = config.tls_domain.to_owned();
+    let domain = ServerName::try_from(tls_domain).map_err(|error| {
+        error!("Failed to create a server name from the domain. {error}",);
+        IggyError::InvalidTlsDomain
+    })?;
+    connector.connect(domain, stream).await.map_err(|error| {
+        error!("Failed to establish a TLS connection to the server: {error}",);
+        IggyError::CannotEstablishConnection
+    })
+}
+
+#[cfg(all(feature = "native-tls", not(feature = "rustls")))]
+pub(crate) async fn connect(
+    config: &TcpClientConfig,
+    stream: TcpStream,
+) -> Result<TlsClientStream, IggyError> {
+    let mut builder = native_tls::TlsConnector::builder();
+    if let Some(certificate_path) = &config.tls_ca_file {
+        let pem = tokio::fs::read(certificate_path).await.map_err(|error| {
+            error!("Failed to read the CA file: {certificate_path}. {error}",);
+            IggyError::InvalidTlsCertificatePath
+        })?;
+        let certificate = native_tls::Certificate::from_pem(&pem).map_err(|error| {
+            error!("Failed to read a certificate from the CA file: {certificate_path}. {error}",);
+            IggyError::InvalidTlsCertificate
+        })?;
+        builder.add_root_certificate(certificate);
+    }
+    if let (Some(cert_file), Some(key_file)) = (&config.tls_cert_file, &config.tls_key_file) {
+        let certificate = tokio::fs::read(cert_file).await.map_err(|error| {
+            error!("Failed to read the client certificate file: {cert_file}. {error}",);
+            IggyError::InvalidTlsCertificatePath
+        })?;
+        let key = tokio::fs::read(key_file).await.map_err(|error| {
+            error!("Failed to read the client key file: {key_file}. {error}",);
+            IggyError::InvalidTlsCertificatePath
+        })?;
+        let identity = native_tls::Identity::from_pkcs8(&certificate, &key).map_err(|error| {
+            error!("Failed to use the client certificate. {error}",);
+            IggyError::InvalidTlsCertificate
+        })?;
+        builder.identity(identity);
+    }
+
+    let connector = builder.build().map_err(|error| {
+        error!("Failed to create the TLS connector. {error}",);
+        IggyError::CannotEstablishConnection
+    })?;
+    let connector = tokio_native_tls::TlsConnector::from(connector);
+    connector
+        .connect(&config.tls_domain, stream)
+        .await
+        .map_err(|error| {
+            error!("Failed to establish a TLS connection to the server: {error}",);
+            IggyError::CannotEstablishConnection
+        })
+}
+
+#[cfg(not(any(feature = "rustls", feature = "native-tls")))]
+pub(crate) async fn connect(
+    _config: &TcpClientConfig,
+    _stream: TcpStream,
+) -> Result<TlsClientStream, IggyError> {
+    error!(
+        "TLS is enabled, but the SDK has been built without the `rustls` or `native-tls` feature."
+    );
+    Err(IggyError::CannotEstablishConnection)
+}
@@ -0,0 +1,103 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::error::IggyError;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// The policy used by the server to clean up the old messages of the topic.
+/// The policy is chosen when the topic is created and recorded in the topic metadata:
+/// - `ServerDefault`: use the policy configured on the server (only valid when creating the topic).
+/// - `Delete`: the old segments are deleted based on the message expiry and the max topic size.
+/// - `Compact`: the closed segments are periodically compacted, keeping only the latest message per message ID.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CleanupPolicy {
+    #[default]
+    ServerDefault,
+    Delete,
+    Compact,
+}
+
+impl CleanupPolicy {
+    pub fn as_code(&self) -> u8 {
+        match self {
+            CleanupPolicy::ServerDefault => 0,
+            CleanupPolicy::Delete => 1,
+            CleanupPolicy::Compact => 2,
+        }
+    }
+
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            0 => Ok(CleanupPolicy::ServerDefault),
+            1 => Ok(CleanupPolicy::Delete),
+            2 => Ok(CleanupPolicy::Compact),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+impl FromStr for CleanupPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "server_default" | "default" => Ok(CleanupPolicy::ServerDefault),
+            "delete" => Ok(CleanupPolicy::Delete),
+            "compact" => Ok(CleanupPolicy::Compact),
+            _ => Err(format!("Unknown cleanup policy: {s}")),
+        }
+    }
+}
+
+impl Display for CleanupPolicy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CleanupPolicy::ServerDefault => write!(f, "server_default"),
+            CleanupPolicy::Delete => write!(f, "delete"),
+            CleanupPolicy::Compact => write!(f, "compact"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn policy_should_be_mapped_from_code() {
+        for policy in [
+            CleanupPolicy::ServerDefault,
+            CleanupPolicy::Delete,
+            CleanupPolicy::Compact,
+        ] {
+            assert_eq!(CleanupPolicy::from_code(policy.as_code()).unwrap(), policy);
+            assert_eq!(
+                CleanupPolicy::from_str(&policy.to_string()).unwrap(),
+                policy
+            );
+        }
+    }
+
+    #[test]
+    fn unknown_code_should_fail() {
+        assert!(CleanupPolicy::from_code(3).is_err());
+    }
+}
@@ -21,6 +21,9 @@ use crate::command::{Command, CREATE_TOPIC_CODE};
 use crate::compression::compression_algorithm::CompressionAlgorithm;
 use crate::error::IggyError;
 use crate::identifier::Identifier;
+use crate::messages::message_id_scheme::MessageIdScheme;
+use crate::models::metadata::ResourceMetadata;
+use crate::topics::cleanup_policy::CleanupPolicy;
 use crate::topics::{MAX_NAME_LENGTH, MAX_PARTITIONS_COUNT};
 use crate::utils::expiry::IggyExpiry;
 use crate::utils::sizeable::Sizeable;
@@ -41,6 +44,9 @@ use std::str::from_utf8;
 ///                      Can't be lower than segment size in the config.
 /// - `replication_factor` - replication factor for the topic.
 /// - `name` - unique topic name, max length is 255 characters.
+/// - `metadata` - optional description, owner and labels of the topic.
+/// - `message_id_scheme` - scheme used to assign the IDs to the messages sent without ID.
+/// - `cleanup_policy` - policy used to clean up the old messages, either deleting or compacting them.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct CreateTopic {
     /// Unique stream ID (numeric or name).
@@ -61,6 +67,15 @@ pub struct CreateTopic {
     pub replication_factor: Option<u8>,
     /// Unique topic name, max length is 255 characters.
     pub name: String,
+    /// Optional description, owner and labels of the topic.
+    #[serde(default)]
+    pub metadata: ResourceMetadata,
+    /// Scheme used to assign the IDs to the messages sent without ID, if `ServerDefault` then the server default is used.
+    #[serde(default)]
+    pub message_id_scheme: MessageIdScheme,
+    /// Policy used to clean up the old messages, if `ServerDefault` then the server default is used.
+    #[serde(default)]
+    pub cleanup_policy: CleanupPolicy,
 }
 
 impl Command for CreateTopic {
@@ -80,10 +95,20 @@ impl Default for CreateTopic {
             max_topic_size: MaxTopicSize::ServerDefault,
             replication_factor: None,
             name: "topic".to_string(),
+            metadata: ResourceMetadata::default(),
+            message_id_scheme: MessageIdScheme::ServerDefault,
+            cleanup_policy: CleanupPolicy::ServerDefault,
         }
     }
 }
 
+/// The optional settings of the topic created with `TopicClient::create_topic_with_options`.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CreateTopicOptions {
+    /// Description, owner and labels of the topic.
+    pub metadata: ResourceMetadata,
+}
+
 impl Validatable<IggyError> for CreateTopic {
     fn validate(&self) -> Result<(), IggyError> {
         if let Some(topic_id) = self.topic_id {
@@ -106,14 +131,16 @@ impl Validatable<IggyError> for CreateTopic {
             }
         }
 
-        Ok(())
+        self.metadata.validate()
     }
 }
 
 impl BytesSerializable for CreateTopic {
     fn to_bytes(&self) -> Bytes {
         let stream_id_bytes = self.stream_id.to_bytes();
-        let mut bytes = BytesMut::with_capacity(23 + stream_id_bytes.len() + self.name.len());
+        let mut bytes = BytesMut::with_capacity(
+            24 + stream_id_bytes.len() + self.name.len() + self.metadata.get_size_bytes(),
+        );
         bytes.put_slice(&stream_id_bytes);
         bytes.put_u32_le(self.topic_id.unwrap_or(0));
         bytes.put_u32_le(self.partitions_count);
@@ -127,6 +154,19 @@ impl BytesSerializable for CreateTopic {
         #[allow(clippy::cast_possible_truncation)]
         bytes.put_u8(self.name.len() as u8);
         bytes.put_slice(self.name.as_bytes());
+        // All the fields are optional, each one must be present if any of the next ones follows it.
+        let has_cleanup_policy = self.cleanup_policy != CleanupPolicy::ServerDefault;
+        let has_message_id_scheme =
+            self.message_id_scheme != MessageIdScheme::ServerDefault || has_cleanup_policy;
+        if !self.metadata.is_empty() || has_message_id_scheme {
+            self.metadata.write_to_buffer(&mut bytes);
+        }
+        if has_message_id_scheme {
+            bytes.put_u8(self.message_id_scheme.as_code());
+        }
+        if has_cleanup_policy {
+            bytes.put_u8(self.cleanup_policy.as_code());
+        }
         bytes.freeze()
     }
 
@@ -172,6 +212,23 @@ impl BytesSerializable for CreateTopic {
         if name.len() != name_length as usize {
             return Err(IggyError::InvalidCommand);
         }
+        let position = position + 27 + name_length as usize;
+        let (metadata, metadata_bytes) = if bytes.len() > position {
+            ResourceMetadata::from_bytes_at(&bytes, position)?
+        } else {
+            (ResourceMetadata::default(), 0)
+        };
+        let position = position + metadata_bytes;
+        let message_id_scheme = if bytes.len() > position {
+            MessageIdScheme::from_code(bytes[position])?
+        } else {
+            MessageIdScheme::ServerDefault
+        };
+        let cleanup_policy = if bytes.len() > position + 1 {
+            CleanupPolicy::from_code(bytes[position + 1])?
+        } else {
+            CleanupPolicy::ServerDefault
+        };
         let command = CreateTopic {
             stream_id,
             topic_id,
@@ -181,6 +238,9 @@ impl BytesSerializable for CreateTopic {
             max_topic_size,
             replication_factor,
             name,
+            metadata,
+            message_id_scheme,
+            cleanup_policy,
         };
         Ok(command)
     }
@@ -190,14 +250,17 @@ impl Display for CreateTopic {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{}|{}|{}|{}|{}|{}|{}",
+            "{}|{}|{}|{}|{}|{}|{}|{}|{}|{}",
             self.stream_id,
             self.topic_id.unwrap_or(0),
             self.partitions_count,
             self.message_expiry,
             self.max_topic_size,
             self.replication_factor.unwrap_or(0),
-            self.name
+            self.name,
+            self.metadata,
+            self.message_id_scheme,
+            self.cleanup_policy
         )
     }
 }
@@ -218,6 +281,9 @@ mod tests {
             max_topic_size: MaxTopicSize::ServerDefault,
             replication_factor: Some(1),
             name: "test".to_string(),
+            metadata: ResourceMetadata::default(),
+            message_id_scheme: MessageIdScheme::ServerDefault,
+            cleanup_policy: CleanupPolicy::ServerDefault,
         };
         let bytes = command.to_bytes();
         let mut position = 0;
@@ -287,5 +353,51 @@ mod tests {
         assert_eq!(command.max_topic_size, max_topic_size);
         assert_eq!(command.replication_factor.unwrap(), replication_factor);
         assert_eq!(command.partitions_count, partitions_count);
+        assert!(command.metadata.is_empty());
+        assert_eq!(command.message_id_scheme, MessageIdScheme::ServerDefault);
+        assert_eq!(command.cleanup_policy, CleanupPolicy::ServerDefault);
+    }
+
+    #[test]
+    fn should_be_serialized_and_deserialized_with_metadata() {
+        let command = CreateTopic {
+            metadata: ResourceMetadata {
+                description: Some("description".to_string()),
+                owner: Some("team".to_string()),
+                labels: [("env".to_string(), "prod".to_string())].into(),
+            },
+            ..CreateTopic::default()
+        };
+
+        let deserialized = CreateTopic::from_bytes(command.to_bytes()).unwrap();
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_be_serialized_and_deserialized_with_message_id_scheme() {
+        let command = CreateTopic {
+            message_id_scheme: MessageIdScheme::Snowflake,
+            ..CreateTopic::default()
+        };
+
+        let deserialized = CreateTopic::from_bytes(command.to_bytes()).unwrap();
+        assert_eq!(deserialized, command);
+        assert!(deserialized.metadata.is_empty());
+    }
+
+    #[test]
+    fn should_be_serialized_and_deserialized_with_cleanup_policy() {
+        let command = CreateTopic {
+            cleanup_policy: CleanupPolicy::Compact,
+            ..CreateTopic::default()
+        };
+
+        let deserialized = CreateTopic::from_bytes(command.to_bytes()).unwrap();
+        assert_eq!(deserialized, command);
+        assert_eq!(
+            deserialized.message_id_scheme,
+            MessageIdScheme::ServerDefault
+        );
+        assert!(deserialized.metadata.is_empty());
     }
 }
@@ -20,19 +20,25 @@ use crate::bytes_serializable::BytesSerializable;
 use crate::command::{Command, GET_TOPICS_CODE};
 use crate::error::IggyError;
 use crate::identifier::Identifier;
+use crate::models::metadata::MetadataFilter;
+use crate::utils::sizeable::Sizeable;
 use crate::validatable::Validatable;
-use bytes::Bytes;
+use bytes::{BufMut, Bytes, BytesMut};
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 
 /// `GetTopics` command is used to retrieve the collection of topics from a stream.
 /// It has additional payload:
 /// - `stream_id` - unique stream ID (numeric or name).
+/// - `filter` - optional metadata filter (owner, labels, description), empty filter matches all the topics.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
 pub struct GetTopics {
     /// Unique stream ID (numeric or name).
     #[serde(skip)]
     pub stream_id: Identifier,
+    /// The metadata filter, empty filter matches all the topics.
+    #[serde(default)]
+    pub filter: MetadataFilter,
 }
 
 impl Command for GetTopics {
@@ -43,13 +49,22 @@ impl Command for GetTopics {
 
 impl Validatable<IggyError> for GetTopics {
     fn validate(&self) -> Result<(), IggyError> {
-        Ok(())
+        self.filter.validate()
     }
 }
 
 impl BytesSerializable for GetTopics {
     fn to_bytes(&self) -> Bytes {
-        self.stream_id.to_bytes()
+        let stream_id_bytes = self.stream_id.to_bytes();
+        if self.filter.is_empty() {
+            return stream_id_bytes;
+        }
+
+        let mut bytes =
+            BytesMut::with_capacity(stream_id_bytes.len() + self.filter.get_size_bytes());
+        bytes.put_slice(&stream_id_bytes);
+        self.filter.write_to_buffer(&mut bytes);
+        bytes.freeze()
     }
 
     fn from_bytes(bytes: Bytes) -> std::result::Result<GetTopics, IggyError> {
@@ -57,15 +72,21 @@ impl BytesSerializable for GetTopics {
             return Err(IggyError::InvalidCommand);
         }
 
-        let stream_id = Identifier::from_bytes(bytes)?;
-        let command = GetTopics { stream_id };
+        let stream_id = Identifier::from_bytes(bytes.clone())?;
+        let position = stream_id.get_size_bytes().as_bytes_usize();
+        let filter = if bytes.len() > position {
+            MetadataFilter::from_bytes_at(&bytes, position)?.0
+        } else {
+            MetadataFilter::default()
+        };
+        let command = GetTopics { stream_id, filter };
         Ok(command)
     }
 }
 
 impl Display for GetTopics {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.stream_id)
+        write!(f, "{}|{}", self.stream_id, self.filter)
     }
 }
 
@@ -77,6 +98,7 @@ mod tests {
     fn should_be_serialized_as_bytes() {
         let command = GetTopics {
             stream_id: Identifier::numeric(1).unwrap(),
+            filter: MetadataFilter::default(),
         };
 
         let bytes = command.to_bytes();
@@ -95,5 +117,21 @@ mod tests {
 
         let command = command.unwrap();
         assert_eq!(command.stream_id, stream_id);
+        assert!(command.filter.is_empty());
+    }
+
+    #[test]
+    fn should_be_serialized_and_deserialized_with_filter() {
+        let command = GetTopics {
+            stream_id: Identifier::named("stream").unwrap(),
+            filter: MetadataFilter {
+                owner: Some("team".to_string()),
+                labels: Default::default(),
+                description: Some("orders".to_string()),
+            },
+        };
+
+        let deserialized = GetTopics::from_bytes(command.to_bytes()).unwrap();
+        assert_eq!(deserialized, command);
     }
 }
@@ -0,0 +1,141 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, MARK_TOPIC_FOR_DELETION_CODE};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::utils::duration::IggyDuration;
+use crate::utils::sizeable::Sizeable;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `MarkTopicForDeletion` command is used to delete the topic gracefully.
+/// The topic stops accepting the messages immediately, but it can still be polled until the drain period elapses,
+/// so that the consumers can process their backlog before the topic is deleted.
+/// It has additional payload:
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `drain_period` - the time after which the topic is deleted, '0' to use the server default one.
+///   Marking the topic again replaces its deletion time.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct MarkTopicForDeletion {
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    /// The time after which the topic is deleted, '0' to use the server default one.
+    #[serde(default)]
+    pub drain_period: IggyDuration,
+}
+
+impl Command for MarkTopicForDeletion {
+    fn code(&self) -> u32 {
+        MARK_TOPIC_FOR_DELETION_CODE
+    }
+}
+
+impl Validatable<IggyError> for MarkTopicForDeletion {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for MarkTopicForDeletion {
+    fn to_bytes(&self) -> Bytes {
+        let stream_id_bytes = self.stream_id.to_bytes();
+        let topic_id_bytes = self.topic_id.to_bytes();
+        let mut bytes = BytesMut::with_capacity(stream_id_bytes.len() + topic_id_bytes.len() + 8);
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        bytes.put_u64_le(self.drain_period.as_micros());
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> std::result::Result<MarkTopicForDeletion, IggyError> {
+        if bytes.len() < 14 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone())?;
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        if bytes.len() != position + 8 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let drain_period = u64::from_le_bytes(
+            bytes[position..position + 8]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let command = MarkTopicForDeletion {
+            stream_id,
+            topic_id,
+            drain_period: drain_period.into(),
+        };
+        Ok(command)
+    }
+}
+
+impl Display for MarkTopicForDeletion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}",
+            self.stream_id, self.topic_id, self.drain_period
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_and_deserialized_from_bytes() {
+        let command = MarkTopicForDeletion {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::named("payments").unwrap(),
+            drain_period: IggyDuration::new_from_secs(3600),
+        };
+
+        let bytes = command.to_bytes();
+        let deserialized = MarkTopicForDeletion::from_bytes(bytes).unwrap();
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_truncated_bytes() {
+        let command = MarkTopicForDeletion {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            drain_period: IggyDuration::default(),
+        };
+
+        let bytes = command.to_bytes();
+        let command = MarkTopicForDeletion::from_bytes(bytes.slice(..bytes.len() - 1));
+        assert!(command.is_err());
+    }
+}
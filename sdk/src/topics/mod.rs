@@ -16,12 +16,20 @@
  * under the License.
  */
 
+pub mod cleanup_policy;
 pub mod create_topic;
 pub mod delete_topic;
 pub mod get_topic;
 pub mod get_topics;
+pub mod mark_topic_for_deletion;
 pub mod purge_topic;
+pub mod topic_config;
 pub mod update_topic;
+pub mod update_topic_config;
+pub mod update_topic_expiry_watcher;
+pub mod update_topic_metadata;
+pub mod update_topic_producers;
 
 const MAX_NAME_LENGTH: usize = 255;
 const MAX_PARTITIONS_COUNT: u32 = 1000;
+const MAX_ALLOWED_PRODUCERS_COUNT: u32 = 100;
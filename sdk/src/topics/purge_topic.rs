@@ -21,6 +21,7 @@ use crate::command::{Command, PURGE_TOPIC_CODE};
 use crate::error::IggyError;
 use crate::identifier::Identifier;
 use crate::utils::sizeable::Sizeable;
+use crate::utils::timestamp::IggyTimestamp;
 use crate::validatable::Validatable;
 use bytes::{BufMut, Bytes, BytesMut};
 use serde::{Deserialize, Serialize};
@@ -30,6 +31,8 @@ use std::fmt::Display;
 /// It has additional payload:
 /// - `stream_id` - unique stream ID (numeric or name).
 /// - `topic_id` - unique topic ID (numeric or name).
+/// - `keep_consumer_offsets` - whether to keep the stored consumer offsets and continue the offsets sequence instead of resetting it.
+/// - `older_than` - optional timestamp, if set only the closed segments with all the messages older than it are purged.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
 pub struct PurgeTopic {
     /// Unique stream ID (numeric or name).
@@ -38,6 +41,21 @@ pub struct PurgeTopic {
     /// Unique topic ID (numeric or name).
     #[serde(skip)]
     pub topic_id: Identifier,
+    /// Whether to keep the stored consumer offsets and continue the offsets sequence instead of resetting it.
+    #[serde(default)]
+    pub keep_consumer_offsets: bool,
+    /// If set, only the closed segments with all the messages older than this timestamp are purged.
+    #[serde(default)]
+    pub older_than: Option<IggyTimestamp>,
+}
+
+/// The optional settings of the topic purged with `TopicClient::purge_topic_with_options`.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct PurgeTopicOptions {
+    /// Whether to keep the stored consumer offsets and continue the offsets sequence instead of resetting it.
+    pub keep_consumer_offsets: bool,
+    /// If set, only the closed segments with all the messages older than this timestamp are purged.
+    pub older_than: Option<IggyTimestamp>,
 }
 
 impl Command for PurgeTopic {
@@ -56,9 +74,11 @@ impl BytesSerializable for PurgeTopic {
     fn to_bytes(&self) -> Bytes {
         let stream_id_bytes = self.stream_id.to_bytes();
         let topic_id_bytes = self.topic_id.to_bytes();
-        let mut bytes = BytesMut::with_capacity(stream_id_bytes.len() + topic_id_bytes.len());
+        let mut bytes = BytesMut::with_capacity(9 + stream_id_bytes.len() + topic_id_bytes.len());
         bytes.put_slice(&stream_id_bytes);
         bytes.put_slice(&topic_id_bytes);
+        bytes.put_u8(u8::from(self.keep_consumer_offsets));
+        bytes.put_u64_le(self.older_than.map_or(0, |timestamp| timestamp.as_micros()));
         bytes.freeze()
     }
 
@@ -71,9 +91,39 @@ impl BytesSerializable for PurgeTopic {
         let stream_id = Identifier::from_bytes(bytes.clone())?;
         position += stream_id.get_size_bytes().as_bytes_usize();
         let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        // The purge options were added later, the commands stored without them purge everything.
+        if bytes.len() == position {
+            return Ok(PurgeTopic {
+                stream_id,
+                topic_id,
+                ..Default::default()
+            });
+        }
+
+        if bytes.len() != position + 9 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let keep_consumer_offsets = match bytes[position] {
+            0 => false,
+            1 => true,
+            _ => return Err(IggyError::InvalidCommand),
+        };
+        let older_than = u64::from_le_bytes(
+            bytes[position + 1..position + 9]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let older_than = match older_than {
+            0 => None,
+            older_than => Some(older_than.into()),
+        };
         let command = PurgeTopic {
             stream_id,
             topic_id,
+            keep_consumer_offsets,
+            older_than,
         };
         Ok(command)
     }
@@ -81,7 +131,14 @@ impl BytesSerializable for PurgeTopic {
 
 impl Display for PurgeTopic {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}|{}", self.stream_id, self.topic_id)
+        write!(
+            f,
+            "{}|{}|{}|{}",
+            self.stream_id,
+            self.topic_id,
+            self.keep_consumer_offsets,
+            self.older_than.map_or(0, |timestamp| timestamp.as_micros())
+        )
     }
 }
 
@@ -94,6 +151,8 @@ mod tests {
         let command = PurgeTopic {
             stream_id: Identifier::numeric(1).unwrap(),
             topic_id: Identifier::numeric(2).unwrap(),
+            keep_consumer_offsets: true,
+            older_than: Some(IggyTimestamp::from(1000)),
         };
 
         let bytes = command.to_bytes();
@@ -101,10 +160,15 @@ mod tests {
         let stream_id = Identifier::from_bytes(bytes.clone()).unwrap();
         position += stream_id.get_size_bytes().as_bytes_usize();
         let topic_id = Identifier::from_bytes(bytes.slice(position..)).unwrap();
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        let keep_consumer_offsets = bytes[position];
+        let older_than = u64::from_le_bytes(bytes[position + 1..position + 9].try_into().unwrap());
 
         assert!(!bytes.is_empty());
         assert_eq!(stream_id, command.stream_id);
         assert_eq!(topic_id, command.topic_id);
+        assert_eq!(keep_consumer_offsets, 1);
+        assert_eq!(older_than, 1000);
     }
 
     #[test]
@@ -114,11 +178,30 @@ mod tests {
         let mut bytes = BytesMut::new();
         bytes.put_slice(&stream_id.to_bytes());
         bytes.put_slice(&topic_id.to_bytes());
+        bytes.put_u8(1);
+        bytes.put_u64_le(1000);
         let command = PurgeTopic::from_bytes(bytes.freeze());
         assert!(command.is_ok());
 
         let command = command.unwrap();
         assert_eq!(command.stream_id, stream_id);
         assert_eq!(command.topic_id, topic_id);
+        assert!(command.keep_consumer_offsets);
+        assert_eq!(command.older_than, Some(IggyTimestamp::from(1000)));
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes_without_purge_options() {
+        let stream_id = Identifier::numeric(1).unwrap();
+        let topic_id = Identifier::numeric(2).unwrap();
+        let mut bytes = BytesMut::new();
+        bytes.put_slice(&stream_id.to_bytes());
+        bytes.put_slice(&topic_id.to_bytes());
+        let command = PurgeTopic::from_bytes(bytes.freeze()).unwrap();
+
+        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.topic_id, topic_id);
+        assert!(!command.keep_consumer_offsets);
+        assert!(command.older_than.is_none());
     }
 }
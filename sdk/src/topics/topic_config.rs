@@ -0,0 +1,186 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::compression::compression_algorithm::CompressionAlgorithm;
+use crate::error::IggyError;
+use crate::utils::byte_size::IggyByteSize;
+use crate::utils::expiry::IggyExpiry;
+use crate::validatable::Validatable;
+use bytes::{BufMut, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+const CACHE_NOT_OVERRIDDEN: u8 = 0;
+const CACHE_DISABLED: u8 = 1;
+const CACHE_ENABLED: u8 = 2;
+
+/// `TopicConfig` represents the settings overriding the server defaults (`system.*`) for a single topic.
+/// Each setting left as `None` uses the server default:
+/// - `segment_size`: the maximum size of the segment, applied to the open segments and the new ones.
+/// - `message_expiry`: the expiry of the messages, same as the one set when creating or updating the topic.
+/// - `cache_enabled`: whether the messages are cached, the cache can't be enabled if it's disabled on the server.
+/// - `compression_algorithm`: the algorithm used for storing the messages, if the server allows overriding it.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TopicConfig {
+    /// The maximum size of the segment.
+    #[serde(default)]
+    pub segment_size: Option<IggyByteSize>,
+    /// The expiry of the messages.
+    #[serde(default)]
+    pub message_expiry: Option<IggyExpiry>,
+    /// Whether the messages are cached.
+    #[serde(default)]
+    pub cache_enabled: Option<bool>,
+    /// The algorithm used for storing the messages.
+    #[serde(default)]
+    pub compression_algorithm: Option<CompressionAlgorithm>,
+}
+
+impl TopicConfig {
+    /// Returns true if none of the server defaults is overridden.
+    pub fn is_empty(&self) -> bool {
+        *self == TopicConfig::default()
+    }
+
+    /// Returns the config with the message expiry and the compression algorithm set when creating or updating the topic,
+    /// so that both ways of changing them are reflected in the overrides.
+    pub fn with_topic_settings(
+        self,
+        message_expiry: IggyExpiry,
+        compression_algorithm: CompressionAlgorithm,
+    ) -> Self {
+        TopicConfig {
+            message_expiry: match message_expiry {
+                IggyExpiry::ServerDefault => None,
+                message_expiry => Some(message_expiry),
+            },
+            compression_algorithm: match compression_algorithm {
+                CompressionAlgorithm::None => None,
+                compression_algorithm => Some(compression_algorithm),
+            },
+            ..self
+        }
+    }
+
+    /// Returns the size of the binary representation in bytes.
+    pub fn get_size_bytes(&self) -> usize {
+        8 + 8 + 1 + 1
+    }
+
+    /// Appends the binary representation to the provided buffer.
+    pub fn write_to_buffer(&self, bytes: &mut BytesMut) {
+        bytes.put_u64_le(
+            self.segment_size
+                .map(|segment_size| segment_size.as_bytes_u64())
+                .unwrap_or_default(),
+        );
+        bytes.put_u64_le(self.message_expiry.map(u64::from).unwrap_or_default());
+        bytes.put_u8(match self.cache_enabled {
+            None => CACHE_NOT_OVERRIDDEN,
+            Some(false) => CACHE_DISABLED,
+            Some(true) => CACHE_ENABLED,
+        });
+        bytes.put_u8(
+            self.compression_algorithm
+                .map(|compression_algorithm| compression_algorithm.as_code())
+                .unwrap_or_default(),
+        );
+    }
+
+    /// Reads the config from the provided bytes starting at the given position.
+    /// Returns the config and the number of bytes read.
+    pub fn from_bytes_at(bytes: &[u8], position: usize) -> Result<(Self, usize), IggyError> {
+        let read_u64 = |position: usize| -> Result<u64, IggyError> {
+            Ok(u64::from_le_bytes(
+                bytes
+                    .get(position..position + 8)
+                    .ok_or(IggyError::InvalidCommand)?
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            ))
+        };
+        let segment_size = match read_u64(position)? {
+            0 => None,
+            segment_size => Some(IggyByteSize::from(segment_size)),
+        };
+        let message_expiry = match read_u64(position + 8)? {
+            0 => None,
+            message_expiry => Some(IggyExpiry::from(message_expiry)),
+        };
+        let cache_enabled = match bytes.get(position + 16) {
+            Some(&CACHE_NOT_OVERRIDDEN) => None,
+            Some(&CACHE_DISABLED) => Some(false),
+            Some(&CACHE_ENABLED) => Some(true),
+            _ => return Err(IggyError::InvalidCommand),
+        };
+        let compression_algorithm = match bytes.get(position + 17) {
+            Some(0) => None,
+            Some(code) => Some(CompressionAlgorithm::from_code(*code)?),
+            None => return Err(IggyError::InvalidCommand),
+        };
+        let config = TopicConfig {
+            segment_size,
+            message_expiry,
+            cache_enabled,
+            compression_algorithm,
+        };
+        Ok((config, 18))
+    }
+}
+
+impl Validatable<IggyError> for TopicConfig {
+    fn validate(&self) -> Result<(), IggyError> {
+        if let Some(segment_size) = self.segment_size {
+            // The positions of the messages in the segment are stored as 32-bit numbers.
+            if segment_size.as_bytes_u64() == 0 || segment_size.as_bytes_u64() > u32::MAX as u64 {
+                return Err(IggyError::InvalidTopicConfig);
+            }
+        }
+
+        if self.message_expiry == Some(IggyExpiry::ServerDefault) {
+            return Err(IggyError::InvalidTopicConfig);
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for TopicConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let segment_size = self
+            .segment_size
+            .map(|segment_size| segment_size.as_human_string())
+            .unwrap_or_else(|| "default".to_string());
+        let message_expiry = self
+            .message_expiry
+            .map(|message_expiry| message_expiry.to_string())
+            .unwrap_or_else(|| "default".to_string());
+        let cache_enabled = self
+            .cache_enabled
+            .map(|cache_enabled| cache_enabled.to_string())
+            .unwrap_or_else(|| "default".to_string());
+        let compression_algorithm = self
+            .compression_algorithm
+            .map(|compression_algorithm| compression_algorithm.to_string())
+            .unwrap_or_else(|| "default".to_string());
+        write!(
+            f,
+            "{segment_size}|{message_expiry}|{cache_enabled}|{compression_algorithm}"
+        )
+    }
+}
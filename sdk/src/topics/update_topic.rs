@@ -40,6 +40,8 @@ use std::str::from_utf8;
 ///                      Can't be lower than segment size in the config.
 /// - `replication_factor` - replication factor for the topic.
 /// - `name` - unique topic name, max length is 255 characters.
+/// - `max_segments` - maximum number of segments per partition, if `0` then the number of segments is unlimited,
+///                    if not set then the current one is kept.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct UpdateTopic {
     /// Unique stream ID (numeric or name).
@@ -59,6 +61,11 @@ pub struct UpdateTopic {
     pub replication_factor: Option<u8>,
     /// Unique topic name, max length is 255 characters.
     pub name: String,
+    /// Max number of segments per partition, if `0` then the number of segments is unlimited.
+    /// The oldest closed segments exceeding the limit are deleted by the maintenance.
+    /// If not set, the current max number of segments is kept.
+    #[serde(default)]
+    pub max_segments: Option<u32>,
 }
 
 impl Command for UpdateTopic {
@@ -77,10 +84,18 @@ impl Default for UpdateTopic {
             max_topic_size: MaxTopicSize::ServerDefault,
             replication_factor: None,
             name: "topic".to_string(),
+            max_segments: None,
         }
     }
 }
 
+/// The optional settings of the topic updated with `TopicClient::update_topic_with_options`.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct UpdateTopicOptions {
+    /// Max number of segments per partition, `0` for unlimited, the current one is kept if not set.
+    pub max_segments: Option<u32>,
+}
+
 impl Validatable<IggyError> for UpdateTopic {
     fn validate(&self) -> Result<(), IggyError> {
         if self.name.is_empty() || self.name.len() > MAX_NAME_LENGTH {
@@ -102,7 +117,7 @@ impl BytesSerializable for UpdateTopic {
         let stream_id_bytes = self.stream_id.to_bytes();
         let topic_id_bytes = self.topic_id.to_bytes();
         let mut bytes = BytesMut::with_capacity(
-            19 + stream_id_bytes.len() + topic_id_bytes.len() + self.name.len(),
+            24 + stream_id_bytes.len() + topic_id_bytes.len() + self.name.len(),
         );
         bytes.put_slice(&stream_id_bytes.clone());
         bytes.put_slice(&topic_id_bytes.clone());
@@ -116,6 +131,13 @@ impl BytesSerializable for UpdateTopic {
         #[allow(clippy::cast_possible_truncation)]
         bytes.put_u8(self.name.len() as u8);
         bytes.put_slice(self.name.as_bytes());
+        match self.max_segments {
+            Some(max_segments) => {
+                bytes.put_u8(1);
+                bytes.put_u32_le(max_segments);
+            }
+            None => bytes.put_u8(0),
+        }
         bytes.freeze()
     }
 
@@ -153,6 +175,20 @@ impl BytesSerializable for UpdateTopic {
         if name.len() != name_length as usize {
             return Err(IggyError::InvalidCommand);
         }
+        // The max segments might be missing in the commands sent by the older clients or recorded before
+        // it was introduced, in which case the current one is kept.
+        let position = position + 18 + name_length as usize;
+        let max_segments = match bytes.get(position) {
+            Some(1) => Some(u32::from_le_bytes(
+                bytes
+                    .get(position + 1..position + 5)
+                    .ok_or(IggyError::InvalidCommand)?
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            )),
+            Some(0) | None => None,
+            Some(_) => return Err(IggyError::InvalidCommand),
+        };
         let command = UpdateTopic {
             stream_id,
             topic_id,
@@ -161,6 +197,7 @@ impl BytesSerializable for UpdateTopic {
             max_topic_size,
             replication_factor,
             name,
+            max_segments,
         };
         Ok(command)
     }
@@ -168,15 +205,20 @@ impl BytesSerializable for UpdateTopic {
 
 impl Display for UpdateTopic {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let max_segments = match self.max_segments {
+            Some(max_segments) => max_segments.to_string(),
+            None => "unchanged".to_string(),
+        };
         write!(
             f,
-            "{}|{}|{}|{}|{}|{}",
+            "{}|{}|{}|{}|{}|{}|{}",
             self.stream_id,
             self.topic_id,
             self.message_expiry,
             self.max_topic_size,
             self.replication_factor.unwrap_or(0),
             self.name,
+            max_segments,
         )
     }
 }
@@ -197,6 +239,7 @@ mod tests {
             max_topic_size: MaxTopicSize::ServerDefault,
             replication_factor: Some(1),
             name: "test".to_string(),
+            max_segments: Some(10),
         };
 
         let bytes = command.to_bytes();
@@ -227,6 +270,12 @@ mod tests {
         assert_eq!(replication_factor, command.replication_factor.unwrap());
         assert_eq!(name.len() as u8, command.name.len() as u8);
         assert_eq!(name, command.name);
+        let position = position + 18 + name_length as usize;
+        assert_eq!(bytes[position], 1);
+        assert_eq!(
+            u32::from_le_bytes(bytes[position + 1..position + 5].try_into().unwrap()),
+            command.max_segments.unwrap()
+        );
     }
 
     #[test]
@@ -265,5 +314,26 @@ mod tests {
         assert_eq!(command.max_topic_size, max_topic_size);
         assert_eq!(command.replication_factor, Some(replication_factor));
         assert_eq!(command.name, name);
+        assert_eq!(command.max_segments, None);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes_with_max_segments() {
+        let command = UpdateTopic {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            max_segments: Some(0),
+            ..Default::default()
+        };
+
+        let deserialized = UpdateTopic::from_bytes(command.to_bytes()).unwrap();
+        assert_eq!(deserialized, command);
+
+        let command = UpdateTopic {
+            max_segments: None,
+            ..command
+        };
+        let deserialized = UpdateTopic::from_bytes(command.to_bytes()).unwrap();
+        assert_eq!(deserialized, command);
     }
 }
@@ -0,0 +1,153 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, UPDATE_TOPIC_CONFIG_CODE};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::topics::topic_config::TopicConfig;
+use crate::utils::sizeable::Sizeable;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `UpdateTopicConfig` command is used to override the server defaults for an existing topic.
+/// It has additional payload:
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `config` - the settings overriding the server defaults, replacing the previous ones.
+///   The settings left as `None` use the server defaults.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct UpdateTopicConfig {
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    /// The settings overriding the server defaults.
+    #[serde(default)]
+    pub config: TopicConfig,
+}
+
+impl Command for UpdateTopicConfig {
+    fn code(&self) -> u32 {
+        UPDATE_TOPIC_CONFIG_CODE
+    }
+}
+
+impl Validatable<IggyError> for UpdateTopicConfig {
+    fn validate(&self) -> Result<(), IggyError> {
+        self.config.validate()
+    }
+}
+
+impl BytesSerializable for UpdateTopicConfig {
+    fn to_bytes(&self) -> Bytes {
+        let stream_id_bytes = self.stream_id.to_bytes();
+        let topic_id_bytes = self.topic_id.to_bytes();
+        let mut bytes = BytesMut::with_capacity(
+            stream_id_bytes.len() + topic_id_bytes.len() + self.config.get_size_bytes(),
+        );
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        self.config.write_to_buffer(&mut bytes);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> std::result::Result<UpdateTopicConfig, IggyError> {
+        if bytes.len() < 24 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone())?;
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        let (config, read_bytes) = TopicConfig::from_bytes_at(&bytes, position)?;
+        if bytes.len() != position + read_bytes {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let command = UpdateTopicConfig {
+            stream_id,
+            topic_id,
+            config,
+        };
+        Ok(command)
+    }
+}
+
+impl Display for UpdateTopicConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}|{}|{}", self.stream_id, self.topic_id, self.config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compression::compression_algorithm::CompressionAlgorithm;
+    use crate::utils::byte_size::IggyByteSize;
+    use crate::utils::duration::IggyDuration;
+    use crate::utils::expiry::IggyExpiry;
+
+    #[test]
+    fn should_be_serialized_and_deserialized_from_bytes() {
+        let command = UpdateTopicConfig {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::named("payments").unwrap(),
+            config: TopicConfig {
+                segment_size: Some(IggyByteSize::from(64_000_000)),
+                message_expiry: Some(IggyExpiry::ExpireDuration(IggyDuration::from(
+                    3_600_000_000,
+                ))),
+                cache_enabled: Some(false),
+                compression_algorithm: Some(CompressionAlgorithm::Zstd),
+            },
+        };
+
+        let bytes = command.to_bytes();
+        let deserialized = UpdateTopicConfig::from_bytes(bytes).unwrap();
+        assert_eq!(deserialized, command);
+
+        let command = UpdateTopicConfig {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            config: TopicConfig::default(),
+        };
+        let deserialized = UpdateTopicConfig::from_bytes(command.to_bytes()).unwrap();
+        assert!(deserialized.config.is_empty());
+    }
+
+    #[test]
+    fn should_not_be_valid_given_empty_segment_size() {
+        let command = UpdateTopicConfig {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            config: TopicConfig {
+                segment_size: Some(IggyByteSize::from(0)),
+                ..Default::default()
+            },
+        };
+
+        assert!(command.validate().is_err());
+    }
+}
@@ -0,0 +1,186 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, UPDATE_TOPIC_EXPIRY_WATCHER_CODE};
+use crate::consumer::{Consumer, ConsumerKind};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::utils::sizeable::Sizeable;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+use std::fmt::Display;
+
+/// `UpdateTopicExpiryWatcher` command is used to register (or remove) the interest of the consumer
+/// in the expiry of the messages of the topic. The registered consumers and consumer groups are listed
+/// in the notifications published to the internal `__expiry_notifications` topic before the expired messages are deleted.
+/// It has additional payload:
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `consumer_kind` - the kind of the consumer, either the consumer or the consumer group.
+/// - `consumer_id` - unique consumer ID (numeric or name), the consumer group must exist.
+/// - `enabled` - `true` registers the interest, `false` removes it.
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct UpdateTopicExpiryWatcher {
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    /// The kind of the consumer, either the consumer or the consumer group.
+    #[serde(default)]
+    pub consumer_kind: ConsumerKind,
+    /// Unique consumer ID (numeric or name).
+    #[serde_as(as = "DisplayFromStr")]
+    pub consumer_id: Identifier,
+    /// `true` registers the interest in the expiry, `false` removes it.
+    pub enabled: bool,
+}
+
+impl UpdateTopicExpiryWatcher {
+    /// Returns the consumer registering (or removing) the interest in the expiry.
+    pub fn consumer(&self) -> Consumer {
+        Consumer {
+            kind: self.consumer_kind,
+            id: self.consumer_id.clone(),
+        }
+    }
+}
+
+impl Default for UpdateTopicExpiryWatcher {
+    fn default() -> Self {
+        UpdateTopicExpiryWatcher {
+            stream_id: Identifier::default(),
+            topic_id: Identifier::default(),
+            consumer_kind: ConsumerKind::ConsumerGroup,
+            consumer_id: Identifier::default(),
+            enabled: true,
+        }
+    }
+}
+
+impl Command for UpdateTopicExpiryWatcher {
+    fn code(&self) -> u32 {
+        UPDATE_TOPIC_EXPIRY_WATCHER_CODE
+    }
+}
+
+impl Validatable<IggyError> for UpdateTopicExpiryWatcher {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for UpdateTopicExpiryWatcher {
+    fn to_bytes(&self) -> Bytes {
+        let stream_id_bytes = self.stream_id.to_bytes();
+        let topic_id_bytes = self.topic_id.to_bytes();
+        let consumer_id_bytes = self.consumer_id.to_bytes();
+        let mut bytes = BytesMut::with_capacity(
+            stream_id_bytes.len() + topic_id_bytes.len() + 1 + consumer_id_bytes.len() + 1,
+        );
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        bytes.put_u8(self.consumer_kind.as_code());
+        bytes.put_slice(&consumer_id_bytes);
+        bytes.put_u8(if self.enabled { 1 } else { 0 });
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> std::result::Result<UpdateTopicExpiryWatcher, IggyError> {
+        if bytes.len() < 11 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone())?;
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        let consumer_kind =
+            ConsumerKind::from_code(*bytes.get(position).ok_or(IggyError::InvalidCommand)?)?;
+        position += 1;
+        let consumer_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += consumer_id.get_size_bytes().as_bytes_usize();
+        if bytes.len() != position + 1 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let enabled = match bytes[position] {
+            0 => false,
+            1 => true,
+            _ => return Err(IggyError::InvalidCommand),
+        };
+        let command = UpdateTopicExpiryWatcher {
+            stream_id,
+            topic_id,
+            consumer_kind,
+            consumer_id,
+            enabled,
+        };
+        Ok(command)
+    }
+}
+
+impl Display for UpdateTopicExpiryWatcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}|{}",
+            self.stream_id, self.topic_id, self.consumer_kind, self.consumer_id, self.enabled
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_and_deserialized_from_bytes() {
+        let command = UpdateTopicExpiryWatcher {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::named("payments").unwrap(),
+            consumer_kind: ConsumerKind::ConsumerGroup,
+            consumer_id: Identifier::named("archiver").unwrap(),
+            enabled: true,
+        };
+
+        let bytes = command.to_bytes();
+        let deserialized = UpdateTopicExpiryWatcher::from_bytes(bytes).unwrap();
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_truncated_bytes() {
+        let command = UpdateTopicExpiryWatcher {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            consumer_kind: ConsumerKind::Consumer,
+            consumer_id: Identifier::numeric(3).unwrap(),
+            enabled: false,
+        };
+
+        let bytes = command.to_bytes();
+        let command = UpdateTopicExpiryWatcher::from_bytes(bytes.slice(..bytes.len() - 1));
+        assert!(command.is_err());
+    }
+}
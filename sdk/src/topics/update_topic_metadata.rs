@@ -0,0 +1,136 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, UPDATE_TOPIC_METADATA_CODE};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::models::metadata::ResourceMetadata;
+use crate::utils::sizeable::Sizeable;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `UpdateTopicMetadata` command is used to replace the metadata of an existing topic.
+/// It has additional payload:
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `metadata` - description, owner and labels of the topic, empty metadata clears all the fields.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct UpdateTopicMetadata {
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    /// Description, owner and labels of the topic.
+    #[serde(flatten)]
+    pub metadata: ResourceMetadata,
+}
+
+impl Command for UpdateTopicMetadata {
+    fn code(&self) -> u32 {
+        UPDATE_TOPIC_METADATA_CODE
+    }
+}
+
+impl Validatable<IggyError> for UpdateTopicMetadata {
+    fn validate(&self) -> Result<(), IggyError> {
+        self.metadata.validate()
+    }
+}
+
+impl BytesSerializable for UpdateTopicMetadata {
+    fn to_bytes(&self) -> Bytes {
+        let stream_id_bytes = self.stream_id.to_bytes();
+        let topic_id_bytes = self.topic_id.to_bytes();
+        let mut bytes = BytesMut::with_capacity(
+            stream_id_bytes.len() + topic_id_bytes.len() + self.metadata.get_size_bytes(),
+        );
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        self.metadata.write_to_buffer(&mut bytes);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> std::result::Result<UpdateTopicMetadata, IggyError> {
+        if bytes.len() < 10 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone())?;
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        let (metadata, read_bytes) = ResourceMetadata::from_bytes_at(&bytes, position)?;
+        if position + read_bytes != bytes.len() {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let command = UpdateTopicMetadata {
+            stream_id,
+            topic_id,
+            metadata,
+        };
+        Ok(command)
+    }
+}
+
+impl Display for UpdateTopicMetadata {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}|{}|{}", self.stream_id, self.topic_id, self.metadata)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_and_deserialized_from_bytes() {
+        let command = UpdateTopicMetadata {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::named("orders").unwrap(),
+            metadata: ResourceMetadata {
+                description: Some("description".to_string()),
+                owner: Some("team".to_string()),
+                labels: [("env".to_string(), "prod".to_string())].into(),
+            },
+        };
+
+        let bytes = command.to_bytes();
+        let deserialized = UpdateTopicMetadata::from_bytes(bytes).unwrap();
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_truncated_bytes() {
+        let command = UpdateTopicMetadata {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            metadata: ResourceMetadata::default(),
+        };
+
+        let bytes = command.to_bytes();
+        let command = UpdateTopicMetadata::from_bytes(bytes.slice(..bytes.len() - 1));
+        assert!(command.is_err());
+    }
+}
@@ -0,0 +1,182 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, UPDATE_TOPIC_PRODUCERS_CODE};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::topics::MAX_ALLOWED_PRODUCERS_COUNT;
+use crate::utils::sizeable::Sizeable;
+use crate::validatable::Validatable;
+use ahash::AHashSet;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `UpdateTopicProducers` command is used to restrict the producers of an existing topic.
+/// It has additional payload:
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `allowed_producers` - IDs of the users allowed to send the messages to the topic, regardless of their other permissions.
+///   Empty list removes the restriction.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct UpdateTopicProducers {
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    /// IDs of the users allowed to send the messages to the topic, empty for no restriction.
+    #[serde(default)]
+    pub allowed_producers: Vec<u32>,
+}
+
+impl Command for UpdateTopicProducers {
+    fn code(&self) -> u32 {
+        UPDATE_TOPIC_PRODUCERS_CODE
+    }
+}
+
+impl Validatable<IggyError> for UpdateTopicProducers {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.allowed_producers.len() > MAX_ALLOWED_PRODUCERS_COUNT as usize {
+            return Err(IggyError::InvalidTopicProducers(
+                MAX_ALLOWED_PRODUCERS_COUNT,
+            ));
+        }
+
+        let unique_producers = self.allowed_producers.iter().collect::<AHashSet<_>>();
+        if unique_producers.len() != self.allowed_producers.len() {
+            return Err(IggyError::InvalidTopicProducers(
+                MAX_ALLOWED_PRODUCERS_COUNT,
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for UpdateTopicProducers {
+    fn to_bytes(&self) -> Bytes {
+        let stream_id_bytes = self.stream_id.to_bytes();
+        let topic_id_bytes = self.topic_id.to_bytes();
+        let mut bytes = BytesMut::with_capacity(
+            stream_id_bytes.len() + topic_id_bytes.len() + 4 + 4 * self.allowed_producers.len(),
+        );
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        bytes.put_u32_le(self.allowed_producers.len() as u32);
+        for user_id in &self.allowed_producers {
+            bytes.put_u32_le(*user_id);
+        }
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> std::result::Result<UpdateTopicProducers, IggyError> {
+        if bytes.len() < 10 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone())?;
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        let count = u32::from_le_bytes(
+            bytes
+                .get(position..position + 4)
+                .ok_or(IggyError::InvalidCommand)?
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        position += 4;
+        if bytes.len() != position + 4 * count as usize {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let allowed_producers = bytes[position..]
+            .chunks_exact(4)
+            .map(|chunk| u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+            .collect();
+        let command = UpdateTopicProducers {
+            stream_id,
+            topic_id,
+            allowed_producers,
+        };
+        Ok(command)
+    }
+}
+
+impl Display for UpdateTopicProducers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let allowed_producers = self
+            .allowed_producers
+            .iter()
+            .map(|user_id| user_id.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        write!(
+            f,
+            "{}|{}|{}",
+            self.stream_id, self.topic_id, allowed_producers
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_and_deserialized_from_bytes() {
+        let command = UpdateTopicProducers {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::named("payments").unwrap(),
+            allowed_producers: vec![3, 7],
+        };
+
+        let bytes = command.to_bytes();
+        let deserialized = UpdateTopicProducers::from_bytes(bytes).unwrap();
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_truncated_bytes() {
+        let command = UpdateTopicProducers {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            allowed_producers: vec![3],
+        };
+
+        let bytes = command.to_bytes();
+        let command = UpdateTopicProducers::from_bytes(bytes.slice(..bytes.len() - 1));
+        assert!(command.is_err());
+    }
+
+    #[test]
+    fn should_not_be_valid_given_duplicated_producers() {
+        let command = UpdateTopicProducers {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            allowed_producers: vec![3, 3],
+        };
+
+        assert!(command.validate().is_err());
+    }
+}
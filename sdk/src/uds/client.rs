@@ -0,0 +1,530 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::binary_client::BinaryClient;
+use crate::binary::{mapper, service, BinaryTransport, ClientState};
+use crate::bytes_serializable::BytesSerializable;
+use crate::client::{AutoLogin, Client, Credentials, PersonalAccessTokenClient, UserClient};
+use crate::command::Command;
+use crate::diagnostic::DiagnosticEvent;
+use crate::error::IggyError;
+use crate::system::get_me::GetMe;
+use crate::system::handshake::Handshake;
+use crate::uds::config::UdsClientConfig;
+use crate::utils::duration::IggyDuration;
+use crate::utils::timestamp::IggyTimestamp;
+use async_broadcast::{broadcast, Receiver, Sender};
+use async_trait::async_trait;
+use bytes::{BufMut, Bytes, BytesMut};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+use tokio::io::{AsyncReadExt, AsyncWriteExt, BufReader, BufWriter};
+use tokio::net::unix::{OwnedReadHalf, OwnedWriteHalf};
+use tokio::net::UnixStream;
+use tokio::sync::Mutex;
+use tokio::time::sleep;
+use tracing::{error, info, trace, warn};
+
+const REQUEST_INITIAL_BYTES_LENGTH: usize = 4;
+const RESPONSE_INITIAL_BYTES_LENGTH: usize = 8;
+const NAME: &str = "Iggy";
+
+/// UDS client for interacting with the Iggy API from the same host.
+/// It requires a valid path to the socket file of the server.
+#[derive(Debug)]
+pub struct UdsClient {
+    stream: Mutex<Option<UdsConnectionStream>>,
+    pub(crate) config: Arc<UdsClientConfig>,
+    pub(crate) state: Mutex<ClientState>,
+    events: (Sender<DiagnosticEvent>, Receiver<DiagnosticEvent>),
+    connected_at: Mutex<Option<IggyTimestamp>>,
+    protocol_features: AtomicU32,
+}
+
+#[derive(Debug)]
+struct UdsConnectionStream {
+    reader: BufReader<OwnedReadHalf>,
+    writer: BufWriter<OwnedWriteHalf>,
+}
+
+impl UdsConnectionStream {
+    fn new(stream: UnixStream) -> Self {
+        let (reader, writer) = stream.into_split();
+        Self {
+            reader: BufReader::new(reader),
+            writer: BufWriter::new(writer),
+        }
+    }
+
+    async fn read(&mut self, buf: &mut [u8]) -> Result<usize, IggyError> {
+        self.reader.read_exact(buf).await.map_err(|error| {
+            error!("Failed to read data from the UDS connection: {error}");
+            IggyError::UdsError
+        })
+    }
+
+    async fn write(&mut self, buf: &[u8]) -> Result<(), IggyError> {
+        self.writer.write_all(buf).await.map_err(|error| {
+            error!("Failed to write data to the UDS connection: {error}");
+            IggyError::UdsError
+        })
+    }
+
+    async fn flush(&mut self) -> Result<(), IggyError> {
+        self.writer.flush().await.map_err(|error| {
+            error!("Failed to flush data to the UDS connection: {error}");
+            IggyError::UdsError
+        })
+    }
+
+    async fn shutdown(&mut self) -> Result<(), IggyError> {
+        self.writer.shutdown().await.map_err(|error| {
+            error!("Failed to shutdown the UDS connection: {error}");
+            IggyError::UdsError
+        })
+    }
+}
+
+impl Default for UdsClient {
+    fn default() -> Self {
+        UdsClient::create(Arc::new(UdsClientConfig::default())).unwrap()
+    }
+}
+
+#[async_trait]
+impl Client for UdsClient {
+    async fn connect(&self) -> Result<(), IggyError> {
+        UdsClient::connect(self).await
+    }
+
+    async fn disconnect(&self) -> Result<(), IggyError> {
+        UdsClient::disconnect(self).await
+    }
+
+    async fn shutdown(&self) -> Result<(), IggyError> {
+        UdsClient::shutdown(self).await
+    }
+
+    async fn subscribe_events(&self) -> Receiver<DiagnosticEvent> {
+        self.events.1.clone()
+    }
+}
+
+#[async_trait]
+impl BinaryTransport for UdsClient {
+    async fn get_state(&self) -> ClientState {
+        *self.state.lock().await
+    }
+
+    async fn set_state(&self, state: ClientState) {
+        *self.state.lock().await = state;
+    }
+
+    async fn send_with_response<T: Command>(&self, command: &T) -> Result<Bytes, IggyError> {
+        command.validate()?;
+        self.send_raw_with_response(command.code(), command.to_bytes())
+            .await
+    }
+
+    async fn send_raw_with_response(&self, code: u32, payload: Bytes) -> Result<Bytes, IggyError> {
+        let result = self.send_raw(code, payload.clone()).await;
+        if result.is_ok() {
+            return result;
+        }
+
+        let error = result.unwrap_err();
+        if !matches!(
+            error,
+            IggyError::Disconnected
+                | IggyError::EmptyResponse
+                | IggyError::Unauthenticated
+                | IggyError::StaleClient
+        ) {
+            return Err(error);
+        }
+
+        if !self.config.reconnection.enabled {
+            return Err(IggyError::Disconnected);
+        }
+
+        self.disconnect().await?;
+        info!(
+            "Reconnecting to the server via socket: {}...",
+            self.config.socket_path
+        );
+        self.connect().await?;
+        self.send_raw(code, payload).await
+    }
+
+    async fn publish_event(&self, event: DiagnosticEvent) {
+        if let Err(error) = self.events.0.broadcast(event).await {
+            error!("Failed to send a UDS diagnostic event: {error}");
+        }
+    }
+
+    fn get_heartbeat_interval(&self) -> IggyDuration {
+        self.config.heartbeat_interval
+    }
+
+    fn get_protocol_features(&self) -> Handshake {
+        Handshake::from_flags(self.protocol_features.load(Ordering::SeqCst))
+    }
+}
+
+impl BinaryClient for UdsClient {}
+
+#[async_trait]
+impl service::Connection for UdsClient {
+    async fn connect(&self) -> Result<(), IggyError> {
+        UdsClient::connect(self).await
+    }
+
+    async fn disconnect(&self) -> Result<(), IggyError> {
+        UdsClient::disconnect(self).await
+    }
+
+    async fn shutdown(&self) -> Result<(), IggyError> {
+        UdsClient::shutdown(self).await
+    }
+
+    async fn get_state(&self) -> ClientState {
+        BinaryTransport::get_state(self).await
+    }
+
+    async fn set_state(&self, state: ClientState) {
+        BinaryTransport::set_state(self, state).await
+    }
+
+    async fn publish_event(&self, event: DiagnosticEvent) {
+        BinaryTransport::publish_event(self, event).await
+    }
+
+    async fn subscribe_events(&self) -> Receiver<DiagnosticEvent> {
+        self.events.1.clone()
+    }
+
+    fn get_heartbeat_interval(&self) -> IggyDuration {
+        BinaryTransport::get_heartbeat_interval(self)
+    }
+
+    fn get_protocol_features(&self) -> Handshake {
+        BinaryTransport::get_protocol_features(self)
+    }
+}
+
+impl UdsClient {
+    /// Create a new UDS client for the provided socket path.
+    pub fn new(
+        socket_path: &str,
+        auto_sign_in: AutoLogin,
+        heartbeat_interval: IggyDuration,
+    ) -> Result<Self, IggyError> {
+        Self::create(Arc::new(UdsClientConfig {
+            heartbeat_interval,
+            socket_path: socket_path.to_string(),
+            auto_login: auto_sign_in,
+            ..Default::default()
+        }))
+    }
+
+    /// Create a new UDS client based on the provided configuration.
+    pub fn create(config: Arc<UdsClientConfig>) -> Result<Self, IggyError> {
+        Ok(Self {
+            config,
+            stream: Mutex::new(None),
+            state: Mutex::new(ClientState::Disconnected),
+            events: broadcast(1000),
+            connected_at: Mutex::new(None),
+            protocol_features: AtomicU32::new(0),
+        })
+    }
+
+    /// Negotiates the optional features of the binary protocol with the server, falling back to the initial version
+    /// of the protocol if the server doesn't support them, so that the client can still connect to the older servers.
+    async fn negotiate_protocol_features(&self) {
+        self.protocol_features.store(0, Ordering::SeqCst);
+        let handshake = Handshake {
+            resource_metadata: true,
+            remaining_messages: true,
+            message_id_scheme: true,
+            consumer_group_rebalances: true,
+            stream_quota: true,
+            cleanup_policy: true,
+            message_gaps: true,
+            consumer_group_assignment: true,
+            allowed_producers: true,
+            offset_recovery: true,
+            dead_letter: true,
+            visibility_timeout: true,
+            throttle_time: true,
+            topic_deletion_time: true,
+            max_segments: true,
+            partition_recovery: true,
+            topic_config: true,
+            message_sizes: true,
+            token_scope: true,
+            token_last_used: true,
+            ..Default::default()
+        };
+        match self
+            .send_raw(handshake.code(), handshake.to_bytes())
+            .await
+            .and_then(Handshake::from_bytes)
+        {
+            Ok(accepted) => {
+                self.protocol_features
+                    .store(accepted.as_flags(), Ordering::SeqCst);
+            }
+            Err(error) => {
+                warn!("{NAME} client cannot negotiate the binary protocol features, the initial version of the protocol will be used. {error}");
+            }
+        }
+    }
+
+    /// The server signs in the connections of its trusted user on its own,
+    /// in which case the client is already authenticated without logging in.
+    async fn resume_trusted_session(&self) -> bool {
+        let get_me = GetMe {};
+        let Ok(response) = self.send_raw(get_me.code(), get_me.to_bytes()).await else {
+            return false;
+        };
+        match mapper::map_client(response) {
+            Ok(client) if client.user_id.is_some() => {
+                self.set_state(ClientState::Authenticated).await;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    async fn handle_response(
+        &self,
+        status: u32,
+        length: u32,
+        stream: &mut UdsConnectionStream,
+    ) -> Result<Bytes, IggyError> {
+        if status != 0 {
+            error!(
+                "Received an invalid response with status: {} ({}).",
+                status,
+                IggyError::from_code_as_string(status),
+            );
+            return Err(IggyError::from_code(status));
+        }
+
+        trace!("Status: OK. Response length: {}", length);
+        if length <= 1 {
+            return Ok(Bytes::new());
+        }
+
+        let mut response_buffer = BytesMut::with_capacity(length as usize);
+        response_buffer.put_bytes(0, length as usize);
+        stream.read(&mut response_buffer).await?;
+        Ok(response_buffer.freeze())
+    }
+
+    async fn connect(&self) -> Result<(), IggyError> {
+        match self.get_state().await {
+            ClientState::Shutdown => {
+                trace!("Cannot connect. Client is shutdown.");
+                return Err(IggyError::ClientShutdown);
+            }
+            ClientState::Connected | ClientState::Authenticating | ClientState::Authenticated => {
+                trace!("Client is already connected.");
+                return Ok(());
+            }
+            ClientState::Connecting => {
+                trace!("Client is already connecting.");
+                return Ok(());
+            }
+            _ => {}
+        }
+
+        self.set_state(ClientState::Connecting).await;
+        if let Some(connected_at) = self.connected_at.lock().await.as_ref() {
+            let now = IggyTimestamp::now();
+            let elapsed = now.as_micros() - connected_at.as_micros();
+            let interval = self.config.reconnection.reestablish_after.as_micros();
+            if elapsed < interval {
+                let remaining = IggyDuration::from(interval - elapsed);
+                info!("Trying to connect to the server in: {remaining}",);
+                sleep(remaining.get_duration()).await;
+            }
+        }
+
+        let socket_path = &self.config.socket_path;
+        let mut retry_count = 0;
+        let stream = loop {
+            info!("{NAME} client is connecting to server via socket: {socket_path}...");
+            match UnixStream::connect(socket_path).await {
+                Ok(stream) => break stream,
+                Err(error) => {
+                    error!("Failed to connect to server via socket: {socket_path}. {error}");
+                    if !self.config.reconnection.enabled {
+                        warn!("Automatic reconnection is disabled.");
+                        self.set_state(ClientState::Disconnected).await;
+                        return Err(IggyError::CannotEstablishConnection);
+                    }
+
+                    let unlimited_retries = self.config.reconnection.max_retries.is_none();
+                    let max_retries = self.config.reconnection.max_retries.unwrap_or_default();
+                    let max_retries_str =
+                        if let Some(max_retries) = self.config.reconnection.max_retries {
+                            max_retries.to_string()
+                        } else {
+                            "unlimited".to_string()
+                        };
+
+                    let interval_str = self.config.reconnection.interval.as_human_time_string();
+                    if unlimited_retries || retry_count < max_retries {
+                        retry_count += 1;
+                        info!(
+                            "Retrying to connect to server via socket ({retry_count}/{max_retries_str}): {socket_path} in: {interval_str}",
+                        );
+                        sleep(self.config.reconnection.interval.get_duration()).await;
+                        continue;
+                    }
+
+                    self.set_state(ClientState::Disconnected).await;
+                    self.publish_event(DiagnosticEvent::Disconnected).await;
+                    return Err(IggyError::CannotEstablishConnection);
+                }
+            }
+        };
+
+        let now = IggyTimestamp::now();
+        info!("{NAME} client has connected to server via socket: {socket_path} at: {now}");
+        self.stream
+            .lock()
+            .await
+            .replace(UdsConnectionStream::new(stream));
+        self.set_state(ClientState::Connected).await;
+        self.connected_at.lock().await.replace(now);
+        self.publish_event(DiagnosticEvent::Connected).await;
+        self.negotiate_protocol_features().await;
+        match &self.config.auto_login {
+            AutoLogin::Disabled => {
+                if self.resume_trusted_session().await {
+                    info!(
+                        "{NAME} UDS client has been signed in by the server as the trusted user."
+                    );
+                } else {
+                    info!("Automatic sign-in is disabled.");
+                }
+                Ok(())
+            }
+            AutoLogin::Enabled(credentials) => {
+                info!("{NAME} UDS client is signing in...");
+                self.set_state(ClientState::Authenticating).await;
+                match credentials {
+                    Credentials::UsernamePassword(username, password) => {
+                        self.login_user(username, password).await?;
+                        info!("{NAME} UDS client has signed in with the user credentials, username: {username}",);
+                        Ok(())
+                    }
+                    Credentials::PersonalAccessToken(token) => {
+                        self.login_with_personal_access_token(token).await?;
+                        info!("{NAME} UDS client has signed in with a personal access token.",);
+                        Ok(())
+                    }
+                }
+            }
+        }
+    }
+
+    async fn disconnect(&self) -> Result<(), IggyError> {
+        if self.get_state().await == ClientState::Disconnected {
+            return Ok(());
+        }
+
+        info!("{NAME} UDS client is disconnecting from server...");
+        self.set_state(ClientState::Disconnected).await;
+        self.stream.lock().await.take();
+        self.publish_event(DiagnosticEvent::Disconnected).await;
+        let now = IggyTimestamp::now();
+        info!("{NAME} UDS client has disconnected from server at: {now}.");
+        Ok(())
+    }
+
+    async fn shutdown(&self) -> Result<(), IggyError> {
+        if self.get_state().await == ClientState::Shutdown {
+            return Ok(());
+        }
+
+        info!("Shutting down the {NAME} UDS client...");
+        let stream = self.stream.lock().await.take();
+        if let Some(mut stream) = stream {
+            stream.shutdown().await?;
+        }
+        self.set_state(ClientState::Shutdown).await;
+        self.publish_event(DiagnosticEvent::Shutdown).await;
+        info!("{NAME} UDS client has been shutdown.");
+        Ok(())
+    }
+
+    async fn send_raw(&self, code: u32, payload: Bytes) -> Result<Bytes, IggyError> {
+        match self.get_state().await {
+            ClientState::Shutdown => {
+                trace!("Cannot send data. Client is shutdown.");
+                return Err(IggyError::ClientShutdown);
+            }
+            ClientState::Disconnected | ClientState::Connecting => {
+                trace!("Cannot send data. Client is not connected.");
+                return Err(IggyError::NotConnected);
+            }
+            _ => {}
+        }
+
+        let mut stream = self.stream.lock().await;
+        if let Some(stream) = stream.as_mut() {
+            let payload_length = payload.len() + REQUEST_INITIAL_BYTES_LENGTH;
+            trace!("Sending a UDS request with code: {code}");
+            stream.write(&(payload_length as u32).to_le_bytes()).await?;
+            stream.write(&code.to_le_bytes()).await?;
+            stream.write(&payload).await?;
+            stream.flush().await?;
+            trace!("Sent a UDS request with code: {code}, waiting for a response...");
+
+            let mut response_buffer = [0u8; RESPONSE_INITIAL_BYTES_LENGTH];
+            let read_bytes = stream.read(&mut response_buffer).await.map_err(|error| {
+                error!("Failed to read response for UDS request with code: {code}: {error}");
+                IggyError::Disconnected
+            })?;
+
+            if read_bytes != RESPONSE_INITIAL_BYTES_LENGTH {
+                error!("Received an invalid or empty response.");
+                return Err(IggyError::EmptyResponse);
+            }
+
+            let status = u32::from_le_bytes(
+                response_buffer[..4]
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            );
+            let length = u32::from_le_bytes(
+                response_buffer[4..]
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            );
+            return self.handle_response(status, length, stream).await;
+        }
+
+        error!("Cannot send data. Client is not connected.");
+        Err(IggyError::NotConnected)
+    }
+}
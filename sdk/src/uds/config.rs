@@ -0,0 +1,109 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::client::AutoLogin;
+use crate::utils::duration::IggyDuration;
+use std::str::FromStr;
+
+/// Configuration for the UDS client.
+#[derive(Debug, Clone)]
+pub struct UdsClientConfig {
+    /// The path to the socket file of the Iggy server.
+    pub socket_path: String,
+    /// Whether to automatically login user after establishing connection.
+    pub auto_login: AutoLogin,
+    /// Whether to automatically reconnect when disconnected.
+    pub reconnection: UdsClientReconnectionConfig,
+    /// Interval of heartbeats sent by the client
+    pub heartbeat_interval: IggyDuration,
+}
+
+#[derive(Debug, Clone)]
+pub struct UdsClientReconnectionConfig {
+    pub enabled: bool,
+    pub max_retries: Option<u32>,
+    pub interval: IggyDuration,
+    pub reestablish_after: IggyDuration,
+}
+
+impl Default for UdsClientConfig {
+    fn default() -> UdsClientConfig {
+        UdsClientConfig {
+            socket_path: "local_data/iggy.sock".to_string(),
+            heartbeat_interval: IggyDuration::from_str("5s").unwrap(),
+            auto_login: AutoLogin::Disabled,
+            reconnection: UdsClientReconnectionConfig::default(),
+        }
+    }
+}
+
+impl Default for UdsClientReconnectionConfig {
+    fn default() -> UdsClientReconnectionConfig {
+        UdsClientReconnectionConfig {
+            enabled: true,
+            max_retries: None,
+            interval: IggyDuration::from_str("1s").unwrap(),
+            reestablish_after: IggyDuration::from_str("5s").unwrap(),
+        }
+    }
+}
+
+/// Builder for the UDS client configuration.
+/// Allows configuring the UDS client with custom settings or using defaults:
+/// - `socket_path`: Default is "local_data/iggy.sock"
+/// - `auto_login`: Default is AutoLogin::Disabled.
+/// - `reconnection`: Default is enabled unlimited retries and 1 second interval.
+#[derive(Debug, Default)]
+pub struct UdsClientConfigBuilder {
+    config: UdsClientConfig,
+}
+
+impl UdsClientConfigBuilder {
+    pub fn new() -> Self {
+        UdsClientConfigBuilder::default()
+    }
+
+    /// Sets the path to the socket file of the server.
+    pub fn with_socket_path(mut self, socket_path: String) -> Self {
+        self.config.socket_path = socket_path;
+        self
+    }
+
+    /// Sets the auto sign in during connection.
+    pub fn with_auto_sign_in(mut self, auto_sign_in: AutoLogin) -> Self {
+        self.config.auto_login = auto_sign_in;
+        self
+    }
+
+    /// Sets the number of retries when connecting to the server.
+    pub fn with_reconnection_max_retries(mut self, max_retries: Option<u32>) -> Self {
+        self.config.reconnection.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the interval between retries when connecting to the server.
+    pub fn with_reconnection_interval(mut self, interval: IggyDuration) -> Self {
+        self.config.reconnection.interval = interval;
+        self
+    }
+
+    /// Builds the UDS client configuration.
+    pub fn build(self) -> UdsClientConfig {
+        self.config
+    }
+}
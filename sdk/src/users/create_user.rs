@@ -34,6 +34,7 @@ use std::str::from_utf8;
 /// - `password` - password of the user, must be between 3 and 100 characters long.
 /// - `status` - status of the user, can be either `active` or `inactive`.
 /// - `permissions` - optional permissions of the user. If not provided, user will have no permissions.
+/// - `namespace_id` - optional namespace of the user, the users created by the namespaced users always belong to the same namespace.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct CreateUser {
     /// Unique name of the user, must be between 3 and 50 characters long.
@@ -44,6 +45,9 @@ pub struct CreateUser {
     pub status: UserStatus,
     /// Optional permissions of the user. If not provided, user will have no permissions.
     pub permissions: Option<Permissions>,
+    /// Optional namespace of the user, if not provided the user belongs to the namespace of its creator (if any).
+    #[serde(default)]
+    pub namespace_id: Option<u32>,
 }
 
 impl Command for CreateUser {
@@ -59,6 +63,7 @@ impl Default for CreateUser {
             password: "secret".to_string(),
             status: UserStatus::Active,
             permissions: None,
+            namespace_id: None,
         }
     }
 }
@@ -79,6 +84,10 @@ impl Validatable<IggyError> for CreateUser {
             return Err(IggyError::InvalidPassword);
         }
 
+        if self.namespace_id == Some(0) {
+            return Err(IggyError::InvalidNamespaceId);
+        }
+
         Ok(())
     }
 }
@@ -102,6 +111,9 @@ impl BytesSerializable for CreateUser {
         } else {
             bytes.put_u8(0);
         }
+        if let Some(namespace_id) = self.namespace_id {
+            bytes.put_u32_le(namespace_id);
+        }
         bytes.freeze()
     }
 
@@ -144,9 +156,24 @@ impl BytesSerializable for CreateUser {
                     .map_err(|_| IggyError::InvalidNumberEncoding)?,
             );
             position += 4;
-            Some(Permissions::from_bytes(
+            let permissions = Permissions::from_bytes(
                 bytes.slice(position..position + permissions_length as usize),
-            )?)
+            )?;
+            position += permissions_length as usize;
+            Some(permissions)
+        } else {
+            None
+        };
+
+        let namespace_id = if bytes.len() > position {
+            let namespace_id = u32::from_le_bytes(
+                bytes
+                    .get(position..position + 4)
+                    .ok_or(IggyError::InvalidCommand)?
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            );
+            Some(namespace_id).filter(|namespace_id| *namespace_id > 0)
         } else {
             None
         };
@@ -156,6 +183,7 @@ impl BytesSerializable for CreateUser {
             password,
             status,
             permissions,
+            namespace_id,
         };
         Ok(command)
     }
@@ -172,7 +200,11 @@ impl Display for CreateUser {
             f,
             "{}|******|{}|{}",
             self.username, self.status, permissions
-        )
+        )?;
+        if let Some(namespace_id) = self.namespace_id {
+            write!(f, "|{namespace_id}")?;
+        }
+        Ok(())
     }
 }
 
@@ -202,6 +234,7 @@ mod tests {
                 },
                 streams: None,
             }),
+            namespace_id: None,
         };
 
         let bytes = command.to_bytes();
@@ -276,5 +309,17 @@ mod tests {
         assert_eq!(command.status, status);
         assert!(command.permissions.is_some());
         assert_eq!(command.permissions.unwrap(), permissions);
+        assert!(command.namespace_id.is_none());
+    }
+
+    #[test]
+    fn should_be_serialized_and_deserialized_with_namespace() {
+        let command = CreateUser {
+            namespace_id: Some(3),
+            ..CreateUser::default()
+        };
+
+        let deserialized = CreateUser::from_bytes(command.to_bytes()).unwrap();
+        assert_eq!(deserialized, command);
     }
 }
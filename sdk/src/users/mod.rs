@@ -22,7 +22,10 @@ pub mod defaults;
 pub mod delete_user;
 pub mod get_user;
 pub mod get_users;
+pub mod login_as;
 pub mod login_user;
 pub mod logout_user;
 pub mod update_permissions;
 pub mod update_user;
+pub mod update_user_quotas;
+pub mod user_quotas;
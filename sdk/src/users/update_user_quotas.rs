@@ -0,0 +1,131 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, UPDATE_USER_QUOTAS_CODE};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::users::user_quotas::UserQuotas;
+use crate::utils::sizeable::Sizeable;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `UpdateUserQuotas` command is used to override the server default quotas for a user.
+/// It has additional payload:
+/// - `user_id` - unique user ID (numeric or name).
+/// - `quotas` - the quotas overriding the server defaults, replacing the previous ones.
+///   The quotas left as `None` use the server defaults.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct UpdateUserQuotas {
+    /// Unique user ID (numeric or name).
+    #[serde(skip)]
+    pub user_id: Identifier,
+    /// The quotas overriding the server defaults.
+    #[serde(default)]
+    pub quotas: UserQuotas,
+}
+
+impl Command for UpdateUserQuotas {
+    fn code(&self) -> u32 {
+        UPDATE_USER_QUOTAS_CODE
+    }
+}
+
+impl Validatable<IggyError> for UpdateUserQuotas {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for UpdateUserQuotas {
+    fn to_bytes(&self) -> Bytes {
+        let user_id_bytes = self.user_id.to_bytes();
+        let mut bytes = BytesMut::with_capacity(user_id_bytes.len() + self.quotas.get_size_bytes());
+        bytes.put_slice(&user_id_bytes);
+        self.quotas.write_to_buffer(&mut bytes);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> std::result::Result<UpdateUserQuotas, IggyError> {
+        if bytes.len() < 26 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let user_id = Identifier::from_bytes(bytes.clone())?;
+        let position = user_id.get_size_bytes().as_bytes_usize();
+        let (quotas, read_bytes) = UserQuotas::from_bytes_at(&bytes, position)?;
+        if bytes.len() != position + read_bytes {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let command = UpdateUserQuotas { user_id, quotas };
+        Ok(command)
+    }
+}
+
+impl Display for UpdateUserQuotas {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}|{}", self.user_id, self.quotas)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::byte_size::IggyByteSize;
+
+    #[test]
+    fn should_be_serialized_and_deserialized_from_bytes() {
+        let command = UpdateUserQuotas {
+            user_id: Identifier::named("producer").unwrap(),
+            quotas: UserQuotas {
+                produce_bytes_per_second: Some(IggyByteSize::from(10_000_000)),
+                consume_bytes_per_second: Some(IggyByteSize::from(0)),
+                requests_per_second: None,
+            },
+        };
+
+        let bytes = command.to_bytes();
+        let deserialized = UpdateUserQuotas::from_bytes(bytes).unwrap();
+        assert_eq!(deserialized, command);
+
+        let command = UpdateUserQuotas {
+            user_id: Identifier::numeric(1).unwrap(),
+            quotas: UserQuotas::default(),
+        };
+        let deserialized = UpdateUserQuotas::from_bytes(command.to_bytes()).unwrap();
+        assert!(deserialized.quotas.is_empty());
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_truncated_bytes() {
+        let command = UpdateUserQuotas {
+            user_id: Identifier::numeric(1).unwrap(),
+            quotas: UserQuotas {
+                requests_per_second: Some(100),
+                ..Default::default()
+            },
+        };
+
+        let bytes = command.to_bytes();
+        let command = UpdateUserQuotas::from_bytes(bytes.slice(..bytes.len() - 1));
+        assert!(command.is_err());
+    }
+}
@@ -0,0 +1,138 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::error::IggyError;
+use crate::utils::byte_size::IggyByteSize;
+use bytes::{BufMut, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+const NOT_OVERRIDDEN: u8 = 0;
+const OVERRIDDEN: u8 = 1;
+
+/// `UserQuotas` represents the quotas of a single user, overriding the server defaults (`system.quotas` and `system.fetch_quotas`).
+/// Each quota left as `None` uses the server default, while `0` means unlimited:
+/// - `produce_bytes_per_second`: the maximum number of bytes sent by the user per second, across all its clients.
+/// - `consume_bytes_per_second`: the maximum number of bytes polled by the user per second, across all its consumers.
+/// - `requests_per_second`: the maximum number of requests sent by the user per second, across all its clients.
+///
+/// The user exceeding its quota is throttled, the responses are delayed and the polled messages report the throttle time.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct UserQuotas {
+    /// The maximum number of bytes sent per second.
+    #[serde(default)]
+    pub produce_bytes_per_second: Option<IggyByteSize>,
+    /// The maximum number of bytes polled per second.
+    #[serde(default)]
+    pub consume_bytes_per_second: Option<IggyByteSize>,
+    /// The maximum number of requests per second.
+    #[serde(default)]
+    pub requests_per_second: Option<u32>,
+}
+
+impl UserQuotas {
+    /// Returns true if none of the server defaults is overridden.
+    pub fn is_empty(&self) -> bool {
+        *self == UserQuotas::default()
+    }
+
+    /// Returns the size of the binary representation in bytes.
+    pub fn get_size_bytes(&self) -> usize {
+        1 + 8 + 1 + 8 + 1 + 4
+    }
+
+    /// Appends the binary representation to the provided buffer.
+    pub fn write_to_buffer(&self, bytes: &mut BytesMut) {
+        for bytes_per_second in [self.produce_bytes_per_second, self.consume_bytes_per_second] {
+            match bytes_per_second {
+                Some(bytes_per_second) => {
+                    bytes.put_u8(OVERRIDDEN);
+                    bytes.put_u64_le(bytes_per_second.as_bytes_u64());
+                }
+                None => {
+                    bytes.put_u8(NOT_OVERRIDDEN);
+                    bytes.put_u64_le(0);
+                }
+            }
+        }
+        bytes.put_u8(match self.requests_per_second {
+            Some(_) => OVERRIDDEN,
+            None => NOT_OVERRIDDEN,
+        });
+        bytes.put_u32_le(self.requests_per_second.unwrap_or_default());
+    }
+
+    /// Reads the quotas from the provided bytes starting at the given position.
+    /// Returns the quotas and the number of bytes read.
+    pub fn from_bytes_at(bytes: &[u8], position: usize) -> Result<(Self, usize), IggyError> {
+        let is_overridden = |position: usize| -> Result<bool, IggyError> {
+            match bytes.get(position) {
+                Some(&NOT_OVERRIDDEN) => Ok(false),
+                Some(&OVERRIDDEN) => Ok(true),
+                _ => Err(IggyError::InvalidCommand),
+            }
+        };
+        let read_bytes_per_second = |position: usize| -> Result<Option<IggyByteSize>, IggyError> {
+            let value = u64::from_le_bytes(
+                bytes
+                    .get(position + 1..position + 9)
+                    .ok_or(IggyError::InvalidCommand)?
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            );
+            Ok(is_overridden(position)?.then(|| IggyByteSize::from(value)))
+        };
+        let produce_bytes_per_second = read_bytes_per_second(position)?;
+        let consume_bytes_per_second = read_bytes_per_second(position + 9)?;
+        let requests_per_second = u32::from_le_bytes(
+            bytes
+                .get(position + 19..position + 23)
+                .ok_or(IggyError::InvalidCommand)?
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let requests_per_second = is_overridden(position + 18)?.then_some(requests_per_second);
+        let quotas = UserQuotas {
+            produce_bytes_per_second,
+            consume_bytes_per_second,
+            requests_per_second,
+        };
+        Ok((quotas, 23))
+    }
+}
+
+impl Display for UserQuotas {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let format_bytes_per_second = |bytes_per_second: Option<IggyByteSize>| {
+            bytes_per_second
+                .map(|bytes_per_second| bytes_per_second.as_human_string_with_zero_as_unlimited())
+                .unwrap_or_else(|| "default".to_string())
+        };
+        let requests_per_second = match self.requests_per_second {
+            Some(0) => "unlimited".to_string(),
+            Some(requests_per_second) => requests_per_second.to_string(),
+            None => "default".to_string(),
+        };
+        write!(
+            f,
+            "{}|{}|{requests_per_second}",
+            format_bytes_per_second(self.produce_bytes_per_second),
+            format_bytes_per_second(self.consume_bytes_per_second)
+        )
+    }
+}
@@ -19,3 +19,34 @@
 pub fn calculate(data: &[u8]) -> u32 {
     crc32fast::hash(data)
 }
+
+/// Calculates the CRC32C (Castagnoli) checksum, using the hardware instructions if the CPU supports them.
+pub fn calculate_crc32c(data: &[u8]) -> u32 {
+    crc32c::crc32c(data)
+}
+
+/// Calculates the CRC32C (Castagnoli) checksum of the binary protocol frame consisting of the given parts.
+pub fn calculate_frame(parts: &[&[u8]]) -> u32 {
+    parts
+        .iter()
+        .fold(0, |checksum, part| crc32c::crc32c_append(checksum, part))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frame_checksum_should_be_crc32c() {
+        assert_eq!(calculate_frame(&[b"123456789"]), 0xE306_9283);
+        assert_eq!(calculate_crc32c(b"123456789"), 0xE306_9283);
+    }
+
+    #[test]
+    fn frame_checksum_should_not_depend_on_parts_split() {
+        assert_eq!(
+            calculate_frame(&[b"1234", b"", b"56789"]),
+            calculate_frame(&[b"123456789"])
+        );
+    }
+}
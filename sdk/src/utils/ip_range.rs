@@ -0,0 +1,150 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::error::IggyError;
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+use std::fmt::{Display, Formatter};
+use std::net::IpAddr;
+use std::str::FromStr;
+
+/// `IpRange` represents a range of IP addresses in the CIDR notation, e.g. `10.0.0.0/8` or `fd00::/8`.
+/// A single IP address without the prefix length is treated as a range containing only that address.
+/// It's serialized and deserialized as a string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, SerializeDisplay, DeserializeFromStr)]
+pub struct IpRange {
+    address: IpAddr,
+    prefix_length: u8,
+}
+
+impl IpRange {
+    /// Creates a new IP range, the host bits of the address are cleared.
+    pub fn new(address: IpAddr, prefix_length: u8) -> Result<Self, IggyError> {
+        let max_prefix_length = Self::max_prefix_length(&address);
+        if prefix_length > max_prefix_length {
+            return Err(IggyError::InvalidIpRange(format!(
+                "{address}/{prefix_length}"
+            )));
+        }
+
+        let address = match address {
+            IpAddr::V4(address) => {
+                let mask = u32::MAX.checked_shl(32 - prefix_length as u32).unwrap_or(0);
+                IpAddr::V4((u32::from(address) & mask).into())
+            }
+            IpAddr::V6(address) => {
+                let mask = u128::MAX
+                    .checked_shl(128 - prefix_length as u32)
+                    .unwrap_or(0);
+                IpAddr::V6((u128::from(address) & mask).into())
+            }
+        };
+        Ok(Self {
+            address,
+            prefix_length,
+        })
+    }
+
+    /// Returns the network address of the range.
+    pub fn address(&self) -> IpAddr {
+        self.address
+    }
+
+    /// Returns the prefix length of the range.
+    pub fn prefix_length(&self) -> u8 {
+        self.prefix_length
+    }
+
+    /// Checks if the IP address belongs to the range.
+    /// IPv4-mapped IPv6 addresses are matched against the IPv4 ranges.
+    pub fn contains(&self, address: &IpAddr) -> bool {
+        match Self::new(address.to_canonical(), self.prefix_length) {
+            Ok(range) => range.address == self.address,
+            Err(_) => false,
+        }
+    }
+
+    fn max_prefix_length(address: &IpAddr) -> u8 {
+        match address {
+            IpAddr::V4(_) => 32,
+            IpAddr::V6(_) => 128,
+        }
+    }
+}
+
+impl FromStr for IpRange {
+    type Err = IggyError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value = value.trim();
+        let (address, prefix_length) = match value.split_once('/') {
+            Some((address, prefix_length)) => (address, Some(prefix_length)),
+            None => (value, None),
+        };
+        let address =
+            IpAddr::from_str(address).map_err(|_| IggyError::InvalidIpRange(value.to_owned()))?;
+        let prefix_length = match prefix_length {
+            Some(prefix_length) => prefix_length
+                .parse::<u8>()
+                .map_err(|_| IggyError::InvalidIpRange(value.to_owned()))?,
+            None => Self::max_prefix_length(&address),
+        };
+        Self::new(address, prefix_length)
+    }
+}
+
+impl Display for IpRange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.address, self.prefix_length)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_contain_addresses_from_ipv4_range() {
+        let range = IpRange::from_str("10.1.2.3/16").unwrap();
+        assert_eq!(range.to_string(), "10.1.0.0/16");
+        assert!(range.contains(&"10.1.255.1".parse().unwrap()));
+        assert!(range.contains(&"::ffff:10.1.0.7".parse().unwrap()));
+        assert!(!range.contains(&"10.2.0.1".parse().unwrap()));
+        assert!(!range.contains(&"fd00::1".parse().unwrap()));
+    }
+
+    #[test]
+    fn should_treat_single_address_as_host_range() {
+        let range = IpRange::from_str("fd00::1").unwrap();
+        assert_eq!(range.prefix_length(), 128);
+        assert!(range.contains(&"fd00::1".parse().unwrap()));
+        assert!(!range.contains(&"fd00::2".parse().unwrap()));
+    }
+
+    #[test]
+    fn should_match_any_address_given_zero_prefix_length() {
+        let range = IpRange::from_str("0.0.0.0/0").unwrap();
+        assert!(range.contains(&"192.168.1.1".parse().unwrap()));
+    }
+
+    #[test]
+    fn should_fail_to_parse_invalid_ranges() {
+        assert!(IpRange::from_str("10.0.0.0/33").is_err());
+        assert!(IpRange::from_str("10.0.0/8").is_err());
+        assert!(IpRange::from_str("10.0.0.0/x").is_err());
+    }
+}
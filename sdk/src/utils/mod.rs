@@ -21,6 +21,7 @@ pub mod checksum;
 pub mod crypto;
 pub mod duration;
 pub mod expiry;
+pub mod ip_range;
 pub mod personal_access_token_expiry;
 pub mod sizeable;
 pub mod text;
@@ -0,0 +1,793 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::archiver::http::{read_chunk, RetryPolicy, UPLOAD_CHUNK_SIZE};
+use crate::archiver::{Archiver, COMPONENT};
+use crate::configs::server::AzureArchiverConfig;
+use crate::server_error::ArchiverError;
+use chrono::Utc;
+use derive_more::Display;
+use error_set::ErrContext;
+use openssl::base64;
+use reqwest::header::{HeaderValue, AUTHORIZATION, CONTENT_LENGTH, CONTENT_TYPE};
+use reqwest::{Request, RequestBuilder, Response, StatusCode, Url};
+use ring::hmac;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+use std::collections::BTreeMap;
+use std::fmt::{Debug, Formatter};
+use std::path::Path;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+use tokio::fs;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::Mutex;
+use tracing::{debug, error, info};
+
+const API_VERSION: &str = "2021-08-06";
+const DATE_FORMAT: &str = "%a, %d %b %Y %H:%M:%S GMT";
+const IMDS_TOKEN_URL: &str = "http://169.254.169.254/metadata/identity/oauth2/token";
+const IMDS_API_VERSION: &str = "2018-02-01";
+const STORAGE_RESOURCE: &str = "https://storage.azure.com/";
+const DEFAULT_ENDPOINT_SUFFIX: &str = "core.windows.net";
+/// The access token is refreshed slightly before it expires, so that it doesn't expire in flight.
+const TOKEN_EXPIRY_MARGIN: Duration = Duration::from_secs(60);
+/// The standard headers included (in this order) in the string to sign by the Shared Key authorization.
+const SIGNED_HEADERS: [&str; 11] = [
+    "content-encoding",
+    "content-language",
+    "content-length",
+    "content-md5",
+    "content-type",
+    "date",
+    "if-modified-since",
+    "if-match",
+    "if-none-match",
+    "if-unmodified-since",
+    "range",
+];
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Display, Copy, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum AzureAuthKind {
+    #[default]
+    #[display("connection_string")]
+    ConnectionString,
+    #[display("managed_identity")]
+    ManagedIdentity,
+}
+
+impl FromStr for AzureAuthKind {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "connection_string" => Ok(AzureAuthKind::ConnectionString),
+            "managed_identity" => Ok(AzureAuthKind::ManagedIdentity),
+            _ => Err(format!("Unknown Azure auth kind: {}", s)),
+        }
+    }
+}
+
+enum AzureCredentials {
+    /// The requests are signed with the key of the storage account.
+    SharedKey {
+        account_name: String,
+        key: hmac::Key,
+    },
+    /// The shared access signature is appended to the query of the requests.
+    SharedAccessSignature(String),
+    /// The tokens are obtained from the instance metadata service of the environment the server runs in.
+    ManagedIdentity { client_id: Option<String> },
+}
+
+impl Debug for AzureCredentials {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AzureCredentials::SharedKey { account_name, .. } => {
+                write!(f, "SharedKey({account_name})")
+            }
+            AzureCredentials::SharedAccessSignature(_) => write!(f, "SharedAccessSignature"),
+            AzureCredentials::ManagedIdentity { client_id } => {
+                write!(
+                    f,
+                    "ManagedIdentity({})",
+                    client_id.as_deref().unwrap_or("system")
+                )
+            }
+        }
+    }
+}
+
+/// The settings of the storage connection string relevant to the Blob service.
+#[derive(Debug, Default, PartialEq)]
+struct ConnectionString {
+    protocol: Option<String>,
+    account_name: Option<String>,
+    account_key: Option<String>,
+    endpoint_suffix: Option<String>,
+    blob_endpoint: Option<String>,
+    shared_access_signature: Option<String>,
+}
+
+impl ConnectionString {
+    fn parse(value: &str) -> Result<Self, String> {
+        let mut connection_string = ConnectionString::default();
+        for setting in value.split(';').map(str::trim).filter(|s| !s.is_empty()) {
+            let Some((key, value)) = setting.split_once('=') else {
+                return Err(format!("invalid setting: {setting}"));
+            };
+            let value = Some(value.to_owned());
+            match key {
+                "DefaultEndpointsProtocol" => connection_string.protocol = value,
+                "AccountName" => connection_string.account_name = value,
+                "AccountKey" => connection_string.account_key = value,
+                "EndpointSuffix" => connection_string.endpoint_suffix = value,
+                "BlobEndpoint" => connection_string.blob_endpoint = value,
+                "SharedAccessSignature" => connection_string.shared_access_signature = value,
+                _ => {}
+            }
+        }
+        Ok(connection_string)
+    }
+
+    fn get_blob_endpoint(&self) -> Option<String> {
+        if let Some(blob_endpoint) = &self.blob_endpoint {
+            return Some(blob_endpoint.clone());
+        }
+
+        self.account_name.as_ref().map(|account_name| {
+            format!(
+                "{}://{account_name}.blob.{}",
+                self.protocol.as_deref().unwrap_or("https"),
+                self.endpoint_suffix
+                    .as_deref()
+                    .unwrap_or(DEFAULT_ENDPOINT_SUFFIX)
+            )
+        })
+    }
+}
+
+#[serde_as]
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    #[serde_as(as = "DisplayFromStr")]
+    expires_in: u64,
+}
+
+#[derive(Debug)]
+struct AccessToken {
+    value: String,
+    expires_at: Instant,
+}
+
+#[derive(Debug)]
+pub struct AzureArchiver {
+    client: reqwest::Client,
+    endpoint: Url,
+    container: String,
+    prefix: String,
+    credentials: AzureCredentials,
+    token: Mutex<Option<AccessToken>>,
+    retry_policy: RetryPolicy,
+}
+
+impl AzureArchiver {
+    pub fn new(config: AzureArchiverConfig) -> Result<Self, ArchiverError> {
+        // The provider might be already installed e.g. by the other component.
+        let _ = rustls::crypto::ring::default_provider().install_default();
+        let client = reqwest::Client::builder().build().map_err(|error| {
+            error!("{COMPONENT} (error: {error}) - failed to create Azure client");
+            ArchiverError::CannotInitializeAzureArchiver
+        })?;
+
+        let (credentials, default_endpoint) = match config.auth {
+            AzureAuthKind::ConnectionString => {
+                let connection_string = ConnectionString::parse(
+                    config.connection_string.as_deref().unwrap_or_default(),
+                )
+                .map_err(|error| {
+                    error!("{COMPONENT} - invalid Azure connection string: {error}");
+                    ArchiverError::InvalidAzureCredentials
+                })?;
+                let credentials = match (
+                    &connection_string.account_name,
+                    &connection_string.account_key,
+                    &connection_string.shared_access_signature,
+                ) {
+                    (_, _, Some(signature)) => AzureCredentials::SharedAccessSignature(
+                        signature.trim_start_matches('?').to_owned(),
+                    ),
+                    (Some(account_name), Some(account_key), None) => {
+                        let key = base64::decode_block(account_key).map_err(|error| {
+                            error!("{COMPONENT} (error: {error}) - invalid key of Azure storage account: {account_name}");
+                            ArchiverError::InvalidAzureCredentials
+                        })?;
+                        AzureCredentials::SharedKey {
+                            account_name: account_name.clone(),
+                            key: hmac::Key::new(hmac::HMAC_SHA256, &key),
+                        }
+                    }
+                    _ => {
+                        error!("{COMPONENT} - Azure connection string must contain either the account name and key or the shared access signature");
+                        return Err(ArchiverError::InvalidAzureCredentials);
+                    }
+                };
+                (credentials, connection_string.get_blob_endpoint())
+            }
+            AzureAuthKind::ManagedIdentity => (
+                AzureCredentials::ManagedIdentity {
+                    client_id: config.client_id.filter(|client_id| !client_id.is_empty()),
+                },
+                config
+                    .account_name
+                    .as_deref()
+                    .filter(|account_name| !account_name.is_empty())
+                    .map(|account_name| {
+                        format!("https://{account_name}.blob.{DEFAULT_ENDPOINT_SUFFIX}")
+                    }),
+            ),
+        };
+
+        let endpoint = config
+            .endpoint
+            .filter(|endpoint| !endpoint.is_empty())
+            .or(default_endpoint)
+            .unwrap_or_default();
+        let endpoint = Url::parse(&endpoint)
+            .ok()
+            .filter(|endpoint| !endpoint.cannot_be_a_base())
+            .ok_or_else(|| {
+                error!("{COMPONENT} - invalid Azure Blob Storage endpoint: {endpoint}");
+                ArchiverError::CannotInitializeAzureArchiver
+            })?;
+
+        let mut prefix = config
+            .prefix
+            .unwrap_or_default()
+            .trim_start_matches('/')
+            .to_owned();
+        if !prefix.is_empty() && !prefix.ends_with('/') {
+            prefix.push('/');
+        }
+
+        Ok(Self {
+            client,
+            endpoint,
+            container: config.container,
+            prefix,
+            credentials,
+            token: Mutex::new(None),
+            retry_policy: RetryPolicy::new(
+                "Azure",
+                config.max_retries,
+                config.retry_initial_backoff.get_duration(),
+                config.retry_max_backoff.get_duration(),
+            ),
+        })
+    }
+
+    fn get_blob_name(&self, file: &str, base_directory: Option<String>) -> String {
+        let base_directory = base_directory.as_deref().unwrap_or_default();
+        let path = Path::new(&base_directory).join(file);
+        let path = path.to_str().unwrap_or_default().trim_start_matches('/');
+        format!("{}{path}", self.prefix)
+    }
+
+    fn get_container_url(&self) -> Url {
+        let mut url = self.endpoint.clone();
+        if let Ok(mut path_segments) = url.path_segments_mut() {
+            path_segments.pop_if_empty().push(&self.container);
+        }
+        url
+    }
+
+    /// Builds the URL of the blob, the slashes of the blob name are kept, so that it's listed as a virtual directory.
+    fn get_blob_url(&self, blob_name: &str) -> Url {
+        let mut url = self.get_container_url();
+        if let Ok(mut path_segments) = url.path_segments_mut() {
+            path_segments.extend(blob_name.split('/'));
+        }
+        url
+    }
+
+    async fn get_access_token(
+        &self,
+        client_id: Option<&str>,
+        refresh: bool,
+    ) -> Result<String, String> {
+        let mut token = self.token.lock().await;
+        if !refresh {
+            if let Some(token) = token
+                .as_ref()
+                .filter(|token| token.expires_at > Instant::now() + TOKEN_EXPIRY_MARGIN)
+            {
+                return Ok(token.value.clone());
+            }
+        }
+
+        let mut query = vec![
+            ("api-version", IMDS_API_VERSION),
+            ("resource", STORAGE_RESOURCE),
+        ];
+        if let Some(client_id) = client_id {
+            query.push(("client_id", client_id));
+        }
+        let response = self
+            .client
+            .get(IMDS_TOKEN_URL)
+            .query(&query)
+            .header("Metadata", "true")
+            .send()
+            .await
+            .map_err(|error| format!("failed to request access token: {error}"))?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(format!("access token request failed with status: {status}"));
+        }
+
+        let body = response
+            .bytes()
+            .await
+            .map_err(|error| format!("failed to read access token: {error}"))?;
+        let token_response = serde_json::from_slice::<TokenResponse>(&body)
+            .map_err(|error| format!("invalid access token response: {error}"))?;
+        debug!(
+            "Obtained Azure access token, expires in: {} s.",
+            token_response.expires_in
+        );
+        *token = Some(AccessToken {
+            value: token_response.access_token.clone(),
+            expires_at: Instant::now() + Duration::from_secs(token_response.expires_in),
+        });
+        Ok(token_response.access_token)
+    }
+
+    /// Builds the request and authorizes it using the configured credentials.
+    async fn authorize(
+        &self,
+        request: RequestBuilder,
+        refresh_token: bool,
+    ) -> Result<Request, String> {
+        let request = request
+            .header("x-ms-version", API_VERSION)
+            .header("x-ms-date", Utc::now().format(DATE_FORMAT).to_string());
+        let request = match &self.credentials {
+            AzureCredentials::ManagedIdentity { client_id } => {
+                let token = self
+                    .get_access_token(client_id.as_deref(), refresh_token)
+                    .await?;
+                request.bearer_auth(token)
+            }
+            _ => request,
+        };
+        let mut request = request
+            .build()
+            .map_err(|error| format!("failed to build request: {error}"))?;
+
+        match &self.credentials {
+            AzureCredentials::SharedKey { account_name, key } => {
+                let string_to_sign = get_string_to_sign(account_name, &request);
+                let signature =
+                    base64::encode_block(hmac::sign(key, string_to_sign.as_bytes()).as_ref());
+                let authorization =
+                    HeaderValue::from_str(&format!("SharedKey {account_name}:{signature}"))
+                        .map_err(|error| format!("invalid authorization header: {error}"))?;
+                request.headers_mut().insert(AUTHORIZATION, authorization);
+            }
+            AzureCredentials::SharedAccessSignature(signature) => {
+                let url = request.url_mut();
+                let query = match url.query() {
+                    Some(query) => format!("{query}&{signature}"),
+                    None => signature.clone(),
+                };
+                url.set_query(Some(&query));
+            }
+            AzureCredentials::ManagedIdentity { .. } => {}
+        }
+        Ok(request)
+    }
+
+    /// Sends the authorized request, retrying it as described by the `RetryPolicy`.
+    /// The access token is refreshed on retry only for the managed identity, as the other credentials don't expire.
+    async fn send<F>(&self, build_request: F) -> Result<Response, String>
+    where
+        F: Fn(&reqwest::Client) -> RequestBuilder,
+    {
+        let build_request = &build_request;
+        let can_refresh_token =
+            matches!(self.credentials, AzureCredentials::ManagedIdentity { .. });
+        self.retry_policy
+            .send(can_refresh_token, |refresh_token| async move {
+                let request = self
+                    .authorize(build_request(&self.client), refresh_token)
+                    .await?;
+                self.client
+                    .execute(request)
+                    .await
+                    .map_err(|error| error.to_string())
+            })
+            .await
+    }
+
+    /// Uploads the file as the blocks committed once all of them are uploaded, so that only a single block of it
+    /// is kept in memory and the blob becomes visible only when it's complete.
+    async fn upload(&self, path: &str, url: &Url) -> Result<(), ArchiverError> {
+        let mut file = fs::File::open(path).await.with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to open file: {path} for archiving")
+        })?;
+        let mut block_ids = Vec::new();
+        loop {
+            let block = read_chunk(&mut file, UPLOAD_CHUNK_SIZE)
+                .await
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to read file: {path} for archiving"
+                    )
+                })?;
+            if block.is_empty() {
+                break;
+            }
+
+            let block_id = get_block_id(block_ids.len());
+            let response = self
+                .send(|client| {
+                    client
+                        .put(url.clone())
+                        .query(&[("comp", "block"), ("blockid", &block_id)])
+                        .body(block.clone())
+                })
+                .await;
+            check_upload_response(path, response)?;
+            block_ids.push(block_id);
+        }
+
+        let block_list = get_block_list(&block_ids);
+        let response = self
+            .send(|client| {
+                client
+                    .put(url.clone())
+                    .query(&[("comp", "blocklist")])
+                    .header("x-ms-blob-content-type", "application/octet-stream")
+                    .header(CONTENT_TYPE, "application/xml")
+                    .body(block_list.clone())
+            })
+            .await;
+        check_upload_response(path, response)
+    }
+}
+
+fn check_upload_response(
+    path: &str,
+    response: Result<Response, String>,
+) -> Result<(), ArchiverError> {
+    match response {
+        Ok(response) if response.status().is_success() => Ok(()),
+        Ok(response) => {
+            error!(
+                "Cannot archive file: {path} on Azure, received an invalid status code: {}.",
+                response.status()
+            );
+            Err(ArchiverError::CannotArchiveFile {
+                file_path: path.to_string(),
+            })
+        }
+        Err(error) => {
+            error!("Cannot archive file: {path} on Azure: {error}");
+            Err(ArchiverError::CannotArchiveFile {
+                file_path: path.to_string(),
+            })
+        }
+    }
+}
+
+/// Returns the Base64 encoded ID of the block, all the IDs of the blob must have the same length.
+fn get_block_id(index: usize) -> String {
+    base64::encode_block(format!("{index:08}").as_bytes())
+}
+
+/// Builds the body of the Put Block List request, committing the uploaded blocks in their order.
+fn get_block_list(block_ids: &[String]) -> String {
+    let mut block_list = String::from(r#"<?xml version="1.0" encoding="utf-8"?><BlockList>"#);
+    for block_id in block_ids {
+        block_list.push_str(&format!("<Latest>{block_id}</Latest>"));
+    }
+    block_list.push_str("</BlockList>");
+    block_list
+}
+
+/// Builds the string to sign of the Shared Key authorization, as described in
+/// https://learn.microsoft.com/rest/api/storageservices/authorize-with-shared-key
+fn get_string_to_sign(account_name: &str, request: &Request) -> String {
+    let headers = request.headers();
+    let mut string_to_sign = format!("{}\n", request.method());
+    for name in SIGNED_HEADERS {
+        let value = match name {
+            // The content length isn't set until the request is sent, and it's empty for no content.
+            "content-length" => request
+                .body()
+                .and_then(|body| body.as_bytes())
+                .map(|body| body.len())
+                .filter(|length| *length > 0)
+                .map(|length| length.to_string())
+                .or_else(|| {
+                    headers
+                        .get(CONTENT_LENGTH)
+                        .and_then(|value| value.to_str().ok())
+                        .filter(|value| *value != "0")
+                        .map(str::to_owned)
+                })
+                .unwrap_or_default(),
+            _ => headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .unwrap_or_default()
+                .to_owned(),
+        };
+        string_to_sign.push_str(&value);
+        string_to_sign.push('\n');
+    }
+
+    let ms_headers = headers
+        .iter()
+        .filter(|(name, _)| name.as_str().starts_with("x-ms-"))
+        .map(|(name, value)| (name.as_str(), value.to_str().unwrap_or_default().trim()))
+        .collect::<BTreeMap<_, _>>();
+    for (name, value) in ms_headers {
+        string_to_sign.push_str(&format!("{name}:{value}\n"));
+    }
+
+    string_to_sign.push_str(&format!("/{account_name}{}", request.url().path()));
+    let mut parameters = BTreeMap::<String, Vec<String>>::new();
+    for (name, value) in request.url().query_pairs() {
+        parameters
+            .entry(name.to_lowercase())
+            .or_default()
+            .push(value.into_owned());
+    }
+    for (name, mut values) in parameters {
+        values.sort();
+        string_to_sign.push_str(&format!("\n{name}:{}", values.join(",")));
+    }
+    string_to_sign
+}
+
+impl Archiver for AzureArchiver {
+    async fn init(&self) -> Result<(), ArchiverError> {
+        let url = self.get_container_url();
+        let response = self
+            .send(|client| {
+                client.get(url.clone()).query(&[
+                    ("restype", "container"),
+                    ("comp", "list"),
+                    ("maxresults", "1"),
+                    ("prefix", &self.prefix),
+                ])
+            })
+            .await;
+        match response {
+            Ok(response) if response.status().is_success() => {
+                info!(
+                    "Initialized Azure archiver for container: {}, prefix: {}.",
+                    self.container, self.prefix
+                );
+                Ok(())
+            }
+            Ok(response) => {
+                error!(
+                    "Cannot initialize Azure archiver, received an invalid status code: {}.",
+                    response.status()
+                );
+                Err(ArchiverError::CannotInitializeAzureArchiver)
+            }
+            Err(error) => {
+                error!("Cannot initialize Azure archiver: {error}");
+                Err(ArchiverError::CannotInitializeAzureArchiver)
+            }
+        }
+    }
+
+    async fn is_archived(
+        &self,
+        file: &str,
+        base_directory: Option<String>,
+    ) -> Result<bool, ArchiverError> {
+        debug!("Checking if file: {file} is archived on Azure.");
+        let url = self.get_blob_url(&self.get_blob_name(file, base_directory));
+        let response = self.send(|client| client.head(url.clone())).await;
+        match response {
+            Ok(response) if response.status() == StatusCode::OK => {
+                debug!("File: {file} is archived on Azure.");
+                Ok(true)
+            }
+            Ok(_) => {
+                debug!("File: {file} is not archived on Azure.");
+                Ok(false)
+            }
+            Err(error) => {
+                debug!("Cannot check if file: {file} is archived on Azure: {error}");
+                Ok(false)
+            }
+        }
+    }
+
+    async fn archive(
+        &self,
+        files: &[&str],
+        base_directory: Option<String>,
+    ) -> Result<(), ArchiverError> {
+        for path in files {
+            if !Path::new(path).exists() {
+                return Err(ArchiverError::FileToArchiveNotFound {
+                    file_path: path.to_string(),
+                });
+            }
+
+            let blob_name = self.get_blob_name(path, base_directory.clone());
+            let url = self.get_blob_url(&blob_name);
+            debug!("Archiving file: {path} on Azure as blob: {blob_name}");
+            self.upload(path, &url).await?;
+            debug!("Archived file: {path} on Azure.");
+        }
+        Ok(())
+    }
+
+    async fn fetch(
+        &self,
+        file: &str,
+        base_directory: Option<String>,
+        destination: &str,
+    ) -> Result<(), ArchiverError> {
+        debug!("Fetching archived file: {file} from Azure to: {destination}");
+        let url = self.get_blob_url(&self.get_blob_name(file, base_directory));
+        let mut response = match self.send(|client| client.get(url.clone())).await {
+            Ok(response) if response.status().is_success() => response,
+            Ok(response) if response.status() == StatusCode::NOT_FOUND => {
+                return Err(ArchiverError::ArchivedFileNotFound {
+                    file_path: file.to_string(),
+                });
+            }
+            Ok(response) => {
+                error!(
+                    "Cannot fetch file: {file} from Azure, received an invalid status code: {}.",
+                    response.status()
+                );
+                return Err(ArchiverError::CannotFetchFile {
+                    file_path: file.to_string(),
+                });
+            }
+            Err(error) => {
+                error!("Cannot fetch file: {file} from Azure: {error}");
+                return Err(ArchiverError::CannotFetchFile {
+                    file_path: file.to_string(),
+                });
+            }
+        };
+
+        let destination_path = Path::new(destination);
+        if let Some(parent) = destination_path.parent() {
+            fs::create_dir_all(parent).await.with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to create directory for fetched file: {destination}")
+            })?;
+        }
+
+        let mut output = fs::File::create(destination_path)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to create file: {destination} for fetched Azure blob")
+            })?;
+        loop {
+            let chunk = match response.chunk().await {
+                Ok(Some(chunk)) => chunk,
+                Ok(None) => break,
+                Err(error) => {
+                    error!("Cannot fetch file: {file} from Azure: {error}");
+                    let _ = fs::remove_file(destination_path).await;
+                    return Err(ArchiverError::CannotFetchFile {
+                        file_path: file.to_string(),
+                    });
+                }
+            };
+            output.write_all(&chunk).await.with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to write fetched Azure blob to file: {destination}")
+            })?;
+        }
+        output.flush().await?;
+        debug!("Fetched archived file: {file} from Azure to: {destination}");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+    use iggy::utils::duration::IggyDuration;
+
+    fn create_archiver(connection_string: &str) -> AzureArchiver {
+        AzureArchiver::new(AzureArchiverConfig {
+            container: "iggy".to_owned(),
+            prefix: Some("/cluster-1".to_owned()),
+            auth: AzureAuthKind::ConnectionString,
+            connection_string: Some(connection_string.to_owned()),
+            account_name: None,
+            client_id: None,
+            endpoint: None,
+            max_retries: 0,
+            retry_initial_backoff: IggyDuration::from_str("1 s").unwrap(),
+            retry_max_backoff: IggyDuration::from_str("1 s").unwrap(),
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn blob_urls_should_be_resolved_from_connection_string() {
+        let archiver = create_archiver(
+            "DefaultEndpointsProtocol=https;AccountName=iggy;AccountKey=a2V5;EndpointSuffix=core.windows.net",
+        );
+        let blob_name = archiver.get_blob_name("streams/1/00001.log", Some("/backup".to_owned()));
+        assert_eq!(blob_name, "cluster-1/backup/streams/1/00001.log");
+        assert_eq!(
+            archiver.get_blob_url(&blob_name).as_str(),
+            "https://iggy.blob.core.windows.net/iggy/cluster-1/backup/streams/1/00001.log"
+        );
+
+        let archiver = create_archiver("BlobEndpoint=http://127.0.0.1:10000/devstoreaccount1;SharedAccessSignature=sv=2021-08-06&sig=abc%3D");
+        assert_eq!(
+            archiver.get_blob_url("segment 1.log").as_str(),
+            "http://127.0.0.1:10000/devstoreaccount1/iggy/segment%201.log"
+        );
+    }
+
+    #[test]
+    fn string_to_sign_should_contain_canonicalized_headers_and_resource() {
+        let request = reqwest::Client::new()
+            .put("https://iggy.blob.core.windows.net/iggy/00001.log?comp=block&blockid=AA%3D%3D")
+            .header("x-ms-version", API_VERSION)
+            .header("x-ms-date", "Fri, 16 Oct 2026 10:00:00 GMT")
+            .header("x-ms-blob-type", "BlockBlob")
+            .header(CONTENT_TYPE, "application/octet-stream")
+            .body(Bytes::from_static(b"data"))
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            get_string_to_sign("iggy", &request),
+            "PUT\n\n\n4\n\napplication/octet-stream\n\n\n\n\n\n\n\
+             x-ms-blob-type:BlockBlob\nx-ms-date:Fri, 16 Oct 2026 10:00:00 GMT\nx-ms-version:2021-08-06\n\
+             /iggy/iggy/00001.log\nblockid:AA==\ncomp:block"
+        );
+    }
+
+    #[test]
+    fn block_list_should_contain_block_ids_of_same_length_in_order() {
+        let block_ids = (0..11).map(get_block_id).collect::<Vec<_>>();
+        assert!(block_ids
+            .iter()
+            .all(|block_id| block_id.len() == block_ids[0].len()));
+        assert_eq!(block_ids[10], base64::encode_block(b"00000010"));
+
+        assert_eq!(
+            get_block_list(&block_ids[..2]),
+            format!(
+                r#"<?xml version="1.0" encoding="utf-8"?><BlockList><Latest>{}</Latest><Latest>{}</Latest></BlockList>"#,
+                block_ids[0], block_ids[1]
+            )
+        );
+    }
+}
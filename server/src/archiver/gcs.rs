@@ -0,0 +1,633 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::archiver::http::{read_chunk, RetryPolicy, UPLOAD_CHUNK_SIZE};
+use crate::archiver::{Archiver, COMPONENT};
+use crate::configs::server::GcsArchiverConfig;
+use crate::server_error::ArchiverError;
+use derive_more::Display;
+use error_set::ErrContext;
+use iggy::utils::timestamp::IggyTimestamp;
+use jsonwebtoken::{encode, Algorithm, EncodingKey, Header};
+use reqwest::header::{HeaderMap, CONTENT_LENGTH, CONTENT_RANGE, LOCATION, RANGE};
+use reqwest::redirect::Policy;
+use reqwest::{RequestBuilder, Response, StatusCode, Url};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Debug, Formatter};
+use std::path::Path;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+use tokio::fs;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::Mutex;
+use tracing::{debug, error, info};
+
+const METADATA_TOKEN_URL: &str =
+    "http://metadata.google.internal/computeMetadata/v1/instance/service-accounts/default/token";
+const STORAGE_SCOPE: &str = "https://www.googleapis.com/auth/devstorage.read_write";
+const JWT_BEARER_GRANT_TYPE: &str = "urn:ietf:params:oauth:grant-type:jwt-bearer";
+const DEFAULT_ENDPOINT: &str = "https://storage.googleapis.com";
+const ASSERTION_LIFETIME_SECS: u64 = 3600;
+/// The access token is refreshed slightly before it expires, so that it doesn't expire in flight.
+const TOKEN_EXPIRY_MARGIN: Duration = Duration::from_secs(60);
+/// The status of the resumable upload which is still missing some of the content.
+const RESUME_INCOMPLETE: StatusCode = StatusCode::PERMANENT_REDIRECT;
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Display, Copy, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum GcsAuthKind {
+    #[display("service_account")]
+    ServiceAccount,
+    #[default]
+    #[display("workload_identity")]
+    WorkloadIdentity,
+}
+
+impl FromStr for GcsAuthKind {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "service_account" => Ok(GcsAuthKind::ServiceAccount),
+            "workload_identity" => Ok(GcsAuthKind::WorkloadIdentity),
+            _ => Err(format!("Unknown GCS auth kind: {}", s)),
+        }
+    }
+}
+
+enum GcsCredentials {
+    /// The token requests are signed with the private key of the service account.
+    ServiceAccount {
+        client_email: String,
+        token_uri: String,
+        key: EncodingKey,
+    },
+    /// The tokens are obtained from the metadata server of the environment the server runs in.
+    WorkloadIdentity,
+}
+
+impl Debug for GcsCredentials {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GcsCredentials::ServiceAccount { client_email, .. } => {
+                write!(f, "ServiceAccount({client_email})")
+            }
+            GcsCredentials::WorkloadIdentity => write!(f, "WorkloadIdentity"),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct ServiceAccountKey {
+    client_email: String,
+    private_key: String,
+    token_uri: String,
+}
+
+#[derive(Serialize)]
+struct AssertionClaims<'a> {
+    iss: &'a str,
+    scope: &'a str,
+    aud: &'a str,
+    iat: u64,
+    exp: u64,
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    expires_in: u64,
+}
+
+#[derive(Debug)]
+struct AccessToken {
+    value: String,
+    expires_at: Instant,
+}
+
+#[derive(Debug)]
+pub struct GcsArchiver {
+    client: reqwest::Client,
+    endpoint: Url,
+    bucket: String,
+    prefix: String,
+    credentials: GcsCredentials,
+    token: Mutex<Option<AccessToken>>,
+    retry_policy: RetryPolicy,
+}
+
+impl GcsArchiver {
+    pub fn new(config: GcsArchiverConfig) -> Result<Self, ArchiverError> {
+        // The provider might be already installed e.g. by the other component.
+        let _ = rustls::crypto::ring::default_provider().install_default();
+        // The redirects aren't followed, as the resumable upload responds with 308 until all the content is uploaded.
+        let client = reqwest::Client::builder()
+            .redirect(Policy::none())
+            .build()
+            .map_err(|error| {
+                error!("{COMPONENT} (error: {error}) - failed to create GCS client");
+                ArchiverError::CannotInitializeGcsArchiver
+            })?;
+        let endpoint = config.endpoint.as_deref().unwrap_or(DEFAULT_ENDPOINT);
+        let endpoint = Url::parse(endpoint)
+            .ok()
+            .filter(|endpoint| !endpoint.cannot_be_a_base())
+            .ok_or_else(|| {
+                error!("{COMPONENT} - invalid GCS endpoint: {endpoint}");
+                ArchiverError::CannotInitializeGcsArchiver
+            })?;
+
+        let credentials = match config.auth {
+            GcsAuthKind::ServiceAccount => {
+                let path = config.credentials_path.as_deref().unwrap_or_default();
+                let key = std::fs::read(path)
+                    .map_err(|error| {
+                        error!("{COMPONENT} (error: {error}) - failed to read GCS service account key: {path}");
+                        ArchiverError::InvalidGcsCredentials
+                    })
+                    .and_then(|key| {
+                        serde_json::from_slice::<ServiceAccountKey>(&key).map_err(|error| {
+                            error!("{COMPONENT} (error: {error}) - invalid GCS service account key: {path}");
+                            ArchiverError::InvalidGcsCredentials
+                        })
+                    })?;
+                let encoding_key = EncodingKey::from_rsa_pem(key.private_key.as_bytes())
+                    .map_err(|error| {
+                        error!("{COMPONENT} (error: {error}) - invalid private key of GCS service account: {}", key.client_email);
+                        ArchiverError::InvalidGcsCredentials
+                    })?;
+                GcsCredentials::ServiceAccount {
+                    client_email: key.client_email,
+                    token_uri: key.token_uri,
+                    key: encoding_key,
+                }
+            }
+            GcsAuthKind::WorkloadIdentity => GcsCredentials::WorkloadIdentity,
+        };
+
+        let mut prefix = config
+            .prefix
+            .unwrap_or_default()
+            .trim_start_matches('/')
+            .to_owned();
+        if !prefix.is_empty() && !prefix.ends_with('/') {
+            prefix.push('/');
+        }
+
+        Ok(Self {
+            client,
+            endpoint,
+            bucket: config.bucket,
+            prefix,
+            credentials,
+            token: Mutex::new(None),
+            retry_policy: RetryPolicy::new(
+                "GCS",
+                config.max_retries,
+                config.retry_initial_backoff.get_duration(),
+                config.retry_max_backoff.get_duration(),
+            ),
+        })
+    }
+
+    fn get_object_name(&self, file: &str, base_directory: Option<String>) -> String {
+        let base_directory = base_directory.as_deref().unwrap_or_default();
+        let path = Path::new(&base_directory).join(file);
+        let path = path.to_str().unwrap_or_default().trim_start_matches('/');
+        format!("{}{path}", self.prefix)
+    }
+
+    /// Builds the URL of the JSON API, the segments (e.g. the object name) are percent-encoded.
+    fn get_url(&self, segments: &[&str]) -> Url {
+        let mut url = self.endpoint.clone();
+        if let Ok(mut path_segments) = url.path_segments_mut() {
+            path_segments.pop_if_empty().extend(segments);
+        }
+        url
+    }
+
+    fn get_object_url(&self, object_name: &str) -> Url {
+        self.get_url(&["storage", "v1", "b", &self.bucket, "o", object_name])
+    }
+
+    async fn get_access_token(&self, refresh: bool) -> Result<String, String> {
+        let mut token = self.token.lock().await;
+        if !refresh {
+            if let Some(token) = token
+                .as_ref()
+                .filter(|token| token.expires_at > Instant::now() + TOKEN_EXPIRY_MARGIN)
+            {
+                return Ok(token.value.clone());
+            }
+        }
+
+        let request = match &self.credentials {
+            GcsCredentials::ServiceAccount {
+                client_email,
+                token_uri,
+                key,
+            } => {
+                let now = IggyTimestamp::now().to_secs();
+                let claims = AssertionClaims {
+                    iss: client_email,
+                    scope: STORAGE_SCOPE,
+                    aud: token_uri,
+                    iat: now,
+                    exp: now + ASSERTION_LIFETIME_SECS,
+                };
+                let assertion = encode(&Header::new(Algorithm::RS256), &claims, key)
+                    .map_err(|error| format!("failed to sign token request: {error}"))?;
+                self.client.post(token_uri).form(&[
+                    ("grant_type", JWT_BEARER_GRANT_TYPE),
+                    ("assertion", &assertion),
+                ])
+            }
+            GcsCredentials::WorkloadIdentity => self
+                .client
+                .get(METADATA_TOKEN_URL)
+                .header("Metadata-Flavor", "Google"),
+        };
+
+        let response = request
+            .send()
+            .await
+            .map_err(|error| format!("failed to request access token: {error}"))?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(format!("access token request failed with status: {status}"));
+        }
+
+        let body = response
+            .bytes()
+            .await
+            .map_err(|error| format!("failed to read access token: {error}"))?;
+        let token_response = serde_json::from_slice::<TokenResponse>(&body)
+            .map_err(|error| format!("invalid access token response: {error}"))?;
+        debug!(
+            "Obtained GCS access token, expires in: {} s.",
+            token_response.expires_in
+        );
+        *token = Some(AccessToken {
+            value: token_response.access_token.clone(),
+            expires_at: Instant::now() + Duration::from_secs(token_response.expires_in),
+        });
+        Ok(token_response.access_token)
+    }
+
+    /// Sends the authorized request, retrying it as described by the `RetryPolicy`.
+    async fn send<F>(&self, build_request: F) -> Result<Response, String>
+    where
+        F: Fn(&reqwest::Client) -> RequestBuilder,
+    {
+        let build_request = &build_request;
+        self.retry_policy
+            .send(true, |refresh_token| async move {
+                let token = self.get_access_token(refresh_token).await?;
+                build_request(&self.client)
+                    .bearer_auth(token)
+                    .send()
+                    .await
+                    .map_err(|error| error.to_string())
+            })
+            .await
+    }
+
+    /// Uploads the file using the resumable upload, so that only a single chunk of it is kept in memory.
+    async fn upload(&self, path: &str, object_name: &str) -> Result<(), ArchiverError> {
+        let mut file = fs::File::open(path).await.with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to open file: {path} for archiving")
+        })?;
+        let file_size = file
+            .metadata()
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to read metadata of file: {path} for archiving")
+            })?
+            .len();
+
+        let url = self.get_url(&["upload", "storage", "v1", "b", &self.bucket, "o"]);
+        let response = self
+            .send(|client| {
+                client
+                    .post(url.clone())
+                    .query(&[("uploadType", "resumable"), ("name", object_name)])
+                    .header("X-Upload-Content-Type", "application/octet-stream")
+                    .header("X-Upload-Content-Length", file_size)
+                    .header(CONTENT_LENGTH, 0)
+            })
+            .await;
+        let response = check_upload_response(path, response)?;
+        let Some(session_url) = response
+            .headers()
+            .get(LOCATION)
+            .and_then(|location| location.to_str().ok())
+            .and_then(|location| Url::parse(location).ok())
+        else {
+            error!("Cannot archive file: {path} on GCS, missing the resumable upload session.");
+            return Err(ArchiverError::CannotArchiveFile {
+                file_path: path.to_string(),
+            });
+        };
+
+        let mut offset = 0;
+        loop {
+            let mut chunk = read_chunk(&mut file, UPLOAD_CHUNK_SIZE)
+                .await
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to read file: {path} for archiving"
+                    )
+                })?;
+            if chunk.is_empty() && offset < file_size {
+                error!("Cannot archive file: {path} on GCS, it was truncated while archiving.");
+                return Err(ArchiverError::CannotArchiveFile {
+                    file_path: path.to_string(),
+                });
+            }
+
+            let chunk_end = offset + chunk.len() as u64;
+            loop {
+                let content_range = match chunk.is_empty() {
+                    true => format!("bytes */{file_size}"),
+                    false => format!("bytes {offset}-{}/{file_size}", chunk_end - 1),
+                };
+                let response = self
+                    .send(|client| {
+                        client
+                            .put(session_url.clone())
+                            .header(CONTENT_RANGE, &content_range)
+                            .body(chunk.clone())
+                    })
+                    .await;
+                let response = check_upload_response(path, response)?;
+                if response.status() != RESUME_INCOMPLETE {
+                    return Ok(());
+                }
+                if chunk.is_empty() {
+                    error!("Cannot archive file: {path} on GCS, the upload wasn't completed.");
+                    return Err(ArchiverError::CannotArchiveFile {
+                        file_path: path.to_string(),
+                    });
+                }
+
+                // Only a part of the chunk might have been persisted, in which case the rest of it is sent again.
+                let persisted = get_persisted_size(response.headers())
+                    .filter(|persisted| *persisted >= offset && *persisted <= chunk_end);
+                let Some(persisted) = persisted else {
+                    error!("Cannot archive file: {path} on GCS, received an invalid range of the persisted content.");
+                    return Err(ArchiverError::CannotArchiveFile {
+                        file_path: path.to_string(),
+                    });
+                };
+                chunk = chunk.slice((persisted - offset) as usize..);
+                offset = persisted;
+                if chunk.is_empty() {
+                    break;
+                }
+            }
+        }
+    }
+}
+
+fn check_upload_response(
+    path: &str,
+    response: Result<Response, String>,
+) -> Result<Response, ArchiverError> {
+    match response {
+        Ok(response)
+            if response.status().is_success() || response.status() == RESUME_INCOMPLETE =>
+        {
+            Ok(response)
+        }
+        Ok(response) => {
+            error!(
+                "Cannot archive file: {path} on GCS, received an invalid status code: {}.",
+                response.status()
+            );
+            Err(ArchiverError::CannotArchiveFile {
+                file_path: path.to_string(),
+            })
+        }
+        Err(error) => {
+            error!("Cannot archive file: {path} on GCS: {error}");
+            Err(ArchiverError::CannotArchiveFile {
+                file_path: path.to_string(),
+            })
+        }
+    }
+}
+
+/// Returns the size of the content persisted so far by the resumable upload, based on its `Range` header
+/// which is missing if nothing was persisted yet.
+fn get_persisted_size(headers: &HeaderMap) -> Option<u64> {
+    match headers.get(RANGE) {
+        Some(range) => range
+            .to_str()
+            .ok()?
+            .strip_prefix("bytes=0-")?
+            .parse::<u64>()
+            .ok()
+            .map(|end| end + 1),
+        None => Some(0),
+    }
+}
+
+impl Archiver for GcsArchiver {
+    async fn init(&self) -> Result<(), ArchiverError> {
+        let url = self.get_url(&["storage", "v1", "b", &self.bucket, "o"]);
+        let response = self
+            .send(|client| {
+                client
+                    .get(url.clone())
+                    .query(&[("maxResults", "1"), ("prefix", &self.prefix)])
+            })
+            .await;
+        match response {
+            Ok(response) if response.status().is_success() => {
+                info!(
+                    "Initialized GCS archiver for bucket: {}, prefix: {}.",
+                    self.bucket, self.prefix
+                );
+                Ok(())
+            }
+            Ok(response) => {
+                error!(
+                    "Cannot initialize GCS archiver, received an invalid status code: {}.",
+                    response.status()
+                );
+                Err(ArchiverError::CannotInitializeGcsArchiver)
+            }
+            Err(error) => {
+                error!("Cannot initialize GCS archiver: {error}");
+                Err(ArchiverError::CannotInitializeGcsArchiver)
+            }
+        }
+    }
+
+    async fn is_archived(
+        &self,
+        file: &str,
+        base_directory: Option<String>,
+    ) -> Result<bool, ArchiverError> {
+        debug!("Checking if file: {file} is archived on GCS.");
+        let url = self.get_object_url(&self.get_object_name(file, base_directory));
+        let response = self.send(|client| client.get(url.clone())).await;
+        match response {
+            Ok(response) if response.status() == StatusCode::OK => {
+                debug!("File: {file} is archived on GCS.");
+                Ok(true)
+            }
+            Ok(_) => {
+                debug!("File: {file} is not archived on GCS.");
+                Ok(false)
+            }
+            Err(error) => {
+                debug!("Cannot check if file: {file} is archived on GCS: {error}");
+                Ok(false)
+            }
+        }
+    }
+
+    async fn archive(
+        &self,
+        files: &[&str],
+        base_directory: Option<String>,
+    ) -> Result<(), ArchiverError> {
+        for path in files {
+            if !Path::new(path).exists() {
+                return Err(ArchiverError::FileToArchiveNotFound {
+                    file_path: path.to_string(),
+                });
+            }
+
+            let object_name = self.get_object_name(path, base_directory.clone());
+            debug!("Archiving file: {path} on GCS as object: {object_name}");
+            self.upload(path, &object_name).await?;
+            debug!("Archived file: {path} on GCS.");
+        }
+        Ok(())
+    }
+
+    async fn fetch(
+        &self,
+        file: &str,
+        base_directory: Option<String>,
+        destination: &str,
+    ) -> Result<(), ArchiverError> {
+        debug!("Fetching archived file: {file} from GCS to: {destination}");
+        let url = self.get_object_url(&self.get_object_name(file, base_directory));
+        let mut response = match self
+            .send(|client| client.get(url.clone()).query(&[("alt", "media")]))
+            .await
+        {
+            Ok(response) if response.status().is_success() => response,
+            Ok(response) if response.status() == StatusCode::NOT_FOUND => {
+                return Err(ArchiverError::ArchivedFileNotFound {
+                    file_path: file.to_string(),
+                });
+            }
+            Ok(response) => {
+                error!(
+                    "Cannot fetch file: {file} from GCS, received an invalid status code: {}.",
+                    response.status()
+                );
+                return Err(ArchiverError::CannotFetchFile {
+                    file_path: file.to_string(),
+                });
+            }
+            Err(error) => {
+                error!("Cannot fetch file: {file} from GCS: {error}");
+                return Err(ArchiverError::CannotFetchFile {
+                    file_path: file.to_string(),
+                });
+            }
+        };
+
+        let destination_path = Path::new(destination);
+        if let Some(parent) = destination_path.parent() {
+            fs::create_dir_all(parent).await.with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to create directory for fetched file: {destination}")
+            })?;
+        }
+
+        let mut output = fs::File::create(destination_path)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to create file: {destination} for fetched GCS object")
+            })?;
+        loop {
+            let chunk = match response.chunk().await {
+                Ok(Some(chunk)) => chunk,
+                Ok(None) => break,
+                Err(error) => {
+                    error!("Cannot fetch file: {file} from GCS: {error}");
+                    let _ = fs::remove_file(destination_path).await;
+                    return Err(ArchiverError::CannotFetchFile {
+                        file_path: file.to_string(),
+                    });
+                }
+            };
+            output.write_all(&chunk).await.with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to write fetched GCS object to file: {destination}")
+            })?;
+        }
+        output.flush().await?;
+        debug!("Fetched archived file: {file} from GCS to: {destination}");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy::utils::duration::IggyDuration;
+
+    #[test]
+    fn object_urls_should_be_prefixed_and_percent_encoded() {
+        let archiver = GcsArchiver::new(GcsArchiverConfig {
+            bucket: "iggy".to_owned(),
+            prefix: Some("/cluster-1".to_owned()),
+            auth: GcsAuthKind::WorkloadIdentity,
+            credentials_path: None,
+            endpoint: Some("http://localhost:4443/".to_owned()),
+            max_retries: 0,
+            retry_initial_backoff: IggyDuration::from_str("1 s").unwrap(),
+            retry_max_backoff: IggyDuration::from_str("1 s").unwrap(),
+        })
+        .unwrap();
+
+        let object_name =
+            archiver.get_object_name("streams/1/00001.log", Some("/backup".to_owned()));
+        assert_eq!(object_name, "cluster-1/backup/streams/1/00001.log");
+        assert_eq!(
+            archiver.get_object_url(&object_name).as_str(),
+            "http://localhost:4443/storage/v1/b/iggy/o/cluster-1%2Fbackup%2Fstreams%2F1%2F00001.log"
+        );
+    }
+
+    #[test]
+    fn persisted_size_should_be_read_from_range_header() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(get_persisted_size(&headers), Some(0));
+
+        headers.insert(RANGE, "bytes=0-262143".parse().unwrap());
+        assert_eq!(get_persisted_size(&headers), Some(262144));
+
+        headers.insert(RANGE, "bytes=1-262143".parse().unwrap());
+        assert_eq!(get_persisted_size(&headers), None);
+    }
+}
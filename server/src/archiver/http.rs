@@ -0,0 +1,181 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use bytes::Bytes;
+use reqwest::{Response, StatusCode};
+use std::future::Future;
+use std::time::Duration;
+use tokio::fs::File;
+use tokio::io::AsyncReadExt;
+use tokio::time::sleep;
+use tracing::warn;
+
+/// The size of the chunks in which the files are uploaded, so that only a single chunk is kept in memory
+/// and sent again on retry. It's a multiple of 256 KiB, as required by the GCS resumable upload.
+pub const UPLOAD_CHUNK_SIZE: usize = 8 * 1024 * 1024;
+
+/// The retries of the requests sent by the archivers using the HTTP APIs of the object storages.
+#[derive(Debug)]
+pub struct RetryPolicy {
+    service: &'static str,
+    max_retries: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+}
+
+impl RetryPolicy {
+    pub fn new(
+        service: &'static str,
+        max_retries: u32,
+        initial_backoff: Duration,
+        max_backoff: Duration,
+    ) -> Self {
+        Self {
+            service,
+            max_retries,
+            initial_backoff,
+            max_backoff,
+        }
+    }
+
+    /// Sends the request, retrying with the exponential backoff on the network errors, throttling and server errors.
+    /// The response with the retryable status is returned once the retries are exhausted.
+    /// If the request is rejected as unauthorized and `can_refresh_token` is set, it's sent once again
+    /// with `true` passed to `send_request`, as the access token might have been revoked.
+    pub async fn send<F, Fut>(
+        &self,
+        can_refresh_token: bool,
+        send_request: F,
+    ) -> Result<Response, String>
+    where
+        F: Fn(bool) -> Fut,
+        Fut: Future<Output = Result<Response, String>>,
+    {
+        let mut attempt = 0;
+        let mut backoff = self.initial_backoff;
+        let mut refresh_token = false;
+        loop {
+            let reason = match send_request(refresh_token).await {
+                Ok(response)
+                    if response.status() == StatusCode::UNAUTHORIZED
+                        && can_refresh_token
+                        && !refresh_token =>
+                {
+                    refresh_token = true;
+                    continue;
+                }
+                Ok(response) if is_retryable(response.status()) && attempt < self.max_retries => {
+                    format!("status: {}", response.status())
+                }
+                Ok(response) => return Ok(response),
+                Err(error) if attempt < self.max_retries => error,
+                Err(error) => return Err(error),
+            };
+
+            attempt += 1;
+            warn!(
+                "{} request failed ({reason}), retrying in: {} ms, attempt: {attempt}/{}.",
+                self.service,
+                backoff.as_millis(),
+                self.max_retries
+            );
+            sleep(backoff).await;
+            backoff = next_backoff(backoff, self.max_backoff);
+        }
+    }
+}
+
+/// Reads the next chunk of the file, which is shorter than the chunk size only at the end of the file.
+pub async fn read_chunk(file: &mut File, chunk_size: usize) -> Result<Bytes, std::io::Error> {
+    let mut chunk = Vec::with_capacity(chunk_size);
+    file.take(chunk_size as u64).read_to_end(&mut chunk).await?;
+    Ok(Bytes::from(chunk))
+}
+
+fn is_retryable(status: StatusCode) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS
+        || status == StatusCode::REQUEST_TIMEOUT
+        || status.is_server_error()
+}
+
+fn next_backoff(backoff: Duration, max_backoff: Duration) -> Duration {
+    backoff.saturating_mul(2).min(max_backoff)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use tokio::io::AsyncWriteExt;
+
+    fn create_policy(max_retries: u32) -> RetryPolicy {
+        RetryPolicy::new(
+            "Test",
+            max_retries,
+            Duration::from_millis(1),
+            Duration::from_millis(2),
+        )
+    }
+
+    #[tokio::test]
+    async fn errors_should_be_returned_once_retries_are_exhausted() {
+        let attempts = AtomicU32::new(0);
+        let result = create_policy(1)
+            .send(false, |_| async {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Err("connection reset".to_owned())
+            })
+            .await;
+
+        assert_eq!(result.unwrap_err(), "connection reset");
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn backoff_should_be_doubled_up_to_max() {
+        let max_backoff = Duration::from_secs(5);
+        assert_eq!(
+            next_backoff(Duration::from_secs(2), max_backoff),
+            Duration::from_secs(4)
+        );
+        assert_eq!(
+            next_backoff(Duration::from_secs(4), max_backoff),
+            max_backoff
+        );
+    }
+
+    #[tokio::test]
+    async fn file_should_be_read_in_chunks() {
+        let directory = tempfile::TempDir::new().unwrap();
+        let path = directory.path().join("00001.log");
+        let mut file = File::create(&path).await.unwrap();
+        file.write_all(&[7; 10]).await.unwrap();
+        file.flush().await.unwrap();
+
+        let mut file = File::open(&path).await.unwrap();
+        let first = read_chunk(&mut file, 4).await.unwrap();
+        let second = read_chunk(&mut file, 4).await.unwrap();
+        let third = read_chunk(&mut file, 4).await.unwrap();
+        let last = read_chunk(&mut file, 4).await.unwrap();
+
+        assert_eq!(first.len(), 4);
+        assert_eq!(second.len(), 4);
+        assert_eq!(third.len(), 2);
+        assert!(last.is_empty());
+    }
+}
@@ -16,10 +16,15 @@
  * under the License.
  */
 
+pub mod azure;
 pub mod disk;
+pub mod gcs;
+pub mod http;
 pub mod s3;
 
-use crate::configs::server::{DiskArchiverConfig, S3ArchiverConfig};
+use crate::configs::server::{
+    ArchiverConfig, AzureArchiverConfig, DiskArchiverConfig, GcsArchiverConfig, S3ArchiverConfig,
+};
 use crate::server_error::ArchiverError;
 use derive_more::Display;
 use serde::{Deserialize, Serialize};
@@ -27,7 +32,9 @@ use std::fmt::Debug;
 use std::future::Future;
 use std::str::FromStr;
 
+use crate::archiver::azure::AzureArchiver;
 use crate::archiver::disk::DiskArchiver;
+use crate::archiver::gcs::GcsArchiver;
 use crate::archiver::s3::S3Archiver;
 
 pub const COMPONENT: &str = "ARCHIVER";
@@ -40,6 +47,10 @@ pub enum ArchiverKindType {
     Disk,
     #[display("s3")]
     S3,
+    #[display("gcs")]
+    Gcs,
+    #[display("azure")]
+    Azure,
 }
 
 impl FromStr for ArchiverKindType {
@@ -48,6 +59,8 @@ impl FromStr for ArchiverKindType {
         match s.to_lowercase().as_str() {
             "disk" => Ok(ArchiverKindType::Disk),
             "s3" => Ok(ArchiverKindType::S3),
+            "gcs" => Ok(ArchiverKindType::Gcs),
+            "azure" => Ok(ArchiverKindType::Azure),
             _ => Err(format!("Unknown archiver kind: {}", s)),
         }
     }
@@ -65,15 +78,44 @@ pub trait Archiver: Send {
         files: &[&str],
         base_directory: Option<String>,
     ) -> impl Future<Output = Result<(), ArchiverError>> + Send;
+    fn fetch(
+        &self,
+        file: &str,
+        base_directory: Option<String>,
+        destination: &str,
+    ) -> impl Future<Output = Result<(), ArchiverError>> + Send;
 }
 
 #[derive(Debug)]
 pub enum ArchiverKind {
     Disk(DiskArchiver),
     S3(S3Archiver),
+    Gcs(GcsArchiver),
+    Azure(AzureArchiver),
 }
 
 impl ArchiverKind {
+    /// Creates the archiver of the configured kind, failing if its configuration section is missing.
+    pub fn from_config(config: &ArchiverConfig) -> Result<Self, ArchiverError> {
+        let missing_config = || ArchiverError::MissingArchiverConfig {
+            kind: config.kind.to_string(),
+        };
+        match config.kind {
+            ArchiverKindType::Disk => Ok(Self::get_disk_archiver(
+                config.disk.clone().ok_or_else(missing_config)?,
+            )),
+            ArchiverKindType::S3 => {
+                Self::get_s3_archiver(config.s3.clone().ok_or_else(missing_config)?)
+            }
+            ArchiverKindType::Gcs => {
+                Self::get_gcs_archiver(config.gcs.clone().ok_or_else(missing_config)?)
+            }
+            ArchiverKindType::Azure => {
+                Self::get_azure_archiver(config.azure.clone().ok_or_else(missing_config)?)
+            }
+        }
+    }
+
     pub fn get_disk_archiver(config: DiskArchiverConfig) -> Self {
         Self::Disk(DiskArchiver::new(config))
     }
@@ -83,10 +125,22 @@ impl ArchiverKind {
         Ok(Self::S3(archiver))
     }
 
+    pub fn get_gcs_archiver(config: GcsArchiverConfig) -> Result<Self, ArchiverError> {
+        let archiver = GcsArchiver::new(config)?;
+        Ok(Self::Gcs(archiver))
+    }
+
+    pub fn get_azure_archiver(config: AzureArchiverConfig) -> Result<Self, ArchiverError> {
+        let archiver = AzureArchiver::new(config)?;
+        Ok(Self::Azure(archiver))
+    }
+
     pub async fn init(&self) -> Result<(), ArchiverError> {
         match self {
             Self::Disk(a) => a.init().await,
             Self::S3(a) => a.init().await,
+            Self::Gcs(a) => a.init().await,
+            Self::Azure(a) => a.init().await,
         }
     }
 
@@ -98,6 +152,8 @@ impl ArchiverKind {
         match self {
             Self::Disk(d) => d.is_archived(file, base_directory).await,
             Self::S3(d) => d.is_archived(file, base_directory).await,
+            Self::Gcs(d) => d.is_archived(file, base_directory).await,
+            Self::Azure(d) => d.is_archived(file, base_directory).await,
         }
     }
 
@@ -109,6 +165,22 @@ impl ArchiverKind {
         match self {
             Self::Disk(d) => d.archive(files, base_directory).await,
             Self::S3(d) => d.archive(files, base_directory).await,
+            Self::Gcs(d) => d.archive(files, base_directory).await,
+            Self::Azure(d) => d.archive(files, base_directory).await,
+        }
+    }
+
+    pub async fn fetch(
+        &self,
+        file: &str,
+        base_directory: Option<String>,
+        destination: &str,
+    ) -> Result<(), ArchiverError> {
+        match self {
+            Self::Disk(d) => d.fetch(file, base_directory, destination).await,
+            Self::S3(d) => d.fetch(file, base_directory, destination).await,
+            Self::Gcs(d) => d.fetch(file, base_directory, destination).await,
+            Self::Azure(d) => d.fetch(file, base_directory, destination).await,
         }
     }
 }
@@ -194,4 +194,58 @@ impl Archiver for S3Archiver {
         }
         Ok(())
     }
+    async fn fetch(
+        &self,
+        file: &str,
+        base_directory: Option<String>,
+        destination: &str,
+    ) -> Result<(), ArchiverError> {
+        debug!("Fetching archived file: {file} from S3 to: {destination}");
+        let base_directory = base_directory.as_deref().unwrap_or_default();
+        let source = Path::new(&base_directory).join(file);
+        let source_path = source.to_str().unwrap_or_default().to_owned();
+        let destination_path = Path::new(destination);
+        if let Some(parent) = destination_path.parent() {
+            fs::create_dir_all(parent).await.with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to create directory for fetched file: {destination}")
+            })?;
+        }
+
+        let mut output = fs::File::create(destination_path)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to create file: {destination} for fetched S3 object")
+            })?;
+        let response = self
+            .bucket
+            .get_object_to_writer(&source_path, &mut output)
+            .await;
+        let status = match response {
+            Ok(status) => status,
+            Err(error) => {
+                error!("Cannot fetch file: {file} from S3: {error}");
+                let _ = fs::remove_file(destination_path).await;
+                return Err(ArchiverError::CannotFetchFile {
+                    file_path: file.to_string(),
+                });
+            }
+        };
+
+        if status == 200 {
+            debug!("Fetched archived file: {file} from S3 to: {destination}");
+            return Ok(());
+        }
+
+        let _ = fs::remove_file(destination_path).await;
+        if status == 404 {
+            return Err(ArchiverError::ArchivedFileNotFound {
+                file_path: file.to_string(),
+            });
+        }
+
+        error!("Cannot fetch file: {file} from S3, received an invalid status code: {status}.");
+        Err(ArchiverError::CannotFetchFile {
+            file_path: file.to_string(),
+        })
+    }
 }
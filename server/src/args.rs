@@ -16,7 +16,7 @@
  * under the License.
  */
 
-use clap::Parser;
+use clap::{Parser, Subcommand};
 
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
@@ -30,4 +30,28 @@ pub struct Args {
         help = "Remove system path (local_data by default) before starting. THIS WILL REMOVE ALL SAVED DATA!"
     )]
     pub fresh: bool,
+
+    #[arg(
+        long,
+        default_value_t = false,
+        conflicts_with = "fresh",
+        help = "Start in read-only recovery mode: load all the data without modifying it, produce an integrity report and expose only the read and inspection APIs."
+    )]
+    pub recovery: bool,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Verify the checksums of all the stored segments and exit, without starting the server.
+    Verify {
+        #[arg(
+            long,
+            default_value_t = false,
+            help = "Truncate each corrupted segment at its first corrupted batch and remove its index, so that it's rebuilt on the next startup."
+        )]
+        repair: bool,
+    },
 }
@@ -0,0 +1,139 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::authenticator::{Authenticator, Credentials};
+use crate::server_error::AuthenticatorError;
+use libloading::Library;
+use std::ffi::{c_char, CStr, CString};
+use std::sync::Arc;
+use tracing::error;
+
+const AUTHENTICATE_SYMBOL: &[u8] = b"iggy_authenticate\0";
+const MAX_USERNAME_LENGTH: usize = 256;
+const AUTHENTICATED: i32 = 1;
+const NOT_AUTHENTICATED: i32 = 0;
+
+/// Signature of the function exported by the library:
+///
+/// ```c
+/// int32_t iggy_authenticate(const char *username, const char *password,
+///                           const uint8_t *certificate, size_t certificate_length,
+///                           char *mapped_username, size_t mapped_username_capacity);
+/// ```
+///
+/// It returns 1 when the credentials are verified, after writing the NUL-terminated username of the mapped user
+/// (leaving it empty maps to the provided username), 0 when they are not, and a negative value on error.
+/// The certificate is null when the client didn't present any. The function might be called concurrently.
+type AuthenticateFn =
+    unsafe extern "C" fn(*const c_char, *const c_char, *const u8, usize, *mut c_char, usize) -> i32;
+
+#[derive(Debug)]
+pub struct DynamicLibraryAuthenticator {
+    path: String,
+    authenticate: AuthenticateFn,
+    // Keeps the library loaded as long as the function can be called.
+    library: Arc<Library>,
+}
+
+impl DynamicLibraryAuthenticator {
+    pub fn new(path: &str) -> Result<Self, AuthenticatorError> {
+        // SAFETY: the library is trusted by the operator who configured it,
+        // its initialization routines are executed on load.
+        let library = unsafe { Library::new(path) }.map_err(|error| {
+            error!("Cannot load authenticator library: {path}. {error}");
+            AuthenticatorError::CannotLoadAuthenticatorLibrary {
+                path: path.to_owned(),
+            }
+        })?;
+        // SAFETY: the exported function must match the documented signature.
+        let authenticate = unsafe { library.get::<AuthenticateFn>(AUTHENTICATE_SYMBOL) }
+            .map(|function| *function)
+            .map_err(|error| {
+                error!("Cannot find the authenticate function in library: {path}. {error}");
+                AuthenticatorError::CannotLoadAuthenticatorLibrary {
+                    path: path.to_owned(),
+                }
+            })?;
+
+        Ok(Self {
+            path: path.to_owned(),
+            authenticate,
+            library: Arc::new(library),
+        })
+    }
+}
+
+impl Authenticator for DynamicLibraryAuthenticator {
+    async fn authenticate(
+        &self,
+        credentials: &Credentials<'_>,
+    ) -> Result<Option<String>, AuthenticatorError> {
+        let (Ok(username), Ok(password)) = (
+            CString::new(credentials.username),
+            CString::new(credentials.password),
+        ) else {
+            return Ok(None);
+        };
+
+        let certificate = credentials.peer_certificate.map(|c| c.to_vec());
+        let authenticate = self.authenticate;
+        let library = self.library.clone();
+        let (result, mapped_username) = tokio::task::spawn_blocking(move || {
+            let _library = library;
+            let mut mapped_username = vec![0 as c_char; MAX_USERNAME_LENGTH];
+            let (certificate, certificate_length) = match &certificate {
+                Some(certificate) => (certificate.as_ptr(), certificate.len()),
+                None => (std::ptr::null(), 0),
+            };
+            // SAFETY: all the pointers are valid for the duration of the call
+            // and the output buffer capacity is passed along.
+            let result = unsafe {
+                authenticate(
+                    username.as_ptr(),
+                    password.as_ptr(),
+                    certificate,
+                    certificate_length,
+                    mapped_username.as_mut_ptr(),
+                    mapped_username.len(),
+                )
+            };
+            // Guards against the missing NUL terminator.
+            mapped_username[MAX_USERNAME_LENGTH - 1] = 0;
+            // SAFETY: the buffer is NUL-terminated.
+            let mapped_username = unsafe { CStr::from_ptr(mapped_username.as_ptr()) }
+                .to_string_lossy()
+                .into_owned();
+            (result, mapped_username)
+        })
+        .await
+        .map_err(|error| AuthenticatorError::AuthenticatorRequestFailed {
+            reason: error.to_string(),
+        })?;
+
+        match result {
+            AUTHENTICATED if mapped_username.is_empty() => {
+                Ok(Some(credentials.username.to_owned()))
+            }
+            AUTHENTICATED => Ok(Some(mapped_username)),
+            NOT_AUTHENTICATED => Ok(None),
+            code => Err(AuthenticatorError::AuthenticatorRequestFailed {
+                reason: format!("library: {} returned error code: {code}", self.path),
+            }),
+        }
+    }
+}
@@ -0,0 +1,248 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::authenticator::{is_loopback_host, Authenticator, Credentials, COMPONENT};
+use crate::configs::system::GrpcAuthenticatorConfig;
+use crate::server_error::AuthenticatorError;
+use tonic::codec::ProstCodec;
+use tonic::codegen::http::uri::PathAndQuery;
+use tonic::codegen::http::Uri;
+use tonic::transport::{Certificate, Channel, ClientTlsConfig, Endpoint, Identity};
+use tonic::Code;
+use tracing::error;
+
+const AUTHENTICATE_PATH: &str = "/iggy.auth.v1.Authenticator/Authenticate";
+
+/// Calls the external authentication service implementing the following contract:
+///
+/// ```proto
+/// syntax = "proto3";
+/// package iggy.auth.v1;
+///
+/// service Authenticator {
+///   rpc Authenticate(AuthenticateRequest) returns (AuthenticateResponse);
+/// }
+///
+/// message AuthenticateRequest {
+///   string username = 1;
+///   string password = 2;
+///   bytes peer_certificate = 3;
+/// }
+///
+/// message AuthenticateResponse {
+///   bool authenticated = 1;
+///   // Username of the mapped user, empty maps to the provided username.
+///   string username = 2;
+/// }
+/// ```
+#[derive(Debug)]
+pub struct GrpcAuthenticator {
+    channel: Channel,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+struct AuthenticateRequest {
+    #[prost(string, tag = "1")]
+    username: String,
+    #[prost(string, tag = "2")]
+    password: String,
+    #[prost(bytes = "vec", tag = "3")]
+    peer_certificate: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+struct AuthenticateResponse {
+    #[prost(bool, tag = "1")]
+    authenticated: bool,
+    #[prost(string, tag = "2")]
+    username: String,
+}
+
+impl GrpcAuthenticator {
+    pub fn new(config: GrpcAuthenticatorConfig) -> Result<Self, AuthenticatorError> {
+        let mut endpoint = Endpoint::from_shared(config.endpoint.clone())
+            .map_err(|error| {
+                error!(
+                    "{COMPONENT} (error: {error}) - invalid gRPC authenticator endpoint: {}",
+                    config.endpoint
+                );
+                AuthenticatorError::InvalidAuthenticatorConfiguration
+            })?
+            .timeout(config.timeout.get_duration());
+
+        if endpoint.uri().scheme_str() == Some("https") {
+            endpoint = endpoint
+                .tls_config(Self::tls_config(&config)?)
+                .map_err(|error| {
+                    error!("{COMPONENT} (error: {error}) - invalid gRPC authenticator TLS configuration");
+                    AuthenticatorError::InvalidAuthenticatorConfiguration
+                })?;
+        }
+
+        Ok(Self {
+            channel: endpoint.connect_lazy(),
+        })
+    }
+
+    fn tls_config(config: &GrpcAuthenticatorConfig) -> Result<ClientTlsConfig, AuthenticatorError> {
+        let mut tls_config = ClientTlsConfig::new()
+            .ca_certificate(Certificate::from_pem(read_pem_file(&config.tls_ca_file)?));
+        if !config.tls_cert_file.is_empty() {
+            tls_config = tls_config.identity(Identity::from_pem(
+                read_pem_file(&config.tls_cert_file)?,
+                read_pem_file(&config.tls_key_file)?,
+            ));
+        }
+        if !config.tls_domain.is_empty() {
+            tls_config = tls_config.domain_name(config.tls_domain.clone());
+        }
+        Ok(tls_config)
+    }
+}
+
+/// Validates the configuration of the gRPC authenticator, the credentials are sent in plain text
+/// over the "http" endpoints, so these are accepted only for the loopback hosts,
+/// while the "https" endpoints require the CA certificate verifying the authentication service.
+pub fn validate_grpc_config(config: &GrpcAuthenticatorConfig) -> Result<(), AuthenticatorError> {
+    let Ok(uri) = config.endpoint.parse::<Uri>() else {
+        return Err(AuthenticatorError::InvalidAuthenticatorConfiguration);
+    };
+
+    let Some(host) = uri.host() else {
+        return Err(AuthenticatorError::InvalidAuthenticatorConfiguration);
+    };
+
+    let is_valid = match uri.scheme_str() {
+        Some("https") => !config.tls_ca_file.is_empty(),
+        Some("http") => is_loopback_host(host),
+        _ => false,
+    };
+    if !is_valid || config.tls_cert_file.is_empty() != config.tls_key_file.is_empty() {
+        return Err(AuthenticatorError::InvalidAuthenticatorConfiguration);
+    }
+
+    Ok(())
+}
+
+fn read_pem_file(path: &str) -> Result<Vec<u8>, AuthenticatorError> {
+    std::fs::read(path).map_err(|error| {
+        error!(
+            "{COMPONENT} (error: {error}) - failed to read the gRPC authenticator TLS file: {path}"
+        );
+        AuthenticatorError::InvalidAuthenticatorConfiguration
+    })
+}
+
+impl Authenticator for GrpcAuthenticator {
+    async fn authenticate(
+        &self,
+        credentials: &Credentials<'_>,
+    ) -> Result<Option<String>, AuthenticatorError> {
+        let mut client = tonic::client::Grpc::new(self.channel.clone());
+        client
+            .ready()
+            .await
+            .map_err(|error| AuthenticatorError::AuthenticatorRequestFailed {
+                reason: error.to_string(),
+            })?;
+
+        let request = AuthenticateRequest {
+            username: credentials.username.to_owned(),
+            password: credentials.password.to_owned(),
+            peer_certificate: credentials
+                .peer_certificate
+                .map(|certificate| certificate.to_vec())
+                .unwrap_or_default(),
+        };
+        let response = client
+            .unary(
+                tonic::Request::new(request),
+                PathAndQuery::from_static(AUTHENTICATE_PATH),
+                ProstCodec::<AuthenticateRequest, AuthenticateResponse>::default(),
+            )
+            .await;
+
+        match response {
+            Ok(response) => {
+                let response = response.into_inner();
+                if !response.authenticated {
+                    return Ok(None);
+                }
+
+                if response.username.is_empty() {
+                    return Ok(Some(credentials.username.to_owned()));
+                }
+
+                Ok(Some(response.username))
+            }
+            Err(status) if status.code() == Code::Unauthenticated => Ok(None),
+            Err(status) => Err(AuthenticatorError::AuthenticatorRequestFailed {
+                reason: status.to_string(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy::utils::duration::IggyDuration;
+
+    fn config(endpoint: &str, tls_ca_file: &str) -> GrpcAuthenticatorConfig {
+        GrpcAuthenticatorConfig {
+            endpoint: endpoint.to_owned(),
+            timeout: IggyDuration::ONE_SECOND,
+            tls_ca_file: tls_ca_file.to_owned(),
+            tls_cert_file: "".to_owned(),
+            tls_key_file: "".to_owned(),
+            tls_domain: "".to_owned(),
+        }
+    }
+
+    #[test]
+    fn should_accept_plain_text_endpoint_only_for_loopback_host() {
+        assert!(validate_grpc_config(&config("http://127.0.0.1:50051", "")).is_ok());
+        assert!(validate_grpc_config(&config("http://127.10.0.1:50051", "")).is_ok());
+        assert!(validate_grpc_config(&config("http://localhost:50051", "")).is_ok());
+        assert!(validate_grpc_config(&config("http://[::1]:50051", "")).is_ok());
+        assert!(validate_grpc_config(&config("http://10.0.0.1:50051", "")).is_err());
+        assert!(validate_grpc_config(&config("http://auth.example.com:50051", "")).is_err());
+    }
+
+    #[test]
+    fn should_require_ca_certificate_for_tls_endpoint() {
+        assert!(validate_grpc_config(&config("https://auth.example.com:50051", "ca.pem")).is_ok());
+        assert!(validate_grpc_config(&config("https://auth.example.com:50051", "")).is_err());
+    }
+
+    #[test]
+    fn should_reject_invalid_endpoint() {
+        assert!(validate_grpc_config(&config("", "")).is_err());
+        assert!(validate_grpc_config(&config("ftp://127.0.0.1:50051", "")).is_err());
+        assert!(validate_grpc_config(&config("127.0.0.1:50051", "")).is_err());
+    }
+
+    #[test]
+    fn should_require_both_client_certificate_and_key() {
+        let mut config = config("https://auth.example.com:50051", "ca.pem");
+        config.tls_cert_file = "client.pem".to_owned();
+        assert!(validate_grpc_config(&config).is_err());
+        config.tls_key_file = "client_key.pem".to_owned();
+        assert!(validate_grpc_config(&config).is_ok());
+    }
+}
@@ -0,0 +1,165 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+pub mod dynamic_library;
+pub mod grpc;
+pub mod mtls;
+pub mod oidc;
+
+use crate::authenticator::dynamic_library::DynamicLibraryAuthenticator;
+use crate::authenticator::grpc::GrpcAuthenticator;
+use crate::authenticator::mtls::MtlsAuthenticator;
+use crate::authenticator::oidc::OidcAuthenticator;
+use crate::configs::system::AuthenticationConfig;
+use crate::server_error::AuthenticatorError;
+use derive_more::Display;
+use error_set::ErrContext;
+use serde::{Deserialize, Serialize};
+use std::fmt::Debug;
+use std::future::Future;
+use std::net::IpAddr;
+use std::str::FromStr;
+use tracing::info;
+
+pub const COMPONENT: &str = "AUTHENTICATOR";
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Display, Copy, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum AuthenticatorKindType {
+    #[display("internal")]
+    Internal,
+    #[display("oidc")]
+    Oidc,
+    #[display("mtls")]
+    Mtls,
+    #[display("dynamic_library")]
+    DynamicLibrary,
+    #[display("grpc")]
+    Grpc,
+}
+
+impl FromStr for AuthenticatorKindType {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "internal" => Ok(AuthenticatorKindType::Internal),
+            "oidc" => Ok(AuthenticatorKindType::Oidc),
+            "mtls" => Ok(AuthenticatorKindType::Mtls),
+            "dynamic_library" => Ok(AuthenticatorKindType::DynamicLibrary),
+            "grpc" => Ok(AuthenticatorKindType::Grpc),
+            _ => Err(format!("Unknown authenticator kind: {}", s)),
+        }
+    }
+}
+
+/// Credentials provided by the client on login.
+#[derive(Debug, Clone, Copy)]
+pub struct Credentials<'a> {
+    pub username: &'a str,
+    pub password: &'a str,
+    /// DER encoded certificate presented by the client during the TLS handshake, if any.
+    pub peer_certificate: Option<&'a [u8]>,
+}
+
+/// Verifies the credentials using an external identity backend.
+pub trait Authenticator: Send + Sync {
+    /// Returns the username of the user mapped to the verified identity,
+    /// or `None` if the credentials couldn't be verified by this authenticator.
+    fn authenticate(
+        &self,
+        credentials: &Credentials<'_>,
+    ) -> impl Future<Output = Result<Option<String>, AuthenticatorError>> + Send;
+}
+
+/// Authenticator resolved at startup from the configuration.
+/// The internal one is handled by the system itself, as it verifies the password of the stored user.
+#[derive(Debug)]
+pub enum AuthenticatorKind {
+    Internal,
+    Oidc(OidcAuthenticator),
+    Mtls(MtlsAuthenticator),
+    DynamicLibrary(DynamicLibraryAuthenticator),
+    Grpc(GrpcAuthenticator),
+}
+
+impl AuthenticatorKind {
+    /// Resolves the authenticators in the configured order.
+    pub fn resolve(config: &AuthenticationConfig) -> Result<Vec<Self>, AuthenticatorError> {
+        let mut authenticators = Vec::with_capacity(config.authenticators.len());
+        for kind in &config.authenticators {
+            let authenticator = match kind {
+                AuthenticatorKindType::Internal => Self::Internal,
+                AuthenticatorKindType::Oidc => {
+                    Self::Oidc(OidcAuthenticator::new(config.oidc.clone())?)
+                }
+                AuthenticatorKindType::Mtls => Self::Mtls(MtlsAuthenticator::new(&config.mtls)?),
+                AuthenticatorKindType::DynamicLibrary => Self::DynamicLibrary(
+                    DynamicLibraryAuthenticator::new(&config.dynamic_library.path)
+                        .with_error_context(|error| {
+                            format!(
+                                "{COMPONENT} (error: {error}) - failed to load library: {}",
+                                config.dynamic_library.path
+                            )
+                        })?,
+                ),
+                AuthenticatorKindType::Grpc => {
+                    Self::Grpc(GrpcAuthenticator::new(config.grpc.clone())?)
+                }
+            };
+            info!("Resolved {kind} authenticator.");
+            authenticators.push(authenticator);
+        }
+        Ok(authenticators)
+    }
+
+    pub fn kind(&self) -> AuthenticatorKindType {
+        match self {
+            Self::Internal => AuthenticatorKindType::Internal,
+            Self::Oidc(_) => AuthenticatorKindType::Oidc,
+            Self::Mtls(_) => AuthenticatorKindType::Mtls,
+            Self::DynamicLibrary(_) => AuthenticatorKindType::DynamicLibrary,
+            Self::Grpc(_) => AuthenticatorKindType::Grpc,
+        }
+    }
+
+    pub async fn authenticate(
+        &self,
+        credentials: &Credentials<'_>,
+    ) -> Result<Option<String>, AuthenticatorError> {
+        match self {
+            Self::Internal => Ok(None),
+            Self::Oidc(a) => a.authenticate(credentials).await,
+            Self::Mtls(a) => a.authenticate(credentials).await,
+            Self::DynamicLibrary(a) => a.authenticate(credentials).await,
+            Self::Grpc(a) => a.authenticate(credentials).await,
+        }
+    }
+}
+
+/// Checks whether the host of the authentication service endpoint is the loopback one,
+/// so that the credentials can be sent to it in plain text.
+pub(crate) fn is_loopback_host(host: &str) -> bool {
+    if host.eq_ignore_ascii_case("localhost") {
+        return true;
+    }
+
+    host.trim_start_matches('[')
+        .trim_end_matches(']')
+        .parse::<IpAddr>()
+        .is_ok_and(|address| address.is_loopback())
+}
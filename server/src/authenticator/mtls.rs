@@ -0,0 +1,319 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::authenticator::{Authenticator, Credentials};
+use crate::configs::system::MtlsAuthenticatorConfig;
+use crate::server_error::AuthenticatorError;
+use ahash::AHashMap;
+use ring::digest::{digest, SHA256};
+use std::fmt::Write;
+use tracing::{debug, warn};
+use x509_parser::certificate::X509Certificate;
+use x509_parser::error::X509Error;
+use x509_parser::extensions::GeneralName;
+use x509_parser::nom;
+use x509_parser::prelude::FromDer;
+
+const FINGERPRINT_LENGTH: usize = 64;
+
+/// Maps the client certificates to the users by their SHA-256 fingerprints,
+/// or by the common name and the subject alternative names of their subject.
+#[derive(Debug)]
+pub struct MtlsAuthenticator {
+    usernames: AHashMap<String, String>,
+    subjects: AHashMap<String, String>,
+    subject_as_username: bool,
+}
+
+impl MtlsAuthenticator {
+    pub fn new(config: &MtlsAuthenticatorConfig) -> Result<Self, AuthenticatorError> {
+        let mut usernames = AHashMap::with_capacity(config.certificate_mappings.len());
+        for mapping in &config.certificate_mappings {
+            let (fingerprint, username) = parse_certificate_mapping(mapping)?;
+            usernames.insert(fingerprint, username);
+        }
+        let mut subjects = AHashMap::with_capacity(config.subject_mappings.len());
+        for mapping in &config.subject_mappings {
+            let (subject, username) = parse_subject_mapping(mapping)?;
+            subjects.insert(subject, username);
+        }
+        Ok(Self {
+            usernames,
+            subjects,
+            subject_as_username: config.subject_as_username,
+        })
+    }
+
+    fn map_subject(&self, certificate: &[u8]) -> Option<String> {
+        let names = match subject_names(certificate) {
+            Ok(names) => names,
+            Err(error) => {
+                warn!("Cannot parse the client certificate. {error}");
+                return None;
+            }
+        };
+
+        if let Some(username) = names.iter().find_map(|name| self.subjects.get(name)) {
+            return Some(username.clone());
+        }
+
+        if self.subject_as_username {
+            return names.into_iter().next();
+        }
+
+        None
+    }
+}
+
+impl Authenticator for MtlsAuthenticator {
+    async fn authenticate(
+        &self,
+        credentials: &Credentials<'_>,
+    ) -> Result<Option<String>, AuthenticatorError> {
+        let Some(certificate) = credentials.peer_certificate else {
+            return Ok(None);
+        };
+
+        let fingerprint = calculate_fingerprint(certificate);
+        let username = self
+            .usernames
+            .get(&fingerprint)
+            .cloned()
+            .or_else(|| self.map_subject(certificate));
+        if username.is_none() {
+            debug!("Client certificate with fingerprint: {fingerprint} is not mapped to any user.");
+        }
+        Ok(username)
+    }
+}
+
+/// Parses the mapping in the "<SHA-256 fingerprint (hex)>=<username>" format,
+/// the fingerprint can contain the colons separating the bytes.
+pub fn parse_certificate_mapping(mapping: &str) -> Result<(String, String), AuthenticatorError> {
+    let Some((fingerprint, username)) = mapping.split_once('=') else {
+        return Err(AuthenticatorError::InvalidAuthenticatorConfiguration);
+    };
+
+    let fingerprint = fingerprint.trim().replace(':', "").to_lowercase();
+    let username = username.trim();
+    if fingerprint.len() != FINGERPRINT_LENGTH
+        || !fingerprint.chars().all(|c| c.is_ascii_hexdigit())
+        || username.is_empty()
+    {
+        return Err(AuthenticatorError::InvalidAuthenticatorConfiguration);
+    }
+
+    Ok((fingerprint, username.to_owned()))
+}
+
+/// Parses the mapping in the "<CN or SAN>=<username>" format, the subject name itself can contain
+/// the equal signs (e.g. the URI with the query), so the mapping is split at the last one.
+pub fn parse_subject_mapping(mapping: &str) -> Result<(String, String), AuthenticatorError> {
+    let Some((subject, username)) = mapping.rsplit_once('=') else {
+        return Err(AuthenticatorError::InvalidAuthenticatorConfiguration);
+    };
+
+    let subject = subject.trim();
+    let username = username.trim();
+    if subject.is_empty() || username.is_empty() {
+        return Err(AuthenticatorError::InvalidAuthenticatorConfiguration);
+    }
+
+    Ok((subject.to_owned(), username.to_owned()))
+}
+
+/// Returns the common names of the certificate subject, followed by its DNS, email and URI alternative names.
+fn subject_names(certificate: &[u8]) -> Result<Vec<String>, nom::Err<X509Error>> {
+    let (_, certificate) = X509Certificate::from_der(certificate)?;
+    let mut names = certificate
+        .subject()
+        .iter_common_name()
+        .filter_map(|name| name.as_str().ok())
+        .map(str::to_owned)
+        .collect::<Vec<_>>();
+    if let Ok(Some(alternative_names)) = certificate.subject_alternative_name() {
+        for name in &alternative_names.value.general_names {
+            match name {
+                GeneralName::DNSName(name)
+                | GeneralName::RFC822Name(name)
+                | GeneralName::URI(name) => names.push((*name).to_owned()),
+                _ => {}
+            }
+        }
+    }
+    Ok(names)
+}
+
+fn calculate_fingerprint(certificate: &[u8]) -> String {
+    digest(&SHA256, certificate).as_ref().iter().fold(
+        String::with_capacity(FINGERPRINT_LENGTH),
+        |mut hex, byte| {
+            let _ = write!(hex, "{byte:02x}");
+            hex
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CERTIFICATE: &[u8] = b"certificate";
+
+    fn config(
+        certificate_mappings: Vec<String>,
+        subject_mappings: Vec<String>,
+        subject_as_username: bool,
+    ) -> MtlsAuthenticatorConfig {
+        MtlsAuthenticatorConfig {
+            certificate_mappings,
+            subject_mappings,
+            subject_as_username,
+        }
+    }
+
+    fn generate_certificate(common_name: &str, alternative_names: &[&str]) -> Vec<u8> {
+        let mut params = rcgen::CertificateParams::new(
+            alternative_names
+                .iter()
+                .map(|name| name.to_string())
+                .collect::<Vec<_>>(),
+        )
+        .unwrap();
+        params
+            .distinguished_name
+            .push(rcgen::DnType::CommonName, common_name);
+        let key_pair = rcgen::KeyPair::generate().unwrap();
+        params.self_signed(&key_pair).unwrap().der().to_vec()
+    }
+
+    #[test]
+    fn mapping_should_be_parsed_with_or_without_colons() {
+        let fingerprint = calculate_fingerprint(CERTIFICATE);
+        let with_colons = fingerprint
+            .as_bytes()
+            .chunks(2)
+            .map(|chunk| std::str::from_utf8(chunk).unwrap().to_uppercase())
+            .collect::<Vec<_>>()
+            .join(":");
+
+        for mapping in [
+            format!("{fingerprint}=user"),
+            format!("{with_colons} = user"),
+        ] {
+            let (parsed_fingerprint, username) = parse_certificate_mapping(&mapping).unwrap();
+            assert_eq!(parsed_fingerprint, fingerprint);
+            assert_eq!(username, "user");
+        }
+    }
+
+    #[test]
+    fn invalid_mapping_should_be_rejected() {
+        assert!(parse_certificate_mapping("user").is_err());
+        assert!(parse_certificate_mapping("abc=user").is_err());
+        let fingerprint = calculate_fingerprint(CERTIFICATE);
+        assert!(parse_certificate_mapping(&format!("{fingerprint}=")).is_err());
+    }
+
+    #[tokio::test]
+    async fn mapped_certificate_should_be_authenticated_as_user() {
+        let fingerprint = calculate_fingerprint(CERTIFICATE);
+        let authenticator =
+            MtlsAuthenticator::new(&config(vec![format!("{fingerprint}=user")], vec![], false))
+                .unwrap();
+        let mut credentials = Credentials {
+            username: "user",
+            password: "secret",
+            peer_certificate: Some(CERTIFICATE),
+        };
+
+        let username = authenticator.authenticate(&credentials).await.unwrap();
+        assert_eq!(username.as_deref(), Some("user"));
+
+        credentials.peer_certificate = Some(b"other");
+        assert!(authenticator
+            .authenticate(&credentials)
+            .await
+            .unwrap()
+            .is_none());
+
+        credentials.peer_certificate = None;
+        assert!(authenticator
+            .authenticate(&credentials)
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn subject_mapping_should_be_split_at_last_equal_sign() {
+        let (subject, username) =
+            parse_subject_mapping("spiffe://iggy/orders?env=prod = orders").unwrap();
+        assert_eq!(subject, "spiffe://iggy/orders?env=prod");
+        assert_eq!(username, "orders");
+        assert!(parse_subject_mapping("orders-service").is_err());
+        assert!(parse_subject_mapping("=orders").is_err());
+    }
+
+    #[tokio::test]
+    async fn certificate_should_be_authenticated_by_common_name_or_alternative_name() {
+        let authenticator = MtlsAuthenticator::new(&config(
+            vec![],
+            vec![
+                "orders-service=orders".to_owned(),
+                "billing.iggy.local=billing".to_owned(),
+            ],
+            false,
+        ))
+        .unwrap();
+
+        for (certificate, expected_username) in [
+            (generate_certificate("orders-service", &[]), Some("orders")),
+            (
+                generate_certificate("billing", &["billing.iggy.local"]),
+                Some("billing"),
+            ),
+            (
+                generate_certificate("unknown", &["unknown.iggy.local"]),
+                None,
+            ),
+        ] {
+            let credentials = Credentials {
+                username: "",
+                password: "",
+                peer_certificate: Some(&certificate),
+            };
+            let username = authenticator.authenticate(&credentials).await.unwrap();
+            assert_eq!(username.as_deref(), expected_username);
+        }
+    }
+
+    #[tokio::test]
+    async fn common_name_should_be_used_as_username_if_enabled() {
+        let authenticator = MtlsAuthenticator::new(&config(vec![], vec![], true)).unwrap();
+        let certificate = generate_certificate("orders", &["orders.iggy.local"]);
+        let credentials = Credentials {
+            username: "",
+            password: "",
+            peer_certificate: Some(&certificate),
+        };
+
+        let username = authenticator.authenticate(&credentials).await.unwrap();
+        assert_eq!(username.as_deref(), Some("orders"));
+    }
+}
@@ -0,0 +1,246 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::authenticator::{is_loopback_host, Authenticator, Credentials, COMPONENT};
+use crate::configs::system::OidcAuthenticatorConfig;
+use crate::server_error::AuthenticatorError;
+use jsonwebtoken::{decode, decode_header, DecodingKey, Validation};
+use reqwest::{StatusCode, Url};
+use serde::Deserialize;
+use serde_json::Value;
+use tracing::{debug, error};
+
+/// Exchanges the username and password for the tokens at the OpenID Connect provider
+/// (resource owner password credentials grant) and maps the ID token to the user by the configured claim.
+/// The ID token is received directly from the token endpoint over TLS (or the loopback interface,
+/// see `validate_oidc_config`), so its signature isn't verified.
+#[derive(Debug)]
+pub struct OidcAuthenticator {
+    client: reqwest::Client,
+    config: OidcAuthenticatorConfig,
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    id_token: Option<String>,
+}
+
+impl OidcAuthenticator {
+    pub fn new(config: OidcAuthenticatorConfig) -> Result<Self, AuthenticatorError> {
+        // The provider might be already installed e.g. by the other component.
+        let _ = rustls::crypto::ring::default_provider().install_default();
+        let client = reqwest::Client::builder()
+            .timeout(config.timeout.get_duration())
+            .build()
+            .map_err(|error| {
+                error!("{COMPONENT} (error: {error}) - failed to create OIDC client");
+                AuthenticatorError::InvalidAuthenticatorConfiguration
+            })?;
+        Ok(Self { client, config })
+    }
+
+    fn map_id_token(&self, id_token: &str) -> Result<Option<String>, AuthenticatorError> {
+        let header = decode_header(id_token).map_err(|error| {
+            error!("{COMPONENT} (error: {error}) - invalid OIDC ID token header");
+            AuthenticatorError::InvalidAuthenticatorResponse
+        })?;
+        let mut validation = Validation::new(header.alg);
+        validation.insecure_disable_signature_validation();
+        validation.set_audience(&[&self.config.client_id]);
+        if !self.config.issuer.is_empty() {
+            validation.set_issuer(&[&self.config.issuer]);
+        }
+
+        let claims = match decode::<Value>(id_token, &DecodingKey::from_secret(&[]), &validation) {
+            Ok(token) => token.claims,
+            Err(error) => {
+                error!("{COMPONENT} (error: {error}) - invalid OIDC ID token");
+                return Ok(None);
+            }
+        };
+
+        Ok(claims
+            .get(&self.config.username_claim)
+            .and_then(Value::as_str)
+            .map(ToOwned::to_owned))
+    }
+}
+
+impl Authenticator for OidcAuthenticator {
+    async fn authenticate(
+        &self,
+        credentials: &Credentials<'_>,
+    ) -> Result<Option<String>, AuthenticatorError> {
+        let mut form = vec![
+            ("grant_type", "password"),
+            ("scope", "openid"),
+            ("username", credentials.username),
+            ("password", credentials.password),
+            ("client_id", &self.config.client_id),
+        ];
+        if !self.config.client_secret.is_empty() {
+            form.push(("client_secret", &self.config.client_secret));
+        }
+
+        let response = self
+            .client
+            .post(&self.config.token_endpoint)
+            .form(&form)
+            .send()
+            .await
+            .map_err(|error| AuthenticatorError::AuthenticatorRequestFailed {
+                reason: error.to_string(),
+            })?;
+
+        match response.status() {
+            status if status.is_success() => {}
+            StatusCode::BAD_REQUEST | StatusCode::UNAUTHORIZED => {
+                debug!(
+                    "OIDC provider rejected the credentials of user: {}.",
+                    credentials.username
+                );
+                return Ok(None);
+            }
+            status => {
+                return Err(AuthenticatorError::AuthenticatorRequestFailed {
+                    reason: format!("unexpected status: {status}"),
+                })
+            }
+        }
+
+        let body = response.bytes().await.map_err(|error| {
+            AuthenticatorError::AuthenticatorRequestFailed {
+                reason: error.to_string(),
+            }
+        })?;
+        let token_response = serde_json::from_slice::<TokenResponse>(&body)
+            .map_err(|_| AuthenticatorError::InvalidAuthenticatorResponse)?;
+        let Some(id_token) = token_response.id_token else {
+            error!("{COMPONENT} - OIDC provider didn't return the ID token, make sure that the client has the `openid` scope.");
+            return Err(AuthenticatorError::InvalidAuthenticatorResponse);
+        };
+
+        self.map_id_token(&id_token)
+    }
+}
+
+/// Validates the configuration of the OpenID Connect authenticator. The signature of the ID token
+/// isn't verified, so the token endpoint must be authenticated by TLS, while the "http" endpoints
+/// are accepted only for the loopback hosts.
+pub fn validate_oidc_config(config: &OidcAuthenticatorConfig) -> Result<(), AuthenticatorError> {
+    if config.client_id.is_empty() || config.username_claim.is_empty() {
+        return Err(AuthenticatorError::InvalidAuthenticatorConfiguration);
+    }
+
+    let Ok(url) = Url::parse(&config.token_endpoint) else {
+        return Err(AuthenticatorError::InvalidAuthenticatorConfiguration);
+    };
+
+    let Some(host) = url.host_str() else {
+        return Err(AuthenticatorError::InvalidAuthenticatorConfiguration);
+    };
+
+    let is_valid = match url.scheme() {
+        "https" => true,
+        "http" => is_loopback_host(host),
+        _ => false,
+    };
+    if !is_valid {
+        return Err(AuthenticatorError::InvalidAuthenticatorConfiguration);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy::utils::duration::IggyDuration;
+    use iggy::utils::timestamp::IggyTimestamp;
+    use jsonwebtoken::{encode, EncodingKey, Header};
+    use serde_json::json;
+
+    fn config(token_endpoint: &str, issuer: &str) -> OidcAuthenticatorConfig {
+        OidcAuthenticatorConfig {
+            token_endpoint: token_endpoint.to_owned(),
+            client_id: "iggy".to_owned(),
+            client_secret: "".to_owned(),
+            issuer: issuer.to_owned(),
+            username_claim: "preferred_username".to_owned(),
+            timeout: IggyDuration::ONE_SECOND,
+        }
+    }
+
+    fn authenticator(issuer: &str) -> OidcAuthenticator {
+        OidcAuthenticator::new(config("http://localhost/token", issuer)).unwrap()
+    }
+
+    fn id_token(issuer: &str, audience: &str) -> String {
+        let expiry = IggyTimestamp::now().to_secs() + 60;
+        encode(
+            &Header::default(),
+            &json!({
+                "iss": issuer,
+                "aud": audience,
+                "exp": expiry,
+                "preferred_username": "user",
+            }),
+            &EncodingKey::from_secret(b"provider_secret"),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn id_token_should_be_mapped_to_user_by_claim() {
+        let authenticator = authenticator("https://idp");
+        let username = authenticator
+            .map_id_token(&id_token("https://idp", "iggy"))
+            .unwrap();
+        assert_eq!(username.as_deref(), Some("user"));
+    }
+
+    #[test]
+    fn id_token_for_other_issuer_or_audience_should_not_be_mapped() {
+        let authenticator = authenticator("https://idp");
+        assert!(authenticator
+            .map_id_token(&id_token("https://other", "iggy"))
+            .unwrap()
+            .is_none());
+        assert!(authenticator
+            .map_id_token(&id_token("https://idp", "other"))
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn should_accept_plain_text_token_endpoint_only_for_loopback_host() {
+        assert!(validate_oidc_config(&config("http://127.0.0.1:8080/token", "")).is_ok());
+        assert!(validate_oidc_config(&config("http://localhost:8080/token", "")).is_ok());
+        assert!(validate_oidc_config(&config("http://[::1]:8080/token", "")).is_ok());
+        assert!(validate_oidc_config(&config("http://10.0.0.1:8080/token", "")).is_err());
+        assert!(validate_oidc_config(&config("http://idp.example.com/token", "")).is_err());
+        assert!(validate_oidc_config(&config("https://idp.example.com/token", "")).is_ok());
+    }
+
+    #[test]
+    fn should_reject_invalid_token_endpoint() {
+        assert!(validate_oidc_config(&config("", "")).is_err());
+        assert!(validate_oidc_config(&config("ftp://127.0.0.1/token", "")).is_err());
+        assert!(validate_oidc_config(&config("idp.example.com/token", "")).is_err());
+    }
+}
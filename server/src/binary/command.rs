@@ -17,32 +17,41 @@
  */
 
 use crate::binary::handlers::consumer_groups::{
-    create_consumer_group_handler, delete_consumer_group_handler, get_consumer_group_handler,
-    get_consumer_groups_handler, join_consumer_group_handler, leave_consumer_group_handler,
+    create_consumer_group_handler, delete_consumer_group_handler, delete_partition_state_handler,
+    get_consumer_group_handler, get_consumer_groups_handler, get_partition_state_handler,
+    join_consumer_group_handler, leave_consumer_group_handler, store_partition_state_handler,
+    update_consumer_group_dead_letter_handler, update_consumer_group_visibility_timeout_handler,
 };
 use crate::binary::handlers::consumer_offsets::*;
 use crate::binary::handlers::messages::*;
+use crate::binary::handlers::namespaces::{
+    create_namespace_handler, delete_namespace_handler, get_namespace_handler,
+    get_namespaces_handler, update_namespace_handler,
+};
 use crate::binary::handlers::partitions::*;
 use crate::binary::handlers::personal_access_tokens::{
     create_personal_access_token_handler, delete_personal_access_token_handler,
     get_personal_access_tokens_handler, login_with_personal_access_token_handler,
+    rotate_personal_access_token_handler,
 };
 use crate::binary::handlers::streams::*;
 use crate::binary::handlers::system::*;
 use crate::binary::handlers::topics::*;
 use crate::binary::handlers::users::{
     change_password_handler, create_user_handler, delete_user_handler, get_user_handler,
-    get_users_handler, login_user_handler, logout_user_handler, update_permissions_handler,
-    update_user_handler,
+    get_users_handler, login_as_handler, login_user_handler, logout_user_handler,
+    update_permissions_handler, update_user_handler, update_user_quotas_handler,
 };
 use crate::binary::sender::SenderKind;
 use crate::binary::COMPONENT;
 use crate::command::ServerCommand;
 use crate::streaming::session::Session;
-use crate::streaming::systems::system::SharedSystem;
+use crate::streaming::systems::system::{SharedSystem, System};
+use crate::AUDIT_LOG_TARGET;
 use error_set::ErrContext;
 use iggy::error::IggyError;
-use tracing::{debug, error};
+use tokio::time::sleep;
+use tracing::{debug, error, info};
 
 pub async fn handle(
     command: ServerCommand,
@@ -88,13 +97,79 @@ async fn try_handle(
     system: &SharedSystem,
 ) -> Result<(), IggyError> {
     debug!("Handling command '{command}', session: {session}...");
+    if let Some(impersonator_id) = session.get_impersonator_id() {
+        info!(
+            target: AUDIT_LOG_TARGET,
+            "User with ID: {impersonator_id} impersonating user with ID: {} executes command: '{command}', client ID: {}.",
+            session.get_user_id(),
+            session.client_id
+        );
+    }
+    let delay = {
+        let system = system.read().await;
+        ensure_in_personal_access_token_scope(&command, session, &system)?;
+        if !command.is_read_only() {
+            system.ensure_writable()?;
+        }
+        if command.is_replicated() {
+            system.ensure_cluster_leader()?;
+        }
+        if !command.is_allowed_in_maintenance() {
+            system.ensure_not_in_maintenance(command.is_read_only())?;
+        }
+        system.throttle_request(session)
+    };
+    // The client exceeding its request quota is slowed down, instead of being disconnected.
+    if !delay.is_zero() {
+        sleep(delay).await;
+    }
+
     match command {
         ServerCommand::Ping(command) => {
             ping_handler::handle(command, sender, session, system).await
         }
+        ServerCommand::Handshake(command) => {
+            handshake_handler::handle(command, sender, session).await
+        }
         ServerCommand::GetStats(command) => {
             get_stats_handler::handle(command, sender, session, system).await
         }
+        ServerCommand::GetMaintenanceMode(command) => {
+            get_maintenance_mode_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::GetServerInfo(command) => {
+            get_server_info_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::SetMaintenanceMode(command) => {
+            set_maintenance_mode_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::CreateBackup(command) => {
+            create_backup_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::GetUnsavedState(command) => {
+            get_unsaved_state_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::GetAuditLog(command) => {
+            get_audit_log_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::GetErrorCatalog(command) => {
+            get_error_catalog_handler::handle(command, sender, session).await
+        }
+        ServerCommand::GetNamespace(command) => {
+            get_namespace_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::GetNamespaces(command) => {
+            get_namespaces_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::CreateNamespace(command) => {
+            create_namespace_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::UpdateNamespace(command) => {
+            update_namespace_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::DeleteNamespace(command) => {
+            delete_namespace_handler::handle(command, sender, session, system).await
+        }
         ServerCommand::GetMe(command) => {
             get_me_handler::handle(command, sender, session, system).await
         }
@@ -122,6 +197,9 @@ async fn try_handle(
         ServerCommand::UpdatePermissions(command) => {
             update_permissions_handler::handle(command, sender, session, system).await
         }
+        ServerCommand::UpdateUserQuotas(command) => {
+            update_user_quotas_handler::handle(command, sender, session, system).await
+        }
         ServerCommand::ChangePassword(command) => {
             change_password_handler::handle(command, sender, session, system).await
         }
@@ -131,6 +209,9 @@ async fn try_handle(
         ServerCommand::LogoutUser(command) => {
             logout_user_handler::handle(command, sender, session, system).await
         }
+        ServerCommand::LoginAs(command) => {
+            login_as_handler::handle(command, sender, session, system).await
+        }
         ServerCommand::GetPersonalAccessTokens(command) => {
             get_personal_access_tokens_handler::handle(command, sender, session, system).await
         }
@@ -140,12 +221,18 @@ async fn try_handle(
         ServerCommand::DeletePersonalAccessToken(command) => {
             delete_personal_access_token_handler::handle(command, sender, session, system).await
         }
+        ServerCommand::RotatePersonalAccessToken(command) => {
+            rotate_personal_access_token_handler::handle(command, sender, session, system).await
+        }
         ServerCommand::LoginWithPersonalAccessToken(command) => {
             login_with_personal_access_token_handler::handle(command, sender, session, system).await
         }
         ServerCommand::SendMessages(command) => {
             send_messages_handler::handle(command, sender, session, system).await
         }
+        ServerCommand::SendMessagesBatch(command) => {
+            send_messages_batch_handler::handle(command, sender, session, system).await
+        }
         ServerCommand::PollMessages(command) => {
             poll_messages_handler::handle(command, sender, session, system).await
         }
@@ -155,6 +242,12 @@ async fn try_handle(
         ServerCommand::StoreConsumerOffset(command) => {
             store_consumer_offset_handler::handle(command, sender, session, system).await
         }
+        ServerCommand::StoreConsumerOffsets(command) => {
+            store_consumer_offsets_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::GetOffsetsForTimestamps(command) => {
+            get_offsets_for_timestamps_handler::handle(command, sender, session, system).await
+        }
         ServerCommand::DeleteConsumerOffset(command) => {
             delete_consumer_offset_handler::handle(command, sender, session, system).await
         }
@@ -176,6 +269,12 @@ async fn try_handle(
         ServerCommand::PurgeStream(command) => {
             purge_stream_handler::handle(command, sender, session, system).await
         }
+        ServerCommand::UpdateStreamMetadata(command) => {
+            update_stream_metadata_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::UpdateStreamQuota(command) => {
+            update_stream_quota_handler::handle(command, sender, session, system).await
+        }
         ServerCommand::GetTopic(command) => {
             get_topic_handler::handle(command, sender, session, system).await
         }
@@ -194,12 +293,36 @@ async fn try_handle(
         ServerCommand::PurgeTopic(command) => {
             purge_topic_handler::handle(command, sender, session, system).await
         }
+        ServerCommand::UpdateTopicMetadata(command) => {
+            update_topic_metadata_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::UpdateTopicProducers(command) => {
+            update_topic_producers_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::UpdateTopicConfig(command) => {
+            update_topic_config_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::UpdateTopicExpiryWatcher(command) => {
+            update_topic_expiry_watcher_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::MarkTopicForDeletion(command) => {
+            mark_topic_for_deletion_handler::handle(command, sender, session, system).await
+        }
         ServerCommand::CreatePartitions(command) => {
             create_partitions_handler::handle(command, sender, session, system).await
         }
         ServerCommand::DeletePartitions(command) => {
             delete_partitions_handler::handle(command, sender, session, system).await
         }
+        ServerCommand::RestoreArchivedSegments(command) => {
+            restore_archived_segments_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::FlushPartition(command) => {
+            flush_partition_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::ReadSegmentRange(command) => {
+            read_segment_range_handler::handle(command, sender, session, system).await
+        }
         ServerCommand::GetConsumerGroup(command) => {
             get_consumer_group_handler::handle(command, sender, session, system).await
         }
@@ -212,17 +335,136 @@ async fn try_handle(
         ServerCommand::DeleteConsumerGroup(command) => {
             delete_consumer_group_handler::handle(command, sender, session, system).await
         }
+        ServerCommand::UpdateConsumerGroupDeadLetter(command) => {
+            update_consumer_group_dead_letter_handler::handle(command, sender, session, system)
+                .await
+        }
+        ServerCommand::UpdateConsumerGroupVisibilityTimeout(command) => {
+            update_consumer_group_visibility_timeout_handler::handle(
+                command, sender, session, system,
+            )
+            .await
+        }
         ServerCommand::JoinConsumerGroup(command) => {
             join_consumer_group_handler::handle(command, sender, session, system).await
         }
         ServerCommand::LeaveConsumerGroup(command) => {
             leave_consumer_group_handler::handle(command, sender, session, system).await
         }
+        ServerCommand::GetPartitionState(command) => {
+            get_partition_state_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::StorePartitionState(command) => {
+            store_partition_state_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::DeletePartitionState(command) => {
+            delete_partition_state_handler::handle(command, sender, session, system).await
+        }
         ServerCommand::FlushUnsavedBuffer(command) => {
             flush_unsaved_buffer_handler::handle(command, sender, session, system).await
         }
+        ServerCommand::NackMessages(command) => {
+            nack_messages_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::AckMessages(command) => {
+            ack_messages_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::ReplayMessages(command) => {
+            replay_messages_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::GetReplayJobs(command) => {
+            get_replay_jobs_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::CancelReplayJob(command) => {
+            cancel_replay_job_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::CreatePushSubscription(command) => {
+            create_push_subscription_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::GetPushSubscriptions(command) => {
+            get_push_subscriptions_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::DeletePushSubscription(command) => {
+            delete_push_subscription_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::RegisterProducer(command) => {
+            register_producer_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::InitProducerId(command) => {
+            init_producer_id_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::BeginTransaction(command) => {
+            begin_transaction_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::CommitTransaction(command) => {
+            commit_transaction_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::AbortTransaction(command) => {
+            abort_transaction_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::AggregateMessages(command) => {
+            aggregate_messages_handler::handle(command, sender, session, system).await
+        }
         ServerCommand::GetSnapshotFile(command) => {
             get_snapshot::handle(command, sender, session, system).await
         }
     }
 }
+
+/// Checks the command against the scope of the personal access token used to authenticate the session.
+/// The permissions of the user are still validated separately by the system.
+fn ensure_in_personal_access_token_scope(
+    command: &ServerCommand,
+    session: &Session,
+    system: &System,
+) -> Result<(), IggyError> {
+    let Some(scope) = session.personal_access_token_scope() else {
+        return Ok(());
+    };
+    if !scope.restricts_commands() {
+        return Ok(());
+    }
+
+    // Impersonating another user would drop the scope of the token.
+    if let ServerCommand::LoginAs(_) = command {
+        error!("Command: '{command}' is not allowed with a scoped personal access token, session: {session}.");
+        return Err(IggyError::PersonalAccessTokenScopeExceeded);
+    }
+
+    if scope.read_only && !command.is_read_only() && !command.is_consuming() {
+        error!("Command: '{command}' is not allowed with a read-only personal access token, session: {session}.");
+        return Err(IggyError::PersonalAccessTokenScopeExceeded);
+    }
+
+    if scope.resources.is_empty() {
+        return Ok(());
+    }
+
+    let resources = command.get_stream_resources();
+    if resources.is_empty() && !command.is_session_level() {
+        error!("Command: '{command}' is not allowed with a personal access token limited to the streams, session: {session}.");
+        return Err(IggyError::PersonalAccessTokenScopeExceeded);
+    }
+
+    for (stream_id, topic_id) in resources {
+        let stream = system
+            .get_stream(session, stream_id)
+            .map_err(|_| IggyError::PersonalAccessTokenScopeExceeded)?;
+        let allowed = match topic_id {
+            Some(topic_id) => {
+                let topic = stream
+                    .get_topic(topic_id)
+                    .map_err(|_| IggyError::PersonalAccessTokenScopeExceeded)?;
+                scope.allows_topic(topic.stream_id, topic.topic_id)
+            }
+            None if command.is_read_only() => scope.allows_any_of_stream(stream.stream_id),
+            None => scope.allows_stream(stream.stream_id),
+        };
+        if !allowed {
+            error!("Command: '{command}' targets the stream: {stream_id} outside of the personal access token scope, session: {session}.");
+            return Err(IggyError::PersonalAccessTokenScopeExceeded);
+        }
+    }
+
+    Ok(())
+}
@@ -45,6 +45,7 @@ pub async fn handle(
                 &command.topic_id,
                 command.group_id,
                 &command.name,
+                command.offset_recovery,
             )
             .await
             .with_error_context(|error| {
@@ -55,7 +56,8 @@ pub async fn handle(
             })?;
     let consumer_group = consumer_group.read().await;
     let group_id = consumer_group.group_id;
-    let response = mapper::map_consumer_group(&consumer_group).await;
+    let response =
+        mapper::map_consumer_group(&consumer_group, session.get_protocol_features()).await;
     drop(consumer_group);
 
     let system = system.downgrade();
@@ -48,7 +48,8 @@ pub async fn handle(
     };
 
     let consumer_group = consumer_group.read().await;
-    let consumer_group = mapper::map_consumer_group(&consumer_group).await;
+    let consumer_group =
+        mapper::map_consumer_group(&consumer_group, session.get_protocol_features()).await;
     sender.send_ok_response(&consumer_group).await?;
     Ok(())
 }
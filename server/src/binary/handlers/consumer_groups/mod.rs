@@ -18,9 +18,14 @@
 
 pub mod create_consumer_group_handler;
 pub mod delete_consumer_group_handler;
+pub mod delete_partition_state_handler;
 pub mod get_consumer_group_handler;
 pub mod get_consumer_groups_handler;
+pub mod get_partition_state_handler;
 pub mod join_consumer_group_handler;
 pub mod leave_consumer_group_handler;
+pub mod store_partition_state_handler;
+pub mod update_consumer_group_dead_letter_handler;
+pub mod update_consumer_group_visibility_timeout_handler;
 
 pub const COMPONENT: &str = "CONSUMER_GROUP_HANDLER";
@@ -0,0 +1,82 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::{handlers::consumer_groups::COMPONENT, sender::SenderKind};
+use crate::state::command::EntryCommand;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use error_set::ErrContext;
+use iggy::consumer_groups::update_consumer_group_dead_letter::UpdateConsumerGroupDeadLetter;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use tracing::{debug, instrument};
+
+#[instrument(skip_all, name = "trace_update_consumer_group_dead_letter", fields(iggy_user_id = session.get_user_id(), iggy_client_id = session.client_id, iggy_stream_id = command.stream_id.as_string(), iggy_topic_id = command.topic_id.as_string(), iggy_group_id = command.group_id.as_string()))]
+pub async fn handle(
+    command: UpdateConsumerGroupDeadLetter,
+    sender: &mut SenderKind,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read().await;
+    let dead_letter = system
+        .update_consumer_group_dead_letter(
+            session,
+            &command.stream_id,
+            &command.topic_id,
+            &command.group_id,
+            command.dead_letter_stream_id.as_ref(),
+            command.dead_letter_topic_id.as_ref(),
+            command.max_delivery_count,
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to update dead letter of consumer group with ID: {} for topic with ID: {} and stream with ID: {}, session: {}",
+                command.group_id, command.topic_id, command.stream_id, session
+            )
+        })?;
+
+    // The dead-letter topic is stored by its numeric IDs, so that it's not affected by the later renames.
+    let command = UpdateConsumerGroupDeadLetter {
+        dead_letter_stream_id: dead_letter
+            .map(|dead_letter| Identifier::numeric(dead_letter.stream_id))
+            .transpose()?,
+        dead_letter_topic_id: dead_letter
+            .map(|dead_letter| Identifier::numeric(dead_letter.topic_id))
+            .transpose()?,
+        ..command
+    };
+    let group_id = command.group_id.clone();
+    system
+        .state
+        .apply(
+            session.get_user_id(),
+            EntryCommand::UpdateConsumerGroupDeadLetter(command),
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to apply update dead letter of consumer group with ID: {group_id}, session: {session}"
+            )
+        })?;
+    sender.send_empty_ok_response().await?;
+    Ok(())
+}
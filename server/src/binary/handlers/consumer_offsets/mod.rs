@@ -18,6 +18,8 @@
 
 pub mod delete_consumer_offset_handler;
 pub mod get_consumer_offset_handler;
+pub mod get_offsets_for_timestamps_handler;
 pub mod store_consumer_offset_handler;
+pub mod store_consumer_offsets_handler;
 
 pub const COMPONENT: &str = "CONSUMER_OFFSET_HANDLER";
@@ -0,0 +1,48 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::{handlers::messages::COMPONENT, sender::SenderKind};
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::messages::cancel_replay_job::CancelReplayJob;
+use tracing::{debug, instrument};
+
+#[instrument(skip_all, name = "trace_cancel_replay_job", fields(iggy_user_id = session.get_user_id(), iggy_client_id = session.client_id, iggy_replay_job_id = command.job_id))]
+pub async fn handle(
+    command: CancelReplayJob,
+    sender: &mut SenderKind,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    system
+        .read()
+        .await
+        .cancel_replay_job(session, command.job_id)
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to cancel replay job with ID: {}, session: {session}",
+                command.job_id
+            )
+        })?;
+    sender.send_empty_ok_response().await?;
+    Ok(())
+}
@@ -16,8 +16,23 @@
  * under the License.
  */
 
+pub mod abort_transaction_handler;
+pub mod ack_messages_handler;
+pub mod aggregate_messages_handler;
+pub mod begin_transaction_handler;
+pub mod cancel_replay_job_handler;
+pub mod commit_transaction_handler;
+pub mod create_push_subscription_handler;
+pub mod delete_push_subscription_handler;
 pub mod flush_unsaved_buffer_handler;
+pub mod get_push_subscriptions_handler;
+pub mod get_replay_jobs_handler;
+pub mod init_producer_id_handler;
+pub mod nack_messages_handler;
 pub mod poll_messages_handler;
+pub mod register_producer_handler;
+pub mod replay_messages_handler;
+pub mod send_messages_batch_handler;
 pub mod send_messages_handler;
 
 pub const COMPONENT: &str = "MESSAGE_HANDLER";
@@ -26,6 +26,8 @@ use anyhow::Result;
 use error_set::ErrContext;
 use iggy::error::IggyError;
 use iggy::messages::poll_messages::PollMessages;
+use std::time::Duration;
+use tokio::time::sleep;
 use tracing::debug;
 
 pub async fn handle(
@@ -36,21 +38,72 @@ pub async fn handle(
 ) -> Result<(), IggyError> {
     debug!("session: {session}, command: {command}");
     let system = system.read().await;
-    let messages = system
+    let args = PollingArgs::new(
+        command.strategy,
+        command.count,
+        command.auto_commit,
+        command.isolation_level,
+    )
+    .with_chunk_size(command.chunk_size)
+    .with_filter(command.filter)
+    .with_partition_epoch(command.partition_epoch)
+    .with_peek(command.peek);
+    let features = session.get_protocol_features();
+    let with_partition_epoch = command.partition_epoch.is_some();
+    if sender.supports_zero_copy() {
+        let message_slices = system
+            .poll_message_slices(
+                session,
+                &command.consumer,
+                &command.stream_id,
+                &command.topic_id,
+                command.partition_id,
+                &args,
+            )
+            .await
+            .with_error_context(|error| format!(
+                "{COMPONENT} (error: {error}) - failed to poll message slices for consumer: {}, stream ID: {}, topic ID: {}, partition_id: {:?}, session: {}.",
+                command.consumer, command.stream_id, command.topic_id, command.partition_id, session
+            ))?;
+        if let Some(mut message_slices) = message_slices {
+            let (delay, throttle_time_ms) =
+                system.split_fetch_throttle_time(message_slices.throttle_time_ms);
+            message_slices.throttle_time_ms = throttle_time_ms;
+            drop(system);
+            delay_throttled_response(delay).await;
+            let message_slices =
+                mapper::map_message_slices(&message_slices, features, with_partition_epoch);
+            sender.send_ok_response_slices(&message_slices).await?;
+            return Ok(());
+        }
+    }
+
+    let mut messages = system
         .poll_messages(
             session,
             &command.consumer,
             &command.stream_id,
             &command.topic_id,
             command.partition_id,
-            PollingArgs::new(command.strategy, command.count, command.auto_commit),
+            args,
         )
         .await
         .with_error_context(|error| format!(
             "{COMPONENT} (error: {error}) - failed to poll messages for consumer: {}, stream ID: {}, topic ID: {}, partition_id: {:?}, session: {}.",
             command.consumer, command.stream_id, command.topic_id, command.partition_id, session
         ))?;
-    let messages = mapper::map_polled_messages(&messages);
-    sender.send_ok_response(&messages).await?;
+    let (delay, throttle_time_ms) = system.split_fetch_throttle_time(messages.throttle_time_ms);
+    messages.throttle_time_ms = throttle_time_ms;
+    drop(system);
+    delay_throttled_response(delay).await;
+    let messages = mapper::map_polled_messages(&messages, features, with_partition_epoch);
+    sender.send_ok_response_vectored(&messages).await?;
     Ok(())
 }
+
+/// Delays the response of the consumer exceeding its fetch quota, after the system lock has been released.
+async fn delay_throttled_response(delay: Duration) {
+    if !delay.is_zero() {
+        sleep(delay).await;
+    }
+}
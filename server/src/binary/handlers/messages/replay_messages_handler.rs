@@ -0,0 +1,62 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::{handlers::messages::COMPONENT, mapper, sender::SenderKind};
+use crate::streaming::replay::replayer;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::messages::replay_messages::ReplayMessages;
+use tracing::{debug, instrument};
+
+#[instrument(skip_all, name = "trace_replay_messages", fields(iggy_user_id = session.get_user_id(), iggy_client_id = session.client_id, iggy_stream_id = command.source_stream_id.as_string(), iggy_topic_id = command.source_topic_id.as_string(), iggy_partition_id = command.source_partition_id))]
+pub async fn handle(
+    command: ReplayMessages,
+    sender: &mut SenderKind,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let job = system
+        .read()
+        .await
+        .create_replay_job(
+            session,
+            &command.source_stream_id,
+            &command.source_topic_id,
+            command.source_partition_id,
+            command.range,
+            &command.destination_stream_id,
+            &command.destination_topic_id,
+            command.partitioning.clone(),
+            command.headers.clone(),
+            command.messages_per_second,
+            command.batch_size,
+        )
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to create replay job for command: {command}, session: {session}"
+            )
+        })?;
+    replayer::start(system.clone(), job.clone());
+    let response = mapper::map_replay_job(&job.to_info());
+    sender.send_ok_response(&response).await?;
+    Ok(())
+}
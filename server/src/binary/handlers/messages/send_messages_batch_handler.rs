@@ -0,0 +1,60 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::binary::handlers::messages::COMPONENT;
+use crate::binary::sender::SenderKind;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::messages::send_messages_batch::SendMessagesBatch;
+use iggy::utils::sizeable::Sizeable;
+use tokio::time::sleep;
+use tracing::debug;
+
+pub async fn handle(
+    command: SendMessagesBatch,
+    sender: &mut SenderKind,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read().await;
+    let batches_count = command.batches.len();
+    let bytes = command
+        .batches
+        .iter()
+        .flat_map(|batch| &batch.messages)
+        .map(|message| message.get_size_bytes().as_bytes_u64())
+        .sum::<u64>();
+    system
+        .append_messages_batch(session, command.batches, None)
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to append {batches_count} messages batches, session: {session}"
+            )
+        })?;
+    let delay = system.throttle_produce(session, bytes);
+    drop(system);
+    if !delay.is_zero() {
+        sleep(delay).await;
+    }
+    sender.send_empty_ok_response().await?;
+    Ok(())
+}
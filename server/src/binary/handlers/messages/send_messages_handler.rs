@@ -24,6 +24,8 @@ use anyhow::Result;
 use error_set::ErrContext;
 use iggy::error::IggyError;
 use iggy::messages::send_messages::SendMessages;
+use iggy::utils::sizeable::Sizeable;
+use tokio::time::sleep;
 use tracing::debug;
 
 pub async fn handle(
@@ -38,6 +40,10 @@ pub async fn handle(
     let topic_id = command.topic_id.clone();
     let partitioning = command.partitioning.clone();
     let messages = command.messages;
+    let bytes = messages
+        .iter()
+        .map(|message| message.get_size_bytes().as_bytes_u64())
+        .sum::<u64>();
     // TODO(haze): Add confirmation level after testing is complete
     system
         .append_messages(session, stream_id, topic_id, partitioning, messages, None)
@@ -48,6 +54,11 @@ pub async fn handle(
                 command.stream_id, command.topic_id, command.partitioning, session
             )
         })?;
+    let delay = system.throttle_produce(session, bytes);
+    drop(system);
+    if !delay.is_zero() {
+        sleep(delay).await;
+    }
     sender.send_empty_ok_response().await?;
     Ok(())
 }
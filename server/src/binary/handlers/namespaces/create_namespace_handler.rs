@@ -0,0 +1,72 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::handlers::namespaces::COMPONENT;
+use crate::binary::mapper;
+use crate::binary::sender::SenderKind;
+use crate::state::command::EntryCommand;
+use crate::state::models::CreateNamespaceWithId;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::namespaces::create_namespace::CreateNamespace;
+use tracing::{debug, instrument};
+
+#[instrument(skip_all, name = "trace_create_namespace", fields(iggy_user_id = session.get_user_id(), iggy_client_id = session.client_id))]
+pub async fn handle(
+    command: CreateNamespace,
+    sender: &mut SenderKind,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+
+    let mut system = system.write().await;
+    let namespace_id = system
+        .create_namespace(session, None, &command.name, command.quotas)
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to create namespace with name: {}, session: {session}",
+                command.name
+            )
+        })?
+        .namespace_id;
+    let namespace = system.get_namespace(&Identifier::numeric(namespace_id)?)?;
+    let response = mapper::map_namespace(&system.get_namespace_details(namespace));
+
+    let system = system.downgrade();
+    system
+        .state
+        .apply(
+            session.get_user_id(),
+            EntryCommand::CreateNamespace(CreateNamespaceWithId {
+                namespace_id,
+                command,
+            }),
+        )
+        .await
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to apply create namespace with ID: {namespace_id}, session: {session}")
+        })?;
+    sender.send_ok_response(&response).await?;
+    Ok(())
+}
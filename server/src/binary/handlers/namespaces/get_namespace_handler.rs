@@ -0,0 +1,49 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::mapper;
+use crate::binary::sender::SenderKind;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use iggy::error::IggyError;
+use iggy::namespaces::get_namespace::GetNamespace;
+use tracing::debug;
+
+pub async fn handle(
+    command: GetNamespace,
+    sender: &mut SenderKind,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read().await;
+    let Ok(namespace) = system.try_find_namespace(session, &command.namespace_id) else {
+        sender.send_empty_ok_response().await?;
+        return Ok(());
+    };
+
+    let Some(namespace) = namespace else {
+        sender.send_empty_ok_response().await?;
+        return Ok(());
+    };
+
+    let response = mapper::map_namespace(&system.get_namespace_details(namespace));
+    sender.send_ok_response(&response).await?;
+    Ok(())
+}
@@ -0,0 +1,58 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::handlers::namespaces::COMPONENT;
+use crate::binary::sender::SenderKind;
+use crate::state::command::EntryCommand;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::namespaces::update_namespace::UpdateNamespace;
+use tracing::{debug, instrument};
+
+#[instrument(skip_all, name = "trace_update_namespace", fields(iggy_user_id = session.get_user_id(), iggy_client_id = session.client_id, iggy_namespace_id = command.namespace_id.as_string()))]
+pub async fn handle(
+    command: UpdateNamespace,
+    sender: &mut SenderKind,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let namespace_id = command.namespace_id.clone();
+
+    let mut system = system.write().await;
+    system
+        .update_namespace(session, &command.namespace_id, command.quotas)
+        .await
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to update namespace with ID: {namespace_id}, session: {session}")
+        })?;
+
+    let system = system.downgrade();
+    system
+        .state
+        .apply(session.get_user_id(), EntryCommand::UpdateNamespace(command))
+        .await
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to apply update namespace with ID: {namespace_id}, session: {session}")
+        })?;
+    sender.send_empty_ok_response().await?;
+    Ok(())
+}
@@ -18,5 +18,8 @@
 
 pub mod create_partitions_handler;
 pub mod delete_partitions_handler;
+pub mod flush_partition_handler;
+pub mod read_segment_range_handler;
+pub mod restore_archived_segments_handler;
 
 pub const COMPONENT: &str = "PARTITIONS_HANDLER";
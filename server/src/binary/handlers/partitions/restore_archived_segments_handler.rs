@@ -0,0 +1,58 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::handlers::partitions::COMPONENT;
+use crate::binary::mapper;
+use crate::binary::sender::SenderKind;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::partitions::restore_archived_segments::RestoreArchivedSegments;
+use tracing::{debug, instrument};
+
+#[instrument(skip_all, name = "trace_restore_archived_segments", fields(iggy_user_id = session.get_user_id(), iggy_client_id = session.client_id, iggy_stream_id = command.stream_id.as_string(), iggy_topic_id = command.topic_id.as_string(), iggy_partition_id = command.partition_id))]
+pub async fn handle(
+    command: RestoreArchivedSegments,
+    sender: &mut SenderKind,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let restored_segments = system
+        .read()
+        .await
+        .restore_archived_segments(
+            session,
+            &command.stream_id,
+            &command.topic_id,
+            command.partition_id,
+            command.start_offset,
+            command.end_offset,
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to restore archived segments for partition with ID: {} for topic with ID: {} in stream with ID: {}, session: {session}",
+                command.partition_id, command.topic_id, command.stream_id
+            )
+        })?;
+    let bytes = mapper::map_restored_segments(&restored_segments);
+    sender.send_ok_response(&bytes).await?;
+    Ok(())
+}
@@ -40,7 +40,7 @@ pub async fn handle(
 
     let mut system = system.write().await;
     let token = system
-            .create_personal_access_token(session, &command.name, command.expiry)
+            .create_personal_access_token(session, &command.name, command.expiry, &command.scope)
             .await
             .with_error_context(|error| {
                 format!(
@@ -61,6 +61,7 @@ pub async fn handle(
                 command: CreatePersonalAccessToken {
                     name: command.name.to_owned(),
                     expiry: command.expiry,
+                    scope: command.scope.clone(),
                 }
             }),
         )
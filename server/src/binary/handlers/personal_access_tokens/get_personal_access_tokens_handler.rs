@@ -40,7 +40,10 @@ pub async fn handle(
         .with_error_context(|error| {
             format!("{COMPONENT} (error: {error}) - failed to get personal access tokens with session: {session}")
         })?;
-    let personal_access_tokens = mapper::map_personal_access_tokens(&personal_access_tokens);
+    let personal_access_tokens = mapper::map_personal_access_tokens(
+        &personal_access_tokens,
+        session.get_protocol_features(),
+    );
     sender.send_ok_response(&personal_access_tokens).await?;
     Ok(())
 }
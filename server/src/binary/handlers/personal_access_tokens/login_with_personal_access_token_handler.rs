@@ -36,7 +36,11 @@ pub async fn handle(
     debug!("session: {session}, command: {command}");
     let system = system.read().await;
     let user = system
-        .login_with_personal_access_token(&command.token, Some(session))
+        .login_with_personal_access_token(
+            &command.token,
+            Some(session),
+            Some(session.ip_address.ip()),
+        )
         .await
         .with_error_context(|error| {
             format!(
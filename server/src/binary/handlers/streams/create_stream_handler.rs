@@ -40,7 +40,7 @@ pub async fn handle(
 
     let mut system = system.write().await;
     let stream = system
-            .create_stream(session, command.stream_id, &command.name)
+            .create_stream(session, command.stream_id, &command.name, command.metadata.clone(), command.namespace_id)
             .await
             .with_error_context(|error| {
                 format!(
@@ -49,7 +49,11 @@ pub async fn handle(
                 )
             })?;
     let stream_id = stream.stream_id;
-    let response = mapper::map_stream(stream);
+    let command = CreateStream {
+        namespace_id: stream.namespace_id,
+        ..command
+    };
+    let response = mapper::map_stream(stream, session.get_protocol_features());
 
     let system = system.downgrade();
     system
@@ -43,7 +43,7 @@ pub async fn handle(
         return Ok(());
     };
 
-    let response = mapper::map_stream(stream);
+    let response = mapper::map_stream(stream, session.get_protocol_features());
     sender.send_ok_response(&response).await?;
     Ok(())
 }
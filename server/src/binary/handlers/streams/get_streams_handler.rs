@@ -35,10 +35,12 @@ pub async fn handle(
 ) -> Result<(), IggyError> {
     debug!("session: {session}, command: {command}");
     let system = system.read().await;
-    let streams = system.find_streams(session).with_error_context(|error| {
-        format!("{COMPONENT} (error: {error}) - failed to find streams for session: {session}")
-    })?;
-    let response = mapper::map_streams(&streams);
+    let streams = system
+        .find_streams(session, &command.filter)
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to find streams for session: {session}")
+        })?;
+    let response = mapper::map_streams(&streams, session.get_protocol_features());
     sender.send_ok_response(&response).await?;
     Ok(())
 }
@@ -22,5 +22,7 @@ pub mod get_stream_handler;
 pub mod get_streams_handler;
 pub mod purge_stream_handler;
 pub mod update_stream_handler;
+pub mod update_stream_metadata_handler;
+pub mod update_stream_quota_handler;
 
 pub const COMPONENT: &str = "STREAM_HANDLER";
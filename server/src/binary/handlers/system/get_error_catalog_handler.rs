@@ -0,0 +1,34 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::binary::mapper;
+use crate::binary::sender::SenderKind;
+use crate::streaming::session::Session;
+use iggy::error::IggyError;
+use iggy::system::get_error_catalog::GetErrorCatalog;
+use tracing::debug;
+
+pub async fn handle(
+    command: GetErrorCatalog,
+    sender: &mut SenderKind,
+    session: &Session,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let bytes = mapper::map_error_catalog(&IggyError::catalog());
+    sender.send_ok_response(&bytes).await?;
+    Ok(())
+}
@@ -0,0 +1,63 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::binary::sender::SenderKind;
+use crate::streaming::session::Session;
+use anyhow::Result;
+use iggy::bytes_serializable::BytesSerializable;
+use iggy::error::IggyError;
+use iggy::system::handshake::Handshake;
+use tracing::{debug, info};
+
+pub async fn handle(
+    command: Handshake,
+    sender: &mut SenderKind,
+    session: &Session,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let accepted = Handshake {
+        resource_metadata: command.resource_metadata,
+        remaining_messages: command.remaining_messages,
+        message_id_scheme: command.message_id_scheme,
+        consumer_group_rebalances: command.consumer_group_rebalances,
+        stream_quota: command.stream_quota,
+        cleanup_policy: command.cleanup_policy,
+        message_gaps: command.message_gaps,
+        consumer_group_assignment: command.consumer_group_assignment,
+        allowed_producers: command.allowed_producers,
+        offset_recovery: command.offset_recovery,
+        dead_letter: command.dead_letter,
+        frame_checksums: command.frame_checksums && sender.supports_frame_checksums(),
+        visibility_timeout: command.visibility_timeout,
+        throttle_time: command.throttle_time,
+        topic_deletion_time: command.topic_deletion_time,
+        max_segments: command.max_segments,
+        partition_recovery: command.partition_recovery,
+        topic_config: command.topic_config,
+        message_sizes: command.message_sizes,
+        token_scope: command.token_scope,
+        token_last_used: command.token_last_used,
+        error_categories: command.error_categories,
+    };
+    // The response is still framed using the previously negotiated features.
+    sender.send_ok_response(&accepted.to_bytes()).await?;
+    sender.set_frame_checksums(accepted.frame_checksums);
+    sender.set_error_categories(accepted.error_categories);
+    session.set_protocol_features(&accepted);
+    info!("Negotiated the binary protocol features: {accepted} for session: {session}");
+    Ok(())
+}
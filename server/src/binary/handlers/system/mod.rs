@@ -16,11 +16,19 @@
  * under the License.
  */
 
+pub mod create_backup_handler;
+pub mod get_audit_log_handler;
 pub mod get_client_handler;
 pub mod get_clients_handler;
+pub mod get_error_catalog_handler;
+pub mod get_maintenance_mode_handler;
 pub mod get_me_handler;
+pub mod get_server_info_handler;
 pub mod get_snapshot;
 pub mod get_stats_handler;
+pub mod get_unsaved_state_handler;
+pub mod handshake_handler;
 pub mod ping_handler;
+pub mod set_maintenance_mode_handler;
 
 pub const COMPONENT: &str = "SYSTEM_HANDLER";
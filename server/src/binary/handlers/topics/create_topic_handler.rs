@@ -50,6 +50,9 @@ pub async fn handle(
                 command.compression_algorithm,
                 command.max_topic_size,
                 command.replication_factor,
+                command.metadata.clone(),
+                command.message_id_scheme,
+                command.cleanup_policy,
             )
             .await
             .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to create topic for stream ID: {stream_id}, topic_id: {:?}",
@@ -57,8 +60,10 @@ pub async fn handle(
             ))?;
     command.message_expiry = topic.message_expiry;
     command.max_topic_size = topic.max_topic_size;
+    command.message_id_scheme = topic.message_id_scheme;
+    command.cleanup_policy = topic.cleanup_policy;
     let topic_id = topic.topic_id;
-    let response = mapper::map_topic(topic).await;
+    let response = mapper::map_topic(topic, session.get_protocol_features()).await;
 
     let system = system.downgrade();
     system
@@ -43,7 +43,7 @@ pub async fn handle(
         return Ok(());
     };
 
-    let topic = mapper::map_topic(topic).await;
+    let topic = mapper::map_topic(topic, session.get_protocol_features()).await;
     sender.send_ok_response(&topic).await?;
     Ok(())
 }
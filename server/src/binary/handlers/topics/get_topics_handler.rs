@@ -36,14 +36,14 @@ pub async fn handle(
     debug!("session: {session}, command: {command}");
     let system = system.read().await;
     let topics = system
-        .find_topics(session, &command.stream_id)
+        .find_topics(session, &command.stream_id, &command.filter)
         .with_error_context(|error| {
             format!(
                 "{COMPONENT} (error: {error}) - failed to find topics, stream ID: {}, session: {session}",
                 command.stream_id
             )
         })?;
-    let response = mapper::map_topics(&topics);
+    let response = mapper::map_topics(&topics, session.get_protocol_features());
     sender.send_ok_response(&response).await?;
     Ok(())
 }
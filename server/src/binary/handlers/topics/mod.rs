@@ -20,7 +20,12 @@ pub mod create_topic_handler;
 pub mod delete_topic_handler;
 pub mod get_topic_handler;
 pub mod get_topics_handler;
+pub mod mark_topic_for_deletion_handler;
 pub mod purge_topic_handler;
+pub mod update_topic_config_handler;
+pub mod update_topic_expiry_watcher_handler;
 pub mod update_topic_handler;
+pub mod update_topic_metadata_handler;
+pub mod update_topic_producers_handler;
 
 pub const COMPONENT: &str = "TOPIC_HANDLER";
@@ -36,7 +36,13 @@ pub async fn handle(
     debug!("session: {session}, command: {command}");
     let system = system.read().await;
     system
-        .purge_topic(session, &command.stream_id, &command.topic_id)
+        .purge_topic(
+            session,
+            &command.stream_id,
+            &command.topic_id,
+            command.keep_consumer_offsets,
+            command.older_than,
+        )
         .await
         .with_error_context(|error| {
             format!(
@@ -47,6 +47,7 @@ pub async fn handle(
                 command.compression_algorithm,
                 command.max_topic_size,
                 command.replication_factor,
+                command.max_segments,
             )
             .await
             .with_error_context(|error| format!(
@@ -45,6 +45,7 @@ pub async fn handle(
                 &command.password,
                 command.status,
                 command.permissions.clone(),
+                command.namespace_id,
             )
             .await
             .with_error_context(|error| {
@@ -54,6 +55,7 @@ pub async fn handle(
                 )
             })?;
     let user_id = user.id;
+    let namespace_id = user.namespace_id;
     let response = mapper::map_user(user);
 
     // For the security of the system, we hash the password before storing it in metadata.
@@ -69,6 +71,7 @@ pub async fn handle(
                     password: crypto::hash_password(&command.password),
                     status: command.status,
                     permissions: command.permissions.clone(),
+                    namespace_id,
                 }
             }),
         )
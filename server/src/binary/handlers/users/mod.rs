@@ -21,9 +21,11 @@ pub mod create_user_handler;
 pub mod delete_user_handler;
 pub mod get_user_handler;
 pub mod get_users_handler;
+pub mod login_as_handler;
 pub mod login_user_handler;
 pub mod logout_user_handler;
 pub mod update_permissions_handler;
 pub mod update_user_handler;
+pub mod update_user_quotas_handler;
 
 pub const COMPONENT: &str = "USER_HANDLER";
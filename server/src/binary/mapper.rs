@@ -19,6 +19,7 @@
 use crate::streaming::clients::client_manager::{Client, Transport};
 use crate::streaming::partitions::partition::Partition;
 use crate::streaming::personal_access_tokens::personal_access_token::PersonalAccessToken;
+use crate::streaming::segments::message_slices::{PolledMessageSlices, Slice};
 use crate::streaming::streams::stream::Stream;
 use crate::streaming::topics::consumer_group::ConsumerGroup;
 use crate::streaming::topics::topic::Topic;
@@ -26,11 +27,27 @@ use crate::streaming::users::user::User;
 use bytes::{BufMut, Bytes, BytesMut};
 use iggy::bytes_serializable::BytesSerializable;
 use iggy::locking::{IggySharedMut, IggySharedMutFn};
+use iggy::models::audit_entry::AuditEntry;
+use iggy::models::backup::Backup;
 use iggy::models::consumer_offset_info::ConsumerOffsetInfo;
+use iggy::models::error_catalog::ErrorCatalogEntry;
+use iggy::models::maintenance_mode::MaintenanceMode;
 use iggy::models::messages::PolledMessages;
+use iggy::models::messages_aggregate::MessagesBucket;
+use iggy::models::namespace::{Namespace, NamespaceDetails};
+use iggy::models::partition_timestamp_offset::PartitionTimestampOffset;
+use iggy::models::producer_epoch::ProducerEpoch;
+use iggy::models::producer_session::ProducerSession;
+use iggy::models::push_subscription::PushSubscription;
+use iggy::models::replay_job::ReplayJob;
+use iggy::models::restored_segments::RestoredSegments;
+use iggy::models::segment_range::SegmentRange;
+use iggy::models::server_info::ServerInfo;
 use iggy::models::stats::Stats;
+use iggy::models::transaction::Transaction;
+use iggy::models::unsaved_state::PartitionUnsavedState;
 use iggy::models::user_info::UserId;
-use iggy::utils::byte_size::IggyByteSize;
+use iggy::system::handshake::Handshake;
 use iggy::utils::sizeable::Sizeable;
 use tokio::sync::RwLock;
 
@@ -79,6 +96,18 @@ pub fn map_stats(stats: &Stats) -> Bytes {
         bytes.put_f32_le(metrics.hit_ratio);
     }
 
+    bytes.put_u32_le(stats.transport_utilization.len() as u32);
+    for utilization in &stats.transport_utilization {
+        bytes.put_u8(utilization.transport.len() as u8);
+        bytes.put_slice(utilization.transport.as_bytes());
+        bytes.put_u32_le(utilization.connections);
+        bytes.put_u32_le(utilization.max_connections);
+        bytes.put_u32_le(utilization.in_flight_requests);
+        bytes.put_u32_le(utilization.max_in_flight_requests);
+        bytes.put_u64_le(utilization.rejected_connections);
+        bytes.put_u64_le(utilization.rejected_requests);
+    }
+
     bytes.freeze()
 }
 
@@ -90,6 +119,29 @@ pub fn map_consumer_offset(offset: &ConsumerOffsetInfo) -> Bytes {
     bytes.freeze()
 }
 
+pub fn map_messages_buckets(buckets: &[MessagesBucket]) -> Bytes {
+    let mut bytes = BytesMut::with_capacity(40 * buckets.len());
+    for bucket in buckets {
+        bytes.put_u64_le(bucket.start_timestamp.as_micros());
+        bytes.put_u64_le(bucket.messages_count);
+        bytes.put_u64_le(bucket.size_bytes.as_bytes_u64());
+        bytes.put_u64_le(bucket.min_timestamp.as_micros());
+        bytes.put_u64_le(bucket.max_timestamp.as_micros());
+    }
+    bytes.freeze()
+}
+
+pub fn map_partition_timestamp_offsets(offsets: &[PartitionTimestampOffset]) -> Bytes {
+    let mut bytes = BytesMut::with_capacity(21 * offsets.len());
+    for offset in offsets {
+        bytes.put_u32_le(offset.partition_id);
+        bytes.put_u64_le(offset.timestamp.as_micros());
+        bytes.put_u8(offset.offset.is_some() as u8);
+        bytes.put_u64_le(offset.offset.unwrap_or_default());
+    }
+    bytes.freeze()
+}
+
 pub fn map_client(client: &Client) -> Bytes {
     let mut bytes = BytesMut::new();
     extend_client(client, &mut bytes);
@@ -120,8 +172,9 @@ pub fn map_user(user: &User) -> Bytes {
         bytes.put_u32_le(permissions.len() as u32);
         bytes.put_slice(&permissions);
     } else {
-        bytes.put_u32_le(0);
+        bytes.put_u8(0);
     }
+    user.quotas.write_to_buffer(&mut bytes);
     bytes.freeze()
 }
 
@@ -146,69 +199,372 @@ pub fn map_raw_pat(token: &str) -> Bytes {
     bytes.freeze()
 }
 
-pub fn map_personal_access_tokens(personal_access_tokens: &[&PersonalAccessToken]) -> Bytes {
+pub fn map_personal_access_tokens(
+    personal_access_tokens: &[&PersonalAccessToken],
+    features: Handshake,
+) -> Bytes {
     let mut bytes = BytesMut::new();
     for personal_access_token in personal_access_tokens {
-        extend_pat(personal_access_token, &mut bytes);
+        extend_pat(personal_access_token, features, &mut bytes);
+    }
+    bytes.freeze()
+}
+
+pub fn map_server_info(server_info: &ServerInfo) -> Bytes {
+    let mut bytes = BytesMut::with_capacity(17 + server_info.version.len());
+    bytes.put_u32_le(server_info.limits.max_streams);
+    bytes.put_u32_le(server_info.limits.max_topics_per_stream);
+    bytes.put_u32_le(server_info.limits.max_partitions_per_topic);
+    bytes.put_u32_le(server_info.limits.max_total_partitions);
+    bytes.put_u8(server_info.version.len() as u8);
+    bytes.put_slice(server_info.version.as_bytes());
+    bytes.freeze()
+}
+
+pub fn map_maintenance_mode(maintenance_mode: &MaintenanceMode) -> Bytes {
+    let message = maintenance_mode.message.as_deref().unwrap_or_default();
+    let mut bytes = BytesMut::with_capacity(14 + message.len());
+    bytes.put_u8(if maintenance_mode.enabled { 1 } else { 0 });
+    bytes.put_u8(if maintenance_mode.reject_reads { 1 } else { 0 });
+    bytes.put_u64_le(
+        maintenance_mode
+            .enabled_at
+            .map_or(0, |enabled_at| enabled_at.as_micros()),
+    );
+    bytes.put_u32_le(message.len() as u32);
+    bytes.put_slice(message.as_bytes());
+    bytes.freeze()
+}
+
+pub fn map_backup(backup: &Backup) -> Bytes {
+    let mut bytes = BytesMut::with_capacity(29 + backup.name.len() + backup.path.len());
+    bytes.put_u64_le(backup.created_at.as_micros());
+    bytes.put_u32_le(backup.files_count);
+    bytes.put_u32_le(backup.linked_files_count);
+    bytes.put_u64_le(backup.size.as_bytes_u64());
+    bytes.put_u8(backup.name.len() as u8);
+    bytes.put_slice(backup.name.as_bytes());
+    bytes.put_u32_le(backup.path.len() as u32);
+    bytes.put_slice(backup.path.as_bytes());
+    bytes.freeze()
+}
+
+pub fn map_restored_segments(restored_segments: &RestoredSegments) -> Bytes {
+    let mut bytes = BytesMut::with_capacity(20);
+    bytes.put_u32_le(restored_segments.segments_count);
+    bytes.put_u64_le(restored_segments.messages_count);
+    bytes.put_u64_le(restored_segments.size.as_bytes_u64());
+    bytes.freeze()
+}
+
+pub fn map_segment_range(segment_range: &SegmentRange) -> Bytes {
+    let mut bytes = BytesMut::with_capacity(41 + segment_range.bytes.len());
+    bytes.put_u64_le(segment_range.segment_start_offset);
+    bytes.put_u64_le(segment_range.segment_end_offset);
+    bytes.put_u64_le(segment_range.segment_size);
+    bytes.put_u8(if segment_range.segment_closed { 1 } else { 0 });
+    bytes.put_u64_le(segment_range.start_position);
+    bytes.put_u64_le(segment_range.next_position);
+    bytes.put_slice(&segment_range.bytes);
+    bytes.freeze()
+}
+
+pub fn map_unsaved_state(unsaved_state: &[PartitionUnsavedState]) -> Bytes {
+    let mut bytes = BytesMut::with_capacity(28 * unsaved_state.len());
+    for partition in unsaved_state {
+        bytes.put_u32_le(partition.stream_id);
+        bytes.put_u32_le(partition.topic_id);
+        bytes.put_u32_le(partition.partition_id);
+        bytes.put_u64_le(partition.messages_count);
+        bytes.put_u64_le(partition.size.as_bytes_u64());
     }
     bytes.freeze()
 }
 
-pub fn map_polled_messages(polled_messages: &PolledMessages) -> Bytes {
+pub fn map_audit_entries(entries: &[AuditEntry]) -> Bytes {
+    let mut bytes = BytesMut::with_capacity(
+        entries
+            .iter()
+            .map(|entry| entry.get_size_bytes())
+            .sum::<usize>(),
+    );
+    for entry in entries {
+        entry.write_to_buffer(&mut bytes);
+    }
+    bytes.freeze()
+}
+
+pub fn map_error_catalog(entries: &[ErrorCatalogEntry]) -> Bytes {
+    let mut bytes = BytesMut::with_capacity(
+        entries
+            .iter()
+            .map(|entry| entry.get_size_bytes())
+            .sum::<usize>(),
+    );
+    for entry in entries {
+        entry.write_to_buffer(&mut bytes);
+    }
+    bytes.freeze()
+}
+
+pub fn map_replay_job(replay_job: &ReplayJob) -> Bytes {
+    let mut bytes = BytesMut::with_capacity(82);
+    extend_replay_job(replay_job, &mut bytes);
+    bytes.freeze()
+}
+
+pub fn map_replay_jobs(replay_jobs: &[ReplayJob]) -> Bytes {
+    let mut bytes = BytesMut::with_capacity(82 * replay_jobs.len());
+    for replay_job in replay_jobs {
+        extend_replay_job(replay_job, &mut bytes);
+    }
+    bytes.freeze()
+}
+
+pub fn map_producer_session(producer_session: &ProducerSession) -> Bytes {
+    let mut bytes = BytesMut::with_capacity(8);
+    bytes.put_u64_le(producer_session.producer_id);
+    bytes.freeze()
+}
+
+pub fn map_transaction(transaction: &Transaction) -> Bytes {
+    let mut bytes = BytesMut::with_capacity(8);
+    bytes.put_u64_le(transaction.transaction_id);
+    bytes.freeze()
+}
+
+pub fn map_producer_epoch(producer_epoch: &ProducerEpoch) -> Bytes {
+    let mut bytes = BytesMut::with_capacity(5 + producer_epoch.name.len());
+    bytes.put_u32_le(producer_epoch.epoch);
+    bytes.put_u8(producer_epoch.name.len() as u8);
+    bytes.put_slice(producer_epoch.name.as_bytes());
+    bytes.freeze()
+}
+
+pub fn map_push_subscription(push_subscription: &PushSubscription) -> Bytes {
+    let mut bytes = BytesMut::new();
+    extend_push_subscription(push_subscription, &mut bytes);
+    bytes.freeze()
+}
+
+pub fn map_push_subscriptions(push_subscriptions: &[PushSubscription]) -> Bytes {
+    let mut bytes = BytesMut::new();
+    for push_subscription in push_subscriptions {
+        extend_push_subscription(push_subscription, &mut bytes);
+    }
+    bytes.freeze()
+}
+
+/// The payloads smaller than this are copied into the contiguous buffer along with the messages headers,
+/// as sending them as separate slices would cost more than copying.
+const INLINE_PAYLOAD_THRESHOLD: usize = 512;
+
+/// Maps the polled messages into the rope of slices, where the large payloads reference the cached
+/// or loaded buffers instead of being copied, while all the other fields share a single buffer.
+pub fn map_polled_messages(
+    polled_messages: &PolledMessages,
+    features: Handshake,
+    with_partition_epoch: bool,
+) -> Vec<Bytes> {
     let messages_count = polled_messages.messages.len() as u32;
-    let messages_size = polled_messages
-        .messages
-        .iter()
-        .map(|message| message.get_size_bytes())
-        .sum::<IggyByteSize>();
+    let mut inline_size = 0;
+    let mut referenced_payloads = 0;
+    for message in polled_messages.messages.iter() {
+        let payload_size = message.payload.len();
+        inline_size += message.get_size_bytes().as_bytes_usize() - payload_size;
+        if payload_size < INLINE_PAYLOAD_THRESHOLD {
+            inline_size += payload_size;
+        } else {
+            referenced_payloads += 1;
+        }
+    }
 
-    let mut bytes = BytesMut::with_capacity(20 + messages_size.as_bytes_usize());
+    let mut rope = Vec::with_capacity(2 * referenced_payloads + 1);
+    let gaps_size = 17 * polled_messages.gaps.len();
+    let chunks_size = match polled_messages.chunk_sizes.len() {
+        0 => 0,
+        chunks_count => 4 + 4 * chunks_count,
+    };
+    let mut bytes = BytesMut::with_capacity(36 + gaps_size + chunks_size + inline_size);
     bytes.put_u32_le(polled_messages.partition_id);
     bytes.put_u64_le(polled_messages.current_offset);
+    // The remaining messages count is present only if it was negotiated, so that the older clients can still read the response.
+    if features.remaining_messages {
+        bytes.put_u64_le(polled_messages.remaining_messages);
+    }
     bytes.put_u32_le(messages_count);
+    // The gaps are present only if these were negotiated, so that the clients not handling them don't pay for them.
+    if features.message_gaps {
+        bytes.put_u32_le(polled_messages.gaps.len() as u32);
+        for gap in polled_messages.gaps.iter() {
+            bytes.put_u64_le(gap.start_offset);
+            bytes.put_u64_le(gap.end_offset);
+            bytes.put_u8(gap.reason.as_code());
+        }
+    }
+    // The partition epoch is present only if it was requested, so that the older clients can still read the response.
+    if with_partition_epoch {
+        bytes.put_u64_le(polled_messages.partition_epoch);
+    }
+    // The throttle time and the topic deletion time are present only if these were negotiated,
+    // so that they reach also the clients not fencing the epochs.
+    if features.throttle_time {
+        bytes.put_u32_le(polled_messages.throttle_time_ms);
+    }
+    if features.topic_deletion_time {
+        bytes.put_u64_le(polled_messages.topic_delete_at);
+    }
+    // The chunk sizes are present only if the chunking was requested, so that the older clients can still read the response.
+    if !polled_messages.chunk_sizes.is_empty() {
+        bytes.put_u32_le(polled_messages.chunk_sizes.len() as u32);
+        for chunk_size in polled_messages.chunk_sizes.iter() {
+            bytes.put_u32_le(*chunk_size);
+        }
+    }
     for message in polled_messages.messages.iter() {
-        message.extend(&mut bytes);
+        message.extend_header(&mut bytes);
+        if message.payload.len() < INLINE_PAYLOAD_THRESHOLD {
+            bytes.put_slice(&message.payload);
+            continue;
+        }
+
+        // Splitting keeps the remaining capacity, so all the headers still share the single allocation.
+        rope.push(bytes.split().freeze());
+        rope.push(message.payload.clone());
     }
 
-    bytes.freeze()
+    if !bytes.is_empty() {
+        rope.push(bytes.freeze());
+    }
+    rope
+}
+
+/// Maps the polled message slices into the same response as `map_polled_messages`, without any gaps,
+/// where the payloads left in the log files are sent directly from there.
+pub fn map_message_slices(
+    polled_messages: &PolledMessageSlices,
+    features: Handshake,
+    with_partition_epoch: bool,
+) -> Vec<Slice> {
+    let mut slices = Vec::new();
+    let mut bytes = BytesMut::with_capacity(
+        36 + polled_messages
+            .messages
+            .iter()
+            .map(|message| message.header.len())
+            .sum::<usize>(),
+    );
+    bytes.put_u32_le(polled_messages.partition_id);
+    bytes.put_u64_le(polled_messages.current_offset);
+    if features.remaining_messages {
+        bytes.put_u64_le(polled_messages.remaining_messages);
+    }
+    bytes.put_u32_le(polled_messages.messages.len() as u32);
+    if features.message_gaps {
+        bytes.put_u32_le(0);
+    }
+    if with_partition_epoch {
+        bytes.put_u64_le(polled_messages.partition_epoch);
+    }
+    if features.throttle_time {
+        bytes.put_u32_le(polled_messages.throttle_time_ms);
+    }
+    if features.topic_deletion_time {
+        bytes.put_u64_le(polled_messages.topic_delete_at);
+    }
+    for message in polled_messages.messages.iter() {
+        bytes.put_slice(&message.header);
+        match &message.payload {
+            Slice::Bytes(payload) => bytes.put_slice(payload),
+            payload => {
+                slices.push(Slice::Bytes(bytes.split().freeze()));
+                slices.push(payload.clone());
+            }
+        }
+    }
+
+    if !bytes.is_empty() {
+        slices.push(Slice::Bytes(bytes.freeze()));
+    }
+    slices
 }
 
-pub fn map_stream(stream: &Stream) -> Bytes {
+// The fields added after the initial version of the protocol are present only if their features were negotiated,
+// so that the older clients can still read the streams, topics and partitions.
+pub fn map_stream(stream: &Stream, features: Handshake) -> Bytes {
     let mut bytes = BytesMut::new();
-    extend_stream(stream, &mut bytes);
+    extend_stream(stream, features, &mut bytes);
     for topic in stream.get_topics() {
-        extend_topic(topic, &mut bytes);
+        extend_topic(topic, features, &mut bytes);
     }
     bytes.freeze()
 }
 
-pub fn map_streams(streams: &[&Stream]) -> Bytes {
+pub fn map_streams(streams: &[&Stream], features: Handshake) -> Bytes {
     let mut bytes = BytesMut::new();
     for stream in streams {
-        extend_stream(stream, &mut bytes);
+        extend_stream(stream, features, &mut bytes);
+    }
+    bytes.freeze()
+}
+
+pub fn map_namespace(namespace: &NamespaceDetails) -> Bytes {
+    let mut bytes = BytesMut::new();
+    extend_namespace(
+        &Namespace {
+            id: namespace.id,
+            created_at: namespace.created_at,
+            name: namespace.name.clone(),
+            quotas: namespace.quotas,
+            streams_count: namespace.streams_count,
+            users_count: namespace.users_count,
+            size: namespace.size,
+            messages_count: namespace.messages_count,
+        },
+        &mut bytes,
+    );
+    for stream_id in &namespace.stream_ids {
+        bytes.put_u32_le(*stream_id);
+    }
+    for user_id in &namespace.user_ids {
+        bytes.put_u32_le(*user_id);
+    }
+    bytes.freeze()
+}
+
+pub fn map_namespaces(namespaces: &[Namespace]) -> Bytes {
+    let mut bytes = BytesMut::new();
+    for namespace in namespaces {
+        extend_namespace(namespace, &mut bytes);
     }
     bytes.freeze()
 }
 
-pub fn map_topics(topics: &[&Topic]) -> Bytes {
+pub fn map_topics(topics: &[&Topic], features: Handshake) -> Bytes {
     let mut bytes = BytesMut::new();
     for topic in topics {
-        extend_topic(topic, &mut bytes);
+        extend_topic(topic, features, &mut bytes);
     }
     bytes.freeze()
 }
 
-pub async fn map_topic(topic: &Topic) -> Bytes {
+pub async fn map_topic(topic: &Topic, features: Handshake) -> Bytes {
     let mut bytes = BytesMut::new();
-    extend_topic(topic, &mut bytes);
+    extend_topic(topic, features, &mut bytes);
+    if features.message_sizes {
+        topic
+            .message_sizes
+            .to_distribution()
+            .write_to_buffer(&mut bytes);
+    }
     for partition in topic.get_partitions() {
         let partition = partition.read().await;
-        extend_partition(&partition, &mut bytes);
+        extend_partition(&partition, features, &mut bytes);
     }
     bytes.freeze()
 }
 
-pub async fn map_consumer_group(consumer_group: &ConsumerGroup) -> Bytes {
+pub async fn map_consumer_group(consumer_group: &ConsumerGroup, features: Handshake) -> Bytes {
     let mut bytes = BytesMut::new();
     extend_consumer_group(consumer_group, &mut bytes);
     let members = consumer_group.get_members();
@@ -221,6 +577,48 @@ pub async fn map_consumer_group(consumer_group: &ConsumerGroup) -> Bytes {
             bytes.put_u32_le(partition);
         }
     }
+    if features.consumer_group_rebalances {
+        let rebalances = consumer_group.get_rebalances();
+        bytes.put_u32_le(rebalances.len() as u32);
+        for rebalance in rebalances {
+            bytes.put_u64_le(rebalance.timestamp.into());
+            bytes.put_u8(rebalance.trigger.as_code());
+            bytes.put_u64_le(rebalance.duration.as_micros());
+            bytes.put_u32_le(rebalance.partitions_moved);
+            for members in [&rebalance.members_before, &rebalance.members_after] {
+                bytes.put_u32_le(members.len() as u32);
+                for member_id in members {
+                    bytes.put_u32_le(*member_id);
+                }
+            }
+        }
+    }
+    if features.consumer_group_assignment {
+        bytes.put_u32_le(consumer_group.get_generation());
+        bytes.put_u8(consumer_group.assignment_strategy.as_code());
+    }
+    if features.offset_recovery {
+        bytes.put_u8(consumer_group.offset_recovery.as_code());
+    }
+    if features.dead_letter {
+        match &consumer_group.dead_letter {
+            Some(dead_letter) => {
+                bytes.put_u8(1);
+                bytes.put_u32_le(dead_letter.stream_id);
+                bytes.put_u32_le(dead_letter.topic_id);
+                bytes.put_u32_le(dead_letter.max_delivery_count);
+            }
+            None => bytes.put_u8(0),
+        }
+    }
+    if features.visibility_timeout {
+        bytes.put_u64_le(
+            consumer_group
+                .visibility_timeout
+                .map(|timeout| timeout.as_micros())
+                .unwrap_or_default(),
+        );
+    }
     bytes.freeze()
 }
 
@@ -233,7 +631,7 @@ pub async fn map_consumer_groups(consumer_groups: &[&RwLock<ConsumerGroup>]) ->
     bytes.freeze()
 }
 
-fn extend_stream(stream: &Stream, bytes: &mut BytesMut) {
+fn extend_stream(stream: &Stream, features: Handshake, bytes: &mut BytesMut) {
     bytes.put_u32_le(stream.stream_id);
     bytes.put_u64_le(stream.created_at.into());
     bytes.put_u32_le(stream.get_topics().len() as u32);
@@ -241,9 +639,28 @@ fn extend_stream(stream: &Stream, bytes: &mut BytesMut) {
     bytes.put_u64_le(stream.get_messages_count());
     bytes.put_u8(stream.name.len() as u8);
     bytes.put_slice(stream.name.as_bytes());
+    if features.resource_metadata {
+        stream.metadata.write_to_buffer(bytes);
+    }
+    if features.stream_quota {
+        bytes.put_u64_le(stream.quota.soft_limit.as_bytes_u64());
+        bytes.put_u64_le(stream.quota.hard_limit.as_bytes_u64());
+    }
 }
 
-fn extend_topic(topic: &Topic, bytes: &mut BytesMut) {
+fn extend_namespace(namespace: &Namespace, bytes: &mut BytesMut) {
+    bytes.put_u32_le(namespace.id);
+    bytes.put_u64_le(namespace.created_at);
+    bytes.put_u32_le(namespace.streams_count);
+    bytes.put_u32_le(namespace.users_count);
+    bytes.put_u64_le(namespace.size.as_bytes_u64());
+    bytes.put_u64_le(namespace.messages_count);
+    namespace.quotas.write_to_buffer(bytes);
+    bytes.put_u8(namespace.name.len() as u8);
+    bytes.put_slice(namespace.name.as_bytes());
+}
+
+fn extend_topic(topic: &Topic, features: Handshake, bytes: &mut BytesMut) {
     bytes.put_u32_le(topic.topic_id);
     bytes.put_u64_le(topic.created_at.into());
     bytes.put_u32_le(topic.get_partitions().len() as u32);
@@ -255,15 +672,61 @@ fn extend_topic(topic: &Topic, bytes: &mut BytesMut) {
     bytes.put_u64_le(topic.get_messages_count());
     bytes.put_u8(topic.name.len() as u8);
     bytes.put_slice(topic.name.as_bytes());
+    if features.resource_metadata {
+        topic.metadata.write_to_buffer(bytes);
+    }
+    if features.message_id_scheme {
+        bytes.put_u8(topic.message_id_scheme.as_code());
+    }
+    if features.cleanup_policy {
+        bytes.put_u8(topic.cleanup_policy.as_code());
+    }
+    if features.allowed_producers {
+        bytes.put_u32_le(topic.allowed_producers.len() as u32);
+        for user_id in &topic.allowed_producers {
+            bytes.put_u32_le(*user_id);
+        }
+    }
+    if features.topic_deletion_time {
+        bytes.put_u64_le(topic.get_delete_at_micros());
+    }
+    if features.max_segments {
+        bytes.put_u32_le(topic.max_segments);
+    }
+    if features.topic_config {
+        topic.topic_config.write_to_buffer(bytes);
+    }
 }
 
-fn extend_partition(partition: &Partition, bytes: &mut BytesMut) {
+fn extend_partition(partition: &Partition, features: Handshake, bytes: &mut BytesMut) {
     bytes.put_u32_le(partition.partition_id);
     bytes.put_u64_le(partition.created_at.into());
     bytes.put_u32_le(partition.get_segments().len() as u32);
     bytes.put_u64_le(partition.current_offset);
     bytes.put_u64_le(partition.get_size_bytes().as_bytes_u64());
     bytes.put_u64_le(partition.get_messages_count());
+    if !features.partition_recovery {
+        return;
+    }
+
+    match partition.get_recovery_progress() {
+        Some(recovery) => {
+            bytes.put_u8(1);
+            bytes.put_u64_le(recovery.started_at.into());
+            bytes.put_u32_le(recovery.total_segments);
+            bytes.put_u32_le(recovery.recovered_segments);
+            bytes.put_u64_le(recovery.total_size.as_bytes_u64());
+            bytes.put_u64_le(recovery.recovered_size.as_bytes_u64());
+            match recovery.eta {
+                Some(eta) => {
+                    bytes.put_u8(1);
+                    bytes.put_u64_le(eta.as_micros());
+                }
+                None => bytes.put_u8(0),
+            }
+        }
+        None => bytes.put_u8(0),
+    }
 }
 
 fn extend_consumer_group(consumer_group: &ConsumerGroup, bytes: &mut BytesMut) {
@@ -280,6 +743,8 @@ fn extend_client(client: &Client, bytes: &mut BytesMut) {
     let transport: u8 = match client.transport {
         Transport::Tcp => 1,
         Transport::Quic => 2,
+        Transport::Uds => 3,
+        Transport::WebSocket => 4,
     };
     bytes.put_u8(transport);
     let address = client.session.ip_address.to_string();
@@ -296,7 +761,11 @@ fn extend_user(user: &User, bytes: &mut BytesMut) {
     bytes.put_slice(user.username.as_bytes());
 }
 
-fn extend_pat(personal_access_token: &PersonalAccessToken, bytes: &mut BytesMut) {
+fn extend_pat(
+    personal_access_token: &PersonalAccessToken,
+    features: Handshake,
+    bytes: &mut BytesMut,
+) {
     bytes.put_u8(personal_access_token.name.len() as u8);
     bytes.put_slice(personal_access_token.name.as_bytes());
     match &personal_access_token.expiry_at {
@@ -307,4 +776,216 @@ fn extend_pat(personal_access_token: &PersonalAccessToken, bytes: &mut BytesMut)
             bytes.put_u64_le(0);
         }
     }
+    if features.token_scope {
+        personal_access_token.scope.write_to_buffer(bytes);
+    }
+    if features.token_last_used {
+        match personal_access_token.get_last_used_at() {
+            Some(last_used_at) => {
+                bytes.put_u64_le(last_used_at.as_micros());
+            }
+            None => {
+                bytes.put_u64_le(0);
+            }
+        }
+    }
+}
+
+fn extend_replay_job(replay_job: &ReplayJob, bytes: &mut BytesMut) {
+    bytes.put_u32_le(replay_job.id);
+    bytes.put_u32_le(replay_job.user_id);
+    bytes.put_u8(replay_job.status.as_code());
+    bytes.put_u32_le(replay_job.source_stream_id);
+    bytes.put_u32_le(replay_job.source_topic_id);
+    bytes.put_u32_le(replay_job.source_partition_id);
+    bytes.put_u32_le(replay_job.destination_stream_id);
+    bytes.put_u32_le(replay_job.destination_topic_id);
+    bytes.put_u8(replay_job.range.kind.as_code());
+    bytes.put_u64_le(replay_job.range.start);
+    bytes.put_u64_le(replay_job.range.end);
+    bytes.put_u64_le(replay_job.current_offset);
+    bytes.put_u64_le(replay_job.replayed_messages);
+    bytes.put_u32_le(replay_job.messages_per_second);
+    bytes.put_u64_le(replay_job.created_at.as_micros());
+    bytes.put_u64_le(
+        replay_job
+            .finished_at
+            .map(|finished_at| finished_at.as_micros())
+            .unwrap_or(0),
+    );
+}
+
+fn extend_push_subscription(push_subscription: &PushSubscription, bytes: &mut BytesMut) {
+    bytes.put_u32_le(push_subscription.id);
+    bytes.put_u32_le(push_subscription.user_id);
+    bytes.put_u8(push_subscription.status.as_code());
+    bytes.put_u32_le(push_subscription.stream_id);
+    bytes.put_u32_le(push_subscription.topic_id);
+    bytes.put_u32_le(push_subscription.partition_id);
+    bytes.put_u32_le(push_subscription.batch_size);
+    bytes.put_u64_le(push_subscription.next_offset);
+    bytes.put_u64_le(push_subscription.pushed_messages);
+    bytes.put_u32_le(push_subscription.failed_attempts);
+    bytes.put_u64_le(push_subscription.created_at.as_micros());
+    bytes.put_u64_le(
+        push_subscription
+            .last_pushed_at
+            .map(|last_pushed_at| last_pushed_at.as_micros())
+            .unwrap_or(0),
+    );
+    bytes.put_u32_le(push_subscription.endpoint.len() as u32);
+    bytes.put_slice(push_subscription.endpoint.as_bytes());
+    let last_error = push_subscription.last_error.as_deref().unwrap_or_default();
+    bytes.put_u32_le(last_error.len() as u32);
+    bytes.put_slice(last_error.as_bytes());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy::models::messages::{MessageState, MessagesGap, MessagesGapReason, PolledMessage};
+    use iggy::utils::timestamp::IggyTimestamp;
+
+    fn payloads() -> [Bytes; 4] {
+        [
+            Bytes::from(vec![1; 10]),
+            Bytes::from(vec![2; INLINE_PAYLOAD_THRESHOLD]),
+            Bytes::from(vec![3; 20]),
+            Bytes::from(vec![4; 4 * INLINE_PAYLOAD_THRESHOLD]),
+        ]
+    }
+
+    fn polled_messages(payloads: &[Bytes]) -> PolledMessages {
+        let messages = payloads
+            .iter()
+            .enumerate()
+            .map(|(offset, payload)| {
+                PolledMessage::create(
+                    offset as u64,
+                    MessageState::Available,
+                    IggyTimestamp::now(),
+                    offset as u128,
+                    payload.clone(),
+                    0,
+                    None,
+                )
+            })
+            .collect::<Vec<_>>();
+        PolledMessages {
+            partition_id: 1,
+            current_offset: 3,
+            remaining_messages: 5,
+            partition_epoch: 1,
+            gaps: vec![MessagesGap {
+                start_offset: 4,
+                end_offset: 9,
+                reason: MessagesGapReason::Compacted,
+            }],
+            chunk_sizes: Vec::new(),
+            throttle_time_ms: 250,
+            topic_delete_at: 5000,
+            messages,
+        }
+    }
+
+    #[test]
+    fn polled_messages_rope_should_match_contiguous_encoding() {
+        let payloads = payloads();
+        let polled_messages = polled_messages(&payloads);
+        let features = Handshake {
+            remaining_messages: true,
+            message_gaps: true,
+            ..Default::default()
+        };
+
+        let rope = map_polled_messages(&polled_messages, features, false);
+
+        let mut expected = BytesMut::new();
+        expected.put_u32_le(polled_messages.partition_id);
+        expected.put_u64_le(polled_messages.current_offset);
+        expected.put_u64_le(polled_messages.remaining_messages);
+        expected.put_u32_le(polled_messages.messages.len() as u32);
+        expected.put_u32_le(1);
+        expected.put_u64_le(4);
+        expected.put_u64_le(9);
+        expected.put_u8(MessagesGapReason::Compacted.as_code());
+        for message in polled_messages.messages.iter() {
+            message.extend(&mut expected);
+        }
+        assert_eq!(rope.concat(), expected.freeze().to_vec());
+        // Both large payloads are referenced, each followed by the shared headers buffer.
+        assert_eq!(rope.len(), 4);
+        assert_eq!(rope[1].as_ptr(), payloads[1].as_ptr());
+        assert_eq!(rope[3].as_ptr(), payloads[3].as_ptr());
+    }
+
+    #[test]
+    fn remaining_messages_should_be_mapped_only_if_negotiated() {
+        let polled_messages = polled_messages(&payloads());
+        let features = Handshake {
+            remaining_messages: true,
+            ..Default::default()
+        };
+
+        let negotiated = map_polled_messages(&polled_messages, features, false).concat();
+        let initial = map_polled_messages(&polled_messages, Handshake::default(), false).concat();
+
+        assert_eq!(negotiated.len(), initial.len() + 8);
+        assert_eq!(negotiated[12..20], 5u64.to_le_bytes());
+        assert_eq!(initial[..12], negotiated[..12]);
+        assert_eq!(initial[12..], negotiated[20..]);
+    }
+
+    #[test]
+    fn gaps_should_be_mapped_only_if_negotiated() {
+        let polled_messages = polled_messages(&payloads());
+        let features = Handshake {
+            message_gaps: true,
+            ..Default::default()
+        };
+
+        let negotiated = map_polled_messages(&polled_messages, features, false).concat();
+        let initial = map_polled_messages(&polled_messages, Handshake::default(), false).concat();
+
+        assert_eq!(negotiated.len(), initial.len() + 4 + 17);
+        assert_eq!(negotiated[16..20], 1u32.to_le_bytes());
+        assert_eq!(negotiated[20..28], 4u64.to_le_bytes());
+        assert_eq!(negotiated[28..36], 9u64.to_le_bytes());
+        assert_eq!(initial[..16], negotiated[..16]);
+        assert_eq!(initial[16..], negotiated[37..]);
+    }
+
+    #[test]
+    fn throttle_time_should_be_mapped_only_if_negotiated() {
+        let polled_messages = polled_messages(&payloads());
+        let features = Handshake {
+            throttle_time: true,
+            ..Default::default()
+        };
+
+        let negotiated = map_polled_messages(&polled_messages, features, false).concat();
+        let initial = map_polled_messages(&polled_messages, Handshake::default(), false).concat();
+
+        assert_eq!(negotiated.len(), initial.len() + 4);
+        assert_eq!(negotiated[16..20], 250u32.to_le_bytes());
+        assert_eq!(initial[..16], negotiated[..16]);
+        assert_eq!(initial[16..], negotiated[20..]);
+    }
+
+    #[test]
+    fn topic_deletion_time_should_be_mapped_only_if_negotiated() {
+        let polled_messages = polled_messages(&payloads());
+        let features = Handshake {
+            topic_deletion_time: true,
+            ..Default::default()
+        };
+
+        let negotiated = map_polled_messages(&polled_messages, features, false).concat();
+        let initial = map_polled_messages(&polled_messages, Handshake::default(), false).concat();
+
+        assert_eq!(negotiated.len(), initial.len() + 8);
+        assert_eq!(negotiated[16..24], 5000u64.to_le_bytes());
+        assert_eq!(initial[..16], negotiated[..16]);
+        assert_eq!(initial[16..], negotiated[24..]);
+    }
 }
@@ -18,7 +18,7 @@
 
 pub mod command;
 mod handlers;
-mod mapper;
+pub(crate) mod mapper;
 pub mod sender;
 
 pub const COMPONENT: &str = "BINARY";
@@ -18,13 +18,21 @@
 
 use std::future::Future;
 
+use crate::streaming::segments::message_slices::Slice;
 use crate::tcp::tcp_sender::TcpSender;
-use crate::tcp::tcp_tls_sender::TcpTlsSender;
+use crate::tcp::tcp_tls_sender::{TcpTlsSender, TcpTlsStream};
+use crate::uds::uds_sender::UdsSender;
+#[cfg(feature = "websocket")]
+use crate::websocket::websocket_sender::WebSocketSender;
 use crate::{quic::quic_sender::QuicSender, server_error::ServerError};
+use bytes::Bytes;
+#[cfg(feature = "websocket")]
+use bytes::BytesMut;
 use iggy::error::IggyError;
 use quinn::{RecvStream, SendStream};
-use tokio::net::TcpStream;
-use tokio_native_tls::TlsStream;
+use tokio::net::{TcpStream, UnixStream};
+#[cfg(feature = "websocket")]
+use tokio_tungstenite::WebSocketStream;
 
 macro_rules! forward_async_methods {
     (
@@ -40,6 +48,9 @@ macro_rules! forward_async_methods {
                     Self::Tcp(d) => d.$method_name($( $arg ),*).await,
                     Self::TcpTls(s) => s.$method_name($( $arg ),*).await,
                     Self::Quic(s) => s.$method_name($( $arg ),*).await,
+                    Self::Uds(s) => s.$method_name($( $arg ),*).await,
+                    #[cfg(feature = "websocket")]
+                    Self::WebSocket(s) => s.$method_name($( $arg ),*).await,
                 }
             }
         )*
@@ -53,10 +64,27 @@ pub trait Sender {
         &mut self,
         payload: &[u8],
     ) -> impl Future<Output = Result<(), IggyError>> + Send;
+    /// Sends the payload consisting of multiple slices, e.g. referencing the cached messages, without copying them.
+    fn send_ok_response_vectored(
+        &mut self,
+        payload: &[Bytes],
+    ) -> impl Future<Output = Result<(), IggyError>> + Send;
     fn send_error_response(
         &mut self,
         error: IggyError,
     ) -> impl Future<Output = Result<(), IggyError>> + Send;
+    /// Returns `true` if the transport can follow the frames with their CRC32C checksums,
+    /// the transports having their own integrity checks (e.g. QUIC) don't need them.
+    fn supports_frame_checksums(&self) -> bool {
+        false
+    }
+    /// Returns `true` if the frame checksums have been negotiated for the connection.
+    fn frame_checksums(&self) -> bool {
+        false
+    }
+    fn set_frame_checksums(&mut self, _enabled: bool) {}
+    /// Enables appending the error category code as the payload of the error responses.
+    fn set_error_categories(&mut self, enabled: bool);
     fn shutdown(&mut self) -> impl Future<Output = Result<(), ServerError>> + Send;
 }
 
@@ -64,28 +92,120 @@ pub enum SenderKind {
     Tcp(TcpSender),
     TcpTls(TcpTlsSender),
     Quic(QuicSender),
+    Uds(UdsSender),
+    #[cfg(feature = "websocket")]
+    WebSocket(WebSocketSender),
 }
 
 impl SenderKind {
-    pub fn get_tcp_sender(stream: TcpStream) -> Self {
-        Self::Tcp(TcpSender { stream })
+    pub fn get_tcp_sender(stream: TcpStream, zero_copy: bool) -> Self {
+        Self::Tcp(TcpSender {
+            stream,
+            frame_checksums: false,
+            error_categories: false,
+            zero_copy,
+        })
     }
 
-    pub fn get_tcp_tls_sender(stream: TlsStream<TcpStream>) -> Self {
-        Self::TcpTls(TcpTlsSender { stream })
+    pub fn get_tcp_tls_sender(stream: TcpTlsStream) -> Self {
+        Self::TcpTls(TcpTlsSender {
+            stream,
+            frame_checksums: false,
+            error_categories: false,
+        })
     }
 
     pub fn get_quic_sender(send_stream: SendStream, recv_stream: RecvStream) -> Self {
         Self::Quic(QuicSender {
             send: send_stream,
             recv: recv_stream,
+            error_categories: false,
         })
     }
 
+    pub fn get_uds_sender(stream: UnixStream) -> Self {
+        Self::Uds(UdsSender {
+            stream,
+            frame_checksums: false,
+            error_categories: false,
+        })
+    }
+
+    #[cfg(feature = "websocket")]
+    pub fn get_websocket_sender(stream: WebSocketStream<TcpStream>) -> Self {
+        Self::WebSocket(WebSocketSender {
+            stream,
+            buffer: BytesMut::new(),
+            error_categories: false,
+        })
+    }
+
+    pub fn supports_frame_checksums(&self) -> bool {
+        match self {
+            Self::Tcp(s) => s.supports_frame_checksums(),
+            Self::TcpTls(s) => s.supports_frame_checksums(),
+            Self::Quic(s) => s.supports_frame_checksums(),
+            Self::Uds(s) => s.supports_frame_checksums(),
+            #[cfg(feature = "websocket")]
+            Self::WebSocket(s) => s.supports_frame_checksums(),
+        }
+    }
+
+    pub fn frame_checksums(&self) -> bool {
+        match self {
+            Self::Tcp(s) => s.frame_checksums(),
+            Self::TcpTls(s) => s.frame_checksums(),
+            Self::Quic(s) => s.frame_checksums(),
+            Self::Uds(s) => s.frame_checksums(),
+            #[cfg(feature = "websocket")]
+            Self::WebSocket(s) => s.frame_checksums(),
+        }
+    }
+
+    pub fn set_frame_checksums(&mut self, enabled: bool) {
+        match self {
+            Self::Tcp(s) => s.set_frame_checksums(enabled),
+            Self::TcpTls(s) => s.set_frame_checksums(enabled),
+            Self::Quic(s) => s.set_frame_checksums(enabled),
+            Self::Uds(s) => s.set_frame_checksums(enabled),
+            #[cfg(feature = "websocket")]
+            Self::WebSocket(s) => s.set_frame_checksums(enabled),
+        }
+    }
+
+    pub fn set_error_categories(&mut self, enabled: bool) {
+        match self {
+            Self::Tcp(s) => s.set_error_categories(enabled),
+            Self::TcpTls(s) => s.set_error_categories(enabled),
+            Self::Quic(s) => s.set_error_categories(enabled),
+            Self::Uds(s) => s.set_error_categories(enabled),
+            #[cfg(feature = "websocket")]
+            Self::WebSocket(s) => s.set_error_categories(enabled),
+        }
+    }
+
+    /// Returns `true` if the polled messages can be sent directly from the segment files,
+    /// which is supported only by the plain TCP transport without the frame checksums.
+    pub fn supports_zero_copy(&self) -> bool {
+        matches!(self, Self::Tcp(s) if s.zero_copy && !s.frame_checksums)
+    }
+
+    /// Sends the payload consisting of the slices held in memory or stored in the segment files,
+    /// it must be used only if the sender `supports_zero_copy`.
+    pub async fn send_ok_response_slices(&mut self, payload: &[Slice]) -> Result<(), IggyError> {
+        match self {
+            Self::Tcp(s) if s.zero_copy && !s.frame_checksums => {
+                s.send_ok_response_slices(payload).await
+            }
+            _ => Err(IggyError::FeatureUnavailable),
+        }
+    }
+
     forward_async_methods! {
         async fn read(&mut self, buffer: &mut [u8]) -> Result<usize, IggyError>;
         async fn send_empty_ok_response(&mut self) -> Result<(), IggyError>;
         async fn send_ok_response(&mut self, payload: &[u8]) -> Result<(), IggyError>;
+        async fn send_ok_response_vectored(&mut self, payload: &[Bytes]) -> Result<(), IggyError>;
         async fn send_error_response(&mut self, error: IggyError) -> Result<(), IggyError>;
         async fn shutdown(&mut self) -> Result<(), ServerError>;
     }
@@ -21,6 +21,11 @@ use std::path::PathBuf;
 use vergen_git2::{BuildBuilder, CargoBuilder, Emitter, Git2Builder, RustcBuilder, SysinfoBuilder};
 
 fn main() -> Result<(), Box<dyn error::Error>> {
+    #[cfg(feature = "grpc")]
+    tonic_build::configure()
+        .bytes(["."])
+        .compile_protos(&["proto/iggy.proto"], &["proto"])?;
+
     if option_env!("IGGY_CI_BUILD") == Some("true") {
         Emitter::default()
             .add_instructions(&BuildBuilder::all_build()?)?
@@ -0,0 +1,108 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::channels::server_command::ServerCommand;
+use crate::configs::server::ServerConfig;
+use crate::configs::system::TransactionsConfig;
+use crate::streaming::systems::system::SharedSystem;
+use flume::{Receiver, Sender};
+use iggy::utils::duration::IggyDuration;
+use tokio::time;
+use tracing::{error, info, instrument};
+
+pub struct TransactionsExpirer {
+    timeout: IggyDuration,
+    interval: IggyDuration,
+    sender: Sender<AbortExpiredTransactionsCommand>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct AbortExpiredTransactionsCommand;
+
+#[derive(Debug, Default, Clone)]
+pub struct AbortExpiredTransactionsExecutor;
+
+impl TransactionsExpirer {
+    pub fn new(
+        config: &TransactionsConfig,
+        sender: Sender<AbortExpiredTransactionsCommand>,
+    ) -> Self {
+        Self {
+            timeout: config.timeout,
+            interval: config.expiry_check_interval,
+            sender,
+        }
+    }
+
+    pub fn start(&self) {
+        let interval = self.interval;
+        let sender = self.sender.clone();
+        info!(
+            "Transactions expirer is enabled, transactions open for longer than: {} will be aborted every: {interval}.",
+            self.timeout
+        );
+        tokio::spawn(async move {
+            let mut interval_timer = time::interval(interval.get_duration());
+            loop {
+                interval_timer.tick().await;
+                sender
+                    .send(AbortExpiredTransactionsCommand)
+                    .unwrap_or_else(|err| {
+                        error!(
+                            "Failed to send AbortExpiredTransactionsCommand. Error: {}",
+                            err
+                        );
+                    });
+            }
+        });
+    }
+}
+
+impl ServerCommand<AbortExpiredTransactionsCommand> for AbortExpiredTransactionsExecutor {
+    #[instrument(skip_all, name = "trace_abort_expired_transactions")]
+    async fn execute(&mut self, system: &SharedSystem, _command: AbortExpiredTransactionsCommand) {
+        let system = system.read().await;
+        if let Err(error) = system.abort_expired_transactions().await {
+            error!("Failed to abort expired transactions. Error: {error}");
+        }
+    }
+
+    fn start_command_sender(
+        &mut self,
+        _system: SharedSystem,
+        config: &ServerConfig,
+        sender: Sender<AbortExpiredTransactionsCommand>,
+    ) {
+        let transactions_expirer = TransactionsExpirer::new(&config.system.transactions, sender);
+        transactions_expirer.start();
+    }
+
+    fn start_command_consumer(
+        mut self,
+        system: SharedSystem,
+        _config: &ServerConfig,
+        receiver: Receiver<AbortExpiredTransactionsCommand>,
+    ) {
+        tokio::spawn(async move {
+            let system = system.clone();
+            while let Ok(command) = receiver.recv_async().await {
+                self.execute(&system, command).await;
+            }
+            info!("Transactions expirer receiver stopped.");
+        });
+    }
+}
@@ -18,10 +18,12 @@
 
 use crate::channels::server_command::ServerCommand;
 use crate::configs::server::StateMaintenanceConfig;
+use crate::streaming::diagnostics::telemetry_metrics::TelemetryMetrics;
 use crate::streaming::systems::system::SharedSystem;
 use flume::Sender;
 use iggy::utils::duration::IggyDuration;
 use iggy::utils::timestamp::IggyTimestamp;
+use std::time::Instant;
 use tokio::time;
 use tracing::{error, info, instrument, warn};
 
@@ -131,7 +133,9 @@ impl ServerCommand<ArchiveStateCommand> for ArchiveStateExecutor {
         tokio::spawn(async move {
             let system = system.clone();
             while let Ok(command) = receiver.recv_async().await {
+                let now = Instant::now();
                 self.execute(&system, command).await;
+                TelemetryMetrics::get_instance().record_maintenance("archive_state", now.elapsed());
             }
             info!("State archiver receiver stopped.");
         });
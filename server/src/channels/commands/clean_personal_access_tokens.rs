@@ -35,7 +35,10 @@ pub struct PersonalAccessTokenCleaner {
 pub struct CleanPersonalAccessTokensCommand;
 
 #[derive(Debug, Default, Clone)]
-pub struct CleanPersonalAccessTokensExecutor;
+pub struct CleanPersonalAccessTokensExecutor {
+    max_inactivity: IggyDuration,
+    started_at: IggyTimestamp,
+}
 
 impl PersonalAccessTokenCleaner {
     pub fn new(
@@ -104,6 +107,30 @@ impl ServerCommand<CleanPersonalAccessTokensCommand> for CleanPersonalAccessToke
             }
         }
         info!("Deleted {deleted_tokens_count} expired personal access tokens.");
+        system.pat_login_guard.prune(now.as_micros());
+
+        // The last usage is not persisted, so the tokens can't be considered stale
+        // until the server has been running for at least the maximum inactivity time.
+        if self.max_inactivity.is_zero() {
+            return;
+        }
+
+        let max_inactivity = self.max_inactivity.as_micros();
+        if now.as_micros() < self.started_at.as_micros() + max_inactivity {
+            return;
+        }
+
+        let not_used_since = IggyTimestamp::from(now.as_micros() - max_inactivity);
+        match system
+            .delete_stale_personal_access_tokens(not_used_since)
+            .await
+        {
+            Ok(deleted_tokens_count) => info!(
+                "Deleted {deleted_tokens_count} personal access tokens unused for: {}.",
+                self.max_inactivity
+            ),
+            Err(error) => error!("Failed to delete unused personal access tokens. Error: {error}"),
+        }
     }
 
     fn start_command_sender(
@@ -120,9 +147,11 @@ impl ServerCommand<CleanPersonalAccessTokensCommand> for CleanPersonalAccessToke
     fn start_command_consumer(
         mut self,
         system: SharedSystem,
-        _config: &crate::configs::server::ServerConfig,
+        config: &crate::configs::server::ServerConfig,
         receiver: flume::Receiver<CleanPersonalAccessTokensCommand>,
     ) {
+        self.max_inactivity = config.personal_access_token.cleaner.max_inactivity;
+        self.started_at = IggyTimestamp::now();
         tokio::spawn(async move {
             let system = system.clone();
             while let Ok(command) = receiver.recv_async().await {
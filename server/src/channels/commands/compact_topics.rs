@@ -0,0 +1,151 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::channels::server_command::ServerCommand;
+use crate::configs::server::{CompactionMaintenanceConfig, ServerConfig};
+use crate::streaming::diagnostics::telemetry_metrics::TelemetryMetrics;
+use crate::streaming::systems::system::SharedSystem;
+use flume::{Receiver, Sender};
+use iggy::locking::IggySharedMutFn;
+use iggy::utils::duration::IggyDuration;
+use std::time::Instant;
+use tokio::time;
+use tracing::{debug, error, info, instrument, trace};
+
+pub struct TopicsCompactor {
+    enabled: bool,
+    interval: IggyDuration,
+    sender: Sender<CompactTopicsCommand>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct CompactTopicsCommand;
+
+#[derive(Debug, Default, Clone)]
+pub struct CompactTopicsExecutor;
+
+impl TopicsCompactor {
+    pub fn new(config: &CompactionMaintenanceConfig, sender: Sender<CompactTopicsCommand>) -> Self {
+        Self {
+            enabled: config.enabled,
+            interval: config.interval,
+            sender,
+        }
+    }
+
+    pub fn start(&self) {
+        if !self.enabled {
+            info!("Topics compactor is disabled.");
+            return;
+        }
+
+        let interval = self.interval;
+        let sender = self.sender.clone();
+        info!("Topics compactor is enabled, topics with compact cleanup policy will be compacted every: {interval}.");
+        tokio::spawn(async move {
+            let mut interval_timer = time::interval(interval.get_duration());
+            loop {
+                interval_timer.tick().await;
+                sender.send(CompactTopicsCommand).unwrap_or_else(|err| {
+                    error!("Failed to send CompactTopicsCommand. Error: {}", err);
+                });
+            }
+        });
+    }
+}
+
+impl ServerCommand<CompactTopicsCommand> for CompactTopicsExecutor {
+    #[instrument(skip_all, name = "trace_compact_topics")]
+    async fn execute(&mut self, system: &SharedSystem, _command: CompactTopicsCommand) {
+        let system = system.read().await;
+        if system.is_in_maintenance() {
+            debug!("Server is in maintenance mode, skipping topics compaction.");
+            return;
+        }
+
+        for stream in system.get_streams() {
+            for topic in stream.get_topics() {
+                if !topic.is_compacted() {
+                    continue;
+                }
+
+                let mut removed_messages_count = 0;
+                for partition in topic.get_partitions() {
+                    let mut partition = partition.write().await;
+                    match partition.compact().await {
+                        Ok(result) => removed_messages_count += result.messages_count,
+                        Err(error) => {
+                            error!("Failed to compact partition: {partition} of topic: {topic}. Error: {error}");
+                        }
+                    }
+                }
+
+                if removed_messages_count == 0 {
+                    trace!(
+                        "No messages were compacted for stream ID: {}, topic ID: {}",
+                        topic.stream_id,
+                        topic.topic_id
+                    );
+                    continue;
+                }
+
+                info!(
+                    "Compacted {} messages for stream ID: {}, topic ID: {}",
+                    removed_messages_count, topic.stream_id, topic.topic_id
+                );
+                system.metrics.decrement_messages(removed_messages_count);
+            }
+        }
+    }
+
+    fn start_command_sender(
+        &mut self,
+        _system: SharedSystem,
+        config: &ServerConfig,
+        sender: Sender<CompactTopicsCommand>,
+    ) {
+        if !config.data_maintenance.compaction.enabled {
+            return;
+        }
+
+        let topics_compactor = TopicsCompactor::new(&config.data_maintenance.compaction, sender);
+        topics_compactor.start();
+    }
+
+    fn start_command_consumer(
+        mut self,
+        system: SharedSystem,
+        config: &ServerConfig,
+        receiver: Receiver<CompactTopicsCommand>,
+    ) {
+        if !config.data_maintenance.compaction.enabled {
+            return;
+        }
+
+        tokio::spawn(async move {
+            let system = system.clone();
+            while let Ok(command) = receiver.recv_async().await {
+                let now = Instant::now();
+                self.execute(&system, command).await;
+                TelemetryMetrics::get_instance()
+                    .record_maintenance("compact_topics", now.elapsed());
+            }
+            info!("Topics compactor receiver stopped.");
+        });
+    }
+}
@@ -0,0 +1,103 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::channels::server_command::ServerCommand;
+use crate::configs::server::ServerConfig;
+use crate::configs::system::TopicConfig;
+use crate::streaming::diagnostics::telemetry_metrics::TelemetryMetrics;
+use crate::streaming::systems::system::SharedSystem;
+use flume::{Receiver, Sender};
+use iggy::utils::duration::IggyDuration;
+use std::time::Instant;
+use tokio::time;
+use tracing::{error, info, instrument};
+
+pub struct DrainedTopicsDeleter {
+    interval: IggyDuration,
+    sender: Sender<DeleteDrainedTopicsCommand>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct DeleteDrainedTopicsCommand;
+
+#[derive(Debug, Default, Clone)]
+pub struct DeleteDrainedTopicsExecutor;
+
+impl DrainedTopicsDeleter {
+    pub fn new(config: &TopicConfig, sender: Sender<DeleteDrainedTopicsCommand>) -> Self {
+        Self {
+            interval: config.deletion_check_interval,
+            sender,
+        }
+    }
+
+    pub fn start(&self) {
+        let interval = self.interval;
+        let sender = self.sender.clone();
+        info!("Drained topics deleter is enabled, topics marked for deletion will be deleted once drained, checking every: {interval}.");
+        tokio::spawn(async move {
+            let mut interval_timer = time::interval(interval.get_duration());
+            loop {
+                interval_timer.tick().await;
+                sender
+                    .send(DeleteDrainedTopicsCommand)
+                    .unwrap_or_else(|err| {
+                        error!("Failed to send DeleteDrainedTopicsCommand. Error: {}", err);
+                    });
+            }
+        });
+    }
+}
+
+impl ServerCommand<DeleteDrainedTopicsCommand> for DeleteDrainedTopicsExecutor {
+    #[instrument(skip_all, name = "trace_delete_drained_topics")]
+    async fn execute(&mut self, system: &SharedSystem, _command: DeleteDrainedTopicsCommand) {
+        let mut system = system.write().await;
+        if let Err(error) = system.delete_drained_topics().await {
+            error!("Failed to delete drained topics. Error: {error}");
+        }
+    }
+
+    fn start_command_sender(
+        &mut self,
+        _system: SharedSystem,
+        config: &ServerConfig,
+        sender: Sender<DeleteDrainedTopicsCommand>,
+    ) {
+        let drained_topics_deleter = DrainedTopicsDeleter::new(&config.system.topic, sender);
+        drained_topics_deleter.start();
+    }
+
+    fn start_command_consumer(
+        mut self,
+        system: SharedSystem,
+        _config: &ServerConfig,
+        receiver: Receiver<DeleteDrainedTopicsCommand>,
+    ) {
+        tokio::spawn(async move {
+            let system = system.clone();
+            while let Ok(command) = receiver.recv_async().await {
+                let now = Instant::now();
+                self.execute(&system, command).await;
+                TelemetryMetrics::get_instance()
+                    .record_maintenance("delete_drained_topics", now.elapsed());
+            }
+            info!("Drained topics deleter receiver stopped.");
+        });
+    }
+}
@@ -20,7 +20,10 @@ use crate::archiver::ArchiverKind;
 use crate::channels::server_command::ServerCommand;
 use crate::configs::server::MessagesMaintenanceConfig;
 use crate::map_toggle_str;
-use crate::streaming::systems::system::SharedSystem;
+use crate::streaming::diagnostics::telemetry_metrics::TelemetryMetrics;
+use crate::streaming::partitions::archived_segments::ArchivedSegment;
+use crate::streaming::systems::expiry_notifications::ExpiryNotices;
+use crate::streaming::systems::system::{SharedSystem, System};
 use crate::streaming::topics::topic::Topic;
 use error_set::ErrContext;
 use flume::Sender;
@@ -29,6 +32,7 @@ use iggy::locking::IggySharedMutFn;
 use iggy::utils::duration::IggyDuration;
 use iggy::utils::timestamp::IggyTimestamp;
 use std::sync::Arc;
+use std::time::Instant;
 use tokio::time;
 use tracing::{debug, error, info, instrument, trace};
 
@@ -46,7 +50,9 @@ pub struct MaintainMessagesCommand {
 }
 
 #[derive(Debug, Default, Clone)]
-pub struct MaintainMessagesExecutor;
+pub struct MaintainMessagesExecutor {
+    expiry_notices: ExpiryNotices,
+}
 
 impl MessagesMaintainer {
     pub fn new(
@@ -97,6 +103,11 @@ impl ServerCommand<MaintainMessagesCommand> for MaintainMessagesExecutor {
     #[instrument(skip_all, name = "trace_maintain_messages")]
     async fn execute(&mut self, system: &SharedSystem, command: MaintainMessagesCommand) {
         let system = system.read().await;
+        if system.is_in_maintenance() {
+            debug!("Server is in maintenance mode, skipping messages maintenance.");
+            return;
+        }
+
         let streams = system.get_streams();
         for stream in streams {
             let topics = stream.get_topics();
@@ -107,10 +118,12 @@ impl ServerCommand<MaintainMessagesCommand> for MaintainMessagesExecutor {
                     None
                 };
                 let expired_segments = handle_expired_segments(
+                    &system,
                     topic,
                     archiver.clone(),
                     system.config.segment.archive_expired,
                     command.clean_messages,
+                    &mut self.expiry_notices,
                 )
                 .await;
                 if expired_segments.is_err() {
@@ -121,6 +134,21 @@ impl ServerCommand<MaintainMessagesCommand> for MaintainMessagesExecutor {
                     continue;
                 }
 
+                let excess_segments = handle_excess_segments(
+                    topic,
+                    archiver.clone(),
+                    system.config.segment.archive_expired,
+                    command.clean_messages,
+                )
+                .await;
+                if excess_segments.is_err() {
+                    error!(
+                        "Failed to get excess segments for stream ID: {}, topic ID: {}",
+                        topic.stream_id, topic.topic_id
+                    );
+                    continue;
+                }
+
                 let oldest_segments = handle_oldest_segments(
                     topic,
                     archiver.clone(),
@@ -136,11 +164,14 @@ impl ServerCommand<MaintainMessagesCommand> for MaintainMessagesExecutor {
                 }
 
                 let deleted_expired_segments = expired_segments.unwrap();
+                let deleted_excess_segments = excess_segments.unwrap();
                 let deleted_oldest_segments = oldest_segments.unwrap();
                 let deleted_segments = HandledSegments {
                     segments_count: deleted_expired_segments.segments_count
+                        + deleted_excess_segments.segments_count
                         + deleted_oldest_segments.segments_count,
                     messages_count: deleted_expired_segments.messages_count
+                        + deleted_excess_segments.messages_count
                         + deleted_oldest_segments.messages_count,
                 };
 
@@ -167,6 +198,12 @@ impl ServerCommand<MaintainMessagesCommand> for MaintainMessagesExecutor {
                 system
                     .metrics
                     .decrement_messages(deleted_segments.messages_count);
+                TelemetryMetrics::get_instance().record_deleted_segments(
+                    topic.stream_id,
+                    topic.topic_id,
+                    deleted_segments.segments_count,
+                    deleted_segments.messages_count,
+                );
             }
         }
     }
@@ -205,7 +242,10 @@ impl ServerCommand<MaintainMessagesCommand> for MaintainMessagesExecutor {
         tokio::spawn(async move {
             let system = system.clone();
             while let Ok(command) = receiver.recv_async().await {
+                let now = Instant::now();
                 self.execute(&system, command).await;
+                TelemetryMetrics::get_instance()
+                    .record_maintenance("maintain_messages", now.elapsed());
             }
             info!("Messages maintainer receiver stopped.");
         });
@@ -213,28 +253,76 @@ impl ServerCommand<MaintainMessagesCommand> for MaintainMessagesExecutor {
 }
 
 async fn handle_expired_segments(
+    system: &System,
     topic: &Topic,
     archiver: Option<Arc<ArchiverKind>>,
     archive: bool,
     clean: bool,
+    expiry_notices: &mut ExpiryNotices,
 ) -> Result<HandledSegments, IggyError> {
-    let expired_segments = get_expired_segments(topic, IggyTimestamp::now()).await;
-    if expired_segments.is_empty() {
+    let now = IggyTimestamp::now();
+    let mut expired_segments = get_expired_segments(topic, now).await;
+    if clean {
+        // The watchers of the topic are notified first, and the segments are deleted once the grace period elapses.
+        for segments in expired_segments.iter_mut() {
+            segments.start_offsets = system
+                .hold_expired_segments(
+                    topic,
+                    segments.partition_id,
+                    &segments.start_offsets,
+                    now,
+                    expiry_notices,
+                )
+                .await;
+        }
+        expired_segments.retain(|segments| !segments.start_offsets.is_empty());
+    }
+    handle_retention_segments(
+        topic,
+        &expired_segments,
+        "expired",
+        archiver,
+        archive,
+        clean,
+    )
+    .await
+}
+
+async fn handle_excess_segments(
+    topic: &Topic,
+    archiver: Option<Arc<ArchiverKind>>,
+    archive: bool,
+    clean: bool,
+) -> Result<HandledSegments, IggyError> {
+    let excess_segments = get_excess_segments(topic).await;
+    handle_retention_segments(topic, &excess_segments, "excess", archiver, archive, clean).await
+}
+
+/// Archives (if enabled) and deletes the segments which are no longer retained by the topic.
+async fn handle_retention_segments(
+    topic: &Topic,
+    segments: &[SegmentsToHandle],
+    kind: &str,
+    archiver: Option<Arc<ArchiverKind>>,
+    archive: bool,
+    clean: bool,
+) -> Result<HandledSegments, IggyError> {
+    if segments.is_empty() {
         return Ok(HandledSegments::none());
     }
 
     if archive {
         if let Some(archiver) = archiver {
             info!(
-                "Archiving expired segments for stream ID: {}, topic ID: {}",
+                "Archiving {kind} segments for stream ID: {}, topic ID: {}",
                 topic.stream_id, topic.topic_id
             );
-            archive_segments(topic, &expired_segments, archiver.clone()).await.with_error_context(|error| {
-                format!("CHANNEL_COMMAND - failed to archive expired segments for stream ID: {}, topic ID: {}. {error}", topic.stream_id, topic.topic_id)
+            archive_segments(topic, segments, archiver.clone()).await.with_error_context(|error| {
+                format!("CHANNEL_COMMAND - failed to archive {kind} segments for stream ID: {}, topic ID: {}. {error}", topic.stream_id, topic.topic_id)
             })?;
         } else {
             error!(
-                "Archiver is not enabled, yet archive_expired is set to true. Cannot archive expired segments for stream ID: {}, topic ID: {}",
+                "Archiver is not enabled, yet archive_expired is set to true. Cannot archive {kind} segments for stream ID: {}, topic ID: {}",
                 topic.stream_id, topic.topic_id
             );
             return Ok(HandledSegments::none());
@@ -243,13 +331,13 @@ async fn handle_expired_segments(
 
     if clean {
         info!(
-            "Deleting expired segments for stream ID: {}, topic ID: {}",
+            "Deleting {kind} segments for stream ID: {}, topic ID: {}",
             topic.stream_id, topic.topic_id
         );
-        delete_segments(topic, &expired_segments).await
+        delete_segments(topic, segments).await
     } else {
         info!(
-            "Deleting expired segments is disabled for stream ID: {}, topic ID: {}",
+            "Deleting {kind} segments is disabled for stream ID: {}, topic ID: {}",
             topic.stream_id, topic.topic_id
         );
         Ok(HandledSegments::none())
@@ -284,6 +372,35 @@ async fn get_expired_segments(topic: &Topic, now: IggyTimestamp) -> Vec<Segments
         .collect()
 }
 
+async fn get_excess_segments(topic: &Topic) -> Vec<SegmentsToHandle> {
+    let excess_segments = topic
+        .get_excess_segments_start_offsets_per_partition()
+        .await;
+    if excess_segments.is_empty() {
+        debug!(
+            "No segments exceeding max segments: {} found for stream ID: {}, topic ID: {}",
+            topic.max_segments, topic.stream_id, topic.topic_id
+        );
+        return Vec::new();
+    }
+
+    debug!(
+        "Found {} partitions with segments exceeding max segments: {} for stream ID: {}, topic ID: {}",
+        excess_segments.len(),
+        topic.max_segments,
+        topic.stream_id,
+        topic.topic_id
+    );
+
+    excess_segments
+        .into_iter()
+        .map(|(partition_id, start_offsets)| SegmentsToHandle {
+            partition_id,
+            start_offsets,
+        })
+        .collect()
+}
+
 async fn handle_oldest_segments(
     topic: &Topic,
     archiver: Option<Arc<ArchiverKind>>,
@@ -453,8 +570,9 @@ async fn archive_segments(
     let mut archived_segments = 0;
     for segment_to_archive in segments_to_archive {
         match topic.get_partition(segment_to_archive.partition_id) {
-            Ok(partition) => {
-                let partition = partition.read().await;
+            Ok(partition_lock) => {
+                let mut partition_archived_segments = Vec::new();
+                let partition = partition_lock.read().await;
                 for start_offset in &segment_to_archive.start_offsets {
                     let segment = partition.get_segment(*start_offset);
                     if segment.is_none() {
@@ -478,8 +596,30 @@ async fn archive_segments(
                         "Archived Segment with start offset: {}, for stream ID: {}, topic ID: {}, partition ID: {}",
                         start_offset, topic.stream_id, topic.topic_id, partition.partition_id
                     );
+                    partition_archived_segments.push(ArchivedSegment {
+                        start_offset: segment.start_offset,
+                        end_offset: segment.current_offset,
+                        restored: false,
+                    });
                     archived_segments += 1;
                 }
+                drop(partition);
+
+                if partition_archived_segments.is_empty() {
+                    continue;
+                }
+
+                // The archived segments are recorded, so that they can be read back by the tiered storage once deleted.
+                let mut partition = partition_lock.write().await;
+                if let Err(error) = partition
+                    .add_archived_segments(&partition_archived_segments)
+                    .await
+                {
+                    error!(
+                        "Failed to save archived segments for stream ID: {}, topic ID: {}, partition ID: {}. Error: {}",
+                        topic.stream_id, topic.topic_id, partition.partition_id, error
+                    );
+                }
             }
             Err(error) => {
                 error!(
@@ -16,9 +16,14 @@
  * under the License.
  */
 
+pub mod abort_expired_transactions;
 pub mod archive_state;
 pub mod clean_personal_access_tokens;
+pub mod compact_topics;
+pub mod delete_drained_topics;
 pub mod maintain_messages;
 pub mod print_sysinfo;
+pub mod save_cache_heat_map;
 pub mod save_messages;
+pub mod snapshot_topics;
 pub mod verify_heartbeats;
@@ -0,0 +1,112 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::channels::server_command::ServerCommand;
+use crate::configs::server::ServerConfig;
+use crate::configs::system::CacheConfig;
+use crate::streaming::diagnostics::telemetry_metrics::TelemetryMetrics;
+use crate::streaming::systems::system::SharedSystem;
+use flume::{Receiver, Sender};
+use iggy::utils::duration::IggyDuration;
+use std::time::Instant;
+use tokio::time;
+use tracing::{error, info, instrument};
+
+pub struct CacheHeatMapSaver {
+    enabled: bool,
+    interval: IggyDuration,
+    sender: Sender<SaveCacheHeatMapCommand>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct SaveCacheHeatMapCommand;
+
+#[derive(Debug, Default, Clone)]
+pub struct SaveCacheHeatMapExecutor;
+
+impl CacheHeatMapSaver {
+    pub fn new(config: &CacheConfig, sender: Sender<SaveCacheHeatMapCommand>) -> Self {
+        Self {
+            enabled: config.enabled && config.warmup.enabled,
+            interval: config.warmup.heat_map_interval,
+            sender,
+        }
+    }
+
+    pub fn start(&self) {
+        if !self.enabled {
+            info!("Cache heat map saver is disabled.");
+            return;
+        }
+
+        let interval = self.interval;
+        let sender = self.sender.clone();
+        info!("Cache heat map saver is enabled, the heat map will be saved every: {interval}.");
+        tokio::spawn(async move {
+            let mut interval_timer = time::interval(interval.get_duration());
+            loop {
+                interval_timer.tick().await;
+                sender.send(SaveCacheHeatMapCommand).unwrap_or_else(|err| {
+                    error!("Failed to send SaveCacheHeatMapCommand. Error: {}", err);
+                });
+            }
+        });
+    }
+}
+
+impl ServerCommand<SaveCacheHeatMapCommand> for SaveCacheHeatMapExecutor {
+    #[instrument(skip_all, name = "trace_save_cache_heat_map")]
+    async fn execute(&mut self, system: &SharedSystem, _command: SaveCacheHeatMapCommand) {
+        let system = system.read().await;
+        if let Err(error) = system.save_cache_heat_map().await {
+            error!("Failed to save cache heat map. Error: {error}");
+        }
+    }
+
+    fn start_command_sender(
+        &mut self,
+        _system: SharedSystem,
+        config: &ServerConfig,
+        sender: Sender<SaveCacheHeatMapCommand>,
+    ) {
+        let cache_heat_map_saver = CacheHeatMapSaver::new(&config.system.cache, sender);
+        cache_heat_map_saver.start();
+    }
+
+    fn start_command_consumer(
+        mut self,
+        system: SharedSystem,
+        config: &ServerConfig,
+        receiver: Receiver<SaveCacheHeatMapCommand>,
+    ) {
+        if !config.system.cache.enabled || !config.system.cache.warmup.enabled {
+            return;
+        }
+
+        tokio::spawn(async move {
+            let system = system.clone();
+            while let Ok(command) = receiver.recv_async().await {
+                let now = Instant::now();
+                self.execute(&system, command).await;
+                TelemetryMetrics::get_instance()
+                    .record_maintenance("save_cache_heat_map", now.elapsed());
+            }
+            info!("Cache heat map saver receiver stopped.");
+        });
+    }
+}
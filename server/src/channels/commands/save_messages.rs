@@ -19,11 +19,13 @@
 use crate::channels::server_command::ServerCommand;
 use crate::configs::server::MessageSaverConfig;
 use crate::configs::server::ServerConfig;
+use crate::streaming::diagnostics::telemetry_metrics::TelemetryMetrics;
 use crate::streaming::systems::system::SharedSystem;
 use flume::{Receiver, Sender};
 use iggy::utils::duration::IggyDuration;
+use std::time::Instant;
 use tokio::time;
-use tracing::{error, info, instrument, warn};
+use tracing::{debug, error, info, instrument, warn};
 
 pub struct MessagesSaver {
     enabled: bool,
@@ -76,7 +78,13 @@ impl MessagesSaver {
 impl ServerCommand<SaveMessagesCommand> for SaveMessagesExecutor {
     #[instrument(skip_all, name = "trace_save_messages")]
     async fn execute(&mut self, system: &SharedSystem, _command: SaveMessagesCommand) {
-        let saved_messages_count = system.read().await.persist_messages().await;
+        let system = system.read().await;
+        if system.is_in_maintenance() {
+            debug!("Server is in maintenance mode, skipping saving buffered messages.");
+            return;
+        }
+
+        let saved_messages_count = system.persist_messages().await;
         match saved_messages_count {
             Ok(n) => {
                 if n > 0 {
@@ -108,7 +116,9 @@ impl ServerCommand<SaveMessagesCommand> for SaveMessagesExecutor {
         tokio::spawn(async move {
             let system = system.clone();
             while let Ok(command) = receiver.recv_async().await {
+                let now = Instant::now();
                 self.execute(&system, command).await;
+                TelemetryMetrics::get_instance().record_maintenance("save_messages", now.elapsed());
             }
             warn!("Server command handler stopped receiving commands.");
         });
@@ -0,0 +1,154 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::channels::server_command::ServerCommand;
+use crate::configs::server::{ServerConfig, TopicSnapshotsMaintenanceConfig};
+use crate::streaming::diagnostics::telemetry_metrics::TelemetryMetrics;
+use crate::streaming::systems::system::SharedSystem;
+use flume::{Receiver, Sender};
+use iggy::utils::duration::IggyDuration;
+use std::time::Instant;
+use tokio::time;
+use tracing::{debug, error, info, instrument, warn};
+
+pub struct TopicSnapshotter {
+    enabled: bool,
+    max_messages: u32,
+    interval: IggyDuration,
+    sender: Sender<SnapshotTopicsCommand>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct SnapshotTopicsCommand {
+    max_messages: u32,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct SnapshotTopicsExecutor;
+
+impl TopicSnapshotter {
+    pub fn new(
+        config: &TopicSnapshotsMaintenanceConfig,
+        sender: Sender<SnapshotTopicsCommand>,
+    ) -> Self {
+        Self {
+            enabled: config.enabled,
+            max_messages: config.max_messages,
+            interval: config.interval,
+            sender,
+        }
+    }
+
+    pub fn start(&self) {
+        if !self.enabled {
+            info!("Topic snapshotter is disabled.");
+            return;
+        }
+
+        let max_messages = self.max_messages;
+        let interval = self.interval;
+        let sender = self.sender.clone();
+        info!("Topic snapshotter is enabled, up to {max_messages} messages per partition will be stored every: {interval}.");
+        tokio::spawn(async move {
+            let mut interval_timer = time::interval(interval.get_duration());
+            loop {
+                interval_timer.tick().await;
+                sender
+                    .send(SnapshotTopicsCommand { max_messages })
+                    .unwrap_or_else(|err| {
+                        error!("Failed to send SnapshotTopicsCommand. Error: {}", err);
+                    });
+            }
+        });
+    }
+}
+
+impl ServerCommand<SnapshotTopicsCommand> for SnapshotTopicsExecutor {
+    #[instrument(skip_all, name = "trace_snapshot_topics")]
+    async fn execute(&mut self, system: &SharedSystem, command: SnapshotTopicsCommand) {
+        let system = system.read().await;
+        let Some(archiver) = system.archiver.as_ref() else {
+            warn!("Archiver is disabled, topic snapshots will not be stored.");
+            return;
+        };
+
+        for stream in system.get_streams() {
+            for topic in stream.get_topics() {
+                let files = match system
+                    .save_topic_snapshot(topic, command.max_messages)
+                    .await
+                {
+                    Ok(files) => files,
+                    Err(error) => {
+                        error!("Failed to save snapshot of topic: {topic}. Error: {error}");
+                        continue;
+                    }
+                };
+
+                let files = files.iter().map(|file| file.as_str()).collect::<Vec<_>>();
+                if let Err(error) = archiver.archive(&files, None).await {
+                    error!("Failed to archive snapshot of topic: {topic}. Error: {error}");
+                    continue;
+                }
+                debug!("Archived snapshot of topic: {topic}.");
+            }
+        }
+        info!("Topic snapshots archived successfully.");
+    }
+
+    fn start_command_sender(
+        &mut self,
+        _system: SharedSystem,
+        config: &ServerConfig,
+        sender: Sender<SnapshotTopicsCommand>,
+    ) {
+        if !config.data_maintenance.archiver.enabled
+            || !config.data_maintenance.topic_snapshots.enabled
+        {
+            return;
+        }
+
+        let topic_snapshotter =
+            TopicSnapshotter::new(&config.data_maintenance.topic_snapshots, sender);
+        topic_snapshotter.start();
+    }
+
+    fn start_command_consumer(
+        mut self,
+        system: SharedSystem,
+        config: &ServerConfig,
+        receiver: Receiver<SnapshotTopicsCommand>,
+    ) {
+        if !config.data_maintenance.archiver.enabled
+            || !config.data_maintenance.topic_snapshots.enabled
+        {
+            return;
+        }
+
+        tokio::spawn(async move {
+            let system = system.clone();
+            while let Ok(command) = receiver.recv_async().await {
+                let now = Instant::now();
+                self.execute(&system, command).await;
+                TelemetryMetrics::get_instance()
+                    .record_maintenance("snapshot_topics", now.elapsed());
+            }
+            info!("Topic snapshotter receiver stopped.");
+        });
+    }
+}
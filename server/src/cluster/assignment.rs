@@ -0,0 +1,88 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartitionAssignment {
+    pub leader_id: u32,
+    pub replica_ids: Vec<u32>,
+}
+
+/// Assigns the leader and the replicas of the partition, so that every node computes the same assignment
+/// from the same members of the cluster, without any coordination. The partitions of the topic are spread
+/// in a round-robin fashion, starting at the node depending on the stream and the topic, and the replicas
+/// are the nodes following the leader, up to the replication factor including the leader itself.
+pub fn assign_partition(
+    node_ids: &[u32],
+    stream_id: u32,
+    topic_id: u32,
+    partition_id: u32,
+    replication_factor: u8,
+) -> PartitionAssignment {
+    let mut node_ids = node_ids.to_vec();
+    node_ids.sort_unstable();
+    let nodes_count = node_ids.len() as u64;
+    let offset = (stream_id as u64 * 31 + topic_id as u64 + partition_id.saturating_sub(1) as u64)
+        % nodes_count;
+    let replicas_count = (replication_factor.max(1) as u64).min(nodes_count);
+    let mut assigned_ids = (0..replicas_count)
+        .map(|position| node_ids[((offset + position) % nodes_count) as usize])
+        .collect::<Vec<_>>();
+    let leader_id = assigned_ids.remove(0);
+    PartitionAssignment {
+        leader_id,
+        replica_ids: assigned_ids,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn partitions_should_be_spread_across_nodes_with_following_replicas() {
+        let node_ids = [3, 1, 2];
+        let assignments = (1..=3)
+            .map(|partition_id| assign_partition(&node_ids, 1, 1, partition_id, 2))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            assignments,
+            vec![
+                PartitionAssignment {
+                    leader_id: 3,
+                    replica_ids: vec![1],
+                },
+                PartitionAssignment {
+                    leader_id: 1,
+                    replica_ids: vec![2],
+                },
+                PartitionAssignment {
+                    leader_id: 2,
+                    replica_ids: vec![3],
+                },
+            ]
+        );
+        assert_eq!(
+            assign_partition(&node_ids, 1, 1, 1, 5).replica_ids,
+            vec![1, 2]
+        );
+        assert!(assign_partition(&node_ids, 1, 1, 1, 1)
+            .replica_ids
+            .is_empty());
+    }
+}
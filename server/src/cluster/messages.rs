@@ -0,0 +1,343 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use iggy::bytes_serializable::BytesSerializable;
+use iggy::error::IggyError;
+use iggy::messages::send_messages::SendMessages;
+
+const REQUEST_VOTE_CODE: u8 = 1;
+const REQUEST_VOTE_RESPONSE_CODE: u8 = 2;
+const APPEND_ENTRIES_CODE: u8 = 3;
+const APPEND_ENTRIES_RESPONSE_CODE: u8 = 4;
+const APPEND_MESSAGES_CODE: u8 = 5;
+const INSTALL_SNAPSHOT_CODE: u8 = 6;
+const INSTALL_SNAPSHOT_RESPONSE_CODE: u8 = 7;
+
+/// The entry of the replicated log, the index starts at 1 and the payload is opaque to the Raft protocol.
+/// The entry with an empty payload is the no-op one, appended by the leader at the start of its term.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogEntry {
+    pub index: u64,
+    pub term: u64,
+    pub payload: Bytes,
+}
+
+/// The message of the Raft protocol exchanged between the nodes.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RaftMessage {
+    RequestVote {
+        term: u64,
+        last_log_index: u64,
+        last_log_term: u64,
+    },
+    RequestVoteResponse {
+        term: u64,
+        vote_granted: bool,
+    },
+    AppendEntries {
+        term: u64,
+        prev_log_index: u64,
+        prev_log_term: u64,
+        leader_commit: u64,
+        entries: Vec<LogEntry>,
+    },
+    /// On success, `last_log_index` is the index of the last entry matching the leader's log,
+    /// otherwise it's the last index of the follower's log, used by the leader to skip the missing entries.
+    AppendEntriesResponse {
+        term: u64,
+        success: bool,
+        last_log_index: u64,
+    },
+    /// Sent instead of the `AppendEntries`, once the entries missing on the follower were compacted into
+    /// the snapshot. The chunk consists of the committed entries with the indexes from `first_index`
+    /// to `last_index`, the follower replaces its log with the snapshot once `last_index` reaches
+    /// `last_included_index`. The no-op entries are not part of the snapshot.
+    InstallSnapshot {
+        term: u64,
+        last_included_index: u64,
+        last_included_term: u64,
+        first_index: u64,
+        last_index: u64,
+        entries: Vec<LogEntry>,
+    },
+    /// `last_index` is the index up to which the follower received the snapshot.
+    InstallSnapshotResponse {
+        term: u64,
+        last_index: u64,
+    },
+}
+
+/// The message sent between the nodes of the cluster, either of the Raft protocol
+/// or the batch of messages forwarded by the partition leader to its replicas.
+#[derive(Debug, PartialEq)]
+pub enum ClusterMessage {
+    Raft(RaftMessage),
+    AppendMessages(SendMessages),
+}
+
+impl BytesSerializable for ClusterMessage {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::new();
+        match self {
+            ClusterMessage::Raft(RaftMessage::RequestVote {
+                term,
+                last_log_index,
+                last_log_term,
+            }) => {
+                bytes.put_u8(REQUEST_VOTE_CODE);
+                bytes.put_u64_le(*term);
+                bytes.put_u64_le(*last_log_index);
+                bytes.put_u64_le(*last_log_term);
+            }
+            ClusterMessage::Raft(RaftMessage::RequestVoteResponse { term, vote_granted }) => {
+                bytes.put_u8(REQUEST_VOTE_RESPONSE_CODE);
+                bytes.put_u64_le(*term);
+                bytes.put_u8(*vote_granted as u8);
+            }
+            ClusterMessage::Raft(RaftMessage::AppendEntries {
+                term,
+                prev_log_index,
+                prev_log_term,
+                leader_commit,
+                entries,
+            }) => {
+                bytes.put_u8(APPEND_ENTRIES_CODE);
+                bytes.put_u64_le(*term);
+                bytes.put_u64_le(*prev_log_index);
+                bytes.put_u64_le(*prev_log_term);
+                bytes.put_u64_le(*leader_commit);
+                put_entries(&mut bytes, entries);
+            }
+            ClusterMessage::Raft(RaftMessage::AppendEntriesResponse {
+                term,
+                success,
+                last_log_index,
+            }) => {
+                bytes.put_u8(APPEND_ENTRIES_RESPONSE_CODE);
+                bytes.put_u64_le(*term);
+                bytes.put_u8(*success as u8);
+                bytes.put_u64_le(*last_log_index);
+            }
+            ClusterMessage::Raft(RaftMessage::InstallSnapshot {
+                term,
+                last_included_index,
+                last_included_term,
+                first_index,
+                last_index,
+                entries,
+            }) => {
+                bytes.put_u8(INSTALL_SNAPSHOT_CODE);
+                bytes.put_u64_le(*term);
+                bytes.put_u64_le(*last_included_index);
+                bytes.put_u64_le(*last_included_term);
+                bytes.put_u64_le(*first_index);
+                bytes.put_u64_le(*last_index);
+                put_entries(&mut bytes, entries);
+            }
+            ClusterMessage::Raft(RaftMessage::InstallSnapshotResponse { term, last_index }) => {
+                bytes.put_u8(INSTALL_SNAPSHOT_RESPONSE_CODE);
+                bytes.put_u64_le(*term);
+                bytes.put_u64_le(*last_index);
+            }
+            ClusterMessage::AppendMessages(command) => {
+                bytes.put_u8(APPEND_MESSAGES_CODE);
+                bytes.put_slice(&command.to_bytes());
+            }
+        }
+        bytes.freeze()
+    }
+
+    fn from_bytes(mut bytes: Bytes) -> Result<Self, IggyError>
+    where
+        Self: Sized,
+    {
+        ensure_remaining(&bytes, 1)?;
+        let message = match bytes.get_u8() {
+            REQUEST_VOTE_CODE => {
+                ensure_remaining(&bytes, 24)?;
+                RaftMessage::RequestVote {
+                    term: bytes.get_u64_le(),
+                    last_log_index: bytes.get_u64_le(),
+                    last_log_term: bytes.get_u64_le(),
+                }
+            }
+            REQUEST_VOTE_RESPONSE_CODE => {
+                ensure_remaining(&bytes, 9)?;
+                RaftMessage::RequestVoteResponse {
+                    term: bytes.get_u64_le(),
+                    vote_granted: bytes.get_u8() == 1,
+                }
+            }
+            APPEND_ENTRIES_CODE => {
+                ensure_remaining(&bytes, 36)?;
+                let term = bytes.get_u64_le();
+                let prev_log_index = bytes.get_u64_le();
+                let prev_log_term = bytes.get_u64_le();
+                let leader_commit = bytes.get_u64_le();
+                let entries = get_entries(&mut bytes)?;
+                RaftMessage::AppendEntries {
+                    term,
+                    prev_log_index,
+                    prev_log_term,
+                    leader_commit,
+                    entries,
+                }
+            }
+            APPEND_ENTRIES_RESPONSE_CODE => {
+                ensure_remaining(&bytes, 17)?;
+                RaftMessage::AppendEntriesResponse {
+                    term: bytes.get_u64_le(),
+                    success: bytes.get_u8() == 1,
+                    last_log_index: bytes.get_u64_le(),
+                }
+            }
+            INSTALL_SNAPSHOT_CODE => {
+                ensure_remaining(&bytes, 44)?;
+                let term = bytes.get_u64_le();
+                let last_included_index = bytes.get_u64_le();
+                let last_included_term = bytes.get_u64_le();
+                let first_index = bytes.get_u64_le();
+                let last_index = bytes.get_u64_le();
+                let entries = get_entries(&mut bytes)?;
+                RaftMessage::InstallSnapshot {
+                    term,
+                    last_included_index,
+                    last_included_term,
+                    first_index,
+                    last_index,
+                    entries,
+                }
+            }
+            INSTALL_SNAPSHOT_RESPONSE_CODE => {
+                ensure_remaining(&bytes, 16)?;
+                RaftMessage::InstallSnapshotResponse {
+                    term: bytes.get_u64_le(),
+                    last_index: bytes.get_u64_le(),
+                }
+            }
+            APPEND_MESSAGES_CODE => {
+                return Ok(ClusterMessage::AppendMessages(SendMessages::from_bytes(
+                    bytes,
+                )?));
+            }
+            _ => return Err(IggyError::InvalidCommand),
+        };
+        Ok(ClusterMessage::Raft(message))
+    }
+}
+
+pub(crate) fn put_entries(bytes: &mut BytesMut, entries: &[LogEntry]) {
+    bytes.put_u32_le(entries.len() as u32);
+    for entry in entries {
+        bytes.put_u64_le(entry.index);
+        bytes.put_u64_le(entry.term);
+        bytes.put_u32_le(entry.payload.len() as u32);
+        bytes.put_slice(&entry.payload);
+    }
+}
+
+pub(crate) fn get_entries(bytes: &mut Bytes) -> Result<Vec<LogEntry>, IggyError> {
+    ensure_remaining(bytes, 4)?;
+    let entries_count = bytes.get_u32_le();
+    let mut entries = Vec::new();
+    for _ in 0..entries_count {
+        ensure_remaining(bytes, 20)?;
+        let index = bytes.get_u64_le();
+        let term = bytes.get_u64_le();
+        let payload_length = bytes.get_u32_le() as usize;
+        ensure_remaining(bytes, payload_length)?;
+        let payload = bytes.split_to(payload_length);
+        entries.push(LogEntry {
+            index,
+            term,
+            payload,
+        });
+    }
+    Ok(entries)
+}
+
+fn ensure_remaining(bytes: &Bytes, length: usize) -> Result<(), IggyError> {
+    if bytes.remaining() < length {
+        return Err(IggyError::InvalidCommand);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn append_entries_should_be_serialized_and_deserialized() {
+        let message = ClusterMessage::Raft(RaftMessage::AppendEntries {
+            term: 3,
+            prev_log_index: 10,
+            prev_log_term: 2,
+            leader_commit: 9,
+            entries: vec![
+                LogEntry {
+                    index: 11,
+                    term: 3,
+                    payload: Bytes::from_static(b"first"),
+                },
+                LogEntry {
+                    index: 12,
+                    term: 3,
+                    payload: Bytes::from_static(b"second"),
+                },
+            ],
+        });
+
+        let bytes = message.to_bytes();
+        let deserialized = ClusterMessage::from_bytes(bytes.clone()).unwrap();
+
+        assert_eq!(deserialized, message);
+        assert!(ClusterMessage::from_bytes(bytes.slice(..bytes.len() - 1)).is_err());
+    }
+
+    #[test]
+    fn install_snapshot_should_be_serialized_and_deserialized() {
+        let message = ClusterMessage::Raft(RaftMessage::InstallSnapshot {
+            term: 4,
+            last_included_index: 20,
+            last_included_term: 3,
+            first_index: 5,
+            last_index: 8,
+            entries: vec![
+                LogEntry {
+                    index: 5,
+                    term: 2,
+                    payload: Bytes::from_static(b"first"),
+                },
+                LogEntry {
+                    index: 8,
+                    term: 3,
+                    payload: Bytes::from_static(b"second"),
+                },
+            ],
+        });
+
+        let bytes = message.to_bytes();
+        let deserialized = ClusterMessage::from_bytes(bytes.clone()).unwrap();
+
+        assert_eq!(deserialized, message);
+        assert!(ClusterMessage::from_bytes(bytes.slice(..bytes.len() - 1)).is_err());
+    }
+}
@@ -0,0 +1,55 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use iggy::error::IggyError;
+
+pub mod assignment;
+pub mod messages;
+pub mod node;
+pub mod raft;
+pub mod transport;
+
+pub const COMPONENT: &str = "CLUSTER";
+
+/// The other node of the cluster, as configured in the `system.cluster.nodes`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClusterNodeAddress {
+    pub id: u32,
+    pub address: String,
+}
+
+/// Parses the node of the cluster in the "<node ID>=<address>" format.
+pub fn parse_cluster_node(node: &str) -> Result<ClusterNodeAddress, IggyError> {
+    let Some((id, address)) = node.split_once('=') else {
+        return Err(IggyError::InvalidConfiguration);
+    };
+
+    let id = id
+        .trim()
+        .parse::<u32>()
+        .map_err(|_| IggyError::InvalidConfiguration)?;
+    let address = address.trim();
+    if id == 0 || address.is_empty() {
+        return Err(IggyError::InvalidConfiguration);
+    }
+
+    Ok(ClusterNodeAddress {
+        id,
+        address: address.to_owned(),
+    })
+}
@@ -0,0 +1,768 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cluster::assignment::{assign_partition, PartitionAssignment};
+use crate::cluster::messages::{self, ClusterMessage, LogEntry, RaftMessage};
+use crate::cluster::raft::{self, HardState, Raft, RaftConfig, SnapshotMetadata};
+use crate::cluster::transport::ClusterTransport;
+use crate::cluster::{parse_cluster_node, ClusterNodeAddress, COMPONENT};
+use crate::configs::system::ClusterConfig;
+use crate::state::command::EntryCommand;
+use crate::state::entry::StateEntry;
+use crate::state::file::FileState;
+use crate::state::State;
+use crate::streaming::persistence::persister::PersisterKind;
+use crate::streaming::systems::system::SharedSystem;
+use crate::streaming::topics::topic::Topic;
+use crate::streaming::utils::file;
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use dashmap::DashMap;
+use error_set::ErrContext;
+use flume::{Receiver, Sender};
+use iggy::bytes_serializable::BytesSerializable;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::send_messages::{Message, Partitioning, PartitioningKind, SendMessages};
+use std::collections::VecDeque;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Duration;
+use tokio::sync::{oneshot, Notify};
+use tokio::time::{self, Instant, MissedTickBehavior};
+use tracing::{error, info, warn};
+
+const TICK_INTERVAL: Duration = Duration::from_millis(10);
+
+#[derive(Debug)]
+struct Proposal {
+    term: u64,
+    sender: Option<oneshot::Sender<()>>,
+}
+
+/// The changes of the Raft node which weren't persisted or appended to the state file yet.
+#[derive(Debug)]
+struct Ready {
+    hard_state: HardState,
+    log: Option<(SnapshotMetadata, Vec<LogEntry>)>,
+    committed_entries: VecDeque<LogEntry>,
+}
+
+/// The node of the cluster, replicating the state log with the Raft protocol. The leader replicates
+/// the entry of each change before applying it to the system, and waits until it's committed and appended
+/// to the state file, while the followers append the committed entries to their state files and apply them
+/// to the system. The log of the entries not yet compacted into the snapshot is persisted next to the state
+/// file, the snapshot itself is the state file, from which the lagging followers receive the entries.
+#[derive(Debug)]
+pub struct ClusterNode {
+    config: ClusterConfig,
+    nodes: Vec<ClusterNodeAddress>,
+    node_ids: Vec<u32>,
+    raft_path: String,
+    raft_log_path: String,
+    persister: Arc<PersisterKind>,
+    state: FileState,
+    raft: Mutex<Raft>,
+    proposals: DashMap<u64, Proposal>,
+    applied_index: AtomicU64,
+    pending_entries: AtomicU64,
+    started: AtomicBool,
+    transport: OnceLock<ClusterTransport>,
+    notify: Notify,
+}
+
+impl ClusterNode {
+    pub fn new(
+        config: &ClusterConfig,
+        raft_path: &str,
+        raft_log_path: &str,
+        persister: Arc<PersisterKind>,
+        state: FileState,
+    ) -> Self {
+        let nodes = config
+            .nodes
+            .iter()
+            .filter_map(|node| parse_cluster_node(node).ok())
+            .collect::<Vec<_>>();
+        let mut node_ids = nodes.iter().map(|node| node.id).collect::<Vec<_>>();
+        let raft = Raft::new(
+            config.node_id,
+            node_ids.clone(),
+            Self::get_raft_config(config),
+            HardState::default(),
+            SnapshotMetadata::default(),
+            Vec::new(),
+            0,
+        );
+        node_ids.push(config.node_id);
+        node_ids.sort_unstable();
+        Self {
+            config: config.clone(),
+            nodes,
+            node_ids,
+            raft_path: raft_path.to_owned(),
+            raft_log_path: raft_log_path.to_owned(),
+            persister,
+            state,
+            raft: Mutex::new(raft),
+            proposals: DashMap::new(),
+            applied_index: AtomicU64::new(0),
+            pending_entries: AtomicU64::new(0),
+            started: AtomicBool::new(false),
+            transport: OnceLock::new(),
+            notify: Notify::new(),
+        }
+    }
+
+    pub fn node_id(&self) -> u32 {
+        self.config.node_id
+    }
+
+    pub fn leader_id(&self) -> Option<u32> {
+        self.raft.lock().unwrap().leader_id()
+    }
+
+    /// Returns an error pointing to the current leader, if this node can't accept the changes of the state.
+    pub fn ensure_leader(&self) -> Result<(), IggyError> {
+        let raft = self.raft.lock().unwrap();
+        if raft.is_leader() {
+            return Ok(());
+        }
+
+        Err(IggyError::NotClusterLeader(
+            raft.leader_id().unwrap_or_default(),
+        ))
+    }
+
+    pub fn get_partition_assignment(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+        replication_factor: u8,
+    ) -> PartitionAssignment {
+        assign_partition(
+            &self.node_ids,
+            stream_id,
+            topic_id,
+            partition_id,
+            replication_factor,
+        )
+    }
+
+    /// Returns the ID of the partition led by this node to which the messages are appended.
+    /// The balanced partitioning picks only the partitions led by this node.
+    pub fn resolve_partition_id(
+        &self,
+        topic: &Topic,
+        partitioning: &Partitioning,
+    ) -> Result<u32, IggyError> {
+        let attempts = match partitioning.kind {
+            PartitioningKind::Balanced => topic.get_partitions_count().max(1),
+            _ => 1,
+        };
+        let mut leader_id = 0;
+        let mut partition_id = 0;
+        for _ in 0..attempts {
+            partition_id = topic.resolve_partition_id(partitioning)?;
+            leader_id = self
+                .get_partition_assignment(
+                    topic.stream_id,
+                    topic.topic_id,
+                    partition_id,
+                    topic.replication_factor,
+                )
+                .leader_id;
+            if leader_id == self.config.node_id {
+                return Ok(partition_id);
+            }
+        }
+
+        Err(IggyError::NotPartitionLeader(partition_id, leader_id))
+    }
+
+    /// Forwards the messages appended by the partition leader to the replicas of the partition.
+    pub fn replicate_messages(&self, topic: &Topic, partition_id: u32, messages: Vec<Message>) {
+        let Some(transport) = self.transport.get() else {
+            return;
+        };
+
+        let assignment = self.get_partition_assignment(
+            topic.stream_id,
+            topic.topic_id,
+            partition_id,
+            topic.replication_factor,
+        );
+        if assignment.replica_ids.is_empty() {
+            return;
+        }
+
+        let (Ok(stream_id), Ok(topic_id)) = (
+            Identifier::numeric(topic.stream_id),
+            Identifier::numeric(topic.topic_id),
+        ) else {
+            return;
+        };
+
+        let message = ClusterMessage::AppendMessages(SendMessages {
+            stream_id,
+            topic_id,
+            partitioning: Partitioning::partition_id(partition_id),
+            messages,
+        });
+        for replica_id in assignment.replica_ids {
+            transport.send(replica_id, &message);
+        }
+    }
+
+    /// Initializes the state file and restores the replicated log, the entries up to the last one
+    /// appended to the state file were already applied.
+    pub async fn init(&self) -> Result<Vec<StateEntry>, IggyError> {
+        let entries = self.state.init().await.with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to initialize state")
+        })?;
+        let mut hard_state = self.load_hard_state().await?;
+        let (mut snapshot, mut log) = self.load_log().await?;
+        let mut applied_index = snapshot.index;
+        if let Some(entry) = entries.last() {
+            applied_index = applied_index.max(get_raft_index(entry));
+            hard_state.term = hard_state.term.max(entry.term);
+            self.state.set_term(entry.term, entry.leader_id);
+            // The log is missing or lags behind the state file, e.g. it was created by the previous version.
+            let last_index = log.last().map_or(snapshot.index, |entry| entry.index);
+            if applied_index > last_index {
+                snapshot = SnapshotMetadata {
+                    index: applied_index,
+                    term: entry.term,
+                };
+                log.clear();
+            }
+        }
+
+        self.applied_index.store(applied_index, Ordering::SeqCst);
+        let peers = self.nodes.iter().map(|node| node.id).collect();
+        *self.raft.lock().unwrap() = Raft::new(
+            self.config.node_id,
+            peers,
+            Self::get_raft_config(&self.config),
+            hard_state,
+            snapshot,
+            log,
+            applied_index,
+        );
+        Ok(entries)
+    }
+
+    pub async fn load_entries(&self) -> Result<Vec<StateEntry>, IggyError> {
+        self.state.load_entries().await
+    }
+
+    /// Appends the entry of the change already applied to the system before the node joined the cluster,
+    /// e.g. the root user created on the first start. Once the node has started, the changes are replicated
+    /// with `replicate` before they're applied, so there's nothing left to append.
+    pub async fn apply(&self, user_id: u32, command: EntryCommand) -> Result<(), IggyError> {
+        if self.started.load(Ordering::SeqCst) {
+            return Ok(());
+        }
+
+        let payload = encode_payload(user_id, &command.to_bytes());
+        let (index, log) = {
+            let mut raft = self.raft.lock().unwrap();
+            let index = raft.append_committed(payload);
+            (index, raft.take_changed_log())
+        };
+        if let Some((snapshot, log)) = log {
+            self.save_log(snapshot, &log).await?;
+        }
+        self.state
+            .append(user_id, command, encode_raft_index(index))
+            .await?;
+        self.applied_index.store(index, Ordering::SeqCst);
+        Ok(())
+    }
+
+    /// Replicates the entry of the change and returns once it's committed and appended to the state file,
+    /// so that the change can be applied to the system. The leader accepts the changes only once its state
+    /// is up to date, otherwise they couldn't be validated against the current state.
+    pub async fn replicate(&self, user_id: u32, command: &EntryCommand) -> Result<(), IggyError> {
+        if !self.started.load(Ordering::SeqCst) {
+            return Ok(());
+        }
+
+        let payload = encode_payload(user_id, &command.to_bytes());
+        let (sender, mut receiver) = oneshot::channel();
+        let index = {
+            let mut raft = self.raft.lock().unwrap();
+            self.ensure_ready(&raft)?;
+            let index = raft.propose(payload)?;
+            self.proposals.insert(
+                index,
+                Proposal {
+                    term: raft.term(),
+                    sender: Some(sender),
+                },
+            );
+            index
+        };
+        self.notify.notify_one();
+        if let Ok(Ok(())) = time::timeout(
+            self.config.replication_timeout.get_duration(),
+            &mut receiver,
+        )
+        .await
+        {
+            return Ok(());
+        }
+
+        // The entry might still be committed later, then it's applied to the system as the replicated one.
+        if let Some(mut proposal) = self.proposals.get_mut(&index) {
+            proposal.sender = None;
+        }
+        if receiver.try_recv().is_ok() {
+            return Ok(());
+        }
+
+        error!("Failed to replicate state entry with index: {index}, command: {command}");
+        Err(IggyError::CannotReplicateStateEntry(index))
+    }
+
+    /// Returns an error pointing to this node, if it's the leader which hasn't applied all the entries
+    /// committed by the previous leaders yet, or still waits for the abandoned proposals to be committed.
+    fn ensure_ready(&self, raft: &Raft) -> Result<(), IggyError> {
+        if !raft.is_leader() {
+            return Err(IggyError::NotClusterLeader(
+                raft.leader_id().unwrap_or_default(),
+            ));
+        }
+
+        let is_ready = raft.is_leader_ready()
+            && self.applied_index.load(Ordering::SeqCst) >= raft.term_start_index()
+            && self.pending_entries.load(Ordering::SeqCst) == 0
+            && self
+                .proposals
+                .iter()
+                .all(|proposal| proposal.sender.is_some());
+        if !is_ready {
+            warn!("Cluster leader is not ready to replicate the state entries yet.");
+            return Err(IggyError::NotClusterLeader(self.config.node_id));
+        }
+
+        Ok(())
+    }
+
+    /// Starts accepting the authenticated connections from the other nodes and running the Raft protocol.
+    pub async fn start(self: &Arc<Self>, system: SharedSystem) -> Result<(), IggyError> {
+        let (inbound_sender, inbound_receiver) = flume::unbounded();
+        let peer_ids = self.nodes.iter().map(|node| node.id).collect();
+        ClusterTransport::listen(
+            &self.config.address,
+            &self.config.secret,
+            peer_ids,
+            inbound_sender,
+        )
+        .await
+        .map_err(|error| {
+            error!(
+                "Cannot start cluster transport on address: {}. {error}",
+                self.config.address
+            );
+            IggyError::TcpError
+        })?;
+        let _ = self.transport.set(ClusterTransport::new(
+            self.config.node_id,
+            &self.nodes,
+            &self.config.secret,
+        ));
+
+        let (entries_sender, entries_receiver) = flume::unbounded();
+        let (messages_sender, messages_receiver) = flume::unbounded();
+        tokio::spawn(apply_entries(
+            self.clone(),
+            system.clone(),
+            entries_receiver,
+        ));
+        tokio::spawn(append_messages(system, messages_receiver));
+        self.started.store(true, Ordering::SeqCst);
+        let node = self.clone();
+        tokio::spawn(async move {
+            node.run(inbound_receiver, entries_sender, messages_sender)
+                .await
+        });
+        info!(
+            "Cluster node with ID: {} has started, nodes: {:?}",
+            self.config.node_id, self.node_ids
+        );
+        Ok(())
+    }
+
+    async fn run(
+        &self,
+        inbound: Receiver<(u32, ClusterMessage)>,
+        entries: Sender<(u32, Bytes)>,
+        messages: Sender<SendMessages>,
+    ) {
+        let mut interval = time::interval(TICK_INTERVAL);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        let mut last_tick = Instant::now();
+        let mut ready = Ready {
+            hard_state: self.raft.lock().unwrap().hard_state(),
+            log: None,
+            committed_entries: VecDeque::new(),
+        };
+        loop {
+            tokio::select! {
+                _ = interval.tick() => {
+                    let now = Instant::now();
+                    let elapsed = now.duration_since(last_tick).as_millis() as u64;
+                    last_tick = now;
+                    self.raft.lock().unwrap().tick(elapsed);
+                }
+                message = inbound.recv_async() => {
+                    let Ok((from, message)) = message else {
+                        return;
+                    };
+                    match message {
+                        ClusterMessage::Raft(message) => {
+                            self.raft.lock().unwrap().step(from, message);
+                        }
+                        ClusterMessage::AppendMessages(command) => {
+                            let _ = messages.send(command);
+                        }
+                    }
+                }
+                _ = self.notify.notified() => {}
+            }
+            self.process_ready(&mut ready, &entries).await;
+        }
+    }
+
+    /// Persists the changed hard state and log before sending the messages, then appends the committed
+    /// entries to the state file and compacts the log, once enough of them were applied.
+    async fn process_ready(&self, ready: &mut Ready, entries: &Sender<(u32, Bytes)>) {
+        let (hard_state, messages, leader_id) = {
+            let mut raft = self.raft.lock().unwrap();
+            if let Some(log) = raft.take_changed_log() {
+                ready.log = Some(log);
+            }
+            ready
+                .committed_entries
+                .extend(raft.take_committed_entries());
+            (
+                raft.hard_state(),
+                raft.take_messages(),
+                raft.leader_id().unwrap_or_default(),
+            )
+        };
+
+        // The proposals of the previous terms are committed, if at all, as the entries of the other leaders.
+        self.proposals
+            .retain(|_, proposal| proposal.term == hard_state.term);
+        if hard_state != ready.hard_state {
+            if let Err(error) = self.save_hard_state(&hard_state).await {
+                // The messages are dropped, the Raft protocol sends them again.
+                error!("Failed to save Raft hard state. {error}");
+                return;
+            }
+            ready.hard_state = hard_state;
+        }
+
+        if let Some((snapshot, log)) = ready.log.take() {
+            if let Err(error) = self.save_log(snapshot, &log).await {
+                error!("Failed to save Raft log. {error}");
+                ready.log = Some((snapshot, log));
+                return;
+            }
+        }
+
+        self.send_messages(messages).await;
+        while let Some(entry) = ready.committed_entries.pop_front() {
+            let index = entry.index;
+            if let Err(error) = self
+                .append_committed_entry(entry.clone(), leader_id, entries)
+                .await
+            {
+                error!("Failed to append committed state entry with index: {index}. {error}");
+                ready.committed_entries.push_front(entry);
+                break;
+            }
+        }
+
+        let applied_index = self.applied_index.load(Ordering::SeqCst);
+        let mut raft = self.raft.lock().unwrap();
+        if applied_index.saturating_sub(raft.snapshot().index)
+            > self.config.snapshot_threshold as u64
+        {
+            raft.compact(applied_index);
+        }
+    }
+
+    /// Sends the messages to the other nodes, filling the chunks of the snapshot with the entries
+    /// of the state file, which are loaded at most once.
+    async fn send_messages(&self, messages: Vec<(u32, RaftMessage)>) {
+        let Some(transport) = self.transport.get() else {
+            return;
+        };
+
+        let mut log_entries = None;
+        for (to, mut message) in messages {
+            if matches!(message, RaftMessage::InstallSnapshot { .. }) {
+                if log_entries.is_none() {
+                    log_entries = Some(self.load_log_entries().await);
+                }
+                match &log_entries {
+                    Some(Ok(entries)) => raft::fill_install_snapshot(
+                        &mut message,
+                        entries.iter().cloned(),
+                        self.config.max_entries_per_append as usize,
+                    ),
+                    Some(Err(error)) => {
+                        error!("Failed to load state entries for Raft snapshot. {error}");
+                        continue;
+                    }
+                    None => continue,
+                }
+            }
+            transport.send(to, &ClusterMessage::Raft(message));
+        }
+    }
+
+    async fn append_committed_entry(
+        &self,
+        entry: LogEntry,
+        leader_id: u32,
+        entries: &Sender<(u32, Bytes)>,
+    ) -> Result<(), IggyError> {
+        // The no-op entry appended by the leader at the start of its term is not a part of the state.
+        if entry.payload.is_empty() {
+            self.applied_index.store(entry.index, Ordering::SeqCst);
+            return Ok(());
+        }
+
+        let decoded = decode_payload(entry.payload).and_then(|(user_id, command_bytes)| {
+            let command = EntryCommand::from_bytes(command_bytes.clone())?;
+            Ok((user_id, command_bytes, command))
+        });
+        let (user_id, command_bytes, command) = match decoded {
+            Ok(decoded) => decoded,
+            Err(error) => {
+                // The invalid entry would never be appended, so it's skipped instead of blocking the others.
+                error!(
+                    "Failed to parse committed state entry with index: {}. {error}",
+                    entry.index
+                );
+                self.applied_index.store(entry.index, Ordering::SeqCst);
+                return Ok(());
+            }
+        };
+
+        self.state.set_term(entry.term, leader_id);
+        self.state
+            .append(user_id, command, encode_raft_index(entry.index))
+            .await?;
+        self.applied_index.store(entry.index, Ordering::SeqCst);
+        if let Some((_, proposal)) = self.proposals.remove(&entry.index) {
+            // The leader applies its own proposal to the system, once it's notified.
+            if proposal.term == entry.term {
+                if let Some(sender) = proposal.sender {
+                    if sender.send(()).is_ok() {
+                        return Ok(());
+                    }
+                }
+            }
+        }
+
+        self.pending_entries.fetch_add(1, Ordering::SeqCst);
+        let _ = entries.send((user_id, command_bytes));
+        Ok(())
+    }
+
+    /// Loads the entries of the state file, as the entries of the replicated log.
+    async fn load_log_entries(&self) -> Result<Vec<LogEntry>, IggyError> {
+        let entries = self.state.load_entries().await?;
+        Ok(entries
+            .into_iter()
+            .map(|entry| LogEntry {
+                index: get_raft_index(&entry),
+                term: entry.term,
+                payload: encode_payload(entry.user_id, &entry.command),
+            })
+            .collect())
+    }
+
+    async fn load_hard_state(&self) -> Result<HardState, IggyError> {
+        if !Path::new(&self.raft_path).exists() {
+            return Ok(HardState::default());
+        }
+
+        let data = tokio::fs::read(&self.raft_path)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to read file at path: {}",
+                    self.raft_path
+                )
+            })
+            .map_err(|_| IggyError::CannotReadFile)?;
+        let (hard_state, _): (HardState, _) =
+            bincode::serde::decode_from_slice(&data, bincode::config::standard())
+                .map_err(|_| IggyError::CannotDeserializeResource)?;
+        Ok(hard_state)
+    }
+
+    async fn save_hard_state(&self, hard_state: &HardState) -> Result<(), IggyError> {
+        let data = bincode::serde::encode_to_vec(hard_state, bincode::config::standard())
+            .map_err(|_| IggyError::CannotSerializeResource)?;
+        self.persister.overwrite(&self.raft_path, &data).await
+    }
+
+    /// Loads the snapshot metadata and the entries of the log, which are empty if there's no log yet.
+    async fn load_log(&self) -> Result<(SnapshotMetadata, Vec<LogEntry>), IggyError> {
+        if !Path::new(&self.raft_log_path).exists() {
+            return Ok((SnapshotMetadata::default(), Vec::new()));
+        }
+
+        let data = tokio::fs::read(&self.raft_log_path)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to read file at path: {}",
+                    self.raft_log_path
+                )
+            })
+            .map_err(|_| IggyError::CannotReadFile)?;
+        let mut data = Bytes::from(data);
+        if data.len() < 16 {
+            return Err(IggyError::CannotDeserializeResource);
+        }
+
+        let snapshot = SnapshotMetadata {
+            index: data.get_u64_le(),
+            term: data.get_u64_le(),
+        };
+        let log =
+            messages::get_entries(&mut data).map_err(|_| IggyError::CannotDeserializeResource)?;
+        Ok((snapshot, log))
+    }
+
+    /// Replaces the log file, so that it's never left partially written.
+    async fn save_log(
+        &self,
+        snapshot: SnapshotMetadata,
+        log: &[LogEntry],
+    ) -> Result<(), IggyError> {
+        let mut data = BytesMut::new();
+        data.put_u64_le(snapshot.index);
+        data.put_u64_le(snapshot.term);
+        messages::put_entries(&mut data, log);
+        let temp_path = format!("{}.tmp", self.raft_log_path);
+        self.persister
+            .overwrite(&temp_path, &data)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to save Raft log at path: {temp_path}"
+                )
+            })?;
+        if let Err(error) = file::rename(&temp_path, &self.raft_log_path).await {
+            error!(
+                "Failed to replace Raft log at path: {}. {error}",
+                self.raft_log_path
+            );
+            return Err(IggyError::CannotWriteToFile);
+        }
+
+        Ok(())
+    }
+
+    fn get_raft_config(config: &ClusterConfig) -> RaftConfig {
+        RaftConfig {
+            election_timeout_min: config.election_timeout_min.get_duration().as_millis() as u64,
+            election_timeout_max: config.election_timeout_max.get_duration().as_millis() as u64,
+            heartbeat_interval: config.heartbeat_interval.get_duration().as_millis() as u64,
+            max_entries_per_append: config.max_entries_per_append as usize,
+        }
+    }
+}
+
+/// Applies the state entries committed by the other nodes to the system, one by one in the commit order.
+async fn apply_entries(
+    node: Arc<ClusterNode>,
+    system: SharedSystem,
+    receiver: Receiver<(u32, Bytes)>,
+) {
+    while let Ok((user_id, command)) = receiver.recv_async().await {
+        apply_entry(&system, user_id, command).await;
+        node.pending_entries.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+async fn apply_entry(system: &SharedSystem, user_id: u32, command: Bytes) {
+    let command = match EntryCommand::from_bytes(command) {
+        Ok(command) => command,
+        Err(error) => {
+            error!("Failed to parse replicated state entry command. {error}");
+            return;
+        }
+    };
+
+    let description = command.to_string();
+    let mut system = system.write().await;
+    if let Err(error) = system.apply_replicated_entry(user_id, command).await {
+        error!("Failed to apply replicated state entry command: {description}, user ID: {user_id}. {error}");
+    }
+}
+
+/// Appends the messages forwarded by the partition leaders, in the order they were received.
+async fn append_messages(system: SharedSystem, receiver: Receiver<SendMessages>) {
+    while let Ok(command) = receiver.recv_async().await {
+        let system = system.read().await;
+        if let Err(error) = system.append_replicated_messages(command).await {
+            warn!("Failed to append replicated messages. {error}");
+        }
+    }
+}
+
+/// Returns the index of the state entry in the replicated log, stored in its context. The entries
+/// appended by the previous versions have no context, their indexes were following the state file.
+fn get_raft_index(entry: &StateEntry) -> u64 {
+    if entry.context.len() == 8 {
+        return entry.context.clone().get_u64_le();
+    }
+
+    entry.index + 1
+}
+
+fn encode_raft_index(index: u64) -> Bytes {
+    Bytes::copy_from_slice(&index.to_le_bytes())
+}
+
+fn encode_payload(user_id: u32, command: &Bytes) -> Bytes {
+    let mut payload = BytesMut::with_capacity(4 + command.len());
+    payload.put_u32_le(user_id);
+    payload.put_slice(command);
+    payload.freeze()
+}
+
+fn decode_payload(mut payload: Bytes) -> Result<(u32, Bytes), IggyError> {
+    if payload.len() < 4 {
+        return Err(IggyError::InvalidCommand);
+    }
+
+    let user_id = payload.get_u32_le();
+    Ok((user_id, payload))
+}
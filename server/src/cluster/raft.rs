@@ -0,0 +1,1015 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cluster::messages::{LogEntry, RaftMessage};
+use ahash::{AHashMap, AHashSet};
+use bytes::Bytes;
+use iggy::error::IggyError;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    Follower,
+    Candidate,
+    Leader,
+}
+
+/// The part of the Raft state which must be persisted before responding to the other nodes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HardState {
+    pub term: u64,
+    pub voted_for: Option<u32>,
+}
+
+/// The last entry compacted into the snapshot, the log contains only the entries following it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnapshotMetadata {
+    pub index: u64,
+    pub term: u64,
+}
+
+/// The timeouts are expressed in milliseconds, the same unit is used by `Raft::tick`.
+#[derive(Debug, Clone)]
+pub struct RaftConfig {
+    pub election_timeout_min: u64,
+    pub election_timeout_max: u64,
+    pub heartbeat_interval: u64,
+    pub max_entries_per_append: usize,
+}
+
+/// The Raft consensus state machine, free of any I/O: the messages to be sent are collected
+/// and returned by `take_messages`, the committed entries by `take_committed_entries`, and the changed
+/// log by `take_changed_log`. The log is kept in memory, the entry with index `i` is stored at position
+/// `i - snapshot.index - 1`, while the entries up to the snapshot are kept only by the state.
+#[derive(Debug)]
+pub struct Raft {
+    id: u32,
+    peers: Vec<u32>,
+    config: RaftConfig,
+    role: Role,
+    hard_state: HardState,
+    leader_id: Option<u32>,
+    snapshot: SnapshotMetadata,
+    log: Vec<LogEntry>,
+    log_changed: bool,
+    commit_index: u64,
+    committed_index: u64,
+    committed_entries: Vec<LogEntry>,
+    term_start_index: u64,
+    next_index: AHashMap<u32, u64>,
+    match_index: AHashMap<u32, u64>,
+    votes: AHashSet<u32>,
+    elapsed: u64,
+    election_timeout: u64,
+    messages: Vec<(u32, RaftMessage)>,
+}
+
+impl Raft {
+    /// Creates the node with the log restored from the storage, the entries up to the commit index
+    /// were already applied to the state.
+    pub fn new(
+        id: u32,
+        peers: Vec<u32>,
+        config: RaftConfig,
+        hard_state: HardState,
+        snapshot: SnapshotMetadata,
+        log: Vec<LogEntry>,
+        commit_index: u64,
+    ) -> Self {
+        let commit_index = commit_index.max(snapshot.index);
+        let mut raft = Self {
+            id,
+            peers,
+            config,
+            role: Role::Follower,
+            hard_state,
+            leader_id: None,
+            snapshot,
+            log,
+            log_changed: false,
+            commit_index,
+            committed_index: commit_index,
+            committed_entries: Vec::new(),
+            term_start_index: 0,
+            next_index: AHashMap::new(),
+            match_index: AHashMap::new(),
+            votes: AHashSet::new(),
+            elapsed: 0,
+            election_timeout: 0,
+            messages: Vec::new(),
+        };
+        raft.reset_election_timeout();
+        raft
+    }
+
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    pub fn role(&self) -> Role {
+        self.role
+    }
+
+    pub fn is_leader(&self) -> bool {
+        self.role == Role::Leader
+    }
+
+    /// Returns true once the leader committed the no-op entry of its term, along with all the entries
+    /// of the previous terms, so that its state is up to date.
+    pub fn is_leader_ready(&self) -> bool {
+        self.is_leader() && self.commit_index >= self.term_start_index
+    }
+
+    pub fn leader_id(&self) -> Option<u32> {
+        self.leader_id
+    }
+
+    pub fn term(&self) -> u64 {
+        self.hard_state.term
+    }
+
+    pub fn hard_state(&self) -> HardState {
+        self.hard_state
+    }
+
+    pub fn snapshot(&self) -> SnapshotMetadata {
+        self.snapshot
+    }
+
+    pub fn commit_index(&self) -> u64 {
+        self.commit_index
+    }
+
+    /// Returns the index of the no-op entry appended by the leader at the start of its term.
+    pub fn term_start_index(&self) -> u64 {
+        self.term_start_index
+    }
+
+    pub fn last_index(&self) -> u64 {
+        self.last_log().0
+    }
+
+    /// Returns the entries committed since the last call, in the order of their indexes, including
+    /// the ones received with the snapshot, which must be applied to the state.
+    pub fn take_committed_entries(&mut self) -> Vec<LogEntry> {
+        std::mem::take(&mut self.committed_entries)
+    }
+
+    /// Returns the snapshot and the entries of the log, if they were changed since the last call,
+    /// which must be persisted before sending the messages.
+    pub fn take_changed_log(&mut self) -> Option<(SnapshotMetadata, Vec<LogEntry>)> {
+        if !self.log_changed {
+            return None;
+        }
+
+        self.log_changed = false;
+        Some((self.snapshot, self.log.clone()))
+    }
+
+    pub fn take_messages(&mut self) -> Vec<(u32, RaftMessage)> {
+        std::mem::take(&mut self.messages)
+    }
+
+    /// Advances the logical clock by the elapsed milliseconds, sending the heartbeats as the leader
+    /// or starting the election once the leader wasn't heard of within the election timeout.
+    pub fn tick(&mut self, elapsed: u64) {
+        self.elapsed += elapsed;
+        if self.role == Role::Leader {
+            if self.elapsed >= self.config.heartbeat_interval {
+                self.elapsed = 0;
+                self.broadcast_append_entries();
+            }
+            return;
+        }
+
+        if self.elapsed >= self.election_timeout {
+            self.start_election();
+        }
+    }
+
+    /// Appends the payload to the log of the leader and returns its index, once it's committed
+    /// the entry is returned by `take_committed_entries` on all the nodes.
+    pub fn propose(&mut self, payload: Bytes) -> Result<u64, IggyError> {
+        if self.role != Role::Leader {
+            return Err(IggyError::NotClusterLeader(
+                self.leader_id.unwrap_or_default(),
+            ));
+        }
+
+        let index = self.append(self.hard_state.term, payload);
+        self.broadcast_append_entries();
+        self.maybe_commit();
+        Ok(index)
+    }
+
+    /// Appends the entry committed locally before the node joined the cluster, e.g. the root user
+    /// created on the first start, which is expected to be the same on all the nodes.
+    /// The entry is already applied, so it's not returned by `take_committed_entries`.
+    pub fn append_committed(&mut self, payload: Bytes) -> u64 {
+        let (_, last_term) = self.last_log();
+        let index = self.append(last_term, payload);
+        self.commit_index = index;
+        self.committed_index = index;
+        index
+    }
+
+    /// Compacts the entries up to the given index, already applied to the state, into the snapshot.
+    pub fn compact(&mut self, index: u64) {
+        let index = index.min(self.committed_index);
+        if index <= self.snapshot.index {
+            return;
+        }
+
+        let Some(term) = self.term_at(index) else {
+            return;
+        };
+
+        self.log.drain(..self.position(index) + 1);
+        self.snapshot = SnapshotMetadata { index, term };
+        self.log_changed = true;
+    }
+
+    pub fn step(&mut self, from: u32, message: RaftMessage) {
+        let term = match &message {
+            RaftMessage::RequestVote { term, .. }
+            | RaftMessage::RequestVoteResponse { term, .. }
+            | RaftMessage::AppendEntries { term, .. }
+            | RaftMessage::AppendEntriesResponse { term, .. }
+            | RaftMessage::InstallSnapshot { term, .. }
+            | RaftMessage::InstallSnapshotResponse { term, .. } => *term,
+        };
+        if term > self.hard_state.term {
+            self.become_follower(term, None);
+        }
+
+        match message {
+            RaftMessage::RequestVote {
+                term,
+                last_log_index,
+                last_log_term,
+            } => self.handle_request_vote(from, term, last_log_index, last_log_term),
+            RaftMessage::RequestVoteResponse { term, vote_granted } => {
+                if self.role != Role::Candidate || term != self.hard_state.term || !vote_granted {
+                    return;
+                }
+
+                self.votes.insert(from);
+                if self.has_quorum(self.votes.len()) {
+                    self.become_leader();
+                }
+            }
+            RaftMessage::AppendEntries {
+                term,
+                prev_log_index,
+                prev_log_term,
+                leader_commit,
+                entries,
+            } => self.handle_append_entries(
+                from,
+                term,
+                prev_log_index,
+                prev_log_term,
+                leader_commit,
+                entries,
+            ),
+            RaftMessage::AppendEntriesResponse {
+                term,
+                success,
+                last_log_index,
+            } => self.handle_append_entries_response(from, term, success, last_log_index),
+            RaftMessage::InstallSnapshot {
+                term,
+                last_included_index,
+                last_included_term,
+                first_index,
+                last_index,
+                entries,
+            } => self.handle_install_snapshot(
+                from,
+                term,
+                SnapshotMetadata {
+                    index: last_included_index,
+                    term: last_included_term,
+                },
+                first_index,
+                last_index,
+                entries,
+            ),
+            RaftMessage::InstallSnapshotResponse { term, last_index } => {
+                self.handle_install_snapshot_response(from, term, last_index)
+            }
+        }
+    }
+
+    fn handle_request_vote(
+        &mut self,
+        from: u32,
+        term: u64,
+        last_log_index: u64,
+        last_log_term: u64,
+    ) {
+        let (own_last_index, own_last_term) = self.last_log();
+        let is_up_to_date = last_log_term > own_last_term
+            || (last_log_term == own_last_term && last_log_index >= own_last_index);
+        let can_vote = self
+            .hard_state
+            .voted_for
+            .is_none_or(|voted_for| voted_for == from);
+        let vote_granted = term == self.hard_state.term && can_vote && is_up_to_date;
+        if vote_granted {
+            self.hard_state.voted_for = Some(from);
+            self.elapsed = 0;
+        }
+
+        self.send(
+            from,
+            RaftMessage::RequestVoteResponse {
+                term: self.hard_state.term,
+                vote_granted,
+            },
+        );
+    }
+
+    fn handle_append_entries(
+        &mut self,
+        from: u32,
+        term: u64,
+        mut prev_log_index: u64,
+        mut prev_log_term: u64,
+        leader_commit: u64,
+        mut entries: Vec<LogEntry>,
+    ) {
+        if term < self.hard_state.term {
+            self.send_append_entries_response(from, false, self.last_index());
+            return;
+        }
+
+        self.role = Role::Follower;
+        self.leader_id = Some(from);
+        self.elapsed = 0;
+        if prev_log_index < self.snapshot.index {
+            // The entries compacted into the snapshot are committed, so they match the leader's log.
+            entries.retain(|entry| entry.index > self.snapshot.index);
+            prev_log_index = self.snapshot.index;
+            prev_log_term = self.snapshot.term;
+        }
+
+        if self.term_at(prev_log_index) != Some(prev_log_term) {
+            // Let the leader skip all the entries the follower doesn't have at once.
+            let last_index = self.last_index().min(prev_log_index.saturating_sub(1));
+            self.send_append_entries_response(from, false, last_index);
+            return;
+        }
+
+        let last_new_index = prev_log_index + entries.len() as u64;
+        for entry in entries {
+            // The committed entries are never in conflict with the leader's log.
+            if entry.index <= self.commit_index {
+                continue;
+            }
+
+            match self.term_at(entry.index) {
+                Some(term) if term == entry.term => continue,
+                Some(_) => {
+                    let position = self.position(entry.index);
+                    self.log.truncate(position);
+                    self.log.push(entry);
+                }
+                None => self.log.push(entry),
+            }
+            self.log_changed = true;
+        }
+
+        if leader_commit > self.commit_index {
+            self.advance_commit_index(leader_commit.min(last_new_index));
+        }
+        self.send_append_entries_response(from, true, last_new_index);
+    }
+
+    fn handle_append_entries_response(
+        &mut self,
+        from: u32,
+        term: u64,
+        success: bool,
+        last_log_index: u64,
+    ) {
+        if self.role != Role::Leader || term != self.hard_state.term {
+            return;
+        }
+
+        if success {
+            self.update_match_index(from, last_log_index);
+            return;
+        }
+
+        let next_index = self.next_index.get(&from).copied().unwrap_or(1);
+        let next_index = next_index.saturating_sub(1).min(last_log_index + 1).max(1);
+        self.next_index.insert(from, next_index);
+        self.send_append_entries(from);
+    }
+
+    fn handle_install_snapshot(
+        &mut self,
+        from: u32,
+        term: u64,
+        snapshot: SnapshotMetadata,
+        first_index: u64,
+        last_index: u64,
+        entries: Vec<LogEntry>,
+    ) {
+        if term < self.hard_state.term {
+            self.send_install_snapshot_response(from, 0);
+            return;
+        }
+
+        self.role = Role::Follower;
+        self.leader_id = Some(from);
+        self.elapsed = 0;
+        // The committed entries were already received, either with the log or the previous chunks.
+        if snapshot.index <= self.committed_index {
+            if snapshot.index > self.snapshot.index
+                && self.term_at(snapshot.index) != Some(snapshot.term)
+            {
+                self.install_snapshot(snapshot);
+            }
+            self.send_install_snapshot_response(from, snapshot.index);
+            return;
+        }
+
+        // The chunk is accepted only if it follows the already received entries, without any gap.
+        if first_index > self.committed_index + 1 {
+            self.send_install_snapshot_response(from, self.committed_index);
+            return;
+        }
+
+        let committed_index = self.committed_index;
+        let last_index = last_index.min(snapshot.index);
+        self.committed_entries.extend(
+            entries
+                .into_iter()
+                .filter(|entry| entry.index > committed_index && entry.index <= last_index),
+        );
+        self.committed_index = self.committed_index.max(last_index);
+        if self.committed_index == snapshot.index {
+            self.install_snapshot(snapshot);
+        }
+        self.send_install_snapshot_response(from, self.committed_index);
+    }
+
+    fn handle_install_snapshot_response(&mut self, from: u32, term: u64, last_index: u64) {
+        if self.role != Role::Leader || term != self.hard_state.term {
+            return;
+        }
+
+        // The follower has already applied the committed entries up to the last index,
+        // the next chunk or the following entries of the log are sent from there.
+        let match_index = self.match_index.entry(from).or_default();
+        *match_index = (*match_index).max(last_index);
+        self.next_index.insert(from, last_index + 1);
+        self.maybe_commit();
+        if last_index < self.last_index() {
+            self.send_append_entries(from);
+        }
+    }
+
+    /// Replaces the log with the snapshot, retaining the entries following it if the log matches it.
+    fn install_snapshot(&mut self, snapshot: SnapshotMetadata) {
+        if self.term_at(snapshot.index) == Some(snapshot.term) {
+            self.log.drain(..self.position(snapshot.index) + 1);
+        } else {
+            self.log.clear();
+        }
+        self.snapshot = snapshot;
+        self.commit_index = self.commit_index.max(snapshot.index);
+        self.log_changed = true;
+    }
+
+    fn update_match_index(&mut self, peer: u32, index: u64) {
+        let match_index = self.match_index.entry(peer).or_default();
+        *match_index = (*match_index).max(index);
+        let next_index = *match_index + 1;
+        self.next_index.insert(peer, next_index);
+        self.maybe_commit();
+        if next_index <= self.last_index() {
+            self.send_append_entries(peer);
+        }
+    }
+
+    fn start_election(&mut self) {
+        self.role = Role::Candidate;
+        self.hard_state.term += 1;
+        self.hard_state.voted_for = Some(self.id);
+        self.leader_id = None;
+        self.votes.clear();
+        self.votes.insert(self.id);
+        self.elapsed = 0;
+        self.reset_election_timeout();
+        if self.has_quorum(self.votes.len()) {
+            self.become_leader();
+            return;
+        }
+
+        let (last_log_index, last_log_term) = self.last_log();
+        for peer in self.peers.clone() {
+            self.send(
+                peer,
+                RaftMessage::RequestVote {
+                    term: self.hard_state.term,
+                    last_log_index,
+                    last_log_term,
+                },
+            );
+        }
+    }
+
+    /// Appends the no-op entry of the new term, the entries of the previous terms can't be committed
+    /// by counting the replicas, so they're committed along with it.
+    fn become_leader(&mut self) {
+        self.role = Role::Leader;
+        self.leader_id = Some(self.id);
+        self.elapsed = 0;
+        self.term_start_index = self.append(self.hard_state.term, Bytes::new());
+        for peer in &self.peers {
+            self.next_index.insert(*peer, self.term_start_index);
+            self.match_index.insert(*peer, 0);
+        }
+        self.broadcast_append_entries();
+        self.maybe_commit();
+    }
+
+    fn become_follower(&mut self, term: u64, leader_id: Option<u32>) {
+        self.role = Role::Follower;
+        self.hard_state.term = term;
+        self.hard_state.voted_for = None;
+        self.leader_id = leader_id;
+        self.votes.clear();
+        self.elapsed = 0;
+        self.reset_election_timeout();
+    }
+
+    /// Commits the highest index replicated on the majority of the nodes, as long as it's
+    /// from the current term, the entries from the previous terms are committed along with it.
+    fn maybe_commit(&mut self) {
+        let mut match_indexes = self.match_index.values().copied().collect::<Vec<_>>();
+        match_indexes.push(self.last_index());
+        match_indexes.sort_unstable_by(|a, b| b.cmp(a));
+        let quorum_index = match_indexes[(self.peers.len() + 1) / 2];
+        if quorum_index > self.commit_index
+            && self.term_at(quorum_index) == Some(self.hard_state.term)
+        {
+            self.advance_commit_index(quorum_index);
+        }
+    }
+
+    /// Advances the commit index and collects the newly committed entries, which haven't been
+    /// received with the snapshot yet.
+    fn advance_commit_index(&mut self, commit_index: u64) {
+        if commit_index <= self.commit_index {
+            return;
+        }
+
+        self.commit_index = commit_index;
+        if commit_index <= self.committed_index {
+            return;
+        }
+
+        let start = self.position(self.committed_index.max(self.snapshot.index) + 1);
+        let end = self.position(commit_index) + 1;
+        self.committed_entries
+            .extend_from_slice(&self.log[start..end]);
+        self.committed_index = commit_index;
+    }
+
+    fn broadcast_append_entries(&mut self) {
+        for peer in self.peers.clone() {
+            self.send_append_entries(peer);
+        }
+    }
+
+    fn send_append_entries(&mut self, peer: u32) {
+        let next_index = self.next_index.get(&peer).copied().unwrap_or(1).max(1);
+        if next_index <= self.snapshot.index {
+            // The entries are filled in by the node from its state, see `fill_install_snapshot`.
+            self.send(
+                peer,
+                RaftMessage::InstallSnapshot {
+                    term: self.hard_state.term,
+                    last_included_index: self.snapshot.index,
+                    last_included_term: self.snapshot.term,
+                    first_index: next_index,
+                    last_index: self.snapshot.index,
+                    entries: Vec::new(),
+                },
+            );
+            return;
+        }
+
+        let prev_log_index = next_index - 1;
+        let prev_log_term = self.term_at(prev_log_index).unwrap_or_default();
+        let entries = self.log[self.position(next_index).min(self.log.len())..]
+            .iter()
+            .take(self.config.max_entries_per_append)
+            .cloned()
+            .collect();
+        self.send(
+            peer,
+            RaftMessage::AppendEntries {
+                term: self.hard_state.term,
+                prev_log_index,
+                prev_log_term,
+                leader_commit: self.commit_index,
+                entries,
+            },
+        );
+    }
+
+    fn send_append_entries_response(&mut self, to: u32, success: bool, last_log_index: u64) {
+        self.send(
+            to,
+            RaftMessage::AppendEntriesResponse {
+                term: self.hard_state.term,
+                success,
+                last_log_index,
+            },
+        );
+    }
+
+    fn send_install_snapshot_response(&mut self, to: u32, last_index: u64) {
+        self.send(
+            to,
+            RaftMessage::InstallSnapshotResponse {
+                term: self.hard_state.term,
+                last_index,
+            },
+        );
+    }
+
+    fn send(&mut self, to: u32, message: RaftMessage) {
+        self.messages.push((to, message));
+    }
+
+    fn has_quorum(&self, count: usize) -> bool {
+        count > (self.peers.len() + 1) / 2
+    }
+
+    fn append(&mut self, term: u64, payload: Bytes) -> u64 {
+        let index = self.last_index() + 1;
+        self.log.push(LogEntry {
+            index,
+            term,
+            payload,
+        });
+        self.log_changed = true;
+        index
+    }
+
+    fn last_log(&self) -> (u64, u64) {
+        self.log
+            .last()
+            .map(|entry| (entry.index, entry.term))
+            .unwrap_or((self.snapshot.index, self.snapshot.term))
+    }
+
+    /// Returns the position in the log of the entry with the given index, following the snapshot.
+    fn position(&self, index: u64) -> usize {
+        (index - self.snapshot.index - 1) as usize
+    }
+
+    fn term_at(&self, index: u64) -> Option<u64> {
+        if index == self.snapshot.index {
+            return Some(self.snapshot.term);
+        }
+
+        if index < self.snapshot.index {
+            return None;
+        }
+
+        self.log.get(self.position(index)).map(|entry| entry.term)
+    }
+
+    fn reset_election_timeout(&mut self) {
+        self.election_timeout = rand::rng()
+            .random_range(self.config.election_timeout_min..=self.config.election_timeout_max);
+    }
+}
+
+/// Fills the chunk of the snapshot sent to the follower with the committed entries of the state,
+/// up to the given count, in the order of their indexes.
+pub fn fill_install_snapshot(
+    message: &mut RaftMessage,
+    entries: impl IntoIterator<Item = LogEntry>,
+    max_entries: usize,
+) {
+    let RaftMessage::InstallSnapshot {
+        first_index,
+        last_index,
+        entries: chunk,
+        ..
+    } = message
+    else {
+        return;
+    };
+
+    chunk.extend(
+        entries
+            .into_iter()
+            .filter(|entry| entry.index >= *first_index && entry.index <= *last_index)
+            .take(max_entries + 1),
+    );
+    if chunk.len() > max_entries {
+        chunk.truncate(max_entries);
+        if let Some(entry) = chunk.last() {
+            *last_index = entry.index;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAX_ENTRIES_PER_APPEND: usize = 2;
+
+    struct TestNode {
+        raft: Raft,
+        /// The committed entries applied to the state, without the no-op ones, used as the snapshot.
+        applied: Vec<LogEntry>,
+    }
+
+    impl TestNode {
+        fn apply(&mut self) {
+            self.applied.extend(
+                self.raft
+                    .take_committed_entries()
+                    .into_iter()
+                    .filter(|entry| !entry.payload.is_empty()),
+            );
+        }
+
+        fn payloads(&self) -> Vec<Bytes> {
+            self.applied
+                .iter()
+                .map(|entry| entry.payload.clone())
+                .collect()
+        }
+    }
+
+    fn cluster(timeouts: &[u64]) -> Vec<TestNode> {
+        let ids = (1..=timeouts.len() as u32).collect::<Vec<_>>();
+        ids.iter()
+            .zip(timeouts)
+            .map(|(id, timeout)| TestNode {
+                raft: Raft::new(
+                    *id,
+                    ids.iter().copied().filter(|peer| peer != id).collect(),
+                    RaftConfig {
+                        election_timeout_min: *timeout,
+                        election_timeout_max: *timeout,
+                        heartbeat_interval: 10,
+                        max_entries_per_append: MAX_ENTRIES_PER_APPEND,
+                    },
+                    HardState::default(),
+                    SnapshotMetadata::default(),
+                    Vec::new(),
+                    0,
+                ),
+                applied: Vec::new(),
+            })
+            .collect()
+    }
+
+    /// Delivers all the pending messages, except the ones from or to the disconnected nodes,
+    /// and applies the committed entries.
+    fn deliver(nodes: &mut [TestNode], disconnected: &[u32]) {
+        loop {
+            let mut messages = Vec::new();
+            for node in nodes.iter_mut() {
+                node.apply();
+                let from = node.raft.id();
+                for (to, mut message) in node.raft.take_messages() {
+                    fill_install_snapshot(
+                        &mut message,
+                        node.applied.iter().cloned(),
+                        MAX_ENTRIES_PER_APPEND,
+                    );
+                    messages.push((from, to, message));
+                }
+            }
+            if messages.is_empty() {
+                return;
+            }
+
+            for (from, to, message) in messages {
+                if disconnected.contains(&from) || disconnected.contains(&to) {
+                    continue;
+                }
+                nodes[to as usize - 1].raft.step(from, message);
+            }
+        }
+    }
+
+    fn propose(nodes: &mut [TestNode], leader_id: u32, payloads: &[&'static [u8]]) {
+        for payload in payloads {
+            nodes[leader_id as usize - 1]
+                .raft
+                .propose(Bytes::from_static(payload))
+                .unwrap();
+        }
+    }
+
+    fn bytes(payloads: &[&'static [u8]]) -> Vec<Bytes> {
+        payloads
+            .iter()
+            .map(|payload| Bytes::from_static(payload))
+            .collect()
+    }
+
+    #[test]
+    fn should_elect_leader_and_replicate_committed_entries() {
+        let mut nodes = cluster(&[50, 100, 150]);
+        nodes[0].raft.tick(50);
+        deliver(&mut nodes, &[]);
+
+        assert!(nodes[0].raft.is_leader());
+        assert!(nodes[0].raft.is_leader_ready());
+        assert_eq!(nodes[1].raft.leader_id(), Some(1));
+        assert!(matches!(
+            nodes[1].raft.propose(Bytes::from_static(b"rejected")),
+            Err(IggyError::NotClusterLeader(1))
+        ));
+
+        propose(&mut nodes, 1, &[b"first"]);
+        deliver(&mut nodes, &[]);
+        nodes[0].raft.tick(10);
+        deliver(&mut nodes, &[]);
+
+        for node in &nodes {
+            // The first entry is the no-op one of the leader's term.
+            assert_eq!(node.raft.commit_index(), 2);
+            assert_eq!(node.payloads(), bytes(&[b"first"]));
+        }
+    }
+
+    #[test]
+    fn leader_should_not_be_ready_until_no_op_entry_is_committed() {
+        let mut nodes = cluster(&[50, 100, 150]);
+        nodes[0].raft.tick(50);
+        deliver(&mut nodes, &[]);
+        propose(&mut nodes, 1, &[b"first"]);
+        deliver(&mut nodes, &[3]);
+
+        // The second node, which has all the entries, becomes the leader before committing them.
+        nodes[1].raft.tick(100);
+        for (to, message) in nodes[1].raft.take_messages() {
+            if to == 3 {
+                nodes[2].raft.step(2, message);
+            }
+        }
+        for (_, message) in nodes[2].raft.take_messages() {
+            nodes[1].raft.step(3, message);
+        }
+
+        assert!(nodes[1].raft.is_leader());
+        assert!(!nodes[1].raft.is_leader_ready());
+
+        deliver(&mut nodes, &[1]);
+
+        assert!(nodes[1].raft.is_leader_ready());
+        assert_eq!(nodes[1].payloads(), bytes(&[b"first"]));
+
+        nodes[1].raft.tick(10);
+        deliver(&mut nodes, &[1]);
+
+        assert_eq!(nodes[2].payloads(), bytes(&[b"first"]));
+    }
+
+    #[test]
+    fn new_leader_should_be_elected_after_leader_failure() {
+        let mut nodes = cluster(&[50, 100, 150]);
+        nodes[0].raft.tick(50);
+        deliver(&mut nodes, &[]);
+        propose(&mut nodes, 1, &[b"first"]);
+        deliver(&mut nodes, &[]);
+
+        // The entry proposed by the disconnected leader is never committed.
+        propose(&mut nodes, 1, &[b"lost"]);
+        deliver(&mut nodes, &[1]);
+        nodes[1].raft.tick(100);
+        deliver(&mut nodes, &[1]);
+
+        assert!(nodes[1].raft.is_leader_ready());
+        assert_eq!(nodes[2].raft.leader_id(), Some(2));
+
+        propose(&mut nodes, 2, &[b"second"]);
+        deliver(&mut nodes, &[1]);
+        nodes[1].raft.tick(10);
+        deliver(&mut nodes, &[]);
+        nodes[1].raft.tick(10);
+        deliver(&mut nodes, &[]);
+
+        assert!(!nodes[0].raft.is_leader());
+        assert_eq!(nodes[0].raft.leader_id(), Some(2));
+        assert_eq!(nodes[0].raft.last_index(), nodes[1].raft.last_index());
+        for node in &nodes {
+            assert_eq!(node.payloads(), bytes(&[b"first", b"second"]));
+        }
+    }
+
+    #[test]
+    fn lagging_follower_should_catch_up_after_reconnecting() {
+        let mut nodes = cluster(&[50, 100, 150]);
+        nodes[0].raft.tick(50);
+        deliver(&mut nodes, &[]);
+        propose(
+            &mut nodes,
+            1,
+            &[b"first", b"second", b"third", b"fourth", b"fifth"],
+        );
+        deliver(&mut nodes, &[3]);
+
+        assert_eq!(nodes[0].raft.commit_index(), 6);
+        assert_eq!(nodes[2].raft.last_index(), 1);
+
+        nodes[0].raft.tick(10);
+        deliver(&mut nodes, &[]);
+        nodes[0].raft.tick(10);
+        deliver(&mut nodes, &[]);
+
+        assert_eq!(nodes[2].raft.commit_index(), 6);
+        assert_eq!(nodes[2].payloads(), nodes[0].payloads());
+    }
+
+    #[test]
+    fn lagging_follower_should_install_snapshot_of_compacted_log() {
+        let mut nodes = cluster(&[50, 100, 150]);
+        nodes[0].raft.tick(50);
+        deliver(&mut nodes, &[]);
+        propose(&mut nodes, 1, &[b"first"]);
+        deliver(&mut nodes, &[]);
+        propose(
+            &mut nodes,
+            1,
+            &[b"second", b"third", b"fourth", b"fifth", b"sixth"],
+        );
+        deliver(&mut nodes, &[3]);
+        nodes[0].raft.compact(5);
+
+        assert_eq!(
+            nodes[0].raft.snapshot(),
+            SnapshotMetadata { index: 5, term: 1 }
+        );
+
+        nodes[0].raft.tick(10);
+        deliver(&mut nodes, &[]);
+        nodes[0].raft.tick(10);
+        deliver(&mut nodes, &[]);
+
+        assert_eq!(nodes[2].raft.snapshot().index, 5);
+        assert_eq!(nodes[2].raft.commit_index(), 7);
+        assert_eq!(nodes[2].payloads(), nodes[0].payloads());
+
+        propose(&mut nodes, 1, &[b"seventh"]);
+        deliver(&mut nodes, &[]);
+        nodes[0].raft.tick(10);
+        deliver(&mut nodes, &[]);
+
+        assert_eq!(
+            nodes[2].payloads(),
+            bytes(&[b"first", b"second", b"third", b"fourth", b"fifth", b"sixth", b"seventh"])
+        );
+    }
+
+    #[test]
+    fn changed_log_should_be_taken_only_once() {
+        let mut nodes = cluster(&[50]);
+        nodes[0].raft.tick(50);
+        propose(&mut nodes, 1, &[b"first"]);
+
+        let (snapshot, log) = nodes[0].raft.take_changed_log().unwrap();
+        assert_eq!(snapshot, SnapshotMetadata::default());
+        assert_eq!(log.len(), 2);
+        assert!(nodes[0].raft.take_changed_log().is_none());
+
+        nodes[0].apply();
+        nodes[0].raft.compact(2);
+        let (snapshot, log) = nodes[0].raft.take_changed_log().unwrap();
+        assert_eq!(snapshot, SnapshotMetadata { index: 2, term: 1 });
+        assert!(log.is_empty());
+    }
+}
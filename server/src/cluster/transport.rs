@@ -0,0 +1,305 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cluster::messages::ClusterMessage;
+use crate::cluster::ClusterNodeAddress;
+use ahash::AHashMap;
+use bytes::{BufMut, Bytes, BytesMut};
+use flume::{Receiver, Sender, TrySendError};
+use iggy::bytes_serializable::BytesSerializable;
+use ring::hmac;
+use ring::rand::{SecureRandom, SystemRandom};
+use std::io::{Error, ErrorKind};
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt, BufWriter};
+use tokio::net::{TcpListener, TcpStream};
+use tracing::{debug, error, info, warn};
+
+/// Maximum number of the messages queued for the single node, the next ones are dropped
+/// while the node is unreachable, as the Raft protocol retries them on its own.
+const MAX_QUEUED_MESSAGES: usize = 10_000;
+const MAX_FRAME_SIZE: usize = 64 * 1024 * 1024;
+const RECONNECT_INTERVAL: Duration = Duration::from_secs(1);
+const AUTHENTICATION_TIMEOUT: Duration = Duration::from_secs(5);
+const CHALLENGE_LENGTH: usize = 32;
+const SIGNATURE_LENGTH: usize = 32;
+
+/// Sends the messages to the other nodes of the cluster over TCP, each one through its own connection
+/// re-established in the background. The frame consists of the length (u32), the ID of the sender (u32)
+/// and the serialized message.
+///
+/// Each connection is authenticated with the secret shared by the nodes: the accepting node sends
+/// the random challenge, and the connecting one responds with its ID (u32) and the HMAC-SHA256
+/// of the challenge followed by its ID. The frames are accepted only from the authenticated node.
+#[derive(Debug)]
+pub struct ClusterTransport {
+    node_id: u32,
+    queues: AHashMap<u32, Sender<Bytes>>,
+}
+
+impl ClusterTransport {
+    pub fn new(node_id: u32, nodes: &[ClusterNodeAddress], secret: &str) -> Self {
+        let key = hmac::Key::new(hmac::HMAC_SHA256, secret.as_bytes());
+        let mut queues = AHashMap::new();
+        for node in nodes {
+            let (sender, receiver) = flume::bounded(MAX_QUEUED_MESSAGES);
+            queues.insert(node.id, sender);
+            tokio::spawn(send_frames(node_id, node.clone(), key.clone(), receiver));
+        }
+        Self { node_id, queues }
+    }
+
+    pub fn send(&self, to: u32, message: &ClusterMessage) {
+        let Some(queue) = self.queues.get(&to) else {
+            warn!("Cannot send cluster message to unknown node with ID: {to}.");
+            return;
+        };
+
+        let payload = message.to_bytes();
+        let mut frame = BytesMut::with_capacity(8 + payload.len());
+        frame.put_u32_le(4 + payload.len() as u32);
+        frame.put_u32_le(self.node_id);
+        frame.put_slice(&payload);
+        if let Err(TrySendError::Full(_)) = queue.try_send(frame.freeze()) {
+            debug!("Queue of cluster messages for node with ID: {to} is full, dropping message.");
+        }
+    }
+
+    /// Accepts the connections from the other nodes and passes the received messages along with the sender ID.
+    pub async fn listen(
+        address: &str,
+        secret: &str,
+        node_ids: Vec<u32>,
+        inbound: Sender<(u32, ClusterMessage)>,
+    ) -> Result<(), std::io::Error> {
+        let listener = TcpListener::bind(address).await?;
+        let key = hmac::Key::new(hmac::HMAC_SHA256, secret.as_bytes());
+        info!("Cluster transport is listening on: {address}");
+        tokio::spawn(async move {
+            loop {
+                match listener.accept().await {
+                    Ok((stream, peer_address)) => {
+                        debug!("Accepted cluster connection from: {peer_address}");
+                        let inbound = inbound.clone();
+                        let key = key.clone();
+                        let node_ids = node_ids.clone();
+                        tokio::spawn(async move {
+                            if let Err(error) =
+                                receive_frames(stream, &key, &node_ids, inbound).await
+                            {
+                                debug!(
+                                    "Cluster connection from: {peer_address} was closed. {error}"
+                                );
+                            }
+                        });
+                    }
+                    Err(error) => error!("Failed to accept cluster connection. {error}"),
+                }
+            }
+        });
+        Ok(())
+    }
+}
+
+async fn send_frames(
+    node_id: u32,
+    node: ClusterNodeAddress,
+    key: hmac::Key,
+    receiver: Receiver<Bytes>,
+) {
+    loop {
+        let mut stream = match TcpStream::connect(&node.address).await {
+            Ok(stream) => stream,
+            Err(error) => {
+                debug!(
+                    "Cannot connect to cluster node with ID: {} at: {}. {error}",
+                    node.id, node.address
+                );
+                tokio::time::sleep(RECONNECT_INTERVAL).await;
+                continue;
+            }
+        };
+
+        if let Err(error) = authenticate(&mut stream, node_id, &key).await {
+            warn!(
+                "Cannot authenticate to cluster node with ID: {} at: {}. {error}",
+                node.id, node.address
+            );
+            tokio::time::sleep(RECONNECT_INTERVAL).await;
+            continue;
+        }
+
+        info!(
+            "Connected to cluster node with ID: {} at: {}",
+            node.id, node.address
+        );
+        let _ = stream.set_nodelay(true);
+        let mut writer = BufWriter::new(stream);
+        loop {
+            let Ok(frame) = receiver.recv_async().await else {
+                return;
+            };
+
+            let mut result = writer.write_all(&frame).await;
+            // Write all the frames already queued before flushing them at once.
+            while result.is_ok() {
+                let Ok(frame) = receiver.try_recv() else {
+                    break;
+                };
+                result = writer.write_all(&frame).await;
+            }
+            if let Err(error) = result.and(writer.flush().await) {
+                warn!(
+                    "Failed to send frame to cluster node with ID: {}. {error}",
+                    node.id
+                );
+                break;
+            }
+        }
+    }
+}
+
+/// Responds to the challenge of the accepting node with the ID of this node and the signature.
+async fn authenticate(
+    stream: &mut TcpStream,
+    node_id: u32,
+    key: &hmac::Key,
+) -> Result<(), std::io::Error> {
+    let mut challenge = [0; CHALLENGE_LENGTH];
+    tokio::time::timeout(AUTHENTICATION_TIMEOUT, stream.read_exact(&mut challenge))
+        .await
+        .map_err(|_| Error::new(ErrorKind::TimedOut, "cluster challenge timed out"))??;
+    let signature = hmac::sign(key, &challenge_message(&challenge, node_id));
+    let mut response = BytesMut::with_capacity(4 + SIGNATURE_LENGTH);
+    response.put_u32_le(node_id);
+    response.put_slice(signature.as_ref());
+    stream.write_all(&response).await
+}
+
+/// Sends the random challenge to the connecting node and returns its ID, once it's verified.
+async fn verify(
+    stream: &mut TcpStream,
+    key: &hmac::Key,
+    node_ids: &[u32],
+) -> Result<u32, std::io::Error> {
+    let mut challenge = [0; CHALLENGE_LENGTH];
+    SystemRandom::new()
+        .fill(&mut challenge)
+        .map_err(|_| Error::other("cannot generate cluster challenge"))?;
+    stream.write_all(&challenge).await?;
+    let mut response = [0; 4 + SIGNATURE_LENGTH];
+    tokio::time::timeout(AUTHENTICATION_TIMEOUT, stream.read_exact(&mut response))
+        .await
+        .map_err(|_| Error::new(ErrorKind::TimedOut, "cluster authentication timed out"))??;
+    let node_id = u32::from_le_bytes(response[..4].try_into().unwrap());
+    if !node_ids.contains(&node_id)
+        || hmac::verify(key, &challenge_message(&challenge, node_id), &response[4..]).is_err()
+    {
+        return Err(Error::new(
+            ErrorKind::PermissionDenied,
+            format!("invalid cluster credentials of node with ID: {node_id}"),
+        ));
+    }
+
+    Ok(node_id)
+}
+
+fn challenge_message(challenge: &[u8], node_id: u32) -> Vec<u8> {
+    let mut message = Vec::with_capacity(challenge.len() + 4);
+    message.extend_from_slice(challenge);
+    message.extend_from_slice(&node_id.to_le_bytes());
+    message
+}
+
+async fn receive_frames(
+    mut stream: TcpStream,
+    key: &hmac::Key,
+    node_ids: &[u32],
+    inbound: Sender<(u32, ClusterMessage)>,
+) -> Result<(), std::io::Error> {
+    let node_id = verify(&mut stream, key, node_ids).await?;
+    info!("Authenticated cluster connection from node with ID: {node_id}");
+    loop {
+        let length = stream.read_u32_le().await? as usize;
+        if !(4..=MAX_FRAME_SIZE).contains(&length) {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("invalid cluster frame length: {length}"),
+            ));
+        }
+
+        let from = stream.read_u32_le().await?;
+        if from != node_id {
+            return Err(Error::new(
+                ErrorKind::PermissionDenied,
+                format!("cluster frame from node with ID: {from} sent by node with ID: {node_id}"),
+            ));
+        }
+
+        let mut payload = vec![0; length - 4];
+        stream.read_exact(&mut payload).await?;
+        match ClusterMessage::from_bytes(Bytes::from(payload)) {
+            Ok(message) => {
+                if inbound.send_async((from, message)).await.is_err() {
+                    return Ok(());
+                }
+            }
+            Err(error) => {
+                warn!("Received invalid cluster message from node with ID: {from}. {error}")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn connect(
+        listener_secret: &str,
+        node_secret: &str,
+        node_id: u32,
+    ) -> Result<u32, std::io::Error> {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let listener_key = hmac::Key::new(hmac::HMAC_SHA256, listener_secret.as_bytes());
+        let node_key = hmac::Key::new(hmac::HMAC_SHA256, node_secret.as_bytes());
+        let verified = tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            verify(&mut stream, &listener_key, &[2, 3]).await
+        });
+
+        let mut stream = TcpStream::connect(address).await.unwrap();
+        authenticate(&mut stream, node_id, &node_key).await.unwrap();
+        verified.await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn node_knowing_shared_secret_should_be_authenticated() {
+        assert_eq!(connect("secret", "secret", 2).await.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn node_with_invalid_secret_or_unknown_id_should_be_rejected() {
+        let error = connect("secret", "other", 2).await.unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::PermissionDenied);
+
+        let error = connect("secret", "secret", 4).await.unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::PermissionDenied);
+    }
+}
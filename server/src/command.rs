@@ -21,49 +21,98 @@ use error_set::ErrContext;
 use iggy::command::*;
 use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use iggy::consumer_groups::delete_consumer_group::DeleteConsumerGroup;
+use iggy::consumer_groups::delete_partition_state::DeletePartitionState;
 use iggy::consumer_groups::get_consumer_group::GetConsumerGroup;
 use iggy::consumer_groups::get_consumer_groups::GetConsumerGroups;
+use iggy::consumer_groups::get_partition_state::GetPartitionState;
 use iggy::consumer_groups::join_consumer_group::JoinConsumerGroup;
 use iggy::consumer_groups::leave_consumer_group::LeaveConsumerGroup;
+use iggy::consumer_groups::store_partition_state::StorePartitionState;
+use iggy::consumer_groups::update_consumer_group_dead_letter::UpdateConsumerGroupDeadLetter;
+use iggy::consumer_groups::update_consumer_group_visibility_timeout::UpdateConsumerGroupVisibilityTimeout;
 use iggy::consumer_offsets::delete_consumer_offset::DeleteConsumerOffset;
 use iggy::consumer_offsets::get_consumer_offset::GetConsumerOffset;
+use iggy::consumer_offsets::get_offsets_for_timestamps::GetOffsetsForTimestamps;
 use iggy::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
+use iggy::consumer_offsets::store_consumer_offsets::StoreConsumerOffsets;
 use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::abort_transaction::AbortTransaction;
+use iggy::messages::ack_messages::AckMessages;
+use iggy::messages::aggregate_messages::AggregateMessages;
+use iggy::messages::begin_transaction::BeginTransaction;
+use iggy::messages::cancel_replay_job::CancelReplayJob;
+use iggy::messages::commit_transaction::CommitTransaction;
+use iggy::messages::create_push_subscription::CreatePushSubscription;
+use iggy::messages::delete_push_subscription::DeletePushSubscription;
+use iggy::messages::get_push_subscriptions::GetPushSubscriptions;
+use iggy::messages::get_replay_jobs::GetReplayJobs;
+use iggy::messages::init_producer_id::InitProducerId;
+use iggy::messages::nack_messages::NackMessages;
 use iggy::messages::poll_messages::PollMessages;
+use iggy::messages::register_producer::RegisterProducer;
+use iggy::messages::replay_messages::ReplayMessages;
 use iggy::messages::send_messages::SendMessages;
+use iggy::messages::send_messages_batch::SendMessagesBatch;
+use iggy::namespaces::create_namespace::CreateNamespace;
+use iggy::namespaces::delete_namespace::DeleteNamespace;
+use iggy::namespaces::get_namespace::GetNamespace;
+use iggy::namespaces::get_namespaces::GetNamespaces;
+use iggy::namespaces::update_namespace::UpdateNamespace;
 use iggy::partitions::create_partitions::CreatePartitions;
 use iggy::partitions::delete_partitions::DeletePartitions;
+use iggy::partitions::flush_partition::FlushPartition;
+use iggy::partitions::read_segment_range::ReadSegmentRange;
+use iggy::partitions::restore_archived_segments::RestoreArchivedSegments;
 use iggy::personal_access_tokens::create_personal_access_token::CreatePersonalAccessToken;
 use iggy::personal_access_tokens::delete_personal_access_token::DeletePersonalAccessToken;
 use iggy::personal_access_tokens::get_personal_access_tokens::GetPersonalAccessTokens;
 use iggy::personal_access_tokens::login_with_personal_access_token::LoginWithPersonalAccessToken;
+use iggy::personal_access_tokens::rotate_personal_access_token::RotatePersonalAccessToken;
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::delete_stream::DeleteStream;
 use iggy::streams::get_stream::GetStream;
 use iggy::streams::get_streams::GetStreams;
 use iggy::streams::purge_stream::PurgeStream;
 use iggy::streams::update_stream::UpdateStream;
+use iggy::streams::update_stream_metadata::UpdateStreamMetadata;
+use iggy::streams::update_stream_quota::UpdateStreamQuota;
+use iggy::system::create_backup::CreateBackup;
+use iggy::system::get_audit_log::GetAuditLog;
 use iggy::system::get_client::GetClient;
 use iggy::system::get_clients::GetClients;
+use iggy::system::get_error_catalog::GetErrorCatalog;
+use iggy::system::get_maintenance_mode::GetMaintenanceMode;
 use iggy::system::get_me::GetMe;
+use iggy::system::get_server_info::GetServerInfo;
 use iggy::system::get_snapshot::GetSnapshot;
 use iggy::system::get_stats::GetStats;
+use iggy::system::get_unsaved_state::GetUnsavedState;
+use iggy::system::handshake::Handshake;
 use iggy::system::ping::Ping;
+use iggy::system::set_maintenance_mode::SetMaintenanceMode;
 use iggy::topics::create_topic::CreateTopic;
 use iggy::topics::delete_topic::DeleteTopic;
 use iggy::topics::get_topic::GetTopic;
 use iggy::topics::get_topics::GetTopics;
+use iggy::topics::mark_topic_for_deletion::MarkTopicForDeletion;
 use iggy::topics::purge_topic::PurgeTopic;
 use iggy::topics::update_topic::UpdateTopic;
+use iggy::topics::update_topic_config::UpdateTopicConfig;
+use iggy::topics::update_topic_expiry_watcher::UpdateTopicExpiryWatcher;
+use iggy::topics::update_topic_metadata::UpdateTopicMetadata;
+use iggy::topics::update_topic_producers::UpdateTopicProducers;
 use iggy::users::change_password::ChangePassword;
 use iggy::users::create_user::CreateUser;
 use iggy::users::delete_user::DeleteUser;
 use iggy::users::get_user::GetUser;
 use iggy::users::get_users::GetUsers;
+use iggy::users::login_as::LoginAs;
 use iggy::users::login_user::LoginUser;
 use iggy::users::logout_user::LogoutUser;
 use iggy::users::update_permissions::UpdatePermissions;
 use iggy::users::update_user::UpdateUser;
+use iggy::users::update_user_quotas::UpdateUserQuotas;
 use iggy::validatable::Validatable;
 use iggy::{
     bytes_serializable::BytesSerializable, messages::flush_unsaved_buffer::FlushUnsavedBuffer,
@@ -75,6 +124,7 @@ use tracing::error;
 #[derive(Debug, PartialEq, EnumString)]
 pub enum ServerCommand {
     Ping(Ping),
+    Handshake(Handshake),
     GetStats(GetStats),
     GetMe(GetMe),
     GetClient(GetClient),
@@ -85,18 +135,38 @@ pub enum ServerCommand {
     DeleteUser(DeleteUser),
     UpdateUser(UpdateUser),
     UpdatePermissions(UpdatePermissions),
+    UpdateUserQuotas(UpdateUserQuotas),
     ChangePassword(ChangePassword),
     LoginUser(LoginUser),
     LogoutUser(LogoutUser),
+    LoginAs(LoginAs),
     GetPersonalAccessTokens(GetPersonalAccessTokens),
     CreatePersonalAccessToken(CreatePersonalAccessToken),
     DeletePersonalAccessToken(DeletePersonalAccessToken),
+    RotatePersonalAccessToken(RotatePersonalAccessToken),
     LoginWithPersonalAccessToken(LoginWithPersonalAccessToken),
     SendMessages(SendMessages),
+    SendMessagesBatch(SendMessagesBatch),
     PollMessages(PollMessages),
     FlushUnsavedBuffer(FlushUnsavedBuffer),
+    NackMessages(NackMessages),
+    AckMessages(AckMessages),
+    BeginTransaction(BeginTransaction),
+    CommitTransaction(CommitTransaction),
+    AbortTransaction(AbortTransaction),
+    AggregateMessages(AggregateMessages),
+    ReplayMessages(ReplayMessages),
+    GetReplayJobs(GetReplayJobs),
+    CancelReplayJob(CancelReplayJob),
+    CreatePushSubscription(CreatePushSubscription),
+    GetPushSubscriptions(GetPushSubscriptions),
+    DeletePushSubscription(DeletePushSubscription),
+    RegisterProducer(RegisterProducer),
+    InitProducerId(InitProducerId),
     GetConsumerOffset(GetConsumerOffset),
     StoreConsumerOffset(StoreConsumerOffset),
+    StoreConsumerOffsets(StoreConsumerOffsets),
+    GetOffsetsForTimestamps(GetOffsetsForTimestamps),
     DeleteConsumerOffset(DeleteConsumerOffset),
     GetStream(GetStream),
     GetStreams(GetStreams),
@@ -104,27 +174,348 @@ pub enum ServerCommand {
     DeleteStream(DeleteStream),
     UpdateStream(UpdateStream),
     PurgeStream(PurgeStream),
+    UpdateStreamMetadata(UpdateStreamMetadata),
+    UpdateStreamQuota(UpdateStreamQuota),
     GetTopic(GetTopic),
     GetTopics(GetTopics),
     CreateTopic(CreateTopic),
     DeleteTopic(DeleteTopic),
     UpdateTopic(UpdateTopic),
     PurgeTopic(PurgeTopic),
+    UpdateTopicMetadata(UpdateTopicMetadata),
+    UpdateTopicProducers(UpdateTopicProducers),
+    UpdateTopicConfig(UpdateTopicConfig),
+    UpdateTopicExpiryWatcher(UpdateTopicExpiryWatcher),
+    MarkTopicForDeletion(MarkTopicForDeletion),
     CreatePartitions(CreatePartitions),
     DeletePartitions(DeletePartitions),
+    RestoreArchivedSegments(RestoreArchivedSegments),
+    FlushPartition(FlushPartition),
+    ReadSegmentRange(ReadSegmentRange),
     GetConsumerGroup(GetConsumerGroup),
     GetConsumerGroups(GetConsumerGroups),
     CreateConsumerGroup(CreateConsumerGroup),
     DeleteConsumerGroup(DeleteConsumerGroup),
+    UpdateConsumerGroupDeadLetter(UpdateConsumerGroupDeadLetter),
+    UpdateConsumerGroupVisibilityTimeout(UpdateConsumerGroupVisibilityTimeout),
     JoinConsumerGroup(JoinConsumerGroup),
     LeaveConsumerGroup(LeaveConsumerGroup),
+    GetPartitionState(GetPartitionState),
+    StorePartitionState(StorePartitionState),
+    DeletePartitionState(DeletePartitionState),
     GetSnapshotFile(GetSnapshot),
+    GetMaintenanceMode(GetMaintenanceMode),
+    GetServerInfo(GetServerInfo),
+    SetMaintenanceMode(SetMaintenanceMode),
+    CreateBackup(CreateBackup),
+    GetUnsavedState(GetUnsavedState),
+    GetAuditLog(GetAuditLog),
+    GetErrorCatalog(GetErrorCatalog),
+    GetNamespace(GetNamespace),
+    GetNamespaces(GetNamespaces),
+    CreateNamespace(CreateNamespace),
+    UpdateNamespace(UpdateNamespace),
+    DeleteNamespace(DeleteNamespace),
+}
+
+impl ServerCommand {
+    /// Returns `true` if the command does not modify the system and can be handled in read-only mode.
+    pub fn is_read_only(&self) -> bool {
+        matches!(
+            self,
+            ServerCommand::Ping(_)
+                | ServerCommand::Handshake(_)
+                | ServerCommand::GetStats(_)
+                | ServerCommand::GetMe(_)
+                | ServerCommand::GetClient(_)
+                | ServerCommand::GetClients(_)
+                | ServerCommand::GetUser(_)
+                | ServerCommand::GetUsers(_)
+                | ServerCommand::LoginUser(_)
+                | ServerCommand::LogoutUser(_)
+                | ServerCommand::LoginAs(_)
+                | ServerCommand::GetPersonalAccessTokens(_)
+                | ServerCommand::LoginWithPersonalAccessToken(_)
+                | ServerCommand::PollMessages(_)
+                | ServerCommand::AggregateMessages(_)
+                | ServerCommand::ReadSegmentRange(_)
+                | ServerCommand::GetReplayJobs(_)
+                | ServerCommand::GetPushSubscriptions(_)
+                | ServerCommand::GetConsumerOffset(_)
+                | ServerCommand::GetOffsetsForTimestamps(_)
+                | ServerCommand::GetStream(_)
+                | ServerCommand::GetStreams(_)
+                | ServerCommand::GetTopic(_)
+                | ServerCommand::GetTopics(_)
+                | ServerCommand::GetConsumerGroup(_)
+                | ServerCommand::GetConsumerGroups(_)
+                | ServerCommand::GetPartitionState(_)
+                | ServerCommand::GetSnapshotFile(_)
+                | ServerCommand::GetMaintenanceMode(_)
+                | ServerCommand::GetServerInfo(_)
+                | ServerCommand::CreateBackup(_)
+                | ServerCommand::GetUnsavedState(_)
+                | ServerCommand::GetAuditLog(_)
+                | ServerCommand::GetErrorCatalog(_)
+                | ServerCommand::GetNamespace(_)
+                | ServerCommand::GetNamespaces(_)
+        )
+    }
+
+    /// Returns `true` if the command changes the state replicated across the cluster,
+    /// so that it can be handled only by the leader of the cluster.
+    pub fn is_replicated(&self) -> bool {
+        matches!(
+            self,
+            ServerCommand::CreateUser(_)
+                | ServerCommand::DeleteUser(_)
+                | ServerCommand::UpdateUser(_)
+                | ServerCommand::UpdatePermissions(_)
+                | ServerCommand::UpdateUserQuotas(_)
+                | ServerCommand::ChangePassword(_)
+                | ServerCommand::CreatePersonalAccessToken(_)
+                | ServerCommand::DeletePersonalAccessToken(_)
+                | ServerCommand::RotatePersonalAccessToken(_)
+                | ServerCommand::CreateStream(_)
+                | ServerCommand::DeleteStream(_)
+                | ServerCommand::UpdateStream(_)
+                | ServerCommand::PurgeStream(_)
+                | ServerCommand::UpdateStreamMetadata(_)
+                | ServerCommand::UpdateStreamQuota(_)
+                | ServerCommand::CreateTopic(_)
+                | ServerCommand::DeleteTopic(_)
+                | ServerCommand::UpdateTopic(_)
+                | ServerCommand::PurgeTopic(_)
+                | ServerCommand::UpdateTopicMetadata(_)
+                | ServerCommand::UpdateTopicProducers(_)
+                | ServerCommand::UpdateTopicConfig(_)
+                | ServerCommand::UpdateTopicExpiryWatcher(_)
+                | ServerCommand::MarkTopicForDeletion(_)
+                | ServerCommand::CreatePartitions(_)
+                | ServerCommand::DeletePartitions(_)
+                | ServerCommand::CreateConsumerGroup(_)
+                | ServerCommand::DeleteConsumerGroup(_)
+                | ServerCommand::UpdateConsumerGroupDeadLetter(_)
+                | ServerCommand::UpdateConsumerGroupVisibilityTimeout(_)
+                | ServerCommand::CreateNamespace(_)
+                | ServerCommand::UpdateNamespace(_)
+                | ServerCommand::DeleteNamespace(_)
+        )
+    }
+
+    /// Returns `true` if the command can be handled while the server is in maintenance mode,
+    /// so that the clients can still connect, learn about the maintenance and operators can back up the data and end it.
+    pub fn is_allowed_in_maintenance(&self) -> bool {
+        matches!(
+            self,
+            ServerCommand::Ping(_)
+                | ServerCommand::Handshake(_)
+                | ServerCommand::GetStats(_)
+                | ServerCommand::GetMe(_)
+                | ServerCommand::LoginUser(_)
+                | ServerCommand::LogoutUser(_)
+                | ServerCommand::LoginWithPersonalAccessToken(_)
+                | ServerCommand::GetMaintenanceMode(_)
+                | ServerCommand::GetServerInfo(_)
+                | ServerCommand::SetMaintenanceMode(_)
+                | ServerCommand::CreateBackup(_)
+                | ServerCommand::GetUnsavedState(_)
+                | ServerCommand::GetAuditLog(_)
+                | ServerCommand::GetErrorCatalog(_)
+                | ServerCommand::FlushPartition(_)
+        )
+    }
+
+    /// Returns `true` if the command only tracks the progress of consuming the messages,
+    /// so that it can be executed by the clients restricted to the read-only access.
+    pub fn is_consuming(&self) -> bool {
+        matches!(
+            self,
+            ServerCommand::StoreConsumerOffset(_)
+                | ServerCommand::StoreConsumerOffsets(_)
+                | ServerCommand::AckMessages(_)
+                | ServerCommand::NackMessages(_)
+                | ServerCommand::JoinConsumerGroup(_)
+                | ServerCommand::LeaveConsumerGroup(_)
+        )
+    }
+
+    /// Returns `true` if the command affects only the current session and not any other resource.
+    pub fn is_session_level(&self) -> bool {
+        matches!(
+            self,
+            ServerCommand::Ping(_)
+                | ServerCommand::Handshake(_)
+                | ServerCommand::GetMe(_)
+                | ServerCommand::GetServerInfo(_)
+                | ServerCommand::GetErrorCatalog(_)
+                | ServerCommand::LoginUser(_)
+                | ServerCommand::LogoutUser(_)
+                | ServerCommand::LoginWithPersonalAccessToken(_)
+                | ServerCommand::BeginTransaction(_)
+                | ServerCommand::CommitTransaction(_)
+                | ServerCommand::AbortTransaction(_)
+        )
+    }
+
+    /// Returns the streams and the topics (if any) targeted by the command.
+    /// The topic is not set for the commands targeting the whole stream, e.g. creating a topic.
+    pub fn get_stream_resources(&self) -> Vec<(&Identifier, Option<&Identifier>)> {
+        match self {
+            ServerCommand::SendMessages(command) => {
+                vec![(&command.stream_id, Some(&command.topic_id))]
+            }
+            ServerCommand::SendMessagesBatch(command) => command
+                .batches
+                .iter()
+                .map(|batch| (&batch.stream_id, Some(&batch.topic_id)))
+                .collect(),
+            ServerCommand::PollMessages(command) => {
+                vec![(&command.stream_id, Some(&command.topic_id))]
+            }
+            ServerCommand::FlushUnsavedBuffer(command) => {
+                vec![(&command.stream_id, Some(&command.topic_id))]
+            }
+            ServerCommand::NackMessages(command) => {
+                vec![(&command.stream_id, Some(&command.topic_id))]
+            }
+            ServerCommand::AckMessages(command) => {
+                vec![(&command.stream_id, Some(&command.topic_id))]
+            }
+            ServerCommand::AggregateMessages(command) => {
+                vec![(&command.stream_id, Some(&command.topic_id))]
+            }
+            ServerCommand::ReplayMessages(command) => vec![
+                (&command.source_stream_id, Some(&command.source_topic_id)),
+                (
+                    &command.destination_stream_id,
+                    Some(&command.destination_topic_id),
+                ),
+            ],
+            ServerCommand::CreatePushSubscription(command) => {
+                vec![(&command.stream_id, Some(&command.topic_id))]
+            }
+            ServerCommand::RegisterProducer(command) => {
+                vec![(&command.stream_id, Some(&command.topic_id))]
+            }
+            ServerCommand::InitProducerId(command) => {
+                vec![(&command.stream_id, Some(&command.topic_id))]
+            }
+            ServerCommand::GetConsumerOffset(command) => {
+                vec![(&command.stream_id, Some(&command.topic_id))]
+            }
+            ServerCommand::StoreConsumerOffset(command) => {
+                vec![(&command.stream_id, Some(&command.topic_id))]
+            }
+            ServerCommand::StoreConsumerOffsets(command) => {
+                vec![(&command.stream_id, Some(&command.topic_id))]
+            }
+            ServerCommand::GetOffsetsForTimestamps(command) => {
+                vec![(&command.stream_id, Some(&command.topic_id))]
+            }
+            ServerCommand::DeleteConsumerOffset(command) => {
+                vec![(&command.stream_id, Some(&command.topic_id))]
+            }
+            ServerCommand::GetStream(command) => vec![(&command.stream_id, None)],
+            ServerCommand::DeleteStream(command) => vec![(&command.stream_id, None)],
+            ServerCommand::UpdateStream(command) => vec![(&command.stream_id, None)],
+            ServerCommand::PurgeStream(command) => vec![(&command.stream_id, None)],
+            ServerCommand::UpdateStreamMetadata(command) => vec![(&command.stream_id, None)],
+            ServerCommand::UpdateStreamQuota(command) => vec![(&command.stream_id, None)],
+            ServerCommand::GetTopic(command) => {
+                vec![(&command.stream_id, Some(&command.topic_id))]
+            }
+            ServerCommand::GetTopics(command) => vec![(&command.stream_id, None)],
+            ServerCommand::CreateTopic(command) => vec![(&command.stream_id, None)],
+            ServerCommand::DeleteTopic(command) => {
+                vec![(&command.stream_id, Some(&command.topic_id))]
+            }
+            ServerCommand::UpdateTopic(command) => {
+                vec![(&command.stream_id, Some(&command.topic_id))]
+            }
+            ServerCommand::PurgeTopic(command) => {
+                vec![(&command.stream_id, Some(&command.topic_id))]
+            }
+            ServerCommand::UpdateTopicMetadata(command) => {
+                vec![(&command.stream_id, Some(&command.topic_id))]
+            }
+            ServerCommand::UpdateTopicProducers(command) => {
+                vec![(&command.stream_id, Some(&command.topic_id))]
+            }
+            ServerCommand::UpdateTopicConfig(command) => {
+                vec![(&command.stream_id, Some(&command.topic_id))]
+            }
+            ServerCommand::UpdateTopicExpiryWatcher(command) => {
+                vec![(&command.stream_id, Some(&command.topic_id))]
+            }
+            ServerCommand::MarkTopicForDeletion(command) => {
+                vec![(&command.stream_id, Some(&command.topic_id))]
+            }
+            ServerCommand::CreatePartitions(command) => {
+                vec![(&command.stream_id, Some(&command.topic_id))]
+            }
+            ServerCommand::DeletePartitions(command) => {
+                vec![(&command.stream_id, Some(&command.topic_id))]
+            }
+            ServerCommand::RestoreArchivedSegments(command) => {
+                vec![(&command.stream_id, Some(&command.topic_id))]
+            }
+            ServerCommand::FlushPartition(command) => {
+                vec![(&command.stream_id, Some(&command.topic_id))]
+            }
+            ServerCommand::ReadSegmentRange(command) => {
+                vec![(&command.stream_id, Some(&command.topic_id))]
+            }
+            ServerCommand::GetConsumerGroup(command) => {
+                vec![(&command.stream_id, Some(&command.topic_id))]
+            }
+            ServerCommand::GetConsumerGroups(command) => {
+                vec![(&command.stream_id, Some(&command.topic_id))]
+            }
+            ServerCommand::CreateConsumerGroup(command) => {
+                vec![(&command.stream_id, Some(&command.topic_id))]
+            }
+            ServerCommand::DeleteConsumerGroup(command) => {
+                vec![(&command.stream_id, Some(&command.topic_id))]
+            }
+            ServerCommand::UpdateConsumerGroupDeadLetter(command) => {
+                let mut resources = vec![(&command.stream_id, Some(&command.topic_id))];
+                if let (Some(stream_id), Some(topic_id)) = (
+                    &command.dead_letter_stream_id,
+                    &command.dead_letter_topic_id,
+                ) {
+                    resources.push((stream_id, Some(topic_id)));
+                }
+                resources
+            }
+            ServerCommand::UpdateConsumerGroupVisibilityTimeout(command) => {
+                vec![(&command.stream_id, Some(&command.topic_id))]
+            }
+            ServerCommand::JoinConsumerGroup(command) => {
+                vec![(&command.stream_id, Some(&command.topic_id))]
+            }
+            ServerCommand::LeaveConsumerGroup(command) => {
+                vec![(&command.stream_id, Some(&command.topic_id))]
+            }
+            ServerCommand::GetPartitionState(command) => {
+                vec![(&command.stream_id, Some(&command.topic_id))]
+            }
+            ServerCommand::StorePartitionState(command) => {
+                vec![(&command.stream_id, Some(&command.topic_id))]
+            }
+            ServerCommand::DeletePartitionState(command) => {
+                vec![(&command.stream_id, Some(&command.topic_id))]
+            }
+            _ => Vec::new(),
+        }
+    }
 }
 
 impl BytesSerializable for ServerCommand {
     fn to_bytes(&self) -> Bytes {
         match self {
             ServerCommand::Ping(payload) => as_bytes(payload),
+            ServerCommand::Handshake(payload) => as_bytes(payload),
             ServerCommand::GetStats(payload) => as_bytes(payload),
             ServerCommand::GetMe(payload) => as_bytes(payload),
             ServerCommand::GetClient(payload) => as_bytes(payload),
@@ -135,16 +526,22 @@ impl BytesSerializable for ServerCommand {
             ServerCommand::DeleteUser(payload) => as_bytes(payload),
             ServerCommand::UpdateUser(payload) => as_bytes(payload),
             ServerCommand::UpdatePermissions(payload) => as_bytes(payload),
+            ServerCommand::UpdateUserQuotas(payload) => as_bytes(payload),
             ServerCommand::ChangePassword(payload) => as_bytes(payload),
             ServerCommand::LoginUser(payload) => as_bytes(payload),
             ServerCommand::LogoutUser(payload) => as_bytes(payload),
+            ServerCommand::LoginAs(payload) => as_bytes(payload),
             ServerCommand::GetPersonalAccessTokens(payload) => as_bytes(payload),
             ServerCommand::CreatePersonalAccessToken(payload) => as_bytes(payload),
             ServerCommand::DeletePersonalAccessToken(payload) => as_bytes(payload),
+            ServerCommand::RotatePersonalAccessToken(payload) => as_bytes(payload),
             ServerCommand::LoginWithPersonalAccessToken(payload) => as_bytes(payload),
             ServerCommand::SendMessages(payload) => as_bytes(payload),
+            ServerCommand::SendMessagesBatch(payload) => as_bytes(payload),
             ServerCommand::PollMessages(payload) => as_bytes(payload),
             ServerCommand::StoreConsumerOffset(payload) => as_bytes(payload),
+            ServerCommand::StoreConsumerOffsets(payload) => as_bytes(payload),
+            ServerCommand::GetOffsetsForTimestamps(payload) => as_bytes(payload),
             ServerCommand::DeleteConsumerOffset(payload) => as_bytes(payload),
             ServerCommand::GetConsumerOffset(payload) => as_bytes(payload),
             ServerCommand::GetStream(payload) => as_bytes(payload),
@@ -153,22 +550,63 @@ impl BytesSerializable for ServerCommand {
             ServerCommand::DeleteStream(payload) => as_bytes(payload),
             ServerCommand::UpdateStream(payload) => as_bytes(payload),
             ServerCommand::PurgeStream(payload) => as_bytes(payload),
+            ServerCommand::UpdateStreamMetadata(payload) => as_bytes(payload),
+            ServerCommand::UpdateStreamQuota(payload) => as_bytes(payload),
             ServerCommand::GetTopic(payload) => as_bytes(payload),
             ServerCommand::GetTopics(payload) => as_bytes(payload),
             ServerCommand::CreateTopic(payload) => as_bytes(payload),
             ServerCommand::DeleteTopic(payload) => as_bytes(payload),
             ServerCommand::UpdateTopic(payload) => as_bytes(payload),
             ServerCommand::PurgeTopic(payload) => as_bytes(payload),
+            ServerCommand::UpdateTopicMetadata(payload) => as_bytes(payload),
+            ServerCommand::UpdateTopicProducers(payload) => as_bytes(payload),
+            ServerCommand::UpdateTopicConfig(payload) => as_bytes(payload),
+            ServerCommand::UpdateTopicExpiryWatcher(payload) => as_bytes(payload),
+            ServerCommand::MarkTopicForDeletion(payload) => as_bytes(payload),
             ServerCommand::CreatePartitions(payload) => as_bytes(payload),
             ServerCommand::DeletePartitions(payload) => as_bytes(payload),
+            ServerCommand::RestoreArchivedSegments(payload) => as_bytes(payload),
+            ServerCommand::FlushPartition(payload) => as_bytes(payload),
+            ServerCommand::ReadSegmentRange(payload) => as_bytes(payload),
             ServerCommand::GetConsumerGroup(payload) => as_bytes(payload),
             ServerCommand::GetConsumerGroups(payload) => as_bytes(payload),
             ServerCommand::CreateConsumerGroup(payload) => as_bytes(payload),
             ServerCommand::DeleteConsumerGroup(payload) => as_bytes(payload),
+            ServerCommand::UpdateConsumerGroupDeadLetter(payload) => as_bytes(payload),
+            ServerCommand::UpdateConsumerGroupVisibilityTimeout(payload) => as_bytes(payload),
             ServerCommand::JoinConsumerGroup(payload) => as_bytes(payload),
             ServerCommand::LeaveConsumerGroup(payload) => as_bytes(payload),
+            ServerCommand::GetPartitionState(payload) => as_bytes(payload),
+            ServerCommand::StorePartitionState(payload) => as_bytes(payload),
+            ServerCommand::DeletePartitionState(payload) => as_bytes(payload),
             ServerCommand::FlushUnsavedBuffer(payload) => as_bytes(payload),
+            ServerCommand::NackMessages(payload) => as_bytes(payload),
+            ServerCommand::AckMessages(payload) => as_bytes(payload),
+            ServerCommand::BeginTransaction(payload) => as_bytes(payload),
+            ServerCommand::CommitTransaction(payload) => as_bytes(payload),
+            ServerCommand::AbortTransaction(payload) => as_bytes(payload),
+            ServerCommand::AggregateMessages(payload) => as_bytes(payload),
+            ServerCommand::ReplayMessages(payload) => as_bytes(payload),
+            ServerCommand::GetReplayJobs(payload) => as_bytes(payload),
+            ServerCommand::CancelReplayJob(payload) => as_bytes(payload),
+            ServerCommand::CreatePushSubscription(payload) => as_bytes(payload),
+            ServerCommand::GetPushSubscriptions(payload) => as_bytes(payload),
+            ServerCommand::DeletePushSubscription(payload) => as_bytes(payload),
+            ServerCommand::RegisterProducer(payload) => as_bytes(payload),
+            ServerCommand::InitProducerId(payload) => as_bytes(payload),
             ServerCommand::GetSnapshotFile(payload) => as_bytes(payload),
+            ServerCommand::GetMaintenanceMode(payload) => as_bytes(payload),
+            ServerCommand::GetServerInfo(payload) => as_bytes(payload),
+            ServerCommand::SetMaintenanceMode(payload) => as_bytes(payload),
+            ServerCommand::CreateBackup(payload) => as_bytes(payload),
+            ServerCommand::GetUnsavedState(payload) => as_bytes(payload),
+            ServerCommand::GetAuditLog(payload) => as_bytes(payload),
+            ServerCommand::GetErrorCatalog(payload) => as_bytes(payload),
+            ServerCommand::GetNamespace(payload) => as_bytes(payload),
+            ServerCommand::GetNamespaces(payload) => as_bytes(payload),
+            ServerCommand::CreateNamespace(payload) => as_bytes(payload),
+            ServerCommand::UpdateNamespace(payload) => as_bytes(payload),
+            ServerCommand::DeleteNamespace(payload) => as_bytes(payload),
         }
     }
 
@@ -182,6 +620,7 @@ impl BytesSerializable for ServerCommand {
         let payload = bytes.slice(4..);
         match code {
             PING_CODE => Ok(ServerCommand::Ping(Ping::from_bytes(payload)?)),
+            HANDSHAKE_CODE => Ok(ServerCommand::Handshake(Handshake::from_bytes(payload)?)),
             GET_STATS_CODE => Ok(ServerCommand::GetStats(GetStats::from_bytes(payload)?)),
             GET_ME_CODE => Ok(ServerCommand::GetMe(GetMe::from_bytes(payload)?)),
             GET_CLIENT_CODE => Ok(ServerCommand::GetClient(GetClient::from_bytes(payload)?)),
@@ -194,11 +633,15 @@ impl BytesSerializable for ServerCommand {
             UPDATE_PERMISSIONS_CODE => Ok(ServerCommand::UpdatePermissions(
                 UpdatePermissions::from_bytes(payload)?,
             )),
+            UPDATE_USER_QUOTAS_CODE => Ok(ServerCommand::UpdateUserQuotas(
+                UpdateUserQuotas::from_bytes(payload)?,
+            )),
             CHANGE_PASSWORD_CODE => Ok(ServerCommand::ChangePassword(ChangePassword::from_bytes(
                 payload,
             )?)),
             LOGIN_USER_CODE => Ok(ServerCommand::LoginUser(LoginUser::from_bytes(payload)?)),
             LOGOUT_USER_CODE => Ok(ServerCommand::LogoutUser(LogoutUser::from_bytes(payload)?)),
+            LOGIN_AS_CODE => Ok(ServerCommand::LoginAs(LoginAs::from_bytes(payload)?)),
             GET_PERSONAL_ACCESS_TOKENS_CODE => Ok(ServerCommand::GetPersonalAccessTokens(
                 GetPersonalAccessTokens::from_bytes(payload)?,
             )),
@@ -208,6 +651,9 @@ impl BytesSerializable for ServerCommand {
             DELETE_PERSONAL_ACCESS_TOKEN_CODE => Ok(ServerCommand::DeletePersonalAccessToken(
                 DeletePersonalAccessToken::from_bytes(payload)?,
             )),
+            ROTATE_PERSONAL_ACCESS_TOKEN_CODE => Ok(ServerCommand::RotatePersonalAccessToken(
+                RotatePersonalAccessToken::from_bytes(payload)?,
+            )),
             LOGIN_WITH_PERSONAL_ACCESS_TOKEN_CODE => {
                 Ok(ServerCommand::LoginWithPersonalAccessToken(
                     LoginWithPersonalAccessToken::from_bytes(payload)?,
@@ -216,15 +662,66 @@ impl BytesSerializable for ServerCommand {
             SEND_MESSAGES_CODE => Ok(ServerCommand::SendMessages(SendMessages::from_bytes(
                 payload,
             )?)),
+            SEND_MESSAGES_BATCH_CODE => Ok(ServerCommand::SendMessagesBatch(
+                SendMessagesBatch::from_bytes(payload)?,
+            )),
             POLL_MESSAGES_CODE => Ok(ServerCommand::PollMessages(PollMessages::from_bytes(
                 payload,
             )?)),
             FLUSH_UNSAVED_BUFFER_CODE => Ok(ServerCommand::FlushUnsavedBuffer(
                 FlushUnsavedBuffer::from_bytes(payload)?,
             )),
+            NACK_MESSAGES_CODE => Ok(ServerCommand::NackMessages(NackMessages::from_bytes(
+                payload,
+            )?)),
+            ACK_MESSAGES_CODE => Ok(ServerCommand::AckMessages(AckMessages::from_bytes(
+                payload,
+            )?)),
+            BEGIN_TRANSACTION_CODE => Ok(ServerCommand::BeginTransaction(
+                BeginTransaction::from_bytes(payload)?,
+            )),
+            COMMIT_TRANSACTION_CODE => Ok(ServerCommand::CommitTransaction(
+                CommitTransaction::from_bytes(payload)?,
+            )),
+            ABORT_TRANSACTION_CODE => Ok(ServerCommand::AbortTransaction(
+                AbortTransaction::from_bytes(payload)?,
+            )),
+            AGGREGATE_MESSAGES_CODE => Ok(ServerCommand::AggregateMessages(
+                AggregateMessages::from_bytes(payload)?,
+            )),
+            REPLAY_MESSAGES_CODE => Ok(ServerCommand::ReplayMessages(ReplayMessages::from_bytes(
+                payload,
+            )?)),
+            GET_REPLAY_JOBS_CODE => Ok(ServerCommand::GetReplayJobs(GetReplayJobs::from_bytes(
+                payload,
+            )?)),
+            CANCEL_REPLAY_JOB_CODE => Ok(ServerCommand::CancelReplayJob(
+                CancelReplayJob::from_bytes(payload)?,
+            )),
+            CREATE_PUSH_SUBSCRIPTION_CODE => Ok(ServerCommand::CreatePushSubscription(
+                CreatePushSubscription::from_bytes(payload)?,
+            )),
+            GET_PUSH_SUBSCRIPTIONS_CODE => Ok(ServerCommand::GetPushSubscriptions(
+                GetPushSubscriptions::from_bytes(payload)?,
+            )),
+            DELETE_PUSH_SUBSCRIPTION_CODE => Ok(ServerCommand::DeletePushSubscription(
+                DeletePushSubscription::from_bytes(payload)?,
+            )),
+            REGISTER_PRODUCER_CODE => Ok(ServerCommand::RegisterProducer(
+                RegisterProducer::from_bytes(payload)?,
+            )),
+            INIT_PRODUCER_ID_CODE => Ok(ServerCommand::InitProducerId(InitProducerId::from_bytes(
+                payload,
+            )?)),
             STORE_CONSUMER_OFFSET_CODE => Ok(ServerCommand::StoreConsumerOffset(
                 StoreConsumerOffset::from_bytes(payload)?,
             )),
+            STORE_CONSUMER_OFFSETS_CODE => Ok(ServerCommand::StoreConsumerOffsets(
+                StoreConsumerOffsets::from_bytes(payload)?,
+            )),
+            GET_OFFSETS_FOR_TIMESTAMPS_CODE => Ok(ServerCommand::GetOffsetsForTimestamps(
+                GetOffsetsForTimestamps::from_bytes(payload)?,
+            )),
             DELETE_CONSUMER_OFFSET_CODE => Ok(ServerCommand::DeleteConsumerOffset(
                 DeleteConsumerOffset::from_bytes(payload)?,
             )),
@@ -245,6 +742,12 @@ impl BytesSerializable for ServerCommand {
             PURGE_STREAM_CODE => Ok(ServerCommand::PurgeStream(PurgeStream::from_bytes(
                 payload,
             )?)),
+            UPDATE_STREAM_METADATA_CODE => Ok(ServerCommand::UpdateStreamMetadata(
+                UpdateStreamMetadata::from_bytes(payload)?,
+            )),
+            UPDATE_STREAM_QUOTA_CODE => Ok(ServerCommand::UpdateStreamQuota(
+                UpdateStreamQuota::from_bytes(payload)?,
+            )),
             GET_TOPIC_CODE => Ok(ServerCommand::GetTopic(GetTopic::from_bytes(payload)?)),
             GET_TOPICS_CODE => Ok(ServerCommand::GetTopics(GetTopics::from_bytes(payload)?)),
             CREATE_TOPIC_CODE => Ok(ServerCommand::CreateTopic(CreateTopic::from_bytes(
@@ -257,12 +760,36 @@ impl BytesSerializable for ServerCommand {
                 payload,
             )?)),
             PURGE_TOPIC_CODE => Ok(ServerCommand::PurgeTopic(PurgeTopic::from_bytes(payload)?)),
+            UPDATE_TOPIC_METADATA_CODE => Ok(ServerCommand::UpdateTopicMetadata(
+                UpdateTopicMetadata::from_bytes(payload)?,
+            )),
+            UPDATE_TOPIC_PRODUCERS_CODE => Ok(ServerCommand::UpdateTopicProducers(
+                UpdateTopicProducers::from_bytes(payload)?,
+            )),
+            UPDATE_TOPIC_CONFIG_CODE => Ok(ServerCommand::UpdateTopicConfig(
+                UpdateTopicConfig::from_bytes(payload)?,
+            )),
+            UPDATE_TOPIC_EXPIRY_WATCHER_CODE => Ok(ServerCommand::UpdateTopicExpiryWatcher(
+                UpdateTopicExpiryWatcher::from_bytes(payload)?,
+            )),
+            MARK_TOPIC_FOR_DELETION_CODE => Ok(ServerCommand::MarkTopicForDeletion(
+                MarkTopicForDeletion::from_bytes(payload)?,
+            )),
             CREATE_PARTITIONS_CODE => Ok(ServerCommand::CreatePartitions(
                 CreatePartitions::from_bytes(payload)?,
             )),
             DELETE_PARTITIONS_CODE => Ok(ServerCommand::DeletePartitions(
                 DeletePartitions::from_bytes(payload)?,
             )),
+            RESTORE_ARCHIVED_SEGMENTS_CODE => Ok(ServerCommand::RestoreArchivedSegments(
+                RestoreArchivedSegments::from_bytes(payload)?,
+            )),
+            FLUSH_PARTITION_CODE => Ok(ServerCommand::FlushPartition(FlushPartition::from_bytes(
+                payload,
+            )?)),
+            READ_SEGMENT_RANGE_CODE => Ok(ServerCommand::ReadSegmentRange(
+                ReadSegmentRange::from_bytes(payload)?,
+            )),
             GET_CONSUMER_GROUP_CODE => Ok(ServerCommand::GetConsumerGroup(
                 GetConsumerGroup::from_bytes(payload)?,
             )),
@@ -275,6 +802,25 @@ impl BytesSerializable for ServerCommand {
             DELETE_CONSUMER_GROUP_CODE => Ok(ServerCommand::DeleteConsumerGroup(
                 DeleteConsumerGroup::from_bytes(payload)?,
             )),
+            UPDATE_CONSUMER_GROUP_DEAD_LETTER_CODE => {
+                Ok(ServerCommand::UpdateConsumerGroupDeadLetter(
+                    UpdateConsumerGroupDeadLetter::from_bytes(payload)?,
+                ))
+            }
+            UPDATE_CONSUMER_GROUP_VISIBILITY_TIMEOUT_CODE => {
+                Ok(ServerCommand::UpdateConsumerGroupVisibilityTimeout(
+                    UpdateConsumerGroupVisibilityTimeout::from_bytes(payload)?,
+                ))
+            }
+            GET_PARTITION_STATE_CODE => Ok(ServerCommand::GetPartitionState(
+                GetPartitionState::from_bytes(payload)?,
+            )),
+            STORE_PARTITION_STATE_CODE => Ok(ServerCommand::StorePartitionState(
+                StorePartitionState::from_bytes(payload)?,
+            )),
+            DELETE_PARTITION_STATE_CODE => Ok(ServerCommand::DeletePartitionState(
+                DeletePartitionState::from_bytes(payload)?,
+            )),
             JOIN_CONSUMER_GROUP_CODE => Ok(ServerCommand::JoinConsumerGroup(
                 JoinConsumerGroup::from_bytes(payload)?,
             )),
@@ -284,6 +830,42 @@ impl BytesSerializable for ServerCommand {
             GET_SNAPSHOT_FILE_CODE => Ok(ServerCommand::GetSnapshotFile(GetSnapshot::from_bytes(
                 payload,
             )?)),
+            GET_MAINTENANCE_MODE_CODE => Ok(ServerCommand::GetMaintenanceMode(
+                GetMaintenanceMode::from_bytes(payload)?,
+            )),
+            GET_SERVER_INFO_CODE => Ok(ServerCommand::GetServerInfo(GetServerInfo::from_bytes(
+                payload,
+            )?)),
+            SET_MAINTENANCE_MODE_CODE => Ok(ServerCommand::SetMaintenanceMode(
+                SetMaintenanceMode::from_bytes(payload)?,
+            )),
+            CREATE_BACKUP_CODE => Ok(ServerCommand::CreateBackup(CreateBackup::from_bytes(
+                payload,
+            )?)),
+            GET_UNSAVED_STATE_CODE => Ok(ServerCommand::GetUnsavedState(
+                GetUnsavedState::from_bytes(payload)?,
+            )),
+            GET_AUDIT_LOG_CODE => Ok(ServerCommand::GetAuditLog(GetAuditLog::from_bytes(
+                payload,
+            )?)),
+            GET_ERROR_CATALOG_CODE => Ok(ServerCommand::GetErrorCatalog(
+                GetErrorCatalog::from_bytes(payload)?,
+            )),
+            GET_NAMESPACE_CODE => Ok(ServerCommand::GetNamespace(GetNamespace::from_bytes(
+                payload,
+            )?)),
+            GET_NAMESPACES_CODE => Ok(ServerCommand::GetNamespaces(GetNamespaces::from_bytes(
+                payload,
+            )?)),
+            CREATE_NAMESPACE_CODE => Ok(ServerCommand::CreateNamespace(
+                CreateNamespace::from_bytes(payload)?,
+            )),
+            UPDATE_NAMESPACE_CODE => Ok(ServerCommand::UpdateNamespace(
+                UpdateNamespace::from_bytes(payload)?,
+            )),
+            DELETE_NAMESPACE_CODE => Ok(ServerCommand::DeleteNamespace(
+                DeleteNamespace::from_bytes(payload)?,
+            )),
             _ => {
                 error!("Invalid server command: {code}");
                 Err(IggyError::InvalidCommand)
@@ -304,6 +886,7 @@ impl Validatable<IggyError> for ServerCommand {
     fn validate(&self) -> Result<(), IggyError> {
         match self {
             ServerCommand::Ping(command) => command.validate(),
+            ServerCommand::Handshake(command) => command.validate(),
             ServerCommand::GetStats(command) => command.validate(),
             ServerCommand::GetMe(command) => command.validate(),
             ServerCommand::GetClient(command) => command.validate(),
@@ -314,16 +897,22 @@ impl Validatable<IggyError> for ServerCommand {
             ServerCommand::DeleteUser(command) => command.validate(),
             ServerCommand::UpdateUser(command) => command.validate(),
             ServerCommand::UpdatePermissions(command) => command.validate(),
+            ServerCommand::UpdateUserQuotas(command) => command.validate(),
             ServerCommand::ChangePassword(command) => command.validate(),
             ServerCommand::LoginUser(command) => command.validate(),
             ServerCommand::LogoutUser(command) => command.validate(),
+            ServerCommand::LoginAs(command) => command.validate(),
             ServerCommand::GetPersonalAccessTokens(command) => command.validate(),
             ServerCommand::CreatePersonalAccessToken(command) => command.validate(),
             ServerCommand::DeletePersonalAccessToken(command) => command.validate(),
+            ServerCommand::RotatePersonalAccessToken(command) => command.validate(),
             ServerCommand::LoginWithPersonalAccessToken(command) => command.validate(),
             ServerCommand::SendMessages(command) => command.validate(),
+            ServerCommand::SendMessagesBatch(command) => command.validate(),
             ServerCommand::PollMessages(command) => command.validate(),
             ServerCommand::StoreConsumerOffset(command) => command.validate(),
+            ServerCommand::StoreConsumerOffsets(command) => command.validate(),
+            ServerCommand::GetOffsetsForTimestamps(command) => command.validate(),
             ServerCommand::DeleteConsumerOffset(command) => command.validate(),
             ServerCommand::GetConsumerOffset(command) => command.validate(),
             ServerCommand::GetStream(command) => command.validate(),
@@ -332,22 +921,63 @@ impl Validatable<IggyError> for ServerCommand {
             ServerCommand::DeleteStream(command) => command.validate(),
             ServerCommand::UpdateStream(command) => command.validate(),
             ServerCommand::PurgeStream(command) => command.validate(),
+            ServerCommand::UpdateStreamMetadata(command) => command.validate(),
+            ServerCommand::UpdateStreamQuota(command) => command.validate(),
             ServerCommand::GetTopic(command) => command.validate(),
             ServerCommand::GetTopics(command) => command.validate(),
             ServerCommand::CreateTopic(command) => command.validate(),
             ServerCommand::DeleteTopic(command) => command.validate(),
             ServerCommand::UpdateTopic(command) => command.validate(),
             ServerCommand::PurgeTopic(command) => command.validate(),
+            ServerCommand::UpdateTopicMetadata(command) => command.validate(),
+            ServerCommand::UpdateTopicProducers(command) => command.validate(),
+            ServerCommand::UpdateTopicConfig(command) => command.validate(),
+            ServerCommand::UpdateTopicExpiryWatcher(command) => command.validate(),
+            ServerCommand::MarkTopicForDeletion(command) => command.validate(),
             ServerCommand::CreatePartitions(command) => command.validate(),
             ServerCommand::DeletePartitions(command) => command.validate(),
+            ServerCommand::RestoreArchivedSegments(command) => command.validate(),
+            ServerCommand::FlushPartition(command) => command.validate(),
+            ServerCommand::ReadSegmentRange(command) => command.validate(),
             ServerCommand::GetConsumerGroup(command) => command.validate(),
             ServerCommand::GetConsumerGroups(command) => command.validate(),
             ServerCommand::CreateConsumerGroup(command) => command.validate(),
             ServerCommand::DeleteConsumerGroup(command) => command.validate(),
+            ServerCommand::UpdateConsumerGroupDeadLetter(command) => command.validate(),
+            ServerCommand::UpdateConsumerGroupVisibilityTimeout(command) => command.validate(),
             ServerCommand::JoinConsumerGroup(command) => command.validate(),
             ServerCommand::LeaveConsumerGroup(command) => command.validate(),
+            ServerCommand::GetPartitionState(command) => command.validate(),
+            ServerCommand::StorePartitionState(command) => command.validate(),
+            ServerCommand::DeletePartitionState(command) => command.validate(),
             ServerCommand::FlushUnsavedBuffer(command) => command.validate(),
+            ServerCommand::NackMessages(command) => command.validate(),
+            ServerCommand::AckMessages(command) => command.validate(),
+            ServerCommand::BeginTransaction(command) => command.validate(),
+            ServerCommand::CommitTransaction(command) => command.validate(),
+            ServerCommand::AbortTransaction(command) => command.validate(),
+            ServerCommand::AggregateMessages(command) => command.validate(),
+            ServerCommand::ReplayMessages(command) => command.validate(),
+            ServerCommand::GetReplayJobs(command) => command.validate(),
+            ServerCommand::CancelReplayJob(command) => command.validate(),
+            ServerCommand::CreatePushSubscription(command) => command.validate(),
+            ServerCommand::GetPushSubscriptions(command) => command.validate(),
+            ServerCommand::DeletePushSubscription(command) => command.validate(),
+            ServerCommand::RegisterProducer(command) => command.validate(),
+            ServerCommand::InitProducerId(command) => command.validate(),
             ServerCommand::GetSnapshotFile(command) => command.validate(),
+            ServerCommand::GetMaintenanceMode(command) => command.validate(),
+            ServerCommand::GetServerInfo(command) => command.validate(),
+            ServerCommand::SetMaintenanceMode(command) => command.validate(),
+            ServerCommand::CreateBackup(command) => command.validate(),
+            ServerCommand::GetUnsavedState(command) => command.validate(),
+            ServerCommand::GetAuditLog(command) => command.validate(),
+            ServerCommand::GetErrorCatalog(command) => command.validate(),
+            ServerCommand::GetNamespace(command) => command.validate(),
+            ServerCommand::GetNamespaces(command) => command.validate(),
+            ServerCommand::CreateNamespace(command) => command.validate(),
+            ServerCommand::UpdateNamespace(command) => command.validate(),
+            ServerCommand::DeleteNamespace(command) => command.validate(),
         }
     }
 }
@@ -356,6 +986,7 @@ impl Display for ServerCommand {
     fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
             ServerCommand::Ping(_) => write!(formatter, "{PING}"),
+            ServerCommand::Handshake(payload) => write!(formatter, "{HANDSHAKE}|{payload}"),
             ServerCommand::GetStats(_) => write!(formatter, "{GET_STATS}"),
             ServerCommand::GetMe(_) => write!(formatter, "{GET_ME}"),
             ServerCommand::GetClient(payload) => write!(formatter, "{GET_CLIENT}|{payload}"),
@@ -368,11 +999,15 @@ impl Display for ServerCommand {
             ServerCommand::UpdatePermissions(payload) => {
                 write!(formatter, "{UPDATE_PERMISSIONS}|{payload}")
             }
+            ServerCommand::UpdateUserQuotas(payload) => {
+                write!(formatter, "{UPDATE_USER_QUOTAS}|{payload}")
+            }
             ServerCommand::ChangePassword(payload) => {
                 write!(formatter, "{CHANGE_PASSWORD}|{payload}")
             }
             ServerCommand::LoginUser(payload) => write!(formatter, "{LOGIN_USER}|{payload}"),
             ServerCommand::LogoutUser(_) => write!(formatter, "{LOGOUT_USER}"),
+            ServerCommand::LoginAs(payload) => write!(formatter, "{LOGIN_AS}|{payload}"),
             ServerCommand::GetPersonalAccessTokens(_) => {
                 write!(formatter, "{GET_PERSONAL_ACCESS_TOKENS}")
             }
@@ -382,6 +1017,9 @@ impl Display for ServerCommand {
             ServerCommand::DeletePersonalAccessToken(payload) => {
                 write!(formatter, "{DELETE_PERSONAL_ACCESS_TOKEN}|{payload}")
             }
+            ServerCommand::RotatePersonalAccessToken(payload) => {
+                write!(formatter, "{ROTATE_PERSONAL_ACCESS_TOKEN}|{payload}")
+            }
             ServerCommand::LoginWithPersonalAccessToken(payload) => {
                 write!(formatter, "{LOGIN_WITH_PERSONAL_ACCESS_TOKEN}|{payload}")
             }
@@ -391,23 +1029,62 @@ impl Display for ServerCommand {
             ServerCommand::DeleteStream(payload) => write!(formatter, "{DELETE_STREAM}|{payload}"),
             ServerCommand::UpdateStream(payload) => write!(formatter, "{UPDATE_STREAM}|{payload}"),
             ServerCommand::PurgeStream(payload) => write!(formatter, "{PURGE_STREAM}|{payload}"),
+            ServerCommand::UpdateStreamMetadata(payload) => {
+                write!(formatter, "{UPDATE_STREAM_METADATA}|{payload}")
+            }
+            ServerCommand::UpdateStreamQuota(payload) => {
+                write!(formatter, "{UPDATE_STREAM_QUOTA}|{payload}")
+            }
             ServerCommand::GetTopic(payload) => write!(formatter, "{GET_TOPIC}|{payload}"),
             ServerCommand::GetTopics(payload) => write!(formatter, "{GET_TOPICS}|{payload}"),
             ServerCommand::CreateTopic(payload) => write!(formatter, "{CREATE_TOPIC}|{payload}"),
             ServerCommand::DeleteTopic(payload) => write!(formatter, "{DELETE_TOPIC}|{payload}"),
             ServerCommand::UpdateTopic(payload) => write!(formatter, "{UPDATE_TOPIC}|{payload}"),
             ServerCommand::PurgeTopic(payload) => write!(formatter, "{PURGE_TOPIC}|{payload}"),
+            ServerCommand::UpdateTopicMetadata(payload) => {
+                write!(formatter, "{UPDATE_TOPIC_METADATA}|{payload}")
+            }
+            ServerCommand::UpdateTopicProducers(payload) => {
+                write!(formatter, "{UPDATE_TOPIC_PRODUCERS}|{payload}")
+            }
+            ServerCommand::UpdateTopicConfig(payload) => {
+                write!(formatter, "{UPDATE_TOPIC_CONFIG}|{payload}")
+            }
+            ServerCommand::UpdateTopicExpiryWatcher(payload) => {
+                write!(formatter, "{UPDATE_TOPIC_EXPIRY_WATCHER}|{payload}")
+            }
+            ServerCommand::MarkTopicForDeletion(payload) => {
+                write!(formatter, "{MARK_TOPIC_FOR_DELETION}|{payload}")
+            }
             ServerCommand::CreatePartitions(payload) => {
                 write!(formatter, "{CREATE_PARTITIONS}|{payload}")
             }
             ServerCommand::DeletePartitions(payload) => {
                 write!(formatter, "{DELETE_PARTITIONS}|{payload}")
             }
+            ServerCommand::RestoreArchivedSegments(payload) => {
+                write!(formatter, "{RESTORE_ARCHIVED_SEGMENTS}|{payload}")
+            }
+            ServerCommand::FlushPartition(payload) => {
+                write!(formatter, "{FLUSH_PARTITION}|{payload}")
+            }
+            ServerCommand::ReadSegmentRange(payload) => {
+                write!(formatter, "{READ_SEGMENT_RANGE}|{payload}")
+            }
             ServerCommand::PollMessages(payload) => write!(formatter, "{POLL_MESSAGES}|{payload}"),
             ServerCommand::SendMessages(payload) => write!(formatter, "{SEND_MESSAGES}|{payload}"),
+            ServerCommand::SendMessagesBatch(payload) => {
+                write!(formatter, "{SEND_MESSAGES_BATCH}|{payload}")
+            }
             ServerCommand::StoreConsumerOffset(payload) => {
                 write!(formatter, "{STORE_CONSUMER_OFFSET}|{payload}")
             }
+            ServerCommand::StoreConsumerOffsets(payload) => {
+                write!(formatter, "{STORE_CONSUMER_OFFSETS}|{payload}")
+            }
+            ServerCommand::GetOffsetsForTimestamps(payload) => {
+                write!(formatter, "{GET_OFFSETS_FOR_TIMESTAMPS}|{payload}")
+            }
             ServerCommand::DeleteConsumerOffset(payload) => {
                 write!(formatter, "{DELETE_CONSUMER_OFFSET}|{payload}")
             }
@@ -426,18 +1103,100 @@ impl Display for ServerCommand {
             ServerCommand::DeleteConsumerGroup(payload) => {
                 write!(formatter, "{DELETE_CONSUMER_GROUP}|{payload}")
             }
+            ServerCommand::UpdateConsumerGroupDeadLetter(payload) => {
+                write!(formatter, "{UPDATE_CONSUMER_GROUP_DEAD_LETTER}|{payload}")
+            }
+            ServerCommand::UpdateConsumerGroupVisibilityTimeout(payload) => {
+                write!(
+                    formatter,
+                    "{UPDATE_CONSUMER_GROUP_VISIBILITY_TIMEOUT}|{payload}"
+                )
+            }
             ServerCommand::JoinConsumerGroup(payload) => {
                 write!(formatter, "{JOIN_CONSUMER_GROUP}|{payload}")
             }
             ServerCommand::LeaveConsumerGroup(payload) => {
                 write!(formatter, "{LEAVE_CONSUMER_GROUP}|{payload}")
             }
+            ServerCommand::GetPartitionState(payload) => {
+                write!(formatter, "{GET_PARTITION_STATE}|{payload}")
+            }
+            ServerCommand::StorePartitionState(payload) => {
+                write!(formatter, "{STORE_PARTITION_STATE}|{payload}")
+            }
+            ServerCommand::DeletePartitionState(payload) => {
+                write!(formatter, "{DELETE_PARTITION_STATE}|{payload}")
+            }
             ServerCommand::FlushUnsavedBuffer(payload) => {
                 write!(formatter, "{FLUSH_UNSAVED_BUFFER}|{payload}")
             }
+            ServerCommand::NackMessages(payload) => {
+                write!(formatter, "{NACK_MESSAGES}|{payload}")
+            }
+            ServerCommand::AckMessages(payload) => {
+                write!(formatter, "{ACK_MESSAGES}|{payload}")
+            }
+            ServerCommand::BeginTransaction(_) => write!(formatter, "{BEGIN_TRANSACTION}"),
+            ServerCommand::CommitTransaction(payload) => {
+                write!(formatter, "{COMMIT_TRANSACTION}|{payload}")
+            }
+            ServerCommand::AbortTransaction(payload) => {
+                write!(formatter, "{ABORT_TRANSACTION}|{payload}")
+            }
+            ServerCommand::AggregateMessages(payload) => {
+                write!(formatter, "{AGGREGATE_MESSAGES}|{payload}")
+            }
+            ServerCommand::ReplayMessages(payload) => {
+                write!(formatter, "{REPLAY_MESSAGES}|{payload}")
+            }
+            ServerCommand::GetReplayJobs(_) => write!(formatter, "{GET_REPLAY_JOBS}"),
+            ServerCommand::CancelReplayJob(payload) => {
+                write!(formatter, "{CANCEL_REPLAY_JOB}|{payload}")
+            }
+            ServerCommand::CreatePushSubscription(payload) => {
+                write!(formatter, "{CREATE_PUSH_SUBSCRIPTION}|{payload}")
+            }
+            ServerCommand::GetPushSubscriptions(_) => {
+                write!(formatter, "{GET_PUSH_SUBSCRIPTIONS}")
+            }
+            ServerCommand::DeletePushSubscription(payload) => {
+                write!(formatter, "{DELETE_PUSH_SUBSCRIPTION}|{payload}")
+            }
+            ServerCommand::RegisterProducer(payload) => {
+                write!(formatter, "{REGISTER_PRODUCER}|{payload}")
+            }
+            ServerCommand::InitProducerId(payload) => {
+                write!(formatter, "{INIT_PRODUCER_ID}|{payload}")
+            }
             ServerCommand::GetSnapshotFile(payload) => {
                 write!(formatter, "{GET_SNAPSHOT_FILE}|{payload}")
             }
+            ServerCommand::GetMaintenanceMode(_) => write!(formatter, "{GET_MAINTENANCE_MODE}"),
+            ServerCommand::GetServerInfo(_) => write!(formatter, "{GET_SERVER_INFO}"),
+            ServerCommand::SetMaintenanceMode(payload) => {
+                write!(formatter, "{SET_MAINTENANCE_MODE}|{payload}")
+            }
+            ServerCommand::CreateBackup(payload) => {
+                write!(formatter, "{CREATE_BACKUP}|{payload}")
+            }
+            ServerCommand::GetUnsavedState(_) => write!(formatter, "{GET_UNSAVED_STATE}"),
+            ServerCommand::GetAuditLog(payload) => {
+                write!(formatter, "{GET_AUDIT_LOG}|{payload}")
+            }
+            ServerCommand::GetErrorCatalog(_) => write!(formatter, "{GET_ERROR_CATALOG}"),
+            ServerCommand::GetNamespace(payload) => {
+                write!(formatter, "{GET_NAMESPACE}|{payload}")
+            }
+            ServerCommand::GetNamespaces(_) => write!(formatter, "{GET_NAMESPACES}"),
+            ServerCommand::CreateNamespace(payload) => {
+                write!(formatter, "{CREATE_NAMESPACE}|{payload}")
+            }
+            ServerCommand::UpdateNamespace(payload) => {
+                write!(formatter, "{UPDATE_NAMESPACE}|{payload}")
+            }
+            ServerCommand::DeleteNamespace(payload) => {
+                write!(formatter, "{DELETE_NAMESPACE}|{payload}")
+            }
         }
     }
 }
@@ -453,6 +1212,57 @@ mod tests {
             PING_CODE,
             &Ping::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::Handshake(Handshake {
+                resource_metadata: true,
+                remaining_messages: true,
+                message_id_scheme: true,
+                consumer_group_rebalances: true,
+                stream_quota: true,
+                cleanup_policy: true,
+                message_gaps: true,
+                consumer_group_assignment: true,
+                allowed_producers: true,
+                offset_recovery: true,
+                dead_letter: true,
+                frame_checksums: true,
+                visibility_timeout: true,
+                throttle_time: true,
+                topic_deletion_time: true,
+                max_segments: true,
+                partition_recovery: true,
+                topic_config: true,
+                message_sizes: true,
+                token_scope: true,
+                token_last_used: true,
+                error_categories: true,
+            }),
+            HANDSHAKE_CODE,
+            &Handshake {
+                resource_metadata: true,
+                remaining_messages: true,
+                message_id_scheme: true,
+                consumer_group_rebalances: true,
+                stream_quota: true,
+                cleanup_policy: true,
+                message_gaps: true,
+                consumer_group_assignment: true,
+                allowed_producers: true,
+                offset_recovery: true,
+                dead_letter: true,
+                frame_checksums: true,
+                visibility_timeout: true,
+                throttle_time: true,
+                topic_deletion_time: true,
+                max_segments: true,
+                partition_recovery: true,
+                topic_config: true,
+                message_sizes: true,
+                token_scope: true,
+                token_last_used: true,
+                error_categories: true,
+            },
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &ServerCommand::GetStats(GetStats::default()),
             GET_STATS_CODE,
@@ -503,6 +1313,11 @@ mod tests {
             UPDATE_PERMISSIONS_CODE,
             &UpdatePermissions::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::UpdateUserQuotas(UpdateUserQuotas::default()),
+            UPDATE_USER_QUOTAS_CODE,
+            &UpdateUserQuotas::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &ServerCommand::ChangePassword(ChangePassword::default()),
             CHANGE_PASSWORD_CODE,
@@ -518,6 +1333,11 @@ mod tests {
             LOGOUT_USER_CODE,
             &LogoutUser::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::LoginAs(LoginAs::default()),
+            LOGIN_AS_CODE,
+            &LoginAs::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &ServerCommand::GetPersonalAccessTokens(GetPersonalAccessTokens::default()),
             GET_PERSONAL_ACCESS_TOKENS_CODE,
@@ -533,6 +1353,11 @@ mod tests {
             DELETE_PERSONAL_ACCESS_TOKEN_CODE,
             &DeletePersonalAccessToken::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::RotatePersonalAccessToken(RotatePersonalAccessToken::default()),
+            ROTATE_PERSONAL_ACCESS_TOKEN_CODE,
+            &RotatePersonalAccessToken::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &ServerCommand::LoginWithPersonalAccessToken(LoginWithPersonalAccessToken::default()),
             LOGIN_WITH_PERSONAL_ACCESS_TOKEN_CODE,
@@ -543,6 +1368,11 @@ mod tests {
             SEND_MESSAGES_CODE,
             &SendMessages::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::SendMessagesBatch(SendMessagesBatch::default()),
+            SEND_MESSAGES_BATCH_CODE,
+            &SendMessagesBatch::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &ServerCommand::PollMessages(PollMessages::default()),
             POLL_MESSAGES_CODE,
@@ -553,6 +1383,16 @@ mod tests {
             STORE_CONSUMER_OFFSET_CODE,
             &StoreConsumerOffset::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::StoreConsumerOffsets(StoreConsumerOffsets::default()),
+            STORE_CONSUMER_OFFSETS_CODE,
+            &StoreConsumerOffsets::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetOffsetsForTimestamps(GetOffsetsForTimestamps::default()),
+            GET_OFFSETS_FOR_TIMESTAMPS_CODE,
+            &GetOffsetsForTimestamps::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &ServerCommand::GetConsumerOffset(GetConsumerOffset::default()),
             GET_CONSUMER_OFFSET_CODE,
@@ -588,6 +1428,16 @@ mod tests {
             PURGE_STREAM_CODE,
             &PurgeStream::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::UpdateStreamMetadata(UpdateStreamMetadata::default()),
+            UPDATE_STREAM_METADATA_CODE,
+            &UpdateStreamMetadata::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::UpdateStreamQuota(UpdateStreamQuota::default()),
+            UPDATE_STREAM_QUOTA_CODE,
+            &UpdateStreamQuota::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &ServerCommand::GetTopic(GetTopic::default()),
             GET_TOPIC_CODE,
@@ -618,6 +1468,31 @@ mod tests {
             PURGE_TOPIC_CODE,
             &PurgeTopic::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::UpdateTopicMetadata(UpdateTopicMetadata::default()),
+            UPDATE_TOPIC_METADATA_CODE,
+            &UpdateTopicMetadata::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::UpdateTopicProducers(UpdateTopicProducers::default()),
+            UPDATE_TOPIC_PRODUCERS_CODE,
+            &UpdateTopicProducers::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::UpdateTopicConfig(UpdateTopicConfig::default()),
+            UPDATE_TOPIC_CONFIG_CODE,
+            &UpdateTopicConfig::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::UpdateTopicExpiryWatcher(UpdateTopicExpiryWatcher::default()),
+            UPDATE_TOPIC_EXPIRY_WATCHER_CODE,
+            &UpdateTopicExpiryWatcher::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::MarkTopicForDeletion(MarkTopicForDeletion::default()),
+            MARK_TOPIC_FOR_DELETION_CODE,
+            &MarkTopicForDeletion::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &ServerCommand::CreatePartitions(CreatePartitions::default()),
             CREATE_PARTITIONS_CODE,
@@ -628,6 +1503,21 @@ mod tests {
             DELETE_PARTITIONS_CODE,
             &DeletePartitions::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::RestoreArchivedSegments(RestoreArchivedSegments::default()),
+            RESTORE_ARCHIVED_SEGMENTS_CODE,
+            &RestoreArchivedSegments::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::FlushPartition(FlushPartition::default()),
+            FLUSH_PARTITION_CODE,
+            &FlushPartition::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::ReadSegmentRange(ReadSegmentRange::default()),
+            READ_SEGMENT_RANGE_CODE,
+            &ReadSegmentRange::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &ServerCommand::GetConsumerGroup(GetConsumerGroup::default()),
             GET_CONSUMER_GROUP_CODE,
@@ -648,6 +1538,33 @@ mod tests {
             DELETE_CONSUMER_GROUP_CODE,
             &DeleteConsumerGroup::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::UpdateConsumerGroupDeadLetter(UpdateConsumerGroupDeadLetter::default()),
+            UPDATE_CONSUMER_GROUP_DEAD_LETTER_CODE,
+            &UpdateConsumerGroupDeadLetter::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::UpdateConsumerGroupVisibilityTimeout(
+                UpdateConsumerGroupVisibilityTimeout::default(),
+            ),
+            UPDATE_CONSUMER_GROUP_VISIBILITY_TIMEOUT_CODE,
+            &UpdateConsumerGroupVisibilityTimeout::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetPartitionState(GetPartitionState::default()),
+            GET_PARTITION_STATE_CODE,
+            &GetPartitionState::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::StorePartitionState(StorePartitionState::default()),
+            STORE_PARTITION_STATE_CODE,
+            &StorePartitionState::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::DeletePartitionState(DeletePartitionState::default()),
+            DELETE_PARTITION_STATE_CODE,
+            &DeletePartitionState::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &ServerCommand::JoinConsumerGroup(JoinConsumerGroup::default()),
             JOIN_CONSUMER_GROUP_CODE,
@@ -663,6 +1580,176 @@ mod tests {
             FLUSH_UNSAVED_BUFFER_CODE,
             &FlushUnsavedBuffer::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::NackMessages(NackMessages::default()),
+            NACK_MESSAGES_CODE,
+            &NackMessages::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::AckMessages(AckMessages::default()),
+            ACK_MESSAGES_CODE,
+            &AckMessages::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::BeginTransaction(BeginTransaction::default()),
+            BEGIN_TRANSACTION_CODE,
+            &BeginTransaction::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::CommitTransaction(CommitTransaction::default()),
+            COMMIT_TRANSACTION_CODE,
+            &CommitTransaction::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::AbortTransaction(AbortTransaction::default()),
+            ABORT_TRANSACTION_CODE,
+            &AbortTransaction::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::AggregateMessages(AggregateMessages::default()),
+            AGGREGATE_MESSAGES_CODE,
+            &AggregateMessages::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::ReplayMessages(ReplayMessages::default()),
+            REPLAY_MESSAGES_CODE,
+            &ReplayMessages::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetReplayJobs(GetReplayJobs::default()),
+            GET_REPLAY_JOBS_CODE,
+            &GetReplayJobs::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::CancelReplayJob(CancelReplayJob::default()),
+            CANCEL_REPLAY_JOB_CODE,
+            &CancelReplayJob::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::CreatePushSubscription(CreatePushSubscription::default()),
+            CREATE_PUSH_SUBSCRIPTION_CODE,
+            &CreatePushSubscription::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetPushSubscriptions(GetPushSubscriptions::default()),
+            GET_PUSH_SUBSCRIPTIONS_CODE,
+            &GetPushSubscriptions::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::DeletePushSubscription(DeletePushSubscription::default()),
+            DELETE_PUSH_SUBSCRIPTION_CODE,
+            &DeletePushSubscription::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::RegisterProducer(RegisterProducer::default()),
+            REGISTER_PRODUCER_CODE,
+            &RegisterProducer::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::InitProducerId(InitProducerId::default()),
+            INIT_PRODUCER_ID_CODE,
+            &InitProducerId::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetMaintenanceMode(GetMaintenanceMode::default()),
+            GET_MAINTENANCE_MODE_CODE,
+            &GetMaintenanceMode::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetServerInfo(GetServerInfo::default()),
+            GET_SERVER_INFO_CODE,
+            &GetServerInfo::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::SetMaintenanceMode(SetMaintenanceMode::default()),
+            SET_MAINTENANCE_MODE_CODE,
+            &SetMaintenanceMode::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::CreateBackup(CreateBackup::default()),
+            CREATE_BACKUP_CODE,
+            &CreateBackup::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetUnsavedState(GetUnsavedState::default()),
+            GET_UNSAVED_STATE_CODE,
+            &GetUnsavedState::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetAuditLog(GetAuditLog::default()),
+            GET_AUDIT_LOG_CODE,
+            &GetAuditLog::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetErrorCatalog(GetErrorCatalog::default()),
+            GET_ERROR_CATALOG_CODE,
+            &GetErrorCatalog::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetNamespace(GetNamespace::default()),
+            GET_NAMESPACE_CODE,
+            &GetNamespace::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetNamespaces(GetNamespaces::default()),
+            GET_NAMESPACES_CODE,
+            &GetNamespaces::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::CreateNamespace(CreateNamespace::default()),
+            CREATE_NAMESPACE_CODE,
+            &CreateNamespace::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::UpdateNamespace(UpdateNamespace::default()),
+            UPDATE_NAMESPACE_CODE,
+            &UpdateNamespace::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::DeleteNamespace(DeleteNamespace::default()),
+            DELETE_NAMESPACE_CODE,
+            &DeleteNamespace::default(),
+        );
+    }
+
+    #[test]
+    fn only_non_modifying_commands_should_be_read_only() {
+        assert!(ServerCommand::Ping(Ping::default()).is_read_only());
+        assert!(ServerCommand::GetStreams(GetStreams::default()).is_read_only());
+        assert!(ServerCommand::PollMessages(PollMessages::default()).is_read_only());
+        assert!(!ServerCommand::CreateStream(CreateStream::default()).is_read_only());
+        assert!(!ServerCommand::SendMessages(SendMessages::default()).is_read_only());
+        assert!(!ServerCommand::SendMessagesBatch(SendMessagesBatch::default()).is_read_only());
+        assert!(!ServerCommand::StoreConsumerOffset(StoreConsumerOffset::default()).is_read_only());
+        assert!(
+            !ServerCommand::StoreConsumerOffsets(StoreConsumerOffsets::default()).is_read_only()
+        );
+    }
+
+    #[test]
+    fn only_session_and_maintenance_commands_should_be_allowed_in_maintenance() {
+        assert!(ServerCommand::Ping(Ping::default()).is_allowed_in_maintenance());
+        assert!(ServerCommand::Handshake(Handshake::default()).is_allowed_in_maintenance());
+        assert!(ServerCommand::LoginUser(LoginUser::default()).is_allowed_in_maintenance());
+        assert!(
+            ServerCommand::SetMaintenanceMode(SetMaintenanceMode::default())
+                .is_allowed_in_maintenance()
+        );
+        assert!(ServerCommand::CreateBackup(CreateBackup::default()).is_allowed_in_maintenance());
+        assert!(
+            ServerCommand::FlushPartition(FlushPartition::default()).is_allowed_in_maintenance()
+        );
+        assert!(!ServerCommand::PollMessages(PollMessages::default()).is_allowed_in_maintenance());
+        assert!(!ServerCommand::SendMessages(SendMessages::default()).is_allowed_in_maintenance());
+    }
+
+    #[test]
+    fn only_state_changing_commands_should_be_replicated() {
+        assert!(ServerCommand::CreateStream(CreateStream::default()).is_replicated());
+        assert!(ServerCommand::CreateUser(CreateUser::default()).is_replicated());
+        assert!(!ServerCommand::GetStreams(GetStreams::default()).is_replicated());
+        assert!(!ServerCommand::SendMessages(SendMessages::default()).is_replicated());
+        assert!(!ServerCommand::LoginUser(LoginUser::default()).is_replicated());
     }
 
     fn assert_serialized_as_bytes_and_deserialized_from_bytes(
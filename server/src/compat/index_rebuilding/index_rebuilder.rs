@@ -18,7 +18,8 @@
 
 use crate::streaming::utils::file;
 use crate::{
-    server_error::CompatError, streaming::batching::message_batch::RETAINED_BATCH_HEADER_LEN,
+    server_error::CompatError,
+    streaming::batching::message_batch::{parse_batch_length, RETAINED_BATCH_HEADER_LEN},
 };
 use std::io::SeekFrom;
 use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt, BufReader, BufWriter};
@@ -50,7 +51,11 @@ impl IndexRebuilder {
         reader: &mut BufReader<tokio::fs::File>,
     ) -> Result<BatchHeader, std::io::Error> {
         let base_offset = reader.read_u64_le().await?;
-        let length = reader.read_u32_le().await?;
+        // The compressed batches keep the algorithm code in the highest bits of the length.
+        // The length of the batches written in the segment format version 2 includes their checksum.
+        let length = parse_batch_length(reader.read_u32_le().await?)
+            .map_err(|error| std::io::Error::new(std::io::ErrorKind::InvalidData, error))?
+            .length;
         let last_offset_delta = reader.read_u32_le().await?;
         let max_timestamp = reader.read_u64_le().await?;
 
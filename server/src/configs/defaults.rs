@@ -19,22 +19,34 @@
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::duration::IggyDuration;
 
+use crate::configs::grpc::GrpcConfig;
 use crate::configs::http::{
-    HttpConfig, HttpCorsConfig, HttpJwtConfig, HttpMetricsConfig, HttpTlsConfig,
+    HttpConfig, HttpCorsConfig, HttpJwtConfig, HttpMetricsConfig, HttpOidcConfig, HttpTlsConfig,
 };
+use crate::configs::limits::TransportLimitsConfig;
+use crate::configs::mqtt::MqttBridgeConfig;
 use crate::configs::quic::{QuicCertificateConfig, QuicConfig};
 use crate::configs::server::{
-    ArchiverConfig, DataMaintenanceConfig, HeartbeatConfig, MessageSaverConfig,
-    MessagesMaintenanceConfig, PersonalAccessTokenCleanerConfig, PersonalAccessTokenConfig,
-    ServerConfig, StateMaintenanceConfig, TelemetryConfig, TelemetryLogsConfig,
-    TelemetryTracesConfig,
+    ArchiverConfig, CompactionMaintenanceConfig, DataMaintenanceConfig, HeartbeatConfig,
+    MessageSaverConfig, MessagesMaintenanceConfig, PersonalAccessTokenCleanerConfig,
+    PersonalAccessTokenConfig, PersonalAccessTokenLoginGuardConfig, PreflightConfig, ServerConfig,
+    StateMaintenanceConfig, TelemetryConfig, TelemetryLogsConfig, TelemetryMetricsConfig,
+    TelemetryTracesConfig, TieredStorageConfig, TopicSnapshotsMaintenanceConfig,
 };
 use crate::configs::system::{
-    BackupConfig, CacheConfig, CompatibilityConfig, CompressionConfig, EncryptionConfig,
-    LoggingConfig, MessageDeduplicationConfig, PartitionConfig, RecoveryConfig, RuntimeConfig,
-    SegmentConfig, StateConfig, StreamConfig, SystemConfig, TopicConfig,
+    AuditLogConfig, AuthenticationConfig, BackupConfig, CacheConfig, CacheWarmupConfig,
+    ClusterConfig, CompatibilityConfig, CompressionConfig, ConsumerOffsetsConfig,
+    DynamicLibraryAuthenticatorConfig, EncryptionConfig, ExpiryNotificationsConfig,
+    FetchQuotasConfig, GrpcAuthenticatorConfig, IoUringConfig, LogConsumerOffsetsConfig,
+    LoggingConfig, MessageAuditConfig, MessageDeduplicationConfig, MessageIdConfig,
+    MetadataChangesConfig, MtlsAuthenticatorConfig, OidcAuthenticatorConfig, PartitionConfig,
+    PushSubscriptionsConfig, QuotasConfig, ReadAheadConfig, RecoveryConfig,
+    RedisConsumerOffsetsConfig, ReplayConfig, ResourceLimitsConfig, RuntimeConfig, SegmentConfig,
+    StateConfig, StreamConfig, SystemConfig, TopicConfig, TransactionsConfig,
 };
-use crate::configs::tcp::{TcpConfig, TcpTlsConfig};
+use crate::configs::tcp::{TcpConfig, TcpHeartbeatConfig, TcpTlsConfig};
+use crate::configs::uds::UdsConfig;
+use crate::configs::websocket::WebSocketConfig;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -50,11 +62,16 @@ impl Default for ServerConfig {
         ServerConfig {
             data_maintenance: DataMaintenanceConfig::default(),
             heartbeat: HeartbeatConfig::default(),
+            preflight: PreflightConfig::default(),
             message_saver: MessageSaverConfig::default(),
             personal_access_token: PersonalAccessTokenConfig::default(),
             system: Arc::new(SystemConfig::default()),
             quic: QuicConfig::default(),
             tcp: TcpConfig::default(),
+            uds: UdsConfig::default(),
+            websocket: WebSocketConfig::default(),
+            grpc: GrpcConfig::default(),
+            mqtt: MqttBridgeConfig::default(),
             http: HttpConfig::default(),
             telemetry: TelemetryConfig::default(),
         }
@@ -73,6 +90,8 @@ impl Default for ArchiverConfig {
                 .unwrap(),
             disk: None,
             s3: None,
+            gcs: None,
+            azure: None,
         }
     }
 }
@@ -107,6 +126,53 @@ impl Default for StateMaintenanceConfig {
     }
 }
 
+impl Default for TopicSnapshotsMaintenanceConfig {
+    fn default() -> TopicSnapshotsMaintenanceConfig {
+        TopicSnapshotsMaintenanceConfig {
+            enabled: SERVER_CONFIG.data_maintenance.topic_snapshots.enabled,
+            max_messages: SERVER_CONFIG.data_maintenance.topic_snapshots.max_messages as u32,
+            interval: SERVER_CONFIG
+                .data_maintenance
+                .topic_snapshots
+                .interval
+                .parse()
+                .unwrap(),
+        }
+    }
+}
+
+impl Default for TieredStorageConfig {
+    fn default() -> TieredStorageConfig {
+        TieredStorageConfig {
+            enabled: SERVER_CONFIG.data_maintenance.tiered_storage.enabled,
+            cache_path: SERVER_CONFIG
+                .data_maintenance
+                .tiered_storage
+                .cache_path
+                .parse()
+                .unwrap(),
+            max_cached_segments: SERVER_CONFIG
+                .data_maintenance
+                .tiered_storage
+                .max_cached_segments as u32,
+        }
+    }
+}
+
+impl Default for CompactionMaintenanceConfig {
+    fn default() -> CompactionMaintenanceConfig {
+        CompactionMaintenanceConfig {
+            enabled: SERVER_CONFIG.data_maintenance.compaction.enabled,
+            interval: SERVER_CONFIG
+                .data_maintenance
+                .compaction
+                .interval
+                .parse()
+                .unwrap(),
+        }
+    }
+}
+
 impl Default for QuicConfig {
     fn default() -> QuicConfig {
         QuicConfig {
@@ -124,6 +190,11 @@ impl Default for QuicConfig {
             keep_alive_interval: SERVER_CONFIG.quic.keep_alive_interval.parse().unwrap(),
             max_idle_timeout: SERVER_CONFIG.quic.max_idle_timeout.parse().unwrap(),
             certificate: QuicCertificateConfig::default(),
+            limits: TransportLimitsConfig {
+                max_connections: SERVER_CONFIG.quic.limits.max_connections as u32,
+                max_in_flight_requests: SERVER_CONFIG.quic.limits.max_in_flight_requests as u32,
+                max_frame_size: SERVER_CONFIG.quic.limits.max_frame_size.parse().unwrap(),
+            },
         }
     }
 }
@@ -134,6 +205,25 @@ impl Default for QuicCertificateConfig {
             self_signed: SERVER_CONFIG.quic.certificate.self_signed,
             cert_file: SERVER_CONFIG.quic.certificate.cert_file.parse().unwrap(),
             key_file: SERVER_CONFIG.quic.certificate.key_file.parse().unwrap(),
+            reload_interval: SERVER_CONFIG
+                .quic
+                .certificate
+                .reload_interval
+                .parse()
+                .unwrap(),
+            reconnect_grace_period: SERVER_CONFIG
+                .quic
+                .certificate
+                .reconnect_grace_period
+                .parse()
+                .unwrap(),
+            client_ca_file: SERVER_CONFIG
+                .quic
+                .certificate
+                .client_ca_file
+                .parse()
+                .unwrap(),
+            require_client_certificate: SERVER_CONFIG.quic.certificate.require_client_certificate,
         }
     }
 }
@@ -144,8 +234,89 @@ impl Default for TcpConfig {
             enabled: SERVER_CONFIG.tcp.enabled,
             address: SERVER_CONFIG.tcp.address.parse().unwrap(),
             ipv6: SERVER_CONFIG.tcp.ipv_6,
+            acceptors: SERVER_CONFIG.tcp.acceptors as u32,
+            zero_copy: SERVER_CONFIG.tcp.zero_copy,
             tls: TcpTlsConfig::default(),
             socket: TcpSocketConfig::default(),
+            heartbeat: TcpHeartbeatConfig::default(),
+            limits: TransportLimitsConfig {
+                max_connections: SERVER_CONFIG.tcp.limits.max_connections as u32,
+                max_in_flight_requests: SERVER_CONFIG.tcp.limits.max_in_flight_requests as u32,
+                max_frame_size: SERVER_CONFIG.tcp.limits.max_frame_size.parse().unwrap(),
+            },
+        }
+    }
+}
+
+impl Default for UdsConfig {
+    fn default() -> UdsConfig {
+        UdsConfig {
+            enabled: SERVER_CONFIG.uds.enabled,
+            path: SERVER_CONFIG.uds.path.parse().unwrap(),
+            permissions: SERVER_CONFIG.uds.permissions as u32,
+            trusted_username: SERVER_CONFIG.uds.trusted_username.parse().unwrap(),
+            limits: TransportLimitsConfig {
+                max_connections: SERVER_CONFIG.uds.limits.max_connections as u32,
+                max_in_flight_requests: SERVER_CONFIG.uds.limits.max_in_flight_requests as u32,
+                max_frame_size: SERVER_CONFIG.uds.limits.max_frame_size.parse().unwrap(),
+            },
+        }
+    }
+}
+
+impl Default for WebSocketConfig {
+    fn default() -> WebSocketConfig {
+        WebSocketConfig {
+            enabled: SERVER_CONFIG.websocket.enabled,
+            address: SERVER_CONFIG.websocket.address.parse().unwrap(),
+            limits: TransportLimitsConfig {
+                max_connections: SERVER_CONFIG.websocket.limits.max_connections as u32,
+                max_in_flight_requests: SERVER_CONFIG.websocket.limits.max_in_flight_requests
+                    as u32,
+                max_frame_size: SERVER_CONFIG
+                    .websocket
+                    .limits
+                    .max_frame_size
+                    .parse()
+                    .unwrap(),
+            },
+        }
+    }
+}
+
+impl Default for GrpcConfig {
+    fn default() -> GrpcConfig {
+        GrpcConfig {
+            enabled: SERVER_CONFIG.grpc.enabled,
+            address: SERVER_CONFIG.grpc.address.parse().unwrap(),
+            limits: TransportLimitsConfig {
+                max_connections: SERVER_CONFIG.grpc.limits.max_connections as u32,
+                max_in_flight_requests: SERVER_CONFIG.grpc.limits.max_in_flight_requests as u32,
+                max_frame_size: SERVER_CONFIG.grpc.limits.max_frame_size.parse().unwrap(),
+            },
+        }
+    }
+}
+
+impl Default for MqttBridgeConfig {
+    fn default() -> MqttBridgeConfig {
+        MqttBridgeConfig {
+            enabled: SERVER_CONFIG.mqtt.enabled,
+            address: SERVER_CONFIG.mqtt.address.parse().unwrap(),
+            client_id: SERVER_CONFIG.mqtt.client_id.parse().unwrap(),
+            username: SERVER_CONFIG.mqtt.username.parse().unwrap(),
+            password: SERVER_CONFIG.mqtt.password.parse().unwrap(),
+            keep_alive: SERVER_CONFIG.mqtt.keep_alive.parse().unwrap(),
+            reconnect_interval: SERVER_CONFIG.mqtt.reconnect_interval.parse().unwrap(),
+            qos: SERVER_CONFIG.mqtt.qos as u8,
+            max_batch_size: SERVER_CONFIG.mqtt.max_batch_size as u32,
+            mappings: SERVER_CONFIG
+                .mqtt
+                .mappings
+                .iter()
+                .filter(|s| !s.is_empty())
+                .map(|s| s.parse().unwrap())
+                .collect(),
         }
     }
 }
@@ -156,6 +327,19 @@ impl Default for TcpTlsConfig {
             enabled: SERVER_CONFIG.tcp.tls.enabled,
             certificate: SERVER_CONFIG.tcp.tls.certificate.parse().unwrap(),
             password: SERVER_CONFIG.tcp.tls.password.parse().unwrap(),
+            cert_file: SERVER_CONFIG.tcp.tls.cert_file.parse().unwrap(),
+            key_file: SERVER_CONFIG.tcp.tls.key_file.parse().unwrap(),
+            client_ca_file: SERVER_CONFIG.tcp.tls.client_ca_file.parse().unwrap(),
+            require_client_certificate: SERVER_CONFIG.tcp.tls.require_client_certificate,
+        }
+    }
+}
+
+impl Default for TcpHeartbeatConfig {
+    fn default() -> TcpHeartbeatConfig {
+        TcpHeartbeatConfig {
+            enabled: SERVER_CONFIG.tcp.heartbeat.enabled,
+            timeout: SERVER_CONFIG.tcp.heartbeat.timeout.parse().unwrap(),
         }
     }
 }
@@ -179,10 +363,17 @@ impl Default for HttpConfig {
             enabled: SERVER_CONFIG.http.enabled,
             address: SERVER_CONFIG.http.address.parse().unwrap(),
             max_request_size: SERVER_CONFIG.http.max_request_size.parse().unwrap(),
+            acceptors: SERVER_CONFIG.http.acceptors as u32,
             cors: HttpCorsConfig::default(),
             jwt: HttpJwtConfig::default(),
+            oidc: HttpOidcConfig::default(),
             metrics: HttpMetricsConfig::default(),
             tls: HttpTlsConfig::default(),
+            limits: TransportLimitsConfig {
+                max_connections: SERVER_CONFIG.http.limits.max_connections as u32,
+                max_in_flight_requests: SERVER_CONFIG.http.limits.max_in_flight_requests as u32,
+                max_frame_size: SERVER_CONFIG.http.limits.max_frame_size.parse().unwrap(),
+            },
         }
     }
 }
@@ -255,6 +446,33 @@ impl Default for HttpJwtConfig {
     }
 }
 
+impl Default for HttpOidcConfig {
+    fn default() -> HttpOidcConfig {
+        HttpOidcConfig {
+            enabled: SERVER_CONFIG.http.oidc.enabled,
+            issuer: SERVER_CONFIG.http.oidc.issuer.parse().unwrap(),
+            jwks_uri: SERVER_CONFIG.http.oidc.jwks_uri.parse().unwrap(),
+            audience: SERVER_CONFIG.http.oidc.audience.parse().unwrap(),
+            username_claim: SERVER_CONFIG.http.oidc.username_claim.parse().unwrap(),
+            roles_claim: SERVER_CONFIG.http.oidc.roles_claim.parse().unwrap(),
+            role_mappings: SERVER_CONFIG
+                .http
+                .oidc
+                .role_mappings
+                .iter()
+                .map(|s| s.parse().unwrap())
+                .collect(),
+            jwks_refresh_interval: SERVER_CONFIG
+                .http
+                .oidc
+                .jwks_refresh_interval
+                .parse()
+                .unwrap(),
+            timeout: SERVER_CONFIG.http.oidc.timeout.parse().unwrap(),
+        }
+    }
+}
+
 impl Default for HttpMetricsConfig {
     fn default() -> HttpMetricsConfig {
         HttpMetricsConfig {
@@ -289,6 +507,7 @@ impl Default for PersonalAccessTokenConfig {
         PersonalAccessTokenConfig {
             max_tokens_per_user: SERVER_CONFIG.personal_access_token.max_tokens_per_user as u32,
             cleaner: PersonalAccessTokenCleanerConfig::default(),
+            login_guard: PersonalAccessTokenLoginGuardConfig::default(),
         }
     }
 }
@@ -303,6 +522,52 @@ impl Default for PersonalAccessTokenCleanerConfig {
                 .interval
                 .parse()
                 .unwrap(),
+            max_inactivity: SERVER_CONFIG
+                .personal_access_token
+                .cleaner
+                .max_inactivity
+                .parse()
+                .unwrap(),
+        }
+    }
+}
+
+impl Default for PersonalAccessTokenLoginGuardConfig {
+    fn default() -> PersonalAccessTokenLoginGuardConfig {
+        PersonalAccessTokenLoginGuardConfig {
+            enabled: SERVER_CONFIG.personal_access_token.login_guard.enabled,
+            max_failed_attempts: SERVER_CONFIG
+                .personal_access_token
+                .login_guard
+                .max_failed_attempts as u32,
+            failed_attempts_window: SERVER_CONFIG
+                .personal_access_token
+                .login_guard
+                .failed_attempts_window
+                .parse()
+                .unwrap(),
+            block_duration: SERVER_CONFIG
+                .personal_access_token
+                .login_guard
+                .block_duration
+                .parse()
+                .unwrap(),
+            max_new_ip_addresses: SERVER_CONFIG
+                .personal_access_token
+                .login_guard
+                .max_new_ip_addresses as u32,
+            new_ip_addresses_window: SERVER_CONFIG
+                .personal_access_token
+                .login_guard
+                .new_ip_addresses_window
+                .parse()
+                .unwrap(),
+            webhook_url: SERVER_CONFIG
+                .personal_access_token
+                .login_guard
+                .webhook_url
+                .parse()
+                .unwrap(),
         }
     }
 }
@@ -319,11 +584,25 @@ impl Default for SystemConfig {
             encryption: EncryptionConfig::default(),
             topic: TopicConfig::default(),
             partition: PartitionConfig::default(),
+            consumer_offsets: ConsumerOffsetsConfig::default(),
             segment: SegmentConfig::default(),
             state: StateConfig::default(),
             compression: CompressionConfig::default(),
             message_deduplication: MessageDeduplicationConfig::default(),
+            message_id: MessageIdConfig::default(),
             recovery: RecoveryConfig::default(),
+            replay: ReplayConfig::default(),
+            limits: ResourceLimitsConfig::default(),
+            transactions: TransactionsConfig::default(),
+            push_subscriptions: PushSubscriptionsConfig::default(),
+            metadata_changes: MetadataChangesConfig::default(),
+            message_audit: MessageAuditConfig::default(),
+            audit_log: AuditLogConfig::default(),
+            expiry_notifications: ExpiryNotificationsConfig::default(),
+            fetch_quotas: FetchQuotasConfig::default(),
+            quotas: QuotasConfig::default(),
+            authentication: AuthenticationConfig::default(),
+            cluster: ClusterConfig::default(),
         }
     }
 }
@@ -360,6 +639,18 @@ impl Default for HeartbeatConfig {
     }
 }
 
+impl Default for PreflightConfig {
+    fn default() -> PreflightConfig {
+        PreflightConfig {
+            enabled: SERVER_CONFIG.preflight.enabled,
+            min_free_disk_space: SERVER_CONFIG.preflight.min_free_disk_space.parse().unwrap(),
+            reserved_file_descriptors: SERVER_CONFIG.preflight.reserved_file_descriptors as u64,
+            max_clock_drift: SERVER_CONFIG.preflight.max_clock_drift.parse().unwrap(),
+            archiver_timeout: SERVER_CONFIG.preflight.archiver_timeout.parse().unwrap(),
+        }
+    }
+}
+
 impl Default for RuntimeConfig {
     fn default() -> RuntimeConfig {
         RuntimeConfig {
@@ -404,6 +695,23 @@ impl Default for CacheConfig {
         CacheConfig {
             enabled: SERVER_CONFIG.system.cache.enabled,
             size: SERVER_CONFIG.system.cache.size.parse().unwrap(),
+            warmup: CacheWarmupConfig::default(),
+        }
+    }
+}
+
+impl Default for CacheWarmupConfig {
+    fn default() -> CacheWarmupConfig {
+        CacheWarmupConfig {
+            enabled: SERVER_CONFIG.system.cache.warmup.enabled,
+            partitions: SERVER_CONFIG.system.cache.warmup.partitions as u32,
+            heat_map_interval: SERVER_CONFIG
+                .system
+                .cache
+                .warmup
+                .heat_map_interval
+                .parse()
+                .unwrap(),
         }
     }
 }
@@ -431,6 +739,33 @@ impl Default for TopicConfig {
             path: SERVER_CONFIG.system.topic.path.parse().unwrap(),
             max_size: SERVER_CONFIG.system.topic.max_size.parse().unwrap(),
             delete_oldest_segments: SERVER_CONFIG.system.topic.delete_oldest_segments,
+            max_consumer_group_rebalances: SERVER_CONFIG.system.topic.max_consumer_group_rebalances
+                as u32,
+            consumer_group_assignment_strategy: SERVER_CONFIG
+                .system
+                .topic
+                .consumer_group_assignment_strategy
+                .parse()
+                .unwrap(),
+            cleanup_policy: SERVER_CONFIG.system.topic.cleanup_policy.parse().unwrap(),
+            consumer_offset_recovery: SERVER_CONFIG
+                .system
+                .topic
+                .consumer_offset_recovery
+                .parse()
+                .unwrap(),
+            deletion_drain_period: SERVER_CONFIG
+                .system
+                .topic
+                .deletion_drain_period
+                .parse()
+                .unwrap(),
+            deletion_check_interval: SERVER_CONFIG
+                .system
+                .topic
+                .deletion_check_interval
+                .parse()
+                .unwrap(),
         }
     }
 }
@@ -443,6 +778,89 @@ impl Default for PartitionConfig {
                 as u32,
             enforce_fsync: SERVER_CONFIG.system.partition.enforce_fsync,
             validate_checksum: SERVER_CONFIG.system.partition.validate_checksum,
+            use_manifest: SERVER_CONFIG.system.partition.use_manifest,
+            max_state_store_size: SERVER_CONFIG
+                .system
+                .partition
+                .max_state_store_size
+                .parse()
+                .unwrap(),
+            read_ahead: ReadAheadConfig::default(),
+            io_uring: IoUringConfig::default(),
+        }
+    }
+}
+
+impl Default for ConsumerOffsetsConfig {
+    fn default() -> ConsumerOffsetsConfig {
+        ConsumerOffsetsConfig {
+            backend: SERVER_CONFIG
+                .system
+                .consumer_offsets
+                .backend
+                .parse()
+                .unwrap(),
+            log: LogConsumerOffsetsConfig::default(),
+            redis: RedisConsumerOffsetsConfig::default(),
+        }
+    }
+}
+
+impl Default for LogConsumerOffsetsConfig {
+    fn default() -> LogConsumerOffsetsConfig {
+        LogConsumerOffsetsConfig {
+            batch_size: SERVER_CONFIG.system.consumer_offsets.log.batch_size as u32,
+        }
+    }
+}
+
+impl Default for RedisConsumerOffsetsConfig {
+    fn default() -> RedisConsumerOffsetsConfig {
+        RedisConsumerOffsetsConfig {
+            url: SERVER_CONFIG
+                .system
+                .consumer_offsets
+                .redis
+                .url
+                .parse()
+                .unwrap(),
+            key_prefix: SERVER_CONFIG
+                .system
+                .consumer_offsets
+                .redis
+                .key_prefix
+                .parse()
+                .unwrap(),
+        }
+    }
+}
+
+impl Default for ReadAheadConfig {
+    fn default() -> ReadAheadConfig {
+        ReadAheadConfig {
+            enabled: SERVER_CONFIG.system.partition.read_ahead.enabled,
+            min_sequential_polls: SERVER_CONFIG
+                .system
+                .partition
+                .read_ahead
+                .min_sequential_polls as u32,
+            lookahead: SERVER_CONFIG
+                .system
+                .partition
+                .read_ahead
+                .lookahead
+                .parse()
+                .unwrap(),
+            max_messages: SERVER_CONFIG.system.partition.read_ahead.max_messages as u32,
+        }
+    }
+}
+
+impl Default for IoUringConfig {
+    fn default() -> IoUringConfig {
+        IoUringConfig {
+            enabled: SERVER_CONFIG.system.partition.io_uring.enabled,
+            queue_depth: SERVER_CONFIG.system.partition.io_uring.queue_depth as u32,
         }
     }
 }
@@ -451,7 +869,12 @@ impl Default for SegmentConfig {
     fn default() -> SegmentConfig {
         SegmentConfig {
             size: SERVER_CONFIG.system.segment.size.parse().unwrap(),
-            cache_indexes: SERVER_CONFIG.system.segment.cache_indexes,
+            index_cache_size: SERVER_CONFIG
+                .system
+                .segment
+                .index_cache_size
+                .parse()
+                .unwrap(),
             message_expiry: SERVER_CONFIG.system.segment.message_expiry.parse().unwrap(),
             archive_expired: SERVER_CONFIG.system.segment.archive_expired,
             server_confirmation: SERVER_CONFIG
@@ -460,6 +883,8 @@ impl Default for SegmentConfig {
                 .server_confirmation
                 .parse()
                 .unwrap(),
+            format_version: SERVER_CONFIG.system.segment.format_version as u8,
+            verify_batch_checksums: SERVER_CONFIG.system.segment.verify_batch_checksums,
         }
     }
 }
@@ -490,10 +915,439 @@ impl Default for MessageDeduplicationConfig {
     }
 }
 
+impl Default for MessageIdConfig {
+    fn default() -> MessageIdConfig {
+        MessageIdConfig {
+            scheme: SERVER_CONFIG.system.message_id.scheme.parse().unwrap(),
+            node_id: SERVER_CONFIG.system.message_id.node_id as u16,
+        }
+    }
+}
+
 impl Default for RecoveryConfig {
     fn default() -> RecoveryConfig {
         RecoveryConfig {
             recreate_missing_state: SERVER_CONFIG.system.recovery.recreate_missing_state,
+            read_only: SERVER_CONFIG.system.recovery.read_only,
+            startup_concurrency: SERVER_CONFIG.system.recovery.startup_concurrency as u32,
+            index_rebuild_concurrency: SERVER_CONFIG.system.recovery.index_rebuild_concurrency
+                as u32,
+            index_rebuild_write_rate: SERVER_CONFIG
+                .system
+                .recovery
+                .index_rebuild_write_rate
+                .parse()
+                .unwrap(),
+        }
+    }
+}
+
+impl Default for ReplayConfig {
+    fn default() -> ReplayConfig {
+        ReplayConfig {
+            max_running_jobs: SERVER_CONFIG.system.replay.max_running_jobs as u32,
+            max_finished_jobs: SERVER_CONFIG.system.replay.max_finished_jobs as u32,
+        }
+    }
+}
+
+impl Default for ResourceLimitsConfig {
+    fn default() -> ResourceLimitsConfig {
+        ResourceLimitsConfig {
+            max_streams: SERVER_CONFIG.system.limits.max_streams as u32,
+            max_topics_per_stream: SERVER_CONFIG.system.limits.max_topics_per_stream as u32,
+            max_partitions_per_topic: SERVER_CONFIG.system.limits.max_partitions_per_topic as u32,
+            max_total_partitions: SERVER_CONFIG.system.limits.max_total_partitions as u32,
+        }
+    }
+}
+
+impl Default for TransactionsConfig {
+    fn default() -> TransactionsConfig {
+        TransactionsConfig {
+            timeout: SERVER_CONFIG.system.transactions.timeout.parse().unwrap(),
+            expiry_check_interval: SERVER_CONFIG
+                .system
+                .transactions
+                .expiry_check_interval
+                .parse()
+                .unwrap(),
+        }
+    }
+}
+
+impl Default for PushSubscriptionsConfig {
+    fn default() -> PushSubscriptionsConfig {
+        PushSubscriptionsConfig {
+            enabled: SERVER_CONFIG.system.push_subscriptions.enabled,
+            allow_insecure_endpoints: SERVER_CONFIG
+                .system
+                .push_subscriptions
+                .allow_insecure_endpoints,
+            max_subscriptions: SERVER_CONFIG.system.push_subscriptions.max_subscriptions as u32,
+            idle_interval: SERVER_CONFIG
+                .system
+                .push_subscriptions
+                .idle_interval
+                .parse()
+                .unwrap(),
+            request_timeout: SERVER_CONFIG
+                .system
+                .push_subscriptions
+                .request_timeout
+                .parse()
+                .unwrap(),
+            retry_initial_backoff: SERVER_CONFIG
+                .system
+                .push_subscriptions
+                .retry_initial_backoff
+                .parse()
+                .unwrap(),
+            retry_max_backoff: SERVER_CONFIG
+                .system
+                .push_subscriptions
+                .retry_max_backoff
+                .parse()
+                .unwrap(),
+        }
+    }
+}
+
+impl Default for MetadataChangesConfig {
+    fn default() -> MetadataChangesConfig {
+        MetadataChangesConfig {
+            enabled: SERVER_CONFIG.system.metadata_changes.enabled,
+            stream: SERVER_CONFIG
+                .system
+                .metadata_changes
+                .stream
+                .parse()
+                .unwrap(),
+            message_expiry: SERVER_CONFIG
+                .system
+                .metadata_changes
+                .message_expiry
+                .parse()
+                .unwrap(),
+        }
+    }
+}
+
+impl Default for MessageAuditConfig {
+    fn default() -> MessageAuditConfig {
+        MessageAuditConfig {
+            enabled: SERVER_CONFIG.system.message_audit.enabled,
+            sample_rate: SERVER_CONFIG.system.message_audit.sample_rate,
+            stream: SERVER_CONFIG.system.message_audit.stream.parse().unwrap(),
+            message_expiry: SERVER_CONFIG
+                .system
+                .message_audit
+                .message_expiry
+                .parse()
+                .unwrap(),
+        }
+    }
+}
+
+impl Default for AuditLogConfig {
+    fn default() -> AuditLogConfig {
+        AuditLogConfig {
+            enabled: SERVER_CONFIG.system.audit_log.enabled,
+            path: SERVER_CONFIG.system.audit_log.path.parse().unwrap(),
+            max_file_size: SERVER_CONFIG
+                .system
+                .audit_log
+                .max_file_size
+                .parse()
+                .unwrap(),
+            recent_entries: SERVER_CONFIG.system.audit_log.recent_entries as u32,
+        }
+    }
+}
+
+impl Default for ExpiryNotificationsConfig {
+    fn default() -> ExpiryNotificationsConfig {
+        ExpiryNotificationsConfig {
+            enabled: SERVER_CONFIG.system.expiry_notifications.enabled,
+            stream: SERVER_CONFIG
+                .system
+                .expiry_notifications
+                .stream
+                .parse()
+                .unwrap(),
+            message_expiry: SERVER_CONFIG
+                .system
+                .expiry_notifications
+                .message_expiry
+                .parse()
+                .unwrap(),
+            grace_period: SERVER_CONFIG
+                .system
+                .expiry_notifications
+                .grace_period
+                .parse()
+                .unwrap(),
+        }
+    }
+}
+
+impl Default for FetchQuotasConfig {
+    fn default() -> FetchQuotasConfig {
+        FetchQuotasConfig {
+            user_bytes_per_second: SERVER_CONFIG
+                .system
+                .fetch_quotas
+                .user_bytes_per_second
+                .parse()
+                .unwrap(),
+            client_bytes_per_second: SERVER_CONFIG
+                .system
+                .fetch_quotas
+                .client_bytes_per_second
+                .parse()
+                .unwrap(),
+            consumer_group_bytes_per_second: SERVER_CONFIG
+                .system
+                .fetch_quotas
+                .consumer_group_bytes_per_second
+                .parse()
+                .unwrap(),
+            max_throttle_delay: SERVER_CONFIG
+                .system
+                .fetch_quotas
+                .max_throttle_delay
+                .parse()
+                .unwrap(),
+        }
+    }
+}
+
+impl Default for QuotasConfig {
+    fn default() -> QuotasConfig {
+        QuotasConfig {
+            user_produce_bytes_per_second: SERVER_CONFIG
+                .system
+                .quotas
+                .user_produce_bytes_per_second
+                .parse()
+                .unwrap(),
+            client_produce_bytes_per_second: SERVER_CONFIG
+                .system
+                .quotas
+                .client_produce_bytes_per_second
+                .parse()
+                .unwrap(),
+            user_requests_per_second: SERVER_CONFIG.system.quotas.user_requests_per_second as u32,
+            client_requests_per_second: SERVER_CONFIG.system.quotas.client_requests_per_second
+                as u32,
+            max_throttle_delay: SERVER_CONFIG
+                .system
+                .quotas
+                .max_throttle_delay
+                .parse()
+                .unwrap(),
+        }
+    }
+}
+
+impl Default for AuthenticationConfig {
+    fn default() -> AuthenticationConfig {
+        AuthenticationConfig {
+            authenticators: SERVER_CONFIG
+                .system
+                .authentication
+                .authenticators
+                .iter()
+                .map(|s| s.parse().unwrap())
+                .collect(),
+            allow_impersonation: SERVER_CONFIG.system.authentication.allow_impersonation,
+            oidc: OidcAuthenticatorConfig::default(),
+            mtls: MtlsAuthenticatorConfig::default(),
+            dynamic_library: DynamicLibraryAuthenticatorConfig::default(),
+            grpc: GrpcAuthenticatorConfig::default(),
+        }
+    }
+}
+
+impl Default for OidcAuthenticatorConfig {
+    fn default() -> OidcAuthenticatorConfig {
+        OidcAuthenticatorConfig {
+            token_endpoint: SERVER_CONFIG
+                .system
+                .authentication
+                .oidc
+                .token_endpoint
+                .parse()
+                .unwrap(),
+            client_id: SERVER_CONFIG
+                .system
+                .authentication
+                .oidc
+                .client_id
+                .parse()
+                .unwrap(),
+            client_secret: SERVER_CONFIG
+                .system
+                .authentication
+                .oidc
+                .client_secret
+                .parse()
+                .unwrap(),
+            issuer: SERVER_CONFIG
+                .system
+                .authentication
+                .oidc
+                .issuer
+                .parse()
+                .unwrap(),
+            username_claim: SERVER_CONFIG
+                .system
+                .authentication
+                .oidc
+                .username_claim
+                .parse()
+                .unwrap(),
+            timeout: SERVER_CONFIG
+                .system
+                .authentication
+                .oidc
+                .timeout
+                .parse()
+                .unwrap(),
+        }
+    }
+}
+
+impl Default for ClusterConfig {
+    fn default() -> ClusterConfig {
+        ClusterConfig {
+            enabled: SERVER_CONFIG.system.cluster.enabled,
+            node_id: SERVER_CONFIG.system.cluster.node_id as u32,
+            address: SERVER_CONFIG.system.cluster.address.parse().unwrap(),
+            nodes: SERVER_CONFIG
+                .system
+                .cluster
+                .nodes
+                .iter()
+                .filter(|s| !s.is_empty())
+                .map(|s| s.parse().unwrap())
+                .collect(),
+            secret: SERVER_CONFIG.system.cluster.secret.parse().unwrap(),
+            election_timeout_min: SERVER_CONFIG
+                .system
+                .cluster
+                .election_timeout_min
+                .parse()
+                .unwrap(),
+            election_timeout_max: SERVER_CONFIG
+                .system
+                .cluster
+                .election_timeout_max
+                .parse()
+                .unwrap(),
+            heartbeat_interval: SERVER_CONFIG
+                .system
+                .cluster
+                .heartbeat_interval
+                .parse()
+                .unwrap(),
+            replication_timeout: SERVER_CONFIG
+                .system
+                .cluster
+                .replication_timeout
+                .parse()
+                .unwrap(),
+            max_entries_per_append: SERVER_CONFIG.system.cluster.max_entries_per_append as u32,
+            snapshot_threshold: SERVER_CONFIG.system.cluster.snapshot_threshold as u32,
+        }
+    }
+}
+
+impl Default for MtlsAuthenticatorConfig {
+    fn default() -> MtlsAuthenticatorConfig {
+        MtlsAuthenticatorConfig {
+            certificate_mappings: SERVER_CONFIG
+                .system
+                .authentication
+                .mtls
+                .certificate_mappings
+                .iter()
+                .filter(|s| !s.is_empty())
+                .map(|s| s.parse().unwrap())
+                .collect(),
+            subject_mappings: SERVER_CONFIG
+                .system
+                .authentication
+                .mtls
+                .subject_mappings
+                .iter()
+                .filter(|s| !s.is_empty())
+                .map(|s| s.parse().unwrap())
+                .collect(),
+            subject_as_username: SERVER_CONFIG.system.authentication.mtls.subject_as_username,
+        }
+    }
+}
+
+impl Default for DynamicLibraryAuthenticatorConfig {
+    fn default() -> DynamicLibraryAuthenticatorConfig {
+        DynamicLibraryAuthenticatorConfig {
+            path: SERVER_CONFIG
+                .system
+                .authentication
+                .dynamic_library
+                .path
+                .parse()
+                .unwrap(),
+        }
+    }
+}
+
+impl Default for GrpcAuthenticatorConfig {
+    fn default() -> GrpcAuthenticatorConfig {
+        GrpcAuthenticatorConfig {
+            endpoint: SERVER_CONFIG
+                .system
+                .authentication
+                .grpc
+                .endpoint
+                .parse()
+                .unwrap(),
+            timeout: SERVER_CONFIG
+                .system
+                .authentication
+                .grpc
+                .timeout
+                .parse()
+                .unwrap(),
+            tls_ca_file: SERVER_CONFIG
+                .system
+                .authentication
+                .grpc
+                .tls_ca_file
+                .parse()
+                .unwrap(),
+            tls_cert_file: SERVER_CONFIG
+                .system
+                .authentication
+                .grpc
+                .tls_cert_file
+                .parse()
+                .unwrap(),
+            tls_key_file: SERVER_CONFIG
+                .system
+                .authentication
+                .grpc
+                .tls_key_file
+                .parse()
+                .unwrap(),
+            tls_domain: SERVER_CONFIG
+                .system
+                .authentication
+                .grpc
+                .tls_domain
+                .parse()
+                .unwrap(),
         }
     }
 }
@@ -503,8 +1357,16 @@ impl Default for TelemetryConfig {
         TelemetryConfig {
             enabled: SERVER_CONFIG.telemetry.enabled,
             service_name: SERVER_CONFIG.telemetry.service_name.parse().unwrap(),
+            resource_attributes: SERVER_CONFIG
+                .telemetry
+                .resource_attributes
+                .iter()
+                .filter(|s| !s.is_empty())
+                .map(|s| s.parse().unwrap())
+                .collect(),
             logs: TelemetryLogsConfig::default(),
             traces: TelemetryTracesConfig::default(),
+            metrics: TelemetryMetricsConfig::default(),
         }
     }
 }
@@ -526,3 +1388,14 @@ impl Default for TelemetryTracesConfig {
         }
     }
 }
+
+impl Default for TelemetryMetricsConfig {
+    fn default() -> TelemetryMetricsConfig {
+        TelemetryMetricsConfig {
+            enabled: SERVER_CONFIG.telemetry.metrics.enabled,
+            transport: SERVER_CONFIG.telemetry.metrics.transport.parse().unwrap(),
+            endpoint: SERVER_CONFIG.telemetry.metrics.endpoint.parse().unwrap(),
+            interval: SERVER_CONFIG.telemetry.metrics.interval.parse().unwrap(),
+        }
+    }
+}
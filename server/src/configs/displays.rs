@@ -18,20 +18,35 @@
 
 use crate::configs::quic::{QuicCertificateConfig, QuicConfig};
 use crate::configs::server::{
-    ArchiverConfig, DataMaintenanceConfig, DiskArchiverConfig, HeartbeatConfig,
-    MessagesMaintenanceConfig, S3ArchiverConfig, StateMaintenanceConfig, TelemetryConfig,
-    TelemetryLogsConfig, TelemetryTracesConfig,
+    ArchiverConfig, AzureArchiverConfig, CompactionMaintenanceConfig, DataMaintenanceConfig,
+    DiskArchiverConfig, GcsArchiverConfig, HeartbeatConfig, MessagesMaintenanceConfig,
+    PreflightConfig, S3ArchiverConfig, StateMaintenanceConfig, TelemetryConfig,
+    TelemetryLogsConfig, TelemetryMetricsConfig, TelemetryTracesConfig, TieredStorageConfig,
+    TopicSnapshotsMaintenanceConfig,
+};
+use crate::configs::system::{
+    AuditLogConfig, AuthenticationConfig, ClusterConfig, ConsumerOffsetsConfig,
+    ExpiryNotificationsConfig, FetchQuotasConfig, MessageAuditConfig, MessageDeduplicationConfig,
+    MessageIdConfig, MetadataChangesConfig, PushSubscriptionsConfig, QuotasConfig, ReplayConfig,
+    ResourceLimitsConfig, TransactionsConfig,
 };
-use crate::configs::system::MessageDeduplicationConfig;
 use crate::configs::{
-    http::{HttpConfig, HttpCorsConfig, HttpJwtConfig, HttpMetricsConfig, HttpTlsConfig},
+    grpc::GrpcConfig,
+    http::{
+        HttpConfig, HttpCorsConfig, HttpJwtConfig, HttpMetricsConfig, HttpOidcConfig, HttpTlsConfig,
+    },
+    limits::TransportLimitsConfig,
+    mqtt::MqttBridgeConfig,
     resource_quota::MemoryResourceQuota,
     server::{MessageSaverConfig, ServerConfig},
     system::{
-        CacheConfig, CompressionConfig, EncryptionConfig, LoggingConfig, PartitionConfig,
-        SegmentConfig, StateConfig, StreamConfig, SystemConfig, TopicConfig,
+        CacheConfig, CacheWarmupConfig, CompressionConfig, EncryptionConfig, IoUringConfig,
+        LoggingConfig, PartitionConfig, ReadAheadConfig, SegmentConfig, StateConfig, StreamConfig,
+        SystemConfig, TopicConfig,
     },
-    tcp::{TcpConfig, TcpSocketConfig, TcpTlsConfig},
+    tcp::{TcpConfig, TcpHeartbeatConfig, TcpSocketConfig, TcpTlsConfig},
+    uds::UdsConfig,
+    websocket::WebSocketConfig,
 };
 use std::fmt::{Display, Formatter};
 
@@ -39,8 +54,8 @@ impl Display for HttpConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ enabled: {}, address: {}, max_request_size: {}, cors: {}, jwt: {}, metrics: {}, tls: {} }}",
-            self.enabled, self.address, self.max_request_size, self.cors, self.jwt, self.metrics, self.tls
+            "{{ enabled: {}, address: {}, max_request_size: {}, acceptors: {}, cors: {}, jwt: {}, oidc: {}, metrics: {}, tls: {}, limits: {} }}",
+            self.enabled, self.address, self.max_request_size, self.acceptors, self.cors, self.jwt, self.oidc, self.metrics, self.tls, self.limits
         )
     }
 }
@@ -65,6 +80,24 @@ impl Display for HttpJwtConfig {
     }
 }
 
+impl Display for HttpOidcConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, issuer: {}, jwks_uri: {}, audience: {}, username_claim: {}, roles_claim: {}, role_mappings: {:?}, jwks_refresh_interval: {}, timeout: {} }}",
+            self.enabled,
+            self.issuer,
+            self.jwks_uri,
+            self.audience,
+            self.username_claim,
+            self.roles_claim,
+            self.role_mappings,
+            self.jwks_refresh_interval,
+            self.timeout
+        )
+    }
+}
+
 impl Display for HttpMetricsConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -89,7 +122,7 @@ impl Display for QuicConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
           f,
-          "{{ enabled: {}, address: {}, max_concurrent_bidi_streams: {}, datagram_send_buffer_size: {}, initial_mtu: {}, send_window: {}, receive_window: {}, keep_alive_interval: {}, max_idle_timeout: {}, certificate: {} }}",
+          "{{ enabled: {}, address: {}, max_concurrent_bidi_streams: {}, datagram_send_buffer_size: {}, initial_mtu: {}, send_window: {}, receive_window: {}, keep_alive_interval: {}, max_idle_timeout: {}, certificate: {}, limits: {} }}",
           self.enabled,
           self.address,
           self.max_concurrent_bidi_streams,
@@ -99,7 +132,8 @@ impl Display for QuicConfig {
           self.receive_window,
           self.keep_alive_interval,
           self.max_idle_timeout,
-          self.certificate
+          self.certificate,
+          self.limits
       )
     }
 }
@@ -108,8 +142,26 @@ impl Display for QuicCertificateConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ self_signed: {}, cert_file: {}, key_file: {} }}",
-            self.self_signed, self.cert_file, self.key_file
+            "{{ self_signed: {}, cert_file: {}, key_file: {}, reload_interval: {}, reconnect_grace_period: {}, client_ca_file: {}, require_client_certificate: {} }}",
+            self.self_signed,
+            self.cert_file,
+            self.key_file,
+            self.reload_interval,
+            self.reconnect_grace_period,
+            self.client_ca_file,
+            self.require_client_certificate
+        )
+    }
+}
+
+impl Display for TransportLimitsConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ max_connections: {}, max_in_flight_requests: {}, max_frame_size: {} }}",
+            self.max_connections,
+            self.max_in_flight_requests,
+            self.max_frame_size.as_human_string_with_zero_as_unlimited()
         )
     }
 }
@@ -137,8 +189,13 @@ impl Display for DataMaintenanceConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ archiver: {}, messages: {}, state: {} }}",
-            self.archiver, self.messages, self.state
+            "{{ archiver: {}, messages: {}, state: {}, topic_snapshots: {}, tiered_storage: {}, compaction: {} }}",
+            self.archiver,
+            self.messages,
+            self.state,
+            self.topic_snapshots,
+            self.tiered_storage,
+            self.compaction
         )
     }
 }
@@ -153,9 +210,17 @@ impl Display for ArchiverConfig {
             .s3
             .as_ref()
             .map_or("none".to_string(), |s3| s3.to_string());
+        let gcs = self
+            .gcs
+            .as_ref()
+            .map_or("none".to_string(), |gcs| gcs.to_string());
+        let azure = self
+            .azure
+            .as_ref()
+            .map_or("none".to_string(), |azure| azure.to_string());
         write!(
             f,
-            "{{ enabled: {}, kind: {}, disk: {disk}, s3: {s3} }}",
+            "{{ enabled: {}, kind: {}, disk: {disk}, s3: {s3}, gcs: {gcs}, azure: {azure} }}",
             self.enabled, self.kind,
         )
     }
@@ -180,6 +245,40 @@ impl Display for S3ArchiverConfig {
     }
 }
 
+impl Display for GcsArchiverConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ bucket: {}, prefix: {}, auth: {}, endpoint: {}, max_retries: {}, retry_initial_backoff: {}, retry_max_backoff: {} }}",
+            self.bucket,
+            self.prefix.as_deref().unwrap_or_default(),
+            self.auth,
+            self.endpoint.as_deref().unwrap_or_default(),
+            self.max_retries,
+            self.retry_initial_backoff,
+            self.retry_max_backoff
+        )
+    }
+}
+
+impl Display for AzureArchiverConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ container: {}, prefix: {}, auth: {}, account_name: {}, client_id: {}, endpoint: {}, max_retries: {}, retry_initial_backoff: {}, retry_max_backoff: {} }}",
+            self.container,
+            self.prefix.as_deref().unwrap_or_default(),
+            self.auth,
+            self.account_name.as_deref().unwrap_or_default(),
+            self.client_id.as_deref().unwrap_or_default(),
+            self.endpoint.as_deref().unwrap_or_default(),
+            self.max_retries,
+            self.retry_initial_backoff,
+            self.retry_max_backoff
+        )
+    }
+}
+
 impl Display for MessagesMaintenanceConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -200,12 +299,42 @@ impl Display for StateMaintenanceConfig {
     }
 }
 
+impl Display for TopicSnapshotsMaintenanceConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, max_messages: {}, interval: {} }}",
+            self.enabled, self.max_messages, self.interval
+        )
+    }
+}
+
+impl Display for TieredStorageConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, cache_path: {}, max_cached_segments: {} }}",
+            self.enabled, self.cache_path, self.max_cached_segments
+        )
+    }
+}
+
+impl Display for CompactionMaintenanceConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, interval: {} }}",
+            self.enabled, self.interval
+        )
+    }
+}
+
 impl Display for ServerConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ data_maintenance: {}, message_saver: {}, heartbeat: {}, system: {}, quic: {}, tcp: {}, http: {}, telemetry: {} }}",
-            self.data_maintenance, self.message_saver, self.heartbeat, self.system, self.quic, self.tcp, self.http, self.telemetry
+            "{{ data_maintenance: {}, message_saver: {}, heartbeat: {}, preflight: {}, system: {}, quic: {}, tcp: {}, uds: {}, websocket: {}, grpc: {}, mqtt: {}, http: {}, telemetry: {} }}",
+            self.data_maintenance, self.message_saver, self.heartbeat, self.preflight, self.system, self.quic, self.tcp, self.uds, self.websocket, self.grpc, self.mqtt, self.http, self.telemetry
         )
     }
 }
@@ -222,7 +351,21 @@ impl Display for MessageSaverConfig {
 
 impl Display for CacheConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{{ enabled: {}, size: {} }}", self.enabled, self.size)
+        write!(
+            f,
+            "{{ enabled: {}, size: {}, warmup: {} }}",
+            self.enabled, self.size, self.warmup
+        )
+    }
+}
+
+impl Display for CacheWarmupConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, partitions: {}, heat_map_interval: {} }}",
+            self.enabled, self.partitions, self.heat_map_interval
+        )
     }
 }
 
@@ -236,6 +379,20 @@ impl Display for HeartbeatConfig {
     }
 }
 
+impl Display for PreflightConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, min_free_disk_space: {}, reserved_file_descriptors: {}, max_clock_drift: {}, archiver_timeout: {} }}",
+            self.enabled,
+            self.min_free_disk_space,
+            self.reserved_file_descriptors,
+            self.max_clock_drift,
+            self.archiver_timeout
+        )
+    }
+}
+
 impl Display for EncryptionConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{{ enabled: {} }}", self.enabled)
@@ -252,8 +409,16 @@ impl Display for TopicConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ path: {}, max_size: {}, delete_oldest_segments: {} }}",
-            self.path, self.max_size, self.delete_oldest_segments
+            "{{ path: {}, max_size: {}, delete_oldest_segments: {}, max_consumer_group_rebalances: {}, consumer_group_assignment_strategy: {}, cleanup_policy: {}, consumer_offset_recovery: {}, deletion_drain_period: {}, deletion_check_interval: {} }}",
+            self.path,
+            self.max_size,
+            self.delete_oldest_segments,
+            self.max_consumer_group_rebalances,
+            self.consumer_group_assignment_strategy,
+            self.cleanup_policy,
+            self.consumer_offset_recovery,
+            self.deletion_drain_period,
+            self.deletion_check_interval
         )
     }
 }
@@ -262,15 +427,59 @@ impl Display for PartitionConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
           f,
-          "{{ path: {}, messages_required_to_save: {}, enforce_fsync: {}, validate_checksum: {} }}",
+          "{{ path: {}, messages_required_to_save: {}, enforce_fsync: {}, validate_checksum: {}, use_manifest: {}, max_state_store_size: {}, read_ahead: {}, io_uring: {} }}",
           self.path,
           self.messages_required_to_save,
           self.enforce_fsync,
-          self.validate_checksum
+          self.validate_checksum,
+          self.use_manifest,
+          self.max_state_store_size,
+          self.read_ahead,
+          self.io_uring
       )
     }
 }
 
+impl Display for ConsumerOffsetsConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ backend: {}, log: {{ batch_size: {} }}, redis: {{ key_prefix: {} }} }}",
+            self.backend, self.log.batch_size, self.redis.key_prefix
+        )
+    }
+}
+
+impl Display for ReadAheadConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, min_sequential_polls: {}, lookahead: {}, max_messages: {} }}",
+            self.enabled, self.min_sequential_polls, self.lookahead, self.max_messages
+        )
+    }
+}
+
+impl Display for IoUringConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, queue_depth: {} }}",
+            self.enabled, self.queue_depth
+        )
+    }
+}
+
+impl Display for MessageIdConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ scheme: {}, node_id: {} }}",
+            self.scheme, self.node_id
+        )
+    }
+}
+
 impl Display for MessageDeduplicationConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -281,12 +490,180 @@ impl Display for MessageDeduplicationConfig {
     }
 }
 
+impl Display for ReplayConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ max_running_jobs: {}, max_finished_jobs: {} }}",
+            self.max_running_jobs, self.max_finished_jobs
+        )
+    }
+}
+
+impl Display for ClusterConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, node_id: {}, address: {}, nodes: {:?}, election_timeout_min: {}, election_timeout_max: {}, heartbeat_interval: {}, replication_timeout: {}, max_entries_per_append: {}, snapshot_threshold: {} }}",
+            self.enabled,
+            self.node_id,
+            self.address,
+            self.nodes,
+            self.election_timeout_min,
+            self.election_timeout_max,
+            self.heartbeat_interval,
+            self.replication_timeout,
+            self.max_entries_per_append,
+            self.snapshot_threshold
+        )
+    }
+}
+
+impl Display for ResourceLimitsConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ max_streams: {}, max_topics_per_stream: {}, max_partitions_per_topic: {}, max_total_partitions: {} }}",
+            self.max_streams,
+            self.max_topics_per_stream,
+            self.max_partitions_per_topic,
+            self.max_total_partitions
+        )
+    }
+}
+
+impl Display for TransactionsConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ timeout: {}, expiry_check_interval: {} }}",
+            self.timeout, self.expiry_check_interval
+        )
+    }
+}
+
+impl Display for PushSubscriptionsConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, allow_insecure_endpoints: {}, max_subscriptions: {}, idle_interval: {}, request_timeout: {}, retry_initial_backoff: {}, retry_max_backoff: {} }}",
+            self.enabled,
+            self.allow_insecure_endpoints,
+            self.max_subscriptions,
+            self.idle_interval,
+            self.request_timeout,
+            self.retry_initial_backoff,
+            self.retry_max_backoff
+        )
+    }
+}
+
+impl Display for MetadataChangesConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, stream: {}, message_expiry: {} }}",
+            self.enabled, self.stream, self.message_expiry
+        )
+    }
+}
+
+impl Display for MessageAuditConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, sample_rate: {}, stream: {}, message_expiry: {} }}",
+            self.enabled, self.sample_rate, self.stream, self.message_expiry
+        )
+    }
+}
+
+impl Display for AuditLogConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, path: {}, max_file_size: {}, recent_entries: {} }}",
+            self.enabled, self.path, self.max_file_size, self.recent_entries
+        )
+    }
+}
+
+impl Display for ExpiryNotificationsConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, stream: {}, message_expiry: {}, grace_period: {} }}",
+            self.enabled, self.stream, self.message_expiry, self.grace_period
+        )
+    }
+}
+
+impl Display for FetchQuotasConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ user_bytes_per_second: {}, client_bytes_per_second: {}, consumer_group_bytes_per_second: {}, max_throttle_delay: {} }}",
+            self.user_bytes_per_second.as_human_string_with_zero_as_unlimited(),
+            self.client_bytes_per_second.as_human_string_with_zero_as_unlimited(),
+            self.consumer_group_bytes_per_second
+                .as_human_string_with_zero_as_unlimited(),
+            self.max_throttle_delay
+        )
+    }
+}
+
+impl Display for QuotasConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ user_produce_bytes_per_second: {}, client_produce_bytes_per_second: {}, user_requests_per_second: {}, client_requests_per_second: {}, max_throttle_delay: {} }}",
+            self.user_produce_bytes_per_second
+                .as_human_string_with_zero_as_unlimited(),
+            self.client_produce_bytes_per_second
+                .as_human_string_with_zero_as_unlimited(),
+            self.user_requests_per_second,
+            self.client_requests_per_second,
+            self.max_throttle_delay
+        )
+    }
+}
+
+impl Display for AuthenticationConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let authenticators = self
+            .authenticators
+            .iter()
+            .map(|authenticator| authenticator.to_string())
+            .collect::<Vec<_>>();
+        write!(
+            f,
+            "{{ authenticators: {:?}, allow_impersonation: {}, oidc: {{ token_endpoint: {}, client_id: {}, issuer: {}, username_claim: {}, timeout: {} }}, mtls: {{ certificate_mappings: {}, subject_mappings: {}, subject_as_username: {} }}, dynamic_library: {{ path: {} }}, grpc: {{ endpoint: {}, timeout: {}, tls_ca_file: {}, tls_cert_file: {}, tls_domain: {} }} }}",
+            authenticators,
+            self.allow_impersonation,
+            self.oidc.token_endpoint,
+            self.oidc.client_id,
+            self.oidc.issuer,
+            self.oidc.username_claim,
+            self.oidc.timeout,
+            self.mtls.certificate_mappings.len(),
+            self.mtls.subject_mappings.len(),
+            self.mtls.subject_as_username,
+            self.dynamic_library.path,
+            self.grpc.endpoint,
+            self.grpc.timeout,
+            self.grpc.tls_ca_file,
+            self.grpc.tls_cert_file,
+            self.grpc.tls_domain
+        )
+    }
+}
+
 impl Display for SegmentConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ size_bytes: {}, cache_indexes: {}, message_expiry: {}, archive_expired: {}, server_confirmation: {} }}",
-            self.size, self.cache_indexes, self.message_expiry, self.archive_expired, self.server_confirmation,
+            "{{ size_bytes: {}, index_cache_size: {}, message_expiry: {}, archive_expired: {}, server_confirmation: {}, format_version: {}, verify_batch_checksums: {} }}",
+            self.size, self.index_cache_size, self.message_expiry, self.archive_expired, self.server_confirmation, self.format_version, self.verify_batch_checksums,
         )
     }
 }
@@ -308,8 +685,64 @@ impl Display for TcpConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ enabled: {}, address: {}, ipv6: {}, tls: {}, socket: {} }}",
-            self.enabled, self.address, self.ipv6, self.tls, self.socket,
+            "{{ enabled: {}, address: {}, ipv6: {}, acceptors: {}, zero_copy: {}, tls: {}, socket: {}, heartbeat: {}, limits: {} }}",
+            self.enabled,
+            self.address,
+            self.ipv6,
+            self.acceptors,
+            self.zero_copy,
+            self.tls,
+            self.socket,
+            self.heartbeat,
+            self.limits,
+        )
+    }
+}
+
+impl Display for UdsConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, path: {}, permissions: {:o}, trusted_username: {}, limits: {} }}",
+            self.enabled, self.path, self.permissions, self.trusted_username, self.limits
+        )
+    }
+}
+
+impl Display for WebSocketConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, address: {}, limits: {} }}",
+            self.enabled, self.address, self.limits
+        )
+    }
+}
+
+impl Display for GrpcConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, address: {}, limits: {} }}",
+            self.enabled, self.address, self.limits
+        )
+    }
+}
+
+impl Display for MqttBridgeConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, address: {}, client_id: {}, username: {}, keep_alive: {}, reconnect_interval: {}, qos: {}, max_batch_size: {}, mappings: {:?} }}",
+            self.enabled,
+            self.address,
+            self.client_id,
+            self.username,
+            self.keep_alive,
+            self.reconnect_interval,
+            self.qos,
+            self.max_batch_size,
+            self.mappings
         )
     }
 }
@@ -318,8 +751,23 @@ impl Display for TcpTlsConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ enabled: {}, certificate: {} }}",
-            self.enabled, self.certificate
+            "{{ enabled: {}, certificate: {}, cert_file: {}, key_file: {}, client_ca_file: {}, require_client_certificate: {} }}",
+            self.enabled,
+            self.certificate,
+            self.cert_file,
+            self.key_file,
+            self.client_ca_file,
+            self.require_client_certificate
+        )
+    }
+}
+
+impl Display for TcpHeartbeatConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, timeout: {} }}",
+            self.enabled, self.timeout
         )
     }
 }
@@ -338,8 +786,8 @@ impl Display for TelemetryConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ enabled: {}, service_name: {}, logs: {}, traces: {} }}",
-            self.enabled, self.service_name, self.logs, self.traces
+            "{{ enabled: {}, service_name: {}, resource_attributes: {:?}, logs: {}, traces: {}, metrics: {} }}",
+            self.enabled, self.service_name, self.resource_attributes, self.logs, self.traces, self.metrics
         )
     }
 }
@@ -374,20 +822,43 @@ impl Display for TelemetryTracesConfig {
     }
 }
 
+impl Display for TelemetryMetricsConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, transport: {}, endpoint: {}, interval: {} }}",
+            self.enabled, self.transport, self.endpoint, self.interval
+        )
+    }
+}
+
 impl Display for SystemConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
           f,
-          "{{ path: {}, logging: {}, cache: {}, stream: {}, topic: {}, partition: {}, segment: {}, encryption: {}, state: {} }}",
+          "{{ path: {}, logging: {}, cache: {}, stream: {}, topic: {}, partition: {}, consumer_offsets: {}, segment: {}, encryption: {}, state: {}, message_id: {}, limits: {}, transactions: {}, push_subscriptions: {}, metadata_changes: {}, message_audit: {}, audit_log: {}, expiry_notifications: {}, fetch_quotas: {}, quotas: {}, authentication: {}, cluster: {} }}",
           self.path,
           self.logging,
           self.cache,
           self.stream,
           self.topic,
           self.partition,
+          self.consumer_offsets,
           self.segment,
           self.encryption,
           self.state,
+          self.message_id,
+          self.limits,
+          self.transactions,
+          self.push_subscriptions,
+          self.metadata_changes,
+          self.message_audit,
+          self.audit_log,
+          self.expiry_notifications,
+          self.fetch_quotas,
+          self.quotas,
+          self.authentication,
+          self.cluster,
       )
     }
 }
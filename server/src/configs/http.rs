@@ -16,6 +16,7 @@
  * under the License.
  */
 
+use crate::configs::limits::TransportLimitsConfig;
 use iggy::error::IggyError;
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::duration::IggyDuration;
@@ -30,10 +31,13 @@ pub struct HttpConfig {
     pub enabled: bool,
     pub address: String,
     pub max_request_size: IggyByteSize,
+    pub acceptors: u32,
     pub cors: HttpCorsConfig,
     pub jwt: HttpJwtConfig,
+    pub oidc: HttpOidcConfig,
     pub metrics: HttpMetricsConfig,
     pub tls: HttpTlsConfig,
+    pub limits: TransportLimitsConfig,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -66,6 +70,22 @@ pub struct HttpJwtConfig {
     pub use_base64_secret: bool,
 }
 
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct HttpOidcConfig {
+    pub enabled: bool,
+    pub issuer: String,
+    pub jwks_uri: String,
+    pub audience: String,
+    pub username_claim: String,
+    pub roles_claim: String,
+    pub role_mappings: Vec<String>,
+    #[serde_as(as = "DisplayFromStr")]
+    pub jwks_refresh_interval: IggyDuration,
+    #[serde_as(as = "DisplayFromStr")]
+    pub timeout: IggyDuration,
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct HttpMetricsConfig {
     pub enabled: bool,
@@ -0,0 +1,33 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use iggy::utils::byte_size::IggyByteSize;
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use serde_with::DisplayFromStr;
+
+/// Load shedding limits applied independently to each transport.
+/// Zero value of any limit means that it's disabled.
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct TransportLimitsConfig {
+    pub max_connections: u32,
+    pub max_in_flight_requests: u32,
+    #[serde_as(as = "DisplayFromStr")]
+    pub max_frame_size: IggyByteSize,
+}
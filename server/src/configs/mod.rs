@@ -19,13 +19,18 @@
 pub mod server;
 pub mod system;
 
+pub mod grpc;
 pub mod http;
+pub mod mqtt;
 pub mod quic;
 pub mod tcp;
+pub mod uds;
+pub mod websocket;
 
 pub mod config_provider;
 pub mod defaults;
 pub mod displays;
+pub mod limits;
 pub mod resource_quota;
 pub mod validators;
 
@@ -0,0 +1,39 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use iggy::utils::duration::IggyDuration;
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use serde_with::DisplayFromStr;
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct MqttBridgeConfig {
+    pub enabled: bool,
+    pub address: String,
+    pub client_id: String,
+    pub username: String,
+    pub password: String,
+    #[serde_as(as = "DisplayFromStr")]
+    pub keep_alive: IggyDuration,
+    #[serde_as(as = "DisplayFromStr")]
+    pub reconnect_interval: IggyDuration,
+    pub qos: u8,
+    pub max_batch_size: u32,
+    pub mappings: Vec<String>,
+}
@@ -16,6 +16,7 @@
  * under the License.
  */
 
+use crate::configs::limits::TransportLimitsConfig;
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::duration::IggyDuration;
 use serde::{Deserialize, Serialize};
@@ -37,11 +38,19 @@ pub struct QuicConfig {
     #[serde_as(as = "DisplayFromStr")]
     pub max_idle_timeout: IggyDuration,
     pub certificate: QuicCertificateConfig,
+    pub limits: TransportLimitsConfig,
 }
 
+#[serde_as]
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct QuicCertificateConfig {
     pub self_signed: bool,
     pub cert_file: String,
     pub key_file: String,
+    #[serde_as(as = "DisplayFromStr")]
+    pub reload_interval: IggyDuration,
+    #[serde_as(as = "DisplayFromStr")]
+    pub reconnect_grace_period: IggyDuration,
+    pub client_ca_file: String,
+    pub require_client_certificate: bool,
 }
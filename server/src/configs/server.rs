@@ -16,16 +16,23 @@
  * under the License.
  */
 
+use crate::archiver::azure::AzureAuthKind;
+use crate::archiver::gcs::GcsAuthKind;
 use crate::archiver::ArchiverKindType;
 use crate::configs::config_provider::ConfigProviderKind;
+use crate::configs::grpc::GrpcConfig;
 use crate::configs::http::HttpConfig;
+use crate::configs::mqtt::MqttBridgeConfig;
 use crate::configs::quic::QuicConfig;
 use crate::configs::system::SystemConfig;
 use crate::configs::tcp::TcpConfig;
+use crate::configs::uds::UdsConfig;
+use crate::configs::websocket::WebSocketConfig;
 use crate::configs::COMPONENT;
 use crate::server_error::ConfigError;
 use derive_more::Display;
 use error_set::ErrContext;
+use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::duration::IggyDuration;
 use iggy::validatable::Validatable;
 use serde::{Deserialize, Serialize};
@@ -40,9 +47,14 @@ pub struct ServerConfig {
     pub message_saver: MessageSaverConfig,
     pub personal_access_token: PersonalAccessTokenConfig,
     pub heartbeat: HeartbeatConfig,
+    pub preflight: PreflightConfig,
     pub system: Arc<SystemConfig>,
     pub quic: QuicConfig,
     pub tcp: TcpConfig,
+    pub uds: UdsConfig,
+    pub websocket: WebSocketConfig,
+    pub grpc: GrpcConfig,
+    pub mqtt: MqttBridgeConfig,
     pub http: HttpConfig,
     pub telemetry: TelemetryConfig,
 }
@@ -53,6 +65,9 @@ pub struct DataMaintenanceConfig {
     pub archiver: ArchiverConfig,
     pub messages: MessagesMaintenanceConfig,
     pub state: StateMaintenanceConfig,
+    pub topic_snapshots: TopicSnapshotsMaintenanceConfig,
+    pub tiered_storage: TieredStorageConfig,
+    pub compaction: CompactionMaintenanceConfig,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -61,6 +76,8 @@ pub struct ArchiverConfig {
     pub kind: ArchiverKindType,
     pub disk: Option<DiskArchiverConfig>,
     pub s3: Option<S3ArchiverConfig>,
+    pub gcs: Option<GcsArchiverConfig>,
+    pub azure: Option<AzureArchiverConfig>,
 }
 
 #[serde_as]
@@ -81,6 +98,30 @@ pub struct StateMaintenanceConfig {
     pub interval: IggyDuration,
 }
 
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct TopicSnapshotsMaintenanceConfig {
+    pub enabled: bool,
+    pub max_messages: u32,
+    #[serde_as(as = "DisplayFromStr")]
+    pub interval: IggyDuration,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct CompactionMaintenanceConfig {
+    pub enabled: bool,
+    #[serde_as(as = "DisplayFromStr")]
+    pub interval: IggyDuration,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct TieredStorageConfig {
+    pub enabled: bool,
+    pub cache_path: String,
+    pub max_cached_segments: u32,
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct DiskArchiverConfig {
     pub path: String,
@@ -96,6 +137,42 @@ pub struct S3ArchiverConfig {
     pub tmp_upload_dir: String,
 }
 
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct GcsArchiverConfig {
+    pub bucket: String,
+    pub prefix: Option<String>,
+    pub auth: GcsAuthKind,
+    /// Path to the JSON key of the service account, required by the `service_account` authentication.
+    pub credentials_path: Option<String>,
+    pub endpoint: Option<String>,
+    pub max_retries: u32,
+    #[serde_as(as = "DisplayFromStr")]
+    pub retry_initial_backoff: IggyDuration,
+    #[serde_as(as = "DisplayFromStr")]
+    pub retry_max_backoff: IggyDuration,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct AzureArchiverConfig {
+    pub container: String,
+    pub prefix: Option<String>,
+    pub auth: AzureAuthKind,
+    /// Connection string of the storage account, required by the `connection_string` authentication.
+    pub connection_string: Option<String>,
+    /// Name of the storage account, used to build the endpoint for the `managed_identity` authentication.
+    pub account_name: Option<String>,
+    /// Client ID of the user-assigned managed identity, the system-assigned one is used if not set.
+    pub client_id: Option<String>,
+    pub endpoint: Option<String>,
+    pub max_retries: u32,
+    #[serde_as(as = "DisplayFromStr")]
+    pub retry_initial_backoff: IggyDuration,
+    #[serde_as(as = "DisplayFromStr")]
+    pub retry_max_backoff: IggyDuration,
+}
+
 #[serde_as]
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct MessageSaverConfig {
@@ -109,6 +186,7 @@ pub struct MessageSaverConfig {
 pub struct PersonalAccessTokenConfig {
     pub max_tokens_per_user: u32,
     pub cleaner: PersonalAccessTokenCleanerConfig,
+    pub login_guard: PersonalAccessTokenLoginGuardConfig,
 }
 
 #[serde_as]
@@ -117,6 +195,23 @@ pub struct PersonalAccessTokenCleanerConfig {
     pub enabled: bool,
     #[serde_as(as = "DisplayFromStr")]
     pub interval: IggyDuration,
+    #[serde_as(as = "DisplayFromStr")]
+    pub max_inactivity: IggyDuration,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct PersonalAccessTokenLoginGuardConfig {
+    pub enabled: bool,
+    pub max_failed_attempts: u32,
+    #[serde_as(as = "DisplayFromStr")]
+    pub failed_attempts_window: IggyDuration,
+    #[serde_as(as = "DisplayFromStr")]
+    pub block_duration: IggyDuration,
+    pub max_new_ip_addresses: u32,
+    #[serde_as(as = "DisplayFromStr")]
+    pub new_ip_addresses_window: IggyDuration,
+    pub webhook_url: String,
 }
 
 #[serde_as]
@@ -127,12 +222,28 @@ pub struct HeartbeatConfig {
     pub interval: IggyDuration,
 }
 
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct PreflightConfig {
+    pub enabled: bool,
+    pub min_free_disk_space: IggyByteSize,
+    pub reserved_file_descriptors: u64,
+    #[serde_as(as = "DisplayFromStr")]
+    pub max_clock_drift: IggyDuration,
+    #[serde_as(as = "DisplayFromStr")]
+    pub archiver_timeout: IggyDuration,
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct TelemetryConfig {
     pub enabled: bool,
     pub service_name: String,
+    /// Additional attributes of the resource in the "<key>=<value>" format.
+    #[serde(default)]
+    pub resource_attributes: Vec<String>,
     pub logs: TelemetryLogsConfig,
     pub traces: TelemetryTracesConfig,
+    pub metrics: TelemetryMetricsConfig,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -147,6 +258,16 @@ pub struct TelemetryTracesConfig {
     pub endpoint: String,
 }
 
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct TelemetryMetricsConfig {
+    pub enabled: bool,
+    pub transport: TelemetryTransport,
+    pub endpoint: String,
+    #[serde_as(as = "DisplayFromStr")]
+    pub interval: IggyDuration,
+}
+
 #[derive(Debug, Serialize, Deserialize, PartialEq, Display, Copy, Clone)]
 #[serde(rename_all = "lowercase")]
 pub enum TelemetryTransport {
@@ -167,6 +288,20 @@ impl FromStr for TelemetryTransport {
     }
 }
 
+/// Parses the attribute of the telemetry resource in the "<key>=<value>" format.
+pub fn parse_resource_attribute(attribute: &str) -> Result<(&str, &str), ConfigError> {
+    let Some((key, value)) = attribute.split_once('=') else {
+        return Err(ConfigError::InvalidConfiguration);
+    };
+
+    let key = key.trim();
+    if key.is_empty() {
+        return Err(ConfigError::InvalidConfiguration);
+    }
+
+    Ok((key, value.trim()))
+}
+
 impl ServerConfig {
     pub async fn load(config_provider: &ConfigProviderKind) -> Result<ServerConfig, ConfigError> {
         let server_config = config_provider
@@ -16,8 +16,14 @@
  * under the License.
  */
 
+use crate::authenticator::AuthenticatorKindType;
 use crate::configs::resource_quota::MemoryResourceQuota;
+use crate::streaming::consumer_offsets::ConsumerOffsetsBackend;
 use iggy::confirmation::Confirmation;
+use iggy::consumer_groups::offset_recovery_policy::OffsetRecoveryPolicy;
+use iggy::messages::message_id_scheme::MessageIdScheme;
+use iggy::models::consumer_group::PartitionAssignmentStrategy;
+use iggy::topics::cleanup_policy::CleanupPolicy;
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::expiry::IggyExpiry;
 use iggy::utils::topic_size::MaxTopicSize;
@@ -39,11 +45,25 @@ pub struct SystemConfig {
     pub stream: StreamConfig,
     pub topic: TopicConfig,
     pub partition: PartitionConfig,
+    pub consumer_offsets: ConsumerOffsetsConfig,
     pub segment: SegmentConfig,
     pub encryption: EncryptionConfig,
     pub compression: CompressionConfig,
     pub message_deduplication: MessageDeduplicationConfig,
+    pub message_id: MessageIdConfig,
     pub recovery: RecoveryConfig,
+    pub replay: ReplayConfig,
+    pub limits: ResourceLimitsConfig,
+    pub transactions: TransactionsConfig,
+    pub push_subscriptions: PushSubscriptionsConfig,
+    pub metadata_changes: MetadataChangesConfig,
+    pub message_audit: MessageAuditConfig,
+    pub audit_log: AuditLogConfig,
+    pub expiry_notifications: ExpiryNotificationsConfig,
+    pub fetch_quotas: FetchQuotasConfig,
+    pub quotas: QuotasConfig,
+    pub authentication: AuthenticationConfig,
+    pub cluster: ClusterConfig,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -89,6 +109,17 @@ pub struct LoggingConfig {
 pub struct CacheConfig {
     pub enabled: bool,
     pub size: MemoryResourceQuota,
+    #[serde(default)]
+    pub warmup: CacheWarmupConfig,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CacheWarmupConfig {
+    pub enabled: bool,
+    pub partitions: u32,
+    #[serde_as(as = "DisplayFromStr")]
+    pub heat_map_interval: IggyDuration,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -109,6 +140,17 @@ pub struct TopicConfig {
     #[serde_as(as = "DisplayFromStr")]
     pub max_size: MaxTopicSize,
     pub delete_oldest_segments: bool,
+    pub max_consumer_group_rebalances: u32,
+    #[serde_as(as = "DisplayFromStr")]
+    pub consumer_group_assignment_strategy: PartitionAssignmentStrategy,
+    #[serde_as(as = "DisplayFromStr")]
+    pub cleanup_policy: CleanupPolicy,
+    #[serde_as(as = "DisplayFromStr")]
+    pub consumer_offset_recovery: OffsetRecoveryPolicy,
+    #[serde_as(as = "DisplayFromStr")]
+    pub deletion_drain_period: IggyDuration,
+    #[serde_as(as = "DisplayFromStr")]
+    pub deletion_check_interval: IggyDuration,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -117,6 +159,26 @@ pub struct PartitionConfig {
     pub messages_required_to_save: u32,
     pub enforce_fsync: bool,
     pub validate_checksum: bool,
+    pub use_manifest: bool,
+    pub max_state_store_size: IggyByteSize,
+    pub read_ahead: ReadAheadConfig,
+    pub io_uring: IoUringConfig,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct IoUringConfig {
+    pub enabled: bool,
+    pub queue_depth: u32,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ReadAheadConfig {
+    pub enabled: bool,
+    pub min_sequential_polls: u32,
+    #[serde_as(as = "DisplayFromStr")]
+    pub lookahead: IggyDuration,
+    pub max_messages: u32,
 }
 
 #[serde_as]
@@ -128,21 +190,215 @@ pub struct MessageDeduplicationConfig {
     pub expiry: IggyDuration,
 }
 
+#[derive(Debug, Deserialize, Serialize)]
+pub struct MessageIdConfig {
+    pub scheme: MessageIdScheme,
+    pub node_id: u16,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct RecoveryConfig {
     pub recreate_missing_state: bool,
+    pub read_only: bool,
+    pub startup_concurrency: u32,
+    pub index_rebuild_concurrency: u32,
+    pub index_rebuild_write_rate: IggyByteSize,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ReplayConfig {
+    pub max_running_jobs: u32,
+    pub max_finished_jobs: u32,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy)]
+pub struct ResourceLimitsConfig {
+    pub max_streams: u32,
+    pub max_topics_per_stream: u32,
+    pub max_partitions_per_topic: u32,
+    pub max_total_partitions: u32,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct TransactionsConfig {
+    #[serde_as(as = "DisplayFromStr")]
+    pub timeout: IggyDuration,
+    #[serde_as(as = "DisplayFromStr")]
+    pub expiry_check_interval: IggyDuration,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PushSubscriptionsConfig {
+    pub enabled: bool,
+    pub allow_insecure_endpoints: bool,
+    pub max_subscriptions: u32,
+    #[serde_as(as = "DisplayFromStr")]
+    pub idle_interval: IggyDuration,
+    #[serde_as(as = "DisplayFromStr")]
+    pub request_timeout: IggyDuration,
+    #[serde_as(as = "DisplayFromStr")]
+    pub retry_initial_backoff: IggyDuration,
+    #[serde_as(as = "DisplayFromStr")]
+    pub retry_max_backoff: IggyDuration,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct MetadataChangesConfig {
+    pub enabled: bool,
+    pub stream: String,
+    #[serde_as(as = "DisplayFromStr")]
+    pub message_expiry: IggyExpiry,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct MessageAuditConfig {
+    pub enabled: bool,
+    pub sample_rate: f64,
+    pub stream: String,
+    #[serde_as(as = "DisplayFromStr")]
+    pub message_expiry: IggyExpiry,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AuditLogConfig {
+    pub enabled: bool,
+    pub path: String,
+    pub max_file_size: IggyByteSize,
+    pub recent_entries: u32,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ExpiryNotificationsConfig {
+    pub enabled: bool,
+    pub stream: String,
+    #[serde_as(as = "DisplayFromStr")]
+    pub message_expiry: IggyExpiry,
+    #[serde_as(as = "DisplayFromStr")]
+    pub grace_period: IggyDuration,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct FetchQuotasConfig {
+    pub user_bytes_per_second: IggyByteSize,
+    pub client_bytes_per_second: IggyByteSize,
+    pub consumer_group_bytes_per_second: IggyByteSize,
+    #[serde_as(as = "DisplayFromStr")]
+    pub max_throttle_delay: IggyDuration,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct QuotasConfig {
+    pub user_produce_bytes_per_second: IggyByteSize,
+    pub client_produce_bytes_per_second: IggyByteSize,
+    pub user_requests_per_second: u32,
+    pub client_requests_per_second: u32,
+    #[serde_as(as = "DisplayFromStr")]
+    pub max_throttle_delay: IggyDuration,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AuthenticationConfig {
+    pub authenticators: Vec<AuthenticatorKindType>,
+    pub allow_impersonation: bool,
+    pub oidc: OidcAuthenticatorConfig,
+    pub mtls: MtlsAuthenticatorConfig,
+    pub dynamic_library: DynamicLibraryAuthenticatorConfig,
+    pub grpc: GrpcAuthenticatorConfig,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct OidcAuthenticatorConfig {
+    pub token_endpoint: String,
+    pub client_id: String,
+    pub client_secret: String,
+    pub issuer: String,
+    pub username_claim: String,
+    #[serde_as(as = "DisplayFromStr")]
+    pub timeout: IggyDuration,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct MtlsAuthenticatorConfig {
+    pub certificate_mappings: Vec<String>,
+    pub subject_mappings: Vec<String>,
+    pub subject_as_username: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct DynamicLibraryAuthenticatorConfig {
+    pub path: String,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct GrpcAuthenticatorConfig {
+    pub endpoint: String,
+    #[serde_as(as = "DisplayFromStr")]
+    pub timeout: IggyDuration,
+    pub tls_ca_file: String,
+    pub tls_cert_file: String,
+    pub tls_key_file: String,
+    pub tls_domain: String,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ClusterConfig {
+    pub enabled: bool,
+    pub node_id: u32,
+    pub address: String,
+    pub nodes: Vec<String>,
+    pub secret: String,
+    #[serde_as(as = "DisplayFromStr")]
+    pub election_timeout_min: IggyDuration,
+    #[serde_as(as = "DisplayFromStr")]
+    pub election_timeout_max: IggyDuration,
+    #[serde_as(as = "DisplayFromStr")]
+    pub heartbeat_interval: IggyDuration,
+    #[serde_as(as = "DisplayFromStr")]
+    pub replication_timeout: IggyDuration,
+    pub max_entries_per_append: u32,
+    pub snapshot_threshold: u32,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ConsumerOffsetsConfig {
+    pub backend: ConsumerOffsetsBackend,
+    pub log: LogConsumerOffsetsConfig,
+    pub redis: RedisConsumerOffsetsConfig,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct LogConsumerOffsetsConfig {
+    pub batch_size: u32,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct RedisConsumerOffsetsConfig {
+    pub url: String,
+    pub key_prefix: String,
 }
 
 #[serde_as]
 #[derive(Debug, Deserialize, Serialize)]
 pub struct SegmentConfig {
     pub size: IggyByteSize,
-    pub cache_indexes: bool,
+    pub index_cache_size: IggyByteSize,
     #[serde_as(as = "DisplayFromStr")]
     pub message_expiry: IggyExpiry,
     pub archive_expired: bool,
     #[serde_as(as = "DisplayFromStr")]
     pub server_confirmation: Confirmation,
+    pub format_version: u8,
+    pub verify_batch_checksums: bool,
 }
 
 #[serde_as]
@@ -174,10 +430,50 @@ impl SystemConfig {
         format!("{}/tokens", self.get_state_path())
     }
 
+    pub fn get_state_push_subscriptions_path(&self) -> String {
+        format!("{}/push_subscriptions", self.get_state_path())
+    }
+
+    pub fn get_state_producer_epochs_path(&self) -> String {
+        format!("{}/producer_epochs", self.get_state_path())
+    }
+
+    pub fn get_state_transactions_path(&self) -> String {
+        format!("{}/transactions", self.get_state_path())
+    }
+
+    pub fn get_state_raft_path(&self) -> String {
+        format!("{}/raft", self.get_state_path())
+    }
+
+    pub fn get_state_raft_log_path(&self) -> String {
+        format!("{}/raft_log", self.get_state_path())
+    }
+
+    pub fn get_state_cache_heat_map_path(&self) -> String {
+        format!("{}/cache_heat_map", self.get_state_path())
+    }
+
+    pub fn get_audit_log_path(&self) -> String {
+        format!("{}/{}", self.get_system_path(), self.audit_log.path)
+    }
+
+    pub fn get_topic_snapshots_path(&self) -> String {
+        format!("{}/topic_snapshots", self.get_system_path())
+    }
+
+    pub fn get_topic_snapshot_path(&self, stream_id: u32, topic_id: u32) -> String {
+        format!("{}/{stream_id}/{topic_id}", self.get_topic_snapshots_path())
+    }
+
     pub fn get_backup_path(&self) -> String {
         format!("{}/{}", self.get_system_path(), self.backup.path)
     }
 
+    pub fn get_data_backups_path(&self) -> String {
+        format!("{}/data", self.get_backup_path())
+    }
+
     pub fn get_compatibility_backup_path(&self) -> String {
         format!(
             "{}/{}",
@@ -16,6 +16,7 @@
  * under the License.
  */
 
+use crate::configs::limits::TransportLimitsConfig;
 use iggy::utils::{byte_size::IggyByteSize, duration::IggyDuration};
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
@@ -26,8 +27,12 @@ pub struct TcpConfig {
     pub enabled: bool,
     pub address: String,
     pub ipv6: bool,
+    pub acceptors: u32,
+    pub zero_copy: bool,
     pub tls: TcpTlsConfig,
     pub socket: TcpSocketConfig,
+    pub heartbeat: TcpHeartbeatConfig,
+    pub limits: TransportLimitsConfig,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -35,6 +40,18 @@ pub struct TcpTlsConfig {
     pub enabled: bool,
     pub certificate: String,
     pub password: String,
+    pub cert_file: String,
+    pub key_file: String,
+    pub client_ca_file: String,
+    pub require_client_certificate: bool,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct TcpHeartbeatConfig {
+    pub enabled: bool,
+    #[serde_as(as = "DisplayFromStr")]
+    pub timeout: IggyDuration,
 }
 
 #[serde_as]
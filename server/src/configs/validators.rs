@@ -19,24 +19,50 @@
 extern crate sysinfo;
 
 use super::server::{
-    ArchiverConfig, DataMaintenanceConfig, MessageSaverConfig, MessagesMaintenanceConfig,
-    StateMaintenanceConfig, TelemetryConfig,
+    ArchiverConfig, CompactionMaintenanceConfig, DataMaintenanceConfig, MessageSaverConfig,
+    MessagesMaintenanceConfig, PreflightConfig, StateMaintenanceConfig, TelemetryConfig,
+    TieredStorageConfig, TopicSnapshotsMaintenanceConfig,
 };
 use super::system::CompressionConfig;
+use crate::archiver::azure::AzureAuthKind;
+use crate::archiver::gcs::GcsAuthKind;
 use crate::archiver::ArchiverKindType;
-use crate::configs::server::{PersonalAccessTokenConfig, ServerConfig};
-use crate::configs::system::{CacheConfig, SegmentConfig};
+use crate::authenticator::grpc::validate_grpc_config;
+use crate::authenticator::mtls::{parse_certificate_mapping, parse_subject_mapping};
+use crate::authenticator::oidc::validate_oidc_config;
+use crate::authenticator::AuthenticatorKindType;
+use crate::cluster::parse_cluster_node;
+use crate::configs::http::HttpConfig;
+use crate::configs::mqtt::MqttBridgeConfig;
+use crate::configs::quic::QuicConfig;
+use crate::configs::server::{parse_resource_attribute, PersonalAccessTokenConfig, ServerConfig};
+use crate::configs::system::{
+    AuditLogConfig, AuthenticationConfig, CacheConfig, ClusterConfig, ConsumerOffsetsConfig,
+    ExpiryNotificationsConfig, IoUringConfig, MessageAuditConfig, MessageIdConfig,
+    MetadataChangesConfig, PushSubscriptionsConfig, ReadAheadConfig, ReplayConfig,
+    ResourceLimitsConfig, SegmentConfig, TransactionsConfig,
+};
+use crate::configs::tcp::TcpConfig;
 use crate::configs::COMPONENT;
+use crate::http::jwt::oidc::parse_role_mapping;
+use crate::mqtt::mapping::parse_topic_mapping;
 use crate::server_error::ConfigError;
+use crate::streaming::consumer_offsets::ConsumerOffsetsBackend;
 use crate::streaming::segments::*;
+use crate::streaming::utils::message_id::MAX_SNOWFLAKE_NODE_ID;
 use error_set::ErrContext;
-use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer_groups::offset_recovery_policy::OffsetRecoveryPolicy;
+use iggy::messages::message_id_scheme::MessageIdScheme;
+use iggy::topics::cleanup_policy::CleanupPolicy;
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::expiry::IggyExpiry;
 use iggy::utils::topic_size::MaxTopicSize;
 use iggy::validatable::Validatable;
 use sysinfo::{Pid, ProcessesToUpdate, System};
 
+/// The maximum number of the submission queue entries supported by the kernel.
+const MAX_IO_URING_QUEUE_DEPTH: u32 = 32768;
+
 impl Validatable<ConfigError> for ServerConfig {
     fn validate(&self) -> Result<(), ConfigError> {
         self.data_maintenance
@@ -49,6 +75,21 @@ impl Validatable<ConfigError> for ServerConfig {
             .with_error_context(|error| {
                 format!("{COMPONENT} (error: {error}) - failed to validate personal access token config")
             })?;
+        self.preflight.validate().with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to validate preflight config")
+        })?;
+        self.tcp.validate().with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to validate TCP config")
+        })?;
+        self.quic.validate().with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to validate QUIC config")
+        })?;
+        self.http.validate().with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to validate HTTP config")
+        })?;
+        self.mqtt.validate().with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to validate MQTT bridge config")
+        })?;
         self.system.segment.validate().with_error_context(|error| {
             format!("{COMPONENT} (error: {error}) - failed to validate segment config")
         })?;
@@ -64,6 +105,87 @@ impl Validatable<ConfigError> for ServerConfig {
         self.telemetry.validate().with_error_context(|error| {
             format!("{COMPONENT} (error: {error}) - failed to validate telemetry config")
         })?;
+        self.system
+            .message_id
+            .validate()
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to validate message ID config")
+            })?;
+        self.system.replay.validate().with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to validate replay config")
+        })?;
+        self.system.limits.validate().with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to validate resource limits config")
+        })?;
+        self.system
+            .transactions
+            .validate()
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to validate transactions config")
+            })?;
+        self.system
+            .push_subscriptions
+            .validate()
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to validate push subscriptions config"
+                )
+            })?;
+        self.system
+            .metadata_changes
+            .validate()
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to validate metadata changes config")
+            })?;
+        self.system
+            .message_audit
+            .validate()
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to validate message audit config")
+            })?;
+        self.system
+            .audit_log
+            .validate()
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to validate audit log config")
+            })?;
+        self.system
+            .expiry_notifications
+            .validate()
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to validate expiry notifications config"
+                )
+            })?;
+        self.system
+            .partition
+            .read_ahead
+            .validate()
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to validate read-ahead config")
+            })?;
+        self.system
+            .partition
+            .io_uring
+            .validate()
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to validate io_uring config")
+            })?;
+        self.system
+            .consumer_offsets
+            .validate()
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to validate consumer offsets config")
+            })?;
+        self.system
+            .authentication
+            .validate()
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to validate authentication config")
+            })?;
+        self.system.cluster.validate().with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to validate cluster config")
+        })?;
 
         let topic_size = match self.system.topic.max_size {
             MaxTopicSize::Custom(size) => Ok(size.as_bytes_u64()),
@@ -75,6 +197,18 @@ impl Validatable<ConfigError> for ServerConfig {
             return Err(ConfigError::InvalidConfiguration);
         }
 
+        if self.system.topic.cleanup_policy == CleanupPolicy::ServerDefault {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        if self.system.topic.consumer_offset_recovery == OffsetRecoveryPolicy::ServerDefault {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        if self.system.topic.deletion_check_interval.is_zero() {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
         if self.http.enabled {
             if let IggyExpiry::ServerDefault = self.http.jwt.access_token_expiry {
                 return Err(ConfigError::InvalidConfiguration);
@@ -91,15 +225,6 @@ impl Validatable<ConfigError> for ServerConfig {
 
 impl Validatable<ConfigError> for CompressionConfig {
     fn validate(&self) -> Result<(), ConfigError> {
-        let compression_alg = &self.default_algorithm;
-        if *compression_alg != CompressionAlgorithm::None {
-            // TODO(numinex): Change this message once server side compression is fully developed.
-            println!(
-                "Server started with server-side compression enabled, using algorithm: {}, this feature is not implemented yet!",
-                compression_alg
-            );
-        }
-
         Ok(())
     }
 }
@@ -122,6 +247,21 @@ impl Validatable<ConfigError> for TelemetryConfig {
             return Err(ConfigError::InvalidConfiguration);
         }
 
+        if self
+            .resource_attributes
+            .iter()
+            .filter(|attribute| !attribute.is_empty())
+            .any(|attribute| parse_resource_attribute(attribute).is_err())
+        {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        if self.metrics.enabled
+            && (self.metrics.endpoint.is_empty() || self.metrics.interval.is_zero())
+        {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
         Ok(())
     }
 }
@@ -152,6 +292,12 @@ impl Validatable<ConfigError> for CacheConfig {
             return Err(ConfigError::CacheConfigValidationFailure);
         }
 
+        if self.warmup.enabled
+            && (self.warmup.partitions == 0 || self.warmup.heat_map_interval.is_zero())
+        {
+            return Err(ConfigError::CacheConfigValidationFailure);
+        }
+
         if limit_bytes > (total_memory as f64 * 0.75) as u64 {
             println!(
                 "Cache configuration -> cache size exceeds 75% of total memory. Set to: {} ({:.2}% of total memory: {}).",
@@ -168,12 +314,70 @@ impl Validatable<ConfigError> for CacheConfig {
     }
 }
 
+impl Validatable<ConfigError> for TcpConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if self.acceptors == 0 {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        if self.heartbeat.enabled && self.heartbeat.timeout.is_zero() {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        if self.tls.require_client_certificate && self.tls.client_ca_file.is_empty() {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for QuicConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if self.certificate.require_client_certificate && self.certificate.client_ca_file.is_empty()
+        {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for HttpConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if self.acceptors == 0 {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        if self.oidc.enabled
+            && (self.oidc.issuer.is_empty()
+                || self.oidc.jwks_uri.is_empty()
+                || self.oidc.username_claim.is_empty()
+                || self
+                    .oidc
+                    .role_mappings
+                    .iter()
+                    .filter(|mapping| !mapping.is_empty())
+                    .any(|mapping| parse_role_mapping(mapping).is_err()))
+        {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
 impl Validatable<ConfigError> for SegmentConfig {
     fn validate(&self) -> Result<(), ConfigError> {
         if self.size > SEGMENT_MAX_SIZE_BYTES {
             return Err(ConfigError::InvalidConfiguration);
         }
 
+        if !(LEGACY_SEGMENT_FORMAT_VERSION..=SEGMENT_FORMAT_VERSION).contains(&self.format_version)
+        {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
         Ok(())
     }
 }
@@ -188,6 +392,16 @@ impl Validatable<ConfigError> for MessageSaverConfig {
     }
 }
 
+impl Validatable<ConfigError> for PreflightConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if self.enabled && (self.max_clock_drift.is_zero() || self.archiver_timeout.is_zero()) {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
 impl Validatable<ConfigError> for DataMaintenanceConfig {
     fn validate(&self) -> Result<(), ConfigError> {
         self.archiver.validate().with_error_context(|error| {
@@ -199,6 +413,17 @@ impl Validatable<ConfigError> for DataMaintenanceConfig {
         self.state.validate().with_error_context(|error| {
             format!("{COMPONENT} (error: {error}) - failed to validate state maintenance config")
         })?;
+        self.topic_snapshots.validate().with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to validate topic snapshots maintenance config")
+        })?;
+        self.tiered_storage.validate().with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to validate tiered storage config")
+        })?;
+        self.compaction.validate().with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to validate compaction maintenance config"
+            )
+        })?;
         Ok(())
     }
 }
@@ -250,6 +475,66 @@ impl Validatable<ConfigError> for ArchiverConfig {
                 }
                 Ok(())
             }
+            ArchiverKindType::Gcs => {
+                let Some(gcs) = self.gcs.as_ref() else {
+                    return Err(ConfigError::InvalidConfiguration);
+                };
+
+                if gcs.bucket.is_empty() {
+                    return Err(ConfigError::InvalidConfiguration);
+                }
+
+                if gcs.auth == GcsAuthKind::ServiceAccount
+                    && gcs
+                        .credentials_path
+                        .as_deref()
+                        .unwrap_or_default()
+                        .is_empty()
+                {
+                    return Err(ConfigError::InvalidConfiguration);
+                }
+
+                if gcs.max_retries > 0
+                    && (gcs.retry_initial_backoff.is_zero()
+                        || gcs.retry_initial_backoff.as_micros()
+                            > gcs.retry_max_backoff.as_micros())
+                {
+                    return Err(ConfigError::InvalidConfiguration);
+                }
+                Ok(())
+            }
+            ArchiverKindType::Azure => {
+                let Some(azure) = self.azure.as_ref() else {
+                    return Err(ConfigError::InvalidConfiguration);
+                };
+
+                if azure.container.is_empty() {
+                    return Err(ConfigError::InvalidConfiguration);
+                }
+
+                let is_empty =
+                    |value: &Option<String>| value.as_deref().unwrap_or_default().is_empty();
+                match azure.auth {
+                    AzureAuthKind::ConnectionString if is_empty(&azure.connection_string) => {
+                        return Err(ConfigError::InvalidConfiguration);
+                    }
+                    AzureAuthKind::ManagedIdentity
+                        if is_empty(&azure.account_name) && is_empty(&azure.endpoint) =>
+                    {
+                        return Err(ConfigError::InvalidConfiguration);
+                    }
+                    _ => {}
+                }
+
+                if azure.max_retries > 0
+                    && (azure.retry_initial_backoff.is_zero()
+                        || azure.retry_initial_backoff.as_micros()
+                            > azure.retry_max_backoff.as_micros())
+                {
+                    return Err(ConfigError::InvalidConfiguration);
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -274,6 +559,40 @@ impl Validatable<ConfigError> for StateMaintenanceConfig {
     }
 }
 
+impl Validatable<ConfigError> for TopicSnapshotsMaintenanceConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if self.enabled && (self.interval.is_zero() || self.max_messages == 0) {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for TieredStorageConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.cache_path.is_empty() || self.max_cached_segments == 0 {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for CompactionMaintenanceConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if self.enabled && self.interval.is_zero() {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
 impl Validatable<ConfigError> for PersonalAccessTokenConfig {
     fn validate(&self) -> Result<(), ConfigError> {
         if self.max_tokens_per_user == 0 {
@@ -284,6 +603,319 @@ impl Validatable<ConfigError> for PersonalAccessTokenConfig {
             return Err(ConfigError::InvalidConfiguration);
         }
 
+        let login_guard = &self.login_guard;
+        if login_guard.enabled
+            && (login_guard.max_failed_attempts == 0
+                || login_guard.failed_attempts_window.is_zero()
+                || login_guard.block_duration.is_zero()
+                || login_guard.max_new_ip_addresses == 0
+                || login_guard.new_ip_addresses_window.is_zero())
+        {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for AuthenticationConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if self.authenticators.is_empty() {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        for authenticator in &self.authenticators {
+            let is_valid = match authenticator {
+                AuthenticatorKindType::Internal => true,
+                AuthenticatorKindType::Oidc => validate_oidc_config(&self.oidc).is_ok(),
+                AuthenticatorKindType::Mtls => {
+                    self.mtls
+                        .certificate_mappings
+                        .iter()
+                        .all(|mapping| parse_certificate_mapping(mapping).is_ok())
+                        && self
+                            .mtls
+                            .subject_mappings
+                            .iter()
+                            .all(|mapping| parse_subject_mapping(mapping).is_ok())
+                }
+                AuthenticatorKindType::DynamicLibrary => !self.dynamic_library.path.is_empty(),
+                AuthenticatorKindType::Grpc => validate_grpc_config(&self.grpc).is_ok(),
+            };
+
+            if !is_valid {
+                return Err(ConfigError::InvalidConfiguration);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for MetadataChangesConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.stream.is_empty() || self.stream.len() > 255 {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        if let IggyExpiry::ServerDefault = self.message_expiry {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for MessageAuditConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if !(self.sample_rate > 0.0 && self.sample_rate <= 1.0) {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        if self.stream.is_empty() || self.stream.len() > 255 {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        if let IggyExpiry::ServerDefault = self.message_expiry {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for AuditLogConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.path.is_empty() {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        if self.max_file_size.as_bytes_u64() == 0 {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        if self.recent_entries == 0 {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for ExpiryNotificationsConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.stream.is_empty() || self.stream.len() > 255 {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        if let IggyExpiry::ServerDefault = self.message_expiry {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for ReadAheadConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.min_sequential_polls == 0 || self.max_messages == 0 || self.lookahead.is_zero() {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for IoUringConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if cfg!(not(all(target_os = "linux", feature = "io-uring"))) {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        if self.queue_depth == 0 || self.queue_depth > MAX_IO_URING_QUEUE_DEPTH {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for MqttBridgeConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        let is_valid_address = self
+            .address
+            .rsplit_once(':')
+            .is_some_and(|(host, port)| !host.is_empty() && port.parse::<u16>().is_ok());
+        if !is_valid_address
+            || self.client_id.is_empty()
+            || self.qos > 2
+            || self.max_batch_size == 0
+            || self.reconnect_interval.is_zero()
+            || (!self.keep_alive.is_zero() && self.keep_alive.as_secs() < 1)
+            || self.mappings.is_empty()
+            || self
+                .mappings
+                .iter()
+                .any(|mapping| parse_topic_mapping(mapping).is_err())
+        {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for ConsumerOffsetsConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        match self.backend {
+            ConsumerOffsetsBackend::File => Ok(()),
+            ConsumerOffsetsBackend::Log => {
+                if self.log.batch_size == 0 {
+                    return Err(ConfigError::InvalidConfiguration);
+                }
+                Ok(())
+            }
+            #[cfg(feature = "redis")]
+            ConsumerOffsetsBackend::Redis => {
+                if self.redis.key_prefix.is_empty()
+                    || redis::Client::open(self.redis.url.as_str()).is_err()
+                {
+                    return Err(ConfigError::InvalidConfiguration);
+                }
+                Ok(())
+            }
+            #[cfg(not(feature = "redis"))]
+            ConsumerOffsetsBackend::Redis => Err(ConfigError::InvalidConfiguration),
+        }
+    }
+}
+
+impl Validatable<ConfigError> for TransactionsConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if self.timeout.is_zero() || self.expiry_check_interval.is_zero() {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for PushSubscriptionsConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.max_subscriptions == 0
+            || self.idle_interval.is_zero()
+            || self.request_timeout.is_zero()
+            || self.retry_initial_backoff.is_zero()
+        {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        if self.retry_max_backoff.get_duration() < self.retry_initial_backoff.get_duration() {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for MessageIdConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if self.scheme == MessageIdScheme::ServerDefault || self.node_id > MAX_SNOWFLAKE_NODE_ID {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for ReplayConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if self.max_running_jobs == 0 {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for ResourceLimitsConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if self.max_total_partitions > 0
+            && self.max_partitions_per_topic > self.max_total_partitions
+        {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for ClusterConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.node_id == 0
+            || self.address.is_empty()
+            || self.secret.is_empty()
+            || self.max_entries_per_append == 0
+            || self.snapshot_threshold == 0
+        {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        if self.heartbeat_interval.is_zero()
+            || self.replication_timeout.is_zero()
+            || self.election_timeout_min.get_duration() <= self.heartbeat_interval.get_duration()
+            || self.election_timeout_max.get_duration() < self.election_timeout_min.get_duration()
+        {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        let mut node_ids = vec![self.node_id];
+        for node in &self.nodes {
+            let Ok(node) = parse_cluster_node(node) else {
+                return Err(ConfigError::InvalidConfiguration);
+            };
+            if node_ids.contains(&node.id) {
+                return Err(ConfigError::InvalidConfiguration);
+            }
+            node_ids.push(node.id);
+        }
+
         Ok(())
     }
 }
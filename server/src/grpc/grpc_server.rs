@@ -0,0 +1,129 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::configs::grpc::GrpcConfig;
+use crate::grpc::grpc_service::IggyGrpcService;
+use crate::grpc::proto::iggy_server::IggyServer;
+use crate::streaming::clients::transport_limiter::{ConnectionPermit, TransportLimiter};
+use crate::streaming::systems::system::SharedSystem;
+use std::io;
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+use tokio::net::{TcpListener, TcpStream};
+use tonic::transport::server::{Connected, TcpConnectInfo};
+use tonic::transport::Server;
+use tracing::{error, info, warn};
+
+/// Starts the gRPC server.
+/// Returns the address the server is listening on.
+pub async fn start(config: GrpcConfig, system: SharedSystem) -> SocketAddr {
+    info!("Initializing Iggy gRPC server...");
+    let listener = TcpListener::bind(&config.address)
+        .await
+        .unwrap_or_else(|error| {
+            panic!(
+                "Unable to bind gRPC server to address {}: {error}",
+                config.address
+            )
+        });
+    let address = listener
+        .local_addr()
+        .expect("Failed to get local address for gRPC server");
+
+    let limiter = TransportLimiter::register("gRPC", &config.limits);
+    let mut service = IggyServer::new(IggyGrpcService::new(system, limiter.clone()));
+    if let Some(max_frame_size) = limiter.max_frame_size() {
+        service = service.max_decoding_message_size(max_frame_size as usize);
+    }
+
+    let incoming = futures::stream::unfold(listener, move |listener| {
+        let limiter = limiter.clone();
+        async move {
+            loop {
+                match listener.accept().await {
+                    Ok((stream, address)) => match limiter.try_acquire_connection() {
+                        Ok(permit) => {
+                            let connection = LimitedConnection {
+                                stream,
+                                _permit: permit,
+                            };
+                            return Some((Ok::<_, io::Error>(connection), listener));
+                        }
+                        Err(error) => warn!("Rejected gRPC connection: {address}. {error}"),
+                    },
+                    Err(error) => error!("Unable to accept gRPC connection. {error}"),
+                }
+            }
+        }
+    });
+
+    tokio::spawn(async move {
+        if let Err(error) = Server::builder()
+            .add_service(service)
+            .serve_with_incoming(incoming)
+            .await
+        {
+            error!("Failed to start gRPC server, error: {error}");
+        }
+    });
+    info!("Iggy gRPC server has started on: {address}");
+    address
+}
+
+/// Keeps the connection slot acquired until the connection is closed.
+struct LimitedConnection {
+    stream: TcpStream,
+    _permit: ConnectionPermit,
+}
+
+impl Connected for LimitedConnection {
+    type ConnectInfo = TcpConnectInfo;
+
+    fn connect_info(&self) -> Self::ConnectInfo {
+        self.stream.connect_info()
+    }
+}
+
+impl AsyncRead for LimitedConnection {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.stream).poll_read(cx, buf)
+    }
+}
+
+impl AsyncWrite for LimitedConnection {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.stream).poll_write(cx, buf)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.stream).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.stream).poll_shutdown(cx)
+    }
+}
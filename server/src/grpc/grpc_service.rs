@@ -0,0 +1,209 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::grpc::handlers;
+use crate::grpc::mapper::map_error;
+use crate::grpc::proto;
+use crate::grpc::proto::iggy_server::Iggy;
+use crate::streaming::clients::transport_limiter::{RequestPermit, TransportLimiter};
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use iggy::error::IggyError;
+use std::sync::Arc;
+use tonic::{Request, Response, Status};
+
+const AUTHORIZATION: &str = "authorization";
+const BEARER_PREFIX: &str = "Bearer ";
+
+/// Handles the authenticated call with the handler taking the system, the session and the request message.
+macro_rules! handle {
+    ($self:ident, $request:ident, $handler:path) => {{
+        let (_request_permit, session) = $self.authenticate(&$request).await?;
+        $handler(&$self.system, &session, $request.into_inner())
+            .await
+            .map(Response::new)
+            .map_err(map_error)
+    }};
+}
+
+/// The calls are stateless, each of them is authenticated with the personal access token
+/// and handled within a session not registered in the client manager.
+pub struct IggyGrpcService {
+    system: SharedSystem,
+    limiter: Arc<TransportLimiter>,
+}
+
+impl IggyGrpcService {
+    pub fn new(system: SharedSystem, limiter: Arc<TransportLimiter>) -> Self {
+        Self { system, limiter }
+    }
+
+    async fn authenticate<T>(
+        &self,
+        request: &Request<T>,
+    ) -> Result<(RequestPermit, Session), Status> {
+        let request_permit = self.limiter.try_acquire_request().map_err(map_error)?;
+        let Some(ip_address) = request.remote_addr() else {
+            return Err(Status::internal("Missing remote address of the gRPC call"));
+        };
+        let Some(token) = request
+            .metadata()
+            .get(AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix(BEARER_PREFIX))
+        else {
+            return Err(map_error(IggyError::AccessTokenMissing));
+        };
+
+        let system = self.system.read().await;
+        let user = system
+            .login_with_personal_access_token(token, None, Some(ip_address.ip()))
+            .await
+            .map_err(map_error)?;
+        Ok((request_permit, Session::stateless(user.id, ip_address)))
+    }
+}
+
+#[tonic::async_trait]
+impl Iggy for IggyGrpcService {
+    async fn ping(
+        &self,
+        _request: Request<proto::Empty>,
+    ) -> Result<Response<proto::Empty>, Status> {
+        Ok(Response::new(proto::Empty {}))
+    }
+
+    async fn get_stream(
+        &self,
+        request: Request<proto::GetStreamRequest>,
+    ) -> Result<Response<proto::StreamDetails>, Status> {
+        handle!(self, request, handlers::get_stream)
+    }
+
+    async fn get_streams(
+        &self,
+        request: Request<proto::Empty>,
+    ) -> Result<Response<proto::Streams>, Status> {
+        let (_request_permit, session) = self.authenticate(&request).await?;
+        handlers::get_streams(&self.system, &session)
+            .await
+            .map(Response::new)
+            .map_err(map_error)
+    }
+
+    async fn create_stream(
+        &self,
+        request: Request<proto::CreateStreamRequest>,
+    ) -> Result<Response<proto::StreamDetails>, Status> {
+        handle!(self, request, handlers::create_stream)
+    }
+
+    async fn delete_stream(
+        &self,
+        request: Request<proto::DeleteStreamRequest>,
+    ) -> Result<Response<proto::Empty>, Status> {
+        handle!(self, request, handlers::delete_stream)
+    }
+
+    async fn get_topic(
+        &self,
+        request: Request<proto::GetTopicRequest>,
+    ) -> Result<Response<proto::TopicDetails>, Status> {
+        handle!(self, request, handlers::get_topic)
+    }
+
+    async fn get_topics(
+        &self,
+        request: Request<proto::GetTopicsRequest>,
+    ) -> Result<Response<proto::Topics>, Status> {
+        handle!(self, request, handlers::get_topics)
+    }
+
+    async fn create_topic(
+        &self,
+        request: Request<proto::CreateTopicRequest>,
+    ) -> Result<Response<proto::TopicDetails>, Status> {
+        handle!(self, request, handlers::create_topic)
+    }
+
+    async fn delete_topic(
+        &self,
+        request: Request<proto::DeleteTopicRequest>,
+    ) -> Result<Response<proto::Empty>, Status> {
+        handle!(self, request, handlers::delete_topic)
+    }
+
+    async fn send_messages(
+        &self,
+        request: Request<proto::SendMessagesRequest>,
+    ) -> Result<Response<proto::Empty>, Status> {
+        handle!(self, request, handlers::send_messages)
+    }
+
+    async fn poll_messages(
+        &self,
+        request: Request<proto::PollMessagesRequest>,
+    ) -> Result<Response<proto::PolledMessages>, Status> {
+        handle!(self, request, handlers::poll_messages)
+    }
+
+    async fn get_consumer_offset(
+        &self,
+        request: Request<proto::GetConsumerOffsetRequest>,
+    ) -> Result<Response<proto::ConsumerOffsetInfo>, Status> {
+        handle!(self, request, handlers::get_consumer_offset)
+    }
+
+    async fn store_consumer_offset(
+        &self,
+        request: Request<proto::StoreConsumerOffsetRequest>,
+    ) -> Result<Response<proto::Empty>, Status> {
+        handle!(self, request, handlers::store_consumer_offset)
+    }
+
+    async fn get_user(
+        &self,
+        request: Request<proto::GetUserRequest>,
+    ) -> Result<Response<proto::UserInfo>, Status> {
+        handle!(self, request, handlers::get_user)
+    }
+
+    async fn get_users(
+        &self,
+        request: Request<proto::Empty>,
+    ) -> Result<Response<proto::Users>, Status> {
+        let (_request_permit, session) = self.authenticate(&request).await?;
+        handlers::get_users(&self.system, &session)
+            .await
+            .map(Response::new)
+            .map_err(map_error)
+    }
+
+    async fn create_user(
+        &self,
+        request: Request<proto::CreateUserRequest>,
+    ) -> Result<Response<proto::UserInfo>, Status> {
+        handle!(self, request, handlers::create_user)
+    }
+
+    async fn delete_user(
+        &self,
+        request: Request<proto::DeleteUserRequest>,
+    ) -> Result<Response<proto::Empty>, Status> {
+        handle!(self, request, handlers::delete_user)
+    }
+}
@@ -0,0 +1,639 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::grpc::mapper::{map_code, map_identifier, map_message, map_metadata};
+use crate::grpc::{mapper, proto, COMPONENT};
+use crate::http::mapper as models_mapper;
+use crate::state::command::EntryCommand;
+use crate::state::models::{CreateStreamWithId, CreateTopicWithId, CreateUserWithId};
+use crate::streaming::session::Session;
+use crate::streaming::systems::messages::PollingArgs;
+use crate::streaming::systems::system::{SharedSystem, System};
+use crate::streaming::utils::crypto;
+use error_set::ErrContext;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::{Consumer, ConsumerKind};
+use iggy::consumer_offsets::get_consumer_offset::GetConsumerOffset;
+use iggy::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
+use iggy::error::IggyError;
+use iggy::messages::message_filter::MessageFilter;
+use iggy::messages::message_id_scheme::MessageIdScheme;
+use iggy::messages::poll_messages::{IsolationLevel, PollMessages, PollingKind, PollingStrategy};
+use iggy::messages::send_messages::{Partitioning, PartitioningKind, SendMessages};
+use iggy::models::metadata::MetadataFilter;
+use iggy::models::user_status::UserStatus;
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::topics::cleanup_policy::CleanupPolicy;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::topics::delete_topic::DeleteTopic;
+use iggy::users::create_user::CreateUser;
+use iggy::users::delete_user::DeleteUser;
+use iggy::validatable::Validatable;
+use std::str::FromStr;
+
+/// Applies the same checks as the binary protocol does before handling a command.
+fn ensure_allowed(system: &System, is_read_only: bool) -> Result<(), IggyError> {
+    if !is_read_only {
+        system.ensure_writable()?;
+        system.ensure_cluster_leader()?;
+    }
+    system.ensure_not_in_maintenance(is_read_only)
+}
+
+fn map_consumer(consumer: Option<proto::Consumer>) -> Result<Consumer, IggyError> {
+    let Some(consumer) = consumer else {
+        return Ok(Consumer::default());
+    };
+
+    Ok(Consumer {
+        kind: map_code(consumer.kind, ConsumerKind::from_code)?,
+        id: map_identifier(&consumer.id)?,
+    })
+}
+
+pub async fn get_stream(
+    system: &SharedSystem,
+    session: &Session,
+    request: proto::GetStreamRequest,
+) -> Result<proto::StreamDetails, IggyError> {
+    let stream_id = map_identifier(&request.stream_id)?;
+    let system = system.read().await;
+    ensure_allowed(&system, true)?;
+    let Some(stream) = system.try_find_stream(session, &stream_id)? else {
+        return Err(IggyError::ResourceNotFound(request.stream_id));
+    };
+
+    Ok(mapper::map_stream_details(&models_mapper::map_stream(
+        stream,
+    )))
+}
+
+pub async fn get_streams(
+    system: &SharedSystem,
+    session: &Session,
+) -> Result<proto::Streams, IggyError> {
+    let system = system.read().await;
+    ensure_allowed(&system, true)?;
+    let streams = system
+        .find_streams(session, &MetadataFilter::default())
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to find streams, session: {session}")
+        })?;
+    Ok(proto::Streams {
+        streams: models_mapper::map_streams(&streams)
+            .iter()
+            .map(mapper::map_stream)
+            .collect(),
+    })
+}
+
+pub async fn create_stream(
+    system: &SharedSystem,
+    session: &Session,
+    request: proto::CreateStreamRequest,
+) -> Result<proto::StreamDetails, IggyError> {
+    let command = CreateStream {
+        stream_id: request.stream_id,
+        name: request.name,
+        metadata: map_metadata(request.metadata),
+        namespace_id: None,
+    };
+    command.validate()?;
+
+    let mut system = system.write().await;
+    ensure_allowed(&system, false)?;
+    let stream = system
+        .create_stream(
+            session,
+            command.stream_id,
+            &command.name,
+            command.metadata.clone(),
+            command.namespace_id,
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to create stream, stream ID: {:?}",
+                command.stream_id
+            )
+        })?;
+    let stream_id = stream.stream_id;
+    let command = CreateStream {
+        namespace_id: stream.namespace_id,
+        ..command
+    };
+    let response = mapper::map_stream_details(&models_mapper::map_stream(stream));
+
+    let system = system.downgrade();
+    system
+        .state
+        .apply(
+            session.get_user_id(),
+            EntryCommand::CreateStream(CreateStreamWithId { stream_id, command }),
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to apply create stream, stream ID: {stream_id}"
+            )
+        })?;
+    Ok(response)
+}
+
+pub async fn delete_stream(
+    system: &SharedSystem,
+    session: &Session,
+    request: proto::DeleteStreamRequest,
+) -> Result<proto::Empty, IggyError> {
+    let stream_id = map_identifier(&request.stream_id)?;
+    let mut system = system.write().await;
+    ensure_allowed(&system, false)?;
+    system
+        .delete_stream(session, &stream_id)
+        .await
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to delete stream with ID: {stream_id}")
+        })?;
+
+    let system = system.downgrade();
+    system
+        .state
+        .apply(
+            session.get_user_id(),
+            EntryCommand::DeleteStream(DeleteStream {
+                stream_id: stream_id.clone(),
+            }),
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to apply delete stream with ID: {stream_id}"
+            )
+        })?;
+    Ok(proto::Empty {})
+}
+
+pub async fn get_topic(
+    system: &SharedSystem,
+    session: &Session,
+    request: proto::GetTopicRequest,
+) -> Result<proto::TopicDetails, IggyError> {
+    let stream_id = map_identifier(&request.stream_id)?;
+    let topic_id = map_identifier(&request.topic_id)?;
+    let system = system.read().await;
+    ensure_allowed(&system, true)?;
+    let Some(topic) = system.try_find_topic(session, &stream_id, &topic_id)? else {
+        return Err(IggyError::ResourceNotFound(request.topic_id));
+    };
+
+    Ok(mapper::map_topic_details(
+        &models_mapper::map_topic(topic).await,
+    ))
+}
+
+pub async fn get_topics(
+    system: &SharedSystem,
+    session: &Session,
+    request: proto::GetTopicsRequest,
+) -> Result<proto::Topics, IggyError> {
+    let stream_id = map_identifier(&request.stream_id)?;
+    let system = system.read().await;
+    ensure_allowed(&system, true)?;
+    let topics = system
+        .find_topics(session, &stream_id, &MetadataFilter::default())
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to find topics for stream with ID: {stream_id}"
+            )
+        })?;
+    Ok(proto::Topics {
+        topics: models_mapper::map_topics(&topics)
+            .iter()
+            .map(mapper::map_topic)
+            .collect(),
+    })
+}
+
+pub async fn create_topic(
+    system: &SharedSystem,
+    session: &Session,
+    request: proto::CreateTopicRequest,
+) -> Result<proto::TopicDetails, IggyError> {
+    let replication_factor = request
+        .replication_factor
+        .map(|factor| u8::try_from(factor).map_err(|_| IggyError::InvalidReplicationFactor))
+        .transpose()?;
+    let mut command = CreateTopic {
+        stream_id: map_identifier(&request.stream_id)?,
+        topic_id: request.topic_id,
+        partitions_count: request.partitions_count,
+        compression_algorithm: map_code(
+            request.compression_algorithm,
+            CompressionAlgorithm::from_code,
+        )?,
+        message_expiry: request.message_expiry.into(),
+        max_topic_size: request.max_topic_size.into(),
+        replication_factor,
+        name: request.name,
+        metadata: map_metadata(request.metadata),
+        message_id_scheme: map_code(request.message_id_scheme, MessageIdScheme::from_code)?,
+        cleanup_policy: map_code(request.cleanup_policy, CleanupPolicy::from_code)?,
+    };
+    command.validate()?;
+
+    let mut system = system.write().await;
+    ensure_allowed(&system, false)?;
+    let topic = system
+        .create_topic(
+            session,
+            &command.stream_id,
+            command.topic_id,
+            &command.name,
+            command.partitions_count,
+            command.message_expiry,
+            command.compression_algorithm,
+            command.max_topic_size,
+            command.replication_factor,
+            command.metadata.clone(),
+            command.message_id_scheme,
+            command.cleanup_policy,
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to create topic, stream ID: {}",
+                command.stream_id
+            )
+        })?;
+    command.message_expiry = topic.message_expiry;
+    command.max_topic_size = topic.max_topic_size;
+    command.message_id_scheme = topic.message_id_scheme;
+    command.cleanup_policy = topic.cleanup_policy;
+    let topic_id = topic.topic_id;
+    let response = mapper::map_topic_details(&models_mapper::map_topic(topic).await);
+
+    let system = system.downgrade();
+    let stream_id = command.stream_id.clone();
+    system
+        .state
+        .apply(
+            session.get_user_id(),
+            EntryCommand::CreateTopic(CreateTopicWithId { topic_id, command }),
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to apply create topic, stream ID: {stream_id}"
+            )
+        })?;
+    Ok(response)
+}
+
+pub async fn delete_topic(
+    system: &SharedSystem,
+    session: &Session,
+    request: proto::DeleteTopicRequest,
+) -> Result<proto::Empty, IggyError> {
+    let stream_id = map_identifier(&request.stream_id)?;
+    let topic_id = map_identifier(&request.topic_id)?;
+    let mut system = system.write().await;
+    ensure_allowed(&system, false)?;
+    system
+        .delete_topic(session, &stream_id, &topic_id)
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to delete topic with ID: {topic_id} in stream with ID: {stream_id}"
+            )
+        })?;
+
+    let system = system.downgrade();
+    system
+        .state
+        .apply(
+            session.get_user_id(),
+            EntryCommand::DeleteTopic(DeleteTopic {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+            }),
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to apply delete topic, stream ID: {stream_id}, topic ID: {topic_id}"
+            )
+        })?;
+    Ok(proto::Empty {})
+}
+
+pub async fn send_messages(
+    system: &SharedSystem,
+    session: &Session,
+    request: proto::SendMessagesRequest,
+) -> Result<proto::Empty, IggyError> {
+    let partitioning = request.partitioning.unwrap_or_default();
+    let command = SendMessages {
+        stream_id: map_identifier(&request.stream_id)?,
+        topic_id: map_identifier(&request.topic_id)?,
+        partitioning: Partitioning {
+            kind: map_code(partitioning.kind, PartitioningKind::from_code)?,
+            length: u8::try_from(partitioning.value.len())
+                .map_err(|_| IggyError::InvalidCommand)?,
+            value: partitioning.value.to_vec(),
+        },
+        messages: request
+            .messages
+            .into_iter()
+            .map(map_message)
+            .collect::<Result<Vec<_>, _>>()?,
+    };
+    command.validate()?;
+
+    let system = system.read().await;
+    ensure_allowed(&system, false)?;
+    let stream_id = command.stream_id.clone();
+    let topic_id = command.topic_id.clone();
+    system
+        .append_messages(
+            session,
+            command.stream_id,
+            command.topic_id,
+            command.partitioning,
+            command.messages,
+            None,
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to append messages, stream ID: {stream_id}, topic ID: {topic_id}"
+            )
+        })?;
+    Ok(proto::Empty {})
+}
+
+pub async fn poll_messages(
+    system: &SharedSystem,
+    session: &Session,
+    request: proto::PollMessagesRequest,
+) -> Result<proto::PolledMessages, IggyError> {
+    let command = PollMessages {
+        consumer: map_consumer(request.consumer)?,
+        stream_id: map_identifier(&request.stream_id)?,
+        topic_id: map_identifier(&request.topic_id)?,
+        partition_id: request.partition_id,
+        strategy: PollingStrategy {
+            kind: map_code(request.strategy_kind, PollingKind::from_code)?,
+            value: request.strategy_value,
+        },
+        count: request.count,
+        auto_commit: request.auto_commit,
+        isolation_level: map_code(request.isolation_level, IsolationLevel::from_code)?,
+        chunk_size: request.chunk_size,
+        filter: request
+            .filter
+            .as_deref()
+            .map(MessageFilter::from_str)
+            .transpose()?,
+        partition_epoch: None,
+        peek: request.peek,
+    };
+    command.validate()?;
+
+    let system = system.read().await;
+    ensure_allowed(&system, true)?;
+    let polled_messages = system
+        .poll_messages(
+            session,
+            &command.consumer,
+            &command.stream_id,
+            &command.topic_id,
+            command.partition_id,
+            PollingArgs::new(
+                command.strategy,
+                command.count,
+                command.auto_commit,
+                command.isolation_level,
+            )
+            .with_chunk_size(command.chunk_size)
+            .with_filter(command.filter.clone())
+            .with_peek(command.peek),
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to poll messages, stream ID: {}, topic ID: {}, partition ID: {:?}",
+                command.stream_id, command.topic_id, command.partition_id
+            )
+        })?;
+    Ok(mapper::map_polled_messages(&polled_messages))
+}
+
+pub async fn get_consumer_offset(
+    system: &SharedSystem,
+    session: &Session,
+    request: proto::GetConsumerOffsetRequest,
+) -> Result<proto::ConsumerOffsetInfo, IggyError> {
+    let command = GetConsumerOffset {
+        consumer: map_consumer(request.consumer)?,
+        stream_id: map_identifier(&request.stream_id)?,
+        topic_id: map_identifier(&request.topic_id)?,
+        partition_id: request.partition_id,
+    };
+    command.validate()?;
+
+    let system = system.read().await;
+    ensure_allowed(&system, true)?;
+    let Some(offset) = system
+        .get_consumer_offset(
+            session,
+            &command.consumer,
+            &command.stream_id,
+            &command.topic_id,
+            command.partition_id,
+        )
+        .await?
+    else {
+        return Err(IggyError::ResourceNotFound(command.consumer.id.to_string()));
+    };
+
+    Ok(mapper::map_consumer_offset(&offset))
+}
+
+pub async fn store_consumer_offset(
+    system: &SharedSystem,
+    session: &Session,
+    request: proto::StoreConsumerOffsetRequest,
+) -> Result<proto::Empty, IggyError> {
+    let command = StoreConsumerOffset {
+        consumer: map_consumer(request.consumer)?,
+        stream_id: map_identifier(&request.stream_id)?,
+        topic_id: map_identifier(&request.topic_id)?,
+        partition_id: request.partition_id,
+        offset: request.offset,
+    };
+    command.validate()?;
+
+    let system = system.read().await;
+    ensure_allowed(&system, false)?;
+    system
+        .store_consumer_offset(
+            session,
+            command.consumer,
+            &command.stream_id,
+            &command.topic_id,
+            command.partition_id,
+            command.offset,
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to store consumer offset, stream ID: {}, topic ID: {}, partition ID: {:?}",
+                command.stream_id, command.topic_id, command.partition_id
+            )
+        })?;
+    Ok(proto::Empty {})
+}
+
+pub async fn get_user(
+    system: &SharedSystem,
+    session: &Session,
+    request: proto::GetUserRequest,
+) -> Result<proto::UserInfo, IggyError> {
+    let user_id = map_identifier(&request.user_id)?;
+    let system = system.read().await;
+    ensure_allowed(&system, true)?;
+    let Some(user) = system.find_user(session, &user_id)? else {
+        return Err(IggyError::ResourceNotFound(request.user_id));
+    };
+
+    Ok(mapper::map_user_details(&models_mapper::map_user(user)))
+}
+
+pub async fn get_users(
+    system: &SharedSystem,
+    session: &Session,
+) -> Result<proto::Users, IggyError> {
+    let system = system.read().await;
+    ensure_allowed(&system, true)?;
+    let users = system
+        .get_users(session)
+        .await
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to get users, session: {session}")
+        })?;
+    Ok(proto::Users {
+        users: models_mapper::map_users(&users)
+            .iter()
+            .map(mapper::map_user)
+            .collect(),
+    })
+}
+
+pub async fn create_user(
+    system: &SharedSystem,
+    session: &Session,
+    request: proto::CreateUserRequest,
+) -> Result<proto::UserInfo, IggyError> {
+    let command = CreateUser {
+        username: request.username,
+        password: request.password,
+        status: map_code(request.status, UserStatus::from_code)?,
+        permissions: None,
+        namespace_id: None,
+    };
+    command.validate()?;
+
+    let mut system = system.write().await;
+    ensure_allowed(&system, false)?;
+    let user = system
+        .create_user(
+            session,
+            &command.username,
+            &command.password,
+            command.status,
+            None,
+            command.namespace_id,
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to create user, username: {}",
+                command.username
+            )
+        })?;
+    let user_id = user.id;
+    let namespace_id = user.namespace_id;
+    let response = mapper::map_user_details(&models_mapper::map_user(user));
+
+    // For the security of the system, we hash the password before storing it in metadata.
+    let system = system.downgrade();
+    system
+        .state
+        .apply(
+            session.get_user_id(),
+            EntryCommand::CreateUser(CreateUserWithId {
+                user_id,
+                command: CreateUser {
+                    username: command.username.to_owned(),
+                    password: crypto::hash_password(&command.password),
+                    status: command.status,
+                    permissions: None,
+                    namespace_id,
+                },
+            }),
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to apply create user, username: {}",
+                command.username
+            )
+        })?;
+    Ok(response)
+}
+
+pub async fn delete_user(
+    system: &SharedSystem,
+    session: &Session,
+    request: proto::DeleteUserRequest,
+) -> Result<proto::Empty, IggyError> {
+    let user_id = map_identifier(&request.user_id)?;
+    let mut system = system.write().await;
+    ensure_allowed(&system, false)?;
+    system
+        .delete_user(session, &user_id)
+        .await
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to delete user with ID: {user_id}")
+        })?;
+
+    let system = system.downgrade();
+    system
+        .state
+        .apply(
+            session.get_user_id(),
+            EntryCommand::DeleteUser(DeleteUser {
+                user_id: user_id.clone(),
+            }),
+        )
+        .await
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to apply delete user with ID: {user_id}")
+        })?;
+    Ok(proto::Empty {})
+}
@@ -0,0 +1,362 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::grpc::proto;
+use bytes::Bytes;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::send_messages::Message;
+use iggy::models::consumer_offset_info::ConsumerOffsetInfo;
+use iggy::models::header::{HeaderKey, HeaderKind, HeaderValue};
+use iggy::models::messages::PolledMessages;
+use iggy::models::metadata::ResourceMetadata;
+use iggy::models::stream::{Stream, StreamDetails};
+use iggy::models::topic::{Topic, TopicDetails};
+use iggy::models::user_info::{UserInfo, UserInfoDetails};
+use iggy::topics::topic_config::TopicConfig;
+use std::collections::HashMap;
+use tonic::Status;
+
+/// Maps the code of the enum-like value, where 0 means the default one.
+pub fn map_code<T: Default>(
+    code: u32,
+    from_code: impl FnOnce(u8) -> Result<T, IggyError>,
+) -> Result<T, IggyError> {
+    match code {
+        0 => Ok(T::default()),
+        code => from_code(u8::try_from(code).map_err(|_| IggyError::InvalidCommand)?),
+    }
+}
+
+pub fn map_identifier(value: &str) -> Result<Identifier, IggyError> {
+    Identifier::from_str_value(value)
+}
+
+pub fn map_message_id(id: &[u8]) -> Result<u128, IggyError> {
+    match id.len() {
+        0 => Ok(0),
+        16 => Ok(u128::from_le_bytes(id.try_into().unwrap())),
+        _ => Err(IggyError::CannotReadMessageId),
+    }
+}
+
+pub fn map_message(message: proto::Message) -> Result<Message, IggyError> {
+    let id = map_message_id(&message.id)?;
+    let headers = map_headers(message.headers)?;
+    Ok(Message::new(Some(id), message.payload, headers))
+}
+
+fn map_headers(
+    headers: HashMap<String, proto::HeaderValue>,
+) -> Result<Option<HashMap<HeaderKey, HeaderValue>>, IggyError> {
+    if headers.is_empty() {
+        return Ok(None);
+    }
+
+    let mut mapped_headers = HashMap::with_capacity(headers.len());
+    for (key, value) in headers {
+        let kind = HeaderKind::from_code(
+            u8::try_from(value.kind).map_err(|_| IggyError::InvalidCommand)?,
+        )?;
+        if value.value.is_empty() || value.value.len() > 255 {
+            return Err(IggyError::InvalidHeaderValue);
+        }
+        mapped_headers.insert(
+            HeaderKey::new(&key)?,
+            HeaderValue {
+                kind,
+                value: value.value,
+            },
+        );
+    }
+    Ok(Some(mapped_headers))
+}
+
+fn map_proto_headers(
+    headers: &Option<HashMap<HeaderKey, HeaderValue>>,
+) -> HashMap<String, proto::HeaderValue> {
+    let Some(headers) = headers else {
+        return HashMap::new();
+    };
+
+    headers
+        .iter()
+        .map(|(key, value)| {
+            (
+                key.as_str().to_owned(),
+                proto::HeaderValue {
+                    kind: value.kind.as_code() as u32,
+                    value: value.value.clone(),
+                },
+            )
+        })
+        .collect()
+}
+
+pub fn map_metadata(metadata: Option<proto::ResourceMetadata>) -> ResourceMetadata {
+    let Some(metadata) = metadata else {
+        return ResourceMetadata::default();
+    };
+
+    ResourceMetadata {
+        description: metadata.description,
+        owner: metadata.owner,
+        labels: metadata.labels.into_iter().collect(),
+    }
+}
+
+fn map_proto_metadata(metadata: &ResourceMetadata) -> proto::ResourceMetadata {
+    proto::ResourceMetadata {
+        description: metadata.description.clone(),
+        owner: metadata.owner.clone(),
+        labels: metadata
+            .labels
+            .iter()
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect(),
+    }
+}
+
+fn map_topic_config(config: &TopicConfig) -> proto::TopicConfig {
+    proto::TopicConfig {
+        segment_size: config
+            .segment_size
+            .map(|segment_size| segment_size.as_bytes_u64()),
+        message_expiry: config.message_expiry.map(u64::from),
+        cache_enabled: config.cache_enabled,
+        compression_algorithm: config
+            .compression_algorithm
+            .map(|compression_algorithm| compression_algorithm.as_code() as u32),
+    }
+}
+
+pub fn map_stream(stream: &Stream) -> proto::Stream {
+    proto::Stream {
+        id: stream.id,
+        created_at: stream.created_at.as_micros(),
+        name: stream.name.clone(),
+        size_bytes: stream.size.as_bytes_u64(),
+        messages_count: stream.messages_count,
+        topics_count: stream.topics_count,
+        metadata: Some(map_proto_metadata(&stream.metadata)),
+    }
+}
+
+pub fn map_stream_details(stream: &StreamDetails) -> proto::StreamDetails {
+    proto::StreamDetails {
+        stream: Some(proto::Stream {
+            id: stream.id,
+            created_at: stream.created_at.as_micros(),
+            name: stream.name.clone(),
+            size_bytes: stream.size.as_bytes_u64(),
+            messages_count: stream.messages_count,
+            topics_count: stream.topics_count,
+            metadata: Some(map_proto_metadata(&stream.metadata)),
+        }),
+        topics: stream.topics.iter().map(map_topic).collect(),
+    }
+}
+
+pub fn map_topic(topic: &Topic) -> proto::Topic {
+    proto::Topic {
+        id: topic.id,
+        created_at: topic.created_at.as_micros(),
+        name: topic.name.clone(),
+        size_bytes: topic.size.as_bytes_u64(),
+        message_expiry: topic.message_expiry.into(),
+        compression_algorithm: topic.compression_algorithm.as_code() as u32,
+        max_topic_size: topic.max_topic_size.into(),
+        replication_factor: topic.replication_factor as u32,
+        messages_count: topic.messages_count,
+        partitions_count: topic.partitions_count,
+        metadata: Some(map_proto_metadata(&topic.metadata)),
+        message_id_scheme: topic.message_id_scheme.as_code() as u32,
+        cleanup_policy: topic.cleanup_policy.as_code() as u32,
+        allowed_producers: topic.allowed_producers.clone(),
+        delete_at: topic.delete_at.map(u64::from).unwrap_or_default(),
+        max_segments: topic.max_segments,
+        config: Some(map_topic_config(&topic.config)),
+    }
+}
+
+pub fn map_topic_details(topic: &TopicDetails) -> proto::TopicDetails {
+    proto::TopicDetails {
+        topic: Some(proto::Topic {
+            id: topic.id,
+            created_at: topic.created_at.as_micros(),
+            name: topic.name.clone(),
+            size_bytes: topic.size.as_bytes_u64(),
+            message_expiry: topic.message_expiry.into(),
+            compression_algorithm: topic.compression_algorithm.as_code() as u32,
+            max_topic_size: topic.max_topic_size.into(),
+            replication_factor: topic.replication_factor as u32,
+            messages_count: topic.messages_count,
+            partitions_count: topic.partitions_count,
+            metadata: Some(map_proto_metadata(&topic.metadata)),
+            message_id_scheme: topic.message_id_scheme.as_code() as u32,
+            cleanup_policy: topic.cleanup_policy.as_code() as u32,
+            allowed_producers: topic.allowed_producers.clone(),
+            delete_at: topic.delete_at.map(u64::from).unwrap_or_default(),
+            max_segments: topic.max_segments,
+            config: Some(map_topic_config(&topic.config)),
+        }),
+        partitions: topic
+            .partitions
+            .iter()
+            .map(|partition| proto::Partition {
+                id: partition.id,
+                created_at: partition.created_at.as_micros(),
+                segments_count: partition.segments_count,
+                current_offset: partition.current_offset,
+                size_bytes: partition.size.as_bytes_u64(),
+                messages_count: partition.messages_count,
+                recovery: partition
+                    .recovery
+                    .as_ref()
+                    .map(|recovery| proto::PartitionRecovery {
+                        started_at: recovery.started_at.as_micros(),
+                        total_segments: recovery.total_segments,
+                        recovered_segments: recovery.recovered_segments,
+                        total_size_bytes: recovery.total_size.as_bytes_u64(),
+                        recovered_size_bytes: recovery.recovered_size.as_bytes_u64(),
+                        eta: recovery.eta.map(|eta| eta.as_micros()),
+                    }),
+            })
+            .collect(),
+    }
+}
+
+pub fn map_polled_messages(polled_messages: &PolledMessages) -> proto::PolledMessages {
+    proto::PolledMessages {
+        partition_id: polled_messages.partition_id,
+        current_offset: polled_messages.current_offset,
+        remaining_messages: polled_messages.remaining_messages,
+        messages: polled_messages
+            .messages
+            .iter()
+            .map(|message| proto::PolledMessage {
+                offset: message.offset,
+                state: message.state.as_code() as u32,
+                timestamp: message.timestamp,
+                id: Bytes::copy_from_slice(&message.id.to_le_bytes()),
+                checksum: message.checksum,
+                headers: map_proto_headers(&message.headers),
+                payload: message.payload.clone(),
+            })
+            .collect(),
+        chunk_sizes: polled_messages.chunk_sizes.clone(),
+        topic_delete_at: polled_messages.topic_delete_at,
+    }
+}
+
+pub fn map_consumer_offset(offset: &ConsumerOffsetInfo) -> proto::ConsumerOffsetInfo {
+    proto::ConsumerOffsetInfo {
+        partition_id: offset.partition_id,
+        current_offset: offset.current_offset,
+        stored_offset: offset.stored_offset,
+    }
+}
+
+pub fn map_user(user: &UserInfo) -> proto::UserInfo {
+    proto::UserInfo {
+        id: user.id,
+        created_at: user.created_at.as_micros(),
+        status: user.status.as_code() as u32,
+        username: user.username.clone(),
+    }
+}
+
+pub fn map_user_details(user: &UserInfoDetails) -> proto::UserInfo {
+    proto::UserInfo {
+        id: user.id,
+        created_at: user.created_at.as_micros(),
+        status: user.status.as_code() as u32,
+        username: user.username.clone(),
+    }
+}
+
+/// Maps the error to the gRPC status, with the same categories as the HTTP status codes.
+pub fn map_error(error: IggyError) -> Status {
+    let message = error.to_string();
+    match error {
+        IggyError::StreamIdNotFound(_)
+        | IggyError::TopicIdNotFound(_, _)
+        | IggyError::PartitionNotFound(_, _, _)
+        | IggyError::SegmentNotFound
+        | IggyError::ClientNotFound(_)
+        | IggyError::ConsumerGroupIdNotFound(_, _)
+        | IggyError::ConsumerGroupNameNotFound(_, _)
+        | IggyError::ConsumerGroupMemberNotFound(_, _, _)
+        | IggyError::ConsumerOffsetNotFound(_)
+        | IggyError::ResourceNotFound(_) => Status::not_found(message),
+        IggyError::Unauthenticated
+        | IggyError::AccessTokenMissing
+        | IggyError::InvalidAccessToken
+        | IggyError::InvalidPersonalAccessToken
+        | IggyError::PersonalAccessTokenExpired(_, _) => Status::unauthenticated(message),
+        IggyError::Unauthorized
+        | IggyError::PersonalAccessTokenIpAddressNotAllowed(_, _, _)
+        | IggyError::PersonalAccessTokenScopeExceeded => Status::permission_denied(message),
+        IggyError::ReadOnlyMode
+        | IggyError::ServerInMaintenance
+        | IggyError::NotClusterLeader(_)
+        | IggyError::NotPartitionLeader(_, _)
+        | IggyError::PartitionRecovering(_, _, _) => Status::unavailable(message),
+        IggyError::TooManyConnections(_, _)
+        | IggyError::TooManyInFlightRequests(_, _)
+        | IggyError::PersonalAccessTokenLoginThrottled(_)
+        | IggyError::FrameTooLarge(_, _, _)
+        | IggyError::StreamQuotaExceeded(_, _, _) => Status::resource_exhausted(message),
+        IggyError::StreamIdAlreadyExists(_)
+        | IggyError::StreamNameAlreadyExists(_)
+        | IggyError::TopicIdAlreadyExists(_, _)
+        | IggyError::TopicNameAlreadyExists(_, _)
+        | IggyError::UserAlreadyExists => Status::already_exists(message),
+        IggyError::ProducerFenced(_, _, _) => Status::failed_precondition(message),
+        _ => Status::invalid_argument(message),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy::messages::send_messages::PartitioningKind;
+
+    #[test]
+    fn zero_code_should_be_mapped_to_default_value() {
+        let kind = map_code(0, PartitioningKind::from_code).unwrap();
+        assert_eq!(kind, PartitioningKind::default());
+        let kind = map_code(3, PartitioningKind::from_code).unwrap();
+        assert_eq!(kind, PartitioningKind::MessagesKey);
+        assert!(map_code(256, PartitioningKind::from_code).is_err());
+    }
+
+    #[test]
+    fn message_id_should_be_mapped_from_16_bytes_or_empty() {
+        assert_eq!(map_message_id(&[]).unwrap(), 0);
+        assert_eq!(map_message_id(&42u128.to_le_bytes()).unwrap(), 42);
+        assert!(map_message_id(&[1, 2, 3]).is_err());
+    }
+
+    #[test]
+    fn not_found_error_should_be_mapped_to_not_found_status() {
+        let status = map_error(IggyError::StreamIdNotFound(1));
+        assert_eq!(status.code(), tonic::Code::NotFound);
+        let status = map_error(IggyError::Unauthorized);
+        assert_eq!(status.code(), tonic::Code::PermissionDenied);
+    }
+}
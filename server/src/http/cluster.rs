@@ -0,0 +1,90 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::http::error::CustomError;
+use crate::http::maintenance::is_read;
+use crate::http::shared::AppState;
+use axum::body::Body;
+use axum::extract::State;
+use axum::http::Request;
+use axum::middleware::Next;
+use axum::response::Response;
+use std::sync::Arc;
+
+/// Paths of the resources whose changes are replicated across the cluster.
+const REPLICATED_PATHS: &[&str] = &[
+    "/namespaces",
+    "/streams",
+    "/users",
+    "/personal-access-tokens",
+];
+
+/// Paths which don't change the replicated state, even though they're nested under the replicated ones.
+const NOT_REPLICATED_PATHS: &[&str] = &[
+    "/users/login",
+    "/users/logout",
+    "/users/refresh-token",
+    "/personal-access-tokens/login",
+];
+
+/// Segments of the paths under the streams, whose changes are local to the node.
+const NOT_REPLICATED_SEGMENTS: &[&str] = &["messages", "consumer-offsets", "producers"];
+
+/// Rejects the changes of the replicated state when the server is not the leader of the cluster.
+pub async fn cluster_leader(
+    State(state): State<Arc<AppState>>,
+    request: Request<Body>,
+    next: Next,
+) -> Result<Response, CustomError> {
+    if !is_read(request.method()) && is_replicated(request.uri().path()) {
+        state.system.read().await.ensure_cluster_leader()?;
+    }
+
+    Ok(next.run(request).await)
+}
+
+fn is_replicated(path: &str) -> bool {
+    if NOT_REPLICATED_PATHS.contains(&path) {
+        return false;
+    }
+
+    let is_replicated_path = REPLICATED_PATHS.iter().any(|replicated_path| {
+        path == *replicated_path || path.starts_with(&format!("{replicated_path}/"))
+    });
+    is_replicated_path
+        && !path
+            .split('/')
+            .any(|segment| NOT_REPLICATED_SEGMENTS.contains(&segment))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_changes_of_replicated_resources_should_be_replicated() {
+        assert!(is_replicated("/streams"));
+        assert!(is_replicated("/streams/1/topics/2/partitions"));
+        assert!(is_replicated("/users/1/password"));
+        assert!(is_replicated("/personal-access-tokens/token"));
+        assert!(!is_replicated("/users/login"));
+        assert!(!is_replicated("/streams/1/topics/2/messages"));
+        assert!(!is_replicated("/streams/1/topics/2/consumer-offsets/batch"));
+        assert!(!is_replicated("/replay-jobs"));
+    }
+}
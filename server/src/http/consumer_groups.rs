@@ -26,11 +26,13 @@ use crate::state::models::CreateConsumerGroupWithId;
 use crate::streaming::session::Session;
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
-use axum::routing::get;
+use axum::routing::{get, put};
 use axum::{Extension, Json, Router};
 use error_set::ErrContext;
 use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use iggy::consumer_groups::delete_consumer_group::DeleteConsumerGroup;
+use iggy::consumer_groups::update_consumer_group_dead_letter::UpdateConsumerGroupDeadLetter;
+use iggy::consumer_groups::update_consumer_group_visibility_timeout::UpdateConsumerGroupVisibilityTimeout;
 use iggy::identifier::Identifier;
 use iggy::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails};
 use iggy::validatable::Validatable;
@@ -47,6 +49,14 @@ pub fn router(state: Arc<AppState>) -> Router {
             "/streams/{stream_id}/topics/{topic_id}/consumer-groups/{group_id}",
             get(get_consumer_group).delete(delete_consumer_group),
         )
+        .route(
+            "/streams/{stream_id}/topics/{topic_id}/consumer-groups/{group_id}/dead-letter",
+            put(update_consumer_group_dead_letter),
+        )
+        .route(
+            "/streams/{stream_id}/topics/{topic_id}/consumer-groups/{group_id}/visibility-timeout",
+            put(update_consumer_group_visibility_timeout),
+        )
         .with_state(state)
 }
 
@@ -111,6 +121,7 @@ async fn create_consumer_group(
                 &command.topic_id,
                 command.group_id,
                 &command.name,
+                command.offset_recovery,
             )
             .await
             .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to create consumer group, stream ID: {}, topic ID: {}, group ID: {:?}", stream_id, topic_id, command.group_id))?;
@@ -167,3 +178,84 @@ async fn delete_consumer_group(
 
     Ok(StatusCode::NO_CONTENT)
 }
+
+#[instrument(skip_all, name = "trace_update_consumer_group_dead_letter", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id, iggy_group_id = group_id))]
+async fn update_consumer_group_dead_letter(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id, group_id)): Path<(String, String, String)>,
+    Json(mut command): Json<UpdateConsumerGroupDeadLetter>,
+) -> Result<StatusCode, CustomError> {
+    command.stream_id = Identifier::from_str_value(&stream_id)?;
+    command.topic_id = Identifier::from_str_value(&topic_id)?;
+    command.group_id = Identifier::from_str_value(&group_id)?;
+    command.validate()?;
+
+    let system = state.system.read().await;
+    let dead_letter = system
+            .update_consumer_group_dead_letter(
+                &Session::stateless(identity.user_id, identity.ip_address),
+                &command.stream_id,
+                &command.topic_id,
+                &command.group_id,
+                command.dead_letter_stream_id.as_ref(),
+                command.dead_letter_topic_id.as_ref(),
+                command.max_delivery_count,
+            )
+            .await
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to update dead letter of consumer group with ID: {group_id} for topic with ID: {topic_id} in stream with ID: {stream_id}"))?;
+
+    let command = UpdateConsumerGroupDeadLetter {
+        dead_letter_stream_id: dead_letter
+            .map(|dead_letter| Identifier::numeric(dead_letter.stream_id))
+            .transpose()?,
+        dead_letter_topic_id: dead_letter
+            .map(|dead_letter| Identifier::numeric(dead_letter.topic_id))
+            .transpose()?,
+        ..command
+    };
+    system
+        .state
+        .apply(
+            identity.user_id,
+            EntryCommand::UpdateConsumerGroupDeadLetter(command),
+        )
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[instrument(skip_all, name = "trace_update_consumer_group_visibility_timeout", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id, iggy_group_id = group_id))]
+async fn update_consumer_group_visibility_timeout(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id, group_id)): Path<(String, String, String)>,
+    Json(mut command): Json<UpdateConsumerGroupVisibilityTimeout>,
+) -> Result<StatusCode, CustomError> {
+    command.stream_id = Identifier::from_str_value(&stream_id)?;
+    command.topic_id = Identifier::from_str_value(&topic_id)?;
+    command.group_id = Identifier::from_str_value(&group_id)?;
+    command.validate()?;
+
+    let system = state.system.read().await;
+    system
+            .update_consumer_group_visibility_timeout(
+                &Session::stateless(identity.user_id, identity.ip_address),
+                &command.stream_id,
+                &command.topic_id,
+                &command.group_id,
+                command.visibility_timeout,
+            )
+            .await
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to update visibility timeout of consumer group with ID: {group_id} for topic with ID: {topic_id} in stream with ID: {stream_id}"))?;
+
+    system
+        .state
+        .apply(
+            identity.user_id,
+            EntryCommand::UpdateConsumerGroupVisibilityTimeout(command),
+        )
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
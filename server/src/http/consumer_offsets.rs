@@ -23,15 +23,18 @@ use crate::http::COMPONENT;
 use crate::streaming::session::Session;
 use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
-use axum::routing::{delete, get};
+use axum::routing::{delete, get, post, put};
 use axum::{Extension, Json, Router};
 use error_set::ErrContext;
 use iggy::consumer::Consumer;
 use iggy::consumer_offsets::delete_consumer_offset::DeleteConsumerOffset;
 use iggy::consumer_offsets::get_consumer_offset::GetConsumerOffset;
+use iggy::consumer_offsets::get_offsets_for_timestamps::GetOffsetsForTimestamps;
 use iggy::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
+use iggy::consumer_offsets::store_consumer_offsets::StoreConsumerOffsets;
 use iggy::identifier::Identifier;
 use iggy::models::consumer_offset_info::ConsumerOffsetInfo;
+use iggy::models::partition_timestamp_offset::PartitionTimestampOffset;
 use iggy::validatable::Validatable;
 use std::sync::Arc;
 
@@ -41,6 +44,14 @@ pub fn router(state: Arc<AppState>) -> Router {
             "/streams/{stream_id}/topics/{topic_id}/consumer-offsets",
             get(get_consumer_offset).put(store_consumer_offset),
         )
+        .route(
+            "/streams/{stream_id}/topics/{topic_id}/consumer-offsets/batch",
+            put(store_consumer_offsets),
+        )
+        .route(
+            "/streams/{stream_id}/topics/{topic_id}/consumer-offsets/timestamps",
+            post(get_offsets_for_timestamps),
+        )
         .route(
             "/streams/{stream_id}/topics/{topic_id}/consumer-offsets/{consumer_id}",
             delete(delete_consumer_offset),
@@ -104,6 +115,52 @@ async fn store_consumer_offset(
     Ok(StatusCode::NO_CONTENT)
 }
 
+async fn store_consumer_offsets(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    mut command: Json<StoreConsumerOffsets>,
+) -> Result<StatusCode, CustomError> {
+    command.stream_id = Identifier::from_str_value(&stream_id)?;
+    command.topic_id = Identifier::from_str_value(&topic_id)?;
+    command.validate()?;
+    let consumer = Consumer::new(command.0.consumer.id);
+    let system = state.system.read().await;
+    system
+        .store_consumer_offsets(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            consumer,
+            &command.0.stream_id,
+            &command.0.topic_id,
+            &command.0.offsets,
+        )
+        .await
+        .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to store {} consumer offsets, stream ID: {}, topic ID: {}", command.0.offsets.len(), stream_id, topic_id))?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn get_offsets_for_timestamps(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    mut command: Json<GetOffsetsForTimestamps>,
+) -> Result<Json<Vec<PartitionTimestampOffset>>, CustomError> {
+    command.stream_id = Identifier::from_str_value(&stream_id)?;
+    command.topic_id = Identifier::from_str_value(&topic_id)?;
+    command.validate()?;
+    let system = state.system.read().await;
+    let offsets = system
+        .get_offsets_for_timestamps(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &command.0.stream_id,
+            &command.0.topic_id,
+            &command.0.timestamps,
+        )
+        .await
+        .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to get offsets for {} timestamps, stream ID: {}, topic ID: {}", command.0.timestamps.len(), stream_id, topic_id))?;
+    Ok(Json(offsets))
+}
+
 async fn delete_consumer_offset(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
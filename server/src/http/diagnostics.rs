@@ -19,6 +19,7 @@
 use crate::http::shared::RequestDetails;
 use crate::streaming::utils::random_id;
 use axum::body::Body;
+use axum::http::{HeaderName, HeaderValue};
 use axum::{
     extract::ConnectInfo,
     http::{Request, StatusCode},
@@ -27,14 +28,20 @@ use axum::{
 };
 use std::net::SocketAddr;
 use tokio::time::Instant;
-use tracing::{debug, error};
+use tracing::{debug, error, info_span, Instrument};
 
+const REQUEST_ID_HEADER: HeaderName = HeaderName::from_static("x-request-id");
+const MAX_REQUEST_ID_LENGTH: usize = 128;
+
+/// Logs the processing of the request, correlated by the `X-Request-Id` header, which is taken
+/// from the request if present and valid, or generated otherwise, and echoed in the response.
 pub async fn request_diagnostics(
     ConnectInfo(ip_address): ConnectInfo<SocketAddr>,
     mut request: Request<Body>,
     next: Next,
 ) -> Result<Response, StatusCode> {
-    let request_id = random_id::get_ulid();
+    let request_id = get_request_id(request.headers().get(&REQUEST_ID_HEADER))
+        .unwrap_or_else(|| random_id::get_ulid().to_string());
     let path_and_query = request
         .uri()
         .path_and_query()
@@ -46,21 +53,62 @@ pub async fn request_diagnostics(
         path_and_query,
     );
     request.extensions_mut().insert(RequestDetails {
-        request_id,
+        request_id: request_id.clone(),
         ip_address,
     });
     let now = Instant::now();
-    let result = Ok(next.run(request).await);
-    if let Ok(response) = &result {
-        let status = response.status();
-        if status != StatusCode::NOT_FOUND && status >= StatusCode::BAD_REQUEST {
-            error!("Returning an invalid status code: {status}, IP address: {ip_address}, request ID: {request_id}");
-        }
+    let mut response = next
+        .run(request)
+        .instrument(info_span!("http_request", request_id = %request_id))
+        .await;
+    let status = response.status();
+    if status != StatusCode::NOT_FOUND && status >= StatusCode::BAD_REQUEST {
+        error!("Returning an invalid status code: {status}, IP address: {ip_address}, request ID: {request_id}");
+    }
+    if let Ok(value) = HeaderValue::from_str(&request_id) {
+        response.headers_mut().insert(REQUEST_ID_HEADER, value);
     }
     let elapsed = now.elapsed();
     debug!(
         "Processed a request with ID: {request_id} from client with IP address: {ip_address} in {} ms.",
         elapsed.as_millis()
     );
-    result
+    Ok(response)
+}
+
+fn get_request_id(header: Option<&HeaderValue>) -> Option<String> {
+    let request_id = header?.to_str().ok()?.trim();
+    if request_id.is_empty()
+        || request_id.len() > MAX_REQUEST_ID_LENGTH
+        || !request_id.chars().all(|c| c.is_ascii_graphic())
+    {
+        return None;
+    }
+    Some(request_id.to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid_request_id_should_be_taken_from_the_header() {
+        let header = HeaderValue::from_static("client-request-1");
+        assert_eq!(
+            get_request_id(Some(&header)),
+            Some("client-request-1".to_owned())
+        );
+    }
+
+    #[test]
+    fn invalid_request_id_should_be_ignored() {
+        assert_eq!(get_request_id(None), None);
+        assert_eq!(get_request_id(Some(&HeaderValue::from_static(""))), None);
+        assert_eq!(
+            get_request_id(Some(&HeaderValue::from_static("request id"))),
+            None
+        );
+        let too_long = HeaderValue::from_str(&"a".repeat(MAX_REQUEST_ID_LENGTH + 1)).unwrap();
+        assert_eq!(get_request_id(Some(&too_long)), None);
+    }
 }
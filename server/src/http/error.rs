@@ -20,6 +20,7 @@ use axum::http::StatusCode;
 use axum::response::{IntoResponse, Response};
 use axum::Json;
 use iggy::error::IggyError;
+use iggy::models::error_catalog::ErrorCategory;
 use serde::Serialize;
 use thiserror::Error;
 use tracing::error;
@@ -36,6 +37,7 @@ pub enum CustomError {
 pub struct ErrorResponse {
     pub id: u32,
     pub code: String,
+    pub category: String,
     pub reason: String,
     pub field: Option<String>,
 }
@@ -56,11 +58,44 @@ impl IntoResponse for CustomError {
                     IggyError::ConsumerGroupMemberNotFound(_, _, _) => StatusCode::NOT_FOUND,
                     IggyError::ConsumerOffsetNotFound(_) => StatusCode::NOT_FOUND,
                     IggyError::ResourceNotFound(_) => StatusCode::NOT_FOUND,
+                    IggyError::ReplayJobNotFound(_) => StatusCode::NOT_FOUND,
+                    IggyError::PushSubscriptionNotFound(_) => StatusCode::NOT_FOUND,
+                    IggyError::NamespaceIdNotFound(_) => StatusCode::NOT_FOUND,
+                    IggyError::NamespaceNameNotFound(_) => StatusCode::NOT_FOUND,
                     IggyError::Unauthenticated => StatusCode::UNAUTHORIZED,
                     IggyError::AccessTokenMissing => StatusCode::UNAUTHORIZED,
                     IggyError::InvalidAccessToken => StatusCode::UNAUTHORIZED,
                     IggyError::InvalidPersonalAccessToken => StatusCode::UNAUTHORIZED,
                     IggyError::Unauthorized => StatusCode::FORBIDDEN,
+                    IggyError::PersonalAccessTokenIpAddressNotAllowed(_, _, _) => {
+                        StatusCode::FORBIDDEN
+                    }
+                    IggyError::PersonalAccessTokenScopeExceeded => StatusCode::FORBIDDEN,
+                    IggyError::ReadOnlyMode => StatusCode::SERVICE_UNAVAILABLE,
+                    IggyError::ServerInMaintenance => StatusCode::SERVICE_UNAVAILABLE,
+                    IggyError::NotClusterLeader(_) => StatusCode::MISDIRECTED_REQUEST,
+                    IggyError::NotPartitionLeader(_, _) => StatusCode::MISDIRECTED_REQUEST,
+                    IggyError::TooManyConnections(_, _) => StatusCode::SERVICE_UNAVAILABLE,
+                    IggyError::TooManyInFlightRequests(_, _) => StatusCode::SERVICE_UNAVAILABLE,
+                    IggyError::PartitionRecovering(_, _, _) => StatusCode::SERVICE_UNAVAILABLE,
+                    IggyError::PersonalAccessTokenLoginThrottled(_) => {
+                        StatusCode::TOO_MANY_REQUESTS
+                    }
+                    IggyError::FrameTooLarge(_, _, _) => StatusCode::PAYLOAD_TOO_LARGE,
+                    IggyError::ProducerFenced(_, _, _) => StatusCode::CONFLICT,
+                    IggyError::PartitionEpochChanged(_, _, _) => StatusCode::CONFLICT,
+                    IggyError::StreamQuotaExceeded(_, _, _) => StatusCode::INSUFFICIENT_STORAGE,
+                    IggyError::ProducerNotAllowed(_, _, _) => StatusCode::FORBIDDEN,
+                    IggyError::TopicMarkedForDeletion(_, _) => StatusCode::GONE,
+                    IggyError::BackupAlreadyExists(_) => StatusCode::CONFLICT,
+                    IggyError::NamespaceNotEmpty(_) => StatusCode::CONFLICT,
+                    IggyError::NamespaceStorageQuotaExceeded(_, _, _) => {
+                        StatusCode::INSUFFICIENT_STORAGE
+                    }
+                    IggyError::CannotCreateBackup(_) => StatusCode::INTERNAL_SERVER_ERROR,
+                    IggyError::CannotRestoreArchivedSegment(_, _) => {
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    }
                     _ => StatusCode::BAD_REQUEST,
                 };
                 (status_code, Json(ErrorResponse::from_error(error)))
@@ -70,6 +105,7 @@ impl IntoResponse for CustomError {
                 Json(ErrorResponse {
                     id: 404,
                     code: "not_found".to_string(),
+                    category: ErrorCategory::NotFound.to_string(),
                     reason: "Resource not found".to_string(),
                     field: None,
                 }),
@@ -84,6 +120,7 @@ impl ErrorResponse {
         ErrorResponse {
             id: error.as_code(),
             code: error.as_string().to_string(),
+            category: error.category().to_string(),
             reason: error.to_string(),
             field: match error {
                 IggyError::StreamIdNotFound(_) => Some("stream_id".to_string()),
@@ -106,7 +143,28 @@ impl ErrorResponse {
                 }
                 IggyError::ConsumerGroupNameAlreadyExists(_, _) => Some("name".to_string()),
                 IggyError::UserAlreadyExists => Some("username".to_string()),
+                IggyError::InvalidNamespaceName => Some("name".to_string()),
+                IggyError::NamespaceNameAlreadyExists(_) => Some("name".to_string()),
+                IggyError::InvalidNamespaceId => Some("namespace_id".to_string()),
+                IggyError::NamespaceIdNotFound(_) => Some("namespace_id".to_string()),
                 IggyError::PersonalAccessTokenAlreadyExists(_, _) => Some("name".to_string()),
+                IggyError::InvalidResourceMetadata => Some("metadata".to_string()),
+                IggyError::InvalidStreamQuota(_, _) => Some("soft_limit".to_string()),
+                IggyError::InvalidTopicProducers(_) => Some("allowed_producers".to_string()),
+                IggyError::InvalidTopicConfig => Some("config".to_string()),
+                IggyError::InvalidReplayRange => Some("range".to_string()),
+                IggyError::InvalidRestoreRange => Some("start_offset".to_string()),
+                IggyError::InvalidAggregateRange(_) => Some("from".to_string()),
+                IggyError::ReplayJobNotFound(_) => Some("job_id".to_string()),
+                IggyError::InvalidPushSubscriptionEndpoint => Some("endpoint".to_string()),
+                IggyError::PushSubscriptionNotFound(_) => Some("subscription_id".to_string()),
+                IggyError::InvalidProducerName => Some("name".to_string()),
+                IggyError::InvalidBackupName => Some("name".to_string()),
+                IggyError::BackupAlreadyExists(_) => Some("name".to_string()),
+                IggyError::InvalidDeadLetterConfiguration => {
+                    Some("dead_letter_topic_id".to_string())
+                }
+                IggyError::InvalidNackReason => Some("reason".to_string()),
                 _ => None,
             },
         }
@@ -17,13 +17,20 @@
  */
 
 use crate::configs::http::{HttpConfig, HttpCorsConfig};
+use crate::http::cluster::cluster_leader;
 use crate::http::diagnostics::request_diagnostics;
 use crate::http::jwt::cleaner::start_expired_tokens_cleaner;
 use crate::http::jwt::jwt_manager::JwtManager;
 use crate::http::jwt::middleware::jwt_auth;
+use crate::http::jwt::oidc::OidcTokenValidator;
+use crate::http::load_shedding::{load_shedding, ConnectionLimit};
+use crate::http::maintenance::maintenance;
 use crate::http::metrics::metrics;
+use crate::http::quotas::quotas;
+use crate::http::read_only::read_only;
 use crate::http::shared::AppState;
 use crate::http::*;
+use crate::streaming::clients::transport_limiter::TransportLimiter;
 use crate::streaming::systems::system::SharedSystem;
 use axum::extract::DefaultBodyLimit;
 use axum::http::Method;
@@ -32,6 +39,7 @@ use axum_server::tls_rustls::RustlsConfig;
 use std::net::SocketAddr;
 use std::path::PathBuf;
 use std::sync::Arc;
+use tokio::net::TcpSocket;
 use tower_http::cors::{AllowOrigin, CorsLayer};
 use tracing::{error, info};
 
@@ -44,22 +52,47 @@ pub async fn start(config: HttpConfig, system: SharedSystem) -> SocketAddr {
         "HTTP API"
     };
 
+    let is_read_only = system.read().await.is_read_only();
+    let is_cluster_enabled = system.read().await.get_cluster().is_some();
+    let limiter = TransportLimiter::register("HTTP", &config.limits);
+    let max_request_size = match limiter.max_frame_size() {
+        Some(max_frame_size) => max_frame_size.min(config.max_request_size.as_bytes_u64()),
+        None => config.max_request_size.as_bytes_u64(),
+    };
     let app_state = build_app_state(&config, system).await;
     let mut app = Router::new()
         .merge(system::router(app_state.clone(), &config.metrics))
         .merge(personal_access_tokens::router(app_state.clone()))
         .merge(users::router(app_state.clone()))
+        .merge(namespaces::router(app_state.clone()))
         .merge(streams::router(app_state.clone()))
         .merge(topics::router(app_state.clone()))
         .merge(consumer_groups::router(app_state.clone()))
         .merge(consumer_offsets::router(app_state.clone()))
         .merge(partitions::router(app_state.clone()))
         .merge(messages::router(app_state.clone()))
-        .layer(DefaultBodyLimit::max(
-            config.max_request_size.as_bytes_u64() as usize,
-        ))
+        .merge(replay_jobs::router(app_state.clone()))
+        .merge(push_subscriptions::router(app_state.clone()))
+        .layer(DefaultBodyLimit::max(max_request_size as usize))
+        .layer(middleware::from_fn_with_state(app_state.clone(), quotas))
         .layer(middleware::from_fn_with_state(app_state.clone(), jwt_auth));
 
+    if is_read_only {
+        app = app.layer(middleware::from_fn(read_only));
+    }
+
+    app = app.layer(middleware::from_fn_with_state(
+        app_state.clone(),
+        maintenance,
+    ));
+
+    if is_cluster_enabled {
+        app = app.layer(middleware::from_fn_with_state(
+            app_state.clone(),
+            cluster_leader,
+        ));
+    }
+
     if config.cors.enabled {
         app = app.layer(configure_cors(config.cors));
     }
@@ -68,27 +101,43 @@ pub async fn start(config: HttpConfig, system: SharedSystem) -> SocketAddr {
         app = app.layer(middleware::from_fn_with_state(app_state.clone(), metrics));
     }
 
-    start_expired_tokens_cleaner(app_state.clone());
+    if !is_read_only {
+        start_expired_tokens_cleaner(app_state.clone());
+    }
+
     app = app.layer(middleware::from_fn(request_diagnostics));
+    app = app.layer(middleware::from_fn_with_state(
+        limiter.clone(),
+        load_shedding,
+    ));
+    let make_service = ConnectionLimit::new(
+        app.into_make_service_with_connect_info::<SocketAddr>(),
+        limiter,
+    );
 
+    let reuse_port = config.acceptors > 1;
+    let mut address: SocketAddr = config
+        .address
+        .parse()
+        .unwrap_or_else(|_| panic!("Unable to parse HTTP address {}", config.address));
     if !config.tls.enabled {
-        let listener = tokio::net::TcpListener::bind(config.address.clone())
-            .await
-            .unwrap_or_else(|_| panic!("Failed to bind to HTTP address {}", config.address));
-        let address = listener
-            .local_addr()
-            .expect("Failed to get local address for HTTP server");
-        info!("Started {api_name} on: {address}");
-        tokio::task::spawn(async move {
-            if let Err(error) = axum::serve(
-                listener,
-                app.into_make_service_with_connect_info::<SocketAddr>(),
-            )
-            .await
-            {
-                error!("Failed to start {api_name} server, error {}", error);
-            }
-        });
+        for _ in 0..config.acceptors {
+            let listener = bind_listener(address, reuse_port);
+            // The remaining acceptors must bind to the resolved address, e.g. when the port is assigned by the OS.
+            address = listener
+                .local_addr()
+                .expect("Failed to get local address for HTTP server");
+            let make_service = make_service.clone();
+            tokio::task::spawn(async move {
+                if let Err(error) = axum::serve(listener, make_service).await {
+                    error!("Failed to start {api_name} server, error {}", error);
+                }
+            });
+        }
+        info!(
+            "Started {api_name} on: {address} with {} acceptor(s)",
+            config.acceptors
+        );
 
         address
     } else {
@@ -99,26 +148,57 @@ pub async fn start(config: HttpConfig, system: SharedSystem) -> SocketAddr {
         .await
         .unwrap();
 
-        let listener = std::net::TcpListener::bind(config.address).unwrap();
-        let address = listener
-            .local_addr()
-            .expect("Failed to get local address for HTTPS / TLS server");
-
-        info!("Started {api_name} on: {address}");
-
-        tokio::task::spawn(async move {
-            if let Err(error) = axum_server::from_tcp_rustls(listener, tls_config)
-                .serve(app.into_make_service_with_connect_info::<SocketAddr>())
-                .await
-            {
-                error!("Failed to start {api_name} server, error: {}", error);
-            }
-        });
+        for _ in 0..config.acceptors {
+            let listener = bind_listener(address, reuse_port)
+                .into_std()
+                .expect("Failed to convert HTTPS / TLS listener");
+            address = listener
+                .local_addr()
+                .expect("Failed to get local address for HTTPS / TLS server");
+            let tls_config = tls_config.clone();
+            let make_service = make_service.clone();
+            tokio::task::spawn(async move {
+                if let Err(error) = axum_server::from_tcp_rustls(listener, tls_config)
+                    .serve(make_service)
+                    .await
+                {
+                    error!("Failed to start {api_name} server, error: {}", error);
+                }
+            });
+        }
+        info!(
+            "Started {api_name} on: {address} with {} acceptor(s)",
+            config.acceptors
+        );
 
         address
     }
 }
 
+/// Binds the listener of a single acceptor, with SO_REUSEPORT when more acceptors share the address.
+fn bind_listener(address: SocketAddr, reuse_port: bool) -> tokio::net::TcpListener {
+    let socket = if address.is_ipv6() {
+        TcpSocket::new_v6()
+    } else {
+        TcpSocket::new_v4()
+    }
+    .expect("Unable to create HTTP socket");
+    socket
+        .set_reuseaddr(true)
+        .expect("Unable to set SO_REUSEADDR on HTTP socket");
+    if reuse_port {
+        socket
+            .set_reuseport(true)
+            .expect("Unable to set SO_REUSEPORT on HTTP socket");
+    }
+    socket
+        .bind(address)
+        .unwrap_or_else(|_| panic!("Failed to bind to HTTP address {address}"));
+    socket
+        .listen(1024)
+        .unwrap_or_else(|_| panic!("Failed to listen on HTTP address {address}"))
+}
+
 async fn build_app_state(config: &HttpConfig, system: SharedSystem) -> Arc<AppState> {
     let tokens_path;
     let persister;
@@ -138,8 +218,18 @@ async fn build_app_state(config: &HttpConfig, system: SharedSystem) -> Arc<AppSt
         panic!("Failed to load revoked access tokens");
     }
 
+    let oidc_validator = if config.oidc.enabled {
+        match OidcTokenValidator::new(config.oidc.clone()) {
+            Ok(oidc_validator) => Some(oidc_validator),
+            Err(error) => panic!("Failed to initialize OIDC token validator: {}", error),
+        }
+    } else {
+        None
+    };
+
     Arc::new(AppState {
         jwt_manager,
+        oidc_validator,
         system,
     })
 }
@@ -17,6 +17,7 @@
  */
 
 use crate::http::jwt::json_web_token::Identity;
+use crate::http::jwt::oidc::OidcToken;
 use crate::http::shared::{AppState, RequestDetails};
 use axum::body::Body;
 use axum::{
@@ -26,7 +27,10 @@ use axum::{
     response::Response,
 };
 use error_set::ErrContext;
+use iggy::identifier::Identifier;
+use iggy::models::user_info::UserId;
 use std::sync::Arc;
+use tracing::warn;
 
 const COMPONENT: &str = "JWT_MIDDLEWARE";
 const AUTHORIZATION: &str = "authorization";
@@ -38,6 +42,7 @@ const PUBLIC_PATHS: &[&str] = &[
     "/metrics",
     "/ping",
     "/stats",
+    "/error-catalog",
     "/users/login",
     "/users/refresh-token",
     "/personal-access-tokens/login",
@@ -75,28 +80,74 @@ pub async fn jwt_auth(
             format!("{COMPONENT} (error: {error}) - failed to decode JWT header")
         })
         .map_err(|_| UNAUTHORIZED)?;
-    let jwt_claims = state
-        .jwt_manager
-        .decode(jwt_token, token_header.alg)
-        .with_error_context(|error| {
-            format!("{COMPONENT} (error: {error}) - failed to decode JWT with provided algorithm")
-        })
-        .map_err(|_| UNAUTHORIZED)?;
-    if state
-        .jwt_manager
-        .is_token_revoked(&jwt_claims.claims.jti)
-        .await
-    {
+    let jwt_claims = state.jwt_manager.decode(jwt_token, token_header.alg);
+    let (token_id, token_expiry, user_id) = match (jwt_claims, &state.oidc_validator) {
+        (Ok(jwt_claims), _) => (
+            jwt_claims.claims.jti,
+            jwt_claims.claims.exp,
+            jwt_claims.claims.sub,
+        ),
+        // The token not issued by the server might be issued by the external OpenID Connect provider.
+        (Err(_), Some(oidc_validator)) => {
+            let token = oidc_validator
+                .validate(jwt_token, &token_header)
+                .await
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to validate OIDC token")
+                })
+                .map_err(|_| UNAUTHORIZED)?
+                .ok_or(UNAUTHORIZED)?;
+            let user_id = map_oidc_token_to_user(&state, &token).await?;
+            (token.token_id, token.token_expiry, user_id)
+        }
+        (Err(error), None) => {
+            return Err(error)
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to decode JWT with provided algorithm"
+                    )
+                })
+                .map_err(|_| UNAUTHORIZED);
+        }
+    };
+    if !token_id.is_empty() && state.jwt_manager.is_token_revoked(&token_id).await {
         return Err(StatusCode::UNAUTHORIZED);
     }
 
     let request_details = request.extensions().get::<RequestDetails>().unwrap();
     let identity = Identity {
-        token_id: jwt_claims.claims.jti,
-        token_expiry: jwt_claims.claims.exp,
-        user_id: jwt_claims.claims.sub,
+        token_id,
+        token_expiry,
+        user_id,
         ip_address: request_details.ip_address,
     };
     request.extensions_mut().insert(identity);
     Ok(next.run(request).await)
 }
+
+/// Maps the token to the first existing user by its usernames, which must be active.
+async fn map_oidc_token_to_user(state: &AppState, token: &OidcToken) -> Result<UserId, StatusCode> {
+    let system = state.system.read().await;
+    for username in &token.usernames {
+        let Ok(Some(user)) = Identifier::named(username).and_then(|id| system.try_get_user(&id))
+        else {
+            continue;
+        };
+
+        if !user.is_active() {
+            warn!(
+                "User: {username} with ID: {} mapped from OIDC token is inactive.",
+                user.id
+            );
+            return Err(UNAUTHORIZED);
+        }
+
+        return Ok(user.id);
+    }
+
+    warn!(
+        "OIDC token cannot be mapped to any user, usernames: {:?}.",
+        token.usernames
+    );
+    Err(UNAUTHORIZED)
+}
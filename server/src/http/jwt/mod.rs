@@ -20,6 +20,7 @@ pub mod cleaner;
 pub mod json_web_token;
 pub mod jwt_manager;
 pub mod middleware;
+pub mod oidc;
 pub mod storage;
 
 pub const COMPONENT: &str = "HTTP_JWT";
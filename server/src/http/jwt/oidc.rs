@@ -0,0 +1,316 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::configs::http::HttpOidcConfig;
+use crate::http::jwt::COMPONENT;
+use crate::server_error::AuthenticatorError;
+use ahash::AHashMap;
+use iggy::locking::IggySharedMut;
+use iggy::locking::IggySharedMutFn;
+use jsonwebtoken::jwk::{JwkSet, PublicKeyUse};
+use jsonwebtoken::{decode, Algorithm, DecodingKey, Header, Validation};
+use serde_json::Value;
+use std::time::Instant;
+use tracing::{debug, error, info};
+
+/// Access token issued by the external OpenID Connect provider, validated by the server.
+#[derive(Debug)]
+pub struct OidcToken {
+    pub token_id: String,
+    pub token_expiry: u64,
+    /// Usernames of the users the token can be mapped to, in the order of precedence:
+    /// the one from the username claim first, then the ones mapped to the roles of the token.
+    pub usernames: Vec<String>,
+}
+
+/// Validates the access tokens issued by the external OpenID Connect provider,
+/// using the keys from its JSON Web Key Set, fetched again when the token is signed by an unknown key.
+pub struct OidcTokenValidator {
+    client: reqwest::Client,
+    config: HttpOidcConfig,
+    role_mappings: Vec<(String, String)>,
+    keys: IggySharedMut<SigningKeys>,
+}
+
+#[derive(Default)]
+struct SigningKeys {
+    keys: AHashMap<String, DecodingKey>,
+    fetched_at: Option<Instant>,
+}
+
+impl SigningKeys {
+    fn find(&self, key_id: Option<&str>) -> Option<DecodingKey> {
+        match key_id {
+            Some(key_id) => self.keys.get(key_id).cloned(),
+            // The token without the key ID can be verified only if there's no doubt about the key.
+            None if self.keys.len() == 1 => self.keys.values().next().cloned(),
+            None => None,
+        }
+    }
+}
+
+impl OidcTokenValidator {
+    pub fn new(config: HttpOidcConfig) -> Result<Self, AuthenticatorError> {
+        let mut role_mappings = Vec::with_capacity(config.role_mappings.len());
+        for mapping in config.role_mappings.iter().filter(|m| !m.is_empty()) {
+            role_mappings.push(parse_role_mapping(mapping)?);
+        }
+
+        // The provider might be already installed e.g. by the other component.
+        let _ = rustls::crypto::ring::default_provider().install_default();
+        let client = reqwest::Client::builder()
+            .timeout(config.timeout.get_duration())
+            .build()
+            .map_err(|error| {
+                error!("{COMPONENT} (error: {error}) - failed to create OIDC client");
+                AuthenticatorError::InvalidAuthenticatorConfiguration
+            })?;
+        Ok(Self {
+            client,
+            config,
+            role_mappings,
+            keys: IggySharedMut::new(SigningKeys::default()),
+        })
+    }
+
+    /// Returns the validated token, or `None` if it wasn't issued by the provider or is no longer valid.
+    pub async fn validate(
+        &self,
+        token: &str,
+        header: &Header,
+    ) -> Result<Option<OidcToken>, AuthenticatorError> {
+        if matches!(
+            header.alg,
+            Algorithm::HS256 | Algorithm::HS384 | Algorithm::HS512
+        ) {
+            debug!("OIDC token signed with the symmetric algorithm is not supported.");
+            return Ok(None);
+        }
+
+        let Some(key) = self.get_key(header.kid.as_deref()).await? else {
+            debug!("OIDC token signed by the unknown key: {:?}.", header.kid);
+            return Ok(None);
+        };
+
+        let mut validation = Validation::new(header.alg);
+        validation.set_issuer(&[&self.config.issuer]);
+        if self.config.audience.is_empty() {
+            validation.validate_aud = false;
+        } else {
+            validation.set_audience(&[&self.config.audience]);
+        }
+
+        let claims = match decode::<Value>(token, &key, &validation) {
+            Ok(token) => token.claims,
+            Err(error) => {
+                debug!("{COMPONENT} (error: {error}) - invalid OIDC token");
+                return Ok(None);
+            }
+        };
+
+        Ok(Some(self.map_claims(&claims)))
+    }
+
+    fn map_claims(&self, claims: &Value) -> OidcToken {
+        let mut usernames = Vec::new();
+        if let Some(username) = claims
+            .get(&self.config.username_claim)
+            .and_then(Value::as_str)
+        {
+            usernames.push(username.to_owned());
+        }
+
+        let roles = get_roles(claims, &self.config.roles_claim);
+        for (role, username) in &self.role_mappings {
+            if roles.contains(&role.as_str()) && !usernames.contains(username) {
+                usernames.push(username.clone());
+            }
+        }
+
+        OidcToken {
+            token_id: claims
+                .get("jti")
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .to_owned(),
+            token_expiry: claims
+                .get("exp")
+                .and_then(Value::as_u64)
+                .unwrap_or_default(),
+            usernames,
+        }
+    }
+
+    async fn get_key(
+        &self,
+        key_id: Option<&str>,
+    ) -> Result<Option<DecodingKey>, AuthenticatorError> {
+        if let Some(key) = self.keys.read().await.find(key_id) {
+            return Ok(Some(key));
+        }
+
+        let mut keys = self.keys.write().await;
+        // The keys might have been fetched by the other request in the meantime.
+        if let Some(key) = keys.find(key_id) {
+            return Ok(Some(key));
+        }
+
+        let refresh_interval = self.config.jwks_refresh_interval.get_duration();
+        if keys
+            .fetched_at
+            .is_some_and(|fetched_at| fetched_at.elapsed() < refresh_interval)
+        {
+            return Ok(None);
+        }
+
+        keys.fetched_at = Some(Instant::now());
+        keys.keys = self.fetch_keys().await?;
+        info!(
+            "Fetched {} OIDC signing keys from: {}.",
+            keys.keys.len(),
+            self.config.jwks_uri
+        );
+        Ok(keys.find(key_id))
+    }
+
+    async fn fetch_keys(&self) -> Result<AHashMap<String, DecodingKey>, AuthenticatorError> {
+        let response = self
+            .client
+            .get(&self.config.jwks_uri)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|error| AuthenticatorError::AuthenticatorRequestFailed {
+                reason: error.to_string(),
+            })?;
+        let key_set = response.json::<JwkSet>().await.map_err(|error| {
+            error!("{COMPONENT} (error: {error}) - invalid OIDC key set");
+            AuthenticatorError::InvalidAuthenticatorResponse
+        })?;
+
+        let mut keys = AHashMap::with_capacity(key_set.keys.len());
+        for jwk in key_set.keys {
+            if matches!(jwk.common.public_key_use, Some(PublicKeyUse::Encryption)) {
+                continue;
+            }
+
+            match DecodingKey::from_jwk(&jwk) {
+                Ok(key) => {
+                    keys.insert(jwk.common.key_id.unwrap_or_default(), key);
+                }
+                Err(error) => {
+                    error!(
+                        "{COMPONENT} (error: {error}) - unsupported OIDC signing key: {:?}",
+                        jwk.common.key_id
+                    );
+                }
+            }
+        }
+        Ok(keys)
+    }
+}
+
+/// Parses the mapping in the "<role>=<username>" format.
+pub fn parse_role_mapping(mapping: &str) -> Result<(String, String), AuthenticatorError> {
+    let Some((role, username)) = mapping.split_once('=') else {
+        return Err(AuthenticatorError::InvalidAuthenticatorConfiguration);
+    };
+
+    let role = role.trim();
+    let username = username.trim();
+    if role.is_empty() || username.is_empty() {
+        return Err(AuthenticatorError::InvalidAuthenticatorConfiguration);
+    }
+
+    Ok((role.to_owned(), username.to_owned()))
+}
+
+/// Returns the roles from the claim, the nested claims are separated by dots.
+/// The roles can be either an array of strings, or a single string separated by spaces.
+fn get_roles<'a>(claims: &'a Value, roles_claim: &str) -> Vec<&'a str> {
+    if roles_claim.is_empty() {
+        return Vec::new();
+    }
+
+    let roles = roles_claim
+        .split('.')
+        .try_fold(claims, |value, claim| value.get(claim));
+    match roles {
+        Some(Value::Array(roles)) => roles.iter().filter_map(Value::as_str).collect(),
+        Some(Value::String(roles)) => roles.split_whitespace().collect(),
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy::utils::duration::IggyDuration;
+    use serde_json::json;
+
+    fn validator(roles_claim: &str, role_mappings: &[&str]) -> OidcTokenValidator {
+        OidcTokenValidator::new(HttpOidcConfig {
+            enabled: true,
+            issuer: "https://idp".to_owned(),
+            jwks_uri: "http://localhost/certs".to_owned(),
+            audience: "iggy".to_owned(),
+            username_claim: "preferred_username".to_owned(),
+            roles_claim: roles_claim.to_owned(),
+            role_mappings: role_mappings.iter().map(|m| m.to_string()).collect(),
+            jwks_refresh_interval: IggyDuration::ONE_SECOND,
+            timeout: IggyDuration::ONE_SECOND,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn claims_should_be_mapped_to_username_and_then_to_roles() {
+        let validator = validator(
+            "realm_access.roles",
+            &["readers=reader", "admins=admin", "writers=writer"],
+        );
+        let token = validator.map_claims(&json!({
+            "jti": "token-1",
+            "exp": 100,
+            "preferred_username": "user",
+            "realm_access": { "roles": ["writers", "admins"] },
+        }));
+
+        assert_eq!(token.token_id, "token-1");
+        assert_eq!(token.token_expiry, 100);
+        assert_eq!(token.usernames, vec!["user", "admin", "writer"]);
+    }
+
+    #[test]
+    fn roles_should_be_read_from_space_separated_claim() {
+        let validator = validator("scope", &["iggy.read=reader"]);
+        let token = validator.map_claims(&json!({ "scope": "openid iggy.read" }));
+
+        assert_eq!(token.usernames, vec!["reader"]);
+    }
+
+    #[test]
+    fn invalid_role_mapping_should_be_rejected() {
+        assert!(parse_role_mapping("admins").is_err());
+        assert!(parse_role_mapping("=admin").is_err());
+        assert_eq!(
+            parse_role_mapping(" admins = admin ").unwrap(),
+            ("admins".to_owned(), "admin".to_owned())
+        );
+    }
+}
@@ -0,0 +1,126 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::http::error::CustomError;
+use crate::streaming::clients::transport_limiter::{ConnectionPermit, TransportLimiter};
+use axum::body::Body;
+use axum::extract::State;
+use axum::http::{header, HeaderValue, Request};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use futures::future::BoxFuture;
+use std::convert::Infallible;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use tower_service::Service;
+
+/// Rejects the requests above the in-flight limit or declaring a body bigger than the frame size limit.
+pub async fn load_shedding(
+    State(limiter): State<Arc<TransportLimiter>>,
+    request: Request<Body>,
+    next: Next,
+) -> Result<Response, CustomError> {
+    if let Some(content_length) = request
+        .headers()
+        .get(header::CONTENT_LENGTH)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<u64>().ok())
+    {
+        limiter.validate_frame_size(content_length)?;
+    }
+
+    let _request_permit = limiter.try_acquire_request()?;
+    Ok(next.run(request).await)
+}
+
+/// Wraps the service making a new service for each accepted connection, so that the connections
+/// above the limit are answered with an error and closed, while the others keep the connection slot
+/// acquired until they are closed and all the services handling them are dropped.
+#[derive(Debug, Clone)]
+pub struct ConnectionLimit<M> {
+    inner: M,
+    limiter: Arc<TransportLimiter>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ConnectionLimitedService<S> {
+    inner: S,
+    limiter: Arc<TransportLimiter>,
+    permit: Option<Arc<ConnectionPermit>>,
+}
+
+impl<M> ConnectionLimit<M> {
+    pub fn new(inner: M, limiter: Arc<TransportLimiter>) -> Self {
+        Self { inner, limiter }
+    }
+}
+
+impl<M, T> Service<T> for ConnectionLimit<M>
+where
+    M: Service<T>,
+    M::Future: Send + 'static,
+    M::Response: Send + 'static,
+{
+    type Response = ConnectionLimitedService<M::Response>;
+    type Error = M::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, target: T) -> Self::Future {
+        let limiter = self.limiter.clone();
+        let permit = limiter.try_acquire_connection().ok().map(Arc::new);
+        let make_service = self.inner.call(target);
+        Box::pin(async move {
+            Ok(ConnectionLimitedService {
+                inner: make_service.await?,
+                limiter,
+                permit,
+            })
+        })
+    }
+}
+
+impl<S, B> Service<Request<B>> for ConnectionLimitedService<S>
+where
+    S: Service<Request<B>, Response = Response, Error = Infallible>,
+    S::Future: Send + 'static,
+{
+    type Response = Response;
+    type Error = Infallible;
+    type Future = BoxFuture<'static, Result<Response, Infallible>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request<B>) -> Self::Future {
+        if self.permit.is_none() {
+            let mut response =
+                CustomError::Error(self.limiter.too_many_connections()).into_response();
+            response
+                .headers_mut()
+                .insert(header::CONNECTION, HeaderValue::from_static("close"));
+            return Box::pin(std::future::ready(Ok(response)));
+        }
+
+        Box::pin(self.inner.call(request))
+    }
+}
@@ -0,0 +1,78 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::http::error::CustomError;
+use crate::http::shared::AppState;
+use axum::body::Body;
+use axum::extract::State;
+use axum::http::{Method, Request};
+use axum::middleware::Next;
+use axum::response::Response;
+use std::sync::Arc;
+
+/// Paths which remain available in maintenance mode, so that the clients can still
+/// authenticate, learn about the maintenance and the operators can back up the data and end it.
+const MAINTENANCE_ALLOWED_PATHS: &[&str] = &[
+    "/",
+    "/ping",
+    "/stats",
+    "/info",
+    "/error-catalog",
+    "/maintenance",
+    "/backups",
+    "/users/login",
+    "/users/logout",
+    "/users/refresh-token",
+    "/personal-access-tokens/login",
+];
+
+/// Rejects the writes (and optionally the reads) with the retryable error, when the server is in maintenance mode.
+pub async fn maintenance(
+    State(state): State<Arc<AppState>>,
+    request: Request<Body>,
+    next: Next,
+) -> Result<Response, CustomError> {
+    if !MAINTENANCE_ALLOWED_PATHS.contains(&request.uri().path()) {
+        let is_read = is_read(request.method());
+        state
+            .system
+            .read()
+            .await
+            .ensure_not_in_maintenance(is_read)?;
+    }
+
+    Ok(next.run(request).await)
+}
+
+pub(crate) fn is_read(method: &Method) -> bool {
+    matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_safe_methods_should_be_treated_as_reads() {
+        assert!(is_read(&Method::GET));
+        assert!(is_read(&Method::HEAD));
+        assert!(!is_read(&Method::POST));
+        assert!(!is_read(&Method::PUT));
+        assert!(!is_read(&Method::DELETE));
+    }
+}
@@ -44,6 +44,8 @@ pub fn map_stream(stream: &Stream) -> StreamDetails {
         topics_count: topics.len() as u32,
         size: stream.get_size(),
         messages_count: stream.get_messages_count(),
+        metadata: stream.metadata.clone(),
+        quota: stream.quota,
         topics,
     };
     stream_details.topics.sort_by(|a, b| a.id.cmp(&b.id));
@@ -60,6 +62,8 @@ pub fn map_streams(streams: &[&Stream]) -> Vec<iggy::models::stream::Stream> {
             size: stream.get_size(),
             topics_count: stream.get_topics().len() as u32,
             messages_count: stream.get_messages_count(),
+            metadata: stream.metadata.clone(),
+            quota: stream.quota,
         };
         streams_data.push(stream);
     }
@@ -81,7 +85,14 @@ pub fn map_topics(topics: &[&Topic]) -> Vec<iggy::models::topic::Topic> {
             message_expiry: topic.message_expiry,
             compression_algorithm: topic.compression_algorithm,
             max_topic_size: topic.max_topic_size,
+            max_segments: topic.max_segments,
             replication_factor: topic.replication_factor,
+            metadata: topic.metadata.clone(),
+            message_id_scheme: topic.message_id_scheme,
+            cleanup_policy: topic.cleanup_policy,
+            allowed_producers: topic.allowed_producers.clone(),
+            delete_at: topic.delete_at,
+            config: topic.topic_config,
         };
         topics_data.push(topic);
     }
@@ -101,7 +112,15 @@ pub async fn map_topic(topic: &Topic) -> TopicDetails {
         message_expiry: topic.message_expiry,
         compression_algorithm: topic.compression_algorithm,
         max_topic_size: topic.max_topic_size,
+        max_segments: topic.max_segments,
         replication_factor: topic.replication_factor,
+        metadata: topic.metadata.clone(),
+        message_id_scheme: topic.message_id_scheme,
+        cleanup_policy: topic.cleanup_policy,
+        allowed_producers: topic.allowed_producers.clone(),
+        delete_at: topic.delete_at,
+        config: topic.topic_config,
+        message_sizes: topic.message_sizes.to_distribution(),
     };
     for partition in topic.get_partitions() {
         let partition = partition.read().await;
@@ -114,6 +133,7 @@ pub async fn map_topic(topic: &Topic) -> TopicDetails {
                 current_offset: partition.current_offset,
                 size: partition.get_size_bytes(),
                 messages_count: partition.get_messages_count(),
+                recovery: partition.get_recovery_progress(),
             });
     }
     topic_details.partitions.sort_by(|a, b| a.id.cmp(&b.id));
@@ -127,6 +147,7 @@ pub fn map_user(user: &User) -> UserInfoDetails {
         created_at: user.created_at,
         status: user.status,
         permissions: user.permissions.clone(),
+        quotas: user.quotas,
     }
 }
 
@@ -153,6 +174,8 @@ pub fn map_personal_access_tokens(
         let personal_access_token = PersonalAccessTokenInfo {
             name: personal_access_token.name.clone(),
             expiry_at: personal_access_token.expiry_at,
+            scope: personal_access_token.scope.clone(),
+            last_used_at: personal_access_token.get_last_used_at(),
         };
         personal_access_tokens_data.push(personal_access_token);
     }
@@ -225,6 +248,16 @@ pub async fn map_consumer_group(consumer_group: &ConsumerGroup) -> ConsumerGroup
         partitions_count: consumer_group.partitions_count,
         members_count: consumer_group.get_members().len() as u32,
         members: Vec::new(),
+        rebalances: consumer_group
+            .get_rebalances()
+            .into_iter()
+            .cloned()
+            .collect(),
+        generation: consumer_group.get_generation(),
+        assignment_strategy: consumer_group.assignment_strategy,
+        offset_recovery: consumer_group.offset_recovery,
+        dead_letter: consumer_group.dead_letter,
+        visibility_timeout: consumer_group.visibility_timeout,
     };
     let members = consumer_group.get_members();
     for member in members {
@@ -22,19 +22,29 @@ use crate::http::shared::AppState;
 use crate::http::COMPONENT;
 use crate::streaming::session::Session;
 use crate::streaming::systems::messages::PollingArgs;
-use crate::streaming::utils::random_id;
 use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
-use axum::routing::get;
+use axum::routing::{get, post};
 use axum::{Extension, Json, Router};
 use error_set::ErrContext;
-use iggy::consumer::Consumer;
+use iggy::consumer::{Consumer, ConsumerKind};
 use iggy::identifier::Identifier;
+use iggy::messages::ack_messages::AckMessages;
+use iggy::messages::aggregate_messages::AggregateMessages;
+use iggy::messages::nack_messages::NackMessages;
 use iggy::messages::poll_messages::PollMessages;
+use iggy::messages::register_producer::RegisterProducer;
 use iggy::messages::send_messages::SendMessages;
+use iggy::messages::send_messages_batch::SendMessagesBatch;
 use iggy::models::messages::PolledMessages;
+use iggy::models::messages_aggregate::MessagesBucket;
+use iggy::models::producer_epoch::ProducerEpoch;
+use iggy::models::producer_session::ProducerSession;
+use iggy::models::transaction::Transaction;
+use iggy::utils::sizeable::Sizeable;
 use iggy::validatable::Validatable;
 use std::sync::Arc;
+use tokio::time::sleep;
 use tracing::instrument;
 
 pub fn router(state: Arc<AppState>) -> Router {
@@ -47,6 +57,36 @@ pub fn router(state: Arc<AppState>) -> Router {
             "/streams/{stream_id}/topics/{topic_id}/messages/flush/{partition_id}/{fsync}",
             get(flush_unsaved_buffer),
         )
+        .route(
+            "/streams/{stream_id}/topics/{topic_id}/messages/aggregate",
+            get(aggregate_messages),
+        )
+        .route(
+            "/streams/{stream_id}/topics/{topic_id}/messages/nack",
+            post(nack_messages),
+        )
+        .route(
+            "/streams/{stream_id}/topics/{topic_id}/messages/ack",
+            post(ack_messages),
+        )
+        .route(
+            "/streams/{stream_id}/topics/{topic_id}/producers",
+            post(register_producer),
+        )
+        .route(
+            "/streams/{stream_id}/topics/{topic_id}/producer-sessions",
+            post(init_producer_id),
+        )
+        .route("/messages/batch", post(send_messages_batch))
+        .route("/transactions", post(begin_transaction))
+        .route(
+            "/transactions/{transaction_id}/commit",
+            post(commit_transaction),
+        )
+        .route(
+            "/transactions/{transaction_id}/abort",
+            post(abort_transaction),
+        )
         .with_state(state)
 }
 
@@ -62,14 +102,23 @@ async fn poll_messages(
 
     let consumer = Consumer::new(query.0.consumer.id);
     let system = state.system.read().await;
-    let polled_messages = system
+    let mut polled_messages = system
         .poll_messages(
             &Session::stateless(identity.user_id, identity.ip_address),
             &consumer,
             &query.0.stream_id,
             &query.0.topic_id,
             query.0.partition_id,
-            PollingArgs::new(query.0.strategy, query.0.count, query.0.auto_commit),
+            PollingArgs::new(
+                query.0.strategy,
+                query.0.count,
+                query.0.auto_commit,
+                query.0.isolation_level,
+            )
+            .with_chunk_size(query.0.chunk_size)
+            .with_filter(query.0.filter.clone())
+            .with_partition_epoch(query.0.partition_epoch)
+            .with_peek(query.0.peek),
         )
         .await
         .with_error_context(|error| {
@@ -78,9 +127,48 @@ async fn poll_messages(
                 stream_id, topic_id, query.0.partition_id
             )
         })?;
+
+    let (delay, throttle_time_ms) =
+        system.split_fetch_throttle_time(polled_messages.throttle_time_ms);
+    polled_messages.throttle_time_ms = throttle_time_ms;
+    drop(system);
+    if !delay.is_zero() {
+        sleep(delay).await;
+    }
     Ok(Json(polled_messages))
 }
 
+async fn aggregate_messages(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    mut query: Query<AggregateMessages>,
+) -> Result<Json<Vec<MessagesBucket>>, CustomError> {
+    query.stream_id = Identifier::from_str_value(&stream_id)?;
+    query.topic_id = Identifier::from_str_value(&topic_id)?;
+    query.validate()?;
+
+    let system = state.system.read().await;
+    let buckets = system
+        .aggregate_messages(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &query.0.stream_id,
+            &query.0.topic_id,
+            query.0.partition_id,
+            query.0.from,
+            query.0.to,
+            query.0.bucket,
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to aggregate messages, stream ID: {}, topic ID: {}, partition ID: {:?}",
+                stream_id, topic_id, query.0.partition_id
+            )
+        })?;
+    Ok(Json(buckets))
+}
+
 async fn send_messages(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -90,22 +178,22 @@ async fn send_messages(
     command.stream_id = Identifier::from_str_value(&stream_id)?;
     command.topic_id = Identifier::from_str_value(&topic_id)?;
     command.partitioning.length = command.partitioning.value.len() as u8;
-    command.messages.iter_mut().for_each(|msg| {
-        if msg.id == 0 {
-            msg.id = random_id::get_uuid();
-        }
-    });
     command.validate()?;
 
     let messages = command.messages;
     let command_stream_id = command.stream_id;
     let command_topic_id = command.topic_id;
     let partitioning = command.partitioning;
+    let bytes = messages
+        .iter()
+        .map(|message| message.get_size_bytes().as_bytes_u64())
+        .sum::<u64>();
+    let session = Session::stateless(identity.user_id, identity.ip_address);
     let system = state.system.read().await;
     // TODO(haze): Add confirmation level after testing is complete
     system
         .append_messages(
-            &Session::stateless(identity.user_id, identity.ip_address),
+            &session,
             command_stream_id,
             command_topic_id,
             partitioning,
@@ -119,6 +207,46 @@ async fn send_messages(
                 stream_id, topic_id
             )
         })?;
+    let delay = system.throttle_produce(&session, bytes);
+    drop(system);
+    if !delay.is_zero() {
+        sleep(delay).await;
+    }
+    Ok(StatusCode::CREATED)
+}
+
+async fn send_messages_batch(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Json(mut command): Json<SendMessagesBatch>,
+) -> Result<StatusCode, CustomError> {
+    // The lengths are not a part of the JSON payload.
+    for batch in command.batches.iter_mut() {
+        batch.stream_id.length = batch.stream_id.value.len() as u8;
+        batch.topic_id.length = batch.topic_id.value.len() as u8;
+        batch.partitioning.length = batch.partitioning.value.len() as u8;
+    }
+    command.validate()?;
+
+    let bytes = command
+        .batches
+        .iter()
+        .flat_map(|batch| &batch.messages)
+        .map(|message| message.get_size_bytes().as_bytes_u64())
+        .sum::<u64>();
+    let session = Session::stateless(identity.user_id, identity.ip_address);
+    let system = state.system.read().await;
+    system
+        .append_messages_batch(&session, command.batches, None)
+        .await
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to append messages batches")
+        })?;
+    let delay = system.throttle_produce(&session, bytes);
+    drop(system);
+    if !delay.is_zero() {
+        sleep(delay).await;
+    }
     Ok(StatusCode::CREATED)
 }
 
@@ -142,3 +270,172 @@ async fn flush_unsaved_buffer(
         .await?;
     Ok(StatusCode::OK)
 }
+
+#[instrument(skip_all, name = "trace_nack_messages", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id))]
+async fn nack_messages(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    Json(mut command): Json<NackMessages>,
+) -> Result<StatusCode, CustomError> {
+    command.stream_id = Identifier::from_str_value(&stream_id)?;
+    command.topic_id = Identifier::from_str_value(&topic_id)?;
+    // The consumer kind is not serialized, only the consumer groups can nack the messages.
+    command.consumer.kind = ConsumerKind::ConsumerGroup;
+    command.validate()?;
+    let system = state.system.read().await;
+    system
+        .nack_messages(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &command.consumer,
+            &command.stream_id,
+            &command.topic_id,
+            command.partition_id,
+            &command.offsets,
+            &command.reason,
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to nack messages, stream ID: {}, topic ID: {}",
+                stream_id, topic_id
+            )
+        })?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[instrument(skip_all, name = "trace_ack_messages", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id))]
+async fn ack_messages(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    Json(mut command): Json<AckMessages>,
+) -> Result<StatusCode, CustomError> {
+    command.stream_id = Identifier::from_str_value(&stream_id)?;
+    command.topic_id = Identifier::from_str_value(&topic_id)?;
+    // The consumer kind is not serialized, only the consumer groups can ack the messages.
+    command.consumer.kind = ConsumerKind::ConsumerGroup;
+    command.validate()?;
+    let system = state.system.read().await;
+    system
+        .ack_messages(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &command.consumer,
+            &command.stream_id,
+            &command.topic_id,
+            command.partition_id,
+            &command.offsets,
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to ack messages, stream ID: {}, topic ID: {}",
+                stream_id, topic_id
+            )
+        })?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[instrument(skip_all, name = "trace_register_producer", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id))]
+async fn register_producer(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    Json(mut command): Json<RegisterProducer>,
+) -> Result<Json<ProducerEpoch>, CustomError> {
+    command.stream_id = Identifier::from_str_value(&stream_id)?;
+    command.topic_id = Identifier::from_str_value(&topic_id)?;
+    command.validate()?;
+    let system = state.system.read().await;
+    let producer_epoch = system
+        .register_producer(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &command.stream_id,
+            &command.topic_id,
+            &command.name,
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to register producer: {}, stream ID: {}, topic ID: {}",
+                command.name, stream_id, topic_id
+            )
+        })?;
+    Ok(Json(producer_epoch))
+}
+
+#[instrument(skip_all, name = "trace_init_producer_id", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id))]
+async fn init_producer_id(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+) -> Result<Json<ProducerSession>, CustomError> {
+    let identifier_stream_id = Identifier::from_str_value(&stream_id)?;
+    let identifier_topic_id = Identifier::from_str_value(&topic_id)?;
+    let system = state.system.read().await;
+    let producer_session = system
+        .init_producer_id(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &identifier_stream_id,
+            &identifier_topic_id,
+        )
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to init producer ID, stream ID: {stream_id}, topic ID: {topic_id}"
+            )
+        })?;
+    Ok(Json(producer_session))
+}
+
+#[instrument(skip_all, name = "trace_begin_transaction", fields(iggy_user_id = identity.user_id))]
+async fn begin_transaction(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Json<Transaction>, CustomError> {
+    let system = state.system.read().await;
+    let transaction = system
+        .begin_transaction(&Session::stateless(identity.user_id, identity.ip_address))
+        .await
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to begin transaction")
+        })?;
+    Ok(Json(transaction))
+}
+
+#[instrument(skip_all, name = "trace_commit_transaction", fields(iggy_user_id = identity.user_id, iggy_transaction_id = transaction_id))]
+async fn commit_transaction(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(transaction_id): Path<u64>,
+) -> Result<StatusCode, CustomError> {
+    let system = state.system.read().await;
+    system
+        .commit_transaction(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            transaction_id,
+        )
+        .await
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to commit transaction with ID: {transaction_id}")
+        })?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[instrument(skip_all, name = "trace_abort_transaction", fields(iggy_user_id = identity.user_id, iggy_transaction_id = transaction_id))]
+async fn abort_transaction(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(transaction_id): Path<u64>,
+) -> Result<StatusCode, CustomError> {
+    let system = state.system.read().await;
+    system
+        .abort_transaction(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            transaction_id,
+        )
+        .await
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to abort transaction with ID: {transaction_id}")
+        })?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -17,7 +17,10 @@
  */
 
 use crate::http::shared::AppState;
-use axum::body::Body;
+use crate::streaming::diagnostics::metrics::HttpRouteLabels;
+use axum::body::{Body, HttpBody};
+use axum::extract::MatchedPath;
+use axum::http::{header, HeaderMap};
 use axum::{
     extract::State,
     http::{Request, StatusCode},
@@ -25,12 +28,47 @@ use axum::{
     response::Response,
 };
 use std::sync::Arc;
+use tokio::time::Instant;
 
+/// Records the number of the requests and, per matched route, the latency, the status code
+/// and the size of the request and response bodies.
 pub async fn metrics(
     State(state): State<Arc<AppState>>,
     request: Request<Body>,
     next: Next,
 ) -> Result<Response, StatusCode> {
-    state.system.read().await.metrics.increment_http_requests();
-    Ok(next.run(request).await)
+    // The route template is used instead of the path, so that the IDs don't blow up the labels.
+    let route = HttpRouteLabels {
+        method: request.method().as_str().to_owned(),
+        route: request
+            .extensions()
+            .get::<MatchedPath>()
+            .map(|path| path.as_str().to_owned())
+            .unwrap_or_else(|| "unmatched".to_owned()),
+    };
+    let request_size = get_body_size(request.headers(), request.body());
+    let now = Instant::now();
+    let response = next.run(request).await;
+    let elapsed = now.elapsed();
+    let response_size = get_body_size(response.headers(), response.body());
+
+    let system = state.system.read().await;
+    system.metrics.increment_http_requests();
+    system.metrics.record_http_response(
+        route,
+        response.status().as_u16(),
+        elapsed,
+        request_size,
+        response_size,
+    );
+    Ok(response)
+}
+
+fn get_body_size(headers: &HeaderMap, body: &Body) -> u64 {
+    headers
+        .get(header::CONTENT_LENGTH)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<u64>().ok())
+        .or_else(|| body.size_hint().exact())
+        .unwrap_or_else(|| body.size_hint().lower())
 }
@@ -16,17 +16,25 @@
  * under the License.
  */
 
+pub mod cluster;
 pub mod consumer_groups;
 pub mod consumer_offsets;
 pub mod diagnostics;
 pub mod error;
 pub mod http_server;
 pub mod jwt;
+pub mod load_shedding;
+pub mod maintenance;
 mod mapper;
 pub mod messages;
 pub mod metrics;
+pub mod namespaces;
 pub mod partitions;
 pub mod personal_access_tokens;
+pub mod push_subscriptions;
+pub mod quotas;
+pub mod read_only;
+pub mod replay_jobs;
 mod shared;
 pub mod streams;
 pub mod system;
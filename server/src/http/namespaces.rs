@@ -0,0 +1,211 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::http::error::CustomError;
+use crate::http::jwt::json_web_token::Identity;
+use crate::http::shared::AppState;
+use crate::http::COMPONENT;
+use crate::state::command::EntryCommand;
+use crate::state::models::CreateNamespaceWithId;
+use crate::streaming::session::Session;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::routing::get;
+use axum::{Extension, Json, Router};
+use error_set::ErrContext;
+use iggy::identifier::Identifier;
+use iggy::models::namespace::{Namespace, NamespaceDetails};
+use iggy::namespaces::create_namespace::CreateNamespace;
+use iggy::namespaces::delete_namespace::DeleteNamespace;
+use iggy::namespaces::update_namespace::UpdateNamespace;
+use iggy::validatable::Validatable;
+use std::sync::Arc;
+use tracing::instrument;
+
+pub fn router(state: Arc<AppState>) -> Router {
+    Router::new()
+        .route("/namespaces", get(get_namespaces).post(create_namespace))
+        .route(
+            "/namespaces/{namespace_id}",
+            get(get_namespace)
+                .put(update_namespace)
+                .delete(delete_namespace),
+        )
+        .with_state(state)
+}
+
+async fn get_namespace(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(namespace_id): Path<String>,
+) -> Result<Json<NamespaceDetails>, CustomError> {
+    let system = state.system.read().await;
+    let namespace_id = Identifier::from_str_value(&namespace_id)?;
+    let Ok(namespace) = system.try_find_namespace(
+        &Session::stateless(identity.user_id, identity.ip_address),
+        &namespace_id,
+    ) else {
+        return Err(CustomError::ResourceNotFound);
+    };
+    let Some(namespace) = namespace else {
+        return Err(CustomError::ResourceNotFound);
+    };
+
+    Ok(Json(system.get_namespace_details(namespace)))
+}
+
+async fn get_namespaces(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Json<Vec<Namespace>>, CustomError> {
+    let system = state.system.read().await;
+    let namespaces = system
+        .find_namespaces(&Session::stateless(identity.user_id, identity.ip_address))
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to find namespaces, user ID: {}",
+                identity.user_id
+            )
+        })?
+        .into_iter()
+        .map(|namespace| system.get_namespace_info(namespace))
+        .collect();
+    Ok(Json(namespaces))
+}
+
+#[instrument(skip_all, name = "trace_create_namespace", fields(iggy_user_id = identity.user_id))]
+async fn create_namespace(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Json(command): Json<CreateNamespace>,
+) -> Result<Json<NamespaceDetails>, CustomError> {
+    command.validate()?;
+
+    let mut system = state.system.write().await;
+    let namespace_id = system
+        .create_namespace(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            None,
+            &command.name,
+            command.quotas,
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to create namespace, name: {}",
+                command.name
+            )
+        })?
+        .namespace_id;
+    let namespace = system.get_namespace(&Identifier::numeric(namespace_id)?)?;
+    let response = Json(system.get_namespace_details(namespace));
+
+    let system = system.downgrade();
+    system
+        .state
+        .apply(
+            identity.user_id,
+            EntryCommand::CreateNamespace(CreateNamespaceWithId {
+                namespace_id,
+                command,
+            }),
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to apply create namespace, namespace ID: {namespace_id}",
+            )
+        })?;
+    Ok(response)
+}
+
+#[instrument(skip_all, name = "trace_update_namespace", fields(iggy_user_id = identity.user_id, iggy_namespace_id = namespace_id))]
+async fn update_namespace(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(namespace_id): Path<String>,
+    Json(mut command): Json<UpdateNamespace>,
+) -> Result<StatusCode, CustomError> {
+    command.namespace_id = Identifier::from_str_value(&namespace_id)?;
+    command.validate()?;
+
+    let mut system = state.system.write().await;
+    system
+        .update_namespace(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &command.namespace_id,
+            command.quotas,
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to update namespace, namespace ID: {namespace_id}"
+            )
+        })?;
+
+    let system = system.downgrade();
+    system
+        .state
+        .apply(identity.user_id, EntryCommand::UpdateNamespace(command))
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to apply update namespace, namespace ID: {namespace_id}"
+            )
+        })?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[instrument(skip_all, name = "trace_delete_namespace", fields(iggy_user_id = identity.user_id, iggy_namespace_id = namespace_id))]
+async fn delete_namespace(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(namespace_id): Path<String>,
+) -> Result<StatusCode, CustomError> {
+    let identifier_namespace_id = Identifier::from_str_value(&namespace_id)?;
+
+    let mut system = state.system.write().await;
+    system
+        .delete_namespace(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &identifier_namespace_id,
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to delete namespace with ID: {namespace_id}"
+            )
+        })?;
+
+    let system = system.downgrade();
+    system
+        .state
+        .apply(
+            identity.user_id,
+            EntryCommand::DeleteNamespace(DeleteNamespace {
+                namespace_id: identifier_namespace_id,
+            }),
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to apply delete namespace with ID: {namespace_id}"
+            )
+        })?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -22,14 +22,25 @@ use crate::http::shared::AppState;
 use crate::http::COMPONENT;
 use crate::state::command::EntryCommand;
 use crate::streaming::session::Session;
+use axum::body::Body;
 use axum::extract::{Path, Query, State};
-use axum::http::StatusCode;
-use axum::routing::post;
+use axum::http::{header, HeaderMap, HeaderValue, StatusCode};
+use axum::response::IntoResponse;
+use axum::routing::{get, post};
 use axum::{Extension, Json, Router};
 use error_set::ErrContext;
 use iggy::identifier::Identifier;
+use iggy::models::restored_segments::RestoredSegments;
+use iggy::models::segment_range::{
+    SEGMENT_CLOSED_HEADER, SEGMENT_END_OFFSET_HEADER, SEGMENT_NEXT_POSITION_HEADER,
+    SEGMENT_SIZE_HEADER, SEGMENT_START_OFFSET_HEADER, SEGMENT_START_POSITION_HEADER,
+};
+use iggy::models::unsaved_state::PartitionUnsavedState;
 use iggy::partitions::create_partitions::CreatePartitions;
 use iggy::partitions::delete_partitions::DeletePartitions;
+use iggy::partitions::flush_partition::FlushPartition;
+use iggy::partitions::read_segment_range::ReadSegmentRange;
+use iggy::partitions::restore_archived_segments::RestoreArchivedSegments;
 use iggy::validatable::Validatable;
 use std::sync::Arc;
 use tracing::instrument;
@@ -40,6 +51,18 @@ pub fn router(state: Arc<AppState>) -> Router {
             "/streams/{stream_id}/topics/{topic_id}/partitions",
             post(create_partitions).delete(delete_partitions),
         )
+        .route(
+            "/streams/{stream_id}/topics/{topic_id}/partitions/{partition_id}/restore",
+            post(restore_archived_segments),
+        )
+        .route(
+            "/streams/{stream_id}/topics/{topic_id}/partitions/flush",
+            post(flush_partition),
+        )
+        .route(
+            "/streams/{stream_id}/topics/{topic_id}/partitions/{partition_id}/segments/range",
+            get(read_segment_range),
+        )
         .with_state(state)
 }
 
@@ -131,3 +154,136 @@ async fn delete_partitions(
         })?;
     Ok(StatusCode::NO_CONTENT)
 }
+
+#[instrument(skip_all, name = "trace_restore_archived_segments", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id, iggy_partition_id = partition_id))]
+async fn restore_archived_segments(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id, partition_id)): Path<(String, String, u32)>,
+    Json(mut command): Json<RestoreArchivedSegments>,
+) -> Result<Json<RestoredSegments>, CustomError> {
+    command.stream_id = Identifier::from_str_value(&stream_id)?;
+    command.topic_id = Identifier::from_str_value(&topic_id)?;
+    command.partition_id = partition_id;
+    command.validate()?;
+
+    let restored_segments = state
+        .system
+        .read()
+        .await
+        .restore_archived_segments(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &command.stream_id,
+            &command.topic_id,
+            command.partition_id,
+            command.start_offset,
+            command.end_offset,
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to restore archived segments for partition with ID: {partition_id} for topic with ID: {topic_id} in stream with ID: {stream_id}",
+            )
+        })?;
+    Ok(Json(restored_segments))
+}
+
+#[instrument(skip_all, name = "trace_flush_partition", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id))]
+async fn flush_partition(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    Json(mut command): Json<FlushPartition>,
+) -> Result<Json<Vec<PartitionUnsavedState>>, CustomError> {
+    command.stream_id = Identifier::from_str_value(&stream_id)?;
+    command.topic_id = Identifier::from_str_value(&topic_id)?;
+    command.validate()?;
+
+    let flushed_partitions = state
+        .system
+        .read()
+        .await
+        .flush_partition(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &command.stream_id,
+            &command.topic_id,
+            command.partition_id,
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to flush partition with ID: {:?} for topic with ID: {topic_id} in stream with ID: {stream_id}",
+                command.partition_id
+            )
+        })?;
+    Ok(Json(flushed_partitions))
+}
+
+#[instrument(skip_all, name = "trace_read_segment_range", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id, iggy_partition_id = partition_id))]
+async fn read_segment_range(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id, partition_id)): Path<(String, String, u32)>,
+    Query(mut query): Query<ReadSegmentRange>,
+) -> Result<impl IntoResponse, CustomError> {
+    query.stream_id = Identifier::from_str_value(&stream_id)?;
+    query.topic_id = Identifier::from_str_value(&topic_id)?;
+    query.partition_id = partition_id;
+    query.validate()?;
+
+    let segment_range = state
+        .system
+        .read()
+        .await
+        .read_segment_range(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &query.stream_id,
+            &query.topic_id,
+            query.partition_id,
+            query.offset,
+            query.start_position,
+            query.end_position,
+            query.max_bytes,
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to read segment range for partition with ID: {partition_id} for topic with ID: {topic_id} in stream with ID: {stream_id}",
+            )
+        })?;
+
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        header::CONTENT_TYPE,
+        HeaderValue::from_static("application/octet-stream"),
+    );
+    headers.insert(
+        SEGMENT_START_OFFSET_HEADER,
+        HeaderValue::from(segment_range.segment_start_offset),
+    );
+    headers.insert(
+        SEGMENT_END_OFFSET_HEADER,
+        HeaderValue::from(segment_range.segment_end_offset),
+    );
+    headers.insert(
+        SEGMENT_SIZE_HEADER,
+        HeaderValue::from(segment_range.segment_size),
+    );
+    headers.insert(
+        SEGMENT_CLOSED_HEADER,
+        HeaderValue::from_static(if segment_range.segment_closed {
+            "true"
+        } else {
+            "false"
+        }),
+    );
+    headers.insert(
+        SEGMENT_START_POSITION_HEADER,
+        HeaderValue::from(segment_range.start_position),
+    );
+    headers.insert(
+        SEGMENT_NEXT_POSITION_HEADER,
+        HeaderValue::from(segment_range.next_position),
+    );
+    Ok((headers, Body::from(segment_range.bytes)))
+}
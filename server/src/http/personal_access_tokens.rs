@@ -23,10 +23,10 @@ use crate::http::mapper::map_generated_access_token_to_identity_info;
 use crate::http::shared::AppState;
 use crate::http::COMPONENT;
 use crate::state::command::EntryCommand;
-use crate::state::models::CreatePersonalAccessTokenWithHash;
+use crate::state::models::{CreatePersonalAccessTokenWithHash, RotatePersonalAccessTokenWithHash};
 use crate::streaming::personal_access_tokens::personal_access_token::PersonalAccessToken;
 use crate::streaming::session::Session;
-use axum::extract::{Path, State};
+use axum::extract::{ConnectInfo, Path, State};
 use axum::http::StatusCode;
 use axum::routing::{delete, get, post};
 use axum::{Extension, Json, Router};
@@ -36,7 +36,9 @@ use iggy::models::personal_access_token::{PersonalAccessTokenInfo, RawPersonalAc
 use iggy::personal_access_tokens::create_personal_access_token::CreatePersonalAccessToken;
 use iggy::personal_access_tokens::delete_personal_access_token::DeletePersonalAccessToken;
 use iggy::personal_access_tokens::login_with_personal_access_token::LoginWithPersonalAccessToken;
+use iggy::personal_access_tokens::rotate_personal_access_token::RotatePersonalAccessToken;
 use iggy::validatable::Validatable;
+use std::net::SocketAddr;
 use std::sync::Arc;
 use tracing::instrument;
 
@@ -50,6 +52,10 @@ pub fn router(state: Arc<AppState>) -> Router {
             "/personal-access-tokens/{name}",
             delete(delete_personal_access_token),
         )
+        .route(
+            "/personal-access-tokens/{name}/rotate",
+            post(rotate_personal_access_token),
+        )
         .route(
             "/personal-access-tokens/login",
             post(login_with_personal_access_token),
@@ -89,6 +95,7 @@ async fn create_personal_access_token(
                 &Session::stateless(identity.user_id, identity.ip_address),
                 &command.name,
                 command.expiry,
+                &command.scope,
             )
             .await
             .with_error_context(|error| {
@@ -156,15 +163,62 @@ async fn delete_personal_access_token(
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[instrument(skip_all, name = "trace_rotate_personal_access_token", fields(iggy_user_id = identity.user_id))]
+async fn rotate_personal_access_token(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(name): Path<String>,
+    Json(mut command): Json<RotatePersonalAccessToken>,
+) -> Result<Json<RawPersonalAccessToken>, CustomError> {
+    command.name = name;
+    command.validate()?;
+
+    let mut system = state.system.write().await;
+    let token = system
+            .rotate_personal_access_token(
+                &Session::stateless(identity.user_id, identity.ip_address),
+                &command.name,
+                command.expiry,
+            )
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to rotate personal access token, user ID: {}",
+                    identity.user_id
+                )
+            })?;
+
+    let system = system.downgrade();
+    let token_hash = PersonalAccessToken::hash_token(&token);
+    system
+        .state
+        .apply(
+            identity.user_id,
+            EntryCommand::RotatePersonalAccessToken(RotatePersonalAccessTokenWithHash {
+                command,
+                hash: token_hash,
+            }),
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to apply rotate personal access token with hash, user ID: {}",
+                identity.user_id
+            )
+        })?;
+    Ok(Json(RawPersonalAccessToken { token }))
+}
+
 #[instrument(skip_all, name = "trace_login_with_personal_access_token")]
 async fn login_with_personal_access_token(
     State(state): State<Arc<AppState>>,
+    ConnectInfo(ip_address): ConnectInfo<SocketAddr>,
     Json(command): Json<LoginWithPersonalAccessToken>,
 ) -> Result<Json<IdentityInfo>, CustomError> {
     command.validate()?;
     let system = state.system.read().await;
     let user = system
-        .login_with_personal_access_token(&command.token, None)
+        .login_with_personal_access_token(&command.token, None, Some(ip_address.ip()))
         .await
         .with_error_context(|error| {
             format!("{COMPONENT} (error: {error}) - failed to login with personal access token")
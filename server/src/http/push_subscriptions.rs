@@ -0,0 +1,116 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::http::error::CustomError;
+use crate::http::jwt::json_web_token::Identity;
+use crate::http::shared::AppState;
+use crate::http::COMPONENT;
+use crate::streaming::push::pusher;
+use crate::streaming::session::Session;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::routing::{delete, get};
+use axum::{Extension, Json, Router};
+use error_set::ErrContext;
+use iggy::messages::create_push_subscription::CreatePushSubscription;
+use iggy::models::push_subscription::PushSubscription;
+use iggy::validatable::Validatable;
+use std::sync::Arc;
+use tracing::instrument;
+
+pub fn router(state: Arc<AppState>) -> Router {
+    Router::new()
+        .route(
+            "/push-subscriptions",
+            get(get_push_subscriptions).post(create_push_subscription),
+        )
+        .route(
+            "/push-subscriptions/{subscription_id}",
+            delete(delete_push_subscription),
+        )
+        .with_state(state)
+}
+
+async fn get_push_subscriptions(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Json<Vec<PushSubscription>>, CustomError> {
+    let system = state.system.read().await;
+    let push_subscriptions = system
+        .get_push_subscriptions(&Session::stateless(identity.user_id, identity.ip_address))
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to get push subscriptions, user ID: {}",
+                identity.user_id
+            )
+        })?;
+    Ok(Json(push_subscriptions))
+}
+
+#[instrument(skip_all, name = "trace_create_push_subscription", fields(iggy_user_id = identity.user_id))]
+async fn create_push_subscription(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Json(command): Json<CreatePushSubscription>,
+) -> Result<Json<PushSubscription>, CustomError> {
+    command.validate()?;
+
+    let system = state.system.read().await;
+    let push_subscription = system
+        .create_push_subscription(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &command.stream_id,
+            &command.topic_id,
+            command.partition_id,
+            &command.endpoint,
+            command.start_offset,
+            command.batch_size,
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to create push subscription, user ID: {}",
+                identity.user_id
+            )
+        })?;
+    drop(system);
+    pusher::start(state.system.clone(), push_subscription.clone());
+    Ok(Json(push_subscription.to_info()))
+}
+
+#[instrument(skip_all, name = "trace_delete_push_subscription", fields(iggy_user_id = identity.user_id, iggy_push_subscription_id = subscription_id))]
+async fn delete_push_subscription(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(subscription_id): Path<u32>,
+) -> Result<StatusCode, CustomError> {
+    let system = state.system.read().await;
+    system
+        .delete_push_subscription(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            subscription_id,
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to delete push subscription with ID: {subscription_id}, user ID: {}",
+                identity.user_id
+            )
+        })?;
+    Ok(StatusCode::NO_CONTENT)
+}
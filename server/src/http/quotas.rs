@@ -0,0 +1,48 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::http::jwt::json_web_token::Identity;
+use crate::http::shared::AppState;
+use crate::streaming::session::Session;
+use axum::body::Body;
+use axum::extract::State;
+use axum::http::Request;
+use axum::middleware::Next;
+use axum::response::Response;
+use std::sync::Arc;
+use tokio::time::sleep;
+
+/// Delays the requests of the authenticated user exceeding its request quota, instead of rejecting them.
+pub async fn quotas(
+    State(state): State<Arc<AppState>>,
+    request: Request<Body>,
+    next: Next,
+) -> Response {
+    if let Some(identity) = request.extensions().get::<Identity>() {
+        let delay = state
+            .system
+            .read()
+            .await
+            .throttle_request(&Session::stateless(identity.user_id, identity.ip_address));
+        if !delay.is_zero() {
+            sleep(delay).await;
+        }
+    }
+
+    next.run(request).await
+}
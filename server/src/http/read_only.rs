@@ -0,0 +1,70 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::http::error::CustomError;
+use axum::body::Body;
+use axum::http::{Method, Request};
+use axum::middleware::Next;
+use axum::response::Response;
+use iggy::error::IggyError;
+
+/// Paths accepting non-idempotent methods that do not modify the system.
+const READ_ONLY_WRITE_PATHS: &[&str] = &[
+    "/users/login",
+    "/users/refresh-token",
+    "/personal-access-tokens/login",
+    "/backups",
+];
+
+/// Rejects all the requests which could modify the system, when the server runs in read-only mode.
+pub async fn read_only(request: Request<Body>, next: Next) -> Result<Response, CustomError> {
+    if is_allowed(request.method(), request.uri().path()) {
+        return Ok(next.run(request).await);
+    }
+
+    Err(CustomError::Error(IggyError::ReadOnlyMode))
+}
+
+fn is_allowed(method: &Method, path: &str) -> bool {
+    match *method {
+        Method::GET | Method::HEAD | Method::OPTIONS => true,
+        Method::POST => READ_ONLY_WRITE_PATHS.contains(&path),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn safe_methods_and_login_should_be_allowed() {
+        assert!(is_allowed(&Method::GET, "/streams"));
+        assert!(is_allowed(&Method::HEAD, "/streams"));
+        assert!(is_allowed(&Method::POST, "/users/login"));
+        assert!(is_allowed(&Method::POST, "/personal-access-tokens/login"));
+    }
+
+    #[test]
+    fn modifying_requests_should_be_rejected() {
+        assert!(!is_allowed(&Method::POST, "/streams"));
+        assert!(!is_allowed(&Method::PUT, "/streams/1"));
+        assert!(!is_allowed(&Method::DELETE, "/streams/1"));
+        assert!(!is_allowed(&Method::POST, "/snapshot"));
+    }
+}
@@ -0,0 +1,112 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::http::error::CustomError;
+use crate::http::jwt::json_web_token::Identity;
+use crate::http::shared::AppState;
+use crate::http::COMPONENT;
+use crate::streaming::replay::replayer;
+use crate::streaming::session::Session;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::routing::{delete, get};
+use axum::{Extension, Json, Router};
+use error_set::ErrContext;
+use iggy::messages::replay_messages::ReplayMessages;
+use iggy::models::replay_job::ReplayJob;
+use iggy::validatable::Validatable;
+use std::sync::Arc;
+use tracing::instrument;
+
+pub fn router(state: Arc<AppState>) -> Router {
+    Router::new()
+        .route("/replay-jobs", get(get_replay_jobs).post(replay_messages))
+        .route("/replay-jobs/{job_id}", delete(cancel_replay_job))
+        .with_state(state)
+}
+
+async fn get_replay_jobs(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Json<Vec<ReplayJob>>, CustomError> {
+    let system = state.system.read().await;
+    let replay_jobs = system
+        .get_replay_jobs(&Session::stateless(identity.user_id, identity.ip_address))
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to get replay jobs, user ID: {}",
+                identity.user_id
+            )
+        })?;
+    Ok(Json(replay_jobs))
+}
+
+#[instrument(skip_all, name = "trace_replay_messages", fields(iggy_user_id = identity.user_id))]
+async fn replay_messages(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Json(command): Json<ReplayMessages>,
+) -> Result<Json<ReplayJob>, CustomError> {
+    command.validate()?;
+
+    let system = state.system.read().await;
+    let replay_job = system
+        .create_replay_job(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &command.source_stream_id,
+            &command.source_topic_id,
+            command.source_partition_id,
+            command.range,
+            &command.destination_stream_id,
+            &command.destination_topic_id,
+            command.partitioning,
+            command.headers,
+            command.messages_per_second,
+            command.batch_size,
+        )
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to create replay job, user ID: {}",
+                identity.user_id
+            )
+        })?;
+    drop(system);
+    replayer::start(state.system.clone(), replay_job.clone());
+    Ok(Json(replay_job.to_info()))
+}
+
+#[instrument(skip_all, name = "trace_cancel_replay_job", fields(iggy_user_id = identity.user_id, iggy_replay_job_id = job_id))]
+async fn cancel_replay_job(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(job_id): Path<u32>,
+) -> Result<StatusCode, CustomError> {
+    let system = state.system.read().await;
+    system
+        .cancel_replay_job(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            job_id,
+        )
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to cancel replay job with ID: {job_id}, user ID: {}",
+                identity.user_id
+            )
+        })?;
+    Ok(StatusCode::NO_CONTENT)
+}
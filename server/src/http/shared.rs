@@ -17,18 +17,19 @@
  */
 
 use crate::http::jwt::jwt_manager::JwtManager;
+use crate::http::jwt::oidc::OidcTokenValidator;
 use crate::streaming::systems::system::SharedSystem;
 use std::net::SocketAddr;
-use ulid::Ulid;
 
 pub struct AppState {
     pub jwt_manager: JwtManager,
+    pub oidc_validator: Option<OidcTokenValidator>,
     pub system: SharedSystem,
 }
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Clone)]
 pub struct RequestDetails {
     #[allow(dead_code)]
-    pub request_id: Ulid,
+    pub request_id: String,
     pub ip_address: SocketAddr,
 }
@@ -22,17 +22,20 @@ use crate::http::mapper;
 use crate::http::shared::AppState;
 use crate::http::COMPONENT;
 use crate::streaming::session::Session;
-use axum::extract::{Path, State};
+use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
-use axum::routing::{delete, get};
+use axum::routing::{delete, get, put};
 use axum::{Extension, Json, Router};
 use error_set::ErrContext;
 use iggy::identifier::Identifier;
+use iggy::models::metadata::{MetadataFilter, MetadataFilterQuery};
 use iggy::models::stream::{Stream, StreamDetails};
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::delete_stream::DeleteStream;
 use iggy::streams::purge_stream::PurgeStream;
 use iggy::streams::update_stream::UpdateStream;
+use iggy::streams::update_stream_metadata::UpdateStreamMetadata;
+use iggy::streams::update_stream_quota::UpdateStreamQuota;
 use iggy::validatable::Validatable;
 
 use crate::state::command::EntryCommand;
@@ -48,6 +51,8 @@ pub fn router(state: Arc<AppState>) -> Router {
             get(get_stream).put(update_stream).delete(delete_stream),
         )
         .route("/streams/{stream_id}/purge", delete(purge_stream))
+        .route("/streams/{stream_id}/metadata", put(update_stream_metadata))
+        .route("/streams/{stream_id}/quota", put(update_stream_quota))
         .with_state(state)
 }
 
@@ -75,10 +80,16 @@ async fn get_stream(
 async fn get_streams(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
+    Query(query): Query<MetadataFilterQuery>,
 ) -> Result<Json<Vec<Stream>>, CustomError> {
+    let filter = MetadataFilter::try_from(query)?;
+    filter.validate()?;
     let system = state.system.read().await;
     let streams = system
-        .find_streams(&Session::stateless(identity.user_id, identity.ip_address))
+        .find_streams(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &filter,
+        )
         .with_error_context(|error| {
             format!(
                 "{COMPONENT} (error: {error}) - failed to find streams, user ID: {}",
@@ -103,6 +114,8 @@ async fn create_stream(
             &Session::stateless(identity.user_id, identity.ip_address),
             command.stream_id,
             &command.name,
+            command.metadata.clone(),
+            command.namespace_id,
         )
         .await
         .with_error_context(|error| {
@@ -112,6 +125,10 @@ async fn create_stream(
             )
         })?;
     let stream_id = stream.stream_id;
+    let command = CreateStream {
+        namespace_id: stream.namespace_id,
+        ..command
+    };
     let response = Json(mapper::map_stream(stream));
 
     let system = system.downgrade();
@@ -169,6 +186,84 @@ async fn update_stream(
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[instrument(skip_all, name = "trace_update_stream_metadata", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id))]
+async fn update_stream_metadata(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(stream_id): Path<String>,
+    Json(mut command): Json<UpdateStreamMetadata>,
+) -> Result<StatusCode, CustomError> {
+    command.stream_id = Identifier::from_str_value(&stream_id)?;
+    command.validate()?;
+
+    let mut system = state.system.write().await;
+    system
+        .update_stream_metadata(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &command.stream_id,
+            command.metadata.clone(),
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to update stream metadata, stream ID: {}",
+                stream_id
+            )
+        })?;
+
+    let system = system.downgrade();
+    system
+        .state
+        .apply(identity.user_id, EntryCommand::UpdateStreamMetadata(command))
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to apply update stream metadata, stream ID: {}",
+                stream_id
+            )
+        })?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[instrument(skip_all, name = "trace_update_stream_quota", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id))]
+async fn update_stream_quota(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(stream_id): Path<String>,
+    Json(mut command): Json<UpdateStreamQuota>,
+) -> Result<StatusCode, CustomError> {
+    command.stream_id = Identifier::from_str_value(&stream_id)?;
+    command.validate()?;
+
+    let mut system = state.system.write().await;
+    system
+        .update_stream_quota(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &command.stream_id,
+            command.quota,
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to update stream quota, stream ID: {}",
+                stream_id
+            )
+        })?;
+
+    let system = system.downgrade();
+    system
+        .state
+        .apply(identity.user_id, EntryCommand::UpdateStreamQuota(command))
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to apply update stream quota, stream ID: {}",
+                stream_id
+            )
+        })?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
 #[instrument(skip_all, name = "trace_delete_stream", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id))]
 async fn delete_stream(
     State(state): State<Arc<AppState>>,
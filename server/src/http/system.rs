@@ -23,19 +23,31 @@ use crate::http::mapper;
 use crate::http::shared::AppState;
 use crate::http::COMPONENT;
 use crate::streaming::session::Session;
+use crate::streaming::systems::health::SystemHealth;
+use crate::streaming::systems::integrity::IntegrityReport;
 use axum::body::Body;
-use axum::extract::{Path, State};
-use axum::http::{header, HeaderMap};
+use axum::extract::{Path, Query, State};
+use axum::http::{header, HeaderMap, StatusCode};
 use axum::response::IntoResponse;
 use axum::routing::{get, post};
 use axum::{Extension, Json, Router};
 use bytes::Bytes;
 use chrono::Local;
 use error_set::ErrContext;
+use iggy::error::IggyError;
 use iggy::locking::IggySharedMutFn;
+use iggy::models::audit_entry::AuditEntry;
+use iggy::models::backup::Backup;
 use iggy::models::client_info::{ClientInfo, ClientInfoDetails};
+use iggy::models::error_catalog::ErrorCatalogEntry;
+use iggy::models::maintenance_mode::MaintenanceMode;
+use iggy::models::server_info::ServerInfo;
 use iggy::models::stats::Stats;
+use iggy::models::unsaved_state::PartitionUnsavedState;
+use iggy::system::create_backup::CreateBackup;
+use iggy::system::get_audit_log::GetAuditLog;
 use iggy::system::get_snapshot::GetSnapshot;
+use iggy::system::set_maintenance_mode::SetMaintenanceMode;
 use iggy::validatable::Validatable;
 use std::sync::Arc;
 
@@ -47,9 +59,20 @@ pub fn router(state: Arc<AppState>, metrics_config: &HttpMetricsConfig) -> Route
         .route("/", get(|| async { NAME }))
         .route("/ping", get(|| async { PONG }))
         .route("/stats", get(get_stats))
+        .route("/info", get(get_server_info))
         .route("/clients", get(get_clients))
         .route("/clients/{client_id}", get(get_client))
-        .route("/snapshot", post(get_snapshot));
+        .route("/snapshot", post(get_snapshot))
+        .route("/integrity", get(get_integrity_report))
+        .route("/health", get(get_health))
+        .route(
+            "/maintenance",
+            get(get_maintenance_mode).put(set_maintenance_mode),
+        )
+        .route("/backups", post(create_backup))
+        .route("/unsaved-state", get(get_unsaved_state))
+        .route("/audit-log", get(get_audit_log))
+        .route("/error-catalog", get(get_error_catalog));
     if metrics_config.enabled {
         router = router.route(&metrics_config.endpoint, get(get_metrics));
     }
@@ -59,6 +82,7 @@ pub fn router(state: Arc<AppState>, metrics_config: &HttpMetricsConfig) -> Route
 
 async fn get_metrics(State(state): State<Arc<AppState>>) -> Result<String, CustomError> {
     let system = state.system.read().await;
+    system.refresh_namespace_metrics();
     Ok(system.metrics.get_formatted_output())
 }
 
@@ -70,6 +94,173 @@ async fn get_stats(State(state): State<Arc<AppState>>) -> Result<Json<Stats>, Cu
     Ok(Json(stats))
 }
 
+async fn get_server_info(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Json<ServerInfo>, CustomError> {
+    let system = state.system.read().await;
+    let server_info = system
+        .get_server_info(&Session::stateless(identity.user_id, identity.ip_address))
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to get server info, user ID: {}",
+                identity.user_id
+            )
+        })?;
+    Ok(Json(server_info))
+}
+
+async fn get_integrity_report(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Json<IntegrityReport>, CustomError> {
+    let system = state.system.read().await;
+    let report = system
+        .get_integrity_report(&Session::stateless(identity.user_id, identity.ip_address))
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to get integrity report, user ID: {}",
+                identity.user_id
+            )
+        })?;
+    let Some(report) = report else {
+        return Err(CustomError::ResourceNotFound);
+    };
+
+    Ok(Json(report.clone()))
+}
+
+async fn get_health(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Json<SystemHealth>, CustomError> {
+    let system = state.system.read().await;
+    let health = system
+        .get_health(&Session::stateless(identity.user_id, identity.ip_address))
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to get health, user ID: {}",
+                identity.user_id
+            )
+        })?;
+    Ok(Json(health))
+}
+
+async fn get_maintenance_mode(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Json<MaintenanceMode>, CustomError> {
+    let system = state.system.read().await;
+    let maintenance_mode = system
+        .get_maintenance_mode(&Session::stateless(identity.user_id, identity.ip_address))
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to get maintenance mode, user ID: {}",
+                identity.user_id
+            )
+        })?;
+    Ok(Json(maintenance_mode.clone()))
+}
+
+async fn set_maintenance_mode(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Json(command): Json<SetMaintenanceMode>,
+) -> Result<StatusCode, CustomError> {
+    command.validate()?;
+    let mut system = state.system.write().await;
+    system
+        .set_maintenance_mode(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            command.enabled,
+            command.reject_reads,
+            command.message,
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to set maintenance mode, user ID: {}",
+                identity.user_id
+            )
+        })?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn create_backup(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Json(command): Json<CreateBackup>,
+) -> Result<Json<Backup>, CustomError> {
+    command.validate()?;
+    let session = Session::stateless(identity.user_id, identity.ip_address);
+    let pending_backup = state
+        .system
+        .write()
+        .await
+        .start_backup(&session, &command.name)
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to start backup: {}, user ID: {}",
+                command.name, identity.user_id
+            )
+        })?;
+    let backup = pending_backup
+        .complete()
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to complete backup: {}, user ID: {}",
+                command.name, identity.user_id
+            )
+        })?;
+    Ok(Json(backup))
+}
+
+async fn get_unsaved_state(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Json<Vec<PartitionUnsavedState>>, CustomError> {
+    let system = state.system.read().await;
+    let unsaved_state = system
+        .get_unsaved_state(&Session::stateless(identity.user_id, identity.ip_address))
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to get unsaved state, user ID: {}",
+                identity.user_id
+            )
+        })?;
+    Ok(Json(unsaved_state))
+}
+
+async fn get_audit_log(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Query(query): Query<GetAuditLog>,
+) -> Result<Json<Vec<AuditEntry>>, CustomError> {
+    query.validate()?;
+    let system = state.system.read().await;
+    let entries = system
+        .get_audit_log(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            query.count,
+            query.user_id,
+            query.action,
+        )
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to get audit log, user ID: {}",
+                identity.user_id
+            )
+        })?;
+    Ok(Json(entries))
+}
+
+async fn get_error_catalog() -> Json<Vec<ErrorCatalogEntry>> {
+    Json(IggyError::catalog())
+}
+
 async fn get_client(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -22,19 +22,25 @@ use crate::http::mapper;
 use crate::http::shared::AppState;
 use crate::http::COMPONENT;
 use crate::state::command::EntryCommand;
-use crate::state::models::CreateTopicWithId;
+use crate::state::models::{CreateTopicWithId, MarkTopicForDeletionWithDeadline};
 use crate::streaming::session::Session;
-use axum::extract::{Path, State};
+use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
-use axum::routing::{delete, get};
+use axum::routing::{delete, get, put};
 use axum::{Extension, Json, Router};
 use error_set::ErrContext;
 use iggy::identifier::Identifier;
+use iggy::models::metadata::{MetadataFilter, MetadataFilterQuery};
 use iggy::models::topic::{Topic, TopicDetails};
 use iggy::topics::create_topic::CreateTopic;
 use iggy::topics::delete_topic::DeleteTopic;
+use iggy::topics::mark_topic_for_deletion::MarkTopicForDeletion;
 use iggy::topics::purge_topic::PurgeTopic;
 use iggy::topics::update_topic::UpdateTopic;
+use iggy::topics::update_topic_config::UpdateTopicConfig;
+use iggy::topics::update_topic_expiry_watcher::UpdateTopicExpiryWatcher;
+use iggy::topics::update_topic_metadata::UpdateTopicMetadata;
+use iggy::topics::update_topic_producers::UpdateTopicProducers;
 use iggy::validatable::Validatable;
 use std::sync::Arc;
 use tracing::instrument;
@@ -53,6 +59,26 @@ pub fn router(state: Arc<AppState>) -> Router {
             "/streams/{stream_id}/topics/{topic_id}/purge",
             delete(purge_topic),
         )
+        .route(
+            "/streams/{stream_id}/topics/{topic_id}/metadata",
+            put(update_topic_metadata),
+        )
+        .route(
+            "/streams/{stream_id}/topics/{topic_id}/producers",
+            put(update_topic_producers),
+        )
+        .route(
+            "/streams/{stream_id}/topics/{topic_id}/config",
+            put(update_topic_config),
+        )
+        .route(
+            "/streams/{stream_id}/topics/{topic_id}/expiry-watchers",
+            put(update_topic_expiry_watcher),
+        )
+        .route(
+            "/streams/{stream_id}/topics/{topic_id}/deletion",
+            put(mark_topic_for_deletion),
+        )
         .with_state(state)
 }
 
@@ -83,13 +109,17 @@ async fn get_topics(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
     Path(stream_id): Path<String>,
+    Query(query): Query<MetadataFilterQuery>,
 ) -> Result<Json<Vec<Topic>>, CustomError> {
     let stream_id = Identifier::from_str_value(&stream_id)?;
+    let filter = MetadataFilter::try_from(query)?;
+    filter.validate()?;
     let system = state.system.read().await;
     let topics = system
         .find_topics(
             &Session::stateless(identity.user_id, identity.ip_address),
             &stream_id,
+            &filter,
         )
         .with_error_context(|error| {
             format!(
@@ -123,6 +153,9 @@ async fn create_topic(
             command.compression_algorithm,
             command.max_topic_size,
             command.replication_factor,
+            command.metadata.clone(),
+            command.message_id_scheme,
+            command.cleanup_policy,
         )
         .await
         .with_error_context(|error| {
@@ -133,6 +166,8 @@ async fn create_topic(
         })?;
     command.message_expiry = topic.message_expiry;
     command.max_topic_size = topic.max_topic_size;
+    command.message_id_scheme = topic.message_id_scheme;
+    command.cleanup_policy = topic.cleanup_policy;
     let topic_id = topic.topic_id;
     let response = Json(mapper::map_topic(topic).await);
 
@@ -174,6 +209,7 @@ async fn update_topic(
                 command.compression_algorithm,
                 command.max_topic_size,
                 command.replication_factor,
+                command.max_segments,
             )
             .await
             .with_error_context(|error| {
@@ -199,6 +235,223 @@ async fn update_topic(
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[instrument(skip_all, name = "trace_update_topic_metadata", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id))]
+async fn update_topic_metadata(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    Json(mut command): Json<UpdateTopicMetadata>,
+) -> Result<StatusCode, CustomError> {
+    command.stream_id = Identifier::from_str_value(&stream_id)?;
+    command.topic_id = Identifier::from_str_value(&topic_id)?;
+    command.validate()?;
+
+    let mut system = state.system.write().await;
+    system
+        .update_topic_metadata(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &command.stream_id,
+            &command.topic_id,
+            command.metadata.clone(),
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to update topic metadata, stream ID: {}, topic ID: {}",
+                stream_id, topic_id
+            )
+        })?;
+
+    let system = system.downgrade();
+    system
+        .state
+        .apply(identity.user_id, EntryCommand::UpdateTopicMetadata(command))
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to apply update topic metadata, stream ID: {}, topic ID: {}",
+                stream_id, topic_id
+            )
+        })?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[instrument(skip_all, name = "trace_update_topic_producers", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id))]
+async fn update_topic_producers(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    Json(mut command): Json<UpdateTopicProducers>,
+) -> Result<StatusCode, CustomError> {
+    command.stream_id = Identifier::from_str_value(&stream_id)?;
+    command.topic_id = Identifier::from_str_value(&topic_id)?;
+    command.validate()?;
+
+    let mut system = state.system.write().await;
+    system
+        .update_topic_producers(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &command.stream_id,
+            &command.topic_id,
+            command.allowed_producers.clone(),
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to update topic producers, stream ID: {}, topic ID: {}",
+                stream_id, topic_id
+            )
+        })?;
+
+    let system = system.downgrade();
+    system
+        .state
+        .apply(identity.user_id, EntryCommand::UpdateTopicProducers(command))
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to apply update topic producers, stream ID: {}, topic ID: {}",
+                stream_id, topic_id
+            )
+        })?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[instrument(skip_all, name = "trace_mark_topic_for_deletion", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id))]
+async fn mark_topic_for_deletion(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    Json(mut command): Json<MarkTopicForDeletion>,
+) -> Result<StatusCode, CustomError> {
+    command.stream_id = Identifier::from_str_value(&stream_id)?;
+    command.topic_id = Identifier::from_str_value(&topic_id)?;
+    command.validate()?;
+
+    let mut system = state.system.write().await;
+    let delete_at = system.get_topic_delete_at(command.drain_period);
+    system
+        .mark_topic_for_deletion(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &command.stream_id,
+            &command.topic_id,
+            command.drain_period,
+            delete_at,
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to mark topic for deletion, stream ID: {}, topic ID: {}",
+                stream_id, topic_id
+            )
+        })?;
+
+    let system = system.downgrade();
+    system
+        .state
+        .apply(
+            identity.user_id,
+            EntryCommand::MarkTopicForDeletion(MarkTopicForDeletionWithDeadline {
+                delete_at,
+                command,
+            }),
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to apply mark topic for deletion, stream ID: {}, topic ID: {}",
+                stream_id, topic_id
+            )
+        })?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[instrument(skip_all, name = "trace_update_topic_config", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id))]
+async fn update_topic_config(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    Json(mut command): Json<UpdateTopicConfig>,
+) -> Result<StatusCode, CustomError> {
+    command.stream_id = Identifier::from_str_value(&stream_id)?;
+    command.topic_id = Identifier::from_str_value(&topic_id)?;
+    command.validate()?;
+
+    let mut system = state.system.write().await;
+    system
+        .update_topic_config(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &command.stream_id,
+            &command.topic_id,
+            command.config,
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to update topic config, stream ID: {}, topic ID: {}",
+                stream_id, topic_id
+            )
+        })?;
+
+    let system = system.downgrade();
+    system
+        .state
+        .apply(identity.user_id, EntryCommand::UpdateTopicConfig(command))
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to apply update topic config, stream ID: {}, topic ID: {}",
+                stream_id, topic_id
+            )
+        })?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[instrument(skip_all, name = "trace_update_topic_expiry_watcher", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id))]
+async fn update_topic_expiry_watcher(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    Json(mut command): Json<UpdateTopicExpiryWatcher>,
+) -> Result<StatusCode, CustomError> {
+    command.stream_id = Identifier::from_str_value(&stream_id)?;
+    command.topic_id = Identifier::from_str_value(&topic_id)?;
+    command.validate()?;
+
+    let mut system = state.system.write().await;
+    system
+        .update_topic_expiry_watcher(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &command.stream_id,
+            &command.topic_id,
+            &command.consumer(),
+            command.enabled,
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to update topic expiry watcher, stream ID: {}, topic ID: {}",
+                stream_id, topic_id
+            )
+        })?;
+
+    let system = system.downgrade();
+    system
+        .state
+        .apply(
+            identity.user_id,
+            EntryCommand::UpdateTopicExpiryWatcher(command),
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to apply update topic expiry watcher, stream ID: {}, topic ID: {}",
+                stream_id, topic_id
+            )
+        })?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
 #[instrument(skip_all, name = "trace_delete_topic", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id))]
 async fn delete_topic(
     State(state): State<Arc<AppState>>,
@@ -247,15 +500,20 @@ async fn purge_topic(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
     Path((stream_id, topic_id)): Path<(String, String)>,
+    mut query: Query<PurgeTopic>,
 ) -> Result<StatusCode, CustomError> {
-    let identifier_stream_id = Identifier::from_str_value(&stream_id)?;
-    let identifier_topic_id = Identifier::from_str_value(&topic_id)?;
+    query.stream_id = Identifier::from_str_value(&stream_id)?;
+    query.topic_id = Identifier::from_str_value(&topic_id)?;
+    query.validate()?;
+
     let system = state.system.read().await;
     system
         .purge_topic(
             &Session::stateless(identity.user_id, identity.ip_address),
-            &identifier_stream_id,
-            &identifier_topic_id,
+            &query.stream_id,
+            &query.topic_id,
+            query.keep_consumer_offsets,
+            query.older_than,
         )
         .await
         .with_error_context(|error| {
@@ -269,8 +527,10 @@ async fn purge_topic(
         .apply(
             identity.user_id,
             EntryCommand::PurgeTopic(PurgeTopic {
-                stream_id: identifier_stream_id,
-                topic_id: identifier_topic_id,
+                stream_id: query.stream_id.clone(),
+                topic_id: query.topic_id.clone(),
+                keep_consumer_offsets: query.keep_consumer_offsets,
+                older_than: query.older_than,
             }),
         )
         .await
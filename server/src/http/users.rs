@@ -40,6 +40,7 @@ use iggy::users::delete_user::DeleteUser;
 use iggy::users::login_user::LoginUser;
 use iggy::users::update_permissions::UpdatePermissions;
 use iggy::users::update_user::UpdateUser;
+use iggy::users::update_user_quotas::UpdateUserQuotas;
 use iggy::validatable::Validatable;
 use serde::Deserialize;
 use std::sync::Arc;
@@ -53,6 +54,7 @@ pub fn router(state: Arc<AppState>) -> Router {
             get(get_user).put(update_user).delete(delete_user),
         )
         .route("/users/{user_id}/permissions", put(update_permissions))
+        .route("/users/{user_id}/quotas", put(update_user_quotas))
         .route("/users/{user_id}/password", put(change_password))
         .route("/users/login", post(login_user))
         .route("/users/logout", delete(logout_user))
@@ -115,6 +117,7 @@ async fn create_user(
             &command.password,
             command.status,
             command.permissions.clone(),
+            command.namespace_id,
         )
         .await
         .with_error_context(|error| {
@@ -124,6 +127,7 @@ async fn create_user(
             )
         })?;
     let user_id = user.id;
+    let namespace_id = user.namespace_id;
     let response = Json(mapper::map_user(user));
 
     // For the security of the system, we hash the password before storing it in metadata.
@@ -139,6 +143,7 @@ async fn create_user(
                     password: crypto::hash_password(&command.password),
                     status: command.status,
                     permissions: command.permissions.clone(),
+                    namespace_id,
                 },
             }),
         )
@@ -232,6 +237,41 @@ async fn update_permissions(
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[instrument(skip_all, name = "trace_update_user_quotas", fields(iggy_user_id = identity.user_id, iggy_updated_user_id = user_id))]
+async fn update_user_quotas(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(user_id): Path<String>,
+    Json(mut command): Json<UpdateUserQuotas>,
+) -> Result<StatusCode, CustomError> {
+    command.user_id = Identifier::from_str_value(&user_id)?;
+    command.validate()?;
+
+    let mut system = state.system.write().await;
+    system
+        .update_user_quotas(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &command.user_id,
+            command.quotas,
+        )
+        .await
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to update quotas, user ID: {user_id}")
+        })?;
+
+    let system = system.downgrade();
+    system
+        .state
+        .apply(identity.user_id, EntryCommand::UpdateUserQuotas(command))
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to apply update quotas, user ID: {user_id}"
+            )
+        })?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
 #[instrument(skip_all, name = "trace_change_password", fields(iggy_user_id = identity.user_id, iggy_updated_user_id = user_id))]
 async fn change_password(
     State(state): State<Arc<AppState>>,
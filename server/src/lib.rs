@@ -28,23 +28,33 @@ compile_error!("iggy-server doesn't support windows.");
 
 pub mod archiver;
 pub mod args;
+pub mod authenticator;
 pub mod binary;
 pub mod channels;
+pub mod cluster;
 mod command;
 pub(crate) mod compat;
 pub mod configs;
+#[cfg(feature = "grpc")]
+pub mod grpc;
 pub mod http;
 pub mod log;
+pub mod mqtt;
+pub mod preflight;
 pub mod quic;
 pub mod server_error;
 pub mod state;
 pub mod streaming;
 pub mod tcp;
+pub mod uds;
 pub mod versioning;
+#[cfg(feature = "websocket")]
+pub mod websocket;
 
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 const IGGY_ROOT_USERNAME_ENV: &str = "IGGY_ROOT_USERNAME";
 const IGGY_ROOT_PASSWORD_ENV: &str = "IGGY_ROOT_PASSWORD";
+const AUDIT_LOG_TARGET: &str = "iggy_audit";
 
 pub(crate) fn map_toggle_str<'a>(enabled: bool) -> &'a str {
     match enabled {
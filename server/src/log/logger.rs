@@ -16,7 +16,7 @@
  * under the License.
  */
 
-use crate::configs::server::{TelemetryConfig, TelemetryTransport};
+use crate::configs::server::{parse_resource_attribute, TelemetryConfig, TelemetryTransport};
 use crate::configs::system::LoggingConfig;
 use crate::server_error::LogError;
 use crate::VERSION;
@@ -26,6 +26,7 @@ use opentelemetry::KeyValue;
 use opentelemetry_appender_tracing::layer::OpenTelemetryTracingBridge;
 use opentelemetry_otlp::{WithExportConfig, WithHttpConfig};
 use opentelemetry_sdk::logs::log_processor_with_async_runtime;
+use opentelemetry_sdk::metrics::{periodic_reader_with_async_runtime, PeriodicReader};
 use opentelemetry_sdk::propagation::TraceContextPropagator;
 use opentelemetry_sdk::runtime;
 use opentelemetry_sdk::trace::span_processor_with_async_runtime;
@@ -185,12 +186,20 @@ impl Logging {
         }
 
         let service_name = self.telemetry_config.service_name.to_owned();
+        let resource_attributes = self
+            .telemetry_config
+            .resource_attributes
+            .iter()
+            .filter_map(|attribute| parse_resource_attribute(attribute).ok())
+            .map(|(key, value)| KeyValue::new(key.to_owned(), value.to_owned()))
+            .collect::<Vec<_>>();
         let resource = Resource::builder()
             .with_service_name(service_name.to_owned())
             .with_attribute(KeyValue::new(
                 opentelemetry_semantic_conventions::resource::SERVICE_VERSION,
                 VERSION,
             ))
+            .with_attributes(resource_attributes)
             .build();
 
         let logger_provider = match self.telemetry_config.logs.transport {
@@ -261,6 +270,50 @@ impl Logging {
         global::set_tracer_provider(tracer_provider.clone());
         global::set_text_map_propagator(TraceContextPropagator::new());
 
+        // The instruments of the server are created from the global meter provider,
+        // which stays a no-op one unless the metrics are enabled.
+        let metrics_config = &self.telemetry_config.metrics;
+        if metrics_config.enabled {
+            let interval = metrics_config.interval.get_duration();
+            let meter_provider = match metrics_config.transport {
+                TelemetryTransport::GRPC => opentelemetry_sdk::metrics::SdkMeterProvider::builder()
+                    .with_resource(resource.clone())
+                    .with_reader(
+                        PeriodicReader::builder(
+                            opentelemetry_otlp::MetricExporter::builder()
+                                .with_tonic()
+                                .with_endpoint(metrics_config.endpoint.clone())
+                                .build()
+                                .expect("Failed to initialize gRPC meter."),
+                        )
+                        .with_interval(interval)
+                        .build(),
+                    )
+                    .build(),
+                TelemetryTransport::HTTP => {
+                    let metric_exporter = opentelemetry_otlp::MetricExporter::builder()
+                        .with_http()
+                        .with_http_client(reqwest::Client::new())
+                        .with_endpoint(metrics_config.endpoint.clone())
+                        .with_protocol(opentelemetry_otlp::Protocol::HttpBinary)
+                        .build()
+                        .expect("Failed to initialize HTTP meter.");
+                    opentelemetry_sdk::metrics::SdkMeterProvider::builder()
+                        .with_resource(resource.clone())
+                        .with_reader(
+                            periodic_reader_with_async_runtime::PeriodicReader::builder(
+                                metric_exporter,
+                                runtime::Tokio,
+                            )
+                            .with_interval(interval)
+                            .build(),
+                        )
+                        .build()
+                }
+            };
+            global::set_meter_provider(meter_provider);
+        }
+
         Registry::default()
             .with(layers)
             .with(OpenTelemetryTracingBridge::new(&logger_provider))
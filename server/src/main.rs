@@ -20,27 +20,46 @@ use anyhow::Result;
 use clap::Parser;
 use dotenvy::dotenv;
 use figlet_rs::FIGfont;
-use server::args::Args;
+use server::args::{Args, Command};
+use server::channels::commands::abort_expired_transactions::AbortExpiredTransactionsExecutor;
 use server::channels::commands::archive_state::ArchiveStateExecutor;
 use server::channels::commands::clean_personal_access_tokens::CleanPersonalAccessTokensExecutor;
+use server::channels::commands::compact_topics::CompactTopicsExecutor;
+use server::channels::commands::delete_drained_topics::DeleteDrainedTopicsExecutor;
 use server::channels::commands::maintain_messages::MaintainMessagesExecutor;
 use server::channels::commands::print_sysinfo::SysInfoPrintExecutor;
+use server::channels::commands::save_cache_heat_map::SaveCacheHeatMapExecutor;
 use server::channels::commands::save_messages::SaveMessagesExecutor;
+use server::channels::commands::snapshot_topics::SnapshotTopicsExecutor;
 use server::channels::commands::verify_heartbeats::VerifyHeartbeatsExecutor;
 use server::channels::handler::ServerCommandHandler;
 use server::configs::config_provider;
 use server::configs::server::ServerConfig;
+#[cfg(feature = "grpc")]
+use server::grpc::grpc_server;
 use server::http::http_server;
 #[cfg(not(feature = "tokio-console"))]
 use server::log::logger::Logging;
 #[cfg(feature = "tokio-console")]
 use server::log::tokio_console::Logging;
+#[cfg(feature = "mqtt")]
+use server::mqtt::mqtt_bridge;
+use server::preflight;
 use server::quic::quic_server;
 use server::server_error::ServerError;
+use server::streaming::push::pusher;
+use server::streaming::segments::verification;
+use server::streaming::systems::audit_log;
+use server::streaming::systems::message_audit;
+use server::streaming::systems::metadata_changes;
 use server::streaming::systems::system::{SharedSystem, System};
 use server::tcp::tcp_server;
+use server::uds::uds_server;
+#[cfg(feature = "websocket")]
+use server::websocket::websocket_server;
+use std::sync::Arc;
 use tokio::time::Instant;
-use tracing::{info, instrument};
+use tracing::{error, info, instrument, warn};
 
 #[tokio::main]
 #[instrument(skip_all, name = "trace_start_server")]
@@ -63,7 +82,13 @@ async fn main() -> Result<(), ServerError> {
 
     let args = Args::parse();
     let config_provider = config_provider::resolve(&args.config_provider)?;
-    let config = ServerConfig::load(&config_provider).await?;
+    let mut config = ServerConfig::load(&config_provider).await?;
+    if args.recovery {
+        Arc::get_mut(&mut config.system)
+            .expect("System config should not be shared at this point")
+            .recovery
+            .read_only = true;
+    }
     if args.fresh {
         let system_path = config.system.get_system_path();
         if tokio::fs::metadata(&system_path).await.is_ok() {
@@ -90,6 +115,22 @@ async fn main() -> Result<(), ServerError> {
     #[cfg(not(feature = "disable-mimalloc"))]
     info!("Using mimalloc allocator");
 
+    if let Some(Command::Verify { repair }) = args.command {
+        let system_config = config.system.clone();
+        let report = tokio::task::spawn_blocking(move || {
+            verification::verify_segments(&system_config, repair)
+        })
+        .await
+        .expect("Segments verification task should not panic")?;
+        for corrupted_batch in &report.corrupted_batches {
+            error!("Found {corrupted_batch}");
+        }
+        info!("Verified segments: {report}");
+        return Ok(());
+    }
+
+    preflight::run(&config).await?;
+
     let system = SharedSystem::new(System::new(
         config.system.clone(),
         config.data_maintenance.clone(),
@@ -101,12 +142,37 @@ async fn main() -> Result<(), ServerError> {
     // have the correct statistics when the server starts.
     system.write().await.get_stats().await?;
     system.write().await.init().await?;
+    let cluster = system.read().await.get_cluster().cloned();
+    if let Some(cluster) = cluster {
+        cluster.start(system.clone()).await?;
+    }
 
-    let _command_handler = ServerCommandHandler::new(system.clone(), &config)
-        .install_handler(SaveMessagesExecutor)
-        .install_handler(MaintainMessagesExecutor)
-        .install_handler(ArchiveStateExecutor)
-        .install_handler(CleanPersonalAccessTokensExecutor)
+    let mut command_handler = ServerCommandHandler::new(system.clone(), &config);
+    if config.system.recovery.read_only {
+        warn!("Iggy server is running in read-only recovery mode, all the data modifications are disabled.");
+    } else {
+        command_handler = command_handler
+            .install_handler(SaveMessagesExecutor)
+            .install_handler(MaintainMessagesExecutor::default())
+            .install_handler(ArchiveStateExecutor)
+            .install_handler(SnapshotTopicsExecutor)
+            .install_handler(CompactTopicsExecutor)
+            .install_handler(CleanPersonalAccessTokensExecutor::default())
+            .install_handler(AbortExpiredTransactionsExecutor)
+            .install_handler(DeleteDrainedTopicsExecutor)
+            .install_handler(SaveCacheHeatMapExecutor);
+        metadata_changes::start_publisher(system.clone());
+        message_audit::start_publisher(system.clone());
+        audit_log::start_writer(system.clone());
+        pusher::start_all(system.clone()).await;
+        if config.mqtt.enabled {
+            #[cfg(feature = "mqtt")]
+            mqtt_bridge::start(config.mqtt.clone(), system.clone());
+            #[cfg(not(feature = "mqtt"))]
+            warn!("MQTT bridge is enabled, but the server was built without the 'mqtt' feature.");
+        }
+    }
+    let _command_handler = command_handler
         .install_handler(SysInfoPrintExecutor)
         .install_handler(VerifyHeartbeatsExecutor);
 
@@ -136,6 +202,31 @@ async fn main() -> Result<(), ServerError> {
         current_config.tcp.address = tcp_addr.to_string();
     }
 
+    if config.uds.enabled {
+        let uds_path = uds_server::start(config.uds, system.clone()).await;
+        current_config.uds.path = uds_path;
+    }
+
+    if config.websocket.enabled {
+        #[cfg(feature = "websocket")]
+        {
+            let websocket_addr = websocket_server::start(config.websocket, system.clone()).await;
+            current_config.websocket.address = websocket_addr.to_string();
+        }
+        #[cfg(not(feature = "websocket"))]
+        warn!("WebSocket server is enabled, but the server was built without the 'websocket' feature.");
+    }
+
+    if config.grpc.enabled {
+        #[cfg(feature = "grpc")]
+        {
+            let grpc_addr = grpc_server::start(config.grpc, system.clone()).await;
+            current_config.grpc.address = grpc_addr.to_string();
+        }
+        #[cfg(not(feature = "grpc"))]
+        warn!("gRPC server is enabled, but the server was built without the 'grpc' feature.");
+    }
+
     let runtime_path = current_config.system.get_runtime_path();
     let current_config_path = format!("{}/current_config.toml", runtime_path);
     let current_config_content =
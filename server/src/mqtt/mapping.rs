@@ -0,0 +1,127 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+
+/// Mapping of the MQTT topic filter to the iggy topic, to which the matching messages are appended.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TopicMapping {
+    pub filter: String,
+    pub stream_id: Identifier,
+    pub topic_id: Identifier,
+}
+
+/// Parses the mapping in the "<MQTT topic filter>=<stream>/<topic>" format,
+/// where the stream and the topic are either numeric IDs or names.
+pub fn parse_topic_mapping(mapping: &str) -> Result<TopicMapping, IggyError> {
+    let Some((filter, target)) = mapping.rsplit_once('=') else {
+        return Err(IggyError::InvalidFormat);
+    };
+    let Some((stream_id, topic_id)) = target.split_once('/') else {
+        return Err(IggyError::InvalidFormat);
+    };
+
+    let filter = filter.trim();
+    if !is_valid_filter(filter) {
+        return Err(IggyError::InvalidFormat);
+    }
+
+    Ok(TopicMapping {
+        filter: filter.to_owned(),
+        stream_id: Identifier::from_str_value(stream_id.trim())?,
+        topic_id: Identifier::from_str_value(topic_id.trim())?,
+    })
+}
+
+/// Checks whether the filter is valid, i.e. the multi-level wildcard `#` is used only as the last level,
+/// and the wildcards don't share the level with the other characters.
+pub fn is_valid_filter(filter: &str) -> bool {
+    if filter.is_empty() {
+        return false;
+    }
+
+    let levels = filter.split('/').collect::<Vec<_>>();
+    let last_index = levels.len() - 1;
+    levels.iter().enumerate().all(|(index, level)| {
+        if level.contains('#') {
+            return *level == "#" && index == last_index;
+        }
+        !level.contains('+') || *level == "+"
+    })
+}
+
+/// Checks whether the topic matches the filter, as defined by the MQTT specification.
+/// The topics starting with `$` are matched only by the filters starting with the same level.
+pub fn topic_matches(filter: &str, topic: &str) -> bool {
+    if topic.starts_with('$') && !filter.starts_with('$') {
+        return false;
+    }
+
+    let mut filter_levels = filter.split('/');
+    let mut topic_levels = topic.split('/');
+    loop {
+        match (filter_levels.next(), topic_levels.next()) {
+            (Some("#"), _) => return true,
+            (Some(filter_level), Some(topic_level)) => {
+                if filter_level != "+" && filter_level != topic_level {
+                    return false;
+                }
+            }
+            (None, None) => return true,
+            _ => return false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mapping_should_be_parsed() {
+        let mapping = parse_topic_mapping("sensors/+/temperature=iot/1").unwrap();
+        assert_eq!(mapping.filter, "sensors/+/temperature");
+        assert_eq!(mapping.stream_id, Identifier::named("iot").unwrap());
+        assert_eq!(mapping.topic_id, Identifier::numeric(1).unwrap());
+
+        assert!(parse_topic_mapping("sensors/#").is_err());
+        assert!(parse_topic_mapping("sensors/#=iot").is_err());
+        assert!(parse_topic_mapping("sensors/#/temperature=iot/1").is_err());
+        assert!(parse_topic_mapping("sensors/a+=iot/1").is_err());
+    }
+
+    #[test]
+    fn topic_should_match_filter_with_wildcards() {
+        assert!(topic_matches(
+            "sensors/+/temperature",
+            "sensors/1/temperature"
+        ));
+        assert!(topic_matches("sensors/#", "sensors"));
+        assert!(topic_matches("sensors/#", "sensors/1/humidity"));
+        assert!(topic_matches("#", "sensors/1"));
+        assert!(topic_matches("+/+", "/sensors"));
+        assert!(!topic_matches("sensors/+", "sensors/1/temperature"));
+        assert!(!topic_matches(
+            "sensors/+/temperature",
+            "sensors/1/humidity"
+        ));
+        assert!(!topic_matches("#", "$SYS/uptime"));
+        assert!(topic_matches("$SYS/#", "$SYS/uptime"));
+    }
+}
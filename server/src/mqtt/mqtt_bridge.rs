@@ -0,0 +1,209 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::configs::mqtt::MqttBridgeConfig;
+use crate::mqtt::mapping::{parse_topic_mapping, topic_matches, TopicMapping};
+use crate::mqtt::COMPONENT;
+use crate::streaming::systems::system::SharedSystem;
+use ahash::AHashMap;
+use error_set::ErrContext;
+use flume::Receiver;
+use iggy::error::IggyError;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::models::header::{HeaderKey, HeaderValue};
+use rumqttc::{AsyncClient, Event, MqttOptions, Packet, Publish, QoS};
+use std::collections::HashMap;
+use std::str::FromStr;
+use tokio::time::sleep;
+use tracing::{error, info, trace, warn};
+
+pub const MQTT_TOPIC_HEADER: &str = "mqtt-topic";
+pub const MQTT_QOS_HEADER: &str = "mqtt-qos";
+pub const MQTT_RETAIN_HEADER: &str = "mqtt-retain";
+
+/// Starts the bridge subscribing to the mapped topic filters at the MQTT broker and the task appending
+/// the received messages to the iggy topics. The messages are acknowledged to the broker only once appended.
+pub fn start(config: MqttBridgeConfig, system: SharedSystem) {
+    let mappings = config
+        .mappings
+        .iter()
+        .filter_map(|mapping| parse_topic_mapping(mapping).ok())
+        .collect::<Vec<_>>();
+    let Some((host, port)) = config
+        .address
+        .rsplit_once(':')
+        .and_then(|(host, port)| port.parse::<u16>().ok().map(|port| (host.to_owned(), port)))
+    else {
+        error!("Invalid MQTT broker address: {}", config.address);
+        return;
+    };
+    let qos = match config.qos {
+        0 => QoS::AtMostOnce,
+        1 => QoS::AtLeastOnce,
+        _ => QoS::ExactlyOnce,
+    };
+    let max_batch_size = config.max_batch_size as usize;
+
+    let mut options = MqttOptions::new(&config.client_id, host.clone(), port);
+    options
+        .set_keep_alive(config.keep_alive.get_duration())
+        .set_clean_session(false)
+        .set_manual_acks(true);
+    if !config.username.is_empty() {
+        options.set_credentials(&config.username, &config.password);
+    }
+
+    let (client, mut event_loop) = AsyncClient::new(options, max_batch_size);
+    let (sender, receiver) = flume::bounded(max_batch_size);
+    tokio::spawn(append_messages(
+        system,
+        client.clone(),
+        mappings.clone(),
+        receiver,
+    ));
+
+    let reconnect_interval = config.reconnect_interval;
+    tokio::spawn(async move {
+        loop {
+            match event_loop.poll().await {
+                Ok(Event::Incoming(Packet::ConnAck(_))) => {
+                    info!("Connected to MQTT broker at: {host}:{port}, subscribing to {} topic filter(s).", mappings.len());
+                    for mapping in &mappings {
+                        if let Err(error) = client.try_subscribe(&mapping.filter, qos) {
+                            error!("{COMPONENT} (error: {error}) - failed to subscribe to MQTT topic filter: {}", mapping.filter);
+                        }
+                    }
+                }
+                Ok(Event::Incoming(Packet::Publish(publish))) => {
+                    if sender.send_async(publish).await.is_err() {
+                        warn!("MQTT bridge stopped appending the messages.");
+                        return;
+                    }
+                }
+                Ok(_) => {}
+                Err(error) => {
+                    warn!("MQTT broker connection error: {error}, reconnecting in {reconnect_interval}...");
+                    sleep(reconnect_interval.get_duration()).await;
+                }
+            }
+        }
+    });
+    info!("MQTT bridge is enabled, broker address: {}", config.address);
+}
+
+async fn append_messages(
+    system: SharedSystem,
+    client: AsyncClient,
+    mappings: Vec<TopicMapping>,
+    receiver: Receiver<Publish>,
+) {
+    while let Ok(publish) = receiver.recv_async().await {
+        let mut batch = vec![publish];
+        batch.extend(receiver.drain());
+
+        // Grouped by the MQTT topic, so that the messages of each device land in the same partition.
+        let mut groups: AHashMap<(usize, String), Vec<Publish>> = AHashMap::new();
+        for publish in batch {
+            let Some(mapping_index) = mappings
+                .iter()
+                .position(|mapping| topic_matches(&mapping.filter, &publish.topic))
+            else {
+                trace!(
+                    "No mapping found for MQTT topic: {}, skipping the message.",
+                    publish.topic
+                );
+                ack(&client, &publish).await;
+                continue;
+            };
+            groups
+                .entry((mapping_index, publish.topic.clone()))
+                .or_default()
+                .push(publish);
+        }
+
+        for ((mapping_index, mqtt_topic), publishes) in groups {
+            let mapping = &mappings[mapping_index];
+            let messages_count = publishes.len();
+            match append_group(&system, mapping, &mqtt_topic, &publishes).await {
+                Ok(()) => {
+                    trace!("Appended {messages_count} message(s) from MQTT topic: {mqtt_topic} to topic: {} in stream: {}.", mapping.topic_id, mapping.stream_id);
+                    for publish in &publishes {
+                        ack(&client, publish).await;
+                    }
+                }
+                Err(error) => {
+                    error!("{COMPONENT} (error: {error}) - failed to append {messages_count} message(s) from MQTT topic: {mqtt_topic} to topic: {} in stream: {}", mapping.topic_id, mapping.stream_id);
+                }
+            }
+        }
+    }
+}
+
+async fn append_group(
+    system: &SharedSystem,
+    mapping: &TopicMapping,
+    mqtt_topic: &str,
+    publishes: &[Publish],
+) -> Result<(), IggyError> {
+    let messages = publishes
+        .iter()
+        .map(map_message)
+        .collect::<Result<Vec<_>, _>>()?;
+    let partitioning =
+        Partitioning::messages_key_str(mqtt_topic).unwrap_or_else(|_| Partitioning::balanced());
+    let system = system.read().await;
+    let topic = system
+        .get_stream_in_namespace(None, &mapping.stream_id)?
+        .get_topic(&mapping.topic_id)
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - topic: {} in stream: {} mapped from MQTT topic filter: {} not found",
+                mapping.topic_id, mapping.stream_id, mapping.filter
+            )
+        })?;
+    system
+        .append_messages_to_topic(topic, partitioning, messages, None)
+        .await
+}
+
+fn map_message(publish: &Publish) -> Result<Message, IggyError> {
+    let headers = HashMap::from([
+        (
+            HeaderKey::new(MQTT_TOPIC_HEADER)?,
+            HeaderValue::from_str(&publish.topic)?,
+        ),
+        (
+            HeaderKey::new(MQTT_QOS_HEADER)?,
+            HeaderValue::from_uint8(publish.qos as u8)?,
+        ),
+        (
+            HeaderKey::new(MQTT_RETAIN_HEADER)?,
+            HeaderValue::from_bool(publish.retain)?,
+        ),
+    ]);
+    Ok(Message::new(None, publish.payload.clone(), Some(headers)))
+}
+
+async fn ack(client: &AsyncClient, publish: &Publish) {
+    if let Err(error) = client.ack(publish).await {
+        warn!(
+            "{COMPONENT} (error: {error}) - failed to acknowledge MQTT message from topic: {}",
+            publish.topic
+        );
+    }
+}
@@ -0,0 +1,377 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::archiver::{ArchiverKind, ArchiverKindType};
+use crate::configs::server::ServerConfig;
+use crate::configs::system::SystemConfig;
+use crate::server_error::PreflightError;
+use crate::streaming::segments::LOG_EXTENSION;
+use nix::sys::resource::{getrlimit, setrlimit, Resource};
+use nix::sys::statvfs::statvfs;
+use std::fmt::Display;
+use std::io::{ErrorKind, Write};
+use std::net::{SocketAddr, TcpListener, ToSocketAddrs, UdpSocket};
+use std::path::Path;
+use std::time::SystemTime;
+use tracing::{error, info, warn};
+
+pub const COMPONENT: &str = "PREFLIGHT";
+
+/// Each loaded segment keeps its log and index files open, for both writing and reading.
+const FILE_DESCRIPTORS_PER_SEGMENT: u64 = 4;
+const PROBE_FILE_NAME: &str = ".preflight";
+
+/// The failed preflight check along with the hint on how to fix the environment.
+#[derive(Debug)]
+struct PreflightFailure {
+    check: &'static str,
+    reason: String,
+    remediation: String,
+}
+
+impl PreflightFailure {
+    fn new(check: &'static str, reason: impl Into<String>, remediation: impl Into<String>) -> Self {
+        Self {
+            check,
+            reason: reason.into(),
+            remediation: remediation.into(),
+        }
+    }
+}
+
+impl Display for PreflightFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {} {}", self.check, self.reason, self.remediation)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Protocol {
+    Tcp,
+    Udp,
+}
+
+/// Verifies the environment before loading the data and serving the traffic, so that the server
+/// fails fast with the actionable message instead of the obscure runtime error later on.
+/// All the checks are run and every failure is logged, before returning the error.
+pub async fn run(config: &ServerConfig) -> Result<(), PreflightError> {
+    if !config.preflight.enabled {
+        return Ok(());
+    }
+
+    let system = &config.system;
+    let mut failures = Vec::new();
+    if system.recovery.read_only {
+        warn!("{COMPONENT} - skipping data directory checks in read-only recovery mode.");
+    } else {
+        failures.extend(check_data_directory(system).err());
+        failures.extend(
+            check_free_disk_space(system, config.preflight.min_free_disk_space.as_bytes_u64())
+                .err(),
+        );
+    }
+    failures
+        .extend(check_file_descriptors(system, config.preflight.reserved_file_descriptors).err());
+    failures.extend(check_clock(config).err());
+    failures.extend(check_ports(config));
+    failures.extend(check_archiver(config).await.err());
+
+    if failures.is_empty() {
+        info!("{COMPONENT} - all checks passed.");
+        return Ok(());
+    }
+
+    for failure in &failures {
+        error!("{COMPONENT} - check failed, {failure}");
+    }
+    Err(PreflightError::PreflightChecksFailed {
+        count: failures.len(),
+    })
+}
+
+fn check_data_directory(system: &SystemConfig) -> Result<(), PreflightFailure> {
+    const CHECK: &str = "data directory";
+    let path = system.get_system_path();
+    let remediation = format!("Make sure that the user running the server owns the directory (e.g. `chown -R <user> {path}`) or change `system.path` in the configuration.");
+    if let Err(error) = std::fs::create_dir_all(&path) {
+        return Err(PreflightFailure::new(
+            CHECK,
+            format!("cannot create directory: {path}, error: {error}."),
+            remediation,
+        ));
+    }
+
+    let probe_path = Path::new(&path).join(PROBE_FILE_NAME);
+    let probe = std::fs::File::create(&probe_path).and_then(|mut file| {
+        file.write_all(COMPONENT.as_bytes())?;
+        file.sync_all()
+    });
+    let _ = std::fs::remove_file(&probe_path);
+    probe.map_err(|error| {
+        PreflightFailure::new(
+            CHECK,
+            format!("directory: {path} is not writable, error: {error}."),
+            remediation,
+        )
+    })
+}
+
+fn check_free_disk_space(
+    system: &SystemConfig,
+    min_free_disk_space: u64,
+) -> Result<(), PreflightFailure> {
+    const CHECK: &str = "free disk space";
+    let path = system.get_system_path();
+    let stats = statvfs(path.as_str()).map_err(|error| {
+        PreflightFailure::new(
+            CHECK,
+            format!("cannot read the file system statistics of: {path}, error: {error}."),
+            "Make sure that the data directory is mounted and accessible.",
+        )
+    })?;
+    let free_disk_space = stats.blocks_available() as u64 * stats.fragment_size() as u64;
+    if free_disk_space < min_free_disk_space {
+        return Err(PreflightFailure::new(
+            CHECK,
+            format!("only {free_disk_space} bytes are available at: {path}, required at least: {min_free_disk_space} bytes."),
+            "Free up the space, e.g. by lowering the topics retention (`message_expiry`, `max_topic_size`) or enabling the archiver, extend the volume or lower `preflight.min_free_disk_space`.",
+        ));
+    }
+
+    Ok(())
+}
+
+fn check_file_descriptors(
+    system: &SystemConfig,
+    reserved_file_descriptors: u64,
+) -> Result<(), PreflightFailure> {
+    const CHECK: &str = "file descriptors limit";
+    let segments_count = count_segments(Path::new(&system.get_streams_path()));
+    let required = segments_count * FILE_DESCRIPTORS_PER_SEGMENT + reserved_file_descriptors;
+    let (soft_limit, hard_limit) = getrlimit(Resource::RLIMIT_NOFILE).map_err(|error| {
+        PreflightFailure::new(
+            CHECK,
+            format!("cannot read the open files limit, error: {error}."),
+            "Make sure that the server is allowed to read its resource limits.",
+        )
+    })?;
+    if soft_limit >= required {
+        return Ok(());
+    }
+
+    if hard_limit >= required && setrlimit(Resource::RLIMIT_NOFILE, required, hard_limit).is_ok() {
+        info!("{COMPONENT} - raised the open files limit from: {soft_limit} to: {required}, for {segments_count} segments.");
+        return Ok(());
+    }
+
+    Err(PreflightFailure::new(
+        CHECK,
+        format!("the open files limit: {soft_limit} (hard limit: {hard_limit}) is lower than: {required} required for {segments_count} segments and {reserved_file_descriptors} reserved descriptors."),
+        format!("Raise the limit to at least {required}, e.g. with `ulimit -n {required}`, `LimitNOFILE={required}` in the systemd unit or `--ulimit nofile={required}` for Docker."),
+    ))
+}
+
+fn count_segments(path: &Path) -> u64 {
+    let Ok(entries) = std::fs::read_dir(path) else {
+        return 0;
+    };
+
+    entries
+        .flatten()
+        .map(|entry| {
+            let path = entry.path();
+            if path.is_dir() {
+                count_segments(&path)
+            } else if path
+                .extension()
+                .is_some_and(|extension| extension == LOG_EXTENSION)
+            {
+                1
+            } else {
+                0
+            }
+        })
+        .sum()
+}
+
+fn check_clock(config: &ServerConfig) -> Result<(), PreflightFailure> {
+    let Ok(last_modified) = std::fs::metadata(config.system.get_state_log_path())
+        .and_then(|metadata| metadata.modified())
+    else {
+        return Ok(());
+    };
+
+    let max_clock_drift = config.preflight.max_clock_drift;
+    let now = SystemTime::now();
+    match last_modified.duration_since(now) {
+        Ok(drift) if drift > max_clock_drift.get_duration() => Err(PreflightFailure::new(
+            "clock",
+            format!("the state log was last modified {} seconds in the future, which exceeds the allowed drift of {max_clock_drift}.", drift.as_secs()),
+            "Make sure that the system clock is synchronized (e.g. check `timedatectl status`, `chronyc tracking` or the NTP service), as the expiry, retention and timestamps of the messages rely on it.",
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn check_ports(config: &ServerConfig) -> Vec<PreflightFailure> {
+    let mut addresses = Vec::new();
+    if config.tcp.enabled {
+        addresses.push(("tcp", config.tcp.address.as_str(), Protocol::Tcp));
+    }
+    if config.quic.enabled {
+        addresses.push(("quic", config.quic.address.as_str(), Protocol::Udp));
+    }
+    if config.http.enabled {
+        addresses.push(("http", config.http.address.as_str(), Protocol::Tcp));
+    }
+    if config.websocket.enabled {
+        addresses.push((
+            "websocket",
+            config.websocket.address.as_str(),
+            Protocol::Tcp,
+        ));
+    }
+    if config.grpc.enabled {
+        addresses.push(("grpc", config.grpc.address.as_str(), Protocol::Tcp));
+    }
+    if config.system.cluster.enabled {
+        addresses.push((
+            "cluster",
+            config.system.cluster.address.as_str(),
+            Protocol::Tcp,
+        ));
+    }
+
+    addresses
+        .into_iter()
+        .filter_map(|(transport, address, protocol)| check_port(transport, address, protocol).err())
+        .collect()
+}
+
+fn check_port(transport: &str, address: &str, protocol: Protocol) -> Result<(), PreflightFailure> {
+    const CHECK: &str = "port availability";
+    let socket_address = address
+        .to_socket_addrs()
+        .ok()
+        .and_then(|mut addresses| addresses.next())
+        .ok_or_else(|| {
+            PreflightFailure::new(
+                CHECK,
+                format!("cannot resolve {transport} address: {address}."),
+                format!("Set `{transport}.address` to the valid `host:port` address."),
+            )
+        })?;
+    if socket_address.port() == 0 {
+        return Ok(());
+    }
+
+    bind(socket_address, protocol).map_err(|error| {
+        let remediation = match error.kind() {
+            ErrorKind::AddrInUse => format!("Stop the process using the port (e.g. find it with `ss -ltnup | grep {}`) or change `{transport}.address`.", socket_address.port()),
+            ErrorKind::PermissionDenied => format!("Use the port above 1023, grant the server the `CAP_NET_BIND_SERVICE` capability or change `{transport}.address`."),
+            ErrorKind::AddrNotAvailable => format!("The address is not assigned to any local network interface, change `{transport}.address`."),
+            _ => format!("Change `{transport}.address` in the configuration."),
+        };
+        PreflightFailure::new(
+            CHECK,
+            format!("cannot bind {transport} address: {socket_address}, error: {error}."),
+            remediation,
+        )
+    })
+}
+
+fn bind(address: SocketAddr, protocol: Protocol) -> std::io::Result<()> {
+    match protocol {
+        Protocol::Tcp => TcpListener::bind(address).map(drop),
+        Protocol::Udp => UdpSocket::bind(address).map(drop),
+    }
+}
+
+async fn check_archiver(config: &ServerConfig) -> Result<(), PreflightFailure> {
+    const CHECK: &str = "archiver";
+    let archiver_config = &config.data_maintenance.archiver;
+    if !archiver_config.enabled {
+        return Ok(());
+    }
+
+    let kind = archiver_config.kind;
+    let remediation = match kind {
+        ArchiverKindType::Disk => "Make sure that `data_maintenance.archiver.disk.path` is writable by the user running the server.",
+        ArchiverKindType::S3 => "Verify the S3 credentials, endpoint and region, and that the bucket exists and the credentials are allowed to list it (`s3:ListBucket`).",
+        ArchiverKindType::Gcs => "Verify the GCS credentials, and that the bucket exists and the service account is allowed to list its objects (`storage.objects.list`).",
+        ArchiverKindType::Azure => "Verify the Azure account, credentials and container, and that the credentials are allowed to list the blobs (e.g. the `Storage Blob Data Reader` role).",
+    };
+    let archiver = ArchiverKind::from_config(archiver_config).map_err(|error| {
+        PreflightFailure::new(
+            CHECK,
+            format!("cannot create {kind} archiver, error: {error}."),
+            remediation,
+        )
+    })?;
+
+    let timeout = config.preflight.archiver_timeout;
+    match tokio::time::timeout(timeout.get_duration(), archiver.init()).await {
+        Ok(Ok(())) => Ok(()),
+        Ok(Err(error)) => Err(PreflightFailure::new(
+            CHECK,
+            format!("cannot access {kind} archiver storage, error: {error}."),
+            remediation,
+        )),
+        Err(_) => Err(PreflightFailure::new(
+            CHECK,
+            format!("{kind} archiver storage did not respond within {timeout}."),
+            format!("Check the network connectivity to the storage endpoint. {remediation}"),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn port_in_use_should_be_reported() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+
+        let failure = check_port("tcp", &address, Protocol::Tcp).unwrap_err();
+        assert!(failure.remediation.contains("tcp.address"));
+        drop(listener);
+        assert!(check_port("tcp", "127.0.0.1:0", Protocol::Tcp).is_ok());
+    }
+
+    #[test]
+    fn segments_should_be_counted_recursively() {
+        let path = std::env::temp_dir().join(format!("iggy_preflight_{}", std::process::id()));
+        let partition_path = path.join("1/topics/1/partitions/1");
+        std::fs::create_dir_all(&partition_path).unwrap();
+        for file in [
+            "00000000000000000000.log",
+            "00000000000000000000.index",
+            "00000000000000001000.log",
+            "00000000000000001000.index",
+        ] {
+            std::fs::File::create(partition_path.join(file)).unwrap();
+        }
+
+        let segments_count = count_segments(&path);
+        std::fs::remove_dir_all(&path).unwrap();
+        assert_eq!(segments_count, 2);
+        assert_eq!(count_segments(&path), 0);
+    }
+}
@@ -0,0 +1,324 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::configs::quic::QuicCertificateConfig;
+use crate::quic::COMPONENT;
+use crate::server_error::QuicError;
+use error_set::ErrContext;
+use iggy::utils::duration::IggyDuration;
+use rustls::crypto::ring::sign::any_supported_type;
+use rustls::pki_types::{CertificateDer, PrivateKeyDer};
+use rustls::server::danger::ClientCertVerifier;
+use rustls::server::{ClientHello, ResolvesServerCert, WebPkiClientVerifier};
+use rustls::sign::CertifiedKey;
+use rustls::RootCertStore;
+use std::fs::File;
+use std::io::BufReader;
+use std::sync::{Arc, RwLock};
+use std::time::SystemTime;
+use tokio::sync::watch;
+use tracing::{error, info};
+
+/// Resolves the server certificate for every new QUIC handshake.
+/// The certificate can be replaced at runtime, each replacement bumps the generation,
+/// so that the connections established with the previous certificate can be told apart.
+#[derive(Debug)]
+pub struct CertificateResolver {
+    cert_file: Option<String>,
+    key_file: Option<String>,
+    certified_key: RwLock<Arc<CertifiedKey>>,
+    generation: watch::Sender<u64>,
+}
+
+impl CertificateResolver {
+    pub fn new(config: &QuicCertificateConfig) -> Result<Self, QuicError> {
+        let (cert_file, key_file, (certificate, key)) = match config.self_signed {
+            true => (None, None, generate_self_signed_cert()?),
+            false => (
+                Some(config.cert_file.clone()),
+                Some(config.key_file.clone()),
+                load_certificates(&config.cert_file, &config.key_file)?,
+            ),
+        };
+
+        let (generation, _) = watch::channel(0);
+        Ok(Self {
+            cert_file,
+            key_file,
+            certified_key: RwLock::new(Arc::new(create_certified_key(certificate, key)?)),
+            generation,
+        })
+    }
+
+    pub fn is_reloadable(&self) -> bool {
+        self.cert_file.is_some() && self.key_file.is_some()
+    }
+
+    pub fn generation(&self) -> u64 {
+        *self.generation.borrow()
+    }
+
+    pub fn subscribe(&self) -> watch::Receiver<u64> {
+        self.generation.subscribe()
+    }
+
+    /// Loads the certificate and key files again and uses them for all the new handshakes.
+    pub fn reload(&self) -> Result<(), QuicError> {
+        let (Some(cert_file), Some(key_file)) = (&self.cert_file, &self.key_file) else {
+            return Ok(());
+        };
+
+        let (certificate, key) = load_certificates(cert_file, key_file)?;
+        let certified_key = Arc::new(create_certified_key(certificate, key)?);
+        *self
+            .certified_key
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = certified_key;
+        self.generation.send_modify(|generation| *generation += 1);
+        info!(
+            "QUIC certificate has been reloaded from: {cert_file}, generation: {}",
+            self.generation()
+        );
+        Ok(())
+    }
+
+    fn get_files_modification_time(&self) -> Option<(SystemTime, SystemTime)> {
+        let (Some(cert_file), Some(key_file)) = (&self.cert_file, &self.key_file) else {
+            return None;
+        };
+
+        let cert_modified = std::fs::metadata(cert_file).ok()?.modified().ok()?;
+        let key_modified = std::fs::metadata(key_file).ok()?.modified().ok()?;
+        Some((cert_modified, key_modified))
+    }
+}
+
+impl ResolvesServerCert for CertificateResolver {
+    fn resolve(&self, _client_hello: ClientHello) -> Option<Arc<CertifiedKey>> {
+        Some(
+            self.certified_key
+                .read()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .clone(),
+        )
+    }
+}
+
+/// Reloads the certificate whenever its files are modified (checked every `reload_interval`)
+/// or the server receives SIGHUP.
+pub fn start_reloader(resolver: Arc<CertificateResolver>, reload_interval: IggyDuration) {
+    if !resolver.is_reloadable() {
+        return;
+    }
+
+    if !reload_interval.is_zero() {
+        let resolver = resolver.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(reload_interval.get_duration());
+            let mut last_modified = resolver.get_files_modification_time();
+            interval.tick().await;
+            loop {
+                interval.tick().await;
+                let modified = resolver.get_files_modification_time();
+                if modified.is_none() || modified == last_modified {
+                    continue;
+                }
+
+                info!("QUIC certificate files have changed, reloading...");
+                if let Err(error) = resolver.reload() {
+                    error!("{COMPONENT} (error: {error}) - failed to reload certificate");
+                    continue;
+                }
+                last_modified = modified;
+            }
+        });
+    }
+
+    #[cfg(unix)]
+    tokio::spawn(async move {
+        use tokio::signal::unix::{signal, SignalKind};
+        let mut hangup = match signal(SignalKind::hangup()) {
+            Ok(hangup) => hangup,
+            Err(error) => {
+                error!("{COMPONENT} (error: {error}) - failed to listen for SIGHUP, certificate will not be reloaded on signal");
+                return;
+            }
+        };
+        while hangup.recv().await.is_some() {
+            info!("Received SIGHUP, reloading QUIC certificate...");
+            if let Err(error) = resolver.reload() {
+                error!("{COMPONENT} (error: {error}) - failed to reload certificate");
+            }
+        }
+    });
+}
+
+fn create_certified_key(
+    certificate: Vec<CertificateDer<'static>>,
+    key: PrivateKeyDer<'static>,
+) -> Result<CertifiedKey, QuicError> {
+    let signing_key = any_supported_type(&key)
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - unsupported private key type")
+        })
+        .map_err(|_| QuicError::CertLoadError)?;
+    Ok(CertifiedKey::new(certificate, signing_key))
+}
+
+fn generate_self_signed_cert(
+) -> Result<(Vec<CertificateDer<'static>>, PrivateKeyDer<'static>), QuicError> {
+    let certificate = rcgen::generate_simple_self_signed(vec!["localhost".into()]).unwrap();
+    let certificate_der = certificate.cert.der().clone();
+    let private_key = certificate.key_pair.serialize_der();
+    let private_key = PrivateKeyDer::try_from(private_key)
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to parse private key")
+        })
+        .map_err(|_| QuicError::CertGenerationError)?;
+    let cert_chain = vec![certificate_der];
+    Ok((cert_chain, private_key))
+}
+
+/// Creates the verifier of the client certificates signed by the CA from `client_ca_file`.
+/// Unless the certificate is required, clients without one are still accepted, so they can log in with the other authenticators.
+/// It's shared by the TCP listener, as its mutual TLS is handled by `rustls` too.
+pub fn create_client_verifier(
+    client_ca_file: &str,
+    require_client_certificate: bool,
+) -> Result<Arc<dyn ClientCertVerifier>, QuicError> {
+    let mut reader = BufReader::new(
+        File::open(client_ca_file)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to open client CA file: {client_ca_file}"
+                )
+            })
+            .map_err(|_| QuicError::CertLoadError)?,
+    );
+    let mut roots = RootCertStore::empty();
+    for certificate in rustls_pemfile::certs(&mut reader) {
+        let certificate = certificate
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to parse client CA file: {client_ca_file}"
+                )
+            })
+            .map_err(|_| QuicError::CertLoadError)?;
+        roots
+            .add(certificate)
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - invalid client CA certificate")
+            })
+            .map_err(|_| QuicError::CertLoadError)?;
+    }
+    if roots.is_empty() {
+        error!("{COMPONENT} - no certificates found in client CA file: {client_ca_file}");
+        return Err(QuicError::CertLoadError);
+    }
+
+    let verifier = WebPkiClientVerifier::builder_with_provider(
+        Arc::new(roots),
+        Arc::new(rustls::crypto::ring::default_provider()),
+    );
+    let verifier = match require_client_certificate {
+        true => verifier,
+        false => verifier.allow_unauthenticated(),
+    };
+    verifier
+        .build()
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to create client certificate verifier")
+        })
+        .map_err(|_| QuicError::ConfigCreationError)
+}
+
+pub fn load_certificates(
+    cert_file: &str,
+    key_file: &str,
+) -> Result<(Vec<CertificateDer<'static>>, PrivateKeyDer<'static>), QuicError> {
+    let mut cert_chain_reader = BufReader::new(
+        File::open(cert_file)
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to open cert file: {cert_file}")
+            })
+            .map_err(|_| QuicError::CertLoadError)?,
+    );
+    let certs = rustls_pemfile::certs(&mut cert_chain_reader)
+        .collect::<Result<Vec<_>, _>>()
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to parse cert file: {cert_file}")
+        })
+        .map_err(|_| QuicError::CertLoadError)?;
+    if certs.is_empty() {
+        error!("{COMPONENT} - no certificates found in cert file: {cert_file}");
+        return Err(QuicError::CertLoadError);
+    }
+
+    let mut key_reader = BufReader::new(
+        File::open(key_file)
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to open key file: {key_file}")
+            })
+            .map_err(|_| QuicError::CertLoadError)?,
+    );
+    let mut keys = rustls_pemfile::rsa_private_keys(&mut key_reader)
+        .filter(|key| key.is_ok())
+        .map(|key| PrivateKeyDer::try_from(key.unwrap().secret_pkcs1_der().to_vec()))
+        .collect::<Result<Vec<_>, _>>()
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to parse private key")
+        })
+        .map_err(|_| QuicError::CertLoadError)?;
+    if keys.is_empty() {
+        error!("{COMPONENT} - no private keys found in key file: {key_file}");
+        return Err(QuicError::CertLoadError);
+    }
+
+    let key = keys.remove(0);
+    Ok((certs, key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn self_signed_certificate_should_not_be_reloaded() {
+        let config = QuicCertificateConfig {
+            self_signed: true,
+            ..Default::default()
+        };
+        let resolver = CertificateResolver::new(&config).unwrap();
+
+        assert!(!resolver.is_reloadable());
+        assert!(resolver.reload().is_ok());
+        assert_eq!(resolver.generation(), 0);
+    }
+
+    #[test]
+    fn missing_certificate_files_should_fail_to_load() {
+        let config = QuicCertificateConfig {
+            self_signed: false,
+            cert_file: "missing_cert.pem".to_string(),
+            key_file: "missing_key.pem".to_string(),
+            ..Default::default()
+        };
+
+        assert!(CertificateResolver::new(&config).is_err());
+    }
+}
@@ -19,31 +19,59 @@
 use crate::binary::command;
 use crate::binary::sender::SenderKind;
 use crate::command::ServerCommand;
+use crate::quic::certificates::CertificateResolver;
 use crate::server_error::ConnectionError;
 use crate::streaming::clients::client_manager::Transport;
+use crate::streaming::clients::transport_limiter::{ConnectionPermit, TransportLimiter};
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::SharedSystem;
-use anyhow::{anyhow, Context};
+use anyhow::Context;
 use bytes::Bytes;
+use iggy::bytes_serializable::BytesSerializable;
+use iggy::utils::duration::IggyDuration;
 use iggy::validatable::Validatable;
-use iggy::{bytes_serializable::BytesSerializable, messages::MAX_PAYLOAD_SIZE};
-use quinn::{Connection, Endpoint, RecvStream, SendStream};
-use tracing::{debug, error, info};
+use quinn::{Connection, Endpoint, RecvStream, SendStream, VarInt};
+use rustls::pki_types::CertificateDer;
+use std::sync::Arc;
+use tokio::sync::watch;
+use tracing::{debug, error, info, warn};
 
 const LISTENERS_COUNT: u32 = 10;
 const INITIAL_BYTES_LENGTH: usize = 4;
+const CERTIFICATE_ROTATED_CODE: VarInt = VarInt::from_u32(1);
+const CERTIFICATE_ROTATED_REASON: &[u8] = b"certificate rotated";
 
-pub fn start(endpoint: Endpoint, system: SharedSystem) {
+pub fn start(
+    endpoint: Endpoint,
+    system: SharedSystem,
+    certificate_resolver: Arc<CertificateResolver>,
+    reconnect_grace_period: IggyDuration,
+    limiter: Arc<TransportLimiter>,
+) {
     for _ in 0..LISTENERS_COUNT {
         let endpoint = endpoint.clone();
         let system = system.clone();
+        let certificate_resolver = certificate_resolver.clone();
+        let limiter = limiter.clone();
         tokio::spawn(async move {
             while let Some(incoming_connection) = endpoint.accept().await {
                 info!(
                     "Incoming connection from client: {}",
                     incoming_connection.remote_address()
                 );
+                let connection_permit = match limiter.try_acquire_connection() {
+                    Ok(permit) => permit,
+                    Err(error) => {
+                        warn!(
+                            "Refused QUIC connection from client: {}. {error}",
+                            incoming_connection.remote_address()
+                        );
+                        incoming_connection.refuse();
+                        continue;
+                    }
+                };
                 let system = system.clone();
+                let certificate_resolver = certificate_resolver.clone();
                 let incoming_connection = incoming_connection.accept();
                 if incoming_connection.is_err() {
                     error!(
@@ -54,7 +82,15 @@ pub fn start(endpoint: Endpoint, system: SharedSystem) {
                 }
                 let incoming_connection = incoming_connection.unwrap();
                 tokio::spawn(async move {
-                    if let Err(error) = handle_connection(incoming_connection, system).await {
+                    if let Err(error) = handle_connection(
+                        incoming_connection,
+                        system,
+                        certificate_resolver,
+                        reconnect_grace_period,
+                        connection_permit,
+                    )
+                    .await
+                    {
                         error!("Connection has failed: {error}");
                     }
                 });
@@ -66,23 +102,53 @@ pub fn start(endpoint: Endpoint, system: SharedSystem) {
 async fn handle_connection(
     incoming_connection: quinn::Connecting,
     system: SharedSystem,
+    certificate_resolver: Arc<CertificateResolver>,
+    reconnect_grace_period: IggyDuration,
+    connection_permit: ConnectionPermit,
 ) -> Result<(), ConnectionError> {
+    let limiter = connection_permit.limiter().clone();
+    let certificate_generation = certificate_resolver.generation();
     let connection = incoming_connection.await?;
     let address = connection.remote_address();
     info!("Client has connected: {address}");
+    if !reconnect_grace_period.is_zero() {
+        close_on_certificate_rotation(
+            connection.clone(),
+            certificate_resolver.subscribe(),
+            certificate_generation,
+            reconnect_grace_period,
+        );
+    }
+
     let session = system
         .read()
         .await
         .add_client(&address, Transport::Quic)
         .await;
+    if let Some(peer_certificate) = connection
+        .peer_identity()
+        .and_then(|identity| identity.downcast::<Vec<CertificateDer<'static>>>().ok())
+        .and_then(|certificates| certificates.first().map(|certificate| certificate.to_vec()))
+    {
+        session.set_peer_certificate(peer_certificate);
+        if let Err(error) = system
+            .read()
+            .await
+            .login_user_with_certificate(&session)
+            .await
+        {
+            warn!("Cannot login client: {address} with the certificate. {error}");
+        }
+    }
 
     let client_id = session.client_id;
     while let Some(stream) = accept_stream(&connection, &system, client_id).await? {
         let system = system.clone();
         let session = session.clone();
+        let limiter = limiter.clone();
 
         let handle_stream_task = async move {
-            if let Err(err) = handle_stream(stream, system, session).await {
+            if let Err(err) = handle_stream(stream, system, session, limiter).await {
                 error!("Error when handling QUIC stream: {:?}", err)
             }
         };
@@ -91,6 +157,46 @@ async fn handle_connection(
     Ok(())
 }
 
+/// Gracefully closes the connection established with the previous certificate once the grace period
+/// after the rotation elapses, so that the client reconnects and handshakes with the new certificate.
+fn close_on_certificate_rotation(
+    connection: Connection,
+    mut certificate_generation: watch::Receiver<u64>,
+    handshake_generation: u64,
+    grace_period: IggyDuration,
+) {
+    tokio::spawn(async move {
+        tokio::select! {
+            rotated = async {
+                certificate_generation
+                    .wait_for(|generation| *generation > handshake_generation)
+                    .await
+                    .map(|_| ())
+            } => {
+                if rotated.is_err() {
+                    return;
+                }
+            }
+            _ = connection.closed() => return,
+        }
+
+        info!(
+            "QUIC certificate has been rotated, connection with client: {} will be closed in: {grace_period}",
+            connection.remote_address()
+        );
+        tokio::select! {
+            _ = tokio::time::sleep(grace_period.get_duration()) => {
+                info!(
+                    "Closing connection with client: {} established with the previous QUIC certificate",
+                    connection.remote_address()
+                );
+                connection.close(CERTIFICATE_ROTATED_CODE, CERTIFICATE_ROTATED_REASON);
+            }
+            _ = connection.closed() => {}
+        }
+    });
+}
+
 type BiStream = (SendStream, RecvStream);
 
 async fn accept_stream(
@@ -104,6 +210,11 @@ async fn accept_stream(
             system.read().await.delete_client(client_id).await;
             Ok(None)
         }
+        Err(quinn::ConnectionError::LocallyClosed) => {
+            info!("Connection closed by the server");
+            system.read().await.delete_client(client_id).await;
+            Ok(None)
+        }
         Err(error) => {
             error!("Error when handling QUIC stream: {:?}", error);
             system.read().await.delete_client(client_id).await;
@@ -117,36 +228,56 @@ async fn handle_stream(
     stream: BiStream,
     system: SharedSystem,
     session: impl AsRef<Session>,
+    limiter: Arc<TransportLimiter>,
 ) -> anyhow::Result<()> {
     let (send_stream, mut recv_stream) = stream;
+    let mut length_bytes = [0u8; INITIAL_BYTES_LENGTH];
+    recv_stream
+        .read_exact(&mut length_bytes)
+        .await
+        .with_context(|| {
+            format!(
+                "Unable to read the QUIC request length, expected: {INITIAL_BYTES_LENGTH} bytes."
+            )
+        })?;
+    let length = u32::from_le_bytes(length_bytes);
+    if let Err(error) = limiter.validate_frame_size(length as u64) {
+        warn!(
+            "Rejected a QUIC request for session: {}. {error}",
+            session.as_ref()
+        );
+        let mut sender = SenderKind::get_quic_sender(send_stream, recv_stream);
+        sender.send_error_response(error).await?;
+        return Ok(());
+    }
+
     // TODO: read to BytesMut instead of Vec<u8>
     let request = recv_stream
-        .read_to_end(MAX_PAYLOAD_SIZE as usize)
+        .read_to_end(length as usize)
         .await
         .with_context(|| "Error when reading the QUIC request.")?;
 
-    if request.len() < INITIAL_BYTES_LENGTH {
-        return Err(anyhow!(
-            "Unable to read the QUIC request length, expected: {INITIAL_BYTES_LENGTH} bytes, received: {} bytes.",
-            request.len()
-        ));
-    }
-
     debug!("Trying to read command...");
-    let length = request[..INITIAL_BYTES_LENGTH]
-        .try_into()
-        .map(u32::from_le_bytes)
-        .unwrap_or_default();
-    let command =
-        ServerCommand::from_bytes(Bytes::copy_from_slice(&request[INITIAL_BYTES_LENGTH..]))
-            .with_context(|| "Error when reading the QUIC request command.")?;
+    let command = ServerCommand::from_bytes(Bytes::from(request))
+        .with_context(|| "Error when reading the QUIC request command.")?;
     command
         .validate()
         .with_context(|| "Error when validating the QUIC command.")?;
 
-    debug!("Received a QUIC command: {command}, payload size: {length}");
-
     let mut sender = SenderKind::get_quic_sender(send_stream, recv_stream);
+    let _request_permit = match limiter.try_acquire_request() {
+        Ok(permit) => permit,
+        Err(error) => {
+            warn!(
+                "Rejected a QUIC command: {command} for session: {}. {error}",
+                session.as_ref()
+            );
+            sender.send_error_response(error).await?;
+            return Ok(());
+        }
+    };
+
+    debug!("Received a QUIC command: {command}, payload size: {length}");
     command::handle(command, &mut sender, session.as_ref(), system.clone())
         .await
         .with_context(|| "Error when handling the QUIC request.")
@@ -16,6 +16,7 @@
  * under the License.
  */
 
+pub mod certificates;
 mod listener;
 pub mod quic_sender;
 pub mod quic_server;
@@ -18,6 +18,7 @@
 
 use crate::quic::COMPONENT;
 use crate::{binary::sender::Sender, server_error::ServerError};
+use bytes::{BufMut, Bytes, BytesMut};
 use error_set::ErrContext;
 use iggy::error::IggyError;
 use quinn::{RecvStream, SendStream};
@@ -29,6 +30,7 @@ const STATUS_OK: &[u8] = &[0; 4];
 pub struct QuicSender {
     pub(crate) send: SendStream,
     pub(crate) recv: RecvStream,
+    pub(crate) error_categories: bool,
 }
 
 impl Sender for QuicSender {
@@ -49,11 +51,47 @@ impl Sender for QuicSender {
         self.send_response(STATUS_OK, payload).await
     }
 
+    async fn send_ok_response_vectored(&mut self, payload: &[Bytes]) -> Result<(), IggyError> {
+        let length = payload.iter().map(Bytes::len).sum::<usize>() as u32;
+        let mut header = BytesMut::with_capacity(8);
+        header.put_slice(STATUS_OK);
+        header.put_u32_le(length);
+        let mut chunks = Vec::with_capacity(payload.len() + 1);
+        chunks.push(header.freeze());
+        chunks.extend(payload.iter().cloned());
+        debug!("Sending vectored response with {} chunks...", chunks.len());
+        self.send
+            .write_all_chunks(&mut chunks)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to write chunks to the stream")
+            })
+            .map_err(|_| IggyError::QuicError)?;
+        self.send
+            .finish()
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to finish send stream")
+            })
+            .map_err(|_| IggyError::QuicError)?;
+        debug!("Sent vectored response with status: {:?}", STATUS_OK);
+        Ok(())
+    }
+
     async fn send_error_response(&mut self, error: IggyError) -> Result<(), IggyError> {
-        self.send_response(&error.as_code().to_le_bytes(), &[])
+        let category = [error.category().as_code()];
+        let payload: &[u8] = if self.error_categories {
+            &category
+        } else {
+            &[]
+        };
+        self.send_response(&error.as_code().to_le_bytes(), payload)
             .await
     }
 
+    fn set_error_categories(&mut self, enabled: bool) {
+        self.error_categories = enabled;
+    }
+
     async fn shutdown(&mut self) -> Result<(), ServerError> {
         Ok(())
     }
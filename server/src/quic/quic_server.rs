@@ -16,21 +16,21 @@
  * under the License.
  */
 
-use std::fs::File;
-use std::io::BufReader;
 use std::net::SocketAddr;
 use std::sync::Arc;
 
 use anyhow::Result;
 use error_set::ErrContext;
+use quinn::crypto::rustls::QuicServerConfig;
 use quinn::{Endpoint, IdleTimeout, VarInt};
-use rustls::pki_types::{CertificateDer, PrivateKeyDer};
 use tracing::info;
 
 use crate::configs::quic::QuicConfig;
+use crate::quic::certificates::{self, CertificateResolver};
 use crate::quic::listener;
 use crate::quic::COMPONENT;
 use crate::server_error::QuicError;
+use crate::streaming::clients::transport_limiter::TransportLimiter;
 use crate::streaming::systems::system::SharedSystem;
 
 /// Starts the QUIC server.
@@ -38,29 +38,60 @@ use crate::streaming::systems::system::SharedSystem;
 pub fn start(config: QuicConfig, system: SharedSystem) -> SocketAddr {
     info!("Initializing Iggy QUIC server...");
     let address = config.address.parse().unwrap();
-    let quic_config = configure_quic(config);
+    let certificate_resolver = match CertificateResolver::new(&config.certificate) {
+        Ok(certificate_resolver) => Arc::new(certificate_resolver),
+        Err(error) => panic!("Error when loading QUIC certificate: {:?}", error),
+    };
+    let quic_config = configure_quic(&config, certificate_resolver.clone());
     if let Err(error) = quic_config {
         panic!("Error when configuring QUIC: {:?}", error);
     }
 
     let endpoint = Endpoint::server(quic_config.unwrap(), address).unwrap();
     let addr = endpoint.local_addr().unwrap();
-    listener::start(endpoint, system);
+    certificates::start_reloader(
+        certificate_resolver.clone(),
+        config.certificate.reload_interval,
+    );
+    listener::start(
+        endpoint,
+        system,
+        certificate_resolver,
+        config.certificate.reconnect_grace_period,
+        TransportLimiter::register("QUIC", &config.limits),
+    );
     info!("Iggy QUIC server has started on: {:?}", addr);
     addr
 }
 
-fn configure_quic(config: QuicConfig) -> Result<quinn::ServerConfig, QuicError> {
-    let (certificate, key) = match config.certificate.self_signed {
-        true => generate_self_signed_cert()?,
-        false => load_certificates(&config.certificate.cert_file, &config.certificate.key_file)?,
+fn configure_quic(
+    config: &QuicConfig,
+    certificate_resolver: Arc<CertificateResolver>,
+) -> Result<quinn::ServerConfig, QuicError> {
+    let crypto_config = rustls::ServerConfig::builder_with_provider(Arc::new(
+        rustls::crypto::ring::default_provider(),
+    ))
+    .with_protocol_versions(&[&rustls::version::TLS13])
+    .with_error_context(|error| {
+        format!("{COMPONENT} (error: {error}) - failed to create TLS config")
+    })
+    .map_err(|_| QuicError::ConfigCreationError)?;
+    let crypto_config = if config.certificate.client_ca_file.is_empty() {
+        crypto_config.with_no_client_auth()
+    } else {
+        crypto_config.with_client_cert_verifier(certificates::create_client_verifier(
+            &config.certificate.client_ca_file,
+            config.certificate.require_client_certificate,
+        )?)
     };
-
-    let mut server_config = quinn::ServerConfig::with_single_cert(certificate, key)
+    let crypto_config = crypto_config.with_cert_resolver(certificate_resolver);
+    let crypto_config = QuicServerConfig::try_from(crypto_config)
         .with_error_context(|error| {
             format!("{COMPONENT} (error: {error}) - failed to create server config")
         })
         .map_err(|_| QuicError::ConfigCreationError)?;
+
+    let mut server_config = quinn::ServerConfig::with_crypto(Arc::new(crypto_config));
     let mut transport = quinn::TransportConfig::default();
     transport.initial_mtu(config.initial_mtu.as_bytes_u64() as u16);
     transport.send_window(config.send_window.as_bytes_u64());
@@ -94,50 +125,3 @@ fn configure_quic(config: QuicConfig) -> Result<quinn::ServerConfig, QuicError>
     server_config.transport_config(Arc::new(transport));
     Ok(server_config)
 }
-
-fn generate_self_signed_cert<'a>() -> Result<(Vec<CertificateDer<'a>>, PrivateKeyDer<'a>), QuicError>
-{
-    let certificate = rcgen::generate_simple_self_signed(vec!["localhost".into()]).unwrap();
-    let certificate_der = certificate.cert.der();
-    let private_key = certificate.key_pair.serialize_der();
-    let private_key = PrivateKeyDer::try_from(private_key)
-        .with_error_context(|error| {
-            format!("{COMPONENT} (error: {error}) - failed to parse private key")
-        })
-        .map_err(|_| QuicError::CertGenerationError)?;
-    let cert_chain = vec![certificate_der.clone()];
-    Ok((cert_chain, private_key))
-}
-
-fn load_certificates(
-    cert_file: &str,
-    key_file: &str,
-) -> Result<(Vec<CertificateDer<'static>>, PrivateKeyDer<'static>), QuicError> {
-    let mut cert_chain_reader = BufReader::new(
-        File::open(cert_file)
-            .with_error_context(|error| {
-                format!("{COMPONENT} (error: {error}) - failed to open cert file: {cert_file}")
-            })
-            .map_err(|_| QuicError::CertLoadError)?,
-    );
-    let certs = rustls_pemfile::certs(&mut cert_chain_reader)
-        .map(|x| CertificateDer::from(x.unwrap().to_vec()))
-        .collect();
-    let mut key_reader = BufReader::new(
-        File::open(key_file)
-            .with_error_context(|error| {
-                format!("{COMPONENT} (error: {error}) - failed to open key file: {key_file}")
-            })
-            .map_err(|_| QuicError::CertLoadError)?,
-    );
-    let mut keys = rustls_pemfile::rsa_private_keys(&mut key_reader)
-        .filter(|key| key.is_ok())
-        .map(|key| PrivateKeyDer::try_from(key.unwrap().secret_pkcs1_der().to_vec()))
-        .collect::<Result<Vec<_>, _>>()
-        .with_error_context(|error| {
-            format!("{COMPONENT} (error: {error}) - failed to parse private key")
-        })
-        .map_err(|_| QuicError::CertLoadError)?;
-    let key = keys.remove(0);
-    Ok((certs, key))
-}
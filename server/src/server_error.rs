@@ -22,7 +22,7 @@ use std::array::TryFromSliceError;
 use tokio::io;
 
 error_set!(
-    ServerError = ConfigError || ArchiverError || ConnectionError || LogError || CompatError || QuicError;
+    ServerError = ConfigError || ArchiverError || AuthenticatorError || ConnectionError || LogError || CompatError || QuicError || PreflightError;
 
     IoError = {
         #[display("IO error")]
@@ -50,6 +50,9 @@ error_set!(
     };
 
     ArchiverError = {
+        #[display("Missing {} archiver configuration", kind)]
+        MissingArchiverConfig { kind: String },
+
         #[display("File to archive not found: {}", file_path)]
         FileToArchiveNotFound { file_path: String },
 
@@ -59,10 +62,42 @@ error_set!(
         #[display("Invalid S3 credentials")]
         InvalidS3Credentials,
 
+        #[display("Cannot initialize GCS archiver")]
+        CannotInitializeGcsArchiver,
+
+        #[display("Invalid GCS credentials")]
+        InvalidGcsCredentials,
+
+        #[display("Cannot initialize Azure archiver")]
+        CannotInitializeAzureArchiver,
+
+        #[display("Invalid Azure credentials")]
+        InvalidAzureCredentials,
+
         #[display("Cannot archive file: {}", file_path)]
         CannotArchiveFile { file_path: String },
+
+        #[display("Archived file not found: {}", file_path)]
+        ArchivedFileNotFound { file_path: String },
+
+        #[display("Cannot fetch archived file: {}", file_path)]
+        CannotFetchFile { file_path: String },
     } || IoError;
 
+    AuthenticatorError = {
+        #[display("Invalid authenticator configuration")]
+        InvalidAuthenticatorConfiguration,
+
+        #[display("Cannot load authenticator library: {}", path)]
+        CannotLoadAuthenticatorLibrary { path: String },
+
+        #[display("Authenticator request failed: {}", reason)]
+        AuthenticatorRequestFailed { reason: String },
+
+        #[display("Invalid authenticator response")]
+        InvalidAuthenticatorResponse,
+    };
+
     ConnectionError = {
         #[display("Connection error")]
         QuicConnectionError(QuicConnectionError),
@@ -79,6 +114,11 @@ error_set!(
         FileReloadFailure,
     };
 
+    PreflightError = {
+        #[display("Preflight checks failed: {}", count)]
+        PreflightChecksFailed { count: usize },
+    };
+
     CompatError = {
         #[display("Index migration error")]
         IndexMigrationError,
@@ -17,84 +17,135 @@
  */
 
 use crate::state::models::{
-    CreateConsumerGroupWithId, CreatePersonalAccessTokenWithHash, CreateStreamWithId,
-    CreateTopicWithId, CreateUserWithId,
+    CreateConsumerGroupWithId, CreateNamespaceWithId, CreatePersonalAccessTokenWithHash,
+    CreateStreamWithId, CreateTopicWithId, CreateUserWithId, MarkTopicForDeletionWithDeadline,
+    RotatePersonalAccessTokenWithHash,
 };
 use bytes::{Buf, BufMut, Bytes, BytesMut};
 use iggy::bytes_serializable::BytesSerializable;
 use iggy::command::{
-    Command, CHANGE_PASSWORD_CODE, CREATE_CONSUMER_GROUP_CODE, CREATE_PARTITIONS_CODE,
-    CREATE_PERSONAL_ACCESS_TOKEN_CODE, CREATE_STREAM_CODE, CREATE_TOPIC_CODE, CREATE_USER_CODE,
-    DELETE_CONSUMER_GROUP_CODE, DELETE_PARTITIONS_CODE, DELETE_PERSONAL_ACCESS_TOKEN_CODE,
-    DELETE_STREAM_CODE, DELETE_TOPIC_CODE, DELETE_USER_CODE, PURGE_STREAM_CODE, PURGE_TOPIC_CODE,
-    UPDATE_PERMISSIONS_CODE, UPDATE_STREAM_CODE, UPDATE_TOPIC_CODE, UPDATE_USER_CODE,
+    Command, CHANGE_PASSWORD_CODE, CREATE_CONSUMER_GROUP_CODE, CREATE_NAMESPACE_CODE,
+    CREATE_PARTITIONS_CODE, CREATE_PERSONAL_ACCESS_TOKEN_CODE, CREATE_STREAM_CODE,
+    CREATE_TOPIC_CODE, CREATE_USER_CODE, DELETE_CONSUMER_GROUP_CODE, DELETE_NAMESPACE_CODE,
+    DELETE_PARTITIONS_CODE, DELETE_PERSONAL_ACCESS_TOKEN_CODE, DELETE_STREAM_CODE,
+    DELETE_TOPIC_CODE, DELETE_USER_CODE, MARK_TOPIC_FOR_DELETION_CODE, PURGE_STREAM_CODE,
+    PURGE_TOPIC_CODE, ROTATE_PERSONAL_ACCESS_TOKEN_CODE, UPDATE_CONSUMER_GROUP_DEAD_LETTER_CODE,
+    UPDATE_CONSUMER_GROUP_VISIBILITY_TIMEOUT_CODE, UPDATE_NAMESPACE_CODE, UPDATE_PERMISSIONS_CODE,
+    UPDATE_STREAM_CODE, UPDATE_STREAM_METADATA_CODE, UPDATE_STREAM_QUOTA_CODE, UPDATE_TOPIC_CODE,
+    UPDATE_TOPIC_CONFIG_CODE, UPDATE_TOPIC_EXPIRY_WATCHER_CODE, UPDATE_TOPIC_METADATA_CODE,
+    UPDATE_TOPIC_PRODUCERS_CODE, UPDATE_USER_CODE, UPDATE_USER_QUOTAS_CODE,
 };
 use iggy::consumer_groups::delete_consumer_group::DeleteConsumerGroup;
+use iggy::consumer_groups::update_consumer_group_dead_letter::UpdateConsumerGroupDeadLetter;
+use iggy::consumer_groups::update_consumer_group_visibility_timeout::UpdateConsumerGroupVisibilityTimeout;
 use iggy::error::IggyError;
+use iggy::namespaces::delete_namespace::DeleteNamespace;
+use iggy::namespaces::update_namespace::UpdateNamespace;
 use iggy::partitions::create_partitions::CreatePartitions;
 use iggy::partitions::delete_partitions::DeletePartitions;
 use iggy::personal_access_tokens::delete_personal_access_token::DeletePersonalAccessToken;
 use iggy::streams::delete_stream::DeleteStream;
 use iggy::streams::purge_stream::PurgeStream;
 use iggy::streams::update_stream::UpdateStream;
+use iggy::streams::update_stream_metadata::UpdateStreamMetadata;
+use iggy::streams::update_stream_quota::UpdateStreamQuota;
 use iggy::topics::delete_topic::DeleteTopic;
 use iggy::topics::purge_topic::PurgeTopic;
 use iggy::topics::update_topic::UpdateTopic;
+use iggy::topics::update_topic_config::UpdateTopicConfig;
+use iggy::topics::update_topic_expiry_watcher::UpdateTopicExpiryWatcher;
+use iggy::topics::update_topic_metadata::UpdateTopicMetadata;
+use iggy::topics::update_topic_producers::UpdateTopicProducers;
 use iggy::users::change_password::ChangePassword;
 use iggy::users::delete_user::DeleteUser;
 use iggy::users::update_permissions::UpdatePermissions;
 use iggy::users::update_user::UpdateUser;
+use iggy::users::update_user_quotas::UpdateUserQuotas;
 use std::fmt::{Display, Formatter};
 
 #[derive(Debug, PartialEq)]
 pub enum EntryCommand {
+    CreateNamespace(CreateNamespaceWithId),
+    UpdateNamespace(UpdateNamespace),
+    DeleteNamespace(DeleteNamespace),
     CreateStream(CreateStreamWithId),
     UpdateStream(UpdateStream),
     DeleteStream(DeleteStream),
     PurgeStream(PurgeStream),
+    UpdateStreamMetadata(UpdateStreamMetadata),
+    UpdateStreamQuota(UpdateStreamQuota),
     CreateTopic(CreateTopicWithId),
     UpdateTopic(UpdateTopic),
     DeleteTopic(DeleteTopic),
     PurgeTopic(PurgeTopic),
+    UpdateTopicMetadata(UpdateTopicMetadata),
+    UpdateTopicProducers(UpdateTopicProducers),
+    UpdateTopicConfig(UpdateTopicConfig),
+    UpdateTopicExpiryWatcher(UpdateTopicExpiryWatcher),
+    MarkTopicForDeletion(MarkTopicForDeletionWithDeadline),
     CreatePartitions(CreatePartitions),
     DeletePartitions(DeletePartitions),
     CreateConsumerGroup(CreateConsumerGroupWithId),
     DeleteConsumerGroup(DeleteConsumerGroup),
+    UpdateConsumerGroupDeadLetter(UpdateConsumerGroupDeadLetter),
+    UpdateConsumerGroupVisibilityTimeout(UpdateConsumerGroupVisibilityTimeout),
     CreateUser(CreateUserWithId),
     UpdateUser(UpdateUser),
     DeleteUser(DeleteUser),
     ChangePassword(ChangePassword),
     UpdatePermissions(UpdatePermissions),
+    UpdateUserQuotas(UpdateUserQuotas),
     CreatePersonalAccessToken(CreatePersonalAccessTokenWithHash),
     DeletePersonalAccessToken(DeletePersonalAccessToken),
+    RotatePersonalAccessToken(RotatePersonalAccessTokenWithHash),
 }
 
 impl BytesSerializable for EntryCommand {
     fn to_bytes(&self) -> Bytes {
         let (code, command) = match self {
+            EntryCommand::CreateNamespace(command) => (command.code(), command.to_bytes()),
+            EntryCommand::UpdateNamespace(command) => (command.code(), command.to_bytes()),
+            EntryCommand::DeleteNamespace(command) => (command.code(), command.to_bytes()),
             EntryCommand::CreateStream(command) => (command.code(), command.to_bytes()),
             EntryCommand::UpdateStream(command) => (command.code(), command.to_bytes()),
             EntryCommand::DeleteStream(command) => (command.code(), command.to_bytes()),
             EntryCommand::PurgeStream(command) => (command.code(), command.to_bytes()),
+            EntryCommand::UpdateStreamMetadata(command) => (command.code(), command.to_bytes()),
+            EntryCommand::UpdateStreamQuota(command) => (command.code(), command.to_bytes()),
             EntryCommand::CreateTopic(command) => (command.code(), command.to_bytes()),
             EntryCommand::UpdateTopic(command) => (command.code(), command.to_bytes()),
             EntryCommand::DeleteTopic(command) => (command.code(), command.to_bytes()),
             EntryCommand::PurgeTopic(command) => (command.code(), command.to_bytes()),
+            EntryCommand::UpdateTopicMetadata(command) => (command.code(), command.to_bytes()),
+            EntryCommand::UpdateTopicProducers(command) => (command.code(), command.to_bytes()),
+            EntryCommand::UpdateTopicConfig(command) => (command.code(), command.to_bytes()),
+            EntryCommand::UpdateTopicExpiryWatcher(command) => (command.code(), command.to_bytes()),
+            EntryCommand::MarkTopicForDeletion(command) => (command.code(), command.to_bytes()),
             EntryCommand::CreatePartitions(command) => (command.code(), command.to_bytes()),
             EntryCommand::DeletePartitions(command) => (command.code(), command.to_bytes()),
             EntryCommand::CreateConsumerGroup(command) => (command.code(), command.to_bytes()),
             EntryCommand::DeleteConsumerGroup(command) => (command.code(), command.to_bytes()),
+            EntryCommand::UpdateConsumerGroupDeadLetter(command) => {
+                (command.code(), command.to_bytes())
+            }
+            EntryCommand::UpdateConsumerGroupVisibilityTimeout(command) => {
+                (command.code(), command.to_bytes())
+            }
             EntryCommand::CreateUser(command) => (command.code(), command.to_bytes()),
             EntryCommand::UpdateUser(command) => (command.code(), command.to_bytes()),
             EntryCommand::DeleteUser(command) => (command.code(), command.to_bytes()),
             EntryCommand::ChangePassword(command) => (command.code(), command.to_bytes()),
             EntryCommand::UpdatePermissions(command) => (command.code(), command.to_bytes()),
+            EntryCommand::UpdateUserQuotas(command) => (command.code(), command.to_bytes()),
             EntryCommand::CreatePersonalAccessToken(command) => {
                 (command.code(), command.to_bytes())
             }
             EntryCommand::DeletePersonalAccessToken(command) => {
                 (command.code(), command.to_bytes())
             }
+            EntryCommand::RotatePersonalAccessToken(command) => {
+                (command.code(), command.to_bytes())
+            }
         };
 
         let mut bytes = BytesMut::with_capacity(4 + 4 + command.len());
@@ -112,6 +163,15 @@ impl BytesSerializable for EntryCommand {
         let length = bytes.slice(4..8).get_u32_le();
         let payload = bytes.slice(8..8 + length as usize);
         match code {
+            CREATE_NAMESPACE_CODE => Ok(EntryCommand::CreateNamespace(
+                CreateNamespaceWithId::from_bytes(payload)?,
+            )),
+            UPDATE_NAMESPACE_CODE => Ok(EntryCommand::UpdateNamespace(
+                UpdateNamespace::from_bytes(payload)?,
+            )),
+            DELETE_NAMESPACE_CODE => Ok(EntryCommand::DeleteNamespace(
+                DeleteNamespace::from_bytes(payload)?,
+            )),
             CREATE_STREAM_CODE => Ok(EntryCommand::CreateStream(CreateStreamWithId::from_bytes(
                 payload,
             )?)),
@@ -122,12 +182,33 @@ impl BytesSerializable for EntryCommand {
                 payload,
             )?)),
             PURGE_STREAM_CODE => Ok(EntryCommand::PurgeStream(PurgeStream::from_bytes(payload)?)),
+            UPDATE_STREAM_METADATA_CODE => Ok(EntryCommand::UpdateStreamMetadata(
+                UpdateStreamMetadata::from_bytes(payload)?,
+            )),
+            UPDATE_STREAM_QUOTA_CODE => Ok(EntryCommand::UpdateStreamQuota(
+                UpdateStreamQuota::from_bytes(payload)?,
+            )),
             CREATE_TOPIC_CODE => Ok(EntryCommand::CreateTopic(CreateTopicWithId::from_bytes(
                 payload,
             )?)),
             UPDATE_TOPIC_CODE => Ok(EntryCommand::UpdateTopic(UpdateTopic::from_bytes(payload)?)),
             DELETE_TOPIC_CODE => Ok(EntryCommand::DeleteTopic(DeleteTopic::from_bytes(payload)?)),
             PURGE_TOPIC_CODE => Ok(EntryCommand::PurgeTopic(PurgeTopic::from_bytes(payload)?)),
+            UPDATE_TOPIC_METADATA_CODE => Ok(EntryCommand::UpdateTopicMetadata(
+                UpdateTopicMetadata::from_bytes(payload)?,
+            )),
+            UPDATE_TOPIC_PRODUCERS_CODE => Ok(EntryCommand::UpdateTopicProducers(
+                UpdateTopicProducers::from_bytes(payload)?,
+            )),
+            UPDATE_TOPIC_CONFIG_CODE => Ok(EntryCommand::UpdateTopicConfig(
+                UpdateTopicConfig::from_bytes(payload)?,
+            )),
+            UPDATE_TOPIC_EXPIRY_WATCHER_CODE => Ok(EntryCommand::UpdateTopicExpiryWatcher(
+                UpdateTopicExpiryWatcher::from_bytes(payload)?,
+            )),
+            MARK_TOPIC_FOR_DELETION_CODE => Ok(EntryCommand::MarkTopicForDeletion(
+                MarkTopicForDeletionWithDeadline::from_bytes(payload)?,
+            )),
             CREATE_PARTITIONS_CODE => Ok(EntryCommand::CreatePartitions(
                 CreatePartitions::from_bytes(payload)?,
             )),
@@ -140,6 +221,16 @@ impl BytesSerializable for EntryCommand {
             DELETE_CONSUMER_GROUP_CODE => Ok(EntryCommand::DeleteConsumerGroup(
                 DeleteConsumerGroup::from_bytes(payload)?,
             )),
+            UPDATE_CONSUMER_GROUP_DEAD_LETTER_CODE => {
+                Ok(EntryCommand::UpdateConsumerGroupDeadLetter(
+                    UpdateConsumerGroupDeadLetter::from_bytes(payload)?,
+                ))
+            }
+            UPDATE_CONSUMER_GROUP_VISIBILITY_TIMEOUT_CODE => {
+                Ok(EntryCommand::UpdateConsumerGroupVisibilityTimeout(
+                    UpdateConsumerGroupVisibilityTimeout::from_bytes(payload)?,
+                ))
+            }
             CREATE_USER_CODE => Ok(EntryCommand::CreateUser(CreateUserWithId::from_bytes(
                 payload,
             )?)),
@@ -151,12 +242,18 @@ impl BytesSerializable for EntryCommand {
             UPDATE_PERMISSIONS_CODE => Ok(EntryCommand::UpdatePermissions(
                 UpdatePermissions::from_bytes(payload)?,
             )),
+            UPDATE_USER_QUOTAS_CODE => Ok(EntryCommand::UpdateUserQuotas(
+                UpdateUserQuotas::from_bytes(payload)?,
+            )),
             CREATE_PERSONAL_ACCESS_TOKEN_CODE => Ok(EntryCommand::CreatePersonalAccessToken(
                 CreatePersonalAccessTokenWithHash::from_bytes(payload)?,
             )),
             DELETE_PERSONAL_ACCESS_TOKEN_CODE => Ok(EntryCommand::DeletePersonalAccessToken(
                 DeletePersonalAccessToken::from_bytes(payload)?,
             )),
+            ROTATE_PERSONAL_ACCESS_TOKEN_CODE => Ok(EntryCommand::RotatePersonalAccessToken(
+                RotatePersonalAccessTokenWithHash::from_bytes(payload)?,
+            )),
             _ => Err(IggyError::InvalidCommand),
         }
     }
@@ -165,14 +262,38 @@ impl BytesSerializable for EntryCommand {
 impl Display for EntryCommand {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
+            EntryCommand::CreateNamespace(command) => write!(f, "CreateNamespace({})", command),
+            EntryCommand::UpdateNamespace(command) => write!(f, "UpdateNamespace({})", command),
+            EntryCommand::DeleteNamespace(command) => write!(f, "DeleteNamespace({})", command),
             EntryCommand::CreateStream(command) => write!(f, "CreateStream({})", command),
             EntryCommand::UpdateStream(command) => write!(f, "UpdateStream({})", command),
             EntryCommand::DeleteStream(command) => write!(f, "DeleteStream({})", command),
             EntryCommand::PurgeStream(command) => write!(f, "PurgeStream({})", command),
+            EntryCommand::UpdateStreamMetadata(command) => {
+                write!(f, "UpdateStreamMetadata({})", command)
+            }
+            EntryCommand::UpdateStreamQuota(command) => {
+                write!(f, "UpdateStreamQuota({})", command)
+            }
             EntryCommand::CreateTopic(command) => write!(f, "CreateTopic({})", command),
             EntryCommand::UpdateTopic(command) => write!(f, "UpdateTopic({})", command),
             EntryCommand::DeleteTopic(command) => write!(f, "DeleteTopic({})", command),
             EntryCommand::PurgeTopic(command) => write!(f, "PurgeTopic({})", command),
+            EntryCommand::UpdateTopicMetadata(command) => {
+                write!(f, "UpdateTopicMetadata({})", command)
+            }
+            EntryCommand::UpdateTopicProducers(command) => {
+                write!(f, "UpdateTopicProducers({})", command)
+            }
+            EntryCommand::UpdateTopicConfig(command) => {
+                write!(f, "UpdateTopicConfig({})", command)
+            }
+            EntryCommand::UpdateTopicExpiryWatcher(command) => {
+                write!(f, "UpdateTopicExpiryWatcher({})", command)
+            }
+            EntryCommand::MarkTopicForDeletion(command) => {
+                write!(f, "MarkTopicForDeletion({})", command)
+            }
             EntryCommand::CreatePartitions(command) => write!(f, "CreatePartitions({})", command),
             EntryCommand::DeletePartitions(command) => write!(f, "DeletePartitions({})", command),
             EntryCommand::CreateConsumerGroup(command) => {
@@ -181,17 +302,27 @@ impl Display for EntryCommand {
             EntryCommand::DeleteConsumerGroup(command) => {
                 write!(f, "DeleteConsumerGroup({})", command)
             }
+            EntryCommand::UpdateConsumerGroupDeadLetter(command) => {
+                write!(f, "UpdateConsumerGroupDeadLetter({})", command)
+            }
+            EntryCommand::UpdateConsumerGroupVisibilityTimeout(command) => {
+                write!(f, "UpdateConsumerGroupVisibilityTimeout({})", command)
+            }
             EntryCommand::CreateUser(command) => write!(f, "CreateUser({})", command),
             EntryCommand::UpdateUser(command) => write!(f, "UpdateUser({})", command),
             EntryCommand::DeleteUser(command) => write!(f, "DeleteUser({})", command),
             EntryCommand::ChangePassword(command) => write!(f, "ChangePassword({})", command),
             EntryCommand::UpdatePermissions(command) => write!(f, "UpdatePermissions({})", command),
+            EntryCommand::UpdateUserQuotas(command) => write!(f, "UpdateUserQuotas({})", command),
             EntryCommand::CreatePersonalAccessToken(command) => {
                 write!(f, "CreatePersonalAccessToken({})", command)
             }
             EntryCommand::DeletePersonalAccessToken(command) => {
                 write!(f, "DeletePersonalAccessToken({})", command)
             }
+            EntryCommand::RotatePersonalAccessToken(command) => {
+                write!(f, "RotatePersonalAccessToken({})", command)
+            }
         }
     }
 }
@@ -80,6 +80,92 @@ impl FileState {
     pub fn term(&self) -> u64 {
         self.term.load(Ordering::SeqCst)
     }
+
+    /// Sets the term and the leader written with the next applied entries, once they were replicated.
+    pub fn set_term(&self, term: u64, leader_id: u32) {
+        self.term.store(term, Ordering::SeqCst);
+        self.current_leader.store(leader_id, Ordering::SeqCst);
+    }
+
+    /// Appends the entry with the additional context, e.g. the index of the entry in the replicated log.
+    pub async fn append(
+        &self,
+        user_id: u32,
+        command: EntryCommand,
+        context: Bytes,
+    ) -> Result<(), IggyError> {
+        debug!("Applying state entry with command: {command}, user ID: {user_id}");
+        let timestamp = IggyTimestamp::now();
+        let index = if self.entries_count.load(Ordering::SeqCst) == 0 {
+            0
+        } else {
+            self.current_index.fetch_add(1, Ordering::SeqCst) + 1
+        };
+        let term = self.term.load(Ordering::SeqCst);
+        let current_leader = self.current_leader.load(Ordering::SeqCst);
+        let version = self.version;
+        let flags = 0;
+        let mut command = command.to_bytes();
+        let checksum = StateEntry::calculate_checksum(
+            index,
+            term,
+            current_leader,
+            version,
+            flags,
+            timestamp,
+            user_id,
+            &context,
+            &command,
+        );
+
+        if let Some(encryptor) = &self.encryptor {
+            debug!("Encrypting state entry command with index: {index}");
+            let command_code = command.slice(0..4).get_u32_le();
+            let mut command_length = command.slice(4..8).get_u32_le() as usize;
+            let command_payload = command.slice(8..8 + command_length);
+            let encrypted_command_payload = encryptor
+                .encrypt(&command_payload)
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to encrypt state entry command, index: {}",
+                        index
+                    )
+                })?;
+            command_length = encrypted_command_payload.len();
+            let mut command_bytes = BytesMut::with_capacity(4 + 4 + command_length);
+            command_bytes.put_u32_le(command_code);
+            command_bytes.put_u32_le(command_length as u32);
+            command_bytes.extend(encrypted_command_payload);
+            command = command_bytes.freeze();
+        }
+
+        let entry = StateEntry::new(
+            index,
+            term,
+            current_leader,
+            version,
+            flags,
+            timestamp,
+            user_id,
+            checksum,
+            context,
+            command,
+        );
+        let bytes = entry.to_bytes();
+        self.entries_count.fetch_add(1, Ordering::SeqCst);
+        self.persister
+            .append(&self.path, &bytes)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to append state entry data to file, path: {}, data size: {}",
+                    self.path,
+                    bytes.len()
+                )
+            })?;
+        debug!("Applied state entry: {entry}");
+        Ok(())
+    }
 }
 
 impl State for FileState {
@@ -306,77 +392,6 @@ impl State for FileState {
     }
 
     async fn apply(&self, user_id: u32, command: EntryCommand) -> Result<(), IggyError> {
-        debug!("Applying state entry with command: {command}, user ID: {user_id}");
-        let timestamp = IggyTimestamp::now();
-        let index = if self.entries_count.load(Ordering::SeqCst) == 0 {
-            0
-        } else {
-            self.current_index.fetch_add(1, Ordering::SeqCst) + 1
-        };
-        let term = self.term.load(Ordering::SeqCst);
-        let current_leader = self.current_leader.load(Ordering::SeqCst);
-        let version = self.version;
-        let flags = 0;
-        let context = Bytes::new();
-        let mut command = command.to_bytes();
-        let checksum = StateEntry::calculate_checksum(
-            index,
-            term,
-            current_leader,
-            version,
-            flags,
-            timestamp,
-            user_id,
-            &context,
-            &command,
-        );
-
-        if let Some(encryptor) = &self.encryptor {
-            debug!("Encrypting state entry command with index: {index}");
-            let command_code = command.slice(0..4).get_u32_le();
-            let mut command_length = command.slice(4..8).get_u32_le() as usize;
-            let command_payload = command.slice(8..8 + command_length);
-            let encrypted_command_payload = encryptor
-                .encrypt(&command_payload)
-                .with_error_context(|error| {
-                    format!(
-                        "{COMPONENT} (error: {error}) - failed to encrypt state entry command, index: {}",
-                        index
-                    )
-                })?;
-            command_length = encrypted_command_payload.len();
-            let mut command_bytes = BytesMut::with_capacity(4 + 4 + command_length);
-            command_bytes.put_u32_le(command_code);
-            command_bytes.put_u32_le(command_length as u32);
-            command_bytes.extend(encrypted_command_payload);
-            command = command_bytes.freeze();
-        }
-
-        let entry = StateEntry::new(
-            index,
-            term,
-            current_leader,
-            version,
-            flags,
-            timestamp,
-            user_id,
-            checksum,
-            context,
-            command,
-        );
-        let bytes = entry.to_bytes();
-        self.entries_count.fetch_add(1, Ordering::SeqCst);
-        self.persister
-            .append(&self.path, &bytes)
-            .await
-            .with_error_context(|error| {
-                format!(
-                    "{COMPONENT} (error: {error}) - failed to append state entry data to file, path: {}, data size: {}",
-                    self.path,
-                    bytes.len()
-                )
-            })?;
-        debug!("Applied state entry: {entry}");
-        Ok(())
+        self.append(user_id, command, Bytes::new()).await
     }
 }
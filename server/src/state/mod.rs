@@ -28,6 +28,7 @@ pub mod command;
 pub mod entry;
 pub mod file;
 pub mod models;
+pub mod replicated;
 pub mod system;
 
 pub const COMPONENT: &str = "STATE";
@@ -35,6 +36,7 @@ pub const COMPONENT: &str = "STATE";
 #[derive(Debug)]
 pub enum StateKind {
     File(file::FileState),
+    Replicated(replicated::ReplicatedState),
     #[cfg(test)]
     Mock(MockState),
 }
@@ -54,6 +56,7 @@ impl StateKind {
     pub async fn init(&self) -> Result<Vec<StateEntry>, IggyError> {
         match self {
             Self::File(s) => s.init().await,
+            Self::Replicated(s) => s.init().await,
             #[cfg(test)]
             Self::Mock(s) => s.init().await,
         }
@@ -62,6 +65,7 @@ impl StateKind {
     pub async fn load_entries(&self) -> Result<Vec<StateEntry>, IggyError> {
         match self {
             Self::File(s) => s.load_entries().await,
+            Self::Replicated(s) => s.load_entries().await,
             #[cfg(test)]
             Self::Mock(s) => s.load_entries().await,
         }
@@ -70,6 +74,7 @@ impl StateKind {
     pub async fn apply(&self, user_id: u32, command: EntryCommand) -> Result<(), IggyError> {
         match self {
             Self::File(s) => s.apply(user_id, command).await,
+            Self::Replicated(s) => s.apply(user_id, command).await,
             #[cfg(test)]
             Self::Mock(s) => s.apply(user_id, command).await,
         }
@@ -23,10 +23,14 @@ use iggy::bytes_serializable::BytesSerializable;
 use iggy::command::Command;
 use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use iggy::error::IggyError;
+use iggy::namespaces::create_namespace::CreateNamespace;
 use iggy::personal_access_tokens::create_personal_access_token::CreatePersonalAccessToken;
+use iggy::personal_access_tokens::rotate_personal_access_token::RotatePersonalAccessToken;
 use iggy::streams::create_stream::CreateStream;
 use iggy::topics::create_topic::CreateTopic;
+use iggy::topics::mark_topic_for_deletion::MarkTopicForDeletion;
 use iggy::users::create_user::CreateUser;
+use iggy::utils::timestamp::IggyTimestamp;
 use iggy::validatable::Validatable;
 use serde::{Deserialize, Serialize};
 use std::fmt;
@@ -39,12 +43,24 @@ pub struct CreateStreamWithId {
     pub command: CreateStream,
 }
 
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct CreateNamespaceWithId {
+    pub namespace_id: u32,
+    pub command: CreateNamespace,
+}
+
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct CreateTopicWithId {
     pub topic_id: u32,
     pub command: CreateTopic,
 }
 
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct MarkTopicForDeletionWithDeadline {
+    pub delete_at: IggyTimestamp,
+    pub command: MarkTopicForDeletion,
+}
+
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct CreateConsumerGroupWithId {
     pub group_id: u32,
@@ -63,6 +79,12 @@ pub struct CreatePersonalAccessTokenWithHash {
     pub command: CreatePersonalAccessToken,
 }
 
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct RotatePersonalAccessTokenWithHash {
+    pub hash: String,
+    pub command: RotatePersonalAccessToken,
+}
+
 impl Validatable<IggyError> for CreateStreamWithId {
     fn validate(&self) -> Result<(), IggyError> {
         self.command.validate()
@@ -75,6 +97,18 @@ impl Command for CreateStreamWithId {
     }
 }
 
+impl Validatable<IggyError> for CreateNamespaceWithId {
+    fn validate(&self) -> Result<(), IggyError> {
+        self.command.validate()
+    }
+}
+
+impl Command for CreateNamespaceWithId {
+    fn code(&self) -> u32 {
+        self.command.code()
+    }
+}
+
 impl Validatable<IggyError> for CreateTopicWithId {
     fn validate(&self) -> Result<(), IggyError> {
         self.command.validate()
@@ -87,6 +121,18 @@ impl Command for CreateTopicWithId {
     }
 }
 
+impl Validatable<IggyError> for MarkTopicForDeletionWithDeadline {
+    fn validate(&self) -> Result<(), IggyError> {
+        self.command.validate()
+    }
+}
+
+impl Command for MarkTopicForDeletionWithDeadline {
+    fn code(&self) -> u32 {
+        self.command.code()
+    }
+}
+
 impl Validatable<IggyError> for CreateConsumerGroupWithId {
     fn validate(&self) -> Result<(), IggyError> {
         self.command.validate()
@@ -123,6 +169,18 @@ impl Command for CreatePersonalAccessTokenWithHash {
     }
 }
 
+impl Validatable<IggyError> for RotatePersonalAccessTokenWithHash {
+    fn validate(&self) -> Result<(), IggyError> {
+        self.command.validate()
+    }
+}
+
+impl Command for RotatePersonalAccessTokenWithHash {
+    fn code(&self) -> u32 {
+        self.command.code()
+    }
+}
+
 impl Display for CreateStreamWithId {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         write!(
@@ -133,6 +191,16 @@ impl Display for CreateStreamWithId {
     }
 }
 
+impl Display for CreateNamespaceWithId {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "CreateNamespaceWithId {{ command: {}, namespace ID: {} }}",
+            self.command, self.namespace_id
+        )
+    }
+}
+
 impl Display for CreateTopicWithId {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         write!(
@@ -143,6 +211,16 @@ impl Display for CreateTopicWithId {
     }
 }
 
+impl Display for MarkTopicForDeletionWithDeadline {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "MarkTopicForDeletionWithDeadline {{ command: {}, delete_at: {} }}",
+            self.command, self.delete_at
+        )
+    }
+}
+
 impl Display for CreateConsumerGroupWithId {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         write!(
@@ -173,6 +251,16 @@ impl Display for CreatePersonalAccessTokenWithHash {
     }
 }
 
+impl Display for RotatePersonalAccessTokenWithHash {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "RotatePersonalAccessTokenWithHash {{ command: {}, hash: {} }}",
+            self.command, self.hash
+        )
+    }
+}
+
 impl BytesSerializable for CreateStreamWithId {
     fn to_bytes(&self) -> Bytes {
         let mut bytes = BytesMut::new();
@@ -214,6 +302,52 @@ impl BytesSerializable for CreateStreamWithId {
     }
 }
 
+impl BytesSerializable for CreateNamespaceWithId {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::new();
+        bytes.put_u32_le(self.namespace_id);
+        let command_bytes = self.command.to_bytes();
+        bytes.put_u32_le(command_bytes.len() as u32);
+        bytes.put_slice(&command_bytes);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<Self, IggyError>
+    where
+        Self: Sized,
+    {
+        let mut position = 0;
+        let namespace_id = u32::from_le_bytes(
+            bytes[position..4]
+                .try_into()
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to parse namespace ID")
+                })
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        position += 4;
+        let command_length = u32::from_le_bytes(
+            bytes[position..position + 4]
+                .try_into()
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to parse namespace command length"
+                    )
+                })
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        position += 4;
+        let command_bytes = bytes.slice(position..position + command_length as usize);
+        let command = CreateNamespace::from_bytes(command_bytes).with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to parse namespace command")
+        })?;
+        Ok(Self {
+            namespace_id,
+            command,
+        })
+    }
+}
+
 impl BytesSerializable for CreateTopicWithId {
     fn to_bytes(&self) -> Bytes {
         let mut bytes = BytesMut::new();
@@ -255,6 +389,50 @@ impl BytesSerializable for CreateTopicWithId {
     }
 }
 
+impl BytesSerializable for MarkTopicForDeletionWithDeadline {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::new();
+        bytes.put_u64_le(self.delete_at.as_micros());
+        let command_bytes = self.command.to_bytes();
+        bytes.put_u32_le(command_bytes.len() as u32);
+        bytes.put_slice(&command_bytes);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<Self, IggyError>
+    where
+        Self: Sized,
+    {
+        let mut position = 0;
+        let delete_at = u64::from_le_bytes(
+            bytes[position..8]
+                .try_into()
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to parse topic deletion time")
+                })
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        position += 8;
+        let command_length = u32::from_le_bytes(
+            bytes[position..position + 4]
+                .try_into()
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to parse mark topic for deletion command length")
+                })
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        position += 4;
+        let command_bytes = bytes.slice(position..position + command_length as usize);
+        let command = MarkTopicForDeletion::from_bytes(command_bytes).with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to parse mark topic for deletion command")
+            })?;
+        Ok(Self {
+            delete_at: delete_at.into(),
+            command,
+        })
+    }
+}
+
 impl BytesSerializable for CreateConsumerGroupWithId {
     fn to_bytes(&self) -> Bytes {
         let mut bytes = BytesMut::new();
@@ -387,3 +565,53 @@ impl BytesSerializable for CreatePersonalAccessTokenWithHash {
         Ok(Self { hash, command })
     }
 }
+
+impl BytesSerializable for RotatePersonalAccessTokenWithHash {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::new();
+        bytes.put_u32_le(self.hash.len() as u32);
+        bytes.put_slice(self.hash.as_bytes());
+        let command_bytes = self.command.to_bytes();
+        bytes.put_u32_le(command_bytes.len() as u32);
+        bytes.put_slice(&command_bytes);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<Self, IggyError>
+    where
+        Self: Sized,
+    {
+        let mut position = 0;
+        let hash_length = u32::from_le_bytes(
+            bytes[position..4]
+                .try_into()
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to parse hash length")
+                })
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        position += 4;
+        let hash = from_utf8(&bytes[position..position + hash_length as usize])
+            .map_err(|_| IggyError::InvalidUtf8)?
+            .to_string();
+
+        position += hash_length as usize;
+        let command_length = u32::from_le_bytes(
+            bytes[position..position + 4]
+                .try_into()
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to parse personal access token command length")
+                })
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        position += 4;
+        let command_bytes = bytes.slice(position..position + command_length as usize);
+        let command =
+            RotatePersonalAccessToken::from_bytes(command_bytes).with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to parse personal access token command"
+                )
+            })?;
+        Ok(Self { hash, command })
+    }
+}
@@ -0,0 +1,54 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cluster::node::ClusterNode;
+use crate::state::command::EntryCommand;
+use crate::state::entry::StateEntry;
+use crate::state::State;
+use iggy::error::IggyError;
+use std::sync::Arc;
+
+/// The state appending the entries to the local state file only once they were committed by the cluster.
+#[derive(Debug)]
+pub struct ReplicatedState {
+    node: Arc<ClusterNode>,
+}
+
+impl ReplicatedState {
+    pub fn new(node: Arc<ClusterNode>) -> Self {
+        Self { node }
+    }
+
+    pub fn node(&self) -> &Arc<ClusterNode> {
+        &self.node
+    }
+}
+
+impl State for ReplicatedState {
+    async fn init(&self) -> Result<Vec<StateEntry>, IggyError> {
+        self.node.init().await
+    }
+
+    async fn load_entries(&self) -> Result<Vec<StateEntry>, IggyError> {
+        self.node.load_entries().await
+    }
+
+    async fn apply(&self, user_id: u32, command: EntryCommand) -> Result<(), IggyError> {
+        self.node.apply(user_id, command).await
+    }
+}
@@ -18,13 +18,27 @@
 
 use crate::state::{EntryCommand, StateEntry, COMPONENT};
 use crate::streaming::personal_access_tokens::personal_access_token::PersonalAccessToken;
+use crate::streaming::polling_consumer::PollingConsumer;
 use ahash::AHashMap;
 use error_set::ErrContext;
 use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::ConsumerKind;
+use iggy::consumer_groups::offset_recovery_policy::OffsetRecoveryPolicy;
 use iggy::error::IggyError;
 use iggy::identifier::{IdKind, Identifier};
+use iggy::messages::message_id_scheme::MessageIdScheme;
+use iggy::models::consumer_group::ConsumerGroupDeadLetter;
+use iggy::models::expiry_notification::ExpiryWatcher;
+use iggy::models::metadata::ResourceMetadata;
+use iggy::models::namespace::NamespaceQuotas;
 use iggy::models::permissions::Permissions;
+use iggy::models::personal_access_token::PersonalAccessTokenScope;
+use iggy::models::stream::StreamQuota;
 use iggy::models::user_status::UserStatus;
+use iggy::topics::cleanup_policy::CleanupPolicy;
+use iggy::topics::topic_config::TopicConfig;
+use iggy::users::user_quotas::UserQuotas;
+use iggy::utils::duration::IggyDuration;
 use iggy::utils::expiry::IggyExpiry;
 use iggy::utils::timestamp::IggyTimestamp;
 use iggy::utils::topic_size::MaxTopicSize;
@@ -33,15 +47,27 @@ use tracing::{debug, info};
 
 #[derive(Debug)]
 pub struct SystemState {
+    pub namespaces: AHashMap<u32, NamespaceState>,
     pub streams: AHashMap<u32, StreamState>,
     pub users: AHashMap<u32, UserState>,
 }
 
+#[derive(Debug)]
+pub struct NamespaceState {
+    pub id: u32,
+    pub name: String,
+    pub created_at: IggyTimestamp,
+    pub quotas: NamespaceQuotas,
+}
+
 #[derive(Debug)]
 pub struct StreamState {
     pub id: u32,
     pub name: String,
     pub created_at: IggyTimestamp,
+    pub metadata: ResourceMetadata,
+    pub quota: StreamQuota,
+    pub namespace_id: Option<u32>,
     pub topics: AHashMap<u32, TopicState>,
 }
 
@@ -54,8 +80,16 @@ pub struct TopicState {
     pub compression_algorithm: CompressionAlgorithm,
     pub message_expiry: IggyExpiry,
     pub max_topic_size: MaxTopicSize,
+    pub max_segments: u32,
+    pub config: TopicConfig,
     pub replication_factor: Option<u8>,
     pub created_at: IggyTimestamp,
+    pub metadata: ResourceMetadata,
+    pub message_id_scheme: MessageIdScheme,
+    pub cleanup_policy: CleanupPolicy,
+    pub allowed_producers: Vec<u32>,
+    pub expiry_watchers: Vec<ExpiryWatcher>,
+    pub delete_at: Option<IggyTimestamp>,
 }
 
 #[derive(Debug)]
@@ -69,6 +103,8 @@ pub struct PersonalAccessTokenState {
     pub name: String,
     pub token_hash: String,
     pub expiry_at: Option<IggyTimestamp>,
+    pub scope: PersonalAccessTokenScope,
+    pub issued_at: IggyTimestamp,
 }
 
 #[derive(Debug)]
@@ -80,23 +116,54 @@ pub struct UserState {
     pub created_at: IggyTimestamp,
     pub permissions: Option<Permissions>,
     pub personal_access_tokens: AHashMap<String, PersonalAccessTokenState>,
+    pub quotas: UserQuotas,
+    pub namespace_id: Option<u32>,
 }
 
 #[derive(Debug)]
 pub struct ConsumerGroupState {
     pub id: u32,
     pub name: String,
+    pub offset_recovery: OffsetRecoveryPolicy,
+    pub dead_letter: Option<ConsumerGroupDeadLetter>,
+    pub visibility_timeout: Option<IggyDuration>,
 }
 
 impl SystemState {
     pub async fn init(entries: Vec<StateEntry>) -> Result<Self, IggyError> {
+        let mut namespaces = AHashMap::new();
         let mut streams = AHashMap::new();
         let mut users = AHashMap::new();
         for entry in entries {
             debug!("Processing state entry: {entry}",);
+            // The stream names are resolved in the namespace of the user who applied the command.
+            let user_namespace_id = users
+                .get(&entry.user_id)
+                .and_then(|user: &UserState| user.namespace_id);
             match entry.command().with_error_context(|error| {
                 format!("{COMPONENT} (error: {error}) - failed to retrieve state entry command: {entry}")
             })? {
+                EntryCommand::CreateNamespace(command) => {
+                    info!("Creating namespace: {command:?}");
+                    let namespace = NamespaceState {
+                        id: command.namespace_id,
+                        name: command.command.name,
+                        created_at: entry.timestamp,
+                        quotas: command.command.quotas,
+                    };
+                    namespaces.insert(namespace.id, namespace);
+                }
+                EntryCommand::UpdateNamespace(command) => {
+                    let namespace_id = find_namespace_id(&namespaces, &command.namespace_id);
+                    let namespace = namespaces.get_mut(&namespace_id).unwrap_or_else(|| {
+                        panic!("{}", format!("Namespace: {namespace_id} not found"))
+                    });
+                    namespace.quotas = command.quotas;
+                }
+                EntryCommand::DeleteNamespace(command) => {
+                    let namespace_id = find_namespace_id(&namespaces, &command.namespace_id);
+                    namespaces.remove(&namespace_id);
+                }
                 EntryCommand::CreateStream(command) => {
                     info!("Creating stream: {command:?}");
                     let stream_id = command.stream_id;
@@ -106,29 +173,46 @@ impl SystemState {
                         name: command.name.clone(),
                         topics: AHashMap::new(),
                         created_at: entry.timestamp,
+                        metadata: command.metadata,
+                        quota: StreamQuota::default(),
+                        namespace_id: command.namespace_id,
                     };
                     streams.insert(stream.id, stream);
                 }
                 EntryCommand::UpdateStream(command) => {
-                    let stream_id = find_stream_id(&streams, &command.stream_id);
+                    let stream_id = find_stream_id(&streams, user_namespace_id, &command.stream_id);
                     let stream = streams
                         .get_mut(&stream_id)
                         .unwrap_or_else(|| panic!("{}", format!("Stream: {stream_id} not found")));
                     stream.name = command.name;
                 }
+                EntryCommand::UpdateStreamMetadata(command) => {
+                    let stream_id = find_stream_id(&streams, user_namespace_id, &command.stream_id);
+                    let stream = streams
+                        .get_mut(&stream_id)
+                        .unwrap_or_else(|| panic!("{}", format!("Stream: {stream_id} not found")));
+                    stream.metadata = command.metadata;
+                }
+                EntryCommand::UpdateStreamQuota(command) => {
+                    let stream_id = find_stream_id(&streams, user_namespace_id, &command.stream_id);
+                    let stream = streams
+                        .get_mut(&stream_id)
+                        .unwrap_or_else(|| panic!("{}", format!("Stream: {stream_id} not found")));
+                    stream.quota = command.quota;
+                }
                 EntryCommand::DeleteStream(command) => {
-                    let stream_id = find_stream_id(&streams, &command.stream_id);
+                    let stream_id = find_stream_id(&streams, user_namespace_id, &command.stream_id);
                     streams.remove(&stream_id);
                 }
                 EntryCommand::PurgeStream(command) => {
-                    let stream_id = find_stream_id(&streams, &command.stream_id);
+                    let stream_id = find_stream_id(&streams, user_namespace_id, &command.stream_id);
                     streams
                         .get(&stream_id)
                         .unwrap_or_else(|| panic!("{}", format!("Stream: {stream_id} not found")));
                     // It only affects the segments which are not part of the state
                 }
                 EntryCommand::CreateTopic(command) => {
-                    let stream_id = find_stream_id(&streams, &command.command.stream_id);
+                    let stream_id = find_stream_id(&streams, user_namespace_id, &command.command.stream_id);
                     let stream = streams
                         .get_mut(&stream_id)
                         .unwrap_or_else(|| panic!("{}", format!("Stream: {stream_id} not found")));
@@ -141,8 +225,19 @@ impl SystemState {
                         compression_algorithm: command.compression_algorithm,
                         message_expiry: command.message_expiry,
                         max_topic_size: command.max_topic_size,
+                        max_segments: 0,
+                        config: TopicConfig::default().with_topic_settings(
+                            command.message_expiry,
+                            command.compression_algorithm,
+                        ),
                         replication_factor: command.replication_factor,
                         created_at: entry.timestamp,
+                        metadata: command.metadata,
+                        message_id_scheme: command.message_id_scheme,
+                        cleanup_policy: command.cleanup_policy,
+                        allowed_producers: Vec::new(),
+                        expiry_watchers: Vec::new(),
+                        delete_at: None,
                         partitions: if command.partitions_count > 0 {
                             let mut partitions = AHashMap::new();
                             for i in 1..=command.partitions_count {
@@ -162,7 +257,7 @@ impl SystemState {
                     stream.topics.insert(topic.id, topic);
                 }
                 EntryCommand::UpdateTopic(command) => {
-                    let stream_id = find_stream_id(&streams, &command.stream_id);
+                    let stream_id = find_stream_id(&streams, user_namespace_id, &command.stream_id);
                     let stream = streams
                         .get_mut(&stream_id)
                         .unwrap_or_else(|| panic!("{}", format!("Stream: {stream_id} not found")));
@@ -175,10 +270,99 @@ impl SystemState {
                     topic.compression_algorithm = command.compression_algorithm;
                     topic.message_expiry = command.message_expiry;
                     topic.max_topic_size = command.max_topic_size;
+                    if let Some(max_segments) = command.max_segments {
+                        topic.max_segments = max_segments;
+                    }
+                    topic.config = topic
+                        .config
+                        .with_topic_settings(command.message_expiry, command.compression_algorithm);
                     topic.replication_factor = command.replication_factor;
                 }
+                EntryCommand::UpdateTopicMetadata(command) => {
+                    let stream_id = find_stream_id(&streams, user_namespace_id, &command.stream_id);
+                    let stream = streams
+                        .get_mut(&stream_id)
+                        .unwrap_or_else(|| panic!("{}", format!("Stream: {stream_id} not found")));
+                    let topic_id = find_topic_id(&stream.topics, &command.topic_id);
+                    let topic = stream
+                        .topics
+                        .get_mut(&topic_id)
+                        .unwrap_or_else(|| panic!("{}", format!("Topic: {topic_id} not found")));
+                    topic.metadata = command.metadata;
+                }
+                EntryCommand::UpdateTopicProducers(command) => {
+                    let stream_id = find_stream_id(&streams, user_namespace_id, &command.stream_id);
+                    let stream = streams
+                        .get_mut(&stream_id)
+                        .unwrap_or_else(|| panic!("{}", format!("Stream: {stream_id} not found")));
+                    let topic_id = find_topic_id(&stream.topics, &command.topic_id);
+                    let topic = stream
+                        .topics
+                        .get_mut(&topic_id)
+                        .unwrap_or_else(|| panic!("{}", format!("Topic: {topic_id} not found")));
+                    topic.allowed_producers = command.allowed_producers;
+                }
+                EntryCommand::UpdateTopicConfig(command) => {
+                    let stream_id = find_stream_id(&streams, user_namespace_id, &command.stream_id);
+                    let stream = streams
+                        .get_mut(&stream_id)
+                        .unwrap_or_else(|| panic!("{}", format!("Stream: {stream_id} not found")));
+                    let topic_id = find_topic_id(&stream.topics, &command.topic_id);
+                    let topic = stream
+                        .topics
+                        .get_mut(&topic_id)
+                        .unwrap_or_else(|| panic!("{}", format!("Topic: {topic_id} not found")));
+                    topic.message_expiry = command
+                        .config
+                        .message_expiry
+                        .unwrap_or(IggyExpiry::ServerDefault);
+                    topic.compression_algorithm =
+                        command.config.compression_algorithm.unwrap_or_default();
+                    topic.config = command.config;
+                }
+                EntryCommand::UpdateTopicExpiryWatcher(command) => {
+                    let stream_id = find_stream_id(&streams, user_namespace_id, &command.stream_id);
+                    let stream = streams
+                        .get_mut(&stream_id)
+                        .unwrap_or_else(|| panic!("{}", format!("Stream: {stream_id} not found")));
+                    let topic_id = find_topic_id(&stream.topics, &command.topic_id);
+                    let topic = stream
+                        .topics
+                        .get_mut(&topic_id)
+                        .unwrap_or_else(|| panic!("{}", format!("Topic: {topic_id} not found")));
+                    let id = match command.consumer_kind {
+                        ConsumerKind::Consumer => {
+                            PollingConsumer::resolve_consumer_id(&command.consumer_id)
+                        }
+                        ConsumerKind::ConsumerGroup => {
+                            find_consumer_group_id(&topic.consumer_groups, &command.consumer_id)
+                        }
+                    };
+                    let watcher = ExpiryWatcher {
+                        kind: command.consumer_kind,
+                        id,
+                    };
+                    topic.expiry_watchers.retain(|existing| *existing != watcher);
+                    if command.enabled {
+                        topic.expiry_watchers.push(watcher);
+                    }
+                }
+                EntryCommand::MarkTopicForDeletion(command) => {
+                    let delete_at = command.delete_at;
+                    let command = command.command;
+                    let stream_id = find_stream_id(&streams, user_namespace_id, &command.stream_id);
+                    let stream = streams
+                        .get_mut(&stream_id)
+                        .unwrap_or_else(|| panic!("{}", format!("Stream: {stream_id} not found")));
+                    let topic_id = find_topic_id(&stream.topics, &command.topic_id);
+                    let topic = stream
+                        .topics
+                        .get_mut(&topic_id)
+                        .unwrap_or_else(|| panic!("{}", format!("Topic: {topic_id} not found")));
+                    topic.delete_at = Some(delete_at);
+                }
                 EntryCommand::DeleteTopic(command) => {
-                    let stream_id = find_stream_id(&streams, &command.stream_id);
+                    let stream_id = find_stream_id(&streams, user_namespace_id, &command.stream_id);
                     let stream = streams
                         .get_mut(&stream_id)
                         .unwrap_or_else(|| panic!("{}", format!("Stream: {stream_id} not found")));
@@ -186,7 +370,7 @@ impl SystemState {
                     stream.topics.remove(&topic_id);
                 }
                 EntryCommand::PurgeTopic(command) => {
-                    let stream_id = find_stream_id(&streams, &command.stream_id);
+                    let stream_id = find_stream_id(&streams, user_namespace_id, &command.stream_id);
                     let stream = streams
                         .get(&stream_id)
                         .unwrap_or_else(|| panic!("{}", format!("Stream: {stream_id} not found")));
@@ -198,7 +382,7 @@ impl SystemState {
                     // It only affects the segments which are not part of the state
                 }
                 EntryCommand::CreatePartitions(command) => {
-                    let stream_id = find_stream_id(&streams, &command.stream_id);
+                    let stream_id = find_stream_id(&streams, user_namespace_id, &command.stream_id);
                     let stream = streams
                         .get_mut(&stream_id)
                         .unwrap_or_else(|| panic!("{}", format!("Stream: {stream_id} not found")));
@@ -228,7 +412,7 @@ impl SystemState {
                     }
                 }
                 EntryCommand::DeletePartitions(command) => {
-                    let stream_id = find_stream_id(&streams, &command.stream_id);
+                    let stream_id = find_stream_id(&streams, user_namespace_id, &command.stream_id);
                     let stream = streams
                         .get_mut(&stream_id)
                         .unwrap_or_else(|| panic!("{}", format!("Stream: {stream_id} not found")));
@@ -254,7 +438,7 @@ impl SystemState {
                 EntryCommand::CreateConsumerGroup(command) => {
                     let consumer_group_id = command.group_id;
                     let command = command.command;
-                    let stream_id = find_stream_id(&streams, &command.stream_id);
+                    let stream_id = find_stream_id(&streams, user_namespace_id, &command.stream_id);
                     let stream = streams
                         .get_mut(&stream_id)
                         .unwrap_or_else(|| panic!("{}", format!("Stream: {stream_id} not found")));
@@ -266,13 +450,16 @@ impl SystemState {
                     let consumer_group = ConsumerGroupState {
                         id: consumer_group_id,
                         name: command.name,
+                        offset_recovery: command.offset_recovery,
+                        dead_letter: None,
+                        visibility_timeout: None,
                     };
                     topic
                         .consumer_groups
                         .insert(consumer_group.id, consumer_group);
                 }
                 EntryCommand::DeleteConsumerGroup(command) => {
-                    let stream_id = find_stream_id(&streams, &command.stream_id);
+                    let stream_id = find_stream_id(&streams, user_namespace_id, &command.stream_id);
                     let stream = streams
                         .get_mut(&stream_id)
                         .unwrap_or_else(|| panic!("{}", format!("Stream: {stream_id} not found")));
@@ -284,6 +471,74 @@ impl SystemState {
                     let consumer_group_id =
                         find_consumer_group_id(&topic.consumer_groups, &command.group_id);
                     topic.consumer_groups.remove(&consumer_group_id);
+                    topic.expiry_watchers.retain(|watcher| {
+                        watcher.kind != ConsumerKind::ConsumerGroup
+                            || watcher.id != consumer_group_id
+                    });
+                }
+                EntryCommand::UpdateConsumerGroupDeadLetter(command) => {
+                    let dead_letter = match (
+                        &command.dead_letter_stream_id,
+                        &command.dead_letter_topic_id,
+                    ) {
+                        (Some(dead_letter_stream_id), Some(dead_letter_topic_id)) => {
+                            let dead_letter_stream_id =
+                                find_stream_id(&streams, user_namespace_id, dead_letter_stream_id);
+                            let dead_letter_stream =
+                                streams.get(&dead_letter_stream_id).unwrap_or_else(|| {
+                                    panic!("{}", format!("Stream: {dead_letter_stream_id} not found"))
+                                });
+                            Some(ConsumerGroupDeadLetter {
+                                stream_id: dead_letter_stream_id,
+                                topic_id: find_topic_id(
+                                    &dead_letter_stream.topics,
+                                    dead_letter_topic_id,
+                                ),
+                                max_delivery_count: command.max_delivery_count,
+                            })
+                        }
+                        _ => None,
+                    };
+                    let stream_id = find_stream_id(&streams, user_namespace_id, &command.stream_id);
+                    let stream = streams
+                        .get_mut(&stream_id)
+                        .unwrap_or_else(|| panic!("{}", format!("Stream: {stream_id} not found")));
+                    let topic_id = find_topic_id(&stream.topics, &command.topic_id);
+                    let topic = stream
+                        .topics
+                        .get_mut(&topic_id)
+                        .unwrap_or_else(|| panic!("{}", format!("Topic: {topic_id} not found")));
+                    let consumer_group_id =
+                        find_consumer_group_id(&topic.consumer_groups, &command.group_id);
+                    let consumer_group = topic
+                        .consumer_groups
+                        .get_mut(&consumer_group_id)
+                        .unwrap_or_else(|| {
+                            panic!("{}", format!("Consumer group: {consumer_group_id} not found"))
+                        });
+                    consumer_group.dead_letter = dead_letter;
+                }
+                EntryCommand::UpdateConsumerGroupVisibilityTimeout(command) => {
+                    let stream_id = find_stream_id(&streams, user_namespace_id, &command.stream_id);
+                    let stream = streams
+                        .get_mut(&stream_id)
+                        .unwrap_or_else(|| panic!("{}", format!("Stream: {stream_id} not found")));
+                    let topic_id = find_topic_id(&stream.topics, &command.topic_id);
+                    let topic = stream
+                        .topics
+                        .get_mut(&topic_id)
+                        .unwrap_or_else(|| panic!("{}", format!("Topic: {topic_id} not found")));
+                    let consumer_group_id =
+                        find_consumer_group_id(&topic.consumer_groups, &command.group_id);
+                    let consumer_group = topic
+                        .consumer_groups
+                        .get_mut(&consumer_group_id)
+                        .unwrap_or_else(|| {
+                            panic!("{}", format!("Consumer group: {consumer_group_id} not found"))
+                        });
+                    let visibility_timeout = command.visibility_timeout;
+                    consumer_group.visibility_timeout =
+                        (visibility_timeout.as_micros() > 0).then_some(visibility_timeout);
                 }
                 EntryCommand::CreateUser(command) => {
                     let user_id = command.user_id;
@@ -296,6 +551,8 @@ impl SystemState {
                         created_at: entry.timestamp,
                         permissions: command.permissions,
                         personal_access_tokens: AHashMap::new(),
+                        quotas: UserQuotas::default(),
+                        namespace_id: command.namespace_id,
                     };
                     users.insert(user.id, user);
                 }
@@ -329,6 +586,13 @@ impl SystemState {
                         .unwrap_or_else(|| panic!("{}", format!("User: {user_id} not found")));
                     user.permissions = command.permissions;
                 }
+                EntryCommand::UpdateUserQuotas(command) => {
+                    let user_id = find_user_id(&users, &command.user_id);
+                    let user = users
+                        .get_mut(&user_id)
+                        .unwrap_or_else(|| panic!("{}", format!("User: {user_id} not found")));
+                    user.quotas = command.quotas;
+                }
                 EntryCommand::CreatePersonalAccessToken(command) => {
                     let token_hash = command.hash;
                     let user_id = find_user_id(
@@ -360,6 +624,8 @@ impl SystemState {
                             name: command.command.name,
                             token_hash,
                             expiry_at,
+                            scope: command.command.scope,
+                            issued_at: entry.timestamp,
                         },
                     );
                 }
@@ -378,10 +644,49 @@ impl SystemState {
                         .unwrap_or_else(|| panic!("{}", format!("User: {user_id} not found")));
                     user.personal_access_tokens.remove(&command.name);
                 }
+                EntryCommand::RotatePersonalAccessToken(command) => {
+                    let user_id = find_user_id(
+                        &users,
+                        &entry.user_id.try_into().with_error_context(|error| {
+                            format!(
+                                "{COMPONENT} (error: {error}) - failed to find user, user ID: {}",
+                                entry.user_id
+                            )
+                        })?,
+                    );
+                    let user = users
+                        .get_mut(&user_id)
+                        .unwrap_or_else(|| panic!("{}", format!("User: {user_id} not found")));
+                    let name = command.command.name;
+                    let expiry_at = PersonalAccessToken::calculate_expiry_at(
+                        entry.timestamp,
+                        command.command.expiry,
+                    );
+                    if let Some(expiry_at) = expiry_at {
+                        if expiry_at.as_micros() <= IggyTimestamp::now().as_micros() {
+                            debug!("Rotated personal access token: {name} has already expired.");
+                            user.personal_access_tokens.remove(&name);
+                            continue;
+                        }
+                    }
+
+                    let Some(personal_access_token) = user.personal_access_tokens.get_mut(&name)
+                    else {
+                        debug!("Rotated personal access token: {name} does not exist.");
+                        continue;
+                    };
+                    personal_access_token.token_hash = command.hash;
+                    personal_access_token.expiry_at = expiry_at;
+                    personal_access_token.issued_at = entry.timestamp;
+                }
             }
         }
 
-        let state = SystemState { streams, users };
+        let state = SystemState {
+            namespaces,
+            streams,
+            users,
+        };
         debug!("+++ State +++");
         debug!("{state}");
         debug!("+++ State +++");
@@ -389,7 +694,29 @@ impl SystemState {
     }
 }
 
-fn find_stream_id(streams: &AHashMap<u32, StreamState>, stream_id: &Identifier) -> u32 {
+fn find_namespace_id(namespaces: &AHashMap<u32, NamespaceState>, namespace_id: &Identifier) -> u32 {
+    match namespace_id.kind {
+        IdKind::Numeric => namespace_id
+            .get_u32_value()
+            .unwrap_or_else(|_| panic!("{}", format!("Invalid namespace ID: {namespace_id}"))),
+        IdKind::String => {
+            let name = namespace_id.get_cow_str_value().unwrap_or_else(|_| {
+                panic!("{}", format!("Invalid namespace name: {namespace_id}"))
+            });
+            let namespace = namespaces
+                .values()
+                .find(|n| n.name == name)
+                .unwrap_or_else(|| panic!("{}", format!("Namespace: {name} not found")));
+            namespace.id
+        }
+    }
+}
+
+fn find_stream_id(
+    streams: &AHashMap<u32, StreamState>,
+    namespace_id: Option<u32>,
+    stream_id: &Identifier,
+) -> u32 {
     match stream_id.kind {
         IdKind::Numeric => stream_id
             .get_u32_value()
@@ -400,7 +727,7 @@ fn find_stream_id(streams: &AHashMap<u32, StreamState>, stream_id: &Identifier)
                 .unwrap_or_else(|_| panic!("{}", format!("Invalid stream name: {stream_id}")));
             let stream = streams
                 .values()
-                .find(|s| s.name == name)
+                .find(|s| s.namespace_id == namespace_id && s.name == name)
                 .unwrap_or_else(|| panic!("{}", format!("Stream: {name} not found")));
             stream.id
         }
@@ -466,6 +793,11 @@ fn find_user_id(users: &AHashMap<u32, UserState>, user_id: &Identifier) -> u32 {
 
 impl Display for SystemState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Namespaces:")?;
+        for namespace in self.namespaces.iter() {
+            write!(f, "\n================\n")?;
+            write!(f, "{}", namespace.1)?;
+        }
         write!(f, "Streams:")?;
         for stream in self.streams.iter() {
             write!(f, "\n================\n")?;
@@ -480,6 +812,16 @@ impl Display for SystemState {
     }
 }
 
+impl Display for NamespaceState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Namespace -> ID: {}, Name: {}, Quotas: {}",
+            self.id, self.name, self.quotas
+        )
+    }
+}
+
 impl Display for ConsumerGroupState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "ConsumerGroup -> ID: {}, Name: {}", self.id, self.name)
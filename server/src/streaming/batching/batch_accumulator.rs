@@ -30,6 +30,7 @@ pub struct BatchAccumulator {
     current_offset: u64,
     current_timestamp: u64,
     messages: Vec<Arc<RetainedMessage>>,
+    compressed_by_producer: bool,
 }
 
 impl BatchAccumulator {
@@ -40,6 +41,7 @@ impl BatchAccumulator {
             current_offset: 0,
             current_timestamp: 0,
             messages: Vec::with_capacity(capacity),
+            compressed_by_producer: false,
         }
     }
 
@@ -77,6 +79,16 @@ impl BatchAccumulator {
         self.messages[start_idx..end_idx].to_vec()
     }
 
+    /// Marks the batch as containing the payloads already compressed by the producer,
+    /// so that it's stored without compressing them again.
+    pub fn mark_compressed_by_producer(&mut self) {
+        self.compressed_by_producer = true;
+    }
+
+    pub fn is_compressed_by_producer(&self) -> bool {
+        self.compressed_by_producer
+    }
+
     pub fn is_empty(&self) -> bool {
         self.messages.is_empty()
     }
@@ -85,6 +97,10 @@ impl BatchAccumulator {
         self.messages.len()
     }
 
+    pub fn unsaved_messages_size(&self) -> IggyByteSize {
+        self.current_size
+    }
+
     pub fn batch_max_offset(&self) -> u64 {
         self.current_offset
     }
@@ -118,6 +134,7 @@ impl BatchAccumulator {
         self.current_size = IggyByteSize::from(0);
         self.current_offset = 0;
         self.current_timestamp = 0;
+        self.compressed_by_producer = false;
 
         let batch_payload = bytes.freeze();
         let batch_payload_len = IggyByteSize::from(batch_payload.len() as u64);
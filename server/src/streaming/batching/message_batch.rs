@@ -20,9 +20,76 @@ use crate::streaming::batching::batch_filter::BatchItemizer;
 use crate::streaming::batching::iterator::IntoMessagesIterator;
 use crate::streaming::models::messages::RetainedMessage;
 use bytes::Bytes;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::error::IggyError;
+use iggy::utils::checksum;
 use iggy::utils::{byte_size::IggyByteSize, sizeable::Sizeable};
 
 pub const RETAINED_BATCH_HEADER_LEN: u64 = 8 + 8 + 4 + 4;
+/// The length of the CRC32C checksum stored between the header and the payload of the batch.
+pub const BATCH_CHECKSUM_LEN: u64 = 4;
+
+/// The highest bit of the batch length marks the batches written in the segment format version 2,
+/// whose payload is preceded by its CRC32C checksum, included in the stored length.
+/// The older servers fail to parse such length instead of misreading the batch.
+/// Without this bit, the whole value is the length of the uncompressed payload, as in the version 1,
+/// so the batches of any size written before remain readable.
+const CHECKSUM_FLAG: u32 = 1 << 31;
+/// In the segment format version 2, the bits below the checksum flag hold the code of the algorithm
+/// the payload is compressed with, where 0 means no compression.
+const COMPRESSION_CODE_SHIFT: u32 = 28;
+const COMPRESSION_CODE_MASK: u32 = 0b111;
+/// The maximum length of the batch (including the checksum) which can be stored in the header
+/// in the segment format version 2, the larger batches are stored in the version 1.
+pub const MAX_CHECKSUMMED_BATCH_LENGTH: u32 = (1 << COMPRESSION_CODE_SHIFT) - 1;
+
+/// The batch length parsed from the header, along with the way the batch is stored.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StoredBatchLength {
+    /// The number of bytes stored after the header, including the checksum.
+    pub length: u32,
+    pub compression_algorithm: CompressionAlgorithm,
+    pub has_checksum: bool,
+}
+
+impl StoredBatchLength {
+    /// Returns the length of the (possibly compressed) payload, following the checksum.
+    pub fn payload_length(&self) -> u32 {
+        match self.has_checksum {
+            true => self.length - BATCH_CHECKSUM_LEN as u32,
+            false => self.length,
+        }
+    }
+}
+
+/// Splits the batch length stored in the header into the stored length, the compression algorithm
+/// of the payload and whether it's preceded by the checksum.
+pub fn parse_batch_length(stored_length: u32) -> Result<StoredBatchLength, IggyError> {
+    let has_checksum = stored_length & CHECKSUM_FLAG != 0;
+    if !has_checksum {
+        return Ok(StoredBatchLength {
+            length: stored_length,
+            compression_algorithm: CompressionAlgorithm::None,
+            has_checksum,
+        });
+    }
+
+    let code = ((stored_length >> COMPRESSION_CODE_SHIFT) & COMPRESSION_CODE_MASK) as u8;
+    let compression_algorithm = match code {
+        0 => CompressionAlgorithm::None,
+        code => CompressionAlgorithm::from_code(code)?,
+    };
+    let length = stored_length & MAX_CHECKSUMMED_BATCH_LENGTH;
+    if (length as u64) < BATCH_CHECKSUM_LEN {
+        return Err(IggyError::CannotReadBatchLength);
+    }
+
+    Ok(StoredBatchLength {
+        length,
+        compression_algorithm,
+        has_checksum,
+    })
+}
 
 #[derive(Debug)]
 pub struct RetainedMessageBatch {
@@ -31,6 +98,9 @@ pub struct RetainedMessageBatch {
     pub max_timestamp: u64,
     pub length: IggyByteSize,
     pub bytes: Bytes,
+    pub compression_algorithm: CompressionAlgorithm,
+    /// The CRC32C checksum of the stored (possibly compressed) payload, written in the segment format version 2.
+    pub checksum: Option<u32>,
 }
 
 impl RetainedMessageBatch {
@@ -47,7 +117,84 @@ impl RetainedMessageBatch {
             max_timestamp,
             length,
             bytes,
+            compression_algorithm: CompressionAlgorithm::None,
+            checksum: None,
+        }
+    }
+
+    /// Compresses the payload, unless the compressed one would not be smaller or would not fit into the header.
+    /// The compressed batch must be written with the checksum, as only the segment format version 2 stores the compression.
+    pub fn compress(self, algorithm: CompressionAlgorithm) -> Result<Self, IggyError> {
+        if algorithm == CompressionAlgorithm::None
+            || self.compression_algorithm != CompressionAlgorithm::None
+            || self.checksum.is_some()
+            || self.bytes.is_empty()
+        {
+            return Ok(self);
         }
+
+        // The space for the checksum is reserved, so that it can still be added to the compressed batch.
+        let compressed = algorithm.compress(&self.bytes)?;
+        if compressed.len() >= self.bytes.len()
+            || compressed.len() as u64 + BATCH_CHECKSUM_LEN > MAX_CHECKSUMMED_BATCH_LENGTH as u64
+        {
+            return Ok(self);
+        }
+
+        Ok(RetainedMessageBatch {
+            length: IggyByteSize::from(compressed.len() as u64),
+            bytes: Bytes::from(compressed),
+            compression_algorithm: algorithm,
+            ..self
+        })
+    }
+
+    /// Calculates the checksum of the payload, so that the batch is written in the segment format version 2.
+    /// Must be invoked after the compression, as the checksum covers the stored payload.
+    /// The batch whose length would not fit into the header is left without the checksum, in the version 1.
+    pub fn with_checksum(self) -> Self {
+        if self.length.as_bytes_u64() + BATCH_CHECKSUM_LEN > MAX_CHECKSUMMED_BATCH_LENGTH as u64 {
+            return self;
+        }
+
+        RetainedMessageBatch {
+            checksum: Some(checksum::calculate_crc32c(&self.bytes)),
+            ..self
+        }
+    }
+
+    /// Verifies the checksum of the stored payload, if the batch has been written with one.
+    pub fn verify_checksum(&self) -> Result<(), IggyError> {
+        let Some(expected) = self.checksum else {
+            return Ok(());
+        };
+
+        let calculated = checksum::calculate_crc32c(&self.bytes);
+        if calculated != expected {
+            return Err(IggyError::InvalidBatchChecksum(
+                calculated,
+                expected,
+                self.base_offset,
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Decompresses the payload, so the messages can be iterated over.
+    pub fn decompress(self) -> Result<Self, IggyError> {
+        if self.compression_algorithm == CompressionAlgorithm::None {
+            return Ok(self);
+        }
+
+        let decompressed = self.compression_algorithm.decompress(&self.bytes)?;
+        Ok(RetainedMessageBatch {
+            length: IggyByteSize::from(decompressed.len() as u64),
+            bytes: Bytes::from(decompressed),
+            compression_algorithm: CompressionAlgorithm::None,
+            checksum: None,
+            ..self
+        })
     }
 
     pub fn is_contained_or_overlapping_within_offset_range(
@@ -64,11 +211,28 @@ impl RetainedMessageBatch {
         self.base_offset + self.last_offset_delta as u64
     }
 
-    pub fn header_as_bytes(&self) -> [u8; 24] {
-        let mut header: [u8; 24] = [0u8; 24];
+    /// Returns the length of the header written before the payload, including the checksum if there's one.
+    pub fn get_header_len(&self) -> u64 {
+        match self.checksum {
+            Some(_) => RETAINED_BATCH_HEADER_LEN + BATCH_CHECKSUM_LEN,
+            None => RETAINED_BATCH_HEADER_LEN,
+        }
+    }
+
+    /// Returns the header along with the checksum, if there's one, to be written before the payload.
+    pub fn header_as_bytes(&self) -> Vec<u8> {
+        let mut header = vec![0u8; self.get_header_len() as usize];
 
         header[0..8].copy_from_slice(&self.base_offset.to_le_bytes());
-        header[8..12].copy_from_slice(&(self.length.as_bytes_u64() as u32).to_le_bytes());
+        let mut length = self.length.as_bytes_u64() as u32;
+        if let Some(checksum) = self.checksum {
+            length = (length + BATCH_CHECKSUM_LEN as u32) | CHECKSUM_FLAG;
+            if self.compression_algorithm != CompressionAlgorithm::None {
+                length |= (self.compression_algorithm.as_code() as u32) << COMPRESSION_CODE_SHIFT;
+            }
+            header[24..28].copy_from_slice(&checksum.to_le_bytes());
+        }
+        header[8..12].copy_from_slice(&length.to_le_bytes());
         header[12..16].copy_from_slice(&self.last_offset_delta.to_le_bytes());
         header[16..24].copy_from_slice(&self.max_timestamp.to_le_bytes());
 
@@ -99,6 +263,136 @@ where
 
 impl Sizeable for RetainedMessageBatch {
     fn get_size_bytes(&self) -> IggyByteSize {
-        self.length + RETAINED_BATCH_HEADER_LEN.into()
+        self.length + self.get_header_len().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn batch(bytes: Bytes) -> RetainedMessageBatch {
+        RetainedMessageBatch::new(100, 9, 1000, IggyByteSize::from(bytes.len() as u64), bytes)
+    }
+
+    #[test]
+    fn compressed_batch_should_be_decompressed_with_length_read_from_header() {
+        let payload = Bytes::from("message".repeat(1000));
+        for algorithm in [
+            CompressionAlgorithm::Gzip,
+            CompressionAlgorithm::Lz4,
+            CompressionAlgorithm::Zstd,
+        ] {
+            let compressed = batch(payload.clone())
+                .compress(algorithm)
+                .unwrap()
+                .with_checksum();
+            assert_eq!(compressed.compression_algorithm, algorithm);
+            assert!(compressed.bytes.len() < payload.len());
+
+            let header = compressed.header_as_bytes();
+            let stored_length = u32::from_le_bytes(header[8..12].try_into().unwrap());
+            let stored = parse_batch_length(stored_length).unwrap();
+            assert_eq!(stored.payload_length() as usize, compressed.bytes.len());
+            assert_eq!(stored.compression_algorithm, algorithm);
+            assert!(stored.has_checksum);
+
+            let decompressed = compressed.decompress().unwrap();
+            assert_eq!(decompressed.bytes, payload);
+            assert_eq!(decompressed.length.as_bytes_u64(), payload.len() as u64);
+        }
+    }
+
+    #[test]
+    fn incompressible_batch_should_be_stored_as_is() {
+        let payload = Bytes::from_static(&[1, 2, 3]);
+        let batch = batch(payload.clone())
+            .compress(CompressionAlgorithm::Gzip)
+            .unwrap();
+        assert_eq!(batch.compression_algorithm, CompressionAlgorithm::None);
+        assert_eq!(batch.bytes, payload);
+
+        let header = batch.header_as_bytes();
+        let stored_length = u32::from_le_bytes(header[8..12].try_into().unwrap());
+        assert_eq!(
+            parse_batch_length(stored_length).unwrap(),
+            StoredBatchLength {
+                length: 3,
+                compression_algorithm: CompressionAlgorithm::None,
+                has_checksum: false,
+            }
+        );
+    }
+
+    #[test]
+    fn batch_too_large_for_checksum_should_be_stored_in_legacy_format() {
+        let length = MAX_CHECKSUMMED_BATCH_LENGTH as u64 + 1;
+        let batch = RetainedMessageBatch::new(
+            100,
+            9,
+            1000,
+            IggyByteSize::from(length),
+            Bytes::from_static(&[1, 2, 3]),
+        )
+        .with_checksum();
+        assert!(batch.checksum.is_none());
+
+        let header = batch.header_as_bytes();
+        assert_eq!(header.len() as u64, RETAINED_BATCH_HEADER_LEN);
+        let stored_length = u32::from_le_bytes(header[8..12].try_into().unwrap());
+        assert_eq!(
+            parse_batch_length(stored_length).unwrap(),
+            StoredBatchLength {
+                length: length as u32,
+                compression_algorithm: CompressionAlgorithm::None,
+                has_checksum: false,
+            }
+        );
+    }
+
+    #[test]
+    fn legacy_batch_length_should_not_be_parsed_as_compressed() {
+        let stored_length = 300 * 1024 * 1024;
+        assert_eq!(
+            parse_batch_length(stored_length).unwrap(),
+            StoredBatchLength {
+                length: stored_length,
+                compression_algorithm: CompressionAlgorithm::None,
+                has_checksum: false,
+            }
+        );
+    }
+
+    #[test]
+    fn batch_with_checksum_should_store_it_before_payload() {
+        let payload = Bytes::from("message".repeat(1000));
+        let batch = batch(payload)
+            .compress(CompressionAlgorithm::Lz4)
+            .unwrap()
+            .with_checksum();
+        batch.verify_checksum().unwrap();
+
+        let header = batch.header_as_bytes();
+        assert_eq!(header.len() as u64, batch.get_header_len());
+        let stored_length = u32::from_le_bytes(header[8..12].try_into().unwrap());
+        let stored = parse_batch_length(stored_length).unwrap();
+        assert!(stored.has_checksum);
+        assert_eq!(stored.compression_algorithm, CompressionAlgorithm::Lz4);
+        assert_eq!(stored.payload_length() as usize, batch.bytes.len());
+        assert_eq!(
+            u32::from_le_bytes(header[24..28].try_into().unwrap()),
+            batch.checksum.unwrap()
+        );
+
+        let mut corrupted = batch.bytes.to_vec();
+        corrupted[0] ^= 0xFF;
+        let corrupted = RetainedMessageBatch {
+            bytes: Bytes::from(corrupted),
+            ..batch
+        };
+        assert!(matches!(
+            corrupted.verify_checksum(),
+            Err(IggyError::InvalidBatchChecksum(_, _, 100))
+        ));
     }
 }
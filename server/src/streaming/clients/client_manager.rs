@@ -53,6 +53,8 @@ pub struct ConsumerGroup {
 pub enum Transport {
     Tcp,
     Quic,
+    Uds,
+    WebSocket,
 }
 
 impl Display for Transport {
@@ -60,6 +62,8 @@ impl Display for Transport {
         match self {
             Transport::Tcp => write!(f, "TCP"),
             Transport::Quic => write!(f, "QUIC"),
+            Transport::Uds => write!(f, "UDS"),
+            Transport::WebSocket => write!(f, "WebSocket"),
         }
     }
 }
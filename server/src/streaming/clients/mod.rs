@@ -17,3 +17,4 @@
  */
 
 pub mod client_manager;
+pub mod transport_limiter;
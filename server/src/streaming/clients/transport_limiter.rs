@@ -0,0 +1,262 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::configs::limits::TransportLimitsConfig;
+use iggy::error::IggyError;
+use iggy::models::stats::TransportUtilization;
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+
+static LIMITERS: OnceLock<Mutex<Vec<Arc<TransportLimiter>>>> = OnceLock::new();
+
+/// Sheds the load of a single transport by limiting the number of its open connections,
+/// the requests processed at the same time and the size of a single request frame,
+/// so that a burst on one transport cannot starve the clients using the other ones.
+#[derive(Debug)]
+pub struct TransportLimiter {
+    transport: &'static str,
+    max_connections: u32,
+    max_in_flight_requests: u32,
+    max_frame_size: u64,
+    connections: AtomicU32,
+    in_flight_requests: AtomicU32,
+    rejected_connections: AtomicU64,
+    rejected_requests: AtomicU64,
+}
+
+/// Keeps the connection slot acquired until dropped.
+#[derive(Debug)]
+pub struct ConnectionPermit {
+    limiter: Arc<TransportLimiter>,
+}
+
+/// Keeps the in-flight request slot acquired until dropped.
+#[derive(Debug)]
+pub struct RequestPermit {
+    limiter: Arc<TransportLimiter>,
+}
+
+impl TransportLimiter {
+    /// Creates the limiter for the transport and registers it, so that its utilization is included in the stats.
+    pub fn register(transport: &'static str, config: &TransportLimitsConfig) -> Arc<Self> {
+        let limiter = Arc::new(Self::new(transport, config));
+        let mut limiters = LIMITERS
+            .get_or_init(|| Mutex::new(Vec::new()))
+            .lock()
+            .unwrap();
+        limiters.retain(|registered| registered.transport != transport);
+        limiters.push(limiter.clone());
+        limiter
+    }
+
+    /// Returns the utilization of all the registered transports.
+    pub fn get_utilization() -> Vec<TransportUtilization> {
+        let Some(limiters) = LIMITERS.get() else {
+            return Vec::new();
+        };
+
+        limiters
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|limiter| limiter.utilization())
+            .collect()
+    }
+
+    fn new(transport: &'static str, config: &TransportLimitsConfig) -> Self {
+        Self {
+            transport,
+            max_connections: config.max_connections,
+            max_in_flight_requests: config.max_in_flight_requests,
+            max_frame_size: config.max_frame_size.as_bytes_u64(),
+            connections: AtomicU32::new(0),
+            in_flight_requests: AtomicU32::new(0),
+            rejected_connections: AtomicU64::new(0),
+            rejected_requests: AtomicU64::new(0),
+        }
+    }
+
+    /// Returns the maximum frame size in bytes, if the limit is enabled.
+    pub fn max_frame_size(&self) -> Option<u64> {
+        match self.max_frame_size {
+            0 => None,
+            size => Some(size),
+        }
+    }
+
+    pub fn try_acquire_connection(self: &Arc<Self>) -> Result<ConnectionPermit, IggyError> {
+        if !try_increment(&self.connections, self.max_connections) {
+            self.rejected_connections.fetch_add(1, Ordering::Relaxed);
+            return Err(self.too_many_connections());
+        }
+
+        Ok(ConnectionPermit {
+            limiter: self.clone(),
+        })
+    }
+
+    pub fn too_many_connections(&self) -> IggyError {
+        IggyError::TooManyConnections(self.transport.to_owned(), self.max_connections)
+    }
+
+    pub fn try_acquire_request(self: &Arc<Self>) -> Result<RequestPermit, IggyError> {
+        if !try_increment(&self.in_flight_requests, self.max_in_flight_requests) {
+            self.rejected_requests.fetch_add(1, Ordering::Relaxed);
+            return Err(IggyError::TooManyInFlightRequests(
+                self.transport.to_owned(),
+                self.max_in_flight_requests,
+            ));
+        }
+
+        Ok(RequestPermit {
+            limiter: self.clone(),
+        })
+    }
+
+    pub fn validate_frame_size(&self, size: u64) -> Result<(), IggyError> {
+        match self.max_frame_size() {
+            Some(max_frame_size) if size > max_frame_size => {
+                self.rejected_requests.fetch_add(1, Ordering::Relaxed);
+                Err(IggyError::FrameTooLarge(
+                    self.transport.to_owned(),
+                    size,
+                    max_frame_size,
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    pub fn utilization(&self) -> TransportUtilization {
+        TransportUtilization {
+            transport: self.transport.to_owned(),
+            connections: self.connections.load(Ordering::Relaxed),
+            max_connections: self.max_connections,
+            in_flight_requests: self.in_flight_requests.load(Ordering::Relaxed),
+            max_in_flight_requests: self.max_in_flight_requests,
+            rejected_connections: self.rejected_connections.load(Ordering::Relaxed),
+            rejected_requests: self.rejected_requests.load(Ordering::Relaxed),
+        }
+    }
+}
+
+impl ConnectionPermit {
+    pub fn limiter(&self) -> &Arc<TransportLimiter> {
+        &self.limiter
+    }
+}
+
+impl Drop for ConnectionPermit {
+    fn drop(&mut self) {
+        self.limiter.connections.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+impl Drop for RequestPermit {
+    fn drop(&mut self) {
+        self.limiter
+            .in_flight_requests
+            .fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+fn try_increment(counter: &AtomicU32, limit: u32) -> bool {
+    counter
+        .fetch_update(Ordering::AcqRel, Ordering::Acquire, |current| {
+            if limit > 0 && current >= limit {
+                None
+            } else {
+                Some(current + 1)
+            }
+        })
+        .is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy::utils::byte_size::IggyByteSize;
+
+    fn limiter(
+        max_connections: u32,
+        max_in_flight_requests: u32,
+        max_frame_size: u64,
+    ) -> Arc<TransportLimiter> {
+        Arc::new(TransportLimiter::new(
+            "TEST",
+            &TransportLimitsConfig {
+                max_connections,
+                max_in_flight_requests,
+                max_frame_size: IggyByteSize::from(max_frame_size),
+            },
+        ))
+    }
+
+    #[test]
+    fn connections_above_the_limit_should_be_rejected_until_permit_is_dropped() {
+        let limiter = limiter(2, 0, 0);
+        let first = limiter.try_acquire_connection().unwrap();
+        let _second = limiter.try_acquire_connection().unwrap();
+        assert!(matches!(
+            limiter.try_acquire_connection(),
+            Err(IggyError::TooManyConnections(_, 2))
+        ));
+
+        drop(first);
+        assert!(limiter.try_acquire_connection().is_ok());
+        let utilization = limiter.utilization();
+        assert_eq!(utilization.connections, 1);
+        assert_eq!(utilization.rejected_connections, 1);
+    }
+
+    #[test]
+    fn in_flight_requests_above_the_limit_should_be_rejected() {
+        let limiter = limiter(0, 1, 0);
+        let permit = limiter.try_acquire_request().unwrap();
+        assert!(matches!(
+            limiter.try_acquire_request(),
+            Err(IggyError::TooManyInFlightRequests(_, 1))
+        ));
+        assert_eq!(limiter.utilization().in_flight_requests, 1);
+
+        drop(permit);
+        assert_eq!(limiter.utilization().in_flight_requests, 0);
+        assert_eq!(limiter.utilization().rejected_requests, 1);
+    }
+
+    #[test]
+    fn zero_limits_should_not_reject_anything() {
+        let limiter = limiter(0, 0, 0);
+        let permits = (0..100)
+            .map(|_| limiter.try_acquire_connection().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(limiter.utilization().connections, permits.len() as u32);
+        assert!(limiter.try_acquire_request().is_ok());
+        assert!(limiter.validate_frame_size(u32::MAX as u64).is_ok());
+    }
+
+    #[test]
+    fn frames_bigger_than_the_limit_should_be_rejected() {
+        let limiter = limiter(0, 0, 1000);
+        assert!(limiter.validate_frame_size(1000).is_ok());
+        assert!(matches!(
+            limiter.validate_frame_size(1001),
+            Err(IggyError::FrameTooLarge(_, 1001, 1000))
+        ));
+    }
+}
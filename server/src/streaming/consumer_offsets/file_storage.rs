@@ -0,0 +1,163 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::consumer_offsets::COMPONENT;
+use crate::streaming::partitions::partition::ConsumerOffset;
+use crate::streaming::persistence::persister::PersisterKind;
+use crate::streaming::storage::ConsumerOffsetStorage;
+use crate::streaming::utils::file;
+use error_set::ErrContext;
+use iggy::consumer::ConsumerKind;
+use iggy::error::IggyError;
+use std::path::Path;
+use std::sync::Arc;
+use tokio::fs;
+use tokio::io::AsyncReadExt;
+use tracing::{error, trace};
+
+/// Stores each offset in a separate file named after the consumer ID.
+#[derive(Debug)]
+pub struct FileConsumerOffsetStorage {
+    persister: Arc<PersisterKind>,
+}
+
+impl FileConsumerOffsetStorage {
+    pub fn new(persister: Arc<PersisterKind>) -> Self {
+        Self { persister }
+    }
+}
+
+impl ConsumerOffsetStorage for FileConsumerOffsetStorage {
+    async fn save_consumer_offset(
+        &self,
+        path: &str,
+        consumer_id: u32,
+        offset: u64,
+    ) -> Result<(), IggyError> {
+        let path = format!("{path}/{consumer_id}");
+        self.persister
+            .overwrite(&path, &offset.to_le_bytes())
+            .await
+            .with_error_context(|error| format!(
+                "{COMPONENT} (error: {error}) - failed to overwrite consumer offset with value: {}, path: {}",
+                offset, path,
+            ))?;
+        trace!("Stored consumer offset value: {}, path: {}", offset, path);
+        Ok(())
+    }
+
+    async fn load_consumer_offsets(
+        &self,
+        kind: ConsumerKind,
+        path: &str,
+    ) -> Result<Vec<ConsumerOffset>, IggyError> {
+        trace!("Loading consumer offsets from path: {path}...");
+        let dir_entries = fs::read_dir(&path).await;
+        if dir_entries.is_err() {
+            return Err(IggyError::CannotReadConsumerOffsets(path.to_owned()));
+        }
+
+        let mut consumer_offsets = Vec::new();
+        let mut dir_entries = dir_entries.unwrap();
+        while let Some(dir_entry) = dir_entries.next_entry().await.unwrap_or(None) {
+            let metadata = dir_entry.metadata().await;
+            if metadata.is_err() {
+                break;
+            }
+
+            if metadata.unwrap().is_dir() {
+                continue;
+            }
+
+            let name = dir_entry.file_name().into_string().unwrap();
+            let consumer_id = name.parse::<u32>();
+            if consumer_id.is_err() {
+                error!("Invalid consumer ID file with name: '{}'.", name);
+                continue;
+            }
+
+            let path = dir_entry.path();
+            let path = path.to_str();
+            if path.is_none() {
+                error!("Invalid consumer ID path for file with name: '{}'.", name);
+                continue;
+            }
+
+            let path = Arc::new(path.unwrap().to_string());
+            let consumer_id = consumer_id.unwrap();
+            let mut file = file::open(&path)
+                .await
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to open offset file, path: {path}"
+                    )
+                })
+                .map_err(|_| IggyError::CannotReadFile)?;
+            let offset = file
+                .read_u64_le()
+                .await
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to read consumer offset from file, path: {path}")
+                })
+                .map_err(|_| IggyError::CannotReadFile)?;
+
+            consumer_offsets.push(ConsumerOffset {
+                kind,
+                consumer_id,
+                offset,
+                path,
+            });
+        }
+
+        consumer_offsets.sort_by(|a, b| a.consumer_id.cmp(&b.consumer_id));
+        Ok(consumer_offsets)
+    }
+
+    async fn delete_consumer_offsets(&self, path: &str) -> Result<(), IggyError> {
+        if !Path::new(path).exists() {
+            trace!("Consumer offsets directory does not exist: {path}.");
+            return Ok(());
+        }
+
+        if fs::remove_dir_all(path).await.is_err() {
+            error!("Cannot delete consumer offsets directory: {}.", path);
+            return Err(IggyError::CannotDeleteConsumerOffsetsDirectory(
+                path.to_owned(),
+            ));
+        }
+        Ok(())
+    }
+
+    async fn delete_consumer_offset(&self, path: &str, consumer_id: u32) -> Result<(), IggyError> {
+        let path = format!("{path}/{consumer_id}");
+        if !Path::new(&path).exists() {
+            trace!("Consumer offset file does not exist: {path}.");
+            return Ok(());
+        }
+
+        if fs::remove_file(&path).await.is_err() {
+            error!("Cannot delete consumer offset file: {path}.");
+            return Err(IggyError::CannotDeleteConsumerOffsetFile(path));
+        }
+        Ok(())
+    }
+
+    async fn flush(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
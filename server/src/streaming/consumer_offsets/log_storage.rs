@@ -0,0 +1,332 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::consumer_offsets::COMPONENT;
+use crate::streaming::partitions::partition::ConsumerOffset;
+use crate::streaming::persistence::persister::PersisterKind;
+use crate::streaming::storage::ConsumerOffsetStorage;
+use ahash::AHashMap;
+use error_set::ErrContext;
+use iggy::consumer::ConsumerKind;
+use iggy::error::IggyError;
+use std::io::ErrorKind;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use tokio::fs;
+use tracing::{error, info, trace, warn};
+
+const LOG_FILE_NAME: &str = "offsets.log";
+const RECORD_SIZE: usize = 13;
+const STORE_RECORD: u8 = 0;
+const DELETE_RECORD: u8 = 1;
+
+/// Appends the stored and deleted offsets as the fixed-size records to a single log in the offsets directory.
+/// The records are buffered until `batch_size` of them is reached or the storage is flushed,
+/// which happens together with saving the buffered messages. The log is compacted when loaded,
+/// which also migrates the offsets stored by the `file` backend, a separate file per consumer.
+#[derive(Debug)]
+pub struct LogConsumerOffsetStorage {
+    persister: Arc<PersisterKind>,
+    batch_size: usize,
+    pending: Mutex<PendingRecords>,
+}
+
+#[derive(Debug, Default)]
+struct PendingRecords {
+    count: usize,
+    logs: AHashMap<String, Vec<u8>>,
+}
+
+impl LogConsumerOffsetStorage {
+    pub fn new(persister: Arc<PersisterKind>, batch_size: u32) -> Self {
+        Self {
+            persister,
+            batch_size: batch_size.max(1) as usize,
+            pending: Mutex::new(PendingRecords::default()),
+        }
+    }
+
+    fn append_record(&self, path: &str, kind: u8, consumer_id: u32, offset: u64) -> bool {
+        let mut pending = self.pending.lock().unwrap();
+        let log = pending.logs.entry(path.to_owned()).or_default();
+        write_record(log, kind, consumer_id, offset);
+        pending.count += 1;
+        pending.count >= self.batch_size
+    }
+
+    fn take_pending(&self) -> AHashMap<String, Vec<u8>> {
+        let mut pending = self.pending.lock().unwrap();
+        pending.count = 0;
+        std::mem::take(&mut pending.logs)
+    }
+
+    fn take_pending_for(&self, path: &str) {
+        let mut pending = self.pending.lock().unwrap();
+        if let Some(log) = pending.logs.remove(path) {
+            pending.count -= log.len() / RECORD_SIZE;
+        }
+    }
+}
+
+impl ConsumerOffsetStorage for LogConsumerOffsetStorage {
+    async fn save_consumer_offset(
+        &self,
+        path: &str,
+        consumer_id: u32,
+        offset: u64,
+    ) -> Result<(), IggyError> {
+        if self.append_record(path, STORE_RECORD, consumer_id, offset) {
+            self.flush().await?;
+        }
+        trace!("Stored consumer offset value: {offset} for consumer with ID: {consumer_id}, path: {path}");
+        Ok(())
+    }
+
+    async fn load_consumer_offsets(
+        &self,
+        kind: ConsumerKind,
+        path: &str,
+    ) -> Result<Vec<ConsumerOffset>, IggyError> {
+        trace!("Loading consumer offsets from path: {path}...");
+        let log_path = get_log_path(path);
+        let mut bytes = match fs::read(&log_path).await {
+            Ok(bytes) => bytes,
+            Err(error) if error.kind() == ErrorKind::NotFound => Vec::new(),
+            Err(error) => {
+                error!("Cannot read consumer offsets log: {log_path}. Error: {error}");
+                return Err(IggyError::CannotReadConsumerOffsets(path.to_owned()));
+            }
+        };
+        let persisted_records_count = bytes.len() / RECORD_SIZE;
+        if bytes.len() % RECORD_SIZE != 0 {
+            warn!("Consumer offsets log: {log_path} ends with an incomplete record, which will be skipped.");
+            bytes.truncate(persisted_records_count * RECORD_SIZE);
+        }
+        if let Some(log) = self.pending.lock().unwrap().logs.get(path) {
+            bytes.extend_from_slice(log);
+        }
+
+        // The log is newer than the files left by the `file` backend, so its records are replayed on top of them.
+        let legacy_offsets = load_legacy_offsets(path).await;
+        let mut offsets = legacy_offsets.clone();
+        replay_records(&mut offsets, &bytes);
+        if offsets.len() < persisted_records_count || !legacy_offsets.is_empty() {
+            let mut compacted = Vec::with_capacity(offsets.len() * RECORD_SIZE);
+            for (consumer_id, offset) in &offsets {
+                write_record(&mut compacted, STORE_RECORD, *consumer_id, *offset);
+            }
+            self.take_pending_for(path);
+            self.persister
+                .overwrite(&log_path, &compacted)
+                .await
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to compact consumer offsets log, path: {log_path}")
+                })?;
+        }
+        if !legacy_offsets.is_empty() {
+            for consumer_id in legacy_offsets.keys() {
+                let legacy_path = format!("{path}/{consumer_id}");
+                if let Err(error) = fs::remove_file(&legacy_path).await {
+                    warn!("Cannot delete migrated consumer offset file: {legacy_path}. Error: {error}");
+                }
+            }
+            info!(
+                "Migrated {} consumer offset files to consumer offsets log: {log_path}.",
+                legacy_offsets.len()
+            );
+        }
+
+        let mut consumer_offsets = offsets
+            .into_iter()
+            .map(|(consumer_id, offset)| ConsumerOffset::new(kind, consumer_id, offset, path))
+            .collect::<Vec<_>>();
+        consumer_offsets.sort_by(|a, b| a.consumer_id.cmp(&b.consumer_id));
+        Ok(consumer_offsets)
+    }
+
+    async fn delete_consumer_offsets(&self, path: &str) -> Result<(), IggyError> {
+        self.take_pending_for(path);
+        let log_path = get_log_path(path);
+        if !Path::new(&log_path).exists() {
+            trace!("Consumer offsets log does not exist: {log_path}.");
+            return Ok(());
+        }
+
+        if fs::remove_file(&log_path).await.is_err() {
+            error!("Cannot delete consumer offsets log: {log_path}.");
+            return Err(IggyError::CannotDeleteConsumerOffsetsDirectory(
+                path.to_owned(),
+            ));
+        }
+        Ok(())
+    }
+
+    async fn delete_consumer_offset(&self, path: &str, consumer_id: u32) -> Result<(), IggyError> {
+        if self.append_record(path, DELETE_RECORD, consumer_id, 0) {
+            self.flush().await?;
+        }
+        Ok(())
+    }
+
+    async fn flush(&self) -> Result<(), IggyError> {
+        for (path, log) in self.take_pending() {
+            let log_path = get_log_path(&path);
+            // The appended file has to exist, so the first records create the log.
+            let result = match Path::new(&log_path).exists() {
+                true => self.persister.append(&log_path, &log).await,
+                false => self.persister.overwrite(&log_path, &log).await,
+            };
+            result.with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to append to consumer offsets log, path: {log_path}")
+            })?;
+        }
+        Ok(())
+    }
+}
+
+fn get_log_path(path: &str) -> String {
+    format!("{path}/{LOG_FILE_NAME}")
+}
+
+fn write_record(log: &mut Vec<u8>, kind: u8, consumer_id: u32, offset: u64) {
+    log.extend_from_slice(&consumer_id.to_le_bytes());
+    log.extend_from_slice(&offset.to_le_bytes());
+    log.push(kind);
+}
+
+/// Reads the offsets stored by the `file` backend, the files named after the consumer IDs in the offsets directory.
+async fn load_legacy_offsets(path: &str) -> AHashMap<u32, u64> {
+    let mut offsets = AHashMap::new();
+    let Ok(mut dir_entries) = fs::read_dir(path).await else {
+        return offsets;
+    };
+
+    while let Ok(Some(dir_entry)) = dir_entries.next_entry().await {
+        let Some(consumer_id) = dir_entry
+            .file_name()
+            .to_str()
+            .and_then(|name| name.parse::<u32>().ok())
+        else {
+            continue;
+        };
+
+        let legacy_path = dir_entry.path();
+        match fs::read(&legacy_path).await {
+            Ok(bytes) if bytes.len() == 8 => {
+                offsets.insert(consumer_id, u64::from_le_bytes(bytes.try_into().unwrap()));
+            }
+            Ok(_) => {
+                warn!(
+                    "Invalid consumer offset file: {}, which will be skipped.",
+                    legacy_path.display()
+                );
+            }
+            Err(error) => {
+                warn!(
+                    "Cannot read consumer offset file: {}, which will be skipped. Error: {error}",
+                    legacy_path.display()
+                );
+            }
+        }
+    }
+    offsets
+}
+
+fn replay_records(offsets: &mut AHashMap<u32, u64>, bytes: &[u8]) {
+    for record in bytes.chunks_exact(RECORD_SIZE) {
+        let consumer_id = u32::from_le_bytes(record[0..4].try_into().unwrap());
+        let offset = u64::from_le_bytes(record[4..12].try_into().unwrap());
+        match record[12] {
+            DELETE_RECORD => {
+                offsets.remove(&consumer_id);
+            }
+            _ => {
+                offsets.insert(consumer_id, offset);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::persistence::persister::FilePersister;
+
+    #[test]
+    fn replaying_records_should_keep_the_last_offset_of_each_consumer() {
+        let mut log = Vec::new();
+        write_record(&mut log, STORE_RECORD, 1, 10);
+        write_record(&mut log, STORE_RECORD, 2, 20);
+        write_record(&mut log, STORE_RECORD, 1, 15);
+        write_record(&mut log, DELETE_RECORD, 2, 0);
+        write_record(&mut log, STORE_RECORD, 3, 5);
+
+        let mut offsets = AHashMap::new();
+        replay_records(&mut offsets, &log);
+        assert_eq!(offsets.len(), 2);
+        assert_eq!(offsets[&1], 15);
+        assert_eq!(offsets[&3], 5);
+    }
+
+    #[test]
+    fn stored_offsets_should_be_flushed_once_the_batch_is_full() {
+        let storage =
+            LogConsumerOffsetStorage::new(Arc::new(PersisterKind::File(FilePersister)), 3);
+        assert!(!storage.append_record("a", STORE_RECORD, 1, 1));
+        assert!(!storage.append_record("b", STORE_RECORD, 1, 1));
+        storage.take_pending_for("a");
+        assert!(!storage.append_record("b", STORE_RECORD, 2, 1));
+        assert!(storage.append_record("b", DELETE_RECORD, 1, 0));
+        let pending = storage.take_pending();
+        assert_eq!(pending["b"].len(), 3 * RECORD_SIZE);
+    }
+
+    #[tokio::test]
+    async fn offset_files_should_be_migrated_to_the_log() {
+        let tempdir = tempfile::TempDir::new().unwrap();
+        let path = tempdir.path().to_str().unwrap();
+        fs::write(format!("{path}/1"), 10u64.to_le_bytes())
+            .await
+            .unwrap();
+        fs::write(format!("{path}/2"), 20u64.to_le_bytes())
+            .await
+            .unwrap();
+        let mut log = Vec::new();
+        write_record(&mut log, STORE_RECORD, 2, 25);
+        fs::write(get_log_path(path), &log).await.unwrap();
+
+        let storage =
+            LogConsumerOffsetStorage::new(Arc::new(PersisterKind::File(FilePersister)), 1);
+        let offsets = storage
+            .load_consumer_offsets(ConsumerKind::Consumer, path)
+            .await
+            .unwrap();
+        let offsets = offsets
+            .iter()
+            .map(|offset| (offset.consumer_id, offset.offset))
+            .collect::<Vec<_>>();
+        assert_eq!(offsets, vec![(1, 10), (2, 25)]);
+        assert!(!Path::new(&format!("{path}/1")).exists());
+        assert!(!Path::new(&format!("{path}/2")).exists());
+
+        let mut migrated = AHashMap::new();
+        replay_records(&mut migrated, &fs::read(get_log_path(path)).await.unwrap());
+        assert_eq!(migrated.len(), 2);
+        assert_eq!(migrated[&1], 10);
+    }
+}
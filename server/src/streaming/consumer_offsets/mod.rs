@@ -0,0 +1,56 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+pub mod file_storage;
+pub mod log_storage;
+#[cfg(feature = "redis")]
+pub mod redis_storage;
+
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+pub const COMPONENT: &str = "STREAMING_CONSUMER_OFFSETS";
+
+/// Backend persisting the consumer offsets, selected for the whole server.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Display, Copy, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum ConsumerOffsetsBackend {
+    /// A file per consumer, overwritten on every stored offset.
+    #[display("file")]
+    File,
+    /// A single append-only log per partition and consumer kind, written in batches.
+    /// The offsets stored by the `file` backend are migrated to the log when loaded.
+    #[display("log")]
+    Log,
+    /// A hash per partition and consumer kind, stored in the external Redis server.
+    #[display("redis")]
+    Redis,
+}
+
+impl FromStr for ConsumerOffsetsBackend {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "file" => Ok(ConsumerOffsetsBackend::File),
+            "log" => Ok(ConsumerOffsetsBackend::Log),
+            "redis" => Ok(ConsumerOffsetsBackend::Redis),
+            _ => Err(format!("Unknown consumer offsets backend: {}", s)),
+        }
+    }
+}
@@ -0,0 +1,141 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::configs::system::RedisConsumerOffsetsConfig;
+use crate::streaming::partitions::partition::ConsumerOffset;
+use crate::streaming::storage::ConsumerOffsetStorage;
+use iggy::consumer::ConsumerKind;
+use iggy::error::IggyError;
+use redis::aio::ConnectionManager;
+use redis::{AsyncCommands, Client};
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter};
+use tokio::sync::OnceCell;
+use tracing::{error, trace};
+
+/// Stores the offsets in the hashes of the external Redis server, keyed by the offsets directory
+/// prefixed with the configured key prefix, so that the server can be replaced without moving its data.
+pub struct RedisConsumerOffsetStorage {
+    client: Client,
+    key_prefix: String,
+    connection: OnceCell<ConnectionManager>,
+}
+
+impl Debug for RedisConsumerOffsetStorage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RedisConsumerOffsetStorage")
+            .field("key_prefix", &self.key_prefix)
+            .finish()
+    }
+}
+
+impl RedisConsumerOffsetStorage {
+    pub fn new(config: &RedisConsumerOffsetsConfig) -> Self {
+        Self {
+            client: Client::open(config.url.as_str()).expect("Invalid Redis URL"),
+            key_prefix: config.key_prefix.clone(),
+            connection: OnceCell::new(),
+        }
+    }
+
+    async fn get_connection(&self, path: &str) -> Result<ConnectionManager, IggyError> {
+        self.connection
+            .get_or_try_init(|| ConnectionManager::new(self.client.clone()))
+            .await
+            .cloned()
+            .map_err(|error| {
+                error!(
+                    "Cannot connect to Redis to access consumer offsets: {path}. Error: {error}"
+                );
+                IggyError::CannotReadConsumerOffsets(path.to_owned())
+            })
+    }
+
+    fn get_key(&self, path: &str) -> String {
+        format!("{}:{path}", self.key_prefix)
+    }
+}
+
+impl ConsumerOffsetStorage for RedisConsumerOffsetStorage {
+    async fn save_consumer_offset(
+        &self,
+        path: &str,
+        consumer_id: u32,
+        offset: u64,
+    ) -> Result<(), IggyError> {
+        let mut connection = self.get_connection(path).await?;
+        connection
+            .hset::<_, _, _, ()>(self.get_key(path), consumer_id, offset)
+            .await
+            .map_err(|error| {
+                error!("Cannot save consumer offset for consumer with ID: {consumer_id}, path: {path}. Error: {error}");
+                IggyError::CannotSaveConsumerOffset(path.to_owned())
+            })?;
+        trace!("Stored consumer offset value: {offset} for consumer with ID: {consumer_id}, path: {path}");
+        Ok(())
+    }
+
+    async fn load_consumer_offsets(
+        &self,
+        kind: ConsumerKind,
+        path: &str,
+    ) -> Result<Vec<ConsumerOffset>, IggyError> {
+        trace!("Loading consumer offsets from Redis for path: {path}...");
+        let mut connection = self.get_connection(path).await?;
+        let offsets: HashMap<u32, u64> =
+            connection
+                .hgetall(self.get_key(path))
+                .await
+                .map_err(|error| {
+                    error!("Cannot load consumer offsets for path: {path}. Error: {error}");
+                    IggyError::CannotReadConsumerOffsets(path.to_owned())
+                })?;
+        let mut consumer_offsets = offsets
+            .into_iter()
+            .map(|(consumer_id, offset)| ConsumerOffset::new(kind, consumer_id, offset, path))
+            .collect::<Vec<_>>();
+        consumer_offsets.sort_by(|a, b| a.consumer_id.cmp(&b.consumer_id));
+        Ok(consumer_offsets)
+    }
+
+    async fn delete_consumer_offsets(&self, path: &str) -> Result<(), IggyError> {
+        let mut connection = self.get_connection(path).await?;
+        connection
+            .del::<_, ()>(self.get_key(path))
+            .await
+            .map_err(|error| {
+                error!("Cannot delete consumer offsets for path: {path}. Error: {error}");
+                IggyError::CannotDeleteConsumerOffsetsDirectory(path.to_owned())
+            })
+    }
+
+    async fn delete_consumer_offset(&self, path: &str, consumer_id: u32) -> Result<(), IggyError> {
+        let mut connection = self.get_connection(path).await?;
+        connection
+            .hdel::<_, _, ()>(self.get_key(path), consumer_id)
+            .await
+            .map_err(|error| {
+                error!("Cannot delete consumer offset for consumer with ID: {consumer_id}, path: {path}. Error: {error}");
+                IggyError::CannotDeleteConsumerOffsetFile(format!("{path}/{consumer_id}"))
+            })
+    }
+
+    async fn flush(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
@@ -16,16 +16,46 @@
  * under the License.
  */
 
+use crate::streaming::diagnostics::storage_metrics::StorageMetrics;
+use iggy::models::namespace::Namespace;
 use prometheus_client::encoding::text::encode;
+use prometheus_client::encoding::EncodeLabelSet;
 use prometheus_client::metrics::counter::Counter;
+use prometheus_client::metrics::family::Family;
 use prometheus_client::metrics::gauge::Gauge;
+use prometheus_client::metrics::histogram::{exponential_buckets, Histogram};
 use prometheus_client::registry::Registry;
+use std::time::Duration;
 use tracing::error;
 
+type HistogramFamily<L> = Family<L, Histogram, fn() -> Histogram>;
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub(crate) struct HttpRouteLabels {
+    pub method: String,
+    pub route: String,
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub(crate) struct HttpResponseLabels {
+    pub method: String,
+    pub route: String,
+    pub status: u16,
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub(crate) struct NamespaceLabels {
+    pub namespace: String,
+}
+
 #[derive(Debug)]
 pub(crate) struct Metrics {
     registry: Registry,
     http_requests: Counter,
+    http_responses: Family<HttpResponseLabels, Counter>,
+    http_request_duration: HistogramFamily<HttpRouteLabels>,
+    http_request_size: HistogramFamily<HttpRouteLabels>,
+    http_response_size: HistogramFamily<HttpRouteLabels>,
     streams: Gauge,
     topics: Gauge,
     partitions: Gauge,
@@ -33,6 +63,10 @@ pub(crate) struct Metrics {
     messages: Gauge,
     users: Gauge,
     clients: Gauge,
+    namespace_streams: Family<NamespaceLabels, Gauge>,
+    namespace_users: Family<NamespaceLabels, Gauge>,
+    namespace_messages: Family<NamespaceLabels, Gauge>,
+    namespace_size: Family<NamespaceLabels, Gauge>,
 }
 
 impl Metrics {
@@ -40,6 +74,16 @@ impl Metrics {
         let mut metrics = Metrics {
             registry: <Registry>::default(),
             http_requests: Counter::default(),
+            http_responses: Family::default(),
+            http_request_duration: Family::new_with_constructor(|| {
+                Histogram::new(exponential_buckets(0.000_1, 2.0, 18))
+            }),
+            http_request_size: Family::new_with_constructor(|| {
+                Histogram::new(exponential_buckets(64.0, 4.0, 12))
+            }),
+            http_response_size: Family::new_with_constructor(|| {
+                Histogram::new(exponential_buckets(64.0, 4.0, 12))
+            }),
             streams: Gauge::default(),
             topics: Gauge::default(),
             partitions: Gauge::default(),
@@ -47,6 +91,10 @@ impl Metrics {
             messages: Gauge::default(),
             users: Gauge::default(),
             clients: Gauge::default(),
+            namespace_streams: Family::default(),
+            namespace_users: Family::default(),
+            namespace_messages: Family::default(),
+            namespace_size: Family::default(),
         };
 
         metrics.register_counter("http_requests", metrics.http_requests.clone());
@@ -57,10 +105,107 @@ impl Metrics {
         metrics.register_gauge("messages", metrics.messages.clone());
         metrics.register_gauge("users", metrics.users.clone());
         metrics.register_gauge("clients", metrics.clients.clone());
+        metrics.register_http_metrics();
+        metrics.register_namespace_metrics();
+        metrics.register_storage_metrics(StorageMetrics::get_instance());
 
         metrics
     }
 
+    fn register_http_metrics(&mut self) {
+        self.registry.register(
+            "http_responses",
+            "number of the HTTP responses, per route and status code",
+            self.http_responses.clone(),
+        );
+        self.registry.register(
+            "http_request_duration_seconds",
+            "latency of the HTTP requests in seconds, per route",
+            self.http_request_duration.clone(),
+        );
+        self.registry.register(
+            "http_request_size_bytes",
+            "size of the HTTP request bodies in bytes, per route",
+            self.http_request_size.clone(),
+        );
+        self.registry.register(
+            "http_response_size_bytes",
+            "size of the HTTP response bodies in bytes, per route",
+            self.http_response_size.clone(),
+        );
+    }
+
+    fn register_namespace_metrics(&mut self) {
+        self.registry.register(
+            "namespace_streams",
+            "total count of streams, per namespace",
+            self.namespace_streams.clone(),
+        );
+        self.registry.register(
+            "namespace_users",
+            "total count of users, per namespace",
+            self.namespace_users.clone(),
+        );
+        self.registry.register(
+            "namespace_messages",
+            "total count of messages, per namespace",
+            self.namespace_messages.clone(),
+        );
+        self.registry.register(
+            "namespace_size_bytes",
+            "total size of the streams in bytes, per namespace",
+            self.namespace_size.clone(),
+        );
+    }
+
+    fn register_storage_metrics(&mut self, storage: &StorageMetrics) {
+        self.registry.register(
+            "storage_fsync_latency_seconds",
+            "latency of the fsync calls in seconds",
+            storage.fsync_latency.clone(),
+        );
+        self.registry.register(
+            "storage_write_size_bytes",
+            "size of the writes to the log, index and state files in bytes",
+            storage.write_size.clone(),
+        );
+        self.registry.register(
+            "storage_batch_coalescing_factor",
+            "number of messages written to the log with a single write",
+            storage.batch_coalescing_factor.clone(),
+        );
+        self.registry.register(
+            "storage_flush_queue_depth",
+            "number of pending writes in the asynchronous persister queue",
+            storage.flush_queue_depth.clone(),
+        );
+        self.registry.register(
+            "storage_appended_bytes",
+            "total bytes of the messages requested to be persisted",
+            storage.appended_bytes.clone(),
+        );
+        self.registry.register(
+            "storage_written_bytes",
+            "total bytes written to disk, divided by the appended bytes gives the write amplification",
+            storage.written_bytes.clone(),
+        );
+        self.registry.register(
+            "storage_index_cache_hits",
+            "number of index lookups served from the index cache, per topic",
+            storage.index_cache_hits.clone(),
+        );
+        self.registry.register(
+            "storage_index_cache_misses",
+            "number of index lookups which had to read the indexes from disk, per topic",
+            storage.index_cache_misses.clone(),
+        );
+        self.registry.register(
+            "storage_index_cache_size_bytes",
+            "size of the indexes held by the index cache in bytes",
+            storage.index_cache_size.clone(),
+        );
+    }
+
     fn register_counter(&mut self, name: &str, counter: Counter) {
         self.registry
             .register(name, format!("total count of {name}"), counter)
@@ -83,6 +228,32 @@ impl Metrics {
         self.http_requests.inc();
     }
 
+    pub fn record_http_response(
+        &self,
+        route: HttpRouteLabels,
+        status: u16,
+        duration: Duration,
+        request_size: u64,
+        response_size: u64,
+    ) {
+        self.http_responses
+            .get_or_create(&HttpResponseLabels {
+                method: route.method.clone(),
+                route: route.route.clone(),
+                status,
+            })
+            .inc();
+        self.http_request_duration
+            .get_or_create(&route)
+            .observe(duration.as_secs_f64());
+        self.http_request_size
+            .get_or_create(&route)
+            .observe(request_size as f64);
+        self.http_response_size
+            .get_or_create(&route)
+            .observe(response_size as f64);
+    }
+
     pub fn increment_streams(&self, count: u32) {
         self.streams.inc_by(count as i64);
     }
@@ -138,4 +309,87 @@ impl Metrics {
     pub fn decrement_clients(&self, count: u32) {
         self.clients.dec_by(count as i64);
     }
+
+    pub fn record_namespace(&self, namespace: &Namespace) {
+        let labels = NamespaceLabels {
+            namespace: namespace.name.clone(),
+        };
+        self.namespace_streams
+            .get_or_create(&labels)
+            .set(namespace.streams_count as i64);
+        self.namespace_users
+            .get_or_create(&labels)
+            .set(namespace.users_count as i64);
+        self.namespace_messages
+            .get_or_create(&labels)
+            .set(namespace.messages_count as i64);
+        self.namespace_size
+            .get_or_create(&labels)
+            .set(namespace.size.as_bytes_u64() as i64);
+    }
+
+    pub fn remove_namespace(&self, name: &str) {
+        let labels = NamespaceLabels {
+            namespace: name.to_owned(),
+        };
+        self.namespace_streams.remove(&labels);
+        self.namespace_users.remove(&labels);
+        self.namespace_messages.remove(&labels);
+        self.namespace_size.remove(&labels);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy::utils::byte_size::IggyByteSize;
+
+    #[test]
+    fn http_responses_should_be_exported_per_route_and_status() {
+        let metrics = Metrics::init();
+        let route = HttpRouteLabels {
+            method: "GET".to_owned(),
+            route: "/streams/{stream_id}".to_owned(),
+        };
+        metrics.record_http_response(route.clone(), 200, Duration::from_millis(3), 0, 512);
+        metrics.record_http_response(route.clone(), 200, Duration::from_millis(5), 0, 512);
+        metrics.record_http_response(route, 404, Duration::from_millis(1), 0, 64);
+
+        let output = metrics.get_formatted_output();
+        assert!(output.contains(
+            "http_responses_total{method=\"GET\",route=\"/streams/{stream_id}\",status=\"200\"} 2"
+        ));
+        assert!(output.contains(
+            "http_responses_total{method=\"GET\",route=\"/streams/{stream_id}\",status=\"404\"} 1"
+        ));
+        assert!(output.contains(
+            "http_request_duration_seconds_count{method=\"GET\",route=\"/streams/{stream_id}\"} 3"
+        ));
+    }
+
+    #[test]
+    fn namespace_metrics_should_be_exported_per_namespace_until_removed() {
+        let metrics = Metrics::init();
+        let namespace = Namespace {
+            id: 1,
+            created_at: 0,
+            name: "tenant-a".to_owned(),
+            quotas: Default::default(),
+            streams_count: 2,
+            users_count: 3,
+            size: IggyByteSize::from(1024),
+            messages_count: 10,
+        };
+        metrics.record_namespace(&namespace);
+
+        let output = metrics.get_formatted_output();
+        assert!(output.contains("namespace_streams{namespace=\"tenant-a\"} 2"));
+        assert!(output.contains("namespace_users{namespace=\"tenant-a\"} 3"));
+        assert!(output.contains("namespace_messages{namespace=\"tenant-a\"} 10"));
+        assert!(output.contains("namespace_size_bytes{namespace=\"tenant-a\"} 1024"));
+
+        metrics.remove_namespace("tenant-a");
+        let output = metrics.get_formatted_output();
+        assert!(!output.contains("namespace=\"tenant-a\""));
+    }
 }
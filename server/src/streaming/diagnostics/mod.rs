@@ -17,3 +17,5 @@
  */
 
 pub mod metrics;
+pub mod storage_metrics;
+pub mod telemetry_metrics;
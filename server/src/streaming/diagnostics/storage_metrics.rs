@@ -0,0 +1,157 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use prometheus_client::encoding::EncodeLabelSet;
+use prometheus_client::metrics::counter::Counter;
+use prometheus_client::metrics::family::Family;
+use prometheus_client::metrics::gauge::Gauge;
+use prometheus_client::metrics::histogram::{exponential_buckets, Histogram};
+use std::sync::OnceLock;
+use std::time::Duration;
+
+static INSTANCE: OnceLock<StorageMetrics> = OnceLock::new();
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub(crate) struct TopicLabels {
+    pub stream_id: u32,
+    pub topic_id: u32,
+}
+
+/// Storage I/O metrics recorded by the segment writers and the persisters.
+/// The instance is shared by the whole process, as the writers are created deep down the partitions
+/// and have no access to the system, and it's registered in the metrics registry on initialization.
+#[derive(Debug, Clone)]
+pub struct StorageMetrics {
+    /// Duration of a single fsync call, in seconds.
+    pub(crate) fsync_latency: Histogram,
+    /// Size of a single write to the log, index or state file, in bytes.
+    pub(crate) write_size: Histogram,
+    /// Number of messages written to the log with a single write.
+    pub(crate) batch_coalescing_factor: Histogram,
+    /// Number of pending write requests observed by the asynchronous persister when a new one is queued.
+    pub(crate) flush_queue_depth: Histogram,
+    /// Bytes of the messages requested to be persisted.
+    pub(crate) appended_bytes: Counter,
+    /// Bytes actually written to disk, including the batch headers, indexes and retried writes.
+    pub(crate) written_bytes: Counter,
+    /// Number of index lookups served from the index cache, per topic.
+    pub(crate) index_cache_hits: Family<TopicLabels, Counter>,
+    /// Number of index lookups which had to read the indexes from disk, per topic.
+    pub(crate) index_cache_misses: Family<TopicLabels, Counter>,
+    /// Bytes of the indexes currently held by the index cache.
+    pub(crate) index_cache_size: Gauge,
+}
+
+impl StorageMetrics {
+    pub fn get_instance() -> &'static StorageMetrics {
+        INSTANCE.get_or_init(StorageMetrics::new)
+    }
+
+    fn new() -> Self {
+        StorageMetrics {
+            fsync_latency: Histogram::new(exponential_buckets(0.000_05, 2.0, 16)),
+            write_size: Histogram::new(exponential_buckets(16.0, 4.0, 12)),
+            batch_coalescing_factor: Histogram::new(exponential_buckets(1.0, 2.0, 16)),
+            flush_queue_depth: Histogram::new(exponential_buckets(1.0, 2.0, 12)),
+            appended_bytes: Counter::default(),
+            written_bytes: Counter::default(),
+            index_cache_hits: Family::default(),
+            index_cache_misses: Family::default(),
+            index_cache_size: Gauge::default(),
+        }
+    }
+
+    pub fn record_fsync(&self, duration: Duration) {
+        self.fsync_latency.observe(duration.as_secs_f64());
+    }
+
+    pub fn record_write(&self, size_bytes: u64) {
+        self.write_size.observe(size_bytes as f64);
+        self.written_bytes.inc_by(size_bytes);
+    }
+
+    pub fn record_appended_batch(&self, messages_count: u32, size_bytes: u64) {
+        self.batch_coalescing_factor.observe(messages_count as f64);
+        self.appended_bytes.inc_by(size_bytes);
+    }
+
+    pub fn record_flush_queue_depth(&self, depth: usize) {
+        self.flush_queue_depth.observe(depth as f64);
+    }
+
+    pub fn record_index_cache_access(&self, stream_id: u32, topic_id: u32, hit: bool) {
+        let labels = TopicLabels {
+            stream_id,
+            topic_id,
+        };
+        if hit {
+            self.index_cache_hits.get_or_create(&labels).inc();
+        } else {
+            self.index_cache_misses.get_or_create(&labels).inc();
+        }
+    }
+
+    pub fn set_index_cache_size(&self, size_bytes: u64) {
+        self.index_cache_size.set(size_bytes as i64);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prometheus_client::encoding::text::encode;
+    use prometheus_client::registry::Registry;
+
+    #[test]
+    fn recorded_values_should_be_exported() {
+        let metrics = StorageMetrics::new();
+        metrics.record_write(100);
+        metrics.record_write(24);
+        metrics.record_appended_batch(10, 100);
+        metrics.record_fsync(Duration::from_millis(2));
+        metrics.record_flush_queue_depth(3);
+        metrics.record_index_cache_access(1, 2, true);
+        metrics.record_index_cache_access(1, 2, false);
+        metrics.record_index_cache_access(1, 2, true);
+
+        let mut registry = <Registry>::default();
+        registry.register(
+            "storage_written_bytes",
+            "written bytes",
+            metrics.written_bytes.clone(),
+        );
+        registry.register(
+            "storage_fsync_latency_seconds",
+            "fsync latency",
+            metrics.fsync_latency.clone(),
+        );
+        registry.register(
+            "storage_index_cache_hits",
+            "index cache hits",
+            metrics.index_cache_hits.clone(),
+        );
+        let mut output = String::new();
+        encode(&mut output, &registry).unwrap();
+
+        assert_eq!(metrics.written_bytes.get(), 124);
+        assert_eq!(metrics.appended_bytes.get(), 100);
+        assert!(output.contains("storage_written_bytes_total 124"));
+        assert!(output.contains("storage_fsync_latency_seconds_count 1"));
+        assert!(output.contains("storage_index_cache_hits_total{stream_id=\"1\",topic_id=\"2\"} 2"));
+    }
+}
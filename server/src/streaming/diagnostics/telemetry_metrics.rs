@@ -0,0 +1,159 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use opentelemetry::metrics::{Counter, Histogram};
+use opentelemetry::{global, KeyValue};
+use std::sync::OnceLock;
+use std::time::Duration;
+
+static INSTANCE: OnceLock<TelemetryMetrics> = OnceLock::new();
+
+const METER_NAME: &str = "iggy-server";
+
+/// OpenTelemetry metrics of the append and poll paths and of the maintenance jobs, exported with the OTLP metrics exporter.
+/// The instruments are created from the global meter provider on the first use, which happens after the telemetry
+/// has been initialized, so they record nothing unless the `telemetry.metrics` are enabled.
+#[derive(Debug, Clone)]
+pub struct TelemetryMetrics {
+    /// Number of messages appended to the partitions, per topic.
+    appended_messages: Counter<u64>,
+    /// Bytes of the messages appended to the partitions, per topic.
+    appended_bytes: Counter<u64>,
+    /// Duration of appending the batch of messages to the partition, in seconds.
+    append_duration: Histogram<f64>,
+    /// Number of messages polled from the partitions, per topic.
+    polled_messages: Counter<u64>,
+    /// Bytes of the messages polled from the partitions, per topic.
+    polled_bytes: Counter<u64>,
+    /// Duration of reading the polled messages from the partition, in seconds.
+    poll_duration: Histogram<f64>,
+    /// Duration of a single run of the maintenance job, in seconds, per job.
+    maintenance_duration: Histogram<f64>,
+    /// Number of segments deleted by the messages maintainer, per topic.
+    deleted_segments: Counter<u64>,
+    /// Number of messages deleted by the messages maintainer, per topic.
+    deleted_messages: Counter<u64>,
+}
+
+impl TelemetryMetrics {
+    pub fn get_instance() -> &'static TelemetryMetrics {
+        INSTANCE.get_or_init(TelemetryMetrics::new)
+    }
+
+    fn new() -> Self {
+        let meter = global::meter(METER_NAME);
+        TelemetryMetrics {
+            appended_messages: meter
+                .u64_counter("iggy.messages.appended")
+                .with_description("Number of messages appended to the partitions.")
+                .build(),
+            appended_bytes: meter
+                .u64_counter("iggy.messages.appended_bytes")
+                .with_description("Bytes of the messages appended to the partitions.")
+                .with_unit("By")
+                .build(),
+            append_duration: meter
+                .f64_histogram("iggy.messages.append_duration")
+                .with_description("Duration of appending the batch of messages.")
+                .with_unit("s")
+                .build(),
+            polled_messages: meter
+                .u64_counter("iggy.messages.polled")
+                .with_description("Number of messages polled from the partitions.")
+                .build(),
+            polled_bytes: meter
+                .u64_counter("iggy.messages.polled_bytes")
+                .with_description("Bytes of the messages polled from the partitions.")
+                .with_unit("By")
+                .build(),
+            poll_duration: meter
+                .f64_histogram("iggy.messages.poll_duration")
+                .with_description("Duration of reading the polled messages.")
+                .with_unit("s")
+                .build(),
+            maintenance_duration: meter
+                .f64_histogram("iggy.maintenance.duration")
+                .with_description("Duration of a single run of the maintenance job.")
+                .with_unit("s")
+                .build(),
+            deleted_segments: meter
+                .u64_counter("iggy.maintenance.deleted_segments")
+                .with_description("Number of segments deleted by the messages maintainer.")
+                .build(),
+            deleted_messages: meter
+                .u64_counter("iggy.maintenance.deleted_messages")
+                .with_description("Number of messages deleted by the messages maintainer.")
+                .build(),
+        }
+    }
+
+    pub fn record_append(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        messages_count: u64,
+        size_bytes: u64,
+        duration: Duration,
+    ) {
+        let attributes = topic_attributes(stream_id, topic_id);
+        self.appended_messages.add(messages_count, &attributes);
+        self.appended_bytes.add(size_bytes, &attributes);
+        self.append_duration
+            .record(duration.as_secs_f64(), &attributes);
+    }
+
+    pub fn record_poll(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        messages_count: u64,
+        size_bytes: u64,
+        duration: Duration,
+    ) {
+        let attributes = topic_attributes(stream_id, topic_id);
+        self.polled_messages.add(messages_count, &attributes);
+        self.polled_bytes.add(size_bytes, &attributes);
+        self.poll_duration
+            .record(duration.as_secs_f64(), &attributes);
+    }
+
+    pub fn record_maintenance(&self, job: &'static str, duration: Duration) {
+        self.maintenance_duration
+            .record(duration.as_secs_f64(), &[KeyValue::new("job", job)]);
+    }
+
+    pub fn record_deleted_segments(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        segments_count: u32,
+        messages_count: u64,
+    ) {
+        let attributes = topic_attributes(stream_id, topic_id);
+        self.deleted_segments
+            .add(segments_count as u64, &attributes);
+        self.deleted_messages.add(messages_count, &attributes);
+    }
+}
+
+fn topic_attributes(stream_id: u32, topic_id: u32) -> [KeyValue; 2] {
+    [
+        KeyValue::new("stream_id", stream_id as i64),
+        KeyValue::new("topic_id", topic_id as i64),
+    ]
+}
@@ -19,14 +19,18 @@
 pub mod batching;
 pub mod cache;
 pub mod clients;
+pub mod consumer_offsets;
 mod deduplication;
 pub mod diagnostics;
 pub mod local_sizeable;
 pub mod models;
+pub mod namespaces;
 pub mod partitions;
 pub mod persistence;
 pub mod personal_access_tokens;
 pub mod polling_consumer;
+pub mod push;
+pub mod replay;
 pub mod segments;
 pub mod session;
 pub mod storage;
@@ -22,6 +22,7 @@ use crate::streaming::models::COMPONENT;
 use bytes::{BufMut, Bytes, BytesMut};
 use error_set::ErrContext;
 use iggy::bytes_serializable::BytesSerializable;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::error::IggyError;
 use iggy::models::messages::PolledMessage;
 use iggy::utils::byte_size::IggyByteSize;
@@ -68,6 +69,16 @@ impl RetainedMessage {
         };
         Ok(message)
     }
+
+    /// Checks if the payload has been compressed by the producer, as marked by the compression header.
+    pub fn is_compressed_by_producer(&self) -> Result<bool, IggyError> {
+        let Some(headers) = &self.headers else {
+            return Ok(false);
+        };
+
+        let headers = HashMap::from_bytes(headers.clone())?;
+        Ok(CompressionAlgorithm::from_headers(&Some(headers))?.is_some())
+    }
 }
 
 impl RetainedMessage {
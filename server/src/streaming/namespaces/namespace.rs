@@ -0,0 +1,41 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use iggy::models::namespace::NamespaceQuotas;
+use iggy::utils::timestamp::IggyTimestamp;
+
+/// The tenant grouping its own streams and users, which are only accessible by the users of the same namespace
+/// (or the users outside any namespace), and limited by the quotas of the namespace.
+#[derive(Debug)]
+pub struct Namespace {
+    pub namespace_id: u32,
+    pub name: String,
+    pub created_at: IggyTimestamp,
+    pub quotas: NamespaceQuotas,
+}
+
+impl Namespace {
+    pub fn new(namespace_id: u32, name: &str, quotas: NamespaceQuotas) -> Self {
+        Self {
+            namespace_id,
+            name: name.to_owned(),
+            created_at: IggyTimestamp::now(),
+            quotas,
+        }
+    }
+}
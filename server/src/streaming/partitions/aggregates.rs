@@ -0,0 +1,103 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::partitions::partition::Partition;
+use crate::streaming::partitions::COMPONENT;
+use crate::streaming::segments::Index;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::models::messages_aggregate::MessagesAggregate;
+use iggy::utils::byte_size::IggyByteSize;
+
+impl Partition {
+    /// Adds the persisted messages of the partition to the aggregate, reading only the segment indexes.
+    pub async fn aggregate_messages(
+        &self,
+        aggregate: &mut MessagesAggregate,
+    ) -> Result<(), IggyError> {
+        for segment in &self.segments {
+            let indexes = segment.load_indexes().await.with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to load indexes for segment with start offset: {}, partition: {self}", segment.start_offset)
+            })?;
+            aggregate_indexes(aggregate, &indexes, segment.last_index_position);
+        }
+        Ok(())
+    }
+}
+
+/// Each index points to the last message of the batch, so the messages count and the size of the batch
+/// are derived from the difference with the previous index, and with the end of the log for the last one.
+fn aggregate_indexes(aggregate: &mut MessagesAggregate, indexes: &[Index], end_position: u32) {
+    let mut previous_offset = None;
+    for (position, index) in indexes.iter().enumerate() {
+        let messages_count = match previous_offset {
+            Some(previous_offset) => index.offset.saturating_sub(previous_offset),
+            None => index.offset + 1,
+        };
+        previous_offset = Some(index.offset);
+        let next_position = indexes
+            .get(position + 1)
+            .map(|next_index| next_index.position)
+            .unwrap_or(end_position);
+        aggregate.add(
+            index.timestamp,
+            messages_count as u64,
+            IggyByteSize::from(next_position.saturating_sub(index.position) as u64),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy::utils::duration::IggyDuration;
+
+    #[test]
+    fn batches_should_be_aggregated_from_indexes() {
+        let indexes = [
+            Index {
+                offset: 9,
+                position: 0,
+                timestamp: 1_500,
+            },
+            Index {
+                offset: 14,
+                position: 1_000,
+                timestamp: 1_900,
+            },
+            Index {
+                offset: 19,
+                position: 1_500,
+                timestamp: 3_100,
+            },
+        ];
+        let mut aggregate =
+            MessagesAggregate::new(1_000.into(), 4_000.into(), IggyDuration::from(1_000));
+
+        aggregate_indexes(&mut aggregate, &indexes, 2_200);
+
+        let buckets = aggregate.into_buckets();
+        assert_eq!(buckets[0].messages_count, 15);
+        assert_eq!(buckets[0].size_bytes, IggyByteSize::from(1_500));
+        assert_eq!(buckets[0].min_timestamp.as_micros(), 1_500);
+        assert_eq!(buckets[0].max_timestamp.as_micros(), 1_900);
+        assert!(buckets[1].is_empty());
+        assert_eq!(buckets[2].messages_count, 5);
+        assert_eq!(buckets[2].size_bytes, IggyByteSize::from(700));
+    }
+}
@@ -0,0 +1,273 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::archiver::ArchiverKind;
+use crate::streaming::partitions::partition::Partition;
+use crate::streaming::partitions::COMPONENT;
+use crate::streaming::segments::Segment;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::models::restored_segments::RestoredSegments;
+use iggy::utils::expiry::IggyExpiry;
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::Ordering;
+use tracing::{info, trace, warn};
+
+pub const ARCHIVED_SEGMENTS_FILE: &str = "archived_segments.json";
+
+/// The segment of the partition which has been stored using the archiver,
+/// so that it can be fetched back by the tiered storage once it's no longer available locally.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ArchivedSegment {
+    pub start_offset: u64,
+    pub end_offset: u64,
+    /// Whether the segment has been restored back into the local storage, in which case it never expires.
+    #[serde(default)]
+    pub restored: bool,
+}
+
+impl ArchivedSegment {
+    pub fn contains(&self, offset: u64) -> bool {
+        offset >= self.start_offset && offset <= self.end_offset
+    }
+}
+
+impl Partition {
+    pub fn get_archived_segments_path(&self) -> String {
+        format!("{}/{ARCHIVED_SEGMENTS_FILE}", self.partition_path)
+    }
+
+    /// Returns the archived segment containing the given offset, if any.
+    pub fn find_archived_segment(&self, offset: u64) -> Option<ArchivedSegment> {
+        self.archived_segments
+            .iter()
+            .find(|segment| segment.contains(offset))
+            .copied()
+    }
+
+    /// Returns `true` if the local segment with the given start offset has been restored from the archive.
+    pub fn is_restored_segment(&self, start_offset: u64) -> bool {
+        self.archived_segments
+            .iter()
+            .any(|segment| segment.restored && segment.start_offset == start_offset)
+    }
+
+    /// Loads the list of the archived segments, if it exists and can be parsed.
+    pub async fn load_archived_segments(&mut self) {
+        let path = self.get_archived_segments_path();
+        let data = match tokio::fs::read(&path).await {
+            Ok(data) => data,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+                trace!("Archived segments at path: {path} do not exist.");
+                return;
+            }
+            Err(error) => {
+                warn!("Failed to read archived segments at path: {path}. {error}");
+                return;
+            }
+        };
+
+        match serde_json::from_slice::<Vec<ArchivedSegment>>(&data) {
+            Ok(archived_segments) => self.archived_segments = archived_segments,
+            Err(error) => {
+                warn!("Failed to parse archived segments at path: {path}, they will be ignored. {error}");
+            }
+        }
+    }
+
+    /// Records the segments which have been archived, ignoring the ones already known.
+    pub async fn add_archived_segments(
+        &mut self,
+        archived_segments: &[ArchivedSegment],
+    ) -> Result<(), IggyError> {
+        let mut added = false;
+        for archived_segment in archived_segments {
+            if self
+                .archived_segments
+                .iter()
+                .any(|segment| segment.start_offset == archived_segment.start_offset)
+            {
+                continue;
+            }
+
+            self.archived_segments.push(*archived_segment);
+            added = true;
+        }
+
+        if !added {
+            return Ok(());
+        }
+
+        self.archived_segments
+            .sort_by(|a, b| a.start_offset.cmp(&b.start_offset));
+        self.save_archived_segments().await
+    }
+
+    /// Restores the archived segments overlapping the given offsets range, which are no longer available locally,
+    /// by fetching them from the archiver back into the partition directory.
+    /// The restored segments never expire, otherwise they would be deleted again by the messages maintenance.
+    pub async fn restore_archived_segments(
+        &mut self,
+        archiver: &ArchiverKind,
+        start_offset: u64,
+        end_offset: u64,
+    ) -> Result<RestoredSegments, IggyError> {
+        let archived_segments = self
+            .archived_segments
+            .iter()
+            .filter(|segment| {
+                segment.start_offset <= end_offset && segment.end_offset >= start_offset
+            })
+            .filter(|segment| self.get_segment(segment.start_offset).is_none())
+            .copied()
+            .collect::<Vec<_>>();
+
+        let mut restored_segments = RestoredSegments::default();
+        let mut result = Ok(());
+        for archived_segment in archived_segments {
+            let segment = match self
+                .fetch_archived_segment(archiver, archived_segment)
+                .await
+            {
+                Ok(segment) => segment,
+                Err(error) => {
+                    result = Err(error);
+                    break;
+                }
+            };
+
+            restored_segments.segments_count += 1;
+            restored_segments.messages_count += segment.get_messages_count();
+            restored_segments.size += segment.size_bytes;
+            self.segments_count_of_parent_stream
+                .fetch_add(1, Ordering::SeqCst);
+            self.segments.push(segment);
+            if let Some(segment) = self
+                .archived_segments
+                .iter_mut()
+                .find(|segment| segment.start_offset == archived_segment.start_offset)
+            {
+                segment.restored = true;
+            }
+        }
+
+        // The segments restored before the failure are kept, so they have to be recorded as well.
+        if restored_segments.segments_count > 0 {
+            self.segments
+                .sort_by(|a, b| a.start_offset.cmp(&b.start_offset));
+            self.save_archived_segments().await?;
+            info!(
+                "Restored {} archived segment(s) with {} message(s) for partition with ID: {} for topic with ID: {} and stream with ID: {}.",
+                restored_segments.segments_count,
+                restored_segments.messages_count,
+                self.partition_id,
+                self.topic_id,
+                self.stream_id
+            );
+        }
+
+        result.map(|_| restored_segments)
+    }
+
+    async fn fetch_archived_segment(
+        &self,
+        archiver: &ArchiverKind,
+        archived_segment: ArchivedSegment,
+    ) -> Result<Segment, IggyError> {
+        info!(
+            "Restoring archived segment with start offset: {} for partition with ID: {} for topic with ID: {} and stream with ID: {}...",
+            archived_segment.start_offset, self.partition_id, self.topic_id, self.stream_id
+        );
+        let mut segment = Segment::create(
+            self.stream_id,
+            self.topic_id,
+            self.partition_id,
+            archived_segment.start_offset,
+            self.config.clone(),
+            IggyExpiry::NeverExpire,
+            self.compression_algorithm,
+            self.size_of_parent_stream.clone(),
+            self.size_of_parent_topic.clone(),
+            self.size_bytes.clone(),
+            self.messages_count_of_parent_stream.clone(),
+            self.messages_count_of_parent_topic.clone(),
+            self.messages_count.clone(),
+        );
+
+        // The segment files are archived using their original paths, so they're fetched right back there.
+        for file in [&segment.index_path, &segment.log_path] {
+            archiver
+                .fetch(file, None, file)
+                .await
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to fetch archived file: {file}, partition: {self}")
+                })
+                .map_err(|_| {
+                    IggyError::CannotRestoreArchivedSegment(
+                        archived_segment.start_offset,
+                        self.partition_id,
+                    )
+                })?;
+        }
+
+        segment.load_from_disk().await.with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to load restored segment: {segment}")
+        })?;
+        segment.is_closed = true;
+        segment.end_offset = segment.current_offset;
+        Ok(segment)
+    }
+
+    async fn save_archived_segments(&self) -> Result<(), IggyError> {
+        let data = serde_json::to_vec_pretty(&self.archived_segments)
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to serialize archived segments for partition: {self}")
+            })
+            .map_err(|_| IggyError::CannotSerializeResource)?;
+        let path = self.get_archived_segments_path();
+        self.storage
+            .persister
+            .overwrite(&path, &data)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to save archived segments at path: {path}")
+            })?;
+        trace!("Saved archived segments for partition: {self}.");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn archived_segment_should_contain_offsets_within_its_range() {
+        let segment = ArchivedSegment {
+            start_offset: 10,
+            end_offset: 19,
+            restored: false,
+        };
+
+        assert!(!segment.contains(9));
+        assert!(segment.contains(10));
+        assert!(segment.contains(15));
+        assert!(segment.contains(19));
+        assert!(!segment.contains(20));
+    }
+}
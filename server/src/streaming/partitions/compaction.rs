@@ -0,0 +1,362 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::models::messages::RetainedMessage;
+use crate::streaming::partitions::partition::Partition;
+use crate::streaming::partitions::COMPONENT;
+use crate::streaming::segments::Segment;
+use ahash::AHashMap;
+use error_set::ErrContext;
+use iggy::confirmation::Confirmation;
+use iggy::error::IggyError;
+use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::sizeable::Sizeable;
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use tokio::fs::{remove_file, rename};
+use tracing::{info, trace, warn};
+
+pub const COMPACTED_SEGMENTS_FILE: &str = "compacted_segments.json";
+const COMPACTING_EXTENSION: &str = "compacting";
+
+/// The segment of the partition which has been compacted, so that its messages count
+/// can be restored on startup, as the offsets of the retained messages are no longer contiguous.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CompactedSegment {
+    pub start_offset: u64,
+    pub end_offset: u64,
+    pub messages_count: u64,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CompactionResult {
+    pub segments_count: u32,
+    pub messages_count: u64,
+    pub size_bytes: u64,
+}
+
+impl Partition {
+    pub fn get_compacted_segments_path(&self) -> String {
+        format!("{}/{COMPACTED_SEGMENTS_FILE}", self.partition_path)
+    }
+
+    /// Returns the compacted segment starting at the given offset, if any.
+    pub fn find_compacted_segment(&self, start_offset: u64) -> Option<CompactedSegment> {
+        self.compacted_segments
+            .iter()
+            .find(|segment| segment.start_offset == start_offset)
+            .copied()
+    }
+
+    /// Loads the list of the compacted segments, if it exists and can be parsed.
+    pub async fn load_compacted_segments(&mut self) {
+        let path = self.get_compacted_segments_path();
+        let data = match tokio::fs::read(&path).await {
+            Ok(data) => data,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+                trace!("Compacted segments at path: {path} do not exist.");
+                return;
+            }
+            Err(error) => {
+                warn!("Failed to read compacted segments at path: {path}. {error}");
+                return;
+            }
+        };
+
+        match serde_json::from_slice::<Vec<CompactedSegment>>(&data) {
+            Ok(compacted_segments) => self.compacted_segments = compacted_segments,
+            Err(error) => {
+                warn!("Failed to parse compacted segments at path: {path}, they will be ignored. {error}");
+            }
+        }
+    }
+
+    /// Compacts the closed segments (except the last one) by keeping only the latest message for each message ID.
+    /// The retained messages keep their original offsets, so the compacted segments contain gaps in the offsets.
+    pub async fn compact(&mut self) -> Result<CompactionResult, IggyError> {
+        let mut result = CompactionResult::default();
+        if self.segments.len() < 2 {
+            return Ok(result);
+        }
+
+        let mut latest_offsets = AHashMap::new();
+        for segment in &self.segments {
+            let messages = segment.get_all_messages().await.with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to read messages from segment: {segment} for compaction")
+            })?;
+            track_latest_offsets(&mut latest_offsets, &messages);
+        }
+
+        let last_index = self.segments.len() - 1;
+        for index in 0..last_index {
+            let segment = &self.segments[index];
+            if !segment.is_closed {
+                continue;
+            }
+
+            let messages = segment.get_all_messages().await.with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to read messages from segment: {segment} for compaction")
+            })?;
+            let messages_count = messages.len() as u64;
+            let retained_messages = retain_latest_messages(messages, &latest_offsets);
+            let removed_messages_count = messages_count - retained_messages.len() as u64;
+            if removed_messages_count == 0 {
+                continue;
+            }
+
+            let size_bytes = segment.size_bytes.as_bytes_u64();
+            self.replace_with_compacted_segment(index, &retained_messages)
+                .await?;
+            let compacted_size_bytes = self.segments[index].size_bytes.as_bytes_u64();
+            result.segments_count += 1;
+            result.messages_count += removed_messages_count;
+            result.size_bytes += size_bytes.saturating_sub(compacted_size_bytes);
+        }
+
+        if result.segments_count == 0 {
+            return Ok(result);
+        }
+
+        self.save_compacted_segments().await?;
+        if let Err(error) = self.save_manifest(false).await {
+            warn!("Failed to save manifest after compaction for partition: {self}. {error}");
+        }
+        info!(
+            "Compacted {} segment(s) for partition with ID: {}, topic with ID: {} and stream with ID: {}, removed {} message(s).",
+            result.segments_count,
+            self.partition_id,
+            self.topic_id,
+            self.stream_id,
+            result.messages_count
+        );
+        Ok(result)
+    }
+
+    async fn replace_with_compacted_segment(
+        &mut self,
+        index: usize,
+        retained_messages: &[Arc<RetainedMessage>],
+    ) -> Result<(), IggyError> {
+        let segment = &self.segments[index];
+        let log_path = format!("{}.{COMPACTING_EXTENSION}", segment.log_path);
+        let index_path = format!("{}.{COMPACTING_EXTENSION}", segment.index_path);
+        for path in [&log_path, &index_path] {
+            if let Err(error) = remove_file(path).await {
+                if error.kind() != std::io::ErrorKind::NotFound {
+                    warn!("Failed to remove stale compaction file: {path}. {error}");
+                }
+            }
+        }
+
+        // The compacting segment must not affect the sizes and messages counts of the partition, topic and stream.
+        let mut compacting_segment = Segment::create(
+            self.stream_id,
+            self.topic_id,
+            self.partition_id,
+            segment.start_offset,
+            self.config.clone(),
+            segment.message_expiry,
+            segment.compression_algorithm,
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+        );
+        compacting_segment.log_path = log_path.clone();
+        compacting_segment.index_path = index_path.clone();
+        compacting_segment.max_size_bytes = IggyByteSize::from(u64::MAX);
+        // The indexes of the compacting segment would otherwise be appended to the cached ones of the original segment.
+        compacting_segment.index_cache = None;
+        compacting_segment.initialize_writing().await?;
+        let messages_per_batch = self.config.partition.messages_required_to_save.max(1) as usize;
+        for batch in retained_messages.chunks(messages_per_batch) {
+            let batch_size = batch
+                .iter()
+                .map(|message| message.get_size_bytes())
+                .sum::<IggyByteSize>();
+            compacting_segment
+                .append_batch(batch_size, batch.len() as u32, batch)
+                .await?;
+            compacting_segment
+                .persist_messages(Some(Confirmation::Wait))
+                .await
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to persist compacted messages to: {log_path}")
+                })?;
+        }
+        compacting_segment.shutdown_writing().await;
+
+        let segment = &mut self.segments[index];
+        segment.shutdown_reading().await;
+        if let Some(index_cache) = &segment.index_cache {
+            index_cache.remove(&segment.index_block_key());
+        }
+        for (compacted_path, path) in [
+            (&log_path, &segment.log_path),
+            (&index_path, &segment.index_path),
+        ] {
+            rename(compacted_path, path)
+                .await
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to replace file: {path} with compacted file: {compacted_path}")
+                })
+                .map_err(|_| IggyError::CannotCompactSegment(segment.start_offset, segment.partition_id))?;
+        }
+
+        let size_bytes = segment.size_bytes.as_bytes_u64();
+        let messages_count = segment.get_messages_count();
+        self.size_of_parent_stream
+            .fetch_sub(size_bytes, Ordering::AcqRel);
+        self.size_of_parent_topic
+            .fetch_sub(size_bytes, Ordering::AcqRel);
+        self.size_bytes.fetch_sub(size_bytes, Ordering::AcqRel);
+        self.messages_count_of_parent_stream
+            .fetch_sub(messages_count, Ordering::SeqCst);
+        self.messages_count_of_parent_topic
+            .fetch_sub(messages_count, Ordering::SeqCst);
+        self.messages_count
+            .fetch_sub(messages_count, Ordering::SeqCst);
+
+        let segment = &self.segments[index];
+        let mut compacted_segment = Segment::create(
+            self.stream_id,
+            self.topic_id,
+            self.partition_id,
+            segment.start_offset,
+            self.config.clone(),
+            segment.message_expiry,
+            segment.compression_algorithm,
+            self.size_of_parent_stream.clone(),
+            self.size_of_parent_topic.clone(),
+            self.size_bytes.clone(),
+            self.messages_count_of_parent_stream.clone(),
+            self.messages_count_of_parent_topic.clone(),
+            self.messages_count.clone(),
+        );
+        compacted_segment.compacted_messages_count = Some(retained_messages.len() as u64);
+        compacted_segment.initialize_reading().await?;
+        compacted_segment
+            .load_from_disk()
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to load compacted segment: {compacted_segment}")
+            })?;
+        compacted_segment.is_closed = true;
+        compacted_segment.start_timestamp = segment.start_timestamp;
+        compacted_segment.end_timestamp = segment.end_timestamp;
+        compacted_segment.end_offset = segment.end_offset;
+        compacted_segment.current_offset = segment.current_offset;
+
+        let compacted = CompactedSegment {
+            start_offset: compacted_segment.start_offset,
+            end_offset: compacted_segment.end_offset,
+            messages_count: retained_messages.len() as u64,
+        };
+        self.compacted_segments
+            .retain(|segment| segment.start_offset != compacted.start_offset);
+        self.compacted_segments.push(compacted);
+        self.segments[index] = compacted_segment;
+        Ok(())
+    }
+
+    async fn save_compacted_segments(&mut self) -> Result<(), IggyError> {
+        let start_offsets = self
+            .segments
+            .iter()
+            .map(|segment| segment.start_offset)
+            .collect::<Vec<_>>();
+        self.compacted_segments
+            .retain(|segment| start_offsets.contains(&segment.start_offset));
+        self.compacted_segments
+            .sort_by(|a, b| a.start_offset.cmp(&b.start_offset));
+        let data = serde_json::to_vec_pretty(&self.compacted_segments)
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to serialize compacted segments for partition: {self}")
+            })
+            .map_err(|_| IggyError::CannotSerializeResource)?;
+        let path = self.get_compacted_segments_path();
+        self.storage
+            .persister
+            .overwrite(&path, &data)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to save compacted segments at path: {path}")
+            })?;
+        trace!("Saved compacted segments for partition: {self}.");
+        Ok(())
+    }
+}
+
+/// Tracks the offset of the latest message for each message ID, the messages must be ordered by their offsets.
+fn track_latest_offsets(
+    latest_offsets: &mut AHashMap<u128, u64>,
+    messages: &[Arc<RetainedMessage>],
+) {
+    for message in messages {
+        latest_offsets.insert(message.id, message.offset);
+    }
+}
+
+fn retain_latest_messages(
+    messages: Vec<Arc<RetainedMessage>>,
+    latest_offsets: &AHashMap<u128, u64>,
+) -> Vec<Arc<RetainedMessage>> {
+    messages
+        .into_iter()
+        .filter(|message| latest_offsets.get(&message.id) == Some(&message.offset))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+    use iggy::models::messages::MessageState;
+
+    fn message(id: u128, offset: u64) -> Arc<RetainedMessage> {
+        Arc::new(RetainedMessage {
+            id,
+            offset,
+            timestamp: 0,
+            checksum: 0,
+            message_state: MessageState::Available,
+            headers: None,
+            payload: Bytes::from("test"),
+        })
+    }
+
+    #[test]
+    fn should_retain_only_the_latest_message_for_each_id() {
+        let first_segment = vec![message(1, 0), message(2, 1), message(1, 2), message(3, 3)];
+        let second_segment = vec![message(2, 4), message(4, 5)];
+        let mut latest_offsets = AHashMap::new();
+        track_latest_offsets(&mut latest_offsets, &first_segment);
+        track_latest_offsets(&mut latest_offsets, &second_segment);
+
+        let retained = retain_latest_messages(first_segment, &latest_offsets);
+
+        let offsets = retained
+            .iter()
+            .map(|message| message.offset)
+            .collect::<Vec<_>>();
+        assert_eq!(offsets, vec![2, 3]);
+    }
+}
@@ -87,20 +87,23 @@ impl Partition {
         Ok(())
     }
 
-    async fn store_offset(
+    pub(crate) async fn store_offset(
         &self,
         kind: ConsumerKind,
         consumer_id: u32,
         offset: u64,
     ) -> Result<(), IggyError> {
+        let path = match kind {
+            ConsumerKind::Consumer => &self.consumer_offsets_path,
+            ConsumerKind::ConsumerGroup => &self.consumer_group_offsets_path,
+        };
         let consumer_offsets = self.get_consumer_offsets(kind);
         if let Some(mut consumer_offset) = consumer_offsets.get_mut(&consumer_id) {
             consumer_offset.offset = offset;
-            let path = consumer_offset.path.clone();
             drop(consumer_offset);
             self.storage
-                .partition
-                .save_consumer_offset(offset, &path)
+                .consumer_offsets
+                .save_consumer_offset(path, consumer_id, offset)
                 .await
                 .with_error_context(|error| {
                     format!(
@@ -110,14 +113,10 @@ impl Partition {
             return Ok(());
         }
 
-        let path = match kind {
-            ConsumerKind::Consumer => &self.consumer_offsets_path,
-            ConsumerKind::ConsumerGroup => &self.consumer_group_offsets_path,
-        };
         let consumer_offset = ConsumerOffset::new(kind, consumer_id, offset, path);
         self.storage
-            .partition
-            .save_consumer_offset(offset, &consumer_offset.path)
+            .consumer_offsets
+            .save_consumer_offset(path, consumer_id, offset)
             .await
             .with_error_context(|error| {
                 format!(
@@ -157,7 +156,7 @@ impl Partition {
         };
         let loaded_consumer_offsets = self
             .storage
-            .partition
+            .consumer_offsets
             .load_consumer_offsets(kind, path)
             .await
             .with_error_context(|error| {
@@ -199,19 +198,17 @@ impl Partition {
         );
         match consumer {
             PollingConsumer::Consumer(consumer_id, _) => {
-                let (_, offset) = self
-                    .consumer_offsets
+                self.consumer_offsets
                     .remove(&consumer_id)
                     .ok_or(IggyError::ConsumerOffsetNotFound(consumer_id))?;
-                self.storage.partition.delete_consumer_offset(&offset.path).await
+                self.storage.consumer_offsets.delete_consumer_offset(&self.consumer_offsets_path, consumer_id).await
                     .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to delete consumer offset, consumer ID: {consumer_id}, partition ID: {partition_id}"))?;
             }
             PollingConsumer::ConsumerGroup(consumer_id, _) => {
-                let (_, offset) = self
-                    .consumer_group_offsets
+                self.consumer_group_offsets
                     .remove(&consumer_id)
                     .ok_or(IggyError::ConsumerOffsetNotFound(consumer_id))?;
-                self.storage.partition.delete_consumer_offset(&offset.path).await
+                self.storage.consumer_offsets.delete_consumer_offset(&self.consumer_group_offsets_path, consumer_id).await
                     .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to delete consumer group offset, consumer ID: {consumer_id}, partition ID: {partition_id}"))?;
             }
         };
@@ -0,0 +1,98 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::partitions::partition::Partition;
+use crate::streaming::partitions::COMPONENT;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use tracing::{info, trace, warn};
+
+pub const EPOCH_FILE: &str = "epoch";
+pub const INITIAL_EPOCH: u64 = 1;
+
+/// Returns an error if the epoch expected by the poller is no longer the current one of the partition.
+/// The expected epoch equal to 0 means that the poller doesn't know the epoch yet.
+pub fn check_epoch(partition_id: u32, expected_epoch: u64, epoch: u64) -> Result<(), IggyError> {
+    if expected_epoch > 0 && expected_epoch != epoch {
+        return Err(IggyError::PartitionEpochChanged(
+            partition_id,
+            expected_epoch,
+            epoch,
+        ));
+    }
+
+    Ok(())
+}
+
+impl Partition {
+    pub fn get_epoch_path(&self) -> String {
+        format!("{}/{EPOCH_FILE}", self.partition_path)
+    }
+
+    /// Loads the epoch of the partition, falling back to the initial one if it hasn't been advanced yet.
+    pub async fn load_epoch(&mut self) {
+        let path = self.get_epoch_path();
+        let data = match tokio::fs::read(&path).await {
+            Ok(data) => data,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+                trace!("Epoch at path: {path} does not exist.");
+                return;
+            }
+            Err(error) => {
+                warn!("Failed to read epoch at path: {path}. {error}");
+                return;
+            }
+        };
+
+        match <[u8; 8]>::try_from(data.as_slice()) {
+            Ok(bytes) => self.epoch = u64::from_le_bytes(bytes),
+            Err(_) => warn!("Invalid epoch at path: {path}, it will be ignored."),
+        }
+    }
+
+    /// Advances the epoch, once the offsets of the partition start over from 0,
+    /// so that the pollers relying on the previous offsets can detect it.
+    pub async fn advance_epoch(&mut self) -> Result<(), IggyError> {
+        self.epoch += 1;
+        let path = self.get_epoch_path();
+        self.storage
+            .persister
+            .overwrite(&path, &self.epoch.to_le_bytes())
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to save epoch at path: {path}")
+            })?;
+        info!("Advanced epoch to: {} for partition: {self}.", self.epoch);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn changed_epoch_should_be_rejected() {
+        assert!(check_epoch(1, 0, 3).is_ok());
+        assert!(check_epoch(1, 3, 3).is_ok());
+        assert!(matches!(
+            check_epoch(1, 2, 3),
+            Err(IggyError::PartitionEpochChanged(1, 2, 3))
+        ));
+    }
+}
@@ -0,0 +1,237 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::models::messages::RetainedMessage;
+use crate::streaming::partitions::compaction::CompactedSegment;
+use crate::streaming::partitions::partition::Partition;
+use crate::streaming::polling_consumer::PollingConsumer;
+use iggy::messages::message_filter::MessageFilter;
+use iggy::models::messages::{MessagesGap, MessagesGapReason, PolledMessage};
+use std::sync::Arc;
+
+impl Partition {
+    /// Returns the offset from which the next messages are polled for the consumer, if it has stored its offset.
+    pub fn get_next_expected_offset(&self, consumer: PollingConsumer) -> Option<u64> {
+        let consumer_offset = match consumer {
+            PollingConsumer::Consumer(consumer_id, _) => self.consumer_offsets.get(&consumer_id),
+            PollingConsumer::ConsumerGroup(group_id, _) => {
+                self.consumer_group_offsets.get(&group_id)
+            }
+        };
+        consumer_offset.map(|consumer_offset| consumer_offset.offset + 1)
+    }
+
+    /// Returns the ranges of offsets missing in the polled messages, including the one before the first message
+    /// if the expected offset is known. The offsets before the first segment are deleted, the ones within the compacted segments are compacted.
+    pub fn get_messages_gaps(
+        &self,
+        expected_offset: Option<u64>,
+        messages: &[Arc<RetainedMessage>],
+    ) -> Vec<MessagesGap> {
+        let first_available_offset = self
+            .segments
+            .first()
+            .map(|segment| segment.start_offset)
+            .unwrap_or_default();
+        resolve_gaps(
+            expected_offset,
+            messages.iter().map(|message| message.offset),
+            first_available_offset,
+            &self.compacted_segments,
+        )
+    }
+}
+
+/// Returns the messages matching the filter and the ranges of the consecutive offsets of the skipped ones.
+pub fn filter_messages(
+    filter: &MessageFilter,
+    messages: Vec<PolledMessage>,
+) -> (Vec<PolledMessage>, Vec<MessagesGap>) {
+    let mut matched_messages = Vec::with_capacity(messages.len());
+    let mut gaps: Vec<MessagesGap> = Vec::new();
+    for message in messages {
+        if filter.matches(&message) {
+            matched_messages.push(message);
+            continue;
+        }
+
+        match gaps.last_mut() {
+            Some(gap) if gap.end_offset + 1 == message.offset => gap.end_offset = message.offset,
+            _ => gaps.push(MessagesGap {
+                start_offset: message.offset,
+                end_offset: message.offset,
+                reason: MessagesGapReason::Filtered,
+            }),
+        }
+    }
+    (matched_messages, gaps)
+}
+
+fn resolve_gaps(
+    expected_offset: Option<u64>,
+    offsets: impl Iterator<Item = u64>,
+    first_available_offset: u64,
+    compacted_segments: &[CompactedSegment],
+) -> Vec<MessagesGap> {
+    let mut gaps = Vec::new();
+    let mut next_offset = expected_offset;
+    for offset in offsets {
+        if let Some(next_offset) = next_offset {
+            if offset > next_offset {
+                push_gap(
+                    &mut gaps,
+                    next_offset,
+                    offset - 1,
+                    first_available_offset,
+                    compacted_segments,
+                );
+            }
+        }
+        next_offset = Some(offset + 1);
+    }
+    gaps
+}
+
+fn push_gap(
+    gaps: &mut Vec<MessagesGap>,
+    mut start_offset: u64,
+    end_offset: u64,
+    first_available_offset: u64,
+    compacted_segments: &[CompactedSegment],
+) {
+    if start_offset < first_available_offset {
+        let deleted_end_offset = end_offset.min(first_available_offset - 1);
+        gaps.push(MessagesGap {
+            start_offset,
+            end_offset: deleted_end_offset,
+            reason: MessagesGapReason::Deleted,
+        });
+        if deleted_end_offset == end_offset {
+            return;
+        }
+
+        start_offset = deleted_end_offset + 1;
+    }
+
+    let is_compacted = compacted_segments
+        .iter()
+        .any(|segment| start_offset >= segment.start_offset && start_offset <= segment.end_offset);
+    gaps.push(MessagesGap {
+        start_offset,
+        end_offset,
+        reason: if is_compacted {
+            MessagesGapReason::Compacted
+        } else {
+            MessagesGapReason::Deleted
+        },
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+    use iggy::models::messages::MessageState;
+    use std::str::FromStr;
+
+    #[test]
+    fn should_resolve_deleted_and_compacted_gaps() {
+        let compacted_segments = [CompactedSegment {
+            start_offset: 10,
+            end_offset: 19,
+            messages_count: 4,
+        }];
+
+        let gaps = resolve_gaps(
+            Some(5),
+            [12, 13, 17, 18, 20].into_iter(),
+            10,
+            &compacted_segments,
+        );
+
+        assert_eq!(
+            gaps,
+            vec![
+                MessagesGap {
+                    start_offset: 5,
+                    end_offset: 9,
+                    reason: MessagesGapReason::Deleted,
+                },
+                MessagesGap {
+                    start_offset: 10,
+                    end_offset: 11,
+                    reason: MessagesGapReason::Compacted,
+                },
+                MessagesGap {
+                    start_offset: 14,
+                    end_offset: 16,
+                    reason: MessagesGapReason::Compacted,
+                },
+                MessagesGap {
+                    start_offset: 19,
+                    end_offset: 19,
+                    reason: MessagesGapReason::Compacted,
+                },
+            ]
+        );
+        assert!(resolve_gaps(None, [0, 1, 2].into_iter(), 0, &[]).is_empty());
+    }
+
+    #[test]
+    fn should_merge_consecutive_filtered_messages_into_gaps() {
+        let messages = (0..6)
+            .map(|offset| {
+                PolledMessage::create(
+                    offset,
+                    MessageState::Available,
+                    0.into(),
+                    offset as u128,
+                    Bytes::new(),
+                    0,
+                    None,
+                )
+            })
+            .collect();
+        let filter = MessageFilter::from_str("id in 2..3").unwrap();
+
+        let (messages, gaps) = filter_messages(&filter, messages);
+
+        assert_eq!(
+            messages
+                .iter()
+                .map(|message| message.offset)
+                .collect::<Vec<_>>(),
+            vec![2, 3]
+        );
+        assert_eq!(
+            gaps,
+            vec![
+                MessagesGap {
+                    start_offset: 0,
+                    end_offset: 1,
+                    reason: MessagesGapReason::Filtered,
+                },
+                MessagesGap {
+                    start_offset: 4,
+                    end_offset: 5,
+                    reason: MessagesGapReason::Filtered,
+                },
+            ]
+        );
+    }
+}
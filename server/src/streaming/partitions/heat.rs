@@ -0,0 +1,45 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::partitions::partition::Partition;
+use std::sync::atomic::Ordering;
+
+impl Partition {
+    /// Records the messages polled from the partition, making it more likely to be warmed up on the next startup.
+    pub fn record_heat(&self, messages_count: u64) {
+        if messages_count > 0 {
+            self.heat.fetch_add(messages_count, Ordering::Relaxed);
+        }
+    }
+
+    pub fn get_heat(&self) -> u64 {
+        self.heat.load(Ordering::Relaxed)
+    }
+
+    /// Returns the current heat and halves it, so that the partitions which are no longer polled cool down.
+    pub fn decay_heat(&self) -> u64 {
+        self.heat
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |heat| Some(heat / 2))
+            .unwrap_or_default()
+    }
+
+    /// Restores the heat saved before the restart.
+    pub fn restore_heat(&self, heat: u64) {
+        self.heat.store(heat, Ordering::Relaxed);
+    }
+}
@@ -0,0 +1,243 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::partitions::partition::Partition;
+use crate::streaming::partitions::COMPONENT;
+use dashmap::mapref::one::RefMut;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use tracing::{trace, warn};
+
+pub const IN_FLIGHT_MESSAGES_FILE: &str = "in_flight.json";
+
+/// The messages delivered to the members of the consumer group having the visibility timeout configured,
+/// which haven't been acknowledged yet, along with the timestamps (in microseconds) of their redelivery.
+/// The offsets below the next offset which aren't tracked anymore are considered acknowledged.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InFlightMessages {
+    next_offset: u64,
+    messages: BTreeMap<u64, u64>,
+}
+
+impl InFlightMessages {
+    pub fn new(next_offset: u64) -> Self {
+        InFlightMessages {
+            next_offset,
+            messages: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the offset of the first message which hasn't been delivered yet.
+    pub fn get_next_offset(&self) -> u64 {
+        self.next_offset
+    }
+
+    /// Forgets the messages below the given offset (e.g. when the offset has been stored explicitly),
+    /// moving the next offset forward if needed.
+    pub fn skip_to(&mut self, offset: u64) {
+        self.next_offset = self.next_offset.max(offset);
+        if self
+            .messages
+            .first_key_value()
+            .is_some_and(|(first_offset, _)| *first_offset < offset)
+        {
+            self.messages = self.messages.split_off(&offset);
+        }
+    }
+
+    /// Returns (up to the given count) the offsets of the messages which should be redelivered.
+    pub fn get_expired(&self, now: u64, count: u32) -> Vec<u64> {
+        self.messages
+            .iter()
+            .filter(|(_, deadline)| **deadline <= now)
+            .map(|(offset, _)| *offset)
+            .take(count as usize)
+            .collect()
+    }
+
+    /// Tracks the messages returned to the member, the offsets up to the last polled one are no longer delivered for the first time.
+    pub fn deliver(&mut self, offsets: &[u64], last_polled_offset: Option<u64>, deadline: u64) {
+        for offset in offsets {
+            self.messages.insert(*offset, deadline);
+        }
+        if let Some(offset) = offsets.iter().copied().max().max(last_polled_offset) {
+            self.next_offset = self.next_offset.max(offset + 1);
+        }
+    }
+
+    pub fn ack(&mut self, offsets: &[u64]) {
+        for offset in offsets {
+            self.messages.remove(offset);
+        }
+    }
+
+    /// Makes the messages available for the immediate redelivery.
+    pub fn release(&mut self, offsets: &[u64]) {
+        for offset in offsets {
+            if let Some(deadline) = self.messages.get_mut(offset) {
+                *deadline = 0;
+            }
+        }
+    }
+
+    /// Returns the offset up to which all the delivered messages have been acknowledged, `None` if there's no such offset.
+    pub fn get_committed_offset(&self) -> Option<u64> {
+        self.messages
+            .keys()
+            .next()
+            .copied()
+            .unwrap_or(self.next_offset)
+            .checked_sub(1)
+    }
+}
+
+impl Partition {
+    pub fn get_in_flight_messages_path(&self) -> String {
+        format!("{}/{IN_FLIGHT_MESSAGES_FILE}", self.offsets_path)
+    }
+
+    /// Returns the in-flight messages of the consumer group, which are tracked from its stored offset onwards.
+    pub fn get_in_flight_messages(&self, group_id: u32) -> RefMut<'_, u32, InFlightMessages> {
+        let next_offset = self
+            .consumer_group_offsets
+            .get(&group_id)
+            .map(|consumer_offset| consumer_offset.offset + 1)
+            .unwrap_or_default();
+        let mut in_flight_messages = self
+            .in_flight_messages
+            .entry(group_id)
+            .or_insert_with(|| InFlightMessages::new(next_offset));
+        in_flight_messages.skip_to(next_offset);
+        in_flight_messages
+    }
+
+    /// Loads the in-flight messages of the consumer groups, if they exist and can be parsed.
+    pub async fn load_in_flight_messages(&mut self) {
+        let path = self.get_in_flight_messages_path();
+        let data = match tokio::fs::read(&path).await {
+            Ok(data) => data,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+                trace!("In-flight messages at path: {path} do not exist.");
+                return;
+            }
+            Err(error) => {
+                warn!("Failed to read in-flight messages at path: {path}. {error}");
+                return;
+            }
+        };
+
+        match serde_json::from_slice::<BTreeMap<u32, InFlightMessages>>(&data) {
+            Ok(in_flight_messages) => {
+                self.in_flight_messages.clear();
+                self.in_flight_messages.extend(in_flight_messages);
+            }
+            Err(error) => {
+                warn!("Failed to parse in-flight messages at path: {path}, they will be redelivered after the stored offsets. {error}");
+            }
+        }
+    }
+
+    pub async fn persist_in_flight_messages(&self) -> Result<(), IggyError> {
+        let in_flight_messages = self
+            .in_flight_messages
+            .iter()
+            .map(|entry| (*entry.key(), entry.value().clone()))
+            .collect::<BTreeMap<_, _>>();
+        let data = serde_json::to_vec(&in_flight_messages)
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to serialize in-flight messages for partition: {self}")
+            })
+            .map_err(|_| IggyError::CannotSerializeResource)?;
+        let path = self.get_in_flight_messages_path();
+        self.storage
+            .persister
+            .overwrite(&path, &data)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to save in-flight messages at path: {path}")
+            })?;
+        trace!("Saved in-flight messages for partition: {self}.");
+        Ok(())
+    }
+
+    /// Stops tracking the in-flight messages of the consumer group, returns `true` if they were tracked.
+    pub async fn delete_in_flight_messages(&self, group_id: u32) -> Result<bool, IggyError> {
+        if self.in_flight_messages.remove(&group_id).is_none() {
+            return Ok(false);
+        }
+
+        self.persist_in_flight_messages().await?;
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expired_messages_should_be_returned_in_order() {
+        let mut in_flight_messages = InFlightMessages::new(10);
+        in_flight_messages.deliver(&[10, 11, 12], None, 100);
+        in_flight_messages.deliver(&[13], None, 200);
+
+        assert_eq!(in_flight_messages.get_next_offset(), 14);
+        assert!(in_flight_messages.get_expired(99, 10).is_empty());
+        assert_eq!(in_flight_messages.get_expired(150, 2), vec![10, 11]);
+        assert_eq!(
+            in_flight_messages.get_expired(200, 10),
+            vec![10, 11, 12, 13]
+        );
+
+        in_flight_messages.release(&[13]);
+        assert_eq!(in_flight_messages.get_expired(0, 10), vec![13]);
+    }
+
+    #[test]
+    fn committed_offset_should_stop_at_first_unacknowledged_message() {
+        let mut in_flight_messages = InFlightMessages::new(0);
+        assert_eq!(in_flight_messages.get_committed_offset(), None);
+
+        in_flight_messages.deliver(&[0, 1, 2], Some(5), 100);
+        assert_eq!(in_flight_messages.get_next_offset(), 6);
+        assert_eq!(in_flight_messages.get_committed_offset(), None);
+
+        in_flight_messages.ack(&[0, 2]);
+        assert_eq!(in_flight_messages.get_committed_offset(), Some(0));
+
+        in_flight_messages.ack(&[1]);
+        assert_eq!(in_flight_messages.get_committed_offset(), Some(5));
+    }
+
+    #[test]
+    fn skipping_forward_should_forget_messages_below_offset() {
+        let mut in_flight_messages = InFlightMessages::new(10);
+        in_flight_messages.deliver(&[10, 11, 12], None, 100);
+
+        in_flight_messages.skip_to(5);
+        assert_eq!(in_flight_messages.get_next_offset(), 13);
+
+        in_flight_messages.skip_to(12);
+        assert_eq!(in_flight_messages.get_expired(100, 10), vec![12]);
+        in_flight_messages.skip_to(20);
+        assert_eq!(in_flight_messages.get_next_offset(), 20);
+        assert_eq!(in_flight_messages.get_committed_offset(), Some(19));
+    }
+}
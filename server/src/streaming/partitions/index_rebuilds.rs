@@ -0,0 +1,174 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::compat::index_rebuilding::index_rebuilder::IndexRebuilder;
+use crate::streaming::partitions::loader::resolve_concurrency;
+use crate::streaming::partitions::partition::Partition;
+use crate::streaming::partitions::recovery::PartitionRecovery;
+use crate::streaming::partitions::COMPONENT;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::locking::{IggySharedMut, IggySharedMutFn};
+use iggy::utils::timestamp::IggyTimestamp;
+use serde::Serialize;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::fs;
+use tokio::sync::Semaphore;
+use tracing::{error, info};
+
+pub const REBUILT_INDEX_EXTENSION: &str = "rebuilding";
+
+/// The background rebuild of the segment index which has failed, reported as the health warning.
+#[derive(Debug, Clone, Serialize)]
+pub struct IndexRebuildFailure {
+    pub stream_id: u32,
+    pub topic_id: u32,
+    pub partition_id: u32,
+    pub start_offset: u64,
+    pub reason: String,
+    pub failed_at: IggyTimestamp,
+}
+
+/// Rebuilds the indexes of the closed segments in the background, so that they don't block the startup.
+/// Until then, the log files of the segments are scanned instead of reading their indexes.
+#[derive(Debug)]
+pub struct IndexRebuilds {
+    semaphore: Arc<Semaphore>,
+    pending: Arc<AtomicU64>,
+    failures: Arc<Mutex<Vec<IndexRebuildFailure>>>,
+}
+
+impl IndexRebuilds {
+    /// Creates the pool of the rebuilds, the concurrency equal to 0 means the number of the available CPUs.
+    pub fn new(concurrency: u32) -> Self {
+        Self {
+            semaphore: Arc::new(Semaphore::new(resolve_concurrency(concurrency))),
+            pending: Arc::new(AtomicU64::new(0)),
+            failures: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// Returns the number of the scheduled or running rebuilds.
+    pub fn get_pending_count(&self) -> u64 {
+        self.pending.load(Ordering::SeqCst)
+    }
+
+    pub fn get_failures(&self) -> Vec<IndexRebuildFailure> {
+        self.failures.lock().unwrap().clone()
+    }
+
+    /// Schedules the rebuilds of the indexes of the partition segments with the given start offsets,
+    /// the partition recovery is finished once all of them are done (or failed).
+    pub fn schedule(
+        &self,
+        partition: IggySharedMut<Partition>,
+        start_offsets: Vec<u64>,
+        recovery: Arc<PartitionRecovery>,
+    ) {
+        for start_offset in start_offsets {
+            let semaphore = self.semaphore.clone();
+            let pending = self.pending.clone();
+            let failures = self.failures.clone();
+            let partition = partition.clone();
+            let recovery = recovery.clone();
+            pending.fetch_add(1, Ordering::SeqCst);
+            tokio::spawn(async move {
+                let _permit = semaphore.acquire().await;
+                if let Err(error) = rebuild_index(&partition, start_offset).await {
+                    let partition = partition.read().await;
+                    error!("Failed to rebuild index of segment with start offset: {start_offset} for partition: {partition}. {error}");
+                    failures.lock().unwrap().push(IndexRebuildFailure {
+                        stream_id: partition.stream_id,
+                        topic_id: partition.topic_id,
+                        partition_id: partition.partition_id,
+                        start_offset,
+                        reason: error.to_string(),
+                        failed_at: IggyTimestamp::now(),
+                    });
+                }
+                if recovery.complete_segment(start_offset) {
+                    let mut partition = partition.write().await;
+                    partition.recovery = None;
+                    info!("Recovery of partition: {partition} finished, the appends are no longer limited.");
+                }
+                pending.fetch_sub(1, Ordering::SeqCst);
+            });
+        }
+    }
+}
+
+/// Rebuilds the index into the separate file without locking the partition,
+/// and then replaces the index of the segment with it, unless the segment has been deleted in the meantime.
+async fn rebuild_index(
+    partition: &IggySharedMut<Partition>,
+    start_offset: u64,
+) -> Result<(), IggyError> {
+    let (log_path, index_path) = {
+        let partition = partition.read().await;
+        let Some(segment) = partition
+            .segments
+            .iter()
+            .find(|segment| segment.start_offset == start_offset)
+        else {
+            return Ok(());
+        };
+        (segment.log_path.clone(), segment.index_path.clone())
+    };
+
+    let now = tokio::time::Instant::now();
+    let rebuilt_index_path = format!("{index_path}.{REBUILT_INDEX_EXTENSION}");
+    // The rebuilder doesn't truncate the file, so the leftover of the interrupted rebuild is removed first.
+    let _ = fs::remove_file(&rebuilt_index_path).await;
+    let index_rebuilder = IndexRebuilder::new(log_path, rebuilt_index_path.clone(), start_offset);
+    if let Err(error) = index_rebuilder.rebuild().await {
+        let _ = fs::remove_file(&rebuilt_index_path).await;
+        return Err(error)
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to rebuild index at path: {rebuilt_index_path}")
+            })
+            .map_err(|_| IggyError::CannotSaveIndexToSegment);
+    }
+
+    let mut partition = partition.write().await;
+    let Some(segment) = partition
+        .segments
+        .iter_mut()
+        .find(|segment| segment.start_offset == start_offset)
+    else {
+        let _ = fs::remove_file(&rebuilt_index_path).await;
+        return Ok(());
+    };
+
+    fs::rename(&rebuilt_index_path, &index_path)
+        .await
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to replace index at path: {index_path}")
+        })
+        .map_err(|_| IggyError::CannotSaveIndexToSegment)?;
+    segment.reload_indexes().await.with_error_context(|error| {
+        format!(
+            "{COMPONENT} (error: {error}) - failed to reload rebuilt index at path: {index_path}"
+        )
+    })?;
+    info!(
+        "Rebuilding index for path {index_path} in the background finished, it took {} ms",
+        now.elapsed().as_millis()
+    );
+    Ok(())
+}
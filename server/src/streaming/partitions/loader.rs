@@ -0,0 +1,126 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Instant;
+use tokio::sync::{Semaphore, SemaphorePermit};
+use tracing::info;
+
+/// The share of the partitions (in percent) loaded between the consecutive progress reports.
+const PROGRESS_REPORT_STEP: u64 = 10;
+
+/// Limits the number of the partitions loaded (and their indexes rebuilt) at the same time on startup,
+/// across all the streams and topics, and reports the loading progress.
+#[derive(Debug)]
+pub struct PartitionsLoader {
+    concurrency: usize,
+    semaphore: Semaphore,
+    total: AtomicU64,
+    loaded: AtomicU64,
+    started_at: Mutex<Option<Instant>>,
+}
+
+impl PartitionsLoader {
+    /// Creates the loader, the concurrency equal to 0 means the number of the available CPUs.
+    pub fn new(concurrency: u32) -> Self {
+        let concurrency = resolve_concurrency(concurrency);
+        Self {
+            concurrency,
+            semaphore: Semaphore::new(concurrency),
+            total: AtomicU64::new(0),
+            loaded: AtomicU64::new(0),
+            started_at: Mutex::new(None),
+        }
+    }
+
+    /// Starts reporting the progress of loading the given number of the partitions.
+    pub fn start(&self, total: u64) {
+        self.total.store(total, Ordering::SeqCst);
+        self.loaded.store(0, Ordering::SeqCst);
+        *self.started_at.lock().unwrap() = Some(Instant::now());
+        info!(
+            "Loading {total} partition(s) with concurrency: {}...",
+            self.concurrency
+        );
+    }
+
+    /// Waits until the partition can be loaded without exceeding the configured concurrency.
+    pub async fn acquire(&self) -> SemaphorePermit<'_> {
+        self.semaphore
+            .acquire()
+            .await
+            .expect("Partitions loader semaphore should never be closed")
+    }
+
+    /// Records the loaded (or failed to load) partition, reporting the progress every 10% of the partitions.
+    pub fn complete(&self) {
+        let loaded = self.loaded.fetch_add(1, Ordering::SeqCst) + 1;
+        let total = self.total.load(Ordering::SeqCst);
+        if total == 0 {
+            return;
+        }
+
+        let step = get_progress_step(loaded, total);
+        if step == get_progress_step(loaded - 1, total) {
+            return;
+        }
+
+        let elapsed = self
+            .started_at
+            .lock()
+            .unwrap()
+            .map(|started_at| started_at.elapsed())
+            .unwrap_or_default();
+        info!(
+            "Loaded {loaded}/{total} partition(s) ({}%) in {} ms.",
+            loaded.min(total) * 100 / total,
+            elapsed.as_millis()
+        );
+    }
+}
+
+/// Returns the given concurrency, or the number of the available CPUs if it's equal to 0.
+pub fn resolve_concurrency(concurrency: u32) -> usize {
+    match concurrency {
+        0 => std::thread::available_parallelism()
+            .map(|parallelism| parallelism.get())
+            .unwrap_or(1),
+        concurrency => concurrency as usize,
+    }
+}
+
+fn get_progress_step(loaded: u64, total: u64) -> u64 {
+    loaded.min(total) * 100 / total / PROGRESS_REPORT_STEP
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn progress_should_be_reported_every_tenth_of_partitions() {
+        let total = 25;
+        let reported = (1..=total)
+            .filter(|loaded| {
+                get_progress_step(*loaded, total) != get_progress_step(loaded - 1, total)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(reported, vec![3, 5, 8, 10, 13, 15, 18, 20, 23, 25]);
+    }
+}
@@ -0,0 +1,218 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::partitions::partition::Partition;
+use crate::streaming::partitions::COMPONENT;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::utils::checksum;
+use iggy::utils::timestamp::IggyTimestamp;
+use serde::{Deserialize, Serialize};
+use tracing::{trace, warn};
+
+pub const MANIFEST_FILE: &str = "manifest.json";
+
+/// The integrity manifest of the partition, describing the segments as they were on disk when it was saved.
+/// It's used on startup to skip the expensive validation of the segments that haven't changed since then.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PartitionManifest {
+    pub clean_shutdown: bool,
+    pub saved_at: IggyTimestamp,
+    pub segments: Vec<SegmentManifest>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SegmentManifest {
+    pub start_offset: u64,
+    pub closed: bool,
+    pub log_size_bytes: u64,
+    pub index_size_bytes: u64,
+    pub index_checksum: u32,
+}
+
+impl SegmentManifest {
+    /// Describes the segment based on its current log and index files.
+    pub async fn read(
+        start_offset: u64,
+        closed: bool,
+        log_path: &str,
+        index_path: &str,
+    ) -> Result<Self, IggyError> {
+        let log_size_bytes = tokio::fs::metadata(log_path)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to read metadata of log file: {log_path}")
+            })
+            .map_err(|_| IggyError::CannotReadFile)?
+            .len();
+        let index = tokio::fs::read(index_path)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to read index file: {index_path}")
+            })
+            .map_err(|_| IggyError::CannotReadFile)?;
+        Ok(SegmentManifest {
+            start_offset,
+            closed,
+            log_size_bytes,
+            index_size_bytes: index.len() as u64,
+            index_checksum: checksum::calculate(&index),
+        })
+    }
+}
+
+impl PartitionManifest {
+    pub fn path(partition_path: &str) -> String {
+        format!("{partition_path}/{MANIFEST_FILE}")
+    }
+
+    /// Loads the manifest of the partition, if it exists and can be parsed.
+    pub async fn load(partition_path: &str) -> Option<Self> {
+        let path = Self::path(partition_path);
+        let data = match tokio::fs::read(&path).await {
+            Ok(data) => data,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+                trace!("Manifest at path: {path} does not exist.");
+                return None;
+            }
+            Err(error) => {
+                warn!("Failed to read manifest at path: {path}. {error}");
+                return None;
+            }
+        };
+
+        match serde_json::from_slice(&data) {
+            Ok(manifest) => Some(manifest),
+            Err(error) => {
+                warn!("Failed to parse manifest at path: {path}, it will be ignored. {error}");
+                None
+            }
+        }
+    }
+
+    /// Returns `true` if the segment is unchanged since the manifest was saved, and the segment could not have been written
+    /// in the meantime - either the server was shut down cleanly, or the segment had already been closed.
+    pub fn is_unchanged(&self, segment: &SegmentManifest) -> bool {
+        let Some(entry) = self
+            .segments
+            .iter()
+            .find(|entry| entry.start_offset == segment.start_offset)
+        else {
+            return false;
+        };
+
+        (self.clean_shutdown || entry.closed)
+            && entry.log_size_bytes == segment.log_size_bytes
+            && entry.index_size_bytes == segment.index_size_bytes
+            && entry.index_checksum == segment.index_checksum
+    }
+}
+
+impl Partition {
+    /// Saves the integrity manifest of the partition. The clean shutdown marker must be set only once no more messages can be appended.
+    pub async fn save_manifest(&self, clean_shutdown: bool) -> Result<(), IggyError> {
+        if !self.config.partition.use_manifest || self.config.recovery.read_only {
+            return Ok(());
+        }
+
+        let mut segments = Vec::with_capacity(self.segments.len());
+        for segment in &self.segments {
+            segments.push(
+                SegmentManifest::read(
+                    segment.start_offset,
+                    segment.is_closed,
+                    &segment.log_path,
+                    &segment.index_path,
+                )
+                .await?,
+            );
+        }
+
+        let manifest = PartitionManifest {
+            clean_shutdown,
+            saved_at: IggyTimestamp::now(),
+            segments,
+        };
+        let data = serde_json::to_vec_pretty(&manifest)
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to serialize manifest for partition: {self}")
+            })
+            .map_err(|_| IggyError::CannotSerializeResource)?;
+        let path = PartitionManifest::path(&self.partition_path);
+        self.storage
+            .persister
+            .overwrite(&path, &data)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to save manifest at path: {path}")
+            })?;
+        trace!("Saved manifest for partition: {self}, clean shutdown: {clean_shutdown}.");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(start_offset: u64, closed: bool, log_size_bytes: u64) -> SegmentManifest {
+        SegmentManifest {
+            start_offset,
+            closed,
+            log_size_bytes,
+            index_size_bytes: 16,
+            index_checksum: 1,
+        }
+    }
+
+    fn manifest(clean_shutdown: bool) -> PartitionManifest {
+        PartitionManifest {
+            clean_shutdown,
+            saved_at: IggyTimestamp::zero(),
+            segments: vec![segment(0, true, 100), segment(10, false, 50)],
+        }
+    }
+
+    #[test]
+    fn all_unchanged_segments_should_be_trusted_after_clean_shutdown() {
+        let manifest = manifest(true);
+
+        assert!(manifest.is_unchanged(&segment(0, true, 100)));
+        assert!(manifest.is_unchanged(&segment(10, false, 50)));
+    }
+
+    #[test]
+    fn only_closed_segments_should_be_trusted_after_crash() {
+        let manifest = manifest(false);
+
+        assert!(manifest.is_unchanged(&segment(0, true, 100)));
+        assert!(!manifest.is_unchanged(&segment(10, false, 50)));
+    }
+
+    #[test]
+    fn changed_or_unknown_segments_should_not_be_trusted() {
+        let manifest = manifest(true);
+
+        assert!(!manifest.is_unchanged(&segment(0, true, 101)));
+        assert!(!manifest.is_unchanged(&SegmentManifest {
+            index_checksum: 2,
+            ..segment(0, true, 100)
+        }));
+        assert!(!manifest.is_unchanged(&segment(20, false, 0)));
+    }
+}
@@ -20,14 +20,18 @@ use crate::streaming::batching::appendable_batch_info::AppendableBatchInfo;
 use crate::streaming::batching::iterator::IntoMessagesIterator;
 use crate::streaming::models::messages::RetainedMessage;
 use crate::streaming::partitions::partition::Partition;
+use crate::streaming::partitions::read_ahead::{ReadAhead, ReadAheadRequest};
 use crate::streaming::partitions::COMPONENT;
 use crate::streaming::polling_consumer::PollingConsumer;
+use crate::streaming::segments::message_slices::MessageSlice;
 use crate::streaming::segments::*;
 use error_set::ErrContext;
 use iggy::confirmation::Confirmation;
 use iggy::error::IggyError;
 use iggy::messages::send_messages::Message;
 use iggy::models::messages::POLLED_MESSAGE_METADATA;
+use iggy::models::transaction::Transaction;
+use iggy::models::unsaved_state::PartitionUnsavedState;
 use iggy::utils::timestamp::IggyTimestamp;
 use std::sync::{atomic::Ordering, Arc};
 use tracing::{trace, warn};
@@ -83,6 +87,16 @@ impl Partition {
         Ok(messages)
     }
 
+    /// Returns the offset of the earliest message with the timestamp greater than or equal to the given one,
+    /// the segments are looked up using their time indexes.
+    pub async fn get_offset_for_timestamp(
+        &self,
+        timestamp: IggyTimestamp,
+    ) -> Result<Option<u64>, IggyError> {
+        let messages = self.get_messages_by_timestamp(timestamp, 1).await?;
+        Ok(messages.first().map(|message| message.offset))
+    }
+
     // Retrieves messages by offset (up to a specified count).
     pub async fn get_messages_by_offset(
         &self,
@@ -103,6 +117,13 @@ impl Partition {
             return Ok(cached);
         }
 
+        if let Some(messages) = self
+            .try_get_messages_from_tiered_storage(start_offset, count)
+            .await?
+        {
+            return Ok(messages);
+        }
+
         let segments = self.filter_segments_by_offsets(start_offset, end_offset);
         match segments.len() {
             0 => Ok(Vec::new()),
@@ -115,6 +136,54 @@ impl Partition {
         }
     }
 
+    /// Retrieves the messages persisted in the segments (up to a specified count), with their payloads
+    /// left in the log files, or `None` if they have to be retrieved using `get_messages_by_offset`,
+    /// e.g. when they're already in the cache or have been archived.
+    pub async fn get_message_slices_by_offset(
+        &self,
+        start_offset: u64,
+        count: u32,
+    ) -> Result<Option<Vec<MessageSlice>>, IggyError> {
+        if self.segments.is_empty()
+            || count == 0
+            || start_offset > self.current_offset
+            || start_offset < self.segments[0].start_offset
+        {
+            return Ok(None);
+        }
+
+        if let Some(cache) = self.cache.as_ref() {
+            if !cache.is_empty() && start_offset >= cache[0].offset {
+                return Ok(None);
+            }
+        }
+
+        let end_offset = self.get_end_offset(start_offset, count);
+        let mut messages = Vec::with_capacity(count as usize);
+        let mut remaining_count = count;
+        for segment in self.filter_segments_by_offsets(start_offset, end_offset) {
+            if remaining_count == 0 {
+                break;
+            }
+            let offset = messages
+                .last()
+                .map(|message: &MessageSlice| message.offset + 1)
+                .unwrap_or(start_offset);
+            let Some(segment_messages) = segment
+                .get_message_slices_by_offset(offset, remaining_count)
+                .await
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to get message slices from segment: {segment}, offset: {offset}, count: {remaining_count}")
+                })?
+            else {
+                return Ok(None);
+            };
+            remaining_count = remaining_count.saturating_sub(segment_messages.len() as u32);
+            messages.extend(segment_messages);
+        }
+        Ok(Some(messages))
+    }
+
     // Retrieves the first messages (up to a specified count).
     pub async fn get_first_messages(
         &self,
@@ -177,7 +246,84 @@ impl Partition {
             offset
         );
 
-        self.get_messages_by_offset(offset, count).await
+        self.get_consumer_messages_by_offset(consumer, offset, count)
+            .await
+    }
+
+    // Retrieves messages by offset for a polling consumer (up to a specified count),
+    // reading ahead in the background once the consumer is detected to poll sequentially.
+    pub async fn get_consumer_messages_by_offset(
+        &self,
+        consumer: PollingConsumer,
+        start_offset: u64,
+        count: u32,
+    ) -> Result<Vec<Arc<RetainedMessage>>, IggyError> {
+        let Some(read_ahead) = self.read_ahead.as_ref() else {
+            return self.get_messages_by_offset(start_offset, count).await;
+        };
+        if self.segments.is_empty() || start_offset > self.current_offset || count == 0 {
+            return self.get_messages_by_offset(start_offset, count).await;
+        }
+
+        let end_offset = self.get_end_offset(start_offset, count);
+        let messages = match read_ahead.get_messages(consumer, start_offset, end_offset) {
+            Some(messages) => messages,
+            None => self.get_messages_by_offset(start_offset, count).await?,
+        };
+
+        // Messages already kept in the cache don't need to be read ahead.
+        let max_offset = match self.cache.as_ref() {
+            Some(cache) if !cache.is_empty() => cache[0].offset.saturating_sub(1),
+            _ => self.current_offset,
+        };
+        if let Some(request) = read_ahead.observe(
+            consumer,
+            start_offset,
+            messages.len() as u32,
+            count,
+            max_offset,
+        ) {
+            self.spawn_read_ahead(read_ahead.clone(), consumer, request);
+        }
+
+        Ok(messages)
+    }
+
+    fn spawn_read_ahead(
+        &self,
+        read_ahead: Arc<ReadAhead>,
+        consumer: PollingConsumer,
+        request: ReadAheadRequest,
+    ) {
+        trace!(
+            "Reading ahead messages for {consumer} in partition: {}, start offset: {}, end offset: {}...",
+            self.partition_id,
+            request.start_offset,
+            request.end_offset
+        );
+        let loaders = self
+            .filter_segments_by_offsets(request.start_offset, request.end_offset)
+            .into_iter()
+            .filter_map(|segment| {
+                segment.read_ahead_loader(request.start_offset, request.end_offset)
+            })
+            .collect::<Vec<_>>();
+        let partition_id = self.partition_id;
+        tokio::spawn(async move {
+            let mut messages = Vec::new();
+            for loader in loaders {
+                match loader.await {
+                    Ok(loaded_messages) => messages.extend(loaded_messages),
+                    Err(error) => {
+                        warn!(
+                            "Failed to read ahead messages for {consumer} in partition: {partition_id}. {error}"
+                        );
+                        break;
+                    }
+                }
+            }
+            read_ahead.complete(consumer, messages);
+        });
     }
 
     fn get_end_offset(&self, offset: u64, count: u32) -> u64 {
@@ -259,6 +405,46 @@ impl Partition {
         None
     }
 
+    // Tries to retrieve messages from the archived segment which is no longer available locally.
+    // Only the messages of that single segment are returned, the next poll continues from there.
+    async fn try_get_messages_from_tiered_storage(
+        &self,
+        start_offset: u64,
+        count: u32,
+    ) -> Result<Option<Vec<Arc<RetainedMessage>>>, IggyError> {
+        let Some(tiered_storage) = self.storage.tiered_storage.as_ref() else {
+            return Ok(None);
+        };
+        if start_offset >= self.segments[0].start_offset {
+            return Ok(None);
+        }
+
+        let Some(archived_segment) = self.find_archived_segment(start_offset) else {
+            return Ok(None);
+        };
+
+        trace!(
+            "Getting messages for start offset: {start_offset} for partition: {} from archived segment with start offset: {}...",
+            self.partition_id,
+            archived_segment.start_offset
+        );
+        let messages = tiered_storage
+            .get_messages_by_offset(
+                &self.config,
+                self.stream_id,
+                self.topic_id,
+                self.partition_id,
+                archived_segment,
+                start_offset,
+                count,
+            )
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to get messages from tiered storage, partition: {self}, start offset: {start_offset}")
+            })?;
+        Ok(Some(messages))
+    }
+
     pub async fn get_newest_messages_by_size(
         &self,
         size_bytes: u64,
@@ -371,6 +557,7 @@ impl Partition {
         messages: Vec<Message>,
         confirmation: Option<Confirmation>,
     ) -> Result<(), IggyError> {
+        self.ensure_append_allowed(appendable_batch_info.batch_size)?;
         {
             let last_segment = self.segments.last_mut().ok_or(IggyError::SegmentNotFound)?;
             if last_segment.is_closed {
@@ -394,6 +581,19 @@ impl Partition {
             self.current_offset + 1
         };
 
+        // All the messages of the batch are sent within the same transaction, as validated by the system.
+        let transaction_id = messages
+            .first()
+            .and_then(|message| Transaction::from_headers(&message.headers).ok().flatten())
+            .map(|transaction| transaction.transaction_id);
+        let messages = self
+            .producer_sessions
+            .retain_new(messages, self.partition_id)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to validate producer sequences, partition: {self}"
+                )
+            })?;
         let mut messages_count = 0u32;
         let mut retained_messages = Vec::with_capacity(messages.len());
         if let Some(message_deduplicator) = &self.message_deduplicator {
@@ -425,6 +625,10 @@ impl Partition {
         }
 
         let last_offset = base_offset + (messages_count - 1) as u64;
+        if let Some(transaction_id) = transaction_id {
+            self.transactions
+                .track(transaction_id, base_offset, last_offset);
+        }
         if self.should_increment_offset {
             self.current_offset = last_offset;
         } else {
@@ -493,10 +697,57 @@ impl Partition {
         self.unsaved_messages_count = 0;
         Ok(())
     }
+
+    /// Returns the messages accumulated in the last segment, which are not yet saved on disk.
+    pub fn get_unsaved_state(&self) -> PartitionUnsavedState {
+        let (messages_count, size) = self
+            .segments
+            .last()
+            .and_then(|segment| segment.unsaved_messages.as_ref())
+            .map(|batch_accumulator| {
+                (
+                    batch_accumulator.unsaved_messages_count() as u64,
+                    batch_accumulator.unsaved_messages_size(),
+                )
+            })
+            .unwrap_or_default();
+        PartitionUnsavedState {
+            stream_id: self.stream_id,
+            topic_id: self.topic_id,
+            partition_id: self.partition_id,
+            messages_count,
+            size,
+        }
+    }
+
+    /// Saves the unsaved messages on disk and fsyncs the last segment, returning the state of the partition before the flush.
+    pub async fn flush(&mut self) -> Result<PartitionUnsavedState, IggyError> {
+        let unsaved_state = self.get_unsaved_state();
+        if unsaved_state.messages_count == 0 {
+            return Ok(unsaved_state);
+        }
+
+        let last_segment = self.segments.last_mut().ok_or(IggyError::SegmentNotFound)?;
+        while last_segment.unsaved_messages.is_some() {
+            last_segment
+                .persist_messages(None)
+                .await
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to persist messages, segment: {last_segment}")
+                })?;
+        }
+        // The segment closed once full is fsynced on its own, when the writers are shut down.
+        last_segment.fsync().await.with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to fsync segment: {last_segment}")
+        })?;
+        self.unsaved_messages_count = 0;
+        Ok(unsaved_state)
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use iggy::compression::compression_algorithm::CompressionAlgorithm;
     use iggy::utils::byte_size::IggyByteSize;
     use iggy::utils::expiry::IggyExpiry;
     use iggy::utils::sizeable::Sizeable;
@@ -586,6 +837,7 @@ mod tests {
                 config,
                 storage,
                 IggyExpiry::NeverExpire,
+                CompressionAlgorithm::None,
                 Arc::new(AtomicU64::new(0)),
                 Arc::new(AtomicU64::new(0)),
                 Arc::new(AtomicU64::new(0)),
@@ -19,12 +19,28 @@
 use bytes::Bytes;
 use iggy::messages::send_messages;
 
+pub mod aggregates;
+pub mod archived_segments;
+pub mod compaction;
 pub mod consumer_offsets;
+pub mod epoch;
+pub mod gaps;
+pub mod heat;
+pub mod in_flight;
+pub mod index_rebuilds;
+pub mod loader;
+pub mod manifest;
 pub mod messages;
+pub mod offset_recovery;
 pub mod partition;
 pub mod persistence;
+pub mod producer_sessions;
+pub mod read_ahead;
+pub mod recovery;
 pub mod segments;
+pub mod state_store;
 pub mod storage;
+pub mod transactions;
 
 pub const COMPONENT: &str = "STREAMING_PARTITIONS";
 
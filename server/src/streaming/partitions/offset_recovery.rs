@@ -0,0 +1,289 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::partitions::archived_segments::ArchivedSegment;
+use crate::streaming::partitions::partition::Partition;
+use crate::streaming::partitions::COMPONENT;
+use ahash::AHashMap;
+use error_set::ErrContext;
+use iggy::consumer::ConsumerKind;
+use iggy::consumer_groups::offset_recovery_policy::OffsetRecoveryPolicy;
+use iggy::error::IggyError;
+use tracing::info;
+
+/// The stored consumer offset which has been moved (or removed) by the offset recovery.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RecoveredConsumerOffset {
+    pub kind: ConsumerKind,
+    pub consumer_id: u32,
+    pub previous_offset: u64,
+    /// The recovered offset, `None` if the stored offset has been removed.
+    pub offset: Option<u64>,
+}
+
+/// The range of the messages available in the partition, used to recover the stored consumer offsets.
+#[derive(Debug, Clone, Copy)]
+struct AvailableOffsets<'a> {
+    first_offset: u64,
+    current_offset: u64,
+    has_messages: bool,
+    archived_segments: &'a [ArchivedSegment],
+}
+
+/// Returns `None` if the stored offset should be kept, `Some(None)` if it should be removed
+/// and `Some(Some(offset))` if it should be replaced with the given offset.
+fn recover_offset(
+    policy: OffsetRecoveryPolicy,
+    offset: u64,
+    available: &AvailableOffsets,
+) -> Option<Option<u64>> {
+    if matches!(
+        policy,
+        OffsetRecoveryPolicy::Disabled | OffsetRecoveryPolicy::ServerDefault
+    ) {
+        return None;
+    }
+
+    // No message has ever been appended after the reset, so any stored offset is dangling.
+    if !available.has_messages {
+        return Some(None);
+    }
+
+    if offset > available.current_offset {
+        return match policy {
+            OffsetRecoveryPolicy::Earliest if available.first_offset == 0 => Some(None),
+            OffsetRecoveryPolicy::Earliest => Some(Some(available.first_offset - 1)),
+            _ => Some(Some(available.current_offset)),
+        };
+    }
+
+    // The next message to be polled is still available locally.
+    if offset + 1 >= available.first_offset {
+        return None;
+    }
+
+    match policy {
+        OffsetRecoveryPolicy::Earliest => Some(Some(available.first_offset - 1)),
+        OffsetRecoveryPolicy::Latest => Some(Some(available.current_offset)),
+        _ => {
+            if available
+                .archived_segments
+                .iter()
+                .any(|segment| segment.contains(offset + 1))
+            {
+                return None;
+            }
+
+            let watermark = available
+                .archived_segments
+                .iter()
+                .map(|segment| segment.start_offset)
+                .filter(|start_offset| *start_offset > offset + 1)
+                .min()
+                .unwrap_or(available.first_offset)
+                .min(available.first_offset);
+            Some(Some(watermark - 1))
+        }
+    }
+}
+
+impl Partition {
+    /// Moves the stored offsets which are out of the range of the available messages,
+    /// using the policy of the consumer group or the default one for the regular consumers.
+    pub async fn recover_consumer_offsets(
+        &self,
+        default_policy: OffsetRecoveryPolicy,
+        consumer_group_policies: &AHashMap<u32, OffsetRecoveryPolicy>,
+    ) -> Result<Vec<RecoveredConsumerOffset>, IggyError> {
+        let available = AvailableOffsets {
+            first_offset: self
+                .segments
+                .first()
+                .map(|segment| segment.start_offset)
+                .unwrap_or_default(),
+            current_offset: self.current_offset,
+            has_messages: self.should_increment_offset,
+            archived_segments: &self.archived_segments,
+        };
+
+        let mut recovered_offsets = Vec::new();
+        for (kind, consumer_offsets) in [
+            (ConsumerKind::Consumer, &self.consumer_offsets),
+            (ConsumerKind::ConsumerGroup, &self.consumer_group_offsets),
+        ] {
+            for consumer_offset in consumer_offsets.iter() {
+                let policy = match kind {
+                    ConsumerKind::Consumer => default_policy,
+                    ConsumerKind::ConsumerGroup => consumer_group_policies
+                        .get(&consumer_offset.consumer_id)
+                        .copied()
+                        .unwrap_or(default_policy),
+                };
+                if let Some(offset) = recover_offset(policy, consumer_offset.offset, &available) {
+                    recovered_offsets.push(RecoveredConsumerOffset {
+                        kind,
+                        consumer_id: consumer_offset.consumer_id,
+                        previous_offset: consumer_offset.offset,
+                        offset,
+                    });
+                }
+            }
+        }
+
+        for recovered_offset in &recovered_offsets {
+            let consumer_id = recovered_offset.consumer_id;
+            match recovered_offset.offset {
+                Some(offset) => {
+                    self.store_offset(recovered_offset.kind, consumer_id, offset)
+                        .await
+                        .with_error_context(|error| {
+                            format!("{COMPONENT} (error: {error}) - failed to store recovered offset: {offset} for {} with ID: {consumer_id}, partition: {self}", recovered_offset.kind)
+                        })?;
+                }
+                None => {
+                    let (consumer_offsets, path) = match recovered_offset.kind {
+                        ConsumerKind::Consumer => {
+                            (&self.consumer_offsets, &self.consumer_offsets_path)
+                        }
+                        ConsumerKind::ConsumerGroup => (
+                            &self.consumer_group_offsets,
+                            &self.consumer_group_offsets_path,
+                        ),
+                    };
+                    consumer_offsets.remove(&consumer_id);
+                    self.storage
+                        .consumer_offsets
+                        .delete_consumer_offset(path, consumer_id)
+                        .await
+                        .with_error_context(|error| {
+                            format!("{COMPONENT} (error: {error}) - failed to delete out of range offset for {} with ID: {consumer_id}, partition: {self}", recovered_offset.kind)
+                        })?;
+                }
+            }
+            info!(
+                "Recovered offset: {} -> {:?} for {} with ID: {consumer_id}, partition with ID: {} for topic with ID: {} and stream with ID: {}.",
+                recovered_offset.previous_offset,
+                recovered_offset.offset,
+                recovered_offset.kind,
+                self.partition_id,
+                self.topic_id,
+                self.stream_id
+            );
+        }
+
+        Ok(recovered_offsets)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ARCHIVED_SEGMENTS: [ArchivedSegment; 2] = [
+        ArchivedSegment {
+            start_offset: 0,
+            end_offset: 9,
+            restored: false,
+        },
+        ArchivedSegment {
+            start_offset: 20,
+            end_offset: 29,
+            restored: false,
+        },
+    ];
+
+    fn available_offsets() -> AvailableOffsets<'static> {
+        AvailableOffsets {
+            first_offset: 40,
+            current_offset: 49,
+            has_messages: true,
+            archived_segments: &ARCHIVED_SEGMENTS,
+        }
+    }
+
+    #[test]
+    fn offsets_within_range_should_be_kept() {
+        let available = available_offsets();
+        for policy in [
+            OffsetRecoveryPolicy::Earliest,
+            OffsetRecoveryPolicy::Latest,
+            OffsetRecoveryPolicy::Archived,
+        ] {
+            assert_eq!(recover_offset(policy, 39, &available), None);
+            assert_eq!(recover_offset(policy, 45, &available), None);
+            assert_eq!(recover_offset(policy, 49, &available), None);
+        }
+        assert_eq!(
+            recover_offset(OffsetRecoveryPolicy::Disabled, 100, &available),
+            None
+        );
+    }
+
+    #[test]
+    fn offsets_behind_the_partition_should_be_recovered_using_the_policy() {
+        let available = available_offsets();
+        assert_eq!(
+            recover_offset(OffsetRecoveryPolicy::Earliest, 15, &available),
+            Some(Some(39))
+        );
+        assert_eq!(
+            recover_offset(OffsetRecoveryPolicy::Latest, 15, &available),
+            Some(Some(49))
+        );
+        assert_eq!(
+            recover_offset(OffsetRecoveryPolicy::Archived, 5, &available),
+            None
+        );
+        assert_eq!(
+            recover_offset(OffsetRecoveryPolicy::Archived, 15, &available),
+            Some(Some(19))
+        );
+        assert_eq!(
+            recover_offset(OffsetRecoveryPolicy::Archived, 32, &available),
+            Some(Some(39))
+        );
+    }
+
+    #[test]
+    fn offsets_ahead_of_the_partition_should_be_recovered_using_the_policy() {
+        let available = available_offsets();
+        assert_eq!(
+            recover_offset(OffsetRecoveryPolicy::Earliest, 60, &available),
+            Some(Some(39))
+        );
+        assert_eq!(
+            recover_offset(OffsetRecoveryPolicy::Latest, 60, &available),
+            Some(Some(49))
+        );
+        assert_eq!(
+            recover_offset(OffsetRecoveryPolicy::Archived, 60, &available),
+            Some(Some(49))
+        );
+
+        let available = AvailableOffsets {
+            first_offset: 0,
+            current_offset: 0,
+            has_messages: false,
+            archived_segments: &[],
+        };
+        assert_eq!(
+            recover_offset(OffsetRecoveryPolicy::Latest, 60, &available),
+            Some(None)
+        );
+    }
+}
@@ -21,9 +21,20 @@ use crate::streaming::cache::buffer::SmartCache;
 use crate::streaming::cache::memory_tracker::CacheMemoryTracker;
 use crate::streaming::deduplication::message_deduplicator::MessageDeduplicator;
 use crate::streaming::models::messages::RetainedMessage;
+use crate::streaming::partitions::archived_segments::ArchivedSegment;
+use crate::streaming::partitions::compaction::CompactedSegment;
+use crate::streaming::partitions::epoch::INITIAL_EPOCH;
+use crate::streaming::partitions::in_flight::InFlightMessages;
+use crate::streaming::partitions::producer_sessions::ProducerSessions;
+use crate::streaming::partitions::read_ahead::ReadAhead;
+use crate::streaming::partitions::recovery::PartitionRecovery;
+use crate::streaming::partitions::state_store::PartitionStateStore;
+use crate::streaming::partitions::transactions::PartitionTransactions;
 use crate::streaming::segments::*;
 use crate::streaming::storage::SystemStorage;
+use ahash::AHashMap;
 use dashmap::DashMap;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::consumer::ConsumerKind;
 use iggy::models::stats::CacheMetrics;
 use iggy::utils::byte_size::IggyByteSize;
@@ -48,6 +59,7 @@ pub struct Partition {
     pub cache: Option<SmartCache<Arc<RetainedMessage>>>,
     pub cached_memory_tracker: Option<Arc<CacheMemoryTracker>>,
     pub message_deduplicator: Option<MessageDeduplicator>,
+    pub read_ahead: Option<Arc<ReadAhead>>,
     pub unsaved_messages_count: u32,
     pub should_increment_offset: bool,
     pub created_at: IggyTimestamp,
@@ -60,9 +72,25 @@ pub struct Partition {
     pub size_bytes: Arc<AtomicU64>,
     pub segments_count_of_parent_stream: Arc<AtomicU32>,
     pub(crate) message_expiry: IggyExpiry,
+    pub(crate) compression_algorithm: CompressionAlgorithm,
+    /// The maximum size of the new segments, overridden for the topic or the server default one.
+    pub(crate) segment_size: IggyByteSize,
     pub(crate) consumer_offsets: DashMap<u32, ConsumerOffset>,
     pub(crate) consumer_group_offsets: DashMap<u32, ConsumerOffset>,
+    pub(crate) in_flight_messages: DashMap<u32, InFlightMessages>,
+    pub(crate) state_stores: AHashMap<u32, PartitionStateStore>,
     pub(crate) segments: Vec<Segment>,
+    pub(crate) archived_segments: Vec<ArchivedSegment>,
+    pub(crate) epoch: u64,
+    /// Start offsets of the segments with their indexes to be rebuilt in the background, once the partition is loaded.
+    pub(crate) pending_index_rebuilds: Vec<u64>,
+    /// The background recovery of the partition, until all of its pending index rebuilds are done.
+    pub(crate) recovery: Option<Arc<PartitionRecovery>>,
+    pub(crate) compacted_segments: Vec<CompactedSegment>,
+    pub(crate) producer_sessions: ProducerSessions,
+    pub(crate) transactions: PartitionTransactions,
+    /// Number of the polled messages, halved every time the cache heat map is saved.
+    pub(crate) heat: AtomicU64,
     pub(crate) config: Arc<SystemConfig>,
     pub(crate) storage: Arc<SystemStorage>,
 }
@@ -96,6 +124,7 @@ impl Partition {
         config: Arc<SystemConfig>,
         storage: Arc<SystemStorage>,
         message_expiry: IggyExpiry,
+        compression_algorithm: CompressionAlgorithm,
         messages_count_of_parent_stream: Arc<AtomicU64>,
         messages_count_of_parent_topic: Arc<AtomicU64>,
         size_of_parent_stream: Arc<AtomicU64>,
@@ -126,6 +155,8 @@ impl Partition {
             consumer_offsets_path,
             consumer_group_offsets_path,
             message_expiry,
+            compression_algorithm,
+            segment_size: config.segment.size,
             cache: messages,
             cached_memory_tracker,
             message_deduplicator: match config.message_deduplication.enabled {
@@ -145,12 +176,26 @@ impl Partition {
                 )),
                 false => None,
             },
+            read_ahead: match config.partition.read_ahead.enabled {
+                true => Some(Arc::new(ReadAhead::new(&config.partition.read_ahead))),
+                false => None,
+            },
             segments: vec![],
+            archived_segments: vec![],
+            epoch: INITIAL_EPOCH,
+            pending_index_rebuilds: Vec::new(),
+            recovery: None,
+            compacted_segments: vec![],
+            producer_sessions: ProducerSessions::default(),
+            transactions: PartitionTransactions::default(),
+            heat: AtomicU64::new(0),
             current_offset: 0,
             unsaved_messages_count: 0,
             should_increment_offset: false,
             consumer_offsets: DashMap::new(),
             consumer_group_offsets: DashMap::new(),
+            in_flight_messages: DashMap::new(),
+            state_stores: AHashMap::new(),
             config,
             storage,
             created_at,
@@ -172,6 +217,7 @@ impl Partition {
                 0,
                 partition.config.clone(),
                 partition.message_expiry,
+                partition.compression_algorithm,
                 partition.size_of_parent_stream.clone(),
                 partition.size_of_parent_topic.clone(),
                 partition.size_bytes.clone(),
@@ -232,6 +278,7 @@ mod tests {
     use crate::streaming::partitions::partition::Partition;
     use crate::streaming::persistence::persister::{FileWithSyncPersister, PersisterKind};
     use crate::streaming::storage::SystemStorage;
+    use iggy::compression::compression_algorithm::CompressionAlgorithm;
     use iggy::utils::duration::IggyDuration;
     use iggy::utils::expiry::IggyExpiry;
     use iggy::utils::timestamp::IggyTimestamp;
@@ -264,6 +311,7 @@ mod tests {
             config,
             storage,
             message_expiry,
+            CompressionAlgorithm::None,
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
@@ -309,11 +357,13 @@ mod tests {
                 cache: CacheConfig {
                     enabled: false,
                     size: "0".parse().unwrap(),
+                    ..Default::default()
                 },
                 ..Default::default()
             }),
             storage,
             IggyExpiry::NeverExpire,
+            CompressionAlgorithm::None,
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
@@ -346,6 +396,7 @@ mod tests {
             Arc::new(SystemConfig::default()),
             storage,
             IggyExpiry::NeverExpire,
+            CompressionAlgorithm::None,
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
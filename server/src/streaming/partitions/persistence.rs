@@ -21,6 +21,7 @@ use crate::streaming::partitions::partition::Partition;
 use crate::streaming::partitions::COMPONENT;
 use error_set::ErrContext;
 use iggy::error::IggyError;
+use iggy::utils::timestamp::IggyTimestamp;
 use std::path::Path;
 use std::sync::atomic::Ordering;
 use tokio::fs::create_dir_all;
@@ -45,18 +46,80 @@ impl Partition {
             self.segments_count_of_parent_stream
                 .fetch_sub(1, Ordering::SeqCst);
         }
+
+        if let Err(err) = self
+            .storage
+            .consumer_offsets
+            .delete_consumer_offsets(&self.consumer_offsets_path)
+            .await
+        {
+            error!("Cannot delete consumer offsets for partition with ID: {} for topic with ID: {} for stream with ID: {}. Error: {}", self.partition_id, self.topic_id, self.stream_id, err);
+            return Err(IggyError::CannotDeletePartition(
+                self.partition_id,
+                self.topic_id,
+                self.stream_id,
+            ));
+        }
+
+        if let Err(err) = self
+            .storage
+            .consumer_offsets
+            .delete_consumer_offsets(&self.consumer_group_offsets_path)
+            .await
+        {
+            error!("Cannot delete consumer group offsets for partition with ID: {} for topic with ID: {} for stream with ID: {}. Error: {}", self.partition_id, self.topic_id, self.stream_id, err);
+            return Err(IggyError::CannotDeletePartition(
+                self.partition_id,
+                self.topic_id,
+                self.stream_id,
+            ));
+        }
+
         self.storage.partition.delete(self).await
     }
 
-    pub async fn purge(&mut self) -> Result<(), IggyError> {
-        self.current_offset = 0;
+    pub async fn purge(
+        &mut self,
+        keep_consumer_offsets: bool,
+        older_than: Option<IggyTimestamp>,
+    ) -> Result<(), IggyError> {
+        if let Some(older_than) = older_than {
+            self.purge_segments_older_than(older_than)
+                .await
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to purge segments older than: {older_than} in partition: {self}")
+                })?;
+        } else {
+            self.purge_all_segments(keep_consumer_offsets)
+                .await
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to purge segments in partition: {self}")
+                })?;
+        }
+
+        if keep_consumer_offsets {
+            return Ok(());
+        }
+
+        self.reset_consumer_offsets().await
+    }
+
+    async fn purge_all_segments(&mut self, keep_offsets: bool) -> Result<(), IggyError> {
+        let offsets_start_over = self.should_increment_offset && !keep_offsets;
+        let start_offset = if keep_offsets && self.should_increment_offset {
+            self.current_offset + 1
+        } else {
+            self.current_offset = 0;
+            self.should_increment_offset = false;
+            0
+        };
         self.unsaved_messages_count = 0;
-        self.should_increment_offset = false;
-        self.consumer_offsets.clear();
-        self.consumer_group_offsets.clear();
         if let Some(cache) = self.cache.as_mut() {
             cache.purge();
         }
+        if let Some(read_ahead) = self.read_ahead.as_ref() {
+            read_ahead.clear();
+        }
         for segment in &mut self.segments {
             segment.delete().await.with_error_context(|error| {
                 format!("{COMPONENT} (error: {error}) - failed to delete segment: {segment}",)
@@ -65,25 +128,52 @@ impl Partition {
                 .fetch_sub(1, Ordering::SeqCst);
         }
         self.segments.clear();
+        self.add_persisted_segment(start_offset)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to add persisted segment in partition: {self}",)
+            })?;
+        if offsets_start_over {
+            self.advance_epoch().await?;
+        }
+        Ok(())
+    }
+
+    async fn purge_segments_older_than(
+        &mut self,
+        older_than: IggyTimestamp,
+    ) -> Result<(), IggyError> {
+        let mut start_offsets = Vec::new();
+        for segment in &self.segments {
+            if segment.is_older_than(older_than).await {
+                start_offsets.push(segment.start_offset);
+            }
+        }
+
+        for start_offset in start_offsets {
+            self.delete_segment(start_offset).await?;
+        }
+        Ok(())
+    }
+
+    async fn reset_consumer_offsets(&mut self) -> Result<(), IggyError> {
+        self.consumer_offsets.clear();
+        self.consumer_group_offsets.clear();
+        self.in_flight_messages.clear();
         self.storage
-            .partition
+            .consumer_offsets
             .delete_consumer_offsets(&self.consumer_offsets_path)
             .await
             .with_error_context(|error| {
                 format!("{COMPONENT} (error: {error}) - failed to delete consumer offsets in partition: {self}")
             })?;
         self.storage
-            .partition
+            .consumer_offsets
             .delete_consumer_offsets(&self.consumer_group_offsets_path)
             .await
             .with_error_context(|error| {
                 format!("{COMPONENT} (error: {error}) - failed to delete consumer offsets in partition: {self}")
             })?;
-        self.add_persisted_segment(0)
-            .await
-            .with_error_context(|error| {
-                format!("{COMPONENT} (error: {error}) - failed to add persisted segment in partition: {self}",)
-            })?;
 
         if !Path::new(&self.consumer_offsets_path).exists()
             && create_dir_all(&self.consumer_offsets_path).await.is_err()
@@ -111,6 +201,6 @@ impl Partition {
             ));
         }
 
-        Ok(())
+        self.persist_in_flight_messages().await
     }
 }
@@ -0,0 +1,174 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use ahash::AHashMap;
+use iggy::error::IggyError;
+use iggy::messages::send_messages::Message;
+use iggy::models::producer_session::ProducerSequence;
+use iggy::utils::timestamp::IggyTimestamp;
+use tracing::warn;
+
+/// The maximum number of the idempotent producer sessions tracked by the partition,
+/// once exceeded, the least recently seen ones are forgotten.
+const MAX_PRODUCER_SESSIONS: usize = 10_000;
+
+#[derive(Debug, Clone, Copy)]
+struct ProducerSessionState {
+    last_sequence: u64,
+    last_seen_at: u64,
+}
+
+/// Tracks the last sequence number appended by each idempotent producer session to the partition.
+#[derive(Debug, Default)]
+pub struct ProducerSessions {
+    sessions: AHashMap<u64, ProducerSessionState>,
+}
+
+impl ProducerSessions {
+    /// Returns the messages with the sequence numbers greater than the last one appended for their producer session,
+    /// and ignores the rest as the duplicates of the already appended messages.
+    /// The messages without the producer session headers are always retained.
+    pub fn retain_new(
+        &mut self,
+        messages: Vec<Message>,
+        partition_id: u32,
+    ) -> Result<Vec<Message>, IggyError> {
+        // All the headers are validated upfront, so the sessions are not updated if the batch is rejected.
+        let sequences = messages
+            .iter()
+            .map(|message| ProducerSequence::from_headers(&message.headers))
+            .collect::<Result<Vec<_>, _>>()?;
+        if sequences.iter().all(Option::is_none) {
+            return Ok(messages);
+        }
+
+        let now = IggyTimestamp::now().as_micros();
+        let mut retained_messages = Vec::with_capacity(messages.len());
+        for (message, sequence) in messages.into_iter().zip(sequences) {
+            let Some(sequence) = sequence else {
+                retained_messages.push(message);
+                continue;
+            };
+
+            let state = self
+                .sessions
+                .entry(sequence.producer_id)
+                .or_insert(ProducerSessionState {
+                    last_sequence: 0,
+                    last_seen_at: now,
+                });
+            state.last_seen_at = now;
+            if sequence.sequence <= state.last_sequence {
+                warn!(
+                    "Ignored the duplicated message with sequence: {} (last sequence: {}) sent by producer with ID: {} for partition with ID: {partition_id}.",
+                    sequence.sequence, state.last_sequence, sequence.producer_id
+                );
+                continue;
+            }
+
+            state.last_sequence = sequence.sequence;
+            retained_messages.push(message);
+        }
+
+        self.evict_least_recently_seen();
+        Ok(retained_messages)
+    }
+
+    fn evict_least_recently_seen(&mut self) {
+        while self.sessions.len() > MAX_PRODUCER_SESSIONS {
+            let Some(producer_id) = self
+                .sessions
+                .iter()
+                .min_by_key(|(_, state)| state.last_seen_at)
+                .map(|(producer_id, _)| *producer_id)
+            else {
+                return;
+            };
+            self.sessions.remove(&producer_id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+
+    fn create_message(producer_id: u64, sequence: u64) -> Message {
+        let mut message = Message::new(None, Bytes::from("message"), None);
+        ProducerSequence {
+            producer_id,
+            sequence,
+        }
+        .set_headers(&mut message.headers)
+        .unwrap();
+        message
+    }
+
+    #[test]
+    fn should_ignore_messages_with_already_appended_sequences() {
+        let mut producer_sessions = ProducerSessions::default();
+        let messages = vec![create_message(1, 1), create_message(1, 2)];
+        assert_eq!(producer_sessions.retain_new(messages, 1).unwrap().len(), 2);
+
+        let messages = vec![
+            create_message(1, 2),
+            create_message(1, 3),
+            create_message(2, 1),
+        ];
+        let retained_messages = producer_sessions.retain_new(messages, 1).unwrap();
+        let sequences = retained_messages
+            .iter()
+            .map(|message| ProducerSequence::from_headers(&message.headers).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            sequences,
+            vec![
+                Some(ProducerSequence {
+                    producer_id: 1,
+                    sequence: 3
+                }),
+                Some(ProducerSequence {
+                    producer_id: 2,
+                    sequence: 1
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_retain_messages_without_producer_session_headers() {
+        let mut producer_sessions = ProducerSessions::default();
+        let messages = vec![
+            Message::new(None, Bytes::from("message"), None),
+            Message::new(None, Bytes::from("message"), None),
+        ];
+        assert_eq!(producer_sessions.retain_new(messages, 1).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn should_evict_the_least_recently_seen_sessions() {
+        let mut producer_sessions = ProducerSessions::default();
+        for producer_id in 0..=MAX_PRODUCER_SESSIONS as u64 {
+            producer_sessions
+                .retain_new(vec![create_message(producer_id, 1)], 1)
+                .unwrap();
+        }
+        assert_eq!(producer_sessions.sessions.len(), MAX_PRODUCER_SESSIONS);
+    }
+}
@@ -0,0 +1,294 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::configs::system::ReadAheadConfig;
+use crate::streaming::models::messages::RetainedMessage;
+use crate::streaming::polling_consumer::PollingConsumer;
+use ahash::AHashMap;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+const IDLE_CONSUMER_EXPIRY: Duration = Duration::from_secs(60);
+const THROUGHPUT_SMOOTHING: f64 = 0.3;
+
+/// Tracks sequential consumption per consumer and buffers the messages read ahead for them.
+#[derive(Debug)]
+pub struct ReadAhead {
+    min_sequential_polls: u32,
+    lookahead: Duration,
+    max_messages: u32,
+    consumers: Mutex<AHashMap<ReadAheadKey, ConsumerReadAhead>>,
+}
+
+/// The offset range to be loaded in the background for a sequential consumer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReadAheadRequest {
+    pub start_offset: u64,
+    pub end_offset: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum ReadAheadKey {
+    Consumer(u32),
+    ConsumerGroup(u32),
+}
+
+#[derive(Debug)]
+struct ConsumerReadAhead {
+    next_offset: u64,
+    sequential_polls: u32,
+    last_poll_at: Instant,
+    messages_per_second: f64,
+    buffer: VecDeque<Arc<RetainedMessage>>,
+    in_flight: bool,
+}
+
+impl From<PollingConsumer> for ReadAheadKey {
+    fn from(consumer: PollingConsumer) -> Self {
+        match consumer {
+            PollingConsumer::Consumer(consumer_id, _) => ReadAheadKey::Consumer(consumer_id),
+            PollingConsumer::ConsumerGroup(group_id, _) => ReadAheadKey::ConsumerGroup(group_id),
+        }
+    }
+}
+
+impl ConsumerReadAhead {
+    fn new(now: Instant) -> Self {
+        Self {
+            next_offset: 0,
+            sequential_polls: 0,
+            last_poll_at: now,
+            messages_per_second: 0.0,
+            buffer: VecDeque::new(),
+            in_flight: false,
+        }
+    }
+
+    fn buffered_until(&self) -> u64 {
+        self.buffer
+            .back()
+            .map(|message| message.offset + 1)
+            .unwrap_or(self.next_offset)
+    }
+
+    fn trim(&mut self, offset: u64) {
+        while self
+            .buffer
+            .front()
+            .is_some_and(|message| message.offset < offset)
+        {
+            self.buffer.pop_front();
+        }
+    }
+}
+
+impl ReadAhead {
+    pub fn new(config: &ReadAheadConfig) -> Self {
+        Self {
+            min_sequential_polls: config.min_sequential_polls,
+            lookahead: config.lookahead.get_duration(),
+            max_messages: config.max_messages,
+            consumers: Mutex::new(AHashMap::new()),
+        }
+    }
+
+    /// Returns the buffered messages for the given range, if the whole range has been read ahead.
+    pub fn get_messages(
+        &self,
+        consumer: PollingConsumer,
+        start_offset: u64,
+        end_offset: u64,
+    ) -> Option<Vec<Arc<RetainedMessage>>> {
+        let mut consumers = self.consumers.lock().unwrap();
+        let state = consumers.get_mut(&consumer.into())?;
+        state.trim(start_offset);
+        let first_offset = state.buffer.front()?.offset;
+        let last_offset = state.buffer.back()?.offset;
+        if first_offset != start_offset || last_offset < end_offset {
+            return None;
+        }
+
+        let count = (end_offset - start_offset + 1) as usize;
+        Some(state.buffer.iter().take(count).cloned().collect())
+    }
+
+    /// Records the poll and returns the range to be read ahead, if the consumer is sequential
+    /// and its buffer is running low. Offsets beyond `max_offset` are never requested.
+    pub fn observe(
+        &self,
+        consumer: PollingConsumer,
+        start_offset: u64,
+        polled_count: u32,
+        requested_count: u32,
+        max_offset: u64,
+    ) -> Option<ReadAheadRequest> {
+        let now = Instant::now();
+        let mut consumers = self.consumers.lock().unwrap();
+        consumers.retain(|_, state| {
+            state.in_flight || now.duration_since(state.last_poll_at) < IDLE_CONSUMER_EXPIRY
+        });
+
+        let state = consumers
+            .entry(consumer.into())
+            .or_insert_with(|| ConsumerReadAhead::new(now));
+        if state.sequential_polls > 0 && start_offset == state.next_offset {
+            let elapsed = now.duration_since(state.last_poll_at).as_secs_f64();
+            if elapsed > 0.0 {
+                let rate = polled_count as f64 / elapsed;
+                state.messages_per_second = if state.messages_per_second == 0.0 {
+                    rate
+                } else {
+                    THROUGHPUT_SMOOTHING * rate
+                        + (1.0 - THROUGHPUT_SMOOTHING) * state.messages_per_second
+                };
+            }
+            state.sequential_polls = state.sequential_polls.saturating_add(1);
+        } else {
+            state.sequential_polls = 1;
+            state.messages_per_second = 0.0;
+            state.buffer.clear();
+        }
+
+        state.last_poll_at = now;
+        state.next_offset = start_offset + polled_count as u64;
+        state.trim(state.next_offset);
+        if polled_count == 0
+            || state.in_flight
+            || state.sequential_polls < self.min_sequential_polls
+        {
+            return None;
+        }
+
+        let window = ((state.messages_per_second * self.lookahead.as_secs_f64()) as u64).clamp(
+            requested_count as u64,
+            self.max_messages.max(requested_count) as u64,
+        );
+        let buffered_until = state.buffered_until();
+        if buffered_until > max_offset || buffered_until >= state.next_offset + window / 2 {
+            return None;
+        }
+
+        let end_offset = (state.next_offset + window - 1).min(max_offset);
+        if buffered_until > end_offset {
+            return None;
+        }
+
+        state.in_flight = true;
+        Some(ReadAheadRequest {
+            start_offset: buffered_until,
+            end_offset,
+        })
+    }
+
+    /// Stores the messages loaded in the background, keeping only the ones contiguous with the buffer.
+    pub fn complete(&self, consumer: PollingConsumer, messages: Vec<Arc<RetainedMessage>>) {
+        let mut consumers = self.consumers.lock().unwrap();
+        let Some(state) = consumers.get_mut(&consumer.into()) else {
+            return;
+        };
+
+        state.in_flight = false;
+        let mut expected_offset = state.buffered_until();
+        for message in messages {
+            if state.buffer.len() >= self.max_messages as usize {
+                break;
+            }
+            if message.offset < expected_offset {
+                continue;
+            }
+            if message.offset > expected_offset {
+                break;
+            }
+            expected_offset += 1;
+            state.buffer.push_back(message);
+        }
+    }
+
+    /// Drops all the read-ahead state, e.g. when the partition messages are purged.
+    pub fn clear(&self) {
+        self.consumers.lock().unwrap().clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+    use iggy::models::messages::MessageState;
+    use iggy::utils::duration::IggyDuration;
+    use std::str::FromStr;
+
+    fn read_ahead() -> ReadAhead {
+        ReadAhead::new(&ReadAheadConfig {
+            enabled: true,
+            min_sequential_polls: 2,
+            lookahead: IggyDuration::from_str("1 s").unwrap(),
+            max_messages: 100,
+        })
+    }
+
+    fn messages(start_offset: u64, end_offset: u64) -> Vec<Arc<RetainedMessage>> {
+        (start_offset..=end_offset)
+            .map(|offset| {
+                Arc::new(RetainedMessage {
+                    id: offset as u128,
+                    offset,
+                    timestamp: 0,
+                    checksum: 0,
+                    message_state: MessageState::Available,
+                    headers: None,
+                    payload: Bytes::from("test"),
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn should_request_read_ahead_only_after_sequential_polls() {
+        let read_ahead = read_ahead();
+        let consumer = PollingConsumer::Consumer(1, 1);
+        assert!(read_ahead.observe(consumer, 0, 10, 10, 1000).is_none());
+
+        let request = read_ahead.observe(consumer, 10, 10, 10, 1000).unwrap();
+        assert_eq!(request.start_offset, 20);
+        assert!(request.end_offset >= 29);
+        assert!(request.end_offset < 120);
+        assert!(read_ahead.observe(consumer, 20, 10, 10, 1000).is_none());
+    }
+
+    #[test]
+    fn should_serve_buffered_messages_and_reset_on_seek() {
+        let read_ahead = read_ahead();
+        let consumer = PollingConsumer::ConsumerGroup(1, 2);
+        read_ahead.observe(consumer, 0, 10, 10, 1000);
+        let request = read_ahead.observe(consumer, 10, 10, 10, 1000).unwrap();
+        read_ahead.complete(consumer, messages(request.start_offset, request.end_offset));
+
+        let buffered = read_ahead.get_messages(consumer, 20, 29).unwrap();
+        assert_eq!(buffered.len(), 10);
+        assert_eq!(buffered[0].offset, 20);
+        assert_eq!(buffered[9].offset, 29);
+        assert!(read_ahead
+            .get_messages(PollingConsumer::ConsumerGroup(2, 2), 20, 29)
+            .is_none());
+
+        read_ahead.observe(consumer, 500, 10, 10, 1000);
+        assert!(read_ahead.get_messages(consumer, 510, 519).is_none());
+    }
+}
@@ -0,0 +1,218 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::partitions::partition::Partition;
+use ahash::AHashMap;
+use iggy::error::IggyError;
+use iggy::models::partition::PartitionRecoveryProgress;
+use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::duration::IggyDuration;
+use iggy::utils::timestamp::IggyTimestamp;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// The bytes which can still be appended within the write rate, negative when the rate has been exceeded.
+/// It's refilled at the write rate, up to the bytes allowed in a single second.
+#[derive(Debug)]
+struct WriteBucket {
+    balance: i64,
+    updated_at: u64,
+}
+
+/// The background recovery of the partition, rebuilding the indexes of its closed segments.
+/// The appends are accepted meanwhile, but limited to the configured rate, so that they don't starve the rebuilds.
+/// The timestamps are expressed in microseconds since the Unix epoch.
+#[derive(Debug)]
+pub struct PartitionRecovery {
+    started_at: IggyTimestamp,
+    total_segments: u64,
+    total_bytes: u64,
+    recovered_segments: AtomicU64,
+    recovered_bytes: AtomicU64,
+    /// The sizes of the segments still being recovered, by their start offsets.
+    pending_segments: Mutex<AHashMap<u64, u64>>,
+    write_bytes_per_second: u64,
+    write_bucket: Mutex<WriteBucket>,
+}
+
+impl PartitionRecovery {
+    /// Starts the recovery of the segments with the given start offsets and sizes,
+    /// the write rate equal to 0 doesn't limit the appends.
+    pub fn new(segments: &[(u64, IggyByteSize)], write_rate: IggyByteSize) -> Self {
+        let started_at = IggyTimestamp::now();
+        let write_bytes_per_second = write_rate.as_bytes_u64();
+        let pending_segments = segments
+            .iter()
+            .map(|(start_offset, size)| (*start_offset, size.as_bytes_u64()))
+            .collect::<AHashMap<_, _>>();
+        Self {
+            started_at,
+            total_segments: pending_segments.len() as u64,
+            total_bytes: pending_segments.values().sum(),
+            recovered_segments: AtomicU64::new(0),
+            recovered_bytes: AtomicU64::new(0),
+            pending_segments: Mutex::new(pending_segments),
+            write_bytes_per_second,
+            write_bucket: Mutex::new(WriteBucket {
+                balance: write_bytes_per_second.min(i64::MAX as u64) as i64,
+                updated_at: started_at.as_micros(),
+            }),
+        }
+    }
+
+    /// Records the segment which has been recovered (or failed to), returning `true` once all the segments are done.
+    pub fn complete_segment(&self, start_offset: u64) -> bool {
+        let mut pending_segments = self.pending_segments.lock().unwrap();
+        if let Some(size) = pending_segments.remove(&start_offset) {
+            self.recovered_segments.fetch_add(1, Ordering::SeqCst);
+            self.recovered_bytes.fetch_add(size, Ordering::SeqCst);
+        }
+        pending_segments.is_empty()
+    }
+
+    pub fn is_completed(&self) -> bool {
+        self.pending_segments.lock().unwrap().is_empty()
+    }
+
+    /// Charges the appended bytes to the write rate, returning `false` if the rate has been exceeded
+    /// and the append should be rejected.
+    pub fn try_append(&self, bytes: u64, now: u64) -> bool {
+        if self.write_bytes_per_second == 0 || self.is_completed() {
+            return true;
+        }
+
+        let mut bucket = self.write_bucket.lock().unwrap();
+        let elapsed = now.saturating_sub(bucket.updated_at) as u128;
+        let refilled = (elapsed * self.write_bytes_per_second as u128 / 1_000_000)
+            .min(i64::MAX as u128) as i64;
+        // The time is not moved forward until at least a single byte is refilled, so that the frequent appends don't starve the bucket.
+        if refilled > 0 {
+            bucket.balance = bucket
+                .balance
+                .saturating_add(refilled)
+                .min(self.write_bytes_per_second.min(i64::MAX as u64) as i64);
+            bucket.updated_at = now;
+        }
+
+        // The batch larger than the bucket is accepted as long as the rate hasn't been exceeded yet.
+        if bucket.balance <= 0 {
+            return false;
+        }
+
+        bucket.balance = bucket
+            .balance
+            .saturating_sub(bytes.min(i64::MAX as u64) as i64);
+        true
+    }
+
+    /// Returns the progress of the recovery, with its completion time estimated from the rate of the recovered bytes so far.
+    pub fn get_progress(&self, now: u64) -> PartitionRecoveryProgress {
+        let recovered_segments = self.recovered_segments.load(Ordering::SeqCst);
+        let recovered_bytes = self.recovered_bytes.load(Ordering::SeqCst);
+        let elapsed = now.saturating_sub(self.started_at.as_micros());
+        PartitionRecoveryProgress {
+            started_at: self.started_at,
+            total_segments: self.total_segments as u32,
+            recovered_segments: recovered_segments as u32,
+            total_size: self.total_bytes.into(),
+            recovered_size: recovered_bytes.into(),
+            eta: estimate_remaining_time(elapsed, recovered_bytes, self.total_bytes),
+        }
+    }
+}
+
+impl Partition {
+    /// Returns the progress of the background recovery, `None` if the partition isn't being recovered.
+    pub fn get_recovery_progress(&self) -> Option<PartitionRecoveryProgress> {
+        self.recovery
+            .as_ref()
+            .map(|recovery| recovery.get_progress(IggyTimestamp::now().as_micros()))
+    }
+
+    /// Rejects the append exceeding the write rate of the partition being recovered.
+    pub(crate) fn ensure_append_allowed(&self, bytes: IggyByteSize) -> Result<(), IggyError> {
+        let Some(recovery) = &self.recovery else {
+            return Ok(());
+        };
+
+        if !recovery.try_append(bytes.as_bytes_u64(), IggyTimestamp::now().as_micros()) {
+            return Err(IggyError::PartitionRecovering(
+                self.partition_id,
+                self.topic_id,
+                self.stream_id,
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns `None` until anything has been recovered, as there's no rate to estimate the remaining time from.
+fn estimate_remaining_time(
+    elapsed: u64,
+    recovered_bytes: u64,
+    total_bytes: u64,
+) -> Option<IggyDuration> {
+    if recovered_bytes == 0 {
+        return None;
+    }
+
+    let remaining_bytes = total_bytes.saturating_sub(recovered_bytes) as u128;
+    let remaining =
+        (elapsed as u128 * remaining_bytes / recovered_bytes as u128).min(u64::MAX as u128) as u64;
+    Some(IggyDuration::new(Duration::from_micros(remaining)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn appends_exceeding_write_rate_should_be_rejected_until_bucket_is_refilled() {
+        let recovery =
+            PartitionRecovery::new(&[(0, IggyByteSize::from(1000))], IggyByteSize::from(1000));
+        let now = recovery.started_at.as_micros();
+        assert!(recovery.try_append(1500, now));
+        assert!(!recovery.try_append(1, now));
+        assert!(!recovery.try_append(1, now + 500_000));
+        assert!(recovery.try_append(1, now + 600_000));
+
+        assert!(recovery.complete_segment(0));
+        assert!(recovery.try_append(10_000, now + 600_000));
+    }
+
+    #[test]
+    fn remaining_time_should_be_estimated_from_recovered_bytes() {
+        let recovery = PartitionRecovery::new(
+            &[(0, IggyByteSize::from(100)), (10, IggyByteSize::from(300))],
+            IggyByteSize::from(0),
+        );
+        let now = recovery.started_at.as_micros();
+        assert_eq!(recovery.get_progress(now + 1_000_000).eta, None);
+
+        assert!(!recovery.complete_segment(0));
+        let progress = recovery.get_progress(now + 1_000_000);
+        assert_eq!(progress.recovered_segments, 1);
+        assert_eq!(progress.recovered_size, IggyByteSize::from(100));
+        assert_eq!(
+            progress.eta.map(|eta| eta.get_duration()),
+            Some(Duration::from_secs(3))
+        );
+    }
+}
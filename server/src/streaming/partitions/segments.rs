@@ -23,8 +23,9 @@ use crate::streaming::partitions::COMPONENT;
 use crate::streaming::segments::*;
 use error_set::ErrContext;
 use iggy::error::IggyError;
+use iggy::models::segment_range::SegmentRange;
 use iggy::utils::timestamp::IggyTimestamp;
-use tracing::info;
+use tracing::{info, warn};
 
 pub struct DeletedSegment {
     pub end_offset: u64,
@@ -56,6 +57,37 @@ impl Partition {
             .find(|s| s.start_offset == start_offset)
     }
 
+    /// Reads the raw bytes of the persisted batches from the segment containing the given offset,
+    /// or the oldest one, if the offset has been already deleted.
+    pub async fn read_segment_range(
+        &self,
+        offset: u64,
+        start_position: u64,
+        end_position: Option<u64>,
+        max_bytes: u32,
+    ) -> Result<SegmentRange, IggyError> {
+        if offset > self.current_offset {
+            return Err(IggyError::InvalidOffset(offset));
+        }
+
+        let segment = self
+            .segments
+            .iter()
+            .rev()
+            .find(|segment| segment.start_offset <= offset)
+            .or_else(|| self.segments.first())
+            .ok_or(IggyError::SegmentNotFound)?;
+        segment
+            .read_segment_range(start_position, end_position, max_bytes)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to read segment range for offset: {offset}, partition ID: {}",
+                    self.partition_id
+                )
+            })
+    }
+
     pub async fn get_expired_segments_start_offsets(&self, now: IggyTimestamp) -> Vec<u64> {
         let mut expired_segments = Vec::new();
         for segment in &self.segments {
@@ -68,6 +100,27 @@ impl Partition {
         expired_segments
     }
 
+    /// Returns the start offsets of the oldest closed segments exceeding the max number of segments, `0` means unlimited.
+    /// The segments restored from the archive are neither counted nor returned.
+    pub fn get_excess_segments_start_offsets(&self, max_segments: u32) -> Vec<u64> {
+        if max_segments == 0 {
+            return Vec::new();
+        }
+
+        let segments = self
+            .segments
+            .iter()
+            .filter(|segment| !self.is_restored_segment(segment.start_offset))
+            .collect::<Vec<_>>();
+        let excess_segments_count = segments.len().saturating_sub(max_segments as usize);
+        segments
+            .into_iter()
+            .take(excess_segments_count)
+            .take_while(|segment| segment.is_closed)
+            .map(|segment| segment.start_offset)
+            .collect()
+    }
+
     pub async fn add_persisted_segment(&mut self, start_offset: u64) -> Result<(), IggyError> {
         info!(
             "Creating the new segment for partition with ID: {}, stream with ID: {}, topic with ID: {}...",
@@ -80,6 +133,7 @@ impl Partition {
             start_offset,
             self.config.clone(),
             self.message_expiry,
+            self.compression_algorithm,
             self.size_of_parent_stream.clone(),
             self.size_of_parent_topic.clone(),
             self.size_bytes.clone(),
@@ -87,6 +141,7 @@ impl Partition {
             self.messages_count_of_parent_topic.clone(),
             self.messages_count.clone(),
         );
+        new_segment.max_size_bytes = self.segment_size;
 
         new_segment.persist().await.with_error_context(|error| {
             format!("{COMPONENT} (error: {error}) - failed to persist new segment: {new_segment}",)
@@ -96,6 +151,10 @@ impl Partition {
             .fetch_add(1, Ordering::SeqCst);
         self.segments
             .sort_by(|a, b| a.start_offset.cmp(&b.start_offset));
+        // The previous segment has been closed, so it no longer needs to be validated after a crash.
+        if let Err(error) = self.save_manifest(false).await {
+            warn!("Failed to save manifest for partition: {self}. {error}");
+        }
         Ok(())
     }
 
@@ -122,6 +181,8 @@ impl Partition {
             .fetch_sub(1, Ordering::SeqCst);
 
         self.segments.retain(|s| s.start_offset != start_offset);
+        self.compacted_segments
+            .retain(|s| s.start_offset != start_offset);
         self.segments
             .sort_by(|a, b| a.start_offset.cmp(&b.start_offset));
         info!(
@@ -0,0 +1,260 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::partitions::partition::Partition;
+use crate::streaming::partitions::COMPONENT;
+use bytes::Bytes;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+use tokio::fs;
+use tracing::{trace, warn};
+
+pub const STATE_STORES_DIRECTORY: &str = "state";
+
+/// The key/value state of the consumer group kept along with the partition,
+/// so that it's available to whichever member the partition is currently assigned to.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PartitionStateStore {
+    entries: BTreeMap<String, Vec<u8>>,
+}
+
+impl PartitionStateStore {
+    pub fn get(&self, key: &str) -> Option<Bytes> {
+        self.entries
+            .get(key)
+            .map(|value| Bytes::copy_from_slice(value))
+    }
+
+    /// Returns the total size (in bytes) of the stored keys and values.
+    pub fn get_size(&self) -> u64 {
+        self.entries
+            .iter()
+            .map(|(key, value)| (key.len() + value.len()) as u64)
+            .sum()
+    }
+
+    /// Stores the value under the key, unless the store would exceed the maximum size.
+    pub fn store(&mut self, key: &str, value: &[u8], max_size: u64) -> Result<(), IggyError> {
+        let replaced_size = self
+            .entries
+            .get(key)
+            .map(|value| (key.len() + value.len()) as u64)
+            .unwrap_or_default();
+        let size = self.get_size() - replaced_size + (key.len() + value.len()) as u64;
+        if size > max_size {
+            return Err(IggyError::PartitionStateStoreFull(max_size));
+        }
+
+        self.entries.insert(key.to_owned(), value.to_vec());
+        Ok(())
+    }
+
+    /// Removes the value stored under the key, returns `true` if it existed.
+    pub fn delete(&mut self, key: &str) -> bool {
+        self.entries.remove(key).is_some()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl Partition {
+    pub fn get_state_stores_path(&self) -> String {
+        format!("{}/{STATE_STORES_DIRECTORY}", self.partition_path)
+    }
+
+    pub fn get_state_store_path(&self, group_id: u32) -> String {
+        format!("{}/{group_id}", self.get_state_stores_path())
+    }
+
+    /// Returns the value stored under the key by the consumer group, `None` if it doesn't exist.
+    pub fn get_state(&self, group_id: u32, key: &str) -> Option<Bytes> {
+        self.state_stores
+            .get(&group_id)
+            .and_then(|state_store| state_store.get(key))
+    }
+
+    pub async fn store_state(
+        &mut self,
+        group_id: u32,
+        key: &str,
+        value: &[u8],
+    ) -> Result<(), IggyError> {
+        let max_size = self.config.partition.max_state_store_size.as_bytes_u64();
+        self.state_stores
+            .entry(group_id)
+            .or_default()
+            .store(key, value, max_size)
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to store state with key: {key} for consumer group with ID: {group_id}, partition: {self}")
+            })?;
+        self.persist_state_store(group_id).await
+    }
+
+    /// Removes the value stored under the key by the consumer group, returns `true` if it existed.
+    pub async fn delete_state(&mut self, group_id: u32, key: &str) -> Result<bool, IggyError> {
+        let Some(state_store) = self.state_stores.get_mut(&group_id) else {
+            return Ok(false);
+        };
+
+        if !state_store.delete(key) {
+            return Ok(false);
+        }
+
+        self.persist_state_store(group_id).await?;
+        Ok(true)
+    }
+
+    /// Loads the state stores of the consumer groups, the ones which cannot be parsed are skipped.
+    pub async fn load_state_stores(&mut self) {
+        let path = self.get_state_stores_path();
+        let Ok(mut dir_entries) = fs::read_dir(&path).await else {
+            trace!("State stores at path: {path} do not exist.");
+            return;
+        };
+
+        self.state_stores.clear();
+        while let Some(dir_entry) = dir_entries.next_entry().await.unwrap_or(None) {
+            let Some(group_id) = dir_entry
+                .file_name()
+                .to_str()
+                .and_then(|file_name| file_name.parse::<u32>().ok())
+            else {
+                continue;
+            };
+
+            let state_store_path = dir_entry.path();
+            let data = match fs::read(&state_store_path).await {
+                Ok(data) => data,
+                Err(error) => {
+                    warn!("Failed to read state store at path: {state_store_path:?}. {error}");
+                    continue;
+                }
+            };
+
+            match bincode::serde::decode_from_slice::<PartitionStateStore, _>(
+                &data,
+                bincode::config::standard(),
+            ) {
+                Ok((state_store, _)) => {
+                    self.state_stores.insert(group_id, state_store);
+                }
+                Err(error) => {
+                    warn!("Failed to parse state store at path: {state_store_path:?}, it will be skipped. {error}");
+                }
+            }
+        }
+    }
+
+    /// Removes the state store of the consumer group, e.g. when the group has been deleted.
+    pub async fn delete_state_store(&mut self, group_id: u32) -> Result<(), IggyError> {
+        self.state_stores.remove(&group_id);
+        self.delete_state_store_file(group_id).await
+    }
+
+    async fn delete_state_store_file(&self, group_id: u32) -> Result<(), IggyError> {
+        let path = self.get_state_store_path(group_id);
+        if !Path::new(&path).exists() {
+            return Ok(());
+        }
+
+        self.storage
+            .persister
+            .delete(&path)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to delete state store at path: {path}"
+                )
+            })
+    }
+
+    async fn persist_state_store(&self, group_id: u32) -> Result<(), IggyError> {
+        let Some(state_store) = self.state_stores.get(&group_id) else {
+            return Ok(());
+        };
+
+        if state_store.is_empty() {
+            return self.delete_state_store_file(group_id).await;
+        }
+
+        let data = bincode::serde::encode_to_vec(state_store, bincode::config::standard())
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to serialize state store for consumer group with ID: {group_id}, partition: {self}")
+            })
+            .map_err(|_| IggyError::CannotSerializeResource)?;
+        let directory = self.get_state_stores_path();
+        if !Path::new(&directory).exists() {
+            fs::create_dir_all(&directory)
+                .await
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to create state stores directory: {directory}")
+                })
+                .map_err(|_| {
+                    IggyError::CannotCreatePartitionDirectory(
+                        self.partition_id,
+                        self.stream_id,
+                        self.topic_id,
+                    )
+                })?;
+        }
+
+        let path = self.get_state_store_path(group_id);
+        self.storage
+            .persister
+            .overwrite(&path, &data)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to save state store at path: {path}")
+            })?;
+        trace!("Saved state store for consumer group with ID: {group_id}, partition: {self}.");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn state_store_should_not_exceed_max_size() {
+        let mut state_store = PartitionStateStore::default();
+        state_store.store("count", &[1, 0, 0, 0], 16).unwrap();
+        state_store.store("count", &[2, 0, 0, 0], 16).unwrap();
+        assert_eq!(state_store.get_size(), 9);
+        assert_eq!(
+            state_store.get("count"),
+            Some(Bytes::from_static(&[2, 0, 0, 0]))
+        );
+
+        assert!(matches!(
+            state_store.store("window", &[0; 8], 16),
+            Err(IggyError::PartitionStateStoreFull(16))
+        ));
+
+        assert!(state_store.delete("count"));
+        assert!(!state_store.delete("count"));
+        assert_eq!(state_store.get("count"), None);
+        state_store.store("window", &[0; 8], 16).unwrap();
+        assert_eq!(state_store.get_size(), 14);
+    }
+}
@@ -19,33 +19,23 @@
 use crate::compat::index_rebuilding::index_rebuilder::IndexRebuilder;
 use crate::state::system::PartitionState;
 use crate::streaming::batching::batch_accumulator::BatchAccumulator;
-use crate::streaming::partitions::partition::{ConsumerOffset, Partition};
+use crate::streaming::partitions::index_rebuilds::REBUILT_INDEX_EXTENSION;
+use crate::streaming::partitions::manifest::{PartitionManifest, SegmentManifest};
+use crate::streaming::partitions::partition::Partition;
 use crate::streaming::partitions::COMPONENT;
-use crate::streaming::persistence::persister::PersisterKind;
 use crate::streaming::segments::*;
 use crate::streaming::storage::PartitionStorage;
-use crate::streaming::utils::file;
 use error_set::ErrContext;
-use iggy::consumer::ConsumerKind;
 use iggy::error::IggyError;
+use iggy::utils::expiry::IggyExpiry;
 use std::path::Path;
 use std::sync::atomic::Ordering;
-use std::sync::Arc;
 use tokio::fs;
 use tokio::fs::create_dir_all;
-use tokio::io::AsyncReadExt;
 use tracing::{error, info, trace, warn};
 
 #[derive(Debug)]
-pub struct FilePartitionStorage {
-    persister: Arc<PersisterKind>,
-}
-
-impl FilePartitionStorage {
-    pub fn new(persister: Arc<PersisterKind>) -> Self {
-        Self { persister }
-    }
-}
+pub struct FilePartitionStorage;
 
 impl PartitionStorage for FilePartitionStorage {
     async fn load(
@@ -75,6 +65,20 @@ impl PartitionStorage for FilePartitionStorage {
                 return Err(IggyError::CannotReadPartitions);
             }
 
+        // The manifest saved on the previous run allows to skip the validation of the unchanged segments.
+        let manifest = if partition.config.partition.use_manifest
+            && partition.config.partition.validate_checksum
+            && !partition.config.recovery.read_only
+        {
+            PartitionManifest::load(&partition.partition_path).await
+        } else {
+            None
+        };
+        let mut unchanged_segments_count = 0;
+        partition.load_compacted_segments().await;
+        partition.load_archived_segments().await;
+        let last_start_offset = get_last_start_offset(&partition.partition_path).await;
+
         let mut dir_entries = dir_entries.unwrap();
         while let Some(dir_entry) = dir_entries.next_entry().await.unwrap_or(None) {
             let path = dir_entry.path();
@@ -94,13 +98,21 @@ impl PartitionStorage for FilePartitionStorage {
                 .replace(&format!(".{}", LOG_EXTENSION), "");
 
             let start_offset = log_file_name.parse::<u64>().unwrap();
+            // The segment restored from the archive never expires, otherwise it would be deleted right away.
+            let is_restored = partition.is_restored_segment(start_offset);
+            let message_expiry = if is_restored {
+                IggyExpiry::NeverExpire
+            } else {
+                partition.message_expiry
+            };
             let mut segment = Segment::create(
                 partition.stream_id,
                 partition.topic_id,
                 partition.partition_id,
                 start_offset,
                 partition.config.clone(),
-                partition.message_expiry,
+                message_expiry,
+                partition.compression_algorithm,
                 partition.size_of_parent_stream.clone(),
                 partition.size_of_parent_topic.clone(),
                 partition.size_bytes.clone(),
@@ -108,49 +120,121 @@ impl PartitionStorage for FilePartitionStorage {
                 partition.messages_count_of_parent_topic.clone(),
                 partition.messages_count.clone(),
             );
+            let compacted_segment = partition.find_compacted_segment(start_offset);
+            if let Some(compacted_segment) = compacted_segment {
+                segment.compacted_messages_count = Some(compacted_segment.messages_count);
+            }
 
             let index_path = segment.index_path.to_owned();
             let log_path = segment.log_path.to_owned();
             let time_index_path = index_path.replace(INDEX_EXTENSION, "timeindex");
 
-            let index_cache_enabled = partition.config.segment.cache_indexes;
+            let index_cache_enabled = segment.index_cache.is_some();
+            let read_only = partition.config.recovery.read_only;
+
+            // The leftover of the background rebuild interrupted by the shutdown is removed, the rebuild starts over if needed.
+            if !read_only {
+                let rebuilt_index_path = format!("{index_path}.{REBUILT_INDEX_EXTENSION}");
+                if fs::remove_file(&rebuilt_index_path).await.is_ok() {
+                    info!("Removed leftover of interrupted index rebuild at path: {rebuilt_index_path}.");
+                }
+            }
 
             let index_path_exists = tokio::fs::try_exists(&index_path).await.unwrap();
             let time_index_path_exists = tokio::fs::try_exists(&time_index_path).await.unwrap();
-
-            // Rebuild indexes in 2 cases:
-            // 1. Index cache is enabled and index at path does not exists.
-            // 2. Index cache is enabled and time index at path exists.
-            if index_cache_enabled && (!index_path_exists || time_index_path_exists) {
+            // The index left empty by the interrupted background rebuild has to be rebuilt again.
+            let index_is_empty = index_path_exists
+                && metadata.len() > 0
+                && fs::metadata(&index_path)
+                    .await
+                    .is_ok_and(|index_metadata| index_metadata.len() == 0);
+
+            // In read-only mode, the index cannot be rebuilt, so the segment is skipped and reported as damaged.
+            if read_only && !index_path_exists {
                 warn!(
-                    "Index at path {} does not exist, rebuilding it based on {}...",
+                    "Index at path {} does not exist, segment {} will be skipped in read-only mode.",
                     index_path, log_path
                 );
-                let now = tokio::time::Instant::now();
-                let index_rebuilder =
-                    IndexRebuilder::new(log_path.clone(), index_path.clone(), start_offset);
-                index_rebuilder.rebuild().await.unwrap_or_else(|e| {
-                    panic!(
-                        "Failed to rebuild index for partition with ID: {} for
-                    stream with ID: {} and topic with ID: {}. Error: {e}",
-                        partition.partition_id, partition.stream_id, partition.topic_id,
-                    )
-                });
-                info!(
-                    "Rebuilding index for path {} finished, it took {} ms",
-                    index_path,
-                    now.elapsed().as_millis()
-                );
+                continue;
+            }
+
+            let mut is_unchanged = false;
+            if let Some(manifest) = &manifest {
+                if index_path_exists {
+                    // The closed flag is irrelevant when comparing with the manifest entry.
+                    if let Ok(current) =
+                        SegmentManifest::read(start_offset, false, &log_path, &index_path).await
+                    {
+                        is_unchanged = manifest.is_unchanged(&current);
+                    }
+                }
+            }
+
+            // Rebuild indexes in 3 cases:
+            // 1. Index cache is enabled and index at path does not exists.
+            // 2. Index cache is enabled and time index at path exists.
+            // 3. Index cache is enabled and index at path is empty, while the log is not.
+            // Only the index of the last segment is rebuilt right away, as the partition offset depends on it,
+            // the log files of the other segments are scanned instead until their indexes are rebuilt in the background.
+            if !read_only
+                && index_cache_enabled
+                && (!index_path_exists || time_index_path_exists || index_is_empty)
+            {
+                if last_start_offset == Some(start_offset) {
+                    warn!(
+                        "Index at path {} does not exist, rebuilding it based on {}...",
+                        index_path, log_path
+                    );
+                    let now = tokio::time::Instant::now();
+                    let index_rebuilder =
+                        IndexRebuilder::new(log_path.clone(), index_path.clone(), start_offset);
+                    index_rebuilder
+                        .rebuild()
+                        .await
+                        .with_error_context(|error| {
+                            format!("{COMPONENT} (error: {error}) - failed to rebuild index at path: {index_path} for partition: {partition}")
+                        })
+                        .map_err(|_| IggyError::CannotSaveIndexToSegment)?;
+                    info!(
+                        "Rebuilding index for path {} finished, it took {} ms",
+                        index_path,
+                        now.elapsed().as_millis()
+                    );
+                } else {
+                    warn!(
+                        "Index at path {} does not exist, it will be rebuilt in the background based on {}.",
+                        index_path, log_path
+                    );
+                    // The legacy index cannot be read, so the segment is loaded without any index entries.
+                    if time_index_path_exists {
+                        fs::write(&index_path, [])
+                            .await
+                            .with_error_context(|error| {
+                                format!("{COMPONENT} (error: {error}) - failed to clear legacy index at path: {index_path}")
+                            })
+                            .map_err(|_| IggyError::CannotSaveIndexToSegment)?;
+                    }
+                    segment.index_rebuild_pending = true;
+                    partition.pending_index_rebuilds.push(start_offset);
+                }
             }
 
             // Remove legacy time index if it exists.
-            if time_index_path_exists {
+            if !read_only && time_index_path_exists {
                 tokio::fs::remove_file(&time_index_path).await.unwrap();
             }
 
             segment.load_from_disk().await.with_error_context(|error| {
                 format!("{COMPONENT} (error: {error}) - failed to load segment: {segment}",)
             })?;
+            // The last messages of the compacted segment might have been removed, so its offsets range is restored.
+            if let Some(compacted_segment) = compacted_segment {
+                segment.is_closed = true;
+                segment.current_offset = segment.current_offset.max(compacted_segment.end_offset);
+            }
+            if is_restored {
+                segment.is_closed = true;
+            }
             let capacity = partition.config.partition.messages_required_to_save;
             if !segment.is_closed {
                 segment.unsaved_messages = Some(BatchAccumulator::new(
@@ -164,7 +248,11 @@ impl PartitionStorage for FilePartitionStorage {
                 partition.should_increment_offset = segment.size_bytes > 0;
             }
 
-            if partition.config.partition.validate_checksum {
+            // In read-only mode, the checksums are validated as part of the integrity report.
+            if is_unchanged {
+                unchanged_segments_count += 1;
+                trace!("Segment with start offset: {} for partition with ID: {} is unchanged since the manifest was saved, skipping its validation.", segment.start_offset, partition.partition_id);
+            } else if !read_only && partition.config.partition.validate_checksum {
                 info!("Validating messages checksum for partition with ID: {} and segment with start offset: {}...", partition.partition_id, segment.start_offset);
                 segment.load_message_checksums().await?;
                 info!("Validated messages checksum for partition with ID: {} and segment with start offset: {}.", partition.partition_id, segment.start_offset);
@@ -211,6 +299,10 @@ impl PartitionStorage for FilePartitionStorage {
             }
 
             segment.end_offset = end_offsets[end_offset_index];
+            // The offsets of the segment without the index are known only from the start offset of the next one.
+            if segment.index_rebuild_pending {
+                segment.advance_current_offset(segment.end_offset);
+            }
         }
 
         if !partition.segments.is_empty() {
@@ -222,12 +314,28 @@ impl PartitionStorage for FilePartitionStorage {
             partition.current_offset = last_segment.current_offset;
         }
 
+        if let Some(manifest) = &manifest {
+            info!(
+                "Skipped validation of {unchanged_segments_count} unchanged segment(s) for partition with ID: {}, stream with ID: {} and topic with ID: {}, previous shutdown was clean: {}.",
+                partition.partition_id, partition.stream_id, partition.topic_id, manifest.clean_shutdown
+            );
+        }
+
+        partition.load_epoch().await;
+
+        // Clear the clean shutdown marker, so that the crash is detected on the next startup.
+        if let Err(error) = partition.save_manifest(false).await {
+            warn!("Failed to save manifest for partition: {partition}. {error}");
+        }
+
         partition
             .load_consumer_offsets()
             .await
             .with_error_context(|error| {
                 format!("{COMPONENT} (error: {error}) - failed to load consumer offsets, partition: {partition}",)
             })?;
+        partition.load_in_flight_messages().await;
+        partition.load_state_stores().await;
         info!(
             "Loaded partition with ID: {} for stream with ID: {} and topic with ID: {}, current offset: {}.",
             partition.partition_id, partition.stream_id, partition.topic_id, partition.current_offset
@@ -314,30 +422,6 @@ impl PartitionStorage for FilePartitionStorage {
             partition.partition_id, partition.stream_id, partition.topic_id,
         );
 
-        if let Err(err) = self
-            .delete_consumer_offsets(&partition.consumer_offsets_path)
-            .await
-        {
-            error!("Cannot delete consumer offsets for partition with ID: {} for topic with ID: {} for stream with ID: {}. Error: {}", partition.partition_id, partition.topic_id, partition.stream_id, err);
-            return Err(IggyError::CannotDeletePartition(
-                partition.partition_id,
-                partition.topic_id,
-                partition.stream_id,
-            ));
-        }
-
-        if let Err(err) = self
-            .delete_consumer_offsets(&partition.consumer_group_offsets_path)
-            .await
-        {
-            error!("Cannot delete consumer group offsets for partition with ID: {} for topic with ID: {} for stream with ID: {}. Error: {}", partition.partition_id, partition.topic_id, partition.stream_id, err);
-            return Err(IggyError::CannotDeletePartition(
-                partition.partition_id,
-                partition.topic_id,
-                partition.stream_id,
-            ));
-        }
-
         if fs::remove_dir_all(&partition.partition_path).await.is_err() {
             error!("Cannot delete partition directory: {} for partition with ID: {} for topic with ID: {} for stream with ID: {}.", partition.partition_path, partition.partition_id, partition.topic_id, partition.stream_id);
             return Err(IggyError::CannotDeletePartitionDirectory(
@@ -352,111 +436,26 @@ impl PartitionStorage for FilePartitionStorage {
         );
         Ok(())
     }
+}
 
-    async fn save_consumer_offset(&self, offset: u64, path: &str) -> Result<(), IggyError> {
-        self.persister
-            .overwrite(path, &offset.to_le_bytes())
-            .await
-            .with_error_context(|error| format!(
-                "{COMPONENT} (error: {error}) - failed to overwrite consumer offset with value: {}, path: {}",
-                offset, path,
-            ))?;
-        trace!("Stored consumer offset value: {}, path: {}", offset, path);
-        Ok(())
-    }
-
-    async fn load_consumer_offsets(
-        &self,
-        kind: ConsumerKind,
-        path: &str,
-    ) -> Result<Vec<ConsumerOffset>, IggyError> {
-        trace!("Loading consumer offsets from path: {path}...");
-        let dir_entries = fs::read_dir(&path).await;
-        if dir_entries.is_err() {
-            return Err(IggyError::CannotReadConsumerOffsets(path.to_owned()));
-        }
-
-        let mut consumer_offsets = Vec::new();
-        let mut dir_entries = dir_entries.unwrap();
-        while let Some(dir_entry) = dir_entries.next_entry().await.unwrap_or(None) {
-            let metadata = dir_entry.metadata().await;
-            if metadata.is_err() {
-                break;
-            }
-
-            if metadata.unwrap().is_dir() {
-                continue;
-            }
-
-            let name = dir_entry.file_name().into_string().unwrap();
-            let consumer_id = name.parse::<u32>();
-            if consumer_id.is_err() {
-                error!("Invalid consumer ID file with name: '{}'.", name);
-                continue;
-            }
-
-            let path = dir_entry.path();
-            let path = path.to_str();
-            if path.is_none() {
-                error!("Invalid consumer ID path for file with name: '{}'.", name);
-                continue;
-            }
-
-            let path = Arc::new(path.unwrap().to_string());
-            let consumer_id = consumer_id.unwrap();
-            let mut file = file::open(&path)
-                .await
-                .with_error_context(|error| {
-                    format!(
-                        "{COMPONENT} (error: {error}) - failed to open offset file, path: {path}"
-                    )
-                })
-                .map_err(|_| IggyError::CannotReadFile)?;
-            let offset = file
-                .read_u64_le()
-                .await
-                .with_error_context(|error| {
-                    format!("{COMPONENT} (error: {error}) - failed to read consumer offset from file, path: {path}")
-                })
-                .map_err(|_| IggyError::CannotReadFile)?;
-
-            consumer_offsets.push(ConsumerOffset {
-                kind,
-                consumer_id,
-                offset,
-                path,
-            });
-        }
-
-        consumer_offsets.sort_by(|a, b| a.consumer_id.cmp(&b.consumer_id));
-        Ok(consumer_offsets)
-    }
-
-    async fn delete_consumer_offsets(&self, path: &str) -> Result<(), IggyError> {
-        if !Path::new(path).exists() {
-            trace!("Consumer offsets directory does not exist: {path}.");
-            return Ok(());
-        }
-
-        if fs::remove_dir_all(path).await.is_err() {
-            error!("Cannot delete consumer offsets directory: {}.", path);
-            return Err(IggyError::CannotDeleteConsumerOffsetsDirectory(
-                path.to_owned(),
-            ));
-        }
-        Ok(())
-    }
-
-    async fn delete_consumer_offset(&self, path: &str) -> Result<(), IggyError> {
-        if !Path::new(path).exists() {
-            trace!("Consumer offset file does not exist: {path}.");
-            return Ok(());
+/// Returns the start offset of the last segment of the partition, based on the names of its log files.
+async fn get_last_start_offset(partition_path: &str) -> Option<u64> {
+    let mut dir_entries = fs::read_dir(partition_path).await.ok()?;
+    let mut last_start_offset = None;
+    while let Some(dir_entry) = dir_entries.next_entry().await.unwrap_or(None) {
+        let path = dir_entry.path();
+        if path
+            .extension()
+            .is_none_or(|extension| extension != LOG_EXTENSION)
+        {
+            continue;
         }
 
-        if fs::remove_file(path).await.is_err() {
-            error!("Cannot delete consumer offset file: {path}.");
-            return Err(IggyError::CannotDeleteConsumerOffsetFile(path.to_owned()));
-        }
-        Ok(())
+        let start_offset = path
+            .file_stem()
+            .and_then(|file_stem| file_stem.to_str())
+            .and_then(|file_stem| file_stem.parse::<u64>().ok());
+        last_start_offset = last_start_offset.max(start_offset);
     }
+    last_start_offset
 }
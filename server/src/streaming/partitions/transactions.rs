@@ -0,0 +1,178 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::partitions::partition::Partition;
+use ahash::AHashMap;
+use iggy::models::messages::{MessagesGap, MessagesGapReason, PolledMessage};
+use iggy::models::transaction::Transaction;
+
+#[derive(Debug, Clone, Copy)]
+struct OpenTransaction {
+    first_offset: u64,
+    last_offset: u64,
+}
+
+/// Tracks the transactions which have appended the messages to the partition,
+/// so that the read-committed polls can hide the messages of the open and aborted ones.
+#[derive(Debug, Default)]
+pub struct PartitionTransactions {
+    open: AHashMap<u64, OpenTransaction>,
+    /// The last offset appended within each aborted transaction, they're forgotten once all their messages are deleted.
+    aborted: AHashMap<u64, u64>,
+}
+
+impl PartitionTransactions {
+    /// Records the range of offsets appended within the open transaction.
+    pub fn track(&mut self, transaction_id: u64, first_offset: u64, last_offset: u64) {
+        let transaction = self.open.entry(transaction_id).or_insert(OpenTransaction {
+            first_offset,
+            last_offset,
+        });
+        transaction.last_offset = last_offset;
+    }
+
+    /// Makes the messages of the transaction visible to the read-committed polls.
+    pub fn commit(&mut self, transaction_id: u64) {
+        self.open.remove(&transaction_id);
+    }
+
+    /// Hides the messages of the transaction from the read-committed polls,
+    /// returns the last offset appended within it, if any.
+    pub fn abort(&mut self, transaction_id: u64) -> Option<u64> {
+        let transaction = self.open.remove(&transaction_id)?;
+        self.aborted.insert(transaction_id, transaction.last_offset);
+        Some(transaction.last_offset)
+    }
+
+    /// Restores the aborted transaction, e.g. after the restart of the server.
+    pub fn mark_aborted(&mut self, transaction_id: u64, last_offset: u64) {
+        self.open.remove(&transaction_id);
+        self.aborted.insert(transaction_id, last_offset);
+    }
+
+    pub fn is_aborted(&self, transaction_id: u64) -> bool {
+        self.aborted.contains_key(&transaction_id)
+    }
+
+    /// Returns `true` if there are neither open nor aborted transactions,
+    /// so that all the messages are visible to the read-committed polls.
+    pub fn is_empty(&self) -> bool {
+        self.open.is_empty() && self.aborted.is_empty()
+    }
+
+    /// Returns the offset of the first message of the oldest open transaction,
+    /// the read-committed polls return only the messages before it.
+    pub fn get_last_stable_offset(&self) -> Option<u64> {
+        self.open
+            .values()
+            .map(|transaction| transaction.first_offset)
+            .min()
+    }
+
+    /// Forgets the aborted transactions whose messages are all before the first available offset,
+    /// returns the IDs of the forgotten ones.
+    pub fn prune_aborted(&mut self, first_available_offset: u64) -> Vec<u64> {
+        let pruned = self
+            .aborted
+            .iter()
+            .filter(|(_, last_offset)| **last_offset < first_available_offset)
+            .map(|(transaction_id, _)| *transaction_id)
+            .collect::<Vec<_>>();
+        for transaction_id in &pruned {
+            self.aborted.remove(transaction_id);
+        }
+        pruned
+    }
+}
+
+impl Partition {
+    /// Returns the messages visible to the read-committed polls, which are the ones before the first message
+    /// of the oldest open transaction, except for the messages of the aborted transactions, returned as the gaps instead.
+    pub fn filter_committed_messages(
+        &self,
+        messages: Vec<PolledMessage>,
+    ) -> (Vec<PolledMessage>, Vec<MessagesGap>) {
+        let last_stable_offset = self.transactions.get_last_stable_offset();
+        let mut committed_messages = Vec::with_capacity(messages.len());
+        let mut gaps: Vec<MessagesGap> = Vec::new();
+        for message in messages {
+            if last_stable_offset.is_some_and(|offset| message.offset >= offset) {
+                break;
+            }
+
+            // The transaction headers are validated when appending the messages.
+            let is_aborted = Transaction::from_headers(&message.headers)
+                .ok()
+                .flatten()
+                .is_some_and(|transaction| {
+                    self.transactions.is_aborted(transaction.transaction_id)
+                });
+            if !is_aborted {
+                committed_messages.push(message);
+                continue;
+            }
+
+            match gaps.last_mut() {
+                Some(gap) if gap.end_offset + 1 == message.offset => {
+                    gap.end_offset = message.offset;
+                }
+                _ => gaps.push(MessagesGap {
+                    start_offset: message.offset,
+                    end_offset: message.offset,
+                    reason: MessagesGapReason::Aborted,
+                }),
+            }
+        }
+        (committed_messages, gaps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_return_first_offset_of_oldest_open_transaction_as_last_stable_offset() {
+        let mut transactions = PartitionTransactions::default();
+        assert_eq!(transactions.get_last_stable_offset(), None);
+
+        transactions.track(1, 10, 12);
+        transactions.track(2, 5, 6);
+        transactions.track(1, 20, 21);
+        assert_eq!(transactions.get_last_stable_offset(), Some(5));
+
+        transactions.commit(2);
+        assert_eq!(transactions.get_last_stable_offset(), Some(10));
+
+        assert_eq!(transactions.abort(1), Some(21));
+        assert_eq!(transactions.get_last_stable_offset(), None);
+        assert!(transactions.is_aborted(1));
+        assert!(!transactions.is_aborted(2));
+    }
+
+    #[test]
+    fn should_prune_aborted_transactions_with_deleted_messages() {
+        let mut transactions = PartitionTransactions::default();
+        transactions.mark_aborted(1, 10);
+        transactions.mark_aborted(2, 20);
+
+        assert_eq!(transactions.prune_aborted(15), vec![1]);
+        assert!(!transactions.is_aborted(1));
+        assert!(transactions.is_aborted(2));
+    }
+}
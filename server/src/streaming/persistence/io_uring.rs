@@ -0,0 +1,391 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::diagnostics::storage_metrics::StorageMetrics;
+use crate::streaming::persistence::persister::Persister;
+use crate::streaming::persistence::COMPONENT;
+use crate::streaming::utils::file;
+use bytes::Bytes;
+use error_set::ErrContext;
+use flume::{Receiver, Sender};
+use iggy::error::IggyError;
+use io_uring::{opcode, squeue, types, IoUring};
+use std::fs::File;
+use std::io::{self, IoSlice, Write};
+use std::os::unix::fs::FileExt;
+use std::os::unix::io::AsRawFd;
+use std::sync::{Arc, OnceLock};
+use std::time::Instant;
+use tokio::fs;
+use tokio::sync::oneshot;
+use tracing::{error, info};
+
+/// The position passed to the write, which makes the kernel use (and advance) the current file position.
+const CURRENT_POSITION: u64 = u64::MAX;
+
+static RING: OnceLock<Arc<IoUringRing>> = OnceLock::new();
+
+/// The write submitted to the ring, the buffers and the file are owned by the request until it's completed,
+/// so that they stay valid even if the awaiting future is dropped.
+struct WriteRequest {
+    file: Arc<File>,
+    buffers: Vec<Bytes>,
+    position: u64,
+    fsync: bool,
+    response: oneshot::Sender<io::Result<()>>,
+}
+
+/// The io_uring instance shared by all the persisters, driven by a dedicated thread.
+/// The requests received while the previous ones are being processed are submitted together,
+/// up to the queue depth, so that a single syscall is made for many writes (and their fsyncs).
+/// The writes of the distinct requests may complete in any order, so the same file must not be written concurrently.
+#[derive(Debug)]
+pub struct IoUringRing {
+    sender: Sender<WriteRequest>,
+}
+
+impl IoUringRing {
+    /// Returns the ring shared by the server, starting it with the given queue depth if it's not running yet.
+    pub fn get_or_init(queue_depth: u32) -> Result<Arc<IoUringRing>, IggyError> {
+        if let Some(ring) = RING.get() {
+            return Ok(ring.clone());
+        }
+
+        let ring = Arc::new(IoUringRing::start(queue_depth)?);
+        Ok(RING.get_or_init(|| ring).clone())
+    }
+
+    fn start(queue_depth: u32) -> Result<IoUringRing, IggyError> {
+        let ring = IoUring::new(queue_depth)
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to create io_uring with queue depth: {queue_depth}")
+            })
+            .map_err(|_| IggyError::CannotInitializeIoUring)?;
+        let (sender, receiver) = flume::unbounded();
+        std::thread::Builder::new()
+            .name("iggy-io-uring".to_owned())
+            .spawn(move || run(ring, receiver, queue_depth as usize))
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to spawn io_uring thread")
+            })
+            .map_err(|_| IggyError::CannotInitializeIoUring)?;
+        info!("Started io_uring persister with queue depth: {queue_depth}.");
+        Ok(IoUringRing { sender })
+    }
+
+    /// Writes the buffers at the given position of the file, or at its current position if `None`,
+    /// optionally followed by fsync, and waits for the completion.
+    pub async fn write(
+        &self,
+        file: Arc<File>,
+        buffers: Vec<Bytes>,
+        position: Option<u64>,
+        fsync: bool,
+    ) -> io::Result<()> {
+        let (response, receiver) = oneshot::channel();
+        let request = WriteRequest {
+            file,
+            buffers,
+            position: position.unwrap_or(CURRENT_POSITION),
+            fsync,
+            response,
+        };
+        self.sender
+            .send_async(request)
+            .await
+            .map_err(|_| io::Error::other("io_uring thread has stopped"))?;
+        receiver
+            .await
+            .map_err(|_| io::Error::other("io_uring thread has stopped"))?
+    }
+}
+
+/// The file written through the shared ring, used by the segment writers.
+#[derive(Debug)]
+pub struct IoUringFile {
+    ring: Arc<IoUringRing>,
+    file: Arc<File>,
+}
+
+impl IoUringFile {
+    /// Duplicates the descriptor of the opened file, so that it can be owned by the in-flight writes.
+    pub async fn new(ring: Arc<IoUringRing>, file: &fs::File) -> io::Result<Self> {
+        let file = file.try_clone().await?.into_std().await;
+        Ok(IoUringFile {
+            ring,
+            file: Arc::new(file),
+        })
+    }
+
+    /// Appends the buffers to the file opened in the append mode.
+    pub async fn append(&self, buffers: Vec<Bytes>, fsync: bool) -> io::Result<()> {
+        let size_bytes = buffers.iter().map(|buffer| buffer.len() as u64).sum();
+        let started_at = Instant::now();
+        self.ring
+            .write(self.file.clone(), buffers, None, fsync)
+            .await?;
+        StorageMetrics::get_instance().record_write(size_bytes);
+        if fsync {
+            StorageMetrics::get_instance().record_fsync(started_at.elapsed());
+        }
+        Ok(())
+    }
+}
+
+/// Persister submitting the writes to the shared ring, the files are opened (and deleted) the regular way.
+#[derive(Debug)]
+pub struct IoUringPersister {
+    ring: Arc<IoUringRing>,
+    fsync: bool,
+}
+
+impl IoUringPersister {
+    pub fn new(ring: Arc<IoUringRing>, fsync: bool) -> Self {
+        Self { ring, fsync }
+    }
+
+    async fn write(&self, file: fs::File, bytes: &[u8], position: Option<u64>) -> io::Result<()> {
+        let file = Arc::new(file.into_std().await);
+        let started_at = Instant::now();
+        self.ring
+            .write(
+                file,
+                vec![Bytes::copy_from_slice(bytes)],
+                position,
+                self.fsync,
+            )
+            .await?;
+        StorageMetrics::get_instance().record_write(bytes.len() as u64);
+        if self.fsync {
+            StorageMetrics::get_instance().record_fsync(started_at.elapsed());
+        }
+        Ok(())
+    }
+}
+
+impl Persister for IoUringPersister {
+    async fn append(&self, path: &str, bytes: &[u8]) -> Result<(), IggyError> {
+        let file = file::append(path)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to append to file: {path}")
+            })
+            .map_err(|_| IggyError::CannotAppendToFile)?;
+        self.write(file, bytes, None)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to write data to file: {path}")
+            })
+            .map_err(|_| IggyError::CannotWriteToFile)
+    }
+
+    async fn overwrite(&self, path: &str, bytes: &[u8]) -> Result<(), IggyError> {
+        let file = file::overwrite(path)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to overwrite file: {path}")
+            })
+            .map_err(|_| IggyError::CannotOverwriteFile)?;
+        self.write(file, bytes, Some(0))
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to write data to file: {path}")
+            })
+            .map_err(|_| IggyError::CannotWriteToFile)
+    }
+
+    async fn delete(&self, path: &str) -> Result<(), IggyError> {
+        fs::remove_file(path)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to delete file: {path}")
+            })
+            .map_err(|_| IggyError::CannotDeleteFile)?;
+        Ok(())
+    }
+}
+
+fn run(mut ring: IoUring, receiver: Receiver<WriteRequest>, queue_depth: usize) {
+    // Each request takes up to two entries, the write and the linked fsync.
+    let max_requests = (queue_depth / 2).max(1);
+    while let Ok(request) = receiver.recv() {
+        let mut requests = vec![request];
+        while requests.len() < max_requests {
+            match receiver.try_recv() {
+                Ok(request) => requests.push(request),
+                Err(_) => break,
+            }
+        }
+
+        let results = match submit(&mut ring, &requests) {
+            Ok(results) => results,
+            Err(error) => {
+                error!(
+                    "{COMPONENT} (error: {error}) - failed to submit {} writes to io_uring",
+                    requests.len()
+                );
+                requests
+                    .iter()
+                    .map(|_| Err(io::Error::from(error.kind())))
+                    .collect()
+            }
+        };
+        for (request, result) in requests.into_iter().zip(results) {
+            let _ = request.response.send(result);
+        }
+    }
+}
+
+fn submit(ring: &mut IoUring, requests: &[WriteRequest]) -> io::Result<Vec<io::Result<()>>> {
+    let slices = requests
+        .iter()
+        .map(|request| {
+            request
+                .buffers
+                .iter()
+                .map(|buffer| IoSlice::new(buffer))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let mut entries_count = 0;
+    for (index, request) in requests.iter().enumerate() {
+        let fd = types::Fd(request.file.as_raw_fd());
+        // IoSlice is guaranteed to be ABI compatible with iovec on Unix.
+        let write = opcode::Writev::new(
+            fd,
+            slices[index].as_ptr().cast(),
+            slices[index].len() as u32,
+        )
+        .offset(request.position)
+        .build()
+        .user_data((index as u64) << 1);
+        let write = if request.fsync {
+            write.flags(squeue::Flags::IO_LINK)
+        } else {
+            write
+        };
+        // SAFETY: the slices and the buffers they point to outlive the submission, as it's awaited below.
+        unsafe { ring.submission().push(&write) }.map_err(io::Error::other)?;
+        entries_count += 1;
+        if request.fsync {
+            let fsync = opcode::Fsync::new(fd)
+                .build()
+                .user_data(((index as u64) << 1) | 1);
+            unsafe { ring.submission().push(&fsync) }.map_err(io::Error::other)?;
+            entries_count += 1;
+        }
+    }
+
+    let mut written = vec![None; requests.len()];
+    let mut synced = vec![None; requests.len()];
+    let mut completed = 0;
+    while completed < entries_count {
+        ring.submit_and_wait(entries_count - completed)?;
+        for entry in ring.completion() {
+            let index = (entry.user_data() >> 1) as usize;
+            let result = entry.result();
+            if entry.user_data() & 1 == 0 {
+                written[index] = Some(result);
+            } else {
+                synced[index] = Some(result);
+            }
+            completed += 1;
+        }
+    }
+
+    Ok(requests
+        .iter()
+        .enumerate()
+        .map(|(index, request)| complete(request, written[index], synced[index]))
+        .collect())
+}
+
+/// Finishes the short write and the fsync cancelled because of it using the regular syscalls.
+fn complete(request: &WriteRequest, written: Option<i32>, synced: Option<i32>) -> io::Result<()> {
+    let written = match written {
+        Some(result) if result < 0 => return Err(io::Error::from_raw_os_error(-result)),
+        Some(result) => result as usize,
+        None => 0,
+    };
+
+    let size = request
+        .buffers
+        .iter()
+        .map(|buffer| buffer.len())
+        .sum::<usize>();
+    if written < size {
+        let remaining = request
+            .buffers
+            .iter()
+            .flat_map(|buffer| buffer.iter().copied())
+            .skip(written)
+            .collect::<Vec<_>>();
+        match request.position {
+            CURRENT_POSITION => (&*request.file).write_all(&remaining)?,
+            position => request
+                .file
+                .write_all_at(&remaining, position + written as u64)?,
+        }
+        if request.fsync {
+            return request.file.sync_all();
+        }
+        return Ok(());
+    }
+
+    match synced {
+        Some(result) if result < 0 => Err(io::Error::from_raw_os_error(-result)),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn writes_should_be_appended_and_overwritten_through_ring() {
+        let Ok(ring) = IoUringRing::get_or_init(8) else {
+            // io_uring may be disabled in the sandboxed environments.
+            return;
+        };
+        let directory = tempfile::TempDir::new().unwrap();
+        let path = directory.path().join("file");
+        let path = path.to_str().unwrap();
+        fs::write(path, b"").await.unwrap();
+
+        let persister = IoUringPersister::new(ring.clone(), true);
+        persister.append(path, b"hello").await.unwrap();
+        persister.append(path, b" world").await.unwrap();
+        assert_eq!(fs::read(path).await.unwrap(), b"hello world");
+
+        persister.overwrite(path, b"HELLO").await.unwrap();
+        assert_eq!(fs::read(path).await.unwrap(), b"HELLO world");
+
+        let file = IoUringFile::new(ring, &file::append(path).await.unwrap())
+            .await
+            .unwrap();
+        file.append(
+            vec![Bytes::from_static(b"!"), Bytes::from_static(b"?")],
+            false,
+        )
+        .await
+        .unwrap();
+        assert_eq!(fs::read(path).await.unwrap(), b"HELLO world!?");
+    }
+}
@@ -16,6 +16,8 @@
  * under the License.
  */
 
+#[cfg(all(target_os = "linux", feature = "io-uring"))]
+pub mod io_uring;
 pub mod persister;
 pub mod task;
 
@@ -16,12 +16,16 @@
  * under the License.
  */
 
+use crate::streaming::diagnostics::storage_metrics::StorageMetrics;
+#[cfg(all(target_os = "linux", feature = "io-uring"))]
+use crate::streaming::persistence::io_uring::IoUringPersister;
 use crate::streaming::persistence::COMPONENT;
 use crate::streaming::utils::file;
 use error_set::ErrContext;
 use iggy::error::IggyError;
 use std::fmt::Debug;
 use std::future::Future;
+use std::time::Instant;
 use tokio::fs;
 use tokio::io::AsyncWriteExt;
 
@@ -32,6 +36,8 @@ use mockall::automock;
 pub enum PersisterKind {
     File(FilePersister),
     FileWithSync(FileWithSyncPersister),
+    #[cfg(all(target_os = "linux", feature = "io-uring"))]
+    IoUring(IoUringPersister),
     #[cfg(test)]
     Mock(MockPersister),
 }
@@ -41,6 +47,8 @@ impl PersisterKind {
         match self {
             PersisterKind::File(p) => p.append(path, bytes).await,
             PersisterKind::FileWithSync(p) => p.append(path, bytes).await,
+            #[cfg(all(target_os = "linux", feature = "io-uring"))]
+            PersisterKind::IoUring(p) => p.append(path, bytes).await,
             #[cfg(test)]
             PersisterKind::Mock(p) => p.append(path, bytes).await,
         }
@@ -50,6 +58,8 @@ impl PersisterKind {
         match self {
             PersisterKind::File(p) => p.overwrite(path, bytes).await,
             PersisterKind::FileWithSync(p) => p.overwrite(path, bytes).await,
+            #[cfg(all(target_os = "linux", feature = "io-uring"))]
+            PersisterKind::IoUring(p) => p.overwrite(path, bytes).await,
             #[cfg(test)]
             PersisterKind::Mock(p) => p.overwrite(path, bytes).await,
         }
@@ -59,6 +69,8 @@ impl PersisterKind {
         match self {
             PersisterKind::File(p) => p.delete(path).await,
             PersisterKind::FileWithSync(p) => p.delete(path).await,
+            #[cfg(all(target_os = "linux", feature = "io-uring"))]
+            PersisterKind::IoUring(p) => p.delete(path).await,
             #[cfg(test)]
             PersisterKind::Mock(p) => p.delete(path).await,
         }
@@ -100,6 +112,7 @@ impl Persister for FilePersister {
                 format!("{COMPONENT} (error: {error}) - failed to write data to file: {path}")
             })
             .map_err(|_| IggyError::CannotWriteToFile)?;
+        StorageMetrics::get_instance().record_write(bytes.len() as u64);
         Ok(())
     }
 
@@ -116,6 +129,7 @@ impl Persister for FilePersister {
                 format!("{COMPONENT} (error: {error}) - failed to write data to file: {path}")
             })
             .map_err(|_| IggyError::CannotWriteToFile)?;
+        StorageMetrics::get_instance().record_write(bytes.len() as u64);
         Ok(())
     }
 
@@ -144,6 +158,8 @@ impl Persister for FileWithSyncPersister {
                 format!("{COMPONENT} (error: {error}) - failed to write data to file: {path}")
             })
             .map_err(|_| IggyError::CannotWriteToFile)?;
+        StorageMetrics::get_instance().record_write(bytes.len() as u64);
+        let started_at = Instant::now();
         file.sync_all()
             .await
             .with_error_context(|error| {
@@ -152,6 +168,7 @@ impl Persister for FileWithSyncPersister {
                 )
             })
             .map_err(|_| IggyError::CannotSyncFile)?;
+        StorageMetrics::get_instance().record_fsync(started_at.elapsed());
         Ok(())
     }
 
@@ -168,6 +185,8 @@ impl Persister for FileWithSyncPersister {
                 format!("{COMPONENT} (error: {error}) - failed to write data to file: {path}")
             })
             .map_err(|_| IggyError::CannotWriteToFile)?;
+        StorageMetrics::get_instance().record_write(bytes.len() as u64);
+        let started_at = Instant::now();
         file.sync_all()
             .await
             .with_error_context(|error| {
@@ -176,6 +195,7 @@ impl Persister for FileWithSyncPersister {
                 )
             })
             .map_err(|_| IggyError::CannotSyncFile)?;
+        StorageMetrics::get_instance().record_fsync(started_at.elapsed());
         Ok(())
     }
 
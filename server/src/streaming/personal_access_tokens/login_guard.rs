@@ -0,0 +1,364 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::configs::server::PersonalAccessTokenLoginGuardConfig;
+use ahash::AHashMap;
+use dashmap::DashMap;
+use iggy::error::IggyError;
+use iggy::models::user_info::UserId;
+use reqwest::header::CONTENT_TYPE;
+use serde::Serialize;
+use std::net::IpAddr;
+use tracing::{error, warn};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum LoginSource {
+    Token(String),
+    IpAddress(IpAddr),
+}
+
+#[derive(Debug, Default)]
+struct FailedAttempts {
+    window_start: u64,
+    count: u32,
+    blocked_until: u64,
+}
+
+#[derive(Debug, Default)]
+struct TokenIpAddresses {
+    first_seen_at: AHashMap<IpAddr, u64>,
+    alerted_at: Option<u64>,
+}
+
+/// The security event emitted by the login guard, written to the server logs and optionally sent to the webhook.
+/// The timestamps are expressed in microseconds since the Unix epoch.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum SecurityEvent {
+    LoginThrottled {
+        timestamp: u64,
+        source: String,
+        failed_attempts: u32,
+        blocked_until: u64,
+    },
+    SuspiciousTokenUsage {
+        timestamp: u64,
+        user_id: UserId,
+        token_name: String,
+        ip_addresses: Vec<IpAddr>,
+    },
+}
+
+/// Throttles the brute-force login attempts with the personal access tokens, by tracking the failed attempts
+/// per token and per client IP address, and detects the tokens used from many new IP addresses in a short window.
+#[derive(Debug)]
+pub struct PersonalAccessTokenLoginGuard {
+    config: PersonalAccessTokenLoginGuardConfig,
+    failed_attempts: DashMap<LoginSource, FailedAttempts>,
+    ip_addresses: DashMap<String, TokenIpAddresses>,
+    webhook_client: Option<reqwest::Client>,
+}
+
+impl PersonalAccessTokenLoginGuard {
+    pub fn new(config: PersonalAccessTokenLoginGuardConfig) -> Self {
+        let webhook_client = if config.enabled && !config.webhook_url.is_empty() {
+            Some(reqwest::Client::new())
+        } else {
+            None
+        };
+        Self {
+            config,
+            failed_attempts: DashMap::new(),
+            ip_addresses: DashMap::new(),
+            webhook_client,
+        }
+    }
+
+    /// Returns an error if the login attempts with the given token or from the given IP address are blocked.
+    pub fn ensure_allowed(
+        &self,
+        token_hash: &str,
+        ip_address: Option<IpAddr>,
+        now: u64,
+    ) -> Result<(), IggyError> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+
+        for source in Self::get_sources(token_hash, ip_address) {
+            if let Some(attempts) = self.failed_attempts.get(&source) {
+                if attempts.blocked_until > now {
+                    let retry_in_seconds = (attempts.blocked_until - now).div_ceil(1_000_000);
+                    return Err(IggyError::PersonalAccessTokenLoginThrottled(
+                        retry_in_seconds,
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn record_failure(&self, token_hash: &str, ip_address: Option<IpAddr>, now: u64) {
+        if !self.config.enabled {
+            return;
+        }
+
+        for event in self.track_failure(token_hash, ip_address, now) {
+            self.emit(event);
+        }
+    }
+
+    pub fn record_success(
+        &self,
+        token_hash: &str,
+        ip_address: Option<IpAddr>,
+        user_id: UserId,
+        token_name: &str,
+        now: u64,
+    ) {
+        if !self.config.enabled {
+            return;
+        }
+
+        // The failures of the IP address are kept, as it could still be used to guess the other tokens.
+        self.failed_attempts
+            .remove(&LoginSource::Token(token_hash.to_owned()));
+        let Some(ip_address) = ip_address else {
+            return;
+        };
+
+        if let Some(event) = self.track_ip_address(token_hash, ip_address, user_id, token_name, now)
+        {
+            self.emit(event);
+        }
+    }
+
+    /// Removes the failed attempts and the IP addresses which are no longer within their windows.
+    pub fn prune(&self, now: u64) {
+        let failed_attempts_window = self.config.failed_attempts_window.as_micros();
+        self.failed_attempts.retain(|_, attempts| {
+            attempts.blocked_until > now
+                || now.saturating_sub(attempts.window_start) <= failed_attempts_window
+        });
+
+        let new_ip_addresses_window = self.config.new_ip_addresses_window.as_micros();
+        self.ip_addresses.retain(|_, addresses| {
+            addresses.first_seen_at.retain(|_, first_seen_at| {
+                now.saturating_sub(*first_seen_at) <= new_ip_addresses_window
+            });
+            !addresses.first_seen_at.is_empty()
+        });
+    }
+
+    fn track_failure(
+        &self,
+        token_hash: &str,
+        ip_address: Option<IpAddr>,
+        now: u64,
+    ) -> Vec<SecurityEvent> {
+        let mut events = Vec::new();
+        let failed_attempts_window = self.config.failed_attempts_window.as_micros();
+        for source in Self::get_sources(token_hash, ip_address) {
+            let mut attempts = self.failed_attempts.entry(source.clone()).or_default();
+            if now.saturating_sub(attempts.window_start) > failed_attempts_window {
+                attempts.window_start = now;
+                attempts.count = 0;
+            }
+
+            attempts.count += 1;
+            if attempts.count < self.config.max_failed_attempts || attempts.blocked_until > now {
+                continue;
+            }
+
+            attempts.blocked_until = now + self.config.block_duration.as_micros();
+            events.push(SecurityEvent::LoginThrottled {
+                timestamp: now,
+                source: match source {
+                    LoginSource::Token(_) => "personal_access_token".to_owned(),
+                    LoginSource::IpAddress(ip_address) => ip_address.to_string(),
+                },
+                failed_attempts: attempts.count,
+                blocked_until: attempts.blocked_until,
+            });
+        }
+        events
+    }
+
+    fn track_ip_address(
+        &self,
+        token_hash: &str,
+        ip_address: IpAddr,
+        user_id: UserId,
+        token_name: &str,
+        now: u64,
+    ) -> Option<SecurityEvent> {
+        let mut addresses = self.ip_addresses.entry(token_hash.to_owned()).or_default();
+        if addresses.first_seen_at.contains_key(&ip_address) {
+            return None;
+        }
+
+        addresses.first_seen_at.insert(ip_address, now);
+        let new_ip_addresses_window = self.config.new_ip_addresses_window.as_micros();
+        let mut new_ip_addresses = addresses
+            .first_seen_at
+            .iter()
+            .filter(|(_, first_seen_at)| {
+                now.saturating_sub(**first_seen_at) <= new_ip_addresses_window
+            })
+            .map(|(ip_address, _)| *ip_address)
+            .collect::<Vec<_>>();
+        if new_ip_addresses.len() as u32 <= self.config.max_new_ip_addresses {
+            return None;
+        }
+
+        // The same token is reported at most once per window.
+        if addresses
+            .alerted_at
+            .is_some_and(|alerted_at| now.saturating_sub(alerted_at) <= new_ip_addresses_window)
+        {
+            return None;
+        }
+
+        addresses.alerted_at = Some(now);
+        new_ip_addresses.sort();
+        Some(SecurityEvent::SuspiciousTokenUsage {
+            timestamp: now,
+            user_id,
+            token_name: token_name.to_owned(),
+            ip_addresses: new_ip_addresses,
+        })
+    }
+
+    fn emit(&self, event: SecurityEvent) {
+        let payload = match serde_json::to_string(&event) {
+            Ok(payload) => payload,
+            Err(error) => {
+                error!("Failed to serialize security event: {event:?}. {error}");
+                return;
+            }
+        };
+
+        warn!("Security event: {payload}");
+        let Some(client) = self.webhook_client.clone() else {
+            return;
+        };
+
+        let webhook_url = self.config.webhook_url.clone();
+        tokio::spawn(async move {
+            let result = client
+                .post(&webhook_url)
+                .header(CONTENT_TYPE, "application/json")
+                .body(payload)
+                .send()
+                .await
+                .and_then(|response| response.error_for_status());
+            if let Err(error) = result {
+                error!("Failed to send security event to webhook: {webhook_url}. {error}");
+            }
+        });
+    }
+
+    fn get_sources(token_hash: &str, ip_address: Option<IpAddr>) -> Vec<LoginSource> {
+        let mut sources = vec![LoginSource::Token(token_hash.to_owned())];
+        if let Some(ip_address) = ip_address {
+            sources.push(LoginSource::IpAddress(ip_address));
+        }
+        sources
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy::utils::duration::IggyDuration;
+    use std::net::Ipv4Addr;
+    use std::str::FromStr;
+
+    const SECOND: u64 = 1_000_000;
+
+    fn login_guard() -> PersonalAccessTokenLoginGuard {
+        PersonalAccessTokenLoginGuard::new(PersonalAccessTokenLoginGuardConfig {
+            enabled: true,
+            max_failed_attempts: 3,
+            failed_attempts_window: IggyDuration::from_str("1 m").unwrap(),
+            block_duration: IggyDuration::from_str("5 m").unwrap(),
+            max_new_ip_addresses: 2,
+            new_ip_addresses_window: IggyDuration::from_str("1 h").unwrap(),
+            webhook_url: "".to_owned(),
+        })
+    }
+
+    fn ip_address(value: u8) -> IpAddr {
+        IpAddr::V4(Ipv4Addr::new(10, 0, 0, value))
+    }
+
+    #[test]
+    fn should_block_login_attempts_after_too_many_failures_until_block_expires() {
+        let guard = login_guard();
+        let ip_address = Some(ip_address(1));
+        assert!(guard.track_failure("token", ip_address, 0).is_empty());
+        assert!(guard.track_failure("token", ip_address, SECOND).is_empty());
+        let events = guard.track_failure("token", ip_address, 2 * SECOND);
+
+        assert_eq!(events.len(), 2);
+        assert!(matches!(
+            guard.ensure_allowed("token", None, 3 * SECOND),
+            Err(IggyError::PersonalAccessTokenLoginThrottled(299))
+        ));
+        assert!(matches!(
+            guard.ensure_allowed("other", ip_address, 3 * SECOND),
+            Err(IggyError::PersonalAccessTokenLoginThrottled(_))
+        ));
+        assert!(guard
+            .ensure_allowed("other", Some(self::ip_address(2)), 3 * SECOND)
+            .is_ok());
+        assert!(guard
+            .ensure_allowed("token", ip_address, 302 * SECOND)
+            .is_ok());
+    }
+
+    #[test]
+    fn should_report_token_used_from_too_many_new_ip_addresses_once_per_window() {
+        let guard = login_guard();
+        assert!(guard
+            .track_ip_address("token", ip_address(1), 1, "pat", 0)
+            .is_none());
+        assert!(guard
+            .track_ip_address("token", ip_address(2), 1, "pat", SECOND)
+            .is_none());
+        assert!(guard
+            .track_ip_address("token", ip_address(1), 1, "pat", 2 * SECOND)
+            .is_none());
+
+        let event = guard.track_ip_address("token", ip_address(3), 1, "pat", 3 * SECOND);
+
+        assert_eq!(
+            event,
+            Some(SecurityEvent::SuspiciousTokenUsage {
+                timestamp: 3 * SECOND,
+                user_id: 1,
+                token_name: "pat".to_owned(),
+                ip_addresses: vec![ip_address(1), ip_address(2), ip_address(3)],
+            })
+        );
+        assert!(guard
+            .track_ip_address("token", ip_address(4), 1, "pat", 4 * SECOND)
+            .is_none());
+    }
+}
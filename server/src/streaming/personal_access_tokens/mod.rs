@@ -16,4 +16,5 @@
  * under the License.
  */
 
+pub mod login_guard;
 pub mod personal_access_token;
@@ -17,11 +17,13 @@
  */
 
 use crate::streaming::utils::hash;
+use iggy::models::personal_access_token::PersonalAccessTokenScope;
 use iggy::models::user_info::UserId;
 use iggy::utils::expiry::IggyExpiry;
 use iggy::utils::text::as_base64;
 use iggy::utils::timestamp::IggyTimestamp;
 use ring::rand::SecureRandom;
+use std::sync::atomic::{AtomicU64, Ordering};
 
 const SIZE: usize = 50;
 
@@ -31,6 +33,11 @@ pub struct PersonalAccessToken {
     pub name: String,
     pub token: String,
     pub expiry_at: Option<IggyTimestamp>,
+    pub scope: PersonalAccessTokenScope,
+    // The moment the current secret was issued, either on creation or on the latest rotation.
+    pub issued_at: IggyTimestamp,
+    // Kept in memory only, 0 means the token has not been used since the server start.
+    last_used_at: AtomicU64,
 }
 
 impl PersonalAccessToken {
@@ -40,11 +47,9 @@ impl PersonalAccessToken {
         name: &str,
         now: IggyTimestamp,
         expiry: IggyExpiry,
+        scope: PersonalAccessTokenScope,
     ) -> (Self, String) {
-        let mut buffer: [u8; SIZE] = [0; SIZE];
-        let system_random = ring::rand::SystemRandom::new();
-        system_random.fill(&mut buffer).unwrap();
-        let token = as_base64(&buffer);
+        let token = Self::generate_token();
         let token_hash = Self::hash_token(&token);
         (
             Self {
@@ -52,6 +57,9 @@ impl PersonalAccessToken {
                 name: name.to_string(),
                 token: token_hash,
                 expiry_at: Self::calculate_expiry_at(now, expiry),
+                scope,
+                issued_at: now,
+                last_used_at: AtomicU64::new(0),
             },
             token,
         )
@@ -62,15 +70,53 @@ impl PersonalAccessToken {
         name: &str,
         token_hash: &str,
         expiry_at: Option<IggyTimestamp>,
+        scope: PersonalAccessTokenScope,
+        issued_at: IggyTimestamp,
     ) -> Self {
         Self {
             user_id,
             name: name.into(),
             token: token_hash.into(),
             expiry_at,
+            scope,
+            issued_at,
+            last_used_at: AtomicU64::new(0),
         }
     }
 
+    // Raw token is generated and returned only once, the previous one is no longer valid.
+    pub fn rotate(&mut self, now: IggyTimestamp, expiry: IggyExpiry) -> String {
+        let token = Self::generate_token();
+        self.reissue(&Self::hash_token(&token), now, expiry);
+        token
+    }
+
+    /// Replaces the token with the one having the given hash, e.g. generated by the leader of the cluster.
+    pub fn reissue(&mut self, token_hash: &str, now: IggyTimestamp, expiry: IggyExpiry) {
+        self.token = token_hash.to_owned();
+        self.expiry_at = Self::calculate_expiry_at(now, expiry);
+        self.issued_at = now;
+    }
+
+    pub fn get_last_used_at(&self) -> Option<IggyTimestamp> {
+        match self.last_used_at.load(Ordering::Relaxed) {
+            0 => None,
+            last_used_at => Some(last_used_at.into()),
+        }
+    }
+
+    pub fn mark_used(&self, now: IggyTimestamp) {
+        self.last_used_at.store(now.as_micros(), Ordering::Relaxed);
+    }
+
+    pub fn is_stale(&self, not_used_since: IggyTimestamp) -> bool {
+        let last_activity = self
+            .last_used_at
+            .load(Ordering::Relaxed)
+            .max(self.issued_at.as_micros());
+        last_activity < not_used_since.as_micros()
+    }
+
     pub fn is_expired(&self, now: IggyTimestamp) -> bool {
         match self.expiry_at {
             None => false,
@@ -78,6 +124,13 @@ impl PersonalAccessToken {
         }
     }
 
+    pub fn generate_token() -> String {
+        let mut buffer: [u8; SIZE] = [0; SIZE];
+        let system_random = ring::rand::SystemRandom::new();
+        system_random.fill(&mut buffer).unwrap();
+        as_base64(&buffer)
+    }
+
     pub fn hash_token(token: &str) -> String {
         hash::calculate_256(token.as_bytes())
     }
@@ -104,8 +157,13 @@ mod tests {
         let user_id = 1;
         let now = IggyTimestamp::now();
         let name = "test_token";
-        let (personal_access_token, raw_token) =
-            PersonalAccessToken::new(user_id, name, now, IggyExpiry::NeverExpire);
+        let (personal_access_token, raw_token) = PersonalAccessToken::new(
+            user_id,
+            name,
+            now,
+            IggyExpiry::NeverExpire,
+            PersonalAccessTokenScope::default(),
+        );
         assert_eq!(personal_access_token.name, name);
         assert!(!personal_access_token.token.is_empty());
         assert!(!raw_token.is_empty());
@@ -123,8 +181,42 @@ mod tests {
         let expiry_ms = 10;
         let expiry = IggyExpiry::ExpireDuration(IggyDuration::from(expiry_ms));
         let name = "test_token";
-        let (personal_access_token, _) = PersonalAccessToken::new(user_id, name, now, expiry);
+        let (personal_access_token, _) = PersonalAccessToken::new(
+            user_id,
+            name,
+            now,
+            expiry,
+            PersonalAccessTokenScope::default(),
+        );
         let later = IggyTimestamp::from(now.as_micros() + expiry_ms + 1);
         assert!(personal_access_token.is_expired(later));
     }
+
+    #[test]
+    fn personal_access_token_should_be_stale_only_when_not_used_or_rotated_since_given_time() {
+        let now = IggyTimestamp::now();
+        let (mut personal_access_token, raw_token) = PersonalAccessToken::new(
+            1,
+            "test_token",
+            now,
+            IggyExpiry::NeverExpire,
+            PersonalAccessTokenScope::default(),
+        );
+        let later = IggyTimestamp::from(now.as_micros() + 10);
+        assert!(personal_access_token.is_stale(later));
+
+        personal_access_token.mark_used(later);
+        assert_eq!(personal_access_token.get_last_used_at(), Some(later));
+        assert!(!personal_access_token.is_stale(later));
+
+        let even_later = IggyTimestamp::from(now.as_micros() + 20);
+        assert!(personal_access_token.is_stale(even_later));
+        let rotated_token = personal_access_token.rotate(even_later, IggyExpiry::NeverExpire);
+        assert_ne!(rotated_token, raw_token);
+        assert_eq!(
+            personal_access_token.token,
+            PersonalAccessToken::hash_token(&rotated_token)
+        );
+        assert!(!personal_access_token.is_stale(even_later));
+    }
 }
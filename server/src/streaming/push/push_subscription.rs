@@ -0,0 +1,238 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use iggy::models::push_subscription::{
+    PushSubscription as PushSubscriptionInfo, PushSubscriptionStatus,
+};
+use iggy::models::user_info::UserId;
+use iggy::utils::timestamp::IggyTimestamp;
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
+use std::sync::Mutex;
+
+#[derive(Debug)]
+pub struct PushSubscription {
+    pub id: u32,
+    pub user_id: UserId,
+    pub stream_id: u32,
+    pub topic_id: u32,
+    pub partition_id: u32,
+    pub endpoint: String,
+    pub batch_size: u32,
+    pub created_at: IggyTimestamp,
+    next_offset: AtomicU64,
+    pushed_messages: AtomicU64,
+    failed_attempts: AtomicU32,
+    last_pushed_at: AtomicU64,
+    last_error: Mutex<Option<String>>,
+    deleted: AtomicBool,
+}
+
+/// The persisted part of the push subscription, restored on the server startup.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct PushSubscriptionState {
+    pub id: u32,
+    pub user_id: UserId,
+    pub stream_id: u32,
+    pub topic_id: u32,
+    pub partition_id: u32,
+    pub endpoint: String,
+    pub batch_size: u32,
+    pub next_offset: u64,
+    pub pushed_messages: u64,
+    pub created_at: u64,
+    pub last_pushed_at: u64,
+}
+
+impl PushSubscription {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: u32,
+        user_id: UserId,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+        endpoint: String,
+        batch_size: u32,
+        start_offset: u64,
+    ) -> Self {
+        Self {
+            id,
+            user_id,
+            stream_id,
+            topic_id,
+            partition_id,
+            endpoint,
+            batch_size,
+            // Truncated to the microseconds kept in the state, so the restored subscription is the same.
+            created_at: IggyTimestamp::now().as_micros().into(),
+            next_offset: AtomicU64::new(start_offset),
+            pushed_messages: AtomicU64::new(0),
+            failed_attempts: AtomicU32::new(0),
+            last_pushed_at: AtomicU64::new(0),
+            last_error: Mutex::new(None),
+            deleted: AtomicBool::new(false),
+        }
+    }
+
+    pub fn from_state(state: PushSubscriptionState) -> Self {
+        let subscription = Self::new(
+            state.id,
+            state.user_id,
+            state.stream_id,
+            state.topic_id,
+            state.partition_id,
+            state.endpoint,
+            state.batch_size,
+            state.next_offset,
+        );
+        subscription
+            .pushed_messages
+            .store(state.pushed_messages, Ordering::Release);
+        subscription
+            .last_pushed_at
+            .store(state.last_pushed_at, Ordering::Release);
+        Self {
+            created_at: state.created_at.into(),
+            ..subscription
+        }
+    }
+
+    pub fn to_state(&self) -> PushSubscriptionState {
+        PushSubscriptionState {
+            id: self.id,
+            user_id: self.user_id,
+            stream_id: self.stream_id,
+            topic_id: self.topic_id,
+            partition_id: self.partition_id,
+            endpoint: self.endpoint.clone(),
+            batch_size: self.batch_size,
+            next_offset: self.next_offset(),
+            pushed_messages: self.pushed_messages(),
+            created_at: self.created_at.as_micros(),
+            last_pushed_at: self.last_pushed_at.load(Ordering::Acquire),
+        }
+    }
+
+    pub fn next_offset(&self) -> u64 {
+        self.next_offset.load(Ordering::Acquire)
+    }
+
+    pub fn pushed_messages(&self) -> u64 {
+        self.pushed_messages.load(Ordering::Acquire)
+    }
+
+    /// Moves the subscription past the batch acknowledged by the endpoint and resets the failures.
+    pub fn record_delivery(&self, last_offset: u64, messages_count: u64) {
+        self.next_offset.store(last_offset + 1, Ordering::Release);
+        self.pushed_messages
+            .fetch_add(messages_count, Ordering::AcqRel);
+        self.last_pushed_at
+            .store(IggyTimestamp::now().as_micros(), Ordering::Release);
+        self.failed_attempts.store(0, Ordering::Release);
+        *self.last_error.lock().unwrap() = None;
+    }
+
+    /// Records the failed delivery of the current batch, returns the number of the consecutive failures.
+    pub fn record_failure(&self, error: String) -> u32 {
+        *self.last_error.lock().unwrap() = Some(error);
+        self.failed_attempts.fetch_add(1, Ordering::AcqRel) + 1
+    }
+
+    pub fn delete(&self) {
+        self.deleted.store(true, Ordering::Release);
+    }
+
+    pub fn is_deleted(&self) -> bool {
+        self.deleted.load(Ordering::Acquire)
+    }
+
+    pub fn to_info(&self) -> PushSubscriptionInfo {
+        let failed_attempts = self.failed_attempts.load(Ordering::Acquire);
+        PushSubscriptionInfo {
+            id: self.id,
+            user_id: self.user_id,
+            status: match failed_attempts {
+                0 => PushSubscriptionStatus::Active,
+                _ => PushSubscriptionStatus::Retrying,
+            },
+            stream_id: self.stream_id,
+            topic_id: self.topic_id,
+            partition_id: self.partition_id,
+            endpoint: self.endpoint.clone(),
+            batch_size: self.batch_size,
+            next_offset: self.next_offset(),
+            pushed_messages: self.pushed_messages(),
+            failed_attempts,
+            last_error: self.last_error.lock().unwrap().clone(),
+            created_at: self.created_at,
+            last_pushed_at: match self.last_pushed_at.load(Ordering::Acquire) {
+                0 => None,
+                last_pushed_at => Some(last_pushed_at.into()),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn push_subscription() -> PushSubscription {
+        PushSubscription::new(
+            1,
+            2,
+            3,
+            4,
+            1,
+            "https://example.com/orders".to_string(),
+            100,
+            10,
+        )
+    }
+
+    #[test]
+    fn delivery_should_move_the_offset_and_reset_failures() {
+        let subscription = push_subscription();
+        assert_eq!(subscription.record_failure("timeout".to_string()), 1);
+        assert_eq!(subscription.record_failure("timeout".to_string()), 2);
+        let info = subscription.to_info();
+        assert_eq!(info.status, PushSubscriptionStatus::Retrying);
+        assert_eq!(info.last_error.as_deref(), Some("timeout"));
+
+        subscription.record_delivery(19, 10);
+        let info = subscription.to_info();
+        assert_eq!(info.status, PushSubscriptionStatus::Active);
+        assert_eq!(info.next_offset, 20);
+        assert_eq!(info.pushed_messages, 10);
+        assert_eq!(info.failed_attempts, 0);
+        assert!(info.last_error.is_none());
+        assert!(info.last_pushed_at.is_some());
+    }
+
+    #[test]
+    fn subscription_should_be_restored_from_state() {
+        let subscription = push_subscription();
+        subscription.record_delivery(29, 20);
+        let state = subscription.to_state();
+
+        let restored = PushSubscription::from_state(state);
+        assert_eq!(restored.to_state(), subscription.to_state());
+        assert_eq!(restored.to_info(), subscription.to_info());
+    }
+}
@@ -0,0 +1,259 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::push::push_subscription::PushSubscription;
+use crate::streaming::push::COMPONENT;
+use crate::streaming::session::Session;
+use crate::streaming::systems::messages::PollingArgs;
+use crate::streaming::systems::system::SharedSystem;
+use iggy::consumer::Consumer;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::{IsolationLevel, PollingStrategy};
+use iggy::models::messages::PolledMessages;
+use iggy::models::push_subscription::PushedMessages;
+use reqwest::header::CONTENT_TYPE;
+use std::net::{Ipv4Addr, SocketAddr};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::sleep;
+use tracing::{debug, error, info, warn};
+
+const PUSH_SUBSCRIPTION_ID_HEADER: &str = "iggy-push-subscription-id";
+
+/// Spawns the background tasks for all the push subscriptions restored on the server startup.
+pub async fn start_all(system: SharedSystem) {
+    let guard = system.read().await;
+    if !guard.config.push_subscriptions.enabled {
+        info!("Push subscriptions are disabled.");
+        return;
+    }
+
+    let subscriptions = guard
+        .push_subscriptions
+        .iter()
+        .map(|subscription| subscription.clone())
+        .collect::<Vec<_>>();
+    drop(guard);
+    info!(
+        "Push subscriptions are enabled, starting {} subscription(s).",
+        subscriptions.len()
+    );
+    for subscription in subscriptions {
+        start(system.clone(), subscription);
+    }
+}
+
+/// Spawns the background task pushing the messages of the given subscription until it's deleted.
+/// The messages are polled on behalf of the user who created the subscription, so the permissions are verified for every batch.
+pub fn start(system: SharedSystem, subscription: Arc<PushSubscription>) {
+    tokio::spawn(async move {
+        info!(
+            "Push subscription with ID: {} has started, source: {}/{}/{}, endpoint: {}, next offset: {}.",
+            subscription.id,
+            subscription.stream_id,
+            subscription.topic_id,
+            subscription.partition_id,
+            subscription.endpoint,
+            subscription.next_offset()
+        );
+        push(&system, &subscription).await;
+        info!(
+            "Push subscription with ID: {} has stopped, pushed messages: {}.",
+            subscription.id,
+            subscription.pushed_messages()
+        );
+    });
+}
+
+async fn push(system: &SharedSystem, subscription: &PushSubscription) {
+    let system_config = system.read().await.config.clone();
+    let config = &system_config.push_subscriptions;
+    let client = match reqwest::Client::builder()
+        .timeout(config.request_timeout.get_duration())
+        .build()
+    {
+        Ok(client) => client,
+        Err(error) => {
+            error!(
+                "{COMPONENT} (error: {error}) - failed to create HTTP client for push subscription with ID: {}.",
+                subscription.id
+            );
+            return;
+        }
+    };
+
+    let idle_interval = config.idle_interval.get_duration();
+    let initial_backoff = config.retry_initial_backoff.get_duration();
+    let max_backoff = config.retry_max_backoff.get_duration();
+    let mut backoff = initial_backoff;
+    while !subscription.is_deleted() {
+        let polled_messages = match poll(system, subscription).await {
+            Ok(Some(polled_messages)) => polled_messages,
+            Ok(None) => {
+                sleep(idle_interval).await;
+                continue;
+            }
+            Err(error) => {
+                subscription.record_failure(error.to_string());
+                warn!(
+                    "{COMPONENT} (error: {error}) - failed to poll messages for push subscription with ID: {}.",
+                    subscription.id
+                );
+                sleep(idle_interval).await;
+                continue;
+            }
+        };
+
+        let Some(last_offset) = polled_messages
+            .messages
+            .last()
+            .map(|message| message.offset)
+        else {
+            sleep(idle_interval).await;
+            continue;
+        };
+
+        let messages_count = polled_messages.messages.len() as u64;
+        let body = match serde_json::to_vec(&PushedMessages {
+            subscription_id: subscription.id,
+            stream_id: subscription.stream_id,
+            topic_id: subscription.topic_id,
+            partition_id: subscription.partition_id,
+            messages: polled_messages.messages,
+        }) {
+            Ok(body) => body,
+            Err(error) => {
+                error!(
+                    "{COMPONENT} (error: {error}) - failed to serialize messages for push subscription with ID: {}.",
+                    subscription.id
+                );
+                return;
+            }
+        };
+
+        // The same batch is sent until it's acknowledged, so the endpoint receives the messages at least once.
+        loop {
+            if subscription.is_deleted() {
+                return;
+            }
+
+            match deliver(&client, subscription, body.clone()).await {
+                Ok(()) => {
+                    subscription.record_delivery(last_offset, messages_count);
+                    backoff = initial_backoff;
+                    debug!(
+                        "Pushed {messages_count} messages up to offset: {last_offset} for push subscription with ID: {}.",
+                        subscription.id
+                    );
+                    if let Err(error) = system.read().await.save_push_subscriptions().await {
+                        error!(
+                            "{COMPONENT} (error: {error}) - failed to save the offset of push subscription with ID: {}.",
+                            subscription.id
+                        );
+                    }
+                    break;
+                }
+                Err(error) => {
+                    let failed_attempts = subscription.record_failure(error.clone());
+                    warn!(
+                        "Failed to push messages up to offset: {last_offset} for push subscription with ID: {}, attempt: {failed_attempts}, retrying in: {} ms. Error: {error}",
+                        subscription.id,
+                        backoff.as_millis()
+                    );
+                    sleep(backoff).await;
+                    backoff = next_backoff(backoff, max_backoff);
+                }
+            }
+        }
+    }
+}
+
+/// Polls the next batch of messages, returns `None` while the server is in maintenance mode.
+async fn poll(
+    system: &SharedSystem,
+    subscription: &PushSubscription,
+) -> Result<Option<PolledMessages>, IggyError> {
+    let system = system.read().await;
+    if system.is_in_maintenance() {
+        return Ok(None);
+    }
+
+    let session = Session::stateless(
+        subscription.user_id,
+        SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 0),
+    );
+    let polled_messages = system
+        .poll_messages(
+            &session,
+            &Consumer::default(),
+            &Identifier::numeric(subscription.stream_id)?,
+            &Identifier::numeric(subscription.topic_id)?,
+            Some(subscription.partition_id),
+            PollingArgs::new(
+                PollingStrategy::offset(subscription.next_offset()),
+                subscription.batch_size,
+                false,
+                IsolationLevel::ReadUncommitted,
+            ),
+        )
+        .await?;
+    Ok(Some(polled_messages))
+}
+
+async fn deliver(
+    client: &reqwest::Client,
+    subscription: &PushSubscription,
+    body: Vec<u8>,
+) -> Result<(), String> {
+    let response = client
+        .post(&subscription.endpoint)
+        .header(CONTENT_TYPE, "application/json")
+        .header(PUSH_SUBSCRIPTION_ID_HEADER, subscription.id.to_string())
+        .body(body)
+        .send()
+        .await
+        .map_err(|error| error.to_string())?;
+    let status = response.status();
+    if !status.is_success() {
+        return Err(format!("endpoint responded with status: {status}"));
+    }
+
+    Ok(())
+}
+
+fn next_backoff(backoff: Duration, max_backoff: Duration) -> Duration {
+    backoff.saturating_mul(2).min(max_backoff)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_should_be_doubled_up_to_the_limit() {
+        let max_backoff = Duration::from_secs(5);
+        let mut backoff = Duration::from_secs(1);
+        backoff = next_backoff(backoff, max_backoff);
+        assert_eq!(backoff, Duration::from_secs(2));
+        backoff = next_backoff(backoff, max_backoff);
+        assert_eq!(backoff, Duration::from_secs(4));
+        backoff = next_backoff(backoff, max_backoff);
+        assert_eq!(backoff, max_backoff);
+    }
+}
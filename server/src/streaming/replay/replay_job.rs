@@ -0,0 +1,217 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use iggy::messages::replay_messages::{HeadersTransform, ReplayRange, ReplayRangeKind};
+use iggy::messages::send_messages::Partitioning;
+use iggy::models::replay_job::{ReplayJob as ReplayJobInfo, ReplayJobStatus};
+use iggy::models::user_info::UserId;
+use iggy::utils::timestamp::IggyTimestamp;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicU64, AtomicU8, Ordering};
+
+#[derive(Debug)]
+pub struct ReplayJob {
+    pub id: u32,
+    pub user_id: UserId,
+    pub ip_address: SocketAddr,
+    pub source_stream_id: u32,
+    pub source_topic_id: u32,
+    pub source_partition_id: u32,
+    pub destination_stream_id: u32,
+    pub destination_topic_id: u32,
+    pub range: ReplayRange,
+    pub partitioning: Partitioning,
+    pub headers: HeadersTransform,
+    pub messages_per_second: u32,
+    pub batch_size: u32,
+    pub created_at: IggyTimestamp,
+    status: AtomicU8,
+    current_offset: AtomicU64,
+    replayed_messages: AtomicU64,
+    finished_at: AtomicU64,
+}
+
+impl ReplayJob {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: u32,
+        user_id: UserId,
+        ip_address: SocketAddr,
+        source: (u32, u32, u32),
+        destination: (u32, u32),
+        range: ReplayRange,
+        partitioning: Partitioning,
+        headers: HeadersTransform,
+        messages_per_second: u32,
+        batch_size: u32,
+    ) -> Self {
+        Self {
+            id,
+            user_id,
+            ip_address,
+            source_stream_id: source.0,
+            source_topic_id: source.1,
+            source_partition_id: source.2,
+            destination_stream_id: destination.0,
+            destination_topic_id: destination.1,
+            range,
+            partitioning,
+            headers,
+            messages_per_second,
+            batch_size,
+            created_at: IggyTimestamp::now(),
+            status: AtomicU8::new(ReplayJobStatus::Running.as_code()),
+            current_offset: AtomicU64::new(0),
+            replayed_messages: AtomicU64::new(0),
+            finished_at: AtomicU64::new(0),
+        }
+    }
+
+    pub fn status(&self) -> ReplayJobStatus {
+        ReplayJobStatus::from_code(self.status.load(Ordering::Acquire))
+            .unwrap_or(ReplayJobStatus::Failed)
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.status() == ReplayJobStatus::Running
+    }
+
+    /// Returns `true` if the message is past the end of the replayed range.
+    pub fn is_past_range(&self, offset: u64, timestamp: u64) -> bool {
+        match self.range.kind {
+            ReplayRangeKind::Offset => offset > self.range.end,
+            ReplayRangeKind::Timestamp => timestamp > self.range.end,
+        }
+    }
+
+    pub fn record_progress(&self, offset: u64, messages_count: u64) {
+        self.current_offset.store(offset, Ordering::Release);
+        self.replayed_messages
+            .fetch_add(messages_count, Ordering::AcqRel);
+    }
+
+    pub fn replayed_messages(&self) -> u64 {
+        self.replayed_messages.load(Ordering::Acquire)
+    }
+
+    /// Moves the running job to the final status, returns `false` if the job was already finished.
+    pub fn finish(&self, status: ReplayJobStatus) -> bool {
+        if self
+            .status
+            .compare_exchange(
+                ReplayJobStatus::Running.as_code(),
+                status.as_code(),
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            )
+            .is_err()
+        {
+            return false;
+        }
+
+        self.finished_at
+            .store(IggyTimestamp::now().as_micros(), Ordering::Release);
+        true
+    }
+
+    pub fn finished_at(&self) -> Option<IggyTimestamp> {
+        match self.finished_at.load(Ordering::Acquire) {
+            0 => None,
+            finished_at => Some(finished_at.into()),
+        }
+    }
+
+    pub fn to_info(&self) -> ReplayJobInfo {
+        ReplayJobInfo {
+            id: self.id,
+            user_id: self.user_id,
+            status: self.status(),
+            source_stream_id: self.source_stream_id,
+            source_topic_id: self.source_topic_id,
+            source_partition_id: self.source_partition_id,
+            destination_stream_id: self.destination_stream_id,
+            destination_topic_id: self.destination_topic_id,
+            range: self.range,
+            current_offset: self.current_offset.load(Ordering::Acquire),
+            replayed_messages: self.replayed_messages(),
+            messages_per_second: self.messages_per_second,
+            created_at: self.created_at,
+            finished_at: self.finished_at(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::{IpAddr, Ipv4Addr};
+
+    fn replay_job(range: ReplayRange) -> ReplayJob {
+        ReplayJob::new(
+            1,
+            1,
+            SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0),
+            (1, 1, 1),
+            (2, 1),
+            range,
+            Partitioning::default(),
+            HeadersTransform::default(),
+            0,
+            100,
+        )
+    }
+
+    #[test]
+    fn job_should_be_finished_only_once() {
+        let job = replay_job(ReplayRange::offset(0, 10));
+        assert!(job.is_running());
+        assert!(job.finished_at().is_none());
+
+        assert!(job.finish(ReplayJobStatus::Cancelled));
+        assert!(!job.finish(ReplayJobStatus::Completed));
+        assert_eq!(job.status(), ReplayJobStatus::Cancelled);
+        assert!(job.finished_at().is_some());
+    }
+
+    #[test]
+    fn progress_should_be_recorded() {
+        let job = replay_job(ReplayRange::offset(0, 10));
+        job.record_progress(4, 5);
+        job.record_progress(9, 5);
+
+        let info = job.to_info();
+        assert_eq!(info.current_offset, 9);
+        assert_eq!(info.replayed_messages, 10);
+        assert_eq!(info.status, ReplayJobStatus::Running);
+    }
+
+    #[test]
+    fn messages_past_the_range_should_be_detected() {
+        let job = replay_job(ReplayRange::offset(0, 10));
+        assert!(!job.is_past_range(10, u64::MAX));
+        assert!(job.is_past_range(11, 0));
+
+        let job = replay_job(ReplayRange {
+            kind: ReplayRangeKind::Timestamp,
+            start: 100,
+            end: 200,
+        });
+        assert!(!job.is_past_range(u64::MAX, 200));
+        assert!(job.is_past_range(0, 201));
+    }
+}
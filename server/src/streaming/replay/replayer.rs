@@ -0,0 +1,172 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::replay::replay_job::ReplayJob;
+use crate::streaming::replay::COMPONENT;
+use crate::streaming::session::Session;
+use crate::streaming::systems::messages::PollingArgs;
+use crate::streaming::systems::system::SharedSystem;
+use error_set::ErrContext;
+use iggy::consumer::Consumer;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::{IsolationLevel, PollingStrategy};
+use iggy::messages::replay_messages::ReplayRangeKind;
+use iggy::messages::send_messages::Message;
+use iggy::models::replay_job::ReplayJobStatus;
+use iggy::models::transaction::Transaction;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::{sleep, Instant};
+use tracing::{error, info};
+
+/// Spawns the background task replaying the messages of the given job.
+/// The job is executed on behalf of the user who started it, so the permissions are verified for every batch.
+pub fn start(system: SharedSystem, job: Arc<ReplayJob>) {
+    tokio::spawn(async move {
+        info!(
+            "Replay job with ID: {} has started, source: {}/{}/{}, destination: {}/{}, range: {}.",
+            job.id,
+            job.source_stream_id,
+            job.source_topic_id,
+            job.source_partition_id,
+            job.destination_stream_id,
+            job.destination_topic_id,
+            job.range
+        );
+        match replay(&system, &job).await {
+            Ok(()) => {
+                if job.finish(ReplayJobStatus::Completed) {
+                    info!(
+                        "Replay job with ID: {} has completed, replayed messages: {}.",
+                        job.id,
+                        job.replayed_messages()
+                    );
+                } else {
+                    info!(
+                        "Replay job with ID: {} has been cancelled, replayed messages: {}.",
+                        job.id,
+                        job.replayed_messages()
+                    );
+                }
+            }
+            Err(error) => {
+                job.finish(ReplayJobStatus::Failed);
+                error!(
+                    "{COMPONENT} (error: {error}) - replay job with ID: {} has failed, replayed messages: {}.",
+                    job.id,
+                    job.replayed_messages()
+                );
+            }
+        }
+    });
+}
+
+async fn replay(system: &SharedSystem, job: &ReplayJob) -> Result<(), IggyError> {
+    let session = Session::stateless(job.user_id, job.ip_address);
+    let consumer = Consumer::default();
+    let source_stream_id = Identifier::numeric(job.source_stream_id)?;
+    let source_topic_id = Identifier::numeric(job.source_topic_id)?;
+    let destination_stream_id = Identifier::numeric(job.destination_stream_id)?;
+    let destination_topic_id = Identifier::numeric(job.destination_topic_id)?;
+    let batch_size = match job.messages_per_second {
+        0 => job.batch_size,
+        messages_per_second => job.batch_size.min(messages_per_second),
+    };
+    let mut strategy = match job.range.kind {
+        ReplayRangeKind::Offset => PollingStrategy::offset(job.range.start),
+        ReplayRangeKind::Timestamp => PollingStrategy::timestamp(job.range.start.into()),
+    };
+    let started_at = Instant::now();
+    while job.is_running() {
+        let guard = system.read().await;
+        let polled_messages = guard
+            .poll_messages(
+                &session,
+                &consumer,
+                &source_stream_id,
+                &source_topic_id,
+                Some(job.source_partition_id),
+                PollingArgs::new(strategy, batch_size, false, IsolationLevel::ReadUncommitted),
+            )
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to poll messages for replay job with ID: {}, strategy: {strategy:?}",
+                    job.id
+                )
+            })?;
+
+        // Reaching the end of the partition completes the replay, even if the range goes beyond it.
+        let polled_count = polled_messages.messages.len();
+        let messages = polled_messages
+            .messages
+            .into_iter()
+            .filter(|message| !job.is_past_range(message.offset, message.timestamp))
+            .collect::<Vec<_>>();
+        let Some(last_offset) = messages.last().map(|message| message.offset) else {
+            return Ok(());
+        };
+
+        let is_range_completed = messages.len() < polled_count;
+        let messages_count = messages.len() as u64;
+        // The replayed messages are not a part of the transactions they were sent within anymore.
+        let messages = messages
+            .into_iter()
+            .map(|message| {
+                let mut headers = job.headers.apply(message.headers);
+                Transaction::remove_headers(&mut headers)?;
+                Ok(Message::new(None, message.payload, headers))
+            })
+            .collect::<Result<Vec<_>, IggyError>>()?;
+        guard
+            .append_messages(
+                &session,
+                destination_stream_id.clone(),
+                destination_topic_id.clone(),
+                job.partitioning.clone(),
+                messages,
+                None,
+            )
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to append messages for replay job with ID: {}, last offset: {last_offset}",
+                    job.id
+                )
+            })?;
+        drop(guard);
+        job.record_progress(last_offset, messages_count);
+        if is_range_completed {
+            return Ok(());
+        }
+
+        strategy = PollingStrategy::offset(last_offset + 1);
+        if job.messages_per_second > 0 {
+            let expected_elapsed = Duration::from_secs_f64(
+                job.replayed_messages() as f64 / job.messages_per_second as f64,
+            );
+            let elapsed = started_at.elapsed();
+            if expected_elapsed > elapsed {
+                sleep(expected_elapsed - elapsed).await;
+            }
+        }
+    }
+
+    Ok(())
+}
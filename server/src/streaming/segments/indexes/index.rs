@@ -98,7 +98,8 @@ impl IndexRange {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::configs::system::{SegmentConfig, SystemConfig};
+    use crate::configs::system::SystemConfig;
+    use iggy::compression::compression_algorithm::CompressionAlgorithm;
     use iggy::utils::expiry::IggyExpiry;
     use std::sync::atomic::AtomicU64;
     use std::sync::Arc;
@@ -108,13 +109,7 @@ mod tests {
         let topic_id = 2;
         let partition_id = 3;
         let start_offset = 0;
-        let config = Arc::new(SystemConfig {
-            segment: SegmentConfig {
-                cache_indexes: true,
-                ..Default::default()
-            },
-            ..Default::default()
-        });
+        let config = Arc::new(SystemConfig::default());
 
         Segment::create(
             stream_id,
@@ -123,6 +118,7 @@ mod tests {
             start_offset,
             config,
             IggyExpiry::NeverExpire,
+            CompressionAlgorithm::None,
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
@@ -132,8 +128,8 @@ mod tests {
         )
     }
 
-    fn create_test_indices(segment: &mut Segment) {
-        let indexes = vec![
+    fn create_test_indices() -> Vec<Index> {
+        vec![
             Index {
                 offset: 5,
                 position: 0,
@@ -159,16 +155,15 @@ mod tests {
                 position: 400,
                 timestamp: 5000,
             },
-        ];
-        segment.indexes.as_mut().unwrap().extend(indexes);
+        ]
     }
 
     #[tokio::test]
     async fn should_find_both_indices() {
-        let mut segment = create_segment().await;
-        create_test_indices(&mut segment);
+        let segment = create_segment().await;
+        let indexes = create_test_indices();
         let result = segment
-            .load_highest_lower_bound_index(segment.indexes.as_ref().unwrap(), 15, 45)
+            .load_highest_lower_bound_index(&indexes, 15, 45)
             .unwrap();
 
         assert_eq!(result.start.offset, 20);
@@ -177,17 +172,17 @@ mod tests {
 
     #[tokio::test]
     async fn start_and_end_index_should_be_equal() {
-        let mut segment = create_segment().await;
-        create_test_indices(&mut segment);
+        let segment = create_segment().await;
+        let indexes = create_test_indices();
         let result_end_range = segment
-            .load_highest_lower_bound_index(segment.indexes.as_ref().unwrap(), 65, 100)
+            .load_highest_lower_bound_index(&indexes, 65, 100)
             .unwrap();
 
         assert_eq!(result_end_range.start.offset, 65);
         assert_eq!(result_end_range.end.offset, 65);
 
         let result_start_range = segment
-            .load_highest_lower_bound_index(segment.indexes.as_ref().unwrap(), 0, 5)
+            .load_highest_lower_bound_index(&indexes, 0, 5)
             .unwrap();
         assert_eq!(result_start_range.start.offset, 5);
         assert_eq!(result_start_range.end.offset, 5);
@@ -195,10 +190,10 @@ mod tests {
 
     #[tokio::test]
     async fn should_clamp_last_index_when_out_of_range() {
-        let mut segment = create_segment().await;
-        create_test_indices(&mut segment);
+        let segment = create_segment().await;
+        let indexes = create_test_indices();
         let result = segment
-            .load_highest_lower_bound_index(segment.indexes.as_ref().unwrap(), 5, 100)
+            .load_highest_lower_bound_index(&indexes, 5, 100)
             .unwrap();
 
         assert_eq!(result.start.offset, 5);
@@ -207,11 +202,10 @@ mod tests {
 
     #[tokio::test]
     async fn should_return_err_when_both_indices_out_of_range() {
-        let mut segment = create_segment().await;
-        create_test_indices(&mut segment);
+        let segment = create_segment().await;
+        let indexes = create_test_indices();
 
-        let result =
-            segment.load_highest_lower_bound_index(segment.indexes.as_ref().unwrap(), 100, 200);
+        let result = segment.load_highest_lower_bound_index(&indexes, 100, 200);
         assert!(result.is_err());
     }
 }
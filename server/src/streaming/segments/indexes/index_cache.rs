@@ -0,0 +1,264 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use super::{Index, INDEX_SIZE};
+use crate::configs::system::SegmentConfig;
+use crate::streaming::diagnostics::storage_metrics::StorageMetrics;
+use iggy::utils::byte_size::IggyByteSize;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex, OnceLock};
+use tracing::info;
+
+static INSTANCE: OnceLock<Arc<IndexCache>> = OnceLock::new();
+
+/// Identifies a single cached block, which holds all the indexes of one segment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IndexBlockKey {
+    pub stream_id: u32,
+    pub topic_id: u32,
+    pub partition_id: u32,
+    pub start_offset: u64,
+}
+
+/// Least recently used cache of the segment indexes, bounded by a byte budget shared by all the segments.
+/// Only the hot segments keep their indexes in memory, the other ones are read from disk on a miss
+/// and cached again, evicting the least recently used blocks until the budget is satisfied.
+#[derive(Debug)]
+pub struct IndexCache {
+    limit_bytes: u64,
+    state: Mutex<IndexCacheState>,
+}
+
+#[derive(Debug, Default)]
+struct IndexCacheState {
+    blocks: HashMap<IndexBlockKey, CachedIndexBlock>,
+    lru: BTreeMap<u64, IndexBlockKey>,
+    tick: u64,
+    size_bytes: u64,
+}
+
+#[derive(Debug)]
+struct CachedIndexBlock {
+    indexes: Arc<Vec<Index>>,
+    last_access: u64,
+}
+
+impl IndexCache {
+    /// Returns the cache shared by all the segments, or `None` if the index cache is disabled (budget set to 0).
+    pub fn initialize(config: &SegmentConfig) -> Option<Arc<IndexCache>> {
+        if config.index_cache_size.as_bytes_u64() == 0 {
+            return None;
+        }
+
+        Some(
+            INSTANCE
+                .get_or_init(|| {
+                    info!(
+                        "Index cache enabled with the budget of {}",
+                        config.index_cache_size.as_human_string()
+                    );
+                    Arc::new(IndexCache::new(config.index_cache_size))
+                })
+                .clone(),
+        )
+    }
+
+    pub fn new(limit: IggyByteSize) -> Self {
+        Self {
+            limit_bytes: limit.as_bytes_u64(),
+            state: Mutex::new(IndexCacheState::default()),
+        }
+    }
+
+    /// Returns the cached indexes of the segment and marks them as the most recently used.
+    pub fn get(&self, key: &IndexBlockKey) -> Option<Arc<Vec<Index>>> {
+        let indexes = {
+            let mut state = self.state.lock().unwrap();
+            state.touch(key)
+        };
+        StorageMetrics::get_instance().record_index_cache_access(
+            key.stream_id,
+            key.topic_id,
+            indexes.is_some(),
+        );
+        indexes
+    }
+
+    /// Caches the indexes of the segment, evicting the least recently used blocks if needed.
+    /// A block larger than the whole budget is returned without being cached.
+    pub fn insert(&self, key: IndexBlockKey, indexes: Vec<Index>) -> Arc<Vec<Index>> {
+        let indexes = Arc::new(indexes);
+        let block_size = block_size(&indexes);
+        if block_size > self.limit_bytes {
+            return indexes;
+        }
+
+        let mut state = self.state.lock().unwrap();
+        state.remove(&key);
+        state.tick += 1;
+        let tick = state.tick;
+        state.lru.insert(tick, key);
+        state.blocks.insert(
+            key,
+            CachedIndexBlock {
+                indexes: indexes.clone(),
+                last_access: tick,
+            },
+        );
+        state.size_bytes += block_size;
+        state.evict(self.limit_bytes);
+        StorageMetrics::get_instance().set_index_cache_size(state.size_bytes);
+        indexes
+    }
+
+    /// Appends the index of a newly persisted batch, if the indexes of the segment are cached.
+    pub fn append(&self, key: &IndexBlockKey, index: Index) {
+        let mut state = self.state.lock().unwrap();
+        let Some(block) = state.blocks.get_mut(key) else {
+            return;
+        };
+
+        Arc::make_mut(&mut block.indexes).push(index);
+        state.size_bytes += INDEX_SIZE;
+        state.touch(key);
+        state.evict(self.limit_bytes);
+        StorageMetrics::get_instance().set_index_cache_size(state.size_bytes);
+    }
+
+    /// Removes the indexes of the segment, e.g. when the segment is deleted.
+    pub fn remove(&self, key: &IndexBlockKey) {
+        let mut state = self.state.lock().unwrap();
+        state.remove(key);
+        StorageMetrics::get_instance().set_index_cache_size(state.size_bytes);
+    }
+
+    pub fn size_bytes(&self) -> IggyByteSize {
+        IggyByteSize::from(self.state.lock().unwrap().size_bytes)
+    }
+}
+
+impl IndexCacheState {
+    fn touch(&mut self, key: &IndexBlockKey) -> Option<Arc<Vec<Index>>> {
+        self.tick += 1;
+        let tick = self.tick;
+        let block = self.blocks.get_mut(key)?;
+        self.lru.remove(&block.last_access);
+        self.lru.insert(tick, *key);
+        block.last_access = tick;
+        Some(block.indexes.clone())
+    }
+
+    fn remove(&mut self, key: &IndexBlockKey) {
+        if let Some(block) = self.blocks.remove(key) {
+            self.lru.remove(&block.last_access);
+            self.size_bytes -= block_size(&block.indexes);
+        }
+    }
+
+    fn evict(&mut self, limit_bytes: u64) {
+        while self.size_bytes > limit_bytes {
+            let Some((_, key)) = self.lru.pop_first() else {
+                break;
+            };
+            if let Some(block) = self.blocks.remove(&key) {
+                self.size_bytes -= block_size(&block.indexes);
+            }
+        }
+    }
+}
+
+fn block_size(indexes: &[Index]) -> u64 {
+    indexes.len() as u64 * INDEX_SIZE
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(start_offset: u64) -> IndexBlockKey {
+        IndexBlockKey {
+            stream_id: 1,
+            topic_id: 1,
+            partition_id: 1,
+            start_offset,
+        }
+    }
+
+    fn indexes(count: u32) -> Vec<Index> {
+        (0..count)
+            .map(|offset| Index {
+                offset,
+                position: offset * 100,
+                timestamp: offset as u64,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn should_evict_least_recently_used_blocks_when_budget_is_exceeded() {
+        let cache = IndexCache::new(IggyByteSize::from(INDEX_SIZE * 20));
+        cache.insert(key(0), indexes(10));
+        cache.insert(key(100), indexes(10));
+        assert!(cache.get(&key(0)).is_some());
+
+        cache.insert(key(200), indexes(10));
+
+        assert!(cache.get(&key(0)).is_some());
+        assert!(cache.get(&key(100)).is_none());
+        assert!(cache.get(&key(200)).is_some());
+        assert_eq!(cache.size_bytes(), IggyByteSize::from(INDEX_SIZE * 20));
+    }
+
+    #[test]
+    fn should_not_cache_block_larger_than_budget() {
+        let cache = IndexCache::new(IggyByteSize::from(INDEX_SIZE * 5));
+        let block = cache.insert(key(0), indexes(10));
+
+        assert_eq!(block.len(), 10);
+        assert!(cache.get(&key(0)).is_none());
+        assert_eq!(cache.size_bytes(), IggyByteSize::from(0));
+    }
+
+    #[test]
+    fn should_append_index_only_to_cached_block() {
+        let cache = IndexCache::new(IggyByteSize::from(INDEX_SIZE * 20));
+        cache.insert(key(0), indexes(2));
+        let index = Index {
+            offset: 2,
+            position: 200,
+            timestamp: 2,
+        };
+
+        cache.append(&key(0), index);
+        cache.append(&key(100), index);
+
+        assert_eq!(cache.get(&key(0)).unwrap().last(), Some(&index));
+        assert!(cache.get(&key(100)).is_none());
+        assert_eq!(cache.size_bytes(), IggyByteSize::from(INDEX_SIZE * 3));
+    }
+
+    #[test]
+    fn should_release_budget_when_block_is_removed() {
+        let cache = IndexCache::new(IggyByteSize::from(INDEX_SIZE * 20));
+        cache.insert(key(0), indexes(10));
+        cache.remove(&key(0));
+
+        assert!(cache.get(&key(0)).is_none());
+        assert_eq!(cache.size_bytes(), IggyByteSize::from(0));
+    }
+}
@@ -35,7 +35,7 @@ use tokio::task::spawn_blocking;
 use tracing::{error, trace};
 
 /// A dedicated struct for reading from the index file.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct SegmentIndexReader {
     file_path: String,
     file: Arc<File>,
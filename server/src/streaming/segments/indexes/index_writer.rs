@@ -17,11 +17,17 @@
  */
 
 use super::{Index, INDEX_SIZE};
+use crate::streaming::diagnostics::storage_metrics::StorageMetrics;
+#[cfg(all(target_os = "linux", feature = "io-uring"))]
+use crate::streaming::persistence::io_uring::{IoUringFile, IoUringRing};
 use error_set::ErrContext;
 use iggy::error::IggyError;
-use std::sync::{
-    atomic::{AtomicU64, Ordering},
-    Arc,
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::Instant,
 };
 use tokio::{
     fs::{File, OpenOptions},
@@ -34,6 +40,9 @@ use tracing::trace;
 pub struct SegmentIndexWriter {
     file_path: String,
     file: File,
+    /// When set, the indexes (and their fsyncs) are submitted to the shared io_uring.
+    #[cfg(all(target_os = "linux", feature = "io-uring"))]
+    io_uring: Option<IoUringFile>,
     index_size_bytes: Arc<AtomicU64>,
     fsync: bool,
 }
@@ -74,11 +83,29 @@ impl SegmentIndexWriter {
         Ok(Self {
             file_path: file_path.to_string(),
             file,
+            #[cfg(all(target_os = "linux", feature = "io-uring"))]
+            io_uring: None,
             index_size_bytes,
             fsync,
         })
     }
 
+    /// Submits the index writes to the given ring.
+    #[cfg(all(target_os = "linux", feature = "io-uring"))]
+    pub async fn use_io_uring(&mut self, ring: Arc<IoUringRing>) -> Result<(), IggyError> {
+        let file = IoUringFile::new(ring, &self.file)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "Failed to register index file: {} in io_uring. {error}",
+                    self.file_path
+                )
+            })
+            .map_err(|_| IggyError::CannotReadFile)?;
+        self.io_uring = Some(file);
+        Ok(())
+    }
+
     /// Append the given index record to the index file.
     pub async fn save_index(&mut self, index: Index) -> Result<(), IggyError> {
         let mut buf = [0u8; INDEX_SIZE as usize];
@@ -86,6 +113,22 @@ impl SegmentIndexWriter {
         buf[4..8].copy_from_slice(&index.position.to_le_bytes());
         buf[8..16].copy_from_slice(&index.timestamp.to_le_bytes());
 
+        #[cfg(all(target_os = "linux", feature = "io-uring"))]
+        if let Some(file) = self.io_uring.as_ref() {
+            file.append(vec![bytes::Bytes::copy_from_slice(&buf)], self.fsync)
+                .await
+                .with_error_context(|error| {
+                    format!(
+                        "Failed to write index to file: {} using io_uring. {error}",
+                        self.file_path
+                    )
+                })
+                .map_err(|_| IggyError::CannotSaveIndexToSegment)?;
+            self.index_size_bytes
+                .fetch_add(INDEX_SIZE, Ordering::Release);
+            return Ok(());
+        }
+
         {
             self.file
                 .write_all(&buf)
@@ -95,6 +138,7 @@ impl SegmentIndexWriter {
                 })
                 .map_err(|_| IggyError::CannotSaveIndexToSegment)?;
         }
+        StorageMetrics::get_instance().record_write(INDEX_SIZE);
         if self.fsync {
             let _ = self.fsync().await;
         }
@@ -104,6 +148,7 @@ impl SegmentIndexWriter {
     }
 
     pub async fn fsync(&self) -> Result<(), IggyError> {
+        let started_at = Instant::now();
         self.file
             .sync_all()
             .await
@@ -111,6 +156,7 @@ impl SegmentIndexWriter {
                 format!("Failed to fsync index file: {}. {error}", self.file_path)
             })
             .map_err(|_| IggyError::CannotWriteToFile)?;
+        StorageMetrics::get_instance().record_fsync(started_at.elapsed());
         Ok(())
     }
 }
@@ -17,6 +17,7 @@
  */
 
 mod index;
+mod index_cache;
 mod index_reader;
 mod index_writer;
 
@@ -25,5 +26,7 @@ pub const INDEX_SIZE: u64 = 16;
 
 pub use index::Index;
 pub use index::IndexRange;
+pub use index_cache::IndexBlockKey;
+pub use index_cache::IndexCache;
 pub use index_reader::SegmentIndexReader;
 pub use index_writer::SegmentIndexWriter;
@@ -19,13 +19,18 @@
 use crate::streaming::{
     batching::{
         iterator::IntoMessagesIterator,
-        message_batch::{RetainedMessageBatch, RETAINED_BATCH_HEADER_LEN},
+        message_batch::{
+            parse_batch_length, RetainedMessageBatch, BATCH_CHECKSUM_LEN, RETAINED_BATCH_HEADER_LEN,
+        },
     },
-    segments::indexes::IndexRange,
+    segments::{indexes::IndexRange, message_slices, message_slices::MessageSlice},
 };
 use bytes::BytesMut;
 use error_set::ErrContext;
-use iggy::{error::IggyError, utils::byte_size::IggyByteSize};
+use iggy::{
+    error::IggyError,
+    utils::{byte_size::IggyByteSize, checksum},
+};
 use std::{
     fs::{File, OpenOptions},
     os::unix::prelude::FileExt,
@@ -41,16 +46,22 @@ use tokio::task::spawn_blocking;
 use tracing::{error, trace, warn};
 
 /// A dedicated struct for reading from the log file.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct SegmentLogReader {
     file_path: String,
     file: Arc<File>,
     log_size_bytes: Arc<AtomicU64>,
+    verify_checksums: bool,
 }
 
 impl SegmentLogReader {
     /// Opens the log file in read mode.
-    pub async fn new(file_path: &str, log_size_bytes: Arc<AtomicU64>) -> Result<Self, IggyError> {
+    /// The `verify_checksums` enables the verification of the batch checksums whenever the batches are read.
+    pub async fn new(
+        file_path: &str,
+        log_size_bytes: Arc<AtomicU64>,
+        verify_checksums: bool,
+    ) -> Result<Self, IggyError> {
         let file = OpenOptions::new()
             .read(true)
             .open(file_path)
@@ -87,6 +98,7 @@ impl SegmentLogReader {
             file_path: file_path.to_string(),
             file: Arc::new(file),
             log_size_bytes,
+            verify_checksums,
         })
     }
 
@@ -128,6 +140,90 @@ impl SegmentLogReader {
         Ok(batches)
     }
 
+    /// Loads the headers of the messages within the offset range, leaving their payloads in the file,
+    /// or returns `None` if the messages have to be loaded as batches, e.g. due to the compression.
+    pub async fn load_message_slices_impl(
+        &self,
+        index_range: &IndexRange,
+        start_offset: u64,
+        end_offset: u64,
+    ) -> Result<Option<Vec<MessageSlice>>, IggyError> {
+        let file_size = self.file_size();
+        if file_size == 0 {
+            trace!("Log file {} is empty.", self.file_path);
+            return Ok(Some(Vec::new()));
+        }
+
+        let file = self.file.clone();
+        let position = index_range.start.position as u64;
+        let verify_checksums = self.verify_checksums;
+        spawn_blocking(move || {
+            message_slices::load_message_slices(
+                &file,
+                file_size,
+                position,
+                start_offset,
+                end_offset,
+                verify_checksums,
+            )
+        })
+        .await
+        .with_error_context(|error| {
+            format!(
+                "Failed to load message slices from log file: {}. {error}",
+                self.file_path
+            )
+        })
+        .map_err(|_| IggyError::CannotReadMessage)?
+    }
+
+    /// Loads the raw bytes of the log file starting at the given position, exactly as they're stored on disk.
+    pub async fn load_raw_bytes_impl(
+        &self,
+        position: u64,
+        length: u64,
+    ) -> Result<Vec<u8>, IggyError> {
+        self.read_at(position, length)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "Failed to read {length} bytes at position: {position} from log file: {}. {error}",
+                    self.file_path
+                )
+            })
+            .map_err(|_| IggyError::CannotReadFile)
+    }
+
+    /// Returns the positions of the complete batches in the log file, read from their headers only,
+    /// so that the batches can be located while the index of the segment is missing.
+    pub async fn load_batch_positions_impl(&self) -> Result<Vec<u64>, IggyError> {
+        let file_size = self.file_size();
+        let mut positions = Vec::new();
+        let mut position = 0_u64;
+        while position + RETAINED_BATCH_HEADER_LEN <= file_size {
+            let header_buf = self
+                .read_at(position, RETAINED_BATCH_HEADER_LEN)
+                .await
+                .with_error_context(|error| {
+                    format!(
+                        "Failed to read batch header at position {position} in file {}: {error}",
+                        self.file_path
+                    )
+                })
+                .map_err(|_| IggyError::CannotReadBatchLength)?;
+            let batch_length = u32::from_le_bytes(header_buf[8..12].try_into().unwrap());
+            let stored = parse_batch_length(batch_length)?;
+            let next_position = position + RETAINED_BATCH_HEADER_LEN + stored.length as u64;
+            if next_position > file_size {
+                break;
+            }
+
+            positions.push(position);
+            position = next_position;
+        }
+        Ok(positions)
+    }
+
     /// Loads and returns all message IDs from the log file.
     pub async fn load_message_ids_impl(&self) -> Result<Vec<u128>, IggyError> {
         let mut file_size = self.file_size();
@@ -159,45 +255,6 @@ impl SegmentLogReader {
         Ok(message_ids)
     }
 
-    /// Loads message batches given an index range and calls the provided callback for each batch.
-    pub async fn load_batches_by_range_with_callback<F>(
-        &self,
-        index_range: &IndexRange,
-        mut on_batch: F,
-    ) -> Result<(), IggyError>
-    where
-        F: FnMut(RetainedMessageBatch) -> Result<(), IggyError>,
-    {
-        let mut file_size = self.file_size();
-        if file_size == 0 {
-            trace!("Log file {} is empty.", self.file_path);
-            return Ok(());
-        }
-
-        let mut offset = index_range.start.position as u64;
-        let mut last_batch_to_read = false;
-
-        while !last_batch_to_read && offset < file_size {
-            file_size = self.file_size();
-            match self.read_next_batch(offset, file_size).await? {
-                Some((batch, bytes_read)) => {
-                    offset += bytes_read;
-                    let last_offset_in_batch = batch.base_offset + batch.last_offset_delta as u64;
-                    if offset >= file_size || last_offset_in_batch >= index_range.end.offset as u64
-                    {
-                        last_batch_to_read = true;
-                    }
-                    on_batch(batch)?;
-                }
-                None => {
-                    break;
-                }
-            }
-        }
-
-        Ok(())
-    }
-
     /// Loads message batches up to a given size and calls the provided callback for each batch
     /// after a threshold is reached.
     pub async fn load_batches_by_size_with_callback(
@@ -234,11 +291,64 @@ impl SegmentLogReader {
         Ok(())
     }
 
+    /// Verifies the checksums of all the batches in the log file, regardless of the configuration.
+    /// The batches written in the segment format version 1 have no checksum, so their messages are verified instead.
+    pub async fn verify_checksums_impl(&self) -> Result<(), IggyError> {
+        let mut file_size = self.file_size();
+        let mut offset = 0_u64;
+        while offset < file_size {
+            file_size = self.file_size();
+            let Some((batch, bytes_read, has_checksum)) =
+                self.read_next_batch_impl(offset, file_size, true).await?
+            else {
+                break;
+            };
+
+            offset += bytes_read;
+            if has_checksum {
+                continue;
+            }
+
+            for message in batch.into_messages_iter() {
+                let calculated_checksum = checksum::calculate(&message.payload);
+                trace!(
+                    "Loaded message for offset: {}, checksum: {}, expected: {}",
+                    message.offset,
+                    calculated_checksum,
+                    message.checksum
+                );
+                if calculated_checksum != message.checksum {
+                    return Err(IggyError::InvalidMessageChecksum(
+                        calculated_checksum,
+                        message.checksum,
+                        message.offset,
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     async fn read_next_batch(
         &self,
         offset: u64,
         file_size: u64,
     ) -> Result<Option<(RetainedMessageBatch, u64)>, IggyError> {
+        Ok(self
+            .read_next_batch_impl(offset, file_size, self.verify_checksums)
+            .await?
+            .map(|(batch, bytes_read, _)| (batch, bytes_read)))
+    }
+
+    /// Reads the batch at the given position, returning it along with the number of bytes read
+    /// and whether it has been stored with the checksum.
+    async fn read_next_batch_impl(
+        &self,
+        offset: u64,
+        file_size: u64,
+        verify_checksum: bool,
+    ) -> Result<Option<(RetainedMessageBatch, u64, bool)>, IggyError> {
         let batch_header_size = RETAINED_BATCH_HEADER_LEN;
         if offset + batch_header_size > file_size {
             return Ok(None);
@@ -308,7 +418,14 @@ impl SegmentLogReader {
                 .map_err(|_| IggyError::CannotReadMaxTimestamp)?,
         );
 
-        let payload_len = batch_length as usize;
+        let stored = parse_batch_length(batch_length).with_error_context(|error| {
+            format!(
+                "Failed to parse batch compression at offset {offset} in file {}: {error}",
+                self.file_path
+            )
+        })?;
+        let compression_algorithm = stored.compression_algorithm;
+        let payload_len = stored.length as usize;
         let payload_offset = offset + batch_header_size;
         if payload_offset + payload_len as u64 > file_size {
             warn!(
@@ -331,15 +448,42 @@ impl SegmentLogReader {
         };
 
         let bytes_read = batch_header_size + payload_len as u64;
-        let batch = RetainedMessageBatch::new(
+        let (checksum, payload_buf) = match stored.has_checksum {
+            true => {
+                let (checksum, payload) = payload_buf.split_at(BATCH_CHECKSUM_LEN as usize);
+                (
+                    Some(u32::from_le_bytes(checksum.try_into().unwrap())),
+                    payload,
+                )
+            }
+            false => (None, &payload_buf[..]),
+        };
+        let mut batch = RetainedMessageBatch::new(
             batch_base_offset,
             last_offset_delta,
             max_timestamp,
-            IggyByteSize::from(payload_len as u64),
-            BytesMut::from(&payload_buf[..]).freeze(),
+            IggyByteSize::from(payload_buf.len() as u64),
+            BytesMut::from(payload_buf).freeze(),
         );
+        batch.compression_algorithm = compression_algorithm;
+        batch.checksum = checksum;
+        if verify_checksum {
+            batch.verify_checksum().with_error_context(|error| {
+                format!(
+                    "Failed to verify batch checksum at offset {offset} in file {}: {error}",
+                    self.file_path
+                )
+            })?;
+        }
+
+        let batch = batch.decompress().with_error_context(|error| {
+            format!(
+                "Failed to decompress batch using: {compression_algorithm} at offset {offset} in file {}: {error}",
+                self.file_path
+            )
+        })?;
 
-        Ok(Some((batch, bytes_read)))
+        Ok(Some((batch, bytes_read, stored.has_checksum)))
     }
 
     fn file_size(&self) -> u64 {
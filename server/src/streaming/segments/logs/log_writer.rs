@@ -18,6 +18,9 @@
 
 use super::PersisterTask;
 use crate::streaming::batching::message_batch::RetainedMessageBatch;
+use crate::streaming::diagnostics::storage_metrics::StorageMetrics;
+#[cfg(all(target_os = "linux", feature = "io-uring"))]
+use crate::streaming::persistence::io_uring::{IoUringFile, IoUringRing};
 use error_set::ErrContext;
 use iggy::{
     confirmation::Confirmation,
@@ -30,6 +33,7 @@ use std::{
         atomic::{AtomicU64, Ordering},
         Arc,
     },
+    time::Instant,
 };
 use tokio::{
     fs::{File, OpenOptions},
@@ -45,6 +49,9 @@ pub struct SegmentLogWriter {
     file: Option<File>,
     /// When set, asynchronous writes are handled by this persister task.
     persister_task: Option<PersisterTask>,
+    /// When set, synchronous writes (and their fsyncs) are submitted to the shared io_uring.
+    #[cfg(all(target_os = "linux", feature = "io-uring"))]
+    io_uring: Option<IoUringFile>,
     log_size_bytes: Arc<AtomicU64>,
     fsync: bool,
 }
@@ -104,11 +111,31 @@ impl SegmentLogWriter {
             file_path: file_path.to_string(),
             file,
             persister_task,
+            #[cfg(all(target_os = "linux", feature = "io-uring"))]
+            io_uring: None,
             log_size_bytes,
             fsync,
         })
     }
 
+    /// Submits the synchronous writes to the given ring, the asynchronous ones are still handled by the persister task.
+    #[cfg(all(target_os = "linux", feature = "io-uring"))]
+    pub async fn use_io_uring(&mut self, ring: Arc<IoUringRing>) -> Result<(), IggyError> {
+        if let Some(file) = self.file.as_ref() {
+            let file = IoUringFile::new(ring, file)
+                .await
+                .with_error_context(|error| {
+                    format!(
+                        "Failed to register log file: {} in io_uring. {error}",
+                        self.file_path
+                    )
+                })
+                .map_err(|_| IggyError::CannotReadFile)?;
+            self.io_uring = Some(file);
+        }
+        Ok(())
+    }
+
     /// Append a message batch to the log file.
     pub async fn save_batches(
         &mut self,
@@ -116,7 +143,19 @@ impl SegmentLogWriter {
         confirmation: Confirmation,
     ) -> Result<IggyByteSize, IggyError> {
         let batch_size = batch.get_size_bytes();
+        StorageMetrics::get_instance()
+            .record_appended_batch(batch.last_offset_delta + 1, batch.bytes.len() as u64);
         match confirmation {
+            #[cfg(all(target_os = "linux", feature = "io-uring"))]
+            Confirmation::Wait if self.io_uring.is_some() => {
+                self.write_batch_with_io_uring(batch).await?;
+                self.log_size_bytes
+                    .fetch_add(batch_size.as_bytes_u64(), Ordering::AcqRel);
+                trace!(
+                    "Written batch of size {batch_size} bytes to log file: {} using io_uring",
+                    self.file_path
+                );
+            }
             Confirmation::Wait => {
                 self.write_batch(batch).await?;
                 self.log_size_bytes
@@ -157,6 +196,8 @@ impl SegmentLogWriter {
                     format!("Failed to log to file: {}. {error}", self.file_path)
                 })
                 .map_err(|_| IggyError::CannotWriteToFile)?;
+            StorageMetrics::get_instance()
+                .record_write(header.len() as u64 + batch_bytes.len() as u64);
 
             Ok(())
         } else {
@@ -165,14 +206,39 @@ impl SegmentLogWriter {
         }
     }
 
+    /// Write a batch of bytes to the log file using io_uring, followed by the linked fsync if enabled.
+    #[cfg(all(target_os = "linux", feature = "io-uring"))]
+    async fn write_batch_with_io_uring(
+        &self,
+        batch_to_write: RetainedMessageBatch,
+    ) -> Result<(), IggyError> {
+        let Some(file) = self.io_uring.as_ref() else {
+            error!("io_uring file is not available for synchronous write.");
+            return Err(IggyError::CannotWriteToFile);
+        };
+
+        let header = bytes::Bytes::from(batch_to_write.header_as_bytes());
+        file.append(vec![header, batch_to_write.bytes], self.fsync)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "Failed to log to file: {} using io_uring. {error}",
+                    self.file_path
+                )
+            })
+            .map_err(|_| IggyError::CannotWriteToFile)
+    }
+
     pub async fn fsync(&self) -> Result<(), IggyError> {
         if let Some(file) = self.file.as_ref() {
+            let started_at = Instant::now();
             file.sync_all()
                 .await
                 .with_error_context(|error| {
                     format!("Failed to fsync log file: {}. {error}", self.file_path)
                 })
                 .map_err(|_| IggyError::CannotWriteToFile)?;
+            StorageMetrics::get_instance().record_fsync(started_at.elapsed());
         }
 
         Ok(())
@@ -16,7 +16,8 @@
  * under the License.
  */
 
-use crate::streaming::batching::message_batch::{RetainedMessageBatch, RETAINED_BATCH_HEADER_LEN};
+use crate::streaming::batching::message_batch::RetainedMessageBatch;
+use crate::streaming::diagnostics::storage_metrics::StorageMetrics;
 use flume::{unbounded, Receiver};
 use iggy::{error::IggyError, utils::duration::IggyDuration};
 use std::{
@@ -25,7 +26,7 @@ use std::{
         atomic::{AtomicU64, Ordering},
         Arc,
     },
-    time::Duration,
+    time::{Duration, Instant},
 };
 use tokio::{fs::File, io::AsyncWriteExt, select, time::sleep};
 use tracing::{error, trace, warn};
@@ -79,6 +80,7 @@ impl PersisterTask {
 
     /// Sends the batch bytes to the persister task (fire-and-forget).
     pub async fn persist(&self, batch_to_write: RetainedMessageBatch) {
+        StorageMetrics::get_instance().record_flush_queue_depth(self.sender.len());
         if let Err(e) = self
             .sender
             .send_async(PersisterTaskCommand::WriteRequest(batch_to_write))
@@ -215,11 +217,14 @@ impl PersisterTask {
                 }
                 PersisterTaskCommand::Shutdown => {
                     trace!("LogPersisterTask for file {file_path} received shutdown command");
+                    let started_at = Instant::now();
                     if let Err(e) = file.sync_all().await {
                         error!(
                             "Failed to sync_all() in LogPersisterTask for file {file_path}: {:?}",
                             e
                         );
+                    } else {
+                        StorageMetrics::get_instance().record_fsync(started_at.elapsed());
                     }
                     break;
                 }
@@ -240,15 +245,21 @@ impl PersisterTask {
         let header = batch_to_write.header_as_bytes();
         let batch_bytes = batch_to_write.bytes;
         let slices = [IoSlice::new(&header), IoSlice::new(&batch_bytes)];
-        let bytes_written = RETAINED_BATCH_HEADER_LEN + batch_bytes.len() as u64;
+        let bytes_written = header.len() as u64 + batch_bytes.len() as u64;
 
+        let storage_metrics = StorageMetrics::get_instance();
         let mut attempts = 0;
         loop {
             match file.write_vectored(&slices).await {
                 Ok(_) => {
+                    storage_metrics.record_write(bytes_written);
                     if fsync {
+                        let started_at = Instant::now();
                         match file.sync_all().await {
-                            Ok(_) => return Ok(bytes_written),
+                            Ok(_) => {
+                                storage_metrics.record_fsync(started_at.elapsed());
+                                return Ok(bytes_written);
+                            }
                             Err(e) => {
                                 attempts += 1;
                                 error!(
@@ -0,0 +1,301 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::batching::message_batch::{parse_batch_length, RETAINED_BATCH_HEADER_LEN};
+use bytes::{BufMut, Bytes, BytesMut};
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::error::IggyError;
+use std::fs::File;
+use std::io::ErrorKind;
+use std::os::unix::fs::FileExt;
+use std::sync::Arc;
+
+/// The payloads smaller than the threshold are read along with the message header,
+/// as sending them from the file separately costs more than copying them.
+const ZERO_COPY_PAYLOAD_THRESHOLD: u64 = 4096;
+/// Length + Offset + State + Timestamp + ID + Checksum + Headers length.
+const MESSAGE_HEADER_LEN: u64 = 4 + 8 + 1 + 8 + 16 + 4 + 4;
+
+/// The part of the response, either held in memory or sent directly from the file.
+#[derive(Debug, Clone)]
+pub enum Slice {
+    Bytes(Bytes),
+    File {
+        file: Arc<File>,
+        position: u64,
+        length: u64,
+    },
+}
+
+impl Slice {
+    pub fn len(&self) -> u64 {
+        match self {
+            Slice::Bytes(bytes) => bytes.len() as u64,
+            Slice::File { length, .. } => *length,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// The message stored in the segment log file, whose payload can be sent without reading it into memory.
+#[derive(Debug, Clone)]
+pub struct MessageSlice {
+    pub offset: u64,
+    /// The message header in the format of the polled message, ending with the length of the payload.
+    pub header: Bytes,
+    pub payload: Slice,
+}
+
+/// The messages polled from the segment log files, along with the state of the partition.
+#[derive(Debug)]
+pub struct PolledMessageSlices {
+    pub partition_id: u32,
+    pub current_offset: u64,
+    pub remaining_messages: u64,
+    pub partition_epoch: u64,
+    pub throttle_time_ms: u32,
+    pub topic_delete_at: u64,
+    pub messages: Vec<MessageSlice>,
+}
+
+impl PolledMessageSlices {
+    /// Returns the number of bytes of the messages, as sent in the response.
+    pub fn get_messages_size_bytes(&self) -> u64 {
+        self.messages
+            .iter()
+            .map(|message| message.header.len() as u64 + message.payload.len())
+            .sum()
+    }
+}
+
+/// Reads the headers of the messages within the offset range, starting from the batch at the given position,
+/// and locates their payloads in the file. Returns `None` if any of the batches is compressed
+/// or its checksum has to be verified, so that the messages have to be read the regular way.
+pub fn load_message_slices(
+    file: &Arc<File>,
+    file_size: u64,
+    mut position: u64,
+    start_offset: u64,
+    end_offset: u64,
+    verify_checksums: bool,
+) -> Result<Option<Vec<MessageSlice>>, IggyError> {
+    let mut messages = Vec::new();
+    let mut batch_header = [0u8; RETAINED_BATCH_HEADER_LEN as usize];
+    while position + RETAINED_BATCH_HEADER_LEN <= file_size {
+        if !read_exact_at(file, &mut batch_header, position)
+            .map_err(|_| IggyError::CannotReadBatchBaseOffset)?
+        {
+            break;
+        }
+
+        let base_offset = u64::from_le_bytes(batch_header[0..8].try_into().unwrap());
+        let stored_length = u32::from_le_bytes(batch_header[8..12].try_into().unwrap());
+        let last_offset_delta = u32::from_le_bytes(batch_header[12..16].try_into().unwrap());
+        let stored = parse_batch_length(stored_length)?;
+        if stored.compression_algorithm != CompressionAlgorithm::None
+            || (stored.has_checksum && verify_checksums)
+        {
+            return Ok(None);
+        }
+
+        let batch_end = position + RETAINED_BATCH_HEADER_LEN + stored.length as u64;
+        let batch_start = batch_end - stored.payload_length() as u64;
+        if batch_end > file_size {
+            break;
+        }
+
+        position = batch_end;
+        if base_offset + last_offset_delta as u64 >= start_offset {
+            load_batch_message_slices(
+                file,
+                batch_start,
+                batch_end,
+                start_offset,
+                end_offset,
+                &mut messages,
+            )?;
+        }
+        if base_offset + last_offset_delta as u64 >= end_offset {
+            break;
+        }
+    }
+
+    Ok(Some(messages))
+}
+
+fn load_batch_message_slices(
+    file: &Arc<File>,
+    mut position: u64,
+    batch_end: u64,
+    start_offset: u64,
+    end_offset: u64,
+    messages: &mut Vec<MessageSlice>,
+) -> Result<(), IggyError> {
+    let mut message_header = [0u8; MESSAGE_HEADER_LEN as usize];
+    while position + MESSAGE_HEADER_LEN <= batch_end {
+        if !read_exact_at(file, &mut message_header, position)
+            .map_err(|_| IggyError::CannotReadMessage)?
+        {
+            return Err(IggyError::CannotReadMessage);
+        }
+
+        let length = u32::from_le_bytes(message_header[0..4].try_into().unwrap()) as u64;
+        let offset = u64::from_le_bytes(message_header[4..12].try_into().unwrap());
+        let headers_length = u32::from_le_bytes(message_header[41..45].try_into().unwrap()) as u64;
+        let message_end = position + 4 + length;
+        let headers_position = position + MESSAGE_HEADER_LEN;
+        if message_end > batch_end || headers_position + headers_length > message_end {
+            return Err(IggyError::CannotReadMessage);
+        }
+
+        if offset > end_offset {
+            break;
+        }
+
+        if offset >= start_offset {
+            let payload_position = headers_position + headers_length;
+            let payload_length = message_end - payload_position;
+            let inline_payload = payload_length < ZERO_COPY_PAYLOAD_THRESHOLD;
+            let read_length = match inline_payload {
+                true => headers_length + payload_length,
+                false => headers_length,
+            };
+            let mut buffer = vec![0u8; read_length as usize];
+            if !read_exact_at(file, &mut buffer, headers_position)
+                .map_err(|_| IggyError::CannotReadMessage)?
+            {
+                return Err(IggyError::CannotReadMessage);
+            }
+
+            let mut header =
+                BytesMut::with_capacity((MESSAGE_HEADER_LEN - 4 + headers_length + 4) as usize);
+            header.put_slice(&message_header[4..]);
+            header.put_slice(&buffer[..headers_length as usize]);
+            header.put_u32_le(payload_length as u32);
+            let payload = match inline_payload {
+                true => Slice::Bytes(Bytes::from(buffer).slice(headers_length as usize..)),
+                false => Slice::File {
+                    file: file.clone(),
+                    position: payload_position,
+                    length: payload_length,
+                },
+            };
+            messages.push(MessageSlice {
+                offset,
+                header: header.freeze(),
+                payload,
+            });
+        }
+
+        position = message_end;
+    }
+    Ok(())
+}
+
+/// Returns `false` if the file ends before the buffer is filled, e.g. when it has been truncated.
+fn read_exact_at(file: &File, buffer: &mut [u8], position: u64) -> Result<bool, std::io::Error> {
+    match file.read_exact_at(buffer, position) {
+        Ok(()) => Ok(true),
+        Err(error) if error.kind() == ErrorKind::UnexpectedEof => Ok(false),
+        Err(error) => Err(error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::batching::message_batch::RetainedMessageBatch;
+    use crate::streaming::models::messages::RetainedMessage;
+    use iggy::messages::send_messages::Message;
+    use iggy::utils::byte_size::IggyByteSize;
+    use std::io::Write;
+
+    fn write_batch(file: &mut File, base_offset: u64, payloads: &[Bytes], with_checksum: bool) {
+        let mut bytes = BytesMut::new();
+        for (index, payload) in payloads.iter().enumerate() {
+            let message = Message::new(Some(index as u128 + 1), payload.clone(), None);
+            RetainedMessage::new(base_offset + index as u64, 1000, message).extend(&mut bytes);
+        }
+        let batch = RetainedMessageBatch::new(
+            base_offset,
+            payloads.len() as u32 - 1,
+            1000,
+            IggyByteSize::from(bytes.len() as u64),
+            bytes.freeze(),
+        );
+        let batch = match with_checksum {
+            true => batch.with_checksum(),
+            false => batch,
+        };
+        file.write_all(&batch.header_as_bytes()).unwrap();
+        file.write_all(&batch.bytes).unwrap();
+    }
+
+    #[test]
+    fn messages_within_range_should_be_sliced_with_large_payloads_left_in_file() {
+        let directory = tempfile::TempDir::new().unwrap();
+        let path = directory.path().join("segment.log");
+        let mut file = File::create(&path).unwrap();
+        let large_payload = Bytes::from(vec![7u8; ZERO_COPY_PAYLOAD_THRESHOLD as usize]);
+        write_batch(
+            &mut file,
+            0,
+            &[Bytes::from_static(b"a"), large_payload.clone()],
+            false,
+        );
+        write_batch(&mut file, 2, &[Bytes::from_static(b"b")], true);
+        let file_size = file.metadata().unwrap().len();
+        let file = Arc::new(File::open(&path).unwrap());
+
+        assert!(load_message_slices(&file, file_size, 0, 1, 2, true)
+            .unwrap()
+            .is_none());
+        let messages = load_message_slices(&file, file_size, 0, 1, 2, false)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0].offset, 1);
+        assert_eq!(
+            u32::from_le_bytes(
+                messages[0].header[messages[0].header.len() - 4..]
+                    .try_into()
+                    .unwrap()
+            ),
+            ZERO_COPY_PAYLOAD_THRESHOLD as u32
+        );
+        let Slice::File {
+            position, length, ..
+        } = &messages[0].payload
+        else {
+            panic!("Large payload should be sent from the file.");
+        };
+        let mut payload = vec![0u8; *length as usize];
+        file.read_exact_at(&mut payload, *position).unwrap();
+        assert_eq!(payload, large_payload);
+
+        assert_eq!(messages[1].offset, 2);
+        let Slice::Bytes(payload) = &messages[1].payload else {
+            panic!("Small payload should be read along with the header.");
+        };
+        assert_eq!(payload, &Bytes::from_static(b"b"));
+    }
+}
@@ -18,8 +18,11 @@
 
 mod indexes;
 mod logs;
+pub mod message_slices;
 mod reading_messages;
 mod segment;
+pub mod tiered_storage;
+pub mod verification;
 mod writing_messages;
 
 pub use indexes::Index;
@@ -28,3 +31,7 @@ pub use segment::Segment;
 pub const LOG_EXTENSION: &str = "log";
 pub const INDEX_EXTENSION: &str = "index";
 pub const SEGMENT_MAX_SIZE_BYTES: u64 = 1000 * 1000 * 1000;
+/// The format of the batches relying only on the checksums of the individual messages.
+pub const LEGACY_SEGMENT_FORMAT_VERSION: u8 = 1;
+/// The format of the batches storing the CRC32C checksum of their payload.
+pub const SEGMENT_FORMAT_VERSION: u8 = 2;
@@ -20,12 +20,16 @@ use super::indexes::*;
 use crate::streaming::batching::message_batch::RetainedMessageBatch;
 use crate::streaming::batching::{batch_filter::BatchItemizer, iterator::IntoMessagesIterator};
 use crate::streaming::models::messages::RetainedMessage;
+use crate::streaming::segments::message_slices::MessageSlice;
 use crate::streaming::segments::segment::Segment;
 use error_set::ErrContext;
 use iggy::{
     error::IggyError,
-    utils::{byte_size::IggyByteSize, checksum, sizeable::Sizeable},
+    models::segment_range::SegmentRange,
+    utils::{byte_size::IggyByteSize, sizeable::Sizeable},
 };
+use std::future::Future;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use tracing::{trace, warn};
 
@@ -38,6 +42,10 @@ impl Segment {
             return 0;
         }
 
+        if let Some(compacted_messages_count) = self.compacted_messages_count {
+            return compacted_messages_count;
+        }
+
         self.current_offset - self.start_offset + 1
     }
 
@@ -133,8 +141,53 @@ impl Segment {
         Ok(messages)
     }
 
+    /// Returns the messages persisted in the log file, with their payloads left in the file,
+    /// or `None` if any of them has to be read the regular way, e.g. when it's still unsaved
+    /// or the segment has been compacted.
+    pub async fn get_message_slices_by_offset(
+        &self,
+        offset: u64,
+        count: u32,
+    ) -> Result<Option<Vec<MessageSlice>>, IggyError> {
+        if count == 0 || self.size_bytes == 0 || self.compacted_messages_count.is_some() {
+            return Ok(None);
+        }
+
+        let offset = offset.max(self.start_offset);
+        let end_offset = (offset + (count - 1) as u64).min(self.current_offset);
+        if offset > end_offset {
+            return Ok(None);
+        }
+
+        if let Some(batch_accumulator) = &self.unsaved_messages {
+            if !batch_accumulator.is_empty() && end_offset >= batch_accumulator.batch_base_offset()
+            {
+                return Ok(None);
+            }
+        }
+
+        let Some(index_range) = self.load_index_range(offset, end_offset).await? else {
+            return Ok(None);
+        };
+
+        self.log_reader
+            .as_ref()
+            .unwrap()
+            .load_message_slices_impl(&index_range, offset, end_offset)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to load message slices, start offset: {offset}, end offset: {end_offset} for {self}")
+            })
+    }
+
     pub async fn get_all_messages(&self) -> Result<Vec<Arc<RetainedMessage>>, IggyError> {
-        self.get_messages_by_offset(self.start_offset, self.get_messages_count() as u32)
+        // The offsets range is used, as there might be gaps in the offsets of the compacted segment.
+        let count = if self.size_bytes == 0 {
+            0
+        } else {
+            self.current_offset - self.start_offset + 1
+        };
+        self.get_messages_by_offset(self.start_offset, count as u32)
             .await
     }
 
@@ -265,33 +318,15 @@ impl Segment {
         Ok(messages)
     }
 
-    /// Loads and verifies message checksums from the log file.
+    /// Loads and verifies the batch (or message, for the segment format version 1) checksums from the log file.
     pub async fn load_message_checksums(&self) -> Result<(), IggyError> {
         self.log_reader
             .as_ref()
             .unwrap()
-            .load_batches_by_range_with_callback(&IndexRange::max_range(), |batch| {
-                for message in batch.into_messages_iter() {
-                    let calculated_checksum = checksum::calculate(&message.payload);
-                    trace!(
-                        "Loaded message for offset: {}, checksum: {}, expected: {}",
-                        message.offset,
-                        calculated_checksum,
-                        message.checksum
-                    );
-                    if calculated_checksum != message.checksum {
-                        return Err(IggyError::InvalidMessageChecksum(
-                            calculated_checksum,
-                            message.checksum,
-                            message.offset,
-                        ));
-                    }
-                }
-                Ok(())
-            })
+            .verify_checksums_impl()
             .await
             .with_error_context(|error| {
-                format!("Failed to load batches by max range for {}. {error}", self)
+                format!("Failed to verify checksums for {self}, use the `verify --repair` command to truncate the corrupted segment. {error}")
             })?;
         Ok(())
     }
@@ -312,6 +347,137 @@ impl Segment {
         Ok(ids)
     }
 
+    /// Reads the raw bytes of the persisted batches, starting at the first batch located at or after the `start_position`
+    /// and including the batches starting before the `end_position` (or the end of the log file, if not specified),
+    /// as long as they fit within `max_bytes`, but always including at least one batch.
+    pub async fn read_segment_range(
+        &self,
+        start_position: u64,
+        end_position: Option<u64>,
+        max_bytes: u32,
+    ) -> Result<SegmentRange, IggyError> {
+        let Some(log_reader) = self.log_reader.as_ref() else {
+            return Err(IggyError::SegmentNotFound);
+        };
+
+        // Only the persisted part of the log file is read, the unsaved messages are never included.
+        let segment_size = self.log_size_bytes.load(Ordering::Acquire);
+        if start_position > segment_size {
+            return Err(IggyError::InvalidSegmentPosition(
+                start_position,
+                segment_size,
+            ));
+        }
+
+        let end_position =
+            end_position.map_or(segment_size, |end_position| end_position.min(segment_size));
+        let positions = if self.index_rebuild_pending {
+            log_reader
+                .load_batch_positions_impl()
+                .await
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to locate batches of {self}")
+                })?
+        } else {
+            self.load_indexes()
+                .await?
+                .iter()
+                .map(|index| index.position as u64)
+                .collect()
+        };
+        let positions = positions
+            .into_iter()
+            .take_while(|position| *position < segment_size)
+            .collect::<Vec<_>>();
+        let first_batch = positions.partition_point(|position| *position < start_position);
+        let range_start = positions.get(first_batch).copied().unwrap_or(segment_size);
+        let mut range_end = range_start;
+        for (batch, position) in positions.iter().enumerate().skip(first_batch) {
+            if *position >= end_position {
+                break;
+            }
+
+            let batch_end = positions.get(batch + 1).copied().unwrap_or(segment_size);
+            if range_end > range_start && batch_end - range_start > max_bytes as u64 {
+                break;
+            }
+            range_end = batch_end;
+        }
+
+        let bytes = if range_end > range_start {
+            log_reader
+                .load_raw_bytes_impl(range_start, range_end - range_start)
+                .await
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to read segment range from position: {range_start} to: {range_end} for {self}")
+                })?
+        } else {
+            Vec::new()
+        };
+
+        Ok(SegmentRange {
+            segment_start_offset: self.start_offset,
+            segment_end_offset: if self.is_closed {
+                self.end_offset
+            } else {
+                self.current_offset
+            },
+            segment_size,
+            segment_closed: self.is_closed,
+            start_position: range_start,
+            next_position: range_end,
+            bytes: bytes.into(),
+        })
+    }
+
+    /// Returns a detached loader reading the persisted part of the given offset range,
+    /// which can be driven by a background task without holding the segment.
+    pub fn read_ahead_loader(
+        &self,
+        start_offset: u64,
+        end_offset: u64,
+    ) -> Option<impl Future<Output = Result<Vec<Arc<RetainedMessage>>, IggyError>> + Send + 'static>
+    {
+        let start_offset = start_offset.max(self.start_offset);
+        let mut end_offset = end_offset.min(self.current_offset);
+        if let Some(batch_accumulator) = &self.unsaved_messages {
+            if !batch_accumulator.is_empty() {
+                end_offset = end_offset.min(batch_accumulator.batch_base_offset().checked_sub(1)?);
+            }
+        }
+        if self.size_bytes == 0 || start_offset > end_offset {
+            return None;
+        }
+
+        let index_reader = self.index_reader.clone()?;
+        let log_reader = self.log_reader.clone()?;
+        let segment_start_offset = self.start_offset;
+        let index_rebuild_pending = self.index_rebuild_pending;
+        Some(async move {
+            let index_range = if index_rebuild_pending {
+                get_log_scan_range(end_offset)
+            } else {
+                let Some(index_range) = index_reader
+                    .load_index_range_impl(start_offset, end_offset, segment_start_offset)
+                    .await?
+                else {
+                    return Ok(Vec::new());
+                };
+                index_range
+            };
+
+            let messages_count = (end_offset - start_offset + 1) as usize;
+            let messages = log_reader
+                .load_batches_by_range_impl(&index_range)
+                .await?
+                .iter()
+                .to_messages_with_filter(messages_count, &|msg| {
+                    msg.offset >= start_offset && msg.offset <= end_offset
+                });
+            Ok(messages.into_iter().map(Arc::new).collect())
+        })
+    }
+
     async fn load_messages_from_disk(
         &self,
         start_offset: u64,
@@ -332,45 +498,95 @@ impl Segment {
             return Ok(EMPTY_MESSAGES.into_iter().map(Arc::new).collect());
         }
 
-        if let Some(indices) = &self.indexes {
+        match self.load_index_range(start_offset, end_offset).await? {
+            Some(index_range) => {
+                self.load_messages_from_segment_file(&index_range, start_offset, end_offset)
+                    .await
+            }
+            None => Ok(EMPTY_MESSAGES.into_iter().map(Arc::new).collect()),
+        }
+    }
+
+    /// Returns the range of the indexes covering the given offsets, using the index cache if it's enabled.
+    async fn load_index_range(
+        &self,
+        start_offset: u64,
+        end_offset: u64,
+    ) -> Result<Option<IndexRange>, IggyError> {
+        if self.index_rebuild_pending {
+            return Ok(Some(get_log_scan_range(end_offset)));
+        }
+
+        if let Some(indices) = self.load_cached_indexes().await? {
             let relative_start_offset = (start_offset - self.start_offset) as u32;
             let relative_end_offset = (end_offset - self.start_offset) as u32;
-            let index_range = match self.load_highest_lower_bound_index(
-                indices,
+            return match self.load_highest_lower_bound_index(
+                &indices,
                 relative_start_offset,
                 relative_end_offset,
             ) {
-                Ok(range) => range,
+                Ok(range) => Ok(Some(range)),
                 Err(_) => {
                     trace!(
                         "Cannot load messages from disk, index range not found: {} - {}.",
                         start_offset,
                         end_offset
                     );
-                    return Ok(EMPTY_MESSAGES.into_iter().map(Arc::new).collect());
+                    Ok(None)
                 }
             };
-
-            return self
-                .load_messages_from_segment_file(&index_range, start_offset, end_offset)
-                .await;
         }
 
-        match self
-            .index_reader
+        self.index_reader
             .as_ref()
             .unwrap()
             .load_index_range_impl(start_offset, end_offset, self.start_offset)
             .await
             .with_error_context(|error| {
                 format!("Failed to load index range start offset: {start_offset}, end offset: {end_offset} for {self}. {error}")
-            })? {
-            Some(index_range) => {
-                self.load_messages_from_segment_file(&index_range, start_offset, end_offset)
-                    .await
-            }
-            None => Ok(EMPTY_MESSAGES.into_iter().map(Arc::new).collect()),
+            })
+    }
+
+    /// Returns the indexes of the segment from the index cache, reading and caching them on a miss,
+    /// or `None` if the index cache is disabled.
+    async fn load_cached_indexes(&self) -> Result<Option<Arc<Vec<Index>>>, IggyError> {
+        let Some(index_cache) = &self.index_cache else {
+            return Ok(None);
+        };
+
+        let key = self.index_block_key();
+        if let Some(indexes) = index_cache.get(&key) {
+            return Ok(Some(indexes));
         }
+
+        let indexes = self
+            .index_reader
+            .as_ref()
+            .unwrap()
+            .load_all_indexes_impl()
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to load indexes for {self}")
+            })?;
+        Ok(Some(index_cache.insert(key, indexes)))
+    }
+
+    /// Returns all the indexes of the segment, using the index cache if it's enabled.
+    pub async fn load_indexes(&self) -> Result<Arc<Vec<Index>>, IggyError> {
+        if let Some(indexes) = self.load_cached_indexes().await? {
+            return Ok(indexes);
+        }
+
+        let indexes = self
+            .index_reader
+            .as_ref()
+            .unwrap()
+            .load_all_indexes_impl()
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to load indexes for {self}")
+            })?;
+        Ok(Arc::new(indexes))
     }
 
     async fn load_messages_from_segment_file(
@@ -408,3 +624,16 @@ impl Segment {
         Ok(messages.into_iter().map(Arc::new).collect())
     }
 }
+
+/// Returns the range scanning the log file from its beginning up to the batch containing the end offset,
+/// used instead of the index range until the index of the segment is rebuilt.
+fn get_log_scan_range(end_offset: u64) -> IndexRange {
+    IndexRange {
+        start: Index::default(),
+        end: Index {
+            offset: end_offset.min((u32::MAX - 1) as u64) as u32,
+            position: u32::MAX,
+            timestamp: u64::MAX,
+        },
+    }
+}
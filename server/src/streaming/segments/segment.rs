@@ -20,8 +20,11 @@ use super::indexes::*;
 use super::logs::*;
 use crate::configs::system::SystemConfig;
 use crate::streaming::batching::batch_accumulator::BatchAccumulator;
+#[cfg(all(target_os = "linux", feature = "io-uring"))]
+use crate::streaming::persistence::io_uring::IoUringRing;
 use crate::streaming::segments::*;
 use error_set::ErrContext;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::error::IggyError;
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::expiry::IggyExpiry;
@@ -53,14 +56,19 @@ pub struct Segment {
     pub messages_count_of_parent_topic: Arc<AtomicU64>,
     pub messages_count_of_parent_partition: Arc<AtomicU64>,
     pub is_closed: bool,
+    /// The number of messages retained in the segment after it was compacted, as there are gaps in the offsets.
+    pub compacted_messages_count: Option<u64>,
+    /// The index of the segment is being rebuilt in the background, meanwhile its log file is scanned instead.
+    pub index_rebuild_pending: bool,
     pub(super) log_writer: Option<SegmentLogWriter>,
     pub(super) log_reader: Option<SegmentLogReader>,
     pub(super) index_writer: Option<SegmentIndexWriter>,
     pub(super) index_reader: Option<SegmentIndexReader>,
     pub message_expiry: IggyExpiry,
+    pub compression_algorithm: CompressionAlgorithm,
     pub unsaved_messages: Option<BatchAccumulator>,
     pub config: Arc<SystemConfig>,
-    pub indexes: Option<Vec<Index>>,
+    pub index_cache: Option<Arc<IndexCache>>,
     pub(super) log_size_bytes: Arc<AtomicU64>,
     pub(super) index_size_bytes: Arc<AtomicU64>,
 }
@@ -74,6 +82,7 @@ impl Segment {
         start_offset: u64,
         config: Arc<SystemConfig>,
         message_expiry: IggyExpiry,
+        compression_algorithm: CompressionAlgorithm,
         size_of_parent_stream: Arc<AtomicU64>,
         size_of_parent_topic: Arc<AtomicU64>,
         size_of_parent_partition: Arc<AtomicU64>,
@@ -88,10 +97,7 @@ impl Segment {
             IggyExpiry::ServerDefault => config.segment.message_expiry,
             _ => message_expiry,
         };
-        let indexes = match config.segment.cache_indexes {
-            true => Some(Vec::new()),
-            false => None,
-        };
+        let index_cache = IndexCache::initialize(&config.segment);
 
         Segment {
             stream_id,
@@ -108,9 +114,12 @@ impl Segment {
             last_index_position: 0,
             max_size_bytes: config.segment.size,
             message_expiry,
-            indexes,
+            compression_algorithm,
+            index_cache,
             unsaved_messages: None,
             is_closed: false,
+            compacted_messages_count: None,
+            index_rebuild_pending: false,
             log_writer: None,
             log_reader: None,
             index_writer: None,
@@ -135,7 +144,9 @@ impl Segment {
         );
 
         if self.log_reader.is_none() || self.index_reader.is_none() {
-            self.initialize_writing().await?;
+            if !self.config.recovery.read_only {
+                self.initialize_writing().await?;
+            }
             self.initialize_reading().await?;
         }
 
@@ -146,33 +157,27 @@ impl Segment {
         self.size_bytes = IggyByteSize::from(log_size_bytes);
         self.last_index_position = log_size_bytes as _;
 
-        self.indexes = Some(
-            self.index_reader
-                .as_ref()
-                .unwrap()
-                .load_all_indexes_impl()
-                .await
-                .with_error_context(|error| format!("Failed to load indexes for {self}. {error}"))
-                .map_err(|_| IggyError::CannotReadFile)?,
-        );
-
-        let last_index_offset = if self.indexes.as_ref().unwrap().is_empty() {
-            0_u64
-        } else {
-            self.indexes.as_ref().unwrap().last().unwrap().offset as u64
-        };
+        let indexes = self
+            .index_reader
+            .as_ref()
+            .unwrap()
+            .load_all_indexes_impl()
+            .await
+            .with_error_context(|error| format!("Failed to load indexes for {self}. {error}"))
+            .map_err(|_| IggyError::CannotReadFile)?;
 
+        let last_index_offset = indexes.last().map_or(0, |index| index.offset as u64);
         self.current_offset = self.start_offset + last_index_offset;
 
         info!("Loaded {} indexes for segment with start offset: {} and partition with ID: {} for topic with ID: {} and stream with ID: {}.",
-              self.indexes.as_ref().unwrap().len(),
+              indexes.len(),
               self.start_offset,
               self.partition_id,
               self.topic_id,
               self.stream_id);
 
-        if !self.config.segment.cache_indexes {
-            self.indexes = None;
+        if let Some(index_cache) = &self.index_cache {
+            index_cache.insert(self.index_block_key(), indexes);
         }
 
         if self.is_full().await {
@@ -208,6 +213,49 @@ impl Segment {
         Ok(())
     }
 
+    /// Reopens the index rebuilt in the background and restores the offsets range of the segment.
+    pub async fn reload_indexes(&mut self) -> Result<(), IggyError> {
+        let index_reader =
+            SegmentIndexReader::new(&self.index_path, self.index_size_bytes.clone()).await?;
+        let indexes = index_reader
+            .load_all_indexes_impl()
+            .await
+            .with_error_context(|error| format!("Failed to load indexes for {self}. {error}"))
+            .map_err(|_| IggyError::CannotReadFile)?;
+        self.index_reader = Some(index_reader);
+        self.index_rebuild_pending = false;
+
+        let last_index_offset = indexes.last().map_or(0, |index| index.offset as u64);
+        self.advance_current_offset(self.start_offset + last_index_offset);
+        info!("Reloaded {} rebuilt indexes for segment with start offset: {} and partition with ID: {} for topic with ID: {} and stream with ID: {}.",
+              indexes.len(),
+              self.start_offset,
+              self.partition_id,
+              self.topic_id,
+              self.stream_id);
+        if let Some(index_cache) = &self.index_cache {
+            index_cache.insert(self.index_block_key(), indexes);
+        }
+        Ok(())
+    }
+
+    /// Moves the current offset of the segment forward, e.g. to the end of its offsets range known from the next segment
+    /// while its index is being rebuilt, and adds the messages counted this way to the parents.
+    pub fn advance_current_offset(&mut self, current_offset: u64) {
+        let previous_messages_count = self.get_messages_count();
+        self.current_offset = self.current_offset.max(current_offset);
+        let messages_count = self.get_messages_count();
+        if messages_count > previous_messages_count {
+            let added_messages_count = messages_count - previous_messages_count;
+            self.messages_count_of_parent_stream
+                .fetch_add(added_messages_count, Ordering::SeqCst);
+            self.messages_count_of_parent_topic
+                .fetch_add(added_messages_count, Ordering::SeqCst);
+            self.messages_count_of_parent_partition
+                .fetch_add(added_messages_count, Ordering::SeqCst);
+        }
+    }
+
     /// Save the segment state to disk.
     pub async fn persist(&mut self) -> Result<(), IggyError> {
         info!("Saving segment with start offset: {} for partition with ID: {} for topic with ID: {} and stream with ID: {}",
@@ -244,11 +292,27 @@ impl Segment {
 
         self.log_writer = Some(log_writer);
         self.index_writer = Some(index_writer);
+
+        #[cfg(all(target_os = "linux", feature = "io-uring"))]
+        if self.config.partition.io_uring.enabled {
+            let ring = IoUringRing::get_or_init(self.config.partition.io_uring.queue_depth)?;
+            if let Some(log_writer) = self.log_writer.as_mut() {
+                log_writer.use_io_uring(ring.clone()).await?;
+            }
+            if let Some(index_writer) = self.index_writer.as_mut() {
+                index_writer.use_io_uring(ring).await?;
+            }
+        }
         Ok(())
     }
 
     pub async fn initialize_reading(&mut self) -> Result<(), IggyError> {
-        let log_reader = SegmentLogReader::new(&self.log_path, self.log_size_bytes.clone()).await?;
+        let log_reader = SegmentLogReader::new(
+            &self.log_path,
+            self.log_size_bytes.clone(),
+            self.config.segment.verify_batch_checksums,
+        )
+        .await?;
         // TODO(hubcio): there is no need to store open fd for reader if we have index cache enabled
         let index_reader =
             SegmentIndexReader::new(&self.index_path, self.index_size_bytes.clone()).await?;
@@ -275,23 +339,51 @@ impl Segment {
             IggyExpiry::NeverExpire => false,
             IggyExpiry::ServerDefault => false,
             IggyExpiry::ExpireDuration(expiry) => {
-                let last_messages = self.get_messages_by_offset(self.current_offset, 1).await;
-                if last_messages.is_err() {
-                    return false;
-                }
-
-                let last_messages = last_messages.unwrap();
-                if last_messages.is_empty() {
+                let Some(last_message_timestamp) = self.get_last_message_timestamp().await else {
                     return false;
-                }
-
-                let last_message = &last_messages[0];
-                let last_message_timestamp = last_message.timestamp;
+                };
                 last_message_timestamp + expiry.as_micros() <= now.as_micros()
             }
         }
     }
 
+    pub async fn is_older_than(&self, timestamp: IggyTimestamp) -> bool {
+        if !self.is_closed {
+            return false;
+        }
+
+        let Some(last_message_timestamp) = self.get_last_message_timestamp().await else {
+            return false;
+        };
+        last_message_timestamp < timestamp.as_micros()
+    }
+
+    async fn get_last_message_timestamp(&self) -> Option<u64> {
+        let last_messages = self
+            .get_messages_by_offset(self.current_offset, 1)
+            .await
+            .ok()?;
+        last_messages.first().map(|message| message.timestamp)
+    }
+
+    /// Returns the sizes of the log and index files written so far, which lag behind the appended messages
+    /// until they're persisted (and written by the background task, if the server confirmation is `no_wait`).
+    pub fn get_written_sizes(&self) -> (u64, u64) {
+        (
+            self.log_size_bytes.load(Ordering::Acquire),
+            self.index_size_bytes.load(Ordering::Acquire),
+        )
+    }
+
+    pub fn index_block_key(&self) -> IndexBlockKey {
+        IndexBlockKey {
+            stream_id: self.stream_id,
+            topic_id: self.topic_id,
+            partition_id: self.partition_id,
+            start_offset: self.start_offset,
+        }
+    }
+
     pub async fn shutdown_reading(&mut self) {
         if let Some(log_reader) = self.log_reader.take() {
             drop(log_reader);
@@ -301,6 +393,16 @@ impl Segment {
         }
     }
 
+    pub async fn fsync(&self) -> Result<(), IggyError> {
+        if let Some(log_writer) = &self.log_writer {
+            log_writer.fsync().await?;
+        }
+        if let Some(index_writer) = &self.index_writer {
+            index_writer.fsync().await?;
+        }
+        Ok(())
+    }
+
     pub async fn shutdown_writing(&mut self) {
         if let Some(log_writer) = self.log_writer.take() {
             tokio::spawn(async move {
@@ -326,6 +428,10 @@ impl Segment {
 
     pub async fn delete(&mut self) -> Result<(), IggyError> {
         let segment_size = self.size_bytes;
+        if let Some(index_cache) = &self.index_cache {
+            index_cache.remove(&self.index_block_key());
+        }
+
         let segment_count_of_messages = self.get_messages_count();
         info!(
             "Deleting segment of size {segment_size} with start offset: {} for partition with ID: {} for stream with ID: {} and topic with ID: {}...",
@@ -403,7 +509,11 @@ impl std::fmt::Display for Segment {
 mod tests {
     use super::*;
     use crate::configs::system::SegmentConfig;
+    use crate::streaming::models::messages::RetainedMessage;
+    use bytes::Bytes;
+    use iggy::messages::send_messages::Message;
     use iggy::utils::duration::IggyDuration;
+    use iggy::utils::sizeable::Sizeable;
 
     #[tokio::test]
     async fn should_be_created_given_valid_parameters() {
@@ -430,6 +540,7 @@ mod tests {
             start_offset,
             config,
             message_expiry,
+            CompressionAlgorithm::None,
             size_of_parent_stream,
             size_of_parent_topic,
             size_of_parent_partition,
@@ -449,20 +560,20 @@ mod tests {
         assert_eq!(segment.index_path, index_path);
         assert_eq!(segment.message_expiry, message_expiry);
         assert!(segment.unsaved_messages.is_none());
-        assert!(segment.indexes.is_some());
+        assert!(segment.index_cache.is_some());
         assert!(!segment.is_closed);
         assert!(!segment.is_full().await);
     }
 
     #[tokio::test]
-    async fn should_not_initialize_indexes_cache_when_disabled() {
+    async fn should_not_use_index_cache_when_disabled() {
         let stream_id = 1;
         let topic_id = 2;
         let partition_id = 3;
         let start_offset = 0;
         let config = Arc::new(SystemConfig {
             segment: SegmentConfig {
-                cache_indexes: false,
+                index_cache_size: IggyByteSize::from(0),
                 ..Default::default()
             },
             ..Default::default()
@@ -482,6 +593,7 @@ mod tests {
             start_offset,
             config,
             message_expiry,
+            CompressionAlgorithm::None,
             size_of_parent_stream,
             size_of_parent_topic,
             size_of_parent_partition,
@@ -490,6 +602,46 @@ mod tests {
             messages_count_of_parent_partition,
         );
 
-        assert!(segment.indexes.is_none());
+        assert!(segment.index_cache.is_none());
+    }
+
+    #[tokio::test]
+    async fn batch_with_payloads_compressed_by_producer_should_not_be_compressed_again() {
+        let config = Arc::new(SystemConfig::default());
+        let mut segment = Segment::create(
+            1,
+            2,
+            3,
+            0,
+            config,
+            IggyExpiry::NeverExpire,
+            CompressionAlgorithm::Gzip,
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+        );
+        let mut headers = None;
+        CompressionAlgorithm::Lz4.set_header(&mut headers).unwrap();
+        let plain = Message::new(Some(1), Bytes::from_static(b"plain"), None);
+        let compressed = Message::new(Some(2), Bytes::from_static(b"compressed"), headers);
+
+        let batch = [Arc::new(RetainedMessage::new(0, 1000, plain))];
+        segment
+            .append_batch(batch[0].get_size_bytes(), 1, &batch)
+            .await
+            .unwrap();
+        let accumulator = segment.unsaved_messages.as_ref().unwrap();
+        assert!(!accumulator.is_compressed_by_producer());
+
+        let batch = [Arc::new(RetainedMessage::new(1, 1000, compressed))];
+        segment
+            .append_batch(batch[0].get_size_bytes(), 1, &batch)
+            .await
+            .unwrap();
+        let accumulator = segment.unsaved_messages.as_ref().unwrap();
+        assert!(accumulator.is_compressed_by_producer());
     }
 }
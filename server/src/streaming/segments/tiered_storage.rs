@@ -0,0 +1,247 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::archiver::ArchiverKind;
+use crate::configs::server::TieredStorageConfig;
+use crate::configs::system::SystemConfig;
+use crate::streaming::models::messages::RetainedMessage;
+use crate::streaming::partitions::archived_segments::ArchivedSegment;
+use crate::streaming::segments::Segment;
+use error_set::ErrContext;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::error::IggyError;
+use iggy::utils::expiry::IggyExpiry;
+use std::collections::VecDeque;
+use std::sync::atomic::AtomicU64;
+use std::sync::Arc;
+use tokio::fs::remove_file;
+use tokio::sync::Mutex;
+use tracing::{info, warn};
+
+const COMPONENT: &str = "STREAMING_TIERED_STORAGE";
+
+/// Serves the reads of the archived segments which are no longer available locally,
+/// by fetching them from the archiver into the local cache directory.
+/// The fetched segments are read-only and the least recently used ones are evicted from the cache.
+#[derive(Debug)]
+pub struct TieredStorage {
+    archiver: Arc<ArchiverKind>,
+    cache_path: String,
+    max_cached_segments: usize,
+    segments: Mutex<VecDeque<Arc<Segment>>>,
+}
+
+impl TieredStorage {
+    pub fn new(archiver: Arc<ArchiverKind>, config: &TieredStorageConfig) -> Self {
+        Self {
+            archiver,
+            cache_path: config.cache_path.clone(),
+            max_cached_segments: config.max_cached_segments as usize,
+            segments: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    /// Reads the messages (up to a specified count) starting at the given offset from the archived segment.
+    /// The returned messages never go beyond the end offset of the archived segment.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn get_messages_by_offset(
+        &self,
+        config: &Arc<SystemConfig>,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+        archived_segment: ArchivedSegment,
+        offset: u64,
+        count: u32,
+    ) -> Result<Vec<Arc<RetainedMessage>>, IggyError> {
+        if count == 0 || !archived_segment.contains(offset) {
+            return Ok(Vec::new());
+        }
+
+        let segment = self
+            .get_segment(config, stream_id, topic_id, partition_id, archived_segment)
+            .await?;
+        let available = archived_segment.end_offset - offset + 1;
+        let count = available.min(count as u64) as u32;
+        segment
+            .get_messages_by_offset(offset, count)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to read messages from archived segment: {segment}, offset: {offset}, count: {count}")
+            })
+    }
+
+    async fn get_segment(
+        &self,
+        config: &Arc<SystemConfig>,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+        archived_segment: ArchivedSegment,
+    ) -> Result<Arc<Segment>, IggyError> {
+        let mut segments = self.segments.lock().await;
+        if let Some(position) = segments.iter().position(|segment| {
+            segment.stream_id == stream_id
+                && segment.topic_id == topic_id
+                && segment.partition_id == partition_id
+                && segment.start_offset == archived_segment.start_offset
+        }) {
+            let segment = segments.remove(position).unwrap();
+            segments.push_back(segment.clone());
+            return Ok(segment);
+        }
+
+        let segment = Arc::new(
+            self.fetch_segment(config, stream_id, topic_id, partition_id, archived_segment)
+                .await?,
+        );
+        segments.push_back(segment.clone());
+        while segments.len() > self.max_cached_segments {
+            if let Some(evicted) = segments.pop_front() {
+                Self::evict(&evicted).await;
+            }
+        }
+
+        Ok(segment)
+    }
+
+    async fn fetch_segment(
+        &self,
+        config: &Arc<SystemConfig>,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+        archived_segment: ArchivedSegment,
+    ) -> Result<Segment, IggyError> {
+        info!(
+            "Fetching archived segment with start offset: {} for partition with ID: {}, topic with ID: {} and stream with ID: {}...",
+            archived_segment.start_offset, partition_id, topic_id, stream_id
+        );
+        // The fetched segment must not affect the sizes and messages counts of the partition, topic and stream.
+        let mut segment = Segment::create(
+            stream_id,
+            topic_id,
+            partition_id,
+            archived_segment.start_offset,
+            config.clone(),
+            IggyExpiry::NeverExpire,
+            CompressionAlgorithm::None,
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+        );
+
+        // The segment files are archived using their original paths.
+        let index_path = format!("{}/{}", self.cache_path, segment.index_path);
+        let log_path = format!("{}/{}", self.cache_path, segment.log_path);
+        for (file, destination) in [
+            (&segment.index_path, &index_path),
+            (&segment.log_path, &log_path),
+        ] {
+            self.archiver
+                .fetch(file, None, destination)
+                .await
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to fetch archived file: {file} to: {destination}")
+                })
+                .map_err(|_| {
+                    IggyError::CannotFetchArchivedSegment(archived_segment.start_offset, partition_id)
+                })?;
+        }
+
+        segment.index_path = index_path;
+        segment.log_path = log_path;
+        segment.initialize_reading().await?;
+        segment.load_from_disk().await.with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to load fetched segment: {segment}")
+        })?;
+        segment.is_closed = true;
+        segment.end_offset = segment.current_offset;
+        info!(
+            "Fetched archived segment with start offset: {}, end offset: {} for partition with ID: {}, topic with ID: {} and stream with ID: {}.",
+            segment.start_offset, segment.end_offset, partition_id, topic_id, stream_id
+        );
+        Ok(segment)
+    }
+
+    async fn evict(segment: &Segment) {
+        if let Some(index_cache) = &segment.index_cache {
+            index_cache.remove(&segment.index_block_key());
+        }
+
+        // The segment might still be read, but the open file descriptors remain valid after removing the files.
+        for path in [&segment.log_path, &segment.index_path] {
+            if let Err(error) = remove_file(path).await {
+                warn!("Failed to remove evicted tiered storage file: {path}. {error}");
+            }
+        }
+        info!(
+            "Evicted fetched segment with start offset: {} for partition with ID: {}, topic with ID: {} and stream with ID: {} from tiered storage cache.",
+            segment.start_offset, segment.partition_id, segment.topic_id, segment.stream_id
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::archiver::disk::DiskArchiver;
+    use crate::configs::server::DiskArchiverConfig;
+    use std::path::Path;
+
+    #[tokio::test]
+    async fn should_fail_when_archived_segment_is_missing() {
+        let tempdir = tempfile::TempDir::new().unwrap();
+        let base_path = tempdir.path().to_str().unwrap();
+        let cache_path = format!("{base_path}/cache");
+        let archiver = Arc::new(ArchiverKind::Disk(DiskArchiver::new(DiskArchiverConfig {
+            path: format!("{base_path}/archive"),
+        })));
+        let tiered_storage = TieredStorage::new(
+            archiver,
+            &TieredStorageConfig {
+                enabled: true,
+                cache_path: cache_path.clone(),
+                max_cached_segments: 1,
+            },
+        );
+        let config = Arc::new(SystemConfig {
+            path: format!("{base_path}/data"),
+            ..Default::default()
+        });
+        let archived_segment = ArchivedSegment {
+            start_offset: 0,
+            end_offset: 9,
+            restored: false,
+        };
+
+        let result = tiered_storage
+            .get_messages_by_offset(&config, 1, 1, 1, archived_segment, 0, 10)
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(IggyError::CannotFetchArchivedSegment(0, 1))
+        ));
+        assert!(tiered_storage.segments.lock().await.is_empty());
+        assert!(!Path::new(&cache_path).exists());
+    }
+}
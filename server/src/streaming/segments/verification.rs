@@ -0,0 +1,316 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::configs::system::SystemConfig;
+use crate::streaming::batching::iterator::IntoMessagesIterator;
+use crate::streaming::batching::message_batch::{
+    parse_batch_length, RetainedMessageBatch, BATCH_CHECKSUM_LEN, RETAINED_BATCH_HEADER_LEN,
+};
+use crate::streaming::segments::{INDEX_EXTENSION, LOG_EXTENSION};
+use iggy::error::IggyError;
+use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::checksum;
+use std::fmt::Display;
+use std::fs::{self, File, OpenOptions};
+use std::io::{ErrorKind, Read};
+use std::path::{Path, PathBuf};
+
+/// The first corrupted batch found in the segment log file.
+#[derive(Debug)]
+pub struct CorruptedBatch {
+    pub log_path: PathBuf,
+    /// The position of the batch in the log file, at which the file is truncated when repaired.
+    pub position: u64,
+    pub base_offset: u64,
+    pub reason: String,
+    pub repaired: bool,
+}
+
+/// The result of verifying all the segments stored in the system path.
+#[derive(Debug, Default)]
+pub struct SegmentsVerificationReport {
+    pub segments_count: u32,
+    pub batches_count: u64,
+    pub corrupted_batches: Vec<CorruptedBatch>,
+}
+
+impl Display for CorruptedBatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "corrupted batch with base offset: {} at position: {} in log file: {}, reason: {}{}",
+            self.base_offset,
+            self.position,
+            self.log_path.display(),
+            self.reason,
+            if self.repaired { " (truncated)" } else { "" }
+        )
+    }
+}
+
+impl Display for SegmentsVerificationReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "segments verification report {{ segments: {}, batches: {}, corrupted segments: {} }}",
+            self.segments_count,
+            self.batches_count,
+            self.corrupted_batches.len()
+        )
+    }
+}
+
+/// Verifies the checksums of the batches in all the segments of all the partitions.
+/// When `repair` is enabled, the corrupted segment is truncated at the first corrupted batch
+/// and its index is removed, so that it's rebuilt on the next startup,
+/// instead of failing the load of the whole partition.
+pub fn verify_segments(
+    config: &SystemConfig,
+    repair: bool,
+) -> Result<SegmentsVerificationReport, IggyError> {
+    let mut report = SegmentsVerificationReport::default();
+    for stream_id in read_numeric_dir_names(Path::new(&config.get_streams_path())) {
+        for topic_id in read_numeric_dir_names(Path::new(&config.get_topics_path(stream_id))) {
+            let partitions_path = config.get_partitions_path(stream_id, topic_id);
+            for partition_id in read_numeric_dir_names(Path::new(&partitions_path)) {
+                let partition_path = config.get_partition_path(stream_id, topic_id, partition_id);
+                for log_path in find_log_files(Path::new(&partition_path)) {
+                    report.segments_count += 1;
+                    let (batches_count, corrupted_batch) = verify_log_file(&log_path, repair)?;
+                    report.batches_count += batches_count;
+                    if let Some(corrupted_batch) = corrupted_batch {
+                        report.corrupted_batches.push(corrupted_batch);
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(report)
+}
+
+/// Reads the batches of the log file until the first corrupted one, returning the number of the valid batches.
+fn verify_log_file(
+    log_path: &Path,
+    repair: bool,
+) -> Result<(u64, Option<CorruptedBatch>), IggyError> {
+    let mut file = File::open(log_path).map_err(|_| IggyError::CannotReadFile)?;
+    let file_size = file
+        .metadata()
+        .map_err(|_| IggyError::CannotReadFileMetadata)?
+        .len();
+    let mut position = 0;
+    let mut batches_count = 0;
+    let mut header = [0u8; RETAINED_BATCH_HEADER_LEN as usize];
+    while position < file_size {
+        let corruption = match read_batch(&mut file, &mut header, file_size - position) {
+            Ok(batch) => match verify_batch(batch) {
+                Ok(bytes_read) => {
+                    position += bytes_read;
+                    batches_count += 1;
+                    continue;
+                }
+                Err(error) => error.to_string(),
+            },
+            Err(error) if error.kind() == ErrorKind::UnexpectedEof => {
+                "batch is truncated".to_string()
+            }
+            Err(error) => error.to_string(),
+        };
+
+        let mut corrupted_batch = CorruptedBatch {
+            log_path: log_path.to_path_buf(),
+            position,
+            base_offset: u64::from_le_bytes(header[0..8].try_into().unwrap()),
+            reason: corruption,
+            repaired: false,
+        };
+        if repair {
+            truncate_log_file(log_path, position)?;
+            corrupted_batch.repaired = true;
+        }
+        return Ok((batches_count, Some(corrupted_batch)));
+    }
+
+    Ok((batches_count, None))
+}
+
+fn read_batch(
+    file: &mut File,
+    header: &mut [u8; RETAINED_BATCH_HEADER_LEN as usize],
+    remaining_size: u64,
+) -> Result<(RetainedMessageBatch, u64), std::io::Error> {
+    header.fill(0);
+    file.read_exact(header)?;
+    let stored = parse_batch_length(u32::from_le_bytes(header[8..12].try_into().unwrap()))
+        .map_err(|error| std::io::Error::new(ErrorKind::InvalidData, error))?;
+    if RETAINED_BATCH_HEADER_LEN + stored.length as u64 > remaining_size {
+        return Err(ErrorKind::UnexpectedEof.into());
+    }
+    let mut payload = vec![0u8; stored.length as usize];
+    file.read_exact(&mut payload)?;
+
+    let checksum = match stored.has_checksum {
+        true => {
+            let checksum = u32::from_le_bytes(payload[..4].try_into().unwrap());
+            payload.drain(..BATCH_CHECKSUM_LEN as usize);
+            Some(checksum)
+        }
+        false => None,
+    };
+    let mut batch = RetainedMessageBatch::new(
+        u64::from_le_bytes(header[0..8].try_into().unwrap()),
+        u32::from_le_bytes(header[12..16].try_into().unwrap()),
+        u64::from_le_bytes(header[16..24].try_into().unwrap()),
+        IggyByteSize::from(payload.len() as u64),
+        payload.into(),
+    );
+    batch.compression_algorithm = stored.compression_algorithm;
+    batch.checksum = checksum;
+    Ok((batch, RETAINED_BATCH_HEADER_LEN + stored.length as u64))
+}
+
+/// Verifies the batch checksum, or the checksums of its messages if the batch has been written without one.
+fn verify_batch((batch, bytes_read): (RetainedMessageBatch, u64)) -> Result<u64, IggyError> {
+    if batch.checksum.is_some() {
+        batch.verify_checksum()?;
+        return Ok(bytes_read);
+    }
+
+    let batch = batch.decompress()?;
+    for message in batch.into_messages_iter() {
+        let calculated_checksum = checksum::calculate(&message.payload);
+        if calculated_checksum != message.checksum {
+            return Err(IggyError::InvalidMessageChecksum(
+                calculated_checksum,
+                message.checksum,
+                message.offset,
+            ));
+        }
+    }
+
+    Ok(bytes_read)
+}
+
+fn truncate_log_file(log_path: &Path, position: u64) -> Result<(), IggyError> {
+    let file = OpenOptions::new()
+        .write(true)
+        .open(log_path)
+        .map_err(|_| IggyError::CannotWriteToFile)?;
+    file.set_len(position)
+        .map_err(|_| IggyError::CannotWriteToFile)?;
+    file.sync_all().map_err(|_| IggyError::CannotWriteToFile)?;
+
+    // The index may point at the removed batches, so it's rebuilt on the next startup.
+    let index_path = log_path.with_extension(INDEX_EXTENSION);
+    if index_path.exists() {
+        fs::remove_file(&index_path).map_err(|_| IggyError::CannotDeleteFile)?;
+    }
+
+    Ok(())
+}
+
+fn read_numeric_dir_names(path: &Path) -> Vec<u32> {
+    let Ok(dir_entries) = fs::read_dir(path) else {
+        return Vec::new();
+    };
+
+    let mut ids = dir_entries
+        .filter_map(|dir_entry| dir_entry.ok())
+        .filter(|dir_entry| dir_entry.path().is_dir())
+        .filter_map(|dir_entry| dir_entry.file_name().to_str()?.parse::<u32>().ok())
+        .collect::<Vec<_>>();
+    ids.sort();
+    ids
+}
+
+fn find_log_files(partition_path: &Path) -> Vec<PathBuf> {
+    let Ok(dir_entries) = fs::read_dir(partition_path) else {
+        return Vec::new();
+    };
+
+    let mut log_paths = dir_entries
+        .filter_map(|dir_entry| dir_entry.ok())
+        .map(|dir_entry| dir_entry.path())
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|extension| extension == LOG_EXTENSION)
+        })
+        .collect::<Vec<_>>();
+    log_paths.sort();
+    log_paths
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::models::messages::RetainedMessage;
+    use bytes::{Bytes, BytesMut};
+    use iggy::messages::send_messages::Message;
+    use std::io::Write;
+
+    fn write_batch(file: &mut File, base_offset: u64) -> u64 {
+        let mut bytes = BytesMut::new();
+        let message = Message::new(Some(1), Bytes::from_static(b"payload"), None);
+        RetainedMessage::new(base_offset, 1000, message).extend(&mut bytes);
+        let batch = RetainedMessageBatch::new(
+            base_offset,
+            0,
+            1000,
+            IggyByteSize::from(bytes.len() as u64),
+            bytes.freeze(),
+        )
+        .with_checksum();
+        let header = batch.header_as_bytes();
+        file.write_all(&header).unwrap();
+        file.write_all(&batch.bytes).unwrap();
+        header.len() as u64 + batch.bytes.len() as u64
+    }
+
+    #[test]
+    fn segment_should_be_truncated_at_first_corrupted_batch() {
+        let directory = tempfile::TempDir::new().unwrap();
+        let log_path = directory.path().join("00000000000000000000.log");
+        let index_path = log_path.with_extension(INDEX_EXTENSION);
+        let mut file = File::create(&log_path).unwrap();
+        let first_batch_size = write_batch(&mut file, 0);
+        let second_batch_size = write_batch(&mut file, 1);
+        write_batch(&mut file, 2);
+        File::create(&index_path).unwrap();
+
+        let mut bytes = fs::read(&log_path).unwrap();
+        let last_payload_byte = (first_batch_size + second_batch_size - 1) as usize;
+        bytes[last_payload_byte] ^= 0xFF;
+        fs::write(&log_path, bytes).unwrap();
+
+        let (batches_count, corrupted_batch) = verify_log_file(&log_path, false).unwrap();
+        let corrupted_batch = corrupted_batch.unwrap();
+        assert_eq!(batches_count, 1);
+        assert_eq!(corrupted_batch.position, first_batch_size);
+        assert_eq!(corrupted_batch.base_offset, 1);
+        assert!(!corrupted_batch.repaired);
+
+        let (_, corrupted_batch) = verify_log_file(&log_path, true).unwrap();
+        assert!(corrupted_batch.unwrap().repaired);
+        assert_eq!(fs::metadata(&log_path).unwrap().len(), first_batch_size);
+        assert!(!index_path.exists());
+        let (batches_count, corrupted_batch) = verify_log_file(&log_path, false).unwrap();
+        assert_eq!(batches_count, 1);
+        assert!(corrupted_batch.is_none());
+    }
+}
@@ -18,10 +18,11 @@
 
 use super::indexes::*;
 use crate::streaming::batching::batch_accumulator::BatchAccumulator;
-use crate::streaming::batching::message_batch::RETAINED_BATCH_HEADER_LEN;
 use crate::streaming::models::messages::RetainedMessage;
 use crate::streaming::segments::segment::Segment;
+use crate::streaming::segments::SEGMENT_FORMAT_VERSION;
 use error_set::ErrContext;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::confirmation::Confirmation;
 use iggy::error::IggyError;
 use iggy::utils::byte_size::IggyByteSize;
@@ -55,6 +56,17 @@ impl Segment {
             .unsaved_messages
             .get_or_insert_with(|| BatchAccumulator::new(batch_base_offset, messages_cap));
         batch_accumulator.append(batch_size, batch);
+        // The payloads already compressed by the producer are stored as they are.
+        if self.compression_algorithm != CompressionAlgorithm::None
+            && !batch_accumulator.is_compressed_by_producer()
+        {
+            for message in batch {
+                if message.is_compressed_by_producer()? {
+                    batch_accumulator.mark_compressed_by_producer();
+                    break;
+                }
+            }
+        }
         self.end_timestamp = batch_accumulator.batch_max_timestamp();
         let curr_offset = batch_accumulator.batch_max_offset();
 
@@ -92,8 +104,8 @@ impl Segment {
             position: self.last_index_position,
             timestamp: batch_max_timestamp,
         };
-        if let Some(indexes) = &mut self.indexes {
-            indexes.push(index);
+        if let Some(index_cache) = &self.index_cache {
+            index_cache.append(&self.index_block_key(), index);
         }
         index
     }
@@ -110,6 +122,7 @@ impl Segment {
         if batch_accumulator.is_empty() {
             return Ok(0);
         }
+        let compressed_by_producer = batch_accumulator.is_compressed_by_producer();
         let batch_max_offset = batch_accumulator.batch_max_offset();
         let batch_max_timestamp = batch_accumulator.batch_max_timestamp();
         let index =
@@ -124,10 +137,31 @@ impl Segment {
         );
 
         let batch = batch_accumulator.materialize_batch_and_update_state();
-        let batch_size = batch.get_size_bytes();
-        if batch_size > 0 {
+        let uncompressed_batch_size = batch.get_size_bytes();
+        if uncompressed_batch_size > 0 {
             self.unsaved_messages = Some(batch_accumulator);
         }
+        let is_checksummed = self.config.segment.format_version >= SEGMENT_FORMAT_VERSION;
+        // Only the segment format version 2 stores the algorithm the batch is compressed with,
+        // and the batches holding the payloads compressed by the producer aren't compressed again.
+        let compression_algorithm = match is_checksummed && !compressed_by_producer {
+            true => self.compression_algorithm,
+            false => CompressionAlgorithm::None,
+        };
+        let batch = batch
+            .compress(compression_algorithm)
+            .with_error_context(|error| {
+                format!(
+                    "Failed to compress batch using: {compression_algorithm} for {self}. {error}"
+                )
+            })?;
+        let compressed_batch_size = batch.get_size_bytes();
+        let batch = match is_checksummed {
+            true => batch.with_checksum(),
+            false => batch,
+        };
+        let header_len = batch.get_header_len();
+        let batch_size = batch.get_size_bytes();
         let confirmation = match confirmation {
             Some(val) => val,
             None => self.config.segment.server_confirmation,
@@ -150,13 +184,25 @@ impl Segment {
             .with_error_context(|error| format!("Failed to save index for {self}. {error}"))?;
 
         self.last_index_position += batch_size.as_bytes_u64() as u32;
-        self.size_bytes += IggyByteSize::from(RETAINED_BATCH_HEADER_LEN);
+        self.size_bytes += IggyByteSize::from(header_len);
         self.size_of_parent_stream
-            .fetch_add(RETAINED_BATCH_HEADER_LEN, Ordering::AcqRel);
+            .fetch_add(header_len, Ordering::AcqRel);
         self.size_of_parent_topic
-            .fetch_add(RETAINED_BATCH_HEADER_LEN, Ordering::AcqRel);
+            .fetch_add(header_len, Ordering::AcqRel);
         self.size_of_parent_partition
-            .fetch_add(RETAINED_BATCH_HEADER_LEN, Ordering::AcqRel);
+            .fetch_add(header_len, Ordering::AcqRel);
+
+        // The sizes were increased by the uncompressed messages when appended, so they must reflect what's on disk.
+        let saved_by_compression = (uncompressed_batch_size - compressed_batch_size).as_bytes_u64();
+        if saved_by_compression > 0 {
+            self.size_bytes -= IggyByteSize::from(saved_by_compression);
+            self.size_of_parent_stream
+                .fetch_sub(saved_by_compression, Ordering::AcqRel);
+            self.size_of_parent_topic
+                .fetch_sub(saved_by_compression, Ordering::AcqRel);
+            self.size_of_parent_partition
+                .fetch_sub(saved_by_compression, Ordering::AcqRel);
+        }
 
         trace!(
             "Saved {} messages on disk in segment with start offset: {} for partition with ID: {}, total bytes written: {}.",
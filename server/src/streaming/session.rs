@@ -16,18 +16,26 @@
  * under the License.
  */
 
+use iggy::models::personal_access_token::PersonalAccessTokenScope;
 use iggy::models::user_info::{AtomicUserId, UserId};
+use iggy::system::handshake::Handshake;
 use std::fmt::Display;
 use std::net::SocketAddr;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::{Arc, OnceLock, RwLock};
 
 // This might be extended with more fields in the future e.g. custom name, permissions etc.
 #[derive(Debug)]
 pub struct Session {
     user_id: AtomicUserId,
+    impersonator_id: AtomicUserId,
     active: AtomicBool,
     pub client_id: u32,
     pub ip_address: SocketAddr,
+    peer_certificate: OnceLock<Vec<u8>>,
+    personal_access_token_scope: RwLock<Option<Arc<PersonalAccessTokenScope>>>,
+    protocol_features: AtomicU32,
+    replicated: bool,
 }
 
 impl Session {
@@ -36,7 +44,12 @@ impl Session {
             client_id,
             active: AtomicBool::new(true),
             user_id: AtomicUserId::new(user_id),
+            impersonator_id: AtomicUserId::new(0),
             ip_address,
+            peer_certificate: OnceLock::new(),
+            personal_access_token_scope: RwLock::new(None),
+            protocol_features: AtomicU32::new(0),
+            replicated: false,
         }
     }
 
@@ -44,6 +57,14 @@ impl Session {
         Self::new(0, user_id, ip_address)
     }
 
+    /// Creates the session applying the state entry already replicated by the leader of the cluster.
+    pub fn replicated(user_id: UserId, ip_address: SocketAddr) -> Self {
+        Self {
+            replicated: true,
+            ..Self::new(0, user_id, ip_address)
+        }
+    }
+
     pub fn from_client_id(client_id: u32, ip_address: SocketAddr) -> Self {
         Self::new(client_id, 0, ip_address)
     }
@@ -56,11 +77,62 @@ impl Session {
         self.user_id.store(user_id, Ordering::Release)
     }
 
+    /// Returns the ID of the user impersonating the current one, if any.
+    pub fn get_impersonator_id(&self) -> Option<UserId> {
+        let impersonator_id = self.impersonator_id.load(Ordering::Acquire);
+        (impersonator_id > 0).then_some(impersonator_id)
+    }
+
+    pub fn set_impersonator_id(&self, impersonator_id: UserId) {
+        self.impersonator_id
+            .store(impersonator_id, Ordering::Release)
+    }
+
+    pub fn is_impersonated(&self) -> bool {
+        self.get_impersonator_id().is_some()
+    }
+
+    /// Returns the DER encoded certificate presented by the client during the TLS handshake, if any.
+    pub fn peer_certificate(&self) -> Option<&[u8]> {
+        self.peer_certificate.get().map(Vec::as_slice)
+    }
+
+    pub fn set_peer_certificate(&self, certificate: Vec<u8>) {
+        let _ = self.peer_certificate.set(certificate);
+    }
+
+    /// Returns the scope of the personal access token used to authenticate the session, if it's restricted in any way.
+    pub fn personal_access_token_scope(&self) -> Option<Arc<PersonalAccessTokenScope>> {
+        self.personal_access_token_scope
+            .read()
+            .expect("Personal access token scope lock is poisoned")
+            .clone()
+    }
+
+    pub fn set_personal_access_token_scope(&self, scope: Option<PersonalAccessTokenScope>) {
+        *self
+            .personal_access_token_scope
+            .write()
+            .expect("Personal access token scope lock is poisoned") = scope.map(Arc::new);
+    }
+
+    /// Returns the optional features of the binary protocol negotiated by the client during the handshake.
+    pub fn get_protocol_features(&self) -> Handshake {
+        Handshake::from_flags(self.protocol_features.load(Ordering::Acquire))
+    }
+
+    pub fn set_protocol_features(&self, features: &Handshake) {
+        self.protocol_features
+            .store(features.as_flags(), Ordering::Release)
+    }
+
     pub fn set_stale(&self) {
         self.active.store(false, Ordering::Release)
     }
 
     pub fn clear_user_id(&self) {
+        self.set_impersonator_id(0);
+        self.set_personal_access_token_scope(None);
         self.set_user_id(0)
     }
 
@@ -71,11 +143,23 @@ impl Session {
     pub fn is_authenticated(&self) -> bool {
         self.get_user_id() > 0
     }
+
+    pub fn is_replicated(&self) -> bool {
+        self.replicated
+    }
 }
 
 impl Display for Session {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let user_id = self.get_user_id();
+        if let Some(impersonator_id) = self.get_impersonator_id() {
+            return write!(
+                f,
+                "client ID: {}, user ID: {}, impersonated by user ID: {}, IP address: {}",
+                self.client_id, user_id, impersonator_id, self.ip_address
+            );
+        }
+
         if user_id > 0 {
             return write!(
                 f,
@@ -19,8 +19,16 @@
 use super::persistence::persister::PersisterKind;
 use crate::configs::system::SystemConfig;
 use crate::state::system::{PartitionState, StreamState, TopicState};
+use crate::streaming::consumer_offsets::file_storage::FileConsumerOffsetStorage;
+use crate::streaming::consumer_offsets::log_storage::LogConsumerOffsetStorage;
+#[cfg(feature = "redis")]
+use crate::streaming::consumer_offsets::redis_storage::RedisConsumerOffsetStorage;
+use crate::streaming::consumer_offsets::ConsumerOffsetsBackend;
+use crate::streaming::partitions::index_rebuilds::IndexRebuilds;
+use crate::streaming::partitions::loader::PartitionsLoader;
 use crate::streaming::partitions::partition::{ConsumerOffset, Partition};
 use crate::streaming::partitions::storage::FilePartitionStorage;
+use crate::streaming::segments::tiered_storage::TieredStorage;
 use crate::streaming::streams::storage::FileStreamStorage;
 use crate::streaming::streams::stream::Stream;
 use crate::streaming::systems::info::SystemInfo;
@@ -34,6 +42,8 @@ use mockall::automock;
 use std::fmt::Debug;
 use std::future::Future;
 use std::sync::Arc;
+#[cfg(not(feature = "redis"))]
+use tracing::warn;
 
 macro_rules! forward_async_methods {
     (
@@ -83,6 +93,16 @@ pub enum PartitionStorageKind {
     Mock(MockPartitionStorage),
 }
 
+#[derive(Debug)]
+pub enum ConsumerOffsetStorageKind {
+    File(FileConsumerOffsetStorage),
+    Log(LogConsumerOffsetStorage),
+    #[cfg(feature = "redis")]
+    Redis(RedisConsumerOffsetStorage),
+    #[cfg(test)]
+    Mock(MockConsumerOffsetStorage),
+}
+
 #[cfg_attr(test, automock)]
 pub trait SystemInfoStorage: Send {
     fn load(&self) -> impl Future<Output = Result<SystemInfo, IggyError>> + Send;
@@ -121,10 +141,17 @@ pub trait PartitionStorage: Send {
     fn save(&self, partition: &mut Partition)
         -> impl Future<Output = Result<(), IggyError>> + Send;
     fn delete(&self, partition: &Partition) -> impl Future<Output = Result<(), IggyError>> + Send;
+}
+
+/// Persists the consumer offsets of the partitions, the `path` is the offsets directory
+/// of the partition for the given consumer kind, which identifies them in every backend.
+#[cfg_attr(test, automock)]
+pub trait ConsumerOffsetStorage: Send {
     fn save_consumer_offset(
         &self,
-        offset: u64,
         path: &str,
+        consumer_id: u32,
+        offset: u64,
     ) -> impl Future<Output = Result<(), IggyError>> + Send;
     fn load_consumer_offsets(
         &self,
@@ -138,7 +165,10 @@ pub trait PartitionStorage: Send {
     fn delete_consumer_offset(
         &self,
         path: &str,
+        consumer_id: u32,
     ) -> impl Future<Output = Result<(), IggyError>> + Send;
+    /// Persists the offsets buffered by the backend, if any.
+    fn flush(&self) -> impl Future<Output = Result<(), IggyError>> + Send;
 }
 
 #[derive(Debug)]
@@ -147,7 +177,11 @@ pub struct SystemStorage {
     pub stream: Arc<StreamStorageKind>,
     pub topic: Arc<TopicStorageKind>,
     pub partition: Arc<PartitionStorageKind>,
+    pub consumer_offsets: Arc<ConsumerOffsetStorageKind>,
     pub persister: Arc<PersisterKind>,
+    pub tiered_storage: Option<Arc<TieredStorage>>,
+    pub partitions_loader: Arc<PartitionsLoader>,
+    pub index_rebuilds: Arc<IndexRebuilds>,
 }
 
 impl SystemStorage {
@@ -159,10 +193,14 @@ impl SystemStorage {
             ))),
             stream: Arc::new(StreamStorageKind::File(FileStreamStorage)),
             topic: Arc::new(TopicStorageKind::File(FileTopicStorage)),
-            partition: Arc::new(PartitionStorageKind::File(FilePartitionStorage::new(
-                persister.clone(),
-            ))),
+            partition: Arc::new(PartitionStorageKind::File(FilePartitionStorage)),
+            consumer_offsets: Arc::new(ConsumerOffsetStorageKind::new(&config, persister.clone())),
             persister,
+            tiered_storage: None,
+            partitions_loader: Arc::new(PartitionsLoader::new(config.recovery.startup_concurrency)),
+            index_rebuilds: Arc::new(IndexRebuilds::new(
+                config.recovery.index_rebuild_concurrency,
+            )),
         }
     }
 }
@@ -196,13 +234,98 @@ impl PartitionStorageKind {
             -> Result<(), IggyError>;
         async fn save(&self, partition: &mut Partition) -> Result<(), IggyError>;
         async fn delete(&self, partition: &Partition) -> Result<(), IggyError>;
-        async fn save_consumer_offset(&self, offset: u64, path: &str) -> Result<(), IggyError>;
-        async fn load_consumer_offsets(
-            &self,
-            kind: ConsumerKind,
-            path: &str
-        ) -> Result<Vec<ConsumerOffset>, IggyError>;
-        async fn delete_consumer_offsets(&self, path: &str) -> Result<(), IggyError>;
-        async fn delete_consumer_offset(&self, path: &str) -> Result<(), IggyError>;
+    }
+}
+
+impl ConsumerOffsetStorageKind {
+    pub fn new(config: &SystemConfig, persister: Arc<PersisterKind>) -> Self {
+        match config.consumer_offsets.backend {
+            ConsumerOffsetsBackend::File => Self::File(FileConsumerOffsetStorage::new(persister)),
+            ConsumerOffsetsBackend::Log => {
+                // The buffered offsets would be lost on crash, so with fsync enforced they are written immediately.
+                let batch_size = match config.partition.enforce_fsync {
+                    true => 1,
+                    false => config.consumer_offsets.log.batch_size,
+                };
+                Self::Log(LogConsumerOffsetStorage::new(persister, batch_size))
+            }
+            #[cfg(feature = "redis")]
+            ConsumerOffsetsBackend::Redis => Self::Redis(RedisConsumerOffsetStorage::new(
+                &config.consumer_offsets.redis,
+            )),
+            #[cfg(not(feature = "redis"))]
+            ConsumerOffsetsBackend::Redis => {
+                warn!("Redis consumer offsets backend requires the server to be built with the 'redis' feature, using the file backend.");
+                Self::File(FileConsumerOffsetStorage::new(persister))
+            }
+        }
+    }
+
+    pub async fn save_consumer_offset(
+        &self,
+        path: &str,
+        consumer_id: u32,
+        offset: u64,
+    ) -> Result<(), IggyError> {
+        match self {
+            Self::File(s) => s.save_consumer_offset(path, consumer_id, offset).await,
+            Self::Log(s) => s.save_consumer_offset(path, consumer_id, offset).await,
+            #[cfg(feature = "redis")]
+            Self::Redis(s) => s.save_consumer_offset(path, consumer_id, offset).await,
+            #[cfg(test)]
+            Self::Mock(s) => s.save_consumer_offset(path, consumer_id, offset).await,
+        }
+    }
+
+    pub async fn load_consumer_offsets(
+        &self,
+        kind: ConsumerKind,
+        path: &str,
+    ) -> Result<Vec<ConsumerOffset>, IggyError> {
+        match self {
+            Self::File(s) => s.load_consumer_offsets(kind, path).await,
+            Self::Log(s) => s.load_consumer_offsets(kind, path).await,
+            #[cfg(feature = "redis")]
+            Self::Redis(s) => s.load_consumer_offsets(kind, path).await,
+            #[cfg(test)]
+            Self::Mock(s) => s.load_consumer_offsets(kind, path).await,
+        }
+    }
+
+    pub async fn delete_consumer_offsets(&self, path: &str) -> Result<(), IggyError> {
+        match self {
+            Self::File(s) => s.delete_consumer_offsets(path).await,
+            Self::Log(s) => s.delete_consumer_offsets(path).await,
+            #[cfg(feature = "redis")]
+            Self::Redis(s) => s.delete_consumer_offsets(path).await,
+            #[cfg(test)]
+            Self::Mock(s) => s.delete_consumer_offsets(path).await,
+        }
+    }
+
+    pub async fn delete_consumer_offset(
+        &self,
+        path: &str,
+        consumer_id: u32,
+    ) -> Result<(), IggyError> {
+        match self {
+            Self::File(s) => s.delete_consumer_offset(path, consumer_id).await,
+            Self::Log(s) => s.delete_consumer_offset(path, consumer_id).await,
+            #[cfg(feature = "redis")]
+            Self::Redis(s) => s.delete_consumer_offset(path, consumer_id).await,
+            #[cfg(test)]
+            Self::Mock(s) => s.delete_consumer_offset(path, consumer_id).await,
+        }
+    }
+
+    pub async fn flush(&self) -> Result<(), IggyError> {
+        match self {
+            Self::File(s) => s.flush().await,
+            Self::Log(s) => s.flush().await,
+            #[cfg(feature = "redis")]
+            Self::Redis(s) => s.flush().await,
+            #[cfg(test)]
+            Self::Mock(s) => s.flush().await,
+        }
     }
 }
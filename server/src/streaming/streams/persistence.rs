@@ -73,7 +73,7 @@ impl Stream {
 
     pub async fn purge(&self) -> Result<(), IggyError> {
         for topic in self.get_topics() {
-            topic.purge().await.with_error_context(|error| {
+            topic.purge(false, None).await.with_error_context(|error| {
                 format!("{COMPONENT} (error: {error}) - failed to purge topic: {topic} in stream: {self}")
             })?;
         }
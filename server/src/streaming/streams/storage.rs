@@ -67,6 +67,11 @@ impl StreamStorage for FileStreamStorage {
 
             let topic_id = topic_id.unwrap();
             let topic_state = state.topics.get(&topic_id);
+            if topic_state.is_none() && stream.config.recovery.read_only {
+                warn!("Topic with ID: '{topic_id}' for stream with ID: '{}' was not found in state, but exists on disk and will be skipped in read-only mode.", stream.stream_id);
+                continue;
+            }
+
             if topic_state.is_none() {
                 let stream_id = stream.stream_id;
                 error!("Topic with ID: '{topic_id}' for stream with ID: '{stream_id}' was not found in state, but exists on disk and will be removed.");
@@ -107,6 +112,8 @@ impl StreamStorage for FileStreamStorage {
                 "All topics for stream with ID: '{}' found on disk were found in state.",
                 stream.stream_id
             );
+        } else if stream.config.recovery.read_only {
+            warn!("Topics with IDs: '{missing_ids:?}' for stream with ID: '{}' were not found on disk and will be skipped in read-only mode.", stream.stream_id);
         } else {
             error!("Topics with IDs: '{missing_ids:?}' for stream with ID: '{}' were not found on disk.", stream.stream_id);
             if !stream.config.recovery.recreate_missing_state {
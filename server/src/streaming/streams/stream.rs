@@ -20,10 +20,13 @@ use crate::configs::system::SystemConfig;
 use crate::streaming::storage::SystemStorage;
 use crate::streaming::topics::topic::Topic;
 use ahash::AHashMap;
+use iggy::error::IggyError;
+use iggy::models::metadata::ResourceMetadata;
+use iggy::models::stream::StreamQuota;
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::timestamp::IggyTimestamp;
 use std::fmt::Display;
-use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
 use std::sync::Arc;
 
 #[derive(Debug)]
@@ -33,6 +36,10 @@ pub struct Stream {
     pub path: String,
     pub topics_path: String,
     pub created_at: IggyTimestamp,
+    pub metadata: ResourceMetadata,
+    pub quota: StreamQuota,
+    pub namespace_id: Option<u32>,
+    pub soft_quota_exceeded: AtomicBool,
     pub current_topic_id: AtomicU32,
     pub size_bytes: Arc<AtomicU64>,
     pub messages_count: Arc<AtomicU64>,
@@ -76,12 +83,42 @@ impl Stream {
             topics_ids: AHashMap::new(),
             storage,
             created_at: IggyTimestamp::now(),
+            metadata: ResourceMetadata::default(),
+            quota: StreamQuota::default(),
+            namespace_id: None,
+            soft_quota_exceeded: AtomicBool::new(false),
         }
     }
 
     pub fn get_size(&self) -> IggyByteSize {
         IggyByteSize::from(self.size_bytes.load(Ordering::SeqCst))
     }
+
+    /// Checks whether appending `batch_size` bytes fits into the hard quota of the stream.
+    /// Returns `Some(size)` with the resulting size when the soft quota has just been crossed,
+    /// so that the caller emits the warning only once per crossing.
+    pub fn check_quota(&self, batch_size: u64) -> Result<Option<u64>, IggyError> {
+        let current_size = self.size_bytes.load(Ordering::SeqCst);
+        let size = current_size + batch_size;
+        if self.quota.is_hard_limit_exceeded(size) {
+            return Err(IggyError::StreamQuotaExceeded(
+                self.stream_id,
+                IggyByteSize::from(current_size),
+                self.quota.hard_limit,
+            ));
+        }
+
+        if !self.quota.is_soft_limit_exceeded(size) {
+            self.soft_quota_exceeded.store(false, Ordering::SeqCst);
+            return Ok(None);
+        }
+
+        if self.soft_quota_exceeded.swap(true, Ordering::SeqCst) {
+            return Ok(None);
+        }
+
+        Ok(Some(size))
+    }
 }
 
 impl Display for Stream {
@@ -124,4 +161,32 @@ mod tests {
         assert_eq!(stream.topics_path, topics_path);
         assert!(stream.topics.is_empty());
     }
+
+    #[test]
+    fn should_enforce_quota_and_report_soft_limit_crossing_once() {
+        let config = Arc::new(SystemConfig::default());
+        let storage = Arc::new(SystemStorage::new(
+            config.clone(),
+            Arc::new(PersisterKind::FileWithSync(FileWithSyncPersister {})),
+        ));
+        let mut stream = Stream::create(1, "test", config, storage);
+        stream.quota = StreamQuota {
+            soft_limit: IggyByteSize::from(100_u64),
+            hard_limit: IggyByteSize::from(200_u64),
+        };
+
+        assert_eq!(stream.check_quota(50).unwrap(), None);
+        stream.size_bytes.store(90, Ordering::SeqCst);
+        assert_eq!(stream.check_quota(50).unwrap(), Some(140));
+        assert_eq!(stream.check_quota(50).unwrap(), None);
+        assert!(matches!(
+            stream.check_quota(111),
+            Err(IggyError::StreamQuotaExceeded(1, _, _))
+        ));
+
+        stream.size_bytes.store(0, Ordering::SeqCst);
+        assert_eq!(stream.check_quota(50).unwrap(), None);
+        stream.size_bytes.store(90, Ordering::SeqCst);
+        assert_eq!(stream.check_quota(50).unwrap(), Some(140));
+    }
 }
@@ -23,7 +23,6 @@ use error_set::ErrContext;
 use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::error::IggyError;
 use iggy::identifier::{IdKind, Identifier};
-use iggy::locking::IggySharedMutFn;
 use iggy::utils::expiry::IggyExpiry;
 use iggy::utils::topic_size::MaxTopicSize;
 use std::sync::atomic::Ordering;
@@ -34,18 +33,8 @@ impl Stream {
         self.topics.len() as u32
     }
 
-    #[allow(clippy::too_many_arguments)]
-    pub async fn create_topic(
-        &mut self,
-        topic_id: Option<u32>,
-        name: &str,
-        partitions_count: u32,
-        message_expiry: IggyExpiry,
-        compression_algorithm: CompressionAlgorithm,
-        max_topic_size: MaxTopicSize,
-        replication_factor: u8,
-    ) -> Result<u32, IggyError> {
-        let max_topic_size = Topic::get_max_topic_size(max_topic_size, &self.config)?;
+    /// Returns the ID of the new topic with the given name, either the given one or the next available one.
+    pub fn get_new_topic_id(&self, topic_id: Option<u32>, name: &str) -> Result<u32, IggyError> {
         if self.topics_ids.contains_key(name) {
             return Err(IggyError::TopicNameAlreadyExists(
                 name.to_owned(),
@@ -74,6 +63,22 @@ impl Stream {
             return Err(IggyError::TopicIdAlreadyExists(id, self.stream_id));
         }
 
+        Ok(id)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create_topic(
+        &mut self,
+        topic_id: Option<u32>,
+        name: &str,
+        partitions_count: u32,
+        message_expiry: IggyExpiry,
+        compression_algorithm: CompressionAlgorithm,
+        max_topic_size: MaxTopicSize,
+        replication_factor: u8,
+    ) -> Result<u32, IggyError> {
+        let max_topic_size = Topic::get_max_topic_size(max_topic_size, &self.config)?;
+        let id = self.get_new_topic_id(topic_id, name)?;
         let topic = Topic::create(
             self.stream_id,
             id,
@@ -107,7 +112,9 @@ impl Stream {
         compression_algorithm: CompressionAlgorithm,
         max_topic_size: MaxTopicSize,
         replication_factor: u8,
+        max_segments: Option<u32>,
     ) -> Result<(), IggyError> {
+        let topic_config_message_expiry = message_expiry;
         let message_expiry = Topic::get_message_expiry(message_expiry, &self.config);
         let max_topic_size = Topic::get_max_topic_size(max_topic_size, &self.config)?;
         let topic_id;
@@ -146,14 +153,14 @@ impl Stream {
             topic.name = name.to_owned();
             topic.message_expiry = message_expiry;
             topic.compression_algorithm = compression_algorithm;
-            for partition in topic.partitions.values_mut() {
-                let mut partition = partition.write().await;
-                partition.message_expiry = message_expiry;
-                for segment in partition.segments.iter_mut() {
-                    segment.message_expiry = message_expiry;
-                }
-            }
+            topic.topic_config = topic
+                .topic_config
+                .with_topic_settings(topic_config_message_expiry, compression_algorithm);
+            topic.apply_config_to_partitions().await;
             topic.max_topic_size = max_topic_size;
+            if let Some(max_segments) = max_segments {
+                topic.max_segments = max_segments;
+            }
             topic.replication_factor = replication_factor;
             topic.persist().await.with_error_context(|error| {
                 format!("{COMPONENT} (error: {error}) - failed to persist topic: {topic}")
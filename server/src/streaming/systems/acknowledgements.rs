@@ -0,0 +1,333 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::state::command::EntryCommand;
+use crate::streaming::polling_consumer::PollingConsumer;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use crate::streaming::systems::COMPONENT;
+use crate::streaming::topics::topic::Topic;
+use error_set::ErrContext;
+use iggy::consumer::{Consumer, ConsumerKind};
+use iggy::consumer_groups::update_consumer_group_visibility_timeout::UpdateConsumerGroupVisibilityTimeout;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::locking::IggySharedMutFn;
+use iggy::messages::message_filter::MessageFilter;
+use iggy::messages::poll_messages::{IsolationLevel, PollingStrategy};
+use iggy::models::messages::PolledMessages;
+use iggy::utils::duration::IggyDuration;
+use iggy::utils::timestamp::IggyTimestamp;
+use tracing::{info, trace};
+
+impl System {
+    /// Configures the visibility timeout of the consumer group, returning the resolved value, `None` if the acknowledgement mode is disabled.
+    pub async fn update_consumer_group_visibility_timeout(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+        visibility_timeout: IggyDuration,
+    ) -> Result<Option<IggyDuration>, IggyError> {
+        self.ensure_authenticated(session)?;
+        let topic = self.find_topic(session, stream_id, topic_id)
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream ID: {stream_id}, topic_id: {topic_id}"))?;
+        self.permissioner.create_consumer_group(
+            session.get_user_id(),
+            topic.stream_id,
+            topic.topic_id,
+        ).with_error_context(|error| format!("{COMPONENT} (error: {error}) - permission denied to update consumer group visibility timeout for user {} on stream ID: {}, topic ID: {}", session.get_user_id(), topic.stream_id, topic.topic_id))?;
+
+        let consumer_group = topic
+            .get_consumer_group(group_id)
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - consumer group not found for group_id: {group_id}")
+            })?;
+        self.replicate(session, || {
+            EntryCommand::UpdateConsumerGroupVisibilityTimeout(
+                UpdateConsumerGroupVisibilityTimeout {
+                    stream_id: stream_id.clone(),
+                    topic_id: topic_id.clone(),
+                    group_id: group_id.clone(),
+                    visibility_timeout,
+                },
+            )
+        })
+        .await?;
+        let visibility_timeout = (visibility_timeout.as_micros() > 0).then_some(visibility_timeout);
+        let group_id = {
+            let mut consumer_group = consumer_group.write().await;
+            consumer_group.visibility_timeout = visibility_timeout;
+            consumer_group.group_id
+        };
+
+        // The unacknowledged messages are polled again after the committed offset, once the acknowledgement mode is disabled.
+        if visibility_timeout.is_none() {
+            for partition in topic.partitions.values() {
+                partition
+                    .read()
+                    .await
+                    .delete_in_flight_messages(group_id)
+                    .await
+                    .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to delete in-flight messages, consumer group ID: {group_id}"))?;
+            }
+        }
+        info!(
+            "Updated visibility timeout: {:?} of consumer group with ID: {} for topic with ID: {} and stream with ID: {}.",
+            visibility_timeout, group_id, topic.topic_id, topic.stream_id
+        );
+        Ok(visibility_timeout)
+    }
+
+    /// Acknowledges the messages polled by the consumer group having the visibility timeout configured.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn ack_messages(
+        &self,
+        session: &Session,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        offsets: &[u64],
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        let topic = self.find_topic(session, stream_id, topic_id).with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream ID: {stream_id}, topic_id: {topic_id}"))?;
+        self.permissioner
+            .poll_messages(session.get_user_id(), topic.stream_id, topic.topic_id)
+            .with_error_context(|error| format!(
+                "{COMPONENT} (error: {error}) - permission denied to ack messages for user {} on stream ID: {}, topic ID: {}",
+                session.get_user_id(),
+                topic.stream_id,
+                topic.topic_id
+            ))?;
+
+        let Some((polling_consumer, partition_id)) = topic
+            .resolve_consumer_with_partition_id(consumer, session.client_id, partition_id, false)
+            .await
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to resolve consumer with partition id, consumer: {consumer}, client ID: {}, partition ID: {:?}", session.client_id, partition_id))? else {
+            return Ok(());
+        };
+        let PollingConsumer::ConsumerGroup(group_id, _) = polling_consumer else {
+            return Err(IggyError::InvalidConsumerGroupId);
+        };
+
+        if topic
+            .get_consumer_group_by_id(group_id)?
+            .read()
+            .await
+            .visibility_timeout
+            .is_none()
+        {
+            return Err(IggyError::AckModeNotEnabled(group_id, topic.topic_id));
+        }
+
+        self.ack_in_flight_messages(topic, group_id, partition_id, offsets)
+            .await
+    }
+
+    /// Returns the expired in-flight messages of the consumer group if there are any, otherwise the ones following
+    /// the already delivered messages, along with the offsets of the messages being redelivered.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) async fn poll_in_flight_messages(
+        &self,
+        topic: &Topic,
+        polling_consumer: PollingConsumer,
+        group_id: u32,
+        partition_id: u32,
+        count: u32,
+        isolation_level: IsolationLevel,
+        filter: Option<&MessageFilter>,
+    ) -> Result<(PolledMessages, Vec<u64>), IggyError> {
+        let partition = topic.get_partition(partition_id)?;
+        let (expired_offsets, next_offset) = {
+            let partition = partition.read().await;
+            let in_flight_messages = partition.get_in_flight_messages(group_id);
+            (
+                in_flight_messages.get_expired(IggyTimestamp::now().as_micros(), count),
+                in_flight_messages.get_next_offset(),
+            )
+        };
+
+        if expired_offsets.is_empty() {
+            let polled_messages = topic
+                .get_messages(
+                    polling_consumer,
+                    partition_id,
+                    PollingStrategy::offset(next_offset),
+                    count,
+                    isolation_level,
+                    filter,
+                )
+                .await?;
+            return Ok((polled_messages, expired_offsets));
+        }
+
+        // The expired offsets are fetched in the contiguous ranges, the already delivered messages don't need to be filtered again.
+        let mut ranges: Vec<(u64, u32)> = Vec::new();
+        for offset in &expired_offsets {
+            match ranges.last_mut() {
+                Some((start_offset, count)) if *start_offset + *count as u64 == *offset => {
+                    *count += 1
+                }
+                _ => ranges.push((*offset, 1)),
+            }
+        }
+
+        let mut redelivered_messages = PolledMessages {
+            partition_id,
+            current_offset: 0,
+            remaining_messages: 0,
+            partition_epoch: 0,
+            throttle_time_ms: 0,
+            topic_delete_at: topic.get_delete_at_micros(),
+            messages: Vec::with_capacity(expired_offsets.len()),
+            gaps: Vec::new(),
+            chunk_sizes: Vec::new(),
+        };
+        for (start_offset, count) in ranges {
+            let polled_messages = topic
+                .get_messages(
+                    polling_consumer,
+                    partition_id,
+                    PollingStrategy::offset(start_offset),
+                    count,
+                    IsolationLevel::ReadUncommitted,
+                    None,
+                )
+                .await?;
+            redelivered_messages.current_offset = polled_messages.current_offset;
+            redelivered_messages.remaining_messages = polled_messages.remaining_messages;
+            redelivered_messages.partition_epoch = polled_messages.partition_epoch;
+            redelivered_messages.messages.extend(
+                polled_messages
+                    .messages
+                    .into_iter()
+                    .filter(|message| expired_offsets.binary_search(&message.offset).is_ok()),
+            );
+        }
+        trace!(
+            "Redelivering {} in-flight messages of consumer group with ID: {group_id} from partition with ID: {partition_id} for topic with ID: {}.",
+            redelivered_messages.messages.len(),
+            topic.topic_id
+        );
+        Ok((redelivered_messages, expired_offsets))
+    }
+
+    /// Marks the messages returned to the member as in flight until the visibility timeout expires,
+    /// the dropped offsets (e.g. dead-lettered or no longer available) are acknowledged.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) async fn track_in_flight_messages(
+        &self,
+        topic: &Topic,
+        group_id: u32,
+        visibility_timeout: IggyDuration,
+        polled_messages: &PolledMessages,
+        last_polled_offset: Option<u64>,
+        dropped_offsets: &[u64],
+    ) -> Result<(), IggyError> {
+        if polled_messages.messages.is_empty()
+            && last_polled_offset.is_none()
+            && dropped_offsets.is_empty()
+        {
+            return Ok(());
+        }
+
+        let partition_id = polled_messages.partition_id;
+        {
+            let partition = topic.get_partition(partition_id)?;
+            let partition = partition.read().await;
+            let offsets = polled_messages
+                .messages
+                .iter()
+                .map(|message| message.offset)
+                .collect::<Vec<_>>();
+            let deadline = IggyTimestamp::now().as_micros() + visibility_timeout.as_micros();
+            partition.get_in_flight_messages(group_id).deliver(
+                &offsets,
+                last_polled_offset,
+                deadline,
+            );
+        }
+        self.ack_in_flight_messages(topic, group_id, partition_id, dropped_offsets)
+            .await
+    }
+
+    /// Stops tracking the acknowledged messages, committing the offset of the consumer group up to the first unacknowledged one.
+    pub(crate) async fn ack_in_flight_messages(
+        &self,
+        topic: &Topic,
+        group_id: u32,
+        partition_id: u32,
+        offsets: &[u64],
+    ) -> Result<(), IggyError> {
+        let committed_offset = {
+            let partition = topic.get_partition(partition_id)?;
+            let partition = partition.read().await;
+            let committed_offset = {
+                let mut in_flight_messages = partition.get_in_flight_messages(group_id);
+                in_flight_messages.ack(offsets);
+                in_flight_messages.get_committed_offset()
+            };
+            partition
+                .persist_in_flight_messages()
+                .await
+                .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to persist in-flight messages, consumer group ID: {group_id}, partition ID: {partition_id}"))?;
+
+            let stored_offset = partition
+                .consumer_group_offsets
+                .get(&group_id)
+                .map(|consumer_offset| consumer_offset.offset);
+            let Some(offset) = committed_offset.filter(|offset| stored_offset < Some(*offset))
+            else {
+                return Ok(());
+            };
+
+            partition
+                .store_offset(ConsumerKind::ConsumerGroup, group_id, offset)
+                .await
+                .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to commit acknowledged offset: {offset}, consumer group ID: {group_id}, partition ID: {partition_id}"))?;
+            offset
+        };
+
+        topic
+            .get_consumer_group_by_id(group_id)?
+            .write()
+            .await
+            .deliveries
+            .forget_below(partition_id, committed_offset + 1);
+        Ok(())
+    }
+
+    /// Makes the negatively acknowledged messages available for the immediate redelivery.
+    pub(crate) async fn release_in_flight_messages(
+        &self,
+        topic: &Topic,
+        group_id: u32,
+        partition_id: u32,
+        offsets: &[u64],
+    ) -> Result<(), IggyError> {
+        let partition = topic.get_partition(partition_id)?;
+        let partition = partition.read().await;
+        partition.get_in_flight_messages(group_id).release(offsets);
+        partition
+            .persist_in_flight_messages()
+            .await
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to persist in-flight messages, consumer group ID: {group_id}, partition ID: {partition_id}"))
+    }
+}
@@ -0,0 +1,74 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use crate::streaming::systems::COMPONENT;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::locking::IggySharedMutFn;
+use iggy::models::messages_aggregate::{MessagesAggregate, MessagesBucket};
+use iggy::utils::duration::IggyDuration;
+use iggy::utils::timestamp::IggyTimestamp;
+
+impl System {
+    /// Aggregates the messages of the partition, or all the partitions of the topic, into the time buckets
+    /// covering the `[from, to)` range, using only the segment indexes.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn aggregate_messages(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        from: IggyTimestamp,
+        to: IggyTimestamp,
+        bucket: IggyDuration,
+    ) -> Result<Vec<MessagesBucket>, IggyError> {
+        self.ensure_authenticated(session)?;
+        let topic = self.find_topic(session, stream_id, topic_id)
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic with ID: {topic_id} was not found in stream with ID: {stream_id}"))?;
+        self.permissioner.poll_messages(
+            session.get_user_id(),
+            topic.stream_id,
+            topic.topic_id,
+        ).with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - permission denied to aggregate messages for user with ID: {} in topic with ID: {topic_id} and stream with ID: {stream_id}",
+                session.get_user_id(),
+            )
+        })?;
+
+        let partitions = match partition_id {
+            Some(partition_id) => vec![topic.get_partition(partition_id).with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - partition with ID: {partition_id} was not found in topic with ID: {topic_id}")
+            })?],
+            None => topic.get_partitions(),
+        };
+        let mut aggregate = MessagesAggregate::new(from, to, bucket);
+        for partition in partitions {
+            partition
+                .read()
+                .await
+                .aggregate_messages(&mut aggregate)
+                .await?;
+        }
+        Ok(aggregate.into_buckets())
+    }
+}
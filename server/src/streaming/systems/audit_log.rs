@@ -0,0 +1,340 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::{SharedSystem, System};
+use crate::streaming::systems::COMPONENT;
+use error_set::ErrContext;
+use flume::{Receiver, Sender};
+use iggy::error::IggyError;
+use iggy::models::audit_entry::{AuditAction, AuditEntry};
+use iggy::models::user_info::UserId;
+use iggy::utils::timestamp::IggyTimestamp;
+use std::collections::VecDeque;
+use std::path::Path;
+use std::sync::Mutex;
+use tokio::fs::{File, OpenOptions};
+use tokio::io::AsyncWriteExt;
+use tracing::{error, info, warn};
+
+const AUDIT_LOG_FILE: &str = "audit.log";
+
+/// The most recent entries of the audit log kept in memory, along with the queue of the entries
+/// waiting to be appended to the audit log file by the writer task.
+#[derive(Debug)]
+pub struct AuditLog {
+    recent: Mutex<RecentEntries>,
+    capacity: usize,
+    sender: Sender<AuditEntry>,
+    receiver: Receiver<AuditEntry>,
+}
+
+#[derive(Debug)]
+struct RecentEntries {
+    next_id: u64,
+    entries: VecDeque<AuditEntry>,
+}
+
+impl System {
+    /// Loads the most recent entries from the current audit log file and resumes the numbering of the entries.
+    pub(crate) async fn init_audit_log(&mut self) -> Result<(), IggyError> {
+        if !self.config.audit_log.enabled {
+            info!("Audit log is disabled.");
+            return Ok(());
+        }
+
+        let path = self.config.get_audit_log_path();
+        if !Path::new(&path).exists() && tokio::fs::create_dir_all(&path).await.is_err() {
+            return Err(IggyError::CannotCreateBaseDirectory(path));
+        }
+
+        let capacity = self.config.audit_log.recent_entries as usize;
+        let mut entries = VecDeque::with_capacity(capacity);
+        let file_path = format!("{path}/{AUDIT_LOG_FILE}");
+        if Path::new(&file_path).exists() {
+            let data = tokio::fs::read_to_string(&file_path)
+                .await
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to read audit log file at path: {file_path}")
+                })
+                .map_err(|_| IggyError::CannotReadFile)?;
+            for line in data.lines().filter(|line| !line.is_empty()) {
+                // The last line might be incomplete if the server crashed while appending it.
+                let Ok(entry) = serde_json::from_str::<AuditEntry>(line) else {
+                    warn!("Skipping invalid audit log entry in file: {file_path}.");
+                    continue;
+                };
+                if entries.len() == capacity {
+                    entries.pop_front();
+                }
+                entries.push_back(entry);
+            }
+        }
+
+        let last_id = match entries.back() {
+            Some(entry) => entry.id,
+            None => get_last_rotated_id(&path).await?,
+        };
+        let (sender, receiver) = flume::unbounded();
+        info!(
+            "Audit log is enabled, loaded {} recent entries, the entries will be appended to: {file_path}.",
+            entries.len()
+        );
+        self.audit_log = Some(AuditLog {
+            recent: Mutex::new(RecentEntries {
+                next_id: last_id + 1,
+                entries,
+            }),
+            capacity,
+            sender,
+            receiver,
+        });
+        Ok(())
+    }
+
+    /// Records the action successfully performed by the authenticated user of the session.
+    pub(crate) fn audit(&self, session: &Session, action: AuditAction, resource: String) {
+        self.record_audit_entry(session.get_user_id(), Some(session), action, resource, true);
+    }
+
+    /// Records the login attempt, either successful or not, the user ID is 0 if the user couldn't be authenticated.
+    pub(crate) fn audit_login(
+        &self,
+        session: Option<&Session>,
+        user_id: UserId,
+        action: AuditAction,
+        resource: String,
+        succeeded: bool,
+    ) {
+        self.record_audit_entry(user_id, session, action, resource, succeeded);
+    }
+
+    /// Returns up to `count` most recent entries kept in memory, in chronological order,
+    /// optionally filtered by the user who performed the action and the kind of the action.
+    pub fn get_audit_log(
+        &self,
+        session: &Session,
+        count: u32,
+        user_id: Option<UserId>,
+        action: Option<AuditAction>,
+    ) -> Result<Vec<AuditEntry>, IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .get_audit_log(session.get_user_id())
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to get audit log for user with id: {}",
+                    session.get_user_id()
+                )
+            })?;
+        let Some(audit_log) = &self.audit_log else {
+            warn!("Audit log is disabled, session: {session}.");
+            return Err(IggyError::FeatureUnavailable);
+        };
+
+        let recent = audit_log.recent.lock().unwrap();
+        let mut entries = recent
+            .entries
+            .iter()
+            .rev()
+            .filter(|entry| user_id.is_none_or(|user_id| entry.user_id == user_id))
+            .filter(|entry| action.is_none_or(|action| entry.action == action))
+            .take(count as usize)
+            .cloned()
+            .collect::<Vec<_>>();
+        entries.reverse();
+        Ok(entries)
+    }
+
+    fn record_audit_entry(
+        &self,
+        user_id: UserId,
+        session: Option<&Session>,
+        action: AuditAction,
+        resource: String,
+        succeeded: bool,
+    ) {
+        let Some(audit_log) = &self.audit_log else {
+            return;
+        };
+
+        // The IDs are assigned and the entries are enqueued under the lock, so that they're appended in order.
+        let mut recent = audit_log.recent.lock().unwrap();
+        let entry = AuditEntry {
+            id: recent.next_id,
+            timestamp: IggyTimestamp::now(),
+            user_id,
+            client_id: session.map(|session| session.client_id).unwrap_or_default(),
+            ip_address: session
+                .map(|session| session.ip_address.to_string())
+                .unwrap_or_default(),
+            action,
+            resource,
+            succeeded,
+        };
+        recent.next_id += 1;
+        if recent.entries.len() == audit_log.capacity {
+            recent.entries.pop_front();
+        }
+        recent.entries.push_back(entry.clone());
+        if let Err(error) = audit_log.sender.send(entry) {
+            error!("{COMPONENT} (error: {error}) - failed to enqueue the audit log entry.");
+        }
+    }
+}
+
+/// Returns the ID of the last entry of the most recently rotated audit log file, encoded in its name, or 0 if there is none.
+async fn get_last_rotated_id(path: &str) -> Result<u64, IggyError> {
+    let mut directory = tokio::fs::read_dir(path)
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to read audit log directory at path: {path}"
+            )
+        })
+        .map_err(|_| IggyError::CannotReadFile)?;
+    let mut last_id = 0;
+    while let Ok(Some(file)) = directory.next_entry().await {
+        let name = file.file_name();
+        let Some(id) = name
+            .to_str()
+            .and_then(|name| name.strip_prefix(AUDIT_LOG_FILE))
+            .and_then(|suffix| suffix.strip_prefix('.'))
+            .and_then(|id| id.parse::<u64>().ok())
+        else {
+            continue;
+        };
+        last_id = last_id.max(id);
+    }
+    Ok(last_id)
+}
+
+/// Appends the entries as JSON lines to the current audit log file and rotates it once it exceeds the size limit.
+struct AuditLogWriter {
+    file_path: String,
+    file: File,
+    size: u64,
+    max_file_size: u64,
+}
+
+impl AuditLogWriter {
+    async fn open(file_path: String, max_file_size: u64) -> Result<Self, IggyError> {
+        let file = open_file(&file_path).await?;
+        let size = file
+            .metadata()
+            .await
+            .map_err(|_| IggyError::CannotReadFileMetadata)?
+            .len();
+        Ok(Self {
+            file_path,
+            file,
+            size,
+            max_file_size,
+        })
+    }
+
+    async fn append(&mut self, entries: &[AuditEntry]) -> Result<(), IggyError> {
+        let mut bytes = Vec::new();
+        for entry in entries {
+            serde_json::to_writer(&mut bytes, entry)
+                .map_err(|_| IggyError::CannotSerializeResource)?;
+            bytes.push(b'\n');
+        }
+
+        self.file
+            .write_all(&bytes)
+            .await
+            .map_err(|_| IggyError::CannotAppendToFile)?;
+        self.file
+            .sync_data()
+            .await
+            .map_err(|_| IggyError::CannotAppendToFile)?;
+        self.size += bytes.len() as u64;
+        if self.size < self.max_file_size {
+            return Ok(());
+        }
+
+        let Some(last_entry) = entries.last() else {
+            return Ok(());
+        };
+        let rotated_file_path = format!("{}.{}", self.file_path, last_entry.id);
+        tokio::fs::rename(&self.file_path, &rotated_file_path)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to rotate audit log file to: {rotated_file_path}"
+                )
+            })
+            .map_err(|_| IggyError::CannotAppendToFile)?;
+        self.file = open_file(&self.file_path).await?;
+        self.size = 0;
+        info!("Rotated audit log file to: {rotated_file_path}.");
+        Ok(())
+    }
+}
+
+async fn open_file(file_path: &str) -> Result<File, IggyError> {
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(file_path)
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to open audit log file at path: {file_path}"
+            )
+        })
+        .map_err(|_| IggyError::CannotAppendToFile)
+}
+
+/// Starts the task appending the enqueued entries to the audit log file in batches.
+pub fn start_writer(system: SharedSystem) {
+    tokio::spawn(async move {
+        let (receiver, file_path, max_file_size) = {
+            let system = system.read().await;
+            let Some(audit_log) = system.audit_log.as_ref() else {
+                return;
+            };
+            (
+                audit_log.receiver.clone(),
+                format!("{}/{AUDIT_LOG_FILE}", system.config.get_audit_log_path()),
+                system.config.audit_log.max_file_size.as_bytes_u64(),
+            )
+        };
+
+        let mut writer = match AuditLogWriter::open(file_path, max_file_size).await {
+            Ok(writer) => writer,
+            Err(error) => {
+                error!("Failed to open audit log file, the entries will not be persisted. Error: {error}");
+                return;
+            }
+        };
+
+        while let Ok(entry) = receiver.recv_async().await {
+            let mut entries = vec![entry];
+            entries.extend(receiver.drain());
+            if let Err(error) = writer.append(&entries).await {
+                error!(
+                    "Failed to append {} audit log entries. Error: {error}",
+                    entries.len()
+                );
+            }
+        }
+        warn!("Audit log writer stopped receiving entries.");
+    });
+}
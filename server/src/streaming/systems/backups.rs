@@ -0,0 +1,353 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use crate::streaming::systems::COMPONENT;
+use ahash::{AHashMap, AHashSet};
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::locking::IggySharedMutFn;
+use iggy::models::backup::Backup;
+use iggy::utils::timestamp::IggyTimestamp;
+use std::path::{Path, PathBuf};
+use tokio::fs;
+use tokio::io::AsyncReadExt;
+use tracing::{error, info};
+
+const PENDING_BACKUP_EXTENSION: &str = "partial";
+const PENDING_COPY_EXTENSION: &str = "link";
+
+/// The file of the active segment, hard-linked at the time of the cut, to be copied up to the size written then.
+#[derive(Debug)]
+struct PendingCopy {
+    link_path: String,
+    path: String,
+    size: u64,
+}
+
+/// The backup which has taken the consistent cut, but still has to copy the active segments.
+/// It doesn't need the access to the system, so that the writes are resumed in the meantime.
+#[derive(Debug)]
+pub struct PendingBackup {
+    backup: Backup,
+    pending_path: String,
+    copies: Vec<PendingCopy>,
+}
+
+impl System {
+    /// Takes the consistent cut of the data for the backup, which requires the exclusive access to the system,
+    /// so that no messages are appended and no offsets or state entries are stored in the meantime.
+    ///
+    /// The buffered messages are saved on disk, then the closed segments are hard-linked and the remaining files
+    /// (the state, offsets and partition metadata) are copied, while the active segments are hard-linked
+    /// to be copied up to their current size by `PendingBackup::complete`, once the system is released.
+    pub async fn start_backup(
+        &mut self,
+        session: &Session,
+        name: &str,
+    ) -> Result<PendingBackup, IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .create_backup(session.get_user_id())
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to create backup for user with ID: {}",
+                    session.get_user_id()
+                )
+            })?;
+
+        let path = format!("{}/{name}", self.config.get_data_backups_path());
+        let pending_path = format!("{path}.{PENDING_BACKUP_EXTENSION}");
+        if Path::new(&path).exists() || Path::new(&pending_path).exists() {
+            return Err(IggyError::BackupAlreadyExists(name.to_owned()));
+        }
+
+        fs::create_dir_all(&pending_path)
+            .await
+            .map_err(|error| map_backup_error(&pending_path, error))?;
+        let mut pending_backup = PendingBackup {
+            backup: Backup {
+                name: name.to_owned(),
+                path,
+                created_at: IggyTimestamp::now(),
+                ..Default::default()
+            },
+            pending_path,
+            copies: Vec::new(),
+        };
+
+        if let Err(error) = self.persist_messages().await {
+            error!("{COMPONENT} - failed to save buffered messages before backup: {name}. {error}");
+            pending_backup.discard().await;
+            return Err(error);
+        }
+
+        let mut closed_segment_files = AHashSet::new();
+        let mut active_segment_files = AHashMap::new();
+        for stream in self.streams.values() {
+            for topic in stream.topics.values() {
+                for partition in topic.partitions.values() {
+                    let partition = partition.read().await;
+                    for segment in partition.get_segments() {
+                        if segment.is_closed {
+                            closed_segment_files.insert(segment.log_path.clone());
+                            closed_segment_files.insert(segment.index_path.clone());
+                        } else {
+                            let (log_size, index_size) = segment.get_written_sizes();
+                            active_segment_files.insert(segment.log_path.clone(), log_size);
+                            active_segment_files.insert(segment.index_path.clone(), index_size);
+                        }
+                    }
+                }
+            }
+        }
+
+        let directories = [
+            (self.config.get_state_path(), "state".to_owned()),
+            (
+                self.config.get_streams_path(),
+                self.config.stream.path.clone(),
+            ),
+        ];
+        if let Err(error) = pending_backup
+            .cut(&directories, &closed_segment_files, &active_segment_files)
+            .await
+        {
+            pending_backup.discard().await;
+            return Err(error);
+        }
+
+        info!(
+            "Taken cut of backup: {name} by user with ID: {}, {} files, {} active segment files to be copied.",
+            session.get_user_id(),
+            pending_backup.backup.files_count,
+            pending_backup.copies.len()
+        );
+        Ok(pending_backup)
+    }
+}
+
+impl PendingBackup {
+    /// Copies the active segments up to the size written at the time of the cut, then publishes the backup
+    /// under its name, so that the incomplete backup is never mistaken for the complete one.
+    pub async fn complete(self) -> Result<Backup, IggyError> {
+        if let Err(error) = self.copy_active_segments().await {
+            self.discard().await;
+            return Err(error);
+        }
+
+        if let Err(error) = fs::rename(&self.pending_path, &self.backup.path).await {
+            self.discard().await;
+            return Err(map_backup_error(&self.backup.path, error));
+        }
+
+        info!(
+            "Created backup: {} at path: {}, {} files ({} hard-linked), size: {}.",
+            self.backup.name,
+            self.backup.path,
+            self.backup.files_count,
+            self.backup.linked_files_count,
+            self.backup.size
+        );
+        Ok(self.backup)
+    }
+
+    async fn cut(
+        &mut self,
+        directories: &[(String, String)],
+        closed_segment_files: &AHashSet<String>,
+        active_segment_files: &AHashMap<String, u64>,
+    ) -> Result<(), IggyError> {
+        let mut directories = directories
+            .iter()
+            .map(|(path, name)| (PathBuf::from(path), format!("{}/{name}", self.pending_path)))
+            .collect::<Vec<_>>();
+        let mut size = 0;
+        while let Some((directory_path, target_directory_path)) = directories.pop() {
+            if !directory_path.exists() {
+                continue;
+            }
+
+            fs::create_dir_all(&target_directory_path)
+                .await
+                .map_err(|error| map_backup_error(&target_directory_path, error))?;
+            let directory = directory_path.to_string_lossy().to_string();
+            let mut entries = fs::read_dir(&directory_path)
+                .await
+                .map_err(|error| map_backup_error(&directory, error))?;
+            while let Some(entry) = entries
+                .next_entry()
+                .await
+                .map_err(|error| map_backup_error(&directory, error))?
+            {
+                let path = entry.path().to_string_lossy().to_string();
+                let target_path = format!(
+                    "{target_directory_path}/{}",
+                    entry.file_name().to_string_lossy()
+                );
+                let metadata = entry
+                    .metadata()
+                    .await
+                    .map_err(|error| map_backup_error(&path, error))?;
+                if metadata.is_dir() {
+                    directories.push((entry.path(), target_path));
+                    continue;
+                }
+
+                if closed_segment_files.contains(&path) {
+                    // The closed segments are never modified, only replaced or deleted, so the links stay intact.
+                    if fs::hard_link(&path, &target_path).await.is_ok() {
+                        self.backup.linked_files_count += 1;
+                    } else {
+                        fs::copy(&path, &target_path)
+                            .await
+                            .map_err(|error| map_backup_error(&path, error))?;
+                    }
+                    size += metadata.len();
+                } else if let Some(written_size) = active_segment_files.get(&path).copied() {
+                    // The link keeps the data even if the segment is deleted before it's copied.
+                    let link_path = format!("{target_path}.{PENDING_COPY_EXTENSION}");
+                    if fs::hard_link(&path, &link_path).await.is_ok() {
+                        self.copies.push(PendingCopy {
+                            link_path,
+                            path: target_path,
+                            size: written_size,
+                        });
+                    } else {
+                        copy_prefix(&path, &target_path, written_size).await?;
+                    }
+                    size += written_size;
+                } else {
+                    fs::copy(&path, &target_path)
+                        .await
+                        .map_err(|error| map_backup_error(&path, error))?;
+                    size += metadata.len();
+                }
+                self.backup.files_count += 1;
+            }
+        }
+
+        self.backup.size = size.into();
+        Ok(())
+    }
+
+    async fn copy_active_segments(&self) -> Result<(), IggyError> {
+        for copy in &self.copies {
+            copy_prefix(&copy.link_path, &copy.path, copy.size).await?;
+            fs::remove_file(&copy.link_path)
+                .await
+                .map_err(|error| map_backup_error(&copy.link_path, error))?;
+        }
+        Ok(())
+    }
+
+    async fn discard(&self) {
+        if let Err(error) = fs::remove_dir_all(&self.pending_path).await {
+            error!(
+                "{COMPONENT} - failed to remove incomplete backup at path: {}. {error}",
+                self.pending_path
+            );
+        }
+    }
+}
+
+/// Copies the beginning of the file, which is only appended to, up to the given size.
+async fn copy_prefix(path: &str, target_path: &str, size: u64) -> Result<(), IggyError> {
+    let file = fs::File::open(path)
+        .await
+        .map_err(|error| map_backup_error(path, error))?;
+    let mut target_file = fs::File::create(target_path)
+        .await
+        .map_err(|error| map_backup_error(target_path, error))?;
+    let copied = tokio::io::copy(&mut file.take(size), &mut target_file)
+        .await
+        .map_err(|error| map_backup_error(target_path, error))?;
+    if copied != size {
+        error!("{COMPONENT} - copied {copied} bytes instead of {size} from: {path} to backup: {target_path}.");
+        return Err(IggyError::CannotCreateBackup(target_path.to_owned()));
+    }
+
+    target_file
+        .sync_all()
+        .await
+        .map_err(|error| map_backup_error(target_path, error))?;
+    Ok(())
+}
+
+fn map_backup_error(path: &str, error: std::io::Error) -> IggyError {
+    error!("{COMPONENT} - failed to create backup at path: {path}. {error}");
+    IggyError::CannotCreateBackup(path.to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn active_segment_should_be_copied_up_to_the_size_written_at_the_cut() {
+        let tempdir = tempfile::TempDir::new().unwrap();
+        let base_path = tempdir.path().to_str().unwrap();
+        let data_path = format!("{base_path}/data");
+        fs::create_dir_all(format!("{data_path}/partition"))
+            .await
+            .unwrap();
+        let closed_log = format!("{data_path}/partition/00000000000000000000.log");
+        let active_log = format!("{data_path}/partition/00000000000000000010.log");
+        let offsets = format!("{data_path}/partition/offsets.log");
+        fs::write(&closed_log, [1; 10]).await.unwrap();
+        fs::write(&active_log, [2; 5]).await.unwrap();
+        fs::write(&offsets, [3; 13]).await.unwrap();
+
+        let mut pending_backup = PendingBackup {
+            backup: Backup {
+                name: "backup".to_owned(),
+                path: format!("{base_path}/backup"),
+                ..Default::default()
+            },
+            pending_path: format!("{base_path}/backup.{PENDING_BACKUP_EXTENSION}"),
+            copies: Vec::new(),
+        };
+        pending_backup
+            .cut(
+                &[(data_path, "streams".to_owned())],
+                &AHashSet::from_iter([closed_log]),
+                &AHashMap::from_iter([(active_log.clone(), 3)]),
+            )
+            .await
+            .unwrap();
+        fs::write(&active_log, [2; 8]).await.unwrap();
+
+        let backup = pending_backup.complete().await.unwrap();
+        assert_eq!(backup.files_count, 3);
+        assert_eq!(backup.linked_files_count, 1);
+        assert_eq!(backup.size.as_bytes_u64(), 26);
+        let partition_path = format!("{}/streams/partition", backup.path);
+        let active_log = fs::read(format!("{partition_path}/00000000000000000010.log"))
+            .await
+            .unwrap();
+        assert_eq!(active_log, vec![2; 3]);
+        let link_path =
+            format!("{partition_path}/00000000000000000010.log.{PENDING_COPY_EXTENSION}");
+        assert!(!Path::new(&link_path).exists());
+        let offsets = fs::read(format!("{partition_path}/offsets.log"))
+            .await
+            .unwrap();
+        assert_eq!(offsets.len(), 13);
+    }
+}
@@ -0,0 +1,210 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::systems::system::System;
+use crate::streaming::systems::COMPONENT;
+use crate::streaming::topics::topic::Topic;
+use anyhow::Context;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::locking::IggySharedMutFn;
+use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::sizeable::Sizeable;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use tokio::time::Instant;
+use tracing::{info, trace, warn};
+
+/// The heat of the partition, saved in the heat map to warm up the cache on the next startup.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+struct PartitionHeat {
+    stream_id: u32,
+    topic_id: u32,
+    partition_id: u32,
+    heat: u64,
+}
+
+/// Splits the cache between the hottest partitions, proportionally to their heat.
+fn get_warmup_sizes(heat_map: &[PartitionHeat], cache_size_bytes: u64) -> Vec<u64> {
+    let total_heat = heat_map
+        .iter()
+        .map(|entry| entry.heat as u128)
+        .sum::<u128>();
+    if total_heat == 0 {
+        return vec![0; heat_map.len()];
+    }
+
+    heat_map
+        .iter()
+        .map(|entry| (cache_size_bytes as u128 * entry.heat as u128 / total_heat) as u64)
+        .collect()
+}
+
+impl System {
+    /// Saves the heat of the hottest partitions and cools all of them down.
+    pub async fn save_cache_heat_map(&self) -> Result<(), IggyError> {
+        if !self.config.cache.enabled || !self.config.cache.warmup.enabled || self.is_read_only() {
+            return Ok(());
+        }
+
+        let mut heat_map = Vec::new();
+        for stream in self.streams.values() {
+            for topic in stream.topics.values() {
+                for partition in topic.partitions.values() {
+                    let partition = partition.read().await;
+                    let heat = partition.decay_heat();
+                    if heat > 0 {
+                        heat_map.push(PartitionHeat {
+                            stream_id: partition.stream_id,
+                            topic_id: partition.topic_id,
+                            partition_id: partition.partition_id,
+                            heat,
+                        });
+                    }
+                }
+            }
+        }
+        heat_map.sort_by(|x, y| y.heat.cmp(&x.heat));
+        heat_map.truncate(self.config.cache.warmup.partitions as usize);
+
+        let data = bincode::serde::encode_to_vec(&heat_map, bincode::config::standard())
+            .with_context(|| "Failed to serialize cache heat map")
+            .map_err(|_| IggyError::CannotSerializeResource)?;
+        let path = self.config.get_state_cache_heat_map_path();
+        self.storage
+            .persister
+            .overwrite(&path, &data)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to overwrite file at path: {path}")
+            })?;
+        trace!("Saved cache heat map with {} partition(s).", heat_map.len());
+        Ok(())
+    }
+
+    /// Fills the cache with the newest messages of the partitions which were the hottest before the shutdown.
+    pub(crate) async fn warm_up_cache(&self) -> Result<(), IggyError> {
+        if !self.config.cache.enabled || !self.config.cache.warmup.enabled {
+            return Ok(());
+        }
+
+        let path = self.config.get_state_cache_heat_map_path();
+        if !Path::new(&path).exists() {
+            info!(
+                "Cache heat map does not exist, the cache will be filled by the polled messages."
+            );
+            return Ok(());
+        }
+
+        let data = tokio::fs::read(&path)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to read file at path: {path}")
+            })
+            .map_err(|_| IggyError::CannotReadFile)?;
+        let (mut heat_map, _): (Vec<PartitionHeat>, _) =
+            bincode::serde::decode_from_slice(&data, bincode::config::standard())
+                .with_context(|| "Failed to deserialize cache heat map")
+                .map_err(|_| IggyError::CannotDeserializeResource)?;
+        heat_map.truncate(self.config.cache.warmup.partitions as usize);
+
+        let now = Instant::now();
+        let cache_size: IggyByteSize = self.config.cache.size.clone().into();
+        let sizes = get_warmup_sizes(&heat_map, cache_size.as_bytes_u64());
+        let mut warmed_partitions = 0;
+        let mut warmed_size = IggyByteSize::default();
+        for (entry, size_bytes) in heat_map.iter().zip(sizes) {
+            let Some(topic) = self
+                .streams
+                .get(&entry.stream_id)
+                .and_then(|stream| stream.topics.get(&entry.topic_id))
+            else {
+                continue;
+            };
+            let Some(partition) = topic.partitions.get(&entry.partition_id) else {
+                continue;
+            };
+
+            let mut partition = partition.write().await;
+            partition.restore_heat(entry.heat);
+            if size_bytes == 0 || !topic.is_cache_enabled() || partition.cache.is_none() {
+                continue;
+            }
+
+            let messages = partition
+                .get_newest_messages_by_size(size_bytes)
+                .await
+                .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to get newest messages by size: {size_bytes} for partition: {}", partition.partition_id))?;
+            if messages.is_empty() {
+                continue;
+            }
+
+            if !Topic::cache_integrity_check(&messages) {
+                warn!(
+                    "Cache integrity check failed for partition with ID: {}, topic ID: {}, stream ID: {}, skipping its warm-up.",
+                    partition.partition_id, partition.topic_id, partition.stream_id
+                );
+                continue;
+            }
+
+            let size = messages
+                .iter()
+                .map(|message| message.get_size_bytes())
+                .sum::<IggyByteSize>();
+            if let Some(cache) = &mut partition.cache {
+                for message in messages {
+                    cache.push_safe(message);
+                }
+            }
+            warmed_partitions += 1;
+            warmed_size += size;
+        }
+
+        info!(
+            "Warmed up cache with {warmed_size} of messages from {warmed_partitions} hottest partition(s) in {} ms.",
+            now.elapsed().as_millis()
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cache_should_be_split_proportionally_to_heat() {
+        let heat_map = [
+            PartitionHeat {
+                stream_id: 1,
+                topic_id: 1,
+                partition_id: 1,
+                heat: 300,
+            },
+            PartitionHeat {
+                stream_id: 1,
+                topic_id: 1,
+                partition_id: 2,
+                heat: 100,
+            },
+        ];
+
+        assert_eq!(get_warmup_sizes(&heat_map, 1000), vec![750, 250]);
+        assert_eq!(get_warmup_sizes(&[], 1000), Vec::<u64>::new());
+    }
+}
@@ -0,0 +1,352 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::state::command::EntryCommand;
+use crate::streaming::personal_access_tokens::personal_access_token::PersonalAccessToken;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use crate::streaming::systems::COMPONENT;
+use crate::streaming::users::user::User;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::utils::timestamp::IggyTimestamp;
+use std::net::{Ipv4Addr, SocketAddr};
+
+impl System {
+    /// Replicates the state entry of the change before it's applied to the system, if the server is
+    /// the leader of the cluster. The changes of the entries replicated by the leader are applied as they are.
+    pub(crate) async fn replicate(
+        &self,
+        session: &Session,
+        command: impl FnOnce() -> EntryCommand,
+    ) -> Result<(), IggyError> {
+        let Some(cluster) = self.cluster.as_ref() else {
+            return Ok(());
+        };
+
+        if session.is_replicated() {
+            return Ok(());
+        }
+
+        cluster.replicate(session.get_user_id(), &command()).await
+    }
+
+    /// Applies the state entry committed by the leader of the cluster, on behalf of the user who issued it.
+    /// The passwords and the tokens are already hashed, so they're stored as they are.
+    pub(crate) async fn apply_replicated_entry(
+        &mut self,
+        user_id: u32,
+        command: EntryCommand,
+    ) -> Result<(), IggyError> {
+        let session = Session::replicated(user_id, SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 0));
+        match command {
+            EntryCommand::CreateStream(command) => {
+                self.create_stream(
+                    &session,
+                    Some(command.stream_id),
+                    &command.command.name,
+                    command.command.metadata,
+                    command.command.namespace_id,
+                )
+                .await?;
+            }
+            EntryCommand::UpdateStream(command) => {
+                self.update_stream(&session, &command.stream_id, &command.name)
+                    .await?;
+            }
+            EntryCommand::DeleteStream(command) => {
+                self.delete_stream(&session, &command.stream_id).await?;
+            }
+            EntryCommand::PurgeStream(command) => {
+                self.purge_stream(&session, &command.stream_id).await?;
+            }
+            EntryCommand::UpdateStreamMetadata(command) => {
+                self.update_stream_metadata(&session, &command.stream_id, command.metadata)
+                    .await?;
+            }
+            EntryCommand::UpdateStreamQuota(command) => {
+                self.update_stream_quota(&session, &command.stream_id, command.quota)
+                    .await?;
+            }
+            EntryCommand::CreateTopic(command) => {
+                let topic_id = command.topic_id;
+                let command = command.command;
+                self.create_topic(
+                    &session,
+                    &command.stream_id,
+                    Some(topic_id),
+                    &command.name,
+                    command.partitions_count,
+                    command.message_expiry,
+                    command.compression_algorithm,
+                    command.max_topic_size,
+                    command.replication_factor,
+                    command.metadata,
+                    command.message_id_scheme,
+                    command.cleanup_policy,
+                )
+                .await?;
+            }
+            EntryCommand::UpdateTopic(command) => {
+                self.update_topic(
+                    &session,
+                    &command.stream_id,
+                    &command.topic_id,
+                    &command.name,
+                    command.message_expiry,
+                    command.compression_algorithm,
+                    command.max_topic_size,
+                    command.replication_factor,
+                    command.max_segments,
+                )
+                .await?;
+            }
+            EntryCommand::DeleteTopic(command) => {
+                self.delete_topic(&session, &command.stream_id, &command.topic_id)
+                    .await?;
+            }
+            EntryCommand::PurgeTopic(command) => {
+                self.purge_topic(
+                    &session,
+                    &command.stream_id,
+                    &command.topic_id,
+                    command.keep_consumer_offsets,
+                    command.older_than,
+                )
+                .await?;
+            }
+            EntryCommand::UpdateTopicMetadata(command) => {
+                self.update_topic_metadata(
+                    &session,
+                    &command.stream_id,
+                    &command.topic_id,
+                    command.metadata,
+                )
+                .await?;
+            }
+            EntryCommand::UpdateTopicConfig(command) => {
+                self.update_topic_config(
+                    &session,
+                    &command.stream_id,
+                    &command.topic_id,
+                    command.config,
+                )
+                .await?;
+            }
+            EntryCommand::UpdateTopicExpiryWatcher(command) => {
+                self.update_topic_expiry_watcher(
+                    &session,
+                    &command.stream_id,
+                    &command.topic_id,
+                    &command.consumer(),
+                    command.enabled,
+                )
+                .await?;
+            }
+            EntryCommand::UpdateTopicProducers(command) => {
+                self.update_topic_producers(
+                    &session,
+                    &command.stream_id,
+                    &command.topic_id,
+                    command.allowed_producers,
+                )
+                .await?;
+            }
+            EntryCommand::MarkTopicForDeletion(command) => {
+                self.mark_topic_for_deletion(
+                    &session,
+                    &command.command.stream_id,
+                    &command.command.topic_id,
+                    command.command.drain_period,
+                    command.delete_at,
+                )
+                .await?;
+            }
+            EntryCommand::CreatePartitions(command) => {
+                self.create_partitions(
+                    &session,
+                    &command.stream_id,
+                    &command.topic_id,
+                    command.partitions_count,
+                )
+                .await?;
+            }
+            EntryCommand::DeletePartitions(command) => {
+                self.delete_partitions(
+                    &session,
+                    &command.stream_id,
+                    &command.topic_id,
+                    command.partitions_count,
+                )
+                .await?;
+            }
+            EntryCommand::CreateConsumerGroup(command) => {
+                let group_id = command.group_id;
+                let command = command.command;
+                self.create_consumer_group(
+                    &session,
+                    &command.stream_id,
+                    &command.topic_id,
+                    Some(group_id),
+                    &command.name,
+                    command.offset_recovery,
+                )
+                .await?;
+            }
+            EntryCommand::DeleteConsumerGroup(command) => {
+                self.delete_consumer_group(
+                    &session,
+                    &command.stream_id,
+                    &command.topic_id,
+                    &command.group_id,
+                )
+                .await?;
+            }
+            EntryCommand::UpdateConsumerGroupDeadLetter(command) => {
+                self.update_consumer_group_dead_letter(
+                    &session,
+                    &command.stream_id,
+                    &command.topic_id,
+                    &command.group_id,
+                    command.dead_letter_stream_id.as_ref(),
+                    command.dead_letter_topic_id.as_ref(),
+                    command.max_delivery_count,
+                )
+                .await?;
+            }
+            EntryCommand::UpdateConsumerGroupVisibilityTimeout(command) => {
+                self.update_consumer_group_visibility_timeout(
+                    &session,
+                    &command.stream_id,
+                    &command.topic_id,
+                    &command.group_id,
+                    command.visibility_timeout,
+                )
+                .await?;
+            }
+            EntryCommand::CreateUser(command) => {
+                let mut user = User::with_password(
+                    command.user_id,
+                    &command.command.username,
+                    command.command.password,
+                    command.command.status,
+                    command.command.permissions,
+                );
+                user.namespace_id = command.command.namespace_id;
+                self.add_replicated_user(user)?;
+            }
+            EntryCommand::UpdateUser(command) => {
+                self.update_user(&session, &command.user_id, command.username, command.status)
+                    .await?;
+            }
+            EntryCommand::DeleteUser(command) => {
+                self.delete_user(&session, &command.user_id).await?;
+            }
+            EntryCommand::ChangePassword(command) => {
+                let user = self
+                    .get_user_mut(&command.user_id)
+                    .with_error_context(|error| {
+                        format!(
+                            "{COMPONENT} (error: {error}) - failed to get user with ID: {}",
+                            command.user_id
+                        )
+                    })?;
+                user.password = command.new_password;
+            }
+            EntryCommand::UpdatePermissions(command) => {
+                self.update_permissions(&session, &command.user_id, command.permissions)
+                    .await?;
+            }
+            EntryCommand::UpdateUserQuotas(command) => {
+                self.update_user_quotas(&session, &command.user_id, command.quotas)
+                    .await?;
+            }
+            EntryCommand::CreatePersonalAccessToken(command) => {
+                let now = IggyTimestamp::now();
+                let expiry_at =
+                    PersonalAccessToken::calculate_expiry_at(now, command.command.expiry);
+                let user = self
+                    .get_user_mut(&user_id.try_into()?)
+                    .with_error_context(|error| {
+                        format!(
+                            "{COMPONENT} (error: {error}) - failed to get user with ID: {user_id}"
+                        )
+                    })?;
+                user.personal_access_tokens.insert(
+                    command.hash.clone(),
+                    PersonalAccessToken::raw(
+                        user_id,
+                        &command.command.name,
+                        &command.hash,
+                        expiry_at,
+                        command.command.scope,
+                        now,
+                    ),
+                );
+            }
+            EntryCommand::DeletePersonalAccessToken(command) => {
+                self.delete_personal_access_token(&session, &command.name)
+                    .await?;
+            }
+            EntryCommand::RotatePersonalAccessToken(command) => {
+                let now = IggyTimestamp::now();
+                let name = command.command.name;
+                let user = self
+                    .get_user_mut(&user_id.try_into()?)
+                    .with_error_context(|error| {
+                        format!(
+                            "{COMPONENT} (error: {error}) - failed to get user with ID: {user_id}"
+                        )
+                    })?;
+                let Some(token) = user
+                    .personal_access_tokens
+                    .values()
+                    .find(|pat| pat.name == name)
+                    .map(|pat| pat.token.clone())
+                else {
+                    return Err(IggyError::ResourceNotFound(name));
+                };
+                let mut personal_access_token = user.personal_access_tokens.remove(&token).unwrap();
+                personal_access_token.token = command.hash.clone();
+                personal_access_token.expiry_at =
+                    PersonalAccessToken::calculate_expiry_at(now, command.command.expiry);
+                personal_access_token.issued_at = now;
+                user.personal_access_tokens
+                    .insert(command.hash, personal_access_token);
+            }
+            EntryCommand::CreateNamespace(command) => {
+                self.create_namespace(
+                    &session,
+                    Some(command.namespace_id),
+                    &command.command.name,
+                    command.command.quotas,
+                )
+                .await?;
+            }
+            EntryCommand::UpdateNamespace(command) => {
+                self.update_namespace(&session, &command.namespace_id, command.quotas)
+                    .await?;
+            }
+            EntryCommand::DeleteNamespace(command) => {
+                self.delete_namespace(&session, &command.namespace_id)
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+}
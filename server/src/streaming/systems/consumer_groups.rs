@@ -16,11 +16,16 @@
  * under the License.
  */
 
+use crate::state::command::EntryCommand;
+use crate::state::models::CreateConsumerGroupWithId;
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::System;
 use crate::streaming::systems::COMPONENT;
 use crate::streaming::topics::consumer_group::ConsumerGroup;
 use error_set::ErrContext;
+use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
+use iggy::consumer_groups::delete_consumer_group::DeleteConsumerGroup;
+use iggy::consumer_groups::offset_recovery_policy::OffsetRecoveryPolicy;
 use iggy::error::IggyError;
 use iggy::identifier::Identifier;
 use iggy::locking::IggySharedMutFn;
@@ -80,9 +85,10 @@ impl System {
         topic_id: &Identifier,
         group_id: Option<u32>,
         name: &str,
+        offset_recovery: OffsetRecoveryPolicy,
     ) -> Result<&RwLock<ConsumerGroup>, IggyError> {
         self.ensure_authenticated(session)?;
-        {
+        let group_id = {
             let topic = self.find_topic(session, stream_id, topic_id)
                 .with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream ID: {stream_id}, topic_id: {topic_id}"))?;
 
@@ -91,14 +97,30 @@ impl System {
                 topic.stream_id,
                 topic.topic_id,
             ).with_error_context(|error| format!("{COMPONENT} (error: {error}) - permission denied to create consumer group for user {} on stream ID: {}, topic ID: {}", session.get_user_id(), topic.stream_id, topic.topic_id))?;
-        }
 
-        let topic = self.get_stream_mut(stream_id)?
+            topic.get_new_consumer_group_id(group_id, name)?
+        };
+
+        self.replicate(session, || {
+            EntryCommand::CreateConsumerGroup(CreateConsumerGroupWithId {
+                group_id,
+                command: CreateConsumerGroup {
+                    stream_id: stream_id.clone(),
+                    topic_id: topic_id.clone(),
+                    group_id: Some(group_id),
+                    name: name.to_owned(),
+                    offset_recovery,
+                },
+            })
+        })
+        .await?;
+
+        let topic = self.get_stream_mut(session, stream_id)?
             .get_topic_mut(topic_id)
             .with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream ID: {stream_id}, topic_id: {topic_id}"))?;
 
         topic
-            .create_consumer_group(group_id, name)
+            .create_consumer_group(Some(group_id), name, offset_recovery)
             .await
             .with_error_context(|error| {
                 format!("{COMPONENT} (error: {error}) - failed to create consumer group with name: {name}")
@@ -125,13 +147,23 @@ impl System {
                 topic.topic_id,
             ).with_error_context(|error| format!("{COMPONENT} (error: {error}) - permission denied to delete consumer group for user {} on stream ID: {}, topic ID: {}", session.get_user_id(), topic.stream_id, topic.topic_id))?;
 
+            topic.get_consumer_group(consumer_group_id)?;
             stream_id_value = topic.stream_id;
             topic_id_value = topic.topic_id;
         }
 
+        self.replicate(session, || {
+            EntryCommand::DeleteConsumerGroup(DeleteConsumerGroup {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                group_id: consumer_group_id.clone(),
+            })
+        })
+        .await?;
+
         let consumer_group;
         {
-            let stream = self.get_stream_mut(stream_id).with_error_context(|error| {
+            let stream = self.get_stream_mut(session, stream_id).with_error_context(|error| {
                 format!(
                     "{COMPONENT} (error: {error}) - failed to get mutable reference to stream with id: {stream_id}"
                 )
@@ -241,6 +273,8 @@ impl System {
         consumer_group_id: &Identifier,
     ) -> Result<(), IggyError> {
         self.ensure_authenticated(session)?;
+        let stream_id_value;
+        let topic_id_value;
         {
             let topic = self
                 .find_topic(session, stream_id, topic_id)
@@ -256,11 +290,14 @@ impl System {
                 topic.stream_id,
                 topic.topic_id,
             ).with_error_context(|error| format!("{COMPONENT} (error: {error}) - permission denied to leave consumer group for user {} on stream ID: {}, topic ID: {}", session.get_user_id(), topic.stream_id, topic.topic_id))?;
+            stream_id_value = topic.stream_id;
+            topic_id_value = topic.topic_id;
         }
 
+        // The topic has been resolved in the namespace of the session, so pass on its numeric IDs.
         self.leave_consumer_group_by_client(
-            stream_id,
-            topic_id,
+            &Identifier::numeric(stream_id_value)?,
+            &Identifier::numeric(topic_id_value)?,
             consumer_group_id,
             session.client_id,
         )
@@ -285,9 +322,13 @@ impl System {
         let group_id;
 
         {
-            let stream = self.get_stream(stream_id).with_error_context(|error| {
-                format!("{COMPONENT} (error: {error}) - failed to get stream with ID: {stream_id}")
-            })?;
+            let stream = self
+                .get_stream_in_namespace(None, stream_id)
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to get stream with ID: {stream_id}"
+                    )
+                })?;
             let topic = stream.get_topic(topic_id)
                 .with_error_context(|error| {
                     format!(
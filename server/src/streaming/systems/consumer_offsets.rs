@@ -21,9 +21,14 @@ use crate::streaming::systems::system::System;
 use crate::streaming::systems::COMPONENT;
 use error_set::ErrContext;
 use iggy::consumer::Consumer;
+use iggy::consumer_offsets::get_offsets_for_timestamps::PartitionTimestamp;
+use iggy::consumer_offsets::store_consumer_offsets::PartitionOffset;
 use iggy::error::IggyError;
 use iggy::identifier::Identifier;
+use iggy::locking::IggySharedMutFn;
+use iggy::models::audit_entry::AuditAction;
 use iggy::models::consumer_offset_info::ConsumerOffsetInfo;
+use iggy::models::partition_timestamp_offset::PartitionTimestampOffset;
 
 impl System {
     pub async fn store_consumer_offset(
@@ -49,6 +54,43 @@ impl System {
             .await
     }
 
+    pub async fn store_consumer_offsets(
+        &self,
+        session: &Session,
+        consumer: Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        offsets: &[PartitionOffset],
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        let topic = self.find_topic(session, stream_id, topic_id)
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic with ID: {topic_id} was not found in stream with ID: {stream_id}"))?;
+        self.permissioner.store_consumer_offset(
+            session.get_user_id(),
+            topic.stream_id,
+            topic.topic_id,
+        )?;
+
+        // Validate all the partitions upfront, so that the batch is not partially applied.
+        for offset in offsets {
+            topic
+                .get_partition(offset.partition_id)
+                .with_error_context(|error| format!("{COMPONENT} (error: {error}) - partition with ID: {} was not found in topic with ID: {topic_id}", offset.partition_id))?;
+        }
+
+        for offset in offsets {
+            topic
+                .store_consumer_offset(
+                    consumer.clone(),
+                    offset.offset,
+                    Some(offset.partition_id),
+                    session.client_id,
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
     pub async fn get_consumer_offset(
         &self,
         session: &Session,
@@ -100,8 +142,54 @@ impl System {
             )
         })?;
 
+        let resource = format!(
+            "streams/{}/topics/{}/consumer_offsets/{}/{}",
+            topic.stream_id, topic.topic_id, consumer.kind, consumer.id
+        );
         topic
             .delete_consumer_offset(consumer, partition_id, session.client_id)
-            .await
+            .await?;
+        self.audit(session, AuditAction::DeleteConsumerOffset, resource);
+        Ok(())
+    }
+
+    pub async fn get_offsets_for_timestamps(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        timestamps: &[PartitionTimestamp],
+    ) -> Result<Vec<PartitionTimestampOffset>, IggyError> {
+        self.ensure_authenticated(session)?;
+        let topic = self.find_topic(session, stream_id, topic_id)
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic with ID: {topic_id} was not found in stream with ID: {stream_id}"))?;
+        self.permissioner.poll_messages(
+            session.get_user_id(),
+            topic.stream_id,
+            topic.topic_id,
+        ).with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - permission denied to get offsets for timestamps for user with ID: {} in topic with ID: {topic_id} and stream with ID: {stream_id}",
+                session.get_user_id(),
+            )
+        })?;
+
+        let mut offsets = Vec::with_capacity(timestamps.len());
+        for timestamp in timestamps {
+            let partition = topic
+                .get_partition(timestamp.partition_id)
+                .with_error_context(|error| format!("{COMPONENT} (error: {error}) - partition with ID: {} was not found in topic with ID: {topic_id}", timestamp.partition_id))?;
+            let offset = partition
+                .read()
+                .await
+                .get_offset_for_timestamp(timestamp.timestamp)
+                .await?;
+            offsets.push(PartitionTimestampOffset {
+                partition_id: timestamp.partition_id,
+                timestamp: timestamp.timestamp,
+                offset,
+            });
+        }
+        Ok(offsets)
     }
 }
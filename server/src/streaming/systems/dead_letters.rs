@@ -0,0 +1,498 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::state::command::EntryCommand;
+use crate::streaming::polling_consumer::PollingConsumer;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use crate::streaming::systems::COMPONENT;
+use crate::streaming::topics::dead_letter::DeliveryOutcome;
+use crate::streaming::topics::topic::Topic;
+use error_set::ErrContext;
+use iggy::consumer::Consumer;
+use iggy::consumer_groups::update_consumer_group_dead_letter::UpdateConsumerGroupDeadLetter;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::{IsolationLevel, PollingStrategy};
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::models::consumer_group::{
+    ConsumerGroupDeadLetter, DEAD_LETTER_CONSUMER_GROUP_HEADER, DEAD_LETTER_DELIVERY_COUNT_HEADER,
+    DEAD_LETTER_REASON_HEADER, DEAD_LETTER_SOURCE_OFFSET_HEADER,
+    DEAD_LETTER_SOURCE_PARTITION_HEADER, DEAD_LETTER_SOURCE_STREAM_HEADER,
+    DEAD_LETTER_SOURCE_TOPIC_HEADER, DEAD_LETTER_TIMESTAMP_HEADER,
+};
+use iggy::models::header::{HeaderKey, HeaderValue};
+use iggy::models::messages::{MessagesGap, MessagesGapReason, PolledMessage, PolledMessages};
+use iggy::utils::timestamp::IggyTimestamp;
+use std::str::FromStr;
+use tracing::{info, warn};
+
+const MAX_DELIVERY_COUNT_EXCEEDED_REASON: &str = "max_delivery_count_exceeded";
+const NACK_REASON: &str = "nack";
+
+impl System {
+    /// Configures the dead-letter topic of the consumer group, returning the resolved configuration, `None` if disabled.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn update_consumer_group_dead_letter(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+        dead_letter_stream_id: Option<&Identifier>,
+        dead_letter_topic_id: Option<&Identifier>,
+        max_delivery_count: u32,
+    ) -> Result<Option<ConsumerGroupDeadLetter>, IggyError> {
+        self.ensure_authenticated(session)?;
+        let topic = self.find_topic(session, stream_id, topic_id)
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream ID: {stream_id}, topic_id: {topic_id}"))?;
+        self.permissioner.create_consumer_group(
+            session.get_user_id(),
+            topic.stream_id,
+            topic.topic_id,
+        ).with_error_context(|error| format!("{COMPONENT} (error: {error}) - permission denied to update consumer group dead letter for user {} on stream ID: {}, topic ID: {}", session.get_user_id(), topic.stream_id, topic.topic_id))?;
+
+        let dead_letter = match (dead_letter_stream_id, dead_letter_topic_id) {
+            (Some(dead_letter_stream_id), Some(dead_letter_topic_id)) => {
+                let dead_letter_topic = self
+                    .find_topic(session, dead_letter_stream_id, dead_letter_topic_id)
+                    .with_error_context(|error| format!("{COMPONENT} (error: {error}) - dead letter topic not found for stream ID: {dead_letter_stream_id}, topic_id: {dead_letter_topic_id}"))?;
+                self.permissioner.append_messages(
+                    session.get_user_id(),
+                    dead_letter_topic.stream_id,
+                    dead_letter_topic.topic_id,
+                ).with_error_context(|error| format!("{COMPONENT} (error: {error}) - permission denied to append messages to dead letter topic for user {} on stream ID: {}, topic ID: {}", session.get_user_id(), dead_letter_topic.stream_id, dead_letter_topic.topic_id))?;
+                if dead_letter_topic.stream_id == topic.stream_id
+                    && dead_letter_topic.topic_id == topic.topic_id
+                {
+                    return Err(IggyError::InvalidDeadLetterConfiguration);
+                }
+
+                Some(ConsumerGroupDeadLetter {
+                    stream_id: dead_letter_topic.stream_id,
+                    topic_id: dead_letter_topic.topic_id,
+                    max_delivery_count,
+                })
+            }
+            (None, None) if max_delivery_count == 0 => None,
+            _ => return Err(IggyError::InvalidDeadLetterConfiguration),
+        };
+
+        let consumer_group = topic
+            .get_consumer_group(group_id)
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - consumer group not found for group_id: {group_id}")
+            })?;
+        self.replicate(session, || {
+            EntryCommand::UpdateConsumerGroupDeadLetter(UpdateConsumerGroupDeadLetter {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                group_id: group_id.clone(),
+                dead_letter_stream_id: dead_letter_stream_id.cloned(),
+                dead_letter_topic_id: dead_letter_topic_id.cloned(),
+                max_delivery_count,
+            })
+        })
+        .await?;
+        let mut consumer_group = consumer_group.write().await;
+        consumer_group.dead_letter = dead_letter;
+        consumer_group.deliveries.clear();
+        info!(
+            "Updated dead letter: {:?} of consumer group with ID: {} for topic with ID: {} and stream with ID: {}.",
+            dead_letter, consumer_group.group_id, topic.topic_id, topic.stream_id
+        );
+        Ok(dead_letter)
+    }
+
+    /// Republishes the negatively acknowledged messages to the dead-letter topic of the consumer group.
+    /// In the acknowledgement mode, without the dead-letter topic configured, the messages are redelivered immediately instead.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn nack_messages(
+        &self,
+        session: &Session,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        offsets: &[u64],
+        reason: &str,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        let topic = self.find_topic(session, stream_id, topic_id).with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream ID: {stream_id}, topic_id: {topic_id}"))?;
+        self.permissioner
+            .poll_messages(session.get_user_id(), topic.stream_id, topic.topic_id)
+            .with_error_context(|error| format!(
+                "{COMPONENT} (error: {error}) - permission denied to nack messages for user {} on stream ID: {}, topic ID: {}",
+                session.get_user_id(),
+                topic.stream_id,
+                topic.topic_id
+            ))?;
+
+        let Some((polling_consumer, partition_id)) = topic
+            .resolve_consumer_with_partition_id(consumer, session.client_id, partition_id, false)
+            .await
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to resolve consumer with partition id, consumer: {consumer}, client ID: {}, partition ID: {:?}", session.client_id, partition_id))? else {
+            return Ok(());
+        };
+        let PollingConsumer::ConsumerGroup(group_id, _) = polling_consumer else {
+            return Err(IggyError::InvalidConsumerGroupId);
+        };
+
+        let mut delivery_counts = Vec::with_capacity(offsets.len());
+        let dead_letter;
+        let ack_mode;
+        {
+            let consumer_group = topic.get_consumer_group_by_id(group_id)?.read().await;
+            ack_mode = consumer_group.visibility_timeout.is_some();
+            let Some(group_dead_letter) = consumer_group.dead_letter else {
+                drop(consumer_group);
+                if ack_mode {
+                    return self
+                        .release_in_flight_messages(topic, group_id, partition_id, offsets)
+                        .await;
+                }
+                return Err(IggyError::DeadLetterNotConfigured(group_id, topic.topic_id));
+            };
+            dead_letter = group_dead_letter;
+            for offset in offsets {
+                if !consumer_group
+                    .deliveries
+                    .is_dead_lettered(partition_id, *offset)
+                {
+                    delivery_counts.push((
+                        *offset,
+                        consumer_group
+                            .deliveries
+                            .get_delivery_count(partition_id, *offset),
+                    ));
+                }
+            }
+        }
+
+        let reason = if reason.is_empty() {
+            NACK_REASON
+        } else {
+            reason
+        };
+        let mut messages = Vec::with_capacity(delivery_counts.len());
+        for (offset, delivery_count) in delivery_counts {
+            let mut polled_messages = topic
+                .get_messages(
+                    polling_consumer,
+                    partition_id,
+                    PollingStrategy::offset(offset),
+                    1,
+                    IsolationLevel::ReadUncommitted,
+                    None,
+                )
+                .await
+                .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to get message with offset: {offset}, partition ID: {partition_id}"))?;
+            polled_messages
+                .messages
+                .retain(|message| message.offset == offset);
+            self.decrypt_polled_messages(&mut polled_messages)?;
+            let Some(message) = polled_messages.messages.first() else {
+                warn!(
+                    "Cannot nack the message with offset: {offset}, partition ID: {partition_id}, topic ID: {}, stream ID: {}, it was not found.",
+                    topic.topic_id, topic.stream_id
+                );
+                continue;
+            };
+            messages.push((
+                offset,
+                create_dead_letter_message(
+                    message,
+                    topic,
+                    partition_id,
+                    group_id,
+                    delivery_count,
+                    reason,
+                )?,
+            ));
+        }
+
+        self.publish_dead_letters(topic, group_id, partition_id, dead_letter, messages)
+            .await?;
+        if ack_mode {
+            self.ack_in_flight_messages(topic, group_id, partition_id, offsets)
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Removes the dead-lettered messages from the messages polled by the consumer group,
+    /// republishing the ones which exceeded the max delivery count to the dead-letter topic.
+    pub(crate) async fn dead_letter_polled_messages(
+        &self,
+        topic: &Topic,
+        group_id: u32,
+        polled_messages: &mut PolledMessages,
+    ) -> Result<(), IggyError> {
+        let partition_id = polled_messages.partition_id;
+        let offsets = polled_messages
+            .messages
+            .iter()
+            .map(|message| message.offset)
+            .collect::<Vec<_>>();
+        let (dead_letter, outcomes) = {
+            let mut consumer_group = topic.get_consumer_group_by_id(group_id)?.write().await;
+            let Some(dead_letter) = consumer_group.dead_letter else {
+                return Ok(());
+            };
+            // In the acknowledgement mode, the deliveries are forgotten once the offset is committed by the acknowledgements.
+            if let (None, Some(first_offset)) = (consumer_group.visibility_timeout, offsets.first())
+            {
+                consumer_group
+                    .deliveries
+                    .forget_below(partition_id, *first_offset);
+            }
+            let outcomes = consumer_group.deliveries.get_outcomes(
+                partition_id,
+                &offsets,
+                dead_letter.max_delivery_count,
+            );
+            (dead_letter, outcomes)
+        };
+        if outcomes
+            .iter()
+            .all(|outcome| *outcome == DeliveryOutcome::Deliver)
+        {
+            return Ok(());
+        }
+
+        let has_dead_letter_topic = self.get_dead_letter_topic(&dead_letter).is_some();
+        if !has_dead_letter_topic {
+            warn!(
+                "Dead letter topic with ID: {} in stream with ID: {} of consumer group with ID: {} for topic with ID: {} was not found, messages will be delivered.",
+                dead_letter.topic_id, dead_letter.stream_id, group_id, topic.topic_id
+            );
+        }
+
+        let mut delivered_messages = Vec::with_capacity(polled_messages.messages.len());
+        let mut dead_letters = Vec::new();
+        let mut skipped_offsets = Vec::new();
+        for (message, outcome) in polled_messages.messages.drain(..).zip(outcomes) {
+            match outcome {
+                DeliveryOutcome::DeadLetter(delivery_count) if has_dead_letter_topic => {
+                    dead_letters.push((
+                        message.offset,
+                        create_dead_letter_message(
+                            &message,
+                            topic,
+                            partition_id,
+                            group_id,
+                            delivery_count,
+                            MAX_DELIVERY_COUNT_EXCEEDED_REASON,
+                        )?,
+                    ));
+                    skipped_offsets.push(message.offset);
+                }
+                DeliveryOutcome::Skip => skipped_offsets.push(message.offset),
+                _ => delivered_messages.push(message),
+            }
+        }
+        polled_messages.messages = delivered_messages;
+        add_dead_letter_gaps(polled_messages, &skipped_offsets);
+        if dead_letters.is_empty() {
+            return Ok(());
+        }
+
+        self.publish_dead_letters(topic, group_id, partition_id, dead_letter, dead_letters)
+            .await
+    }
+
+    /// Counts the deliveries of the messages returned to the member of the consumer group.
+    pub(crate) async fn record_deliveries(
+        &self,
+        topic: &Topic,
+        group_id: u32,
+        polled_messages: &PolledMessages,
+    ) -> Result<(), IggyError> {
+        let mut consumer_group = topic.get_consumer_group_by_id(group_id)?.write().await;
+        if consumer_group.dead_letter.is_none() {
+            return Ok(());
+        }
+
+        let offsets = polled_messages
+            .messages
+            .iter()
+            .map(|message| message.offset)
+            .collect::<Vec<_>>();
+        consumer_group
+            .deliveries
+            .record_deliveries(polled_messages.partition_id, &offsets);
+        Ok(())
+    }
+
+    async fn publish_dead_letters(
+        &self,
+        topic: &Topic,
+        group_id: u32,
+        partition_id: u32,
+        dead_letter: ConsumerGroupDeadLetter,
+        messages: Vec<(u64, Message)>,
+    ) -> Result<(), IggyError> {
+        if messages.is_empty() {
+            return Ok(());
+        }
+
+        let Some(dead_letter_topic) = self.get_dead_letter_topic(&dead_letter) else {
+            return Err(IggyError::TopicIdNotFound(
+                dead_letter.topic_id,
+                dead_letter.stream_id,
+            ));
+        };
+
+        let (offsets, messages): (Vec<_>, Vec<_>) = messages.into_iter().unzip();
+        self.append_messages_to_topic(dead_letter_topic, Partitioning::balanced(), messages, None)
+            .await
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to append dead letters to topic with ID: {}, stream ID: {}", dead_letter.topic_id, dead_letter.stream_id))?;
+
+        let mut consumer_group = topic.get_consumer_group_by_id(group_id)?.write().await;
+        for offset in &offsets {
+            consumer_group
+                .deliveries
+                .mark_dead_lettered(partition_id, *offset);
+        }
+        info!(
+            "Dead-lettered {} messages of consumer group with ID: {} from partition with ID: {} for topic with ID: {} and stream with ID: {}.",
+            offsets.len(),
+            group_id,
+            partition_id,
+            topic.topic_id,
+            topic.stream_id
+        );
+        Ok(())
+    }
+
+    fn get_dead_letter_topic(&self, dead_letter: &ConsumerGroupDeadLetter) -> Option<&Topic> {
+        let topic_id = Identifier::numeric(dead_letter.topic_id).ok()?;
+        self.get_stream_by_id(dead_letter.stream_id)
+            .ok()?
+            .get_topic(&topic_id)
+            .ok()
+    }
+}
+
+fn create_dead_letter_message(
+    message: &PolledMessage,
+    topic: &Topic,
+    partition_id: u32,
+    group_id: u32,
+    delivery_count: u32,
+    reason: &str,
+) -> Result<Message, IggyError> {
+    let mut headers = message.headers.clone().unwrap_or_default();
+    headers.insert(
+        HeaderKey::new(DEAD_LETTER_SOURCE_STREAM_HEADER)?,
+        HeaderValue::from_uint32(topic.stream_id)?,
+    );
+    headers.insert(
+        HeaderKey::new(DEAD_LETTER_SOURCE_TOPIC_HEADER)?,
+        HeaderValue::from_uint32(topic.topic_id)?,
+    );
+    headers.insert(
+        HeaderKey::new(DEAD_LETTER_SOURCE_PARTITION_HEADER)?,
+        HeaderValue::from_uint32(partition_id)?,
+    );
+    headers.insert(
+        HeaderKey::new(DEAD_LETTER_SOURCE_OFFSET_HEADER)?,
+        HeaderValue::from_uint64(message.offset)?,
+    );
+    headers.insert(
+        HeaderKey::new(DEAD_LETTER_CONSUMER_GROUP_HEADER)?,
+        HeaderValue::from_uint32(group_id)?,
+    );
+    headers.insert(
+        HeaderKey::new(DEAD_LETTER_DELIVERY_COUNT_HEADER)?,
+        HeaderValue::from_uint32(delivery_count)?,
+    );
+    headers.insert(
+        HeaderKey::new(DEAD_LETTER_REASON_HEADER)?,
+        HeaderValue::from_str(reason)?,
+    );
+    headers.insert(
+        HeaderKey::new(DEAD_LETTER_TIMESTAMP_HEADER)?,
+        HeaderValue::from_uint64(IggyTimestamp::now().as_micros())?,
+    );
+    Ok(Message::new(None, message.payload.clone(), Some(headers)))
+}
+
+/// Reports the skipped offsets as the gaps, merging the consecutive ones.
+fn add_dead_letter_gaps(polled_messages: &mut PolledMessages, skipped_offsets: &[u64]) {
+    let mut gaps: Vec<MessagesGap> = Vec::new();
+    for offset in skipped_offsets {
+        match gaps.last_mut() {
+            Some(gap) if gap.end_offset + 1 == *offset => gap.end_offset = *offset,
+            _ => gaps.push(MessagesGap {
+                start_offset: *offset,
+                end_offset: *offset,
+                reason: MessagesGapReason::DeadLettered,
+            }),
+        }
+    }
+    polled_messages.gaps.extend(gaps);
+    polled_messages.gaps.sort_by_key(|gap| gap.start_offset);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn consecutive_skipped_offsets_should_be_merged_into_single_gap() {
+        let mut polled_messages = PolledMessages {
+            partition_id: 1,
+            current_offset: 20,
+            remaining_messages: 0,
+            partition_epoch: 1,
+            throttle_time_ms: 0,
+            topic_delete_at: 0,
+            messages: Vec::new(),
+            gaps: vec![MessagesGap {
+                start_offset: 13,
+                end_offset: 14,
+                reason: MessagesGapReason::Aborted,
+            }],
+            chunk_sizes: Vec::new(),
+        };
+
+        add_dead_letter_gaps(&mut polled_messages, &[10, 11, 12, 16]);
+
+        assert_eq!(
+            polled_messages.gaps,
+            vec![
+                MessagesGap {
+                    start_offset: 10,
+                    end_offset: 12,
+                    reason: MessagesGapReason::DeadLettered,
+                },
+                MessagesGap {
+                    start_offset: 13,
+                    end_offset: 14,
+                    reason: MessagesGapReason::Aborted,
+                },
+                MessagesGap {
+                    start_offset: 16,
+                    end_offset: 16,
+                    reason: MessagesGapReason::DeadLettered,
+                },
+            ]
+        );
+    }
+}
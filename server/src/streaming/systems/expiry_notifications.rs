@@ -0,0 +1,269 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::streaming::systems::system::System;
+use crate::streaming::systems::COMPONENT;
+use crate::streaming::topics::topic::Topic;
+use ahash::AHashMap;
+use bytes::Bytes;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::locking::IggySharedMutFn;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::models::expiry_notification::{ExpiryNotification, EXPIRY_NOTIFICATIONS_TOPIC};
+use iggy::utils::duration::IggyDuration;
+use iggy::utils::timestamp::IggyTimestamp;
+use tracing::{error, info};
+
+/// The internal topic to which the notifications about the expired segments are published,
+/// before the segments are deleted.
+#[derive(Debug)]
+pub struct ExpiryNotifications {
+    stream_id: u32,
+    topic_id: u32,
+    grace_period: IggyDuration,
+}
+
+/// What should happen with the expired segment of the topic having the expiry watchers registered.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExpiryNotice {
+    /// The watchers should be notified, the segment is going to be deleted at the given time.
+    Notify(IggyTimestamp),
+    /// The watchers have already been notified, but the grace period hasn't elapsed yet.
+    Hold,
+    /// The grace period has elapsed and the segment can be deleted.
+    Release,
+}
+
+type SegmentKey = (u32, u32, u32, u64);
+
+/// Tracks the expired segments whose watchers have been notified, along with the time of their deletion.
+/// The notices are kept in memory only, so the watchers are notified again after the restart.
+#[derive(Debug, Default, Clone)]
+pub struct ExpiryNotices {
+    segments: AHashMap<SegmentKey, IggyTimestamp>,
+}
+
+impl ExpiryNotices {
+    /// Returns the notice for the expired segment, starting its grace period if it hasn't been notified yet.
+    pub fn get_notice(
+        &mut self,
+        key: SegmentKey,
+        now: IggyTimestamp,
+        grace_period: IggyDuration,
+    ) -> ExpiryNotice {
+        match self.segments.get(&key) {
+            None => {
+                let delete_at = IggyTimestamp::from(now.as_micros() + grace_period.as_micros());
+                self.segments.insert(key, delete_at);
+                ExpiryNotice::Notify(delete_at)
+            }
+            Some(delete_at) if now.as_micros() >= delete_at.as_micros() => {
+                self.segments.remove(&key);
+                ExpiryNotice::Release
+            }
+            Some(_) => ExpiryNotice::Hold,
+        }
+    }
+
+    /// Forgets the segment, so that its watchers are notified again.
+    pub fn forget(&mut self, key: &SegmentKey) {
+        self.segments.remove(key);
+    }
+
+    /// Forgets all the segments of the topic, e.g. once it has no watchers anymore.
+    pub fn forget_topic(&mut self, stream_id: u32, topic_id: u32) {
+        self.segments
+            .retain(|key, _| key.0 != stream_id || key.1 != topic_id);
+    }
+}
+
+impl System {
+    /// Creates the internal stream and the `__expiry_notifications` topic if they don't exist yet.
+    pub(crate) async fn init_expiry_notifications(&mut self) -> Result<(), IggyError> {
+        if !self.config.expiry_notifications.enabled {
+            info!("Expiry notifications are disabled.");
+            return Ok(());
+        }
+
+        let stream_name = self.config.expiry_notifications.stream.clone();
+        let message_expiry = self.config.expiry_notifications.message_expiry;
+        let (stream_id, topic_id) = self
+            .ensure_internal_topic(&stream_name, EXPIRY_NOTIFICATIONS_TOPIC, message_expiry)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to initialize expiry notifications topic in stream: {stream_name}")
+            })?;
+
+        let grace_period = self.config.expiry_notifications.grace_period;
+        self.expiry_notifications = Some(ExpiryNotifications {
+            stream_id,
+            topic_id,
+            grace_period,
+        });
+        info!("Expiry notifications are enabled with grace period: {grace_period}, notifications will be published to topic with ID: {topic_id} in stream with ID: {stream_id}.");
+        Ok(())
+    }
+
+    /// Notifies the watchers of the topic about the expired segments of the partition and returns the start offsets
+    /// of the segments which can be deleted, that is all of them if the topic has no watchers,
+    /// or the ones whose grace period has elapsed otherwise.
+    pub(crate) async fn hold_expired_segments(
+        &self,
+        topic: &Topic,
+        partition_id: u32,
+        start_offsets: &[u64],
+        now: IggyTimestamp,
+        notices: &mut ExpiryNotices,
+    ) -> Vec<u64> {
+        let Some(expiry_notifications) = &self.expiry_notifications else {
+            return start_offsets.to_vec();
+        };
+
+        if topic.expiry_watchers.is_empty() || topic.stream_id == expiry_notifications.stream_id {
+            notices.forget_topic(topic.stream_id, topic.topic_id);
+            return start_offsets.to_vec();
+        }
+
+        let Ok(partition) = topic.get_partition(partition_id) else {
+            return Vec::new();
+        };
+
+        let mut released = Vec::new();
+        let mut notified = Vec::new();
+        let mut notifications = Vec::new();
+        {
+            let partition = partition.read().await;
+            for start_offset in start_offsets {
+                let key = (topic.stream_id, topic.topic_id, partition_id, *start_offset);
+                match notices.get_notice(key, now, expiry_notifications.grace_period) {
+                    ExpiryNotice::Notify(delete_at) => {
+                        let Some(segment) = partition.get_segment(*start_offset) else {
+                            notices.forget(&key);
+                            continue;
+                        };
+                        notified.push(key);
+                        notifications.push(ExpiryNotification {
+                            stream_id: topic.stream_id,
+                            topic_id: topic.topic_id,
+                            partition_id,
+                            start_offset: segment.start_offset,
+                            end_offset: segment.end_offset,
+                            size_bytes: segment.size_bytes,
+                            delete_at,
+                            watchers: topic.expiry_watchers.clone(),
+                        });
+                    }
+                    ExpiryNotice::Hold => {}
+                    ExpiryNotice::Release => released.push(*start_offset),
+                }
+            }
+        }
+
+        if notifications.is_empty() {
+            return released;
+        }
+
+        let notifications_count = notifications.len();
+        if let Err(error) = self.append_expiry_notifications(notifications).await {
+            error!("Failed to publish {notifications_count} expiry notification(s) for partition with ID: {partition_id}, topic with ID: {}, stream with ID: {}. Error: {error}", topic.topic_id, topic.stream_id);
+            for key in &notified {
+                notices.forget(key);
+            }
+            return released;
+        }
+
+        info!(
+            "Published {notifications_count} expiry notification(s) for partition with ID: {partition_id}, topic with ID: {}, stream with ID: {}, the segments are held for: {}.",
+            topic.topic_id, topic.stream_id, expiry_notifications.grace_period
+        );
+        released
+    }
+
+    async fn append_expiry_notifications(
+        &self,
+        notifications: Vec<ExpiryNotification>,
+    ) -> Result<(), IggyError> {
+        let Some(expiry_notifications) = &self.expiry_notifications else {
+            return Ok(());
+        };
+
+        let topic = self
+            .get_stream_by_id(expiry_notifications.stream_id)?
+            .get_topic(&Identifier::numeric(expiry_notifications.topic_id)?)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - expiry notifications topic with ID: {} in stream with ID: {} not found",
+                    expiry_notifications.topic_id, expiry_notifications.stream_id
+                )
+            })?;
+        let mut messages = Vec::with_capacity(notifications.len());
+        for notification in notifications {
+            let payload = serde_json::to_vec(&notification)
+                .map_err(|_| IggyError::CannotSerializeResource)?;
+            messages.push(Message::new(None, Bytes::from(payload), None));
+        }
+
+        self.append_messages_to_topic(topic, Partitioning::balanced(), messages, None)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expired_segment_should_be_held_until_grace_period_elapses() {
+        let mut notices = ExpiryNotices::default();
+        let key = (1, 2, 3, 100);
+        let grace_period = IggyDuration::from(1000);
+
+        assert_eq!(
+            notices.get_notice(key, IggyTimestamp::from(5000), grace_period),
+            ExpiryNotice::Notify(IggyTimestamp::from(6000))
+        );
+        assert_eq!(
+            notices.get_notice(key, IggyTimestamp::from(5500), grace_period),
+            ExpiryNotice::Hold
+        );
+        assert_eq!(
+            notices.get_notice(key, IggyTimestamp::from(6000), grace_period),
+            ExpiryNotice::Release
+        );
+    }
+
+    #[test]
+    fn forgotten_segments_should_be_notified_again() {
+        let mut notices = ExpiryNotices::default();
+        let grace_period = IggyDuration::from(1000);
+        let now = IggyTimestamp::from(5000);
+        notices.get_notice((1, 2, 3, 100), now, grace_period);
+        notices.get_notice((1, 4, 3, 100), now, grace_period);
+
+        notices.forget_topic(1, 2);
+        assert!(matches!(
+            notices.get_notice((1, 2, 3, 100), now, grace_period),
+            ExpiryNotice::Notify(_)
+        ));
+        assert_eq!(
+            notices.get_notice((1, 4, 3, 100), now, grace_period),
+            ExpiryNotice::Hold
+        );
+    }
+}
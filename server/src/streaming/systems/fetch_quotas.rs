@@ -0,0 +1,213 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::configs::system::FetchQuotasConfig;
+use crate::streaming::polling_consumer::PollingConsumer;
+use crate::streaming::session::Session;
+use crate::streaming::systems::quotas::QuotaBucket;
+use crate::streaming::systems::system::System;
+use crate::streaming::topics::topic::Topic;
+use dashmap::DashMap;
+use iggy::models::user_info::UserId;
+use iggy::utils::byte_size::IggyByteSize;
+use std::time::Duration;
+
+/// The consumer whose polled bytes are charged to the fetch quota.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FetchQuotaKey {
+    User(UserId),
+    Client(u32),
+    ConsumerGroup {
+        stream_id: u32,
+        topic_id: u32,
+        group_id: u32,
+    },
+}
+
+/// Tracks the bytes polled by the users, the clients and the consumer groups, to throttle the ones exceeding their fetch bandwidth quotas.
+/// The quotas of the users can be overridden, the timestamps are expressed in microseconds since the Unix epoch.
+#[derive(Debug)]
+pub struct FetchQuotas {
+    user_bytes_per_second: u64,
+    client_bytes_per_second: u64,
+    consumer_group_bytes_per_second: u64,
+    user_overrides: DashMap<UserId, u64>,
+    buckets: DashMap<FetchQuotaKey, QuotaBucket>,
+}
+
+impl FetchQuotas {
+    pub fn new(config: &FetchQuotasConfig) -> Self {
+        Self {
+            user_bytes_per_second: config.user_bytes_per_second.as_bytes_u64(),
+            client_bytes_per_second: config.client_bytes_per_second.as_bytes_u64(),
+            consumer_group_bytes_per_second: config.consumer_group_bytes_per_second.as_bytes_u64(),
+            user_overrides: DashMap::new(),
+            buckets: DashMap::new(),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.user_bytes_per_second > 0
+            || self.client_bytes_per_second > 0
+            || self.consumer_group_bytes_per_second > 0
+            || !self.user_overrides.is_empty()
+    }
+
+    /// Overrides the server default quota of the user, `None` restores the default one.
+    pub fn set_user_bytes_per_second(
+        &self,
+        user_id: UserId,
+        bytes_per_second: Option<IggyByteSize>,
+    ) {
+        match bytes_per_second {
+            Some(bytes_per_second) => {
+                self.user_overrides
+                    .insert(user_id, bytes_per_second.as_bytes_u64());
+            }
+            None => {
+                self.user_overrides.remove(&user_id);
+            }
+        }
+        self.buckets.remove(&FetchQuotaKey::User(user_id));
+    }
+
+    /// Returns the time in milliseconds the consumer has to wait until none of its quotas is exceeded.
+    pub fn get_throttle_time_ms(&self, keys: &[FetchQuotaKey], now: u64) -> u32 {
+        keys.iter()
+            .filter_map(|key| {
+                let bytes_per_second = self.get_bytes_per_second(key);
+                if bytes_per_second == 0 {
+                    return None;
+                }
+
+                let mut bucket = self.buckets.get_mut(key)?;
+                bucket.refill(bytes_per_second, now);
+                Some(bucket.get_throttle_time_ms(bytes_per_second))
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Charges the polled bytes to the quotas of the consumer, returning the resulting throttle time in milliseconds.
+    pub fn record(&self, keys: &[FetchQuotaKey], bytes: u64, now: u64) -> u32 {
+        keys.iter()
+            .filter_map(|key| {
+                let bytes_per_second = self.get_bytes_per_second(key);
+                if bytes_per_second == 0 {
+                    return None;
+                }
+
+                let mut bucket = self
+                    .buckets
+                    .entry(*key)
+                    .or_insert_with(|| QuotaBucket::new(bytes_per_second, now));
+                bucket.refill(bytes_per_second, now);
+                bucket.charge(bytes);
+                Some(bucket.get_throttle_time_ms(bytes_per_second))
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn get_bytes_per_second(&self, key: &FetchQuotaKey) -> u64 {
+        match key {
+            FetchQuotaKey::User(user_id) => self
+                .user_overrides
+                .get(user_id)
+                .map(|bytes_per_second| *bytes_per_second)
+                .unwrap_or(self.user_bytes_per_second),
+            FetchQuotaKey::Client(_) => self.client_bytes_per_second,
+            FetchQuotaKey::ConsumerGroup { .. } => self.consumer_group_bytes_per_second,
+        }
+    }
+}
+
+impl System {
+    /// Returns the quotas which the bytes polled by the consumer are charged to.
+    pub(crate) fn get_fetch_quota_keys(
+        &self,
+        session: &Session,
+        topic: &Topic,
+        polling_consumer: PollingConsumer,
+    ) -> Vec<FetchQuotaKey> {
+        if !self.fetch_quotas.is_enabled() {
+            return Vec::new();
+        }
+
+        let mut keys = vec![FetchQuotaKey::User(session.get_user_id())];
+        // The stateless sessions (HTTP) have no client.
+        if session.client_id > 0 {
+            keys.push(FetchQuotaKey::Client(session.client_id));
+        }
+        if let PollingConsumer::ConsumerGroup(group_id, _) = polling_consumer {
+            keys.push(FetchQuotaKey::ConsumerGroup {
+                stream_id: topic.stream_id,
+                topic_id: topic.topic_id,
+                group_id,
+            });
+        }
+        keys
+    }
+
+    /// Splits the throttle time of the poll response into the delay of the response, up to the configured maximum,
+    /// and the remaining time reported to the consumer. The response should be delayed without holding the system lock.
+    pub fn split_fetch_throttle_time(&self, throttle_time_ms: u32) -> (Duration, u32) {
+        let max_delay_ms = self
+            .config
+            .fetch_quotas
+            .max_throttle_delay
+            .get_duration()
+            .as_millis()
+            .min(u32::MAX as u128) as u32;
+        let delay_ms = throttle_time_ms.min(max_delay_ms);
+        (
+            Duration::from_millis(delay_ms as u64),
+            throttle_time_ms - delay_ms,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy::utils::duration::IggyDuration;
+
+    #[test]
+    fn consumer_exceeding_quota_should_be_throttled_until_bucket_is_refilled() {
+        let quotas = FetchQuotas::new(&FetchQuotasConfig {
+            user_bytes_per_second: IggyByteSize::from(1000),
+            client_bytes_per_second: IggyByteSize::from(0),
+            consumer_group_bytes_per_second: IggyByteSize::from(0),
+            max_throttle_delay: IggyDuration::from(1_000_000),
+        });
+        let user = [FetchQuotaKey::User(1)];
+        let group = [FetchQuotaKey::ConsumerGroup {
+            stream_id: 1,
+            topic_id: 1,
+            group_id: 1,
+        }];
+
+        assert_eq!(quotas.get_throttle_time_ms(&user, 0), 0);
+        assert_eq!(quotas.record(&user, 1000, 0), 0);
+        assert_eq!(quotas.record(&user, 500, 0), 500);
+        assert_eq!(quotas.get_throttle_time_ms(&user, 200_000), 300);
+        assert_eq!(quotas.get_throttle_time_ms(&user, 500_000), 0);
+        assert_eq!(quotas.record(&group, 1_000_000, 0), 0);
+        assert_eq!(quotas.get_throttle_time_ms(&[FetchQuotaKey::User(2)], 0), 0);
+    }
+}
@@ -0,0 +1,67 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::partitions::index_rebuilds::IndexRebuildFailure;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use crate::streaming::systems::COMPONENT;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use serde::Serialize;
+
+/// The health of the running server, including the problems which don't prevent it from serving the requests.
+#[derive(Debug, Clone, Serialize)]
+pub struct SystemHealth {
+    pub healthy: bool,
+    pub pending_index_rebuilds: u64,
+    pub warnings: Vec<HealthWarning>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum HealthWarning {
+    /// The segment index couldn't be rebuilt in the background, so the log file of the segment is still scanned when reading it.
+    IndexRebuildFailed(IndexRebuildFailure),
+}
+
+impl System {
+    pub fn get_health(&self, session: &Session) -> Result<SystemHealth, IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .get_stats(session.get_user_id())
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to get health for user with ID: {}",
+                    session.get_user_id()
+                )
+            })?;
+
+        let warnings = self
+            .storage
+            .index_rebuilds
+            .get_failures()
+            .into_iter()
+            .map(HealthWarning::IndexRebuildFailed)
+            .collect::<Vec<_>>();
+        Ok(SystemHealth {
+            healthy: warnings.is_empty(),
+            pending_index_rebuilds: self.storage.index_rebuilds.get_pending_count(),
+            warnings,
+        })
+    }
+}
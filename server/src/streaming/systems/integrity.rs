@@ -0,0 +1,498 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::state::system::SystemState;
+use crate::streaming::segments::{INDEX_EXTENSION, LOG_EXTENSION};
+use crate::streaming::systems::system::System;
+use crate::streaming::systems::COMPONENT;
+use ahash::AHashSet;
+use iggy::consumer::ConsumerKind;
+use iggy::error::IggyError;
+use iggy::locking::IggySharedMutFn;
+use iggy::utils::timestamp::IggyTimestamp;
+use serde::Serialize;
+use std::fmt::Display;
+use std::path::Path;
+use tokio::fs;
+use tracing::{error, info, warn};
+
+pub const INTEGRITY_REPORT_FILE: &str = "integrity_report.json";
+
+/// The result of verifying the data directory against the state, produced in the read-only recovery mode.
+#[derive(Debug, Clone, Serialize)]
+pub struct IntegrityReport {
+    pub generated_at: IggyTimestamp,
+    pub streams_count: u32,
+    pub topics_count: u32,
+    pub partitions_count: u32,
+    pub segments_count: u32,
+    pub issues: Vec<IntegrityIssue>,
+}
+
+/// A single problem found while verifying the integrity of the data.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum IntegrityIssue {
+    /// The resource exists in the state, but its directory is missing on disk.
+    MissingOnDisk { resource: String, path: String },
+    /// The directory exists on disk, but the resource is missing in the state.
+    MissingInState { resource: String, path: String },
+    /// The segment log file has no corresponding index file.
+    MissingIndex {
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+        start_offset: u64,
+        path: String,
+    },
+    /// The stored checksum of the message does not match its payload.
+    ChecksumFailure {
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+        start_offset: u64,
+        offset: u64,
+        calculated: u32,
+        expected: u32,
+    },
+    /// The stored checksum of the batch does not match its payload.
+    BatchChecksumFailure {
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+        start_offset: u64,
+        base_offset: u64,
+        calculated: u32,
+        expected: u32,
+    },
+    /// The segment could not be read at all.
+    UnreadableSegment {
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+        start_offset: u64,
+        reason: String,
+    },
+    /// The stored consumer offset points beyond the current offset of the partition.
+    OrphanConsumerOffset {
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+        consumer_kind: ConsumerKind,
+        consumer_id: u32,
+        offset: u64,
+        current_offset: u64,
+    },
+}
+
+impl Default for IntegrityReport {
+    fn default() -> Self {
+        Self {
+            generated_at: IggyTimestamp::now(),
+            streams_count: 0,
+            topics_count: 0,
+            partitions_count: 0,
+            segments_count: 0,
+            issues: Vec::new(),
+        }
+    }
+}
+
+impl IntegrityReport {
+    pub fn is_healthy(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+impl Display for IntegrityReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "integrity report {{ streams: {}, topics: {}, partitions: {}, segments: {}, issues: {} }}",
+            self.streams_count,
+            self.topics_count,
+            self.partitions_count,
+            self.segments_count,
+            self.issues.len()
+        )
+    }
+}
+
+impl Display for IntegrityIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IntegrityIssue::MissingOnDisk { resource, path } => {
+                write!(f, "{resource} exists in state, but is missing on disk at path: {path}")
+            }
+            IntegrityIssue::MissingInState { resource, path } => {
+                write!(f, "{resource} exists on disk at path: {path}, but is missing in state")
+            }
+            IntegrityIssue::MissingIndex {
+                stream_id,
+                topic_id,
+                partition_id,
+                start_offset,
+                path,
+            } => write!(
+                f,
+                "missing index for segment with start offset: {start_offset}, partition ID: {partition_id}, topic ID: {topic_id}, stream ID: {stream_id}, path: {path}"
+            ),
+            IntegrityIssue::ChecksumFailure {
+                stream_id,
+                topic_id,
+                partition_id,
+                start_offset,
+                offset,
+                calculated,
+                expected,
+            } => write!(
+                f,
+                "invalid checksum: {calculated}, expected: {expected} for message at offset: {offset} in segment with start offset: {start_offset}, partition ID: {partition_id}, topic ID: {topic_id}, stream ID: {stream_id}"
+            ),
+            IntegrityIssue::BatchChecksumFailure {
+                stream_id,
+                topic_id,
+                partition_id,
+                start_offset,
+                base_offset,
+                calculated,
+                expected,
+            } => write!(
+                f,
+                "invalid checksum: {calculated}, expected: {expected} for batch with base offset: {base_offset} in segment with start offset: {start_offset}, partition ID: {partition_id}, topic ID: {topic_id}, stream ID: {stream_id}"
+            ),
+            IntegrityIssue::UnreadableSegment {
+                stream_id,
+                topic_id,
+                partition_id,
+                start_offset,
+                reason,
+            } => write!(
+                f,
+                "cannot read segment with start offset: {start_offset}, partition ID: {partition_id}, topic ID: {topic_id}, stream ID: {stream_id}: {reason}"
+            ),
+            IntegrityIssue::OrphanConsumerOffset {
+                stream_id,
+                topic_id,
+                partition_id,
+                consumer_kind,
+                consumer_id,
+                offset,
+                current_offset,
+            } => write!(
+                f,
+                "{consumer_kind} with ID: {consumer_id} has offset: {offset} beyond the current offset: {current_offset} of partition ID: {partition_id}, topic ID: {topic_id}, stream ID: {stream_id}"
+            ),
+        }
+    }
+}
+
+impl System {
+    /// Compares the streams, topics and partitions stored in the state with the directories on disk.
+    /// Must be invoked before loading the streams, as the loading consumes the state.
+    pub(crate) async fn verify_state_consistency(
+        &self,
+        state: &SystemState,
+        report: &mut IntegrityReport,
+    ) {
+        info!("Verifying state consistency with the data on disk...");
+        let streams_path = self.config.get_streams_path();
+        let stream_ids = read_numeric_dir_names(&streams_path).await;
+        let state_stream_ids = state.streams.keys().copied().collect::<AHashSet<u32>>();
+        for stream_id in stream_ids.difference(&state_stream_ids) {
+            report.issues.push(IntegrityIssue::MissingInState {
+                resource: format!("stream with ID: {stream_id}"),
+                path: self.config.get_stream_path(*stream_id),
+            });
+        }
+
+        for stream in state.streams.values() {
+            if !stream_ids.contains(&stream.id) {
+                report.issues.push(IntegrityIssue::MissingOnDisk {
+                    resource: format!("stream with ID: {}", stream.id),
+                    path: self.config.get_stream_path(stream.id),
+                });
+                continue;
+            }
+
+            let topic_ids = read_numeric_dir_names(&self.config.get_topics_path(stream.id)).await;
+            let state_topic_ids = stream.topics.keys().copied().collect::<AHashSet<u32>>();
+            for topic_id in topic_ids.difference(&state_topic_ids) {
+                report.issues.push(IntegrityIssue::MissingInState {
+                    resource: format!(
+                        "topic with ID: {topic_id} for stream with ID: {}",
+                        stream.id
+                    ),
+                    path: self.config.get_topic_path(stream.id, *topic_id),
+                });
+            }
+
+            for topic in stream.topics.values() {
+                if !topic_ids.contains(&topic.id) {
+                    report.issues.push(IntegrityIssue::MissingOnDisk {
+                        resource: format!(
+                            "topic with ID: {} for stream with ID: {}",
+                            topic.id, stream.id
+                        ),
+                        path: self.config.get_topic_path(stream.id, topic.id),
+                    });
+                    continue;
+                }
+
+                let partition_ids =
+                    read_numeric_dir_names(&self.config.get_partitions_path(stream.id, topic.id))
+                        .await;
+                let state_partition_ids =
+                    topic.partitions.keys().copied().collect::<AHashSet<u32>>();
+                for partition_id in partition_ids.difference(&state_partition_ids) {
+                    report.issues.push(IntegrityIssue::MissingInState {
+                        resource: format!(
+                            "partition with ID: {partition_id} for topic with ID: {}, stream with ID: {}",
+                            topic.id, stream.id
+                        ),
+                        path: self
+                            .config
+                            .get_partition_path(stream.id, topic.id, *partition_id),
+                    });
+                }
+
+                for partition_id in topic.partitions.keys() {
+                    if !partition_ids.contains(partition_id) {
+                        report.issues.push(IntegrityIssue::MissingOnDisk {
+                            resource: format!(
+                                "partition with ID: {partition_id} for topic with ID: {}, stream with ID: {}",
+                                topic.id, stream.id
+                            ),
+                            path: self
+                                .config
+                                .get_partition_path(stream.id, topic.id, *partition_id),
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    /// Verifies the loaded partitions: missing indexes, message checksums and consumer offsets.
+    pub(crate) async fn verify_loaded_data(&self, report: &mut IntegrityReport) {
+        info!("Verifying integrity of the loaded data...");
+        for stream in self.streams.values() {
+            report.streams_count += 1;
+            for topic in stream.get_topics() {
+                report.topics_count += 1;
+                for partition in topic.get_partitions() {
+                    report.partitions_count += 1;
+                    let partition = partition.read().await;
+                    for log_start_offset in
+                        find_segments_without_index(&partition.partition_path).await
+                    {
+                        report.issues.push(IntegrityIssue::MissingIndex {
+                            stream_id: partition.stream_id,
+                            topic_id: partition.topic_id,
+                            partition_id: partition.partition_id,
+                            start_offset: log_start_offset,
+                            path: format!(
+                                "{}/{:0>20}.{INDEX_EXTENSION}",
+                                partition.partition_path, log_start_offset
+                            ),
+                        });
+                    }
+
+                    for segment in partition.get_segments() {
+                        report.segments_count += 1;
+                        match segment.load_message_checksums().await {
+                            Ok(()) => {}
+                            Err(IggyError::InvalidMessageChecksum(
+                                calculated,
+                                expected,
+                                offset,
+                            )) => report.issues.push(IntegrityIssue::ChecksumFailure {
+                                stream_id: partition.stream_id,
+                                topic_id: partition.topic_id,
+                                partition_id: partition.partition_id,
+                                start_offset: segment.start_offset,
+                                offset,
+                                calculated,
+                                expected,
+                            }),
+                            Err(IggyError::InvalidBatchChecksum(
+                                calculated,
+                                expected,
+                                base_offset,
+                            )) => report.issues.push(IntegrityIssue::BatchChecksumFailure {
+                                stream_id: partition.stream_id,
+                                topic_id: partition.topic_id,
+                                partition_id: partition.partition_id,
+                                start_offset: segment.start_offset,
+                                base_offset,
+                                calculated,
+                                expected,
+                            }),
+                            Err(error) => report.issues.push(IntegrityIssue::UnreadableSegment {
+                                stream_id: partition.stream_id,
+                                topic_id: partition.topic_id,
+                                partition_id: partition.partition_id,
+                                start_offset: segment.start_offset,
+                                reason: error.to_string(),
+                            }),
+                        }
+                    }
+
+                    let consumer_offsets = partition
+                        .consumer_offsets
+                        .iter()
+                        .chain(partition.consumer_group_offsets.iter());
+                    for consumer_offset in consumer_offsets {
+                        if consumer_offset.offset <= partition.current_offset {
+                            continue;
+                        }
+
+                        report.issues.push(IntegrityIssue::OrphanConsumerOffset {
+                            stream_id: partition.stream_id,
+                            topic_id: partition.topic_id,
+                            partition_id: partition.partition_id,
+                            consumer_kind: consumer_offset.kind,
+                            consumer_id: consumer_offset.consumer_id,
+                            offset: consumer_offset.offset,
+                            current_offset: partition.current_offset,
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    /// Logs the integrity report and stores it as JSON in the runtime directory.
+    pub(crate) async fn save_integrity_report(
+        &self,
+        report: &IntegrityReport,
+    ) -> Result<(), IggyError> {
+        if report.is_healthy() {
+            info!("Recovery mode - no integrity issues were found, {report}.");
+        } else {
+            warn!("Recovery mode - found integrity issues, {report}:");
+            for issue in &report.issues {
+                warn!("{issue}");
+            }
+        }
+
+        let path = format!("{}/{INTEGRITY_REPORT_FILE}", self.config.get_runtime_path());
+        let content = serde_json::to_vec_pretty(report).map_err(|error| {
+            error!("{COMPONENT} (error: {error}) - failed to serialize integrity report");
+            IggyError::CannotSerializeResource
+        })?;
+        fs::write(&path, content).await.map_err(|error| {
+            error!(
+                "{COMPONENT} (error: {error}) - failed to write integrity report to path: {path}"
+            );
+            IggyError::CannotWriteToFile
+        })?;
+        info!("Integrity report was saved to path: {path}");
+        Ok(())
+    }
+}
+
+async fn read_numeric_dir_names(path: &str) -> AHashSet<u32> {
+    let mut ids = AHashSet::new();
+    let Ok(mut dir_entries) = fs::read_dir(path).await else {
+        return ids;
+    };
+
+    while let Some(dir_entry) = dir_entries.next_entry().await.unwrap_or(None) {
+        if !dir_entry.path().is_dir() {
+            continue;
+        }
+
+        if let Some(id) = dir_entry
+            .file_name()
+            .to_str()
+            .and_then(|name| name.parse::<u32>().ok())
+        {
+            ids.insert(id);
+        }
+    }
+    ids
+}
+
+async fn find_segments_without_index(partition_path: &str) -> Vec<u64> {
+    let mut start_offsets = Vec::new();
+    let Ok(mut dir_entries) = fs::read_dir(partition_path).await else {
+        return start_offsets;
+    };
+
+    while let Some(dir_entry) = dir_entries.next_entry().await.unwrap_or(None) {
+        let path = dir_entry.path();
+        let extension = path.extension();
+        if extension.is_none() || extension.unwrap() != LOG_EXTENSION {
+            continue;
+        }
+
+        let Some(start_offset) = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .and_then(|stem| stem.parse::<u64>().ok())
+        else {
+            continue;
+        };
+
+        if !Path::new(&path.with_extension(INDEX_EXTENSION)).exists() {
+            start_offsets.push(start_offset);
+        }
+    }
+    start_offsets.sort();
+    start_offsets
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn should_find_segments_without_index() {
+        let tempdir = tempfile::TempDir::new().unwrap();
+        let path = tempdir.path().to_str().unwrap();
+        for (start_offset, with_index) in [(0, true), (1000, false), (2000, false)] {
+            fs::write(format!("{path}/{start_offset:0>20}.{LOG_EXTENSION}"), [])
+                .await
+                .unwrap();
+            if with_index {
+                fs::write(format!("{path}/{start_offset:0>20}.{INDEX_EXTENSION}"), [])
+                    .await
+                    .unwrap();
+            }
+        }
+
+        let start_offsets = find_segments_without_index(path).await;
+
+        assert_eq!(start_offsets, vec![1000, 2000]);
+    }
+
+    #[tokio::test]
+    async fn should_read_only_numeric_directory_names() {
+        let tempdir = tempfile::TempDir::new().unwrap();
+        let path = tempdir.path().to_str().unwrap();
+        fs::create_dir(format!("{path}/1")).await.unwrap();
+        fs::create_dir(format!("{path}/2")).await.unwrap();
+        fs::create_dir(format!("{path}/invalid")).await.unwrap();
+        fs::write(format!("{path}/3"), []).await.unwrap();
+
+        let ids = read_numeric_dir_names(path).await;
+
+        assert_eq!(ids, AHashSet::from_iter([1, 2]));
+    }
+}
@@ -0,0 +1,99 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use crate::VERSION;
+use iggy::error::IggyError;
+use iggy::models::server_info::{ServerInfo, ServerLimits};
+
+impl System {
+    pub fn get_server_info(&self, session: &Session) -> Result<ServerInfo, IggyError> {
+        self.ensure_authenticated(session)?;
+        let limits = &self.config.limits;
+        Ok(ServerInfo {
+            version: VERSION.to_owned(),
+            limits: ServerLimits {
+                max_streams: limits.max_streams,
+                max_topics_per_stream: limits.max_topics_per_stream,
+                max_partitions_per_topic: limits.max_partitions_per_topic,
+                max_total_partitions: limits.max_total_partitions,
+            },
+        })
+    }
+
+    pub(crate) fn ensure_streams_limit(&self) -> Result<(), IggyError> {
+        let max_streams = self.config.limits.max_streams;
+        if max_streams > 0 && self.streams.len() as u32 >= max_streams {
+            return Err(IggyError::StreamsLimitReached(max_streams));
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn ensure_topics_limit(&self, stream_id: u32) -> Result<(), IggyError> {
+        let max_topics = self.config.limits.max_topics_per_stream;
+        if max_topics == 0 {
+            return Ok(());
+        }
+
+        let topics_count = self
+            .streams
+            .get(&stream_id)
+            .map_or(0, |stream| stream.get_topics_count());
+        if topics_count >= max_topics {
+            return Err(IggyError::TopicsLimitReached(stream_id, max_topics));
+        }
+
+        Ok(())
+    }
+
+    /// Ensures that the partitions can be added to the topic currently having `current_partitions_count` partitions.
+    pub(crate) fn ensure_partitions_limit(
+        &self,
+        current_partitions_count: u32,
+        partitions_count: u32,
+    ) -> Result<(), IggyError> {
+        let limits = &self.config.limits;
+        if limits.max_partitions_per_topic > 0
+            && current_partitions_count + partitions_count > limits.max_partitions_per_topic
+        {
+            return Err(IggyError::PartitionsLimitReached(
+                partitions_count,
+                limits.max_partitions_per_topic,
+            ));
+        }
+
+        if limits.max_total_partitions > 0 {
+            let total_partitions_count = self
+                .streams
+                .values()
+                .flat_map(|stream| stream.topics.values())
+                .map(|topic| topic.get_partitions_count())
+                .sum::<u32>();
+            if total_partitions_count + partitions_count > limits.max_total_partitions {
+                return Err(IggyError::TotalPartitionsLimitReached(
+                    partitions_count,
+                    limits.max_total_partitions,
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
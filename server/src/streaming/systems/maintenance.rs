@@ -0,0 +1,96 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use crate::streaming::systems::COMPONENT;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::models::maintenance_mode::MaintenanceMode;
+use iggy::utils::timestamp::IggyTimestamp;
+use tracing::info;
+
+impl System {
+    pub fn get_maintenance_mode(&self, session: &Session) -> Result<&MaintenanceMode, IggyError> {
+        self.ensure_authenticated(session)?;
+        Ok(&self.maintenance_mode)
+    }
+
+    /// Enables or disables the maintenance mode. As it requires the exclusive access to the system,
+    /// the already running requests and background jobs finish before it takes effect,
+    /// then all the buffered messages are saved on disk.
+    pub async fn set_maintenance_mode(
+        &mut self,
+        session: &Session,
+        enabled: bool,
+        reject_reads: bool,
+        message: Option<String>,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .set_maintenance_mode(session.get_user_id())
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to set maintenance mode for user with ID: {}",
+                    session.get_user_id()
+                )
+            })?;
+
+        if !enabled {
+            self.maintenance_mode = MaintenanceMode::default();
+            info!(
+                "Maintenance mode was disabled by user with ID: {}.",
+                session.get_user_id()
+            );
+            return Ok(());
+        }
+
+        let saved_messages = self.persist_messages().await.with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to save buffered messages before entering maintenance mode")
+        })?;
+        let enabled_at = self
+            .maintenance_mode
+            .enabled_at
+            .unwrap_or_else(IggyTimestamp::now);
+        self.maintenance_mode = MaintenanceMode {
+            enabled: true,
+            reject_reads,
+            message,
+            enabled_at: Some(enabled_at),
+        };
+        info!(
+            "Maintenance mode was enabled by user with ID: {}, rejecting reads: {reject_reads}, saved {saved_messages} buffered messages.",
+            session.get_user_id()
+        );
+        Ok(())
+    }
+
+    /// Returns `true` if the server is in maintenance mode, during which the background jobs modifying the data are paused.
+    pub fn is_in_maintenance(&self) -> bool {
+        self.maintenance_mode.enabled
+    }
+
+    /// Fails with the retryable `ServerInMaintenance` error if the request can't be handled in maintenance mode.
+    pub fn ensure_not_in_maintenance(&self, is_read: bool) -> Result<(), IggyError> {
+        if !self.maintenance_mode.enabled || (is_read && !self.maintenance_mode.reject_reads) {
+            return Ok(());
+        }
+
+        Err(IggyError::ServerInMaintenance)
+    }
+}
@@ -0,0 +1,175 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::{SharedSystem, System};
+use crate::streaming::systems::COMPONENT;
+use crate::streaming::topics::topic::Topic;
+use bytes::Bytes;
+use error_set::ErrContext;
+use flume::{Receiver, Sender};
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::models::message_audit::{MessageAuditRecord, MESSAGE_AUDIT_TOPIC};
+use iggy::utils::timestamp::IggyTimestamp;
+use rand::Rng;
+use tracing::{error, info, warn};
+
+/// The internal topic to which the provenance of the sampled messages is published, along with the queue
+/// of the records waiting to be appended by the publisher task.
+#[derive(Debug)]
+pub struct MessageAudit {
+    stream_id: u32,
+    topic_id: u32,
+    sample_rate: f64,
+    sender: Sender<MessageAuditRecord>,
+    receiver: Receiver<MessageAuditRecord>,
+}
+
+impl System {
+    /// Creates the internal stream and the `__message_audit` topic if they don't exist yet.
+    pub(crate) async fn init_message_audit(&mut self) -> Result<(), IggyError> {
+        if !self.config.message_audit.enabled {
+            info!("Message audit is disabled.");
+            return Ok(());
+        }
+
+        let stream_name = self.config.message_audit.stream.clone();
+        let message_expiry = self.config.message_audit.message_expiry;
+        let (stream_id, topic_id) = self
+            .ensure_internal_topic(&stream_name, MESSAGE_AUDIT_TOPIC, message_expiry)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to initialize message audit topic in stream: {stream_name}")
+            })?;
+
+        let sample_rate = self.config.message_audit.sample_rate;
+        let (sender, receiver) = flume::unbounded();
+        self.message_audit = Some(MessageAudit {
+            stream_id,
+            topic_id,
+            sample_rate,
+            sender,
+            receiver,
+        });
+        info!("Message audit is enabled with sample rate: {sample_rate}, records will be published to topic with ID: {topic_id} in stream with ID: {stream_id}.");
+        Ok(())
+    }
+
+    /// Enqueues the provenance of the sampled messages sent by the client, unless they're sent to the internal stream itself.
+    /// The IDs of the sampled messages are assigned upfront, so that the records can refer to them.
+    pub(crate) fn audit_messages(
+        &self,
+        session: &Session,
+        topic: &Topic,
+        messages: &mut [Message],
+    ) {
+        let Some(message_audit) = &self.message_audit else {
+            return;
+        };
+
+        if topic.stream_id == message_audit.stream_id {
+            return;
+        }
+
+        let received_at = IggyTimestamp::now();
+        let ip_address = session.ip_address.to_string();
+        let mut rng = rand::rng();
+        for message in messages.iter_mut() {
+            if !rng.random_bool(message_audit.sample_rate) {
+                continue;
+            }
+
+            topic.assign_messages_ids(std::slice::from_mut(message));
+            let record = MessageAuditRecord {
+                stream_id: topic.stream_id,
+                topic_id: topic.topic_id,
+                message_id: message.id,
+                user_id: session.get_user_id(),
+                client_id: session.client_id,
+                ip_address: ip_address.clone(),
+                received_at,
+            };
+            if let Err(error) = message_audit.sender.send(record) {
+                error!(
+                    "{COMPONENT} (error: {error}) - failed to enqueue the message audit record."
+                );
+                return;
+            }
+        }
+    }
+
+    async fn append_message_audit_records(
+        &self,
+        records: Vec<MessageAuditRecord>,
+    ) -> Result<(), IggyError> {
+        let Some(message_audit) = &self.message_audit else {
+            return Ok(());
+        };
+
+        let topic = self
+            .get_stream_by_id(message_audit.stream_id)?
+            .get_topic(&Identifier::numeric(message_audit.topic_id)?)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - message audit topic with ID: {} in stream with ID: {} not found",
+                    message_audit.topic_id, message_audit.stream_id
+                )
+            })?;
+        let mut messages = Vec::with_capacity(records.len());
+        for record in records {
+            let payload =
+                serde_json::to_vec(&record).map_err(|_| IggyError::CannotSerializeResource)?;
+            messages.push(Message::new(None, Bytes::from(payload), None));
+        }
+
+        self.append_messages_to_topic(topic, Partitioning::balanced(), messages, None)
+            .await
+    }
+}
+
+/// Starts the task appending the enqueued audit records to the `__message_audit` topic in batches.
+pub fn start_publisher(system: SharedSystem) {
+    tokio::spawn(async move {
+        let Some(receiver) = system
+            .read()
+            .await
+            .message_audit
+            .as_ref()
+            .map(|message_audit| message_audit.receiver.clone())
+        else {
+            return;
+        };
+
+        while let Ok(record) = receiver.recv_async().await {
+            let mut records = vec![record];
+            records.extend(receiver.drain());
+            let records_count = records.len();
+            if let Err(error) = system
+                .read()
+                .await
+                .append_message_audit_records(records)
+                .await
+            {
+                error!("Failed to publish {records_count} message audit record(s). Error: {error}");
+            }
+        }
+        warn!("Message audit publisher stopped receiving records.");
+    });
+}
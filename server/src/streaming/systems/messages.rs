@@ -17,21 +17,34 @@
  */
 
 use crate::streaming::cache::memory_tracker::CacheMemoryTracker;
+use crate::streaming::diagnostics::telemetry_metrics::TelemetryMetrics;
+use crate::streaming::polling_consumer::PollingConsumer;
+use crate::streaming::segments::message_slices::PolledMessageSlices;
 use crate::streaming::session::Session;
+use crate::streaming::systems::fetch_quotas::FetchQuotaKey;
 use crate::streaming::systems::system::System;
 use crate::streaming::systems::COMPONENT;
+use crate::streaming::topics::topic::Topic;
 use bytes::Bytes;
 use error_set::ErrContext;
 use iggy::confirmation::Confirmation;
-use iggy::consumer::Consumer;
-use iggy::messages::poll_messages::PollingStrategy;
+use iggy::consumer::{Consumer, ConsumerKind};
+use iggy::locking::IggySharedMutFn;
+use iggy::messages::message_filter::MessageFilter;
+use iggy::messages::poll_messages::{IsolationLevel, PollingKind, PollingStrategy};
 use iggy::messages::send_messages::Message;
 use iggy::messages::send_messages::Partitioning;
-use iggy::models::messages::{PolledMessage, PolledMessages};
+use iggy::messages::send_messages::SendMessages;
+use iggy::messages::send_messages_batch::TopicMessages;
+use iggy::models::messages::{MessagesGapReason, PolledMessage, PolledMessages};
+use iggy::models::metadata_change::MetadataChange;
+use iggy::models::transaction::Transaction;
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::sizeable::Sizeable;
+use iggy::utils::timestamp::IggyTimestamp;
 use iggy::{error::IggyError, identifier::Identifier};
-use tracing::{error, trace};
+use std::time::Instant;
+use tracing::{error, trace, warn};
 
 impl System {
     pub async fn poll_messages(
@@ -43,6 +56,279 @@ impl System {
         partition_id: Option<u32>,
         args: PollingArgs,
     ) -> Result<PolledMessages, IggyError> {
+        let (topic, polled_partition) = self
+            .prepare_polling(session, consumer, stream_id, topic_id, partition_id, &args)
+            .await?;
+        let Some(PolledPartition {
+            polling_consumer,
+            partition_id,
+            quota_keys,
+            throttle_time_ms,
+        }) = polled_partition
+        else {
+            return Ok(PolledMessages {
+                messages: vec![],
+                partition_id: 0,
+                current_offset: 0,
+                remaining_messages: 0,
+                partition_epoch: 0,
+                gaps: Vec::new(),
+                chunk_sizes: Vec::new(),
+                throttle_time_ms: 0,
+                topic_delete_at: topic.get_delete_at_micros(),
+            });
+        };
+
+        // The response of the consumer exceeding its fetch quota is trimmed, without reading any messages.
+        if throttle_time_ms > 0 {
+            let partition = topic.get_partition(partition_id)?;
+            let partition = partition.read().await;
+            return Ok(PolledMessages {
+                messages: vec![],
+                partition_id,
+                current_offset: partition.current_offset,
+                remaining_messages: 0,
+                partition_epoch: partition.epoch,
+                gaps: Vec::new(),
+                chunk_sizes: Vec::new(),
+                throttle_time_ms,
+                topic_delete_at: topic.get_delete_at_micros(),
+            });
+        }
+
+        let count = match args.chunk_size {
+            0 => args.count,
+            chunk_size => args.count.div_ceil(chunk_size).saturating_mul(chunk_size),
+        };
+        // The offsets of the consumer group in the acknowledgement mode are committed only by the acknowledgements.
+        let visibility_timeout = match polling_consumer {
+            PollingConsumer::ConsumerGroup(group_id, _) => {
+                topic
+                    .get_consumer_group_by_id(group_id)?
+                    .read()
+                    .await
+                    .visibility_timeout
+            }
+            PollingConsumer::Consumer(_, _) => None,
+        };
+        let in_flight = match (polling_consumer, visibility_timeout) {
+            (PollingConsumer::ConsumerGroup(group_id, _), Some(visibility_timeout))
+                if args.strategy.kind == PollingKind::Next
+                    && !args.peek
+                    && !self.is_read_only() =>
+            {
+                Some((group_id, visibility_timeout))
+            }
+            _ => None,
+        };
+
+        let now = Instant::now();
+        let (mut polled_messages, redelivered_offsets) = match in_flight {
+            Some((group_id, _)) => {
+                self.poll_in_flight_messages(
+                    topic,
+                    polling_consumer,
+                    group_id,
+                    partition_id,
+                    count,
+                    args.isolation_level,
+                    args.filter.as_ref(),
+                )
+                .await
+                .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to poll in-flight messages, consumer group ID: {group_id}, partition ID: {partition_id}"))?
+            }
+            None => (
+                topic
+                    .get_messages(
+                        polling_consumer,
+                        partition_id,
+                        args.strategy,
+                        count,
+                        args.isolation_level,
+                        args.filter.as_ref(),
+                    )
+                    .await?,
+                Vec::new(),
+            ),
+        };
+        TelemetryMetrics::get_instance().record_poll(
+            topic.stream_id,
+            topic.topic_id,
+            polled_messages.messages.len() as u64,
+            polled_messages
+                .messages
+                .iter()
+                .map(|message| message.get_size_bytes().as_bytes_u64())
+                .sum(),
+            now.elapsed(),
+        );
+        self.decrypt_polled_messages(&mut polled_messages)?;
+        // The peeked messages are returned as they are, without tracking their deliveries or dead-lettering them.
+        let group_id = match polling_consumer {
+            PollingConsumer::ConsumerGroup(group_id, _) if !args.peek => Some(group_id),
+            _ => None,
+        };
+        if let Some(group_id) = group_id {
+            self.dead_letter_polled_messages(topic, group_id, &mut polled_messages)
+                .await
+                .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to dead letter polled messages, consumer group ID: {group_id}, partition ID: {partition_id}"))?;
+        }
+        // The redelivered messages which have been dead-lettered or are no longer available won't be acknowledged by the member.
+        let dropped_offsets = redelivered_offsets
+            .iter()
+            .filter(|offset| {
+                !polled_messages
+                    .messages
+                    .iter()
+                    .any(|message| message.offset == **offset)
+            })
+            .copied()
+            .collect::<Vec<_>>();
+        if args.chunk_size > 0 {
+            split_into_chunks(&mut polled_messages, args.chunk_size);
+        }
+        if !quota_keys.is_empty() {
+            let polled_bytes = polled_messages
+                .messages
+                .iter()
+                .map(|message| message.get_size_bytes().as_bytes_u64())
+                .sum::<u64>();
+            polled_messages.throttle_time_ms = self.fetch_quotas.record(
+                &quota_keys,
+                polled_bytes,
+                IggyTimestamp::now().as_micros(),
+            );
+        }
+        if let Some(group_id) = group_id {
+            self.record_deliveries(topic, group_id, &polled_messages)
+                .await?;
+        }
+
+        let last_polled_offset = get_last_polled_offset(&polled_messages);
+        if let Some((group_id, visibility_timeout)) = in_flight {
+            // The redelivered messages don't move the next offset to be delivered for the first time.
+            let last_polled_offset = last_polled_offset.filter(|_| redelivered_offsets.is_empty());
+            self.track_in_flight_messages(
+                topic,
+                group_id,
+                visibility_timeout,
+                &polled_messages,
+                last_polled_offset,
+                &dropped_offsets,
+            )
+            .await
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to track in-flight messages, consumer group ID: {group_id}, partition ID: {partition_id}"))?;
+            return Ok(polled_messages);
+        }
+
+        if visibility_timeout.is_none() {
+            self.auto_commit_polled_offset(
+                topic,
+                consumer,
+                polling_consumer,
+                partition_id,
+                last_polled_offset,
+                &args,
+            )
+            .await?;
+        }
+
+        Ok(polled_messages)
+    }
+
+    /// Polls the messages persisted in the partition segments, with their payloads left in the log files,
+    /// so that they can be sent without copying them into memory. Returns `None` if the messages
+    /// have to be polled using `poll_messages`, e.g. when they're encrypted, filtered or polled by the consumer group.
+    pub async fn poll_message_slices(
+        &self,
+        session: &Session,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        args: &PollingArgs,
+    ) -> Result<Option<PolledMessageSlices>, IggyError> {
+        if self.encryptor.is_some()
+            || args.count == 0
+            || args.chunk_size > 0
+            || args.filter.is_some()
+            || consumer.kind != ConsumerKind::Consumer
+        {
+            return Ok(None);
+        }
+
+        // The consumer without any partition assigned or throttled gets the response of the regular polling.
+        let (topic, polled_partition) = self
+            .prepare_polling(session, consumer, stream_id, topic_id, partition_id, args)
+            .await?;
+        let Some(PolledPartition {
+            polling_consumer,
+            partition_id,
+            quota_keys,
+            ..
+        }) = polled_partition.filter(|partition| partition.throttle_time_ms == 0)
+        else {
+            return Ok(None);
+        };
+
+        let now = Instant::now();
+        let Some(mut polled_messages) = topic
+            .get_message_slices(
+                polling_consumer,
+                partition_id,
+                args.strategy,
+                args.count,
+                args.isolation_level,
+            )
+            .await?
+        else {
+            return Ok(None);
+        };
+        TelemetryMetrics::get_instance().record_poll(
+            topic.stream_id,
+            topic.topic_id,
+            polled_messages.messages.len() as u64,
+            polled_messages.get_messages_size_bytes(),
+            now.elapsed(),
+        );
+
+        if !quota_keys.is_empty() {
+            polled_messages.throttle_time_ms = self.fetch_quotas.record(
+                &quota_keys,
+                polled_messages.get_messages_size_bytes(),
+                IggyTimestamp::now().as_micros(),
+            );
+        }
+
+        let last_polled_offset = polled_messages
+            .messages
+            .last()
+            .map(|message| message.offset);
+        self.auto_commit_polled_offset(
+            topic,
+            consumer,
+            polling_consumer,
+            partition_id,
+            last_polled_offset,
+            args,
+        )
+        .await?;
+
+        Ok(Some(polled_messages))
+    }
+
+    /// Checks the permissions and resolves the partition to poll the messages from, along with the polling consumer,
+    /// verifying the expected epoch of the partition and the fetch quotas of the consumer.
+    /// The partition is `None` if it's the consumer group member without any partitions assigned.
+    async fn prepare_polling(
+        &self,
+        session: &Session,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        args: &PollingArgs,
+    ) -> Result<(&Topic, Option<PolledPartition>), IggyError> {
         self.ensure_authenticated(session)?;
         if args.count == 0 {
             return Err(IggyError::InvalidMessagesCount);
@@ -63,39 +349,70 @@ impl System {
         }
 
         // There might be no partition assigned, if it's the consumer group member without any partitions.
+        // The peeking member stays on its current partition, instead of moving to the next one.
         let Some((polling_consumer, partition_id)) = topic
-            .resolve_consumer_with_partition_id(consumer, session.client_id, partition_id, true)
+            .resolve_consumer_with_partition_id(consumer, session.client_id, partition_id, !args.peek)
             .await
             .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to resolve consumer with partition id, consumer: {consumer}, client ID: {}, partition ID: {:?}", session.client_id, partition_id))? else {
-            return Ok(PolledMessages {
-                messages: vec![],
-                partition_id: 0,
-                current_offset: 0,
-            })
+            return Ok((topic, None));
         };
 
-        let mut polled_messages = topic
-            .get_messages(polling_consumer, partition_id, args.strategy, args.count)
-            .await?;
-
-        if polled_messages.messages.is_empty() {
-            return Ok(polled_messages);
-        }
-
-        let offset = polled_messages.messages.last().unwrap().offset;
-        if args.auto_commit {
-            trace!("Last offset: {} will be automatically stored for {}, stream: {}, topic: {}, partition: {}", offset, consumer, stream_id, topic_id, partition_id);
+        if let Some(partition_epoch) = args.partition_epoch {
             topic
-                .store_consumer_offset_internal(polling_consumer, offset, partition_id)
-                .await
-                .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to store consumer offset internal, polling consumer: {}, offset: {}, partition ID: {}", polling_consumer, offset, partition_id)) ?;
+                .check_partition_epoch(partition_id, partition_epoch)
+                .await?;
         }
 
-        if self.encryptor.is_none() {
-            return Ok(polled_messages);
+        let quota_keys = self.get_fetch_quota_keys(session, topic, polling_consumer);
+        let throttle_time_ms = self
+            .fetch_quotas
+            .get_throttle_time_ms(&quota_keys, IggyTimestamp::now().as_micros());
+        Ok((
+            topic,
+            Some(PolledPartition {
+                polling_consumer,
+                partition_id,
+                quota_keys,
+                throttle_time_ms,
+            }),
+        ))
+    }
+
+    /// Stores the offset of the last polled message for the consumer, unless the auto-commit is disabled
+    /// or the messages are only peeked.
+    async fn auto_commit_polled_offset(
+        &self,
+        topic: &Topic,
+        consumer: &Consumer,
+        polling_consumer: PollingConsumer,
+        partition_id: u32,
+        last_polled_offset: Option<u64>,
+        args: &PollingArgs,
+    ) -> Result<(), IggyError> {
+        let Some(offset) = last_polled_offset else {
+            return Ok(());
+        };
+
+        if !args.auto_commit || args.peek || self.is_read_only() {
+            return Ok(());
         }
 
-        let encryptor = self.encryptor.as_ref().unwrap();
+        trace!("Last offset: {} will be automatically stored for {}, stream: {}, topic: {}, partition: {}", offset, consumer, topic.stream_id, topic.topic_id, partition_id);
+        topic
+            .store_consumer_offset_internal(polling_consumer, offset, partition_id)
+            .await
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to store consumer offset internal, polling consumer: {}, offset: {}, partition ID: {}", polling_consumer, offset, partition_id))
+    }
+
+    /// Decrypts the payloads of the polled messages, if the server-side encryption is enabled.
+    pub(crate) fn decrypt_polled_messages(
+        &self,
+        polled_messages: &mut PolledMessages,
+    ) -> Result<(), IggyError> {
+        let Some(encryptor) = self.encryptor.as_ref() else {
+            return Ok(());
+        };
+
         let mut decrypted_messages = Vec::with_capacity(polled_messages.messages.len());
         for message in polled_messages.messages.iter() {
             let payload = encryptor.decrypt(&message.payload);
@@ -119,7 +436,7 @@ impl System {
             }
         }
         polled_messages.messages = decrypted_messages;
-        Ok(polled_messages)
+        Ok(())
     }
 
     pub async fn append_messages(
@@ -128,11 +445,155 @@ impl System {
         stream_id: Identifier,
         topic_id: Identifier,
         partitioning: Partitioning,
-        messages: Vec<Message>,
+        mut messages: Vec<Message>,
         confirmation: Option<Confirmation>,
     ) -> Result<(), IggyError> {
         self.ensure_authenticated(session)?;
-        let topic = self.find_topic(session, &stream_id, &topic_id).with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream ID: {stream_id}, topic_id: {topic_id}"))?;
+        let topic = self.prepare_messages(session, &stream_id, &topic_id, &mut messages)?;
+        if let Some(transaction_id) = Self::get_messages_transaction_id(&messages)? {
+            return self
+                .append_messages_within_transaction(
+                    session,
+                    topic,
+                    transaction_id,
+                    partitioning,
+                    messages,
+                    confirmation,
+                )
+                .await;
+        }
+
+        let Some(cluster) = self.cluster.as_ref() else {
+            return self
+                .append_messages_to_topic(topic, partitioning, messages, confirmation)
+                .await;
+        };
+
+        // The replicas must store the messages with the same IDs in the same partition as the leader.
+        topic.assign_messages_ids(&mut messages);
+        let partition_id = cluster.resolve_partition_id(topic, &partitioning).with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to resolve partition led by this node for stream ID: {}, topic ID: {}", topic.stream_id, topic.topic_id))?;
+        let replicated_messages = messages.clone();
+        self.append_messages_to_topic(
+            topic,
+            Partitioning::partition_id(partition_id),
+            messages,
+            confirmation,
+        )
+        .await?;
+        cluster.replicate_messages(topic, partition_id, replicated_messages);
+        Ok(())
+    }
+
+    /// Appends the messages to many topics and partitions, either all of them or none of them.
+    /// All the batches are checked before any of them is appended, and unless the messages are sent within the transaction,
+    /// they're appended within the implicit one (with its ID set in their headers), committed once all the batches
+    /// have been appended, or aborted otherwise, so that the read-committed consumers never see a part of them.
+    pub async fn append_messages_batch(
+        &self,
+        session: &Session,
+        mut batches: Vec<TopicMessages>,
+        confirmation: Option<Confirmation>,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        if batches.len() == 1 {
+            if let Some(batch) = batches.pop() {
+                return self
+                    .append_messages(
+                        session,
+                        batch.stream_id,
+                        batch.topic_id,
+                        batch.partitioning,
+                        batch.messages,
+                        confirmation,
+                    )
+                    .await;
+            }
+        }
+
+        let mut transaction_id = None;
+        let mut prepared_batches = Vec::with_capacity(batches.len());
+        for (index, mut batch) in batches.into_iter().enumerate() {
+            let topic = self.prepare_messages(
+                session,
+                &batch.stream_id,
+                &batch.topic_id,
+                &mut batch.messages,
+            )?;
+            let batch_transaction_id = Self::get_messages_transaction_id(&batch.messages)?;
+            if index > 0 && batch_transaction_id != transaction_id {
+                return Err(IggyError::InvalidTransactionHeader);
+            }
+
+            transaction_id = batch_transaction_id;
+            prepared_batches.push((topic, batch.partitioning, batch.messages));
+        }
+
+        if let Some(transaction_id) = transaction_id {
+            // The transaction started by the client is committed or aborted by the client as well.
+            return self
+                .append_batches_within_transaction(
+                    session,
+                    Transaction { transaction_id },
+                    prepared_batches,
+                    confirmation,
+                )
+                .await;
+        }
+
+        let transaction = self.begin_transaction(session).await.with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to begin transaction for messages batches, session: {session}")
+        })?;
+        let transaction_id = transaction.transaction_id;
+        if let Err(error) = self
+            .append_batches_within_transaction(session, transaction, prepared_batches, confirmation)
+            .await
+        {
+            if let Err(abort_error) = self.abort_transaction(session, transaction_id).await {
+                error!("Failed to abort transaction with ID: {transaction_id} for messages batches. {abort_error}");
+            }
+            return Err(error);
+        }
+
+        self.commit_transaction(session, transaction_id)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to commit transaction with ID: {transaction_id} for messages batches")
+            })
+    }
+
+    async fn append_batches_within_transaction(
+        &self,
+        session: &Session,
+        transaction: Transaction,
+        batches: Vec<(&Topic, Partitioning, Vec<Message>)>,
+        confirmation: Option<Confirmation>,
+    ) -> Result<(), IggyError> {
+        for (topic, partitioning, mut messages) in batches {
+            for message in messages.iter_mut() {
+                transaction.set_headers(&mut message.headers)?;
+            }
+            self.append_messages_within_transaction(
+                session,
+                topic,
+                transaction.transaction_id,
+                partitioning,
+                messages,
+                confirmation,
+            )
+            .await?;
+        }
+        Ok(())
+    }
+
+    /// Checks whether the session can append the messages to the topic and prepares them to be appended.
+    fn prepare_messages(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        messages: &mut [Message],
+    ) -> Result<&Topic, IggyError> {
+        let topic = self.find_topic(session, stream_id, topic_id).with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream ID: {stream_id}, topic_id: {topic_id}"))?;
         self.permissioner.append_messages(
             session.get_user_id(),
             topic.stream_id,
@@ -143,7 +604,86 @@ impl System {
             topic.stream_id,
             topic.topic_id
         ))?;
+        topic
+            .ensure_producer_allowed(session.get_user_id())
+            .with_error_context(|error| {
+                format!(
+            "{COMPONENT} (error: {error}) - producer not allowed for stream ID: {}, topic ID: {}",
+            topic.stream_id,
+            topic.topic_id
+        )
+            })?;
+        self.fence_producers(topic, messages)?;
+        self.enforce_stream_quota(session, stream_id, messages)?;
+        self.audit_messages(session, topic, messages);
+        Ok(topic)
+    }
+
+    /// Appends the messages forwarded by the leader of the partition, without checking the permissions.
+    pub(crate) async fn append_replicated_messages(
+        &self,
+        command: SendMessages,
+    ) -> Result<(), IggyError> {
+        let topic = self
+            .get_stream_in_namespace(None, &command.stream_id)?
+            .get_topic(&command.topic_id)?;
+        self.append_messages_to_topic(topic, command.partitioning, command.messages, None)
+            .await
+    }
 
+    /// Rejects the batch exceeding the hard quota of the stream and reports the crossing of the soft quota.
+    fn enforce_stream_quota(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        messages: &[Message],
+    ) -> Result<(), IggyError> {
+        let stream = self
+            .get_stream(session, stream_id)
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to get stream with ID: {stream_id}")
+            })?;
+        let batch_size_bytes = messages
+            .iter()
+            .map(|message| message.get_size_bytes().as_bytes_u64())
+            .sum::<u64>();
+        self.ensure_namespace_storage_quota(stream, batch_size_bytes)
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - rejected {batch_size_bytes} bytes for stream ID: {}", stream.stream_id)
+            })?;
+        let Some(size) = stream.check_quota(batch_size_bytes).with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - rejected {batch_size_bytes} bytes for stream ID: {}", stream.stream_id)
+        })?
+        else {
+            return Ok(());
+        };
+
+        warn!(
+            "Stream with ID: {} has exceeded its soft quota: {}, size: {}.",
+            stream.stream_id,
+            stream.quota.soft_limit,
+            IggyByteSize::from(size)
+        );
+        self.publish_metadata_change(
+            session,
+            MetadataChange::StreamSoftQuotaExceeded {
+                stream_id: stream.stream_id,
+                size: IggyByteSize::from(size),
+                soft_limit: stream.quota.soft_limit,
+            },
+        );
+        Ok(())
+    }
+
+    /// Appends the messages to the topic without checking the permissions,
+    /// used directly only for the messages produced by the server itself.
+    pub(crate) async fn append_messages_to_topic(
+        &self,
+        topic: &Topic,
+        partitioning: Partitioning,
+        messages: Vec<Message>,
+        confirmation: Option<Confirmation>,
+    ) -> Result<(), IggyError> {
         let mut batch_size_bytes = IggyByteSize::default();
         let mut messages = messages;
         if let Some(encryptor) = &self.encryptor {
@@ -174,10 +714,18 @@ impl System {
             }
         }
         let messages_count = messages.len() as u64;
+        let now = Instant::now();
         topic
             .append_messages(batch_size_bytes, partitioning, messages, confirmation)
             .await?;
         self.metrics.increment_messages(messages_count);
+        TelemetryMetrics::get_instance().record_append(
+            topic.stream_id,
+            topic.topic_id,
+            messages_count,
+            batch_size_bytes.as_bytes_u64(),
+            now.elapsed(),
+        );
         Ok(())
     }
 
@@ -212,14 +760,431 @@ pub struct PollingArgs {
     pub strategy: PollingStrategy,
     pub count: u32,
     pub auto_commit: bool,
+    pub isolation_level: IsolationLevel,
+    pub chunk_size: u32,
+    pub filter: Option<MessageFilter>,
+    pub partition_epoch: Option<u64>,
+    /// Whether to return the messages without committing the offset or affecting the delivery state of the consumer group.
+    pub peek: bool,
 }
 
 impl PollingArgs {
-    pub fn new(strategy: PollingStrategy, count: u32, auto_commit: bool) -> Self {
+    pub fn new(
+        strategy: PollingStrategy,
+        count: u32,
+        auto_commit: bool,
+        isolation_level: IsolationLevel,
+    ) -> Self {
         Self {
             strategy,
             count,
             auto_commit,
+            isolation_level,
+            chunk_size: 0,
+            filter: None,
+            partition_epoch: None,
+            peek: false,
         }
     }
+
+    pub fn with_chunk_size(mut self, chunk_size: u32) -> Self {
+        self.chunk_size = chunk_size;
+        self
+    }
+
+    pub fn with_filter(mut self, filter: Option<MessageFilter>) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    pub fn with_partition_epoch(mut self, partition_epoch: Option<u64>) -> Self {
+        self.partition_epoch = partition_epoch;
+        self
+    }
+
+    pub fn with_peek(mut self, peek: bool) -> Self {
+        self.peek = peek;
+        self
+    }
+}
+
+/// The partition resolved for polling the messages, along with the fetch quotas of the consumer.
+struct PolledPartition {
+    polling_consumer: PollingConsumer,
+    partition_id: u32,
+    quota_keys: Vec<FetchQuotaKey>,
+    throttle_time_ms: u32,
+}
+
+/// Returns the offset of the last polled message, including the skipped messages of the aborted transactions,
+/// not matching the filter or dead-lettered, which are committed as well, so that they're not polled again.
+fn get_last_polled_offset(polled_messages: &PolledMessages) -> Option<u64> {
+    let last_aborted_offset = polled_messages
+        .gaps
+        .iter()
+        .filter(|gap| {
+            gap.reason == MessagesGapReason::Aborted
+                || gap.reason == MessagesGapReason::Filtered
+                || gap.reason == MessagesGapReason::DeadLettered
+        })
+        .map(|gap| gap.end_offset)
+        .max();
+    let last_polled_offset = polled_messages
+        .messages
+        .last()
+        .map(|message| message.offset);
+    last_polled_offset.max(last_aborted_offset)
+}
+
+/// Splits the polled messages into the chunks of the given size. The incomplete last chunk is left
+/// for the next poll if there are more messages in the partition, so that it can be filled up then.
+fn split_into_chunks(polled_messages: &mut PolledMessages, chunk_size: u32) {
+    let messages_count = polled_messages.messages.len() as u32;
+    let full_chunks = messages_count / chunk_size;
+    let mut last_chunk_size = messages_count % chunk_size;
+    if last_chunk_size > 0 && full_chunks > 0 && polled_messages.remaining_messages > 0 {
+        polled_messages
+            .messages
+            .truncate((full_chunks * chunk_size) as usize);
+        polled_messages.remaining_messages += last_chunk_size as u64;
+        last_chunk_size = 0;
+        // The gaps after the withheld messages are reported again by the next poll.
+        if let Some(last_offset) = polled_messages
+            .messages
+            .last()
+            .map(|message| message.offset)
+        {
+            polled_messages
+                .gaps
+                .retain(|gap| gap.start_offset < last_offset);
+        }
+    }
+
+    polled_messages.chunk_sizes = vec![chunk_size; full_chunks as usize];
+    if last_chunk_size > 0 {
+        polled_messages.chunk_sizes.push(last_chunk_size);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binary::mapper;
+    use crate::configs::server::{DataMaintenanceConfig, PersonalAccessTokenConfig};
+    use crate::configs::system::{CacheConfig, SystemConfig};
+    use crate::state::{MockState, StateKind};
+    use crate::streaming::persistence::persister::{FileWithSyncPersister, PersisterKind};
+    use crate::streaming::segments::message_slices::Slice;
+    use crate::streaming::storage::SystemStorage;
+    use crate::streaming::users::user::User;
+    use iggy::compression::compression_algorithm::CompressionAlgorithm;
+    use iggy::messages::message_id_scheme::MessageIdScheme;
+    use iggy::models::messages::{MessageState, MessagesGap};
+    use iggy::models::metadata::ResourceMetadata;
+    use iggy::system::handshake::Handshake;
+    use iggy::topics::cleanup_policy::CleanupPolicy;
+    use iggy::users::defaults::{DEFAULT_ROOT_PASSWORD, DEFAULT_ROOT_USERNAME};
+    use iggy::utils::expiry::IggyExpiry;
+    use iggy::utils::topic_size::MaxTopicSize;
+    use std::net::{Ipv4Addr, SocketAddr};
+    use std::os::unix::fs::FileExt;
+    use std::sync::Arc;
+    use tempfile::TempDir;
+
+    const STREAM_ID: u32 = 1;
+    const TOPIC_ID: u32 = 1;
+    const PARTITION_ID: u32 = 1;
+    const MESSAGES_COUNT: u32 = 10;
+
+    fn polled_messages(count: u64, remaining_messages: u64) -> PolledMessages {
+        let messages = (0..count)
+            .map(|offset| {
+                PolledMessage::create(
+                    offset,
+                    MessageState::Available,
+                    0.into(),
+                    offset as u128,
+                    Bytes::new(),
+                    0,
+                    None,
+                )
+            })
+            .collect();
+        PolledMessages {
+            partition_id: 1,
+            current_offset: count + remaining_messages - 1,
+            remaining_messages,
+            partition_epoch: 1,
+            throttle_time_ms: 0,
+            topic_delete_at: 0,
+            gaps: Vec::new(),
+            chunk_sizes: Vec::new(),
+            messages,
+        }
+    }
+
+    #[test]
+    fn should_split_messages_into_chunks_of_given_size() {
+        let mut polled_messages = polled_messages(10, 0);
+        split_into_chunks(&mut polled_messages, 4);
+        assert_eq!(polled_messages.chunk_sizes, vec![4, 4, 2]);
+        assert_eq!(polled_messages.messages.len(), 10);
+    }
+
+    #[test]
+    fn should_withhold_incomplete_last_chunk_when_more_messages_are_available() {
+        let mut polled_messages = polled_messages(10, 5);
+        polled_messages.gaps.push(MessagesGap {
+            start_offset: 10,
+            end_offset: 11,
+            reason: MessagesGapReason::Aborted,
+        });
+        split_into_chunks(&mut polled_messages, 4);
+        assert_eq!(polled_messages.chunk_sizes, vec![4, 4]);
+        assert_eq!(polled_messages.messages.len(), 8);
+        assert_eq!(polled_messages.remaining_messages, 7);
+        assert!(polled_messages.gaps.is_empty());
+    }
+
+    #[test]
+    fn should_return_single_incomplete_chunk() {
+        let mut polled_messages = polled_messages(3, 5);
+        split_into_chunks(&mut polled_messages, 4);
+        assert_eq!(polled_messages.chunk_sizes, vec![3]);
+        assert_eq!(polled_messages.messages.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn should_return_same_responses_with_and_without_zero_copy() {
+        let tempdir = TempDir::new().unwrap();
+        let (system, session) = init_system_with_messages(&tempdir).await;
+        let scenario = [
+            (PollingStrategy::offset(0), 3, false),
+            (PollingStrategy::first(), MESSAGES_COUNT, false),
+            (PollingStrategy::last(), 2, false),
+            (PollingStrategy::offset(4), 3, false),
+            (PollingStrategy::next(), 4, true),
+            (PollingStrategy::next(), 4, true),
+            (PollingStrategy::next(), 4, true),
+            (PollingStrategy::next(), 4, true),
+        ];
+
+        let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+        let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+        let zero_copy_consumer = Consumer::new(Identifier::numeric(1).unwrap());
+        let consumer = Consumer::new(Identifier::numeric(2).unwrap());
+
+        // The large payloads are left in the segment files to be sent from there.
+        let message_slices = system
+            .poll_message_slices(
+                &session,
+                &zero_copy_consumer,
+                &stream_id,
+                &topic_id,
+                Some(PARTITION_ID),
+                &PollingArgs::new(
+                    PollingStrategy::first(),
+                    MESSAGES_COUNT,
+                    false,
+                    IsolationLevel::default(),
+                ),
+            )
+            .await
+            .unwrap()
+            .expect("Messages should be polled as slices");
+        assert!(message_slices
+            .messages
+            .iter()
+            .any(|message| matches!(message.payload, Slice::File { .. })));
+
+        for (strategy, count, auto_commit) in scenario {
+            let args = PollingArgs::new(strategy, count, auto_commit, IsolationLevel::default())
+                .with_partition_epoch(Some(0));
+            let zero_copy_response =
+                poll_response(&system, &session, &zero_copy_consumer, &args, true).await;
+            let response = poll_response(&system, &session, &consumer, &args, false).await;
+            assert_eq!(
+                zero_copy_response, response,
+                "Responses differ for strategy: {strategy}, count: {count}, auto commit: {auto_commit}"
+            );
+        }
+
+        for consumer in [zero_copy_consumer, consumer] {
+            let consumer_offset = system
+                .get_consumer_offset(
+                    &session,
+                    &consumer,
+                    &stream_id,
+                    &topic_id,
+                    Some(PARTITION_ID),
+                )
+                .await
+                .unwrap()
+                .expect("Consumer offset should be stored");
+            assert_eq!(consumer_offset.stored_offset, (MESSAGES_COUNT - 1) as u64);
+        }
+    }
+
+    /// Polls the messages the way the binary handler does, using the message slices if the zero-copy is enabled,
+    /// and returns the response as it's sent to the client.
+    async fn poll_response(
+        system: &System,
+        session: &Session,
+        consumer: &Consumer,
+        args: &PollingArgs,
+        zero_copy: bool,
+    ) -> Vec<u8> {
+        let features = Handshake::from_flags(u32::MAX);
+        let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+        let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+        if zero_copy {
+            let message_slices = system
+                .poll_message_slices(
+                    session,
+                    consumer,
+                    &stream_id,
+                    &topic_id,
+                    Some(PARTITION_ID),
+                    args,
+                )
+                .await
+                .unwrap();
+            if let Some(message_slices) = message_slices {
+                return read_slices(&mapper::map_message_slices(&message_slices, features, true));
+            }
+        }
+
+        let polled_messages = system
+            .poll_messages(
+                session,
+                consumer,
+                &stream_id,
+                &topic_id,
+                Some(PARTITION_ID),
+                PollingArgs::new(
+                    args.strategy,
+                    args.count,
+                    args.auto_commit,
+                    args.isolation_level,
+                )
+                .with_partition_epoch(args.partition_epoch),
+            )
+            .await
+            .unwrap();
+        mapper::map_polled_messages(&polled_messages, features, true)
+            .iter()
+            .flat_map(|bytes| bytes.iter().copied())
+            .collect()
+    }
+
+    fn read_slices(slices: &[Slice]) -> Vec<u8> {
+        let mut response = Vec::new();
+        for slice in slices {
+            match slice {
+                Slice::Bytes(bytes) => response.extend_from_slice(bytes),
+                Slice::File {
+                    file,
+                    position,
+                    length,
+                } => {
+                    let mut buffer = vec![0; *length as usize];
+                    file.read_exact_at(&mut buffer, *position).unwrap();
+                    response.extend_from_slice(&buffer);
+                }
+            }
+        }
+        response
+    }
+
+    /// Creates the system without the cache, so that the messages are polled from the segments,
+    /// with the small and large (sent from the files) payloads persisted in the partition.
+    async fn init_system_with_messages(tempdir: &TempDir) -> (System, Session) {
+        let config = Arc::new(SystemConfig {
+            path: tempdir.path().to_str().unwrap().to_string(),
+            cache: CacheConfig {
+                enabled: false,
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+        let storage = SystemStorage::new(
+            config.clone(),
+            Arc::new(PersisterKind::FileWithSync(FileWithSyncPersister {})),
+        );
+        let mut system = System::create(
+            config,
+            storage,
+            Arc::new(StateKind::Mock(MockState::new())),
+            None,
+            DataMaintenanceConfig::default(),
+            PersonalAccessTokenConfig::default(),
+        );
+        let root = User::root(DEFAULT_ROOT_USERNAME, DEFAULT_ROOT_PASSWORD);
+        system
+            .permissioner
+            .init_permissions_for_user(root.id, root.permissions.clone());
+        let session = Session::new(
+            1,
+            root.id,
+            SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234),
+        );
+        system.users.insert(root.id, root);
+
+        system
+            .create_stream(
+                &session,
+                Some(STREAM_ID),
+                "stream",
+                ResourceMetadata::default(),
+                None,
+            )
+            .await
+            .unwrap();
+        let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+        system
+            .create_topic(
+                &session,
+                &stream_id,
+                Some(TOPIC_ID),
+                "topic",
+                1,
+                IggyExpiry::NeverExpire,
+                CompressionAlgorithm::None,
+                MaxTopicSize::ServerDefault,
+                None,
+                ResourceMetadata::default(),
+                MessageIdScheme::ServerDefault,
+                CleanupPolicy::ServerDefault,
+            )
+            .await
+            .unwrap();
+
+        let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+        let messages = (0..MESSAGES_COUNT)
+            .map(|index| {
+                let payload = if index % 2 == 0 {
+                    Bytes::from(format!("message-{index}"))
+                } else {
+                    Bytes::from(vec![index as u8; 5000])
+                };
+                Message::new(Some(index as u128 + 1), payload, None)
+            })
+            .collect();
+        system
+            .append_messages(
+                &session,
+                stream_id.clone(),
+                topic_id.clone(),
+                Partitioning::partition_id(PARTITION_ID),
+                messages,
+                None,
+            )
+            .await
+            .unwrap();
+        system
+            .flush_unsaved_buffer(&session, stream_id, topic_id, PARTITION_ID, true)
+            .await
+            .unwrap();
+        (system, session)
+    }
 }
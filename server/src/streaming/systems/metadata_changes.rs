@@ -0,0 +1,257 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::state::command::EntryCommand;
+use crate::state::models::{CreateStreamWithId, CreateTopicWithId};
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::{SharedSystem, System};
+use crate::streaming::systems::COMPONENT;
+use bytes::Bytes;
+use error_set::ErrContext;
+use flume::{Receiver, Sender};
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::message_id_scheme::MessageIdScheme;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::models::metadata::ResourceMetadata;
+use iggy::models::metadata_change::{MetadataChange, MetadataChangeEvent, METADATA_CHANGES_TOPIC};
+use iggy::streams::create_stream::CreateStream;
+use iggy::topics::cleanup_policy::CleanupPolicy;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::users::defaults::DEFAULT_ROOT_USER_ID;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::timestamp::IggyTimestamp;
+use iggy::utils::topic_size::MaxTopicSize;
+use std::net::{Ipv4Addr, SocketAddr};
+use tracing::{error, info, warn};
+
+/// The internal topic to which the metadata changes are published, along with the queue
+/// of the changes waiting to be appended by the publisher task.
+#[derive(Debug)]
+pub struct MetadataChanges {
+    stream_id: u32,
+    topic_id: u32,
+    sender: Sender<MetadataChangeEvent>,
+    receiver: Receiver<MetadataChangeEvent>,
+}
+
+impl System {
+    /// Creates the internal stream and the `__metadata_changes` topic if they don't exist yet.
+    pub(crate) async fn init_metadata_changes(&mut self) -> Result<(), IggyError> {
+        if !self.config.metadata_changes.enabled {
+            info!("Metadata changes capture is disabled.");
+            return Ok(());
+        }
+
+        let stream_name = self.config.metadata_changes.stream.clone();
+        let message_expiry = self.config.metadata_changes.message_expiry;
+        let (stream_id, topic_id) = self
+            .ensure_internal_topic(&stream_name, METADATA_CHANGES_TOPIC, message_expiry)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to initialize metadata changes topic in stream: {stream_name}")
+            })?;
+
+        let (sender, receiver) = flume::unbounded();
+        self.metadata_changes = Some(MetadataChanges {
+            stream_id,
+            topic_id,
+            sender,
+            receiver,
+        });
+        info!("Metadata changes capture is enabled, changes will be published to topic with ID: {topic_id} in stream with ID: {stream_id}.");
+        Ok(())
+    }
+
+    /// Creates the internal stream and the single-partition topic if they don't exist yet,
+    /// returning their identifiers.
+    pub(crate) async fn ensure_internal_topic(
+        &mut self,
+        stream_name: &str,
+        topic_name: &str,
+        message_expiry: IggyExpiry,
+    ) -> Result<(u32, u32), IggyError> {
+        let session = Session::stateless(
+            DEFAULT_ROOT_USER_ID,
+            SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 0),
+        );
+        let stream_id = match self.get_stream_id_by_name(None, stream_name) {
+            Some(stream_id) => stream_id,
+            None => {
+                let stream_id = self
+                    .create_stream(
+                        &session,
+                        None,
+                        stream_name,
+                        ResourceMetadata::default(),
+                        None,
+                    )
+                    .await
+                    .with_error_context(|error| {
+                        format!("{COMPONENT} (error: {error}) - failed to create internal stream: {stream_name}")
+                    })?
+                    .stream_id;
+                let command = CreateStream {
+                    stream_id: Some(stream_id),
+                    name: stream_name.to_owned(),
+                    metadata: ResourceMetadata::default(),
+                    namespace_id: None,
+                };
+                self.state
+                    .apply(
+                        DEFAULT_ROOT_USER_ID,
+                        EntryCommand::CreateStream(CreateStreamWithId { stream_id, command }),
+                    )
+                    .await
+                    .with_error_context(|error| {
+                        format!("{COMPONENT} (error: {error}) - failed to apply create internal stream: {stream_name}")
+                    })?;
+                stream_id
+            }
+        };
+
+        let existing_topic_id = self
+            .get_stream_by_id(stream_id)?
+            .topics_ids
+            .get(topic_name)
+            .copied();
+        let topic_id = match existing_topic_id {
+            Some(topic_id) => topic_id,
+            None => {
+                let topic = self
+                    .create_topic(
+                        &session,
+                        &Identifier::numeric(stream_id)?,
+                        None,
+                        topic_name,
+                        1,
+                        message_expiry,
+                        CompressionAlgorithm::default(),
+                        MaxTopicSize::ServerDefault,
+                        None,
+                        ResourceMetadata::default(),
+                        MessageIdScheme::ServerDefault,
+                        CleanupPolicy::ServerDefault,
+                    )
+                    .await
+                    .with_error_context(|error| {
+                        format!("{COMPONENT} (error: {error}) - failed to create internal topic: {topic_name} in stream: {stream_name}")
+                    })?;
+                let topic_id = topic.topic_id;
+                let command = CreateTopic {
+                    stream_id: Identifier::numeric(stream_id)?,
+                    topic_id: Some(topic_id),
+                    partitions_count: 1,
+                    compression_algorithm: topic.compression_algorithm,
+                    message_expiry: topic.message_expiry,
+                    max_topic_size: topic.max_topic_size,
+                    replication_factor: Some(topic.replication_factor),
+                    name: topic_name.to_owned(),
+                    metadata: ResourceMetadata::default(),
+                    message_id_scheme: topic.message_id_scheme,
+                    cleanup_policy: topic.cleanup_policy,
+                };
+                self.state
+                    .apply(
+                        DEFAULT_ROOT_USER_ID,
+                        EntryCommand::CreateTopic(CreateTopicWithId { topic_id, command }),
+                    )
+                    .await
+                    .with_error_context(|error| {
+                        format!("{COMPONENT} (error: {error}) - failed to apply create internal topic: {topic_name} in stream: {stream_name}")
+                    })?;
+                topic_id
+            }
+        };
+        Ok((stream_id, topic_id))
+    }
+
+    /// Enqueues the change made by the user to be published, unless it affects the internal stream itself.
+    pub(crate) fn publish_metadata_change(&self, session: &Session, change: MetadataChange) {
+        let Some(metadata_changes) = &self.metadata_changes else {
+            return;
+        };
+
+        if change.stream_id() == metadata_changes.stream_id {
+            return;
+        }
+
+        let event = MetadataChangeEvent {
+            user_id: session.get_user_id(),
+            timestamp: IggyTimestamp::now(),
+            change,
+        };
+        if let Err(error) = metadata_changes.sender.send(event) {
+            error!("{COMPONENT} (error: {error}) - failed to enqueue the metadata change.");
+        }
+    }
+
+    async fn append_metadata_changes(
+        &self,
+        events: Vec<MetadataChangeEvent>,
+    ) -> Result<(), IggyError> {
+        let Some(metadata_changes) = &self.metadata_changes else {
+            return Ok(());
+        };
+
+        let topic = self
+            .get_stream_by_id(metadata_changes.stream_id)?
+            .get_topic(&Identifier::numeric(metadata_changes.topic_id)?)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - metadata changes topic with ID: {} in stream with ID: {} not found",
+                    metadata_changes.topic_id, metadata_changes.stream_id
+                )
+            })?;
+        let mut messages = Vec::with_capacity(events.len());
+        for event in events {
+            let payload =
+                serde_json::to_vec(&event).map_err(|_| IggyError::CannotSerializeResource)?;
+            messages.push(Message::new(None, Bytes::from(payload), None));
+        }
+
+        self.append_messages_to_topic(topic, Partitioning::balanced(), messages, None)
+            .await
+    }
+}
+
+/// Starts the task appending the enqueued metadata changes to the `__metadata_changes` topic in batches.
+pub fn start_publisher(system: SharedSystem) {
+    tokio::spawn(async move {
+        let Some(receiver) = system
+            .read()
+            .await
+            .metadata_changes
+            .as_ref()
+            .map(|metadata_changes| metadata_changes.receiver.clone())
+        else {
+            return;
+        };
+
+        while let Ok(event) = receiver.recv_async().await {
+            let mut events = vec![event];
+            events.extend(receiver.drain());
+            let events_count = events.len();
+            if let Err(error) = system.read().await.append_metadata_changes(events).await {
+                error!("Failed to publish {events_count} metadata change(s). Error: {error}");
+            }
+        }
+        warn!("Metadata changes publisher stopped receiving changes.");
+    });
+}
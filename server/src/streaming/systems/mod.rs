@@ -16,19 +16,42 @@
  * under the License.
  */
 
+pub mod acknowledgements;
+pub mod aggregates;
+pub mod audit_log;
+pub mod backups;
+pub mod cache_warmup;
 pub mod clients;
+pub mod cluster;
 pub mod consumer_groups;
 pub mod consumer_offsets;
+pub mod dead_letters;
+pub mod expiry_notifications;
+pub mod fetch_quotas;
+pub mod health;
 pub mod info;
+pub mod integrity;
+pub mod limits;
+pub mod maintenance;
+pub mod message_audit;
 pub mod messages;
+pub mod metadata_changes;
+pub mod namespaces;
+pub mod partition_state;
 pub mod partitions;
 pub mod personal_access_tokens;
+pub mod producers;
+pub mod push_subscriptions;
+pub mod quotas;
+pub mod replay;
 pub mod snapshot;
 pub mod stats;
 pub mod storage;
 pub mod streams;
 pub mod system;
+pub mod topic_snapshots;
 pub mod topics;
+pub mod transactions;
 pub mod users;
 
 pub const COMPONENT: &str = "STREAMING_SYSTEMS";
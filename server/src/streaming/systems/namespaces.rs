@@ -0,0 +1,767 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::state::command::EntryCommand;
+use crate::state::models::CreateNamespaceWithId;
+use crate::state::system::NamespaceState;
+use crate::streaming::namespaces::namespace::Namespace;
+use crate::streaming::session::Session;
+use crate::streaming::streams::stream::Stream;
+use crate::streaming::systems::system::System;
+use crate::streaming::systems::COMPONENT;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::identifier::{IdKind, Identifier};
+use iggy::models::audit_entry::AuditAction;
+use iggy::models::namespace::{Namespace as NamespaceInfo, NamespaceDetails, NamespaceQuotas};
+use iggy::namespaces::create_namespace::CreateNamespace;
+use iggy::namespaces::delete_namespace::DeleteNamespace;
+use iggy::namespaces::update_namespace::UpdateNamespace;
+use iggy::utils::byte_size::IggyByteSize;
+use std::sync::atomic::{AtomicU32, Ordering};
+use tracing::{error, info};
+
+static CURRENT_NAMESPACE_ID: AtomicU32 = AtomicU32::new(1);
+
+impl System {
+    pub(crate) fn load_namespaces(&mut self, namespaces: Vec<NamespaceState>) {
+        info!("Loading namespaces...");
+        for namespace_state in namespaces {
+            let mut namespace = Namespace::new(
+                namespace_state.id,
+                &namespace_state.name,
+                namespace_state.quotas,
+            );
+            namespace.created_at = namespace_state.created_at;
+            self.namespaces_ids
+                .insert(namespace.name.clone(), namespace.namespace_id);
+            self.namespaces.insert(namespace.namespace_id, namespace);
+        }
+
+        let current_namespace_id = self.namespaces.keys().max().unwrap_or(&0);
+        CURRENT_NAMESPACE_ID.store(current_namespace_id + 1, Ordering::SeqCst);
+        info!("Loaded {} namespace(s).", self.namespaces.len());
+    }
+
+    pub fn find_namespaces(&self, session: &Session) -> Result<Vec<&Namespace>, IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .get_namespaces(session.get_user_id())
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to get namespaces for user with ID: {}",
+                    session.get_user_id()
+                )
+            })?;
+        let mut namespaces = self.namespaces.values().collect::<Vec<_>>();
+        namespaces.sort_by_key(|namespace| namespace.namespace_id);
+        Ok(namespaces)
+    }
+
+    pub fn try_find_namespace(
+        &self,
+        session: &Session,
+        identifier: &Identifier,
+    ) -> Result<Option<&Namespace>, IggyError> {
+        self.ensure_authenticated(session)?;
+        let Some(namespace) = self.try_get_namespace(identifier)? else {
+            return Ok(None);
+        };
+
+        self.permissioner
+            .get_namespace(session.get_user_id(), namespace.namespace_id)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to get namespace with ID: {identifier} for user with ID: {}",
+                    session.get_user_id()
+                )
+            })?;
+        Ok(Some(namespace))
+    }
+
+    pub fn try_get_namespace(
+        &self,
+        identifier: &Identifier,
+    ) -> Result<Option<&Namespace>, IggyError> {
+        match identifier.kind {
+            IdKind::Numeric => Ok(self.namespaces.get(&identifier.get_u32_value()?)),
+            IdKind::String => Ok(self
+                .namespaces_ids
+                .get(identifier.get_cow_str_value()?.as_ref())
+                .and_then(|namespace_id| self.namespaces.get(namespace_id))),
+        }
+    }
+
+    pub fn get_namespace(&self, identifier: &Identifier) -> Result<&Namespace, IggyError> {
+        self.try_get_namespace(identifier)?
+            .ok_or_else(|| match identifier.kind {
+                IdKind::Numeric => {
+                    IggyError::NamespaceIdNotFound(identifier.get_u32_value().unwrap_or_default())
+                }
+                IdKind::String => IggyError::NamespaceNameNotFound(identifier.to_string()),
+            })
+    }
+
+    /// Returns the streams of the namespace, sorted by their IDs.
+    pub fn get_namespace_streams(&self, namespace_id: u32) -> Vec<&Stream> {
+        let mut streams = self
+            .streams
+            .values()
+            .filter(|stream| stream.namespace_id == Some(namespace_id))
+            .collect::<Vec<_>>();
+        streams.sort_by_key(|stream| stream.stream_id);
+        streams
+    }
+
+    /// Returns the namespace along with the number of its streams and users, and the size of its streams.
+    pub fn get_namespace_info(&self, namespace: &Namespace) -> NamespaceInfo {
+        let streams = self.get_namespace_streams(namespace.namespace_id);
+        NamespaceInfo {
+            id: namespace.namespace_id,
+            created_at: namespace.created_at.into(),
+            name: namespace.name.clone(),
+            quotas: namespace.quotas,
+            streams_count: streams.len() as u32,
+            users_count: self.get_namespace_user_ids(namespace.namespace_id).len() as u32,
+            size: IggyByteSize::from(
+                streams
+                    .iter()
+                    .map(|stream| stream.get_size().as_bytes_u64())
+                    .sum::<u64>(),
+            ),
+            messages_count: streams
+                .iter()
+                .map(|stream| stream.get_messages_count())
+                .sum(),
+        }
+    }
+
+    /// Refreshes the per-namespace metrics, derived from the current streams and users of each namespace.
+    pub fn refresh_namespace_metrics(&self) {
+        for namespace in self.namespaces.values() {
+            self.metrics
+                .record_namespace(&self.get_namespace_info(namespace));
+        }
+    }
+
+    pub fn get_namespace_details(&self, namespace: &Namespace) -> NamespaceDetails {
+        let info = self.get_namespace_info(namespace);
+        NamespaceDetails {
+            id: info.id,
+            created_at: info.created_at,
+            name: info.name,
+            quotas: info.quotas,
+            streams_count: info.streams_count,
+            users_count: info.users_count,
+            size: info.size,
+            messages_count: info.messages_count,
+            stream_ids: self
+                .get_namespace_streams(namespace.namespace_id)
+                .iter()
+                .map(|stream| stream.stream_id)
+                .collect(),
+            user_ids: self.get_namespace_user_ids(namespace.namespace_id),
+        }
+    }
+
+    /// Returns the IDs of the users of the namespace, sorted in ascending order.
+    pub fn get_namespace_user_ids(&self, namespace_id: u32) -> Vec<u32> {
+        let mut user_ids = self
+            .users
+            .values()
+            .filter(|user| user.namespace_id == Some(namespace_id))
+            .map(|user| user.id)
+            .collect::<Vec<_>>();
+        user_ids.sort_unstable();
+        user_ids
+    }
+
+    pub async fn create_namespace(
+        &mut self,
+        session: &Session,
+        namespace_id: Option<u32>,
+        name: &str,
+        quotas: NamespaceQuotas,
+    ) -> Result<&Namespace, IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .create_namespace(session.get_user_id())
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to create namespace for user with ID: {}",
+                    session.get_user_id()
+                )
+            })?;
+        if self.namespaces_ids.contains_key(name) {
+            return Err(IggyError::NamespaceNameAlreadyExists(name.to_owned()));
+        }
+
+        let id = match namespace_id {
+            Some(namespace_id) => namespace_id,
+            None => loop {
+                let id = CURRENT_NAMESPACE_ID.fetch_add(1, Ordering::SeqCst);
+                if !self.namespaces.contains_key(&id) {
+                    break id;
+                }
+            },
+        };
+        if id == 0 {
+            return Err(IggyError::InvalidNamespaceId);
+        }
+
+        if self.namespaces.contains_key(&id) {
+            error!("Namespace with ID: {id} already exists.");
+            return Err(IggyError::InvalidNamespaceId);
+        }
+
+        self.replicate(session, || {
+            EntryCommand::CreateNamespace(CreateNamespaceWithId {
+                namespace_id: id,
+                command: CreateNamespace {
+                    name: name.to_owned(),
+                    quotas,
+                },
+            })
+        })
+        .await?;
+        CURRENT_NAMESPACE_ID.fetch_max(id + 1, Ordering::SeqCst);
+        self.namespaces_ids.insert(name.to_owned(), id);
+        self.namespaces.insert(id, Namespace::new(id, name, quotas));
+        info!("Created namespace with ID: {id}, name: '{name}', quotas: {quotas}.");
+        self.audit(
+            session,
+            AuditAction::CreateNamespace,
+            format!("namespaces/{id}"),
+        );
+        self.get_namespace(&Identifier::numeric(id)?)
+    }
+
+    pub async fn update_namespace(
+        &mut self,
+        session: &Session,
+        identifier: &Identifier,
+        quotas: NamespaceQuotas,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .update_namespace(session.get_user_id())
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to update namespace for user with ID: {}",
+                    session.get_user_id()
+                )
+            })?;
+        let namespace_id = self
+            .get_namespace(identifier)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to get namespace with ID: {identifier}"
+                )
+            })?
+            .namespace_id;
+        self.replicate(session, || {
+            EntryCommand::UpdateNamespace(UpdateNamespace {
+                namespace_id: identifier.clone(),
+                quotas,
+            })
+        })
+        .await?;
+        if let Some(namespace) = self.namespaces.get_mut(&namespace_id) {
+            namespace.quotas = quotas;
+        }
+        info!("Updated namespace with ID: {namespace_id}, quotas: {quotas}.");
+        self.audit(
+            session,
+            AuditAction::UpdateNamespace,
+            format!("namespaces/{namespace_id}"),
+        );
+        Ok(())
+    }
+
+    /// Deletes the namespace, which must not contain any streams or users anymore.
+    pub async fn delete_namespace(
+        &mut self,
+        session: &Session,
+        identifier: &Identifier,
+    ) -> Result<u32, IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .delete_namespace(session.get_user_id())
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to delete namespace for user with ID: {}",
+                    session.get_user_id()
+                )
+            })?;
+        let namespace_id = self
+            .get_namespace(identifier)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to get namespace with ID: {identifier}"
+                )
+            })?
+            .namespace_id;
+        if !self.get_namespace_streams(namespace_id).is_empty()
+            || !self.get_namespace_user_ids(namespace_id).is_empty()
+        {
+            error!(
+                "Cannot delete namespace with ID: {namespace_id}, it still has streams or users."
+            );
+            return Err(IggyError::NamespaceNotEmpty(namespace_id));
+        }
+
+        self.replicate(session, || {
+            EntryCommand::DeleteNamespace(DeleteNamespace {
+                namespace_id: identifier.clone(),
+            })
+        })
+        .await?;
+        if let Some(namespace) = self.namespaces.remove(&namespace_id) {
+            self.namespaces_ids.remove(&namespace.name);
+            self.metrics.remove_namespace(&namespace.name);
+        }
+        info!("Deleted namespace with ID: {namespace_id}.");
+        self.audit(
+            session,
+            AuditAction::DeleteNamespace,
+            format!("namespaces/{namespace_id}"),
+        );
+        Ok(namespace_id)
+    }
+
+    /// Resolves the namespace of the stream or the user created in the given session.
+    /// The namespaced user always creates them in its own namespace, while the user outside any namespace
+    /// can place them in any existing namespace.
+    pub(crate) fn resolve_namespace(
+        &self,
+        session: &Session,
+        namespace_id: Option<u32>,
+    ) -> Result<Option<u32>, IggyError> {
+        match (
+            self.permissioner.get_user_namespace(session.get_user_id()),
+            namespace_id,
+        ) {
+            (Some(user_namespace_id), Some(namespace_id)) if user_namespace_id != namespace_id => {
+                error!(
+                    "User with ID: {} cannot create resources in namespace with ID: {namespace_id}.",
+                    session.get_user_id()
+                );
+                Err(IggyError::Unauthorized)
+            }
+            (Some(user_namespace_id), _) => Ok(Some(user_namespace_id)),
+            (None, Some(namespace_id)) => {
+                if !self.namespaces.contains_key(&namespace_id) {
+                    return Err(IggyError::NamespaceIdNotFound(namespace_id));
+                }
+
+                Ok(Some(namespace_id))
+            }
+            (None, None) => Ok(None),
+        }
+    }
+
+    pub(crate) fn ensure_namespace_streams_limit(
+        &self,
+        namespace_id: u32,
+    ) -> Result<(), IggyError> {
+        let namespace = self
+            .namespaces
+            .get(&namespace_id)
+            .ok_or(IggyError::NamespaceIdNotFound(namespace_id))?;
+        let streams_count = self.get_namespace_streams(namespace_id).len() as u32;
+        if namespace.quotas.is_streams_limit_reached(streams_count) {
+            return Err(IggyError::NamespaceStreamsLimitReached(
+                namespace_id,
+                namespace.quotas.max_streams,
+            ));
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn ensure_namespace_users_limit(&self, namespace_id: u32) -> Result<(), IggyError> {
+        let namespace = self
+            .namespaces
+            .get(&namespace_id)
+            .ok_or(IggyError::NamespaceIdNotFound(namespace_id))?;
+        let users_count = self.get_namespace_user_ids(namespace_id).len() as u32;
+        if namespace.quotas.is_users_limit_reached(users_count) {
+            return Err(IggyError::NamespaceUsersLimitReached(
+                namespace_id,
+                namespace.quotas.max_users,
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Rejects the batch which would make the total size of the streams in the namespace exceed its storage quota.
+    pub(crate) fn ensure_namespace_storage_quota(
+        &self,
+        stream: &Stream,
+        batch_size_bytes: u64,
+    ) -> Result<(), IggyError> {
+        let Some(namespace) = stream
+            .namespace_id
+            .and_then(|namespace_id| self.namespaces.get(&namespace_id))
+        else {
+            return Ok(());
+        };
+
+        let size = self
+            .get_namespace_streams(namespace.namespace_id)
+            .iter()
+            .map(|stream| stream.get_size().as_bytes_u64())
+            .sum::<u64>()
+            + batch_size_bytes;
+        if namespace.quotas.is_size_exceeded(size) {
+            return Err(IggyError::NamespaceStorageQuotaExceeded(
+                namespace.namespace_id,
+                IggyByteSize::from(size),
+                namespace.quotas.max_size,
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configs::server::{DataMaintenanceConfig, PersonalAccessTokenConfig};
+    use crate::configs::system::SystemConfig;
+    use crate::state::{MockState, StateKind};
+    use crate::streaming::clients::client_manager::Transport;
+    use crate::streaming::persistence::persister::{FileWithSyncPersister, PersisterKind};
+    use crate::streaming::storage::SystemStorage;
+    use crate::streaming::systems::messages::PollingArgs;
+    use crate::streaming::users::user::User;
+    use iggy::compression::compression_algorithm::CompressionAlgorithm;
+    use iggy::consumer::Consumer;
+    use iggy::consumer_groups::offset_recovery_policy::OffsetRecoveryPolicy;
+    use iggy::messages::message_id_scheme::MessageIdScheme;
+    use iggy::messages::poll_messages::{IsolationLevel, PollingStrategy};
+    use iggy::messages::send_messages::{Message, Partitioning};
+    use iggy::models::metadata::{MetadataFilter, ResourceMetadata};
+    use iggy::models::permissions::Permissions;
+    use iggy::models::user_status::UserStatus;
+    use iggy::topics::cleanup_policy::CleanupPolicy;
+    use iggy::users::defaults::{DEFAULT_ROOT_PASSWORD, DEFAULT_ROOT_USERNAME};
+    use iggy::utils::expiry::IggyExpiry;
+    use iggy::utils::topic_size::MaxTopicSize;
+    use std::net::{Ipv4Addr, SocketAddr};
+    use std::sync::Arc;
+    use tempfile::TempDir;
+
+    const STREAM_NAME: &str = "orders";
+    const TOPIC_ID: u32 = 1;
+
+    struct Tenant {
+        namespace_id: u32,
+        session: Session,
+        user_id: u32,
+        stream_id: u32,
+    }
+
+    #[tokio::test]
+    async fn should_create_streams_with_the_same_name_in_different_namespaces() {
+        let tempdir = TempDir::new().unwrap();
+        let (mut system, root) = init_system(&tempdir);
+        let tenant_a = create_tenant(&mut system, &root, "tenant-a", 2).await;
+        let tenant_b = create_tenant(&mut system, &root, "tenant-b", 3).await;
+
+        assert_ne!(tenant_a.stream_id, tenant_b.stream_id);
+        let stream_name = Identifier::named(STREAM_NAME).unwrap();
+        let stream = system.find_stream(&tenant_a.session, &stream_name).unwrap();
+        assert_eq!(stream.stream_id, tenant_a.stream_id);
+        let stream = system.find_stream(&tenant_b.session, &stream_name).unwrap();
+        assert_eq!(stream.stream_id, tenant_b.stream_id);
+
+        let result = system
+            .create_stream(
+                &tenant_a.session,
+                None,
+                STREAM_NAME,
+                ResourceMetadata::default(),
+                None,
+            )
+            .await;
+        assert!(matches!(result, Err(IggyError::StreamNameAlreadyExists(_))));
+    }
+
+    #[tokio::test]
+    async fn should_not_list_or_get_streams_of_other_namespace() {
+        let tempdir = TempDir::new().unwrap();
+        let (mut system, root) = init_system(&tempdir);
+        let tenant_a = create_tenant(&mut system, &root, "tenant-a", 2).await;
+        let tenant_b = create_tenant(&mut system, &root, "tenant-b", 3).await;
+        system
+            .create_stream(
+                &tenant_b.session,
+                None,
+                "invoices",
+                ResourceMetadata::default(),
+                None,
+            )
+            .await
+            .unwrap();
+
+        let streams = system
+            .find_streams(&tenant_a.session, &MetadataFilter::default())
+            .unwrap();
+        assert_eq!(streams.len(), 1);
+        assert_eq!(streams[0].stream_id, tenant_a.stream_id);
+
+        let result = system.find_stream(
+            &tenant_a.session,
+            &Identifier::numeric(tenant_b.stream_id).unwrap(),
+        );
+        assert!(
+            matches!(result, Err(IggyError::StreamIdNotFound(stream_id)) if stream_id == tenant_b.stream_id)
+        );
+        let result = system.find_stream(&tenant_a.session, &Identifier::named("invoices").unwrap());
+        assert!(matches!(result, Err(IggyError::StreamNameNotFound(_))));
+
+        let stream = system
+            .create_stream(
+                &tenant_a.session,
+                None,
+                "invoices",
+                ResourceMetadata::default(),
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(stream.namespace_id, Some(tenant_a.namespace_id));
+    }
+
+    #[tokio::test]
+    async fn should_not_poll_or_send_messages_to_streams_of_other_namespace() {
+        let tempdir = TempDir::new().unwrap();
+        let (mut system, root) = init_system(&tempdir);
+        let tenant_a = create_tenant(&mut system, &root, "tenant-a", 2).await;
+        let tenant_b = create_tenant(&mut system, &root, "tenant-b", 3).await;
+        let stream_id = Identifier::numeric(tenant_b.stream_id).unwrap();
+        let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+
+        let result = system
+            .poll_messages(
+                &tenant_a.session,
+                &Consumer::new(Identifier::numeric(1).unwrap()),
+                &stream_id,
+                &topic_id,
+                Some(1),
+                PollingArgs::new(
+                    PollingStrategy::offset(0),
+                    10,
+                    false,
+                    IsolationLevel::default(),
+                ),
+            )
+            .await;
+        assert!(
+            matches!(result, Err(IggyError::StreamIdNotFound(stream_id)) if stream_id == tenant_b.stream_id)
+        );
+
+        let result = system
+            .append_messages(
+                &tenant_a.session,
+                stream_id,
+                topic_id,
+                Partitioning::balanced(),
+                vec![Message::new(None, "hello".into(), None)],
+                None,
+            )
+            .await;
+        assert!(
+            matches!(result, Err(IggyError::StreamIdNotFound(stream_id)) if stream_id == tenant_b.stream_id)
+        );
+    }
+
+    #[tokio::test]
+    async fn should_not_get_or_update_users_of_other_namespace() {
+        let tempdir = TempDir::new().unwrap();
+        let (mut system, root) = init_system(&tempdir);
+        let tenant_a = create_tenant(&mut system, &root, "tenant-a", 2).await;
+        let tenant_b = create_tenant(&mut system, &root, "tenant-b", 3).await;
+        let user_id = Identifier::numeric(tenant_b.user_id).unwrap();
+
+        let users = system.get_users(&tenant_a.session).await.unwrap();
+        assert_eq!(users.len(), 1);
+        assert_eq!(users[0].id, tenant_a.user_id);
+
+        let result = system.find_user(&tenant_a.session, &user_id);
+        assert!(matches!(result, Err(IggyError::Unauthorized)));
+
+        let result = system
+            .update_user(
+                &tenant_a.session,
+                &user_id,
+                Some("renamed".to_owned()),
+                None,
+            )
+            .await;
+        assert!(matches!(result, Err(IggyError::Unauthorized)));
+        assert_eq!(system.get_user(&user_id).unwrap().username, "tenant-b-user");
+    }
+
+    #[tokio::test]
+    async fn should_join_and_leave_consumer_group_by_names_in_namespace() {
+        let tempdir = TempDir::new().unwrap();
+        let (mut system, root) = init_system(&tempdir);
+        let tenant = create_tenant(&mut system, &root, "tenant", 2).await;
+        let session = system
+            .add_client(
+                &SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 5678),
+                Transport::Tcp,
+            )
+            .await;
+        session.set_user_id(tenant.user_id);
+        let stream_id = Identifier::named(STREAM_NAME).unwrap();
+        let topic_id = Identifier::named("events").unwrap();
+        let group_id = Identifier::named("group").unwrap();
+        system
+            .create_consumer_group(
+                &session,
+                &stream_id,
+                &topic_id,
+                Some(1),
+                "group",
+                OffsetRecoveryPolicy::ServerDefault,
+            )
+            .await
+            .unwrap();
+
+        system
+            .join_consumer_group(&session, &stream_id, &topic_id, &group_id)
+            .await
+            .unwrap();
+        let client = system
+            .client_manager
+            .read()
+            .await
+            .try_get_client(session.client_id)
+            .unwrap();
+        assert_eq!(client.read().await.consumer_groups.len(), 1);
+
+        system
+            .leave_consumer_group(&session, &stream_id, &topic_id, &group_id)
+            .await
+            .unwrap();
+        assert!(client.read().await.consumer_groups.is_empty());
+        let topic = system.find_topic(&session, &stream_id, &topic_id).unwrap();
+        let consumer_group = topic.get_consumer_group(&group_id).unwrap();
+        assert!(consumer_group.read().await.get_members().is_empty());
+    }
+
+    fn init_system(tempdir: &TempDir) -> (System, Session) {
+        let config = Arc::new(SystemConfig {
+            path: tempdir.path().to_str().unwrap().to_string(),
+            ..Default::default()
+        });
+        let storage = SystemStorage::new(
+            config.clone(),
+            Arc::new(PersisterKind::FileWithSync(FileWithSyncPersister {})),
+        );
+        let mut system = System::create(
+            config,
+            storage,
+            Arc::new(StateKind::Mock(MockState::new())),
+            None,
+            DataMaintenanceConfig::default(),
+            PersonalAccessTokenConfig::default(),
+        );
+        let root = User::root(DEFAULT_ROOT_USERNAME, DEFAULT_ROOT_PASSWORD);
+        system
+            .permissioner
+            .init_permissions_for_user(root.id, root.permissions.clone());
+        let session = Session::new(
+            1,
+            root.id,
+            SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234),
+        );
+        system.users.insert(root.id, root);
+        (system, session)
+    }
+
+    /// Creates the namespace with its own user, owning the stream with the same name in every namespace.
+    async fn create_tenant(
+        system: &mut System,
+        root: &Session,
+        name: &str,
+        client_id: u32,
+    ) -> Tenant {
+        let namespace_id = system
+            .create_namespace(root, None, name, NamespaceQuotas::default())
+            .await
+            .unwrap()
+            .namespace_id;
+        let user_id = system
+            .create_user(
+                root,
+                &format!("{name}-user"),
+                "secret",
+                UserStatus::Active,
+                Some(Permissions::root()),
+                Some(namespace_id),
+            )
+            .await
+            .unwrap()
+            .id;
+        let session = Session::new(
+            client_id,
+            user_id,
+            SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234),
+        );
+        let stream_id = system
+            .create_stream(
+                &session,
+                None,
+                STREAM_NAME,
+                ResourceMetadata::default(),
+                None,
+            )
+            .await
+            .unwrap()
+            .stream_id;
+        system
+            .create_topic(
+                &session,
+                &Identifier::numeric(stream_id).unwrap(),
+                Some(TOPIC_ID),
+                "events",
+                1,
+                IggyExpiry::NeverExpire,
+                CompressionAlgorithm::default(),
+                MaxTopicSize::ServerDefault,
+                None,
+                ResourceMetadata::default(),
+                MessageIdScheme::ServerDefault,
+                CleanupPolicy::ServerDefault,
+            )
+            .await
+            .unwrap();
+        Tenant {
+            namespace_id,
+            session,
+            user_id,
+            stream_id,
+        }
+    }
+}
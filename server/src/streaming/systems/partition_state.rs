@@ -0,0 +1,140 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::partitions::partition::Partition;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use crate::streaming::systems::COMPONENT;
+use bytes::Bytes;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::locking::{IggySharedMut, IggySharedMutFn};
+use tracing::trace;
+
+impl System {
+    pub async fn get_partition_state(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+        partition_id: u32,
+        key: &str,
+    ) -> Result<Option<Bytes>, IggyError> {
+        let (partition, group_id) = self
+            .get_assigned_partition(session, stream_id, topic_id, group_id, partition_id)
+            .await?;
+        let value = partition.read().await.get_state(group_id, key);
+        Ok(value)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn store_partition_state(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+        partition_id: u32,
+        key: &str,
+        value: &[u8],
+    ) -> Result<(), IggyError> {
+        let (partition, group_id) = self
+            .get_assigned_partition(session, stream_id, topic_id, group_id, partition_id)
+            .await?;
+        partition
+            .write()
+            .await
+            .store_state(group_id, key, value)
+            .await
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to store partition state with key: {key}, consumer group ID: {group_id}, partition ID: {partition_id}"))?;
+        trace!("Stored partition state with key: {key} for consumer group with ID: {group_id}, partition ID: {partition_id}.");
+        Ok(())
+    }
+
+    pub async fn delete_partition_state(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+        partition_id: u32,
+        key: &str,
+    ) -> Result<(), IggyError> {
+        let (partition, group_id) = self
+            .get_assigned_partition(session, stream_id, topic_id, group_id, partition_id)
+            .await?;
+        partition
+            .write()
+            .await
+            .delete_state(group_id, key)
+            .await
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to delete partition state with key: {key}, consumer group ID: {group_id}, partition ID: {partition_id}"))?;
+        Ok(())
+    }
+
+    /// Returns the partition along with the numeric ID of the consumer group, as long as the partition
+    /// is currently assigned to the member of the group identified by the session client ID.
+    async fn get_assigned_partition(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+        partition_id: u32,
+    ) -> Result<(IggySharedMut<Partition>, u32), IggyError> {
+        self.ensure_authenticated(session)?;
+        if self.config.partition.max_state_store_size.as_bytes_u64() == 0 {
+            return Err(IggyError::FeatureUnavailable);
+        }
+
+        let topic = self.find_topic(session, stream_id, topic_id).with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream ID: {stream_id}, topic_id: {topic_id}"))?;
+        self.permissioner
+            .poll_messages(session.get_user_id(), topic.stream_id, topic.topic_id)
+            .with_error_context(|error| format!(
+                "{COMPONENT} (error: {error}) - permission denied to access partition state for user {} on stream ID: {}, topic ID: {}",
+                session.get_user_id(),
+                topic.stream_id,
+                topic.topic_id
+            ))?;
+
+        let consumer_group = topic
+            .get_consumer_group(group_id)
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - consumer group not found for group_id: {group_id}")
+            })?
+            .read()
+            .await;
+        if !consumer_group
+            .is_partition_assigned(session.client_id, partition_id)
+            .await?
+        {
+            return Err(IggyError::PartitionNotAssignedToMember(
+                partition_id,
+                session.client_id,
+                consumer_group.group_id,
+            ));
+        }
+
+        let partition = topic.get_partition(partition_id).with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - partition not found for partition ID: {partition_id}")
+        })?;
+        Ok((partition, consumer_group.group_id))
+    }
+}
@@ -16,12 +16,21 @@
  * under the License.
  */
 
+use crate::state::command::EntryCommand;
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::System;
 use crate::streaming::systems::COMPONENT;
 use error_set::ErrContext;
 use iggy::error::IggyError;
 use iggy::identifier::Identifier;
+use iggy::locking::IggySharedMutFn;
+use iggy::models::audit_entry::AuditAction;
+use iggy::models::metadata_change::MetadataChange;
+use iggy::models::restored_segments::RestoredSegments;
+use iggy::models::segment_range::SegmentRange;
+use iggy::models::unsaved_state::PartitionUnsavedState;
+use iggy::partitions::create_partitions::CreatePartitions;
+use iggy::partitions::delete_partitions::DeletePartitions;
 
 impl System {
     pub async fn create_partitions(
@@ -44,10 +53,20 @@ impl System {
                 topic.stream_id,
                 topic.topic_id
             ))?;
+            self.ensure_partitions_limit(topic.get_partitions_count(), partitions_count)?;
         }
 
+        self.replicate(session, || {
+            EntryCommand::CreatePartitions(CreatePartitions {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                partitions_count,
+            })
+        })
+        .await?;
+
         let topic = self
-            .get_stream_mut(stream_id)?
+            .get_stream_mut(session, stream_id)?
             .get_topic_mut(topic_id)
             .with_error_context(|error| {
                 format!(
@@ -61,8 +80,20 @@ impl System {
                 format!("{COMPONENT} (error: {error}) - failed to add persisted partitions, topic: {topic}")
             })?;
         topic.reassign_consumer_groups().await;
+        let resource = format!(
+            "streams/{}/topics/{}/partitions",
+            topic.stream_id, topic.topic_id
+        );
+        let change = MetadataChange::PartitionsCreated {
+            stream_id: topic.stream_id,
+            topic_id: topic.topic_id,
+            created_partitions_count: partitions_count,
+            partitions_count: topic.get_partitions_count(),
+        };
         self.metrics.increment_partitions(partitions_count);
         self.metrics.increment_segments(partitions_count);
+        self.publish_metadata_change(session, change);
+        self.audit(session, AuditAction::CreatePartitions, resource);
         Ok(())
     }
 
@@ -88,8 +119,17 @@ impl System {
             ))?;
         }
 
+        self.replicate(session, || {
+            EntryCommand::DeletePartitions(DeletePartitions {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                partitions_count,
+            })
+        })
+        .await?;
+
         let topic = self
-            .get_stream_mut(stream_id)?
+            .get_stream_mut(session, stream_id)?
             .get_topic_mut(topic_id)
             .with_error_context(|error| {
                 format!(
@@ -103,11 +143,175 @@ impl System {
                 format!("{COMPONENT} (error: {error}) - failed to delete persisted partitions for topic: {topic}")
             })?;
         topic.reassign_consumer_groups().await;
+        let resource = format!(
+            "streams/{}/topics/{}/partitions",
+            topic.stream_id, topic.topic_id
+        );
+        let change = MetadataChange::PartitionsDeleted {
+            stream_id: topic.stream_id,
+            topic_id: topic.topic_id,
+            deleted_partitions_count: partitions_count,
+            partitions_count: topic.get_partitions_count(),
+        };
         if let Some(partitions) = partitions {
             self.metrics.decrement_partitions(partitions_count);
             self.metrics.decrement_segments(partitions.segments_count);
             self.metrics.decrement_messages(partitions.messages_count);
+            self.publish_metadata_change(session, change);
+            self.audit(session, AuditAction::DeletePartitions, resource);
         }
         Ok(())
     }
+
+    pub async fn restore_archived_segments(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        start_offset: u64,
+        end_offset: u64,
+    ) -> Result<RestoredSegments, IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .restore_archived_segments(session.get_user_id())
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to restore archived segments for user with ID: {}",
+                    session.get_user_id()
+                )
+            })?;
+        let Some(archiver) = self.archiver.as_ref() else {
+            return Err(IggyError::ArchiverNotEnabled);
+        };
+
+        let topic = self.find_topic(session, stream_id, topic_id).with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream ID: {stream_id}, topic_id: {topic_id}"))?;
+        let partition = topic.get_partition(partition_id).with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - partition with ID: {partition_id} not found, topic: {topic}")
+        })?;
+        let restored_segments = partition
+            .write()
+            .await
+            .restore_archived_segments(archiver, start_offset, end_offset)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to restore archived segments for offsets from: {start_offset} to: {end_offset}, partition ID: {partition_id}, topic: {topic}")
+            })?;
+        self.metrics
+            .increment_segments(restored_segments.segments_count);
+        self.metrics
+            .increment_messages(restored_segments.messages_count);
+        Ok(restored_segments)
+    }
+
+    /// Returns the partitions having any messages which are not yet saved on disk.
+    pub async fn get_unsaved_state(
+        &self,
+        session: &Session,
+    ) -> Result<Vec<PartitionUnsavedState>, IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .get_unsaved_state(session.get_user_id())
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to get unsaved state for user with ID: {}",
+                    session.get_user_id()
+                )
+            })?;
+
+        let mut unsaved_state = Vec::new();
+        for stream in self.streams.values() {
+            for topic in stream.topics.values() {
+                for partition in topic.partitions.values() {
+                    let partition_unsaved_state = partition.read().await.get_unsaved_state();
+                    if partition_unsaved_state.messages_count > 0 {
+                        unsaved_state.push(partition_unsaved_state);
+                    }
+                }
+            }
+        }
+        Ok(unsaved_state)
+    }
+
+    /// Saves the unsaved messages of the partition (or all the partitions of the topic) on disk and fsyncs them,
+    /// returning the state of the partitions which had any messages to save.
+    pub async fn flush_partition(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+    ) -> Result<Vec<PartitionUnsavedState>, IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .flush_partition(session.get_user_id())
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to flush partition for user with ID: {}",
+                    session.get_user_id()
+                )
+            })?;
+
+        let topic = self.find_topic(session, stream_id, topic_id).with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream ID: {stream_id}, topic_id: {topic_id}"))?;
+        let partitions = match partition_id {
+            Some(partition_id) => vec![topic.get_partition(partition_id).with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - partition with ID: {partition_id} not found, topic: {topic}")
+            })?],
+            None => topic.get_partitions(),
+        };
+
+        let mut flushed_partitions = Vec::new();
+        for partition in partitions {
+            let mut partition = partition.write().await;
+            let unsaved_state = partition.flush().await.with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to flush partition with ID: {}, topic: {topic}",
+                    partition.partition_id
+                )
+            })?;
+            if unsaved_state.messages_count > 0 {
+                flushed_partitions.push(unsaved_state);
+            }
+        }
+        Ok(flushed_partitions)
+    }
+
+    /// Reads the raw bytes of the persisted batches from the segment containing the given offset,
+    /// aligned to the batch boundaries, so that they can be processed in bulk by the external processors.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn read_segment_range(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        offset: u64,
+        start_position: u64,
+        end_position: Option<u64>,
+        max_bytes: u32,
+    ) -> Result<SegmentRange, IggyError> {
+        self.ensure_authenticated(session)?;
+        let topic = self.find_topic(session, stream_id, topic_id).with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream ID: {stream_id}, topic_id: {topic_id}"))?;
+        self.permissioner
+            .read_segment_range(session.get_user_id(), topic.stream_id, topic.topic_id)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to read segment range for user with ID: {}, topic: {topic}",
+                    session.get_user_id()
+                )
+            })?;
+
+        let partition = topic.get_partition(partition_id).with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - partition with ID: {partition_id} not found, topic: {topic}")
+        })?;
+        let segment_range = partition
+            .read()
+            .await
+            .read_segment_range(offset, start_position, end_position, max_bytes)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to read segment range for offset: {offset}, position: {start_position}, partition ID: {partition_id}, topic: {topic}")
+            })?;
+        Ok(segment_range)
+    }
 }
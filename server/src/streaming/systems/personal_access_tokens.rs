@@ -16,6 +16,8 @@
  * under the License.
  */
 
+use crate::state::command::EntryCommand;
+use crate::state::models::{CreatePersonalAccessTokenWithHash, RotatePersonalAccessTokenWithHash};
 use crate::streaming::personal_access_tokens::personal_access_token::PersonalAccessToken;
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::System;
@@ -23,8 +25,14 @@ use crate::streaming::systems::COMPONENT;
 use crate::streaming::users::user::User;
 use error_set::ErrContext;
 use iggy::error::IggyError;
+use iggy::models::audit_entry::AuditAction;
+use iggy::models::personal_access_token::PersonalAccessTokenScope;
+use iggy::personal_access_tokens::create_personal_access_token::CreatePersonalAccessToken;
+use iggy::personal_access_tokens::delete_personal_access_token::DeletePersonalAccessToken;
+use iggy::personal_access_tokens::rotate_personal_access_token::RotatePersonalAccessToken;
 use iggy::utils::expiry::IggyExpiry;
 use iggy::utils::timestamp::IggyTimestamp;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
 use tracing::{error, info};
 
 impl System {
@@ -53,9 +61,15 @@ impl System {
         session: &Session,
         name: &str,
         expiry: IggyExpiry,
+        scope: &PersonalAccessTokenScope,
     ) -> Result<String, IggyError> {
         self.ensure_authenticated(session)?;
         let user_id = session.get_user_id();
+        if session.personal_access_token_scope().is_some() {
+            error!("Personal access token: {name} cannot be created by the session authenticated with a scoped personal access token, user ID: {user_id}.");
+            return Err(IggyError::Unauthorized);
+        }
+
         let identifier = user_id.try_into()?;
         {
             let user = self.get_user(&identifier).with_error_context(|error| {
@@ -71,30 +85,45 @@ impl System {
                     max_token_per_user,
                 ));
             }
-        }
-
-        let user = self.get_user_mut(&identifier).with_error_context(|error| {
-            format!("{COMPONENT} (error: {error}) - failed to get mutable reference to the user with id: {user_id}")
-        })?;
 
-        if user
-            .personal_access_tokens
-            .values()
-            .any(|pat| pat.name == name)
-        {
-            error!("Personal access token: {name} for user with ID: {user_id} already exists.");
-            return Err(IggyError::PersonalAccessTokenAlreadyExists(
-                name.to_owned(),
-                user_id,
-            ));
+            if user
+                .personal_access_tokens
+                .values()
+                .any(|pat| pat.name == name)
+            {
+                error!("Personal access token: {name} for user with ID: {user_id} already exists.");
+                return Err(IggyError::PersonalAccessTokenAlreadyExists(
+                    name.to_owned(),
+                    user_id,
+                ));
+            }
         }
 
         info!("Creating personal access token: {name} for user with ID: {user_id}...");
         let (personal_access_token, token) =
-            PersonalAccessToken::new(user_id, name, IggyTimestamp::now(), expiry);
+            PersonalAccessToken::new(user_id, name, IggyTimestamp::now(), expiry, scope.clone());
+        self.replicate(session, || {
+            EntryCommand::CreatePersonalAccessToken(CreatePersonalAccessTokenWithHash {
+                hash: personal_access_token.token.clone(),
+                command: CreatePersonalAccessToken {
+                    name: name.to_owned(),
+                    expiry,
+                    scope: scope.clone(),
+                },
+            })
+        })
+        .await?;
+        let user = self.get_user_mut(&identifier).with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to get mutable reference to the user with id: {user_id}")
+        })?;
         user.personal_access_tokens
             .insert(personal_access_token.token.clone(), personal_access_token);
         info!("Created personal access token: {name} for user with ID: {user_id}.");
+        self.audit(
+            session,
+            AuditAction::CreatePersonalAccessToken,
+            format!("users/{user_id}/personal_access_tokens/{name}"),
+        );
         Ok(token)
     }
 
@@ -105,17 +134,13 @@ impl System {
     ) -> Result<(), IggyError> {
         self.ensure_authenticated(session)?;
         let user_id = session.get_user_id();
-        let user = self
-            .get_user_mut(&user_id.try_into()?)
-            .with_error_context(|error| {
-                format!(
-                    "{COMPONENT} (error: {error}) - failed to get mutable reference to the user with id: {user_id}"
-                )
-            })?;
-
+        let identifier = user_id.try_into()?;
         let token;
 
         {
+            let user = self.get_user(&identifier).with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to get user with id: {user_id}")
+            })?;
             let pat = user
                 .personal_access_tokens
                 .iter()
@@ -128,18 +153,149 @@ impl System {
             token = pat.unwrap().1.token.clone();
         }
 
+        self.replicate(session, || {
+            EntryCommand::DeletePersonalAccessToken(DeletePersonalAccessToken {
+                name: name.to_owned(),
+            })
+        })
+        .await?;
         info!("Deleting personal access token: {name} for user with ID: {user_id}...");
+        let user = self.get_user_mut(&identifier).with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to get mutable reference to the user with id: {user_id}")
+        })?;
         user.personal_access_tokens.remove(&token);
         info!("Deleted personal access token: {name} for user with ID: {user_id}.");
+        self.audit(
+            session,
+            AuditAction::DeletePersonalAccessToken,
+            format!("users/{user_id}/personal_access_tokens/{name}"),
+        );
         Ok(())
     }
 
+    pub async fn rotate_personal_access_token(
+        &mut self,
+        session: &Session,
+        name: &str,
+        expiry: IggyExpiry,
+    ) -> Result<String, IggyError> {
+        self.ensure_authenticated(session)?;
+        let user_id = session.get_user_id();
+        if session.personal_access_token_scope().is_some() {
+            error!("Personal access token: {name} cannot be rotated by the session authenticated with a scoped personal access token, user ID: {user_id}.");
+            return Err(IggyError::Unauthorized);
+        }
+
+        let identifier = user_id.try_into()?;
+        let Some(token_hash) = self
+            .get_user(&identifier)
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to get user with id: {user_id}")
+            })?
+            .personal_access_tokens
+            .values()
+            .find(|pat| pat.name == name)
+            .map(|pat| pat.token.clone())
+        else {
+            error!("Personal access token: {name} for user with ID: {user_id} does not exist.",);
+            return Err(IggyError::ResourceNotFound(name.to_owned()));
+        };
+
+        info!("Rotating personal access token: {name} for user with ID: {user_id}...");
+        let token = PersonalAccessToken::generate_token();
+        let rotated_token_hash = PersonalAccessToken::hash_token(&token);
+        self.replicate(session, || {
+            EntryCommand::RotatePersonalAccessToken(RotatePersonalAccessTokenWithHash {
+                hash: rotated_token_hash.clone(),
+                command: RotatePersonalAccessToken {
+                    name: name.to_owned(),
+                    expiry,
+                },
+            })
+        })
+        .await?;
+        let user = self.get_user_mut(&identifier).with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to get mutable reference to the user with id: {user_id}")
+        })?;
+        let mut personal_access_token = user.personal_access_tokens.remove(&token_hash).unwrap();
+        personal_access_token.reissue(&rotated_token_hash, IggyTimestamp::now(), expiry);
+        user.personal_access_tokens
+            .insert(personal_access_token.token.clone(), personal_access_token);
+        info!("Rotated personal access token: {name} for user with ID: {user_id}.");
+        self.audit(
+            session,
+            AuditAction::RotatePersonalAccessToken,
+            format!("users/{user_id}/personal_access_tokens/{name}"),
+        );
+        Ok(token)
+    }
+
+    /// Deletes the personal access tokens which have been neither used nor (re)issued since the given time,
+    /// returning the number of deleted tokens.
+    pub async fn delete_stale_personal_access_tokens(
+        &mut self,
+        not_used_since: IggyTimestamp,
+    ) -> Result<u32, IggyError> {
+        let stale_tokens = self
+            .users
+            .values()
+            .flat_map(|user| user.personal_access_tokens.values())
+            .filter(|pat| pat.is_stale(not_used_since))
+            .map(|pat| (pat.user_id, pat.token.clone(), pat.name.clone()))
+            .collect::<Vec<_>>();
+        // The stale tokens are deleted by the leader of the cluster, replicating the deletions.
+        if stale_tokens.is_empty() || self.ensure_cluster_leader().is_err() {
+            return Ok(0);
+        }
+
+        let mut deleted_tokens = 0;
+        for (user_id, token, name) in stale_tokens {
+            let session =
+                Session::stateless(user_id, SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 0));
+            self.replicate(&session, || {
+                EntryCommand::DeletePersonalAccessToken(DeletePersonalAccessToken {
+                    name: name.clone(),
+                })
+            })
+            .await?;
+            let Some(user) = self.users.get_mut(&user_id) else {
+                continue;
+            };
+
+            if user.personal_access_tokens.remove(&token).is_none() {
+                continue;
+            }
+
+            info!("Deleted stale personal access token: {name} for user with ID: {user_id}.");
+            deleted_tokens += 1;
+            self.state
+                .apply(
+                    user_id,
+                    EntryCommand::DeletePersonalAccessToken(DeletePersonalAccessToken {
+                        name: name.clone(),
+                    }),
+                )
+                .await
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to apply deletion of stale personal access token: {name} for user with ID: {user_id}")
+                })?;
+        }
+        Ok(deleted_tokens)
+    }
+
     pub async fn login_with_personal_access_token(
         &self,
         token: &str,
         session: Option<&Session>,
+        ip_address: Option<IpAddr>,
     ) -> Result<&User, IggyError> {
         let token_hash = PersonalAccessToken::hash_token(token);
+        let now = IggyTimestamp::now().as_micros();
+        self.pat_login_guard
+            .ensure_allowed(&token_hash, ip_address, now)
+            .inspect_err(|error| {
+                error!("Login with personal access token is not allowed. {error}");
+            })?;
         let mut personal_access_token = None;
         for user in self.users.values() {
             if let Some(pat) = user.personal_access_tokens.get(&token_hash) {
@@ -150,6 +306,15 @@ impl System {
 
         if personal_access_token.is_none() {
             error!("Personal access token: {} does not exist.", token);
+            self.pat_login_guard
+                .record_failure(&token_hash, ip_address, now);
+            self.audit_login(
+                session,
+                0,
+                AuditAction::LoginWithPersonalAccessToken,
+                String::new(),
+                false,
+            );
             return Err(IggyError::ResourceNotFound(token.to_owned()));
         }
 
@@ -159,12 +324,68 @@ impl System {
                 "Personal access token: {} for user with ID: {} has expired.",
                 personal_access_token.name, personal_access_token.user_id
             );
+            self.pat_login_guard
+                .record_failure(&token_hash, ip_address, now);
+            self.audit_login(
+                session,
+                personal_access_token.user_id,
+                AuditAction::LoginWithPersonalAccessToken,
+                get_personal_access_token_resource(personal_access_token),
+                false,
+            );
             return Err(IggyError::PersonalAccessTokenExpired(
                 personal_access_token.name.clone(),
                 personal_access_token.user_id,
             ));
         }
 
+        let scope = &personal_access_token.scope;
+        let ip_address_allowed = match ip_address {
+            Some(ip_address) => scope.is_ip_address_allowed(&ip_address),
+            None => scope.allowed_ip_ranges.is_empty(),
+        };
+        if !ip_address_allowed {
+            let source = ip_address.map_or_else(|| "unknown".to_owned(), |ip| ip.to_string());
+            error!(
+                "Personal access token: {} for user with ID: {} cannot be used from IP address: {source}.",
+                personal_access_token.name, personal_access_token.user_id
+            );
+            self.pat_login_guard
+                .record_failure(&token_hash, ip_address, now);
+            self.audit_login(
+                session,
+                personal_access_token.user_id,
+                AuditAction::LoginWithPersonalAccessToken,
+                get_personal_access_token_resource(personal_access_token),
+                false,
+            );
+            return Err(IggyError::PersonalAccessTokenIpAddressNotAllowed(
+                personal_access_token.name.clone(),
+                personal_access_token.user_id,
+                source,
+            ));
+        }
+
+        // Stateless sessions (HTTP, gRPC) are not tracked, so the commands executed
+        // later with the issued access token cannot be checked against the scope.
+        if scope.restricts_commands() && session.is_none() {
+            error!(
+                "Personal access token: {} for user with ID: {} has a scope which is not supported by the stateless transport.",
+                personal_access_token.name, personal_access_token.user_id
+            );
+            self.audit_login(
+                session,
+                personal_access_token.user_id,
+                AuditAction::LoginWithPersonalAccessToken,
+                get_personal_access_token_resource(personal_access_token),
+                false,
+            );
+            return Err(IggyError::PersonalAccessTokenScopeNotSupported(
+                personal_access_token.name.clone(),
+                personal_access_token.user_id,
+            ));
+        }
+
         let user = self
             .get_user(&personal_access_token.user_id.try_into()?)
             .with_error_context(|error| {
@@ -173,7 +394,37 @@ impl System {
                     personal_access_token.user_id
                 )
             })?;
-        self.login_user_with_credentials(&user.username, None, session)
-            .await
+        let result = self
+            .try_login_user_with_credentials(&user.username, None, session)
+            .await;
+        if let (Ok(_), Some(session)) = (&result, session) {
+            if *scope != PersonalAccessTokenScope::default() {
+                session.set_personal_access_token_scope(Some(scope.clone()));
+            }
+        }
+        self.audit_login(
+            session,
+            personal_access_token.user_id,
+            AuditAction::LoginWithPersonalAccessToken,
+            get_personal_access_token_resource(personal_access_token),
+            result.is_ok(),
+        );
+        let user = result?;
+        personal_access_token.mark_used(IggyTimestamp::now());
+        self.pat_login_guard.record_success(
+            &token_hash,
+            ip_address,
+            personal_access_token.user_id,
+            &personal_access_token.name,
+            now,
+        );
+        Ok(user)
     }
 }
+
+fn get_personal_access_token_resource(personal_access_token: &PersonalAccessToken) -> String {
+    format!(
+        "users/{}/personal_access_tokens/{}",
+        personal_access_token.user_id, personal_access_token.name
+    )
+}
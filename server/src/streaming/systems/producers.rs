@@ -0,0 +1,209 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use crate::streaming::systems::COMPONENT;
+use crate::streaming::topics::topic::Topic;
+use anyhow::Context;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::send_messages::Message;
+use iggy::models::producer_epoch::ProducerEpoch;
+use iggy::models::producer_session::ProducerSession;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::sync::atomic::Ordering;
+use tracing::{info, warn};
+
+/// The producer is identified by its name within the topic.
+pub(crate) type ProducerKey = (u32, u32, String);
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ProducerEpochState {
+    stream_id: u32,
+    topic_id: u32,
+    name: String,
+    epoch: u32,
+}
+
+impl System {
+    pub async fn register_producer(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        name: &str,
+    ) -> Result<ProducerEpoch, IggyError> {
+        self.ensure_authenticated(session)?;
+        let topic = self.find_topic(session, stream_id, topic_id).with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream ID: {stream_id}, topic_id: {topic_id}"))?;
+        self.permissioner.append_messages(
+            session.get_user_id(),
+            topic.stream_id,
+            topic.topic_id,
+        ).with_error_context(|error| format!(
+            "{COMPONENT} (error: {error}) - permission denied to register producer for user {} on stream ID: {}, topic ID: {}",
+            session.get_user_id(),
+            topic.stream_id,
+            topic.topic_id
+        ))?;
+        topic.ensure_producer_allowed(session.get_user_id())?;
+
+        let epoch = {
+            let mut entry = self
+                .producer_epochs
+                .entry((topic.stream_id, topic.topic_id, name.to_string()))
+                .or_insert(0);
+            *entry += 1;
+            *entry
+        };
+        self.save_producer_epochs().await.with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to save producer epochs after registering producer: {name}")
+        })?;
+        info!(
+            "Registered producer: {name} with epoch: {epoch} for topic with ID: {}, stream with ID: {}.",
+            topic.topic_id, topic.stream_id
+        );
+        Ok(ProducerEpoch {
+            name: name.to_string(),
+            epoch,
+        })
+    }
+
+    /// Starts the new idempotent producer session. The sequence numbers sent with the returned producer ID
+    /// are tracked by each partition of the topic, which appends the message with the given sequence number only once.
+    pub fn init_producer_id(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<ProducerSession, IggyError> {
+        self.ensure_authenticated(session)?;
+        let topic = self.find_topic(session, stream_id, topic_id).with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream ID: {stream_id}, topic_id: {topic_id}"))?;
+        self.permissioner.append_messages(
+            session.get_user_id(),
+            topic.stream_id,
+            topic.topic_id,
+        ).with_error_context(|error| format!(
+            "{COMPONENT} (error: {error}) - permission denied to init producer ID for user {} on stream ID: {}, topic ID: {}",
+            session.get_user_id(),
+            topic.stream_id,
+            topic.topic_id
+        ))?;
+        topic.ensure_producer_allowed(session.get_user_id())?;
+
+        let producer_id = self.next_producer_id.fetch_add(1, Ordering::SeqCst);
+        info!(
+            "Initialized idempotent producer with ID: {producer_id} for topic with ID: {}, stream with ID: {}.",
+            topic.topic_id, topic.stream_id
+        );
+        Ok(ProducerSession { producer_id })
+    }
+
+    /// Rejects the messages sent by the registered producer with the epoch older than the latest registered one.
+    /// The messages without the producer headers, or sent by the unknown producers, are not fenced.
+    pub(crate) fn fence_producers(
+        &self,
+        topic: &Topic,
+        messages: &[Message],
+    ) -> Result<(), IggyError> {
+        if self.producer_epochs.is_empty() {
+            return Ok(());
+        }
+
+        for message in messages {
+            let Some(producer_epoch) = ProducerEpoch::from_headers(&message.headers)? else {
+                continue;
+            };
+
+            let key = (topic.stream_id, topic.topic_id, producer_epoch.name);
+            let Some(current_epoch) = self.producer_epochs.get(&key).map(|epoch| *epoch) else {
+                continue;
+            };
+
+            if producer_epoch.epoch < current_epoch {
+                warn!(
+                    "Fenced producer: {} with epoch: {}, current epoch: {current_epoch}, topic with ID: {}, stream with ID: {}.",
+                    key.2, producer_epoch.epoch, topic.topic_id, topic.stream_id
+                );
+                return Err(IggyError::ProducerFenced(
+                    key.2,
+                    producer_epoch.epoch,
+                    current_epoch,
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    async fn save_producer_epochs(&self) -> Result<(), IggyError> {
+        let _guard = self.producer_epochs_save_lock.lock().await;
+        let mut states = self
+            .producer_epochs
+            .iter()
+            .map(|entry| {
+                let (stream_id, topic_id, name) = entry.key().clone();
+                ProducerEpochState {
+                    stream_id,
+                    topic_id,
+                    name,
+                    epoch: *entry.value(),
+                }
+            })
+            .collect::<Vec<_>>();
+        states.sort_by(|x, y| {
+            (x.stream_id, x.topic_id, &x.name).cmp(&(y.stream_id, y.topic_id, &y.name))
+        });
+        let data = bincode::serde::encode_to_vec(&states, bincode::config::standard())
+            .with_context(|| "Failed to serialize producer epochs")
+            .map_err(|_| IggyError::CannotSerializeResource)?;
+        let path = self.config.get_state_producer_epochs_path();
+        self.storage
+            .persister
+            .overwrite(&path, &data)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to overwrite file at path: {path}")
+            })
+    }
+
+    pub(crate) async fn load_producer_epochs(&mut self) -> Result<(), IggyError> {
+        let path = self.config.get_state_producer_epochs_path();
+        if !Path::new(&path).exists() {
+            return Ok(());
+        }
+
+        let data = tokio::fs::read(&path)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to read file at path: {path}")
+            })
+            .map_err(|_| IggyError::CannotReadFile)?;
+        let (states, _): (Vec<ProducerEpochState>, _) =
+            bincode::serde::decode_from_slice(&data, bincode::config::standard())
+                .with_context(|| "Failed to deserialize producer epochs")
+                .map_err(|_| IggyError::CannotDeserializeResource)?;
+        for state in states {
+            self.producer_epochs
+                .insert((state.stream_id, state.topic_id, state.name), state.epoch);
+        }
+        info!("Loaded {} producer epoch(s).", self.producer_epochs.len());
+        Ok(())
+    }
+}
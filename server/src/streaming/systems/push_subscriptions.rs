@@ -0,0 +1,207 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::push::push_subscription::{PushSubscription, PushSubscriptionState};
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use crate::streaming::systems::COMPONENT;
+use anyhow::Context;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::models::push_subscription::PushSubscription as PushSubscriptionInfo;
+use std::path::Path;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use tracing::info;
+
+impl System {
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create_push_subscription(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        endpoint: &str,
+        start_offset: u64,
+        batch_size: u32,
+    ) -> Result<Arc<PushSubscription>, IggyError> {
+        self.ensure_authenticated(session)?;
+        let config = &self.config.push_subscriptions;
+        if !config.enabled {
+            return Err(IggyError::PushSubscriptionsDisabled);
+        }
+
+        if !config.allow_insecure_endpoints && !endpoint.starts_with("https://") {
+            return Err(IggyError::InvalidPushSubscriptionEndpoint);
+        }
+
+        let topic = self.find_topic(session, stream_id, topic_id).with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream ID: {stream_id}, topic_id: {topic_id}"))?;
+        self.permissioner
+            .poll_messages(session.get_user_id(), topic.stream_id, topic.topic_id)
+            .with_error_context(|error| format!(
+                "{COMPONENT} (error: {error}) - permission denied to poll messages for user {} on stream ID: {}, topic ID: {}",
+                session.get_user_id(),
+                topic.stream_id,
+                topic.topic_id
+            ))?;
+        if !topic.partitions.contains_key(&partition_id) {
+            return Err(IggyError::PartitionNotFound(
+                partition_id,
+                topic.topic_id,
+                topic.stream_id,
+            ));
+        }
+
+        if self.push_subscriptions.len() >= config.max_subscriptions as usize {
+            return Err(IggyError::TooManyPushSubscriptions(
+                config.max_subscriptions,
+            ));
+        }
+
+        let id = self
+            .next_push_subscription_id
+            .fetch_add(1, Ordering::SeqCst)
+            + 1;
+        let subscription = Arc::new(PushSubscription::new(
+            id,
+            session.get_user_id(),
+            topic.stream_id,
+            topic.topic_id,
+            partition_id,
+            endpoint.to_string(),
+            batch_size,
+            start_offset,
+        ));
+        self.push_subscriptions.insert(id, subscription.clone());
+        self.save_push_subscriptions()
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to save push subscription with ID: {id}"
+                )
+            })?;
+        info!(
+            "Created push subscription with ID: {id} to endpoint: {endpoint} by user with ID: {}.",
+            session.get_user_id()
+        );
+        Ok(subscription)
+    }
+
+    pub fn get_push_subscriptions(
+        &self,
+        session: &Session,
+    ) -> Result<Vec<PushSubscriptionInfo>, IggyError> {
+        self.ensure_authenticated(session)?;
+        let user_id = session.get_user_id();
+        let can_read_all_subscriptions = self.permissioner.get_push_subscriptions(user_id).is_ok();
+        let mut subscriptions = self
+            .push_subscriptions
+            .iter()
+            .filter(|subscription| can_read_all_subscriptions || subscription.user_id == user_id)
+            .map(|subscription| subscription.to_info())
+            .collect::<Vec<_>>();
+        subscriptions.sort_by_key(|subscription| subscription.id);
+        Ok(subscriptions)
+    }
+
+    pub async fn delete_push_subscription(
+        &self,
+        session: &Session,
+        subscription_id: u32,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        let user_id = session.get_user_id();
+        let subscription = self
+            .push_subscriptions
+            .get(&subscription_id)
+            .map(|subscription| subscription.clone())
+            .ok_or(IggyError::PushSubscriptionNotFound(subscription_id))?;
+        if subscription.user_id != user_id {
+            self.permissioner
+                .delete_push_subscription(user_id)
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - permission denied to delete push subscription with ID: {subscription_id} for user with ID: {user_id}"
+                    )
+                })?;
+        }
+
+        subscription.delete();
+        self.push_subscriptions.remove(&subscription_id);
+        self.save_push_subscriptions().await.with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to save push subscriptions after deleting subscription with ID: {subscription_id}")
+        })?;
+        info!("Deleted push subscription with ID: {subscription_id} by user with ID: {user_id}.");
+        Ok(())
+    }
+
+    /// Saves the push subscriptions along with their acknowledged offsets, so that the delivery is resumed after the restart.
+    pub(crate) async fn save_push_subscriptions(&self) -> Result<(), IggyError> {
+        let _guard = self.push_subscriptions_save_lock.lock().await;
+        let mut states = self
+            .push_subscriptions
+            .iter()
+            .map(|subscription| subscription.to_state())
+            .collect::<Vec<_>>();
+        states.sort_by_key(|state| state.id);
+        let data = bincode::serde::encode_to_vec(&states, bincode::config::standard())
+            .with_context(|| "Failed to serialize push subscriptions")
+            .map_err(|_| IggyError::CannotSerializeResource)?;
+        let path = self.config.get_state_push_subscriptions_path();
+        self.storage
+            .persister
+            .overwrite(&path, &data)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to overwrite file at path: {path}")
+            })
+    }
+
+    pub(crate) async fn load_push_subscriptions(&mut self) -> Result<(), IggyError> {
+        let path = self.config.get_state_push_subscriptions_path();
+        if !Path::new(&path).exists() {
+            return Ok(());
+        }
+
+        let data = tokio::fs::read(&path)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to read file at path: {path}")
+            })
+            .map_err(|_| IggyError::CannotReadFile)?;
+        let (states, _): (Vec<PushSubscriptionState>, _) =
+            bincode::serde::decode_from_slice(&data, bincode::config::standard())
+                .with_context(|| "Failed to deserialize push subscriptions")
+                .map_err(|_| IggyError::CannotDeserializeResource)?;
+        let mut max_id = 0;
+        for state in states {
+            max_id = max_id.max(state.id);
+            self.push_subscriptions
+                .insert(state.id, Arc::new(PushSubscription::from_state(state)));
+        }
+        self.next_push_subscription_id
+            .store(max_id, Ordering::SeqCst);
+        info!(
+            "Loaded {} push subscription(s).",
+            self.push_subscriptions.len()
+        );
+        Ok(())
+    }
+}
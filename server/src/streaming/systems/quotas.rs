@@ -0,0 +1,301 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::configs::system::QuotasConfig;
+use crate::state::command::EntryCommand;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use crate::streaming::systems::COMPONENT;
+use dashmap::DashMap;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::models::user_info::UserId;
+use iggy::users::update_user_quotas::UpdateUserQuotas;
+use iggy::users::user_quotas::UserQuotas;
+use iggy::utils::timestamp::IggyTimestamp;
+use std::time::Duration;
+use tracing::info;
+
+/// The number of units (bytes or requests) which can still be used within the quota, negative when the quota has been exceeded.
+/// It's refilled at the rate of the quota, up to the units allowed in a single second.
+#[derive(Debug)]
+pub(crate) struct QuotaBucket {
+    balance: i64,
+    updated_at: u64,
+}
+
+impl QuotaBucket {
+    pub(crate) fn new(units_per_second: u64, now: u64) -> Self {
+        Self {
+            balance: units_per_second.min(i64::MAX as u64) as i64,
+            updated_at: now,
+        }
+    }
+
+    pub(crate) fn refill(&mut self, units_per_second: u64, now: u64) {
+        let elapsed = now.saturating_sub(self.updated_at) as u128;
+        let refilled =
+            (elapsed * units_per_second as u128 / 1_000_000).min(i64::MAX as u128) as i64;
+        // The time is not moved forward until at least a single unit is refilled, so that the frequent requests don't starve the bucket.
+        if refilled == 0 {
+            return;
+        }
+
+        self.balance = self
+            .balance
+            .saturating_add(refilled)
+            .min(units_per_second.min(i64::MAX as u64) as i64);
+        self.updated_at = now;
+    }
+
+    pub(crate) fn charge(&mut self, units: u64) {
+        self.balance = self
+            .balance
+            .saturating_sub(units.min(i64::MAX as u64) as i64);
+    }
+
+    pub(crate) fn get_throttle_time_ms(&self, units_per_second: u64) -> u32 {
+        if self.balance >= 0 {
+            return 0;
+        }
+
+        (self.balance.unsigned_abs() * 1000)
+            .div_ceil(units_per_second)
+            .min(u32::MAX as u64) as u32
+    }
+}
+
+/// The quota tracked for the user or the client.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum QuotaKind {
+    ProduceBytes,
+    Requests,
+}
+
+/// The user or the client whose usage is charged to the quota.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum QuotaOwner {
+    User(UserId),
+    Client(u32),
+}
+
+/// Tracks the bytes sent and the requests of the users and the clients, to throttle the ones exceeding their quotas.
+/// The quotas of the users can be overridden, the timestamps are expressed in microseconds since the Unix epoch.
+#[derive(Debug)]
+pub struct Quotas {
+    user_produce_bytes_per_second: u64,
+    client_produce_bytes_per_second: u64,
+    user_requests_per_second: u64,
+    client_requests_per_second: u64,
+    max_throttle_delay: Duration,
+    user_quotas: DashMap<UserId, UserQuotas>,
+    buckets: DashMap<(QuotaKind, QuotaOwner), QuotaBucket>,
+}
+
+impl Quotas {
+    pub fn new(config: &QuotasConfig) -> Self {
+        Self {
+            user_produce_bytes_per_second: config.user_produce_bytes_per_second.as_bytes_u64(),
+            client_produce_bytes_per_second: config.client_produce_bytes_per_second.as_bytes_u64(),
+            user_requests_per_second: config.user_requests_per_second as u64,
+            client_requests_per_second: config.client_requests_per_second as u64,
+            max_throttle_delay: config.max_throttle_delay.get_duration(),
+            user_quotas: DashMap::new(),
+            buckets: DashMap::new(),
+        }
+    }
+
+    /// Replaces the quotas overriding the server defaults for the user, resetting its usage.
+    pub fn set_user_quotas(&self, user_id: UserId, quotas: UserQuotas) {
+        if quotas.is_empty() {
+            self.user_quotas.remove(&user_id);
+        } else {
+            self.user_quotas.insert(user_id, quotas);
+        }
+        self.buckets
+            .retain(|(_, owner), _| *owner != QuotaOwner::User(user_id));
+    }
+
+    /// Charges the units to the quotas of the owners, returning the resulting throttle time in milliseconds.
+    pub fn record(&self, kind: QuotaKind, owners: &[QuotaOwner], units: u64, now: u64) -> u32 {
+        owners
+            .iter()
+            .filter_map(|owner| {
+                let units_per_second = self.get_units_per_second(kind, owner);
+                if units_per_second == 0 {
+                    return None;
+                }
+
+                let mut bucket = self
+                    .buckets
+                    .entry((kind, *owner))
+                    .or_insert_with(|| QuotaBucket::new(units_per_second, now));
+                bucket.refill(units_per_second, now);
+                bucket.charge(units);
+                Some(bucket.get_throttle_time_ms(units_per_second))
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Returns the delay of the response, up to the configured maximum. The remaining throttle time isn't lost,
+    /// as the bucket stays in debt, so that the subsequent requests are delayed until it's paid off.
+    pub fn get_throttle_delay(&self, throttle_time_ms: u32) -> Duration {
+        Duration::from_millis(throttle_time_ms as u64).min(self.max_throttle_delay)
+    }
+
+    fn get_units_per_second(&self, kind: QuotaKind, owner: &QuotaOwner) -> u64 {
+        match (kind, owner) {
+            (QuotaKind::ProduceBytes, QuotaOwner::User(user_id)) => self
+                .user_quotas
+                .get(user_id)
+                .and_then(|quotas| quotas.produce_bytes_per_second)
+                .map(|bytes_per_second| bytes_per_second.as_bytes_u64())
+                .unwrap_or(self.user_produce_bytes_per_second),
+            (QuotaKind::ProduceBytes, QuotaOwner::Client(_)) => {
+                self.client_produce_bytes_per_second
+            }
+            (QuotaKind::Requests, QuotaOwner::User(user_id)) => self
+                .user_quotas
+                .get(user_id)
+                .and_then(|quotas| quotas.requests_per_second)
+                .map(|requests_per_second| requests_per_second as u64)
+                .unwrap_or(self.user_requests_per_second),
+            (QuotaKind::Requests, QuotaOwner::Client(_)) => self.client_requests_per_second,
+        }
+    }
+}
+
+impl System {
+    /// Overrides the server default quotas for the user, the quotas left as `None` use the server defaults.
+    pub async fn update_user_quotas(
+        &mut self,
+        session: &Session,
+        user_id: &Identifier,
+        quotas: UserQuotas,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .update_user_quotas(session.get_user_id())
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to update quotas for user with id: {}",
+                    session.get_user_id()
+                )
+            })?;
+        let quotas_user_id = self.get_user(user_id)?.id;
+        self.permissioner
+            .ensure_same_namespace(session.get_user_id(), quotas_user_id)?;
+        self.replicate(session, || {
+            EntryCommand::UpdateUserQuotas(UpdateUserQuotas {
+                user_id: user_id.clone(),
+                quotas,
+            })
+        })
+        .await?;
+        let user = self.get_user_mut(user_id).with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to get user with id: {user_id}")
+        })?;
+        user.quotas = quotas;
+        let user_id = user.id;
+        info!(
+            "Updated quotas: {quotas} for user: {} with ID: {user_id}.",
+            user.username
+        );
+        self.apply_user_quotas(user_id, quotas);
+        Ok(())
+    }
+
+    pub(crate) fn apply_user_quotas(&self, user_id: UserId, quotas: UserQuotas) {
+        self.quotas.set_user_quotas(user_id, quotas);
+        self.fetch_quotas
+            .set_user_bytes_per_second(user_id, quotas.consume_bytes_per_second);
+    }
+
+    /// Charges the request to the quotas of the session, returning the delay of its handling.
+    pub fn throttle_request(&self, session: &Session) -> Duration {
+        let throttle_time_ms = self.quotas.record(
+            QuotaKind::Requests,
+            &get_quota_owners(session),
+            1,
+            IggyTimestamp::now().as_micros(),
+        );
+        self.quotas.get_throttle_delay(throttle_time_ms)
+    }
+
+    /// Charges the sent bytes to the quotas of the session, returning the delay of the response.
+    pub fn throttle_produce(&self, session: &Session, bytes: u64) -> Duration {
+        let throttle_time_ms = self.quotas.record(
+            QuotaKind::ProduceBytes,
+            &get_quota_owners(session),
+            bytes,
+            IggyTimestamp::now().as_micros(),
+        );
+        self.quotas.get_throttle_delay(throttle_time_ms)
+    }
+}
+
+/// The stateless sessions (HTTP) have no client, and the unauthenticated ones have no user.
+fn get_quota_owners(session: &Session) -> Vec<QuotaOwner> {
+    let mut owners = Vec::with_capacity(2);
+    if session.get_user_id() > 0 {
+        owners.push(QuotaOwner::User(session.get_user_id()));
+    }
+    if session.client_id > 0 {
+        owners.push(QuotaOwner::Client(session.client_id));
+    }
+    owners
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy::utils::byte_size::IggyByteSize;
+    use iggy::utils::duration::IggyDuration;
+
+    #[test]
+    fn user_quotas_should_override_server_defaults() {
+        let quotas = Quotas::new(&QuotasConfig {
+            user_produce_bytes_per_second: IggyByteSize::from(1000),
+            client_produce_bytes_per_second: IggyByteSize::from(0),
+            user_requests_per_second: 0,
+            client_requests_per_second: 10,
+            max_throttle_delay: IggyDuration::from(100_000),
+        });
+        let user = [QuotaOwner::User(1)];
+        let client = [QuotaOwner::Client(1)];
+
+        assert_eq!(quotas.record(QuotaKind::ProduceBytes, &user, 1500, 0), 500);
+        assert_eq!(quotas.record(QuotaKind::ProduceBytes, &client, 1500, 0), 0);
+        assert_eq!(quotas.record(QuotaKind::Requests, &user, 100, 0), 0);
+        assert_eq!(quotas.record(QuotaKind::Requests, &client, 15, 0), 500);
+        assert_eq!(quotas.get_throttle_delay(500), Duration::from_millis(100));
+
+        quotas.set_user_quotas(
+            1,
+            UserQuotas {
+                produce_bytes_per_second: Some(IggyByteSize::from(0)),
+                requests_per_second: Some(10),
+                ..Default::default()
+            },
+        );
+        assert_eq!(quotas.record(QuotaKind::ProduceBytes, &user, 1500, 0), 0);
+        assert_eq!(quotas.record(QuotaKind::Requests, &user, 20, 0), 1000);
+    }
+}
@@ -0,0 +1,173 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::replay::replay_job::ReplayJob;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use crate::streaming::systems::COMPONENT;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::replay_messages::{HeadersTransform, ReplayRange};
+use iggy::messages::send_messages::Partitioning;
+use iggy::models::replay_job::{ReplayJob as ReplayJobInfo, ReplayJobStatus};
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use tracing::info;
+
+impl System {
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_replay_job(
+        &self,
+        session: &Session,
+        source_stream_id: &Identifier,
+        source_topic_id: &Identifier,
+        source_partition_id: u32,
+        range: ReplayRange,
+        destination_stream_id: &Identifier,
+        destination_topic_id: &Identifier,
+        partitioning: Partitioning,
+        headers: HeadersTransform,
+        messages_per_second: u32,
+        batch_size: u32,
+    ) -> Result<Arc<ReplayJob>, IggyError> {
+        self.ensure_authenticated(session)?;
+        let source_topic = self.find_topic(session, source_stream_id, source_topic_id).with_error_context(|error| format!("{COMPONENT} (error: {error}) - source topic not found for stream ID: {source_stream_id}, topic_id: {source_topic_id}"))?;
+        self.permissioner
+            .poll_messages(session.get_user_id(), source_topic.stream_id, source_topic.topic_id)
+            .with_error_context(|error| format!(
+                "{COMPONENT} (error: {error}) - permission denied to poll messages for user {} on stream ID: {}, topic ID: {}",
+                session.get_user_id(),
+                source_topic.stream_id,
+                source_topic.topic_id
+            ))?;
+        if !source_topic.partitions.contains_key(&source_partition_id) {
+            return Err(IggyError::PartitionNotFound(
+                source_partition_id,
+                source_topic.topic_id,
+                source_topic.stream_id,
+            ));
+        }
+
+        let destination_topic = self.find_topic(session, destination_stream_id, destination_topic_id).with_error_context(|error| format!("{COMPONENT} (error: {error}) - destination topic not found for stream ID: {destination_stream_id}, topic_id: {destination_topic_id}"))?;
+        self.permissioner
+            .append_messages(
+                session.get_user_id(),
+                destination_topic.stream_id,
+                destination_topic.topic_id,
+            )
+            .with_error_context(|error| format!(
+                "{COMPONENT} (error: {error}) - permission denied to append messages for user {} on stream ID: {}, topic ID: {}",
+                session.get_user_id(),
+                destination_topic.stream_id,
+                destination_topic.topic_id
+            ))?;
+
+        let max_running_jobs = self.config.replay.max_running_jobs;
+        let running_jobs = self
+            .replay_jobs
+            .iter()
+            .filter(|job| job.is_running())
+            .count();
+        if running_jobs >= max_running_jobs as usize {
+            return Err(IggyError::TooManyReplayJobs(max_running_jobs));
+        }
+
+        self.remove_finished_replay_jobs();
+        let id = self.next_replay_job_id.fetch_add(1, Ordering::SeqCst) + 1;
+        let job = Arc::new(ReplayJob::new(
+            id,
+            session.get_user_id(),
+            session.ip_address,
+            (
+                source_topic.stream_id,
+                source_topic.topic_id,
+                source_partition_id,
+            ),
+            (destination_topic.stream_id, destination_topic.topic_id),
+            range,
+            partitioning,
+            headers,
+            messages_per_second,
+            batch_size,
+        ));
+        self.replay_jobs.insert(id, job.clone());
+        info!(
+            "Created replay job with ID: {id} by user with ID: {}.",
+            session.get_user_id()
+        );
+        Ok(job)
+    }
+
+    pub fn get_replay_jobs(&self, session: &Session) -> Result<Vec<ReplayJobInfo>, IggyError> {
+        self.ensure_authenticated(session)?;
+        let user_id = session.get_user_id();
+        let can_read_all_jobs = self.permissioner.get_replay_jobs(user_id).is_ok();
+        let mut jobs = self
+            .replay_jobs
+            .iter()
+            .filter(|job| can_read_all_jobs || job.user_id == user_id)
+            .map(|job| job.to_info())
+            .collect::<Vec<_>>();
+        jobs.sort_by_key(|job| job.id);
+        Ok(jobs)
+    }
+
+    pub fn cancel_replay_job(&self, session: &Session, job_id: u32) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        let user_id = session.get_user_id();
+        let job = self
+            .replay_jobs
+            .get(&job_id)
+            .map(|job| job.clone())
+            .ok_or(IggyError::ReplayJobNotFound(job_id))?;
+        if job.user_id != user_id {
+            self.permissioner
+                .cancel_replay_job(user_id)
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - permission denied to cancel replay job with ID: {job_id} for user with ID: {user_id}"
+                    )
+                })?;
+        }
+
+        if job.finish(ReplayJobStatus::Cancelled) {
+            info!("Cancelled replay job with ID: {job_id} by user with ID: {user_id}.");
+        }
+        Ok(())
+    }
+
+    fn remove_finished_replay_jobs(&self) {
+        let max_finished_jobs = self.config.replay.max_finished_jobs as usize;
+        let mut finished_jobs = self
+            .replay_jobs
+            .iter()
+            .filter(|job| !job.is_running())
+            .map(|job| job.id)
+            .collect::<Vec<_>>();
+        if finished_jobs.len() <= max_finished_jobs {
+            return;
+        }
+
+        finished_jobs.sort_unstable();
+        let jobs_to_remove = finished_jobs.len() - max_finished_jobs;
+        for job_id in finished_jobs.into_iter().take(jobs_to_remove) {
+            self.replay_jobs.remove(&job_id);
+        }
+    }
+}
@@ -16,6 +16,7 @@
  * under the License.
  */
 
+use crate::streaming::clients::transport_limiter::TransportLimiter;
 use crate::streaming::systems::system::System;
 use crate::versioning::SemanticVersion;
 use crate::VERSION;
@@ -86,6 +87,7 @@ impl System {
                 .ok()
                 .and_then(|v| v.get_numeric_version().ok()),
             cache_metrics,
+            transport_utilization: TransportLimiter::get_utilization(),
             ..Default::default()
         };
 
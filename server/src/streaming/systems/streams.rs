@@ -16,6 +16,8 @@
  * under the License.
  */
 
+use crate::state::command::EntryCommand;
+use crate::state::models::CreateStreamWithId;
 use crate::state::system::StreamState;
 use crate::streaming::session::Session;
 use crate::streaming::streams::stream::Stream;
@@ -27,6 +29,16 @@ use futures::future::try_join_all;
 use iggy::error::IggyError;
 use iggy::identifier::{IdKind, Identifier};
 use iggy::locking::IggySharedMutFn;
+use iggy::models::audit_entry::AuditAction;
+use iggy::models::metadata::{MetadataFilter, ResourceMetadata};
+use iggy::models::metadata_change::MetadataChange;
+use iggy::models::stream::StreamQuota;
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::streams::purge_stream::PurgeStream;
+use iggy::streams::update_stream::UpdateStream;
+use iggy::streams::update_stream_metadata::UpdateStreamMetadata;
+use iggy::streams::update_stream_quota::UpdateStreamQuota;
 use std::cell::RefCell;
 use std::sync::atomic::{AtomicU32, Ordering};
 use tokio::fs;
@@ -56,6 +68,11 @@ impl System {
                 IggyError::InvalidNumberValue
             })?;
             let stream_state = streams.iter().find(|s| s.id == stream_id);
+            if stream_state.is_none() && self.config.recovery.read_only {
+                warn!("Stream with ID: '{stream_id}' was not found in state, but exists on disk and will be skipped in read-only mode.");
+                continue;
+            }
+
             if stream_state.is_none() {
                 error!("Stream with ID: '{stream_id}' was not found in state, but exists on disk and will be removed.");
                 if let Err(error) = fs::remove_dir_all(&dir_entry.path()).await {
@@ -74,6 +91,9 @@ impl System {
                 self.storage.clone(),
             );
             stream.created_at = stream_state.created_at;
+            stream.metadata = stream_state.metadata.clone();
+            stream.quota = stream_state.quota;
+            stream.namespace_id = stream_state.namespace_id;
             unloaded_streams.push(stream);
         }
 
@@ -91,6 +111,8 @@ impl System {
             .collect::<AHashSet<u32>>();
         if missing_ids.is_empty() {
             info!("All streams found on disk were found in state.");
+        } else if self.config.recovery.read_only {
+            warn!("Streams with IDs: '{missing_ids:?}' were not found on disk and will be skipped in read-only mode.");
         } else {
             error!("Streams with IDs: '{missing_ids:?}' were not found on disk.");
             if !self.config.recovery.recreate_missing_state {
@@ -101,12 +123,15 @@ impl System {
             info!("Recreating missing state in recovery config is enabled, missing streams will be created.");
             for stream_id in missing_ids {
                 let stream_state = streams.iter().find(|s| s.id == stream_id).unwrap();
-                let stream = Stream::create(
+                let mut stream = Stream::create(
                     stream_id,
                     &stream_state.name,
                     self.config.clone(),
                     self.storage.clone(),
                 );
+                stream.metadata = stream_state.metadata.clone();
+                stream.quota = stream_state.quota;
+                stream.namespace_id = stream_state.namespace_id;
                 stream.persist().await?;
                 unloaded_streams.push(stream);
                 info!(
@@ -120,6 +145,12 @@ impl System {
             .into_iter()
             .map(|s| (s.id, s))
             .collect::<AHashMap<_, _>>();
+        let partitions_count = streams_states
+            .values()
+            .flat_map(|stream| stream.topics.values())
+            .map(|topic| topic.partitions.len() as u64)
+            .sum();
+        self.storage.partitions_loader.start(partitions_count);
         let loaded_streams = RefCell::new(Vec::new());
         let load_stream_tasks = unloaded_streams.into_iter().map(|mut stream| {
             let state = streams_states.remove(&stream.stream_id).unwrap();
@@ -138,7 +169,10 @@ impl System {
                 continue;
             }
 
-            if self.streams_ids.contains_key(&stream.name) {
+            if self
+                .get_stream_id_by_name(stream.namespace_id, &stream.name)
+                .is_some()
+            {
                 error!("Stream with name: '{}' already exists.", &stream.name);
                 continue;
             }
@@ -150,8 +184,9 @@ impl System {
             self.metrics.increment_segments(stream.get_segments_count());
             self.metrics.increment_messages(stream.get_messages_count());
 
-            self.streams_ids
-                .insert(stream.name.clone(), stream.stream_id);
+            self.permissioner
+                .set_stream_namespace(stream.stream_id, stream.namespace_id);
+            self.insert_stream_name(stream.namespace_id, &stream.name, stream.stream_id);
             self.streams.insert(stream.stream_id, stream);
         }
 
@@ -163,7 +198,11 @@ impl System {
         self.streams.values().collect()
     }
 
-    pub fn find_streams(&self, session: &Session) -> Result<Vec<&Stream>, IggyError> {
+    pub fn find_streams(
+        &self,
+        session: &Session,
+        filter: &MetadataFilter,
+    ) -> Result<Vec<&Stream>, IggyError> {
         self.ensure_authenticated(session)?;
         self.permissioner
             .get_streams(session.get_user_id())
@@ -173,7 +212,13 @@ impl System {
                     session.get_user_id(),
                 )
             })?;
-        Ok(self.get_streams())
+        let namespace_id = self.permissioner.get_user_namespace(session.get_user_id());
+        Ok(self
+            .get_streams()
+            .into_iter()
+            .filter(|stream| namespace_id.is_none() || stream.namespace_id == namespace_id)
+            .filter(|stream| stream.metadata.matches(filter))
+            .collect())
     }
 
     pub fn find_stream(
@@ -182,7 +227,7 @@ impl System {
         identifier: &Identifier,
     ) -> Result<&Stream, IggyError> {
         self.ensure_authenticated(session)?;
-        let stream = self.get_stream(identifier);
+        let stream = self.get_stream(session, identifier);
         if let Ok(stream) = stream {
             self.permissioner
                 .get_stream(session.get_user_id(), stream.stream_id)
@@ -204,7 +249,7 @@ impl System {
         identifier: &Identifier,
     ) -> Result<Option<&Stream>, IggyError> {
         self.ensure_authenticated(session)?;
-        let Some(stream) = self.try_get_stream(identifier)? else {
+        let Some(stream) = self.try_get_stream(session, identifier)? else {
             return Ok(None);
         };
 
@@ -219,63 +264,125 @@ impl System {
         Ok(Some(stream))
     }
 
-    pub fn try_get_stream(&self, identifier: &Identifier) -> Result<Option<&Stream>, IggyError> {
-        match identifier.kind {
-            IdKind::Numeric => Ok(self.streams.get(&identifier.get_u32_value()?)),
-            IdKind::String => Ok(self.try_get_stream_by_name(&identifier.get_cow_str_value()?)),
-        }
+    /// Gets the stream by ID or by name as seen by the user of the session, see [`System::try_get_stream_in_namespace`].
+    pub fn try_get_stream(
+        &self,
+        session: &Session,
+        identifier: &Identifier,
+    ) -> Result<Option<&Stream>, IggyError> {
+        let namespace_id = self.permissioner.get_user_namespace(session.get_user_id());
+        self.try_get_stream_in_namespace(namespace_id, identifier)
     }
 
-    fn try_get_stream_by_name(&self, name: &str) -> Option<&Stream> {
-        self.streams_ids
-            .get(name)
-            .and_then(|id| self.streams.get(id))
+    pub fn get_stream(
+        &self,
+        session: &Session,
+        identifier: &Identifier,
+    ) -> Result<&Stream, IggyError> {
+        let namespace_id = self.permissioner.get_user_namespace(session.get_user_id());
+        self.get_stream_in_namespace(namespace_id, identifier)
     }
 
-    pub fn get_stream(&self, identifier: &Identifier) -> Result<&Stream, IggyError> {
-        match identifier.kind {
-            IdKind::Numeric => self.get_stream_by_id(identifier.get_u32_value()?),
-            IdKind::String => self.get_stream_by_name(&identifier.get_cow_str_value()?),
-        }
+    pub fn get_stream_mut(
+        &mut self,
+        session: &Session,
+        identifier: &Identifier,
+    ) -> Result<&mut Stream, IggyError> {
+        let namespace_id = self.permissioner.get_user_namespace(session.get_user_id());
+        self.get_stream_in_namespace_mut(namespace_id, identifier)
     }
 
-    pub fn get_stream_mut(&mut self, identifier: &Identifier) -> Result<&mut Stream, IggyError> {
-        match identifier.kind {
-            IdKind::Numeric => self.get_stream_by_id_mut(identifier.get_u32_value()?),
-            IdKind::String => self.get_stream_by_name_mut(&identifier.get_cow_str_value()?),
-        }
+    /// Gets the stream by ID or by name within the namespace. The name is resolved among the streams of the namespace,
+    /// or among the streams outside any namespace if it's `None`, and the streams of the other namespaces are never found,
+    /// so that their names and IDs don't leak to the namespaced users.
+    pub(crate) fn try_get_stream_in_namespace(
+        &self,
+        namespace_id: Option<u32>,
+        identifier: &Identifier,
+    ) -> Result<Option<&Stream>, IggyError> {
+        Ok(self
+            .try_get_stream_id(namespace_id, identifier)?
+            .and_then(|stream_id| self.streams.get(&stream_id)))
     }
 
-    fn get_stream_by_name(&self, name: &str) -> Result<&Stream, IggyError> {
-        let stream_id = self.streams_ids.get(name);
-        if stream_id.is_none() {
-            return Err(IggyError::StreamNameNotFound(name.to_string()));
-        }
+    pub(crate) fn get_stream_in_namespace(
+        &self,
+        namespace_id: Option<u32>,
+        identifier: &Identifier,
+    ) -> Result<&Stream, IggyError> {
+        self.try_get_stream_in_namespace(namespace_id, identifier)?
+            .ok_or_else(|| get_stream_not_found_error(identifier))
+    }
+
+    fn get_stream_in_namespace_mut(
+        &mut self,
+        namespace_id: Option<u32>,
+        identifier: &Identifier,
+    ) -> Result<&mut Stream, IggyError> {
+        let Some(stream_id) = self.try_get_stream_id(namespace_id, identifier)? else {
+            return Err(get_stream_not_found_error(identifier));
+        };
 
-        self.get_stream_by_id(*stream_id.unwrap())
+        self.get_stream_by_id_mut(stream_id)
     }
 
-    fn get_stream_by_id(&self, stream_id: u32) -> Result<&Stream, IggyError> {
-        let stream = self.streams.get(&stream_id);
-        if stream.is_none() {
-            return Err(IggyError::StreamIdNotFound(stream_id));
-        }
+    fn try_get_stream_id(
+        &self,
+        namespace_id: Option<u32>,
+        identifier: &Identifier,
+    ) -> Result<Option<u32>, IggyError> {
+        let stream_id = match identifier.kind {
+            IdKind::Numeric => identifier.get_u32_value()?,
+            IdKind::String => {
+                let name = identifier.get_cow_str_value()?;
+                let Some(stream_id) = self.get_stream_id_by_name(namespace_id, &name) else {
+                    return Ok(None);
+                };
+                stream_id
+            }
+        };
 
-        Ok(stream.unwrap())
+        Ok(self
+            .streams
+            .get(&stream_id)
+            .filter(|stream| namespace_id.is_none() || stream.namespace_id == namespace_id)
+            .map(|stream| stream.stream_id))
     }
 
-    fn get_stream_by_name_mut(&mut self, name: &str) -> Result<&mut Stream, IggyError> {
-        let stream_id;
-        {
-            let id = self.streams_ids.get_mut(name);
-            if id.is_none() {
-                return Err(IggyError::StreamNameNotFound(name.to_string()));
+    pub(crate) fn get_stream_id_by_name(
+        &self,
+        namespace_id: Option<u32>,
+        name: &str,
+    ) -> Option<u32> {
+        self.streams_ids
+            .get(&namespace_id)
+            .and_then(|streams_ids| streams_ids.get(name))
+            .copied()
+    }
+
+    fn insert_stream_name(&mut self, namespace_id: Option<u32>, name: &str, stream_id: u32) {
+        self.streams_ids
+            .entry(namespace_id)
+            .or_default()
+            .insert(name.to_owned(), stream_id);
+    }
+
+    fn remove_stream_name(&mut self, namespace_id: Option<u32>, name: &str) {
+        if let Some(streams_ids) = self.streams_ids.get_mut(&namespace_id) {
+            streams_ids.remove(name);
+            if streams_ids.is_empty() {
+                self.streams_ids.remove(&namespace_id);
             }
+        }
+    }
 
-            stream_id = *id.unwrap();
+    pub(crate) fn get_stream_by_id(&self, stream_id: u32) -> Result<&Stream, IggyError> {
+        let stream = self.streams.get(&stream_id);
+        if stream.is_none() {
+            return Err(IggyError::StreamIdNotFound(stream_id));
         }
 
-        self.get_stream_by_id_mut(stream_id)
+        Ok(stream.unwrap())
     }
 
     fn get_stream_by_id_mut(&mut self, stream_id: u32) -> Result<&mut Stream, IggyError> {
@@ -292,12 +399,19 @@ impl System {
         session: &Session,
         stream_id: Option<u32>,
         name: &str,
+        metadata: ResourceMetadata,
+        namespace_id: Option<u32>,
     ) -> Result<&Stream, IggyError> {
         self.ensure_authenticated(session)?;
         self.permissioner.create_stream(session.get_user_id())?;
-        if self.streams_ids.contains_key(name) {
+        let namespace_id = self.resolve_namespace(session, namespace_id)?;
+        if self.get_stream_id_by_name(namespace_id, name).is_some() {
             return Err(IggyError::StreamNameAlreadyExists(name.to_owned()));
         }
+        self.ensure_streams_limit()?;
+        if let Some(namespace_id) = namespace_id {
+            self.ensure_namespace_streams_limit(namespace_id)?;
+        }
 
         let mut id;
         if stream_id.is_none() {
@@ -320,12 +434,37 @@ impl System {
             return Err(IggyError::StreamIdAlreadyExists(id));
         }
 
-        let stream = Stream::create(id, name, self.config.clone(), self.storage.clone());
+        self.replicate(session, || {
+            EntryCommand::CreateStream(CreateStreamWithId {
+                stream_id: id,
+                command: CreateStream {
+                    stream_id,
+                    name: name.to_owned(),
+                    metadata: metadata.clone(),
+                    namespace_id,
+                },
+            })
+        })
+        .await?;
+        let mut stream = Stream::create(id, name, self.config.clone(), self.storage.clone());
+        stream.metadata = metadata.clone();
+        stream.namespace_id = namespace_id;
         stream.persist().await?;
         info!("Created stream with ID: {id}, name: '{name}'.");
-        self.streams_ids.insert(name.to_owned(), stream.stream_id);
+        self.permissioner
+            .set_stream_namespace(stream.stream_id, namespace_id);
+        self.insert_stream_name(namespace_id, name, stream.stream_id);
         self.streams.insert(stream.stream_id, stream);
         self.metrics.increment_streams(1);
+        self.publish_metadata_change(
+            session,
+            MetadataChange::StreamCreated {
+                stream_id: id,
+                name: name.to_owned(),
+                metadata,
+            },
+        );
+        self.audit(session, AuditAction::CreateStream, format!("streams/{id}"));
         self.get_stream_by_id(id)
     }
 
@@ -337,11 +476,13 @@ impl System {
     ) -> Result<(), IggyError> {
         self.ensure_authenticated(session)?;
         let stream_id;
+        let namespace_id;
         {
-            let stream = self.get_stream(id).with_error_context(|error| {
+            let stream = self.get_stream(session, id).with_error_context(|error| {
                 format!("{COMPONENT} (error: {error}) - failed to get stream with ID: {id}")
             })?;
             stream_id = stream.stream_id;
+            namespace_id = stream.namespace_id;
         }
 
         self.permissioner
@@ -355,16 +496,23 @@ impl System {
             })?;
 
         {
-            if let Some(stream_id_by_name) = self.streams_ids.get(name) {
-                if *stream_id_by_name != stream_id {
+            if let Some(stream_id_by_name) = self.get_stream_id_by_name(namespace_id, name) {
+                if stream_id_by_name != stream_id {
                     return Err(IggyError::StreamNameAlreadyExists(name.to_owned()));
                 }
             }
         }
 
+        self.replicate(session, || {
+            EntryCommand::UpdateStream(UpdateStream {
+                stream_id: id.clone(),
+                name: name.to_owned(),
+            })
+        })
+        .await?;
         let old_name;
         {
-            let stream = self.get_stream_mut(id).with_error_context(|error| {
+            let stream = self.get_stream_mut(session, id).with_error_context(|error| {
                 format!("{COMPONENT} (error: {error}) - failed to get mutable reference to stream with id: {id}")
             })?;
             old_name = stream.name.clone();
@@ -373,11 +521,120 @@ impl System {
         }
 
         {
-            self.streams_ids.remove(&old_name);
-            self.streams_ids.insert(name.to_owned(), stream_id);
+            self.remove_stream_name(namespace_id, &old_name);
+            self.insert_stream_name(namespace_id, name, stream_id);
         }
 
         info!("Stream with ID '{id}' updated. Old name: '{old_name}' changed to: '{name}'.");
+        self.publish_metadata_change(
+            session,
+            MetadataChange::StreamUpdated {
+                stream_id,
+                name: name.to_owned(),
+            },
+        );
+        self.audit(
+            session,
+            AuditAction::UpdateStream,
+            format!("streams/{stream_id}"),
+        );
+        Ok(())
+    }
+
+    pub async fn update_stream_metadata(
+        &mut self,
+        session: &Session,
+        id: &Identifier,
+        metadata: ResourceMetadata,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        let stream_id;
+        {
+            let stream = self.get_stream(session, id).with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to get stream with ID: {id}")
+            })?;
+            stream_id = stream.stream_id;
+        }
+
+        self.permissioner
+            .update_stream(session.get_user_id(), stream_id)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to update stream metadata, user ID: {}, stream ID: {}",
+                    session.get_user_id(),
+                    stream_id
+                )
+            })?;
+
+        self.replicate(session, || {
+            EntryCommand::UpdateStreamMetadata(UpdateStreamMetadata {
+                stream_id: id.clone(),
+                metadata: metadata.clone(),
+            })
+        })
+        .await?;
+
+        let stream = self.get_stream_mut(session, id).with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to get mutable reference to stream with id: {id}")
+        })?;
+        stream.metadata = metadata.clone();
+        info!("Stream with ID '{stream_id}' metadata updated.");
+        self.publish_metadata_change(
+            session,
+            MetadataChange::StreamMetadataUpdated {
+                stream_id,
+                metadata,
+            },
+        );
+        Ok(())
+    }
+
+    pub async fn update_stream_quota(
+        &mut self,
+        session: &Session,
+        id: &Identifier,
+        quota: StreamQuota,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        let stream_id;
+        {
+            let stream = self.get_stream(session, id).with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to get stream with ID: {id}")
+            })?;
+            stream_id = stream.stream_id;
+        }
+
+        self.permissioner
+            .update_stream(session.get_user_id(), stream_id)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to update stream quota, user ID: {}, stream ID: {}",
+                    session.get_user_id(),
+                    stream_id
+                )
+            })?;
+
+        self.replicate(session, || {
+            EntryCommand::UpdateStreamQuota(UpdateStreamQuota {
+                stream_id: id.clone(),
+                quota,
+            })
+        })
+        .await?;
+
+        let stream = self.get_stream_mut(session, id).with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to get mutable reference to stream with id: {id}")
+        })?;
+        stream.quota = quota;
+        stream.soft_quota_exceeded.store(false, Ordering::SeqCst);
+        info!(
+            "Stream with ID '{stream_id}' quota updated, soft limit: {}, hard limit: {}.",
+            quota.soft_limit, quota.hard_limit
+        );
+        self.publish_metadata_change(
+            session,
+            MetadataChange::StreamQuotaUpdated { stream_id, quota },
+        );
         Ok(())
     }
 
@@ -387,7 +644,7 @@ impl System {
         id: &Identifier,
     ) -> Result<u32, IggyError> {
         self.ensure_authenticated(session)?;
-        let stream = self.get_stream(id).with_error_context(|error| {
+        let stream = self.get_stream(session, id).with_error_context(|error| {
             format!("{COMPONENT} (error: {error}) - failed to get stream with ID: {id}")
         })?;
         let stream_id = stream.stream_id;
@@ -401,6 +658,13 @@ impl System {
                 )
             })?;
         let stream_name = stream.name.clone();
+        let namespace_id = stream.namespace_id;
+        self.replicate(session, || {
+            EntryCommand::DeleteStream(DeleteStream {
+                stream_id: id.clone(),
+            })
+        })
+        .await?;
         if stream.delete().await.is_err() {
             return Err(IggyError::CannotDeleteStream(stream_id));
         }
@@ -412,12 +676,25 @@ impl System {
         self.metrics.decrement_messages(stream.get_messages_count());
         self.metrics.decrement_segments(stream.get_segments_count());
         self.streams.remove(&stream_id);
-        self.streams_ids.remove(&stream_name);
+        self.remove_stream_name(namespace_id, &stream_name);
+        self.permissioner.set_stream_namespace(stream_id, None);
         let current_stream_id = CURRENT_STREAM_ID.load(Ordering::SeqCst);
         if current_stream_id > stream_id {
             CURRENT_STREAM_ID.store(stream_id, Ordering::SeqCst);
         }
 
+        self.publish_metadata_change(
+            session,
+            MetadataChange::StreamDeleted {
+                stream_id,
+                name: stream_name,
+            },
+        );
+        self.audit(
+            session,
+            AuditAction::DeleteStream,
+            format!("streams/{stream_id}"),
+        );
         let client_manager = self.client_manager.read().await;
         client_manager
             .delete_consumer_groups_for_stream(stream_id)
@@ -430,9 +707,11 @@ impl System {
         session: &Session,
         stream_id: &Identifier,
     ) -> Result<(), IggyError> {
-        let stream = self.get_stream(stream_id).with_error_context(|error| {
-            format!("{COMPONENT} (error: {error}) - failed to get stream with ID: {stream_id}")
-        })?;
+        let stream = self
+            .get_stream(session, stream_id)
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to get stream with ID: {stream_id}")
+            })?;
         self.permissioner
             .purge_stream(session.get_user_id(), stream.stream_id)
             .with_error_context(|error| {
@@ -442,7 +721,29 @@ impl System {
                     stream.stream_id,
                 )
             })?;
-        stream.purge().await
+        self.replicate(session, || {
+            EntryCommand::PurgeStream(PurgeStream {
+                stream_id: stream_id.clone(),
+            })
+        })
+        .await?;
+        stream.purge().await?;
+        self.audit(
+            session,
+            AuditAction::PurgeStream,
+            format!("streams/{}", stream.stream_id),
+        );
+        Ok(())
+    }
+}
+
+fn get_stream_not_found_error(identifier: &Identifier) -> IggyError {
+    match identifier.kind {
+        IdKind::Numeric => match identifier.get_u32_value() {
+            Ok(stream_id) => IggyError::StreamIdNotFound(stream_id),
+            Err(error) => error,
+        },
+        IdKind::String => IggyError::StreamNameNotFound(identifier.to_string()),
     }
 }
 
@@ -450,7 +751,7 @@ impl System {
 mod tests {
     use super::*;
     use crate::configs::server::{DataMaintenanceConfig, PersonalAccessTokenConfig};
-    use crate::configs::system::SystemConfig;
+    use crate::configs::system::{ResourceLimitsConfig, SystemConfig};
     use crate::state::{MockState, StateKind};
     use crate::streaming::persistence::persister::{FileWithSyncPersister, PersisterKind};
     use crate::streaming::storage::SystemStorage;
@@ -494,20 +795,83 @@ mod tests {
             .permissioner
             .init_permissions_for_user(root.id, permissions);
         system
-            .create_stream(&session, Some(stream_id), stream_name)
+            .create_stream(
+                &session,
+                Some(stream_id),
+                stream_name,
+                ResourceMetadata::default(),
+                None,
+            )
             .await
             .unwrap();
 
-        let stream = system.get_stream(&Identifier::numeric(stream_id).unwrap());
+        let stream = system.get_stream(&session, &Identifier::numeric(stream_id).unwrap());
         assert!(stream.is_ok());
         let stream = stream.unwrap();
         assert_eq!(stream.stream_id, stream_id);
         assert_eq!(stream.name, stream_name);
 
-        let stream = system.get_stream(&Identifier::named(stream_name).unwrap());
+        let stream = system.get_stream(&session, &Identifier::named(stream_name).unwrap());
         assert!(stream.is_ok());
         let stream = stream.unwrap();
         assert_eq!(stream.stream_id, stream_id);
         assert_eq!(stream.name, stream_name);
     }
+
+    #[tokio::test]
+    async fn should_not_create_stream_when_streams_limit_is_reached() {
+        let tempdir = tempfile::TempDir::new().unwrap();
+        let config = Arc::new(SystemConfig {
+            path: tempdir.path().to_str().unwrap().to_string(),
+            limits: ResourceLimitsConfig {
+                max_streams: 1,
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+        let storage = SystemStorage::new(
+            config.clone(),
+            Arc::new(PersisterKind::FileWithSync(FileWithSyncPersister {})),
+        );
+
+        let mut system = System::create(
+            config,
+            storage,
+            Arc::new(StateKind::Mock(MockState::new())),
+            None,
+            DataMaintenanceConfig::default(),
+            PersonalAccessTokenConfig::default(),
+        );
+        let root = User::root(DEFAULT_ROOT_USERNAME, DEFAULT_ROOT_PASSWORD);
+        let permissions = root.permissions.clone();
+        let session = Session::new(
+            1,
+            root.id,
+            SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234),
+        );
+        system
+            .permissioner
+            .init_permissions_for_user(root.id, permissions);
+        system
+            .create_stream(
+                &session,
+                Some(1),
+                "test-1",
+                ResourceMetadata::default(),
+                None,
+            )
+            .await
+            .unwrap();
+
+        let result = system
+            .create_stream(
+                &session,
+                Some(2),
+                "test-2",
+                ResourceMetadata::default(),
+                None,
+            )
+            .await;
+        assert!(matches!(result, Err(IggyError::StreamsLimitReached(1))));
+    }
 }
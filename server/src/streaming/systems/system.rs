@@ -16,38 +16,61 @@
  * under the License.
  */
 
-use crate::archiver::{ArchiverKind, ArchiverKindType};
+use crate::archiver::ArchiverKind;
+use crate::authenticator::AuthenticatorKind;
+use crate::cluster::node::ClusterNode;
 use crate::configs::server::{DataMaintenanceConfig, PersonalAccessTokenConfig};
-use crate::configs::system::SystemConfig;
+use crate::configs::system::{PartitionConfig, SystemConfig};
 use crate::map_toggle_str;
 use crate::state::file::FileState;
+use crate::state::replicated::ReplicatedState;
 use crate::state::system::SystemState;
 use crate::state::StateKind;
 use crate::streaming::cache::memory_tracker::CacheMemoryTracker;
 use crate::streaming::clients::client_manager::ClientManager;
 use crate::streaming::diagnostics::metrics::Metrics;
+use crate::streaming::namespaces::namespace::Namespace;
+#[cfg(all(target_os = "linux", feature = "io-uring"))]
+use crate::streaming::persistence::io_uring::{IoUringPersister, IoUringRing};
 use crate::streaming::persistence::persister::*;
+use crate::streaming::personal_access_tokens::login_guard::PersonalAccessTokenLoginGuard;
+use crate::streaming::push::push_subscription::PushSubscription;
+use crate::streaming::replay::replay_job::ReplayJob;
+use crate::streaming::segments::tiered_storage::TieredStorage;
 use crate::streaming::session::Session;
 use crate::streaming::storage::SystemStorage;
 use crate::streaming::streams::stream::Stream;
+use crate::streaming::systems::audit_log::AuditLog;
+use crate::streaming::systems::expiry_notifications::ExpiryNotifications;
+use crate::streaming::systems::fetch_quotas::FetchQuotas;
+use crate::streaming::systems::integrity::IntegrityReport;
+use crate::streaming::systems::message_audit::MessageAudit;
+use crate::streaming::systems::metadata_changes::MetadataChanges;
+use crate::streaming::systems::producers::ProducerKey;
+use crate::streaming::systems::quotas::Quotas;
+use crate::streaming::systems::transactions::TransactionState;
 use crate::streaming::systems::COMPONENT;
 use crate::streaming::users::permissioner::Permissioner;
 use crate::streaming::users::user::User;
 use crate::versioning::SemanticVersion;
 use ahash::AHashMap;
+use dashmap::DashMap;
 use error_set::ErrContext;
 use iggy::error::IggyError;
 use iggy::locking::IggySharedMut;
 use iggy::locking::IggySharedMutFn;
+use iggy::models::maintenance_mode::MaintenanceMode;
 use iggy::models::user_info::UserId;
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::crypto::{Aes256GcmEncryptor, EncryptorKind};
+use iggy::utils::timestamp::IggyTimestamp;
 use std::path::Path;
+use std::sync::atomic::{AtomicU32, AtomicU64};
 use std::sync::Arc;
 use tokio::fs::{create_dir_all, remove_dir_all};
-use tokio::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+use tokio::sync::{Mutex, RwLock, RwLockReadGuard, RwLockWriteGuard};
 use tokio::time::Instant;
-use tracing::{error, info, instrument, trace};
+use tracing::{error, info, instrument, trace, warn};
 
 #[derive(Debug)]
 pub struct SharedSystem {
@@ -83,14 +106,39 @@ pub struct System {
     pub permissioner: Permissioner,
     pub(crate) storage: Arc<SystemStorage>,
     pub(crate) streams: AHashMap<u32, Stream>,
-    pub(crate) streams_ids: AHashMap<String, u32>,
+    /// The stream names are unique within the namespace, `None` stands for the streams outside any namespace.
+    pub(crate) streams_ids: AHashMap<Option<u32>, AHashMap<String, u32>>,
     pub(crate) users: AHashMap<UserId, User>,
+    pub(crate) namespaces: AHashMap<u32, Namespace>,
+    pub(crate) namespaces_ids: AHashMap<String, u32>,
     pub(crate) config: Arc<SystemConfig>,
     pub(crate) client_manager: IggySharedMut<ClientManager>,
     pub(crate) encryptor: Option<Arc<EncryptorKind>>,
     pub(crate) metrics: Metrics,
     pub(crate) state: Arc<StateKind>,
+    pub(crate) cluster: Option<Arc<ClusterNode>>,
     pub(crate) archiver: Option<Arc<ArchiverKind>>,
+    pub(crate) authenticators: Vec<AuthenticatorKind>,
+    pub(crate) integrity_report: Option<IntegrityReport>,
+    pub(crate) replay_jobs: DashMap<u32, Arc<ReplayJob>>,
+    pub(crate) next_replay_job_id: AtomicU32,
+    pub(crate) push_subscriptions: DashMap<u32, Arc<PushSubscription>>,
+    pub(crate) next_push_subscription_id: AtomicU32,
+    pub(crate) push_subscriptions_save_lock: Mutex<()>,
+    pub(crate) producer_epochs: DashMap<ProducerKey, u32>,
+    pub(crate) producer_epochs_save_lock: Mutex<()>,
+    pub(crate) next_producer_id: AtomicU64,
+    pub(crate) transactions: DashMap<u64, TransactionState>,
+    pub(crate) transactions_save_lock: Mutex<()>,
+    pub(crate) next_transaction_id: AtomicU64,
+    pub(crate) metadata_changes: Option<MetadataChanges>,
+    pub(crate) message_audit: Option<MessageAudit>,
+    pub(crate) audit_log: Option<AuditLog>,
+    pub(crate) expiry_notifications: Option<ExpiryNotifications>,
+    pub(crate) maintenance_mode: MaintenanceMode,
+    pub(crate) pat_login_guard: PersonalAccessTokenLoginGuard,
+    pub(crate) fetch_quotas: FetchQuotas,
+    pub(crate) quotas: Quotas,
     pub personal_access_token: PersonalAccessTokenConfig,
 }
 
@@ -118,14 +166,31 @@ impl System {
         };
 
         let state_persister = Self::resolve_persister(config.state.enforce_fsync);
-        let partition_persister = Self::resolve_persister(config.partition.enforce_fsync);
+        let partition_persister = Self::resolve_partition_persister(&config.partition);
 
-        let state = Arc::new(StateKind::File(FileState::new(
+        let file_state = FileState::new(
             &config.get_state_log_path(),
             &version,
-            state_persister,
+            state_persister.clone(),
             encryptor.clone(),
-        )));
+        );
+        let state = if config.cluster.enabled {
+            info!(
+                "Cluster is enabled, node ID: {}, address: {}",
+                config.cluster.node_id, config.cluster.address
+            );
+            Arc::new(StateKind::Replicated(ReplicatedState::new(Arc::new(
+                ClusterNode::new(
+                    &config.cluster,
+                    &config.get_state_raft_path(),
+                    &config.get_state_raft_log_path(),
+                    state_persister,
+                    file_state,
+                ),
+            ))))
+        } else {
+            Arc::new(StateKind::File(file_state))
+        };
         Self::create(
             config.clone(),
             SystemStorage::new(config, partition_persister),
@@ -143,6 +208,25 @@ impl System {
         }
     }
 
+    #[cfg(all(target_os = "linux", feature = "io-uring"))]
+    fn resolve_partition_persister(config: &PartitionConfig) -> Arc<PersisterKind> {
+        if !config.io_uring.enabled {
+            return Self::resolve_persister(config.enforce_fsync);
+        }
+
+        info!("Partition writes are submitted using io_uring.");
+        let ring = IoUringRing::get_or_init(config.io_uring.queue_depth).unwrap();
+        Arc::new(PersisterKind::IoUring(IoUringPersister::new(
+            ring,
+            config.enforce_fsync,
+        )))
+    }
+
+    #[cfg(not(all(target_os = "linux", feature = "io-uring")))]
+    fn resolve_partition_persister(config: &PartitionConfig) -> Arc<PersisterKind> {
+        Self::resolve_persister(config.enforce_fsync)
+    }
+
     pub fn create(
         system_config: Arc<SystemConfig>,
         storage: SystemStorage,
@@ -154,28 +238,43 @@ impl System {
         let archiver_config = data_maintenance_config.archiver;
         let archiver: Option<Arc<ArchiverKind>> = if archiver_config.enabled {
             info!("Archiving is enabled, kind: {}", archiver_config.kind);
-            match archiver_config.kind {
-                ArchiverKindType::Disk => Some(Arc::new(ArchiverKind::get_disk_archiver(
-                    archiver_config
-                        .disk
-                        .clone()
-                        .expect("Disk archiver config is missing"),
-                ))),
-                ArchiverKindType::S3 => Some(Arc::new(
-                    ArchiverKind::get_s3_archiver(
-                        archiver_config
-                            .s3
-                            .clone()
-                            .expect("S3 archiver config is missing"),
-                    )
-                    .expect("Failed to create S3 archiver"),
-                )),
-            }
+            Some(Arc::new(
+                ArchiverKind::from_config(&archiver_config).expect("Failed to create archiver"),
+            ))
         } else {
             info!("Archiving is disabled.");
             None
         };
 
+        let mut storage = storage;
+        let tiered_storage_config = data_maintenance_config.tiered_storage;
+        if tiered_storage_config.enabled {
+            match archiver.as_ref() {
+                Some(archiver) => {
+                    info!(
+                        "Tiered storage is enabled, cache path: {}",
+                        tiered_storage_config.cache_path
+                    );
+                    storage.tiered_storage = Some(Arc::new(TieredStorage::new(
+                        archiver.clone(),
+                        &tiered_storage_config,
+                    )));
+                }
+                None => {
+                    warn!("Tiered storage is enabled, but the archiver is disabled, archived segments will not be read.");
+                }
+            }
+        }
+
+        let authenticators = AuthenticatorKind::resolve(&system_config.authentication)
+            .expect("Failed to resolve authenticators");
+        let cluster = match state.as_ref() {
+            StateKind::Replicated(state) => Some(state.node().clone()),
+            _ => None,
+        };
+        let fetch_quotas = FetchQuotas::new(&system_config.fetch_quotas);
+        let quotas = Quotas::new(&system_config.quotas);
+
         System {
             config: system_config,
             streams: AHashMap::new(),
@@ -186,14 +285,46 @@ impl System {
             permissioner: Permissioner::default(),
             metrics: Metrics::init(),
             users: AHashMap::new(),
+            namespaces: AHashMap::new(),
+            namespaces_ids: AHashMap::new(),
             state,
+            cluster,
+            pat_login_guard: PersonalAccessTokenLoginGuard::new(pat_config.login_guard.clone()),
             personal_access_token: pat_config,
+            fetch_quotas,
+            quotas,
             archiver,
+            authenticators,
+            integrity_report: None,
+            replay_jobs: DashMap::new(),
+            next_replay_job_id: AtomicU32::new(0),
+            push_subscriptions: DashMap::new(),
+            next_push_subscription_id: AtomicU32::new(0),
+            push_subscriptions_save_lock: Mutex::new(()),
+            producer_epochs: DashMap::new(),
+            producer_epochs_save_lock: Mutex::new(()),
+            // The producer IDs start with the server start time (in seconds) in the upper bits,
+            // so they are not reused after the restart without persisting the counter.
+            next_producer_id: AtomicU64::new((IggyTimestamp::now().as_micros() / 1_000_000) << 32),
+            transactions: DashMap::new(),
+            transactions_save_lock: Mutex::new(()),
+            next_transaction_id: AtomicU64::new(
+                (IggyTimestamp::now().as_micros() / 1_000_000) << 32,
+            ),
+            metadata_changes: None,
+            message_audit: None,
+            audit_log: None,
+            expiry_notifications: None,
+            maintenance_mode: MaintenanceMode::default(),
         }
     }
 
     #[instrument(skip_all, name = "trace_system_init")]
     pub async fn init(&mut self) -> Result<(), IggyError> {
+        if self.is_read_only() {
+            return self.init_read_only().await;
+        }
+
         let system_path = self.config.get_system_path();
         if !Path::new(&system_path).exists() && create_dir_all(&system_path).await.is_err() {
             return Err(IggyError::CannotCreateBaseDirectory(system_path));
@@ -235,6 +366,7 @@ impl System {
         self.load_version().await.with_error_context(|error| {
             format!("{COMPONENT} (error: {error}) - failed to load version")
         })?;
+        self.load_namespaces(system_state.namespaces.into_values().collect());
         self.load_users(system_state.users.into_values().collect())
             .await
             .with_error_context(|error| {
@@ -245,6 +377,41 @@ impl System {
             .with_error_context(|error| {
                 format!("{COMPONENT} (error: {error}) - failed to load streams")
             })?;
+        // The heat map is only a hint, so the server starts with the cold cache if it can't be used.
+        if let Err(error) = self.warm_up_cache().await {
+            warn!("Failed to warm up cache. {error}");
+        }
+        self.load_push_subscriptions()
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to load push subscriptions")
+            })?;
+        self.load_producer_epochs()
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to load producer epochs")
+            })?;
+        self.load_transactions().await.with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to load transactions")
+        })?;
+        self.init_metadata_changes()
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to initialize metadata changes")
+            })?;
+        self.init_message_audit()
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to initialize message audit")
+            })?;
+        self.init_audit_log().await.with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to initialize audit log")
+        })?;
+        self.init_expiry_notifications()
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to initialize expiry notifications")
+            })?;
         if let Some(archiver) = self.archiver.as_ref() {
             archiver
                 .init()
@@ -255,12 +422,133 @@ impl System {
         Ok(())
     }
 
+    /// Loads the system without modifying any of the data on disk and verifies its integrity.
+    /// Only the runtime directory is recreated, as it's used to store the integrity report.
+    #[instrument(skip_all, name = "trace_system_init_read_only")]
+    async fn init_read_only(&mut self) -> Result<(), IggyError> {
+        warn!("Server is starting in read-only recovery mode, the data will not be modified.");
+        let system_path = self.config.get_system_path();
+        if !Path::new(&system_path).exists() {
+            error!("System path: {system_path} does not exist, there is nothing to recover.");
+            return Err(IggyError::CannotCreateBaseDirectory(system_path));
+        }
+
+        let runtime_path = self.config.get_runtime_path();
+        if Path::new(&runtime_path).exists() && remove_dir_all(&runtime_path).await.is_err() {
+            return Err(IggyError::CannotRemoveRuntimeDirectory(runtime_path));
+        }
+
+        if create_dir_all(&runtime_path).await.is_err() {
+            return Err(IggyError::CannotCreateRuntimeDirectory(runtime_path));
+        }
+
+        info!("Initializing system in read-only mode, data is stored at: {system_path}");
+        let state_entries = self
+            .state
+            .load_entries()
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to load state entries")
+            })?;
+        let system_state = SystemState::init(state_entries)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to initialize system state")
+            })?;
+        let now = Instant::now();
+        let mut report = IntegrityReport::default();
+        self.verify_state_consistency(&system_state, &mut report)
+            .await;
+        self.load_namespaces(system_state.namespaces.into_values().collect());
+        self.load_users(system_state.users.into_values().collect())
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to load users")
+            })?;
+        self.load_streams(system_state.streams.into_values().collect())
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to load streams")
+            })?;
+        self.verify_loaded_data(&mut report).await;
+        self.save_integrity_report(&report)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to save integrity report")
+            })?;
+        self.integrity_report = Some(report);
+        info!(
+            "Initialized system in read-only mode in {} ms.",
+            now.elapsed().as_millis()
+        );
+        Ok(())
+    }
+
+    /// Returns `true` if the server was started in the read-only recovery mode.
+    pub fn is_read_only(&self) -> bool {
+        self.config.recovery.read_only
+    }
+
+    pub fn ensure_writable(&self) -> Result<(), IggyError> {
+        if self.is_read_only() {
+            return Err(IggyError::ReadOnlyMode);
+        }
+
+        Ok(())
+    }
+
+    pub fn get_cluster(&self) -> Option<&Arc<ClusterNode>> {
+        self.cluster.as_ref()
+    }
+
+    /// Returns an error if the server is a member of the cluster, but not its leader.
+    pub fn ensure_cluster_leader(&self) -> Result<(), IggyError> {
+        match self.cluster.as_ref() {
+            Some(cluster) => cluster.ensure_leader(),
+            None => Ok(()),
+        }
+    }
+
+    pub fn get_integrity_report(
+        &self,
+        session: &Session,
+    ) -> Result<Option<&IntegrityReport>, IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .get_stats(session.get_user_id())
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to get integrity report for user with ID: {}",
+                    session.get_user_id()
+                )
+            })?;
+        Ok(self.integrity_report.as_ref())
+    }
+
     #[instrument(skip_all, name = "trace_shutdown")]
     pub async fn shutdown(&mut self) -> Result<(), IggyError> {
         self.persist_messages().await?;
+        self.save_partition_manifests().await;
+        if let Err(error) = self.save_cache_heat_map().await {
+            warn!("Failed to save cache heat map. {error}");
+        }
         Ok(())
     }
 
+    /// Marks the partitions as cleanly shut down, so that their segments don't need to be validated on the next startup.
+    async fn save_partition_manifests(&self) {
+        for stream in self.streams.values() {
+            for topic in stream.get_topics() {
+                for partition in topic.get_partitions() {
+                    let partition = partition.read().await;
+                    if let Err(error) = partition.save_manifest(true).await {
+                        warn!("Failed to save manifest for partition: {partition}. {error}");
+                    }
+                }
+            }
+        }
+    }
+
     #[instrument(skip_all, name = "trace_persist_messages")]
     pub async fn persist_messages(&self) -> Result<usize, IggyError> {
         trace!("Saving buffered messages on disk...");
@@ -268,6 +556,13 @@ impl System {
         for stream in self.streams.values() {
             saved_messages_number += stream.persist_messages().await?;
         }
+        self.storage
+            .consumer_offsets
+            .flush()
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to flush consumer offsets")
+            })?;
 
         Ok(saved_messages_number)
     }
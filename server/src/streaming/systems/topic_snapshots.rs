@@ -0,0 +1,103 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::mapper;
+use crate::streaming::polling_consumer::PollingConsumer;
+use crate::streaming::systems::system::System;
+use crate::streaming::systems::COMPONENT;
+use crate::streaming::topics::topic::Topic;
+use crate::streaming::utils::file;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::messages::poll_messages::{IsolationLevel, PollingStrategy};
+use iggy::system::handshake::Handshake;
+use tokio::fs;
+use tracing::{debug, error};
+
+pub const TOPIC_SNAPSHOT_FILE_EXTENSION: &str = "snapshot";
+
+impl System {
+    /// Saves the most recent messages of each topic partition to the snapshot files, returning their paths.
+    ///
+    /// Each file contains the messages in the same binary format as the poll response, so they can be loaded
+    /// by the SDK, and it's replaced atomically, thus the archiver never reads a partially written snapshot.
+    pub async fn save_topic_snapshot(
+        &self,
+        topic: &Topic,
+        max_messages: u32,
+    ) -> Result<Vec<String>, IggyError> {
+        let snapshot_path = self
+            .config
+            .get_topic_snapshot_path(topic.stream_id, topic.topic_id);
+        fs::create_dir_all(&snapshot_path)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to create topic snapshot directory: {snapshot_path}")
+            })
+            .map_err(|_| IggyError::CannotSaveTopicSnapshot(topic.topic_id, topic.stream_id))?;
+
+        let mut partition_ids = topic.partitions.keys().copied().collect::<Vec<_>>();
+        partition_ids.sort();
+        let mut files = Vec::with_capacity(partition_ids.len());
+        for partition_id in partition_ids {
+            // The consumer is not used for polling the last messages.
+            let mut polled_messages = topic
+                .get_messages(
+                    PollingConsumer::Consumer(0, partition_id),
+                    partition_id,
+                    PollingStrategy::last(),
+                    max_messages,
+                    IsolationLevel::ReadUncommitted,
+                    None,
+                )
+                .await
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to get messages for snapshot of topic: {topic}, partition ID: {partition_id}")
+                })?;
+            self.decrypt_polled_messages(&mut polled_messages)?;
+
+            // The snapshots use the format of the poll response without any optional features.
+            let data =
+                mapper::map_polled_messages(&polled_messages, Handshake::default(), false).concat();
+            let path = format!("{snapshot_path}/{partition_id}.{TOPIC_SNAPSHOT_FILE_EXTENSION}");
+            let temp_path = format!("{path}.tmp");
+            self.storage
+                .persister
+                .overwrite(&temp_path, &data)
+                .await
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to save topic snapshot at path: {temp_path}")
+                })?;
+            if let Err(error) = file::rename(&temp_path, &path).await {
+                error!("Failed to replace topic snapshot at path: {path}. {error}");
+                return Err(IggyError::CannotSaveTopicSnapshot(
+                    topic.topic_id,
+                    topic.stream_id,
+                ));
+            }
+
+            debug!(
+                "Saved snapshot of partition with ID: {partition_id} for topic: {topic} with {} messages.",
+                polled_messages.messages.len()
+            );
+            files.push(path);
+        }
+
+        Ok(files)
+    }
+}
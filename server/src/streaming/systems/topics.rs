@@ -16,17 +16,40 @@
  * under the License.
  */
 
+use crate::state::command::EntryCommand;
+use crate::state::models::{CreateTopicWithId, MarkTopicForDeletionWithDeadline};
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::System;
 use crate::streaming::systems::COMPONENT;
 use crate::streaming::topics::topic::Topic;
 use error_set::ErrContext;
 use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
 use iggy::error::IggyError;
 use iggy::identifier::Identifier;
 use iggy::locking::IggySharedMutFn;
+use iggy::messages::message_id_scheme::MessageIdScheme;
+use iggy::models::audit_entry::AuditAction;
+use iggy::models::metadata::{MetadataFilter, ResourceMetadata};
+use iggy::models::metadata_change::MetadataChange;
+use iggy::topics::cleanup_policy::CleanupPolicy;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::topics::delete_topic::DeleteTopic;
+use iggy::topics::mark_topic_for_deletion::MarkTopicForDeletion;
+use iggy::topics::purge_topic::PurgeTopic;
+use iggy::topics::topic_config::TopicConfig;
+use iggy::topics::update_topic::UpdateTopic;
+use iggy::topics::update_topic_config::UpdateTopicConfig;
+use iggy::topics::update_topic_expiry_watcher::UpdateTopicExpiryWatcher;
+use iggy::topics::update_topic_metadata::UpdateTopicMetadata;
+use iggy::topics::update_topic_producers::UpdateTopicProducers;
+use iggy::users::defaults::DEFAULT_ROOT_USER_ID;
+use iggy::utils::duration::IggyDuration;
 use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::timestamp::IggyTimestamp;
 use iggy::utils::topic_size::MaxTopicSize;
+use std::net::{Ipv4Addr, SocketAddr};
+use tracing::{error, info};
 
 impl System {
     pub fn find_topic(
@@ -61,11 +84,14 @@ impl System {
         &self,
         session: &Session,
         stream_id: &Identifier,
+        filter: &MetadataFilter,
     ) -> Result<Vec<&Topic>, IggyError> {
         self.ensure_authenticated(session)?;
-        let stream = self.get_stream(stream_id).with_error_context(|error| {
-            format!("{COMPONENT} (error: {error}) - failed to get stream with ID: {stream_id}")
-        })?;
+        let stream = self
+            .get_stream(session, stream_id)
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to get stream with ID: {stream_id}")
+            })?;
         self.permissioner
             .get_topics(session.get_user_id(), stream.stream_id)
             .with_error_context(|error| {
@@ -74,7 +100,11 @@ impl System {
                     session.get_user_id(),
                 )
             })?;
-        Ok(stream.get_topics())
+        Ok(stream
+            .get_topics()
+            .into_iter()
+            .filter(|topic| topic.metadata.matches(filter))
+            .collect())
     }
 
     pub fn try_find_topic(
@@ -120,12 +150,19 @@ impl System {
         compression_algorithm: CompressionAlgorithm,
         max_topic_size: MaxTopicSize,
         replication_factor: Option<u8>,
+        metadata: ResourceMetadata,
+        message_id_scheme: MessageIdScheme,
+        cleanup_policy: CleanupPolicy,
     ) -> Result<&Topic, IggyError> {
         self.ensure_authenticated(session)?;
         {
-            let stream = self.get_stream(stream_id).with_error_context(|error| {
-                format!("{COMPONENT} (error: {error}) - failed to get stream with ID: {stream_id}")
-            })?;
+            let stream = self
+                .get_stream(session, stream_id)
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to get stream with ID: {stream_id}"
+                    )
+                })?;
             self.permissioner
                 .create_topic(session.get_user_id(), stream.stream_id)
                 .with_error_context(|error| {
@@ -134,12 +171,39 @@ impl System {
                         session.get_user_id(),
                     )
                 })?;
+            self.ensure_topics_limit(stream.stream_id)?;
+            self.ensure_partitions_limit(0, partitions_count)?;
         }
 
-        let created_topic_id = self
-            .get_stream_mut(stream_id)?
-            .create_topic(
+        Topic::get_max_topic_size(max_topic_size, &self.config)?;
+        let topic_id = self
+            .get_stream(session, stream_id)?
+            .get_new_topic_id(topic_id, name)?;
+        self.replicate(session, || {
+            EntryCommand::CreateTopic(CreateTopicWithId {
                 topic_id,
+                command: CreateTopic {
+                    stream_id: stream_id.clone(),
+                    topic_id: Some(topic_id),
+                    partitions_count,
+                    compression_algorithm,
+                    message_expiry,
+                    max_topic_size,
+                    replication_factor,
+                    name: name.to_owned(),
+                    metadata: metadata.clone(),
+                    message_id_scheme,
+                    cleanup_policy,
+                },
+            })
+        })
+        .await?;
+        let message_id_scheme = Topic::get_message_id_scheme(message_id_scheme, &self.config);
+        let cleanup_policy = Topic::get_cleanup_policy(cleanup_policy, &self.config);
+        let stream = self.get_stream_mut(session, stream_id)?;
+        let created_topic_id = stream
+            .create_topic(
+                Some(topic_id),
                 name,
                 partitions_count,
                 message_expiry,
@@ -151,12 +215,17 @@ impl System {
             .with_error_context(|error| {
                 format!("{COMPONENT} (error: {error}) - failed to create topic with name: {name} in stream ID: {stream_id}")
             })?;
+        let topic = stream.get_topic_mut(&created_topic_id.try_into()?)?;
+        topic.metadata = metadata;
+        topic.message_id_scheme = message_id_scheme;
+        topic.cleanup_policy = cleanup_policy;
 
         self.metrics.increment_topics(1);
         self.metrics.increment_partitions(partitions_count);
         self.metrics.increment_segments(partitions_count);
 
-        self.get_stream(stream_id)
+        let topic = self
+            .get_stream(session, stream_id)
             .with_error_context(|error| {
                 format!("{COMPONENT} (error: {error}) - failed to get stream with ID: {stream_id}")
             })?
@@ -165,7 +234,27 @@ impl System {
                 format!(
                     "{COMPONENT} (error: {error}) - failed to get created topic with ID: {created_topic_id} in stream with ID: {stream_id}",
                 )
-            })
+            })?;
+        self.publish_metadata_change(
+            session,
+            MetadataChange::TopicCreated {
+                stream_id: topic.stream_id,
+                topic_id: topic.topic_id,
+                name: topic.name.clone(),
+                partitions_count: topic.get_partitions_count(),
+                message_expiry: topic.message_expiry,
+                compression_algorithm: topic.compression_algorithm,
+                max_topic_size: topic.max_topic_size,
+                replication_factor: topic.replication_factor,
+                metadata: topic.metadata.clone(),
+            },
+        );
+        self.audit(
+            session,
+            AuditAction::CreateTopic,
+            format!("streams/{}/topics/{}", topic.stream_id, topic.topic_id),
+        );
+        Ok(topic)
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -179,6 +268,7 @@ impl System {
         compression_algorithm: CompressionAlgorithm,
         max_topic_size: MaxTopicSize,
         replication_factor: Option<u8>,
+        max_segments: Option<u32>,
     ) -> Result<&Topic, IggyError> {
         self.ensure_authenticated(session)?;
         {
@@ -203,7 +293,21 @@ impl System {
             })?;
         }
 
-        self.get_stream_mut(stream_id)?
+        self.replicate(session, || {
+            EntryCommand::UpdateTopic(UpdateTopic {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                compression_algorithm,
+                message_expiry,
+                max_topic_size,
+                replication_factor,
+                name: name.to_owned(),
+                max_segments,
+            })
+        })
+        .await?;
+
+        self.get_stream_mut(session, stream_id)?
             .update_topic(
                 topic_id,
                 name,
@@ -211,6 +315,7 @@ impl System {
                 compression_algorithm,
                 max_topic_size,
                 replication_factor.unwrap_or(1),
+                max_segments,
             )
             .await
             .with_error_context(|error| {
@@ -219,17 +324,401 @@ impl System {
                 )
             })?;
 
-        // TODO: if message_expiry is changed, we need to check if we need to purge messages based on the new expiry
-        // TODO: if max_size_bytes is changed, we need to check if we need to purge messages based on the new size
+        // The changed retention (message expiry, max topic size and max segments) is enforced by the messages maintenance.
         // TODO: if replication_factor is changed, we need to do `something`
-        self.get_stream(stream_id)
+        let topic = self
+            .get_stream(session, stream_id)
             .with_error_context(|error| {
                 format!("{COMPONENT} (error: {error}) - failed to get stream with ID: {stream_id}")
             })?
             .get_topic(topic_id)
             .with_error_context(|error| {
                 format!("{COMPONENT} (error: {error}) - failed to get topic with ID: {topic_id} in stream with ID: {stream_id}")
+            })?;
+        self.publish_metadata_change(
+            session,
+            MetadataChange::TopicUpdated {
+                stream_id: topic.stream_id,
+                topic_id: topic.topic_id,
+                name: topic.name.clone(),
+                message_expiry: topic.message_expiry,
+                compression_algorithm: topic.compression_algorithm,
+                max_topic_size: topic.max_topic_size,
+                replication_factor: topic.replication_factor,
+                max_segments: topic.max_segments,
+            },
+        );
+        self.audit(
+            session,
+            AuditAction::UpdateTopic,
+            format!("streams/{}/topics/{}", topic.stream_id, topic.topic_id),
+        );
+        Ok(topic)
+    }
+
+    pub async fn update_topic_metadata(
+        &mut self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        metadata: ResourceMetadata,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        {
+            let topic = self
+                .find_topic(session, stream_id, topic_id)
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to find topic with ID: {topic_id}"
+                    )
+                })?;
+            self.permissioner.update_topic(
+                session.get_user_id(),
+                topic.stream_id,
+                topic.topic_id,
+            ).with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to update topic metadata for user with id: {}, stream ID: {}, topic ID: {}",
+                    session.get_user_id(),
+                    topic.stream_id,
+                    topic.topic_id,
+                )
+            })?;
+        }
+
+        self.replicate(session, || {
+            EntryCommand::UpdateTopicMetadata(UpdateTopicMetadata {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                metadata: metadata.clone(),
             })
+        })
+        .await?;
+
+        let topic = self
+            .get_stream_mut(session, stream_id)?
+            .get_topic_mut(topic_id)
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to get topic with ID: {topic_id} in stream with ID: {stream_id}")
+            })?;
+        topic.metadata = metadata.clone();
+        info!(
+            "Topic with ID: {} in stream with ID: {} metadata updated.",
+            topic.topic_id, topic.stream_id
+        );
+        let change = MetadataChange::TopicMetadataUpdated {
+            stream_id: topic.stream_id,
+            topic_id: topic.topic_id,
+            metadata,
+        };
+        self.publish_metadata_change(session, change);
+        Ok(())
+    }
+
+    pub async fn update_topic_producers(
+        &mut self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        allowed_producers: Vec<u32>,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        {
+            let topic = self
+                .find_topic(session, stream_id, topic_id)
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to find topic with ID: {topic_id}"
+                    )
+                })?;
+            self.permissioner.update_topic(
+                session.get_user_id(),
+                topic.stream_id,
+                topic.topic_id,
+            ).with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to update topic producers for user with id: {}, stream ID: {}, topic ID: {}",
+                    session.get_user_id(),
+                    topic.stream_id,
+                    topic.topic_id,
+                )
+            })?;
+        }
+
+        if let Some(user_id) = allowed_producers
+            .iter()
+            .find(|user_id| !self.users.contains_key(user_id))
+        {
+            return Err(IggyError::ResourceNotFound(user_id.to_string()));
+        }
+
+        self.replicate(session, || {
+            EntryCommand::UpdateTopicProducers(UpdateTopicProducers {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                allowed_producers: allowed_producers.clone(),
+            })
+        })
+        .await?;
+
+        let topic = self
+            .get_stream_mut(session, stream_id)?
+            .get_topic_mut(topic_id)
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to get topic with ID: {topic_id} in stream with ID: {stream_id}")
+            })?;
+        topic.allowed_producers = allowed_producers.clone();
+        info!(
+            "Topic with ID: {} in stream with ID: {} producers updated, allowed: {:?}.",
+            topic.topic_id, topic.stream_id, topic.allowed_producers
+        );
+        let change = MetadataChange::TopicProducersUpdated {
+            stream_id: topic.stream_id,
+            topic_id: topic.topic_id,
+            allowed_producers,
+        };
+        self.publish_metadata_change(session, change);
+        Ok(())
+    }
+
+    pub async fn update_topic_config(
+        &mut self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        config: TopicConfig,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        {
+            let topic = self
+                .find_topic(session, stream_id, topic_id)
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to find topic with ID: {topic_id}"
+                    )
+                })?;
+            self.permissioner.update_topic(
+                session.get_user_id(),
+                topic.stream_id,
+                topic.topic_id,
+            ).with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to update topic config for user with id: {}, stream ID: {}, topic ID: {}",
+                    session.get_user_id(),
+                    topic.stream_id,
+                    topic.topic_id,
+                )
+            })?;
+        }
+
+        self.replicate(session, || {
+            EntryCommand::UpdateTopicConfig(UpdateTopicConfig {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                config,
+            })
+        })
+        .await?;
+
+        let topic = self
+            .get_stream_mut(session, stream_id)?
+            .get_topic_mut(topic_id)
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to get topic with ID: {topic_id} in stream with ID: {stream_id}")
+            })?;
+        topic.update_config(config).await.with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to update config of topic with ID: {topic_id} in stream with ID: {stream_id}")
+        })?;
+        info!(
+            "Topic with ID: {} in stream with ID: {} config updated: {}.",
+            topic.topic_id, topic.stream_id, topic.topic_config
+        );
+        let change = MetadataChange::TopicConfigUpdated {
+            stream_id: topic.stream_id,
+            topic_id: topic.topic_id,
+            config,
+        };
+        self.publish_metadata_change(session, change);
+        Ok(())
+    }
+
+    pub async fn update_topic_expiry_watcher(
+        &mut self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        consumer: &Consumer,
+        enabled: bool,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        {
+            let topic = self
+                .find_topic(session, stream_id, topic_id)
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to find topic with ID: {topic_id}"
+                    )
+                })?;
+            self.permissioner.poll_messages(
+                session.get_user_id(),
+                topic.stream_id,
+                topic.topic_id,
+            ).with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to update expiry watcher for user with id: {}, stream ID: {}, topic ID: {}",
+                    session.get_user_id(),
+                    topic.stream_id,
+                    topic.topic_id,
+                )
+            })?;
+        }
+
+        self.replicate(session, || {
+            EntryCommand::UpdateTopicExpiryWatcher(UpdateTopicExpiryWatcher {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                consumer_kind: consumer.kind,
+                consumer_id: consumer.id.clone(),
+                enabled,
+            })
+        })
+        .await?;
+
+        self.get_stream_mut(session, stream_id)?
+            .get_topic_mut(topic_id)
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to get topic with ID: {topic_id} in stream with ID: {stream_id}")
+            })?
+            .update_expiry_watcher(consumer, enabled)
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to update expiry watcher: {consumer} of topic with ID: {topic_id} in stream with ID: {stream_id}")
+            })?;
+        Ok(())
+    }
+
+    /// Returns the deletion time of the topic being marked for deletion,
+    /// using the server default drain period if the given one is '0'.
+    pub fn get_topic_delete_at(&self, drain_period: IggyDuration) -> IggyTimestamp {
+        let drain_period = if drain_period.is_zero() {
+            self.config.topic.deletion_drain_period
+        } else {
+            drain_period
+        };
+        (IggyTimestamp::now().as_micros() + drain_period.as_micros()).into()
+    }
+
+    /// Marks the topic for deletion, so that it rejects the new messages while the consumers can still drain it
+    /// until the given time, after which it's deleted by the background task.
+    pub async fn mark_topic_for_deletion(
+        &mut self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        drain_period: IggyDuration,
+        delete_at: IggyTimestamp,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        {
+            let topic = self
+                .find_topic(session, stream_id, topic_id)
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to find topic with ID: {topic_id}"
+                    )
+                })?;
+            self.permissioner.delete_topic(
+                session.get_user_id(),
+                topic.stream_id,
+                topic.topic_id,
+            ).with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to mark topic for deletion for user with id: {}, stream ID: {}, topic ID: {}",
+                    session.get_user_id(),
+                    topic.stream_id,
+                    topic.topic_id,
+                )
+            })?;
+        }
+
+        self.replicate(session, || {
+            EntryCommand::MarkTopicForDeletion(MarkTopicForDeletionWithDeadline {
+                delete_at,
+                command: MarkTopicForDeletion {
+                    stream_id: stream_id.clone(),
+                    topic_id: topic_id.clone(),
+                    drain_period,
+                },
+            })
+        })
+        .await?;
+
+        let topic = self
+            .get_stream_mut(session, stream_id)?
+            .get_topic_mut(topic_id)
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to get topic with ID: {topic_id} in stream with ID: {stream_id}")
+            })?;
+        topic.delete_at = Some(delete_at);
+        info!(
+            "Topic with ID: {} in stream with ID: {} marked for deletion, it will be deleted at: {delete_at}.",
+            topic.topic_id, topic.stream_id
+        );
+        let resource = format!("streams/{}/topics/{}", topic.stream_id, topic.topic_id);
+        let change = MetadataChange::TopicMarkedForDeletion {
+            stream_id: topic.stream_id,
+            topic_id: topic.topic_id,
+            delete_at,
+        };
+        self.publish_metadata_change(session, change);
+        self.audit(session, AuditAction::DeleteTopic, resource);
+        Ok(())
+    }
+
+    /// Deletes the topics marked for deletion whose drain period has elapsed.
+    pub async fn delete_drained_topics(&mut self) -> Result<(), IggyError> {
+        let now = IggyTimestamp::now().as_micros();
+        let drained_topics = self
+            .get_streams()
+            .iter()
+            .flat_map(|stream| stream.get_topics())
+            .filter(|topic| {
+                topic
+                    .delete_at
+                    .is_some_and(|delete_at| delete_at.as_micros() <= now)
+            })
+            .map(|topic| (topic.stream_id, topic.topic_id))
+            .collect::<Vec<_>>();
+        // The drained topics are deleted by the leader of the cluster, replicating the deletions.
+        if drained_topics.is_empty() || self.ensure_cluster_leader().is_err() {
+            return Ok(());
+        }
+
+        let session = Session::stateless(
+            DEFAULT_ROOT_USER_ID,
+            SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 0),
+        );
+        for (stream_id, topic_id) in drained_topics {
+            let command = DeleteTopic {
+                stream_id: Identifier::numeric(stream_id)?,
+                topic_id: Identifier::numeric(topic_id)?,
+            };
+            if let Err(error) = self
+                .delete_topic(&session, &command.stream_id, &command.topic_id)
+                .await
+            {
+                error!("Failed to delete drained topic with ID: {topic_id} in stream with ID: {stream_id}. Error: {error}");
+                continue;
+            }
+
+            self.state
+                .apply(DEFAULT_ROOT_USER_ID, EntryCommand::DeleteTopic(command))
+                .await
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to apply delete drained topic with ID: {topic_id} in stream with ID: {stream_id}")
+                })?;
+            info!("Deleted drained topic with ID: {topic_id} in stream with ID: {stream_id}.");
+        }
+        Ok(())
     }
 
     pub async fn delete_topic(
@@ -259,8 +748,15 @@ impl System {
             stream_id_value = topic.stream_id;
         }
 
+        self.replicate(session, || {
+            EntryCommand::DeleteTopic(DeleteTopic {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+            })
+        })
+        .await?;
         let topic = self
-            .get_stream_mut(stream_id)?
+            .get_stream_mut(session, stream_id)?
             .delete_topic(topic_id)
             .await
             .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to delete topic with ID: {topic_id} in stream with ID: {stream_id}"))?;
@@ -271,6 +767,18 @@ impl System {
         self.metrics.decrement_messages(topic.get_messages_count());
         self.metrics
             .decrement_segments(topic.get_segments_count().await);
+        self.publish_metadata_change(
+            session,
+            MetadataChange::TopicDeleted {
+                stream_id: stream_id_value,
+                topic_id: topic.topic_id,
+            },
+        );
+        self.audit(
+            session,
+            AuditAction::DeleteTopic,
+            format!("streams/{stream_id_value}/topics/{}", topic.topic_id),
+        );
         let client_manager = self.client_manager.read().await;
         client_manager
             .delete_consumer_groups_for_topic(stream_id_value, topic.topic_id)
@@ -283,6 +791,8 @@ impl System {
         session: &Session,
         stream_id: &Identifier,
         topic_id: &Identifier,
+        keep_consumer_offsets: bool,
+        older_than: Option<IggyTimestamp>,
     ) -> Result<(), IggyError> {
         let topic = self
             .find_topic(session, stream_id, topic_id)
@@ -297,8 +807,41 @@ impl System {
                     session.get_user_id(),
                 )
             })?;
-        topic.purge().await.with_error_context(|error| {
-            format!("{COMPONENT} (error: {error}) - failed to purge topic with ID: {topic_id} in stream with ID: {stream_id}")
+        self.replicate(session, || {
+            EntryCommand::PurgeTopic(PurgeTopic {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                keep_consumer_offsets,
+                older_than,
+            })
         })
+        .await?;
+        topic.purge(keep_consumer_offsets, older_than).await.with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to purge topic with ID: {topic_id} in stream with ID: {stream_id}")
+        })?;
+
+        let recovered_offsets = topic.recover_consumer_offsets().await.with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to recover consumer offsets in topic with ID: {topic_id} in stream with ID: {stream_id}")
+        })?;
+        for (partition_id, recovered_offset) in recovered_offsets {
+            self.publish_metadata_change(
+                session,
+                MetadataChange::ConsumerOffsetRecovered {
+                    stream_id: topic.stream_id,
+                    topic_id: topic.topic_id,
+                    partition_id,
+                    consumer_kind: recovered_offset.kind,
+                    consumer_id: recovered_offset.consumer_id,
+                    previous_offset: recovered_offset.previous_offset,
+                    offset: recovered_offset.offset,
+                },
+            );
+        }
+        self.audit(
+            session,
+            AuditAction::PurgeTopic,
+            format!("streams/{}/topics/{}", topic.stream_id, topic.topic_id),
+        );
+        Ok(())
     }
 }
@@ -0,0 +1,454 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::partitions::partition::Partition;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use crate::streaming::systems::COMPONENT;
+use crate::streaming::topics::topic::Topic;
+use anyhow::Context;
+use error_set::ErrContext;
+use iggy::confirmation::Confirmation;
+use iggy::error::IggyError;
+use iggy::locking::{IggySharedMut, IggySharedMutFn};
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::models::transaction::Transaction;
+use iggy::models::user_info::UserId;
+use iggy::utils::timestamp::IggyTimestamp;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use tracing::info;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+enum TransactionStatus {
+    Open,
+    /// The aborted transaction is kept until all its messages are deleted,
+    /// so that they remain hidden from the read-committed polls after the restart.
+    Aborted,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TransactionPartition {
+    stream_id: u32,
+    topic_id: u32,
+    partition_id: u32,
+    /// The last offset appended within the transaction, known once it's aborted.
+    last_offset: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct TransactionState {
+    transaction_id: u64,
+    user_id: UserId,
+    started_at: u64,
+    status: TransactionStatus,
+    partitions: Vec<TransactionPartition>,
+    /// Serializes the appends, commit and abort of the transaction.
+    #[serde(skip)]
+    lock: Arc<Mutex<()>>,
+}
+
+impl System {
+    pub async fn begin_transaction(&self, session: &Session) -> Result<Transaction, IggyError> {
+        self.ensure_authenticated(session)?;
+        if self.cluster.is_some() {
+            return Err(IggyError::TransactionsUnsupportedInClusterMode);
+        }
+
+        let transaction_id = self.next_transaction_id.fetch_add(1, Ordering::SeqCst);
+        self.transactions.insert(
+            transaction_id,
+            TransactionState {
+                transaction_id,
+                user_id: session.get_user_id(),
+                started_at: IggyTimestamp::now().as_micros(),
+                status: TransactionStatus::Open,
+                partitions: Vec::new(),
+                lock: Arc::new(Mutex::new(())),
+            },
+        );
+        self.save_transactions().await.with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to save transactions after beginning transaction with ID: {transaction_id}")
+        })?;
+        info!(
+            "Began transaction with ID: {transaction_id} for user with ID: {}.",
+            session.get_user_id()
+        );
+        Ok(Transaction { transaction_id })
+    }
+
+    /// Makes the messages appended within the transaction to all the partitions visible to the read-committed polls.
+    pub async fn commit_transaction(
+        &self,
+        session: &Session,
+        transaction_id: u64,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        let lock = self.get_transaction_lock(session, transaction_id)?;
+        let _guard = lock.lock().await;
+        let partitions = self.get_open_transaction_partitions(transaction_id)?;
+        // The commit is persisted before the messages become visible, the transaction missing from
+        // the saved ones is committed, so that its messages aren't aborted after the restart.
+        let Some((_, state)) = self.transactions.remove(&transaction_id) else {
+            return Err(IggyError::TransactionNotFound(transaction_id));
+        };
+        if let Err(error) = self.save_transactions().await.with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to save transactions after committing transaction with ID: {transaction_id}")
+        }) {
+            self.transactions.insert(transaction_id, state);
+            return Err(error);
+        }
+
+        for transaction_partition in &partitions {
+            let Some(partition) = self.find_transaction_partition(transaction_partition) else {
+                continue;
+            };
+            partition.write().await.transactions.commit(transaction_id);
+        }
+
+        info!(
+            "Committed transaction with ID: {transaction_id} for {} partition(s).",
+            partitions.len()
+        );
+        Ok(())
+    }
+
+    /// Hides the messages appended within the transaction to all the partitions from the read-committed polls.
+    pub async fn abort_transaction(
+        &self,
+        session: &Session,
+        transaction_id: u64,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        let lock = self.get_transaction_lock(session, transaction_id)?;
+        let _guard = lock.lock().await;
+        self.abort_open_transaction(transaction_id).await?;
+        self.save_transactions().await.with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to save transactions after aborting transaction with ID: {transaction_id}")
+        })?;
+        info!("Aborted transaction with ID: {transaction_id}.");
+        Ok(())
+    }
+
+    /// Aborts the open transactions exceeding the configured timeout, and forgets the aborted ones
+    /// whose messages have all been deleted.
+    pub async fn abort_expired_transactions(&self) -> Result<(), IggyError> {
+        let now = IggyTimestamp::now().as_micros();
+        let timeout = self.config.transactions.timeout.as_micros();
+        let expired_transactions = self
+            .transactions
+            .iter()
+            .filter(|transaction| {
+                transaction.status == TransactionStatus::Open
+                    && transaction.started_at + timeout <= now
+            })
+            .map(|transaction| (transaction.transaction_id, transaction.lock.clone()))
+            .collect::<Vec<_>>();
+        let mut changed = false;
+        for (transaction_id, lock) in expired_transactions {
+            let _guard = lock.lock().await;
+            match self.abort_open_transaction(transaction_id).await {
+                Ok(()) => {
+                    info!("Aborted expired transaction with ID: {transaction_id}.");
+                    changed = true;
+                }
+                // The transaction has been committed or aborted in the meantime.
+                Err(IggyError::TransactionNotFound(_)) | Err(IggyError::TransactionNotOpen(_)) => {}
+                Err(error) => return Err(error),
+            }
+        }
+
+        let aborted_transactions = self
+            .transactions
+            .iter()
+            .filter(|transaction| transaction.status == TransactionStatus::Aborted)
+            .map(|transaction| (transaction.transaction_id, transaction.partitions.clone()))
+            .collect::<Vec<_>>();
+        for (transaction_id, partitions) in aborted_transactions {
+            let partitions_count = partitions.len();
+            let mut remaining_partitions = Vec::with_capacity(partitions_count);
+            for transaction_partition in partitions {
+                let Some(partition) = self.find_transaction_partition(&transaction_partition)
+                else {
+                    continue;
+                };
+                let mut partition = partition.write().await;
+                let first_offset = partition
+                    .segments
+                    .first()
+                    .map(|segment| segment.start_offset)
+                    .unwrap_or_default();
+                if transaction_partition.last_offset < first_offset {
+                    partition.transactions.prune_aborted(first_offset);
+                    continue;
+                }
+                remaining_partitions.push(transaction_partition);
+            }
+
+            if remaining_partitions.len() == partitions_count {
+                continue;
+            }
+
+            changed = true;
+            if remaining_partitions.is_empty() {
+                self.transactions.remove(&transaction_id);
+                info!("Removed aborted transaction with ID: {transaction_id}, all its messages have been deleted.");
+            } else if let Some(mut transaction) = self.transactions.get_mut(&transaction_id) {
+                transaction.partitions = remaining_partitions;
+            }
+        }
+
+        if !changed {
+            return Ok(());
+        }
+
+        self.save_transactions().await.with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to save transactions after aborting expired ones")
+        })
+    }
+
+    /// Returns the ID of the transaction within which the messages are sent, all of them must share the same one.
+    pub(crate) fn get_messages_transaction_id(
+        messages: &[Message],
+    ) -> Result<Option<u64>, IggyError> {
+        let mut transaction_id = None;
+        for (index, message) in messages.iter().enumerate() {
+            let message_transaction_id = Transaction::from_headers(&message.headers)?
+                .map(|transaction| transaction.transaction_id);
+            if index == 0 {
+                transaction_id = message_transaction_id;
+            } else if message_transaction_id != transaction_id {
+                return Err(IggyError::InvalidTransactionHeader);
+            }
+        }
+        Ok(transaction_id)
+    }
+
+    /// Appends the messages to the single partition of the topic, which is recorded as a part of the transaction.
+    pub(crate) async fn append_messages_within_transaction(
+        &self,
+        session: &Session,
+        topic: &Topic,
+        transaction_id: u64,
+        partitioning: Partitioning,
+        messages: Vec<Message>,
+        confirmation: Option<Confirmation>,
+    ) -> Result<(), IggyError> {
+        if self.cluster.is_some() {
+            return Err(IggyError::TransactionsUnsupportedInClusterMode);
+        }
+
+        let lock = self.get_transaction_lock(session, transaction_id)?;
+        let _guard = lock.lock().await;
+        self.get_open_transaction_partitions(transaction_id)?;
+        let partition_id = topic.resolve_partition_id(&partitioning)?;
+        if !topic.partitions.contains_key(&partition_id) {
+            return Err(IggyError::PartitionNotFound(
+                partition_id,
+                topic.topic_id,
+                topic.stream_id,
+            ));
+        }
+
+        let added = {
+            let Some(mut transaction) = self.transactions.get_mut(&transaction_id) else {
+                return Err(IggyError::TransactionNotFound(transaction_id));
+            };
+            let exists = transaction.partitions.iter().any(|partition| {
+                partition.stream_id == topic.stream_id
+                    && partition.topic_id == topic.topic_id
+                    && partition.partition_id == partition_id
+            });
+            if !exists {
+                transaction.partitions.push(TransactionPartition {
+                    stream_id: topic.stream_id,
+                    topic_id: topic.topic_id,
+                    partition_id,
+                    last_offset: 0,
+                });
+            }
+            !exists
+        };
+        if added {
+            self.save_transactions().await.with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to save transactions after adding partition with ID: {partition_id} to transaction with ID: {transaction_id}")
+            })?;
+        }
+
+        self.append_messages_to_topic(
+            topic,
+            Partitioning::partition_id(partition_id),
+            messages,
+            confirmation,
+        )
+        .await
+    }
+
+    fn get_transaction_lock(
+        &self,
+        session: &Session,
+        transaction_id: u64,
+    ) -> Result<Arc<Mutex<()>>, IggyError> {
+        let transaction = self
+            .transactions
+            .get(&transaction_id)
+            .ok_or(IggyError::TransactionNotFound(transaction_id))?;
+        if transaction.user_id != session.get_user_id() {
+            return Err(IggyError::Unauthorized);
+        }
+
+        Ok(transaction.lock.clone())
+    }
+
+    fn get_open_transaction_partitions(
+        &self,
+        transaction_id: u64,
+    ) -> Result<Vec<TransactionPartition>, IggyError> {
+        let transaction = self
+            .transactions
+            .get(&transaction_id)
+            .ok_or(IggyError::TransactionNotFound(transaction_id))?;
+        if transaction.status != TransactionStatus::Open {
+            return Err(IggyError::TransactionNotOpen(transaction_id));
+        }
+
+        Ok(transaction.partitions.clone())
+    }
+
+    /// Aborts the transaction in all its partitions, the lock of the transaction must be held by the caller.
+    async fn abort_open_transaction(&self, transaction_id: u64) -> Result<(), IggyError> {
+        let partitions = self.get_open_transaction_partitions(transaction_id)?;
+        let mut aborted_partitions = Vec::with_capacity(partitions.len());
+        for mut transaction_partition in partitions {
+            let Some(partition) = self.find_transaction_partition(&transaction_partition) else {
+                continue;
+            };
+            let Some(last_offset) = partition.write().await.transactions.abort(transaction_id)
+            else {
+                continue;
+            };
+            transaction_partition.last_offset = last_offset;
+            aborted_partitions.push(transaction_partition);
+        }
+
+        if aborted_partitions.is_empty() {
+            self.transactions.remove(&transaction_id);
+        } else if let Some(mut transaction) = self.transactions.get_mut(&transaction_id) {
+            transaction.status = TransactionStatus::Aborted;
+            transaction.partitions = aborted_partitions;
+        }
+        Ok(())
+    }
+
+    fn find_transaction_partition(
+        &self,
+        transaction_partition: &TransactionPartition,
+    ) -> Option<IggySharedMut<Partition>> {
+        self.streams
+            .get(&transaction_partition.stream_id)?
+            .topics
+            .get(&transaction_partition.topic_id)?
+            .partitions
+            .get(&transaction_partition.partition_id)
+            .cloned()
+    }
+
+    async fn save_transactions(&self) -> Result<(), IggyError> {
+        let _guard = self.transactions_save_lock.lock().await;
+        let mut states = self
+            .transactions
+            .iter()
+            .map(|entry| entry.value().clone())
+            .collect::<Vec<_>>();
+        states.sort_by_key(|state| state.transaction_id);
+        let data = bincode::serde::encode_to_vec(&states, bincode::config::standard())
+            .with_context(|| "Failed to serialize transactions")
+            .map_err(|_| IggyError::CannotSerializeResource)?;
+        let path = self.config.get_state_transactions_path();
+        self.storage
+            .persister
+            .overwrite(&path, &data)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to overwrite file at path: {path}")
+            })
+    }
+
+    /// Loads the transactions, the ones left open before the restart are aborted.
+    pub(crate) async fn load_transactions(&mut self) -> Result<(), IggyError> {
+        let path = self.config.get_state_transactions_path();
+        if !Path::new(&path).exists() {
+            return Ok(());
+        }
+
+        let data = tokio::fs::read(&path)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to read file at path: {path}")
+            })
+            .map_err(|_| IggyError::CannotReadFile)?;
+        let (states, _): (Vec<TransactionState>, _) =
+            bincode::serde::decode_from_slice(&data, bincode::config::standard())
+                .with_context(|| "Failed to deserialize transactions")
+                .map_err(|_| IggyError::CannotDeserializeResource)?;
+        let mut aborted_open_transactions = 0;
+        for mut state in states {
+            self.next_transaction_id
+                .fetch_max(state.transaction_id + 1, Ordering::SeqCst);
+            let mut partitions = Vec::with_capacity(state.partitions.len());
+            for mut transaction_partition in state.partitions {
+                let Some(partition) = self.find_transaction_partition(&transaction_partition)
+                else {
+                    continue;
+                };
+                let mut partition = partition.write().await;
+                // The offsets appended within the open transaction are not persisted, but the messages
+                // of the other transactions are never hidden, as they're matched by the transaction ID.
+                if state.status == TransactionStatus::Open {
+                    transaction_partition.last_offset = partition.current_offset;
+                }
+                partition
+                    .transactions
+                    .mark_aborted(state.transaction_id, transaction_partition.last_offset);
+                partitions.push(transaction_partition);
+            }
+
+            if state.status == TransactionStatus::Open {
+                aborted_open_transactions += 1;
+            }
+            if partitions.is_empty() {
+                continue;
+            }
+
+            state.status = TransactionStatus::Aborted;
+            state.partitions = partitions;
+            self.transactions.insert(state.transaction_id, state);
+        }
+
+        info!(
+            "Loaded {} aborted transaction(s), aborted {aborted_open_transactions} transaction(s) left open.",
+            self.transactions.len()
+        );
+        self.save_transactions().await.with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to save transactions after loading them")
+        })
+    }
+}
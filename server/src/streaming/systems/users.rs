@@ -16,6 +16,7 @@
  * under the License.
  */
 
+use crate::authenticator::{AuthenticatorKind, Credentials};
 use crate::state::command::EntryCommand;
 use crate::state::models::CreateUserWithId;
 use crate::state::system::UserState;
@@ -25,15 +26,21 @@ use crate::streaming::systems::system::System;
 use crate::streaming::systems::COMPONENT;
 use crate::streaming::users::user::User;
 use crate::streaming::utils::crypto;
-use crate::{IGGY_ROOT_PASSWORD_ENV, IGGY_ROOT_USERNAME_ENV};
+use crate::{AUDIT_LOG_TARGET, IGGY_ROOT_PASSWORD_ENV, IGGY_ROOT_USERNAME_ENV};
 use error_set::ErrContext;
 use iggy::error::IggyError;
 use iggy::identifier::{IdKind, Identifier};
 use iggy::locking::IggySharedMutFn;
+use iggy::models::audit_entry::AuditAction;
 use iggy::models::permissions::Permissions;
 use iggy::models::user_status::UserStatus;
+use iggy::users::change_password::ChangePassword;
 use iggy::users::create_user::CreateUser;
 use iggy::users::defaults::*;
+use iggy::users::delete_user::DeleteUser;
+use iggy::users::update_permissions::UpdatePermissions;
+use iggy::users::update_user::UpdateUser;
+use iggy::users::user_quotas::UserQuotas;
 use std::env;
 use std::sync::atomic::{AtomicU32, Ordering};
 use tracing::{error, info, warn};
@@ -47,11 +54,18 @@ impl System {
         if users.is_empty() {
             info!("No users found, creating the root user...");
             let root = Self::create_root_user();
+            if self.is_read_only() {
+                self.users.insert(root.id, root);
+                info!("Created the root user in memory only (read-only mode).");
+                return Ok(());
+            }
+
             let command = CreateUser {
                 username: root.username.clone(),
                 password: root.password.clone(),
                 status: root.status,
                 permissions: root.permissions.clone(),
+                namespace_id: None,
             };
             self.state
                 .apply(0, EntryCommand::CreateUser(CreateUserWithId {
@@ -90,10 +104,17 @@ impl System {
                             &token.name,
                             &token.token_hash,
                             token.expiry_at,
+                            token.scope,
+                            token.issued_at,
                         ),
                     )
                 })
                 .collect();
+            user.quotas = user_state.quotas;
+            user.namespace_id = user_state.namespace_id;
+            if !user.quotas.is_empty() {
+                self.apply_user_quotas(user.id, user.quotas);
+            }
             self.users.insert(user_state.id, user);
         }
 
@@ -157,6 +178,8 @@ impl System {
                     "{COMPONENT} (error: {error}) - permission denied to get user with ID: {user_id} for current user with ID: {session_user_id}"
                 )
             })?;
+            self.permissioner
+                .ensure_same_namespace(session_user_id, user.id)?;
         }
 
         Ok(Some(user))
@@ -208,7 +231,12 @@ impl System {
                     session.get_user_id()
                 )
             })?;
-        Ok(self.users.values().collect())
+        let namespace_id = self.permissioner.get_user_namespace(session.get_user_id());
+        Ok(self
+            .users
+            .values()
+            .filter(|user| namespace_id.is_none() || user.namespace_id == namespace_id)
+            .collect())
     }
 
     pub async fn create_user(
@@ -218,6 +246,7 @@ impl System {
         password: &str,
         status: UserStatus,
         permissions: Option<Permissions>,
+        namespace_id: Option<u32>,
     ) -> Result<&User, IggyError> {
         self.ensure_authenticated(session)?;
         self.permissioner
@@ -239,20 +268,69 @@ impl System {
             return Err(IggyError::UsersLimitReached);
         }
 
-        let user_id = USER_ID.fetch_add(1, Ordering::SeqCst);
+        let namespace_id = self.resolve_namespace(session, namespace_id)?;
+        if let Some(namespace_id) = namespace_id {
+            self.ensure_namespace_users_limit(namespace_id)?;
+        }
+
+        let user_id = loop {
+            let user_id = USER_ID.fetch_add(1, Ordering::SeqCst);
+            if !self.users.contains_key(&user_id) {
+                break user_id;
+            }
+        };
+        // For the security of the system, only the hash of the password is replicated.
+        let password = crypto::hash_password(password);
+        self.replicate(session, || {
+            EntryCommand::CreateUser(CreateUserWithId {
+                user_id,
+                command: CreateUser {
+                    username: username.to_owned(),
+                    password: password.clone(),
+                    status,
+                    permissions: permissions.clone(),
+                    namespace_id,
+                },
+            })
+        })
+        .await?;
         info!("Creating user: {username} with ID: {user_id}...");
-        let user = User::new(user_id, username, password, status, permissions.clone());
+        let mut user =
+            User::with_password(user_id, username, password, status, permissions.clone());
+        user.namespace_id = namespace_id;
         self.permissioner
             .init_permissions_for_user(user_id, permissions);
+        self.permissioner.set_user_namespace(user_id, namespace_id);
         self.users.insert(user.id, user);
         info!("Created user: {username} with ID: {user_id}.");
         self.metrics.increment_users(1);
+        self.audit(session, AuditAction::CreateUser, format!("users/{user_id}"));
         self.get_user(&user_id.try_into()?)
             .with_error_context(|error| {
                 format!("{COMPONENT} (error: {error}) - failed to get user with id: {user_id}")
             })
     }
 
+    /// Adds the user created on another node of the cluster, with the already hashed password.
+    pub(crate) fn add_replicated_user(&mut self, user: User) -> Result<(), IggyError> {
+        if self.users.contains_key(&user.id) {
+            return Err(IggyError::UserAlreadyExists);
+        }
+
+        USER_ID.fetch_max(user.id + 1, Ordering::SeqCst);
+        self.permissioner
+            .init_permissions_for_user(user.id, user.permissions.clone());
+        self.permissioner
+            .set_user_namespace(user.id, user.namespace_id);
+        info!(
+            "Added replicated user: {} with ID: {}.",
+            user.username, user.id
+        );
+        self.users.insert(user.id, user);
+        self.metrics.increment_users(1);
+        Ok(())
+    }
+
     pub async fn delete_user(
         &mut self,
         session: &Session,
@@ -278,10 +356,20 @@ impl System {
                 return Err(IggyError::CannotDeleteUser(user.id));
             }
 
+            self.permissioner
+                .ensure_same_namespace(session.get_user_id(), user.id)?;
+
             existing_user_id = user.id;
             existing_username = user.username.clone();
         }
 
+        self.replicate(session, || {
+            EntryCommand::DeleteUser(DeleteUser {
+                user_id: user_id.clone(),
+            })
+        })
+        .await?;
+
         info!("Deleting user: {existing_username} with ID: {user_id}...");
         let user = self
             .users
@@ -289,6 +377,8 @@ impl System {
             .ok_or(IggyError::ResourceNotFound(user_id.to_string()))?;
         self.permissioner
             .delete_permissions_for_user(existing_user_id);
+        self.permissioner.set_user_namespace(existing_user_id, None);
+        self.apply_user_quotas(existing_user_id, UserQuotas::default());
         let mut client_manager = self.client_manager.write().await;
         client_manager
             .delete_clients_for_user(existing_user_id)
@@ -300,6 +390,11 @@ impl System {
             })?;
         info!("Deleted user: {existing_username} with ID: {user_id}.");
         self.metrics.decrement_users(1);
+        self.audit(
+            session,
+            AuditAction::DeleteUser,
+            format!("users/{existing_user_id}"),
+        );
         Ok(user)
     }
 
@@ -319,6 +414,9 @@ impl System {
                     session.get_user_id()
                 )
             })?;
+        let existing_user_id = self.get_user(user_id)?.id;
+        self.permissioner
+            .ensure_same_namespace(session.get_user_id(), existing_user_id)?;
 
         if let Some(username) = username.to_owned() {
             let user = self.get_user(user_id)?;
@@ -329,6 +427,14 @@ impl System {
             }
         }
 
+        self.replicate(session, || {
+            EntryCommand::UpdateUser(UpdateUser {
+                user_id: user_id.clone(),
+                username: username.clone(),
+                status,
+            })
+        })
+        .await?;
         let user = self.get_user_mut(user_id).with_error_context(|error| {
             format!("{COMPONENT} (error: {error}) - failed to get mutable reference to the user with id: {user_id}")
         })?;
@@ -341,7 +447,13 @@ impl System {
         }
 
         info!("Updated user: {} with ID: {}.", user.username, user.id);
-        Ok(user)
+        let updated_user_id = user.id;
+        self.audit(
+            session,
+            AuditAction::UpdateUser,
+            format!("users/{updated_user_id}"),
+        );
+        self.get_user(&updated_user_id.try_into()?)
     }
 
     pub async fn update_permissions(
@@ -369,10 +481,21 @@ impl System {
             }
 
             self.permissioner
-                .update_permissions_for_user(user.id, permissions.clone());
+                .ensure_same_namespace(session.get_user_id(), user.id)?;
         }
 
-        {
+        self.replicate(session, || {
+            EntryCommand::UpdatePermissions(UpdatePermissions {
+                user_id: user_id.clone(),
+                permissions: permissions.clone(),
+            })
+        })
+        .await?;
+        let existing_user_id = self.get_user(user_id)?.id;
+        self.permissioner
+            .update_permissions_for_user(existing_user_id, permissions.clone());
+
+        let updated_user_id = {
             let user = self.get_user_mut(user_id).with_error_context(|error| {
                 format!(
                     "{COMPONENT} (error: {error}) - failed to get mutable reference to the user with id: {user_id}"
@@ -383,8 +506,14 @@ impl System {
                 "Updated permissions for user: {} with ID: {user_id}.",
                 user.username
             );
-        }
+            user.id
+        };
 
+        self.audit(
+            session,
+            AuditAction::UpdatePermissions,
+            format!("users/{updated_user_id}"),
+        );
         Ok(())
     }
 
@@ -404,25 +533,48 @@ impl System {
             let session_user_id = session.get_user_id();
             if user.id != session_user_id {
                 self.permissioner.change_password(session_user_id)?;
+                self.permissioner
+                    .ensure_same_namespace(session_user_id, user.id)?;
             }
         }
 
+        {
+            let user = self.get_user(user_id).with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to get user with id: {user_id}")
+            })?;
+            if !crypto::verify_password(current_password, &user.password) {
+                error!(
+                    "Invalid current password for user: {} with ID: {user_id}.",
+                    user.username
+                );
+                return Err(IggyError::InvalidCredentials);
+            }
+        }
+
+        // For the security of the system, only the hash of the password is replicated.
+        let password = crypto::hash_password(new_password);
+        self.replicate(session, || {
+            EntryCommand::ChangePassword(ChangePassword {
+                user_id: user_id.clone(),
+                current_password: "".into(),
+                new_password: password.clone(),
+            })
+        })
+        .await?;
         let user = self.get_user_mut(user_id).with_error_context(|error| {
             format!("{COMPONENT} (error: {error}) - failed to get mutable reference to the user with id: {user_id}")
         })?;
-        if !crypto::verify_password(current_password, &user.password) {
-            error!(
-                "Invalid current password for user: {} with ID: {user_id}.",
-                user.username
-            );
-            return Err(IggyError::InvalidCredentials);
-        }
-
-        user.password = crypto::hash_password(new_password);
+        user.password = password;
         info!(
             "Changed password for user: {} with ID: {user_id}.",
             user.username
         );
+        let changed_user_id = user.id;
+        self.audit(
+            session,
+            AuditAction::ChangePassword,
+            format!("users/{changed_user_id}"),
+        );
         Ok(())
     }
 
@@ -442,30 +594,52 @@ impl System {
         password: Option<&str>,
         session: Option<&Session>,
     ) -> Result<&User, IggyError> {
-        let user = match self.get_user(&username.try_into()?) {
-            Ok(user) => user,
-            Err(_) => {
-                error!("Cannot login user: {username} (not found).");
-                return Err(IggyError::InvalidCredentials);
+        let result = self
+            .try_login_user_with_credentials(username, password, session)
+            .await;
+        self.audit_login(
+            session,
+            result.as_ref().map(|user| user.id).unwrap_or_default(),
+            AuditAction::LoginUser,
+            username.to_owned(),
+            result.is_ok(),
+        );
+        result
+    }
+
+    /// Logs in the user without recording the attempt in the audit log, so that the caller can record it
+    /// as the specific kind of the login, e.g. with the personal access token.
+    pub(crate) async fn try_login_user_with_credentials(
+        &self,
+        username: &str,
+        password: Option<&str>,
+        session: Option<&Session>,
+    ) -> Result<&User, IggyError> {
+        let user = match password {
+            Some(password) => {
+                let credentials = Credentials {
+                    username,
+                    password,
+                    peer_certificate: session.and_then(|session| session.peer_certificate()),
+                };
+                self.authenticate(&credentials).await?
             }
+            None => match self.get_user(&username.try_into()?) {
+                Ok(user) => user,
+                Err(_) => {
+                    error!("Cannot login user: {username} (not found).");
+                    return Err(IggyError::InvalidCredentials);
+                }
+            },
         };
 
+        let username = &user.username;
         info!("Logging in user: {username} with ID: {}...", user.id);
         if !user.is_active() {
             warn!("User: {username} with ID: {} is inactive.", user.id);
             return Err(IggyError::UserInactive);
         }
 
-        if let Some(password) = password {
-            if !crypto::verify_password(password, &user.password) {
-                warn!(
-                    "Invalid password for user: {username} with ID: {}.",
-                    user.id
-                );
-                return Err(IggyError::InvalidCredentials);
-            }
-        }
-
         info!("Logged in user: {username} with ID: {}.", user.id);
         if session.is_none() {
             return Ok(user);
@@ -481,6 +655,8 @@ impl System {
             self.logout_user(session).await?;
         }
 
+        session.set_impersonator_id(0);
+        session.set_personal_access_token_scope(None);
         session.set_user_id(user.id);
         let mut client_manager = self.client_manager.write().await;
         client_manager
@@ -495,6 +671,163 @@ impl System {
         Ok(user)
     }
 
+    /// Logs in the session with the user mapped by the `mtls` authenticator to the client certificate
+    /// presented during the TLS handshake, so that the client doesn't need to send the credentials.
+    /// Returns `None` if there is no certificate or it's not mapped to any user.
+    pub async fn login_user_with_certificate(
+        &self,
+        session: &Session,
+    ) -> Result<Option<&User>, IggyError> {
+        let Some(peer_certificate) = session.peer_certificate() else {
+            return Ok(None);
+        };
+
+        let credentials = Credentials {
+            username: "",
+            password: "",
+            peer_certificate: Some(peer_certificate),
+        };
+        for authenticator in &self.authenticators {
+            if !matches!(authenticator, AuthenticatorKind::Mtls(_)) {
+                continue;
+            }
+
+            match authenticator.authenticate(&credentials).await {
+                Ok(Some(username)) => {
+                    info!(
+                        "Client certificate of session: {session} is mapped to user: {username}."
+                    );
+                    return self
+                        .login_user_with_credentials(&username, None, Some(session))
+                        .await
+                        .map(Some);
+                }
+                Ok(None) => continue,
+                Err(error) => {
+                    error!("{COMPONENT} (error: {error}) - mtls authenticator failed to verify the client certificate of session: {session}");
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    /// Impersonates the user for the rest of the session, the session already impersonating
+    /// another user switches to the new one, still on behalf of the original user.
+    pub async fn login_as(
+        &self,
+        session: &Session,
+        user_id: &Identifier,
+    ) -> Result<&User, IggyError> {
+        self.ensure_authenticated(session)?;
+        if !self.config.authentication.allow_impersonation {
+            warn!("Impersonation is disabled, session: {session}.");
+            return Err(IggyError::FeatureUnavailable);
+        }
+
+        let impersonator_id = session
+            .get_impersonator_id()
+            .unwrap_or_else(|| session.get_user_id());
+        let user = self.get_user(user_id).with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to get user with id: {user_id}")
+        })?;
+        if user.is_root() {
+            error!("Cannot impersonate the root user.");
+            return Err(IggyError::Unauthorized);
+        }
+
+        self.permissioner
+            .ensure_same_namespace(impersonator_id, user.id)?;
+
+        self.permissioner
+            .impersonate_user(impersonator_id, user.id)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to impersonate user with id: {} for user with id: {impersonator_id}",
+                    user.id
+                )
+            })?;
+        if !user.is_active() {
+            warn!("User: {} with ID: {} is inactive.", user.username, user.id);
+            return Err(IggyError::UserInactive);
+        }
+
+        session.set_impersonator_id(impersonator_id);
+        session.set_user_id(user.id);
+        if session.client_id > 0 {
+            let mut client_manager = self.client_manager.write().await;
+            client_manager
+                .set_user_id(session.client_id, user.id)
+                .await
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to set user_id to client, client ID: {}, user ID: {}",
+                        session.client_id, user.id
+                    )
+                })?;
+        }
+        info!(
+            target: AUDIT_LOG_TARGET,
+            "User with ID: {impersonator_id} started impersonating user: {} with ID: {}, client ID: {}, IP address: {}.",
+            user.username,
+            user.id,
+            session.client_id,
+            session.ip_address
+        );
+        Ok(user)
+    }
+
+    /// Verifies the credentials with the configured authenticators in order and returns the user
+    /// mapped to the identity verified by the first one of them, the others are not asked anymore.
+    async fn authenticate(&self, credentials: &Credentials<'_>) -> Result<&User, IggyError> {
+        let username = credentials.username;
+        for authenticator in &self.authenticators {
+            let mapped_username = match authenticator {
+                AuthenticatorKind::Internal => match self.get_user(&username.try_into()?) {
+                    Ok(user) if crypto::verify_password(credentials.password, &user.password) => {
+                        return Ok(user);
+                    }
+                    Ok(user) => {
+                        warn!(
+                            "Invalid password for user: {username} with ID: {}.",
+                            user.id
+                        );
+                        continue;
+                    }
+                    Err(_) => {
+                        warn!("Cannot authenticate user: {username} (not found).");
+                        continue;
+                    }
+                },
+                authenticator => match authenticator.authenticate(credentials).await {
+                    Ok(Some(mapped_username)) => mapped_username,
+                    Ok(None) => continue,
+                    Err(error) => {
+                        error!(
+                            "{COMPONENT} (error: {error}) - {} authenticator failed to verify the credentials of user: {username}",
+                            authenticator.kind()
+                        );
+                        continue;
+                    }
+                },
+            };
+
+            info!(
+                "User: {username} has been authenticated by {} authenticator as user: {mapped_username}.",
+                authenticator.kind()
+            );
+            return match self.get_user(&mapped_username.as_str().try_into()?) {
+                Ok(user) => Ok(user),
+                Err(_) => {
+                    error!("Cannot login user: {mapped_username} (not found).");
+                    Err(IggyError::InvalidCredentials)
+                }
+            };
+        }
+
+        warn!("Cannot authenticate user: {username} with any of the authenticators.");
+        Err(IggyError::InvalidCredentials)
+    }
+
     pub async fn logout_user(&self, session: &Session) -> Result<(), IggyError> {
         self.ensure_authenticated(session)?;
         let user = self
@@ -517,7 +850,17 @@ impl System {
                 user.id, session.client_id
             );
         }
+        if let Some(impersonator_id) = session.get_impersonator_id() {
+            info!(
+                target: AUDIT_LOG_TARGET,
+                "User with ID: {impersonator_id} stopped impersonating user: {} with ID: {}, client ID: {}.",
+                user.username,
+                user.id,
+                session.client_id
+            );
+        }
         info!("Logged out user: {} with ID: {}.", user.username, user.id);
+        self.audit(session, AuditAction::LogoutUser, user.username.clone());
         Ok(())
     }
 }
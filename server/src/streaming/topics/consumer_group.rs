@@ -16,10 +16,20 @@
  * under the License.
  */
 
+use crate::streaming::topics::consumer_group_assignment::get_assignor;
+use crate::streaming::topics::dead_letter::DeliveryTracker;
 use ahash::AHashMap;
+use iggy::consumer_groups::offset_recovery_policy::OffsetRecoveryPolicy;
 use iggy::error::IggyError;
+use iggy::models::consumer_group::{
+    ConsumerGroupDeadLetter, ConsumerGroupRebalance, PartitionAssignmentStrategy, RebalanceTrigger,
+};
+use iggy::utils::duration::IggyDuration;
+use iggy::utils::timestamp::IggyTimestamp;
+use std::collections::VecDeque;
+use std::time::Instant;
 use tokio::sync::RwLock;
-use tracing::trace;
+use tracing::{info, trace};
 
 #[derive(Debug)]
 pub struct ConsumerGroup {
@@ -27,7 +37,16 @@ pub struct ConsumerGroup {
     pub group_id: u32,
     pub name: String,
     pub partitions_count: u32,
+    pub assignment_strategy: PartitionAssignmentStrategy,
+    pub offset_recovery: OffsetRecoveryPolicy,
+    pub dead_letter: Option<ConsumerGroupDeadLetter>,
+    /// The time after which the unacknowledged messages are redelivered, `None` if the offsets are committed on poll.
+    pub visibility_timeout: Option<IggyDuration>,
+    pub deliveries: DeliveryTracker,
     members: AHashMap<u32, RwLock<ConsumerGroupMember>>,
+    rebalances: VecDeque<ConsumerGroupRebalance>,
+    max_rebalances: usize,
+    generation: u32,
 }
 
 #[derive(Debug)]
@@ -39,13 +58,28 @@ pub struct ConsumerGroupMember {
 }
 
 impl ConsumerGroup {
-    pub fn new(topic_id: u32, group_id: u32, name: &str, partitions_count: u32) -> ConsumerGroup {
+    pub fn new(
+        topic_id: u32,
+        group_id: u32,
+        name: &str,
+        partitions_count: u32,
+        max_rebalances: u32,
+        assignment_strategy: PartitionAssignmentStrategy,
+    ) -> ConsumerGroup {
         ConsumerGroup {
             topic_id,
             group_id,
             name: name.to_string(),
             partitions_count,
+            assignment_strategy,
+            offset_recovery: OffsetRecoveryPolicy::Disabled,
+            dead_letter: None,
+            visibility_timeout: None,
+            deliveries: DeliveryTracker::default(),
             members: AHashMap::new(),
+            rebalances: VecDeque::new(),
+            max_rebalances: max_rebalances as usize,
+            generation: 0,
         }
     }
 
@@ -53,9 +87,22 @@ impl ConsumerGroup {
         self.members.values().collect()
     }
 
+    /// Returns the most recent rebalances, from the oldest to the newest.
+    pub fn get_rebalances(&self) -> Vec<&ConsumerGroupRebalance> {
+        self.rebalances.iter().collect()
+    }
+
+    /// Returns the number incremented on each rebalance, the partitions assigned to the members
+    /// in the previous generations are no longer valid.
+    pub fn get_generation(&self) -> u32 {
+        self.generation
+    }
+
     pub async fn reassign_partitions(&mut self, partitions_count: u32) {
+        let before = self.get_assignments().await;
         self.partitions_count = partitions_count;
-        self.assign_partitions().await;
+        self.rebalance(RebalanceTrigger::PartitionsChanged, before)
+            .await;
     }
 
     pub async fn calculate_partition_id(&self, member_id: u32) -> Result<Option<u32>, IggyError> {
@@ -82,7 +129,29 @@ impl ConsumerGroup {
         ))
     }
 
+    pub async fn is_partition_assigned(
+        &self,
+        member_id: u32,
+        partition_id: u32,
+    ) -> Result<bool, IggyError> {
+        let member = self.members.get(&member_id);
+        if let Some(member) = member {
+            return Ok(member
+                .read()
+                .await
+                .partitions
+                .values()
+                .any(|id| *id == partition_id));
+        }
+        Err(IggyError::ConsumerGroupMemberNotFound(
+            member_id,
+            self.group_id,
+            self.topic_id,
+        ))
+    }
+
     pub async fn add_member(&mut self, member_id: u32) {
+        let before = self.get_assignments().await;
         self.members.insert(
             member_id,
             RwLock::new(ConsumerGroupMember {
@@ -98,10 +167,11 @@ impl ConsumerGroup {
             self.group_id,
             self.topic_id
         );
-        self.assign_partitions().await;
+        self.rebalance(RebalanceTrigger::MemberJoined, before).await;
     }
 
     pub async fn delete_member(&mut self, member_id: u32) {
+        let before = self.get_assignments().await;
         if self.members.remove(&member_id).is_some() {
             trace!(
                 "Deleted member with ID: {} in consumer group: {} for topic with ID: {}",
@@ -109,39 +179,90 @@ impl ConsumerGroup {
                 self.group_id,
                 self.topic_id
             );
-            self.assign_partitions().await;
+            self.rebalance(RebalanceTrigger::MemberLeft, before).await;
         }
     }
 
-    async fn assign_partitions(&mut self) {
-        let mut members = self.members.values_mut().collect::<Vec<_>>();
-        if members.is_empty() {
+    /// Reassigns the partitions and records the rebalance, comparing the members and assignments with the ones
+    /// taken before the membership or the partitions changed.
+    async fn rebalance(
+        &mut self,
+        trigger: RebalanceTrigger,
+        (members_before, assignments_before): (Vec<u32>, AHashMap<u32, u32>),
+    ) {
+        let started_at = Instant::now();
+        self.assign_partitions(&assignments_before).await;
+        self.generation = self.generation.wrapping_add(1);
+        if self.max_rebalances == 0 {
             return;
         }
 
-        let members_count = members.len() as u32;
-        for member in members.iter_mut() {
-            let mut member = member.write().await;
-            member.current_partition_index = None;
-            member.current_partition_id = None;
-            member.partitions.clear();
+        let duration = IggyDuration::new(started_at.elapsed());
+        let (members_after, assignments_after) = self.get_assignments().await;
+        let partitions_moved = assignments_before
+            .iter()
+            .filter(|(partition_id, member_id)| {
+                assignments_after.get(*partition_id) != Some(*member_id)
+            })
+            .count()
+            + assignments_after
+                .keys()
+                .filter(|partition_id| !assignments_before.contains_key(*partition_id))
+                .count();
+        info!(
+            "Rebalanced consumer group: {} for topic with ID: {}, generation: {}, strategy: {}, trigger: {trigger}, members: {} -> {}, partitions moved: {partitions_moved}.",
+            self.group_id,
+            self.topic_id,
+            self.generation,
+            self.assignment_strategy,
+            members_before.len(),
+            members_after.len()
+        );
+        if self.rebalances.len() >= self.max_rebalances {
+            self.rebalances.pop_front();
         }
+        self.rebalances.push_back(ConsumerGroupRebalance {
+            timestamp: IggyTimestamp::now(),
+            trigger,
+            members_before,
+            members_after,
+            partitions_moved: partitions_moved as u32,
+            duration,
+        });
+    }
 
-        for partition_index in 0..self.partitions_count {
-            let partition_id = partition_index + 1;
-            let member_index = partition_index % members_count;
-            let member = members.get(member_index as usize).unwrap();
-            let mut member = member.write().await;
-            let member_partition_index = member.partitions.len() as u32;
-            member
-                .partitions
-                .insert(member_partition_index, partition_id);
-            if member.current_partition_id.is_none() {
-                member.current_partition_id = Some(partition_id);
-                member.current_partition_index = Some(member_partition_index);
+    async fn get_assignments(&self) -> (Vec<u32>, AHashMap<u32, u32>) {
+        let mut members = Vec::with_capacity(self.members.len());
+        let mut assignments = AHashMap::new();
+        for member in self.members.values() {
+            let member = member.read().await;
+            members.push(member.id);
+            for partition_id in member.partitions.values() {
+                assignments.insert(*partition_id, member.id);
             }
-            trace!("Assigned partition ID: {} to member with ID: {} for topic with ID: {} in consumer group: {}",
-                partition_id, member.id, self.topic_id, self.group_id)
+        }
+        members.sort_unstable();
+        (members, assignments)
+    }
+
+    async fn assign_partitions(&self, previous_assignments: &AHashMap<u32, u32>) {
+        if self.members.is_empty() {
+            return;
+        }
+
+        let mut member_ids = self.members.keys().copied().collect::<Vec<_>>();
+        member_ids.sort_unstable();
+        let mut assignments = get_assignor(self.assignment_strategy).assign(
+            &member_ids,
+            self.partitions_count,
+            previous_assignments,
+        );
+        for member in self.members.values() {
+            let mut member = member.write().await;
+            let partitions = assignments.remove(&member.id).unwrap_or_default();
+            trace!("Assigned partition IDs: {:?} to member with ID: {} for topic with ID: {} in consumer group: {}",
+                partitions, member.id, self.topic_id, self.group_id);
+            member.assign_partitions(&partitions);
         }
     }
 }
@@ -151,6 +272,27 @@ impl ConsumerGroupMember {
         self.partitions.values().copied().collect()
     }
 
+    /// Replaces the assigned partitions, continuing from the current partition if it's still assigned.
+    fn assign_partitions(&mut self, partitions: &[u32]) {
+        self.partitions = partitions
+            .iter()
+            .enumerate()
+            .map(|(index, partition_id)| (index as u32, *partition_id))
+            .collect();
+        if partitions.is_empty() {
+            self.current_partition_index = None;
+            self.current_partition_id = None;
+            return;
+        }
+
+        let partition_index = self
+            .current_partition_id
+            .and_then(|partition_id| partitions.iter().position(|id| *id == partition_id))
+            .unwrap_or(0);
+        self.current_partition_index = Some(partition_index as u32);
+        self.current_partition_id = Some(partitions[partition_index]);
+    }
+
     pub fn calculate_partition_id(&mut self) -> Option<u32> {
         let partition_index = self.current_partition_index?;
         let Some(partition_id) = self.partitions.get(&partition_index) else {
@@ -185,13 +327,8 @@ mod tests {
     #[tokio::test]
     async fn should_calculate_partition_id_using_round_robin() {
         let member_id = 123;
-        let mut consumer_group = ConsumerGroup {
-            topic_id: 1,
-            group_id: 1,
-            name: "test".to_string(),
-            partitions_count: 3,
-            members: AHashMap::new(),
-        };
+        let mut consumer_group =
+            ConsumerGroup::new(1, 1, "test", 3, 10, PartitionAssignmentStrategy::RoundRobin);
 
         consumer_group.add_member(member_id).await;
         for i in 0..1000 {
@@ -207,13 +344,8 @@ mod tests {
     #[tokio::test]
     async fn should_assign_all_partitions_to_the_only_single_member() {
         let member_id = 123;
-        let mut consumer_group = ConsumerGroup {
-            topic_id: 1,
-            group_id: 1,
-            name: "test".to_string(),
-            partitions_count: 3,
-            members: AHashMap::new(),
-        };
+        let mut consumer_group =
+            ConsumerGroup::new(1, 1, "test", 3, 10, PartitionAssignmentStrategy::RoundRobin);
 
         consumer_group.add_member(member_id).await;
         let member = consumer_group.members.get(&member_id).unwrap();
@@ -232,13 +364,8 @@ mod tests {
     async fn should_assign_partitions_to_the_multiple_members() {
         let member1_id = 123;
         let member2_id = 456;
-        let mut consumer_group = ConsumerGroup {
-            topic_id: 1,
-            group_id: 1,
-            name: "test".to_string(),
-            partitions_count: 3,
-            members: AHashMap::new(),
-        };
+        let mut consumer_group =
+            ConsumerGroup::new(1, 1, "test", 3, 10, PartitionAssignmentStrategy::RoundRobin);
 
         consumer_group.add_member(member1_id).await;
         consumer_group.add_member(member2_id).await;
@@ -269,13 +396,8 @@ mod tests {
     async fn should_assign_only_single_partition_to_the_only_single_member() {
         let member1_id = 123;
         let member2_id = 456;
-        let mut consumer_group = ConsumerGroup {
-            topic_id: 1,
-            group_id: 1,
-            name: "test".to_string(),
-            partitions_count: 1,
-            members: AHashMap::new(),
-        };
+        let mut consumer_group =
+            ConsumerGroup::new(1, 1, "test", 1, 10, PartitionAssignmentStrategy::RoundRobin);
 
         consumer_group.add_member(member1_id).await;
         consumer_group.add_member(member2_id).await;
@@ -290,4 +412,68 @@ mod tests {
             assert_eq!(member2.partitions.len(), 1);
         }
     }
+
+    #[tokio::test]
+    async fn should_record_rebalances_up_to_the_limit() {
+        let mut consumer_group =
+            ConsumerGroup::new(1, 1, "test", 4, 2, PartitionAssignmentStrategy::RoundRobin);
+
+        consumer_group.add_member(1).await;
+        consumer_group.add_member(2).await;
+        let rebalances = consumer_group.get_rebalances();
+        assert_eq!(rebalances.len(), 2);
+        let rebalance = rebalances[1];
+        assert_eq!(rebalance.trigger, RebalanceTrigger::MemberJoined);
+        assert_eq!(rebalance.members_before, vec![1]);
+        assert_eq!(rebalance.members_after, vec![1, 2]);
+        assert!(rebalance.partitions_moved > 0);
+
+        consumer_group.delete_member(2).await;
+        let rebalances = consumer_group.get_rebalances();
+        assert_eq!(rebalances.len(), 2);
+        let rebalance = rebalances[1];
+        assert_eq!(rebalance.trigger, RebalanceTrigger::MemberLeft);
+        assert_eq!(rebalance.members_before, vec![1, 2]);
+        assert_eq!(rebalance.members_after, vec![1]);
+        assert_eq!(rebalances[0].trigger, RebalanceTrigger::MemberJoined);
+    }
+
+    #[tokio::test]
+    async fn should_increment_generation_on_each_rebalance() {
+        let mut consumer_group =
+            ConsumerGroup::new(1, 1, "test", 4, 0, PartitionAssignmentStrategy::Sticky);
+        assert_eq!(consumer_group.get_generation(), 0);
+
+        consumer_group.add_member(1).await;
+        consumer_group.add_member(2).await;
+        assert_eq!(consumer_group.get_generation(), 2);
+
+        consumer_group.reassign_partitions(6).await;
+        consumer_group.delete_member(2).await;
+        assert_eq!(consumer_group.get_generation(), 4);
+        let member = consumer_group.members.get(&1).unwrap().read().await;
+        let mut partitions = member.get_partitions();
+        partitions.sort_unstable();
+        assert_eq!(partitions, vec![1, 2, 3, 4, 5, 6]);
+    }
+
+    #[tokio::test]
+    async fn should_keep_partitions_of_the_remaining_members_using_sticky_assignment() {
+        let mut consumer_group =
+            ConsumerGroup::new(1, 1, "test", 6, 10, PartitionAssignmentStrategy::Sticky);
+        consumer_group.add_member(1).await;
+        consumer_group.add_member(2).await;
+        consumer_group.add_member(3).await;
+        let (_, assignments_before) = consumer_group.get_assignments().await;
+
+        consumer_group.delete_member(2).await;
+        let (_, assignments_after) = consumer_group.get_assignments().await;
+        for (partition_id, member_id) in assignments_before {
+            if member_id != 2 {
+                assert_eq!(assignments_after.get(&partition_id), Some(&member_id));
+            }
+        }
+        let rebalance = consumer_group.get_rebalances().pop().unwrap();
+        assert_eq!(rebalance.partitions_moved, 2);
+    }
 }
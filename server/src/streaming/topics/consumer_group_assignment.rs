@@ -0,0 +1,245 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use ahash::{AHashMap, AHashSet};
+use iggy::models::consumer_group::PartitionAssignmentStrategy;
+use std::cmp::Reverse;
+
+/// Assigns the partitions (with IDs from 1 to `partitions_count`) to the members of the consumer group.
+pub trait PartitionAssignor {
+    /// The `members` are sorted by ID, and the `previous` assignments map the partition ID to the member ID.
+    /// Returns the sorted partition IDs for each member.
+    fn assign(
+        &self,
+        members: &[u32],
+        partitions_count: u32,
+        previous: &AHashMap<u32, u32>,
+    ) -> AHashMap<u32, Vec<u32>>;
+}
+
+pub fn get_assignor(strategy: PartitionAssignmentStrategy) -> &'static dyn PartitionAssignor {
+    match strategy {
+        PartitionAssignmentStrategy::Range => &RangeAssignor,
+        PartitionAssignmentStrategy::RoundRobin => &RoundRobinAssignor,
+        PartitionAssignmentStrategy::Sticky => &StickyAssignor,
+    }
+}
+
+#[derive(Debug)]
+pub struct RangeAssignor;
+
+#[derive(Debug)]
+pub struct RoundRobinAssignor;
+
+/// Keeps the previously assigned partitions with their members, up to the balanced number of the partitions
+/// per member, and distributes only the remaining ones to the members having less than that.
+#[derive(Debug)]
+pub struct StickyAssignor;
+
+impl PartitionAssignor for RangeAssignor {
+    fn assign(
+        &self,
+        members: &[u32],
+        partitions_count: u32,
+        _previous: &AHashMap<u32, u32>,
+    ) -> AHashMap<u32, Vec<u32>> {
+        let mut assignments = AHashMap::with_capacity(members.len());
+        if members.is_empty() {
+            return assignments;
+        }
+
+        let members_count = members.len() as u32;
+        let partitions_per_member = partitions_count / members_count;
+        let remainder = partitions_count % members_count;
+        let mut next_partition_id = 1;
+        for (index, member_id) in members.iter().enumerate() {
+            let mut count = partitions_per_member;
+            if (index as u32) < remainder {
+                count += 1;
+            }
+            assignments.insert(
+                *member_id,
+                (next_partition_id..next_partition_id + count).collect(),
+            );
+            next_partition_id += count;
+        }
+        assignments
+    }
+}
+
+impl PartitionAssignor for RoundRobinAssignor {
+    fn assign(
+        &self,
+        members: &[u32],
+        partitions_count: u32,
+        _previous: &AHashMap<u32, u32>,
+    ) -> AHashMap<u32, Vec<u32>> {
+        let mut assignments: AHashMap<u32, Vec<u32>> = members
+            .iter()
+            .map(|member_id| (*member_id, Vec::new()))
+            .collect();
+        if members.is_empty() {
+            return assignments;
+        }
+
+        for partition_index in 0..partitions_count {
+            let member_id = members[partition_index as usize % members.len()];
+            if let Some(partitions) = assignments.get_mut(&member_id) {
+                partitions.push(partition_index + 1);
+            }
+        }
+        assignments
+    }
+}
+
+impl PartitionAssignor for StickyAssignor {
+    fn assign(
+        &self,
+        members: &[u32],
+        partitions_count: u32,
+        previous: &AHashMap<u32, u32>,
+    ) -> AHashMap<u32, Vec<u32>> {
+        let mut assignments: AHashMap<u32, Vec<u32>> = members
+            .iter()
+            .map(|member_id| (*member_id, Vec::new()))
+            .collect();
+        if members.is_empty() {
+            return assignments;
+        }
+
+        for (partition_id, member_id) in previous {
+            if *partition_id > partitions_count {
+                continue;
+            }
+            if let Some(partitions) = assignments.get_mut(member_id) {
+                partitions.push(*partition_id);
+            }
+        }
+
+        // The members owning the most partitions get the extra ones, so that the fewest partitions are moved.
+        let members_count = members.len() as u32;
+        let partitions_per_member = partitions_count / members_count;
+        let mut remainder = partitions_count % members_count;
+        let mut ordered_members = members.to_vec();
+        ordered_members
+            .sort_by_key(|member_id| (Reverse(assignments[member_id].len()), *member_id));
+        let mut targets = AHashMap::with_capacity(members.len());
+        let mut kept_partitions = AHashSet::with_capacity(partitions_count as usize);
+        for member_id in ordered_members {
+            let mut target = partitions_per_member as usize;
+            if remainder > 0 {
+                remainder -= 1;
+                target += 1;
+            }
+            let partitions = assignments.get_mut(&member_id).unwrap();
+            partitions.sort_unstable();
+            partitions.truncate(target);
+            kept_partitions.extend(partitions.iter().copied());
+            targets.insert(member_id, target);
+        }
+
+        let mut unassigned_partitions =
+            (1..=partitions_count).filter(|partition_id| !kept_partitions.contains(partition_id));
+        for member_id in members {
+            let partitions = assignments.get_mut(member_id).unwrap();
+            while partitions.len() < targets[member_id] {
+                let Some(partition_id) = unassigned_partitions.next() else {
+                    break;
+                };
+                partitions.push(partition_id);
+            }
+            partitions.sort_unstable();
+        }
+        assignments
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn previous_assignments(assignments: &AHashMap<u32, Vec<u32>>) -> AHashMap<u32, u32> {
+        assignments
+            .iter()
+            .flat_map(|(member_id, partitions)| {
+                partitions
+                    .iter()
+                    .map(move |partition_id| (*partition_id, *member_id))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn range_assignor_should_assign_contiguous_partitions() {
+        let assignments = RangeAssignor.assign(&[1, 2, 3], 7, &AHashMap::new());
+        assert_eq!(assignments[&1], vec![1, 2, 3]);
+        assert_eq!(assignments[&2], vec![4, 5]);
+        assert_eq!(assignments[&3], vec![6, 7]);
+    }
+
+    #[test]
+    fn round_robin_assignor_should_distribute_partitions_one_by_one() {
+        let assignments = RoundRobinAssignor.assign(&[1, 2, 3], 7, &AHashMap::new());
+        assert_eq!(assignments[&1], vec![1, 4, 7]);
+        assert_eq!(assignments[&2], vec![2, 5]);
+        assert_eq!(assignments[&3], vec![3, 6]);
+    }
+
+    #[test]
+    fn sticky_assignor_should_move_only_partitions_of_the_leaving_member() {
+        let assignments = StickyAssignor.assign(&[1, 2, 3], 6, &AHashMap::new());
+        let previous = previous_assignments(&assignments);
+
+        let assignments = StickyAssignor.assign(&[1, 3], 6, &previous);
+        assert_eq!(assignments[&1].len(), 3);
+        assert_eq!(assignments[&3].len(), 3);
+        for (partition_id, member_id) in &previous {
+            if *member_id != 2 {
+                assert!(assignments[member_id].contains(partition_id));
+            }
+        }
+    }
+
+    #[test]
+    fn sticky_assignor_should_move_minimal_partitions_to_the_joining_member() {
+        let assignments = StickyAssignor.assign(&[1, 2], 6, &AHashMap::new());
+        let previous = previous_assignments(&assignments);
+
+        let assignments = StickyAssignor.assign(&[1, 2, 3], 6, &previous);
+        assert_eq!(assignments[&3].len(), 2);
+        let current = previous_assignments(&assignments);
+        let moved = previous
+            .iter()
+            .filter(|(partition_id, member_id)| current.get(*partition_id) != Some(*member_id))
+            .count();
+        assert_eq!(moved, 2);
+    }
+
+    #[test]
+    fn sticky_assignor_should_drop_deleted_partitions() {
+        let assignments = StickyAssignor.assign(&[1, 2], 4, &AHashMap::new());
+        let previous = previous_assignments(&assignments);
+
+        let assignments = StickyAssignor.assign(&[1, 2], 2, &previous);
+        let mut partitions = assignments.values().flatten().copied().collect::<Vec<_>>();
+        partitions.sort_unstable();
+        assert_eq!(partitions, vec![1, 2]);
+        assert_eq!(assignments[&1].len(), 1);
+        assert_eq!(assignments[&2].len(), 1);
+    }
+}
@@ -16,10 +16,13 @@
  * under the License.
  */
 
+use crate::streaming::partitions::offset_recovery::RecoveredConsumerOffset;
 use crate::streaming::topics::consumer_group::ConsumerGroup;
 use crate::streaming::topics::topic::Topic;
 use crate::streaming::topics::COMPONENT;
+use ahash::AHashMap;
 use error_set::ErrContext;
+use iggy::consumer_groups::offset_recovery_policy::OffsetRecoveryPolicy;
 use iggy::error::IggyError;
 use iggy::identifier::{IdKind, Identifier};
 use iggy::locking::IggySharedMutFn;
@@ -100,11 +103,12 @@ impl Topic {
         Ok(consumer_group.unwrap())
     }
 
-    pub async fn create_consumer_group(
-        &mut self,
+    /// Returns the ID of the new consumer group with the given name, either the given one or the next available one.
+    pub fn get_new_consumer_group_id(
+        &self,
         group_id: Option<u32>,
         name: &str,
-    ) -> Result<&RwLock<ConsumerGroup>, IggyError> {
+    ) -> Result<u32, IggyError> {
         if self.consumer_groups_ids.contains_key(name) {
             return Err(IggyError::ConsumerGroupNameAlreadyExists(
                 name.to_owned(),
@@ -137,8 +141,26 @@ impl Topic {
             return Err(IggyError::ConsumerGroupIdAlreadyExists(id, self.topic_id));
         }
 
-        let consumer_group =
-            ConsumerGroup::new(self.topic_id, id, name, self.partitions.len() as u32);
+        Ok(id)
+    }
+
+    pub async fn create_consumer_group(
+        &mut self,
+        group_id: Option<u32>,
+        name: &str,
+        offset_recovery: OffsetRecoveryPolicy,
+    ) -> Result<&RwLock<ConsumerGroup>, IggyError> {
+        let id = self.get_new_consumer_group_id(group_id, name)?;
+        let mut consumer_group = ConsumerGroup::new(
+            self.topic_id,
+            id,
+            name,
+            self.partitions.len() as u32,
+            self.config.topic.max_consumer_group_rebalances,
+            self.config.topic.consumer_group_assignment_strategy,
+        );
+        consumer_group.offset_recovery =
+            Topic::get_offset_recovery_policy(offset_recovery, &self.config);
         self.consumer_groups.insert(id, RwLock::new(consumer_group));
         self.consumer_groups_ids.insert(name.to_owned(), id);
         info!(
@@ -148,6 +170,34 @@ impl Topic {
         self.get_consumer_group_by_id(id)
     }
 
+    /// Recovers the out of range consumer offsets in all the partitions, returning the adjusted ones along with their partition IDs.
+    pub async fn recover_consumer_offsets(
+        &self,
+    ) -> Result<Vec<(u32, RecoveredConsumerOffset)>, IggyError> {
+        let mut consumer_group_policies = AHashMap::new();
+        for consumer_group in self.consumer_groups.values() {
+            let consumer_group = consumer_group.read().await;
+            consumer_group_policies.insert(consumer_group.group_id, consumer_group.offset_recovery);
+        }
+
+        let mut recovered_offsets = Vec::new();
+        for partition in self.get_partitions() {
+            let partition = partition.read().await;
+            let partition_id = partition.partition_id;
+            let recovered = partition
+                .recover_consumer_offsets(
+                    self.config.topic.consumer_offset_recovery,
+                    &consumer_group_policies,
+                )
+                .await
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to recover consumer offsets in partition with ID: {partition_id}, topic: {self}")
+                })?;
+            recovered_offsets.extend(recovered.into_iter().map(|offset| (partition_id, offset)));
+        }
+        Ok(recovered_offsets)
+    }
+
     pub async fn delete_consumer_group(
         &mut self,
         id: &Identifier,
@@ -170,6 +220,7 @@ impl Topic {
             let consumer_group = consumer_group.read().await;
             let group_id = consumer_group.group_id;
             self.consumer_groups_ids.remove(&consumer_group.name);
+            self.remove_consumer_group_expiry_watcher(group_id);
             let current_group_id = self.current_consumer_group_id.load(Ordering::SeqCst);
             if current_group_id > group_id {
                 self.current_consumer_group_id
@@ -177,13 +228,15 @@ impl Topic {
             }
 
             for (_, partition) in self.partitions.iter() {
-                let partition = partition.read().await;
-                if let Some((_, offset)) = partition.consumer_group_offsets.remove(&group_id) {
+                let mut partition = partition.write().await;
+                if partition.consumer_group_offsets.remove(&group_id).is_some() {
                     self.storage
-                        .partition
-                        .delete_consumer_offset(&offset.path)
+                        .consumer_offsets
+                        .delete_consumer_offset(&partition.consumer_group_offsets_path, group_id)
                         .await?;
                 }
+                partition.delete_in_flight_messages(group_id).await?;
+                partition.delete_state_store(group_id).await?;
             }
 
             info!(
@@ -248,7 +301,9 @@ mod tests {
         let name = "test";
         let mut topic = get_topic().await;
         let topic_id = topic.topic_id;
-        let result = topic.create_consumer_group(Some(group_id), name).await;
+        let result = topic
+            .create_consumer_group(Some(group_id), name, OffsetRecoveryPolicy::ServerDefault)
+            .await;
         assert!(result.is_ok());
         {
             let created_consumer_group = result.unwrap().read().await;
@@ -276,10 +331,14 @@ mod tests {
         let group_id = 1;
         let name = "test";
         let mut topic = get_topic().await;
-        let result = topic.create_consumer_group(Some(group_id), name).await;
+        let result = topic
+            .create_consumer_group(Some(group_id), name, OffsetRecoveryPolicy::ServerDefault)
+            .await;
         assert!(result.is_ok());
         assert_eq!(topic.consumer_groups.len(), 1);
-        let result = topic.create_consumer_group(Some(group_id), "test2").await;
+        let result = topic
+            .create_consumer_group(Some(group_id), "test2", OffsetRecoveryPolicy::ServerDefault)
+            .await;
         assert!(result.is_err());
         let err = result.unwrap_err();
         assert!(matches!(err, IggyError::ConsumerGroupIdAlreadyExists(_, _)));
@@ -291,11 +350,15 @@ mod tests {
         let group_id = 1;
         let name = "test";
         let mut topic = get_topic().await;
-        let result = topic.create_consumer_group(Some(group_id), name).await;
+        let result = topic
+            .create_consumer_group(Some(group_id), name, OffsetRecoveryPolicy::ServerDefault)
+            .await;
         assert!(result.is_ok());
         assert_eq!(topic.consumer_groups.len(), 1);
         let group_id = group_id + 1;
-        let result = topic.create_consumer_group(Some(group_id), name).await;
+        let result = topic
+            .create_consumer_group(Some(group_id), name, OffsetRecoveryPolicy::ServerDefault)
+            .await;
         assert!(result.is_err());
         let err = result.unwrap_err();
         assert!(matches!(
@@ -310,7 +373,9 @@ mod tests {
         let group_id = 1;
         let name = "test";
         let mut topic = get_topic().await;
-        let result = topic.create_consumer_group(Some(group_id), name).await;
+        let result = topic
+            .create_consumer_group(Some(group_id), name, OffsetRecoveryPolicy::ServerDefault)
+            .await;
         assert!(result.is_ok());
         assert_eq!(topic.consumer_groups.len(), 1);
         let result = topic
@@ -325,7 +390,9 @@ mod tests {
         let group_id = 1;
         let name = "test";
         let mut topic = get_topic().await;
-        let result = topic.create_consumer_group(Some(group_id), name).await;
+        let result = topic
+            .create_consumer_group(Some(group_id), name, OffsetRecoveryPolicy::ServerDefault)
+            .await;
         assert!(result.is_ok());
         assert_eq!(topic.consumer_groups.len(), 1);
         let group_id = group_id + 1;
@@ -343,7 +410,7 @@ mod tests {
         let member_id = 1;
         let mut topic = get_topic().await;
         topic
-            .create_consumer_group(Some(group_id), name)
+            .create_consumer_group(Some(group_id), name, OffsetRecoveryPolicy::ServerDefault)
             .await
             .unwrap();
         let result = topic
@@ -366,7 +433,7 @@ mod tests {
         let member_id = 1;
         let mut topic = get_topic().await;
         topic
-            .create_consumer_group(Some(group_id), name)
+            .create_consumer_group(Some(group_id), name, OffsetRecoveryPolicy::ServerDefault)
             .await
             .unwrap();
         topic
@@ -0,0 +1,176 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use ahash::AHashMap;
+use std::collections::BTreeMap;
+
+/// What should happen with the polled message of the consumer group having the dead-letter topic configured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeliveryOutcome {
+    /// The message should be returned to the member.
+    Deliver,
+    /// The message has already been delivered the given number of times and should be dead-lettered.
+    DeadLetter(u32),
+    /// The message has already been dead-lettered, but the offset hasn't been committed yet.
+    Skip,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct Delivery {
+    count: u32,
+    dead_lettered: bool,
+}
+
+/// Tracks the deliveries of the uncommitted messages polled by the consumer group, per partition.
+/// The tracked offsets are forgotten once the group commits past them.
+#[derive(Debug, Default)]
+pub struct DeliveryTracker {
+    partitions: AHashMap<u32, BTreeMap<u64, Delivery>>,
+}
+
+impl DeliveryTracker {
+    /// Returns the outcomes of the polled offsets.
+    /// The `max_delivery_count` equal to 0 means unlimited deliveries.
+    pub fn get_outcomes(
+        &self,
+        partition_id: u32,
+        offsets: &[u64],
+        max_delivery_count: u32,
+    ) -> Vec<DeliveryOutcome> {
+        let Some(deliveries) = self.partitions.get(&partition_id) else {
+            return vec![DeliveryOutcome::Deliver; offsets.len()];
+        };
+
+        offsets
+            .iter()
+            .map(|offset| match deliveries.get(offset) {
+                Some(delivery) if delivery.dead_lettered => DeliveryOutcome::Skip,
+                Some(delivery)
+                    if max_delivery_count > 0 && delivery.count >= max_delivery_count =>
+                {
+                    DeliveryOutcome::DeadLetter(delivery.count)
+                }
+                _ => DeliveryOutcome::Deliver,
+            })
+            .collect()
+    }
+
+    /// Increments the delivery counts of the messages returned to the member.
+    pub fn record_deliveries(&mut self, partition_id: u32, offsets: &[u64]) {
+        let deliveries = self.partitions.entry(partition_id).or_default();
+        for offset in offsets {
+            deliveries.entry(*offset).or_default().count += 1;
+        }
+    }
+
+    /// Returns the number of times the message has been delivered.
+    pub fn get_delivery_count(&self, partition_id: u32, offset: u64) -> u32 {
+        self.get_delivery(partition_id, offset)
+            .map(|delivery| delivery.count)
+            .unwrap_or_default()
+    }
+
+    pub fn is_dead_lettered(&self, partition_id: u32, offset: u64) -> bool {
+        self.get_delivery(partition_id, offset)
+            .is_some_and(|delivery| delivery.dead_lettered)
+    }
+
+    /// Marks the message as dead-lettered, so that it's skipped by the subsequent polls.
+    pub fn mark_dead_lettered(&mut self, partition_id: u32, offset: u64) {
+        self.partitions
+            .entry(partition_id)
+            .or_default()
+            .entry(offset)
+            .or_default()
+            .dead_lettered = true;
+    }
+
+    /// Stops tracking the offsets below the given one, as they're not going to be polled again.
+    pub fn forget_below(&mut self, partition_id: u32, offset: u64) {
+        if let Some(deliveries) = self.partitions.get_mut(&partition_id) {
+            *deliveries = deliveries.split_off(&offset);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.partitions.clear();
+    }
+
+    fn get_delivery(&self, partition_id: u32, offset: u64) -> Option<&Delivery> {
+        self.partitions
+            .get(&partition_id)
+            .and_then(|deliveries| deliveries.get(&offset))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn messages_exceeding_max_delivery_count_should_be_dead_lettered() {
+        let mut tracker = DeliveryTracker::default();
+        let offsets = [10, 11, 12];
+        for _ in 0..2 {
+            assert_eq!(
+                tracker.get_outcomes(1, &offsets, 2),
+                vec![DeliveryOutcome::Deliver; 3]
+            );
+            tracker.record_deliveries(1, &offsets);
+        }
+
+        assert_eq!(
+            tracker.get_outcomes(1, &offsets, 2),
+            vec![DeliveryOutcome::DeadLetter(2); 3]
+        );
+        assert_eq!(
+            tracker.get_outcomes(1, &offsets, 0),
+            vec![DeliveryOutcome::Deliver; 3]
+        );
+        assert_eq!(
+            tracker.get_outcomes(2, &offsets, 2),
+            vec![DeliveryOutcome::Deliver; 3]
+        );
+    }
+
+    #[test]
+    fn dead_lettered_messages_should_be_skipped() {
+        let mut tracker = DeliveryTracker::default();
+        tracker.record_deliveries(1, &[10, 11]);
+        tracker.mark_dead_lettered(1, 10);
+
+        assert!(tracker.is_dead_lettered(1, 10));
+        assert_eq!(tracker.get_delivery_count(1, 10), 1);
+        assert_eq!(
+            tracker.get_outcomes(1, &[10, 11], 5),
+            vec![DeliveryOutcome::Skip, DeliveryOutcome::Deliver]
+        );
+    }
+
+    #[test]
+    fn offsets_below_first_polled_one_should_be_forgotten() {
+        let mut tracker = DeliveryTracker::default();
+        tracker.record_deliveries(1, &[10, 11, 12]);
+        tracker.mark_dead_lettered(1, 10);
+
+        tracker.forget_below(1, 12);
+        assert_eq!(tracker.get_delivery_count(1, 11), 0);
+        assert!(!tracker.is_dead_lettered(1, 10));
+        assert_eq!(tracker.get_delivery_count(1, 12), 1);
+    }
+}
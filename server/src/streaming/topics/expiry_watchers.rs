@@ -0,0 +1,77 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::streaming::polling_consumer::PollingConsumer;
+use crate::streaming::topics::topic::Topic;
+use iggy::consumer::{Consumer, ConsumerKind};
+use iggy::error::IggyError;
+use iggy::identifier::IdKind;
+use iggy::models::expiry_notification::ExpiryWatcher;
+use tracing::info;
+
+impl Topic {
+    /// Registers (or removes) the interest of the consumer in the expiry of the messages,
+    /// the consumer group must exist and is stored by its numeric ID.
+    pub fn update_expiry_watcher(
+        &mut self,
+        consumer: &Consumer,
+        enabled: bool,
+    ) -> Result<ExpiryWatcher, IggyError> {
+        let id = match consumer.kind {
+            ConsumerKind::Consumer => PollingConsumer::resolve_consumer_id(&consumer.id),
+            ConsumerKind::ConsumerGroup => match consumer.id.kind {
+                IdKind::Numeric => {
+                    let group_id = consumer.id.get_u32_value()?;
+                    if !self.consumer_groups.contains_key(&group_id) {
+                        return Err(IggyError::ConsumerGroupIdNotFound(group_id, self.topic_id));
+                    }
+                    group_id
+                }
+                IdKind::String => {
+                    let name = consumer.id.get_cow_str_value()?;
+                    *self.consumer_groups_ids.get(&*name).ok_or_else(|| {
+                        IggyError::ConsumerGroupNameNotFound(name.to_string(), self.name.clone())
+                    })?
+                }
+            },
+        };
+
+        let watcher = ExpiryWatcher {
+            kind: consumer.kind,
+            id,
+        };
+        self.expiry_watchers.retain(|existing| *existing != watcher);
+        if enabled {
+            self.expiry_watchers.push(watcher);
+        }
+        info!(
+            "Expiry watcher: {} with ID: {id} {} for topic with ID: {} and stream with ID: {}.",
+            watcher.kind,
+            if enabled { "registered" } else { "removed" },
+            self.topic_id,
+            self.stream_id
+        );
+        Ok(watcher)
+    }
+
+    /// Removes the interest of the deleted consumer group in the expiry of the messages.
+    pub(crate) fn remove_consumer_group_expiry_watcher(&mut self, group_id: u32) {
+        self.expiry_watchers.retain(|watcher| {
+            watcher.kind != ConsumerKind::ConsumerGroup || watcher.id != group_id
+        });
+    }
+}
@@ -0,0 +1,88 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use iggy::models::message_size_distribution::{
+    MessageSizeDistribution, MESSAGE_SIZE_BUCKETS_COUNT,
+};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Counts the sizes of the messages appended to the topic in the fixed buckets,
+/// updated at produce time without locking, so the distribution is approximate while it's being read.
+/// It's kept in memory only, thus it covers the messages appended since the server has started.
+#[derive(Debug)]
+pub struct MessageSizeHistogram {
+    buckets_counts: [AtomicU64; MESSAGE_SIZE_BUCKETS_COUNT],
+    total_size: AtomicU64,
+    min_size: AtomicU64,
+    max_size: AtomicU64,
+}
+
+impl Default for MessageSizeHistogram {
+    fn default() -> Self {
+        Self {
+            buckets_counts: Default::default(),
+            total_size: AtomicU64::new(0),
+            min_size: AtomicU64::new(u64::MAX),
+            max_size: AtomicU64::new(0),
+        }
+    }
+}
+
+impl MessageSizeHistogram {
+    pub fn record(&self, size: u64) {
+        self.buckets_counts[MessageSizeDistribution::bucket_index(size)]
+            .fetch_add(1, Ordering::Relaxed);
+        self.total_size.fetch_add(size, Ordering::Relaxed);
+        self.min_size.fetch_min(size, Ordering::Relaxed);
+        self.max_size.fetch_max(size, Ordering::Relaxed);
+    }
+
+    pub fn to_distribution(&self) -> MessageSizeDistribution {
+        let max_size = self.max_size.load(Ordering::Relaxed);
+        MessageSizeDistribution::new(
+            self.buckets_counts
+                .each_ref()
+                .map(|count| count.load(Ordering::Relaxed)),
+            self.total_size.load(Ordering::Relaxed),
+            self.min_size.load(Ordering::Relaxed).min(max_size),
+            max_size,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recorded_sizes_should_be_reported_in_distribution() {
+        let histogram = MessageSizeHistogram::default();
+        assert_eq!(
+            histogram.to_distribution(),
+            MessageSizeDistribution::from_sizes([])
+        );
+
+        for size in [100, 2000, 100] {
+            histogram.record(size);
+        }
+
+        assert_eq!(
+            histogram.to_distribution(),
+            MessageSizeDistribution::from_sizes([100, 2000, 100])
+        );
+    }
+}
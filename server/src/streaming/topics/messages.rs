@@ -18,19 +18,23 @@
 
 use crate::streaming::batching::appendable_batch_info::AppendableBatchInfo;
 use crate::streaming::models::messages::RetainedMessage;
+use crate::streaming::partitions::epoch::check_epoch;
+use crate::streaming::partitions::gaps::filter_messages;
 use crate::streaming::polling_consumer::PollingConsumer;
+use crate::streaming::segments::message_slices::PolledMessageSlices;
 use crate::streaming::topics::topic::Topic;
 use crate::streaming::topics::COMPONENT;
 use crate::streaming::utils::file::folder_size;
-use crate::streaming::utils::hash;
+use crate::streaming::utils::{hash, message_id};
 use ahash::AHashMap;
 use error_set::ErrContext;
 use iggy::confirmation::Confirmation;
 use iggy::error::IggyError;
 use iggy::locking::IggySharedMutFn;
-use iggy::messages::poll_messages::{PollingKind, PollingStrategy};
+use iggy::messages::message_filter::MessageFilter;
+use iggy::messages::poll_messages::{IsolationLevel, PollingKind, PollingStrategy};
 use iggy::messages::send_messages::{Message, Partitioning, PartitioningKind};
-use iggy::models::messages::PolledMessages;
+use iggy::models::messages::{MessagesGapReason, PolledMessages};
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::expiry::IggyExpiry;
 use iggy::utils::sizeable::Sizeable;
@@ -39,17 +43,33 @@ use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use tracing::{info, trace, warn};
 
+/// The maximum number of reads of the partition for a single poll, when the messages are skipped by the filter.
+const MAX_FILTERED_READS: u32 = 10;
+
 impl Topic {
     pub fn get_messages_count(&self) -> u64 {
         self.messages_count.load(Ordering::SeqCst)
     }
 
+    /// Ensures that the partition hasn't started over its offsets since the poller learned the given epoch.
+    pub async fn check_partition_epoch(
+        &self,
+        partition_id: u32,
+        expected_epoch: u64,
+    ) -> Result<(), IggyError> {
+        let partition = self.get_partition(partition_id)?;
+        let partition = partition.read().await;
+        check_epoch(partition_id, expected_epoch, partition.epoch)
+    }
+
     pub async fn get_messages(
         &self,
         consumer: PollingConsumer,
         partition_id: u32,
         strategy: PollingStrategy,
         count: u32,
+        isolation_level: IsolationLevel,
+        filter: Option<&MessageFilter>,
     ) -> Result<PolledMessages, IggyError> {
         if !self.has_partitions() {
             return Err(IggyError::NoPartitions(self.topic_id, self.stream_id));
@@ -67,8 +87,17 @@ impl Topic {
         let partition = partition.unwrap();
         let partition = partition.read().await;
         let value = strategy.value;
+        let expected_offset = match strategy.kind {
+            PollingKind::Offset => Some(value),
+            PollingKind::Next => partition.get_next_expected_offset(consumer),
+            _ => None,
+        };
         let messages = match strategy.kind {
-            PollingKind::Offset => partition.get_messages_by_offset(value, count).await,
+            PollingKind::Offset => {
+                partition
+                    .get_consumer_messages_by_offset(consumer, value, count)
+                    .await
+            }
             PollingKind::Timestamp => {
                 partition
                     .get_messages_by_timestamp(value.into(), count)
@@ -79,23 +108,180 @@ impl Topic {
             PollingKind::Last => partition.get_last_messages(count).await,
             PollingKind::Next => partition.get_next_messages(consumer, count).await,
         }?;
+        partition.record_heat(messages.len() as u64);
 
-        let messages = messages
+        let mut gaps = partition.get_messages_gaps(expected_offset, &messages);
+        let mut messages = messages
             .into_iter()
             .map(|msg| msg.to_polled_message())
             .collect::<Result<Vec<_>, IggyError>>()?;
+        if isolation_level == IsolationLevel::ReadCommitted {
+            let (committed_messages, aborted_gaps) = partition.filter_committed_messages(messages);
+            messages = committed_messages;
+            gaps.extend(aborted_gaps);
+            // All the polled messages might be aborted, so the polling continues after them, until the committed ones are found.
+            while messages.is_empty() {
+                let Some(next_offset) = gaps
+                    .last()
+                    .filter(|gap| gap.reason == MessagesGapReason::Aborted)
+                    .map(|gap| gap.end_offset + 1)
+                else {
+                    break;
+                };
+                let next_messages = partition
+                    .get_messages_by_offset(next_offset, count)
+                    .await?
+                    .into_iter()
+                    .map(|msg| msg.to_polled_message())
+                    .collect::<Result<Vec<_>, IggyError>>()?;
+                let (committed_messages, aborted_gaps) =
+                    partition.filter_committed_messages(next_messages);
+                messages = committed_messages;
+                if aborted_gaps.is_empty() {
+                    break;
+                }
+                gaps.extend(aborted_gaps);
+            }
+            gaps.sort_by_key(|gap| gap.start_offset);
+        }
+        let mut last_offset = messages.last().map(|message| message.offset);
+        if let Some(filter) = filter {
+            last_offset = last_offset.max(gaps.iter().map(|gap| gap.end_offset).max());
+            let (matched_messages, filtered_gaps) = filter_messages(filter, messages);
+            messages = matched_messages;
+            gaps.extend(filtered_gaps);
+            // The polling continues after the skipped messages, until enough matching ones are found.
+            let mut reads = 1;
+            while (messages.len() as u32) < count && reads < MAX_FILTERED_READS {
+                let Some(next_offset) = last_offset.map(|offset| offset + 1) else {
+                    break;
+                };
+                if next_offset > partition.current_offset {
+                    break;
+                }
+
+                let next_messages = partition
+                    .get_messages_by_offset(next_offset, count - messages.len() as u32)
+                    .await?;
+                let Some(read_offset) = next_messages.last().map(|message| message.offset) else {
+                    break;
+                };
+                gaps.extend(partition.get_messages_gaps(Some(next_offset), &next_messages));
+                let mut next_messages = next_messages
+                    .into_iter()
+                    .map(|msg| msg.to_polled_message())
+                    .collect::<Result<Vec<_>, IggyError>>()?;
+                let mut next_last_offset = Some(read_offset);
+                if isolation_level == IsolationLevel::ReadCommitted {
+                    let (committed_messages, aborted_gaps) =
+                        partition.filter_committed_messages(next_messages);
+                    next_messages = committed_messages;
+                    next_last_offset = next_messages
+                        .last()
+                        .map(|message| message.offset)
+                        .max(aborted_gaps.iter().map(|gap| gap.end_offset).max());
+                    gaps.extend(aborted_gaps);
+                }
+
+                let (matched_messages, filtered_gaps) = filter_messages(filter, next_messages);
+                messages.extend(matched_messages);
+                gaps.extend(filtered_gaps);
+                // The polling stops before the first message of the oldest open transaction.
+                if next_last_offset < Some(read_offset) {
+                    last_offset = last_offset.max(next_last_offset);
+                    break;
+                }
+
+                last_offset = next_last_offset;
+                reads += 1;
+            }
+            gaps.sort_by_key(|gap| gap.start_offset);
+        }
+        // The remaining messages are counted after the last read one, including the skipped ones.
+        let remaining_messages = last_offset
+            .map(|offset| partition.current_offset.saturating_sub(offset))
+            .unwrap_or_default();
         Ok(PolledMessages {
             partition_id,
             current_offset: partition.current_offset,
+            partition_epoch: partition.epoch,
+            remaining_messages,
+            gaps,
+            chunk_sizes: Vec::new(),
+            throttle_time_ms: 0,
+            topic_delete_at: self.get_delete_at_micros(),
             messages,
         })
     }
 
+    /// Returns the messages persisted in the partition segments, with their payloads left in the log files,
+    /// or `None` if they have to be polled using `get_messages`, e.g. when there are gaps or transactions to be handled.
+    pub async fn get_message_slices(
+        &self,
+        consumer: PollingConsumer,
+        partition_id: u32,
+        strategy: PollingStrategy,
+        count: u32,
+        isolation_level: IsolationLevel,
+    ) -> Result<Option<PolledMessageSlices>, IggyError> {
+        let Some(partition) = self.partitions.get(&partition_id) else {
+            return Ok(None);
+        };
+
+        let partition = partition.read().await;
+        if isolation_level == IsolationLevel::ReadCommitted && !partition.transactions.is_empty() {
+            return Ok(None);
+        }
+
+        let start_offset = match strategy.kind {
+            PollingKind::Offset => strategy.value,
+            PollingKind::First => 0,
+            PollingKind::Last => {
+                let count = (count as u64).min(partition.current_offset + 1);
+                1 + partition.current_offset - count
+            }
+            PollingKind::Next => partition
+                .get_next_expected_offset(consumer)
+                .unwrap_or_default(),
+            PollingKind::Timestamp => return Ok(None),
+        };
+        let Some(messages) = partition
+            .get_message_slices_by_offset(start_offset, count)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to get message slices, partition ID: {partition_id}, start offset: {start_offset}, count: {count}")
+            })?
+        else {
+            return Ok(None);
+        };
+
+        // The gaps in the offsets are reported by the regular polling.
+        let is_contiguous = messages
+            .iter()
+            .enumerate()
+            .all(|(index, message)| message.offset == start_offset + index as u64);
+        if messages.is_empty() || !is_contiguous {
+            return Ok(None);
+        }
+
+        partition.record_heat(messages.len() as u64);
+        let last_offset = messages.last().map(|message| message.offset).unwrap();
+        Ok(Some(PolledMessageSlices {
+            partition_id,
+            current_offset: partition.current_offset,
+            partition_epoch: partition.epoch,
+            remaining_messages: partition.current_offset.saturating_sub(last_offset),
+            throttle_time_ms: 0,
+            topic_delete_at: self.get_delete_at_micros(),
+            messages,
+        }))
+    }
+
     pub async fn append_messages(
         &self,
         batch_size: IggyByteSize,
         partitioning: Partitioning,
-        messages: Vec<Message>,
+        mut messages: Vec<Message>,
         confirmation: Option<Confirmation>,
     ) -> Result<(), IggyError> {
         if !self.has_partitions() {
@@ -112,6 +298,31 @@ impl Topic {
             return Ok(());
         }
 
+        self.assign_messages_ids(&mut messages);
+        let partition_id = self.resolve_partition_id(&partitioning)?;
+        for message in &messages {
+            self.message_sizes
+                .record(message.get_size_bytes().as_bytes_u64());
+        }
+        let appendable_batch_info = AppendableBatchInfo::new(batch_size, partition_id);
+        self.append_messages_to_partition(appendable_batch_info, messages, confirmation)
+            .await
+    }
+
+    /// Generates the IDs of the messages which were sent without them.
+    pub(crate) fn assign_messages_ids(&self, messages: &mut [Message]) {
+        for message in messages.iter_mut().filter(|message| message.id == 0) {
+            message.id =
+                message_id::generate(self.message_id_scheme, self.config.message_id.node_id);
+        }
+    }
+
+    /// Returns the ID of the partition to which the messages are appended, the balanced partitioning
+    /// moves to the next partition on every call.
+    pub(crate) fn resolve_partition_id(
+        &self,
+        partitioning: &Partitioning,
+    ) -> Result<u32, IggyError> {
         let partition_id = match partitioning.kind {
             PartitioningKind::Balanced => self.get_next_partition_id(),
             PartitioningKind::PartitionId => u32::from_le_bytes(
@@ -123,10 +334,7 @@ impl Topic {
                 self.calculate_partition_id_by_messages_key_hash(&partitioning.value)
             }
         };
-
-        let appendable_batch_info = AppendableBatchInfo::new(batch_size, partition_id);
-        self.append_messages_to_partition(appendable_batch_info, messages, confirmation)
-            .await
+        Ok(partition_id)
     }
 
     pub async fn flush_unsaved_buffer(
@@ -202,7 +410,8 @@ impl Topic {
     }
 
     pub(crate) async fn load_messages_from_disk_to_cache(&mut self) -> Result<(), IggyError> {
-        if !self.config.cache.enabled {
+        // With the warm-up enabled, the cache is filled once all the topics are loaded, based on the heat map.
+        if !self.is_cache_enabled() || self.config.cache.warmup.enabled {
             return Ok(());
         }
         let path = self.config.get_system_path();
@@ -281,7 +490,7 @@ impl Topic {
         Ok(())
     }
 
-    fn cache_integrity_check(cache: &[Arc<RetainedMessage>]) -> bool {
+    pub(crate) fn cache_integrity_check(cache: &[Arc<RetainedMessage>]) -> bool {
         if cache.is_empty() {
             warn!("Cache is empty!");
             return false;
@@ -326,6 +535,22 @@ impl Topic {
         }
         expired_segments
     }
+
+    pub async fn get_excess_segments_start_offsets_per_partition(&self) -> AHashMap<u32, Vec<u64>> {
+        let mut excess_segments = AHashMap::new();
+        if self.max_segments == 0 {
+            return excess_segments;
+        }
+
+        for partition in self.partitions.values() {
+            let partition = partition.read().await;
+            let segments = partition.get_excess_segments_start_offsets(self.max_segments);
+            if !segments.is_empty() {
+                excess_segments.insert(partition.partition_id, segments);
+            }
+        }
+        excess_segments
+    }
 }
 
 #[cfg(test)]
@@ -17,13 +17,18 @@
  */
 
 pub mod consumer_group;
+pub mod consumer_group_assignment;
 pub mod consumer_groups;
 pub mod consumer_offsets;
+pub mod dead_letter;
+pub mod expiry_watchers;
+pub mod message_sizes;
 pub mod messages;
 pub mod partitions;
 pub mod persistence;
 pub mod segments;
 pub mod storage;
 pub mod topic;
+pub mod topic_config;
 
 pub const COMPONENT: &str = "STREAMING_TOPICS";
@@ -48,7 +48,7 @@ impl Topic {
 
         let mut partition_ids = Vec::with_capacity(count as usize);
         for partition_id in current_partitions_count + 1..=current_partitions_count + count {
-            let partition = Partition::create(
+            let mut partition = Partition::create(
                 self.stream_id,
                 self.topic_id,
                 partition_id,
@@ -56,6 +56,7 @@ impl Topic {
                 self.config.clone(),
                 self.storage.clone(),
                 self.message_expiry,
+                self.get_storage_compression_algorithm(),
                 self.messages_count_of_parent_stream.clone(),
                 self.messages_count.clone(),
                 self.size_of_parent_stream.clone(),
@@ -64,6 +65,7 @@ impl Topic {
                 IggyTimestamp::now(),
             )
             .await;
+            self.apply_config_to_partition(&mut partition);
             self.partitions
                 .insert(partition_id, IggySharedMut::new(partition));
             partition_ids.push(partition_id)
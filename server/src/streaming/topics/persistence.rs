@@ -22,6 +22,7 @@ use crate::streaming::topics::COMPONENT;
 use error_set::ErrContext;
 use iggy::error::IggyError;
 use iggy::locking::IggySharedMutFn;
+use iggy::utils::timestamp::IggyTimestamp;
 
 impl Topic {
     pub async fn load(&mut self, state: TopicState) -> Result<(), IggyError> {
@@ -61,10 +62,14 @@ impl Topic {
         Ok(saved_messages_number)
     }
 
-    pub async fn purge(&self) -> Result<(), IggyError> {
+    pub async fn purge(
+        &self,
+        keep_consumer_offsets: bool,
+        older_than: Option<IggyTimestamp>,
+    ) -> Result<(), IggyError> {
         for partition in self.get_partitions() {
             let mut partition = partition.write().await;
-            partition.purge().await?;
+            partition.purge(keep_consumer_offsets, older_than).await?;
         }
         Ok(())
     }
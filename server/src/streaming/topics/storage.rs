@@ -18,6 +18,7 @@
 
 use crate::state::system::TopicState;
 use crate::streaming::partitions::partition::Partition;
+use crate::streaming::partitions::recovery::PartitionRecovery;
 use crate::streaming::storage::TopicStorage;
 use crate::streaming::topics::consumer_group::ConsumerGroup;
 use crate::streaming::topics::topic::Topic;
@@ -58,8 +59,18 @@ impl TopicStorage for FileTopicStorage {
         topic.created_at = state.created_at;
         topic.message_expiry = message_expiry;
         topic.max_topic_size = max_topic_size;
+        topic.max_segments = state.max_segments;
+        topic.topic_config = state.config;
         topic.compression_algorithm = state.compression_algorithm;
         topic.replication_factor = state.replication_factor.unwrap_or(1);
+        topic.metadata = state.metadata.clone();
+        // Topics created before the scheme was recorded use the server default one.
+        topic.message_id_scheme =
+            Topic::get_message_id_scheme(state.message_id_scheme, &topic.config);
+        topic.cleanup_policy = Topic::get_cleanup_policy(state.cleanup_policy, &topic.config);
+        topic.allowed_producers = state.allowed_producers.clone();
+        topic.expiry_watchers = state.expiry_watchers.clone();
+        topic.delete_at = state.delete_at;
 
         let mut dir_entries = fs::read_dir(&topic.partitions_path).await
             .with_context(|| format!("Failed to read partition with ID: {} for stream with ID: {} for topic with ID: {} and path: {}",
@@ -82,6 +93,11 @@ impl TopicStorage for FileTopicStorage {
 
             let partition_id = partition_id.unwrap();
             let partition_state = state.partitions.get(&partition_id);
+            if partition_state.is_none() && topic.config.recovery.read_only {
+                warn!("Partition with ID: '{partition_id}' for stream with ID: '{}' and topic with ID: '{}' was not found in state, but exists on disk and will be skipped in read-only mode.", topic.stream_id, topic.topic_id);
+                continue;
+            }
+
             if partition_state.is_none() {
                 let stream_id = topic.stream_id;
                 let topic_id = topic.topic_id;
@@ -103,6 +119,7 @@ impl TopicStorage for FileTopicStorage {
                 topic.config.clone(),
                 topic.storage.clone(),
                 message_expiry,
+                topic.get_storage_compression_algorithm(),
                 topic.messages_count_of_parent_stream.clone(),
                 topic.messages_count.clone(),
                 topic.size_of_parent_stream.clone(),
@@ -128,6 +145,11 @@ impl TopicStorage for FileTopicStorage {
                 "All partitions for topic with ID: '{}' for stream with ID: '{}' found on disk were found in state.",
                 topic.topic_id, topic.stream_id
             );
+        } else if topic.config.recovery.read_only {
+            warn!(
+                "Partitions with IDs: '{missing_ids:?}' for topic with ID: '{}' for stream with ID: '{}' were not found on disk and will be skipped in read-only mode.",
+                topic.topic_id, topic.stream_id
+            );
         } else {
             error!(
                 "Partitions with IDs: '{missing_ids:?}' for topic with ID: '{topic_id}' for stream with ID: '{stream_id}' were not found on disk.",
@@ -157,6 +179,7 @@ impl TopicStorage for FileTopicStorage {
                     topic.config.clone(),
                     topic.storage.clone(),
                     message_expiry,
+                    topic.get_storage_compression_algorithm(),
                     topic.messages_count_of_parent_stream.clone(),
                     topic.messages_count.clone(),
                     topic.size_of_parent_stream.clone(),
@@ -185,9 +208,13 @@ impl TopicStorage for FileTopicStorage {
         let mut load_partitions = Vec::new();
         for mut partition in unloaded_partitions {
             let loaded_partitions = loaded_partitions.clone();
+            let partitions_loader = topic.storage.partitions_loader.clone();
             let partition_state = state.partitions.remove(&partition.partition_id).unwrap();
             let load_partition = tokio::spawn(async move {
-                match partition.load(partition_state).await {
+                let _permit = partitions_loader.acquire().await;
+                let result = partition.load(partition_state).await;
+                partitions_loader.complete();
+                match result {
                     Ok(_) => {
                         loaded_partitions.lock().await.push(partition);
                     }
@@ -202,19 +229,51 @@ impl TopicStorage for FileTopicStorage {
         }
 
         join_all(load_partitions).await;
-        for partition in loaded_partitions.lock().await.drain(..) {
-            topic
-                .partitions
-                .insert(partition.partition_id, IggySharedMut::new(partition));
+        for mut partition in loaded_partitions.lock().await.drain(..) {
+            let partition_id = partition.partition_id;
+            let pending_index_rebuilds = std::mem::take(&mut partition.pending_index_rebuilds);
+            let mut recovery = None;
+            if !pending_index_rebuilds.is_empty() {
+                let segments = partition
+                    .segments
+                    .iter()
+                    .filter(|segment| pending_index_rebuilds.contains(&segment.start_offset))
+                    .map(|segment| (segment.start_offset, segment.size_bytes))
+                    .collect::<Vec<_>>();
+                let partition_recovery = Arc::new(PartitionRecovery::new(
+                    &segments,
+                    topic.config.recovery.index_rebuild_write_rate,
+                ));
+                partition.recovery = Some(partition_recovery.clone());
+                recovery = Some(partition_recovery);
+            }
+            topic.apply_config_to_partition(&mut partition);
+            let partition = IggySharedMut::new(partition);
+            if let Some(recovery) = recovery {
+                topic.storage.index_rebuilds.schedule(
+                    partition.clone(),
+                    pending_index_rebuilds,
+                    recovery,
+                );
+            }
+            topic.partitions.insert(partition_id, partition);
         }
 
-        for consumer_group in state.consumer_groups.into_values() {
-            let consumer_group = ConsumerGroup::new(
+        for consumer_group_state in state.consumer_groups.into_values() {
+            let mut consumer_group = ConsumerGroup::new(
                 topic.topic_id,
-                consumer_group.id,
-                &consumer_group.name,
+                consumer_group_state.id,
+                &consumer_group_state.name,
                 topic.get_partitions_count(),
+                topic.config.topic.max_consumer_group_rebalances,
+                topic.config.topic.consumer_group_assignment_strategy,
             );
+            consumer_group.offset_recovery = Topic::get_offset_recovery_policy(
+                consumer_group_state.offset_recovery,
+                &topic.config,
+            );
+            consumer_group.dead_letter = consumer_group_state.dead_letter;
+            consumer_group.visibility_timeout = consumer_group_state.visibility_timeout;
             topic
                 .consumer_groups_ids
                 .insert(consumer_group.name.to_owned(), consumer_group.group_id);
@@ -223,6 +282,24 @@ impl TopicStorage for FileTopicStorage {
                 .insert(consumer_group.group_id, RwLock::new(consumer_group));
         }
 
+        match topic.recover_consumer_offsets().await {
+            Ok(recovered_offsets) if !recovered_offsets.is_empty() => {
+                warn!(
+                    "Recovered {} out of range consumer offsets for topic with ID: '{}' for stream with ID: '{}'.",
+                    recovered_offsets.len(),
+                    topic.topic_id,
+                    topic.stream_id
+                );
+            }
+            Ok(_) => {}
+            Err(error) => {
+                error!(
+                    "Failed to recover consumer offsets for topic with ID: '{}' for stream with ID: '{}'. {error}",
+                    topic.topic_id, topic.stream_id
+                );
+            }
+        }
+
         topic
             .load_messages_from_disk_to_cache()
             .await
@@ -21,12 +21,19 @@ use crate::streaming::partitions::partition::Partition;
 use crate::streaming::polling_consumer::PollingConsumer;
 use crate::streaming::storage::SystemStorage;
 use crate::streaming::topics::consumer_group::ConsumerGroup;
+use crate::streaming::topics::message_sizes::MessageSizeHistogram;
 use ahash::AHashMap;
 use core::fmt;
 use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::consumer::{Consumer, ConsumerKind};
+use iggy::consumer_groups::offset_recovery_policy::OffsetRecoveryPolicy;
 use iggy::error::IggyError;
 use iggy::locking::IggySharedMut;
+use iggy::messages::message_id_scheme::MessageIdScheme;
+use iggy::models::expiry_notification::ExpiryWatcher;
+use iggy::models::metadata::ResourceMetadata;
+use iggy::topics::cleanup_policy::CleanupPolicy;
+use iggy::topics::topic_config::TopicConfig;
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::expiry::IggyExpiry;
 use iggy::utils::sizeable::Sizeable;
@@ -50,6 +57,7 @@ pub struct Topic {
     pub(crate) size_of_parent_stream: Arc<AtomicU64>,
     pub(crate) messages_count_of_parent_stream: Arc<AtomicU64>,
     pub(crate) messages_count: Arc<AtomicU64>,
+    pub(crate) message_sizes: MessageSizeHistogram,
     pub(crate) segments_count_of_parent_stream: Arc<AtomicU32>,
     pub(crate) config: Arc<SystemConfig>,
     pub(crate) partitions: AHashMap<u32, IggySharedMut<Partition>>,
@@ -61,8 +69,17 @@ pub struct Topic {
     pub message_expiry: IggyExpiry,
     pub compression_algorithm: CompressionAlgorithm,
     pub max_topic_size: MaxTopicSize,
+    pub max_segments: u32,
+    /// The settings overriding the server defaults for the topic.
+    pub topic_config: TopicConfig,
     pub replication_factor: u8,
     pub created_at: IggyTimestamp,
+    pub metadata: ResourceMetadata,
+    pub message_id_scheme: MessageIdScheme,
+    pub cleanup_policy: CleanupPolicy,
+    pub allowed_producers: Vec<u32>,
+    pub expiry_watchers: Vec<ExpiryWatcher>,
+    pub delete_at: Option<IggyTimestamp>,
 }
 
 impl Topic {
@@ -126,6 +143,7 @@ impl Topic {
             size_of_parent_stream,
             messages_count_of_parent_stream,
             messages_count: Arc::new(AtomicU64::new(0)),
+            message_sizes: MessageSizeHistogram::default(),
             segments_count_of_parent_stream,
             consumer_groups: AHashMap::new(),
             consumer_groups_ids: AHashMap::new(),
@@ -133,10 +151,19 @@ impl Topic {
             current_partition_id: AtomicU32::new(1),
             message_expiry: Topic::get_message_expiry(message_expiry, &config),
             max_topic_size: Topic::get_max_topic_size(max_topic_size, &config)?,
+            max_segments: 0,
+            topic_config: TopicConfig::default()
+                .with_topic_settings(message_expiry, compression_algorithm),
             compression_algorithm,
             replication_factor,
+            message_id_scheme: config.message_id.scheme,
+            cleanup_policy: config.topic.cleanup_policy,
             config,
             created_at: IggyTimestamp::now(),
+            metadata: ResourceMetadata::default(),
+            allowed_producers: Vec::new(),
+            expiry_watchers: Vec::new(),
+            delete_at: None,
         };
 
         info!(
@@ -173,6 +200,46 @@ impl Topic {
         matches!(self.max_topic_size, MaxTopicSize::Unlimited)
     }
 
+    /// Checks whether the user can send the messages to the topic, which isn't marked for deletion, when its producers are restricted.
+    /// The restriction applies on top of the permissions, so it also covers the users allowed to write to the whole stream.
+    pub fn ensure_producer_allowed(&self, user_id: u32) -> Result<(), IggyError> {
+        if self.delete_at.is_some() {
+            return Err(IggyError::TopicMarkedForDeletion(
+                self.stream_id,
+                self.topic_id,
+            ));
+        }
+
+        if self.allowed_producers.is_empty() || self.allowed_producers.contains(&user_id) {
+            return Ok(());
+        }
+
+        Err(IggyError::ProducerNotAllowed(
+            user_id,
+            self.stream_id,
+            self.topic_id,
+        ))
+    }
+
+    /// Returns the time (in microseconds) at which the topic marked for deletion is going to be deleted, or '0'.
+    pub fn get_delete_at_micros(&self) -> u64 {
+        self.delete_at
+            .map(|delete_at| delete_at.as_micros())
+            .unwrap_or_default()
+    }
+
+    /// Returns the algorithm used for storing the messages, which is the one preferred by the topic
+    /// (if set and overriding is allowed) or the server default one.
+    pub fn get_storage_compression_algorithm(&self) -> CompressionAlgorithm {
+        match self.compression_algorithm {
+            CompressionAlgorithm::None => self.config.compression.default_algorithm,
+            compression_algorithm if self.config.compression.allow_override => {
+                compression_algorithm
+            }
+            _ => self.config.compression.default_algorithm,
+        }
+    }
+
     pub fn get_partitions(&self) -> Vec<IggySharedMut<Partition>> {
         self.partitions.values().cloned().collect()
     }
@@ -254,6 +321,40 @@ impl Topic {
             _ => message_expiry,
         }
     }
+
+    pub fn get_message_id_scheme(
+        message_id_scheme: MessageIdScheme,
+        config: &SystemConfig,
+    ) -> MessageIdScheme {
+        match message_id_scheme {
+            MessageIdScheme::ServerDefault => config.message_id.scheme,
+            _ => message_id_scheme,
+        }
+    }
+
+    pub fn get_cleanup_policy(
+        cleanup_policy: CleanupPolicy,
+        config: &SystemConfig,
+    ) -> CleanupPolicy {
+        match cleanup_policy {
+            CleanupPolicy::ServerDefault => config.topic.cleanup_policy,
+            _ => cleanup_policy,
+        }
+    }
+
+    pub fn get_offset_recovery_policy(
+        offset_recovery: OffsetRecoveryPolicy,
+        config: &SystemConfig,
+    ) -> OffsetRecoveryPolicy {
+        match offset_recovery {
+            OffsetRecoveryPolicy::ServerDefault => config.topic.consumer_offset_recovery,
+            _ => offset_recovery,
+        }
+    }
+
+    pub fn is_compacted(&self) -> bool {
+        self.cleanup_policy == CleanupPolicy::Compact
+    }
 }
 
 impl Sizeable for Topic {
@@ -282,6 +383,7 @@ impl fmt::Display for Topic {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::configs::system::CompressionConfig;
     use crate::streaming::persistence::persister::{FileWithSyncPersister, PersisterKind};
     use iggy::locking::IggySharedMutFn;
     use std::str::FromStr;
@@ -344,4 +446,86 @@ mod tests {
             assert_eq!(partition.segments.len(), 1);
         }
     }
+
+    #[tokio::test]
+    async fn storage_compression_algorithm_should_respect_override_setting() {
+        for (allow_override, expected_algorithm) in [
+            (true, CompressionAlgorithm::Gzip),
+            (false, CompressionAlgorithm::None),
+        ] {
+            let tempdir = tempfile::TempDir::new().unwrap();
+            let config = Arc::new(SystemConfig {
+                path: tempdir.path().to_str().unwrap().to_string(),
+                compression: CompressionConfig {
+                    allow_override,
+                    default_algorithm: CompressionAlgorithm::None,
+                },
+                ..Default::default()
+            });
+            let storage = Arc::new(SystemStorage::new(
+                config.clone(),
+                Arc::new(PersisterKind::FileWithSync(FileWithSyncPersister {})),
+            ));
+
+            let topic = Topic::create(
+                1,
+                2,
+                "test",
+                1,
+                config,
+                storage,
+                Arc::new(AtomicU64::new(0)),
+                Arc::new(AtomicU64::new(0)),
+                Arc::new(AtomicU32::new(0)),
+                IggyExpiry::NeverExpire,
+                CompressionAlgorithm::Gzip,
+                MaxTopicSize::Unlimited,
+                1,
+            )
+            .await
+            .unwrap();
+
+            assert_eq!(
+                topic.get_storage_compression_algorithm(),
+                expected_algorithm
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn should_allow_only_listed_producers_when_restricted() {
+        let tempdir = tempfile::TempDir::new().unwrap();
+        let config = Arc::new(SystemConfig {
+            path: tempdir.path().to_str().unwrap().to_string(),
+            ..Default::default()
+        });
+        let storage = Arc::new(SystemStorage::new(
+            config.clone(),
+            Arc::new(PersisterKind::FileWithSync(FileWithSyncPersister {})),
+        ));
+        let mut topic = Topic::empty(
+            1,
+            2,
+            "test",
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU32::new(0)),
+            config,
+            storage,
+        )
+        .await;
+
+        assert!(topic.ensure_producer_allowed(5).is_ok());
+        topic.allowed_producers = vec![3];
+        assert!(topic.ensure_producer_allowed(3).is_ok());
+        assert!(matches!(
+            topic.ensure_producer_allowed(5),
+            Err(IggyError::ProducerNotAllowed(5, 1, 2))
+        ));
+        topic.delete_at = Some(IggyTimestamp::now());
+        assert!(matches!(
+            topic.ensure_producer_allowed(3),
+            Err(IggyError::TopicMarkedForDeletion(1, 2))
+        ));
+    }
 }
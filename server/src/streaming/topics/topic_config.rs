@@ -0,0 +1,174 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::cache::buffer::SmartCache;
+use crate::streaming::cache::memory_tracker::CacheMemoryTracker;
+use crate::streaming::partitions::partition::Partition;
+use crate::streaming::topics::topic::Topic;
+use iggy::error::IggyError;
+use iggy::locking::IggySharedMutFn;
+use iggy::topics::topic_config::TopicConfig;
+use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_size::MaxTopicSize;
+
+impl Topic {
+    /// Returns the maximum size of the segment, overridden for the topic or the server default one.
+    pub fn get_segment_size(&self) -> IggyByteSize {
+        self.topic_config
+            .segment_size
+            .unwrap_or(self.config.segment.size)
+    }
+
+    /// Returns whether the messages of the topic are cached, the cache can only be disabled for the topic.
+    pub fn is_cache_enabled(&self) -> bool {
+        self.config.cache.enabled && self.topic_config.cache_enabled.unwrap_or(true)
+    }
+
+    /// Replaces the settings overriding the server defaults and applies them to the existing partitions.
+    pub async fn update_config(&mut self, topic_config: TopicConfig) -> Result<(), IggyError> {
+        if topic_config.cache_enabled == Some(true) && !self.config.cache.enabled {
+            return Err(IggyError::TopicCacheDisabled(self.stream_id, self.topic_id));
+        }
+
+        let segment_size = topic_config
+            .segment_size
+            .unwrap_or(self.config.segment.size);
+        if let MaxTopicSize::Custom(max_topic_size) = self.max_topic_size {
+            if max_topic_size.as_bytes_u64() < segment_size.as_bytes_u64() {
+                return Err(IggyError::InvalidTopicSize(
+                    self.max_topic_size,
+                    segment_size,
+                ));
+            }
+        }
+
+        self.topic_config = topic_config;
+        self.message_expiry = Topic::get_message_expiry(
+            topic_config
+                .message_expiry
+                .unwrap_or(IggyExpiry::ServerDefault),
+            &self.config,
+        );
+        self.compression_algorithm = topic_config.compression_algorithm.unwrap_or_default();
+        self.apply_config_to_partitions().await;
+        Ok(())
+    }
+
+    /// Applies the message expiry, the compression algorithm and the config overrides of the topic to all of its partitions.
+    pub(crate) async fn apply_config_to_partitions(&self) {
+        for partition in self.partitions.values() {
+            let mut partition = partition.write().await;
+            self.apply_config_to_partition(&mut partition);
+        }
+    }
+
+    /// Applies the message expiry, the compression algorithm and the config overrides of the topic to the partition.
+    /// The closed segments keep their size, while the open one can grow up to the new segment size.
+    pub(crate) fn apply_config_to_partition(&self, partition: &mut Partition) {
+        let storage_compression_algorithm = self.get_storage_compression_algorithm();
+        let segment_size = self.get_segment_size();
+        partition.message_expiry = self.message_expiry;
+        partition.compression_algorithm = storage_compression_algorithm;
+        partition.segment_size = segment_size;
+        for segment in partition.segments.iter_mut() {
+            // The segments restored from the archive never expire.
+            if !partition.archived_segments.iter().any(|archived_segment| {
+                archived_segment.restored && archived_segment.start_offset == segment.start_offset
+            }) {
+                segment.message_expiry = self.message_expiry;
+            }
+            segment.compression_algorithm = storage_compression_algorithm;
+            if !segment.is_closed {
+                segment.max_size_bytes = segment_size;
+            }
+        }
+
+        match self.is_cache_enabled() {
+            true if partition.cache.is_none() => {
+                partition.cached_memory_tracker = CacheMemoryTracker::get_instance();
+                partition.cache = Some(SmartCache::new());
+            }
+            false => {
+                if let Some(mut cache) = partition.cache.take() {
+                    cache.purge();
+                }
+                partition.cached_memory_tracker = None;
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configs::system::SystemConfig;
+    use crate::streaming::persistence::persister::{FileWithSyncPersister, PersisterKind};
+    use crate::streaming::storage::SystemStorage;
+    use std::sync::atomic::{AtomicU32, AtomicU64};
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn config_overrides_should_be_applied_to_partitions() {
+        let tempdir = tempfile::TempDir::new().unwrap();
+        let config = Arc::new(SystemConfig {
+            path: tempdir.path().to_str().unwrap().to_string(),
+            ..Default::default()
+        });
+        let storage = Arc::new(SystemStorage::new(
+            config.clone(),
+            Arc::new(PersisterKind::FileWithSync(FileWithSyncPersister {})),
+        ));
+        let mut topic = Topic::create(
+            1,
+            2,
+            "test",
+            1,
+            config.clone(),
+            storage,
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU32::new(0)),
+            IggyExpiry::NeverExpire,
+            Default::default(),
+            MaxTopicSize::ServerDefault,
+            1,
+        )
+        .await
+        .unwrap();
+
+        let segment_size = IggyByteSize::from(1_000_000);
+        topic
+            .update_config(TopicConfig {
+                segment_size: Some(segment_size),
+                cache_enabled: Some(false),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(topic.get_segment_size(), segment_size);
+        assert_eq!(topic.message_expiry, config.segment.message_expiry);
+        let partition = topic.get_partition(1).unwrap();
+        let partition = partition.read().await;
+        assert_eq!(partition.segment_size, segment_size);
+        assert_eq!(partition.segments[0].max_size_bytes, segment_size);
+        assert!(partition.cache.is_none());
+    }
+}
@@ -29,15 +29,38 @@ pub struct Permissioner {
     pub(super) users_that_can_send_messages_to_all_streams: AHashSet<UserId>,
     pub(super) users_that_can_poll_messages_from_specific_streams: AHashSet<(UserId, u32)>,
     pub(super) users_that_can_send_messages_to_specific_streams: AHashSet<(UserId, u32)>,
+    pub(super) users_namespaces: AHashMap<UserId, u32>,
+    pub(super) streams_namespaces: AHashMap<u32, u32>,
 }
 
 impl Permissioner {
     pub fn init(&mut self, users: &[&User]) {
         for user in users {
             self.init_permissions_for_user(user.id, user.permissions.clone());
+            self.set_user_namespace(user.id, user.namespace_id);
         }
     }
 
+    /// Assigns the user to the namespace, the namespaced user can only access the streams and the users of its namespace.
+    pub fn set_user_namespace(&mut self, user_id: UserId, namespace_id: Option<u32>) {
+        match namespace_id {
+            Some(namespace_id) => self.users_namespaces.insert(user_id, namespace_id),
+            None => self.users_namespaces.remove(&user_id),
+        };
+    }
+
+    /// Assigns the stream to the namespace, only the users of the namespace or outside any namespace can access it.
+    pub fn set_stream_namespace(&mut self, stream_id: u32, namespace_id: Option<u32>) {
+        match namespace_id {
+            Some(namespace_id) => self.streams_namespaces.insert(stream_id, namespace_id),
+            None => self.streams_namespaces.remove(&stream_id),
+        };
+    }
+
+    pub fn get_user_namespace(&self, user_id: UserId) -> Option<u32> {
+        self.users_namespaces.get(&user_id).copied()
+    }
+
     pub fn init_permissions_for_user(&mut self, user_id: UserId, permissions: Option<Permissions>) {
         if permissions.is_none() {
             return;
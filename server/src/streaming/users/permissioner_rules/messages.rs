@@ -26,6 +26,7 @@ impl Permissioner {
         stream_id: u32,
         topic_id: u32,
     ) -> Result<(), IggyError> {
+        self.ensure_in_namespace(user_id, stream_id)?;
         if self
             .users_that_can_poll_messages_from_all_streams
             .contains(&user_id)
@@ -86,6 +87,7 @@ impl Permissioner {
         stream_id: u32,
         topic_id: u32,
     ) -> Result<(), IggyError> {
+        self.ensure_in_namespace(user_id, stream_id)?;
         if self
             .users_that_can_send_messages_to_all_streams
             .contains(&user_id)
@@ -19,6 +19,7 @@
 mod consumer_groups;
 pub mod consumer_offsets;
 mod messages;
+mod namespaces;
 mod partitions;
 mod streams;
 mod system;
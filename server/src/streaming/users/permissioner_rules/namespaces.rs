@@ -0,0 +1,100 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::users::permissioner::Permissioner;
+use iggy::error::IggyError;
+
+impl Permissioner {
+    pub fn get_namespace(&self, user_id: u32, namespace_id: u32) -> Result<(), IggyError> {
+        if self.get_user_namespace(user_id) == Some(namespace_id) {
+            return Ok(());
+        }
+
+        self.get_namespaces(user_id)
+    }
+
+    pub fn get_namespaces(&self, user_id: u32) -> Result<(), IggyError> {
+        self.ensure_not_namespaced(user_id)?;
+        if let Some(global_permissions) = self.users_permissions.get(&user_id) {
+            if global_permissions.manage_servers || global_permissions.read_servers {
+                return Ok(());
+            }
+        }
+
+        Err(IggyError::Unauthorized)
+    }
+
+    pub fn create_namespace(&self, user_id: u32) -> Result<(), IggyError> {
+        self.manage_namespaces(user_id)
+    }
+
+    pub fn update_namespace(&self, user_id: u32) -> Result<(), IggyError> {
+        self.manage_namespaces(user_id)
+    }
+
+    pub fn delete_namespace(&self, user_id: u32) -> Result<(), IggyError> {
+        self.manage_namespaces(user_id)
+    }
+
+    /// The namespaced user can only manage the users of its own namespace.
+    pub fn ensure_same_namespace(&self, user_id: u32, other_user_id: u32) -> Result<(), IggyError> {
+        match self.get_user_namespace(user_id) {
+            Some(namespace_id) if self.get_user_namespace(other_user_id) != Some(namespace_id) => {
+                Err(IggyError::Unauthorized)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// The namespaced user can only access the streams of its own namespace,
+    /// while the users outside any namespace are only limited by their permissions.
+    pub(super) fn ensure_in_namespace(
+        &self,
+        user_id: u32,
+        stream_id: u32,
+    ) -> Result<(), IggyError> {
+        match self.get_user_namespace(user_id) {
+            Some(namespace_id)
+                if self.streams_namespaces.get(&stream_id) != Some(&namespace_id) =>
+            {
+                Err(IggyError::Unauthorized)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// The server-wide operations are not available to the namespaced users, regardless of their permissions.
+    pub(super) fn ensure_not_namespaced(&self, user_id: u32) -> Result<(), IggyError> {
+        if self.users_namespaces.contains_key(&user_id) {
+            return Err(IggyError::Unauthorized);
+        }
+
+        Ok(())
+    }
+
+    fn manage_namespaces(&self, user_id: u32) -> Result<(), IggyError> {
+        self.ensure_not_namespaced(user_id)?;
+        if let Some(global_permissions) = self.users_permissions.get(&user_id) {
+            if global_permissions.manage_servers {
+                return Ok(());
+            }
+        }
+
+        Err(IggyError::Unauthorized)
+    }
+}
@@ -37,4 +37,41 @@ impl Permissioner {
     ) -> Result<(), IggyError> {
         self.update_topic(user_id, stream_id, topic_id)
     }
+
+    pub fn restore_archived_segments(&self, user_id: u32) -> Result<(), IggyError> {
+        self.ensure_not_namespaced(user_id)?;
+        if let Some(global_permissions) = self.users_permissions.get(&user_id) {
+            if global_permissions.manage_servers {
+                return Ok(());
+            }
+        }
+
+        Err(IggyError::Unauthorized)
+    }
+
+    pub fn flush_partition(&self, user_id: u32) -> Result<(), IggyError> {
+        self.ensure_not_namespaced(user_id)?;
+        if let Some(global_permissions) = self.users_permissions.get(&user_id) {
+            if global_permissions.manage_servers {
+                return Ok(());
+            }
+        }
+
+        Err(IggyError::Unauthorized)
+    }
+
+    pub fn read_segment_range(
+        &self,
+        user_id: u32,
+        stream_id: u32,
+        topic_id: u32,
+    ) -> Result<(), IggyError> {
+        if let Some(global_permissions) = self.users_permissions.get(&user_id) {
+            if global_permissions.manage_servers || global_permissions.read_servers {
+                return self.poll_messages(user_id, stream_id, topic_id);
+            }
+        }
+
+        Err(IggyError::Unauthorized)
+    }
 }
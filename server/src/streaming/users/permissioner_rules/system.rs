@@ -32,7 +32,68 @@ impl Permissioner {
         self.get_server_info(user_id)
     }
 
+    pub fn get_replay_jobs(&self, user_id: u32) -> Result<(), IggyError> {
+        self.get_server_info(user_id)
+    }
+
+    pub fn cancel_replay_job(&self, user_id: u32) -> Result<(), IggyError> {
+        self.ensure_not_namespaced(user_id)?;
+        if let Some(global_permissions) = self.users_permissions.get(&user_id) {
+            if global_permissions.manage_servers {
+                return Ok(());
+            }
+        }
+
+        Err(IggyError::Unauthorized)
+    }
+
+    pub fn set_maintenance_mode(&self, user_id: u32) -> Result<(), IggyError> {
+        self.ensure_not_namespaced(user_id)?;
+        if let Some(global_permissions) = self.users_permissions.get(&user_id) {
+            if global_permissions.manage_servers {
+                return Ok(());
+            }
+        }
+
+        Err(IggyError::Unauthorized)
+    }
+
+    pub fn create_backup(&self, user_id: u32) -> Result<(), IggyError> {
+        self.ensure_not_namespaced(user_id)?;
+        if let Some(global_permissions) = self.users_permissions.get(&user_id) {
+            if global_permissions.manage_servers {
+                return Ok(());
+            }
+        }
+
+        Err(IggyError::Unauthorized)
+    }
+
+    pub fn get_unsaved_state(&self, user_id: u32) -> Result<(), IggyError> {
+        self.get_server_info(user_id)
+    }
+
+    pub fn get_audit_log(&self, user_id: u32) -> Result<(), IggyError> {
+        self.get_server_info(user_id)
+    }
+
+    pub fn get_push_subscriptions(&self, user_id: u32) -> Result<(), IggyError> {
+        self.get_server_info(user_id)
+    }
+
+    pub fn delete_push_subscription(&self, user_id: u32) -> Result<(), IggyError> {
+        self.ensure_not_namespaced(user_id)?;
+        if let Some(global_permissions) = self.users_permissions.get(&user_id) {
+            if global_permissions.manage_servers {
+                return Ok(());
+            }
+        }
+
+        Err(IggyError::Unauthorized)
+    }
+
     fn get_server_info(&self, user_id: u32) -> Result<(), IggyError> {
+        self.ensure_not_namespaced(user_id)?;
         if let Some(global_permissions) = self.users_permissions.get(&user_id) {
             if global_permissions.manage_servers || global_permissions.read_servers {
                 return Ok(());
@@ -21,6 +21,7 @@ use iggy::error::IggyError;
 
 impl Permissioner {
     pub fn get_topic(&self, user_id: u32, stream_id: u32, topic_id: u32) -> Result<(), IggyError> {
+        self.ensure_in_namespace(user_id, stream_id)?;
         if let Some(global_permissions) = self.users_permissions.get(&user_id) {
             if global_permissions.read_streams
                 || global_permissions.manage_streams
@@ -50,6 +51,7 @@ impl Permissioner {
     }
 
     pub fn get_topics(&self, user_id: u32, stream_id: u32) -> Result<(), IggyError> {
+        self.ensure_in_namespace(user_id, stream_id)?;
         if let Some(global_permissions) = self.users_permissions.get(&user_id) {
             if global_permissions.read_streams
                 || global_permissions.manage_streams
@@ -79,6 +81,7 @@ impl Permissioner {
     }
 
     pub fn create_topic(&self, user_id: u32, stream_id: u32) -> Result<(), IggyError> {
+        self.ensure_in_namespace(user_id, stream_id)?;
         if let Some(global_permissions) = self.users_permissions.get(&user_id) {
             if global_permissions.manage_streams || global_permissions.manage_topics {
                 return Ok(());
@@ -123,6 +126,7 @@ impl Permissioner {
     }
 
     fn manage_topic(&self, user_id: u32, stream_id: u32, topic_id: u32) -> Result<(), IggyError> {
+        self.ensure_in_namespace(user_id, stream_id)?;
         if let Some(global_permissions) = self.users_permissions.get(&user_id) {
             if global_permissions.manage_streams || global_permissions.manage_topics {
                 return Ok(());
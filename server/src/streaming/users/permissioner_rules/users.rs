@@ -48,6 +48,24 @@ impl Permissioner {
         self.manager_users(user_id)
     }
 
+    pub fn update_user_quotas(&self, user_id: u32) -> Result<(), IggyError> {
+        self.manager_users(user_id)
+    }
+
+    /// The impersonated user must not be allowed to manage the users itself.
+    pub fn impersonate_user(
+        &self,
+        user_id: u32,
+        impersonated_user_id: u32,
+    ) -> Result<(), IggyError> {
+        self.manager_users(user_id)?;
+        if self.manager_users(impersonated_user_id).is_ok() {
+            return Err(IggyError::Unauthorized);
+        }
+
+        Ok(())
+    }
+
     fn manager_users(&self, user_id: u32) -> Result<(), IggyError> {
         if let Some(global_permissions) = self.users_permissions.get(&user_id) {
             if global_permissions.manage_users {
@@ -22,6 +22,7 @@ use ahash::AHashMap;
 use iggy::models::user_status::UserStatus;
 use iggy::models::{permissions::Permissions, user_info::UserId};
 use iggy::users::defaults::*;
+use iggy::users::user_quotas::UserQuotas;
 use iggy::utils::timestamp::IggyTimestamp;
 
 #[derive(Debug)]
@@ -33,6 +34,8 @@ pub struct User {
     pub created_at: IggyTimestamp,
     pub permissions: Option<Permissions>,
     pub personal_access_tokens: AHashMap<String, PersonalAccessToken>,
+    pub quotas: UserQuotas,
+    pub namespace_id: Option<u32>,
 }
 
 impl Default for User {
@@ -45,6 +48,8 @@ impl Default for User {
             created_at: IggyTimestamp::now(),
             permissions: None,
             personal_access_tokens: AHashMap::new(),
+            quotas: UserQuotas::default(),
+            namespace_id: None,
         }
     }
 }
@@ -88,6 +93,8 @@ impl User {
             status,
             permissions,
             personal_access_tokens: AHashMap::new(),
+            quotas: UserQuotas::default(),
+            namespace_id: None,
         }
     }
 
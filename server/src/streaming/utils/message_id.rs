@@ -0,0 +1,96 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::utils::random_id;
+use iggy::messages::message_id_scheme::MessageIdScheme;
+use iggy::utils::timestamp::IggyTimestamp;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+pub const MAX_SNOWFLAKE_NODE_ID: u16 = (1 << SNOWFLAKE_NODE_ID_BITS) - 1;
+// 2024-01-01T00:00:00Z, gives ~69 years of IDs with the 41-bit timestamp.
+const SNOWFLAKE_EPOCH_MILLIS: u64 = 1_704_067_200_000;
+const SNOWFLAKE_NODE_ID_BITS: u64 = 10;
+const SNOWFLAKE_SEQUENCE_BITS: u64 = 12;
+const SNOWFLAKE_SEQUENCE_MASK: u64 = (1 << SNOWFLAKE_SEQUENCE_BITS) - 1;
+
+// The last generated timestamp and sequence, shared by all the topics using the snowflake scheme.
+static SNOWFLAKE_STATE: AtomicU64 = AtomicU64::new(0);
+
+/// Generates the message ID using the given scheme, `ServerDefault` falls back to UUID.
+pub fn generate(scheme: MessageIdScheme, node_id: u16) -> u128 {
+    match scheme {
+        MessageIdScheme::ServerDefault | MessageIdScheme::Uuid => random_id::get_uuid(),
+        MessageIdScheme::Snowflake => get_snowflake(node_id) as u128,
+        MessageIdScheme::Ulid => random_id::get_ulid().0,
+    }
+}
+
+/// Returns the next snowflake ID: 41 bits of milliseconds since the custom epoch, 10 bits of node ID
+/// and 12 bits of sequence. When the sequence is exhausted within a millisecond, the timestamp is
+/// moved forward, so the IDs are always unique and increasing on the node.
+fn get_snowflake(node_id: u16) -> u64 {
+    let now = (IggyTimestamp::now().as_micros() / 1000).saturating_sub(SNOWFLAKE_EPOCH_MILLIS);
+    let mut current = SNOWFLAKE_STATE.load(Ordering::Acquire);
+    loop {
+        let next = (current + 1).max(now << SNOWFLAKE_SEQUENCE_BITS);
+        match SNOWFLAKE_STATE.compare_exchange_weak(
+            current,
+            next,
+            Ordering::AcqRel,
+            Ordering::Acquire,
+        ) {
+            Ok(_) => {
+                let timestamp = next >> SNOWFLAKE_SEQUENCE_BITS;
+                let sequence = next & SNOWFLAKE_SEQUENCE_MASK;
+                let node_id = (node_id & MAX_SNOWFLAKE_NODE_ID) as u64;
+                return (timestamp << (SNOWFLAKE_NODE_ID_BITS + SNOWFLAKE_SEQUENCE_BITS))
+                    | (node_id << SNOWFLAKE_SEQUENCE_BITS)
+                    | sequence;
+            }
+            Err(actual) => current = actual,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snowflake_ids_should_be_unique_and_increasing() {
+        let mut previous = 0;
+        for _ in 0..10_000 {
+            let id = generate(MessageIdScheme::Snowflake, 7);
+            assert!(id > previous);
+            assert!(id <= u64::MAX as u128);
+            assert_eq!(
+                (id as u64 >> SNOWFLAKE_SEQUENCE_BITS) & MAX_SNOWFLAKE_NODE_ID as u64,
+                7
+            );
+            previous = id;
+        }
+    }
+
+    #[test]
+    fn ulid_ids_should_be_time_sortable() {
+        let first = generate(MessageIdScheme::Ulid, 0);
+        std::thread::sleep(std::time::Duration::from_millis(2));
+        let second = generate(MessageIdScheme::Ulid, 0);
+        assert!(second > first);
+    }
+}
@@ -20,4 +20,5 @@ pub mod crypto;
 pub mod file;
 pub mod hash;
 pub mod head_tail_buf;
+pub mod message_id;
 pub mod random_id;
@@ -19,26 +19,51 @@
 use crate::binary::{command, sender::SenderKind};
 use crate::command::ServerCommand;
 use crate::server_error::ConnectionError;
+use crate::streaming::clients::transport_limiter::TransportLimiter;
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::SharedSystem;
 use bytes::{BufMut, BytesMut};
 use iggy::bytes_serializable::BytesSerializable;
 use iggy::error::IggyError;
+use iggy::utils::checksum;
+use iggy::utils::duration::IggyDuration;
 use iggy::validatable::Validatable;
 use std::io::ErrorKind;
 use std::sync::Arc;
-use tracing::{debug, error, info};
+use tokio::time::timeout;
+use tracing::{debug, error, info, warn};
 
 const INITIAL_BYTES_LENGTH: usize = 4;
+const CHECKSUM_BYTES_LENGTH: usize = 4;
 
 pub(crate) async fn handle_connection(
     session: Arc<Session>,
     sender: &mut SenderKind,
     system: SharedSystem,
+    limiter: &Arc<TransportLimiter>,
+    heartbeat_timeout: Option<IggyDuration>,
 ) -> Result<(), ConnectionError> {
     let mut initial_buffer = [0u8; INITIAL_BYTES_LENGTH];
     loop {
-        let read_length = match sender.read(&mut initial_buffer).await {
+        let read_result = match heartbeat_timeout {
+            Some(heartbeat_timeout) => {
+                match timeout(
+                    heartbeat_timeout.get_duration(),
+                    sender.read(&mut initial_buffer),
+                )
+                .await
+                {
+                    Ok(read_result) => read_result,
+                    Err(_) => {
+                        // Nothing, not even a heartbeat, has been received, so the peer is considered dead.
+                        warn!("Closing the connection for session: {session}, no frame has been received within the heartbeat timeout: {heartbeat_timeout}.");
+                        return Err(ConnectionError::from(IggyError::ConnectionClosed));
+                    }
+                }
+            }
+            None => sender.read(&mut initial_buffer).await,
+        };
+        let read_length = match read_result {
             Ok(read_length) => read_length,
             Err(error) => {
                 if error.as_code() == IggyError::ConnectionClosed.as_code() {
@@ -59,9 +84,30 @@ pub(crate) async fn handle_connection(
 
         let length = u32::from_le_bytes(initial_buffer);
         debug!("Received a TCP request, length: {length}");
+        if let Err(error) = limiter.validate_frame_size(length as u64) {
+            // The frame is not read at all, so the connection can't be used anymore.
+            warn!("Closing the connection for session: {session}. {error}");
+            sender.send_error_response(error).await?;
+            return Err(ConnectionError::from(IggyError::ConnectionClosed));
+        }
+
         let mut command_buffer = BytesMut::with_capacity(length as usize);
         command_buffer.put_bytes(0, length as usize);
         sender.read(&mut command_buffer).await?;
+        if sender.frame_checksums() {
+            let mut checksum_buffer = [0u8; CHECKSUM_BYTES_LENGTH];
+            sender.read(&mut checksum_buffer).await?;
+            let checksum = u32::from_le_bytes(checksum_buffer);
+            let expected_checksum = checksum::calculate_frame(&[&initial_buffer, &command_buffer]);
+            if checksum != expected_checksum {
+                // The length might have been corrupted as well, so the next frame can't be located reliably.
+                let error = IggyError::InvalidFrameChecksum(checksum, expected_checksum);
+                warn!("Closing the connection for session: {session}. {error}");
+                sender.send_error_response(error).await?;
+                return Err(ConnectionError::from(IggyError::ConnectionClosed));
+            }
+        }
+
         let command = ServerCommand::from_bytes(command_buffer.freeze());
         if command.is_err() {
             sender
@@ -76,6 +122,15 @@ pub(crate) async fn handle_connection(
             continue;
         }
 
+        let _request_permit = match limiter.try_acquire_request() {
+            Ok(permit) => permit,
+            Err(error) => {
+                warn!("Rejected a TCP command: {command} for session: {session}. {error}");
+                sender.send_error_response(error).await?;
+                continue;
+            }
+        };
+
         debug!("Received a TCP command: {command}, payload size: {length}");
         command::handle(command, sender, &session, system.clone()).await?;
     }
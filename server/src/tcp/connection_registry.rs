@@ -0,0 +1,85 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+/// Registry of the connections accepted by a single acceptor (accept loop) of the TCP server.
+/// Every acceptor owns its registry, so accepting and closing the connections on different acceptors
+/// never contends on a shared lock.
+#[derive(Debug)]
+pub struct ConnectionRegistry {
+    acceptor_id: u32,
+    connections: Mutex<HashMap<u32, SocketAddr>>,
+    accepted_connections: AtomicU64,
+}
+
+impl ConnectionRegistry {
+    pub fn new(acceptor_id: u32) -> Self {
+        Self {
+            acceptor_id,
+            connections: Mutex::new(HashMap::new()),
+            accepted_connections: AtomicU64::new(0),
+        }
+    }
+
+    pub fn acceptor_id(&self) -> u32 {
+        self.acceptor_id
+    }
+
+    /// Registers the connection of the client and returns the number of the currently open connections.
+    pub fn register(&self, client_id: u32, address: SocketAddr) -> usize {
+        self.accepted_connections.fetch_add(1, Ordering::Relaxed);
+        let mut connections = self.connections.lock().unwrap();
+        connections.insert(client_id, address);
+        connections.len()
+    }
+
+    pub fn unregister(&self, client_id: u32) {
+        self.connections.lock().unwrap().remove(&client_id);
+    }
+
+    pub fn connections_count(&self) -> usize {
+        self.connections.lock().unwrap().len()
+    }
+
+    pub fn accepted_connections(&self) -> u64 {
+        self.accepted_connections.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_track_open_and_accepted_connections() {
+        let registry = ConnectionRegistry::new(1);
+        let address: SocketAddr = "127.0.0.1:1234".parse().unwrap();
+
+        assert_eq!(registry.register(1, address), 1);
+        assert_eq!(registry.register(2, address), 2);
+        registry.unregister(1);
+
+        assert_eq!(registry.acceptor_id(), 1);
+        assert_eq!(registry.connections_count(), 1);
+        assert_eq!(registry.accepted_connections(), 2);
+    }
+}
@@ -17,6 +17,7 @@
  */
 
 pub mod connection_handler;
+pub mod connection_registry;
 pub mod sender;
 pub mod tcp_listener;
 pub mod tcp_sender;
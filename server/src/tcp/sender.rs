@@ -16,7 +16,10 @@
  * under the License.
  */
 
+use bytes::Bytes;
 use iggy::error::IggyError;
+use iggy::utils::checksum;
+use std::io::IoSlice;
 use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
 use tracing::debug;
 
@@ -39,44 +42,206 @@ where
     }
 }
 
-pub(crate) async fn send_empty_ok_response<T>(stream: &mut T) -> Result<(), IggyError>
+pub(crate) async fn send_empty_ok_response<T>(
+    stream: &mut T,
+    frame_checksums: bool,
+) -> Result<(), IggyError>
 where
     T: AsyncRead + AsyncWrite + Unpin,
 {
-    send_ok_response(stream, &[]).await
+    send_ok_response(stream, &[], frame_checksums).await
 }
 
-pub(crate) async fn send_ok_response<T>(stream: &mut T, payload: &[u8]) -> Result<(), IggyError>
+pub(crate) async fn send_ok_response<T>(
+    stream: &mut T,
+    payload: &[u8],
+    frame_checksums: bool,
+) -> Result<(), IggyError>
 where
     T: AsyncRead + AsyncWrite + Unpin,
 {
-    send_response(stream, STATUS_OK, payload).await
+    send_response(stream, STATUS_OK, payload, frame_checksums).await
+}
+
+pub(crate) async fn send_ok_response_vectored<T>(
+    stream: &mut T,
+    payload: &[Bytes],
+    frame_checksums: bool,
+) -> Result<(), IggyError>
+where
+    T: AsyncRead + AsyncWrite + Unpin,
+{
+    let length = (payload.iter().map(Bytes::len).sum::<usize>() as u32).to_le_bytes();
+    let checksum = frame_checksums.then(|| {
+        let mut parts: Vec<&[u8]> = Vec::with_capacity(payload.len() + 2);
+        parts.push(STATUS_OK);
+        parts.push(&length);
+        parts.extend(payload.iter().map(|bytes| bytes.as_ref()));
+        checksum::calculate_frame(&parts).to_le_bytes()
+    });
+    let mut slices = Vec::with_capacity(payload.len() + 3);
+    slices.push(IoSlice::new(STATUS_OK));
+    slices.push(IoSlice::new(&length));
+    slices.extend(payload.iter().map(|bytes| IoSlice::new(bytes)));
+    if let Some(checksum) = &checksum {
+        slices.push(IoSlice::new(checksum));
+    }
+    debug!("Sending vectored response with {} slices...", slices.len());
+    write_all_vectored(stream, &mut slices).await?;
+    debug!("Sent vectored response with status: {:?}", STATUS_OK);
+    Ok(())
+}
+
+/// Sends the payload consisting of multiple slices copied into a single frame, for the TLS streams
+/// which would otherwise encrypt and send every slice as a separate TLS record.
+/// The copy is cheaper than the additional records, as the payload has to be encrypted into a new buffer anyway.
+pub(crate) async fn send_ok_response_coalesced<T>(
+    stream: &mut T,
+    payload: &[Bytes],
+    frame_checksums: bool,
+) -> Result<(), IggyError>
+where
+    T: AsyncRead + AsyncWrite + Unpin,
+{
+    let payload_length = payload.iter().map(Bytes::len).sum::<usize>();
+    let mut frame = Vec::with_capacity(STATUS_OK.len() + 4 + payload_length + 4);
+    frame.extend_from_slice(STATUS_OK);
+    frame.extend_from_slice(&(payload_length as u32).to_le_bytes());
+    for bytes in payload {
+        frame.extend_from_slice(bytes);
+    }
+    if frame_checksums {
+        frame.extend_from_slice(&checksum::calculate_crc32c(&frame).to_le_bytes());
+    }
+    debug!(
+        "Sending coalesced response with {} slices...",
+        payload.len()
+    );
+    stream
+        .write_all(&frame)
+        .await
+        .map_err(|_| IggyError::TcpError)?;
+    debug!("Sent coalesced response with status: {:?}", STATUS_OK);
+    Ok(())
 }
 
 pub(crate) async fn send_error_response<T>(
     stream: &mut T,
     error: IggyError,
+    frame_checksums: bool,
+    error_categories: bool,
 ) -> Result<(), IggyError>
 where
     T: AsyncRead + AsyncWrite + Unpin,
 {
-    send_response(stream, &error.as_code().to_le_bytes(), &[]).await
+    let category = [error.category().as_code()];
+    let payload: &[u8] = if error_categories { &category } else { &[] };
+    send_response(
+        stream,
+        &error.as_code().to_le_bytes(),
+        payload,
+        frame_checksums,
+    )
+    .await
 }
 
+/// Sends the response frame, followed by its CRC32C checksum if the frame checksums have been negotiated.
+/// The frame is copied into a single buffer and written at once, as the TLS streams don't support the vectored writes
+/// and would otherwise write every part as a separate record.
 pub(crate) async fn send_response<T>(
     stream: &mut T,
     status: &[u8],
     payload: &[u8],
+    frame_checksums: bool,
 ) -> Result<(), IggyError>
 where
     T: AsyncRead + AsyncWrite + Unpin,
 {
     debug!("Sending response with status: {:?}...", status);
     let length = (payload.len() as u32).to_le_bytes();
+    let mut frame = Vec::with_capacity(status.len() + length.len() + payload.len() + 4);
+    frame.extend_from_slice(status);
+    frame.extend_from_slice(&length);
+    frame.extend_from_slice(payload);
+    if frame_checksums {
+        frame.extend_from_slice(&checksum::calculate_crc32c(&frame).to_le_bytes());
+    }
     stream
-        .write_all(&[status, &length, payload].as_slice().concat())
+        .write_all(&frame)
         .await
         .map_err(|_| IggyError::TcpError)?;
     debug!("Sent response with status: {:?}", status);
     Ok(())
 }
+
+/// Writes all the slices without copying them into a contiguous buffer,
+/// the streams not supporting the vectored writes fall back to writing the slices one by one.
+/// It's meant for the plain TCP and UDS streams, the TLS streams use `send_ok_response_coalesced` instead.
+pub(crate) async fn write_all_vectored<T>(
+    stream: &mut T,
+    mut slices: &mut [IoSlice<'_>],
+) -> Result<(), IggyError>
+where
+    T: AsyncWrite + Unpin,
+{
+    while !slices.is_empty() {
+        let written = stream
+            .write_vectored(slices)
+            .await
+            .map_err(|_| IggyError::TcpError)?;
+        if written == 0 {
+            return Err(IggyError::TcpError);
+        }
+
+        IoSlice::advance_slices(&mut slices, written);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::duplex;
+
+    #[tokio::test]
+    async fn coalesced_response_should_be_the_same_as_vectored_one() {
+        let payload = [
+            Bytes::from_static(b"header"),
+            Bytes::from(vec![7; 5000]),
+            Bytes::new(),
+            Bytes::from_static(b"tail"),
+        ];
+        let payload = &payload;
+        for frame_checksums in [false, true] {
+            let vectored = read_frame(|mut stream| async move {
+                send_ok_response_vectored(&mut stream, payload, frame_checksums)
+                    .await
+                    .unwrap();
+            })
+            .await;
+            let coalesced = read_frame(|mut stream| async move {
+                send_ok_response_coalesced(&mut stream, payload, frame_checksums)
+                    .await
+                    .unwrap();
+            })
+            .await;
+            assert_eq!(vectored, coalesced);
+            assert_eq!(
+                vectored.len(),
+                8 + 5010 + if frame_checksums { 4 } else { 0 }
+            );
+        }
+    }
+
+    async fn read_frame<F, Fut>(send: F) -> Vec<u8>
+    where
+        F: FnOnce(tokio::io::DuplexStream) -> Fut,
+        Fut: std::future::Future<Output = ()>,
+    {
+        let (mut client, server) = duplex(64 * 1024);
+        send(server).await;
+        let mut frame = Vec::new();
+        client.read_to_end(&mut frame).await.unwrap();
+        frame
+    }
+}
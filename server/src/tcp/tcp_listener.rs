@@ -18,14 +18,26 @@
 
 use crate::binary::sender::SenderKind;
 use crate::streaming::clients::client_manager::Transport;
+use crate::streaming::clients::transport_limiter::TransportLimiter;
 use crate::streaming::systems::system::SharedSystem;
 use crate::tcp::connection_handler::{handle_connection, handle_error};
+use crate::tcp::connection_registry::ConnectionRegistry;
+use iggy::utils::duration::IggyDuration;
 use std::net::SocketAddr;
+use std::sync::Arc;
 use tokio::net::TcpSocket;
 use tokio::sync::oneshot;
-use tracing::{error, info};
+use tracing::{error, info, trace, warn};
 
-pub async fn start(address: &str, socket: TcpSocket, system: SharedSystem) -> SocketAddr {
+pub async fn start(
+    address: &str,
+    socket: TcpSocket,
+    zero_copy: bool,
+    system: SharedSystem,
+    limiter: Arc<TransportLimiter>,
+    registry: Arc<ConnectionRegistry>,
+    heartbeat_timeout: Option<IggyDuration>,
+) -> SocketAddr {
     let address = address.to_string();
     let (tx, rx) = oneshot::channel();
     tokio::spawn(async move {
@@ -54,7 +66,18 @@ pub async fn start(address: &str, socket: TcpSocket, system: SharedSystem) -> So
         loop {
             match listener.accept().await {
                 Ok((stream, address)) => {
-                    info!("Accepted new TCP connection: {address}");
+                    let connection_permit = match limiter.try_acquire_connection() {
+                        Ok(permit) => permit,
+                        Err(error) => {
+                            warn!("Rejected TCP connection: {address}. {error}");
+                            continue;
+                        }
+                    };
+
+                    info!(
+                        "Accepted new TCP connection: {address} by acceptor #{}",
+                        registry.acceptor_id()
+                    );
                     let session = system
                         .read()
                         .await
@@ -62,13 +85,28 @@ pub async fn start(address: &str, socket: TcpSocket, system: SharedSystem) -> So
                         .await;
 
                     let client_id = session.client_id;
+                    let connections_count = registry.register(client_id, address);
+                    trace!(
+                        "Acceptor #{} has {connections_count} open connection(s).",
+                        registry.acceptor_id()
+                    );
                     info!("Created new session: {session}");
                     let system = system.clone();
-                    let mut sender = SenderKind::get_tcp_sender(stream);
+                    let mut sender = SenderKind::get_tcp_sender(stream, zero_copy);
+                    let limiter = limiter.clone();
+                    let registry = registry.clone();
                     tokio::spawn(async move {
-                        if let Err(error) =
-                            handle_connection(session, &mut sender, system.clone()).await
-                        {
+                        let _connection_permit = connection_permit;
+                        let result = handle_connection(
+                            session,
+                            &mut sender,
+                            system.clone(),
+                            &limiter,
+                            heartbeat_timeout,
+                        )
+                        .await;
+                        registry.unregister(client_id);
+                        if let Err(error) = result {
                             handle_error(error);
                             system.read().await.delete_client(client_id).await;
                             if let Err(error) = sender.shutdown().await {
@@ -17,15 +17,117 @@
  */
 
 use crate::binary::sender::Sender;
+use crate::streaming::segments::message_slices::Slice;
 use crate::tcp::COMPONENT;
 use crate::{server_error::ServerError, tcp::sender};
+use bytes::Bytes;
 use error_set::ErrContext;
 use iggy::error::IggyError;
+use std::fs::File;
+use std::io::IoSlice;
 use tokio::{io::AsyncWriteExt, net::TcpStream};
 
+const STATUS_OK: &[u8] = &[0; 4];
+
 #[derive(Debug)]
 pub struct TcpSender {
     pub(crate) stream: TcpStream,
+    pub(crate) frame_checksums: bool,
+    pub(crate) error_categories: bool,
+    /// Whether the polled messages can be sent directly from the segment files.
+    pub(crate) zero_copy: bool,
+}
+
+impl TcpSender {
+    /// Sends the payload consisting of the slices held in memory or stored in the files,
+    /// the latter are sent by the kernel without copying them into the user space.
+    /// The frame checksums aren't supported, as they would require reading the files anyway.
+    pub(crate) async fn send_ok_response_slices(
+        &mut self,
+        payload: &[Slice],
+    ) -> Result<(), IggyError> {
+        let length = (payload.iter().map(Slice::len).sum::<u64>() as u32).to_le_bytes();
+        let mut buffered = vec![
+            Bytes::from_static(STATUS_OK),
+            Bytes::copy_from_slice(&length),
+        ];
+        for slice in payload {
+            match slice {
+                Slice::Bytes(bytes) => buffered.push(bytes.clone()),
+                Slice::File {
+                    file,
+                    position,
+                    length,
+                } => {
+                    self.write_buffered(&mut buffered).await?;
+                    send_file(&mut self.stream, file, *position, *length).await?;
+                }
+            }
+        }
+        self.write_buffered(&mut buffered).await
+    }
+
+    async fn write_buffered(&mut self, buffered: &mut Vec<Bytes>) -> Result<(), IggyError> {
+        let mut slices = buffered
+            .iter()
+            .map(|bytes| IoSlice::new(bytes))
+            .collect::<Vec<_>>();
+        sender::write_all_vectored(&mut self.stream, &mut slices).await?;
+        buffered.clear();
+        Ok(())
+    }
+}
+
+#[cfg(target_os = "linux")]
+async fn send_file(
+    stream: &mut TcpStream,
+    file: &File,
+    mut position: u64,
+    length: u64,
+) -> Result<(), IggyError> {
+    use std::os::fd::AsFd;
+    use tokio::io::Interest;
+
+    let end_position = position + length;
+    while position < end_position {
+        stream.writable().await.map_err(|_| IggyError::TcpError)?;
+        let mut offset = position as i64;
+        let result = stream.try_io(Interest::WRITABLE, || {
+            nix::sys::sendfile::sendfile(
+                stream.as_fd(),
+                file.as_fd(),
+                Some(&mut offset),
+                (end_position - position) as usize,
+            )
+            .map_err(std::io::Error::from)
+        });
+        match result {
+            // The file has been truncated, e.g. the segment has been deleted in the meantime.
+            Ok(0) => return Err(IggyError::TcpError),
+            Ok(sent) => position += sent as u64,
+            Err(error) if error.kind() == std::io::ErrorKind::WouldBlock => continue,
+            Err(_) => return Err(IggyError::TcpError),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(not(target_os = "linux"))]
+async fn send_file(
+    stream: &mut TcpStream,
+    file: &File,
+    position: u64,
+    length: u64,
+) -> Result<(), IggyError> {
+    use std::os::unix::fs::FileExt;
+
+    let mut buffer = vec![0u8; length as usize];
+    file.read_exact_at(&mut buffer, position)
+        .map_err(|_| IggyError::CannotReadMessage)?;
+    stream
+        .write_all(&buffer)
+        .await
+        .map_err(|_| IggyError::TcpError)
 }
 
 impl Sender for TcpSender {
@@ -34,15 +136,41 @@ impl Sender for TcpSender {
     }
 
     async fn send_empty_ok_response(&mut self) -> Result<(), IggyError> {
-        sender::send_empty_ok_response(&mut self.stream).await
+        sender::send_empty_ok_response(&mut self.stream, self.frame_checksums).await
     }
 
     async fn send_ok_response(&mut self, payload: &[u8]) -> Result<(), IggyError> {
-        sender::send_ok_response(&mut self.stream, payload).await
+        sender::send_ok_response(&mut self.stream, payload, self.frame_checksums).await
+    }
+
+    async fn send_ok_response_vectored(&mut self, payload: &[Bytes]) -> Result<(), IggyError> {
+        sender::send_ok_response_vectored(&mut self.stream, payload, self.frame_checksums).await
     }
 
     async fn send_error_response(&mut self, error: IggyError) -> Result<(), IggyError> {
-        sender::send_error_response(&mut self.stream, error).await
+        sender::send_error_response(
+            &mut self.stream,
+            error,
+            self.frame_checksums,
+            self.error_categories,
+        )
+        .await
+    }
+
+    fn supports_frame_checksums(&self) -> bool {
+        true
+    }
+
+    fn frame_checksums(&self) -> bool {
+        self.frame_checksums
+    }
+
+    fn set_frame_checksums(&mut self, enabled: bool) {
+        self.frame_checksums = enabled;
+    }
+
+    fn set_error_categories(&mut self, enabled: bool) {
+        self.error_categories = enabled;
     }
 
     async fn shutdown(&mut self) -> Result<(), ServerError> {
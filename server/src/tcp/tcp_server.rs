@@ -17,12 +17,15 @@
  */
 
 use crate::configs::tcp::TcpConfig;
+use crate::streaming::clients::transport_limiter::TransportLimiter;
 use crate::streaming::systems::system::SharedSystem;
+use crate::tcp::connection_registry::ConnectionRegistry;
 use crate::tcp::{tcp_listener, tcp_socket, tcp_tls_listener};
 use std::net::SocketAddr;
+use std::sync::Arc;
 use tracing::info;
 
-/// Starts the TCP server.
+/// Starts the TCP server with the configured number of acceptors.
 /// Returns the address the server is listening on.
 pub async fn start(config: TcpConfig, system: SharedSystem) -> SocketAddr {
     let server_name = if config.tls.enabled {
@@ -30,12 +33,49 @@ pub async fn start(config: TcpConfig, system: SharedSystem) -> SocketAddr {
     } else {
         "Iggy TCP"
     };
-    info!("Initializing {server_name} server...");
-    let socket = tcp_socket::build(config.ipv6, config.socket);
-    let addr = match config.tls.enabled {
-        true => tcp_tls_listener::start(&config.address, config.tls, socket, system).await,
-        false => tcp_listener::start(&config.address, socket, system).await,
-    };
+    info!(
+        "Initializing {server_name} server with {} acceptor(s)...",
+        config.acceptors
+    );
+    let limiter = TransportLimiter::register("TCP", &config.limits);
+    let heartbeat_timeout = config.heartbeat.enabled.then_some(config.heartbeat.timeout);
+    let reuse_port = config.acceptors > 1;
+    let mut address = config.address.clone();
+    let mut local_addr = None;
+    for acceptor_id in 1..=config.acceptors {
+        let socket = tcp_socket::build(config.ipv6, reuse_port, config.socket.clone());
+        let registry = Arc::new(ConnectionRegistry::new(acceptor_id));
+        let addr = match config.tls.enabled {
+            true => {
+                tcp_tls_listener::start(
+                    &address,
+                    config.tls.clone(),
+                    socket,
+                    system.clone(),
+                    limiter.clone(),
+                    registry,
+                    heartbeat_timeout,
+                )
+                .await
+            }
+            false => {
+                tcp_listener::start(
+                    &address,
+                    socket,
+                    config.zero_copy,
+                    system.clone(),
+                    limiter.clone(),
+                    registry,
+                    heartbeat_timeout,
+                )
+                .await
+            }
+        };
+        // The remaining acceptors must bind to the resolved address, e.g. when the port is assigned by the OS.
+        address = addr.to_string();
+        local_addr.get_or_insert(addr);
+    }
+    let addr = local_addr.expect("At least one TCP acceptor must be started");
     info!("{server_name} server has started on: {:?}", addr);
     addr
 }
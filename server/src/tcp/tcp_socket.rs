@@ -22,13 +22,19 @@ use tokio::net::TcpSocket;
 
 use crate::configs::tcp::TcpSocketConfig;
 
-pub fn build(ipv6: bool, config: TcpSocketConfig) -> TcpSocket {
+pub fn build(ipv6: bool, reuse_port: bool, config: TcpSocketConfig) -> TcpSocket {
     let socket = if ipv6 {
         TcpSocket::new_v6().expect("Unable to create an ipv6 socket")
     } else {
         TcpSocket::new_v4().expect("Unable to create an ipv4 socket")
     };
 
+    if reuse_port {
+        socket
+            .set_reuseport(true)
+            .expect("Unable to set SO_REUSEPORT on socket");
+    }
+
     if config.override_defaults {
         config
             .recv_buffer_size
@@ -78,11 +84,17 @@ mod tests {
             nodelay: true,
             linger: IggyDuration::new(linger_dur),
         };
-        let socket = build(false, config);
+        let socket = build(false, false, config);
         assert!(socket.recv_buffer_size().unwrap() >= buffer_size as u32);
         assert!(socket.send_buffer_size().unwrap() >= buffer_size as u32);
         assert!(socket.keepalive().unwrap());
         assert!(socket.nodelay().unwrap());
         assert_eq!(socket.linger().unwrap(), Some(linger_dur));
     }
+
+    #[test]
+    fn given_reuse_port_socket_should_be_configured() {
+        let socket = build(false, true, TcpSocketConfig::default());
+        assert!(socket.reuseport().unwrap());
+    }
 }
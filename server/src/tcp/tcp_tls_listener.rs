@@ -18,40 +18,104 @@
 
 use crate::binary::sender::SenderKind;
 use crate::configs::tcp::TcpTlsConfig;
+use crate::quic::certificates::{create_client_verifier, load_certificates};
 use crate::streaming::clients::client_manager::Transport;
+use crate::streaming::clients::transport_limiter::TransportLimiter;
 use crate::streaming::systems::system::SharedSystem;
 use crate::tcp::connection_handler::{handle_connection, handle_error};
+use crate::tcp::connection_registry::ConnectionRegistry;
+use crate::tcp::tcp_tls_sender::TcpTlsStream;
+use iggy::utils::duration::IggyDuration;
 use std::net::SocketAddr;
-use tokio::net::TcpSocket;
+use std::sync::Arc;
+use tokio::net::{TcpSocket, TcpStream};
 use tokio::sync::oneshot;
 use tokio_native_tls::native_tls;
 use tokio_native_tls::native_tls::Identity;
-use tracing::{error, info};
+use tokio_util::either::Either;
+use tracing::{error, info, trace, warn};
+
+/// Performs the TLS handshake with `native-tls`, or with `rustls` if the client certificates
+/// are verified against the `client_ca_file` CA bundle (mutual TLS).
+enum TlsAcceptor {
+    Native(tokio_native_tls::TlsAcceptor),
+    Rustls(tokio_rustls::TlsAcceptor),
+}
+
+impl TlsAcceptor {
+    fn new(config: &TcpTlsConfig) -> Self {
+        if config.client_ca_file.is_empty() {
+            let certificate = std::fs::read(config.certificate.clone());
+            if certificate.is_err() {
+                panic!("Unable to read certificate file.");
+            }
+
+            let identity = Identity::from_pkcs12(&certificate.unwrap(), &config.password);
+            if identity.is_err() {
+                panic!("Unable to create identity from certificate.");
+            }
+
+            return Self::Native(tokio_native_tls::TlsAcceptor::from(
+                native_tls::TlsAcceptor::builder(identity.unwrap())
+                    .build()
+                    .unwrap(),
+            ));
+        }
+
+        let (certificates, key) = load_certificates(&config.cert_file, &config.key_file)
+            .unwrap_or_else(|error| panic!("Unable to load TLS certificate. {error}"));
+        let verifier =
+            create_client_verifier(&config.client_ca_file, config.require_client_certificate)
+                .unwrap_or_else(|error| panic!("Unable to load client CA file. {error}"));
+        let tls_config = rustls::ServerConfig::builder_with_provider(Arc::new(
+            rustls::crypto::ring::default_provider(),
+        ))
+        .with_safe_default_protocol_versions()
+        .expect("Unable to create TLS config.")
+        .with_client_cert_verifier(verifier)
+        .with_single_cert(certificates, key)
+        .expect("Unable to create TLS config with certificate.");
+        Self::Rustls(tokio_rustls::TlsAcceptor::from(Arc::new(tls_config)))
+    }
+
+    /// Returns the stream along with the DER encoded certificate presented by the client, if any.
+    async fn accept(
+        &self,
+        stream: TcpStream,
+    ) -> Result<(TcpTlsStream, Option<Vec<u8>>), std::io::Error> {
+        match self {
+            Self::Native(acceptor) => acceptor
+                .accept(stream)
+                .await
+                .map(|stream| (Either::Left(stream), None))
+                .map_err(std::io::Error::other),
+            Self::Rustls(acceptor) => {
+                let stream = acceptor.accept(stream).await?;
+                let peer_certificate = stream
+                    .get_ref()
+                    .1
+                    .peer_certificates()
+                    .and_then(|certificates| certificates.first())
+                    .map(|certificate| certificate.to_vec());
+                Ok((Either::Right(stream), peer_certificate))
+            }
+        }
+    }
+}
 
 pub(crate) async fn start(
     address: &str,
     config: TcpTlsConfig,
     socket: TcpSocket,
     system: SharedSystem,
+    limiter: Arc<TransportLimiter>,
+    registry: Arc<ConnectionRegistry>,
+    heartbeat_timeout: Option<IggyDuration>,
 ) -> SocketAddr {
     let address = address.to_string();
     let (tx, rx) = oneshot::channel();
     tokio::spawn(async move {
-        let certificate = std::fs::read(config.certificate.clone());
-        if certificate.is_err() {
-            panic!("Unable to read certificate file.");
-        }
-
-        let identity = Identity::from_pkcs12(&certificate.unwrap(), &config.password);
-        if identity.is_err() {
-            panic!("Unable to create identity from certificate.");
-        }
-
-        let acceptor = tokio_native_tls::TlsAcceptor::from(
-            native_tls::TlsAcceptor::builder(identity.unwrap())
-                .build()
-                .unwrap(),
-        );
+        let acceptor = TlsAcceptor::new(&config);
 
         let addr = address.parse();
         if addr.is_err() {
@@ -80,22 +144,65 @@ pub(crate) async fn start(
         loop {
             match listener.accept().await {
                 Ok((stream, address)) => {
-                    info!("Accepted new TCP TLS connection: {}", address);
+                    let connection_permit = match limiter.try_acquire_connection() {
+                        Ok(permit) => permit,
+                        Err(error) => {
+                            warn!("Rejected TCP TLS connection: {address}. {error}");
+                            continue;
+                        }
+                    };
+
+                    info!(
+                        "Accepted new TCP TLS connection: {address} by acceptor #{}",
+                        registry.acceptor_id()
+                    );
+                    let (stream, peer_certificate) = match acceptor.accept(stream).await {
+                        Ok(accepted) => accepted,
+                        Err(error) => {
+                            warn!("TLS handshake with: {address} has failed. {error}");
+                            continue;
+                        }
+                    };
+
                     let session = system
                         .read()
                         .await
                         .add_client(&address, Transport::Tcp)
                         .await;
+                    if let Some(peer_certificate) = peer_certificate {
+                        session.set_peer_certificate(peer_certificate);
+                        if let Err(error) = system
+                            .read()
+                            .await
+                            .login_user_with_certificate(&session)
+                            .await
+                        {
+                            warn!("Cannot login client: {address} with the certificate. {error}");
+                        }
+                    }
 
                     let client_id = session.client_id;
-                    let acceptor = acceptor.clone();
-                    let stream = acceptor.accept(stream).await.unwrap();
+                    let connections_count = registry.register(client_id, address);
+                    trace!(
+                        "Acceptor #{} has {connections_count} open connection(s).",
+                        registry.acceptor_id()
+                    );
                     let system = system.clone();
                     let mut sender = SenderKind::get_tcp_tls_sender(stream);
+                    let limiter = limiter.clone();
+                    let registry = registry.clone();
                     tokio::spawn(async move {
-                        if let Err(error) =
-                            handle_connection(session, &mut sender, system.clone()).await
-                        {
+                        let _connection_permit = connection_permit;
+                        let result = handle_connection(
+                            session,
+                            &mut sender,
+                            system.clone(),
+                            &limiter,
+                            heartbeat_timeout,
+                        )
+                        .await;
+                        registry.unregister(client_id);
+                        if let Err(error) = result {
                             handle_error(error);
                             system.read().await.delete_client(client_id).await;
                             if let Err(error) = sender.shutdown().await {
@@ -19,15 +19,22 @@
 use crate::binary::sender::Sender;
 use crate::tcp::COMPONENT;
 use crate::{server_error::ServerError, tcp::sender};
+use bytes::Bytes;
 use error_set::ErrContext;
 use iggy::error::IggyError;
 use tokio::io::AsyncWriteExt;
 use tokio::net::TcpStream;
-use tokio_native_tls::TlsStream;
+use tokio_util::either::Either;
+
+/// TLS stream established with `native-tls`, or with `rustls` if the client certificates are verified.
+pub type TcpTlsStream =
+    Either<tokio_native_tls::TlsStream<TcpStream>, tokio_rustls::server::TlsStream<TcpStream>>;
 
 #[derive(Debug)]
 pub struct TcpTlsSender {
-    pub(crate) stream: TlsStream<TcpStream>,
+    pub(crate) stream: TcpTlsStream,
+    pub(crate) frame_checksums: bool,
+    pub(crate) error_categories: bool,
 }
 
 impl Sender for TcpTlsSender {
@@ -36,15 +43,41 @@ impl Sender for TcpTlsSender {
     }
 
     async fn send_empty_ok_response(&mut self) -> Result<(), IggyError> {
-        sender::send_empty_ok_response(&mut self.stream).await
+        sender::send_empty_ok_response(&mut self.stream, self.frame_checksums).await
     }
 
     async fn send_ok_response(&mut self, payload: &[u8]) -> Result<(), IggyError> {
-        sender::send_ok_response(&mut self.stream, payload).await
+        sender::send_ok_response(&mut self.stream, payload, self.frame_checksums).await
+    }
+
+    async fn send_ok_response_vectored(&mut self, payload: &[Bytes]) -> Result<(), IggyError> {
+        sender::send_ok_response_coalesced(&mut self.stream, payload, self.frame_checksums).await
     }
 
     async fn send_error_response(&mut self, error: IggyError) -> Result<(), IggyError> {
-        sender::send_error_response(&mut self.stream, error).await
+        sender::send_error_response(
+            &mut self.stream,
+            error,
+            self.frame_checksums,
+            self.error_categories,
+        )
+        .await
+    }
+
+    fn supports_frame_checksums(&self) -> bool {
+        true
+    }
+
+    fn frame_checksums(&self) -> bool {
+        self.frame_checksums
+    }
+
+    fn set_frame_checksums(&mut self, enabled: bool) {
+        self.frame_checksums = enabled;
+    }
+
+    fn set_error_categories(&mut self, enabled: bool) {
+        self.error_categories = enabled;
     }
 
     async fn shutdown(&mut self) -> Result<(), ServerError> {
@@ -0,0 +1,144 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::sender::SenderKind;
+use crate::configs::uds::UdsConfig;
+use crate::streaming::clients::client_manager::Transport;
+use crate::streaming::clients::transport_limiter::TransportLimiter;
+use crate::streaming::systems::system::SharedSystem;
+use crate::tcp::connection_handler::{handle_connection, handle_error};
+use std::net::{Ipv6Addr, SocketAddr, SocketAddrV6};
+use std::os::unix::fs::PermissionsExt;
+use std::path::Path;
+use std::sync::atomic::{AtomicU32, Ordering};
+use tokio::net::UnixListener;
+use tracing::{error, info, warn};
+
+static NEXT_CLIENT_ID: AtomicU32 = AtomicU32::new(1);
+
+pub async fn start(config: UdsConfig, system: SharedSystem) -> String {
+    let path = config.path;
+    if tokio::fs::try_exists(&path).await.unwrap_or(false) {
+        info!("Removing the existing UDS socket file: {path}");
+        tokio::fs::remove_file(&path)
+            .await
+            .unwrap_or_else(|error| panic!("Unable to remove UDS socket file {path}: {error}"));
+    }
+
+    if let Some(parent) = Path::new(&path).parent() {
+        if !parent.as_os_str().is_empty() && !parent.exists() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .unwrap_or_else(|error| {
+                    panic!("Unable to create directory for UDS socket file {path}: {error}")
+                });
+        }
+    }
+
+    let listener = UnixListener::bind(&path)
+        .unwrap_or_else(|error| panic!("Unable to bind UDS socket to path {path}: {error}"));
+    std::fs::set_permissions(&path, std::fs::Permissions::from_mode(config.permissions))
+        .unwrap_or_else(|error| {
+            panic!(
+                "Unable to set permissions {:o} for UDS socket file {path}: {error}",
+                config.permissions
+            )
+        });
+
+    let trusted_username = config.trusted_username;
+    let limiter = TransportLimiter::register("UDS", &config.limits);
+    tokio::spawn(async move {
+        loop {
+            match listener.accept().await {
+                Ok((stream, _)) => {
+                    let connection_permit = match limiter.try_acquire_connection() {
+                        Ok(permit) => permit,
+                        Err(error) => {
+                            warn!("Rejected UDS connection. {error}");
+                            continue;
+                        }
+                    };
+
+                    let address = next_client_address();
+                    info!("Accepted new UDS connection: {address}");
+                    let session = system
+                        .read()
+                        .await
+                        .add_client(&address, Transport::Uds)
+                        .await;
+
+                    if !trusted_username.is_empty() {
+                        if let Err(error) = system
+                            .read()
+                            .await
+                            .login_user_with_credentials(&trusted_username, None, Some(&session))
+                            .await
+                        {
+                            error!("Failed to authenticate UDS client: {address} as trusted user: {trusted_username}. {error}");
+                        }
+                    }
+
+                    let client_id = session.client_id;
+                    info!("Created new session: {session}");
+                    let system = system.clone();
+                    let mut sender = SenderKind::get_uds_sender(stream);
+                    let limiter = limiter.clone();
+                    tokio::spawn(async move {
+                        let _connection_permit = connection_permit;
+                        if let Err(error) =
+                            handle_connection(session, &mut sender, system.clone(), &limiter, None)
+                                .await
+                        {
+                            handle_error(error);
+                            system.read().await.delete_client(client_id).await;
+                            if let Err(error) = sender.shutdown().await {
+                                error!("Failed to shutdown UDS stream for client: {client_id}, address: {address}. {error}");
+                            } else {
+                                info!("Successfully closed UDS stream for client: {client_id}, address: {address}.");
+                            }
+                        }
+                    });
+                }
+                Err(error) => error!("Unable to accept UDS socket. {error}"),
+            }
+        }
+    });
+
+    path
+}
+
+/// Unix sockets have no peer address, so each connection gets a unique, non-routable one,
+/// as the clients (and their IDs) are identified by the address.
+fn next_client_address() -> SocketAddr {
+    let id = NEXT_CLIENT_ID.fetch_add(1, Ordering::Relaxed);
+    SocketAddr::V6(SocketAddrV6::new(Ipv6Addr::UNSPECIFIED, 0, 0, id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn each_client_should_get_unique_address() {
+        let first = next_client_address();
+        let second = next_client_address();
+        assert_ne!(first, second);
+        assert_ne!(first.to_string(), second.to_string());
+        assert!(first.ip().is_unspecified());
+    }
+}
@@ -0,0 +1,98 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::sender::SenderKind;
+use crate::configs::websocket::WebSocketConfig;
+use crate::streaming::clients::client_manager::Transport;
+use crate::streaming::clients::transport_limiter::TransportLimiter;
+use crate::streaming::systems::system::SharedSystem;
+use crate::tcp::connection_handler::{handle_connection, handle_error};
+use std::net::SocketAddr;
+use tokio::net::TcpListener;
+use tracing::{error, info, warn};
+
+pub async fn start(config: WebSocketConfig, system: SharedSystem) -> SocketAddr {
+    let listener = TcpListener::bind(&config.address)
+        .await
+        .unwrap_or_else(|error| {
+            panic!(
+                "Unable to bind WebSocket server to address {}: {error}",
+                config.address
+            )
+        });
+    let local_addr = listener
+        .local_addr()
+        .expect("Failed to get local address for WebSocket listener");
+
+    let limiter = TransportLimiter::register("WebSocket", &config.limits);
+    tokio::spawn(async move {
+        loop {
+            match listener.accept().await {
+                Ok((stream, address)) => {
+                    let connection_permit = match limiter.try_acquire_connection() {
+                        Ok(permit) => permit,
+                        Err(error) => {
+                            warn!("Rejected WebSocket connection: {address}. {error}");
+                            continue;
+                        }
+                    };
+
+                    let system = system.clone();
+                    let limiter = limiter.clone();
+                    // The handshake is completed in the separate task, so that the slow clients don't block the listener.
+                    tokio::spawn(async move {
+                        let _connection_permit = connection_permit;
+                        let stream = match tokio_tungstenite::accept_async(stream).await {
+                            Ok(stream) => stream,
+                            Err(error) => {
+                                warn!("Failed to complete WebSocket handshake with: {address}. {error}");
+                                return;
+                            }
+                        };
+
+                        info!("Accepted new WebSocket connection: {address}");
+                        let session = system
+                            .read()
+                            .await
+                            .add_client(&address, Transport::WebSocket)
+                            .await;
+
+                        let client_id = session.client_id;
+                        info!("Created new session: {session}");
+                        let mut sender = SenderKind::get_websocket_sender(stream);
+                        if let Err(error) =
+                            handle_connection(session, &mut sender, system.clone(), &limiter, None)
+                                .await
+                        {
+                            handle_error(error);
+                            system.read().await.delete_client(client_id).await;
+                            if let Err(error) = sender.shutdown().await {
+                                error!("Failed to shutdown WebSocket stream for client: {client_id}, address: {address}. {error}");
+                            } else {
+                                info!("Successfully closed WebSocket stream for client: {client_id}, address: {address}.");
+                            }
+                        }
+                    });
+                }
+                Err(error) => error!("Unable to accept WebSocket connection. {error}"),
+            }
+        }
+    });
+
+    local_addr
+}
@@ -0,0 +1,118 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::sender::Sender;
+use crate::server_error::ServerError;
+use crate::websocket::COMPONENT;
+use bytes::{BufMut, Bytes, BytesMut};
+use error_set::ErrContext;
+use futures::{SinkExt, StreamExt};
+use iggy::error::IggyError;
+use tokio::net::TcpStream;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::WebSocketStream;
+use tracing::debug;
+
+const STATUS_OK: &[u8] = &[0; 4];
+
+/// Each response is sent as a single binary message, while the requests are read as a stream of bytes,
+/// so that the clients can split them into the binary messages in any way.
+#[derive(Debug)]
+pub struct WebSocketSender {
+    pub(crate) stream: WebSocketStream<TcpStream>,
+    /// The received bytes which have not been read yet.
+    pub(crate) buffer: BytesMut,
+    pub(crate) error_categories: bool,
+}
+
+impl WebSocketSender {
+    async fn send_response(&mut self, status: &[u8], payload: &[Bytes]) -> Result<(), IggyError> {
+        debug!("Sending response with status: {:?}...", status);
+        let length = payload.iter().map(Bytes::len).sum::<usize>();
+        let mut bytes = BytesMut::with_capacity(8 + length);
+        bytes.put_slice(status);
+        bytes.put_u32_le(length as u32);
+        for slice in payload {
+            bytes.put_slice(slice);
+        }
+        self.stream
+            .send(Message::binary(bytes.freeze()))
+            .await
+            .map_err(|_| IggyError::TcpError)?;
+        debug!("Sent response with status: {:?}", status);
+        Ok(())
+    }
+}
+
+impl Sender for WebSocketSender {
+    async fn read(&mut self, buffer: &mut [u8]) -> Result<usize, IggyError> {
+        while self.buffer.len() < buffer.len() {
+            match self.stream.next().await {
+                Some(Ok(Message::Binary(data))) => self.buffer.extend_from_slice(&data),
+                Some(Ok(Message::Close(_))) | None => return Err(IggyError::ConnectionClosed),
+                // The pings are answered by the stream itself, and the text messages are not part of the protocol.
+                Some(Ok(_)) => continue,
+                Some(Err(error)) => {
+                    debug!("{COMPONENT} - failed to read WebSocket message. {error}");
+                    return Err(IggyError::ConnectionClosed);
+                }
+            }
+        }
+
+        buffer.copy_from_slice(&self.buffer.split_to(buffer.len()));
+        Ok(buffer.len())
+    }
+
+    async fn send_empty_ok_response(&mut self) -> Result<(), IggyError> {
+        self.send_response(STATUS_OK, &[]).await
+    }
+
+    async fn send_ok_response(&mut self, payload: &[u8]) -> Result<(), IggyError> {
+        self.send_response(STATUS_OK, &[Bytes::copy_from_slice(payload)])
+            .await
+    }
+
+    async fn send_ok_response_vectored(&mut self, payload: &[Bytes]) -> Result<(), IggyError> {
+        self.send_response(STATUS_OK, payload).await
+    }
+
+    async fn send_error_response(&mut self, error: IggyError) -> Result<(), IggyError> {
+        let payload = if self.error_categories {
+            vec![Bytes::copy_from_slice(&[error.category().as_code()])]
+        } else {
+            Vec::new()
+        };
+        self.send_response(&error.as_code().to_le_bytes(), &payload)
+            .await
+    }
+
+    fn set_error_categories(&mut self, enabled: bool) {
+        self.error_categories = enabled;
+    }
+
+    async fn shutdown(&mut self) -> Result<(), ServerError> {
+        self.stream
+            .close(None)
+            .await
+            .map_err(std::io::Error::other)
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to close WebSocket stream")
+            })
+            .map_err(ServerError::IoError)
+    }
+}
@@ -0,0 +1,32 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::configs::websocket::WebSocketConfig;
+use crate::streaming::systems::system::SharedSystem;
+use crate::websocket::websocket_listener;
+use std::net::SocketAddr;
+use tracing::info;
+
+/// Starts the WebSocket server.
+/// Returns the address the server is listening on.
+pub async fn start(config: WebSocketConfig, system: SharedSystem) -> SocketAddr {
+    info!("Initializing Iggy WebSocket server...");
+    let addr = websocket_listener::start(config, system).await;
+    info!("Iggy WebSocket server has started on: {addr}");
+    addr
+}